@@ -16,11 +16,19 @@ export const commandCoverage = {
       "confirm_quit",
       "hide_to_tray",
       "update_tray_menu",
+      "pending_urls::frontend_ready",
       "get_app_settings",
       "save_app_settings",
       "read_log_files",
+      "log_buffer::get_recent_logs",
+      "audit::get_audit_log",
+      "offline::set_offline_mode",
       "get_table_sorting_settings",
       "save_table_sorting_settings",
+      "list_saved_views",
+      "create_saved_view",
+      "update_saved_view",
+      "delete_saved_view",
       "get_system_language",
       "get_system_info",
       "dismiss_window_resize_warning",
@@ -39,17 +47,46 @@ export const commandCoverage = {
       "list_browser_profiles",
       "get_all_tags",
       "update_profile_proxy",
+      "update_profile_proxy_pool",
       "update_profile_vpn",
       "update_profile_tags",
+      "rename_tag",
+      "merge_tags",
       "update_profile_note",
       "update_profile_clear_on_close",
+      "profile::disk_usage::get_profile_disk_usage",
+      "profile::disk_usage::clear_profile_cache",
+      "profile::snapshots::create_profile_snapshot",
+      "profile::snapshots::list_profile_snapshots",
+      "profile::snapshots::restore_profile_snapshot",
+      "profile::archive::archive_profile",
+      "profile::archive::unarchive_profile",
+      "profile::refresh::refresh_profile",
       "update_profile_launch_hook",
       "update_profile_window_color",
       "update_profile_proxy_bypass_rules",
+      "update_profile_startup",
+      "update_profile_preferences",
+      "update_profile_metadata",
+      "pin_profile_version",
+      "unpin_profile_version",
+      "update_profile_restart_policy",
+      "update_profile_dns_mode",
+      "update_profile_exit_mismatch_action",
+      "update_profile_resource_limits",
+      "update_profile_webrtc_mode",
+      "get_launch_queue",
+      "cancel_queued_launch",
+      "cleanup_orphan_processes",
+      "preflight::preflight_profile_launch",
+      "get_observer_mode",
+      "set_profile_shortcut",
+      "open_detached_profile_window",
       "update_profile_dns_blocklist",
       "rename_profile",
       "detect_existing_profiles",
       "import_browser_profiles",
+      "import_external_profiles",
       "scan_folder_for_profiles",
       "scan_profile_archive",
       "cleanup_profile_import_scratch",
@@ -60,6 +97,11 @@ export const commandCoverage = {
       "delete_profile_group",
       "assign_profiles_to_group",
       "delete_selected_profiles",
+      "workspace::export_workspace",
+      "workspace::import_workspace",
+      "workspaces::list_workspaces",
+      "workspaces::create_workspace",
+      "workspaces::switch_workspace",
     ],
   },
   proxyEntities: {
@@ -69,13 +111,25 @@ export const commandCoverage = {
       "create_stored_proxy",
       "get_stored_proxies",
       "update_stored_proxy",
+      "update_stored_proxy_exit_expectation",
       "delete_stored_proxy",
+      "get_proxy_pools",
+      "create_proxy_pool",
+      "update_proxy_pool",
+      "delete_proxy_pool",
+      "get_proxy_usage",
+      "get_vpn_usage",
+      "get_group_usage",
       "check_proxy_validity",
       "get_cached_proxy_check",
       "export_proxies",
       "import_proxies_json",
       "parse_txt_proxies",
       "import_proxies_from_parsed",
+      "list_proxy_sources",
+      "add_proxy_source",
+      "refresh_proxy_source",
+      "remove_proxy_source",
     ],
   },
   extensions: {
@@ -85,7 +139,10 @@ export const commandCoverage = {
       "list_extensions",
       "get_extension_icon",
       "add_extension",
+      "add_unpacked_extension",
       "update_extension",
+      "set_extension_source",
+      "check_extension_updates",
       "delete_extension",
       "list_extension_groups",
       "create_extension_group",
@@ -107,6 +164,8 @@ export const commandCoverage = {
       "delete_vpn_config",
       "create_vpn_config_manual",
       "update_vpn_config",
+      "update_vpn_auto_disconnect",
+      "update_vpn_exit_expectation",
       "check_vpn_validity",
       "disconnect_vpn",
       "get_vpn_status",
@@ -158,6 +217,9 @@ export const commandCoverage = {
       "download_browser",
       "cancel_download",
       "launch_browser_profile",
+      "get_last_launch_timings",
+      "get_profile_exit_history",
+      "get_profile_cdp_endpoint",
       "fetch_browser_versions_with_count",
       "fetch_browser_versions_cached_first",
       "fetch_browser_versions_with_count_cached_first",
@@ -169,13 +231,20 @@ export const commandCoverage = {
       "check_missing_binaries",
       "check_missing_geoip_database",
       "ensure_all_binaries_exist",
+      "cancel_ensure_binaries",
+      "verify_downloaded_browsers",
       "ensure_active_browsers_downloaded",
+      "binaries_usage::list_downloaded_browsers",
+      "binaries_usage::delete_downloaded_browser",
+      "binaries_usage::get_binaries_disk_usage",
       "update_wayfern_config",
       "generate_sample_fingerprint",
+      "wayfern_manager::generate_fingerprint",
       "is_geoip_database_available",
       "download_geoip_database",
       "fingerprint_consistency::check_profile_fingerprint_consistency",
       "fingerprint_consistency::match_profile_fingerprint_to_exit",
+      "fingerprint_quality::validate_profile_fingerprint",
       "check_wayfern_terms_accepted",
       "check_wayfern_downloaded",
       "accept_wayfern_terms",
@@ -188,6 +257,10 @@ export const commandCoverage = {
       "start_api_server",
       "stop_api_server",
       "get_api_server_status",
+      "get_api_endpoint",
+      "list_api_tokens",
+      "generate_api_token",
+      "revoke_api_token",
       "start_mcp_server",
       "stop_mcp_server",
       "get_mcp_server_status",
@@ -211,6 +284,7 @@ export const commandCoverage = {
       "set_profile_sync_mode",
       "cancel_profile_sync",
       "request_profile_sync",
+      "preview_profile_sync",
       "set_proxy_sync_enabled",
       "set_group_sync_enabled",
       "is_proxy_in_use_by_synced_profile",
@@ -220,10 +294,14 @@ export const commandCoverage = {
       "set_extension_sync_enabled",
       "set_extension_group_sync_enabled",
       "get_unsynced_entity_counts",
+      "get_sync_queue_status",
+      "get_sync_subscription_status",
       "enable_sync_for_all_entities",
       "set_e2e_password",
       "check_has_e2e_password",
       "verify_e2e_password",
+      "change_e2e_password",
+      "check_e2e_rotation_pending",
       "delete_e2e_password",
       "rollover_encryption_for_all_entities",
     ],
@@ -262,6 +340,7 @@ export const commandCoverage = {
       "check_for_browser_updates",
       "dismiss_update_notification",
       "complete_browser_update_with_auto_update",
+      "rollback_browser_update",
       "check_for_app_updates",
       "check_for_app_updates_manual",
       "download_and_prepare_app_update",
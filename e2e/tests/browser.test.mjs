@@ -67,6 +67,29 @@ function assertIdleResourceBounds(pid) {
   );
 }
 
+function appResourceSnapshot(apiPort) {
+  const appPid = execFileSync(
+    "lsof",
+    ["-t", `-iTCP:${apiPort}`, "-sTCP:LISTEN"],
+    { encoding: "utf8" },
+  )
+    .trim()
+    .split("\n")[0];
+  const fds = execFileSync("lsof", ["-p", appPid], { encoding: "utf8" })
+    .trim()
+    .split("\n").length;
+  let children = 0;
+  try {
+    children = execFileSync("pgrep", ["-P", appPid], { encoding: "utf8" })
+      .trim()
+      .split("\n")
+      .filter(Boolean).length;
+  } catch {
+    // pgrep exits 1 when there are no matches.
+  }
+  return { fds, children };
+}
+
 function realWayfernTermsPath() {
   if (process.platform === "darwin") {
     return path.join(
@@ -168,8 +191,33 @@ test("real Wayfern fingerprinting, terms, API automation, CDP, cookies, and proc
       true,
     );
     assert.deepEqual(await app.invoke("check_missing_binaries"), []);
-    assert.deepEqual(await app.invoke("ensure_all_binaries_exist"), []);
+    const ensured = await app.invoke("ensure_all_binaries_exist");
+    assert.deepEqual(
+      [ensured.downloaded, ensured.failed, ensured.skipped],
+      [[], [], []],
+    );
+    assert.equal(await app.invoke("cancel_ensure_binaries"), false);
+    const integrity = await app.invoke("verify_downloaded_browsers");
+    assert.ok(
+      integrity.some((item) => item.version === prepared.version) &&
+        integrity.every((item) => item.status !== "corrupted"),
+    );
     assert.deepEqual(await app.invoke("ensure_active_browsers_downloaded"), []);
+    const installed = await app.invoke("list_downloaded_browsers");
+    const preparedInstall = installed.find(
+      (item) => item.version === prepared.version,
+    );
+    assert.ok(preparedInstall && preparedInstall.size_bytes > 0);
+    const binariesUsage = await app.invoke("get_binaries_disk_usage");
+    assert.ok(binariesUsage.total_bytes >= preparedInstall.size_bytes);
+    assert.equal(binariesUsage.version_count, installed.length);
+    assert.match(
+      await app.invokeError("delete_downloaded_browser", {
+        browser: "wayfern",
+        version: "0.0.0-missing",
+      }),
+      /BROWSER_VERSION_NOT_FOUND/,
+    );
     assert.deepEqual(await app.invoke("get_supported_browsers"), ["wayfern"]);
     assert.equal(
       await app.invoke("is_browser_supported_on_platform", {
@@ -214,6 +262,22 @@ test("real Wayfern fingerprinting, terms, API automation, CDP, cookies, and proc
       Object.keys(fingerprint).length >= 10,
       "Wayfern returned an incomplete fingerprint",
     );
+    const batch = await app.invoke("generate_fingerprint", {
+      version: prepared.version,
+      config: { geoip: false },
+      count: 2,
+    });
+    assert.equal(batch.length, 2);
+    for (const generated of batch) {
+      assert.ok(Object.keys(generated).length >= 10);
+    }
+    assert.match(
+      await app.invokeError("generate_fingerprint", {
+        config: {},
+        count: 51,
+      }),
+      /count must be between 1 and 50/,
+    );
 
     const profile = await createRealProfile(
       app,
@@ -233,10 +297,54 @@ test("real Wayfern fingerprinting, terms, API automation, CDP, cookies, and proc
       profileId: profile.id,
       config: profile.wayfern_config,
     });
+    const pinnedLocale = {
+      ...profile.wayfern_config,
+      locale_override: "sw-KE, en-US",
+    };
+    assert.match(
+      await app.invokeError("update_wayfern_config", {
+        profileId: profile.id,
+        config: pinnedLocale,
+      }),
+      /LOCALE_CONFLICTS_WITH_FINGERPRINT/,
+    );
+    await app.invoke("update_wayfern_config", {
+      profileId: profile.id,
+      config: pinnedLocale,
+      forceLocale: true,
+    });
+    const relocalized = JSON.parse(
+      (await app.invoke("list_browser_profiles")).find(
+        (item) => item.id === profile.id,
+      ).wayfern_config.fingerprint,
+    );
+    assert.equal(relocalized.language, "sw-KE");
+    assert.deepEqual(relocalized.languages, ["sw-KE", "sw", "en-US", "en"]);
+    const pinnedTimezone = {
+      ...pinnedLocale,
+      fingerprint: JSON.stringify(relocalized),
+      timezone_override: "asia/tokio",
+    };
+    assert.match(
+      await app.invokeError("update_wayfern_config", {
+        profileId: profile.id,
+        config: pinnedTimezone,
+      }),
+      /INVALID_TIMEZONE.*Asia\/Tokyo/,
+    );
+    await app.invoke("update_wayfern_config", {
+      profileId: profile.id,
+      config: { ...pinnedTimezone, timezone_override: "asia/tokyo" },
+    });
     await app.invoke("match_profile_fingerprint_to_exit", {
       profileId: profile.id,
       exitIp: "8.8.8.8",
     });
+    const retimed = (await app.invoke("list_browser_profiles")).find(
+      (item) => item.id === profile.id,
+    ).wayfern_config;
+    assert.equal(retimed.timezone_override, "Asia/Tokyo");
+    assert.equal(JSON.parse(retimed.fingerprint).timezone, "Asia/Tokyo");
     const consistency = await app.invoke(
       "check_profile_fingerprint_consistency",
       {
@@ -244,6 +352,11 @@ test("real Wayfern fingerprinting, terms, API automation, CDP, cookies, and proc
       },
     );
     assert.equal(typeof consistency, "object");
+    const quality = await app.invoke("validate_profile_fingerprint", {
+      profileId: profile.id,
+    });
+    assert.ok(quality.score >= 0 && quality.score <= 100);
+    assert.ok(Array.isArray(quality.findings));
 
     const directProfile = (await app.invoke("list_browser_profiles")).find(
       (item) => item.id === profile.id,
@@ -253,6 +366,15 @@ test("real Wayfern fingerprinting, terms, API automation, CDP, cookies, and proc
       url: `${fixtureUrl}/direct-command`,
     });
     assert.ok(directLaunch.process_id);
+    const timings = await app.invoke("get_last_launch_timings", {
+      profileId: profile.id,
+    });
+    assert.equal(timings.profile_id, profile.id);
+    assert.deepEqual(
+      timings.phases.map((phase) => phase.phase).slice(0, 2),
+      ["proxy_start", "geoip_lookup"],
+    );
+    assert.ok(timings.phases.some((phase) => phase.phase === "cdp_ready"));
     await app.invoke("open_url_with_profile", {
       profileId: profile.id,
       url: `${fixtureUrl}/direct-open`,
@@ -260,6 +382,34 @@ test("real Wayfern fingerprinting, terms, API automation, CDP, cookies, and proc
     await app.invoke("kill_browser_profile", { profile: directLaunch });
     await waitForProcessExit(app, directLaunch.process_id);
 
+    const headlessLaunch = await app.invoke("launch_browser_profile", {
+      profile: directProfile,
+      url: `${fixtureUrl}/direct-headless`,
+      headless: true,
+    });
+    assert.ok(headlessLaunch.process_id);
+    assert.ok(headlessLaunch.cdp_port);
+    const endpoint = await app.invoke("get_profile_cdp_endpoint", {
+      profileId: profile.id,
+    });
+    assert.equal(endpoint.port, headlessLaunch.cdp_port);
+    assert.match(
+      endpoint.ws_url,
+      /^ws:\/\/127\.0\.0\.1:\d+\/devtools\/browser\//,
+    );
+    assert.equal(
+      await app.invoke("check_browser_status", { profile: headlessLaunch }),
+      true,
+    );
+    await app.invoke("kill_browser_profile", { profile: headlessLaunch });
+    await waitForProcessExit(app, headlessLaunch.process_id);
+    assert.match(
+      await app.invokeError("get_profile_cdp_endpoint", {
+        profileId: profile.id,
+      }),
+      /PROFILE_NOT_RUNNING/,
+    );
+
     const settings = await app.invoke("get_app_settings");
     const saved = await app.invoke("save_app_settings", {
       settings: {
@@ -270,7 +420,7 @@ test("real Wayfern fingerprinting, terms, API automation, CDP, cookies, and proc
         onboarding_completed: true,
       },
     });
-    const port = await app.invoke("start_api_server", { port: 0 });
+    const { port } = await app.invoke("start_api_server", { port: 0 });
     const base = `http://127.0.0.1:${port}`;
     const launched = await request(`${base}/v1/profiles/${profile.id}/run`, {
       method: "POST",
@@ -344,6 +494,28 @@ test("real Wayfern fingerprinting, terms, API automation, CDP, cookies, and proc
       { timeoutMs: 20_000, description: "API-opened Wayfern target" },
     );
 
+    const sessionUrl = `${base}/v1/profiles/${profile.id}/automation-session`;
+    const sessions = await Promise.all(
+      [0, 1].map(() =>
+        request(sessionUrl, { method: "POST", token: saved.api_token }),
+      ),
+    );
+    for (const session of sessions) {
+      assert.equal(session.response.status, 200, JSON.stringify(session.value));
+      assert.equal(session.value.kind, "cdp");
+      assert.equal(session.value.launched, false);
+      assert.equal(
+        session.value.endpoint,
+        `http://127.0.0.1:${launched.value.remote_debugging_port}`,
+      );
+      assert.ok(session.value.user_agent.length > 20);
+    }
+    const closed = await request(sessionUrl, {
+      method: "DELETE",
+      token: saved.api_token,
+    });
+    assert.equal(closed.response.status, 204);
+
     const killed = await request(`${base}/v1/profiles/${profile.id}/kill`, {
       method: "POST",
       token: saved.api_token,
@@ -352,6 +524,15 @@ test("real Wayfern fingerprinting, terms, API automation, CDP, cookies, and proc
     cdp.close();
     cdp = null;
     await waitForProcessExit(app, browserPid);
+    await app.waitFor(
+      async () => {
+        const history = await app.invoke("get_profile_exit_history", {
+          profileId: profile.id,
+        });
+        return history[0]?.exit_kind === "killed";
+      },
+      { timeoutMs: 10_000, description: "killed exit in exit history" },
+    );
     const stoppedProfile = (await app.invoke("list_browser_profiles")).find(
       (item) => item.id === profile.id,
     );
@@ -401,6 +582,55 @@ test("real Wayfern fingerprinting, terms, API automation, CDP, cookies, and proc
       batchStop.value.results[0].error,
     );
 
+    if (process.platform !== "win32") {
+      const cycles = Number(process.env.DONUT_E2E_STRESS_CYCLES ?? 200);
+      const before = appResourceSnapshot(port);
+      for (let cycle = 0; cycle < cycles; cycle += 1) {
+        const created = await request(`${base}/v1/profiles`, {
+          method: "POST",
+          token: saved.api_token,
+          body: {
+            name: `Wayfern Stress ${cycle}`,
+            browser: "wayfern",
+            version: prepared.version,
+            wayfern_config: { fingerprint: sample, geoip: false },
+          },
+        });
+        assert.equal(created.response.status, 200, `create #${cycle}`);
+        const run = await request(
+          `${base}/v1/profiles/${created.value.id}/run`,
+          {
+            method: "POST",
+            token: saved.api_token,
+            body: { url: `${fixtureUrl}/stress`, headless: true },
+          },
+        );
+        assert.ok(run.response.status < 500, `run #${cycle}: ${run.value}`);
+        const kill = await request(
+          `${base}/v1/profiles/${created.value.id}/kill`,
+          { method: "POST", token: saved.api_token },
+        );
+        assert.ok(kill.response.status < 500, `kill #${cycle}: ${kill.value}`);
+        const removed = await request(
+          `${base}/v1/profiles/${created.value.id}`,
+          { method: "DELETE", token: saved.api_token },
+        );
+        assert.ok(
+          removed.response.status < 500,
+          `delete #${cycle}: ${removed.value}`,
+        );
+      }
+      await app.waitFor(
+        () => {
+          const after = appResourceSnapshot(port);
+          return (
+            after.children <= before.children && after.fds <= before.fds + 16
+          );
+        },
+        { timeoutMs: 30_000, description: "fds and children to settle" },
+      );
+    }
+
     await app.invoke("stop_api_server");
     await app.invoke("delete_profile", { profileId: profile.id });
     await app.invoke("delete_profile", { profileId: batchProfile.id });
@@ -1,5 +1,6 @@
 import assert from "node:assert/strict";
 import { mkdir, writeFile } from "node:fs/promises";
+import http from "node:http";
 import path from "node:path";
 import test from "node:test";
 import { withApp } from "../lib/app.mjs";
@@ -34,6 +35,14 @@ test("profile, group, proxy, tag, metadata, clone, and bulk-delete lifecycle", a
       name: "Research Team",
     });
     assert.equal(renamedGroup.name, "Research Team");
+    assert.equal(renamedGroup.protected, false);
+
+    const protectedGroup = await app.invoke("update_profile_group", {
+      groupId: group.id,
+      name: "Research Team",
+      protected: true,
+    });
+    assert.equal(protectedGroup.protected, true);
 
     const duplicateError = await app.invokeError("create_profile_group", {
       name: "Research Team",
@@ -65,6 +74,37 @@ test("profile, group, proxy, tag, metadata, clone, and bulk-delete lifecycle", a
     assert.equal(updatedProxy.name, "Updated Proxy");
     assert.equal(updatedProxy.updated_at >= proxy.updated_at, true);
 
+    const chainedProxy = await app.invoke("update_stored_proxy", {
+      proxyId: proxy.id,
+      chain: [
+        { proxy_type: "http", host: "127.0.0.1", port: 8 },
+        { proxy_type: "socks5", host: "127.0.0.1", port: 9 },
+      ],
+    });
+    assert.equal(chainedProxy.chain.length, 2);
+    assert.equal(chainedProxy.proxy_settings.port, 9);
+    const chainError = await app.invokeError("update_stored_proxy", {
+      proxyId: proxy.id,
+      chain: [{ proxy_type: "socks4", host: "127.0.0.1", port: 8 }],
+    });
+    assert.match(chainError, /PROXY_CHAIN_TOO_SHORT/);
+    const unchainedProxy = await app.invoke("update_stored_proxy", {
+      proxyId: proxy.id,
+      chain: [],
+    });
+    assert.equal(unchainedProxy.chain, null);
+    const expectedProxy = await app.invoke(
+      "update_stored_proxy_exit_expectation",
+      { proxyId: proxy.id, expectedCountry: "de", expectedIpPrefix: "10.1" },
+    );
+    assert.equal(expectedProxy.expected_country, "DE");
+    assert.equal(expectedProxy.expected_ip_prefix, "10.1");
+    const countryError = await app.invokeError(
+      "update_stored_proxy_exit_expectation",
+      { proxyId: proxy.id, expectedCountry: "Germany" },
+    );
+    assert.match(countryError, /INVALID_EXPECTED_COUNTRY/);
+
     const parsed = await app.invoke("parse_txt_proxies", {
       content: [
         "http://one.example:8080",
@@ -131,6 +171,30 @@ test("profile, group, proxy, tag, metadata, clone, and bulk-delete lifecycle", a
       profileIds: [profile.id],
       groupId: group.id,
     });
+    const [proxyUsage] = await app.invoke("get_proxy_usage", {
+      proxyId: proxy.id,
+    });
+    assert.deepEqual(proxyUsage.profiles.map((item) => item.id), [profile.id]);
+    assert.equal(proxyUsage.any_running, false);
+    const [groupUsage] = await app.invoke("get_group_usage", {
+      groupId: group.id,
+    });
+    assert.deepEqual(
+      groupUsage.profiles.map((item) => item.name),
+      ["Entity Profile"],
+    );
+    await app.invoke("open_detached_profile_window", { profileId: profile.id });
+    await app.invoke("open_detached_profile_window", { profileId: profile.id });
+    assert.match(
+      await app.invokeError("open_detached_profile_window", {
+        profileId: "00000000-0000-4000-8000-000000000000",
+      }),
+      /PROFILE_NOT_FOUND/,
+    );
+    const proxyInUse = await app.invokeError("delete_stored_proxy", {
+      proxyId: proxy.id,
+    });
+    assert.match(proxyInUse, /PROXY_IN_USE/);
     await app.invoke("rename_profile", {
       profileId: profile.id,
       newName: "Renamed Profile",
@@ -160,6 +224,178 @@ test("profile, group, proxy, tag, metadata, clone, and bulk-delete lifecycle", a
       profileId: profile.id,
       rules: ["localhost", "*.internal.example"],
     });
+    await app.invoke("update_profile_startup", {
+      profileId: profile.id,
+      startupUrls: [" https://example.com ", "", "about:blank"],
+      sessionRestore: "urls",
+    });
+    const invalidStartup = await app.invokeError("update_profile_startup", {
+      profileId: profile.id,
+      startupUrls: ["javascript:alert(1)"],
+      sessionRestore: "urls",
+    });
+    assert.match(invalidStartup, /INVALID_STARTUP_URL/);
+    await app.invoke("update_profile_preferences", {
+      profileId: profile.id,
+      preferences: { "webrtc.ip_handling_policy": "disable_non_proxied_udp" },
+    });
+    const unsupportedPrefs = await app.invokeError(
+      "update_profile_preferences",
+      {
+        profileId: profile.id,
+        preferences: { "privacy.resistFingerprinting": true },
+      },
+    );
+    assert.match(unsupportedPrefs, /UNSUPPORTED_PREFERENCES/);
+    await app.invoke("update_profile_metadata", {
+      profileId: profile.id,
+      update: { mode: "set", metadata: { account_id: "42" } },
+    });
+    const merged = await app.invoke("update_profile_metadata", {
+      profileId: profile.id,
+      update: { mode: "merge", metadata: { persona_name: "Ann" } },
+    });
+    assert.deepEqual(merged.metadata, {
+      account_id: "42",
+      persona_name: "Ann",
+    });
+    const trimmed = await app.invoke("update_profile_metadata", {
+      profileId: profile.id,
+      update: { mode: "delete", keys: ["account_id"] },
+    });
+    assert.deepEqual(trimmed.metadata, { persona_name: "Ann" });
+    const invalidMetadata = await app.invokeError("update_profile_metadata", {
+      profileId: profile.id,
+      update: { mode: "merge", metadata: { "bad key": "x" } },
+    });
+    assert.match(invalidMetadata, /INVALID_METADATA_KEY/);
+    const pinned = await app.invoke("pin_profile_version", {
+      profileId: profile.id,
+    });
+    assert.equal(pinned.pin_browser_version, true);
+    const unpinned = await app.invoke("unpin_profile_version", {
+      profileId: profile.id,
+    });
+    assert.equal(unpinned.pin_browser_version, false);
+    const restartable = await app.invoke("update_profile_restart_policy", {
+      profileId: profile.id,
+      policy: { mode: "on_crash", max_restarts_per_hour: 5 },
+    });
+    assert.deepEqual(restartable.restart_policy, {
+      mode: "on_crash",
+      max_restarts_per_hour: 5,
+    });
+    const invalidRestartLimit = await app.invokeError(
+      "update_profile_restart_policy",
+      {
+        profileId: profile.id,
+        policy: { mode: "always", max_restarts_per_hour: 0 },
+      },
+    );
+    assert.match(invalidRestartLimit, /INVALID_RESTART_LIMIT/);
+    const dohProfile = await app.invoke("update_profile_dns_mode", {
+      profileId: profile.id,
+      dnsMode: { mode: "doh", resolver_url: "https://dns.example/dns-query" },
+    });
+    assert.deepEqual(dohProfile.dns_mode, {
+      mode: "doh",
+      resolver_url: "https://dns.example/dns-query",
+    });
+    const invalidResolver = await app.invokeError("update_profile_dns_mode", {
+      profileId: profile.id,
+      dnsMode: { mode: "doh", resolver_url: "http://dns.example/dns-query" },
+    });
+    assert.match(invalidResolver, /INVALID_DOH_RESOLVER_URL/);
+    const remoteDns = await app.invoke("update_profile_dns_mode", {
+      profileId: profile.id,
+      dnsMode: { mode: "proxy_remote" },
+    });
+    assert.deepEqual(remoteDns.dns_mode, { mode: "proxy_remote" });
+    const blockingProfile = await app.invoke(
+      "update_profile_exit_mismatch_action",
+      { profileId: profile.id, action: "block" },
+    );
+    assert.equal(blockingProfile.exit_mismatch_action, "block");
+    const limitedProfile = await app.invoke("update_profile_resource_limits", {
+      profileId: profile.id,
+      limits: { priority: "low", memory_limit_mb: 2048 },
+    });
+    assert.deepEqual(limitedProfile.resource_limits, {
+      priority: "low",
+      memory_limit_mb: 2048,
+    });
+    const memoryError = await app.invokeError(
+      "update_profile_resource_limits",
+      { profileId: profile.id, limits: { memory_limit_mb: 16 } },
+    );
+    assert.match(memoryError, /INVALID_MEMORY_LIMIT/);
+    const mockedRtc = await app.invoke("update_profile_webrtc_mode", {
+      profileId: profile.id,
+      mode: { mode: "mock", ip: " " },
+    });
+    assert.deepEqual(mockedRtc.wayfern_config.webrtc_mode, {
+      mode: "mock",
+      ip: null,
+    });
+    const badRtcIp = await app.invokeError("update_profile_webrtc_mode", {
+      profileId: profile.id,
+      mode: { mode: "mock", ip: "not-an-ip" },
+    });
+    assert.match(badRtcIp, /INVALID_WEBRTC_IP/);
+    await app.invoke("update_profile_webrtc_mode", {
+      profileId: profile.id,
+      mode: null,
+    });
+    assert.deepEqual(await app.invoke("get_launch_queue"), []);
+    // The e2e app is the owning instance, so nothing is rejected.
+    assert.equal(await app.invoke("get_observer_mode"), false);
+    const badShortcut = await app.invokeError("set_profile_shortcut", {
+      profileId: profile.id,
+      accelerator: "1",
+    });
+    assert.match(badShortcut, /INVALID_SHORTCUT/);
+    // Clearing never touches the OS, so it works headless too.
+    await app.invoke("set_profile_shortcut", {
+      profileId: profile.id,
+      accelerator: null,
+    });
+    const shortcutSettings = await app.invoke("get_app_settings");
+    assert.equal(shortcutSettings.profile_shortcuts[profile.id], undefined);
+    assert.equal(
+      await app.invoke("cancel_queued_launch", { profileId: profile.id }),
+      false,
+    );
+    const orphans = await app.invoke("cleanup_orphan_processes");
+    assert.equal(orphans.browsers_killed, 0);
+    assert.equal(typeof orphans.locks_removed, "number");
+    const preflight = await app.invoke("preflight_profile_launch", {
+      profileId: profile.id,
+    });
+    assert.deepEqual(
+      preflight.map((item) => item.check),
+      [
+        "wayfern_terms",
+        "binary",
+        "executable",
+        "proxy",
+        "vpn",
+        "geoip",
+        "disk_space",
+        "profile_locks",
+      ],
+    );
+    const byCheck = Object.fromEntries(
+      preflight.map((item) => [item.check, item]),
+    );
+    assert.equal(byCheck.proxy.status, "skipped");
+    assert.equal(byCheck.vpn.status, "skipped");
+    if (byCheck.binary.status === "fail") {
+      assert.equal(byCheck.binary.fixable_by, "download_browser");
+    }
+    const missingPreflight = await app.invokeError("preflight_profile_launch", {
+      profileId: "missing",
+    });
+    assert.match(missingPreflight, /PROFILE_NOT_FOUND/);
     await app.invoke("update_profile_dns_blocklist", {
       profileId: profile.id,
       dnsBlocklist: "light",
@@ -179,12 +415,97 @@ test("profile, group, proxy, tag, metadata, clone, and bulk-delete lifecycle", a
       "localhost",
       "*.internal.example",
     ]);
+    assert.deepEqual(changed.startup_urls, [
+      "https://example.com",
+      "about:blank",
+    ]);
+    assert.equal(changed.session_restore, "urls");
+    assert.deepEqual(changed.preference_overrides, {
+      "webrtc.ip_handling_policy": "disable_non_proxied_udp",
+    });
     assert.equal(changed.dns_blocklist, "light");
     assert.equal(changed.clear_on_close, true);
+
+    const [usage] = await app.invoke("get_profile_disk_usage", {
+      profileId: profile.id,
+      forceRefresh: true,
+    });
+    assert.equal(usage.profile_id, profile.id);
+    assert.ok(usage.cache_bytes <= usage.total_bytes);
+    const freed = await app.invoke("clear_profile_cache", {
+      profileId: profile.id,
+    });
+    assert.equal(typeof freed, "number");
+
+    const snapshot = await app.invoke("create_profile_snapshot", {
+      profileId: profile.id,
+      label: "before update",
+    });
+    assert.equal(snapshot.label, "before update");
+    assert.equal(snapshot.encrypted, false);
+    const snapshots = await app.invoke("list_profile_snapshots", {
+      profileId: profile.id,
+    });
+    assert.deepEqual(
+      snapshots.map((s) => s.id),
+      [snapshot.id],
+    );
+    await app.invoke("restore_profile_snapshot", {
+      profileId: profile.id,
+      snapshotId: snapshot.id,
+    });
+    assert.match(
+      await app.invokeError("restore_profile_snapshot", {
+        profileId: profile.id,
+        snapshotId: "00000000-0000-4000-8000-000000000000",
+      }),
+      /SNAPSHOT_NOT_FOUND/,
+    );
+    await app.invoke("refresh_profile", { profileId: profile.id });
+
+    const archived = await app.invoke("archive_profile", {
+      profileId: profile.id,
+      compress: true,
+    });
+    assert.equal(archived.archived, true);
+    assert.ok(
+      !(await app.invoke("list_browser_profiles")).some(
+        (item) => item.id === profile.id,
+      ),
+    );
+    assert.ok(
+      (
+        await app.invoke("list_browser_profiles", { includeArchived: true })
+      ).some((item) => item.id === profile.id),
+    );
+    const archivedGroup = (
+      await app.invoke("get_groups_with_profile_counts")
+    ).find((item) => item.id === group.id);
+    assert.equal(archivedGroup.archived_count, 1);
+    assert.match(
+      await app.invokeError("launch_browser_profile", { profile: archived }),
+      /PROFILE_ARCHIVED/,
+    );
+    const unarchived = await app.invoke("unarchive_profile", {
+      profileId: profile.id,
+    });
+    assert.equal(unarchived.archived, false);
     assert.deepEqual((await app.invoke("get_all_tags")).sort(), [
       "alpha",
       "automation",
     ]);
+    assert.equal(
+      await app.invoke("rename_tag", { from: "alpha", to: "beta" }),
+      1,
+    );
+    assert.equal(
+      await app.invoke("merge_tags", {
+        sources: ["beta", "automation"],
+        destination: "automation",
+      }),
+      1,
+    );
+    assert.deepEqual(await app.invoke("get_all_tags"), ["automation"]);
 
     assert.ok(Array.isArray(await app.invoke("detect_existing_profiles")));
     const importRoot = path.join(app.root, "profile-import-fixture");
@@ -214,6 +535,31 @@ test("profile, group, proxy, tag, metadata, clone, and bulk-delete lifecycle", a
       wayfernConfig: null,
     });
     assert.equal(importBatch.imported_count + importBatch.failed_count, 1);
+    const adsPowerExport = path.join(app.root, "adspower-export.json");
+    await writeFile(
+      adsPowerExport,
+      JSON.stringify([
+        {
+          name: "AdsPower Fixture",
+          user_proxy_config: {
+            proxy_soft: "other",
+            proxy_type: "socks5",
+            proxy_host: "10.0.0.1",
+            proxy_port: "1080",
+          },
+          fingerprint_config: { screen_resolution: "1920_1080" },
+        },
+      ]),
+    );
+    const externalBatch = await app.invoke("import_external_profiles", {
+      path: adsPowerExport,
+      groupId: null,
+    });
+    assert.equal(externalBatch.imported_count + externalBatch.failed_count, 1);
+    assert.equal(externalBatch.results[0].format, "adspower");
+    const externalProfileIds = externalBatch.results
+      .map((item) => item.profile_id)
+      .filter(Boolean);
     const archivePath = path.join(app.root, "profile-import-fixture.zip");
     await writeFile(archivePath, Buffer.from(extensionZipBase64(), "base64"));
     const archiveScan = await app.invoke("scan_profile_archive", {
@@ -234,15 +580,37 @@ test("profile, group, proxy, tag, metadata, clone, and bulk-delete lifecycle", a
     assert.equal(counts.find((item) => item.id === group.id).count, 2);
     assert.equal((await app.invoke("get_profile_groups")).length, 1);
 
-    await app.invoke("delete_selected_profiles", {
-      profileIds: [profile.id, clone.id],
+    await app.invoke("delete_stored_proxy", {
+      proxyId: proxy.id,
+      force: true,
+    });
+    assert.ok(
+      (await app.invoke("list_browser_profiles")).every(
+        (item) => item.proxy_id == null,
+      ),
+    );
+
+    const missingId = "00000000-0000-4000-8000-000000000000";
+    const deletion = await app.invoke("delete_selected_profiles", {
+      profileIds: [profile.id, clone.id, missingId, ...externalProfileIds],
+      force: true,
     });
+    assert.deepEqual(deletion.deleted, [
+      profile.id,
+      clone.id,
+      ...externalProfileIds,
+    ]);
+    assert.deepEqual(
+      deletion.failed.map((failure) => failure.profile_id),
+      [missingId],
+    );
     assert.deepEqual(await app.invoke("list_browser_profiles"), []);
     await app.invoke("delete_profile_group", { groupId: group.id });
-    await app.invoke("delete_stored_proxy", { proxyId: proxy.id });
     for (const importedProxy of (await app.invoke("get_stored_proxies")).filter(
       (item) =>
-        item.name === "Imported Proxy" || item.name.startsWith("Parsed Proxy"),
+        item.name === "Imported Proxy" ||
+        item.name.startsWith("Parsed Proxy") ||
+        item.name === "10.0.0.1:1080",
     )) {
       await app.invoke("delete_stored_proxy", { proxyId: importedProxy.id });
     }
@@ -286,6 +654,29 @@ test("extensions, extension groups, VPN storage, DNS rules, and event-backed ass
       fileData: null,
     });
     assert.equal(renamed.name, "Renamed Fixture Extension");
+    await app.invokeError("set_extension_source", {
+      extensionId: extension.id,
+      source: { kind: "chrome_web_store", id: "not-a-store-id" },
+    });
+    const sourced = await app.invoke("set_extension_source", {
+      extensionId: extension.id,
+      source: { kind: "url", url: "http://127.0.0.1:1/fixture.zip" },
+    });
+    assert.equal(sourced.source.kind, "url");
+    const updateResults = await app.invoke("check_extension_updates", {
+      extensionIds: [extension.id],
+    });
+    assert.equal(updateResults.length, 1);
+    assert.equal(updateResults[0].status, "failed");
+    assert.equal(
+      (
+        await app.invoke("set_extension_source", {
+          extensionId: extension.id,
+          source: null,
+        })
+      ).source,
+      null,
+    );
     assert.equal(
       await app.invoke("get_extension_icon", { extensionId: extension.id }),
       null,
@@ -308,6 +699,28 @@ test("extensions, extension groups, VPN storage, DNS rules, and event-backed ass
     });
     await app.invoke("delete_extension_group", { groupId: extensionGroup.id });
     await app.invoke("delete_extension", { extensionId: extension.id });
+    const unpackedDir = path.join(app.root, "unpacked-extension");
+    await mkdir(unpackedDir, { recursive: true });
+    await writeFile(
+      path.join(unpackedDir, "manifest.json"),
+      JSON.stringify({
+        manifest_version: 3,
+        name: "Unpacked Fixture",
+        version: "0.0.1",
+      }),
+    );
+    const unpacked = await app.invoke("add_unpacked_extension", {
+      name: "unpacked-extension",
+      path: unpackedDir,
+    });
+    assert.equal(unpacked.file_type, "unpacked");
+    assert.equal(unpacked.name, "Unpacked Fixture");
+    assert.deepEqual(unpacked.browser_compatibility, ["chromium"]);
+    await app.invokeError("add_unpacked_extension", {
+      name: "missing",
+      path: path.join(app.root, "missing-extension"),
+    });
+    await app.invoke("delete_extension", { extensionId: unpacked.id });
 
     const vpn = await app.invoke("create_vpn_config_manual", {
       name: "E2E WireGuard",
@@ -325,6 +738,26 @@ test("extensions, extension groups, VPN storage, DNS rules, and event-backed ass
       name: "Updated WireGuard",
     });
     assert.equal(updatedVpn.name, "Updated WireGuard");
+    assert.equal(
+      (
+        await app.invoke("update_vpn_auto_disconnect", {
+          vpnId: vpn.id,
+          autoDisconnect: true,
+        })
+      ).auto_disconnect,
+      true,
+    );
+    assert.equal(
+      (await app.invoke("list_vpn_configs"))[0].auto_disconnect,
+      true,
+    );
+    const expectedVpn = await app.invoke("update_vpn_exit_expectation", {
+      vpnId: vpn.id,
+      expectedCountry: "NL",
+      expectedIpPrefix: "203.0.113.0/24",
+    });
+    assert.equal(expectedVpn.expected_country, "NL");
+    assert.equal(expectedVpn.expected_ip_prefix, "203.0.113.0/24");
     assert.equal(
       (await app.invoke("get_vpn_status", { vpnId: vpn.id })).connected,
       false,
@@ -338,6 +771,8 @@ test("extensions, extension groups, VPN storage, DNS rules, and event-backed ass
       ).vpn_id,
       vpn.id,
     );
+    const [vpnUsage] = await app.invoke("get_vpn_usage", { vpnId: vpn.id });
+    assert.deepEqual(vpnUsage.profiles.map((item) => item.id), [profile.id]);
     assert.deepEqual(await app.invoke("list_active_vpn_connections"), []);
     await app.invoke("disconnect_vpn", { vpnId: vpn.id });
     const unknownVpnError = await app.invokeError("check_vpn_validity", {
@@ -499,3 +934,245 @@ test("cookie import/copy/export, profile encryption, and traffic-stat read/clear
     });
   });
 });
+
+test("proxy sources import, diff, and mark referenced proxies stale", async () => {
+  let list = ["http://one.example:8080", "two.example:1080", "not a proxy"];
+  const server = http.createServer((_request, response) => {
+    response.writeHead(200, { "content-type": "text/plain" });
+    response.end(list.join("\n"));
+  });
+  await new Promise((resolve, reject) => {
+    server.once("error", reject);
+    server.listen(0, "127.0.0.1", resolve);
+  });
+  try {
+    await withApp("entities-proxy-sources", async (app) => {
+      const invalidUrl = await app.invokeError("add_proxy_source", {
+        url: "ftp://127.0.0.1/list.txt",
+        namePrefix: "Feed",
+        refreshIntervalMins: null,
+      });
+      assert.match(invalidUrl, /PROXY_SOURCE_INVALID_URL/);
+
+      const source = await app.invoke("add_proxy_source", {
+        url: `http://127.0.0.1:${server.address().port}/list.txt`,
+        namePrefix: "Feed",
+        refreshIntervalMins: 60,
+      });
+      assert.equal(source.refresh_interval_mins, 60);
+      assert.deepEqual(
+        (await app.invoke("list_proxy_sources")).map((item) => item.id),
+        [source.id],
+      );
+
+      const first = await app.invoke("refresh_proxy_source", {
+        sourceId: source.id,
+      });
+      assert.equal(first.added, 2);
+      assert.equal(first.errors.length, 1);
+      assert.equal(first.errors[0].line, "not a proxy");
+
+      const imported = (await app.invoke("get_stored_proxies")).filter(
+        (proxy) => proxy.source_id === source.id,
+      );
+      const used = imported.find(
+        (proxy) => proxy.name === "Feed one.example:8080",
+      );
+      assert.ok(used);
+      const profile = await createProfile(app, "Source Profile");
+      await app.invoke("update_profile_proxy", {
+        profileId: profile.id,
+        proxyId: used.id,
+      });
+
+      list = ["three.example:3128"];
+      const second = await app.invoke("refresh_proxy_source", {
+        sourceId: source.id,
+      });
+      assert.equal(second.added, 1);
+      assert.equal(second.removed, 1);
+      assert.equal(second.stale, 1);
+      const afterRefresh = await app.invoke("get_stored_proxies");
+      assert.equal(
+        afterRefresh.find((proxy) => proxy.id === used.id).is_stale,
+        true,
+      );
+      assert.ok(
+        !afterRefresh.some((proxy) => proxy.name === "Feed two.example:1080"),
+      );
+
+      await app.invoke("remove_proxy_source", { sourceId: source.id });
+      assert.deepEqual(await app.invoke("list_proxy_sources"), []);
+      assert.ok(
+        (await app.invoke("get_stored_proxies")).every(
+          (proxy) => proxy.source_id == null,
+        ),
+      );
+    });
+  } finally {
+    await new Promise((resolve) => server.close(resolve));
+  }
+});
+
+test("proxy pools validate members, assign to profiles, and refuse orphaning", async () => {
+  await withApp("entities-proxy-pools", async (app) => {
+    const members = [];
+    for (const port of [9, 10]) {
+      members.push(
+        await app.invoke("create_stored_proxy", {
+          name: `Pool Member ${port}`,
+          proxySettings: { proxy_type: "http", host: "127.0.0.1", port },
+        }),
+      );
+    }
+
+    const unknownMember = await app.invokeError("create_proxy_pool", {
+      name: "Broken Pool",
+      proxyIds: ["missing-proxy"],
+      policy: null,
+    });
+    assert.match(unknownMember, /PROXY_NOT_FOUND/);
+
+    const pool = await app.invoke("create_proxy_pool", {
+      name: "US-resi",
+      proxyIds: [members[0].id, members[1].id, members[0].id],
+      policy: "least_recently_used",
+    });
+    assert.deepEqual(pool.proxy_ids, [members[0].id, members[1].id]);
+    assert.equal(pool.policy, "least_recently_used");
+
+    const renamed = await app.invoke("update_proxy_pool", {
+      poolId: pool.id,
+      name: "DE-dc",
+      proxyIds: null,
+      policy: "sticky_per_profile",
+    });
+    assert.equal(renamed.name, "DE-dc");
+    assert.equal(renamed.policy, "sticky_per_profile");
+
+    const profile = await createProfile(app, "Pool Profile");
+    await app.invoke("update_profile_proxy", {
+      profileId: profile.id,
+      proxyId: members[0].id,
+    });
+    const pooled = await app.invoke("update_profile_proxy_pool", {
+      profileId: profile.id,
+      proxyPoolId: pool.id,
+    });
+    assert.equal(pooled.proxy_pool_id, pool.id);
+    assert.equal(pooled.proxy_id ?? null, null);
+
+    await app.invoke("delete_stored_proxy", {
+      proxyId: members[1].id,
+      force: true,
+    });
+    const [afterDelete] = await app.invoke("get_proxy_pools");
+    assert.deepEqual(afterDelete.proxy_ids, [members[0].id]);
+
+    const inUse = await app.invokeError("delete_proxy_pool", {
+      poolId: pool.id,
+      force: false,
+    });
+    assert.match(inUse, /PROXY_POOL_IN_USE/);
+    await app.invoke("delete_proxy_pool", { poolId: pool.id, force: true });
+    assert.deepEqual(await app.invoke("get_proxy_pools"), []);
+    const detached = (await app.invoke("list_browser_profiles")).find(
+      (item) => item.id === profile.id,
+    );
+    assert.equal(detached.proxy_pool_id ?? null, null);
+  });
+});
+
+test("workspace export seals secrets and import skips existing entities", async () => {
+  await withApp("entities-workspace", async (app) => {
+    const proxy = await app.invoke("create_stored_proxy", {
+      name: "Workspace Proxy",
+      proxySettings: {
+        proxy_type: "http",
+        host: "127.0.0.1",
+        port: 9,
+        username: "user",
+        password: "workspace-secret",
+      },
+    });
+    const profile = await createProfile(app, "Workspace Profile");
+    const archivePath = path.join(app.root, "workspace.tgz");
+
+    const noPassphrase = await app.invokeError("export_workspace", {
+      path: archivePath,
+      passphrase: "",
+      includeBrowserData: false,
+    });
+    assert.match(noPassphrase, /WORKSPACE_PASSPHRASE_REQUIRED/);
+    const summary = await app.invoke("export_workspace", {
+      path: archivePath,
+      passphrase: "correct horse",
+      includeBrowserData: false,
+    });
+    assert.equal(summary.proxies, 1);
+    assert.ok(summary.profiles >= 1);
+
+    const wrongPassphrase = await app.invokeError("import_workspace", {
+      path: archivePath,
+      passphrase: "wrong",
+      force: false,
+    });
+    assert.match(wrongPassphrase, /WORKSPACE_PASSPHRASE_INVALID/);
+
+    const unchanged = await app.invoke("import_workspace", {
+      path: archivePath,
+      passphrase: "correct horse",
+      force: false,
+    });
+    assert.deepEqual(unchanged.imported, []);
+    assert.ok(unchanged.skipped.some((item) => item.id === profile.id));
+    assert.equal(unchanged.settings_applied, false);
+
+    await app.invoke("delete_stored_proxy", {
+      proxyId: proxy.id,
+      force: true,
+    });
+    const restored = await app.invoke("import_workspace", {
+      path: archivePath,
+      passphrase: "correct horse",
+      force: false,
+    });
+    assert.deepEqual(
+      restored.imported.map((item) => [item.kind, item.id]),
+      [["proxy", proxy.id]],
+    );
+    const [back] = await app.invoke("get_stored_proxies");
+    assert.equal(back.proxy_settings.password, "workspace-secret");
+  });
+});
+
+test("workspaces are listed, validated and guard switching", async () => {
+  await withApp("entities-workspaces", async (app) => {
+    const initial = await app.invoke("list_workspaces");
+    assert.equal(initial.active, "default");
+    assert.deepEqual(initial.workspaces.map((item) => item.id), ["default"]);
+
+    const created = await app.invoke("create_workspace", {
+      name: "Client A",
+      sharedBinaries: true,
+    });
+    assert.equal(created.shared_binaries, true);
+    const duplicate = await app.invokeError("create_workspace", {
+      name: "client a",
+      sharedBinaries: false,
+    });
+    assert.match(duplicate, /WORKSPACE_NAME_EXISTS/);
+    const listed = await app.invoke("list_workspaces");
+    assert.deepEqual(
+      listed.workspaces.map((item) => item.name),
+      ["Default", "Client A"],
+    );
+
+    const missing = await app.invokeError("switch_workspace", {
+      workspaceId: "missing",
+    });
+    assert.match(missing, /WORKSPACE_NOT_FOUND/);
+    await app.invoke("switch_workspace", { workspaceId: "default" });
+    assert.equal((await app.invoke("list_workspaces")).active, "default");
+  });
+});
@@ -66,9 +66,14 @@ test("authenticated REST API serves its complete OpenAPI contract and CRUD lifec
       },
     });
     assert.ok(saved.api_token?.length >= 32);
-    const port = await app.invoke("start_api_server", { port: 0 });
-    assert.equal(await app.invoke("get_api_server_status"), port);
-    const base = `http://127.0.0.1:${port}`;
+    const endpoint = await app.invoke("start_api_server", { port: 0 });
+    assert.equal(endpoint.mode, "tcp");
+    assert.deepEqual(await app.invoke("get_api_server_status"), endpoint);
+    const base = `http://127.0.0.1:${endpoint.port}`;
+    const connection = await app.invoke("get_api_endpoint");
+    assert.deepEqual(connection.endpoint, endpoint);
+    assert.equal(connection.base_url, base);
+    assert.equal(connection.token, saved.api_token);
 
     const openapi = await jsonRequest(`${base}/openapi.json`);
     assert.equal(openapi.response.status, 200);
@@ -80,8 +85,13 @@ test("authenticated REST API serves its complete OpenAPI contract and CRUD lifec
       "/v1/groups",
       "/v1/proxies",
       "/v1/vpns/{id}/export",
+      "/v1/vpns/{id}/status",
       "/v1/extensions",
+      "/v1/browsers",
       "/v1/browsers/{browser}/versions",
+      "/v1/browsers/{browser}/versions/{version}/download",
+      "/v1/browsers/downloaded",
+      "/v1/browsers/disk-usage",
     ]) {
       assert.ok(paths.includes(required), `OpenAPI is missing ${required}`);
     }
@@ -93,6 +103,53 @@ test("authenticated REST API serves its complete OpenAPI contract and CRUD lifec
     });
     assert.equal(wrongToken.response.status, 401);
 
+    const initialTokens = await app.invoke("list_api_tokens");
+    assert.deepEqual(
+      initialTokens.map((token) => token.name),
+      ["default"],
+    );
+    const emptyScopes = await app.invokeError("generate_api_token", {
+      name: "empty",
+      scopes: [],
+    });
+    assert.match(emptyScopes, /API_TOKEN_SCOPES_EMPTY/);
+    const readOnly = await app.invoke("generate_api_token", {
+      name: "monitoring",
+      scopes: ["profiles:read"],
+    });
+    assert.ok(readOnly.token.length >= 32);
+    assert.deepEqual(readOnly.scopes, ["profiles:read"]);
+    const scopedRead = await jsonRequest(`${base}/v1/profiles`, {
+      token: readOnly.token,
+    });
+    assert.equal(scopedRead.response.status, 200);
+    const scopedWrite = await jsonRequest(`${base}/v1/groups`, {
+      method: "POST",
+      token: readOnly.token,
+      body: { name: "Forbidden Group" },
+    });
+    assert.equal(scopedWrite.response.status, 403);
+    await app.invoke("revoke_api_token", { tokenId: readOnly.id });
+    const revoked = await jsonRequest(`${base}/v1/profiles`, {
+      token: readOnly.token,
+    });
+    assert.equal(revoked.response.status, 401);
+
+    const filtered = await jsonRequest(
+      `${base}/v1/profiles?browser=wayfern&tag=a&tag=b&running=false` +
+        "&limit=5&offset=0&fields=name",
+      { token: saved.api_token },
+    );
+    assert.equal(filtered.response.status, 200);
+    assert.equal(filtered.response.headers.get("x-total-count"), "0");
+    assert.deepEqual(filtered.value, { profiles: [], total: 0 });
+    for (const bad of ["running=maybe", "limit=0", "fields=fingerprint"]) {
+      const rejected = await jsonRequest(`${base}/v1/profiles?${bad}`, {
+        token: saved.api_token,
+      });
+      assert.equal(rejected.response.status, 400, bad);
+    }
+
     const groupsInitially = await jsonRequest(`${base}/v1/groups`, {
       token: saved.api_token,
     });
@@ -150,12 +207,43 @@ test("authenticated REST API serves its complete OpenAPI contract and CRUD lifec
       token: saved.api_token,
     });
     assert.equal(missing.response.status, 404);
+    assert.equal(missing.value.code, "GROUP_NOT_FOUND");
+    const browsers = await jsonRequest(`${base}/v1/browsers`, {
+      token: saved.api_token,
+    });
+    assert.equal(browsers.response.status, 200);
+    assert.ok(
+      browsers.value.every((b) => Array.isArray(b.downloaded_versions)),
+    );
+    const badChannel = await jsonRequest(
+      `${base}/v1/browsers/wayfern/versions?channel=weekly`,
+      { token: saved.api_token },
+    );
+    assert.equal(badChannel.response.status, 400);
+    const badDownload = await jsonRequest(
+      `${base}/v1/browsers/unsupported/versions/1.0/download`,
+      { method: "POST", token: saved.api_token },
+    );
+    assert.equal(badDownload.response.status, 400);
+    const missingInstall = await jsonRequest(
+      `${base}/v1/browsers/wayfern/versions/0.0.0-missing`,
+      { method: "DELETE", token: saved.api_token },
+    );
+    assert.equal(missingInstall.response.status, 404);
+    assert.equal(missingInstall.value.code, "BROWSER_VERSION_NOT_FOUND");
     const invalidProfile = await jsonRequest(`${base}/v1/profiles`, {
       method: "POST",
       token: saved.api_token,
       body: { name: "Bad", browser: "unsupported", version: "latest" },
     });
     assert.equal(invalidProfile.response.status, 400);
+    assert.equal(invalidProfile.value.code, "INVALID_BROWSER");
+    const missingFingerprint = await jsonRequest(
+      `${base}/v1/profiles/00000000-0000-0000-0000-000000000000/fingerprint`,
+      { method: "PATCH", token: saved.api_token, body: { regenerate: true } },
+    );
+    assert.equal(missingFingerprint.response.status, 404);
+    assert.equal(missingFingerprint.value.code, "PROFILE_NOT_FOUND");
 
     assert.equal(
       (
@@ -181,6 +269,36 @@ test("authenticated REST API serves its complete OpenAPI contract and CRUD lifec
       ).response.status,
       204,
     );
+
+    await app.invoke("save_app_settings", {
+      settings: {
+        ...saved,
+        api_rate_limit_per_second: 1,
+        api_rate_limit_burst: 1,
+      },
+    });
+    await jsonRequest(`${base}/v1/tags`, { token: saved.api_token });
+    const limited = await jsonRequest(`${base}/v1/tags`, {
+      token: saved.api_token,
+    });
+    assert.equal(limited.response.status, 429);
+    assert.ok(Number(limited.response.headers.get("retry-after")) >= 1);
+
+    await app.invoke("save_app_settings", {
+      settings: {
+        ...saved,
+        api_rate_limit_per_second: 0,
+        api_max_body_bytes: 1024,
+      },
+    });
+    const oversized = await jsonRequest(`${base}/v1/groups`, {
+      method: "POST",
+      token: saved.api_token,
+      body: { name: "x".repeat(4096) },
+    });
+    assert.equal(oversized.response.status, 413);
+    await app.invoke("save_app_settings", { settings: saved });
+
     await app.invoke("stop_api_server");
     assert.equal(await app.invoke("get_api_server_status"), null);
   });
@@ -260,6 +378,24 @@ test("MCP Streamable HTTP initialization, auth, discovery, calls, and isolated a
     assert.equal(listed.response.status, 200);
     assert.equal(listed.value.error, undefined);
     assert.ok(listed.value.result);
+    const resources = await jsonRequest(`${base}/mcp/${config.token}`, {
+      method: "POST",
+      headers: mcpHeaders,
+      body: { jsonrpc: "2.0", id: 4, method: "resources/list", params: {} },
+    });
+    assert.equal(resources.response.status, 200);
+    assert.ok(Array.isArray(resources.value.result.resources));
+    const missingResource = await jsonRequest(`${base}/mcp/${config.token}`, {
+      method: "POST",
+      headers: mcpHeaders,
+      body: {
+        jsonrpc: "2.0",
+        id: 5,
+        method: "resources/read",
+        params: { uri: "donut://proxy/missing" },
+      },
+    });
+    assert.equal(missingResource.value.error.code, -32002);
 
     const agents = await app.invoke("list_mcp_agents");
     assert.ok(agents.some((agent) => agent.id === "cursor"));
@@ -368,8 +504,12 @@ test("offline cloud, update, team-lock, trial, and synchronizer contracts are de
         browser: "wayfern",
         newVersion: "150.0.7871.100",
       }),
-      [],
+      { updated_profiles: [], skipped_pinned: 0 },
     );
+    const rollbackError = await app.invokeError("rollback_browser_update", {
+      browser: "wayfern",
+    });
+    assert.match(rollbackError, /ROLLBACK_UNAVAILABLE/);
     const prepareError = await app.invokeError(
       "download_and_prepare_app_update",
       {
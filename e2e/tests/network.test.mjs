@@ -531,7 +531,7 @@ test("visible UI creates and assigns profiles, groups, proxies, VPNs, extensions
         api_token: null,
       },
     });
-    apiPort = await app.invoke("start_api_server", { port: 0 });
+    ({ port: apiPort } = await app.invoke("start_api_server", { port: 0 }));
     const base = `http://127.0.0.1:${apiPort}`;
 
     const proxied = await runProfile(
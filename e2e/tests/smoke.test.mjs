@@ -41,16 +41,63 @@ test("fresh app renders, completes onboarding, persists settings, and never touc
         column: "browser",
         direction: "desc",
       });
+
+      const migrated = await app.invoke("list_saved_views");
+      assert.equal(migrated.length, 1);
+      assert.deepEqual(migrated[0].sorting, {
+        column: "browser",
+        direction: "desc",
+      });
+      const view = await app.invoke("create_saved_view", {
+        name: "Running without proxy",
+        filter: { tags: [], running: true, has_proxy: false },
+        sorting: { column: "name", direction: "asc" },
+        visibleColumns: ["name", "status"],
+      });
+      await app.invokeError("create_saved_view", {
+        name: "Running without proxy",
+        filter: {},
+        sorting: { column: "name", direction: "asc" },
+        visibleColumns: null,
+      });
+      const renamed = await app.invoke("update_saved_view", {
+        view: { ...view, name: "Idle without proxy" },
+      });
+      assert.equal(renamed.name, "Idle without proxy");
+      await app.invoke("delete_saved_view", { id: migrated[0].id });
+      await app.invokeError("delete_saved_view", { id: migrated[0].id });
+      assert.deepEqual(
+        (await app.invoke("list_saved_views")).map((v) => v.id),
+        [view.id],
+      );
       assert.ok((await app.invoke("get_system_language")).length >= 2);
       const system = await app.invoke("get_system_info");
       assert.ok(system && typeof system === "object");
       assert.equal(typeof (await app.invoke("read_log_files")), "string");
+      const recent = await app.invoke("get_recent_logs", {
+        level: "info",
+        limit: 5,
+      });
+      assert.ok(Array.isArray(recent) && recent.length <= 5);
+      await app.invokeError("get_recent_logs", { level: "loud" });
+      const audit = await app.invoke("get_audit_log", {
+        entityType: "settings",
+        limit: 1,
+      });
+      assert.equal(audit.length, 1);
+      assert.deepEqual(audit[0].actor, { kind: "gui" });
+      assert.equal(audit[0].action, "update");
+      assert.equal(audit[0].outcome, "success");
+      await app.invokeError("get_audit_log", { actor: "cron" });
+
+      await app.invoke("set_offline_mode", { enabled: true });
 
       await app.restart();
       const afterRestart = await app.invoke("get_app_settings");
       assert.equal(afterRestart.theme, "dark");
       assert.equal(afterRestart.language, "en");
       assert.equal(afterRestart.onboarding_completed, true);
+      assert.equal(afterRestart.offline_mode, true);
 
       const settingsFile = path.join(
         app.dataRoot,
@@ -131,6 +178,8 @@ test("tray labels, hide-to-tray, and confirmed quit follow the native lifecycle"
   const app = appFromEnvironment("smoke-lifecycle");
   try {
     await app.start();
+    // The page reported ready on load; a repeated handshake is a no-op.
+    await app.invoke("frontend_ready");
     await app.invoke("update_tray_menu", {
       showLabel: "Show Donut E2E",
       quitLabel: "Quit Donut E2E",
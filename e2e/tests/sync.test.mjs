@@ -160,6 +160,15 @@ test("two real app devices reconcile profile files and every config entity with
       profileId: profile.id,
       note: "regular sync metadata",
     });
+    const preview = await deviceA.invoke("preview_profile_sync", {
+      profileId: profile.id,
+    });
+    assert.equal(preview.remote_manifest_found, false);
+    assert.ok(preview.upload_files >= 1);
+    assert.equal(preview.download_files, 0);
+    assert.ok(
+      preview.largest_files.every((file) => file.direction === "upload"),
+    );
     await deviceA.invoke("set_profile_sync_mode", {
       profileId: profile.id,
       syncMode: "Regular",
@@ -243,7 +252,10 @@ test("two real app devices reconcile profile files and every config entity with
       profileId: profile.id,
       newName: "Synced Profile B Wins",
     });
-    await deviceB.invoke("request_profile_sync", { profileId: profile.id });
+    await deviceB.invoke("request_profile_sync", {
+      profileId: profile.id,
+      forceRehash: true,
+    });
     await deviceA.invoke("restart_sync_service");
     await waitFor(
       deviceA,
@@ -280,6 +292,12 @@ test("two real app devices reconcile profile files and every config entity with
     );
     const counts = await deviceA.invoke("get_unsynced_entity_counts");
     assert.equal(typeof counts.proxies, "number");
+    const queue = await deviceA.invoke("get_sync_queue_status");
+    assert.deepEqual(queue.pending, []);
+    assert.equal(queue.server_reachable, true);
+    const subscription = await deviceA.invoke("get_sync_subscription_status");
+    assert.equal(typeof subscription.state, "string");
+    assert.equal(typeof subscription.failed_attempts, "number");
     await deviceA.invoke("enable_sync_for_all_entities");
 
     await Promise.all([
@@ -550,6 +568,28 @@ test("global config sealing and encrypted profile sync reject a wrong password,
       "fresh receiver decrypts rolled profile browser file",
     );
 
+    const wrongOldPassword = await source.invokeError("change_e2e_password", {
+      oldPassword: "not the current password",
+      newPassword: "changed encryption password",
+    });
+    assert.match(wrongOldPassword, /E2E_PASSWORD_INCORRECT/);
+    const metadataBeforeChange = await downloadRemote(profileMetadataKey);
+    await source.invoke("change_e2e_password", {
+      oldPassword: "rolled encryption password",
+      newPassword: "changed encryption password",
+    });
+    assert.equal(await source.invoke("check_e2e_rotation_pending"), false);
+    assert.equal(
+      await source.invoke("verify_e2e_password", {
+        password: "changed encryption password",
+      }),
+      true,
+    );
+    assert.equal(
+      (await downloadRemote(profileMetadataKey)).equals(metadataBeforeChange),
+      false,
+    );
+
     await source.invoke("set_profile_sync_mode", {
       profileId: encryptedProfile.id,
       syncMode: "Disabled",
@@ -557,7 +597,7 @@ test("global config sealing and encrypted profile sync reject a wrong password,
     await source.invoke("delete_e2e_password");
     assert.equal(await source.invoke("check_has_e2e_password"), false);
     const missingPassword = await source.invokeError("verify_e2e_password", {
-      password: "rolled encryption password",
+      password: "changed encryption password",
     });
     assert.match(missingPassword, /NO_E2E_PASSWORD_SET/);
   } catch (error) {
@@ -95,12 +95,29 @@ pub struct BrowserRelease {
 pub struct WayfernVersionInfo {
   pub version: String,
   pub downloads: std::collections::HashMap<String, Option<String>>,
+  /// Per-platform SHA-256 of the download (`<hex>` or `sha256:<hex>`), keyed
+  /// like `downloads`.
+  #[serde(default)]
+  pub checksums: std::collections::HashMap<String, Option<String>>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 struct CachedVersionData {
   releases: Vec<BrowserRelease>,
-  timestamp: u64,
+  #[serde(flatten)]
+  meta: VersionCacheMeta,
+}
+
+/// Freshness of a browser's cached version list.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq)]
+pub struct VersionCacheMeta {
+  /// Unix seconds of the last successful check, including checks that
+  /// found nothing new.
+  #[serde(alias = "timestamp")]
+  pub fetched_at: u64,
+  /// ETag of the upstream response the list was last built from.
+  #[serde(default)]
+  pub etag: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -109,6 +126,20 @@ struct CachedWayfernData {
   timestamp: u64,
 }
 
+/// Result of a (possibly conditional) fetch of wayfern.json.
+pub enum WayfernFetch {
+  /// The server answered 304 for the ETag that was sent.
+  NotModified,
+  Fetched {
+    info: WayfernVersionInfo,
+    etag: Option<String>,
+  },
+}
+
+/// How long a cached version list counts as fresh. Older lists are still
+/// served, but trigger a background refresh.
+pub const VERSION_CACHE_TTL_SECS: u64 = 10 * 60;
+
 pub struct ApiClient {
   client: Client,
 }
@@ -142,8 +173,21 @@ impl ApiClient {
 
   fn is_cache_valid(timestamp: u64) -> bool {
     let current_time = Self::get_current_timestamp();
-    let cache_duration = 10 * 60;
-    current_time - timestamp < cache_duration
+    current_time.saturating_sub(timestamp) < VERSION_CACHE_TTL_SECS
+  }
+
+  fn load_cached_version_data(&self, browser: &str) -> Option<CachedVersionData> {
+    let cache_file = Self::get_cache_dir()
+      .ok()?
+      .join(format!("{browser}_versions.json"));
+    let content = fs::read_to_string(cache_file).ok()?;
+    serde_json::from_str(&content).ok()
+  }
+
+  /// When the browser's version list was last checked, and against which
+  /// upstream ETag. `None` without a cache or for a legacy cache file.
+  pub fn load_version_cache_meta(&self, browser: &str) -> Option<VersionCacheMeta> {
+    self.load_cached_version_data(browser).map(|data| data.meta)
   }
 
   pub fn load_cached_versions(&self, browser: &str) -> Option<Vec<BrowserRelease>> {
@@ -176,44 +220,36 @@ impl ApiClient {
   }
 
   pub fn is_cache_expired(&self, browser: &str) -> bool {
-    let cache_dir = match Self::get_cache_dir() {
-      Ok(dir) => dir,
-      Err(_) => return true,
-    };
-    let cache_file = cache_dir.join(format!("{browser}_versions.json"));
-
-    if !cache_file.exists() {
-      return true;
-    }
-
-    let content = match fs::read_to_string(&cache_file) {
-      Ok(content) => content,
-      Err(_) => return true,
-    };
-
-    let cached_data: CachedVersionData = match serde_json::from_str(&content) {
-      Ok(data) => data,
-      Err(_) => return true,
-    };
-
-    !Self::is_cache_valid(cached_data.timestamp)
+    self
+      .load_version_cache_meta(browser)
+      .is_none_or(|meta| !Self::is_cache_valid(meta.fetched_at))
   }
 
-  pub fn save_cached_versions(
+  /// Write the browser's version list, stamped as checked now. Only
+  /// `BrowserVersionManager` calls this, under its refresh lock.
+  pub(crate) fn save_cached_versions(
     &self,
     browser: &str,
     releases: &[BrowserRelease],
+    etag: Option<String>,
   ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     let cache_dir = Self::get_cache_dir()?;
     let cache_file = cache_dir.join(format!("{browser}_versions.json"));
 
     let cached_data = CachedVersionData {
       releases: releases.to_vec(),
-      timestamp: Self::get_current_timestamp(),
+      meta: VersionCacheMeta {
+        fetched_at: Self::get_current_timestamp(),
+        etag,
+      },
     };
 
+    // Written to a temp file first so a concurrent reader never sees a
+    // half-written list.
     let content = serde_json::to_string_pretty(&cached_data)?;
-    fs::write(&cache_file, content)?;
+    let tmp_file = cache_file.with_extension("json.tmp");
+    fs::write(&tmp_file, content)?;
+    fs::rename(&tmp_file, &cache_file)?;
     log::info!("Cached {} versions for {}", releases.len(), browser);
     Ok(())
   }
@@ -262,28 +298,60 @@ impl ApiClient {
       }
     }
 
+    let WayfernFetch::Fetched {
+      info: version_info, ..
+    } = self.fetch_wayfern_version(None).await?
+    else {
+      return Err("Unexpected 304 for an unconditional Wayfern version request".into());
+    };
+
+    if !no_caching {
+      if let Err(e) = self.save_cached_wayfern_version(&version_info) {
+        log::error!("Failed to cache Wayfern version: {e}");
+      }
+    }
+
+    Ok(version_info)
+  }
+
+  /// Fetch https://donutbrowser.com/wayfern.json, retrying transient
+  /// failures. With `etag`, an unchanged document comes back as
+  /// [`WayfernFetch::NotModified`].
+  pub async fn fetch_wayfern_version(
+    &self,
+    etag: Option<&str>,
+  ) -> Result<WayfernFetch, Box<dyn std::error::Error + Send + Sync>> {
     log::info!("Fetching Wayfern version from https://donutbrowser.com/wayfern.json");
     let url = "https://donutbrowser.com/wayfern.json";
 
     let mut last_err = None;
-    let mut version_info: Option<WayfernVersionInfo> = None;
 
     for attempt in 1..=3 {
-      match self
+      let mut request = self
         .client
         .get(url)
-        .header("User-Agent", "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/136.0.0.0 Safari/537.36")
-        .send()
-        .await
-      {
+        .header("User-Agent", "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/136.0.0.0 Safari/537.36");
+      if let Some(etag) = etag {
+        request = request.header(reqwest::header::IF_NONE_MATCH, etag);
+      }
+      match request.send().await {
         Ok(response) => {
+          if response.status() == reqwest::StatusCode::NOT_MODIFIED {
+            log::info!("Wayfern version unchanged since the cached list");
+            return Ok(WayfernFetch::NotModified);
+          }
           if !response.status().is_success() {
             last_err = Some(format!("HTTP {}", response.status().as_u16()));
           } else {
+            let etag = response
+              .headers()
+              .get(reqwest::header::ETAG)
+              .and_then(|value| value.to_str().ok())
+              .map(str::to_string);
             match response.json::<WayfernVersionInfo>().await {
               Ok(info) => {
-                version_info = Some(info);
-                break;
+                log::info!("Fetched Wayfern version: {}", info.version);
+                return Ok(WayfernFetch::Fetched { info, etag });
               }
               Err(e) => last_err = Some(format!("Failed to parse response: {e}")),
             }
@@ -300,21 +368,13 @@ impl ApiClient {
       }
     }
 
-    let version_info = version_info.ok_or_else(|| {
+    Err(
       format!(
         "Failed to fetch Wayfern version after 3 attempts: {}",
         last_err.unwrap_or_default()
       )
-    })?;
-    log::info!("Fetched Wayfern version: {}", version_info.version);
-
-    if !no_caching {
-      if let Err(e) = self.save_cached_wayfern_version(&version_info) {
-        log::error!("Failed to cache Wayfern version: {e}");
-      }
-    }
-
-    Ok(version_info)
+      .into(),
+    )
   }
 
   /// Get the download URL for Wayfern based on current platform
@@ -328,6 +388,16 @@ impl ApiClient {
       .and_then(|url| url.clone())
   }
 
+  /// Published SHA-256 of the current platform's Wayfern download, if any.
+  pub fn get_wayfern_checksum(&self, version_info: &WayfernVersionInfo) -> Option<String> {
+    let (os, arch) = Self::get_platform_info();
+    version_info
+      .checksums
+      .get(&format!("{os}-{arch}"))
+      .and_then(|digest| digest.as_deref())
+      .and_then(crate::integrity::parse_sha256)
+  }
+
   /// Check if Wayfern has a compatible download for current platform
   pub fn has_wayfern_compatible_download(&self, version_info: &WayfernVersionInfo) -> bool {
     self.get_wayfern_download_url(version_info).is_some()
@@ -408,6 +478,23 @@ mod tests {
     assert!(VersionComponent::parse("138.0.7204.50") > VersionComponent::parse("138.0.7204.49"));
   }
 
+  #[test]
+  fn version_cache_reads_the_legacy_timestamp() {
+    let legacy = r#"{"releases":[{"version":"1.0","date":""}],"timestamp":42}"#;
+    let data: CachedVersionData = serde_json::from_str(legacy).unwrap();
+    assert_eq!(
+      data.meta,
+      VersionCacheMeta {
+        fetched_at: 42,
+        etag: None
+      }
+    );
+
+    let written = serde_json::to_value(&data).unwrap();
+    assert_eq!(written["fetched_at"], 42);
+    assert!(written.get("timestamp").is_none());
+  }
+
   #[test]
   fn test_version_sorting() {
     let mut versions = vec![
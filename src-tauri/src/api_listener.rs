@@ -0,0 +1,304 @@
+//! Transports the local API can be served on: loopback TCP, a Unix domain
+//! socket, or a Windows named pipe. The socket and pipe are only reachable by
+//! the current user, unlike a localhost port any local process can connect to.
+
+use crate::events;
+use crate::settings_manager::ApiListen;
+use serde::Serialize;
+use tokio::net::TcpListener;
+
+/// Where a running API server can be reached.
+#[derive(Debug, Serialize, Clone, PartialEq, Eq)]
+#[serde(tag = "mode", rename_all = "snake_case")]
+pub enum ApiEndpoint {
+  /// `127.0.0.1:<port>`.
+  Tcp { port: u16 },
+  /// Filesystem path of the socket.
+  Unix { path: String },
+  /// Full pipe path, `\\.\pipe\<name>`.
+  WindowsPipe { path: String },
+}
+
+impl ApiEndpoint {
+  /// Base URL for requests. Over a socket or pipe the host is only sent as
+  /// the `Host` header.
+  pub fn base_url(&self) -> String {
+    match self {
+      Self::Tcp { port } => format!("http://127.0.0.1:{port}"),
+      Self::Unix { .. } | Self::WindowsPipe { .. } => "http://localhost".to_string(),
+    }
+  }
+}
+
+impl std::fmt::Display for ApiEndpoint {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::Tcp { port } => write!(f, "127.0.0.1:{port}"),
+      Self::Unix { path } | Self::WindowsPipe { path } => f.write_str(path),
+    }
+  }
+}
+
+pub(crate) enum BoundListener {
+  Tcp(TcpListener),
+  #[cfg(unix)]
+  Unix(tokio::net::UnixListener),
+  #[cfg(windows)]
+  Pipe(pipe::PipeListener),
+}
+
+impl BoundListener {
+  pub(crate) async fn serve(self, app: axum::Router) -> std::io::Result<()> {
+    match self {
+      Self::Tcp(listener) => axum::serve(listener, app).await,
+      #[cfg(unix)]
+      Self::Unix(listener) => axum::serve(listener, app).await,
+      #[cfg(windows)]
+      Self::Pipe(listener) => axum::serve(listener, app).await,
+    }
+  }
+}
+
+fn unsupported(mode: &str) -> String {
+  serde_json::json!({ "code": "API_LISTEN_UNSUPPORTED", "params": { "mode": mode } }).to_string()
+}
+
+/// Bind the transport `listen` asks for. TCP falls back to a random port when
+/// `port` is taken and announces it with `api-port-conflict`.
+pub(crate) async fn bind(
+  listen: &ApiListen,
+  port: u16,
+) -> Result<(BoundListener, ApiEndpoint), String> {
+  match listen {
+    ApiListen::Tcp => bind_tcp(port).await,
+    #[cfg(unix)]
+    ApiListen::Unix { path } => bind_unix(std::path::Path::new(path)),
+    #[cfg(not(unix))]
+    ApiListen::Unix { .. } => Err(unsupported("unix")),
+    #[cfg(windows)]
+    ApiListen::WindowsPipe { name } => {
+      let path = pipe::pipe_path(name);
+      let listener = pipe::PipeListener::bind(&path)
+        .map_err(|e| format!("Failed to create named pipe {path}: {e}"))?;
+      Ok((
+        BoundListener::Pipe(listener),
+        ApiEndpoint::WindowsPipe { path },
+      ))
+    }
+    #[cfg(not(windows))]
+    ApiListen::WindowsPipe { .. } => Err(unsupported("windows_pipe")),
+  }
+}
+
+async fn bind_tcp(preferred_port: u16) -> Result<(BoundListener, ApiEndpoint), String> {
+  // Try preferred port first, then random port
+  let listener = match TcpListener::bind(format!("127.0.0.1:{preferred_port}")).await {
+    Ok(listener) => listener,
+    Err(_) => {
+      // Port conflict, try random port
+      let random_port = rand::random::<u16>().saturating_add(10000);
+      match TcpListener::bind(format!("127.0.0.1:{random_port}")).await {
+        Ok(listener) => {
+          let _ = events::emit(&events::payloads::ApiPortConflict(format!(
+            "API server using fallback port {random_port}"
+          )));
+          listener
+        }
+        Err(e) => return Err(format!("Failed to bind to any port: {e}")),
+      }
+    }
+  };
+
+  let port = listener
+    .local_addr()
+    .map_err(|e| format!("Failed to get local address: {e}"))?
+    .port();
+  Ok((BoundListener::Tcp(listener), ApiEndpoint::Tcp { port }))
+}
+
+#[cfg(unix)]
+fn bind_unix(path: &std::path::Path) -> Result<(BoundListener, ApiEndpoint), String> {
+  use std::os::unix::fs::{FileTypeExt, PermissionsExt};
+
+  // A socket left behind by a crashed run makes bind fail; one another
+  // process still answers on is not ours to take over.
+  if path
+    .symlink_metadata()
+    .is_ok_and(|meta| meta.file_type().is_socket())
+  {
+    if std::os::unix::net::UnixStream::connect(path).is_ok() {
+      return Err(
+        serde_json::json!({
+          "code": "API_SOCKET_IN_USE",
+          "params": { "path": path.to_string_lossy() }
+        })
+        .to_string(),
+      );
+    }
+    std::fs::remove_file(path).map_err(|e| format!("Failed to remove stale socket: {e}"))?;
+  }
+  if let Some(parent) = path.parent() {
+    std::fs::create_dir_all(parent)
+      .map_err(|e| format!("Failed to create socket directory: {e}"))?;
+  }
+
+  let listener = tokio::net::UnixListener::bind(path)
+    .map_err(|e| format!("Failed to bind {}: {e}", path.display()))?;
+  std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o600))
+    .map_err(|e| format!("Failed to restrict socket permissions: {e}"))?;
+
+  Ok((
+    BoundListener::Unix(listener),
+    ApiEndpoint::Unix {
+      path: path.to_string_lossy().into_owned(),
+    },
+  ))
+}
+
+/// Remove what a stopped server leaves on disk.
+pub(crate) fn cleanup(endpoint: &ApiEndpoint) {
+  if let ApiEndpoint::Unix { path } = endpoint {
+    if let Err(e) = std::fs::remove_file(path) {
+      log::debug!("Failed to remove API socket {path}: {e}");
+    }
+  }
+}
+
+#[cfg(windows)]
+mod pipe {
+  use tokio::net::windows::named_pipe::{NamedPipeServer, ServerOptions};
+
+  /// `\\.\pipe\<name>`, unless `name` is already a pipe path.
+  pub(super) fn pipe_path(name: &str) -> String {
+    if name.starts_with(r"\\.\pipe\") {
+      name.to_string()
+    } else {
+      format!(r"\\.\pipe\{name}")
+    }
+  }
+
+  /// Serves one pipe instance per connection. A fresh instance is created
+  /// before each connected one is handed out, so a client never finds the
+  /// pipe missing between connections.
+  pub(crate) struct PipeListener {
+    path: String,
+    next: NamedPipeServer,
+  }
+
+  impl PipeListener {
+    /// The default pipe DACL gives write access to the creating user (and
+    /// administrators) only; remote clients are refused.
+    pub(super) fn bind(path: &str) -> std::io::Result<Self> {
+      let next = ServerOptions::new()
+        .first_pipe_instance(true)
+        .reject_remote_clients(true)
+        .create(path)?;
+      Ok(Self {
+        path: path.to_string(),
+        next,
+      })
+    }
+  }
+
+  impl axum::serve::Listener for PipeListener {
+    type Io = NamedPipeServer;
+    type Addr = String;
+
+    async fn accept(&mut self) -> (Self::Io, Self::Addr) {
+      loop {
+        let connected = match self.next.connect().await {
+          Ok(()) => true,
+          Err(e) => {
+            log::warn!("API pipe connection failed: {e}");
+            false
+          }
+        };
+        match ServerOptions::new()
+          .reject_remote_clients(true)
+          .create(&self.path)
+        {
+          Ok(next) => {
+            let io = std::mem::replace(&mut self.next, next);
+            if connected {
+              return (io, self.path.clone());
+            }
+          }
+          Err(e) => {
+            log::warn!("Failed to create API pipe instance: {e}");
+            tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+          }
+        }
+      }
+    }
+
+    fn local_addr(&self) -> std::io::Result<Self::Addr> {
+      Ok(self.path.clone())
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[cfg(unix)]
+  #[tokio::test]
+  async fn unix_socket_is_owner_only_and_replaces_stale_socket() {
+    use std::os::unix::fs::PermissionsExt;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("api.sock");
+    // A crashed run's socket: bound once, nobody listening anymore.
+    drop(std::os::unix::net::UnixListener::bind(&path).unwrap());
+
+    let listen = ApiListen::Unix {
+      path: path.to_string_lossy().into_owned(),
+    };
+    let (listener, endpoint) = bind(&listen, 0).await.unwrap();
+    assert_eq!(
+      endpoint,
+      ApiEndpoint::Unix {
+        path: path.to_string_lossy().into_owned()
+      }
+    );
+    let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+    assert_eq!(mode & 0o777, 0o600);
+
+    // While served, a second bind must not steal the socket.
+    let app = axum::Router::new().route("/ping", axum::routing::get(|| async { "pong" }));
+    let server = tokio::spawn(listener.serve(app));
+    assert!(bind(&listen, 0)
+      .await
+      .err()
+      .unwrap()
+      .contains("API_SOCKET_IN_USE"));
+
+    let mut stream = tokio::net::UnixStream::connect(&path).await.unwrap();
+    stream
+      .write_all(b"GET /ping HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")
+      .await
+      .unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).await.unwrap();
+    assert!(response.starts_with("HTTP/1.1 200"));
+    assert!(response.ends_with("pong"));
+
+    server.abort();
+    cleanup(&endpoint);
+    assert!(!path.exists());
+  }
+
+  #[cfg(not(windows))]
+  #[tokio::test]
+  async fn windows_pipe_is_rejected_elsewhere() {
+    let listen = ApiListen::WindowsPipe {
+      name: "donut".to_string(),
+    };
+    assert!(bind(&listen, 0)
+      .await
+      .err()
+      .unwrap()
+      .contains("API_LISTEN_UNSUPPORTED"));
+  }
+}
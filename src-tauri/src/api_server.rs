@@ -1,22 +1,30 @@
+use crate::api_listener::{self, ApiEndpoint};
 use crate::browser::ProxySettings;
+use crate::entity_usage::EntityUsage;
 use crate::events;
 use crate::group_manager::GROUP_MANAGER;
 use crate::profile::manager::ProfileManager;
 use crate::proxy_manager::PROXY_MANAGER;
+use crate::settings_manager::ApiListen;
 use crate::tag_manager::TAG_MANAGER;
 use axum::{
-  extract::{Path, Query, State},
-  http::{HeaderMap, StatusCode},
+  extract::{
+    ws::{Message, WebSocketUpgrade},
+    DefaultBodyLimit, MatchedPath, Path, Query, RawQuery, State,
+  },
+  http::{header, HeaderMap, HeaderValue, Method, StatusCode},
   middleware::{self, Next},
-  response::{Json, Response},
+  response::{IntoResponse, Json, Response},
   routing::get,
   Router,
 };
 use lazy_static::lazy_static;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
-use tokio::net::TcpListener;
-use tokio::sync::{mpsc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::sync::{broadcast, mpsc, Mutex};
 use tower_http::cors::CorsLayer;
 use utoipa::{OpenApi, ToSchema};
 use utoipa_axum::{router::OpenApiRouter, routes};
@@ -40,6 +48,13 @@ pub struct ApiProfile {
   pub proxy_bypass_rules: Vec<String>,
   pub vpn_id: Option<String>,
   pub clear_on_close: bool,
+  pub metadata: std::collections::HashMap<String, String>,
+  /// How the browser last exited, if an exit was observed.
+  pub last_exit: Option<crate::profile::exit_history::ProfileExit>,
+  /// Proxy pool a member is picked from at each launch.
+  pub proxy_pool_id: Option<String>,
+  /// Pool member the running browser was launched through.
+  pub pool_proxy_id: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
@@ -87,6 +102,9 @@ pub struct UpdateProfileRequest {
   pub version: Option<String>,
   pub proxy_id: Option<String>,
   pub vpn_id: Option<String>,
+  /// Proxy pool to pick a member from at each launch; an empty string clears
+  /// it. Mutually exclusive with `proxy_id` and `vpn_id`.
+  pub proxy_pool_id: Option<String>,
   pub launch_hook: Option<String>,
   pub release_type: Option<String>,
   pub group_id: Option<String>,
@@ -102,7 +120,7 @@ pub struct UpdateProfileRequest {
 
 #[derive(Clone)]
 struct ApiServerState {
-  app_handle: tauri::AppHandle,
+  app_handle: crate::app_handle::AppHandle,
 }
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
@@ -120,6 +138,9 @@ struct CreateGroupRequest {
 #[derive(Debug, Deserialize, ToSchema)]
 struct UpdateGroupRequest {
   name: String,
+  /// Keep the group's profiles from automated deletion. Unchanged if omitted.
+  #[serde(default)]
+  protected: Option<bool>,
 }
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
@@ -144,6 +165,23 @@ struct UpdateProxyRequest {
   proxy_settings: Option<ProxySettings>,
 }
 
+#[derive(Debug, Deserialize, ToSchema)]
+struct CreateProxyPoolRequest {
+  name: String,
+  /// Stored proxy IDs, in the order round-robin and fallback walk them.
+  #[serde(default)]
+  proxy_ids: Vec<String>,
+  #[serde(default)]
+  policy: crate::proxy_manager::PoolSelectionPolicy,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+struct UpdateProxyPoolRequest {
+  name: Option<String>,
+  proxy_ids: Option<Vec<String>>,
+  policy: Option<crate::proxy_manager::PoolSelectionPolicy>,
+}
+
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 struct ApiVpnResponse {
   id: String,
@@ -200,6 +238,34 @@ struct DownloadBrowserResponse {
   status: String,
 }
 
+/// A browser supported on this platform.
+#[derive(Debug, Serialize, ToSchema)]
+struct ApiBrowser {
+  browser: String,
+  /// Newest known version, from the version cache unless `refresh` is set.
+  latest_version: Option<String>,
+  downloaded_versions: Vec<String>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+struct ApiBrowserVersion {
+  version: String,
+  /// `stable`, `beta` or `nightly`.
+  release_type: String,
+  downloaded: bool,
+  /// A download of this version is in progress.
+  downloading: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct BrowserVersionsQuery {
+  /// Fetch the version list upstream instead of serving the cache.
+  #[serde(default)]
+  refresh: bool,
+  /// Only versions of this release type.
+  channel: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct ToastPayload {
   pub message: String,
@@ -213,12 +279,53 @@ struct RunProfileResponse {
   profile_id: String,
   remote_debugging_port: u16,
   headless: bool,
+  /// Priority and memory limits the browser runs under; `null` when none
+  /// were configured.
+  resource_limits: Option<crate::profile::types::AppliedResourceLimits>,
 }
 
 #[derive(Debug, Deserialize, ToSchema)]
 struct RunProfileRequest {
   url: Option<String>,
   headless: Option<bool>,
+  /// Remote debugging port to bind. Omit or pass 0 to pick a free one.
+  debug_port: Option<u16>,
+}
+
+#[derive(Debug, Default, Deserialize, ToSchema)]
+struct AutomationSessionRequest {
+  /// Opened only when the session has to launch the profile.
+  url: Option<String>,
+  headless: Option<bool>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, ToSchema)]
+#[serde(rename_all = "kebab-case")]
+enum AutomationSessionKind {
+  /// Chrome DevTools Protocol; `endpoint` is the DevTools HTTP endpoint
+  /// (`chromium.connect_over_cdp` in Playwright).
+  Cdp,
+}
+
+/// How an automation tool attaches to a running profile.
+#[derive(Debug, Serialize, ToSchema)]
+struct AutomationSession {
+  kind: AutomationSessionKind,
+  /// `http://127.0.0.1:<port>`
+  endpoint: String,
+  /// Browser-level websocket, for clients that connect to it directly.
+  ws_url: String,
+  browser: String,
+  version: String,
+  user_agent: String,
+  /// The profile was started for this session rather than already running.
+  launched: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct CloseAutomationSessionQuery {
+  #[serde(default)]
+  kill: bool,
 }
 
 #[derive(Debug, Deserialize, ToSchema)]
@@ -292,6 +399,179 @@ struct DetectedProfilesResponse {
   total: usize,
 }
 
+/// Query parameters accepted by `GET /v1/profiles`. Parsed by hand from the
+/// raw query string because `tag` may be repeated.
+#[derive(Debug, Default, PartialEq)]
+struct ProfileListQuery {
+  browser: Option<String>,
+  group_id: Option<String>,
+  tags: Vec<String>,
+  running: Option<bool>,
+  name: Option<String>,
+  /// `metadata.<key>=<value>` pairs, all of which must match exactly.
+  metadata: Vec<(String, String)>,
+  limit: Option<usize>,
+  offset: usize,
+  fields: Option<Vec<String>>,
+}
+
+const PROFILE_LIST_MAX_LIMIT: usize = 1000;
+
+const API_PROFILE_FIELDS: &[&str] = &[
+  "id",
+  "name",
+  "browser",
+  "version",
+  "proxy_id",
+  "launch_hook",
+  "process_id",
+  "last_launch",
+  "release_type",
+  "group_id",
+  "tags",
+  "is_running",
+  "proxy_bypass_rules",
+  "vpn_id",
+  "clear_on_close",
+  "metadata",
+];
+
+fn parse_profile_list_query(raw: Option<&str>) -> Result<ProfileListQuery, String> {
+  let mut query = ProfileListQuery::default();
+  for (key, value) in url::form_urlencoded::parse(raw.unwrap_or_default().as_bytes()) {
+    let value = value.trim().to_string();
+    match key.as_ref() {
+      "browser" => {
+        crate::browser::BrowserType::from_str(&value)
+          .map_err(|_| format!("Invalid browser \"{value}\""))?;
+        query.browser = Some(value);
+      }
+      "group_id" if !value.is_empty() => query.group_id = Some(value),
+      "tag" if !value.is_empty() => query.tags.push(value),
+      "running" => {
+        query.running = Some(match value.as_str() {
+          "true" => true,
+          "false" => false,
+          _ => {
+            return Err(format!(
+              "Invalid running \"{value}\", must be \"true\" or \"false\""
+            ))
+          }
+        })
+      }
+      "name" if !value.is_empty() => query.name = Some(value.to_lowercase()),
+      "limit" => match value.parse::<usize>() {
+        Ok(limit) if (1..=PROFILE_LIST_MAX_LIMIT).contains(&limit) => query.limit = Some(limit),
+        _ => {
+          return Err(format!(
+            "Invalid limit \"{value}\", must be between 1 and {PROFILE_LIST_MAX_LIMIT}"
+          ))
+        }
+      },
+      "offset" => {
+        query.offset = value
+          .parse()
+          .map_err(|_| format!("Invalid offset \"{value}\", must be a non-negative integer"))?
+      }
+      "fields" => {
+        let fields: Vec<String> = value
+          .split(',')
+          .map(|f| f.trim().to_string())
+          .filter(|f| !f.is_empty())
+          .collect();
+        if let Some(unknown) = fields
+          .iter()
+          .find(|f| !API_PROFILE_FIELDS.contains(&f.as_str()))
+        {
+          return Err(format!("Unknown field \"{unknown}\""));
+        }
+        query.fields = Some(fields);
+      }
+      "group_id" | "tag" | "name" => {}
+      other => match other.strip_prefix("metadata.") {
+        Some(metadata_key) => {
+          crate::profile::metadata::validate_metadata_key(metadata_key)
+            .map_err(|_| format!("Invalid metadata key \"{metadata_key}\""))?;
+          query.metadata.push((metadata_key.to_string(), value));
+        }
+        None => return Err(format!("Unknown query parameter \"{other}\"")),
+      },
+    }
+  }
+  Ok(query)
+}
+
+impl ProfileListQuery {
+  fn matches(&self, profile: &crate::profile::BrowserProfile) -> bool {
+    if self.browser.as_ref().is_some_and(|b| &profile.browser != b) {
+      return false;
+    }
+    if let Some(group_id) = &self.group_id {
+      // "default" selects ungrouped profiles, matching the group listing.
+      let profile_group = profile.group_id.as_deref().unwrap_or("default");
+      if profile_group != group_id {
+        return false;
+      }
+    }
+    if !self.tags.iter().all(|t| profile.tags.contains(t)) {
+      return false;
+    }
+    if self
+      .running
+      .is_some_and(|running| profile.process_id.is_some() != running)
+    {
+      return false;
+    }
+    if self
+      .name
+      .as_ref()
+      .is_some_and(|name| !profile.name.to_lowercase().contains(name))
+    {
+      return false;
+    }
+    crate::profile::metadata::metadata_matches(&profile.metadata, &self.metadata)
+  }
+}
+
+fn api_profile_from(profile: &crate::profile::BrowserProfile) -> ApiProfile {
+  ApiProfile {
+    id: profile.id.to_string(),
+    name: profile.name.clone(),
+    browser: profile.browser.clone(),
+    version: profile.version.clone(),
+    proxy_id: profile.proxy_id.clone(),
+    launch_hook: profile.launch_hook.clone(),
+    process_id: profile.process_id,
+    last_launch: profile.last_launch,
+    release_type: profile.release_type.clone(),
+    group_id: profile.group_id.clone(),
+    tags: profile.tags.clone(),
+    is_running: profile.process_id.is_some(), // Simple check based on process_id
+    proxy_bypass_rules: profile.proxy_bypass_rules.clone(),
+    vpn_id: profile.vpn_id.clone(),
+    clear_on_close: profile.clear_on_close,
+    metadata: profile.metadata.clone(),
+    last_exit: crate::profile::exit_history::last_exit(&profile.id.to_string()),
+    proxy_pool_id: profile.proxy_pool_id.clone(),
+    pool_proxy_id: profile
+      .proxy_pool_id
+      .as_ref()
+      .filter(|_| profile.process_id.is_some())
+      .and_then(|_| PROXY_MANAGER.pool_proxy_for_profile(&profile.id.to_string())),
+  }
+}
+
+#[derive(Debug, Deserialize)]
+struct ForceQuery {
+  #[serde(default)]
+  force: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct UsageQuery {
+  id: Option<String>,
+}
+
 #[derive(Debug, Deserialize)]
 struct DetectImportQuery {
   /// Optional folder to scan instead of the default browser locations.
@@ -332,6 +612,56 @@ struct ImportProxiesResponse {
   proxies: Vec<ApiProxyResponse>,
 }
 
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct GenerateFingerprintsRequest {
+  /// Optional. Omit (or pass `"latest"`) to use the newest already-downloaded
+  /// Wayfern version. Nothing is downloaded by this endpoint.
+  #[serde(default)]
+  pub version: Option<String>,
+  /// Generation options, same shape as a profile's `wayfern_config` (`os`,
+  /// screen constraints, `gpu_tier`, ...). Omit for defaults.
+  #[serde(default)]
+  #[schema(value_type = Option<Object>)]
+  pub config: Option<serde_json::Value>,
+  /// How many fingerprints to generate, 1 to 50. Defaults to 1.
+  #[serde(default)]
+  pub count: Option<usize>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+struct GenerateFingerprintsResponse {
+  #[schema(value_type = Vec<Object>)]
+  fingerprints: Vec<serde_json::Value>,
+}
+
+/// Fingerprint settings of a Wayfern profile. Omitted fields keep their
+/// current value.
+#[derive(Debug, Default, Deserialize, ToSchema)]
+pub struct UpdateFingerprintRequest {
+  /// `"windows"`, `"macos"`, `"linux"`, `"android"` or `"ios"`.
+  pub os: Option<String>,
+  pub screen_min_width: Option<u32>,
+  pub screen_min_height: Option<u32>,
+  pub screen_max_width: Option<u32>,
+  pub screen_max_height: Option<u32>,
+  pub randomize_fingerprint_on_launch: Option<bool>,
+  /// A fingerprint to pin, e.g. one from `/v1/fingerprints/generate`. It must
+  /// carry `userAgent`, `platform`, `screenWidth` and `screenHeight`.
+  #[schema(value_type = Option<Object>)]
+  pub fingerprint: Option<serde_json::Value>,
+  /// Generate a fresh fingerprint for the resulting `os` and screen
+  /// constraints. Cannot be combined with `fingerprint`.
+  #[serde(default)]
+  pub regenerate: bool,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+struct UpdateFingerprintResponse {
+  profile: ApiProfile,
+  /// `None` when the profile has no fingerprint yet.
+  fingerprint: Option<crate::fingerprint_quality::FingerprintSummary>,
+}
+
 #[derive(OpenApi)]
 #[openapi(
   paths(
@@ -343,6 +673,13 @@ struct ImportProxiesResponse {
     run_profile,
     open_url_in_profile,
     kill_profile,
+    get_profile_cdp_endpoint,
+    preflight_profile_launch,
+    open_automation_session,
+    close_automation_session,
+    preview_profile_sync_api,
+    update_profile_metadata_api,
+    update_profile_fingerprint_api,
     batch_run_profiles,
     batch_stop_profiles,
     detect_import_profiles,
@@ -360,8 +697,15 @@ struct ImportProxiesResponse {
     import_proxies_api,
     update_proxy,
     delete_proxy,
+    get_proxy_usage,
+    get_proxy_pools_api,
+    get_proxy_pool_api,
+    create_proxy_pool_api,
+    update_proxy_pool_api,
+    delete_proxy_pool_api,
     get_vpns,
     get_vpn,
+    get_vpn_status_api,
     export_vpn,
     import_vpn,
     create_vpn,
@@ -372,30 +716,66 @@ struct ImportProxiesResponse {
     delete_extension_api,
     delete_extension_group_api,
     download_browser_api,
+    get_browsers,
     get_browser_versions,
+    start_browser_download,
     check_browser_downloaded,
+    get_downloaded_browsers,
+    delete_downloaded_browser_api,
+    get_binaries_disk_usage_api,
+    generate_fingerprints_api,
+    get_logs,
+    get_audit_log_api,
+    get_health,
+    stream_events,
   ),
   components(schemas(
+    ApiErrorBody,
     ApiProfile,
     ApiProfilesResponse,
     ApiProfileResponse,
     CreateProfileRequest,
     UpdateProfileRequest,
+    crate::profile::metadata::MetadataUpdate,
     ApiGroupResponse,
     CreateGroupRequest,
     UpdateGroupRequest,
     ApiProxyResponse,
+    EntityUsage,
+    crate::entity_usage::ProfileRef,
     CreateProxyRequest,
     UpdateProxyRequest,
+    crate::proxy_manager::ProxyPool,
+    crate::proxy_manager::PoolSelectionPolicy,
+    CreateProxyPoolRequest,
+    UpdateProxyPoolRequest,
     ApiVpnResponse,
     ApiVpnExportResponse,
+    crate::vpn::VpnStatus,
     ImportVpnRequest,
     CreateVpnRequest,
     UpdateVpnRequest,
     DownloadBrowserRequest,
     DownloadBrowserResponse,
+    ApiBrowser,
+    ApiBrowserVersion,
+    crate::binaries_usage::DownloadedBrowser,
+    crate::binaries_usage::BinariesDiskUsage,
+    crate::binaries_usage::BinaryCleanupRecord,
+    crate::binaries_usage::BinaryRemoval,
     RunProfileResponse,
     RunProfileRequest,
+    crate::wayfern_manager::CdpEndpoint,
+    crate::preflight::PreflightCheck,
+    crate::preflight::PreflightCheckKind,
+    crate::preflight::PreflightStatus,
+    crate::preflight::PreflightFix,
+    AutomationSessionRequest,
+    AutomationSessionKind,
+    AutomationSession,
+    crate::sync::SyncPreview,
+    crate::sync::manifest::PreviewFile,
+    crate::sync::manifest::TransferDirection,
     BatchRunRequest,
     BatchRunResult,
     BatchRunResponse,
@@ -410,7 +790,22 @@ struct ImportProxiesResponse {
     ImportProfilesRequest,
     ImportProxiesRequest,
     ImportProxiesResponse,
+    GenerateFingerprintsRequest,
+    GenerateFingerprintsResponse,
+    UpdateFingerprintRequest,
+    UpdateFingerprintResponse,
+    crate::fingerprint_quality::FingerprintSummary,
+    crate::profile::exit_history::ProfileExit,
+    crate::profile::exit_history::ProfileExitKind,
+    crate::profile::types::AppliedResourceLimits,
+    crate::profile::types::ProcessPriority,
+    crate::log_buffer::LogRecord,
+    crate::audit::AuditRecord,
+    crate::audit::AuditActor,
+    crate::audit::AuditOutcome,
+    ApiHealthResponse,
     crate::profile_importer::DetectedProfile,
+    crate::profile_importer::ImportCategory,
     crate::profile_importer::ImportProfileItem,
     crate::profile_importer::DuplicateStrategy,
     crate::profile_importer::ProfileImportItemResult,
@@ -425,6 +820,11 @@ struct ImportProxiesResponse {
     (name = "extensions", description = "Extension management endpoints"),
     (name = "browsers", description = "Browser management endpoints"),
     (name = "cookies", description = "Cookie management endpoints"),
+    (name = "fingerprints", description = "Standalone fingerprint generation"),
+    (name = "logs", description = "Recent application logs"),
+    (name = "audit", description = "Audit log of mutating actions"),
+    (name = "health", description = "Server status"),
+    (name = "events", description = "Event stream (daemon mode)"),
   ),
   modifiers(&SecurityAddon),
 )]
@@ -449,7 +849,7 @@ impl utoipa::Modify for SecurityAddon {
 }
 
 pub struct ApiServer {
-  port: Option<u16>,
+  endpoint: Option<ApiEndpoint>,
   shutdown_tx: Option<mpsc::Sender<()>>,
   task_handle: Option<tokio::task::JoinHandle<()>>,
 }
@@ -457,21 +857,22 @@ pub struct ApiServer {
 impl ApiServer {
   fn new() -> Self {
     Self {
-      port: None,
+      endpoint: None,
       shutdown_tx: None,
       task_handle: None,
     }
   }
 
-  fn get_port(&self) -> Option<u16> {
-    self.port
+  fn get_endpoint(&self) -> Option<ApiEndpoint> {
+    self.endpoint.clone()
   }
 
   async fn start(
     &mut self,
-    app_handle: tauri::AppHandle,
+    app_handle: crate::app_handle::AppHandle,
     preferred_port: u16,
-  ) -> Result<u16, String> {
+    listen: &ApiListen,
+  ) -> Result<ApiEndpoint, String> {
     // Stop existing server if running
     self.stop().await.ok();
 
@@ -480,29 +881,7 @@ impl ApiServer {
       app_handle: app_handle.clone(),
     };
 
-    // Try preferred port first, then random port
-    let listener = match TcpListener::bind(format!("127.0.0.1:{preferred_port}")).await {
-      Ok(listener) => listener,
-      Err(_) => {
-        // Port conflict, try random port
-        let random_port = rand::random::<u16>().saturating_add(10000);
-        match TcpListener::bind(format!("127.0.0.1:{random_port}")).await {
-          Ok(listener) => {
-            let _ = events::emit(
-              "api-port-conflict",
-              format!("API server using fallback port {random_port}"),
-            );
-            listener
-          }
-          Err(e) => return Err(format!("Failed to bind to any port: {e}")),
-        }
-      }
-    };
-
-    let actual_port = listener
-      .local_addr()
-      .map_err(|e| format!("Failed to get local address: {e}"))?
-      .port();
+    let (listener, endpoint) = api_listener::bind(listen, preferred_port).await?;
 
     // Create router with OpenAPI documentation
     let (v1_routes, _) = OpenApiRouter::new()
@@ -511,6 +890,12 @@ impl ApiServer {
       .routes(routes!(run_profile))
       .routes(routes!(open_url_in_profile))
       .routes(routes!(kill_profile))
+      .routes(routes!(get_profile_cdp_endpoint))
+      .routes(routes!(preflight_profile_launch))
+      .routes(routes!(open_automation_session, close_automation_session))
+      .routes(routes!(preview_profile_sync_api))
+      .routes(routes!(update_profile_metadata_api))
+      .routes(routes!(update_profile_fingerprint_api))
       .routes(routes!(batch_run_profiles))
       .routes(routes!(batch_stop_profiles))
       .routes(routes!(detect_import_profiles))
@@ -521,26 +906,54 @@ impl ApiServer {
       .routes(routes!(get_tags))
       .routes(routes!(get_proxies, create_proxy))
       .routes(routes!(import_proxies_api))
+      .routes(routes!(get_proxy_usage))
       .routes(routes!(get_proxy, update_proxy, delete_proxy))
+      .routes(routes!(get_proxy_pools_api, create_proxy_pool_api))
+      .routes(routes!(
+        get_proxy_pool_api,
+        update_proxy_pool_api,
+        delete_proxy_pool_api
+      ))
       .routes(routes!(get_vpns, create_vpn))
       .routes(routes!(import_vpn))
       .routes(routes!(export_vpn))
+      .routes(routes!(get_vpn_status_api))
       .routes(routes!(get_vpn, update_vpn, delete_vpn))
       .routes(routes!(get_extensions))
       .routes(routes!(delete_extension_api))
       .routes(routes!(get_extension_groups))
       .routes(routes!(delete_extension_group_api))
       .routes(routes!(download_browser_api))
+      .routes(routes!(get_browsers))
       .routes(routes!(get_browser_versions))
+      .routes(routes!(start_browser_download))
       .routes(routes!(check_browser_downloaded))
+      .routes(routes!(get_downloaded_browsers))
+      .routes(routes!(delete_downloaded_browser_api))
+      .routes(routes!(get_binaries_disk_usage_api))
+      .routes(routes!(generate_fingerprints_api))
+      .routes(routes!(get_logs))
+      .routes(routes!(get_audit_log_api))
+      .routes(routes!(get_health))
+      .routes(routes!(stream_events))
       .split_for_parts();
 
     let api = ApiDoc::openapi();
 
+    if let Ok(settings) = crate::settings_manager::SettingsManager::instance().load_settings() {
+      apply_api_limits(&settings);
+    }
+
     let v1_routes = v1_routes
-      // Inert chokepoint (innermost → runs after auth) for the future per-hour
-      // automation request limit. See rate_limit_middleware.
+      // Innermost: only requests that passed auth and the rate limit get
+      // their bodies buffered. Axum's fixed 2 MB default is replaced by the
+      // configurable limit enforced here.
+      .layer(middleware::from_fn(body_limit_middleware))
+      .layer(DefaultBodyLimit::disable())
+      // Runs after auth so limits are tracked per token. See rate_limit_middleware.
       .layer(middleware::from_fn(rate_limit_middleware))
+      // Runs after auth so records name the token.
+      .layer(middleware::from_fn(audit_middleware))
       .layer(middleware::from_fn_with_state(
         state.clone(),
         auth_middleware,
@@ -564,18 +977,18 @@ impl ApiServer {
 
     // Start server task
     let task_handle = tokio::spawn(async move {
-      let server = axum::serve(listener, app);
+      let server = listener.serve(app);
       tokio::select! {
         _ = server => {},
         _ = shutdown_rx.recv() => {},
       }
     });
 
-    self.port = Some(actual_port);
+    self.endpoint = Some(endpoint.clone());
     self.shutdown_tx = Some(shutdown_tx);
     self.task_handle = Some(task_handle);
 
-    Ok(actual_port)
+    Ok(endpoint)
   }
 
   async fn stop(&mut self) -> Result<(), String> {
@@ -587,7 +1000,9 @@ impl ApiServer {
       handle.abort();
     }
 
-    self.port = None;
+    if let Some(endpoint) = self.endpoint.take() {
+      api_listener::cleanup(&endpoint);
+    }
     Ok(())
   }
 }
@@ -609,7 +1024,7 @@ async fn terms_check_middleware(
 async fn auth_middleware(
   State(state): State<ApiServerState>,
   headers: HeaderMap,
-  request: axum::extract::Request,
+  mut request: axum::extract::Request,
   next: Next,
 ) -> Result<Response, StatusCode> {
   let path = request.uri().path().to_string();
@@ -628,38 +1043,122 @@ async fn auth_middleware(
     }
   };
 
-  // Get the stored token
-  let settings_manager = crate::settings_manager::SettingsManager::instance();
-  let stored_token = match settings_manager.get_api_token(&state.app_handle).await {
-    Ok(Some(stored_token)) => stored_token,
-    Ok(None) => {
-      log::warn!(
-        "[api] Rejected {path}: API server has no stored token (was the API toggled off?)"
-      );
+  // Unknown tokens are 401; a valid token without the route's scope is 403
+  // so clients can tell "bad credentials" from "not allowed".
+  let scope = crate::api_tokens::required_scope(request.method(), &path);
+  match crate::api_tokens::authorize(&state.app_handle, token, scope).await {
+    Ok(crate::api_tokens::TokenCheck::Allowed { token_name }) => {
+      request.extensions_mut().insert(ApiCaller { token_name });
+    }
+    Ok(crate::api_tokens::TokenCheck::MissingScope) => {
+      log::warn!("[api] Rejected {path}: token lacks scope {scope:?}");
+      return Err(StatusCode::FORBIDDEN);
+    }
+    Ok(crate::api_tokens::TokenCheck::Unknown) => {
+      log::warn!("[api] Rejected {path}: unknown token");
       return Err(StatusCode::UNAUTHORIZED);
     }
     Err(e) => {
-      log::error!("[api] Failed to read stored API token: {e}");
+      log::error!("[api] Failed to read stored API tokens: {e}");
       return Err(StatusCode::INTERNAL_SERVER_ERROR);
     }
-  };
-
-  // Constant-time comparison so the auth check doesn't leak the shared-prefix
-  // length via timing. `ConstantTimeEq` on equal-length byte slices; differing
-  // lengths simply compare unequal.
-  use subtle::ConstantTimeEq;
-  let token_bytes = token.as_bytes();
-  let stored_bytes = stored_token.as_bytes();
-  let matches = token_bytes.len() == stored_bytes.len() && token_bytes.ct_eq(stored_bytes).into();
-  if !matches {
-    log::warn!("[api] Rejected {path}: token mismatch");
-    return Err(StatusCode::UNAUTHORIZED);
   }
 
   // Token is valid, continue with the request
   Ok(next.run(request).await)
 }
 
+/// The token that authorized a request, set by `auth_middleware`.
+#[derive(Clone)]
+struct ApiCaller {
+  token_name: String,
+}
+
+/// Records every mutating request in the audit log once it has a response.
+/// Reads are not audited.
+async fn audit_middleware(request: axum::extract::Request, next: Next) -> Response {
+  let method = request.method().clone();
+  if matches!(method, Method::GET | Method::HEAD) {
+    return next.run(request).await;
+  }
+  let Some(caller) = request.extensions().get::<ApiCaller>().cloned() else {
+    return next.run(request).await;
+  };
+  let path = request.uri().path().to_string();
+  let route = request
+    .extensions()
+    .get::<MatchedPath>()
+    .map(|p| p.as_str().to_string())
+    .unwrap_or_else(|| path.clone());
+
+  let response = next.run(request).await;
+
+  let (action, entity_type, entity_id) = describe_request(&method, &route, &path);
+  let status = response.status();
+  crate::audit::record(
+    crate::audit::AuditActor::Api {
+      token_name: caller.token_name,
+    },
+    &action,
+    &entity_type,
+    entity_id,
+    (!status.is_success()).then(|| format!("HTTP {}", status.as_u16())),
+  );
+  response
+}
+
+/// Audit action, entity type and entity id for a request to `path`, which
+/// matched the route template `route`, e.g. `/v1/profiles/{id}/run`.
+fn describe_request(method: &Method, route: &str, path: &str) -> (String, String, Option<String>) {
+  let segments = |p: &str| -> Vec<String> {
+    p.trim_start_matches("/v1")
+      .split('/')
+      .filter(|s| !s.is_empty())
+      .map(str::to_string)
+      .collect()
+  };
+  let template = segments(route);
+  let actual = segments(path);
+
+  let entity_type = match template.first().map(String::as_str) {
+    Some("profiles") => "profile".to_string(),
+    Some("groups") => "group".to_string(),
+    Some("proxies") => "proxy".to_string(),
+    Some("proxy-pools") => "proxy_pool".to_string(),
+    Some("vpns") => "vpn".to_string(),
+    Some("extensions") => "extension".to_string(),
+    Some("extension-groups") => "extension_group".to_string(),
+    Some("browsers") => "browser".to_string(),
+    Some("fingerprints") => "fingerprint".to_string(),
+    Some(other) => other.replace('-', "_"),
+    None => "unknown".to_string(),
+  };
+  let entity_id = template
+    .iter()
+    .zip(&actual)
+    .find(|(t, _)| t.starts_with('{'))
+    .map(|(_, id)| id.clone());
+
+  let verb = match *method {
+    Method::POST => "create",
+    Method::PUT | Method::PATCH => "update",
+    Method::DELETE => "delete",
+    _ => "call",
+  };
+  let action = match template
+    .last()
+    .filter(|s| template.len() > 1 && !s.starts_with('{'))
+    .map(String::as_str)
+  {
+    None => verb.to_string(),
+    Some("run") => "launch".to_string(),
+    Some("kill" | "stop") => "kill".to_string(),
+    Some(literal @ ("open-url" | "import" | "download" | "generate")) => literal.replace('-', "_"),
+    Some(literal) => format!("{verb}_{}", literal.replace('-', "_")),
+  };
+  (action, entity_type, entity_id)
+}
+
 /// Logs every request: method, path, query, response status, duration.
 /// Skips Authorization header and request bodies entirely.
 async fn request_logging_middleware(request: axum::extract::Request, next: Next) -> Response {
@@ -692,17 +1191,201 @@ async fn request_logging_middleware(request: axum::extract::Request, next: Next)
   response
 }
 
-/// Chokepoint for the future per-hour automation request limit. The limit
-/// (`requests_per_hour`, default 100) is already plumbed through entitlements;
-/// this middleware is intentionally inert today — it resolves the limit but
-/// never blocks. To enforce, count authenticated requests per rolling hour and
-/// return `StatusCode::TOO_MANY_REQUESTS` once the limit (when > 0) is exceeded.
-async fn rate_limit_middleware(
+/// Request limits read from `AppSettings`. Cached here so the middleware
+/// doesn't hit the settings file per request; `save_app_settings` refreshes
+/// it, so changes apply to a running server.
+#[derive(Debug, Clone, Copy)]
+struct ApiLimits {
+  per_second: u32,
+  burst: u32,
+  max_concurrent_launches: u32,
+  max_body_bytes: u64,
+}
+
+impl ApiLimits {
+  fn from_settings(settings: &crate::settings_manager::AppSettings) -> Self {
+    Self {
+      per_second: settings.api_rate_limit_per_second,
+      burst: settings.api_rate_limit_burst,
+      max_concurrent_launches: settings.api_max_concurrent_launches,
+      max_body_bytes: settings.api_max_body_bytes,
+    }
+  }
+}
+
+/// Token bucket: `burst` requests up front, refilled at `per_second`.
+#[derive(Debug)]
+struct TokenBucket {
+  available: f64,
+  last_refill: Instant,
+}
+
+impl TokenBucket {
+  fn new(now: Instant, burst: u32) -> Self {
+    Self {
+      available: f64::from(burst.max(1)),
+      last_refill: now,
+    }
+  }
+
+  /// Takes one request from the bucket, or returns how long until one is
+  /// available.
+  fn try_take(&mut self, now: Instant, per_second: u32, burst: u32) -> Result<(), Duration> {
+    let rate = f64::from(per_second);
+    let capacity = f64::from(burst.max(1));
+    let elapsed = now
+      .saturating_duration_since(self.last_refill)
+      .as_secs_f64();
+    self.available = (self.available + elapsed * rate).min(capacity);
+    self.last_refill = now;
+    if self.available >= 1.0 {
+      self.available -= 1.0;
+      Ok(())
+    } else {
+      Err(Duration::from_secs_f64((1.0 - self.available) / rate))
+    }
+  }
+}
+
+lazy_static! {
+  static ref API_LIMITS: std::sync::RwLock<ApiLimits> = std::sync::RwLock::new(
+    ApiLimits::from_settings(&crate::settings_manager::AppSettings::default())
+  );
+  static ref RATE_BUCKETS: std::sync::Mutex<HashMap<String, TokenBucket>> =
+    std::sync::Mutex::new(HashMap::new());
+}
+
+static EXPENSIVE_IN_FLIGHT: AtomicUsize = AtomicUsize::new(0);
+
+pub fn apply_api_limits(settings: &crate::settings_manager::AppSettings) {
+  if let Ok(mut limits) = API_LIMITS.write() {
+    *limits = ApiLimits::from_settings(settings);
+  }
+}
+
+fn current_limits() -> ApiLimits {
+  match API_LIMITS.read() {
+    Ok(limits) => *limits,
+    Err(poisoned) => *poisoned.into_inner(),
+  }
+}
+
+/// Routes that spawn browsers, download, or import — the ones that contend on
+/// internal locks when many run at once.
+fn is_expensive_route(method: &Method, path: &str) -> bool {
+  if method != Method::POST {
+    return false;
+  }
+  let segments: Vec<&str> = path
+    .trim_start_matches("/v1")
+    .split('/')
+    .filter(|s| !s.is_empty())
+    .collect();
+  matches!(
+    segments.as_slice(),
+    [
+      "profiles",
+      _,
+      "run" | "open-url" | "kill" | "automation-session"
+    ] | ["profiles", "batch", _]
+      | ["profiles", "import"]
+      | ["profiles", _, "cookies", "import"]
+      | ["browsers", "download"]
+      | ["browsers", _, "versions", _, "download"]
+  )
+}
+
+fn too_many_requests(retry_after: Duration) -> Response {
+  let secs = retry_after.as_secs_f64().ceil().max(1.0) as u64;
+  let mut response = StatusCode::TOO_MANY_REQUESTS.into_response();
+  response
+    .headers_mut()
+    .insert(header::RETRY_AFTER, HeaderValue::from(secs));
+  response
+}
+
+/// Releases an expensive-route slot when the request finishes, including on
+/// panic or client disconnect.
+struct InFlightGuard;
+
+impl Drop for InFlightGuard {
+  fn drop(&mut self) {
+    EXPENSIVE_IN_FLIGHT.fetch_sub(1, Ordering::SeqCst);
+  }
+}
+
+/// Per-token rate limit plus a global cap on concurrent expensive routes.
+/// Both answer 429 with `Retry-After` rather than queueing behind internal
+/// mutexes. The per-hour automation limit (`requests_per_hour`) is already
+/// plumbed through entitlements but not enforced yet.
+async fn rate_limit_middleware(request: axum::extract::Request, next: Next) -> Response {
+  let _requests_per_hour = crate::cloud_auth::CLOUD_AUTH.requests_per_hour().await;
+  // TODO(rate-limit): enforce `_requests_per_hour` for automation routes.
+
+  let limits = current_limits();
+  let path = request.uri().path().to_string();
+
+  if limits.per_second > 0 {
+    // Auth already validated the header; it only keys the bucket here.
+    let key = request
+      .headers()
+      .get("Authorization")
+      .and_then(|h| h.to_str().ok())
+      .unwrap_or_default()
+      .to_string();
+    let verdict = match RATE_BUCKETS.lock() {
+      Ok(mut buckets) => {
+        let now = Instant::now();
+        buckets
+          .entry(key)
+          .or_insert_with(|| TokenBucket::new(now, limits.burst))
+          .try_take(now, limits.per_second, limits.burst)
+      }
+      Err(_) => Ok(()),
+    };
+    if let Err(retry_after) = verdict {
+      log::warn!("[api] Rate limited {path}");
+      return too_many_requests(retry_after);
+    }
+  }
+
+  let _guard = if limits.max_concurrent_launches > 0 && is_expensive_route(request.method(), &path)
+  {
+    let previous = EXPENSIVE_IN_FLIGHT.fetch_add(1, Ordering::SeqCst);
+    let guard = InFlightGuard;
+    if previous >= limits.max_concurrent_launches as usize {
+      log::warn!("[api] Rejected {path}: too many concurrent launch requests");
+      return too_many_requests(Duration::from_secs(1));
+    }
+    Some(guard)
+  } else {
+    None
+  };
+
+  next.run(request).await
+}
+
+/// Buffers the request body up to `api_max_body_bytes`, answering 413 past
+/// that instead of letting a handler deserialize an arbitrarily large body.
+async fn body_limit_middleware(
   request: axum::extract::Request,
   next: Next,
 ) -> Result<Response, StatusCode> {
-  let _requests_per_hour = crate::cloud_auth::CLOUD_AUTH.requests_per_hour().await;
-  // TODO(rate-limit): enforce `_requests_per_hour` for automation routes.
+  let limit = usize::try_from(current_limits().max_body_bytes).unwrap_or(usize::MAX);
+  let declared = request
+    .headers()
+    .get(header::CONTENT_LENGTH)
+    .and_then(|v| v.to_str().ok())
+    .and_then(|v| v.parse::<usize>().ok());
+  if declared.is_some_and(|len| len > limit) {
+    return Err(StatusCode::PAYLOAD_TOO_LARGE);
+  }
+
+  let (parts, body) = request.into_parts();
+  let bytes = axum::body::to_bytes(body, limit)
+    .await
+    .map_err(|_| StatusCode::PAYLOAD_TOO_LARGE)?;
+  let request = axum::extract::Request::from_parts(parts, axum::body::Body::from(bytes));
   Ok(next.run(request).await)
 }
 
@@ -715,10 +1398,11 @@ lazy_static! {
 #[tauri::command]
 pub async fn start_api_server_internal(
   port: u16,
-  app_handle: &tauri::AppHandle,
-) -> Result<u16, String> {
+  listen: &ApiListen,
+  app_handle: &crate::app_handle::AppHandle,
+) -> Result<ApiEndpoint, String> {
   let mut server_guard = API_SERVER.lock().await;
-  server_guard.start(app_handle.clone(), port).await
+  server_guard.start(app_handle.clone(), port, listen).await
 }
 
 #[tauri::command]
@@ -730,138 +1414,440 @@ pub async fn stop_api_server() -> Result<(), String> {
 #[tauri::command]
 pub async fn start_api_server(
   port: Option<u16>,
-  app_handle: tauri::AppHandle,
-) -> Result<u16, String> {
+  app_handle: crate::app_handle::AppHandle,
+) -> Result<ApiEndpoint, String> {
   let actual_port = port.unwrap_or(10108);
-  start_api_server_internal(actual_port, &app_handle).await
+  let listen = crate::settings_manager::SettingsManager::instance()
+    .load_settings()
+    .map(|s| s.api_listen)
+    .unwrap_or_default();
+  start_api_server_internal(actual_port, &listen, &app_handle).await
 }
 
 #[tauri::command]
-pub async fn get_api_server_status() -> Result<Option<u16>, String> {
+pub async fn get_api_server_status() -> Result<Option<ApiEndpoint>, String> {
   let server_guard = API_SERVER.lock().await;
-  Ok(server_guard.get_port())
+  Ok(server_guard.get_endpoint())
 }
 
-// API Handlers - Profiles
-/// Maps a manager-layer error onto a consistent HTTP status: 404 for missing
-/// entities, 400 for validation/duplicate/client-input errors, 500 for
-/// everything else (IO and other internal failures). The error text passes
-/// through as the response body so API clients get a diagnostic instead of a
-/// bare status code. Matching is on message content because the managers
-/// return plain strings (some are the JSON `{"code": ...}` strings shared
-/// with the Tauri commands).
-fn manager_error_response(err: impl std::fmt::Display) -> (StatusCode, String) {
-  let msg = err.to_string();
-
-  // Structured {"code": ...} errors from the shared managers classify exactly.
-  if let Ok(value) = serde_json::from_str::<serde_json::Value>(&msg) {
-    if let Some(code) = value.get("code").and_then(|c| c.as_str()) {
-      let status = if code.ends_with("_NOT_FOUND") {
-        StatusCode::NOT_FOUND
-      } else if code == "INTERNAL_ERROR" {
-        StatusCode::INTERNAL_SERVER_ERROR
-      } else if code.ends_with("_REQUIRES_PRO") || code.ends_with("_PAYMENT_REQUIRED") {
-        // Paid-feature gates (FINGERPRINT_REQUIRES_PRO, PROXY_PAYMENT_REQUIRED).
-        // Mapping them here lets the gate live in the shared manager instead of
-        // being re-implemented in each handler to get the status right.
-        StatusCode::PAYMENT_REQUIRED
-      } else {
-        // Validation-style codes (NAME_CANNOT_BE_EMPTY, GROUP_ALREADY_EXISTS,
-        // WAYFERN_VERSION_NOT_AVAILABLE, ...).
-        StatusCode::BAD_REQUEST
-      };
-      return (status, msg);
-    }
-  }
-
-  // Plain-text manager messages: match the known phrases narrowly so raw
-  // OS/serde/network error text (e.g. "invalid type: ..." from a corrupt
-  // store) falls through to 500 instead of masquerading as a client error.
-  let lower = msg.to_lowercase();
-  let status = if lower.contains("not found") {
-    StatusCode::NOT_FOUND
-  } else if lower.contains("already exists")
-    || lower.contains("cannot set both")
-    || lower.contains("cannot edit")
-    || lower.contains("cannot delete")
-    || lower.contains("cannot open url")
-    || lower.contains("invalid browser")
-    || lower.contains("invalid profile id")
-    || lower.contains("unsupported browser")
-    || lower.contains("not supported on your platform")
-    || lower.contains("is not downloaded")
-    || lower.contains("terms and conditions")
-  {
-    StatusCode::BAD_REQUEST
-  } else {
-    StatusCode::INTERNAL_SERVER_ERROR
-  };
-  (status, msg)
+/// Everything a client needs to reach the running API. The token is the
+/// full-scope `default` one shown in the Integrations dialog.
+#[derive(Debug, Serialize)]
+pub struct ApiConnectionInfo {
+  pub endpoint: ApiEndpoint,
+  pub base_url: String,
+  pub token: Option<String>,
 }
 
-/// Real per-group profile counts, computed from the profile list (the same
-/// source of truth the GUI uses).
-fn group_profile_counts() -> std::collections::HashMap<String, usize> {
-  let mut counts = std::collections::HashMap::new();
-  if let Ok(profiles) = ProfileManager::instance().list_profiles() {
-    for profile in profiles {
-      if let Some(group_id) = profile.group_id {
-        *counts.entry(group_id).or_insert(0) += 1;
-      }
-    }
-  }
-  counts
+#[tauri::command]
+pub async fn get_api_endpoint(
+  app_handle: crate::app_handle::AppHandle,
+) -> Result<ApiConnectionInfo, String> {
+  let endpoint = API_SERVER
+    .lock()
+    .await
+    .get_endpoint()
+    .ok_or_else(|| serde_json::json!({ "code": "API_SERVER_NOT_RUNNING" }).to_string())?;
+  let token = crate::settings_manager::SettingsManager::instance()
+    .get_api_token(&app_handle)
+    .await
+    .ok()
+    .flatten();
+  Ok(ApiConnectionInfo {
+    base_url: endpoint.base_url(),
+    endpoint,
+    token,
+  })
 }
 
-#[utoipa::path(
-  get,
-  path = "/v1/profiles",
-  responses(
-    (status = 200, description = "List of all profiles", body = ApiProfilesResponse),
-    (status = 401, description = "Unauthorized"),
-    (status = 500, description = "Internal server error")
-  ),
-  security(
-    ("bearer_auth" = [])
-  ),
-  tag = "profiles"
-)]
-async fn get_profiles() -> Result<Json<ApiProfilesResponse>, StatusCode> {
-  let profile_manager = ProfileManager::instance();
-  match profile_manager.list_profiles() {
-    Ok(profiles) => {
-      let api_profiles: Vec<ApiProfile> = profiles
-        .iter()
-        .map(|profile| ApiProfile {
-          id: profile.id.to_string(),
-          name: profile.name.clone(),
-          browser: profile.browser.clone(),
-          version: profile.version.clone(),
-          proxy_id: profile.proxy_id.clone(),
-          launch_hook: profile.launch_hook.clone(),
-          process_id: profile.process_id,
-          last_launch: profile.last_launch,
-          release_type: profile.release_type.clone(),
-          group_id: profile.group_id.clone(),
-          tags: profile.tags.clone(),
-          is_running: profile.process_id.is_some(), // Simple check based on process_id
-          proxy_bypass_rules: profile.proxy_bypass_rules.clone(),
-          vpn_id: profile.vpn_id.clone(),
-          clear_on_close: profile.clear_on_close,
-        })
-        .collect();
+/// Body of every failed API request. `code` is stable and meant for clients
+/// to branch on; `message` is for humans; `details` carries the parameters
+/// of the error (e.g. the missing browser version), when it has any.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ApiErrorBody {
+  pub code: String,
+  pub message: String,
+  #[schema(value_type = Option<Object>)]
+  pub details: Option<serde_json::Value>,
+}
 
-      Ok(Json(ApiProfilesResponse {
-        profiles: api_profiles,
-        total: profiles.len(),
-      }))
+/// Error returned by the API handlers. The variant decides the status code;
+/// the body is always an [`ApiErrorBody`].
+#[derive(Debug)]
+pub enum ApiError {
+  /// 400: the request itself is invalid.
+  BadRequest {
+    code: String,
+    message: String,
+    details: Option<serde_json::Value>,
+  },
+  /// 402: the action needs a paid plan or an active proxy subscription.
+  PaymentRequired {
+    code: String,
+    message: String,
+    details: Option<serde_json::Value>,
+  },
+  /// 404: unknown profile, proxy, group or other entity.
+  NotFound {
+    code: String,
+    message: String,
+    details: Option<serde_json::Value>,
+  },
+  /// 409: the profile is already running or locked, or the entity is in use.
+  Conflict {
+    code: String,
+    message: String,
+    details: Option<serde_json::Value>,
+  },
+  /// 424: the browser version the profile needs is not downloaded.
+  BrowserMissing { browser: String, version: String },
+  /// 503: the local proxy, its upstream or the VPN could not be started.
+  ProxyUnavailable {
+    message: String,
+    details: Option<serde_json::Value>,
+  },
+  /// 503: a dependency other than the proxy is not ready yet.
+  Unavailable {
+    code: String,
+    message: String,
+    details: Option<serde_json::Value>,
+  },
+  /// 500: anything else.
+  Internal { message: String },
+}
+
+impl ApiError {
+  fn bad_request(code: &str, message: impl Into<String>) -> Self {
+    ApiError::BadRequest {
+      code: code.to_string(),
+      message: message.into(),
+      details: None,
     }
-    Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
   }
-}
 
-#[utoipa::path(
-  get,
+  fn payment_required(code: &str, message: impl Into<String>) -> Self {
+    ApiError::PaymentRequired {
+      code: code.to_string(),
+      message: message.into(),
+      details: None,
+    }
+  }
+
+  fn not_found(code: &str, message: impl Into<String>) -> Self {
+    ApiError::NotFound {
+      code: code.to_string(),
+      message: message.into(),
+      details: None,
+    }
+  }
+
+  fn conflict(code: &str, message: impl Into<String>) -> Self {
+    ApiError::Conflict {
+      code: code.to_string(),
+      message: message.into(),
+      details: None,
+    }
+  }
+
+  fn internal(message: impl std::fmt::Display) -> Self {
+    ApiError::Internal {
+      message: message.to_string(),
+    }
+  }
+
+  fn profile_not_found(id: &str) -> Self {
+    ApiError::not_found("PROFILE_NOT_FOUND", format!("Profile {id} not found"))
+  }
+
+  /// Browser automation (run, open-url, kill, CDP) is a paid feature.
+  fn automation_required() -> Self {
+    ApiError::payment_required(
+      "AUTOMATION_REQUIRES_PAID_PLAN",
+      "Browser automation requires a paid plan",
+    )
+  }
+
+  pub fn status(&self) -> StatusCode {
+    match self {
+      ApiError::BadRequest { .. } => StatusCode::BAD_REQUEST,
+      ApiError::PaymentRequired { .. } => StatusCode::PAYMENT_REQUIRED,
+      ApiError::NotFound { .. } => StatusCode::NOT_FOUND,
+      ApiError::Conflict { .. } => StatusCode::CONFLICT,
+      ApiError::BrowserMissing { .. } => StatusCode::FAILED_DEPENDENCY,
+      ApiError::ProxyUnavailable { .. } | ApiError::Unavailable { .. } => {
+        StatusCode::SERVICE_UNAVAILABLE
+      }
+      ApiError::Internal { .. } => StatusCode::INTERNAL_SERVER_ERROR,
+    }
+  }
+
+  pub fn body(&self) -> ApiErrorBody {
+    let (code, message, details) = match self {
+      ApiError::BadRequest {
+        code,
+        message,
+        details,
+      }
+      | ApiError::PaymentRequired {
+        code,
+        message,
+        details,
+      }
+      | ApiError::NotFound {
+        code,
+        message,
+        details,
+      }
+      | ApiError::Conflict {
+        code,
+        message,
+        details,
+      }
+      | ApiError::Unavailable {
+        code,
+        message,
+        details,
+      } => (code.clone(), message.clone(), details.clone()),
+      ApiError::BrowserMissing { browser, version } => (
+        "BROWSER_NOT_DOWNLOADED".to_string(),
+        format!("{browser} {version} is not downloaded"),
+        Some(serde_json::json!({ "browser": browser, "version": version })),
+      ),
+      ApiError::ProxyUnavailable { message, details } => (
+        "PROXY_UNAVAILABLE".to_string(),
+        message.clone(),
+        details.clone(),
+      ),
+      ApiError::Internal { message } => ("INTERNAL_ERROR".to_string(), message.clone(), None),
+    };
+    ApiErrorBody {
+      code,
+      message,
+      details,
+    }
+  }
+
+  /// Classifies a manager-layer error. Structured `{"code": ...}` errors
+  /// (shared with the Tauri commands) classify exactly and keep their code,
+  /// with their params as `details`. Plain-text messages are matched on
+  /// known phrases only, so raw OS/serde/network text (e.g. "invalid type:
+  /// ..." from a corrupt store) ends up as a 500 rather than a client error.
+  pub fn from_manager(err: impl std::fmt::Display) -> Self {
+    let msg = err.to_string();
+
+    if let Ok(value) = serde_json::from_str::<serde_json::Value>(&msg) {
+      if let Some(code) = value.get("code").and_then(|c| c.as_str()) {
+        let details = value.get("params").cloned();
+        let message = details
+          .as_ref()
+          .and_then(|p| p.get("error"))
+          .and_then(|e| e.as_str())
+          .map(str::to_string)
+          .unwrap_or_else(|| humanize_error_code(code));
+        return if code.ends_with("_NOT_FOUND") {
+          ApiError::NotFound {
+            code: code.to_string(),
+            message,
+            details,
+          }
+        } else if code == "INTERNAL_ERROR" {
+          ApiError::Internal { message }
+        } else if code.ends_with("_IN_USE")
+          || code.contains("RUNNING")
+          || code == "LAUNCH_CANCELLED"
+        {
+          // PROFILE_RUNNING, PROFILE_RUNNING_EXTERNALLY, PROFILE_NOT_RUNNING,
+          // CDP_PORT_IN_USE, PROXY_IN_USE, ...
+          ApiError::Conflict {
+            code: code.to_string(),
+            message,
+            details,
+          }
+        } else if code.ends_with("_REQUIRES_PRO") || code.ends_with("_PAYMENT_REQUIRED") {
+          // Paid-feature gates (FINGERPRINT_REQUIRES_PRO, PROXY_PAYMENT_REQUIRED).
+          // Mapping them here lets the gate live in the shared manager instead
+          // of being re-implemented in each handler to get the status right.
+          ApiError::PaymentRequired {
+            code: code.to_string(),
+            message,
+            details,
+          }
+        } else if matches!(
+          code,
+          "VPN_CONNECT_FAILED" | "PROXY_NOT_WORKING" | "VPN_NOT_WORKING"
+        ) {
+          ApiError::ProxyUnavailable { message, details }
+        } else if matches!(code, "CDP_UNAVAILABLE" | "SYNC_SERVER_UNREACHABLE") {
+          ApiError::Unavailable {
+            code: code.to_string(),
+            message,
+            details,
+          }
+        } else {
+          // Validation-style codes (NAME_CANNOT_BE_EMPTY, GROUP_ALREADY_EXISTS,
+          // WAYFERN_VERSION_NOT_AVAILABLE, ...).
+          ApiError::BadRequest {
+            code: code.to_string(),
+            message,
+            details,
+          }
+        };
+      }
+    }
+
+    let lower = msg.to_lowercase();
+    if lower.contains("failed to start local proxy") {
+      ApiError::ProxyUnavailable {
+        message: msg,
+        details: None,
+      }
+    } else if lower.contains("not found") {
+      ApiError::not_found("NOT_FOUND", msg)
+    } else if lower.contains("already being downloaded") {
+      ApiError::conflict("DOWNLOAD_IN_PROGRESS", msg)
+    } else if lower.contains("already exists")
+      || lower.contains("cannot set both")
+      || lower.contains("cannot edit")
+      || lower.contains("cannot delete")
+      || lower.contains("cannot open url")
+      || lower.contains("cannot launch profile")
+      || lower.contains("invalid browser")
+      || lower.contains("invalid profile id")
+      || lower.contains("unsupported browser")
+      || lower.contains("not supported on your platform")
+      || lower.contains("is not downloaded")
+      || lower.contains("terms and conditions")
+    {
+      ApiError::bad_request("BAD_REQUEST", msg)
+    } else {
+      ApiError::internal(msg)
+    }
+  }
+}
+
+impl IntoResponse for ApiError {
+  fn into_response(self) -> Response {
+    (self.status(), Json(self.body())).into_response()
+  }
+}
+
+/// `PROFILE_NOT_FOUND` -> "Profile not found".
+fn humanize_error_code(code: &str) -> String {
+  let lower = code.to_lowercase().replace('_', " ");
+  let mut chars = lower.chars();
+  match chars.next() {
+    Some(first) => first.to_uppercase().chain(chars).collect(),
+    None => String::new(),
+  }
+}
+
+/// Checks that can fail a `/run` before the launch starts: a profile that is
+/// already running (409) or whose browser version is missing (424).
+fn launch_precondition(
+  profile: &crate::profile::BrowserProfile,
+  is_running: bool,
+  binary_present: bool,
+) -> Result<(), ApiError> {
+  if profile.is_cross_os() {
+    return Err(ApiError::bad_request(
+      "PROFILE_CROSS_OS",
+      "Profiles created on another operating system cannot be launched",
+    ));
+  }
+  if is_running {
+    return Err(ApiError::Conflict {
+      code: "PROFILE_ALREADY_RUNNING".to_string(),
+      message: format!("Profile {} is already running", profile.id),
+      details: Some(serde_json::json!({ "process_id": profile.process_id })),
+    });
+  }
+  if !binary_present {
+    return Err(ApiError::BrowserMissing {
+      browser: profile.browser.clone(),
+      version: profile.version.clone(),
+    });
+  }
+  Ok(())
+}
+
+// API Handlers - Profiles
+/// Real per-group profile counts, computed from the profile list (the same
+/// source of truth the GUI uses).
+fn group_profile_counts() -> std::collections::HashMap<String, usize> {
+  let mut counts = std::collections::HashMap::new();
+  if let Ok(profiles) = ProfileManager::instance().list_profiles() {
+    for profile in profiles {
+      if let Some(group_id) = profile.group_id {
+        *counts.entry(group_id).or_insert(0) += 1;
+      }
+    }
+  }
+  counts
+}
+
+/// List profiles, optionally filtered and paginated. `total` (and the
+/// `x-total-count` header) is the number of matches before `limit`/`offset`.
+#[utoipa::path(
+  get,
+  path = "/v1/profiles",
+  params(
+    ("browser" = Option<String>, Query, description = "Only profiles of this browser"),
+    ("group_id" = Option<String>, Query, description = "Only profiles in this group; \"default\" selects ungrouped profiles"),
+    ("tag" = Option<Vec<String>>, Query, description = "Only profiles carrying this tag. Repeat to require several tags"),
+    ("running" = Option<bool>, Query, description = "Only running (true) or stopped (false) profiles"),
+    ("name" = Option<String>, Query, description = "Case-insensitive substring of the profile name"),
+    ("metadata.<key>" = Option<String>, Query, description = "Only profiles whose metadata `<key>` equals this value, e.g. `metadata.account_id=42`. Repeat with different keys to require several"),
+    ("limit" = Option<usize>, Query, description = "Maximum number of profiles to return (1-1000)"),
+    ("offset" = Option<usize>, Query, description = "Number of matching profiles to skip"),
+    ("fields" = Option<String>, Query, description = "Comma-separated profile fields to include. `id` is always included")
+  ),
+  responses(
+    (status = 200, description = "List of profiles", body = ApiProfilesResponse,
+      headers(("x-total-count" = usize, description = "Number of matching profiles before pagination"))),
+    (status = 400, description = "Invalid filter or pagination value"),
+    (status = 401, description = "Unauthorized"),
+    (status = 500, description = "Internal server error")
+  ),
+  security(
+    ("bearer_auth" = [])
+  ),
+  tag = "profiles"
+)]
+async fn get_profiles(
+  RawQuery(raw_query): RawQuery,
+) -> Result<(HeaderMap, Json<serde_json::Value>), ApiError> {
+  let query = parse_profile_list_query(raw_query.as_deref())
+    .map_err(|e| ApiError::bad_request("INVALID_QUERY", e))?;
+
+  let profiles = ProfileManager::instance()
+    .list_profiles()
+    .map_err(ApiError::from_manager)?;
+  let matching: Vec<_> = profiles.iter().filter(|p| query.matches(p)).collect();
+  let total = matching.len();
+
+  let page = matching
+    .into_iter()
+    .skip(query.offset)
+    .take(query.limit.unwrap_or(usize::MAX));
+  let mut api_profiles = Vec::new();
+  for profile in page {
+    let mut value = serde_json::to_value(api_profile_from(profile)).map_err(ApiError::internal)?;
+    if let (Some(fields), Some(object)) = (&query.fields, value.as_object_mut()) {
+      object.retain(|key, _| key == "id" || fields.iter().any(|f| f == key));
+    }
+    api_profiles.push(value);
+  }
+
+  let mut headers = HeaderMap::new();
+  headers.insert("x-total-count", total.into());
+  Ok((
+    headers,
+    Json(serde_json::json!({ "profiles": api_profiles, "total": total })),
+  ))
+}
+
+#[utoipa::path(
+  get,
   path = "/v1/profiles/{id}",
   params(
     ("id" = String, Path, description = "Profile ID")
@@ -880,35 +1866,19 @@ async fn get_profiles() -> Result<Json<ApiProfilesResponse>, StatusCode> {
 async fn get_profile(
   Path(id): Path<String>,
   State(_state): State<ApiServerState>,
-) -> Result<Json<ApiProfileResponse>, StatusCode> {
+) -> Result<Json<ApiProfileResponse>, ApiError> {
   let profile_manager = ProfileManager::instance();
   match profile_manager.list_profiles() {
     Ok(profiles) => {
       if let Some(profile) = profiles.iter().find(|p| p.id.to_string() == id) {
         Ok(Json(ApiProfileResponse {
-          profile: ApiProfile {
-            id: profile.id.to_string(),
-            name: profile.name.clone(),
-            browser: profile.browser.clone(),
-            version: profile.version.clone(),
-            proxy_id: profile.proxy_id.clone(),
-            launch_hook: profile.launch_hook.clone(),
-            process_id: profile.process_id,
-            last_launch: profile.last_launch,
-            release_type: profile.release_type.clone(),
-            group_id: profile.group_id.clone(),
-            tags: profile.tags.clone(),
-            is_running: profile.process_id.is_some(), // Simple check based on process_id
-            proxy_bypass_rules: profile.proxy_bypass_rules.clone(),
-            vpn_id: profile.vpn_id.clone(),
-            clear_on_close: profile.clear_on_close,
-          },
+          profile: api_profile_from(profile),
         }))
       } else {
-        Err(StatusCode::NOT_FOUND)
+        Err(ApiError::profile_not_found(&id))
       }
     }
-    Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
+    Err(e) => Err(ApiError::internal(e)),
   }
 }
 
@@ -940,7 +1910,7 @@ async fn get_profile(
 async fn create_profile(
   State(state): State<ApiServerState>,
   Json(request): Json<CreateProfileRequest>,
-) -> Result<Json<ApiProfileResponse>, (StatusCode, String)> {
+) -> Result<Json<ApiProfileResponse>, ApiError> {
   let profile_manager = ProfileManager::instance();
 
   // Only Wayfern profiles are launchable; the rest of the system
@@ -949,8 +1919,8 @@ async fn create_profile(
   // unrecognized browser, then crashes with a 500 on /run. Mirrors the MCP
   // create_profile validation.
   if request.browser != "wayfern" {
-    return Err((
-      StatusCode::BAD_REQUEST,
+    return Err(ApiError::bad_request(
+      "INVALID_BROWSER",
       format!(
         "Invalid browser \"{}\". Must be \"wayfern\" (anti-detect Chromium).",
         request.browser
@@ -966,15 +1936,11 @@ async fn create_profile(
     Some(v) if !v.is_empty() && v != "latest" => v.to_string(),
     _ => {
       let registry = crate::downloaded_browsers_registry::DownloadedBrowsersRegistry::instance();
-      let mut versions = registry.get_downloaded_versions(&request.browser);
-      // browsers is a HashMap, so keys are unordered — sort newest-first by
-      // semver before taking the latest.
-      versions.sort_by(|a, b| crate::api_client::compare_versions(b, a));
-      match versions.into_iter().next() {
+      match registry.get_latest_downloaded_version(&request.browser) {
         Some(v) => v,
         None => {
-          return Err((
-            StatusCode::BAD_REQUEST,
+          return Err(ApiError::bad_request(
+            "NO_DOWNLOADED_VERSION",
             format!(
               "No downloaded version of \"{}\" is available. Download the browser in Donut Browser first — this endpoint does not download browsers.",
               request.browser
@@ -998,15 +1964,18 @@ async fn create_profile(
     crate::validate_profile_network(request.proxy_id.as_deref(), request.vpn_id.as_deref()).await
   {
     return Err(if err.contains("PROXY_PAYMENT_REQUIRED") {
-      (
-        StatusCode::PAYMENT_REQUIRED,
-        "The selected proxy requires an active subscription.".to_string(),
+      ApiError::payment_required(
+        "PROXY_PAYMENT_REQUIRED",
+        "The selected proxy requires an active subscription.",
       )
     } else {
-      (
-        StatusCode::BAD_REQUEST,
-        format!("Profile network validation failed: {err}"),
-      )
+      ApiError::BadRequest {
+        code: "PROFILE_NETWORK_INVALID".to_string(),
+        message: format!("Profile network validation failed: {err}"),
+        details: serde_json::from_str::<serde_json::Value>(&err)
+          .ok()
+          .and_then(|v| v.get("params").cloned()),
+      }
     });
   }
 
@@ -1035,9 +2004,8 @@ async fn create_profile(
           .update_profile_tags(&state.app_handle, &profile.name, tags.clone())
           .is_err()
         {
-          return Err((
-            StatusCode::INTERNAL_SERVER_ERROR,
-            "Profile created but failed to apply tags.".to_string(),
+          return Err(ApiError::internal(
+            "Profile created but failed to apply tags.",
           ));
         }
         profile.tags = tags.clone();
@@ -1067,13 +2035,20 @@ async fn create_profile(
           proxy_bypass_rules: profile.proxy_bypass_rules,
           vpn_id: profile.vpn_id,
           clear_on_close: profile.clear_on_close,
+          metadata: profile.metadata,
+          last_exit: None,
+          proxy_pool_id: profile.proxy_pool_id,
+          pool_proxy_id: None,
         },
       }))
     }
-    Err(e) => Err((
-      StatusCode::BAD_REQUEST,
-      format!("Failed to create profile: {e}"),
-    )),
+    Err(e) => Err(match ApiError::from_manager(&e) {
+      ApiError::Internal { .. } => ApiError::bad_request(
+        "PROFILE_CREATE_FAILED",
+        format!("Failed to create profile: {e}"),
+      ),
+      classified => classified,
+    }),
   }
 }
 
@@ -1100,28 +2075,56 @@ async fn update_profile(
   Path(id): Path<String>,
   State(state): State<ApiServerState>,
   Json(request): Json<UpdateProfileRequest>,
-) -> Result<Json<ApiProfileResponse>, (StatusCode, String)> {
+) -> Result<Json<ApiProfileResponse>, ApiError> {
   let profile_manager = ProfileManager::instance();
 
   if request.proxy_id.as_deref().is_some_and(|s| !s.is_empty())
     && request.vpn_id.as_deref().is_some_and(|s| !s.is_empty())
   {
-    return Err((
-      StatusCode::BAD_REQUEST,
-      "Cannot set both proxy_id and vpn_id".to_string(),
+    return Err(ApiError::bad_request(
+      "PROXY_AND_VPN_MUTUALLY_EXCLUSIVE",
+      "Cannot set both proxy_id and vpn_id",
+    ));
+  }
+  if request
+    .proxy_pool_id
+    .as_deref()
+    .is_some_and(|s| !s.is_empty())
+    && (request.proxy_id.as_deref().is_some_and(|s| !s.is_empty())
+      || request.vpn_id.as_deref().is_some_and(|s| !s.is_empty()))
+  {
+    return Err(ApiError::bad_request(
+      "PROXY_AND_VPN_MUTUALLY_EXCLUSIVE",
+      "Cannot set proxy_pool_id together with proxy_id or vpn_id",
     ));
   }
 
   // Update profile fields
   if let Some(new_name) = request.name {
     if let Err(e) = profile_manager.rename_profile(&state.app_handle, &id, &new_name) {
-      return Err(manager_error_response(e));
+      return Err(ApiError::from_manager(e));
     }
   }
 
   if let Some(version) = request.version {
     if let Err(e) = profile_manager.update_profile_version(&state.app_handle, &id, &version) {
-      return Err(manager_error_response(e));
+      return Err(ApiError::from_manager(e));
+    }
+  }
+
+  // Before proxy/VPN: clearing the pool also clears those, so a proxy or VPN
+  // set in the same request has to be applied after it.
+  if let Some(pool_id) = request.proxy_pool_id {
+    let normalized = if pool_id.is_empty() {
+      None
+    } else {
+      Some(pool_id)
+    };
+    if let Err(e) = profile_manager
+      .update_profile_proxy_pool(&id, normalized)
+      .await
+    {
+      return Err(ApiError::from_manager(e));
     }
   }
 
@@ -1130,7 +2133,7 @@ async fn update_profile(
       .update_profile_proxy(state.app_handle.clone(), &id, Some(proxy_id))
       .await
     {
-      return Err(manager_error_response(e));
+      return Err(ApiError::from_manager(e));
     }
   }
 
@@ -1144,7 +2147,7 @@ async fn update_profile(
       .update_profile_vpn(state.app_handle.clone(), &id, normalized)
       .await
     {
-      return Err(manager_error_response(e));
+      return Err(ApiError::from_manager(e));
     }
   }
 
@@ -1156,7 +2159,7 @@ async fn update_profile(
     };
 
     if let Err(e) = profile_manager.update_profile_launch_hook(&state.app_handle, &id, normalized) {
-      return Err(manager_error_response(e));
+      return Err(ApiError::from_manager(e));
     }
   }
 
@@ -1164,13 +2167,13 @@ async fn update_profile(
     if let Err(e) =
       profile_manager.assign_profiles_to_group(&state.app_handle, vec![id.clone()], Some(group_id))
     {
-      return Err(manager_error_response(e));
+      return Err(ApiError::from_manager(e));
     }
   }
 
   if let Some(tags) = request.tags {
     if let Err(e) = profile_manager.update_profile_tags(&state.app_handle, &id, tags) {
-      return Err(manager_error_response(e));
+      return Err(ApiError::from_manager(e));
     }
 
     // Update tag manager with new tags from all profiles
@@ -1188,7 +2191,7 @@ async fn update_profile(
       Some(extension_group_id)
     };
     if let Err(e) = profile_manager.update_profile_extension_group(&id, ext_group) {
-      return Err(manager_error_response(e));
+      return Err(ApiError::from_manager(e));
     }
   }
 
@@ -1196,15 +2199,15 @@ async fn update_profile(
     if let Err(e) =
       profile_manager.update_profile_proxy_bypass_rules(&state.app_handle, &id, proxy_bypass_rules)
     {
-      return Err(manager_error_response(e));
+      return Err(ApiError::from_manager(e));
     }
   }
 
   if let Some(sync_mode) = request.sync_mode {
     if let Err(e) =
-      crate::sync::set_profile_sync_mode(state.app_handle.clone(), id.clone(), sync_mode).await
+      crate::sync::set_profile_sync_mode_impl(state.app_handle.clone(), id.clone(), sync_mode).await
     {
-      return Err(manager_error_response(e));
+      return Err(ApiError::from_manager(e));
     }
   }
 
@@ -1212,14 +2215,12 @@ async fn update_profile(
     if let Err(e) =
       profile_manager.update_profile_clear_on_close(&state.app_handle, &id, clear_on_close)
     {
-      return Err(manager_error_response(e));
+      return Err(ApiError::from_manager(e));
     }
   }
 
   // Return updated profile
-  get_profile(Path(id), State(state))
-    .await
-    .map_err(|status| (status, String::new()))
+  get_profile(Path(id), State(state)).await
 }
 
 #[utoipa::path(
@@ -1243,11 +2244,11 @@ async fn update_profile(
 async fn delete_profile(
   Path(id): Path<String>,
   State(state): State<ApiServerState>,
-) -> Result<StatusCode, (StatusCode, String)> {
+) -> Result<StatusCode, ApiError> {
   let profile_manager = ProfileManager::instance();
   match profile_manager.delete_profile(&state.app_handle, &id) {
     Ok(_) => Ok(StatusCode::NO_CONTENT),
-    Err(e) => Err(manager_error_response(e)),
+    Err(e) => Err(ApiError::from_manager(e)),
   }
 }
 
@@ -1267,7 +2268,7 @@ async fn delete_profile(
 )]
 async fn get_groups(
   State(_state): State<ApiServerState>,
-) -> Result<Json<Vec<ApiGroupResponse>>, StatusCode> {
+) -> Result<Json<Vec<ApiGroupResponse>>, ApiError> {
   match GROUP_MANAGER.lock() {
     Ok(manager) => match manager.get_all_groups() {
       Ok(groups) => {
@@ -1282,9 +2283,9 @@ async fn get_groups(
           .collect();
         Ok(Json(api_groups))
       }
-      Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
+      Err(e) => Err(ApiError::internal(e)),
     },
-    Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
+    Err(e) => Err(ApiError::internal(e)),
   }
 }
 
@@ -1308,7 +2309,7 @@ async fn get_groups(
 async fn get_group(
   Path(id): Path<String>,
   State(_state): State<ApiServerState>,
-) -> Result<Json<ApiGroupResponse>, StatusCode> {
+) -> Result<Json<ApiGroupResponse>, ApiError> {
   match GROUP_MANAGER.lock() {
     Ok(manager) => match manager.get_all_groups() {
       Ok(groups) => {
@@ -1319,12 +2320,15 @@ async fn get_group(
             name: group.name,
           }))
         } else {
-          Err(StatusCode::NOT_FOUND)
+          Err(ApiError::not_found(
+            "GROUP_NOT_FOUND",
+            format!("Group {id} not found"),
+          ))
         }
       }
-      Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
+      Err(e) => Err(ApiError::internal(e)),
     },
-    Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
+    Err(e) => Err(ApiError::internal(e)),
   }
 }
 
@@ -1346,7 +2350,7 @@ async fn get_group(
 async fn create_group(
   State(state): State<ApiServerState>,
   Json(request): Json<CreateGroupRequest>,
-) -> Result<Json<ApiGroupResponse>, (StatusCode, String)> {
+) -> Result<Json<ApiGroupResponse>, ApiError> {
   match GROUP_MANAGER.lock() {
     Ok(manager) => match manager.create_group(&state.app_handle, request.name) {
       Ok(group) => Ok(Json(ApiGroupResponse {
@@ -1354,12 +2358,9 @@ async fn create_group(
         name: group.name,
         profile_count: 0,
       })),
-      Err(e) => Err(manager_error_response(e)),
+      Err(e) => Err(ApiError::from_manager(e)),
     },
-    Err(_) => Err((
-      StatusCode::INTERNAL_SERVER_ERROR,
-      "group manager unavailable".to_string(),
-    )),
+    Err(_) => Err(ApiError::internal("group manager unavailable")),
   }
 }
 
@@ -1386,20 +2387,22 @@ async fn update_group(
   Path(id): Path<String>,
   State(state): State<ApiServerState>,
   Json(request): Json<UpdateGroupRequest>,
-) -> Result<Json<ApiGroupResponse>, (StatusCode, String)> {
+) -> Result<Json<ApiGroupResponse>, ApiError> {
   match GROUP_MANAGER.lock() {
-    Ok(manager) => match manager.update_group(&state.app_handle, id.clone(), request.name) {
+    Ok(manager) => match manager.update_group(
+      &state.app_handle,
+      id.clone(),
+      request.name,
+      request.protected,
+    ) {
       Ok(group) => Ok(Json(ApiGroupResponse {
         profile_count: group_profile_counts().get(&group.id).copied().unwrap_or(0),
         id: group.id,
         name: group.name,
       })),
-      Err(e) => Err(manager_error_response(e)),
+      Err(e) => Err(ApiError::from_manager(e)),
     },
-    Err(_) => Err((
-      StatusCode::INTERNAL_SERVER_ERROR,
-      "group manager unavailable".to_string(),
-    )),
+    Err(_) => Err(ApiError::internal("group manager unavailable")),
   }
 }
 
@@ -1423,16 +2426,13 @@ async fn update_group(
 async fn delete_group(
   Path(id): Path<String>,
   State(state): State<ApiServerState>,
-) -> Result<StatusCode, (StatusCode, String)> {
+) -> Result<StatusCode, ApiError> {
   match GROUP_MANAGER.lock() {
     Ok(manager) => match manager.delete_group(&state.app_handle, id.clone()) {
       Ok(_) => Ok(StatusCode::NO_CONTENT),
-      Err(e) => Err(manager_error_response(e)),
+      Err(e) => Err(ApiError::from_manager(e)),
     },
-    Err(_) => Err((
-      StatusCode::INTERNAL_SERVER_ERROR,
-      "group manager unavailable".to_string(),
-    )),
+    Err(_) => Err(ApiError::internal("group manager unavailable")),
   }
 }
 
@@ -1450,13 +2450,13 @@ async fn delete_group(
   ),
   tag = "tags"
 )]
-async fn get_tags(State(_state): State<ApiServerState>) -> Result<Json<Vec<String>>, StatusCode> {
+async fn get_tags(State(_state): State<ApiServerState>) -> Result<Json<Vec<String>>, ApiError> {
   match TAG_MANAGER.lock() {
     Ok(manager) => match manager.get_all_tags() {
       Ok(tags) => Ok(Json(tags)),
-      Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
+      Err(e) => Err(ApiError::internal(e)),
     },
-    Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
+    Err(e) => Err(ApiError::internal(e)),
   }
 }
 
@@ -1476,7 +2476,7 @@ async fn get_tags(State(_state): State<ApiServerState>) -> Result<Json<Vec<Strin
 )]
 async fn get_proxies(
   State(_state): State<ApiServerState>,
-) -> Result<Json<Vec<ApiProxyResponse>>, StatusCode> {
+) -> Result<Json<Vec<ApiProxyResponse>>, ApiError> {
   let proxies = PROXY_MANAGER.get_stored_proxies();
   Ok(Json(
     proxies
@@ -1510,7 +2510,7 @@ async fn get_proxies(
 async fn get_proxy(
   Path(id): Path<String>,
   State(_state): State<ApiServerState>,
-) -> Result<Json<ApiProxyResponse>, StatusCode> {
+) -> Result<Json<ApiProxyResponse>, ApiError> {
   let proxies = PROXY_MANAGER.get_stored_proxies();
   if let Some(proxy) = proxies.into_iter().find(|p| p.id == id) {
     Ok(Json(ApiProxyResponse {
@@ -1519,7 +2519,10 @@ async fn get_proxy(
       proxy_settings: proxy.proxy_settings,
     }))
   } else {
-    Err(StatusCode::NOT_FOUND)
+    Err(ApiError::not_found(
+      "PROXY_NOT_FOUND",
+      format!("Proxy {id} not found"),
+    ))
   }
 }
 
@@ -1541,7 +2544,7 @@ async fn get_proxy(
 async fn create_proxy(
   State(state): State<ApiServerState>,
   Json(request): Json<CreateProxyRequest>,
-) -> Result<Json<ApiProxyResponse>, (StatusCode, String)> {
+) -> Result<Json<ApiProxyResponse>, ApiError> {
   let result = PROXY_MANAGER.create_stored_proxy(
     &state.app_handle,
     request.name.clone(),
@@ -1554,7 +2557,7 @@ async fn create_proxy(
       name: proxy.name,
       proxy_settings: proxy.proxy_settings,
     })),
-    Err(e) => Err(manager_error_response(e)),
+    Err(e) => Err(ApiError::from_manager(e)),
   }
 }
 
@@ -1578,11 +2581,11 @@ async fn create_proxy(
 async fn import_proxies_api(
   State(state): State<ApiServerState>,
   Json(request): Json<ImportProxiesRequest>,
-) -> Result<Json<ImportProxiesResponse>, (StatusCode, String)> {
+) -> Result<Json<ImportProxiesResponse>, ApiError> {
   let result = match request.format.as_str() {
     "json" => PROXY_MANAGER
       .import_proxies_json(&state.app_handle, &request.content)
-      .map_err(manager_error_response)?,
+      .map_err(ApiError::from_manager)?,
     "txt" => {
       use crate::proxy_manager::{ProxyManager, ProxyParseResult};
 
@@ -1595,19 +2598,19 @@ async fn import_proxies_api(
         .collect();
 
       if parsed.is_empty() {
-        return Err((
-          StatusCode::BAD_REQUEST,
-          "No valid proxies found in content".to_string(),
+        return Err(ApiError::bad_request(
+          "NO_VALID_PROXIES",
+          "No valid proxies found in content",
         ));
       }
 
       PROXY_MANAGER
         .import_proxies_from_parsed(&state.app_handle, parsed, request.name_prefix)
-        .map_err(manager_error_response)?
+        .map_err(ApiError::from_manager)?
     }
     other => {
-      return Err((
-        StatusCode::BAD_REQUEST,
+      return Err(ApiError::bad_request(
+        "INVALID_IMPORT_FORMAT",
         format!("Invalid format \"{other}\", must be \"json\" or \"txt\""),
       ))
     }
@@ -1652,7 +2655,7 @@ async fn update_proxy(
   Path(id): Path<String>,
   State(state): State<ApiServerState>,
   Json(request): Json<UpdateProxyRequest>,
-) -> Result<Json<ApiProxyResponse>, (StatusCode, String)> {
+) -> Result<Json<ApiProxyResponse>, ApiError> {
   let result =
     PROXY_MANAGER.update_stored_proxy(&state.app_handle, &id, request.name, request.proxy_settings);
 
@@ -1662,7 +2665,7 @@ async fn update_proxy(
       name: proxy.name,
       proxy_settings: proxy.proxy_settings,
     })),
-    Err(e) => Err(manager_error_response(e)),
+    Err(e) => Err(ApiError::from_manager(e)),
   }
 }
 
@@ -1670,11 +2673,13 @@ async fn update_proxy(
   delete,
   path = "/v1/proxies/{id}",
   params(
-    ("id" = String, Path, description = "Proxy ID")
+    ("id" = String, Path, description = "Proxy ID"),
+    ("force" = Option<bool>, Query, description = "Delete even if profiles use the proxy; they are left without one")
   ),
   responses(
     (status = 204, description = "Proxy deleted successfully"),
     (status = 400, description = "Bad request (e.g. cloud-managed proxy)"),
+    (status = 409, description = "Proxy is assigned to profiles and force was not set"),
     (status = 401, description = "Unauthorized"),
     (status = 404, description = "Proxy not found"),
     (status = 500, description = "Internal server error")
@@ -1686,14 +2691,159 @@ async fn update_proxy(
 )]
 async fn delete_proxy(
   Path(id): Path<String>,
+  Query(query): Query<ForceQuery>,
   State(state): State<ApiServerState>,
-) -> Result<StatusCode, (StatusCode, String)> {
-  match PROXY_MANAGER.delete_stored_proxy(&state.app_handle, &id) {
+) -> Result<StatusCode, ApiError> {
+  match crate::entity_usage::delete_stored_proxy(&state.app_handle, &id, query.force) {
     Ok(_) => Ok(StatusCode::NO_CONTENT),
-    Err(e) => Err(manager_error_response(e)),
+    Err(e) => Err(ApiError::from_manager(e)),
   }
 }
 
+#[utoipa::path(
+  get,
+  path = "/v1/proxies/usage",
+  params(
+    ("id" = Option<String>, Query, description = "Only this proxy instead of every stored proxy")
+  ),
+  responses(
+    (status = 200, description = "Profiles referencing each proxy", body = Vec<EntityUsage>),
+    (status = 401, description = "Unauthorized"),
+    (status = 404, description = "Proxy not found"),
+    (status = 500, description = "Internal server error")
+  ),
+  security(
+    ("bearer_auth" = [])
+  ),
+  tag = "proxies"
+)]
+async fn get_proxy_usage(
+  Query(query): Query<UsageQuery>,
+  State(_state): State<ApiServerState>,
+) -> Result<Json<Vec<EntityUsage>>, ApiError> {
+  crate::entity_usage::proxy_usage(query.id.as_deref())
+    .map(Json)
+    .map_err(ApiError::from_manager)
+}
+
+// API Handlers - Proxy pools
+
+#[utoipa::path(
+  get,
+  path = "/v1/proxy-pools",
+  responses(
+    (status = 200, description = "List of all proxy pools", body = Vec<crate::proxy_manager::ProxyPool>),
+    (status = 401, description = "Unauthorized"),
+    (status = 500, description = "Internal server error")
+  ),
+  security(("bearer_auth" = [])),
+  tag = "proxies"
+)]
+async fn get_proxy_pools_api(
+  State(_state): State<ApiServerState>,
+) -> Json<Vec<crate::proxy_manager::ProxyPool>> {
+  Json(PROXY_MANAGER.get_proxy_pools())
+}
+
+#[utoipa::path(
+  get,
+  path = "/v1/proxy-pools/{id}",
+  params(("id" = String, Path, description = "Proxy pool ID")),
+  responses(
+    (status = 200, description = "Proxy pool details", body = crate::proxy_manager::ProxyPool),
+    (status = 401, description = "Unauthorized"),
+    (status = 404, description = "Proxy pool not found"),
+    (status = 500, description = "Internal server error")
+  ),
+  security(("bearer_auth" = [])),
+  tag = "proxies"
+)]
+async fn get_proxy_pool_api(
+  Path(id): Path<String>,
+  State(_state): State<ApiServerState>,
+) -> Result<Json<crate::proxy_manager::ProxyPool>, ApiError> {
+  PROXY_MANAGER.get_proxy_pool(&id).map(Json).ok_or_else(|| {
+    ApiError::not_found("PROXY_POOL_NOT_FOUND", format!("Proxy pool {id} not found"))
+  })
+}
+
+#[utoipa::path(
+  post,
+  path = "/v1/proxy-pools",
+  request_body = CreateProxyPoolRequest,
+  responses(
+    (status = 200, description = "Proxy pool created successfully", body = crate::proxy_manager::ProxyPool),
+    (status = 400, description = "Bad request"),
+    (status = 401, description = "Unauthorized"),
+    (status = 404, description = "A member proxy was not found"),
+    (status = 500, description = "Internal server error")
+  ),
+  security(("bearer_auth" = [])),
+  tag = "proxies"
+)]
+async fn create_proxy_pool_api(
+  State(_state): State<ApiServerState>,
+  Json(request): Json<CreateProxyPoolRequest>,
+) -> Result<Json<crate::proxy_manager::ProxyPool>, ApiError> {
+  PROXY_MANAGER
+    .create_proxy_pool(request.name, request.proxy_ids, request.policy)
+    .map(Json)
+    .map_err(ApiError::from_manager)
+}
+
+#[utoipa::path(
+  put,
+  path = "/v1/proxy-pools/{id}",
+  params(("id" = String, Path, description = "Proxy pool ID")),
+  request_body = UpdateProxyPoolRequest,
+  responses(
+    (status = 200, description = "Proxy pool updated; running browsers keep their current proxy", body = crate::proxy_manager::ProxyPool),
+    (status = 400, description = "Bad request"),
+    (status = 401, description = "Unauthorized"),
+    (status = 404, description = "Proxy pool or a member proxy not found"),
+    (status = 500, description = "Internal server error")
+  ),
+  security(("bearer_auth" = [])),
+  tag = "proxies"
+)]
+async fn update_proxy_pool_api(
+  Path(id): Path<String>,
+  State(_state): State<ApiServerState>,
+  Json(request): Json<UpdateProxyPoolRequest>,
+) -> Result<Json<crate::proxy_manager::ProxyPool>, ApiError> {
+  PROXY_MANAGER
+    .update_proxy_pool(&id, request.name, request.proxy_ids, request.policy)
+    .map(Json)
+    .map_err(ApiError::from_manager)
+}
+
+#[utoipa::path(
+  delete,
+  path = "/v1/proxy-pools/{id}",
+  params(
+    ("id" = String, Path, description = "Proxy pool ID"),
+    ("force" = Option<bool>, Query, description = "Delete even if profiles use the pool; they are left without a proxy")
+  ),
+  responses(
+    (status = 204, description = "Proxy pool deleted successfully"),
+    (status = 409, description = "Proxy pool is assigned to profiles and force was not set"),
+    (status = 401, description = "Unauthorized"),
+    (status = 404, description = "Proxy pool not found"),
+    (status = 500, description = "Internal server error")
+  ),
+  security(("bearer_auth" = [])),
+  tag = "proxies"
+)]
+async fn delete_proxy_pool_api(
+  Path(id): Path<String>,
+  Query(query): Query<ForceQuery>,
+  State(state): State<ApiServerState>,
+) -> Result<StatusCode, ApiError> {
+  crate::entity_usage::delete_proxy_pool(&state.app_handle, &id, query.force)
+    .map(|_| StatusCode::NO_CONTENT)
+    .map_err(ApiError::from_manager)
+}
+
 // API Handlers - VPNs
 
 fn vpn_to_api_response(c: &crate::vpn::VpnConfig) -> ApiVpnResponse {
@@ -1726,13 +2876,9 @@ fn parse_vpn_type(s: &str) -> Option<crate::vpn::VpnType> {
 )]
 async fn get_vpns(
   State(_state): State<ApiServerState>,
-) -> Result<Json<Vec<ApiVpnResponse>>, StatusCode> {
-  let storage = crate::vpn::VPN_STORAGE
-    .lock()
-    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
-  let configs = storage
-    .list_configs()
-    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+) -> Result<Json<Vec<ApiVpnResponse>>, ApiError> {
+  let storage = crate::vpn::VPN_STORAGE.lock().map_err(ApiError::internal)?;
+  let configs = storage.list_configs().map_err(ApiError::internal)?;
   Ok(Json(configs.iter().map(vpn_to_api_response).collect()))
 }
 
@@ -1752,18 +2898,45 @@ async fn get_vpns(
 async fn get_vpn(
   Path(id): Path<String>,
   State(_state): State<ApiServerState>,
-) -> Result<Json<ApiVpnResponse>, StatusCode> {
-  let storage = crate::vpn::VPN_STORAGE
-    .lock()
-    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
-  let configs = storage
-    .list_configs()
-    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+) -> Result<Json<ApiVpnResponse>, ApiError> {
+  let storage = crate::vpn::VPN_STORAGE.lock().map_err(ApiError::internal)?;
+  let configs = storage.list_configs().map_err(ApiError::internal)?;
   configs
     .iter()
     .find(|c| c.id == id)
     .map(|c| Json(vpn_to_api_response(c)))
-    .ok_or(StatusCode::NOT_FOUND)
+    .ok_or_else(|| ApiError::not_found("VPN_NOT_FOUND", format!("VPN {id} not found")))
+}
+
+#[utoipa::path(
+  get,
+  path = "/v1/vpns/{id}/status",
+  params(("id" = String, Path, description = "VPN configuration ID")),
+  responses(
+    (status = 200, description = "Connection status and tunnel counters", body = crate::vpn::VpnStatus),
+    (status = 401, description = "Unauthorized"),
+    (status = 404, description = "VPN configuration not found"),
+    (status = 500, description = "Internal server error")
+  ),
+  security(("bearer_auth" = [])),
+  tag = "vpns"
+)]
+async fn get_vpn_status_api(
+  Path(id): Path<String>,
+  State(_state): State<ApiServerState>,
+) -> Result<Json<crate::vpn::VpnStatus>, ApiError> {
+  let exists = crate::vpn::VPN_STORAGE
+    .lock()
+    .map_err(ApiError::internal)?
+    .load_config(&id)
+    .is_ok();
+  if !exists {
+    return Err(ApiError::not_found(
+      "VPN_NOT_FOUND",
+      format!("VPN {id} not found"),
+    ));
+  }
+  Ok(Json(crate::vpn_worker_runner::vpn_status(&id)))
 }
 
 #[utoipa::path(
@@ -1782,10 +2955,8 @@ async fn get_vpn(
 async fn export_vpn(
   Path(id): Path<String>,
   State(_state): State<ApiServerState>,
-) -> Result<Json<ApiVpnExportResponse>, StatusCode> {
-  let storage = crate::vpn::VPN_STORAGE
-    .lock()
-    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+) -> Result<Json<ApiVpnExportResponse>, ApiError> {
+  let storage = crate::vpn::VPN_STORAGE.lock().map_err(ApiError::internal)?;
   match storage.load_config(&id) {
     Ok(config) => Ok(Json(ApiVpnExportResponse {
       id: config.id,
@@ -1793,7 +2964,10 @@ async fn export_vpn(
       vpn_type: config.vpn_type.to_string(),
       config_data: config.config_data,
     })),
-    Err(_) => Err(StatusCode::NOT_FOUND),
+    Err(_) => Err(ApiError::not_found(
+      "VPN_NOT_FOUND",
+      format!("VPN {id} not found"),
+    )),
   }
 }
 
@@ -1813,19 +2987,17 @@ async fn export_vpn(
 async fn import_vpn(
   State(_state): State<ApiServerState>,
   Json(request): Json<ImportVpnRequest>,
-) -> Result<Json<ApiVpnResponse>, StatusCode> {
+) -> Result<Json<ApiVpnResponse>, ApiError> {
   let result = {
-    let storage = crate::vpn::VPN_STORAGE
-      .lock()
-      .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    let storage = crate::vpn::VPN_STORAGE.lock().map_err(ApiError::internal)?;
     storage.import_config(&request.content, &request.filename, request.name)
   };
   match result {
     Ok(config) => {
-      let _ = events::emit("vpn-configs-changed", ());
+      let _ = events::emit_empty("vpn-configs-changed");
       Ok(Json(vpn_to_api_response(&config)))
     }
-    Err(_) => Err(StatusCode::BAD_REQUEST),
+    Err(e) => Err(ApiError::bad_request("INVALID_VPN_CONFIG", e.to_string())),
   }
 }
 
@@ -1845,20 +3017,23 @@ async fn import_vpn(
 async fn create_vpn(
   State(_state): State<ApiServerState>,
   Json(request): Json<CreateVpnRequest>,
-) -> Result<Json<ApiVpnResponse>, StatusCode> {
-  let vpn_type = parse_vpn_type(&request.vpn_type).ok_or(StatusCode::BAD_REQUEST)?;
+) -> Result<Json<ApiVpnResponse>, ApiError> {
+  let vpn_type = parse_vpn_type(&request.vpn_type).ok_or_else(|| {
+    ApiError::bad_request(
+      "INVALID_VPN_TYPE",
+      format!("Unknown VPN type \"{}\"", request.vpn_type),
+    )
+  })?;
   let result = {
-    let storage = crate::vpn::VPN_STORAGE
-      .lock()
-      .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    let storage = crate::vpn::VPN_STORAGE.lock().map_err(ApiError::internal)?;
     storage.create_config_manual(&request.name, vpn_type, &request.config_data)
   };
   match result {
     Ok(config) => {
-      let _ = events::emit("vpn-configs-changed", ());
+      let _ = events::emit_empty("vpn-configs-changed");
       Ok(Json(vpn_to_api_response(&config)))
     }
-    Err(_) => Err(StatusCode::BAD_REQUEST),
+    Err(e) => Err(ApiError::bad_request("INVALID_VPN_CONFIG", e.to_string())),
   }
 }
 
@@ -1881,19 +3056,20 @@ async fn update_vpn(
   Path(id): Path<String>,
   State(_state): State<ApiServerState>,
   Json(request): Json<UpdateVpnRequest>,
-) -> Result<Json<ApiVpnResponse>, StatusCode> {
+) -> Result<Json<ApiVpnResponse>, ApiError> {
   let result = {
-    let storage = crate::vpn::VPN_STORAGE
-      .lock()
-      .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    let storage = crate::vpn::VPN_STORAGE.lock().map_err(ApiError::internal)?;
     storage.update_config_name(&id, &request.name)
   };
   match result {
     Ok(config) => {
-      let _ = events::emit("vpn-configs-changed", ());
+      let _ = events::emit_empty("vpn-configs-changed");
       Ok(Json(vpn_to_api_response(&config)))
     }
-    Err(_) => Err(StatusCode::NOT_FOUND),
+    Err(_) => Err(ApiError::not_found(
+      "VPN_NOT_FOUND",
+      format!("VPN {id} not found"),
+    )),
   }
 }
 
@@ -1913,21 +3089,22 @@ async fn update_vpn(
 async fn delete_vpn(
   Path(id): Path<String>,
   State(_state): State<ApiServerState>,
-) -> Result<StatusCode, StatusCode> {
+) -> Result<StatusCode, ApiError> {
   let _ = crate::vpn_worker_runner::stop_vpn_worker_by_vpn_id(&id).await;
 
   let result = {
-    let storage = crate::vpn::VPN_STORAGE
-      .lock()
-      .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    let storage = crate::vpn::VPN_STORAGE.lock().map_err(ApiError::internal)?;
     storage.delete_config(&id)
   };
   match result {
     Ok(_) => {
-      let _ = events::emit("vpn-configs-changed", ());
+      let _ = events::emit_empty("vpn-configs-changed");
       Ok(StatusCode::NO_CONTENT)
     }
-    Err(_) => Err(StatusCode::NOT_FOUND),
+    Err(_) => Err(ApiError::not_found(
+      "VPN_NOT_FOUND",
+      format!("VPN {id} not found"),
+    )),
   }
 }
 
@@ -1945,12 +3122,9 @@ async fn delete_vpn(
 )]
 async fn get_extensions(
   State(_state): State<ApiServerState>,
-) -> Result<Json<Vec<crate::extension_manager::Extension>>, StatusCode> {
+) -> Result<Json<Vec<crate::extension_manager::Extension>>, ApiError> {
   let mgr = crate::extension_manager::EXTENSION_MANAGER.lock().unwrap();
-  mgr
-    .list_extensions()
-    .map(Json)
-    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)
+  mgr.list_extensions().map(Json).map_err(ApiError::internal)
 }
 
 #[utoipa::path(
@@ -1965,12 +3139,9 @@ async fn get_extensions(
 )]
 async fn get_extension_groups(
   State(_state): State<ApiServerState>,
-) -> Result<Json<Vec<crate::extension_manager::ExtensionGroup>>, StatusCode> {
+) -> Result<Json<Vec<crate::extension_manager::ExtensionGroup>>, ApiError> {
   let mgr = crate::extension_manager::EXTENSION_MANAGER.lock().unwrap();
-  mgr
-    .list_groups()
-    .map(Json)
-    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)
+  mgr.list_groups().map(Json).map_err(ApiError::internal)
 }
 
 #[utoipa::path(
@@ -1989,12 +3160,12 @@ async fn get_extension_groups(
 async fn delete_extension_api(
   Path(id): Path<String>,
   State(state): State<ApiServerState>,
-) -> Result<StatusCode, (StatusCode, String)> {
+) -> Result<StatusCode, ApiError> {
   let mgr = crate::extension_manager::EXTENSION_MANAGER.lock().unwrap();
   mgr
     .delete_extension(&state.app_handle, &id)
     .map(|_| StatusCode::NO_CONTENT)
-    .map_err(manager_error_response)
+    .map_err(ApiError::from_manager)
 }
 
 #[utoipa::path(
@@ -2013,12 +3184,12 @@ async fn delete_extension_api(
 async fn delete_extension_group_api(
   Path(id): Path<String>,
   State(state): State<ApiServerState>,
-) -> Result<StatusCode, (StatusCode, String)> {
+) -> Result<StatusCode, ApiError> {
   let mgr = crate::extension_manager::EXTENSION_MANAGER.lock().unwrap();
   mgr
     .delete_group(&state.app_handle, &id)
     .map(|_| StatusCode::NO_CONTENT)
-    .map_err(manager_error_response)
+    .map_err(ApiError::from_manager)
 }
 
 // API Handler - Run Profile with Remote Debugging
@@ -2031,12 +3202,14 @@ async fn delete_extension_group_api(
   request_body = RunProfileRequest,
   responses(
     (status = 200, description = "Profile launched successfully", body = RunProfileResponse),
-    (status = 400, description = "Cannot launch cross-OS profile"),
+    (status = 400, description = "Cannot launch cross-OS profile", body = ApiErrorBody),
     (status = 401, description = "Unauthorized"),
-    (status = 402, description = "Active paid plan with browser automation required"),
-    (status = 404, description = "Profile not found"),
-    (status = 409, description = "Profile is locked by another team member"),
-    (status = 500, description = "Internal server error")
+    (status = 402, description = "Active paid plan with browser automation required", body = ApiErrorBody),
+    (status = 404, description = "Profile not found", body = ApiErrorBody),
+    (status = 409, description = "Profile is already running or locked by another team member", body = ApiErrorBody),
+    (status = 424, description = "The profile's browser version is not downloaded; `details` names it", body = ApiErrorBody),
+    (status = 503, description = "The local proxy, its upstream or the VPN could not be started", body = ApiErrorBody),
+    (status = 500, description = "Internal server error", body = ApiErrorBody)
   ),
   security(
     ("bearer_auth" = [])
@@ -2047,12 +3220,12 @@ async fn run_profile(
   Path(id): Path<String>,
   State(state): State<ApiServerState>,
   Json(request): Json<RunProfileRequest>,
-) -> Result<Json<RunProfileResponse>, StatusCode> {
+) -> Result<Json<RunProfileResponse>, ApiError> {
   if !crate::cloud_auth::CLOUD_AUTH
     .can_use_browser_automation()
     .await
   {
-    return Err(StatusCode::PAYMENT_REQUIRED);
+    return Err(ApiError::automation_required());
   }
 
   let headless = request.headless.unwrap_or(false);
@@ -2061,52 +3234,50 @@ async fn run_profile(
   let profile_manager = ProfileManager::instance();
   let profiles = profile_manager
     .list_profiles()
-    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    .map_err(ApiError::internal)?;
 
   let profile = profiles
     .iter()
     .find(|p| p.id.to_string() == id)
-    .ok_or(StatusCode::NOT_FOUND)?;
+    .ok_or_else(|| ApiError::profile_not_found(&id))?;
 
-  if profile.is_cross_os() {
-    return Err(StatusCode::BAD_REQUEST);
-  }
+  let is_running = profile.process_id.is_some()
+    && crate::browser_runner::BrowserRunner::instance()
+      .check_browser_status(state.app_handle.clone(), profile)
+      .await
+      .unwrap_or(false);
+  let binary_present = crate::downloaded_browsers_registry::DownloadedBrowsersRegistry::instance()
+    .is_browser_downloaded(&profile.browser, &profile.version);
+  launch_precondition(profile, is_running, binary_present)?;
 
   // Team lock check
   crate::team_lock::acquire_team_lock_if_needed(profile)
     .await
-    .map_err(|_| StatusCode::CONFLICT)?;
-
-  let remote_debugging_port = {
-    let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
-      .await
-      .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
-    let port = listener
-      .local_addr()
-      .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
-      .port();
-    drop(listener);
-    port
-  };
+    .map_err(|e| ApiError::conflict("PROFILE_LOCKED", e))?;
 
-  // Use the same launch path as the main app, but force a fresh instance with
-  // remote debugging enabled so the returned port is the one the browser binds.
+  // Use the same launch path as the main app, but force a fresh instance.
+  // The port is allocated by the launcher (which keeps concurrent launches
+  // from sharing one) and read back from the launched profile.
   match crate::browser_runner::launch_browser_profile_impl(
     state.app_handle.clone(),
     profile.clone(),
     url,
-    Some(remote_debugging_port),
+    request.debug_port.filter(|p| *p != 0),
     headless,
     true,
+    crate::launch_queue::LaunchPriority::Background,
   )
   .await
   {
     Ok(updated_profile) => Ok(Json(RunProfileResponse {
       profile_id: updated_profile.id.to_string(),
-      remote_debugging_port,
+      remote_debugging_port: updated_profile
+        .cdp_port
+        .ok_or_else(|| ApiError::internal("Browser started without a debugging port"))?,
       headless,
+      resource_limits: updated_profile.applied_resource_limits,
     })),
-    Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
+    Err(e) => Err(ApiError::from_manager(e)),
   }
 }
 
@@ -2135,12 +3306,12 @@ async fn open_url_in_profile(
   Path(id): Path<String>,
   State(state): State<ApiServerState>,
   Json(request): Json<OpenUrlRequest>,
-) -> Result<StatusCode, (StatusCode, String)> {
+) -> Result<StatusCode, ApiError> {
   if !crate::cloud_auth::CLOUD_AUTH
     .can_use_browser_automation()
     .await
   {
-    return Err((StatusCode::PAYMENT_REQUIRED, String::new()));
+    return Err(ApiError::automation_required());
   }
 
   let browser_runner = crate::browser_runner::BrowserRunner::instance();
@@ -2148,7 +3319,7 @@ async fn open_url_in_profile(
   browser_runner
     .open_url_with_profile(state.app_handle.clone(), id, request.url)
     .await
-    .map_err(manager_error_response)?;
+    .map_err(ApiError::from_manager)?;
 
   Ok(StatusCode::OK)
 }
@@ -2175,37 +3346,491 @@ async fn open_url_in_profile(
 async fn kill_profile(
   Path(id): Path<String>,
   State(state): State<ApiServerState>,
-) -> Result<StatusCode, StatusCode> {
+) -> Result<StatusCode, ApiError> {
   // Programmatically launching and stopping profiles is a paid feature; the
   // run/open-url handlers gate the same way.
   if !crate::cloud_auth::CLOUD_AUTH
     .can_use_browser_automation()
     .await
   {
-    return Err(StatusCode::PAYMENT_REQUIRED);
+    return Err(ApiError::automation_required());
   }
 
   let profile_manager = ProfileManager::instance();
   let profiles = profile_manager
     .list_profiles()
-    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    .map_err(ApiError::internal)?;
 
   let profile = profiles
     .iter()
     .find(|p| p.id.to_string() == id)
-    .ok_or(StatusCode::NOT_FOUND)?;
+    .ok_or_else(|| ApiError::profile_not_found(&id))?;
 
   let browser_runner = crate::browser_runner::BrowserRunner::instance();
   browser_runner
     .kill_browser_process(state.app_handle.clone(), profile)
     .await
-    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    .map_err(ApiError::internal)?;
 
   crate::team_lock::release_team_lock_if_needed(profile).await;
 
   Ok(StatusCode::NO_CONTENT)
 }
 
+// API Handler - CDP endpoint of a running profile
+#[utoipa::path(
+  get,
+  path = "/v1/profiles/{id}/cdp",
+  params(
+    ("id" = String, Path, description = "Profile ID")
+  ),
+  responses(
+    (status = 200, description = "CDP endpoint of the running profile", body = crate::wayfern_manager::CdpEndpoint),
+    (status = 401, description = "Unauthorized"),
+    (status = 402, description = "Active paid plan with browser automation required"),
+    (status = 404, description = "Profile not found"),
+    (status = 409, description = "Profile is not running"),
+    (status = 503, description = "Browser is running but its debugging endpoint is unreachable")
+  ),
+  security(
+    ("bearer_auth" = [])
+  ),
+  tag = "profiles"
+)]
+async fn get_profile_cdp_endpoint(
+  Path(id): Path<String>,
+) -> Result<Json<crate::wayfern_manager::CdpEndpoint>, ApiError> {
+  if !crate::cloud_auth::CLOUD_AUTH
+    .can_use_browser_automation()
+    .await
+  {
+    return Err(ApiError::automation_required());
+  }
+
+  crate::browser_runner::get_profile_cdp_endpoint_impl(&id)
+    .await
+    .map(Json)
+    .map_err(ApiError::from_manager)
+}
+
+// API Handler - Launch pre-flight checks
+#[utoipa::path(
+  get,
+  path = "/v1/profiles/{id}/preflight",
+  params(
+    ("id" = String, Path, description = "Profile ID")
+  ),
+  responses(
+    (status = 200, description = "Result of every pre-flight check; nothing is launched", body = Vec<crate::preflight::PreflightCheck>),
+    (status = 401, description = "Unauthorized"),
+    (status = 404, description = "Profile not found")
+  ),
+  security(
+    ("bearer_auth" = [])
+  ),
+  tag = "profiles"
+)]
+async fn preflight_profile_launch(
+  Path(id): Path<String>,
+) -> Result<Json<Vec<crate::preflight::PreflightCheck>>, ApiError> {
+  crate::preflight::preflight_profile_launch_impl(&id)
+    .await
+    .map(Json)
+    .map_err(ApiError::from_manager)
+}
+
+lazy_static! {
+  /// One lock per profile ID, so concurrent session requests for a profile
+  /// wait for the first one's launch and get its endpoint back.
+  static ref AUTOMATION_SESSION_LOCKS: std::sync::Mutex<HashMap<String, Arc<Mutex<()>>>> =
+    std::sync::Mutex::new(HashMap::new());
+}
+
+fn automation_session_lock(profile_id: &str) -> Arc<Mutex<()>> {
+  let mut locks = AUTOMATION_SESSION_LOCKS
+    .lock()
+    .unwrap_or_else(|e| e.into_inner());
+  locks.entry(profile_id.to_string()).or_default().clone()
+}
+
+// API Handler - Automation session: launch if needed, return the endpoint
+#[utoipa::path(
+  post,
+  path = "/v1/profiles/{id}/automation-session",
+  params(
+    ("id" = String, Path, description = "Profile ID")
+  ),
+  request_body(content = AutomationSessionRequest, description = "Optional; launch options used only if the profile is not running"),
+  responses(
+    (status = 200, description = "Where to attach to the running profile", body = AutomationSession),
+    (status = 400, description = "Cannot launch cross-OS profile", body = ApiErrorBody),
+    (status = 401, description = "Unauthorized"),
+    (status = 402, description = "Active paid plan with browser automation required", body = ApiErrorBody),
+    (status = 404, description = "Profile not found", body = ApiErrorBody),
+    (status = 409, description = "Profile is locked by another team member", body = ApiErrorBody),
+    (status = 424, description = "The profile's browser version is not downloaded; `details` names it", body = ApiErrorBody),
+    (status = 503, description = "The browser's debugging endpoint, the local proxy or the VPN is unavailable", body = ApiErrorBody),
+    (status = 500, description = "Internal server error", body = ApiErrorBody)
+  ),
+  security(
+    ("bearer_auth" = [])
+  ),
+  tag = "profiles"
+)]
+async fn open_automation_session(
+  Path(id): Path<String>,
+  State(state): State<ApiServerState>,
+  request: Option<Json<AutomationSessionRequest>>,
+) -> Result<Json<AutomationSession>, ApiError> {
+  if !crate::cloud_auth::CLOUD_AUTH
+    .can_use_browser_automation()
+    .await
+  {
+    return Err(ApiError::automation_required());
+  }
+  let request = request.map(|Json(r)| r).unwrap_or_default();
+
+  let session_lock = automation_session_lock(&id);
+  let _session_guard = session_lock.lock().await;
+
+  let profile = ProfileManager::instance()
+    .list_profiles()
+    .map_err(ApiError::internal)?
+    .into_iter()
+    .find(|p| p.id.to_string() == id)
+    .ok_or_else(|| ApiError::profile_not_found(&id))?;
+
+  let is_running = profile.process_id.is_some()
+    && crate::browser_runner::BrowserRunner::instance()
+      .check_browser_status(state.app_handle.clone(), &profile)
+      .await
+      .unwrap_or(false);
+  if !is_running {
+    let binary_present =
+      crate::downloaded_browsers_registry::DownloadedBrowsersRegistry::instance()
+        .is_browser_downloaded(&profile.browser, &profile.version);
+    launch_precondition(&profile, false, binary_present)?;
+    crate::team_lock::acquire_team_lock_if_needed(&profile)
+      .await
+      .map_err(|e| ApiError::conflict("PROFILE_LOCKED", e))?;
+    crate::browser_runner::launch_browser_profile_impl(
+      state.app_handle.clone(),
+      profile.clone(),
+      request.url,
+      None,
+      request.headless.unwrap_or(false),
+      true,
+      crate::launch_queue::LaunchPriority::Background,
+    )
+    .await
+    .map_err(ApiError::from_manager)?;
+  }
+
+  let cdp = crate::browser_runner::get_profile_cdp_endpoint_impl(&id)
+    .await
+    .map_err(ApiError::from_manager)?;
+  Ok(Json(AutomationSession {
+    kind: AutomationSessionKind::Cdp,
+    endpoint: format!("http://127.0.0.1:{}", cdp.port),
+    ws_url: cdp.ws_url,
+    browser: profile.browser,
+    version: profile.version,
+    user_agent: cdp.user_agent,
+    launched: !is_running,
+  }))
+}
+
+// API Handler - End an automation session, optionally stopping the browser
+#[utoipa::path(
+  delete,
+  path = "/v1/profiles/{id}/automation-session",
+  params(
+    ("id" = String, Path, description = "Profile ID"),
+    ("kill" = Option<bool>, Query, description = "Also stop the browser")
+  ),
+  responses(
+    (status = 204, description = "Session closed"),
+    (status = 401, description = "Unauthorized"),
+    (status = 402, description = "Active paid plan with browser automation required"),
+    (status = 404, description = "Profile not found"),
+    (status = 500, description = "Internal server error")
+  ),
+  security(
+    ("bearer_auth" = [])
+  ),
+  tag = "profiles"
+)]
+async fn close_automation_session(
+  Path(id): Path<String>,
+  Query(query): Query<CloseAutomationSessionQuery>,
+  State(state): State<ApiServerState>,
+) -> Result<StatusCode, ApiError> {
+  if !crate::cloud_auth::CLOUD_AUTH
+    .can_use_browser_automation()
+    .await
+  {
+    return Err(ApiError::automation_required());
+  }
+
+  let session_lock = automation_session_lock(&id);
+  let _session_guard = session_lock.lock().await;
+
+  let profile = ProfileManager::instance()
+    .list_profiles()
+    .map_err(ApiError::internal)?
+    .into_iter()
+    .find(|p| p.id.to_string() == id)
+    .ok_or_else(|| ApiError::profile_not_found(&id))?;
+
+  if query.kill && profile.process_id.is_some() {
+    crate::browser_runner::BrowserRunner::instance()
+      .kill_browser_process(state.app_handle.clone(), &profile)
+      .await
+      .map_err(ApiError::internal)?;
+    crate::team_lock::release_team_lock_if_needed(&profile).await;
+  }
+
+  Ok(StatusCode::NO_CONTENT)
+}
+
+// API Handler - What a sync of the profile would transfer, without syncing
+#[utoipa::path(
+  get,
+  path = "/v1/profiles/{id}/sync/preview",
+  params(
+    ("id" = String, Path, description = "Profile ID")
+  ),
+  responses(
+    (status = 200, description = "Files and bytes the next sync would transfer", body = crate::sync::SyncPreview),
+    (status = 400, description = "Sync is not configured"),
+    (status = 401, description = "Unauthorized"),
+    (status = 404, description = "Profile not found"),
+    (status = 503, description = "Sync server did not answer in time")
+  ),
+  security(
+    ("bearer_auth" = [])
+  ),
+  tag = "profiles"
+)]
+async fn preview_profile_sync_api(
+  Path(id): Path<String>,
+  State(state): State<ApiServerState>,
+) -> Result<Json<crate::sync::SyncPreview>, ApiError> {
+  crate::sync::preview_profile_sync_impl(&state.app_handle, &id)
+    .await
+    .map(Json)
+    .map_err(ApiError::from_manager)
+}
+
+// API Handler - Set, merge, or delete profile metadata keys. Kept separate
+// from `PUT /v1/profiles/{id}` so scripts can touch single keys without
+// racing each other over the whole map.
+#[utoipa::path(
+  patch,
+  path = "/v1/profiles/{id}/metadata",
+  params(
+    ("id" = String, Path, description = "Profile ID")
+  ),
+  request_body = crate::profile::metadata::MetadataUpdate,
+  responses(
+    (status = 200, description = "Metadata updated", body = ApiProfileResponse),
+    (status = 400, description = "Invalid key, value too long, or metadata too large"),
+    (status = 401, description = "Unauthorized"),
+    (status = 404, description = "Profile not found"),
+    (status = 500, description = "Internal server error")
+  ),
+  security(
+    ("bearer_auth" = [])
+  ),
+  tag = "profiles"
+)]
+async fn update_profile_metadata_api(
+  Path(id): Path<String>,
+  State(state): State<ApiServerState>,
+  Json(update): Json<crate::profile::metadata::MetadataUpdate>,
+) -> Result<Json<ApiProfileResponse>, ApiError> {
+  ProfileManager::instance()
+    .update_profile_metadata(&id, update)
+    .map_err(ApiError::from_manager)?;
+
+  get_profile(Path(id), State(state)).await
+}
+
+/// Merge the request's constraints and pinned fingerprint into `config`.
+fn apply_fingerprint_update(
+  config: &mut crate::wayfern_manager::WayfernConfig,
+  request: &UpdateFingerprintRequest,
+) -> Result<(), ApiError> {
+  if request.regenerate && request.fingerprint.is_some() {
+    return Err(ApiError::bad_request(
+      "INVALID_REQUEST",
+      "Pass either fingerprint or regenerate, not both",
+    ));
+  }
+  if let Some(fingerprint) = &request.fingerprint {
+    crate::fingerprint_quality::check_structure(fingerprint)
+      .map_err(|e| ApiError::bad_request("INVALID_FINGERPRINT", e))?;
+    config.fingerprint = Some(fingerprint.to_string());
+    // Nothing is known about which route a supplied fingerprint's location
+    // was built for.
+    config.geo_proxy_signature = None;
+  }
+
+  if request.os.is_some() {
+    config.os = request.os.clone();
+  }
+  let screen = [
+    (&mut config.screen_min_width, request.screen_min_width),
+    (&mut config.screen_min_height, request.screen_min_height),
+    (&mut config.screen_max_width, request.screen_max_width),
+    (&mut config.screen_max_height, request.screen_max_height),
+  ];
+  for (field, value) in screen {
+    if value.is_some() {
+      *field = value;
+    }
+  }
+  if request.randomize_fingerprint_on_launch.is_some() {
+    config.randomize_fingerprint_on_launch = request.randomize_fingerprint_on_launch;
+  }
+
+  let min_max = [
+    (config.screen_min_width, config.screen_max_width),
+    (config.screen_min_height, config.screen_max_height),
+  ];
+  if min_max
+    .iter()
+    .any(|(min, max)| matches!((min, max), (Some(min), Some(max)) if min > max))
+  {
+    return Err(ApiError::bad_request(
+      "INVALID_SCREEN_CONSTRAINTS",
+      "Screen minimum is larger than the maximum",
+    ));
+  }
+  Ok(())
+}
+
+// API Handler - Change the fingerprint settings of a stopped Wayfern profile,
+// optionally regenerating its fingerprint for the new constraints.
+#[utoipa::path(
+  patch,
+  path = "/v1/profiles/{id}/fingerprint",
+  params(
+    ("id" = String, Path, description = "Profile ID")
+  ),
+  request_body = UpdateFingerprintRequest,
+  responses(
+    (status = 200, description = "Fingerprint settings updated", body = UpdateFingerprintResponse),
+    (status = 400, description = "Invalid fingerprint or constraints, or not a Wayfern profile"),
+    (status = 401, description = "Unauthorized"),
+    (status = 402, description = "Pinned fingerprints and OS spoofing require Pro"),
+    (status = 404, description = "Profile not found"),
+    (status = 409, description = "Profile is running"),
+    (status = 500, description = "Internal server error")
+  ),
+  security(
+    ("bearer_auth" = [])
+  ),
+  tag = "profiles"
+)]
+async fn update_profile_fingerprint_api(
+  Path(id): Path<String>,
+  State(state): State<ApiServerState>,
+  Json(request): Json<UpdateFingerprintRequest>,
+) -> Result<Json<UpdateFingerprintResponse>, ApiError> {
+  let profile_manager = ProfileManager::instance();
+  let profile = profile_manager
+    .list_profiles()
+    .map_err(ApiError::internal)?
+    .into_iter()
+    .find(|p| p.id.to_string() == id)
+    .ok_or_else(|| ApiError::profile_not_found(&id))?;
+  if profile.browser != "wayfern" {
+    return Err(ApiError::bad_request(
+      "UNSUPPORTED_BROWSER",
+      "Only Wayfern profiles have a fingerprint",
+    ));
+  }
+
+  // Same gates as the update_wayfern_config command.
+  if request.fingerprint.is_some()
+    && !crate::cloud_auth::CLOUD_AUTH
+      .can_use_cross_os_fingerprints()
+      .await
+  {
+    return Err(ApiError::payment_required(
+      "FINGERPRINT_REQUIRES_PRO",
+      "Pinning a fingerprint requires an active Pro subscription",
+    ));
+  }
+  if !crate::cloud_auth::CLOUD_AUTH
+    .is_fingerprint_os_allowed(request.os.as_deref())
+    .await
+  {
+    return Err(ApiError::payment_required(
+      "FINGERPRINT_OS_REQUIRES_PRO",
+      "Fingerprint OS spoofing requires an active Pro subscription",
+    ));
+  }
+
+  // Checked up front: regenerating takes several seconds.
+  let is_running = profile_manager
+    .check_browser_status(state.app_handle.clone(), &profile)
+    .await
+    .map_err(ApiError::internal)?;
+  if is_running {
+    return Err(ApiError::conflict(
+      "PROFILE_RUNNING",
+      "Stop the profile before changing its fingerprint",
+    ));
+  }
+
+  let mut config = profile.wayfern_config.clone().unwrap_or_default();
+  apply_fingerprint_update(&mut config, &request)?;
+
+  if request.regenerate {
+    // Generated through the profile's proxy so the location matches its exit.
+    let proxy = profile
+      .proxy_id
+      .as_ref()
+      .and_then(|proxy_id| PROXY_MANAGER.get_proxy_settings_by_id(proxy_id));
+    let mut generation = config.clone();
+    generation.fingerprint = None;
+    generation.proxy = proxy
+      .as_ref()
+      .map(crate::proxy_manager::ProxyManager::build_proxy_url);
+    let (fingerprint, geolocation_applied) = crate::wayfern_manager::WayfernManager::instance()
+      .generate_fingerprint_config(&state.app_handle, &profile, &generation)
+      .await
+      .map_err(ApiError::from_manager)?;
+    config.fingerprint = Some(fingerprint);
+    config.geo_proxy_signature = geolocation_applied.then(|| {
+      crate::wayfern_manager::WayfernManager::geo_signature(
+        proxy.as_ref(),
+        None,
+        config.geoip.as_ref(),
+      )
+    });
+  }
+
+  let os = config.os.clone();
+  let fingerprint = config
+    .fingerprint
+    .as_deref()
+    .and_then(|f| serde_json::from_str::<serde_json::Value>(f).ok())
+    .map(|f| crate::fingerprint_quality::summarize(os.as_deref(), &f));
+  profile_manager
+    .update_wayfern_config(state.app_handle.clone(), &id, config, false)
+    .await
+    .map_err(ApiError::from_manager)?;
+
+  let Json(ApiProfileResponse { profile }) = get_profile(Path(id), State(state)).await?;
+  Ok(Json(UpdateFingerprintResponse {
+    profile,
+    fingerprint,
+  }))
+}
+
 // API Handler - Batch run profiles (paid: browser automation). Mirrors the
 // single `/run` gate; never breaks the batch on a single profile's failure —
 // each profile gets its own result entry.
@@ -2227,19 +3852,19 @@ async fn kill_profile(
 async fn batch_run_profiles(
   State(state): State<ApiServerState>,
   Json(request): Json<BatchRunRequest>,
-) -> Result<Json<BatchRunResponse>, StatusCode> {
+) -> Result<Json<BatchRunResponse>, ApiError> {
   if !crate::cloud_auth::CLOUD_AUTH
     .can_use_browser_automation()
     .await
   {
-    return Err(StatusCode::PAYMENT_REQUIRED);
+    return Err(ApiError::automation_required());
   }
 
   let headless = request.headless.unwrap_or(false);
   let profile_manager = ProfileManager::instance();
   let profiles = profile_manager
     .list_profiles()
-    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    .map_err(ApiError::internal)?;
 
   let mut results = Vec::with_capacity(request.profile_ids.len());
   for profile_id in &request.profile_ids {
@@ -2266,34 +3891,21 @@ async fn batch_run_profiles(
       continue;
     }
 
-    let port = match tokio::net::TcpListener::bind("127.0.0.1:0").await {
-      Ok(listener) => match listener.local_addr() {
-        Ok(addr) => addr.port(),
-        Err(_) => {
-          results.push(fail("failed to allocate debugging port"));
-          continue;
-        }
-      },
-      Err(_) => {
-        results.push(fail("failed to allocate debugging port"));
-        continue;
-      }
-    };
-
     match crate::browser_runner::launch_browser_profile_impl(
       state.app_handle.clone(),
       profile.clone(),
       request.url.clone(),
-      Some(port),
+      None,
       headless,
       true,
+      crate::launch_queue::LaunchPriority::Background,
     )
     .await
     {
-      Ok(_) => results.push(BatchRunResult {
+      Ok(updated_profile) => results.push(BatchRunResult {
         profile_id: profile_id.clone(),
         ok: true,
-        remote_debugging_port: Some(port),
+        remote_debugging_port: updated_profile.cdp_port,
         error: None,
       }),
       Err(e) => results.push(fail(&format!("launch failed: {e}"))),
@@ -2322,18 +3934,18 @@ async fn batch_run_profiles(
 async fn batch_stop_profiles(
   State(state): State<ApiServerState>,
   Json(request): Json<BatchStopRequest>,
-) -> Result<Json<BatchStopResponse>, StatusCode> {
+) -> Result<Json<BatchStopResponse>, ApiError> {
   if !crate::cloud_auth::CLOUD_AUTH
     .can_use_browser_automation()
     .await
   {
-    return Err(StatusCode::PAYMENT_REQUIRED);
+    return Err(ApiError::automation_required());
   }
 
   let profile_manager = ProfileManager::instance();
   let profiles = profile_manager
     .list_profiles()
-    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    .map_err(ApiError::internal)?;
   let browser_runner = crate::browser_runner::BrowserRunner::instance();
 
   let mut results = Vec::with_capacity(request.profile_ids.len());
@@ -2392,13 +4004,13 @@ async fn batch_stop_profiles(
 async fn detect_import_profiles(
   Query(query): Query<DetectImportQuery>,
   State(_state): State<ApiServerState>,
-) -> Result<Json<DetectedProfilesResponse>, (StatusCode, String)> {
+) -> Result<Json<DetectedProfilesResponse>, ApiError> {
   let importer = crate::profile_importer::ProfileImporter::instance();
   let profiles = match query.folder.as_deref() {
     Some(folder) => importer.scan_folder(std::path::Path::new(folder)),
     None => importer.detect_existing_profiles(),
   }
-  .map_err(manager_error_response)?;
+  .map_err(ApiError::from_manager)?;
   let total = profiles.len();
   Ok(Json(DetectedProfilesResponse { profiles, total }))
 }
@@ -2426,14 +4038,14 @@ async fn detect_import_profiles(
 async fn import_profiles_api(
   State(state): State<ApiServerState>,
   Json(request): Json<ImportProfilesRequest>,
-) -> Result<Json<crate::profile_importer::ProfileImportBatchResult>, (StatusCode, String)> {
+) -> Result<Json<crate::profile_importer::ProfileImportBatchResult>, ApiError> {
   let wayfern_config: Option<crate::wayfern_manager::WayfernConfig> = request
     .wayfern_config
     .as_ref()
     .and_then(|config| serde_json::from_value(config.clone()).ok());
 
   // The Pro gate for fingerprint OS spoofing lives inside import_profiles, so
-  // every surface inherits it; manager_error_response maps the code to 402.
+  // every surface inherits it; ApiError::from_manager maps the code to 402.
   let importer = crate::profile_importer::ProfileImporter::instance();
   importer
     .import_profiles(
@@ -2445,7 +4057,7 @@ async fn import_profiles_api(
     )
     .await
     .map(Json)
-    .map_err(manager_error_response)
+    .map_err(ApiError::from_manager)
 }
 
 #[utoipa::path(
@@ -2472,14 +4084,14 @@ async fn import_profile_cookies(
   Path(id): Path<String>,
   State(state): State<ApiServerState>,
   Json(request): Json<ImportCookiesRequest>,
-) -> Result<Json<ImportCookiesResponse>, StatusCode> {
+) -> Result<Json<ImportCookiesResponse>, ApiError> {
   let profile_manager = ProfileManager::instance();
   let profiles = profile_manager
     .list_profiles()
-    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    .map_err(ApiError::internal)?;
 
   if !profiles.iter().any(|p| p.id.to_string() == id) {
-    return Err(StatusCode::NOT_FOUND);
+    return Err(ApiError::profile_not_found(&id));
   }
 
   match crate::cookie_manager::CookieManager::import_cookies(
@@ -2509,11 +4121,11 @@ async fn import_profile_cookies(
     Err(e) => {
       let msg = e.to_lowercase();
       if msg.contains("running") {
-        Err(StatusCode::CONFLICT)
+        Err(ApiError::conflict("PROFILE_RUNNING", e))
       } else if msg.contains("no valid cookies") || msg.contains("unsupported browser") {
-        Err(StatusCode::BAD_REQUEST)
+        Err(ApiError::bad_request("INVALID_COOKIES", e))
       } else {
-        Err(StatusCode::INTERNAL_SERVER_ERROR)
+        Err(ApiError::internal(e))
       }
     }
   }
@@ -2539,7 +4151,7 @@ async fn import_profile_cookies(
 async fn download_browser_api(
   State(state): State<ApiServerState>,
   Json(request): Json<DownloadBrowserRequest>,
-) -> Result<Json<DownloadBrowserResponse>, (StatusCode, String)> {
+) -> Result<Json<DownloadBrowserResponse>, ApiError> {
   match crate::downloader::download_browser(
     state.app_handle.clone(),
     request.browser.clone(),
@@ -2553,26 +4165,37 @@ async fn download_browser_api(
       version,
       status: "downloaded".to_string(),
     })),
-    Err(e) => {
-      if e.contains("already being downloaded") {
-        Err((StatusCode::CONFLICT, e))
-      } else {
-        Err(manager_error_response(e))
-      }
-    }
+    Err(e) => Err(ApiError::from_manager(e)),
   }
 }
 
-// API Handler - Get Browser Versions
+/// Versions of `browser`, newest first: cached-first like the GUI's version
+/// list unless `refresh` asks for an upstream fetch.
+async fn browser_version_list(browser: &str, refresh: bool) -> Result<Vec<String>, String> {
+  if refresh {
+    crate::browser_version_manager::BrowserVersionManager::instance()
+      .fetch_browser_versions_with_count(browser, false)
+      .await
+      .map(|result| result.versions)
+      .map_err(|e| format!("Failed to fetch browser versions: {e}"))
+  } else {
+    crate::browser_version_manager::fetch_browser_versions_with_count_cached_first(
+      browser.to_string(),
+    )
+    .await
+    .map(|result| result.versions)
+  }
+}
+
+// API Handler - List Browsers
 #[utoipa::path(
   get,
-  path = "/v1/browsers/{browser}/versions",
+  path = "/v1/browsers",
   params(
-    ("browser" = String, Path, description = "Browser name")
+    ("refresh" = Option<bool>, Query, description = "Fetch version lists upstream instead of serving the cache")
   ),
   responses(
-    (status = 200, description = "List of available browser versions", body = Vec<String>),
-    (status = 400, description = "Unsupported browser"),
+    (status = 200, description = "Browsers supported on this platform", body = Vec<ApiBrowser>),
     (status = 401, description = "Unauthorized"),
     (status = 500, description = "Internal server error")
   ),
@@ -2581,31 +4204,44 @@ async fn download_browser_api(
   ),
   tag = "browsers"
 )]
-async fn get_browser_versions(
-  Path(browser): Path<String>,
+async fn get_browsers(
+  Query(query): Query<BrowserVersionsQuery>,
   State(_state): State<ApiServerState>,
-) -> Result<Json<Vec<String>>, (StatusCode, String)> {
-  let version_manager = crate::browser_version_manager::BrowserVersionManager::instance();
-
-  match version_manager
-    .fetch_browser_versions_with_count(&browser, false)
-    .await
+) -> Result<Json<Vec<ApiBrowser>>, ApiError> {
+  let registry = crate::downloaded_browsers_registry::DownloadedBrowsersRegistry::instance();
+  let mut browsers = Vec::new();
+  for browser in
+    crate::browser_version_manager::BrowserVersionManager::instance().get_supported_browsers()
   {
-    Ok(result) => Ok(Json(result.versions)),
-    Err(e) => Err(manager_error_response(e)),
+    // A failed upstream fetch only costs the latest version, not the listing.
+    let latest_version = match browser_version_list(&browser, query.refresh).await {
+      Ok(versions) => versions.into_iter().next(),
+      Err(e) => {
+        log::warn!("[api] Failed to load versions for {browser}: {e}");
+        None
+      }
+    };
+    browsers.push(ApiBrowser {
+      downloaded_versions: registry.get_downloaded_versions(&browser),
+      latest_version,
+      browser,
+    });
   }
+  Ok(Json(browsers))
 }
 
-// API Handler - Check if Browser is Downloaded
+// API Handler - Get Browser Versions
 #[utoipa::path(
   get,
-  path = "/v1/browsers/{browser}/versions/{version}/downloaded",
+  path = "/v1/browsers/{browser}/versions",
   params(
     ("browser" = String, Path, description = "Browser name"),
-    ("version" = String, Path, description = "Browser version")
+    ("refresh" = Option<bool>, Query, description = "Fetch the version list upstream instead of serving the cache"),
+    ("channel" = Option<String>, Query, description = "Only versions of this release type: stable, beta or nightly")
   ),
   responses(
-    (status = 200, description = "Browser download status", body = bool),
+    (status = 200, description = "Available browser versions, newest first", body = Vec<ApiBrowserVersion>),
+    (status = 400, description = "Unsupported browser or unknown channel"),
     (status = 401, description = "Unauthorized"),
     (status = 500, description = "Internal server error")
   ),
@@ -2614,18 +4250,637 @@ async fn get_browser_versions(
   ),
   tag = "browsers"
 )]
-async fn check_browser_downloaded(
+async fn get_browser_versions(
+  Path(browser): Path<String>,
+  Query(query): Query<BrowserVersionsQuery>,
+  State(_state): State<ApiServerState>,
+) -> Result<Json<Vec<ApiBrowserVersion>>, ApiError> {
+  if let Some(channel) = query.channel.as_deref() {
+    if !matches!(channel, "stable" | "beta" | "nightly") {
+      return Err(ApiError::bad_request(
+        "INVALID_RELEASE_CHANNEL",
+        format!("Unknown release channel '{channel}'"),
+      ));
+    }
+  }
+
+  let versions = browser_version_list(&browser, query.refresh)
+    .await
+    .map_err(ApiError::from_manager)?;
+  let registry = crate::downloaded_browsers_registry::DownloadedBrowsersRegistry::instance();
+  Ok(Json(
+    versions
+      .into_iter()
+      .map(|version| ApiBrowserVersion {
+        release_type: crate::browser_version_manager::release_type(&version).to_string(),
+        downloaded: registry.is_browser_downloaded(&browser, &version),
+        downloading: crate::downloader::is_download_in_progress(&browser, &version),
+        version,
+      })
+      .filter(|v| {
+        query
+          .channel
+          .as_deref()
+          .is_none_or(|channel| v.release_type == channel)
+      })
+      .collect(),
+  ))
+}
+
+// API Handler - Start Browser Download
+/// Starts a download in the background and returns immediately. Progress is
+/// published as `download-progress` events on `/v1/events`, ending in a
+/// `completed` or `error` stage.
+#[utoipa::path(
+  post,
+  path = "/v1/browsers/{browser}/versions/{version}/download",
+  params(
+    ("browser" = String, Path, description = "Browser name"),
+    ("version" = String, Path, description = "Browser version")
+  ),
+  responses(
+    (status = 200, description = "Version is already downloaded", body = DownloadBrowserResponse),
+    (status = 202, description = "Download started", body = DownloadBrowserResponse),
+    (status = 400, description = "Unsupported browser"),
+    (status = 401, description = "Unauthorized"),
+    (status = 409, description = "This browser version is already being downloaded")
+  ),
+  security(
+    ("bearer_auth" = [])
+  ),
+  tag = "browsers"
+)]
+async fn start_browser_download(
+  Path((browser, version)): Path<(String, String)>,
+  State(state): State<ApiServerState>,
+) -> Result<(StatusCode, Json<DownloadBrowserResponse>), ApiError> {
+  let supported = crate::browser_version_manager::BrowserVersionManager::instance()
+    .is_browser_supported(&browser)
+    .map_err(|e| ApiError::bad_request("UNSUPPORTED_BROWSER", e.to_string()))?;
+  if !supported {
+    return Err(ApiError::bad_request(
+      "UNSUPPORTED_BROWSER",
+      format!("Browser '{browser}' is not supported on your platform"),
+    ));
+  }
+
+  if crate::downloaded_browsers_registry::is_browser_downloaded(browser.clone(), version.clone()) {
+    return Ok((
+      StatusCode::OK,
+      Json(DownloadBrowserResponse {
+        browser,
+        version,
+        status: "downloaded".to_string(),
+      }),
+    ));
+  }
+  if crate::downloader::is_download_in_progress(&browser, &version) {
+    return Err(ApiError::conflict(
+      "DOWNLOAD_IN_PROGRESS",
+      format!("Browser '{browser}' version '{version}' is already being downloaded"),
+    ));
+  }
+
+  let app_handle = state.app_handle.clone();
+  let (task_browser, task_version) = (browser.clone(), version.clone());
+  tauri::async_runtime::spawn(async move {
+    // Failures also reach subscribers as an `error` progress event.
+    if let Err(e) =
+      crate::downloader::download_browser(app_handle, task_browser.clone(), task_version.clone())
+        .await
+    {
+      log::error!("[api] Download of {task_browser} {task_version} failed: {e}");
+    }
+  });
+
+  Ok((
+    StatusCode::ACCEPTED,
+    Json(DownloadBrowserResponse {
+      browser,
+      version,
+      status: "downloading".to_string(),
+    }),
+  ))
+}
+
+// API Handler - Check if Browser is Downloaded
+#[utoipa::path(
+  get,
+  path = "/v1/browsers/{browser}/versions/{version}/downloaded",
+  params(
+    ("browser" = String, Path, description = "Browser name"),
+    ("version" = String, Path, description = "Browser version")
+  ),
+  responses(
+    (status = 200, description = "Browser download status", body = bool),
+    (status = 401, description = "Unauthorized"),
+    (status = 500, description = "Internal server error")
+  ),
+  security(
+    ("bearer_auth" = [])
+  ),
+  tag = "browsers"
+)]
+async fn check_browser_downloaded(
   Path((browser, version)): Path<(String, String)>,
   State(_state): State<ApiServerState>,
-) -> Result<Json<bool>, StatusCode> {
+) -> Result<Json<bool>, ApiError> {
   let is_downloaded = crate::downloaded_browsers_registry::is_browser_downloaded(browser, version);
   Ok(Json(is_downloaded))
 }
 
+// API Handler - List downloaded browsers
+#[utoipa::path(
+  get,
+  path = "/v1/browsers/downloaded",
+  responses(
+    (status = 200, description = "Installed browser versions with their size and usage", body = Vec<crate::binaries_usage::DownloadedBrowser>),
+    (status = 401, description = "Unauthorized"),
+    (status = 500, description = "Internal server error")
+  ),
+  security(
+    ("bearer_auth" = [])
+  ),
+  tag = "browsers"
+)]
+async fn get_downloaded_browsers(
+  State(_state): State<ApiServerState>,
+) -> Result<Json<Vec<crate::binaries_usage::DownloadedBrowser>>, ApiError> {
+  crate::binaries_usage::list_downloaded_browsers()
+    .await
+    .map(Json)
+    .map_err(ApiError::from_manager)
+}
+
+// API Handler - Delete a downloaded browser version
+#[utoipa::path(
+  delete,
+  path = "/v1/browsers/{browser}/versions/{version}",
+  params(
+    ("browser" = String, Path, description = "Browser name"),
+    ("version" = String, Path, description = "Browser version"),
+    ("force" = Option<bool>, Query, description = "Delete even if profiles use this version; they download it again on their next launch")
+  ),
+  responses(
+    (status = 204, description = "Version removed from disk"),
+    (status = 401, description = "Unauthorized"),
+    (status = 404, description = "Version is not downloaded"),
+    (status = 409, description = "A running profile uses this version, or profiles use it and force was not set"),
+    (status = 500, description = "Internal server error")
+  ),
+  security(
+    ("bearer_auth" = [])
+  ),
+  tag = "browsers"
+)]
+async fn delete_downloaded_browser_api(
+  Path((browser, version)): Path<(String, String)>,
+  Query(query): Query<ForceQuery>,
+  State(_state): State<ApiServerState>,
+) -> Result<StatusCode, ApiError> {
+  crate::binaries_usage::delete_downloaded_browser(browser, version, Some(query.force))
+    .await
+    .map(|_| StatusCode::NO_CONTENT)
+    .map_err(ApiError::from_manager)
+}
+
+// API Handler - Binaries disk usage
+#[utoipa::path(
+  get,
+  path = "/v1/browsers/disk-usage",
+  responses(
+    (status = 200, description = "Space taken by downloaded browsers, and what cleanup removed", body = crate::binaries_usage::BinariesDiskUsage),
+    (status = 401, description = "Unauthorized"),
+    (status = 500, description = "Internal server error")
+  ),
+  security(
+    ("bearer_auth" = [])
+  ),
+  tag = "browsers"
+)]
+async fn get_binaries_disk_usage_api(
+  State(_state): State<ApiServerState>,
+) -> Result<Json<crate::binaries_usage::BinariesDiskUsage>, ApiError> {
+  crate::binaries_usage::get_binaries_disk_usage()
+    .await
+    .map(Json)
+    .map_err(ApiError::from_manager)
+}
+
+// API Handler - Generate fingerprints
+/// Generates fingerprints without creating a profile, e.g. to pre-build a pool
+/// that is attached to profiles later via `wayfern_config.fingerprint`. The
+/// whole batch is sampled from one headless Wayfern instance.
+#[utoipa::path(
+  post,
+  path = "/v1/fingerprints/generate",
+  request_body = GenerateFingerprintsRequest,
+  responses(
+    (status = 200, description = "Generated fingerprints", body = GenerateFingerprintsResponse),
+    (status = 400, description = "Invalid config or count, or no downloaded Wayfern version"),
+    (status = 401, description = "Unauthorized"),
+    (status = 500, description = "Internal server error")
+  ),
+  security(
+    ("bearer_auth" = [])
+  ),
+  tag = "fingerprints"
+)]
+async fn generate_fingerprints_api(
+  State(state): State<ApiServerState>,
+  Json(request): Json<GenerateFingerprintsRequest>,
+) -> Result<Json<GenerateFingerprintsResponse>, ApiError> {
+  let count = request.count.unwrap_or(1);
+  if count == 0 || count > crate::wayfern_manager::MAX_GENERATED_FINGERPRINTS {
+    return Err(ApiError::bad_request(
+      "INVALID_COUNT",
+      format!(
+        "count must be between 1 and {}",
+        crate::wayfern_manager::MAX_GENERATED_FINGERPRINTS
+      ),
+    ));
+  }
+
+  let config: crate::wayfern_manager::WayfernConfig = match request.config {
+    Some(config) => serde_json::from_value(config)
+      .map_err(|e| ApiError::bad_request("INVALID_CONFIG", format!("Invalid config: {e}")))?,
+    None => Default::default(),
+  };
+
+  let version = match request.version.as_deref() {
+    Some(v) if !v.is_empty() && v != "latest" => v.to_string(),
+    _ => crate::downloaded_browsers_registry::DownloadedBrowsersRegistry::instance()
+      .get_latest_downloaded_version("wayfern")
+      .ok_or_else(|| {
+        ApiError::bad_request(
+          "NO_DOWNLOADED_VERSION",
+          "No downloaded version of \"wayfern\" is available. Download the browser in Donut Browser first — this endpoint does not download browsers.",
+        )
+      })?,
+  };
+
+  let fingerprints = crate::wayfern_manager::generate_fingerprints(
+    &state.app_handle,
+    Some(&version),
+    &config,
+    count,
+  )
+  .await
+  .map_err(ApiError::from_manager)?;
+  Ok(Json(GenerateFingerprintsResponse { fingerprints }))
+}
+
+// API Handler - Recent logs
+/// Recent application log records from an in-memory buffer, oldest first, as
+/// JSON lines. Only records logged since the app started are available.
+#[utoipa::path(
+  get,
+  path = "/v1/logs",
+  params(
+    ("level" = Option<String>, Query, description = "Minimum severity: error, warn, info, debug or trace"),
+    ("module" = Option<String>, Query, description = "Only records whose target starts with this prefix, e.g. donutbrowser_lib::sync"),
+    ("since" = Option<u64>, Query, description = "Only records at or after this epoch-milliseconds timestamp"),
+    ("limit" = Option<usize>, Query, description = "Most recent matching records to return (default 200, max 1000)")
+  ),
+  responses(
+    (status = 200, description = "One log record per line", body = [crate::log_buffer::LogRecord], content_type = "application/x-ndjson"),
+    (status = 400, description = "Invalid level"),
+    (status = 401, description = "Unauthorized")
+  ),
+  security(
+    ("bearer_auth" = [])
+  ),
+  tag = "logs"
+)]
+async fn get_logs(Query(query): Query<crate::log_buffer::LogQuery>) -> Result<Response, ApiError> {
+  let records =
+    crate::log_buffer::query(&query).map_err(|e| ApiError::bad_request("INVALID_QUERY", e))?;
+  let mut body = String::new();
+  for record in &records {
+    let line = serde_json::to_string(record).map_err(ApiError::internal)?;
+    body.push_str(&line);
+    body.push('\n');
+  }
+  Ok(([(header::CONTENT_TYPE, "application/x-ndjson")], body).into_response())
+}
+
+// API Handler - Audit log
+/// Audit records of mutating actions taken through the GUI, the API or MCP,
+/// newest first. Needs a token with the `admin` scope.
+#[utoipa::path(
+  get,
+  path = "/v1/audit-log",
+  params(
+    ("actor" = Option<String>, Query, description = "Only actions by this kind of actor: gui, api or mcp"),
+    ("entity_type" = Option<String>, Query, description = "Only actions on this kind of entity, e.g. profile or proxy"),
+    ("entity_id" = Option<String>, Query, description = "Only actions on this entity"),
+    ("since" = Option<u64>, Query, description = "Only records at or after this epoch-seconds timestamp"),
+    ("until" = Option<u64>, Query, description = "Only records at or before this epoch-seconds timestamp"),
+    ("limit" = Option<usize>, Query, description = "Most recent matching records to return (default 200, max 1000)")
+  ),
+  responses(
+    (status = 200, description = "Matching audit records", body = [crate::audit::AuditRecord]),
+    (status = 400, description = "Invalid actor"),
+    (status = 401, description = "Unauthorized"),
+    (status = 403, description = "Token lacks the admin scope")
+  ),
+  security(
+    ("bearer_auth" = [])
+  ),
+  tag = "audit"
+)]
+async fn get_audit_log_api(
+  Query(query): Query<crate::audit::AuditQuery>,
+) -> Result<Json<Vec<crate::audit::AuditRecord>>, ApiError> {
+  tokio::task::spawn_blocking(move || crate::audit::query(&query))
+    .await
+    .map_err(ApiError::internal)?
+    .map(Json)
+    .map_err(|e| ApiError::bad_request("INVALID_QUERY", e))
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+struct ApiHealthResponse {
+  status: String,
+  version: String,
+  /// Offline mode is on: background network work (update checks, sync) is
+  /// paused. Launches and other requests still work.
+  offline: bool,
+}
+
+// API Handler - Health
+#[utoipa::path(
+  get,
+  path = "/v1/health",
+  responses(
+    (status = 200, description = "Server status", body = ApiHealthResponse),
+    (status = 401, description = "Unauthorized")
+  ),
+  security(
+    ("bearer_auth" = [])
+  ),
+  tag = "health"
+)]
+async fn get_health() -> Json<ApiHealthResponse> {
+  Json(ApiHealthResponse {
+    status: "ok".to_string(),
+    version: env!("CARGO_PKG_VERSION").to_string(),
+    offline: crate::offline::is_offline(),
+  })
+}
+
+// API Handler - Event stream
+/// Streams app events over a WebSocket, one JSON text message per event:
+/// `{"event": "...", "payload": ...}`. Only the headless daemon has an event
+/// stream to offer; the desktop app delivers events to its own window.
+#[utoipa::path(
+  get,
+  path = "/v1/events",
+  responses(
+    (status = 101, description = "Switching to the WebSocket event stream"),
+    (status = 401, description = "Unauthorized"),
+    (status = 404, description = "Not running in daemon mode")
+  ),
+  security(
+    ("bearer_auth" = [])
+  ),
+  tag = "events"
+)]
+async fn stream_events(ws: WebSocketUpgrade) -> Response {
+  // Subscribe before upgrading so nothing emitted during the handshake is lost.
+  let Some(mut rx) = events::subscribe() else {
+    return ApiError::not_found(
+      "EVENT_STREAM_UNAVAILABLE",
+      "Event streaming is only available in daemon mode",
+    )
+    .into_response();
+  };
+
+  ws.on_upgrade(move |mut socket| async move {
+    loop {
+      tokio::select! {
+        received = rx.recv() => match received {
+          Ok(event) => {
+            let Ok(text) = serde_json::to_string(&event) else {
+              continue;
+            };
+            if socket.send(Message::Text(text.into())).await.is_err() {
+              break;
+            }
+          }
+          Err(broadcast::error::RecvError::Lagged(skipped)) => {
+            log::warn!("[api] Event stream client fell behind, skipped {skipped} events");
+          }
+          Err(broadcast::error::RecvError::Closed) => break,
+        },
+        incoming = socket.recv() => match incoming {
+          Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+          Some(Ok(_)) => {}
+        },
+      }
+    }
+  })
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
 
+  #[test]
+  fn token_bucket_allows_burst_then_refills() {
+    let start = Instant::now();
+    let mut bucket = TokenBucket::new(start, 3);
+    for _ in 0..3 {
+      assert!(bucket.try_take(start, 10, 3).is_ok());
+    }
+    let retry = bucket.try_take(start, 10, 3).unwrap_err();
+    assert!(retry <= Duration::from_millis(100));
+    let later = start + Duration::from_millis(250);
+    assert!(bucket.try_take(later, 10, 3).is_ok());
+    assert!(bucket.try_take(later, 10, 3).is_ok());
+    assert!(bucket.try_take(later, 10, 3).is_err());
+  }
+
+  #[test]
+  fn expensive_routes_are_launch_import_and_download_posts() {
+    assert!(is_expensive_route(&Method::POST, "/v1/profiles/abc/run"));
+    assert!(is_expensive_route(&Method::POST, "/v1/profiles/batch/run"));
+    assert!(is_expensive_route(&Method::POST, "/v1/browsers/download"));
+    assert!(is_expensive_route(
+      &Method::POST,
+      "/v1/browsers/wayfern/versions/1.0/download"
+    ));
+    assert!(!is_expensive_route(
+      &Method::GET,
+      "/v1/browsers/wayfern/versions"
+    ));
+    assert!(!is_expensive_route(&Method::GET, "/v1/profiles/abc/cdp"));
+    assert!(is_expensive_route(
+      &Method::POST,
+      "/v1/profiles/abc/automation-session"
+    ));
+    assert!(!is_expensive_route(&Method::POST, "/v1/profiles"));
+  }
+
+  #[test]
+  fn api_errors_map_to_status_codes_per_route() {
+    let mut profile = crate::profile::BrowserProfile {
+      browser: "wayfern".to_string(),
+      version: "140.0.7339.0".to_string(),
+      ..Default::default()
+    };
+    let missing_binary = launch_precondition(&profile, false, false).unwrap_err();
+    profile.process_id = Some(4242);
+    let already_running = launch_precondition(&profile, true, true).unwrap_err();
+    let coded = |code: &str| serde_json::json!({ "code": code }).to_string();
+
+    let cases = [
+      (
+        "GET /v1/profiles/{id}",
+        ApiError::profile_not_found("missing"),
+        StatusCode::NOT_FOUND,
+        "PROFILE_NOT_FOUND",
+      ),
+      (
+        "DELETE /v1/proxies/{id}",
+        ApiError::from_manager(coded("PROXY_NOT_FOUND")),
+        StatusCode::NOT_FOUND,
+        "PROXY_NOT_FOUND",
+      ),
+      (
+        "DELETE /v1/proxies/{id}",
+        ApiError::from_manager(coded("PROXY_IN_USE")),
+        StatusCode::CONFLICT,
+        "PROXY_IN_USE",
+      ),
+      (
+        "POST /v1/profiles/{id}/run",
+        already_running,
+        StatusCode::CONFLICT,
+        "PROFILE_ALREADY_RUNNING",
+      ),
+      (
+        "POST /v1/profiles/{id}/run",
+        ApiError::from_manager(coded("PROFILE_RUNNING_EXTERNALLY")),
+        StatusCode::CONFLICT,
+        "PROFILE_RUNNING_EXTERNALLY",
+      ),
+      (
+        "POST /v1/profiles/{id}/run",
+        missing_binary,
+        StatusCode::FAILED_DEPENDENCY,
+        "BROWSER_NOT_DOWNLOADED",
+      ),
+      (
+        "POST /v1/profiles/{id}/run",
+        ApiError::from_manager("Failed to start local proxy for Wayfern: bind failed"),
+        StatusCode::SERVICE_UNAVAILABLE,
+        "PROXY_UNAVAILABLE",
+      ),
+      (
+        "POST /v1/profiles/{id}/run",
+        ApiError::from_manager(
+          serde_json::json!({
+            "code": "VPN_CONNECT_FAILED",
+            "params": { "name": "wg", "error": "handshake timed out" }
+          })
+          .to_string(),
+        ),
+        StatusCode::SERVICE_UNAVAILABLE,
+        "PROXY_UNAVAILABLE",
+      ),
+      (
+        "GET /v1/profiles/{id}/cdp",
+        ApiError::from_manager(coded("CDP_UNAVAILABLE")),
+        StatusCode::SERVICE_UNAVAILABLE,
+        "CDP_UNAVAILABLE",
+      ),
+      (
+        "POST /v1/profiles/import",
+        ApiError::from_manager(coded("FINGERPRINT_REQUIRES_PRO")),
+        StatusCode::PAYMENT_REQUIRED,
+        "FINGERPRINT_REQUIRES_PRO",
+      ),
+      (
+        "POST /v1/groups",
+        ApiError::from_manager(coded("GROUP_ALREADY_EXISTS")),
+        StatusCode::BAD_REQUEST,
+        "GROUP_ALREADY_EXISTS",
+      ),
+      (
+        "GET /v1/profiles",
+        ApiError::from_manager("invalid type: map, expected a string"),
+        StatusCode::INTERNAL_SERVER_ERROR,
+        "INTERNAL_ERROR",
+      ),
+    ];
+
+    for (route, error, status, code) in cases {
+      assert_eq!(error.status(), status, "{route} -> {code}");
+      assert_eq!(error.body().code, code, "{route}");
+    }
+  }
+
+  #[test]
+  fn api_error_body_carries_code_message_and_details() {
+    let profile = crate::profile::BrowserProfile {
+      browser: "wayfern".to_string(),
+      version: "140.0.7339.0".to_string(),
+      ..Default::default()
+    };
+    let body = launch_precondition(&profile, false, false)
+      .unwrap_err()
+      .body();
+    assert_eq!(body.details.unwrap()["version"], "140.0.7339.0");
+
+    let body = ApiError::from_manager(
+      serde_json::json!({
+        "code": "VPN_CONNECT_FAILED",
+        "params": { "name": "wg", "error": "handshake timed out" }
+      })
+      .to_string(),
+    )
+    .body();
+    assert_eq!(body.message, "handshake timed out");
+    assert_eq!(body.details.unwrap()["name"], "wg");
+
+    assert_eq!(
+      ApiError::from_manager(serde_json::json!({ "code": "PROXY_IN_USE" }).to_string())
+        .body()
+        .message,
+      "Proxy in use"
+    );
+  }
+
+  #[tokio::test]
+  async fn unknown_profile_returns_typed_404() {
+    let dir = tempfile::TempDir::new().unwrap();
+    let _guard = crate::app_dirs::set_test_data_dir(dir.path().to_path_buf());
+
+    let error = get_profile(
+      Path("missing".to_string()),
+      State(ApiServerState {
+        app_handle: crate::app_handle::AppHandle::Headless,
+      }),
+    )
+    .await
+    .unwrap_err();
+    let response = error.into_response();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+      .await
+      .unwrap();
+    let body: ApiErrorBody = serde_json::from_slice(&bytes).unwrap();
+    assert_eq!(body.code, "PROFILE_NOT_FOUND");
+  }
+
+  #[test]
+  fn too_many_requests_sets_retry_after() {
+    let response = too_many_requests(Duration::from_millis(1500));
+    assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+    assert_eq!(response.headers()[header::RETRY_AFTER], "2");
+  }
+
   // Removing `browser` from UpdateProfileRequest, and rejecting invalid
   // `browser` values on create, must NOT make the API reject requests that
   // carry extra/unknown fields — old clients still send them. serde ignores
@@ -2672,6 +4927,72 @@ mod tests {
     assert!(!is_valid(""));
   }
 
+  #[test]
+  fn profile_list_query_parses_filters_and_repeated_tags() {
+    let query = parse_profile_list_query(Some(
+      "browser=wayfern&tag=clientA&tag=social&running=true&name=Shop&limit=20&offset=40&fields=name,tags",
+    ))
+    .unwrap();
+    assert_eq!(query.browser.as_deref(), Some("wayfern"));
+    assert_eq!(query.tags, vec!["clientA", "social"]);
+    assert_eq!(query.running, Some(true));
+    assert_eq!(query.name.as_deref(), Some("shop"));
+    assert_eq!(query.limit, Some(20));
+    assert_eq!(query.offset, 40);
+    assert_eq!(
+      query.fields,
+      Some(vec!["name".to_string(), "tags".to_string()])
+    );
+    assert_eq!(
+      parse_profile_list_query(None).unwrap(),
+      ProfileListQuery::default()
+    );
+  }
+
+  #[test]
+  fn profile_list_query_filters_by_metadata() {
+    let query =
+      parse_profile_list_query(Some("metadata.account_id=42&metadata.persona_name=Ann%20B"))
+        .unwrap();
+    assert_eq!(
+      query.metadata,
+      vec![
+        ("account_id".to_string(), "42".to_string()),
+        ("persona_name".to_string(), "Ann B".to_string())
+      ]
+    );
+
+    let mut profile = crate::profile::BrowserProfile::default();
+    profile
+      .metadata
+      .insert("account_id".to_string(), "42".to_string());
+    assert!(!query.matches(&profile));
+    profile
+      .metadata
+      .insert("persona_name".to_string(), "Ann B".to_string());
+    assert!(query.matches(&profile));
+
+    assert!(parse_profile_list_query(Some("metadata.bad%20key=1")).is_err());
+  }
+
+  #[test]
+  fn profile_list_query_rejects_invalid_values() {
+    for raw in [
+      "running=yes",
+      "limit=0",
+      "limit=abc",
+      "offset=-1",
+      "browser=netscape",
+      "fields=name,fingerprint",
+      "sort=name",
+    ] {
+      assert!(
+        parse_profile_list_query(Some(raw)).is_err(),
+        "{raw} should be rejected"
+      );
+    }
+  }
+
   fn schema_required(spec: &serde_json::Value, schema: &str) -> Vec<String> {
     spec["components"]["schemas"][schema]["required"]
       .as_array()
@@ -2717,7 +5038,7 @@ mod tests {
     }
 
     let import_item = schema_required(&spec, "ImportProfileItem");
-    for field in ["proxy_id", "vpn_id", "browser_type"] {
+    for field in ["proxy_id", "vpn_id", "browser_type", "categories"] {
       assert!(
         !import_item.iter().any(|f| f == field),
         "{field} must be optional on import items, required list: {import_item:?}"
@@ -2738,6 +5059,107 @@ mod tests {
     assert_eq!(parsed.items[0].browser_type, "chromium");
   }
 
+  #[test]
+  fn fingerprint_update_merges_and_validates() {
+    let mut config = crate::wayfern_manager::WayfernConfig {
+      os: Some("windows".to_string()),
+      screen_max_width: Some(1920),
+      geo_proxy_signature: Some("v2:old".to_string()),
+      ..Default::default()
+    };
+    let fingerprint = serde_json::json!({
+      "userAgent": "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7)",
+      "platform": "MacIntel",
+      "screenWidth": 1440,
+      "screenHeight": 900,
+    });
+    let request = UpdateFingerprintRequest {
+      os: Some("macos".to_string()),
+      screen_min_width: Some(1280),
+      fingerprint: Some(fingerprint.clone()),
+      ..Default::default()
+    };
+    apply_fingerprint_update(&mut config, &request).unwrap();
+    assert_eq!(config.os.as_deref(), Some("macos"));
+    assert_eq!(config.screen_min_width, Some(1280));
+    assert_eq!(config.screen_max_width, Some(1920));
+    assert_eq!(config.fingerprint, Some(fingerprint.to_string()));
+    assert!(config.geo_proxy_signature.is_none());
+
+    let broken = UpdateFingerprintRequest {
+      fingerprint: Some(serde_json::json!({ "userAgent": "x", "screenWidth": "wide" })),
+      ..Default::default()
+    };
+    assert!(matches!(
+      apply_fingerprint_update(&mut config, &broken),
+      Err(ApiError::BadRequest { code, .. }) if code == "INVALID_FINGERPRINT"
+    ));
+
+    let both = UpdateFingerprintRequest {
+      fingerprint: Some(fingerprint),
+      regenerate: true,
+      ..Default::default()
+    };
+    assert!(apply_fingerprint_update(&mut config, &both).is_err());
+
+    let inverted = UpdateFingerprintRequest {
+      screen_min_width: Some(2560),
+      ..Default::default()
+    };
+    assert!(matches!(
+      apply_fingerprint_update(&mut config, &inverted),
+      Err(ApiError::BadRequest { code, .. }) if code == "INVALID_SCREEN_CONSTRAINTS"
+    ));
+  }
+
+  #[test]
+  fn describe_request_names_action_and_entity() {
+    let cases = [
+      (
+        Method::POST,
+        "/v1/profiles/{id}/run",
+        "/v1/profiles/p1/run",
+        ("launch", "profile", Some("p1")),
+      ),
+      (
+        Method::DELETE,
+        "/v1/proxies/{id}",
+        "/v1/proxies/x",
+        ("delete", "proxy", Some("x")),
+      ),
+      (
+        Method::POST,
+        "/v1/profiles/batch/stop",
+        "/v1/profiles/batch/stop",
+        ("kill", "profile", None),
+      ),
+      (
+        Method::PUT,
+        "/v1/profiles/{id}/metadata",
+        "/v1/profiles/p2/metadata",
+        ("update_metadata", "profile", Some("p2")),
+      ),
+      (
+        Method::POST,
+        "/v1/proxy-pools",
+        "/v1/proxy-pools",
+        ("create", "proxy_pool", None),
+      ),
+    ];
+    for (method, route, path, (action, entity_type, entity_id)) in cases {
+      let described = describe_request(&method, route, path);
+      assert_eq!(
+        (
+          described.0.as_str(),
+          described.1.as_str(),
+          described.2.as_deref()
+        ),
+        (action, entity_type, entity_id),
+        "{method} {path}"
+      );
+    }
+  }
+
   // The served /openapi.json comes from the hand-maintained ApiDoc `paths(...)`
   // list, not from the router — endpoints registered on the router but missing
   // from ApiDoc silently disappear from the spec. Lock in the ones that were
@@ -2755,7 +5177,19 @@ mod tests {
       "/v1/extension-groups/{id}",
       "/v1/profiles/import",
       "/v1/profiles/import/detect",
+      "/v1/profiles/{id}/sync/preview",
       "/v1/proxies/import",
+      "/v1/proxy-pools",
+      "/v1/proxy-pools/{id}",
+      "/v1/profiles/{id}/cdp",
+      "/v1/profiles/{id}/preflight",
+      "/v1/profiles/{id}/automation-session",
+      "/v1/events",
+      "/v1/fingerprints/generate",
+      "/v1/profiles/{id}/fingerprint",
+      "/v1/logs",
+      "/v1/audit-log",
+      "/v1/health",
     ] {
       assert!(paths.contains_key(path), "missing from ApiDoc: {path}");
     }
@@ -0,0 +1,415 @@
+//! Named, scoped bearer tokens for the local REST API.
+//!
+//! Only SHA-256 hashes are persisted (vault-encrypted in the settings dir);
+//! the plaintext is returned once from `generate_api_token`. The single token
+//! shown in the Integrations dialog is kept as the full-scope `default` token.
+
+use crate::settings_manager::SettingsManager;
+use axum::http::Method;
+use lazy_static::lazy_static;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tokio::sync::Mutex;
+
+const TOKENS_FILE: &str = "api_tokens.dat";
+const TOKENS_HEADER: &[u8; 5] = b"DBATK";
+pub const DEFAULT_TOKEN_NAME: &str = "default";
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ApiScope {
+  #[serde(rename = "profiles:read")]
+  ProfilesRead,
+  #[serde(rename = "profiles:write")]
+  ProfilesWrite,
+  #[serde(rename = "profiles:launch")]
+  ProfilesLaunch,
+  #[serde(rename = "proxies:read")]
+  ProxiesRead,
+  #[serde(rename = "proxies:write")]
+  ProxiesWrite,
+  #[serde(rename = "settings:read")]
+  SettingsRead,
+  #[serde(rename = "settings:write")]
+  SettingsWrite,
+  /// Reading the audit log.
+  #[serde(rename = "admin")]
+  Admin,
+}
+
+pub const ALL_SCOPES: [ApiScope; 8] = [
+  ApiScope::ProfilesRead,
+  ApiScope::ProfilesWrite,
+  ApiScope::ProfilesLaunch,
+  ApiScope::ProxiesRead,
+  ApiScope::ProxiesWrite,
+  ApiScope::SettingsRead,
+  ApiScope::SettingsWrite,
+  ApiScope::Admin,
+];
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct StoredApiToken {
+  id: String,
+  name: String,
+  token_hash: String,
+  scopes: Vec<ApiScope>,
+  created_at: u64,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct ApiTokenInfo {
+  pub id: String,
+  pub name: String,
+  pub scopes: Vec<ApiScope>,
+  pub created_at: u64,
+}
+
+/// Returned once on creation; the plaintext token is not stored anywhere.
+#[derive(Debug, Serialize)]
+pub struct GeneratedApiToken {
+  pub token: String,
+  #[serde(flatten)]
+  pub info: ApiTokenInfo,
+}
+
+impl From<&StoredApiToken> for ApiTokenInfo {
+  fn from(token: &StoredApiToken) -> Self {
+    Self {
+      id: token.id.clone(),
+      name: token.name.clone(),
+      scopes: token.scopes.clone(),
+      created_at: token.created_at,
+    }
+  }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum TokenCheck {
+  Allowed { token_name: String },
+  MissingScope,
+  Unknown,
+}
+
+lazy_static! {
+  // Loaded lazily so the auth middleware doesn't pay the Argon2 vault
+  // decryption on every request.
+  static ref TOKENS: Mutex<Option<Vec<StoredApiToken>>> = Mutex::new(None);
+}
+
+fn hash_token(token: &str) -> String {
+  let digest = Sha256::digest(token.as_bytes());
+  let mut hex = String::with_capacity(digest.len() * 2);
+  for byte in digest {
+    use std::fmt::Write;
+    let _ = write!(hex, "{byte:02x}");
+  }
+  hex
+}
+
+fn random_token() -> String {
+  use base64::{engine::general_purpose, Engine as _};
+  use rand::Rng;
+  let mut bytes = [0u8; 32];
+  rand::rng().fill_bytes(&mut bytes);
+  general_purpose::URL_SAFE_NO_PAD.encode(bytes)
+}
+
+fn backend_error(code: &str, params: serde_json::Value) -> String {
+  serde_json::json!({ "code": code, "params": params }).to_string()
+}
+
+/// The scope a request needs. Routes that don't match a known prefix fall
+/// back to the settings scopes so new endpoints are never implicitly open to
+/// narrow tokens.
+pub fn required_scope(method: &Method, path: &str) -> ApiScope {
+  let segments: Vec<&str> = path
+    .trim_start_matches("/v1")
+    .split('/')
+    .filter(|s| !s.is_empty())
+    .collect();
+  let read = method == Method::GET;
+  match segments.as_slice() {
+    ["audit-log", ..] => ApiScope::Admin,
+    ["profiles", "import", "detect"] => ApiScope::ProfilesRead,
+    ["profiles", "batch", ..] => ApiScope::ProfilesLaunch,
+    ["profiles", _, "run" | "open-url" | "kill" | "cdp" | "automation-session"] => {
+      ApiScope::ProfilesLaunch
+    }
+    ["profiles" | "groups" | "tags", ..] if read => ApiScope::ProfilesRead,
+    ["profiles" | "groups" | "tags", ..] => ApiScope::ProfilesWrite,
+    // Generation only produces profile material; nothing is persisted.
+    ["fingerprints", ..] => ApiScope::ProfilesWrite,
+    ["proxies" | "proxy-pools" | "vpns", ..] if read => ApiScope::ProxiesRead,
+    ["proxies" | "proxy-pools" | "vpns", ..] => ApiScope::ProxiesWrite,
+    _ if read => ApiScope::SettingsRead,
+    _ => ApiScope::SettingsWrite,
+  }
+}
+
+fn default_record(token: &str) -> StoredApiToken {
+  StoredApiToken {
+    id: uuid::Uuid::new_v4().to_string(),
+    name: DEFAULT_TOKEN_NAME.to_string(),
+    token_hash: hash_token(token),
+    scopes: ALL_SCOPES.to_vec(),
+    created_at: crate::proxy_manager::now_secs(),
+  }
+}
+
+fn persist(tokens: &[StoredApiToken]) -> Result<(), String> {
+  let json = serde_json::to_vec(tokens).map_err(|e| format!("Failed to encode API tokens: {e}"))?;
+  SettingsManager::instance()
+    .write_vault_file(TOKENS_FILE, TOKENS_HEADER, &json)
+    .map_err(|e| format!("Failed to store API tokens: {e}"))
+}
+
+/// Loads the token store into `cache` on first use. Installs that predate
+/// scoped tokens have no store yet; their single API token becomes the
+/// full-scope `default` token.
+async fn ensure_loaded<'a>(
+  cache: &'a mut Option<Vec<StoredApiToken>>,
+  app_handle: &crate::app_handle::AppHandle,
+) -> Result<&'a mut Vec<StoredApiToken>, String> {
+  if cache.is_none() {
+    let manager = SettingsManager::instance();
+    let stored = manager
+      .read_vault_file(TOKENS_FILE, TOKENS_HEADER)
+      .map_err(|e| format!("Failed to read API tokens: {e}"))?;
+    let tokens = match stored {
+      Some(bytes) => {
+        let mut tokens = serde_json::from_slice::<Vec<StoredApiToken>>(&bytes)
+          .map_err(|e| format!("Failed to parse API tokens: {e}"))?;
+        // The default token carries every scope, including ones added after
+        // it was stored.
+        for token in tokens.iter_mut().filter(|t| t.name == DEFAULT_TOKEN_NAME) {
+          token.scopes = ALL_SCOPES.to_vec();
+        }
+        tokens
+      }
+      None => {
+        let legacy = manager.get_api_token(app_handle).await.ok().flatten();
+        let tokens: Vec<StoredApiToken> = legacy.iter().map(|t| default_record(t)).collect();
+        if !tokens.is_empty() {
+          log::info!("[api] Migrated legacy API token to the default scoped token");
+          persist(&tokens)?;
+        }
+        tokens
+      }
+    };
+    *cache = Some(tokens);
+  }
+  Ok(cache.as_mut().expect("token cache populated above"))
+}
+
+/// Checks a bearer token against the store. Hashes are compared in constant
+/// time so the check doesn't leak how much of a hash matched.
+pub async fn authorize(
+  app_handle: &crate::app_handle::AppHandle,
+  token: &str,
+  scope: ApiScope,
+) -> Result<TokenCheck, String> {
+  use subtle::ConstantTimeEq;
+  let mut cache = TOKENS.lock().await;
+  let tokens = ensure_loaded(&mut cache, app_handle).await?;
+  let hash = hash_token(token);
+  let found = tokens
+    .iter()
+    .find(|t| bool::from(t.token_hash.as_bytes().ct_eq(hash.as_bytes())));
+  Ok(match found {
+    Some(t) if t.scopes.contains(&scope) => TokenCheck::Allowed {
+      token_name: t.name.clone(),
+    },
+    Some(_) => TokenCheck::MissingScope,
+    None => TokenCheck::Unknown,
+  })
+}
+
+/// Keeps the `default` token in step with the token shown in the settings UI.
+/// `None` (API disabled) removes it.
+pub async fn sync_default_token(
+  app_handle: &crate::app_handle::AppHandle,
+  token: Option<&str>,
+) -> Result<(), String> {
+  let mut cache = TOKENS.lock().await;
+  let tokens = ensure_loaded(&mut cache, app_handle).await?;
+  let existing = tokens.iter().position(|t| t.name == DEFAULT_TOKEN_NAME);
+  match (token, existing) {
+    (Some(token), Some(index)) => {
+      if tokens[index].token_hash == hash_token(token) {
+        return Ok(());
+      }
+      tokens[index].token_hash = hash_token(token);
+    }
+    (Some(token), None) => tokens.insert(0, default_record(token)),
+    (None, Some(index)) => {
+      tokens.remove(index);
+    }
+    (None, None) => return Ok(()),
+  }
+  persist(tokens)
+}
+
+#[tauri::command]
+pub async fn list_api_tokens(
+  app_handle: crate::app_handle::AppHandle,
+) -> Result<Vec<ApiTokenInfo>, String> {
+  let mut cache = TOKENS.lock().await;
+  let tokens = ensure_loaded(&mut cache, &app_handle).await?;
+  Ok(tokens.iter().map(ApiTokenInfo::from).collect())
+}
+
+#[tauri::command]
+pub async fn generate_api_token(
+  app_handle: crate::app_handle::AppHandle,
+  name: String,
+  scopes: Vec<ApiScope>,
+) -> Result<GeneratedApiToken, String> {
+  let name = name.trim().to_string();
+  if name.is_empty() {
+    return Err(backend_error("NAME_CANNOT_BE_EMPTY", serde_json::json!({})));
+  }
+  if scopes.is_empty() {
+    return Err(backend_error(
+      "API_TOKEN_SCOPES_EMPTY",
+      serde_json::json!({}),
+    ));
+  }
+
+  let mut cache = TOKENS.lock().await;
+  let tokens = ensure_loaded(&mut cache, &app_handle).await?;
+  // `default` is reserved for the settings-managed token.
+  if name == DEFAULT_TOKEN_NAME || tokens.iter().any(|t| t.name == name) {
+    return Err(backend_error(
+      "API_TOKEN_NAME_TAKEN",
+      serde_json::json!({ "name": name }),
+    ));
+  }
+
+  let token = random_token();
+  let mut unique_scopes = Vec::with_capacity(scopes.len());
+  for scope in ALL_SCOPES {
+    if scopes.contains(&scope) {
+      unique_scopes.push(scope);
+    }
+  }
+  let record = StoredApiToken {
+    id: uuid::Uuid::new_v4().to_string(),
+    name,
+    token_hash: hash_token(&token),
+    scopes: unique_scopes,
+    created_at: crate::proxy_manager::now_secs(),
+  };
+  let info = ApiTokenInfo::from(&record);
+  tokens.push(record);
+  persist(tokens)?;
+  Ok(GeneratedApiToken { token, info })
+}
+
+#[tauri::command]
+pub async fn revoke_api_token(
+  app_handle: crate::app_handle::AppHandle,
+  token_id: String,
+) -> Result<(), String> {
+  let mut cache = TOKENS.lock().await;
+  let tokens = ensure_loaded(&mut cache, &app_handle).await?;
+  let index = tokens
+    .iter()
+    .position(|t| t.id == token_id)
+    .ok_or_else(|| backend_error("API_TOKEN_NOT_FOUND", serde_json::json!({ "id": token_id })))?;
+  let removed = tokens.remove(index);
+  persist(tokens)?;
+  drop(cache);
+
+  // The settings UI displays the default token; once revoked it must not keep
+  // showing a dead credential. Re-enabling the API issues a fresh one.
+  if removed.name == DEFAULT_TOKEN_NAME {
+    SettingsManager::instance()
+      .remove_api_token(&app_handle)
+      .await
+      .map_err(|e| format!("Failed to remove API token: {e}"))?;
+  }
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_required_scope_maps_routes() {
+    let cases = [
+      (Method::GET, "/v1/profiles", ApiScope::ProfilesRead),
+      (Method::GET, "/v1/profiles/abc", ApiScope::ProfilesRead),
+      (Method::GET, "/v1/tags", ApiScope::ProfilesRead),
+      (
+        Method::GET,
+        "/v1/profiles/import/detect",
+        ApiScope::ProfilesRead,
+      ),
+      (Method::POST, "/v1/profiles", ApiScope::ProfilesWrite),
+      (Method::PUT, "/v1/groups/abc", ApiScope::ProfilesWrite),
+      (
+        Method::POST,
+        "/v1/fingerprints/generate",
+        ApiScope::ProfilesWrite,
+      ),
+      (
+        Method::POST,
+        "/v1/profiles/abc/cookies/import",
+        ApiScope::ProfilesWrite,
+      ),
+      (
+        Method::POST,
+        "/v1/profiles/abc/run",
+        ApiScope::ProfilesLaunch,
+      ),
+      (
+        Method::GET,
+        "/v1/profiles/abc/cdp",
+        ApiScope::ProfilesLaunch,
+      ),
+      (
+        Method::DELETE,
+        "/v1/profiles/abc/automation-session",
+        ApiScope::ProfilesLaunch,
+      ),
+      (
+        Method::POST,
+        "/v1/profiles/batch/stop",
+        ApiScope::ProfilesLaunch,
+      ),
+      (Method::GET, "/v1/vpns/abc/export", ApiScope::ProxiesRead),
+      (Method::POST, "/v1/proxies/import", ApiScope::ProxiesWrite),
+      (Method::GET, "/v1/proxy-pools", ApiScope::ProxiesRead),
+      (Method::PUT, "/v1/proxy-pools/abc", ApiScope::ProxiesWrite),
+      (Method::GET, "/v1/logs", ApiScope::SettingsRead),
+      (Method::GET, "/v1/extensions", ApiScope::SettingsRead),
+      (Method::GET, "/v1/audit-log", ApiScope::Admin),
+      (Method::GET, "/v1/health", ApiScope::SettingsRead),
+      (
+        Method::POST,
+        "/v1/browsers/download",
+        ApiScope::SettingsWrite,
+      ),
+    ];
+    for (method, path, expected) in cases {
+      assert_eq!(required_scope(&method, path), expected, "{method} {path}");
+    }
+  }
+
+  #[test]
+  fn test_scopes_serialize_as_colon_strings() {
+    let json = serde_json::to_string(&[ApiScope::ProfilesLaunch, ApiScope::SettingsWrite]).unwrap();
+    assert_eq!(json, r#"["profiles:launch","settings:write"]"#);
+    let parsed: ApiScope = serde_json::from_str(r#""proxies:write""#).unwrap();
+    assert_eq!(parsed, ApiScope::ProxiesWrite);
+  }
+
+  #[test]
+  fn test_hash_token_is_stable_hex() {
+    let hash = hash_token("secret");
+    assert_eq!(hash.len(), 64);
+    assert_eq!(hash, hash_token("secret"));
+    assert_ne!(hash, hash_token("secret2"));
+  }
+}
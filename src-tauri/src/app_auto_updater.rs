@@ -782,28 +782,6 @@ impl AppAutoUpdater {
     })
   }
 
-  fn sha256_file(path: &Path) -> Result<String, Box<dyn std::error::Error + Send + Sync>> {
-    use sha2::{Digest, Sha256};
-    use std::io::Read;
-    let mut file = fs::File::open(path)?;
-    let mut hasher = Sha256::new();
-    let mut buf = vec![0u8; 1024 * 1024];
-    loop {
-      let n = file.read(&mut buf)?;
-      if n == 0 {
-        break;
-      }
-      hasher.update(&buf[..n]);
-    }
-    let digest = hasher.finalize();
-    let mut hex = String::with_capacity(digest.len() * 2);
-    for byte in digest {
-      use std::fmt::Write;
-      let _ = write!(hex, "{byte:02x}");
-    }
-    Ok(hex)
-  }
-
   /// Fetch the release's SHA256SUMS.txt and return the expected digest for
   /// `filename`. Called BEFORE the (large) asset download so an unverifiable
   /// release is rejected without wasting the transfer. Every failure mode
@@ -877,7 +855,7 @@ impl AppAutoUpdater {
     expected: &str,
     asset_digest: Option<&str>,
   ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-    let actual = Self::sha256_file(file_path)?;
+    let actual = crate::integrity::sha256_file(file_path)?;
 
     let mut mismatch = !actual.eq_ignore_ascii_case(expected);
 
@@ -948,7 +926,7 @@ impl AppAutoUpdater {
   /// Download and prepare app update (silent download + install + notify)
   pub async fn download_and_prepare_update(
     &self,
-    _app_handle: &tauri::AppHandle,
+    _app_handle: &crate::app_handle::AppHandle,
     update_info: &AppUpdateInfo,
   ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     log::info!("Starting background update download and install");
@@ -1015,7 +993,9 @@ impl AppAutoUpdater {
 
     log::info!("Update ready, emitting app-update-ready event");
 
-    let _ = events::emit("app-update-ready", update_info.new_version.clone());
+    let _ = events::emit(&events::payloads::AppUpdateReady(
+      update_info.new_version.clone(),
+    ));
 
     Ok(())
   }
@@ -2049,6 +2029,10 @@ pub async fn check_for_app_updates() -> Result<Option<AppUpdateInfo>, String> {
     log::info!("App auto-updates disabled in portable mode");
     return Ok(None);
   }
+  if crate::offline::is_offline() {
+    log::info!("Offline mode, skipping app update check");
+    return Ok(None);
+  }
   // The disable_auto_updates setting controls app self-updates only
   let disabled = crate::settings_manager::SettingsManager::instance()
     .load_settings()
@@ -2068,7 +2052,7 @@ pub async fn check_for_app_updates() -> Result<Option<AppUpdateInfo>, String> {
 
 #[tauri::command]
 pub async fn download_and_prepare_app_update(
-  app_handle: tauri::AppHandle,
+  app_handle: crate::app_handle::AppHandle,
   update_info: AppUpdateInfo,
 ) -> Result<(), String> {
   let updater = AppAutoUpdater::instance();
@@ -2256,18 +2240,6 @@ not-a-hash  Donut_0.29.0_amd64.deb
     );
   }
 
-  #[test]
-  fn test_sha256_file_matches_known_digest() {
-    let temp_dir = tempfile::TempDir::new().unwrap();
-    let path = temp_dir.path().join("data.bin");
-    std::fs::write(&path, b"hello world").unwrap();
-    assert_eq!(
-      AppAutoUpdater::sha256_file(&path).unwrap(),
-      // sha256 of "hello world"
-      "b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9"
-    );
-  }
-
   #[test]
   fn test_find_checksums_url() {
     let assets = vec![
@@ -4,6 +4,7 @@ use std::sync::OnceLock;
 
 static BASE_DIRS: OnceLock<BaseDirs> = OnceLock::new();
 static PORTABLE_DIR: OnceLock<Option<PathBuf>> = OnceLock::new();
+static ACTIVE_WORKSPACE: OnceLock<Option<crate::workspaces::Workspace>> = OnceLock::new();
 
 fn base_dirs() -> &'static BaseDirs {
   BASE_DIRS.get_or_init(|| BaseDirs::new().expect("Failed to get base directories"))
@@ -51,7 +52,44 @@ pub fn app_name() -> &'static str {
   }
 }
 
+/// The workspace this process runs in, resolved once at startup. `None` is
+/// the default workspace, which lives directly in the root directories.
+/// Switching workspaces restarts the app, so this never changes mid-run.
+pub fn active_workspace() -> Option<&'static crate::workspaces::Workspace> {
+  if cfg!(test) {
+    return None;
+  }
+  ACTIVE_WORKSPACE
+    .get_or_init(|| crate::workspaces::load_active(&root_data_dir()))
+    .as_ref()
+}
+
+/// Data directory of a workspace other than the default one.
+pub fn workspace_data_dir(root: &std::path::Path, workspace_id: &str) -> PathBuf {
+  root.join("workspaces").join(workspace_id)
+}
+
+/// Data directory of the active workspace.
 pub fn data_dir() -> PathBuf {
+  let root = root_data_dir();
+  match active_workspace() {
+    Some(workspace) => workspace_data_dir(&root, &workspace.id),
+    None => root,
+  }
+}
+
+/// Cache directory of the active workspace.
+pub fn cache_dir() -> PathBuf {
+  let root = root_cache_dir();
+  match active_workspace() {
+    Some(workspace) => root.join("workspaces").join(&workspace.id),
+    None => root,
+  }
+}
+
+/// Data directory shared by every workspace. It holds the workspace
+/// registry, the default workspace's data and the shared browser binaries.
+pub fn root_data_dir() -> PathBuf {
   #[cfg(test)]
   {
     if let Some(dir) = TEST_DATA_DIR.with(|cell| cell.borrow().clone()) {
@@ -74,7 +112,7 @@ pub fn data_dir() -> PathBuf {
   base_dirs().data_local_dir().join(app_name())
 }
 
-pub fn cache_dir() -> PathBuf {
+pub fn root_cache_dir() -> PathBuf {
   #[cfg(test)]
   {
     if let Some(dir) = TEST_CACHE_DIR.with(|cell| cell.borrow().clone()) {
@@ -101,8 +139,34 @@ pub fn profiles_dir() -> PathBuf {
   data_dir().join("profiles")
 }
 
+pub fn backups_dir() -> PathBuf {
+  data_dir().join("backups")
+}
+
+/// Workspaces that share binaries keep them, and the registry tracking
+/// them, in the root data dir next to the default workspace's.
+fn binaries_home() -> PathBuf {
+  match active_workspace() {
+    Some(workspace) if workspace.shared_binaries => root_data_dir(),
+    _ => data_dir(),
+  }
+}
+
 pub fn binaries_dir() -> PathBuf {
-  data_dir().join("binaries")
+  binaries_home().join("binaries")
+}
+
+pub fn binaries_registry_file() -> PathBuf {
+  binaries_home()
+    .join("data")
+    .join("downloaded_browsers.json")
+}
+
+/// What binary cleanup removed, next to the registry it was removed from.
+pub fn binaries_cleanup_log_file() -> PathBuf {
+  binaries_home()
+    .join("data")
+    .join("binaries_cleanup_log.json")
 }
 
 pub fn data_subdir() -> PathBuf {
@@ -117,6 +181,10 @@ pub fn proxies_dir() -> PathBuf {
   data_dir().join("proxies")
 }
 
+pub fn proxy_pools_dir() -> PathBuf {
+  data_dir().join("proxy_pools")
+}
+
 pub fn proxy_workers_dir() -> PathBuf {
   cache_dir().join("proxy_workers")
 }
@@ -129,6 +197,10 @@ pub fn extensions_dir() -> PathBuf {
   data_dir().join("extensions")
 }
 
+pub fn audit_dir() -> PathBuf {
+  data_dir().join("audit")
+}
+
 pub fn dns_blocklist_dir() -> PathBuf {
   cache_dir().join("dns_blocklists")
 }
@@ -136,15 +208,15 @@ pub fn dns_blocklist_dir() -> PathBuf {
 /// Resolve the directory that tauri-plugin-log writes to. Mirrors the
 /// `LogDir` target used in the plugin builder so the path matches what's
 /// actually on disk for this OS.
-pub fn log_dir<R: tauri::Runtime>(handle: &tauri::AppHandle<R>) -> PathBuf {
+pub fn log_dir(handle: &crate::app_handle::AppHandle) -> PathBuf {
   if let Some(dir) = log_dir_override() {
     return dir;
   }
   use tauri::Manager;
   handle
-    .path()
-    .app_log_dir()
-    .unwrap_or_else(|_| std::env::temp_dir())
+    .tauri()
+    .and_then(|handle| handle.path().app_log_dir().ok())
+    .unwrap_or_else(std::env::temp_dir)
 }
 
 #[cfg(test)]
@@ -0,0 +1,58 @@
+use tauri::ipc::{CommandArg, CommandItem, InvokeError};
+
+/// Handle threaded through the managers and commands.
+///
+/// The GUI wraps the Tauri handle. The headless daemon has no webview runtime
+/// and passes `Headless`; code that needs the Tauri handle itself (windows,
+/// tray, plugin APIs) asks for it with [`AppHandle::tauri`] and skips that work
+/// when there is none.
+#[derive(Clone)]
+pub enum AppHandle {
+  Tauri(tauri::AppHandle),
+  Headless,
+}
+
+impl AppHandle {
+  pub fn tauri(&self) -> Option<&tauri::AppHandle> {
+    match self {
+      AppHandle::Tauri(handle) => Some(handle),
+      AppHandle::Headless => None,
+    }
+  }
+
+  pub fn is_headless(&self) -> bool {
+    matches!(self, AppHandle::Headless)
+  }
+}
+
+impl From<tauri::AppHandle> for AppHandle {
+  fn from(handle: tauri::AppHandle) -> Self {
+    AppHandle::Tauri(handle)
+  }
+}
+
+impl From<&tauri::AppHandle> for AppHandle {
+  fn from(handle: &tauri::AppHandle) -> Self {
+    AppHandle::Tauri(handle.clone())
+  }
+}
+
+/// Lets `#[tauri::command]` functions take this handle directly, exactly like
+/// they would take `tauri::AppHandle`.
+impl<'de> CommandArg<'de, tauri::Wry> for AppHandle {
+  fn from_command(command: CommandItem<'de, tauri::Wry>) -> Result<Self, InvokeError> {
+    tauri::AppHandle::from_command(command).map(AppHandle::Tauri)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn headless_handle_has_no_tauri_handle() {
+    let handle = AppHandle::Headless;
+    assert!(handle.is_headless());
+    assert!(handle.tauri().is_none());
+  }
+}
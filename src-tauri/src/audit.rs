@@ -0,0 +1,324 @@
+//! Audit trail of mutating actions and who started them: the GUI, a REST API
+//! token or an MCP tool call. Records are appended to a JSONL file in the
+//! data dir that rotates by size. The hooks live at the command layer: the
+//! GUI commands wrap their work in [`audited!`], the API server records every
+//! mutating request from a middleware and the MCP server records every
+//! mutating tool call at dispatch. A failed write never fails the action it
+//! describes; it is counted instead and reported by the MCP `/health` route.
+
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+const LOG_FILE: &str = "audit.jsonl";
+/// The current file is rotated once it reaches this size.
+const MAX_FILE_BYTES: u64 = 5 * 1024 * 1024;
+/// Rotated files kept, `audit.1.jsonl` being the newest.
+const MAX_ROTATED_FILES: usize = 3;
+const DEFAULT_LIMIT: usize = 200;
+/// Upper bound on records returned by a single query.
+pub const MAX_LIMIT: usize = 1000;
+
+static WRITE_FAILURES: AtomicU64 = AtomicU64::new(0);
+/// Serializes appends with rotation.
+static WRITE_LOCK: Mutex<()> = Mutex::new(());
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum AuditActor {
+  Gui,
+  /// A REST API request, by the name of the token that authorized it.
+  Api {
+    token_name: String,
+  },
+  /// An MCP tool call.
+  Mcp {
+    tool: String,
+  },
+}
+
+impl AuditActor {
+  fn kind(&self) -> &'static str {
+    match self {
+      AuditActor::Gui => "gui",
+      AuditActor::Api { .. } => "api",
+      AuditActor::Mcp { .. } => "mcp",
+    }
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditOutcome {
+  Success,
+  Failure,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct AuditRecord {
+  /// Epoch seconds.
+  pub timestamp: u64,
+  pub actor: AuditActor,
+  /// What was done, e.g. `launch`, `kill`, `delete` or `update`.
+  pub action: String,
+  /// What it was done to, e.g. `profile`, `proxy` or `settings`.
+  pub entity_type: String,
+  pub entity_id: Option<String>,
+  pub outcome: AuditOutcome,
+  /// Why a failed action failed.
+  pub error: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct AuditQuery {
+  /// Only records by this kind of actor: `gui`, `api` or `mcp`.
+  pub actor: Option<String>,
+  pub entity_type: Option<String>,
+  pub entity_id: Option<String>,
+  /// Only records at or after this epoch-seconds timestamp.
+  pub since: Option<u64>,
+  /// Only records at or before this epoch-seconds timestamp.
+  pub until: Option<u64>,
+  /// Most recent matching records to return; capped at [`MAX_LIMIT`].
+  pub limit: Option<usize>,
+}
+
+/// Evaluates `$result` (a `Result` whose error is `Display`), records its
+/// outcome and yields it unchanged.
+macro_rules! audited {
+  ($actor:expr, $action:expr, $entity_type:expr, $entity_id:expr, $result:expr) => {{
+    let result = $result;
+    $crate::audit::record(
+      $actor,
+      $action,
+      $entity_type,
+      $entity_id,
+      result.as_ref().err().map(|e| e.to_string()),
+    );
+    result
+  }};
+}
+pub(crate) use audited;
+
+fn log_path(dir: &Path) -> PathBuf {
+  dir.join(LOG_FILE)
+}
+
+fn rotated_path(dir: &Path, index: usize) -> PathBuf {
+  dir.join(format!("audit.{index}.jsonl"))
+}
+
+fn rotate(dir: &Path) -> std::io::Result<()> {
+  let _ = std::fs::remove_file(rotated_path(dir, MAX_ROTATED_FILES));
+  for index in (1..MAX_ROTATED_FILES).rev() {
+    let from = rotated_path(dir, index);
+    if from.exists() {
+      std::fs::rename(&from, rotated_path(dir, index + 1))?;
+    }
+  }
+  std::fs::rename(log_path(dir), rotated_path(dir, 1))
+}
+
+fn append(dir: &Path, record: &AuditRecord) -> std::io::Result<()> {
+  let mut line = serde_json::to_string(record)?;
+  line.push('\n');
+
+  let _guard = WRITE_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+  std::fs::create_dir_all(dir)?;
+  let path = log_path(dir);
+  if std::fs::metadata(&path).is_ok_and(|m| m.len() >= MAX_FILE_BYTES) {
+    rotate(dir)?;
+  }
+  let mut file = OpenOptions::new().create(true).append(true).open(&path)?;
+  file.write_all(line.as_bytes())
+}
+
+/// Appends a record; `error` is `None` for an action that succeeded.
+pub fn record(
+  actor: AuditActor,
+  action: &str,
+  entity_type: &str,
+  entity_id: Option<String>,
+  error: Option<String>,
+) {
+  let record = AuditRecord {
+    timestamp: crate::proxy_manager::now_secs(),
+    actor,
+    action: action.to_string(),
+    entity_type: entity_type.to_string(),
+    entity_id,
+    outcome: if error.is_none() {
+      AuditOutcome::Success
+    } else {
+      AuditOutcome::Failure
+    },
+    error,
+  };
+  if let Err(e) = append(&crate::app_dirs::audit_dir(), &record) {
+    WRITE_FAILURES.fetch_add(1, Ordering::Relaxed);
+    log::warn!(
+      "Failed to write audit record for {} {}: {e}",
+      record.action,
+      record.entity_type
+    );
+  }
+}
+
+/// Records that could not be written since the app started.
+pub fn write_failures() -> u64 {
+  WRITE_FAILURES.load(Ordering::Relaxed)
+}
+
+fn read_records(dir: &Path, query: &AuditQuery) -> Result<Vec<AuditRecord>, String> {
+  if let Some(actor) = query.actor.as_deref() {
+    if !["gui", "api", "mcp"].contains(&actor) {
+      return Err(format!("Invalid actor: {actor}"));
+    }
+  }
+  let limit = query.limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT);
+
+  let files = std::iter::once(log_path(dir))
+    .chain((1..=MAX_ROTATED_FILES).map(|index| rotated_path(dir, index)));
+  let mut records = Vec::new();
+  for path in files {
+    let content = match std::fs::read_to_string(&path) {
+      Ok(content) => content,
+      Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+      Err(e) => return Err(format!("Failed to read audit log: {e}")),
+    };
+    let matching = content
+      .lines()
+      .rev()
+      .filter_map(|line| serde_json::from_str::<AuditRecord>(line).ok())
+      .filter(|r| query.actor.as_deref().is_none_or(|a| r.actor.kind() == a))
+      .filter(|r| {
+        query
+          .entity_type
+          .as_deref()
+          .is_none_or(|t| r.entity_type == t)
+      })
+      .filter(|r| {
+        query
+          .entity_id
+          .as_deref()
+          .is_none_or(|id| r.entity_id.as_deref() == Some(id))
+      })
+      .filter(|r| query.since.is_none_or(|since| r.timestamp >= since))
+      .filter(|r| query.until.is_none_or(|until| r.timestamp <= until));
+    records.extend(matching.take(limit - records.len()));
+    if records.len() >= limit {
+      break;
+    }
+  }
+  Ok(records)
+}
+
+/// Matching records, newest first.
+pub fn query(query: &AuditQuery) -> Result<Vec<AuditRecord>, String> {
+  read_records(&crate::app_dirs::audit_dir(), query)
+}
+
+#[tauri::command]
+pub fn get_audit_log(
+  actor: Option<String>,
+  entity_type: Option<String>,
+  entity_id: Option<String>,
+  since: Option<u64>,
+  until: Option<u64>,
+  limit: Option<usize>,
+) -> Result<Vec<AuditRecord>, String> {
+  query(&AuditQuery {
+    actor,
+    entity_type,
+    entity_id,
+    since,
+    until,
+    limit,
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn entry(timestamp: u64, actor: AuditActor, entity_id: &str) -> AuditRecord {
+    AuditRecord {
+      timestamp,
+      actor,
+      action: "launch".to_string(),
+      entity_type: "profile".to_string(),
+      entity_id: Some(entity_id.to_string()),
+      outcome: AuditOutcome::Success,
+      error: None,
+    }
+  }
+
+  #[test]
+  fn queries_filter_newest_first_across_rotated_files() {
+    let dir = tempfile::tempdir().unwrap();
+    let api = AuditActor::Api {
+      token_name: "ci".to_string(),
+    };
+    append(dir.path(), &entry(1, AuditActor::Gui, "a")).unwrap();
+    append(dir.path(), &entry(2, api.clone(), "a")).unwrap();
+    rotate(dir.path()).unwrap();
+    append(dir.path(), &entry(3, api.clone(), "b")).unwrap();
+    append(dir.path(), &entry(4, AuditActor::Gui, "a")).unwrap();
+
+    let all = read_records(dir.path(), &AuditQuery::default()).unwrap();
+    let timestamps: Vec<u64> = all.iter().map(|r| r.timestamp).collect();
+    assert_eq!(timestamps, vec![4, 3, 2, 1]);
+
+    let by_api = read_records(
+      dir.path(),
+      &AuditQuery {
+        actor: Some("api".to_string()),
+        ..Default::default()
+      },
+    )
+    .unwrap();
+    assert_eq!(by_api.len(), 2);
+    assert!(by_api.iter().all(|r| r.actor == api));
+
+    let windowed = read_records(
+      dir.path(),
+      &AuditQuery {
+        entity_id: Some("a".to_string()),
+        since: Some(2),
+        limit: Some(1),
+        ..Default::default()
+      },
+    )
+    .unwrap();
+    assert_eq!(windowed.len(), 1);
+    assert_eq!(windowed[0].timestamp, 4);
+
+    assert!(read_records(
+      dir.path(),
+      &AuditQuery {
+        actor: Some("cron".to_string()),
+        ..Default::default()
+      },
+    )
+    .is_err());
+  }
+
+  #[test]
+  fn rotation_keeps_a_bounded_number_of_files() {
+    let dir = tempfile::tempdir().unwrap();
+    for timestamp in 0..(MAX_ROTATED_FILES as u64 + 2) {
+      append(dir.path(), &entry(timestamp, AuditActor::Gui, "a")).unwrap();
+      rotate(dir.path()).unwrap();
+    }
+    assert!(rotated_path(dir.path(), MAX_ROTATED_FILES).exists());
+    assert!(!rotated_path(dir.path(), MAX_ROTATED_FILES + 1).exists());
+    let newest = read_records(dir.path(), &AuditQuery::default()).unwrap();
+    assert_eq!(newest.len(), MAX_ROTATED_FILES);
+    assert_eq!(newest[0].timestamp, MAX_ROTATED_FILES as u64 + 1);
+  }
+}
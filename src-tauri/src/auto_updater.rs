@@ -23,6 +23,23 @@ pub struct AutoUpdateState {
   #[serde(default)]
   pub auto_update_downloads: HashSet<String>, // track auto-update downloads for toast suppression
   pub last_check_timestamp: u64,
+  #[serde(default)]
+  pub rolled_back_versions: HashSet<String>, // "{browser}-{version}" the user reverted; never auto-applied again
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct RollbackResult {
+  pub browser: String,
+  pub from_version: String,
+  pub updated_profiles: Vec<String>,
+}
+
+/// Outcome of moving a browser's profiles onto a newly installed version.
+#[derive(Debug, Serialize, Clone, Default)]
+pub struct BrowserUpdateResult {
+  pub updated_profiles: Vec<String>,
+  /// Profiles left on their version because `pin_browser_version` is set.
+  pub skipped_pinned: usize,
 }
 
 pub struct AutoUpdater {
@@ -78,6 +95,11 @@ impl AutoUpdater {
         .push(profile);
     }
 
+    let rolled_back = self
+      .load_auto_update_state()
+      .map(|state| state.rolled_back_versions)
+      .unwrap_or_default();
+
     for (browser, profiles) in browser_profiles {
       // Always fetch fresh versions for update checks — stale cache would miss new releases
       let versions = match self
@@ -100,6 +122,11 @@ impl AutoUpdater {
         }
       };
 
+      let versions: Vec<BrowserVersionInfo> = versions
+        .into_iter()
+        .filter(|v| !rolled_back.contains(&format!("{browser}-{}", v.version)))
+        .collect();
+
       browser_versions.insert(browser.clone(), versions.clone());
 
       // Check each profile for updates
@@ -113,7 +140,11 @@ impl AutoUpdater {
     Ok(notifications)
   }
 
-  pub async fn check_for_updates_with_progress(&self, app_handle: &tauri::AppHandle) {
+  pub async fn check_for_updates_with_progress(&self, app_handle: &crate::app_handle::AppHandle) {
+    if crate::offline::is_offline() {
+      log::info!("Offline mode, skipping browser update check");
+      return;
+    }
     log::info!("Starting auto-update check with progress...");
 
     // Browser auto-updates are always enabled — the disable_auto_updates setting
@@ -160,12 +191,12 @@ impl AutoUpdater {
                   .auto_update_profile_versions(&app_handle_clone, &browser, &new_version)
                   .await
                 {
-                  Ok(updated_profiles) => {
-                    if !updated_profiles.is_empty() {
+                  Ok(result) => {
+                    if !result.updated_profiles.is_empty() {
                       log::info!(
                         "Auto-updated {} profiles to {browser} {new_version}: {:?}",
-                        updated_profiles.len(),
-                        updated_profiles
+                        result.updated_profiles.len(),
+                        result.updated_profiles
                       );
                     }
                   }
@@ -229,6 +260,9 @@ impl AutoUpdater {
     profile: &BrowserProfile,
     available_versions: &[BrowserVersionInfo],
   ) -> Result<Option<UpdateNotification>, Box<dyn std::error::Error + Send + Sync>> {
+    if profile.pin_browser_version {
+      return Ok(None);
+    }
     let current_version = &profile.version;
 
     let best_update = available_versions
@@ -289,16 +323,21 @@ impl AutoUpdater {
   /// Automatically update all affected profile versions after browser download
   pub async fn auto_update_profile_versions(
     &self,
-    app_handle: &tauri::AppHandle,
+    app_handle: &crate::app_handle::AppHandle,
     browser: &str,
     new_version: &str,
-  ) -> Result<Vec<String>, Box<dyn std::error::Error + Send + Sync>> {
+  ) -> Result<BrowserUpdateResult, Box<dyn std::error::Error + Send + Sync>> {
+    if self.is_rolled_back(browser, new_version) {
+      log::info!("Skipping auto-update to {browser} {new_version}: it was rolled back");
+      return Ok(BrowserUpdateResult::default());
+    }
+
     let profiles = self
       .profile_manager
       .list_profiles()
       .map_err(|e| format!("Failed to list profiles: {e}"))?;
 
-    let mut updated_profiles = Vec::new();
+    let mut result = BrowserUpdateResult::default();
 
     // Find all profiles for this browser that should be updated
     for profile in profiles {
@@ -307,6 +346,19 @@ impl AutoUpdater {
           continue;
         }
 
+        if profile.pin_browser_version {
+          if self.is_version_newer(new_version, &profile.version) {
+            log::info!(
+              "Profile {} is pinned to {} {}, skipping update to {new_version}",
+              profile.name,
+              browser,
+              profile.version
+            );
+            result.skipped_pinned += 1;
+          }
+          continue;
+        }
+
         // Check if profile is currently running
         if profile.process_id.is_some() {
           // Store as pending update so it gets applied when browser closes
@@ -349,7 +401,8 @@ impl AutoUpdater {
             new_version,
           ) {
             Ok(_) => {
-              updated_profiles.push(profile.name);
+              self.record_rollback(&profile, new_version);
+              result.updated_profiles.push(profile.name);
             }
             Err(e) => {
               log::error!("Failed to update profile {}: {}", profile.name, e);
@@ -359,18 +412,18 @@ impl AutoUpdater {
       }
     }
 
-    Ok(updated_profiles)
+    Ok(result)
   }
 
   /// Complete browser update process with auto-update of profile versions
   pub async fn complete_browser_update_with_auto_update(
     &self,
-    app_handle: &tauri::AppHandle,
+    app_handle: &crate::app_handle::AppHandle,
     browser: &str,
     new_version: &str,
-  ) -> Result<Vec<String>, Box<dyn std::error::Error + Send + Sync>> {
+  ) -> Result<BrowserUpdateResult, Box<dyn std::error::Error + Send + Sync>> {
     // Auto-update profile versions first
-    let updated_profiles = self
+    let result = self
       .auto_update_profile_versions(app_handle, browser, new_version)
       .await?;
 
@@ -381,7 +434,7 @@ impl AutoUpdater {
     state.auto_update_downloads.remove(&download_key);
     self.save_auto_update_state(&state)?;
 
-    Ok(updated_profiles)
+    Ok(result)
   }
 
   /// Dismiss update notification
@@ -395,6 +448,108 @@ impl AutoUpdater {
     Ok(())
   }
 
+  /// Put every auto-updated profile of `browser` back on the version it ran
+  /// before the last update, re-enable the browser, and stop that update from
+  /// being offered again. All affected profiles must be stopped.
+  pub async fn rollback_browser_update(
+    &self,
+    app_handle: &crate::app_handle::AppHandle,
+    browser: &str,
+  ) -> Result<RollbackResult, Box<dyn std::error::Error + Send + Sync>> {
+    let registry = crate::downloaded_browsers_registry::DownloadedBrowsersRegistry::instance();
+    let unavailable = || {
+      serde_json::json!({ "code": "ROLLBACK_UNAVAILABLE", "params": { "browser": browser } })
+        .to_string()
+    };
+    let pointer = registry
+      .get_rollback_pointer(browser)
+      .ok_or_else(unavailable)?;
+
+    // Only profiles still on the updated version are reverted; anything the
+    // user moved since then is left alone.
+    let profiles: Vec<(BrowserProfile, String)> = self
+      .profile_manager
+      .list_profiles()
+      .map_err(|e| format!("Failed to list profiles: {e}"))?
+      .into_iter()
+      .filter(|p| {
+        p.browser == browser && p.version == pointer.new_version && !p.pin_browser_version
+      })
+      .filter_map(|p| {
+        let previous = pointer.previous_versions.get(&p.id.to_string())?.clone();
+        Some((p, previous))
+      })
+      .collect();
+
+    if profiles.iter().any(|(p, _)| p.process_id.is_some()) {
+      return Err(
+        serde_json::json!({ "code": "PROFILE_RUNNING" })
+          .to_string()
+          .into(),
+      );
+    }
+    if profiles
+      .iter()
+      .any(|(_, previous)| !registry.is_browser_downloaded(browser, previous))
+    {
+      return Err(unavailable().into());
+    }
+
+    let mut updated_profiles = Vec::new();
+    for (profile, previous) in &profiles {
+      match self.profile_manager.update_profile_version(
+        app_handle,
+        &profile.id.to_string(),
+        previous,
+      ) {
+        Ok(_) => updated_profiles.push(profile.name.clone()),
+        Err(e) => log::error!("Failed to roll back profile {}: {e}", profile.name),
+      }
+    }
+
+    let mut state = self.load_auto_update_state()?;
+    state.disabled_browsers.remove(browser);
+    state
+      .rolled_back_versions
+      .insert(format!("{browser}-{}", pointer.new_version));
+    state
+      .pending_updates
+      .retain(|u| !(u.browser == browser && u.new_version == pointer.new_version));
+    self.save_auto_update_state(&state)?;
+    registry.clear_rollback_pointer(browser);
+
+    log::info!(
+      "Rolled back {} profiles from {browser} {}",
+      updated_profiles.len(),
+      pointer.new_version
+    );
+    let result = RollbackResult {
+      browser: browser.to_string(),
+      from_version: pointer.new_version,
+      updated_profiles,
+    };
+    let _ = crate::events::emit(&result);
+    Ok(result)
+  }
+
+  fn record_rollback(&self, profile: &BrowserProfile, new_version: &str) {
+    crate::downloaded_browsers_registry::DownloadedBrowsersRegistry::instance()
+      .record_rollback_candidate(
+        &profile.browser,
+        &profile.id.to_string(),
+        &profile.version,
+        new_version,
+      );
+  }
+
+  fn is_rolled_back(&self, browser: &str, version: &str) -> bool {
+    self.load_auto_update_state().is_ok_and(|state| {
+      state
+        .rolled_back_versions
+        .contains(&format!("{browser}-{version}"))
+    })
+  }
+
   fn is_version_newer(&self, version1: &str, version2: &str) -> bool {
     crate::api_client::is_version_newer(version1, version2)
   }
@@ -477,7 +632,7 @@ impl AutoUpdater {
     let versions = registry.get_downloaded_versions(browser);
     versions
       .into_iter()
-      .filter(|v| registry.is_browser_downloaded(browser, v))
+      .filter(|v| registry.is_browser_downloaded(browser, v) && !self.is_rolled_back(browser, v))
       .max_by(|a, b| self.compare_versions(a, b))
   }
 
@@ -485,9 +640,12 @@ impl AutoUpdater {
   /// Used when a browser closes to ensure it's on the latest version.
   pub fn update_profile_to_latest_installed(
     &self,
-    app_handle: &tauri::AppHandle,
+    app_handle: &crate::app_handle::AppHandle,
     profile: &crate::profile::BrowserProfile,
   ) -> Option<crate::profile::BrowserProfile> {
+    if profile.pin_browser_version {
+      return None;
+    }
     let latest = self.get_latest_installed_version(&profile.browser)?;
 
     if !self.is_version_newer(&latest, &profile.version) {
@@ -499,6 +657,7 @@ impl AutoUpdater {
       .update_profile_version(app_handle, &profile.id.to_string(), &latest)
     {
       Ok(updated) => {
+        self.record_rollback(profile, &latest);
         log::info!(
           "Updated profile {} from {} {} to latest installed version {}",
           profile.name,
@@ -522,7 +681,7 @@ impl AutoUpdater {
   /// Handles the case where a newer version was downloaded but profiles weren't updated.
   pub fn update_profiles_to_latest_installed(
     &self,
-    app_handle: &tauri::AppHandle,
+    app_handle: &crate::app_handle::AppHandle,
   ) -> Result<Vec<String>, Box<dyn std::error::Error + Send + Sync>> {
     let registry = crate::downloaded_browsers_registry::DownloadedBrowsersRegistry::instance();
     let profiles = self
@@ -553,7 +712,9 @@ impl AutoUpdater {
       // Find the latest installed version that actually exists on disk
       let latest_installed = installed_versions
         .iter()
-        .filter(|v| registry.is_browser_downloaded(&browser, v))
+        .filter(|v| {
+          registry.is_browser_downloaded(&browser, v) && !self.is_rolled_back(&browser, v)
+        })
         .max_by(|a, b| self.compare_versions(a, b));
 
       let latest_version = match latest_installed {
@@ -562,7 +723,7 @@ impl AutoUpdater {
       };
 
       for profile in profiles {
-        if profile.process_id.is_some() {
+        if profile.process_id.is_some() || profile.pin_browser_version {
           continue;
         }
 
@@ -576,6 +737,7 @@ impl AutoUpdater {
           &latest_version,
         ) {
           Ok(_) => {
+            self.record_rollback(&profile, &latest_version);
             log::info!(
               "Updated profile {} from {} {} to latest installed version {}",
               profile.name,
@@ -619,10 +781,10 @@ pub async fn dismiss_update_notification(notification_id: String) -> Result<(),
 
 #[tauri::command]
 pub async fn complete_browser_update_with_auto_update(
-  app_handle: tauri::AppHandle,
+  app_handle: crate::app_handle::AppHandle,
   browser: String,
   new_version: String,
-) -> Result<Vec<String>, String> {
+) -> Result<BrowserUpdateResult, String> {
   let updater = AutoUpdater::instance();
   updater
     .complete_browser_update_with_auto_update(&app_handle, &browser, &new_version)
@@ -631,7 +793,18 @@ pub async fn complete_browser_update_with_auto_update(
 }
 
 #[tauri::command]
-pub async fn check_for_updates_with_progress(app_handle: tauri::AppHandle) {
+pub async fn rollback_browser_update(
+  app_handle: crate::app_handle::AppHandle,
+  browser: String,
+) -> Result<RollbackResult, String> {
+  AutoUpdater::instance()
+    .rollback_browser_update(&app_handle, &browser)
+    .await
+    .map_err(|e| crate::wrap_backend_error(e, "Failed to roll back browser update"))
+}
+
+#[tauri::command]
+pub async fn check_for_updates_with_progress(app_handle: crate::app_handle::AppHandle) {
   let updater = AutoUpdater::instance();
   updater.check_for_updates_with_progress(&app_handle).await;
 }
@@ -647,8 +820,10 @@ mod tests {
       browser: browser.to_string(),
       version: version.to_string(),
       process_id: None,
+      cdp_port: None,
       proxy_id: None,
       vpn_id: None,
+      proxy_pool_id: None,
       launch_hook: None,
       last_launch: None,
       release_type: "stable".to_string(),
@@ -669,6 +844,17 @@ mod tests {
       dns_blocklist: None,
       password_protected: false,
       clear_on_close: false,
+      startup_urls: Vec::new(),
+      session_restore: Default::default(),
+      preference_overrides: Default::default(),
+      metadata: Default::default(),
+      pin_browser_version: false,
+      restart_policy: Default::default(),
+      dns_mode: Default::default(),
+      exit_mismatch_action: Default::default(),
+      resource_limits: Default::default(),
+      applied_resource_limits: None,
+      archived: false,
       created_at: None,
       updated_at: None,
     }
@@ -744,6 +930,17 @@ mod tests {
     assert!(result.is_none());
   }
 
+  #[test]
+  fn test_check_profile_update_ignores_pinned_profile() {
+    let updater = AutoUpdater::instance();
+    let mut profile = create_test_profile("test", "wayfern", "138.0.7204.49");
+    profile.pin_browser_version = true;
+    let versions = vec![create_test_version_info("138.0.7204.50")];
+
+    let result = updater.check_profile_update(&profile, &versions).unwrap();
+    assert!(result.is_none());
+  }
+
   #[test]
   fn test_group_update_notifications() {
     let updater = AutoUpdater::instance();
@@ -0,0 +1,3 @@
+fn main() {
+  donutbrowser_lib::run_daemon()
+}
@@ -1,4 +1,5 @@
 use clap::{Arg, Command};
+use donutbrowser_lib::proxy_client_cert::ClientCertPaths;
 use donutbrowser_lib::proxy_runner::{
   start_proxy_process_with_profile, stop_all_proxy_processes, stop_proxy_process,
 };
@@ -147,6 +148,18 @@ async fn main() {
               Arg::new("local-protocol")
                 .long("local-protocol")
                 .help("Protocol served to the browser: http (default) or socks5"),
+            )
+            .arg(
+              Arg::new("client-cert")
+                .long("client-cert")
+                .requires("client-key")
+                .help("PEM client certificate presented to an https upstream"),
+            )
+            .arg(
+              Arg::new("client-key")
+                .long("client-key")
+                .requires("client-cert")
+                .help("PKCS#8 PEM private key for --client-cert"),
             ),
         )
         .subcommand(
@@ -244,6 +257,29 @@ async fn main() {
       let blocklist_file = start_matches.get_one::<String>("blocklist-file").cloned();
       let dns_allowlist_mode = start_matches.get_flag("dns-allowlist-mode");
       let local_protocol = start_matches.get_one::<String>("local-protocol").cloned();
+      let client_cert = match (
+        start_matches.get_one::<String>("client-cert"),
+        start_matches.get_one::<String>("client-key"),
+      ) {
+        (Some(cert_path), Some(key_path)) => Some(ClientCertPaths {
+          cert_path: cert_path.clone(),
+          key_path: key_path.clone(),
+        }),
+        _ => None,
+      };
+      // Entry hops of a proxy chain, as a JSON array of proxy URLs. Passed in
+      // the environment like the credentials above since the URLs carry them.
+      // Refuse to start on a malformed chain rather than dial the exit directly.
+      let upstream_chain: Vec<String> = match std::env::var("DONUT_PROXY_CHAIN") {
+        Ok(raw) => match serde_json::from_str(&raw) {
+          Ok(chain) => chain,
+          Err(e) => {
+            eprintln!("Invalid DONUT_PROXY_CHAIN: {e}");
+            process::exit(2);
+          }
+        },
+        Err(_) => Vec::new(),
+      };
 
       match start_proxy_process_with_profile(
         upstream_url,
@@ -253,6 +289,8 @@ async fn main() {
         blocklist_file,
         dns_allowlist_mode,
         local_protocol,
+        upstream_chain,
+        client_cert,
       )
       .await
       {
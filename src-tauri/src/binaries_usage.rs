@@ -0,0 +1,418 @@
+//! Disk accounting for downloaded browser binaries: what each installed
+//! version takes on disk, when a profile last launched it and whether any
+//! profile still needs it, plus a log of what cleanup removed and why.
+//!
+//! An install dir doesn't change once extracted, so each version is measured
+//! once on the blocking pool and its size cached until the version is removed
+//! from the registry.
+
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use utoipa::ToSchema;
+
+use crate::downloaded_browsers_registry::DownloadedBrowsersRegistry;
+use crate::entity_usage::ProfileRef;
+use crate::profile::{BrowserProfile, ProfileManager};
+
+/// Cleanup records kept in the log, oldest dropped first.
+const MAX_CLEANUP_RECORDS: usize = 50;
+
+/// An installed browser version.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct DownloadedBrowser {
+  pub browser: String,
+  pub version: String,
+  pub size_bytes: u64,
+  /// Latest `last_launch` among the profiles on this version, in epoch
+  /// seconds.
+  pub last_used: Option<u64>,
+  /// Profiles of this workspace on this version.
+  pub profiles: Vec<ProfileRef>,
+  /// Referenced by a profile here or in another workspace sharing the
+  /// binaries dir. Cleanup never removes a version in use.
+  pub in_use: bool,
+}
+
+/// One version removed from disk.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct BinaryRemoval {
+  pub browser: String,
+  pub version: String,
+  pub size_bytes: u64,
+  /// "unused" (no profile referenced it), "superseded" (its profiles moved to
+  /// a newer version) or "deleted" (removed by the user).
+  pub reason: String,
+}
+
+/// What one cleanup pass or manual deletion removed.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct BinaryCleanupRecord {
+  /// Epoch seconds.
+  pub timestamp: u64,
+  pub freed_bytes: u64,
+  pub removed: Vec<BinaryRemoval>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct BinariesDiskUsage {
+  pub total_bytes: u64,
+  /// Held by versions no profile references.
+  pub unused_bytes: u64,
+  pub version_count: usize,
+  pub unused_count: usize,
+  /// Past cleanups, newest first.
+  pub cleanups: Vec<BinaryCleanupRecord>,
+}
+
+lazy_static::lazy_static! {
+  static ref SIZE_CACHE: Mutex<HashMap<(String, String), u64>> = Mutex::new(HashMap::new());
+  /// Serializes read-modify-write of the cleanup log.
+  static ref CLEANUP_LOG_LOCK: Mutex<()> = Mutex::new(());
+}
+
+fn code(code: &str) -> String {
+  serde_json::json!({ "code": code }).to_string()
+}
+
+/// Apparent size of every file under `dir`. Symlinks are counted as links,
+/// never followed.
+fn measure_dir(dir: &Path) -> u64 {
+  let mut total = 0;
+  let mut stack = vec![dir.to_path_buf()];
+  while let Some(current) = stack.pop() {
+    let Ok(entries) = fs::read_dir(&current) else {
+      continue;
+    };
+    for entry in entries.flatten() {
+      let Ok(meta) = entry.metadata() else { continue };
+      if meta.is_dir() {
+        stack.push(entry.path());
+      } else {
+        total += meta.len();
+      }
+    }
+  }
+  total
+}
+
+/// Size of an installed version, measured on first use and cached after.
+/// Blocks while measuring.
+pub(crate) fn install_size(browser: &str, version: &str, path: &Path) -> u64 {
+  let key = (browser.to_string(), version.to_string());
+  if let Some(size) = SIZE_CACHE.lock().unwrap().get(&key) {
+    return *size;
+  }
+  let size = measure_dir(path);
+  SIZE_CACHE.lock().unwrap().insert(key, size);
+  size
+}
+
+/// Drops the cached size of a version that was removed.
+pub(crate) fn forget_size(browser: &str, version: &str) {
+  SIZE_CACHE
+    .lock()
+    .unwrap()
+    .remove(&(browser.to_string(), version.to_string()));
+}
+
+/// Past cleanup records, oldest first.
+pub(crate) fn load_cleanup_log() -> Vec<BinaryCleanupRecord> {
+  let path = crate::app_dirs::binaries_cleanup_log_file();
+  if !path.exists() {
+    return Vec::new();
+  }
+  match crate::json_file::read(&path) {
+    Ok(loaded) => loaded.value,
+    Err(e) => {
+      log::warn!("Failed to read binaries cleanup log: {e}");
+      Vec::new()
+    }
+  }
+}
+
+/// Appends one record for `removed` to the cleanup log. Nothing is recorded
+/// when nothing was removed.
+pub(crate) fn record_cleanup(removed: Vec<BinaryRemoval>) {
+  if removed.is_empty() {
+    return;
+  }
+  let freed_bytes = removed.iter().map(|r| r.size_bytes).sum();
+  log::info!(
+    "Binaries cleanup freed {freed_bytes} bytes: {}",
+    removed
+      .iter()
+      .map(|r| format!("{} {} ({})", r.browser, r.version, r.reason))
+      .collect::<Vec<_>>()
+      .join(", ")
+  );
+  let record = BinaryCleanupRecord {
+    timestamp: chrono::Utc::now().timestamp() as u64,
+    freed_bytes,
+    removed,
+  };
+
+  let _guard = CLEANUP_LOG_LOCK.lock().unwrap();
+  let mut records = load_cleanup_log();
+  records.push(record);
+  let excess = records.len().saturating_sub(MAX_CLEANUP_RECORDS);
+  records.drain(..excess);
+
+  let path = crate::app_dirs::binaries_cleanup_log_file();
+  let written = path
+    .parent()
+    .map_or(Ok(()), fs::create_dir_all)
+    .and_then(|_| serde_json::to_vec_pretty(&records).map_err(std::io::Error::from))
+    .and_then(|json| crate::json_file::write(&path, &json, false));
+  if let Err(e) = written {
+    log::warn!("Failed to write binaries cleanup log: {e}");
+  }
+}
+
+fn list_profiles() -> Result<Vec<BrowserProfile>, String> {
+  ProfileManager::instance()
+    .list_profiles()
+    .map_err(|e| format!("Failed to list profiles: {e}"))
+}
+
+/// Every installed version with its usage, sizes filled in from the cache or
+/// measured. Blocks while measuring.
+fn collect_downloaded(
+  installed: Vec<(String, String, PathBuf)>,
+  profiles: &[BrowserProfile],
+  shared: &HashSet<(String, String)>,
+) -> Vec<DownloadedBrowser> {
+  let mut browsers: Vec<DownloadedBrowser> = installed
+    .into_iter()
+    .map(|(browser, version, path)| {
+      let users: Vec<&BrowserProfile> = profiles
+        .iter()
+        .filter(|p| p.browser == browser && p.version == version)
+        .collect();
+      let shared_elsewhere = shared.contains(&(browser.clone(), version.clone()));
+      DownloadedBrowser {
+        size_bytes: install_size(&browser, &version, &path),
+        last_used: users.iter().filter_map(|p| p.last_launch).max(),
+        in_use: !users.is_empty() || shared_elsewhere,
+        profiles: users
+          .iter()
+          .map(|p| ProfileRef {
+            id: p.id.to_string(),
+            name: p.name.clone(),
+          })
+          .collect(),
+        browser,
+        version,
+      }
+    })
+    .collect();
+  browsers.sort_by(|a, b| {
+    a.browser
+      .cmp(&b.browser)
+      .then_with(|| crate::api_client::compare_versions(&b.version, &a.version))
+  });
+  browsers
+}
+
+fn summarize(
+  browsers: &[DownloadedBrowser],
+  cleanups: Vec<BinaryCleanupRecord>,
+) -> BinariesDiskUsage {
+  let unused: Vec<&DownloadedBrowser> = browsers.iter().filter(|b| !b.in_use).collect();
+  BinariesDiskUsage {
+    total_bytes: browsers.iter().map(|b| b.size_bytes).sum(),
+    unused_bytes: unused.iter().map(|b| b.size_bytes).sum(),
+    version_count: browsers.len(),
+    unused_count: unused.len(),
+    cleanups: cleanups.into_iter().rev().collect(),
+  }
+}
+
+/// Installed browser versions, newest first per browser, with their on-disk
+/// size, when a profile last used them and which profiles reference them.
+#[tauri::command]
+pub async fn list_downloaded_browsers() -> Result<Vec<DownloadedBrowser>, String> {
+  let profiles = list_profiles()?;
+  let installed = DownloadedBrowsersRegistry::instance().installed_versions();
+  tokio::task::spawn_blocking(move || {
+    let shared = crate::workspaces::shared_binary_versions_elsewhere();
+    collect_downloaded(installed, &profiles, &shared)
+  })
+  .await
+  .map_err(|e| format!("Disk usage task failed: {e}"))
+}
+
+/// Totals over every installed version, and the cleanup log.
+#[tauri::command]
+pub async fn get_binaries_disk_usage() -> Result<BinariesDiskUsage, String> {
+  let browsers = list_downloaded_browsers().await?;
+  let cleanups = tokio::task::spawn_blocking(load_cleanup_log)
+    .await
+    .map_err(|e| format!("Disk usage task failed: {e}"))?;
+  Ok(summarize(&browsers, cleanups))
+}
+
+/// Removes an installed version from disk and returns the bytes freed. A
+/// version a running profile uses is always refused; one any profile
+/// references is refused with `BROWSER_VERSION_IN_USE` unless `force` is set,
+/// in which case those profiles download it again on their next launch.
+#[tauri::command]
+pub async fn delete_downloaded_browser(
+  browser: String,
+  version: String,
+  force: Option<bool>,
+) -> Result<u64, String> {
+  let registry = DownloadedBrowsersRegistry::instance();
+  let path = registry
+    .install_path(&browser, &version)
+    .ok_or_else(|| code("BROWSER_VERSION_NOT_FOUND"))?;
+
+  let profiles = list_profiles()?;
+  let users: Vec<&BrowserProfile> = profiles
+    .iter()
+    .filter(|p| p.browser == browser && p.version == version)
+    .collect();
+  if users.iter().any(|p| {
+    p.process_id
+      .is_some_and(crate::proxy_storage::is_process_running)
+  }) {
+    return Err(code("BROWSER_VERSION_RUNNING"));
+  }
+  let key = (browser.clone(), version.clone());
+  let shared_elsewhere = crate::workspaces::shared_binary_versions_elsewhere().contains(&key);
+  if (!users.is_empty() || shared_elsewhere) && !force.unwrap_or(false) {
+    return Err(
+      serde_json::json!({
+        "code": "BROWSER_VERSION_IN_USE",
+        "params": { "count": users.len() }
+      })
+      .to_string(),
+    );
+  }
+
+  let removal = tokio::task::spawn_blocking(move || -> Result<BinaryRemoval, String> {
+    let size_bytes = install_size(&browser, &version, &path);
+    registry.remove_browser(&browser, &version);
+    registry
+      .save()
+      .map_err(|e| format!("Failed to save browser registry: {e}"))?;
+    if path.exists() {
+      fs::remove_dir_all(&path).map_err(|e| format!("Failed to remove {}: {e}", path.display()))?;
+    }
+    // Drop the browser's dir too once its last version is gone.
+    if let Some(browser_dir) = path.parent() {
+      if fs::read_dir(browser_dir).is_ok_and(|mut entries| entries.next().is_none()) {
+        let _ = fs::remove_dir(browser_dir);
+      }
+    }
+    Ok(BinaryRemoval {
+      browser,
+      version,
+      size_bytes,
+      reason: "deleted".to_string(),
+    })
+  })
+  .await
+  .map_err(|e| format!("Delete task failed: {e}"))??;
+
+  let freed = removal.size_bytes;
+  record_cleanup(vec![removal]);
+  Ok(freed)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn profile(browser: &str, version: &str, last_launch: Option<u64>) -> BrowserProfile {
+    BrowserProfile {
+      name: format!("{browser} {version}"),
+      browser: browser.to_string(),
+      version: version.to_string(),
+      last_launch,
+      ..Default::default()
+    }
+  }
+
+  #[test]
+  fn downloaded_versions_report_size_usage_and_last_launch() {
+    let tmp = tempfile::tempdir().unwrap();
+    let old = tmp.path().join("wayfern/140.0");
+    let new = tmp.path().join("wayfern/141.0");
+    fs::create_dir_all(old.join("lib")).unwrap();
+    fs::create_dir_all(&new).unwrap();
+    fs::write(old.join("chrome"), vec![0u8; 300]).unwrap();
+    fs::write(old.join("lib/libx.so"), vec![0u8; 200]).unwrap();
+    fs::write(new.join("chrome"), vec![0u8; 400]).unwrap();
+
+    let installed = vec![
+      ("usagetest".to_string(), "140.0".to_string(), old),
+      ("usagetest".to_string(), "141.0".to_string(), new),
+    ];
+    let profiles = [
+      profile("usagetest", "141.0", Some(100)),
+      profile("usagetest", "141.0", Some(250)),
+      profile("usagetest", "141.0", None),
+    ];
+    let browsers = collect_downloaded(installed, &profiles, &HashSet::new());
+
+    assert_eq!(browsers[0].version, "141.0");
+    assert_eq!(browsers[0].size_bytes, 400);
+    assert_eq!(browsers[0].last_used, Some(250));
+    assert_eq!(browsers[0].profiles.len(), 3);
+    assert!(browsers[0].in_use);
+    assert_eq!(browsers[1].size_bytes, 500);
+    assert_eq!(browsers[1].last_used, None);
+    assert!(!browsers[1].in_use);
+
+    let usage = summarize(&browsers, Vec::new());
+    assert_eq!((usage.total_bytes, usage.unused_bytes), (900, 500));
+    assert_eq!((usage.version_count, usage.unused_count), (2, 1));
+
+    forget_size("usagetest", "140.0");
+    forget_size("usagetest", "141.0");
+  }
+
+  #[test]
+  fn version_used_by_another_workspace_counts_as_in_use() {
+    let tmp = tempfile::tempdir().unwrap();
+    let installed = vec![(
+      "sharedtest".to_string(),
+      "140.0".to_string(),
+      tmp.path().to_path_buf(),
+    )];
+    let shared = HashSet::from([("sharedtest".to_string(), "140.0".to_string())]);
+    let browsers = collect_downloaded(installed, &[], &shared);
+    assert!(browsers[0].in_use);
+    assert!(browsers[0].profiles.is_empty());
+    forget_size("sharedtest", "140.0");
+  }
+
+  #[test]
+  fn cleanup_log_keeps_the_newest_records() {
+    let tmp = tempfile::tempdir().unwrap();
+    let _guard = crate::app_dirs::set_test_data_dir(tmp.path().to_path_buf());
+    record_cleanup(Vec::new());
+    assert!(load_cleanup_log().is_empty());
+
+    for i in 0..MAX_CLEANUP_RECORDS + 2 {
+      record_cleanup(vec![BinaryRemoval {
+        browser: "wayfern".to_string(),
+        version: format!("{i}.0"),
+        size_bytes: 10,
+        reason: "unused".to_string(),
+      }]);
+    }
+    let records = load_cleanup_log();
+    assert_eq!(records.len(), MAX_CLEANUP_RECORDS);
+    assert_eq!(records[0].removed[0].version, "2.0");
+
+    let usage = summarize(&[], records);
+    assert_eq!(
+      usage.cleanups[0].removed[0].version,
+      format!("{}.0", MAX_CLEANUP_RECORDS + 1)
+    );
+  }
+}
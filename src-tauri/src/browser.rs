@@ -9,23 +9,37 @@ pub struct ProxySettings {
   pub port: u16,
   pub username: Option<String>,
   pub password: Option<String>,
+  /// PEM client certificate presented to an "https" proxy during its TLS
+  /// handshake. Set together with `client_key_path`.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub client_cert_path: Option<String>,
+  /// Unencrypted PKCS#8 PEM private key for `client_cert_path`.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub client_key_path: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum BrowserType {
   Wayfern,
+  /// Integration-test stand-in, see [`crate::test_browser`].
+  #[cfg(feature = "test-browser")]
+  Stub,
 }
 
 impl BrowserType {
   pub fn as_str(&self) -> &'static str {
     match self {
       BrowserType::Wayfern => "wayfern",
+      #[cfg(feature = "test-browser")]
+      BrowserType::Stub => crate::test_browser::STUB_BROWSER,
     }
   }
 
   pub fn from_str(s: &str) -> Result<Self, String> {
     match s {
       "wayfern" => Ok(BrowserType::Wayfern),
+      #[cfg(feature = "test-browser")]
+      crate::test_browser::STUB_BROWSER => Ok(BrowserType::Stub),
       _ => Err(format!("Unknown browser type: {s}")),
     }
   }
@@ -374,6 +388,8 @@ impl BrowserFactory {
   pub fn create_browser(&self, browser_type: BrowserType) -> Box<dyn Browser> {
     match browser_type {
       BrowserType::Wayfern => Box::new(WayfernBrowser::new()),
+      #[cfg(feature = "test-browser")]
+      BrowserType::Stub => Box::new(crate::test_browser::StubBrowser),
     }
   }
 }
@@ -581,6 +597,8 @@ mod tests {
       port: 8080,
       username: None,
       password: None,
+      client_cert_path: None,
+      client_key_path: None,
     };
 
     // Test that it can be serialized (implements Serialize)
@@ -622,8 +640,10 @@ mod tests {
       version: "1.0.0".to_string(),
       proxy_id: None,
       vpn_id: None,
+      proxy_pool_id: None,
       launch_hook: None,
       process_id: None,
+      cdp_port: None,
       last_launch: None,
       release_type: "stable".to_string(),
       wayfern_config: None,
@@ -643,6 +663,17 @@ mod tests {
       dns_blocklist: None,
       password_protected: false,
       clear_on_close: false,
+      startup_urls: Vec::new(),
+      session_restore: Default::default(),
+      preference_overrides: Default::default(),
+      metadata: Default::default(),
+      pin_browser_version: false,
+      restart_policy: Default::default(),
+      dns_mode: Default::default(),
+      exit_mismatch_action: Default::default(),
+      resource_limits: Default::default(),
+      applied_resource_limits: None,
+      archived: false,
       created_at: None,
       updated_at: None,
     };
@@ -2,13 +2,23 @@ use crate::browser::ProxySettings;
 use crate::cloud_auth::CLOUD_AUTH;
 use crate::downloaded_browsers_registry::DownloadedBrowsersRegistry;
 use crate::events;
+use crate::events::payloads::{ProfileRunningChanged, ProfileUpdated};
+use crate::launch_timings::{LaunchPhase, LaunchTimer};
 use crate::profile::{BrowserProfile, ProfileManager};
 use crate::proxy_manager::PROXY_MANAGER;
-use crate::wayfern_manager::{WayfernConfig, WayfernManager};
-use serde::Serialize;
+use crate::wayfern_manager::{CdpEndpoint, WayfernConfig, WayfernManager};
 use std::path::PathBuf;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
+/// How long a stopped browser tree gets to exit on SIGTERM before it is
+/// force-killed.
+const GRACEFUL_STOP_TIMEOUT: Duration = Duration::from_secs(3);
+/// How long a force-killed tree gets to disappear from the process table.
+const FORCED_STOP_TIMEOUT: Duration = Duration::from_secs(5);
+/// How long the profile's singleton locks get to be released after the tree
+/// has exited.
+const LOCK_RELEASE_TIMEOUT: Duration = Duration::from_secs(5);
+
 pub struct BrowserRunner {
   pub profile_manager: &'static ProfileManager,
   pub downloaded_browsers_registry: &'static DownloadedBrowsersRegistry,
@@ -183,27 +193,152 @@ impl BrowserRunner {
 
   pub async fn launch_browser(
     &self,
-    app_handle: tauri::AppHandle,
+    app_handle: crate::app_handle::AppHandle,
     profile: &BrowserProfile,
     url: Option<String>,
     local_proxy_settings: Option<&ProxySettings>,
+    headless: bool,
   ) -> Result<BrowserProfile, Box<dyn std::error::Error + Send + Sync>> {
     self
-      .launch_browser_internal(app_handle, profile, url, local_proxy_settings, None, false)
+      .launch_browser_internal(
+        app_handle,
+        profile,
+        url,
+        local_proxy_settings,
+        None,
+        headless,
+      )
       .await
   }
 
+  /// Launch `profile`. A profile on a proxy pool is launched through the
+  /// member the pool's policy picks; a member that fails its health check or
+  /// can't carry the launch is skipped for the next one. The member used is
+  /// recorded with `PROXY_MANAGER`, the profile keeps referencing the pool.
   async fn launch_browser_internal(
     &self,
-    app_handle: tauri::AppHandle,
+    app_handle: crate::app_handle::AppHandle,
+    profile: &BrowserProfile,
+    url: Option<String>,
+    local_proxy_settings: Option<&ProxySettings>,
+    remote_debugging_port: Option<u16>,
+    headless: bool,
+  ) -> Result<BrowserProfile, Box<dyn std::error::Error + Send + Sync>> {
+    let Some(pool_id) = profile.proxy_pool_id.clone() else {
+      return self
+        .launch_browser_attempt(
+          app_handle,
+          profile,
+          url,
+          local_proxy_settings,
+          remote_debugging_port,
+          headless,
+        )
+        .await;
+    };
+
+    let profile_id = profile.id.to_string();
+    let candidates = PROXY_MANAGER.pool_launch_order(&pool_id, &profile_id)?;
+    let mut last_error: Option<Box<dyn std::error::Error + Send + Sync>> = None;
+    for (index, proxy_id) in candidates.iter().enumerate() {
+      // The last candidate is launched through regardless; with nothing left
+      // to fall back to, the launch itself reports what is wrong.
+      let is_last = index + 1 == candidates.len();
+      if !is_last && !PROXY_MANAGER.pool_member_healthy(proxy_id).await {
+        log::warn!(
+          "Skipping proxy {proxy_id} of pool {pool_id} for profile {}: health check failed",
+          profile.name
+        );
+        continue;
+      }
+
+      let mut attempt = profile.clone();
+      attempt.proxy_id = Some(proxy_id.clone());
+      attempt.vpn_id = None;
+      match self
+        .launch_browser_attempt(
+          app_handle.clone(),
+          &attempt,
+          url.clone(),
+          local_proxy_settings,
+          remote_debugging_port,
+          headless,
+        )
+        .await
+      {
+        Ok(mut launched) => {
+          log::info!(
+            "Launched profile {} through proxy {proxy_id} of pool {pool_id}",
+            profile.name
+          );
+          PROXY_MANAGER.record_pool_launch(&pool_id, &profile_id, proxy_id);
+          launched.proxy_id = None;
+          return Ok(launched);
+        }
+        Err(e) if !is_last && Self::is_pool_member_failure(&e.to_string()) => {
+          log::warn!(
+            "Proxy {proxy_id} of pool {pool_id} failed for profile {}, trying the next member: {e}",
+            profile.name
+          );
+          last_error = Some(e);
+        }
+        Err(e) => return Err(e),
+      }
+    }
+    Err(last_error.unwrap_or_else(|| {
+      serde_json::json!({ "code": "PROXY_NOT_WORKING" })
+        .to_string()
+        .into()
+    }))
+  }
+
+  /// Launch failures caused by the proxy itself, which another pool member
+  /// may not have.
+  fn is_pool_member_failure(error: &str) -> bool {
+    error.contains("Failed to start local proxy")
+      || [
+        "PROXY_NOT_WORKING",
+        "EXIT_LOCATION_MISMATCH",
+        "EXIT_CHECK_FAILED",
+      ]
+      .iter()
+      .any(|code| error.contains(&format!("\"code\":\"{code}\"")))
+  }
+
+  async fn launch_browser_attempt(
+    &self,
+    app_handle: crate::app_handle::AppHandle,
     profile: &BrowserProfile,
     url: Option<String>,
     _local_proxy_settings: Option<&ProxySettings>,
     remote_debugging_port: Option<u16>,
     headless: bool,
   ) -> Result<BrowserProfile, Box<dyn std::error::Error + Send + Sync>> {
+    #[cfg(feature = "test-browser")]
+    if profile.browser == crate::test_browser::STUB_BROWSER {
+      return self.launch_stub_browser(app_handle, profile, url).await;
+    }
+
     // Handle Wayfern profiles using WayfernManager
     if profile.browser == "wayfern" {
+      let mut timer = LaunchTimer::start();
+
+      if let Some(updated_profile) = self
+        .launch_from_warm_pool(
+          &app_handle,
+          profile,
+          url.as_deref(),
+          remote_debugging_port,
+          headless,
+          &mut timer,
+        )
+        .await
+      {
+        self.announce_wayfern_launch(&updated_profile)?;
+        timer.finish(&updated_profile);
+        return Ok(updated_profile);
+      }
+
       // Get or create wayfern config
       let mut wayfern_config = profile.wayfern_config.clone().unwrap_or_else(|| {
         log::info!(
@@ -231,12 +366,30 @@ impl BrowserRunner {
                   port,
                   username: None,
                   password: None,
+                  client_cert_path: None,
+                  client_key_path: None,
                 });
                 log::info!("VPN worker started for Wayfern profile on port {}", port);
               }
             }
             Err(e) => {
-              return Err(format!("Failed to start VPN worker: {e}").into());
+              // The worker only reports ready once the WireGuard handshake
+              // completed, so any failure here means the tunnel never came up.
+              let vpn_name = crate::vpn::VPN_STORAGE
+                .lock()
+                .ok()
+                .and_then(|storage| storage.load_config(vpn_id).ok())
+                .map(|config| config.name)
+                .unwrap_or_else(|| vpn_id.clone());
+              log::error!("VPN {vpn_name} failed to connect for launch: {e}");
+              return Err(
+                serde_json::json!({
+                  "code": "VPN_CONNECT_FAILED",
+                  "params": { "name": vpn_name, "error": e.to_string() }
+                })
+                .to_string()
+                .into(),
+              );
             }
           }
         }
@@ -247,7 +400,7 @@ impl BrowserRunner {
         profile.name,
         upstream_proxy
           .as_ref()
-          .map(|p| format!("{}:{}", p.host, p.port))
+          .map(|p| crate::ip_utils::host_port(&p.host, p.port))
           .unwrap_or_else(|| "DIRECT".to_string())
       );
 
@@ -259,10 +412,18 @@ impl BrowserRunner {
       // launches overwrite each other's active_proxies entry, ending with one
       // browser's worker tracked under another browser's PID.
       let launch_placeholder_pid = crate::proxy_manager::next_launch_placeholder_pid();
+      // A chained proxy's upstream is its exit; the worker dials these first.
+      let entry_hops = profile
+        .proxy_id
+        .as_deref()
+        .filter(|_| upstream_proxy.is_some())
+        .map(|id| PROXY_MANAGER.get_proxy_entry_hops(id))
+        .unwrap_or_default();
       let local_proxy = PROXY_MANAGER
         .start_proxy(
           app_handle.clone(),
           upstream_proxy.as_ref(),
+          &entry_hops,
           launch_placeholder_pid,
           Some(&profile_id_str),
           profile.proxy_bypass_rules.clone(),
@@ -280,13 +441,14 @@ impl BrowserRunner {
           log::error!("{}", error_msg);
           error_msg
         })?;
+      timer.mark(LaunchPhase::ProxyStart);
 
       // If any step below fails before the browser is up, the detached worker
       // must be stopped here: its config never gets a browser_pid, so neither
       // the GUI sweeps nor the worker's own watchdog would ever reap it — it
       // would survive until machine reboot.
       struct ProxyLaunchGuard {
-        app_handle: tauri::AppHandle,
+        app_handle: crate::app_handle::AppHandle,
         placeholder_pid: u32,
         profile_name: String,
         armed: bool,
@@ -315,6 +477,13 @@ impl BrowserRunner {
         armed: true,
       };
 
+      // Resolved through the worker the browser is about to use, so a proxy or
+      // VPN exiting in the wrong place is caught before any page loads.
+      let exit_probe_url = format!("socks5h://{}:{}", local_proxy.host, local_proxy.port);
+      crate::exit_check::check_launch_exit(profile, upstream_proxy.as_ref(), &exit_probe_url)
+        .await
+        .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> { e.into() })?;
+
       // Format proxy URL for wayfern - use SOCKS5 for the local proxy so
       // Chromium proxies UDP (QUIC/WebRTC), not just TCP.
       let proxy_url = format!("socks5://{}:{}", local_proxy.host, local_proxy.port);
@@ -322,6 +491,32 @@ impl BrowserRunner {
       // Set proxy in wayfern config
       wayfern_config.proxy = Some(proxy_url);
 
+      // Mock WebRTC without a pinned address reports the exit found through
+      // the worker. If it can't be found the launch fails rather than letting
+      // WebRTC report some other address.
+      if let Some(crate::wayfern_manager::WebRtcMode::Mock { ip: None }) =
+        wayfern_config.effective_webrtc_mode()
+      {
+        let ip =
+          crate::exit_check::launch_exit_ip(profile, upstream_proxy.as_ref(), &exit_probe_url)
+            .await
+            .map_err(|error| -> Box<dyn std::error::Error + Send + Sync> {
+              serde_json::json!({
+                "code": "WEBRTC_EXIT_IP_FAILED",
+                "params": { "error": error }
+              })
+              .to_string()
+              .into()
+            })?;
+        log::info!(
+          "Mocking WebRTC public IP as exit {ip} for profile {}",
+          profile.name
+        );
+        wayfern_config.webrtc_mode =
+          Some(crate::wayfern_manager::WebRtcMode::Mock { ip: Some(ip) });
+      }
+      timer.mark(LaunchPhase::GeoipLookup);
+
       log::info!(
         "Configured local proxy for Wayfern: {:?}",
         wayfern_config.proxy
@@ -383,6 +578,7 @@ impl BrowserRunner {
           profile.name,
           updated_wayfern_config.fingerprint.as_ref().map(|f| f.len()).unwrap_or(0)
         );
+        timer.mark(LaunchPhase::Fingerprint);
       }
       // A non-randomize profile keeps its configured fingerprint verbatim, even
       // when its proxy/VPN routing has changed since the fingerprint was built.
@@ -411,29 +607,38 @@ impl BrowserRunner {
         crate::ephemeral_dirs::get_effective_profile_path(&updated_profile, &profiles_dir);
       let profile_path_str = profile_data_path.to_string_lossy().to_string();
 
+      if let Err(e) = crate::profile::preferences::apply_preference_overrides(
+        &profile_data_path,
+        &updated_profile.preference_overrides,
+      ) {
+        log::warn!("Failed to apply preference overrides: {e}");
+      }
+
       // Install extensions if an extension group is assigned
-      let mut extension_paths = Vec::new();
+      let mut prepared_extensions = crate::extension_manager::PreparedExtensions::default();
       if updated_profile.extension_group_id.is_some() {
         let mgr = crate::extension_manager::EXTENSION_MANAGER.lock().unwrap();
-        match mgr.install_extensions_for_profile(&updated_profile, &profile_data_path) {
-          Ok(paths) => {
-            if !paths.is_empty() {
+        match mgr.install_extensions_for_profile(&updated_profile) {
+          Ok(prepared) => {
+            if !prepared.extensions.is_empty() {
               log::info!(
                 "Prepared {} Chromium extensions for profile: {}",
-                paths.len(),
+                prepared.extensions.len(),
                 updated_profile.name
               );
             }
-            extension_paths = paths;
+            prepared_extensions = prepared;
           }
           Err(e) => {
             log::warn!("Failed to install extensions for Wayfern profile: {e}");
           }
         }
       }
+      let extension_paths = prepared_extensions.paths();
 
       // Get proxy URL from config
       let proxy_url = wayfern_config.proxy.as_deref();
+      timer.mark(LaunchPhase::ConfigBuild);
 
       let wayfern_result = self
         .wayfern_manager
@@ -442,7 +647,8 @@ impl BrowserRunner {
           &updated_profile,
           &profile_path_str,
           &wayfern_config,
-          url.as_deref(),
+          &updated_profile.startup_urls_for_launch(url.as_deref()),
+          updated_profile.session_restore == crate::profile::types::SessionRestore::Restore,
           proxy_url,
           profile.ephemeral,
           &extension_paths,
@@ -453,6 +659,7 @@ impl BrowserRunner {
         .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
           format!("Failed to launch Wayfern: {e}").into()
         })?;
+      timer.extend(&wayfern_result.phase_timings);
 
       // Browser is up and using the worker — failures past this point must
       // not stop it.
@@ -482,7 +689,11 @@ impl BrowserRunner {
 
       // Update profile with the process info
       updated_profile.process_id = Some(process_id);
+      updated_profile.cdp_port = wayfern_result.cdp_port;
+      updated_profile.applied_resource_limits = wayfern_result.resource_limits.clone();
       updated_profile.last_launch = Some(SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs());
+      crate::downloaded_browsers_registry::DownloadedBrowsersRegistry::instance()
+        .mark_version_launched(&profile.browser, &profile.version);
 
       // Update the proxy manager with the correct PID. When the browser
       // reported no PID, keep the entry keyed by its unique placeholder (which
@@ -513,61 +724,145 @@ impl BrowserRunner {
           .map(|f| f.len())
           .unwrap_or(0)
       );
-      self.save_process_info(&updated_profile)?;
-      let _ = crate::tag_manager::TAG_MANAGER.lock().map(|tm| {
-        let _ = tm.rebuild_from_profiles(&self.profile_manager.list_profiles().unwrap_or_default());
-      });
-      log::info!(
-        "Successfully saved profile with process info: {}",
-        updated_profile.name
-      );
-
-      // Emit profiles-changed to trigger frontend to reload profiles from disk
-      if let Err(e) = events::emit_empty("profiles-changed") {
-        log::warn!("Warning: Failed to emit profiles-changed event: {e}");
+      self.announce_wayfern_launch(&updated_profile)?;
+
+      if !prepared_extensions.extensions.is_empty() || !prepared_extensions.failures.is_empty() {
+        tauri::async_runtime::spawn(verify_extensions_loaded(
+          updated_profile.clone(),
+          profile_data_path.clone(),
+          wayfern_result.cdp_port,
+          prepared_extensions,
+        ));
       }
-
-      log::info!(
-        "Emitting profile events for successful Wayfern launch: {}",
-        updated_profile.name
-      );
-
-      // Emit profile update event to frontend
-      if let Err(e) = events::emit("profile-updated", &updated_profile) {
-        log::warn!("Warning: Failed to emit profile update event: {e}");
+      if crate::warm_pool::keeps_alive(&updated_profile, headless) {
+        tauri::async_runtime::spawn(stop_when_windows_closed(
+          app_handle.clone(),
+          updated_profile.id.to_string(),
+          wayfern_result.id.clone(),
+        ));
       }
 
-      // Emit minimal running changed event to frontend
-      #[derive(Serialize)]
-      struct RunningChangedPayload {
-        id: String,
-        is_running: bool,
-      }
+      timer.finish(&updated_profile);
+      return Ok(updated_profile);
+    }
 
-      let payload = RunningChangedPayload {
-        id: updated_profile.id.to_string(),
-        is_running: updated_profile.process_id.is_some(),
-      };
+    Err(format!("Unsupported browser type: {}", profile.browser).into())
+  }
 
-      if let Err(e) = events::emit("profile-running-changed", &payload) {
-        log::warn!("Warning: Failed to emit profile running changed event: {e}");
-      } else {
-        log::info!(
-          "Successfully emitted profile-running-changed event for Wayfern {}: running={}",
-          updated_profile.name,
-          payload.is_running
+  /// Reuses the profile's parked browser from the warm pool when there is one
+  /// and the launch doesn't rule it out. Returns the profile as launched.
+  async fn launch_from_warm_pool(
+    &self,
+    app_handle: &crate::app_handle::AppHandle,
+    profile: &BrowserProfile,
+    url: Option<&str>,
+    remote_debugging_port: Option<u16>,
+    headless: bool,
+    timer: &mut LaunchTimer,
+  ) -> Option<BrowserProfile> {
+    let parked = crate::warm_pool::take(profile).await?;
+    let profile_id = profile.id.to_string();
+    if headless || remote_debugging_port.is_some() {
+      // It still holds the profile directory, so it has to go before the
+      // fresh launch.
+      crate::warm_pool::discard(&profile_id, parked).await;
+      return None;
+    }
+
+    let config = profile.wayfern_config.clone().unwrap_or_default();
+    let result = match self
+      .wayfern_manager
+      .revive_wayfern(
+        &parked.instance_id,
+        &config,
+        &profile.startup_urls_for_launch(url),
+      )
+      .await
+    {
+      Ok(result) => result,
+      Err(e) => {
+        log::warn!(
+          "Failed to reuse parked browser for profile {}: {e}",
+          profile.name
         );
+        crate::warm_pool::discard(&profile_id, parked).await;
+        return None;
       }
+    };
+    timer.extend(&result.phase_timings);
+    log::info!(
+      "Reused parked browser for profile {} (PID: {})",
+      profile.name,
+      parked.pid
+    );
+    tauri::async_runtime::spawn(stop_when_windows_closed(
+      app_handle.clone(),
+      profile_id,
+      result.id.clone(),
+    ));
 
-      return Ok(updated_profile);
+    let mut updated_profile = profile.clone();
+    updated_profile.process_id = Some(parked.pid);
+    updated_profile.cdp_port = result.cdp_port;
+    updated_profile.applied_resource_limits = parked.resource_limits;
+    updated_profile.last_launch = SystemTime::now()
+      .duration_since(UNIX_EPOCH)
+      .ok()
+      .map(|d| d.as_secs());
+    Some(updated_profile)
+  }
+
+  /// Saves a launched Wayfern profile's process info and tells the frontend.
+  fn announce_wayfern_launch(
+    &self,
+    updated_profile: &BrowserProfile,
+  ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    self.save_process_info(updated_profile)?;
+    let _ = crate::tag_manager::TAG_MANAGER.lock().map(|tm| {
+      let _ = tm.rebuild_from_profiles(&self.profile_manager.list_profiles().unwrap_or_default());
+    });
+    log::info!(
+      "Successfully saved profile with process info: {}",
+      updated_profile.name
+    );
+
+    // Emit profiles-changed to trigger frontend to reload profiles from disk
+    if let Err(e) = events::emit_empty("profiles-changed") {
+      log::warn!("Warning: Failed to emit profiles-changed event: {e}");
     }
 
-    Err(format!("Unsupported browser type: {}", profile.browser).into())
+    log::info!(
+      "Emitting profile events for successful Wayfern launch: {}",
+      updated_profile.name
+    );
+
+    // Emit profile update event to frontend
+    if let Err(e) = events::emit(&ProfileUpdated(&updated_profile)) {
+      log::warn!("Warning: Failed to emit profile update event: {e}");
+    }
+
+    // Emit minimal running changed event to frontend
+    let payload = ProfileRunningChanged {
+      id: updated_profile.id.to_string(),
+      is_running: updated_profile.process_id.is_some(),
+    };
+
+    if let Err(e) = events::emit(&payload) {
+      log::warn!("Warning: Failed to emit profile running changed event: {e}");
+    } else {
+      log::info!(
+        "Successfully emitted profile-running-changed event for Wayfern {}: running={}",
+        updated_profile.name,
+        payload.is_running
+      );
+    }
+
+    Ok(())
   }
 
   pub async fn open_url_in_existing_browser(
     &self,
-    _app_handle: tauri::AppHandle,
+    _app_handle: crate::app_handle::AppHandle,
     profile: &BrowserProfile,
     url: &str,
     _internal_proxy_settings: Option<&ProxySettings>,
@@ -610,7 +905,7 @@ impl BrowserRunner {
 
   pub async fn launch_browser_with_debugging(
     &self,
-    app_handle: tauri::AppHandle,
+    app_handle: crate::app_handle::AppHandle,
     profile: &BrowserProfile,
     url: Option<String>,
     remote_debugging_port: Option<u16>,
@@ -633,7 +928,7 @@ impl BrowserRunner {
 
   pub async fn launch_or_open_url(
     &self,
-    app_handle: tauri::AppHandle,
+    app_handle: crate::app_handle::AppHandle,
     profile: &BrowserProfile,
     url: Option<String>,
     internal_proxy_settings: Option<&ProxySettings>,
@@ -736,6 +1031,7 @@ impl BrowserRunner {
             &final_profile,
             url,
             internal_proxy_settings,
+            false,
           )
           .await
       }
@@ -763,8 +1059,14 @@ impl BrowserRunner {
     &self,
     profile: &BrowserProfile,
   ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    // A pool profile is launched with the member filled in as its proxy; only
+    // the pool reference is stored.
+    let mut profile = profile.clone();
+    if profile.proxy_pool_id.is_some() {
+      profile.proxy_id = None;
+    }
     // Use the regular save_profile method which handles the UUID structure
-    self.profile_manager.save_profile(profile).map_err(|e| {
+    self.profile_manager.save_profile(&profile).map_err(|e| {
       let error_string = e.to_string();
       Box::new(std::io::Error::other(error_string)) as Box<dyn std::error::Error + Send + Sync>
     })
@@ -772,7 +1074,7 @@ impl BrowserRunner {
 
   pub async fn check_browser_status(
     &self,
-    app_handle: tauri::AppHandle,
+    app_handle: crate::app_handle::AppHandle,
     profile: &BrowserProfile,
   ) -> Result<bool, Box<dyn std::error::Error + Send + Sync>> {
     self
@@ -783,9 +1085,31 @@ impl BrowserRunner {
 
   pub async fn kill_browser_process(
     &self,
-    app_handle: tauri::AppHandle,
+    app_handle: crate::app_handle::AppHandle,
     profile: &BrowserProfile,
   ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    // Recorded before the kill so the status checker never sees this exit
+    // before knowing it was intentional, whichever caller (UI, API, MCP)
+    // asked for it.
+    let profile_id = profile.id.to_string();
+    crate::profile::restart_supervisor::note_stop_requested(&profile_id);
+    let result = self.stop_browser_process(app_handle, profile).await;
+    if result.is_err() {
+      crate::profile::restart_supervisor::clear_stop_requested(&profile_id);
+    }
+    result
+  }
+
+  async fn stop_browser_process(
+    &self,
+    app_handle: crate::app_handle::AppHandle,
+    profile: &BrowserProfile,
+  ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    #[cfg(feature = "test-browser")]
+    if profile.browser == crate::test_browser::STUB_BROWSER {
+      return self.stop_stub_browser(app_handle, profile).await;
+    }
+
     // Handle Wayfern profiles using WayfernManager
     if profile.browser == "wayfern" {
       let profiles_dir = self.profile_manager.get_profiles_dir();
@@ -799,212 +1123,11 @@ impl BrowserRunner {
         profile.id
       );
 
-      // Stop the proxy associated with this profile first
-      let profile_id_str = profile.id.to_string();
-      if let Err(e) = PROXY_MANAGER
-        .stop_proxy_by_profile_id(app_handle.clone(), &profile_id_str)
-        .await
-      {
-        log::warn!(
-          "Warning: Failed to stop proxy for profile {}: {e}",
-          profile_id_str
-        );
-      }
-
-      let mut process_actually_stopped = false;
-      match self
-        .wayfern_manager
-        .find_wayfern_by_profile(&profile_path_str)
-        .await
-      {
-        Some(wayfern_process) => {
-          log::info!(
-            "Found Wayfern process: {} (PID: {:?})",
-            wayfern_process.id,
-            wayfern_process.processId
-          );
-
-          match self.wayfern_manager.stop_wayfern(&wayfern_process.id).await {
-            Ok(_) => {
-              if let Some(pid) = wayfern_process.processId {
-                // Verify the process actually died by checking after a short delay
-                use tokio::time::{sleep, Duration};
-                sleep(Duration::from_millis(500)).await;
-
-                use sysinfo::{Pid, System};
-                let system = System::new_all();
-                process_actually_stopped = system.process(Pid::from(pid as usize)).is_none();
-
-                if process_actually_stopped {
-                  log::info!(
-                    "Successfully stopped Wayfern process: {} (PID: {:?}) - verified process is dead",
-                    wayfern_process.id,
-                    pid
-                  );
-                } else {
-                  log::warn!(
-                    "Wayfern stop command returned success but process {} (PID: {:?}) is still running - forcing kill",
-                    wayfern_process.id,
-                    pid
-                  );
-                  // Force kill the process
-                  #[cfg(target_os = "macos")]
-                  {
-                    use crate::platform_browser;
-                    if let Err(e) = platform_browser::macos::kill_browser_process_impl(
-                      pid,
-                      Some(&profile_path_str),
-                    )
-                    .await
-                    {
-                      log::error!("Failed to force kill Wayfern process {}: {}", pid, e);
-                    } else {
-                      sleep(Duration::from_millis(500)).await;
-                      let system = System::new_all();
-                      process_actually_stopped = system.process(Pid::from(pid as usize)).is_none();
-                      if process_actually_stopped {
-                        log::info!(
-                          "Successfully force killed Wayfern process {} (PID: {:?})",
-                          wayfern_process.id,
-                          pid
-                        );
-                      }
-                    }
-                  }
-                  #[cfg(target_os = "linux")]
-                  {
-                    use crate::platform_browser;
-                    if let Err(e) = platform_browser::linux::kill_browser_process_impl(
-                      pid,
-                      Some(&profile_path_str),
-                    )
-                    .await
-                    {
-                      log::error!("Failed to force kill Wayfern process {}: {}", pid, e);
-                    } else {
-                      sleep(Duration::from_millis(500)).await;
-                      let system = System::new_all();
-                      process_actually_stopped = system.process(Pid::from(pid as usize)).is_none();
-                      if process_actually_stopped {
-                        log::info!(
-                          "Successfully force killed Wayfern process {} (PID: {:?})",
-                          wayfern_process.id,
-                          pid
-                        );
-                      }
-                    }
-                  }
-                  #[cfg(target_os = "windows")]
-                  {
-                    use crate::platform_browser;
-                    if let Err(e) = platform_browser::windows::kill_browser_process_impl(pid).await
-                    {
-                      log::error!("Failed to force kill Wayfern process {}: {}", pid, e);
-                    } else {
-                      sleep(Duration::from_millis(500)).await;
-                      let system = System::new_all();
-                      process_actually_stopped = system.process(Pid::from(pid as usize)).is_none();
-                      if process_actually_stopped {
-                        log::info!(
-                          "Successfully force killed Wayfern process {} (PID: {:?})",
-                          wayfern_process.id,
-                          pid
-                        );
-                      }
-                    }
-                  }
-                }
-              } else {
-                process_actually_stopped = true;
-              }
-            }
-            Err(e) => {
-              log::error!(
-                "Error stopping Wayfern process {}: {}",
-                wayfern_process.id,
-                e
-              );
-              // Try to force kill if we have a PID
-              if let Some(pid) = wayfern_process.processId {
-                log::info!(
-                  "Attempting force kill after stop_wayfern error for PID: {}",
-                  pid
-                );
-                #[cfg(target_os = "macos")]
-                {
-                  use crate::platform_browser;
-                  if let Err(kill_err) =
-                    platform_browser::macos::kill_browser_process_impl(pid, Some(&profile_path_str))
-                      .await
-                  {
-                    log::error!("Failed to force kill Wayfern process {}: {}", pid, kill_err);
-                  } else {
-                    use tokio::time::{sleep, Duration};
-                    sleep(Duration::from_millis(500)).await;
-                    use sysinfo::{Pid, System};
-                    let system = System::new_all();
-                    process_actually_stopped = system.process(Pid::from(pid as usize)).is_none();
-                  }
-                }
-                #[cfg(target_os = "linux")]
-                {
-                  use crate::platform_browser;
-                  if let Err(kill_err) =
-                    platform_browser::linux::kill_browser_process_impl(pid, Some(&profile_path_str))
-                      .await
-                  {
-                    log::error!("Failed to force kill Wayfern process {}: {}", pid, kill_err);
-                  } else {
-                    use tokio::time::{sleep, Duration};
-                    sleep(Duration::from_millis(500)).await;
-                    use sysinfo::{Pid, System};
-                    let system = System::new_all();
-                    process_actually_stopped = system.process(Pid::from(pid as usize)).is_none();
-                  }
-                }
-                #[cfg(target_os = "windows")]
-                {
-                  use crate::platform_browser;
-                  if let Err(kill_err) =
-                    platform_browser::windows::kill_browser_process_impl(pid).await
-                  {
-                    log::error!("Failed to force kill Wayfern process {}: {}", pid, kill_err);
-                  } else {
-                    use tokio::time::{sleep, Duration};
-                    sleep(Duration::from_millis(500)).await;
-                    use sysinfo::{Pid, System};
-                    let system = System::new_all();
-                    process_actually_stopped = system.process(Pid::from(pid as usize)).is_none();
-                  }
-                }
-              }
-            }
-          }
-        }
-        None => {
-          log::info!(
-            "No running Wayfern process found for profile: {} (ID: {})",
-            profile.name,
-            profile.id
-          );
-          process_actually_stopped = true;
-        }
-      }
-
-      // If process wasn't confirmed stopped, return an error
-      if !process_actually_stopped {
-        log::error!(
-          "Failed to stop Wayfern process for profile: {} (ID: {}) - process may still be running",
-          profile.name,
-          profile.id
-        );
-        return Err(
-          format!(
-            "Failed to stop Wayfern process for profile {} - process may still be running",
-            profile.name
-          )
-          .into(),
-        );
+      // A parked browser keeps running, windowless, with its proxy worker.
+      if !crate::warm_pool::park(profile, &profile_path_str).await {
+        self
+          .terminate_wayfern(app_handle.clone(), profile, &profile_path_str)
+          .await?;
       }
 
       // Clear the process ID from the profile and save immediately so that
@@ -1012,6 +1135,8 @@ impl BrowserRunner {
       // see the cleared process_id.
       let mut updated_profile = profile.clone();
       updated_profile.process_id = None;
+      updated_profile.cdp_port = None;
+      updated_profile.applied_resource_limits = None;
       self
         .save_process_info(&updated_profile)
         .map_err(|e| format!("Failed to update profile: {e}"))?;
@@ -1068,6 +1193,9 @@ impl BrowserRunner {
           updated_profile = p;
         }
       }
+      if updated_profile.version != profile.version {
+        crate::warm_pool::invalidate(&profile.id.to_string());
+      }
 
       log::info!(
         "Emitting profile events for successful Wayfern kill: {}",
@@ -1075,22 +1203,17 @@ impl BrowserRunner {
       );
 
       // Emit profile update event to frontend
-      if let Err(e) = events::emit("profile-updated", &updated_profile) {
+      if let Err(e) = events::emit(&ProfileUpdated(&updated_profile)) {
         log::warn!("Warning: Failed to emit profile update event: {e}");
       }
 
       // Emit minimal running changed event
-      #[derive(Serialize)]
-      struct RunningChangedPayload {
-        id: String,
-        is_running: bool,
-      }
-      let payload = RunningChangedPayload {
+      let payload = ProfileRunningChanged {
         id: updated_profile.id.to_string(),
         is_running: false,
       };
 
-      if let Err(e) = events::emit("profile-running-changed", &payload) {
+      if let Err(e) = events::emit(&payload) {
         log::warn!("Warning: Failed to emit profile running changed event: {e}");
       } else {
         log::info!(
@@ -1144,9 +1267,240 @@ impl BrowserRunner {
     )
   }
 
+  /// Stops the profile's proxy worker and Wayfern process tree, force-killing
+  /// whatever doesn't exit on its own, and waits for the profile's singleton
+  /// locks to be released before reporting success.
+  async fn terminate_wayfern(
+    &self,
+    app_handle: crate::app_handle::AppHandle,
+    profile: &BrowserProfile,
+    profile_path_str: &str,
+  ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    // Stop the proxy associated with this profile first
+    let profile_id_str = profile.id.to_string();
+    if let Err(e) = PROXY_MANAGER
+      .stop_proxy_by_profile_id(app_handle.clone(), &profile_id_str)
+      .await
+    {
+      log::warn!(
+        "Warning: Failed to stop proxy for profile {}: {e}",
+        profile_id_str
+      );
+    }
+    crate::process_registry::reap_profile_children(&profile_id_str);
+
+    let Some(wayfern_process) = self
+      .wayfern_manager
+      .find_wayfern_by_profile(profile_path_str)
+      .await
+    else {
+      log::info!(
+        "No running Wayfern process found for profile: {} (ID: {})",
+        profile.name,
+        profile.id
+      );
+      return Ok(());
+    };
+    log::info!(
+      "Found Wayfern process: {} (PID: {:?})",
+      wayfern_process.id,
+      wayfern_process.processId
+    );
+
+    // Snapshot the tree while the parent chain is intact; children are
+    // re-parented as soon as the main process exits.
+    let tree = wayfern_process
+      .processId
+      .map(|pid| crate::process_tree::tree_pids(pid, Some(profile_path_str)))
+      .unwrap_or_default();
+
+    if let Err(e) = self.wayfern_manager.stop_wayfern(&wayfern_process.id).await {
+      log::error!(
+        "Error stopping Wayfern process {}: {}",
+        wayfern_process.id,
+        e
+      );
+    }
+
+    if let Some(pid) = wayfern_process.processId {
+      let survivors = crate::process_tree::wait_for_exit(&tree, GRACEFUL_STOP_TIMEOUT).await;
+      if !survivors.is_empty() {
+        log::warn!(
+          "Wayfern process tree of {} (PID: {pid}) still has {} live process(es) - forcing kill",
+          wayfern_process.id,
+          survivors.len()
+        );
+        // Only a browser this session spawned leads its own process group.
+        crate::process_tree::kill_tree(pid, !wayfern_process.is_recovered(), &survivors);
+        let survivors = crate::process_tree::wait_for_exit(&tree, FORCED_STOP_TIMEOUT).await;
+        if !survivors.is_empty() {
+          log::error!(
+            "Failed to stop Wayfern process tree for profile: {} (ID: {}) - still running: {survivors:?}",
+            profile.name,
+            profile.id
+          );
+          return Err(
+            format!(
+              "Failed to stop Wayfern process for profile {} - process may still be running",
+              profile.name
+            )
+            .into(),
+          );
+        }
+      }
+      log::info!(
+        "Stopped Wayfern process tree of {} (PID: {pid}, {} process(es))",
+        wayfern_process.id,
+        tree.len()
+      );
+    }
+
+    if !crate::process_tree::wait_for_locks_released(
+      std::path::Path::new(profile_path_str),
+      LOCK_RELEASE_TIMEOUT,
+    )
+    .await
+    {
+      return Err(
+        format!(
+          "Wayfern stopped but the profile {} is still locked by another process",
+          profile.name
+        )
+        .into(),
+      );
+    }
+
+    Ok(())
+  }
+
+  /// Launch a stub profile the way a Wayfern one is launched, minus CDP:
+  /// local proxy worker, platform spawn, PID mapping, profile save, events.
+  #[cfg(feature = "test-browser")]
+  async fn launch_stub_browser(
+    &self,
+    app_handle: crate::app_handle::AppHandle,
+    profile: &BrowserProfile,
+    url: Option<String>,
+  ) -> Result<BrowserProfile, Box<dyn std::error::Error + Send + Sync>> {
+    let upstream_proxy = self.resolve_launch_proxy(profile).await?;
+    let profile_id_str = profile.id.to_string();
+    let launch_placeholder_pid = crate::proxy_manager::next_launch_placeholder_pid();
+    let local_proxy = PROXY_MANAGER
+      .start_proxy(
+        app_handle.clone(),
+        upstream_proxy.as_ref(),
+        &[],
+        launch_placeholder_pid,
+        Some(&profile_id_str),
+        profile.proxy_bypass_rules.clone(),
+        None,
+        false,
+        "http",
+      )
+      .await?;
+
+    let browser = crate::browser::create_browser(crate::browser::BrowserType::Stub);
+    let executable_path = self.get_browser_executable_path(profile)?;
+    let profile_data_path = crate::ephemeral_dirs::get_effective_profile_path(
+      profile,
+      &self.profile_manager.get_profiles_dir(),
+    );
+    let args = browser
+      .create_launch_args(
+        &profile_data_path.to_string_lossy(),
+        Some(&local_proxy),
+        url,
+        None,
+        false,
+      )
+      .map_err(|e| e.to_string())?;
+
+    #[cfg(target_os = "macos")]
+    let spawned =
+      crate::platform_browser::macos::launch_browser_process(&executable_path, &args).await;
+    #[cfg(target_os = "linux")]
+    let spawned =
+      crate::platform_browser::linux::launch_browser_process(&executable_path, &args).await;
+    #[cfg(target_os = "windows")]
+    let spawned =
+      crate::platform_browser::windows::launch_browser_process(&executable_path, &args).await;
+    #[cfg(not(any(target_os = "macos", target_os = "linux", target_os = "windows")))]
+    let spawned: Result<std::process::Child, Box<dyn std::error::Error + Send + Sync>> = {
+      let _ = (&executable_path, &args);
+      Err("Unsupported platform".into())
+    };
+    let mut child = match spawned {
+      Ok(child) => child,
+      Err(e) => {
+        let _ = PROXY_MANAGER
+          .stop_proxy(app_handle, launch_placeholder_pid)
+          .await;
+        return Err(e);
+      }
+    };
+    let process_id = child.id();
+    // Reaped here so the killed stub doesn't linger as a zombie that still
+    // counts as a running process.
+    std::thread::spawn(move || {
+      let _ = child.wait();
+    });
+
+    PROXY_MANAGER.update_proxy_pid(launch_placeholder_pid, process_id)?;
+    PROXY_MANAGER.set_browser_pid_for_profile(&profile_id_str, process_id);
+
+    let mut updated_profile = profile.clone();
+    updated_profile.process_id = Some(process_id);
+    updated_profile.last_launch = Some(SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs());
+    self.save_process_info(&updated_profile)?;
+
+    let _ = events::emit_empty("profiles-changed");
+    let _ = events::emit(&ProfileUpdated(&updated_profile));
+    let _ = events::emit(&ProfileRunningChanged {
+      id: profile_id_str,
+      is_running: true,
+    });
+    Ok(updated_profile)
+  }
+
+  #[cfg(feature = "test-browser")]
+  async fn stop_stub_browser(
+    &self,
+    app_handle: crate::app_handle::AppHandle,
+    profile: &BrowserProfile,
+  ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let profile_id_str = profile.id.to_string();
+    if let Err(e) = PROXY_MANAGER
+      .stop_proxy_by_profile_id(app_handle, &profile_id_str)
+      .await
+    {
+      log::warn!("Failed to stop proxy for stub profile {profile_id_str}: {e}");
+    }
+
+    if let Some(pid) = profile.process_id {
+      let tree = crate::process_tree::tree_pids(pid, None);
+      crate::process_tree::kill_tree(pid, true, &tree);
+      let survivors = crate::process_tree::wait_for_exit(&tree, FORCED_STOP_TIMEOUT).await;
+      if !survivors.is_empty() {
+        return Err(format!("Stub browser processes {survivors:?} are still running").into());
+      }
+    }
+
+    let mut updated_profile = profile.clone();
+    updated_profile.process_id = None;
+    updated_profile.applied_resource_limits = None;
+    self.save_process_info(&updated_profile)?;
+
+    let _ = events::emit(&ProfileUpdated(&updated_profile));
+    let _ = events::emit(&ProfileRunningChanged {
+      id: profile_id_str,
+      is_running: false,
+    });
+    Ok(())
+  }
+
   pub async fn open_url_with_profile(
     &self,
-    app_handle: tauri::AppHandle,
+    app_handle: crate::app_handle::AppHandle,
     profile_id: String,
     url: String,
   ) -> Result<(), String> {
@@ -1187,22 +1541,184 @@ impl BrowserRunner {
   }
 }
 
+/// A keep-alive browser outlives its last window, so closing that window is
+/// picked up here and handled as a stop, which parks or ends the browser.
+async fn stop_when_windows_closed(
+  app_handle: crate::app_handle::AppHandle,
+  profile_id: String,
+  instance_id: String,
+) {
+  let manager = WayfernManager::instance();
+  let mut empty_polls = 0;
+  loop {
+    tokio::time::sleep(Duration::from_secs(2)).await;
+    match manager.keep_alive_has_pages(&instance_id).await {
+      None => return,
+      Some(true) => empty_polls = 0,
+      Some(false) => empty_polls += 1,
+    }
+    // Two in a row, so a tab moving between windows isn't taken for a close.
+    if empty_polls < 2 {
+      continue;
+    }
+    let Some(profile) = ProfileManager::instance()
+      .list_profiles()
+      .ok()
+      .and_then(|profiles| {
+        profiles
+          .into_iter()
+          .find(|p| p.id.to_string() == profile_id)
+      })
+    else {
+      return;
+    };
+    log::info!("Last window of profile {} was closed", profile.name);
+    if let Err(e) = kill_browser_profile_impl(app_handle, profile).await {
+      log::warn!("Failed to stop browser after its last window closed: {e}");
+    }
+    return;
+  }
+}
+
+/// How long a launched browser gets to report every extension as loaded.
+const EXTENSION_VERIFY_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// Confirms that every extension passed to the browser got loaded, through
+/// its CDP targets or the profile's preference files, and emits
+/// `extensions-load-failed` for the ones that didn't, along with any that
+/// couldn't be prepared for launch.
+async fn verify_extensions_loaded(
+  profile: BrowserProfile,
+  user_data_dir: PathBuf,
+  cdp_port: Option<u16>,
+  prepared: crate::extension_manager::PreparedExtensions,
+) {
+  let mut loaded = std::collections::HashSet::new();
+  if let Some(port) = cdp_port.filter(|_| !prepared.extensions.is_empty()) {
+    let deadline = std::time::Instant::now() + EXTENSION_VERIFY_TIMEOUT;
+    loop {
+      if let Ok(ids) = WayfernManager::instance().running_extension_ids(port).await {
+        loaded.extend(ids);
+      }
+      loaded.extend(crate::extension_manager::extension_ids_in_prefs(
+        &user_data_dir,
+      ));
+      let all_loaded = prepared
+        .extensions
+        .iter()
+        .all(|e| loaded.contains(&e.chromium_id));
+      if all_loaded || std::time::Instant::now() >= deadline {
+        break;
+      }
+      tokio::time::sleep(Duration::from_secs(1)).await;
+    }
+  }
+
+  let failures = if cdp_port.is_some() {
+    prepared.failures_after_launch(&loaded)
+  } else {
+    prepared.failures
+  };
+  if failures.is_empty() {
+    return;
+  }
+  log::warn!(
+    "{} extension(s) did not load for profile {}",
+    failures.len(),
+    profile.name
+  );
+  let payload = crate::extension_manager::ExtensionsLoadFailed {
+    profile_id: profile.id.to_string(),
+    profile_name: profile.name,
+    failures,
+  };
+  if let Err(e) = events::emit(&payload) {
+    log::warn!("Failed to emit extensions-load-failed event: {e}");
+  }
+}
+
 #[tauri::command]
 pub async fn launch_browser_profile(
-  app_handle: tauri::AppHandle,
+  app_handle: crate::app_handle::AppHandle,
   profile: BrowserProfile,
   url: Option<String>,
+  headless: Option<bool>,
+  debug_port: Option<u16>,
 ) -> Result<BrowserProfile, String> {
-  launch_browser_profile_impl(app_handle, profile, url, None, false, false).await
+  // Headless or an explicit debug port always starts a fresh instance: handing
+  // the URL to an already-open window would silently drop both. A debug port
+  // of 0 asks for any free port; the chosen one is returned in `cdp_port`.
+  let headless = headless.unwrap_or(false);
+  let force_new = headless || debug_port.is_some();
+  let profile_id = profile.id.to_string();
+  crate::audit::audited!(
+    crate::audit::AuditActor::Gui,
+    "launch",
+    "profile",
+    Some(profile_id),
+    launch_browser_profile_impl(
+      app_handle,
+      profile,
+      url,
+      debug_port.filter(|p| *p != 0),
+      headless,
+      force_new,
+      crate::launch_queue::LaunchPriority::Interactive,
+    )
+    .await
+  )
+}
+
+/// CDP endpoint of a running profile, for attaching Puppeteer/Playwright.
+pub async fn get_profile_cdp_endpoint_impl(profile_id: &str) -> Result<CdpEndpoint, String> {
+  let runner = BrowserRunner::instance();
+  let profile = runner
+    .profile_manager
+    .list_profiles()
+    .map_err(|e| format!("Failed to list profiles: {e}"))?
+    .into_iter()
+    .find(|p| p.id.to_string() == profile_id)
+    .ok_or_else(|| serde_json::json!({ "code": "PROFILE_NOT_FOUND" }).to_string())?;
+
+  if !profile
+    .process_id
+    .is_some_and(crate::proxy_storage::is_process_running)
+  {
+    return Err(serde_json::json!({ "code": "PROFILE_NOT_RUNNING" }).to_string());
+  }
+
+  let cdp_unavailable = || serde_json::json!({ "code": "CDP_UNAVAILABLE" }).to_string();
+  if profile.browser != "wayfern" {
+    return Err(cdp_unavailable());
+  }
+
+  let profiles_dir = runner.profile_manager.get_profiles_dir();
+  let profile_path = crate::ephemeral_dirs::get_effective_profile_path(&profile, &profiles_dir);
+  let manager = WayfernManager::instance();
+  let port = manager
+    .get_cdp_port(&profile_path.to_string_lossy())
+    .await
+    .or(profile.cdp_port)
+    .ok_or_else(cdp_unavailable)?;
+  manager.get_cdp_endpoint(port).await.map_err(|e| {
+    log::warn!("CDP endpoint on port {port} not reachable: {e}");
+    cdp_unavailable()
+  })
+}
+
+#[tauri::command]
+pub async fn get_profile_cdp_endpoint(profile_id: String) -> Result<CdpEndpoint, String> {
+  get_profile_cdp_endpoint_impl(&profile_id).await
 }
 
 pub async fn launch_browser_profile_impl(
-  app_handle: tauri::AppHandle,
+  app_handle: crate::app_handle::AppHandle,
   profile: BrowserProfile,
   url: Option<String>,
   remote_debugging_port: Option<u16>,
   headless: bool,
   force_new: bool,
+  priority: crate::launch_queue::LaunchPriority,
 ) -> Result<BrowserProfile, String> {
   log::info!(
     "Launch request received for profile: {} (ID: {})",
@@ -1218,6 +1734,70 @@ pub async fn launch_browser_profile_impl(
     ));
   }
 
+  if profile.archived {
+    return Err(
+      serde_json::json!({ "code": "PROFILE_ARCHIVED", "params": { "name": profile.name } })
+        .to_string(),
+    );
+  }
+
+  // A browser already running on this profile that the app did not start
+  // (relaunched by hand after a crash, or left over from a previous session)
+  // is adopted rather than launched over: a second instance on the same
+  // user-data-dir corrupts the profile. Opening a URL in it is still fine.
+  if profile.browser == "wayfern" && (force_new || url.is_none()) {
+    let browser_runner = BrowserRunner::instance();
+    let profiles_dir = browser_runner.profile_manager.get_profiles_dir();
+    let profile_path = crate::ephemeral_dirs::get_effective_profile_path(&profile, &profiles_dir);
+    if let Some(existing) = WayfernManager::instance()
+      .find_wayfern_by_profile(&profile_path.to_string_lossy())
+      .await
+      .filter(|instance| instance.is_recovered())
+    {
+      if let Err(e) = browser_runner
+        .check_browser_status(app_handle.clone(), &profile)
+        .await
+      {
+        log::warn!(
+          "Failed to adopt running browser for profile {}: {e}",
+          profile.id
+        );
+      }
+      return Err(
+        serde_json::json!({
+          "code": "PROFILE_RUNNING_EXTERNALLY",
+          "params": { "pid": existing.processId.unwrap_or(0) }
+        })
+        .to_string(),
+      );
+    }
+  }
+
+  let preflight_enabled = crate::settings_manager::SettingsManager::instance()
+    .load_settings()
+    .map(|s| s.preflight_before_launch)
+    .unwrap_or(false);
+  if preflight_enabled {
+    let checks = crate::preflight::run_preflight(&profile).await;
+    if let Some(error) = crate::preflight::launch_blocker(&checks) {
+      log::warn!(
+        "Pre-flight refused launch of profile {}: {error}",
+        profile.id
+      );
+      return Err(error);
+    }
+  }
+
+  // Held until the browser process is spawned or the launch fails, so
+  // later limit changes never reach a launch that is already underway.
+  let launch_permit = crate::launch_queue::LAUNCH_QUEUE
+    .acquire(&profile.id.to_string(), priority)
+    .await?;
+
+  // Keeps the status checker polling at its fast rate until the new browser
+  // is picked up.
+  let _launch_guard = crate::profile::status_checker::track_launch();
+
   // Team lock check: if profile is sync-enabled and user is on a team, acquire lock
   crate::team_lock::acquire_team_lock_if_needed(&profile).await?;
 
@@ -1281,21 +1861,17 @@ pub async fn launch_browser_profile_impl(
       .launch_or_open_url(app_handle.clone(), &profile_for_launch, url, None)
       .await
   };
+  drop(launch_permit);
   let updated_profile = launch_result.map_err(|e| {
     log::info!("Browser launch failed for profile: {}, error: {}", profile_for_launch.name, e);
 
     // Emit a failure event to clear loading states in the frontend
-    #[derive(serde::Serialize)]
-    struct RunningChangedPayload {
-      id: String,
-      is_running: bool,
-    }
-    let payload = RunningChangedPayload {
+    let payload = ProfileRunningChanged {
       id: profile_for_launch.id.to_string(),
       is_running: false,
     };
 
-    if let Err(e) = events::emit("profile-running-changed", &payload) {
+    if let Err(e) = events::emit(&payload) {
       log::warn!("Warning: Failed to emit profile running changed event: {e}");
     }
 
@@ -1314,6 +1890,14 @@ pub async fn launch_browser_profile_impl(
     updated_profile.id
   );
 
+  crate::profile::restart_supervisor::remember_launch(
+    &updated_profile.id.to_string(),
+    crate::profile::restart_supervisor::LaunchOptions {
+      remote_debugging_port,
+      headless,
+    },
+  );
+
   // The proxy PID mapping was already reconciled inside launch_browser_internal
   // (placeholder → real browser PID); nothing is ever keyed by a constant here.
 
@@ -1331,7 +1915,21 @@ pub fn check_browser_exists(browser_str: String, version: String) -> bool {
 
 #[tauri::command]
 pub async fn kill_browser_profile(
-  app_handle: tauri::AppHandle,
+  app_handle: crate::app_handle::AppHandle,
+  profile: BrowserProfile,
+) -> Result<(), String> {
+  let profile_id = profile.id.to_string();
+  crate::audit::audited!(
+    crate::audit::AuditActor::Gui,
+    "kill",
+    "profile",
+    Some(profile_id),
+    kill_browser_profile_impl(app_handle, profile).await
+  )
+}
+
+async fn kill_browser_profile_impl(
+  app_handle: crate::app_handle::AppHandle,
   profile: BrowserProfile,
 ) -> Result<(), String> {
   log::info!(
@@ -1382,12 +1980,12 @@ pub async fn kill_browser_profile(
             )
             .await
           {
-            Ok(updated) => {
-              if !updated.is_empty() {
+            Ok(result) => {
+              if !result.updated_profiles.is_empty() {
                 log::info!(
                   "Auto-updated {} profiles after stop: {:?}",
-                  updated.len(),
-                  updated
+                  result.updated_profiles.len(),
+                  result.updated_profiles
                 );
               }
             }
@@ -1415,18 +2013,13 @@ pub async fn kill_browser_profile(
       log::info!("Failed to kill browser profile {}: {}", profile.name, e);
 
       // Emit a failure event to clear loading states in the frontend
-      #[derive(serde::Serialize)]
-      struct RunningChangedPayload {
-        id: String,
-        is_running: bool,
-      }
       // On kill failure, we assume the process is still running
-      let payload = RunningChangedPayload {
+      let payload = ProfileRunningChanged {
         id: profile.id.to_string(),
         is_running: true,
       };
 
-      if let Err(e) = events::emit("profile-running-changed", &payload) {
+      if let Err(e) = events::emit(&payload) {
         log::warn!("Warning: Failed to emit profile running changed event: {e}");
       }
 
@@ -1437,7 +2030,7 @@ pub async fn kill_browser_profile(
 
 #[tauri::command]
 pub async fn open_url_with_profile(
-  app_handle: tauri::AppHandle,
+  app_handle: crate::app_handle::AppHandle,
   profile_id: String,
   url: String,
 ) -> Result<(), String> {
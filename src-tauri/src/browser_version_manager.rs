@@ -1,6 +1,10 @@
-use crate::api_client::{sort_versions, ApiClient, BrowserRelease};
+use crate::api_client::{sort_versions, ApiClient, BrowserRelease, WayfernFetch};
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
+
+/// Releases kept in a browser's version cache, newest first. Versions a
+/// profile or the downloaded registry still refers to are kept on top.
+const MAX_CACHED_RELEASES: usize = 15;
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct BrowserVersionInfo {
@@ -15,6 +19,15 @@ pub struct BrowserVersionsResult {
   pub total_versions_count: usize,
 }
 
+/// Payload of `browser-versions-updated`, emitted when a refresh changed the
+/// cached version list.
+#[derive(Debug, Serialize, Clone)]
+pub struct BrowserVersionsUpdated {
+  pub browser: String,
+  pub versions: Vec<BrowserVersionInfo>,
+  pub new_versions_count: usize,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct BrowserReleaseTypes {
   pub stable: Option<String>,
@@ -27,14 +40,73 @@ pub struct DownloadInfo {
   pub is_archive: bool, // true for .dmg, .zip, etc.
 }
 
+/// Release channel of a version string. Wayfern only publishes stable builds,
+/// so a version without a pre-release marker is stable.
+pub fn release_type(version: &str) -> &'static str {
+  let lower = version.to_ascii_lowercase();
+  if lower.contains("nightly") || lower.contains("alpha") || lower.contains("dev") {
+    "nightly"
+  } else if lower.contains("beta") || lower.contains("-rc") || lower.contains("rc.") {
+    "beta"
+  } else {
+    "stable"
+  }
+}
+
 pub struct BrowserVersionManager {
   api_client: &'static ApiClient,
+  /// Held for every read-merge-write of the version cache, so the version
+  /// picker's refresh and the background updater can't overwrite each
+  /// other's result.
+  refresh_lock: tokio::sync::Mutex<()>,
+}
+
+/// Merge freshly fetched versions into the cached ones and cap the result.
+/// Returns the list, newest first, and how many fetched versions were new.
+fn merge_versions(
+  existing: &[String],
+  fresh: Vec<String>,
+  pinned: &HashSet<String>,
+  limit: usize,
+) -> (Vec<String>, usize) {
+  let existing_set: HashSet<&String> = existing.iter().collect();
+  let added = fresh.iter().filter(|v| !existing_set.contains(v)).count();
+
+  let mut merged: Vec<String> = existing.iter().cloned().chain(fresh).collect();
+  sort_versions(&mut merged);
+  merged.dedup();
+  let merged = merged
+    .into_iter()
+    .enumerate()
+    .filter(|(index, version)| *index < limit || pinned.contains(version))
+    .map(|(_, version)| version)
+    .collect();
+  (merged, added)
+}
+
+/// Versions that must stay listed: used by a profile or installed.
+fn pinned_versions(browser: &str) -> HashSet<String> {
+  let mut pinned: HashSet<String> =
+    crate::downloaded_browsers_registry::DownloadedBrowsersRegistry::instance()
+      .get_downloaded_versions(browser)
+      .into_iter()
+      .collect();
+  if let Ok(profiles) = crate::profile::ProfileManager::instance().list_profiles() {
+    pinned.extend(
+      profiles
+        .into_iter()
+        .filter(|p| p.browser == browser)
+        .map(|p| p.version),
+    );
+  }
+  pinned
 }
 
 impl BrowserVersionManager {
   fn new() -> Self {
     Self {
       api_client: ApiClient::instance(),
+      refresh_lock: tokio::sync::Mutex::new(()),
     }
   }
 
@@ -162,61 +234,130 @@ impl BrowserVersionManager {
     browser: &str,
     no_caching: bool,
   ) -> Result<BrowserVersionsResult, Box<dyn std::error::Error + Send + Sync>> {
-    // Get existing cached versions to compare and merge
-    let existing_versions = self
-      .api_client
-      .load_cached_versions(browser)
+    if !no_caching {
+      let _guard = self.refresh_lock.lock().await;
+      return self.refresh_cache(browser).await;
+    }
+
+    let existing = self
+      .get_cached_browser_versions(browser)
       .unwrap_or_default();
-    let existing_set: HashSet<String> = existing_versions.into_iter().map(|r| r.version).collect();
+    let (fresh, _) = self
+      .fetch_fresh_versions(browser, None)
+      .await?
+      .unwrap_or_default();
+    let (versions, added) = merge_versions(
+      &existing,
+      fresh,
+      &pinned_versions(browser),
+      MAX_CACHED_RELEASES,
+    );
+    Ok(BrowserVersionsResult {
+      new_versions_count: (!existing.is_empty()).then_some(added),
+      total_versions_count: versions.len(),
+      versions,
+    })
+  }
 
-    // Fetch fresh versions from API
-    let fresh_versions = match browser {
-      "wayfern" => self.fetch_wayfern_versions(true).await?,
-      _ => return Err(format!("Unsupported browser: {browser}").into()),
-    };
+  /// Refresh the cache in the background unless a refresh is already
+  /// running; that one's result is as fresh.
+  pub fn spawn_background_refresh(&'static self, browser: String) {
+    tokio::spawn(async move {
+      let Ok(_guard) = self.refresh_lock.try_lock() else {
+        return;
+      };
+      if let Err(e) = self.refresh_cache(&browser).await {
+        log::error!("Background version update failed for {browser}: {e}");
+      }
+    });
+  }
 
-    let fresh_set: HashSet<String> = fresh_versions.into_iter().collect();
+  /// Fetch the browser's releases, merge them into the cache and emit
+  /// `browser-versions-updated` when the list changed. The only writer of the
+  /// version cache; callers hold `refresh_lock`.
+  async fn refresh_cache(
+    &self,
+    browser: &str,
+  ) -> Result<BrowserVersionsResult, Box<dyn std::error::Error + Send + Sync>> {
+    let cached = self.api_client.load_cached_versions(browser);
+    // A conditional request is only safe when there is a list to fall back on.
+    let cached_etag = cached
+      .as_ref()
+      .and(self.api_client.load_version_cache_meta(browser))
+      .and_then(|meta| meta.etag);
+    let cached = cached.unwrap_or_default();
+    let dates: HashMap<String, String> = cached
+      .iter()
+      .map(|r| (r.version.clone(), r.date.clone()))
+      .collect();
+    let existing: Vec<String> = cached.into_iter().map(|r| r.version).collect();
 
-    // Find new versions (in fresh but not in existing cache)
-    let new_versions: Vec<String> = fresh_set.difference(&existing_set).cloned().collect();
-    let new_versions_count = if existing_set.is_empty() {
-      None
-    } else {
-      Some(new_versions.len())
+    let fetched = self
+      .fetch_fresh_versions(browser, cached_etag.as_deref())
+      .await?;
+    let (fresh, etag) = match fetched {
+      Some(fetched) => fetched,
+      // Unchanged upstream: keep the list, renew its freshness.
+      None => (Vec::new(), cached_etag),
     };
+    let (versions, added) = merge_versions(
+      &existing,
+      fresh,
+      &pinned_versions(browser),
+      MAX_CACHED_RELEASES,
+    );
 
-    // Merge existing and fresh versions
-    let mut merged_versions: Vec<String> = existing_set.union(&fresh_set).cloned().collect();
-
-    // Sort versions using the existing sorting logic
-    crate::api_client::sort_versions(&mut merged_versions);
+    let releases: Vec<BrowserRelease> = versions
+      .iter()
+      .map(|version| BrowserRelease {
+        version: version.clone(),
+        date: dates.get(version).cloned().unwrap_or_default(),
+      })
+      .collect();
+    if let Err(e) = self
+      .api_client
+      .save_cached_versions(browser, &releases, etag)
+    {
+      log::error!("Failed to save version cache for {browser}: {e}");
+    }
 
-    // Save the merged cache (unless explicitly bypassing cache)
-    if !no_caching {
-      let merged_releases: Vec<BrowserRelease> = merged_versions
-        .iter()
-        .map(|v| BrowserRelease {
-          version: v.clone(),
-          date: "".to_string(),
-        })
-        .collect();
-      if let Err(e) = self
-        .api_client
-        .save_cached_versions(browser, &merged_releases)
-      {
-        log::error!("Failed to save merged cache for {browser}: {e}");
+    if versions != existing {
+      let payload = BrowserVersionsUpdated {
+        browser: browser.to_string(),
+        versions: releases
+          .into_iter()
+          .map(|r| BrowserVersionInfo {
+            version: r.version,
+            date: r.date,
+          })
+          .collect(),
+        new_versions_count: added,
+      };
+      if let Err(e) = crate::events::emit(&payload) {
+        log::warn!("Failed to emit browser-versions-updated: {e}");
       }
     }
 
-    let total_versions_count = merged_versions.len();
-
     Ok(BrowserVersionsResult {
-      versions: merged_versions,
-      new_versions_count,
-      total_versions_count,
+      new_versions_count: (!existing.is_empty()).then_some(added),
+      total_versions_count: versions.len(),
+      versions,
     })
   }
 
+  /// Versions upstream currently publishes for this platform, with the
+  /// response's ETag. `None` when `etag` still matches.
+  async fn fetch_fresh_versions(
+    &self,
+    browser: &str,
+    etag: Option<&str>,
+  ) -> Result<Option<(Vec<String>, Option<String>)>, Box<dyn std::error::Error + Send + Sync>> {
+    match browser {
+      "wayfern" => self.fetch_wayfern_versions(etag).await,
+      _ => Err(format!("Unsupported browser: {browser}").into()),
+    }
+  }
+
   /// Fetch detailed browser version information with optional caching
   pub async fn fetch_browser_versions_detailed(
     &self,
@@ -247,45 +388,19 @@ impl BrowserVersionManager {
     Ok(detailed_info)
   }
 
-  /// Update browser versions incrementally (for background updates)
+  /// Update browser versions incrementally (for background updates).
+  /// Returns how many versions were new.
   pub async fn update_browser_versions_incrementally(
     &self,
     browser: &str,
   ) -> Result<usize, Box<dyn std::error::Error + Send + Sync>> {
-    // Get existing cached versions
-    let existing_versions = self
-      .api_client
-      .load_cached_versions(browser)
-      .unwrap_or_default();
-    let existing_set: HashSet<String> = existing_versions.into_iter().map(|r| r.version).collect();
-
-    // Fetch new versions (always bypass cache for background updates)
-    let new_versions = self.fetch_browser_versions(browser, true).await?;
-    let new_set: HashSet<String> = new_versions.into_iter().collect();
-
-    // Find truly new versions (not in existing cache)
-    let really_new_versions: Vec<String> = new_set.difference(&existing_set).cloned().collect();
-    let new_versions_count = really_new_versions.len();
-
-    // Merge existing and new versions
-    let mut all_versions: Vec<String> = existing_set.union(&new_set).cloned().collect();
-
-    // Sort versions using the existing sorting logic
-    sort_versions(&mut all_versions);
-
-    // Save the updated cache
-    let releases: Vec<BrowserRelease> = all_versions
-      .iter()
-      .map(|v| BrowserRelease {
-        version: v.clone(),
-        date: "".to_string(),
-      })
-      .collect();
-    if let Err(e) = self.api_client.save_cached_versions(browser, &releases) {
-      log::error!("Failed to save updated cache for {browser}: {e}");
-    }
-
-    Ok(new_versions_count)
+    let _guard = self.refresh_lock.lock().await;
+    let result = self.refresh_cache(browser).await?;
+    Ok(
+      result
+        .new_versions_count
+        .unwrap_or(result.total_versions_count),
+    )
   }
 
   /// Get download information for a specific browser and version
@@ -351,22 +466,22 @@ impl BrowserVersionManager {
 
   async fn fetch_wayfern_versions(
     &self,
-    no_caching: bool,
-  ) -> Result<Vec<String>, Box<dyn std::error::Error + Send + Sync>> {
-    let version_info = self
-      .api_client
-      .fetch_wayfern_version_with_caching(no_caching)
-      .await?;
+    etag: Option<&str>,
+  ) -> Result<Option<(Vec<String>, Option<String>)>, Box<dyn std::error::Error + Send + Sync>> {
+    let (version_info, etag) = match self.api_client.fetch_wayfern_version(etag).await? {
+      WayfernFetch::NotModified => return Ok(None),
+      WayfernFetch::Fetched { info, etag } => (info, etag),
+    };
 
     // Check if current platform has a download available
     if self
       .api_client
       .has_wayfern_compatible_download(&version_info)
     {
-      Ok(vec![version_info.version])
+      Ok(Some((vec![version_info.version], etag)))
     } else {
       // No compatible download for current platform
-      Ok(vec![])
+      Ok(Some((vec![], etag)))
     }
   }
 }
@@ -409,6 +524,33 @@ mod tests {
     }
   }
 
+  #[test]
+  fn merged_versions_are_capped_except_pinned_ones() {
+    let existing: Vec<String> = (1..=5).rev().map(|v| format!("{v}.0")).collect();
+    let pinned = HashSet::from(["1.0".to_string()]);
+
+    let (versions, added) = merge_versions(
+      &existing,
+      vec!["6.0".to_string(), "5.0".to_string()],
+      &pinned,
+      3,
+    );
+    assert_eq!(versions, ["6.0", "5.0", "4.0", "1.0"]);
+    assert_eq!(added, 1);
+
+    let (versions, added) = merge_versions(&[], vec!["2.0".to_string()], &HashSet::new(), 3);
+    assert_eq!(versions, ["2.0"]);
+    assert_eq!(added, 1);
+  }
+
+  #[test]
+  fn test_release_type() {
+    assert_eq!(release_type("146.0.7680.31"), "stable");
+    assert_eq!(release_type("147.0.1-beta.2"), "beta");
+    assert_eq!(release_type("147.0.1-rc.1"), "beta");
+    assert_eq!(release_type("148.0.0-nightly.20260101"), "nightly");
+  }
+
   #[test]
   fn test_get_download_info() {
     let service = BrowserVersionManager::instance();
@@ -480,19 +622,10 @@ pub async fn fetch_browser_versions_cached_first(
 
   // Get cached versions immediately if available
   if let Some(cached_versions) = service.get_cached_browser_versions_detailed(&browser_str) {
-    // Check if we should update cache in background
+    // Stale-while-revalidate: the refresh announces its result with
+    // `browser-versions-updated`.
     if service.should_update_cache(&browser_str) {
-      // Start background update but return cached data immediately
-      let service_clone = BrowserVersionManager::instance();
-      let browser_str_clone = browser_str.clone();
-      tokio::spawn(async move {
-        if let Err(e) = service_clone
-          .fetch_browser_versions_detailed(&browser_str_clone, false)
-          .await
-        {
-          log::error!("Background version update failed for {browser_str_clone}: {e}");
-        }
-      });
+      service.spawn_background_refresh(browser_str);
     }
     Ok(cached_versions)
   } else {
@@ -512,19 +645,8 @@ pub async fn fetch_browser_versions_with_count_cached_first(
 
   // Get cached versions immediately if available
   if let Some(cached_versions) = service.get_cached_browser_versions(&browser_str) {
-    // Check if we should update cache in background
     if service.should_update_cache(&browser_str) {
-      // Start background update but return cached data immediately
-      let service_clone = BrowserVersionManager::instance();
-      let browser_str_clone = browser_str.clone();
-      tokio::spawn(async move {
-        if let Err(e) = service_clone
-          .fetch_browser_versions_with_count(&browser_str_clone, false)
-          .await
-        {
-          log::error!("Background version update failed for {browser_str_clone}: {e}");
-        }
-      });
+      service.spawn_background_refresh(browser_str.clone());
     }
 
     // Return cached data in the expected format
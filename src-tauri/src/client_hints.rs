@@ -0,0 +1,135 @@
+//! User-Agent Client Hints for generated Wayfern fingerprints.
+//!
+//! Wayfern reports `navigator.userAgentData` and sends the `Sec-CH-UA*`
+//! headers from the fingerprint's `brand`, `brandVersion` and
+//! `platformVersion`, but samples those independently of `userAgent`. A
+//! `Chrome/131` user agent next to brand version `129`, or a Windows
+//! fingerprint reporting a macOS platform version, is exactly what bot
+//! detection compares. [`apply_client_hints`] rewrites the three fields so
+//! they agree with the user agent and the OS the fingerprint was made for.
+
+use serde_json::json;
+
+/// `platformVersion` values Chromium reports: Windows 10 is `10.0.0` and
+/// Windows 11 starts at `13.0.0`; macOS and Linux report the OS and kernel
+/// versions.
+fn platform_versions(os: &str) -> &'static [&'static str] {
+  match os {
+    "windows" => &["10.0.0", "15.0.0", "19.0.0"],
+    "macos" => &["13.6.9", "14.6.1", "14.7.1", "15.1.1", "15.3.2"],
+    "linux" => &["6.5.0", "6.8.0", "6.11.0"],
+    _ => &[],
+  }
+}
+
+/// The branded browser a Chromium user agent claims and its version, e.g.
+/// `("Microsoft Edge", "131.0.2903.86")`. `None` for non-Chromium agents.
+fn ua_brand(user_agent: &str) -> Option<(&'static str, &str)> {
+  let version_after = |token: &str| {
+    user_agent
+      .split(token)
+      .nth(1)
+      .and_then(|rest| rest.split_whitespace().next())
+  };
+  let chrome = version_after("Chrome/")?;
+  match version_after("Edg/") {
+    Some(edge) => Some(("Microsoft Edge", edge)),
+    None => Some(("Google Chrome", chrome)),
+  }
+}
+
+fn major(version: &str) -> Option<u32> {
+  version.split('.').next()?.parse().ok()
+}
+
+/// Whether `version` looks like a `platformVersion` Chromium reports on `os`.
+fn platform_version_matches_os(version: &str, os: &str) -> bool {
+  let Some(major) = major(version) else {
+    return false;
+  };
+  match os {
+    "windows" => major == 10 || (13..=30).contains(&major),
+    "macos" => (11..=30).contains(&major),
+    "linux" => (4..=10).contains(&major),
+    _ => true,
+  }
+}
+
+/// Makes the fingerprint's client hints agree with its `userAgent` and `os`:
+/// the brand and its major version come from the user agent (a full
+/// `brandVersion` of the same major is kept), and a `platformVersion` that
+/// doesn't fit `os` is replaced with one picked by `seed`. Fingerprints
+/// without a Chromium user agent are left untouched.
+pub fn apply_client_hints(os: &str, fingerprint: &mut serde_json::Value, seed: u64) {
+  let Some(obj) = fingerprint.as_object_mut() else {
+    return;
+  };
+  let Some(user_agent) = obj.get("userAgent").and_then(|v| v.as_str()) else {
+    return;
+  };
+  let Some((brand, ua_version)) = ua_brand(user_agent) else {
+    return;
+  };
+  let ua_version = ua_version.to_string();
+
+  let brand_version = obj
+    .get("brandVersion")
+    .and_then(|v| v.as_str())
+    .filter(|v| major(v).is_some() && major(v) == major(&ua_version))
+    .map(str::to_string)
+    .unwrap_or(ua_version);
+  obj.insert("brand".to_string(), json!(brand));
+  obj.insert("brandVersion".to_string(), json!(brand_version));
+
+  let platform_version_ok = obj
+    .get("platformVersion")
+    .and_then(|v| v.as_str())
+    .is_some_and(|v| platform_version_matches_os(v, os));
+  let candidates = platform_versions(os);
+  if !platform_version_ok && !candidates.is_empty() {
+    let pick = candidates[(seed % candidates.len() as u64) as usize];
+    obj.insert("platformVersion".to_string(), json!(pick));
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const CHROME_WINDOWS: &str = "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/131.0.0.0 Safari/537.36";
+
+  #[test]
+  fn brand_major_follows_the_user_agent() {
+    let mut fp = json!({
+      "userAgent": CHROME_WINDOWS,
+      "brand": "Google Chrome",
+      "brandVersion": "129.0.6668.100",
+      "platformVersion": "14.6.1",
+    });
+    apply_client_hints("windows", &mut fp, 7);
+    assert_eq!(fp["brandVersion"], "131.0.0.0");
+    assert!(platform_versions("windows").contains(&fp["platformVersion"].as_str().unwrap()));
+
+    let mut fp = json!({ "userAgent": CHROME_WINDOWS, "brandVersion": "131.0.6778.86" });
+    apply_client_hints("windows", &mut fp, 0);
+    assert_eq!(fp["brand"], "Google Chrome");
+    assert_eq!(fp["brandVersion"], "131.0.6778.86");
+  }
+
+  #[test]
+  fn edge_is_branded_as_edge_and_firefox_is_untouched() {
+    let edge = format!("{CHROME_WINDOWS} Edg/131.0.2903.86");
+    let mut fp = json!({ "userAgent": edge, "platformVersion": "15.0.0" });
+    apply_client_hints("windows", &mut fp, 0);
+    assert_eq!(fp["brand"], "Microsoft Edge");
+    assert_eq!(fp["brandVersion"], "131.0.2903.86");
+    assert_eq!(fp["platformVersion"], "15.0.0");
+
+    let firefox = json!({
+      "userAgent": "Mozilla/5.0 (X11; Linux x86_64; rv:133.0) Gecko/20100101 Firefox/133.0",
+    });
+    let mut fp = firefox.clone();
+    apply_client_hints("linux", &mut fp, 0);
+    assert_eq!(fp, firefox);
+  }
+}
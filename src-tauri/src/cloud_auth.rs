@@ -964,6 +964,8 @@ impl CloudAuthManager {
           port: config.port,
           username: config.username,
           password: config.password,
+          client_cert_path: None,
+          client_key_path: None,
         };
         match PROXY_MANAGER.upsert_cloud_proxy(settings) {
           Ok(_) => {
@@ -1248,12 +1250,12 @@ impl CloudAuthManager {
   }
 
   /// Background loop that refreshes the sync token periodically
-  pub async fn start_sync_token_refresh_loop(app_handle: tauri::AppHandle) {
+  pub async fn start_sync_token_refresh_loop(app_handle: crate::app_handle::AppHandle) {
     let mut wayfern_refresh_counter: u32 = 0;
     loop {
       tokio::time::sleep(std::time::Duration::from_secs(600)).await; // 10 minutes
 
-      if !CLOUD_AUTH.is_logged_in().await {
+      if crate::offline::is_offline() || !CLOUD_AUTH.is_logged_in().await {
         continue;
       }
 
@@ -1358,7 +1360,7 @@ fn has_leading_zero_bits(digest: &[u8], bits: u32) -> bool {
 
 #[tauri::command]
 pub async fn cloud_exchange_device_code(
-  app_handle: tauri::AppHandle,
+  app_handle: crate::app_handle::AppHandle,
   code: String,
 ) -> Result<CloudAuthState, String> {
   let mut state = CLOUD_AUTH.exchange_device_code(&code).await?;
@@ -1418,7 +1420,7 @@ pub async fn cloud_refresh_profile() -> Result<CloudUser, String> {
 }
 
 #[tauri::command]
-pub async fn cloud_logout(app_handle: tauri::AppHandle) -> Result<(), String> {
+pub async fn cloud_logout(app_handle: crate::app_handle::AppHandle) -> Result<(), String> {
   CLOUD_AUTH.logout().await?;
 
   // Always clear the stored sync URL and token on cloud logout. While the
@@ -1609,7 +1611,7 @@ pub async fn cloud_get_proxy_usage() -> Result<Option<CloudProxyUsage>, String>
 }
 
 #[tauri::command]
-pub async fn restart_sync_service(app_handle: tauri::AppHandle) -> Result<(), String> {
+pub async fn restart_sync_service(app_handle: crate::app_handle::AppHandle) -> Result<(), String> {
   // Stop existing scheduler
   if let Some(scheduler) = sync::get_global_scheduler() {
     scheduler.stop();
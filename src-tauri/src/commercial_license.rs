@@ -1,6 +1,6 @@
+use crate::app_handle::AppHandle;
 use serde::{Deserialize, Serialize};
 use std::time::{SystemTime, UNIX_EPOCH};
-use tauri::AppHandle;
 
 use crate::events;
 use crate::settings_manager::SettingsManager;
@@ -81,7 +81,7 @@ impl CommercialLicenseManager {
     log::info!("First launch timestamp recorded: {now}");
 
     // Emit event to notify frontend
-    if let Err(e) = events::emit("first-launch-recorded", now) {
+    if let Err(e) = events::emit(&events::payloads::FirstLaunchRecorded(now)) {
       log::warn!("Failed to emit first-launch-recorded event: {e}");
     }
 
@@ -1,3 +1,4 @@
+use crate::app_handle::AppHandle;
 use crate::profile::manager::ProfileManager;
 use crate::profile::BrowserProfile;
 use rusqlite::{params, Connection, OpenFlags};
@@ -5,7 +6,6 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
-use tauri::AppHandle;
 
 /// Chromium cookie decryption support for reading existing encrypted cookies.
 /// Writes always go through the plaintext `value` column (see `write_chrome_cookies`),
@@ -184,6 +184,20 @@ pub struct CookieImportResult {
   pub errors: Vec<String>,
 }
 
+/// Whether a Wayfern profile's stored cookies can be decrypted after the
+/// profile directory is copied to another machine.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CookieKeyPortability {
+  /// No cookie is stored encrypted, so there is no key to carry over.
+  NoEncryptedCookies,
+  /// The profile-scoped `os_crypt_key` decrypts the stored cookies and
+  /// travels with the profile directory.
+  Portable,
+  /// Cookies were encrypted with key material outside the profile directory
+  /// (OS keychain, DPAPI) and read back as garbage anywhere else.
+  MachineBound,
+}
+
 pub struct CookieManager;
 
 impl CookieManager {
@@ -207,6 +221,36 @@ impl CookieManager {
     }
   }
 
+  /// Check whether the profile's `os_crypt_key` decrypts a sample of its
+  /// encrypted cookies. Wayfern reads that file instead of the OS keychain,
+  /// so a key that works here works on every machine the profile lands on.
+  pub fn cookie_key_portability(profile_data_path: &Path) -> CookieKeyPortability {
+    let cookies_path = Self::wayfern_cookie_path(profile_data_path);
+    let Ok(conn) = Connection::open_with_flags(&cookies_path, OpenFlags::SQLITE_OPEN_READ_ONLY)
+    else {
+      return CookieKeyPortability::NoEncryptedCookies;
+    };
+    let sample: Option<(String, Vec<u8>)> = conn
+      .query_row(
+        "SELECT host_key, encrypted_value FROM cookies WHERE length(encrypted_value) > 0 LIMIT 1",
+        [],
+        |row| Ok((row.get(0)?, row.get(1)?)),
+      )
+      .ok();
+    let Some((host, encrypted)) = sample else {
+      return CookieKeyPortability::NoEncryptedCookies;
+    };
+
+    let decrypts = chrome_decrypt::get_encryption_key(profile_data_path)
+      .and_then(|key| chrome_decrypt::decrypt(&encrypted, &host, &key))
+      .is_some();
+    if decrypts {
+      CookieKeyPortability::Portable
+    } else {
+      CookieKeyPortability::MachineBound
+    }
+  }
+
   /// Get the cookie database path for a profile (read-side: errors if missing).
   fn get_cookie_db_path(profile: &BrowserProfile, profiles_dir: &Path) -> Result<PathBuf, String> {
     let profile_data_path = profile.get_profile_data_path(profiles_dir);
@@ -1338,6 +1382,78 @@ mod tests {
       .unwrap();
   }
 
+  fn insert_encrypted_cookie(db: &Path, host: &str, encrypted: &[u8]) {
+    let conn = Connection::open(db).unwrap();
+    conn
+      .execute(
+        "INSERT INTO cookies (creation_utc, host_key, top_frame_site_key, name, value,
+           encrypted_value, path, expires_utc, is_secure, is_httponly, last_access_utc)
+         VALUES (0, ?1, '', 'sid', '', ?2, '/', 0, 1, 1, 0)",
+        params![host, encrypted],
+      )
+      .unwrap();
+  }
+
+  fn wayfern_profile_with_cookie_db() -> (PathBuf, PathBuf) {
+    let profile_dir =
+      std::env::temp_dir().join(format!("donut_key_portability_{}", uuid::Uuid::new_v4()));
+    let db = CookieManager::wayfern_cookie_path(&profile_dir);
+    std::fs::create_dir_all(db.parent().unwrap()).unwrap();
+    create_chrome_cookies_db(&db);
+    (profile_dir, db)
+  }
+
+  #[test]
+  fn test_cookie_key_portability_without_encrypted_cookies() {
+    let missing = std::env::temp_dir().join(format!("donut_no_profile_{}", uuid::Uuid::new_v4()));
+    assert_eq!(
+      CookieManager::cookie_key_portability(&missing),
+      CookieKeyPortability::NoEncryptedCookies
+    );
+
+    let (profile_dir, _db) = wayfern_profile_with_cookie_db();
+    assert_eq!(
+      CookieManager::cookie_key_portability(&profile_dir),
+      CookieKeyPortability::NoEncryptedCookies
+    );
+    let _ = std::fs::remove_dir_all(&profile_dir);
+  }
+
+  #[test]
+  fn test_cookie_key_portability_flags_undecryptable_cookies() {
+    let (profile_dir, db) = wayfern_profile_with_cookie_db();
+    insert_encrypted_cookie(&db, ".example.test", b"v10not-a-real-ciphertext!");
+    assert_eq!(
+      CookieManager::cookie_key_portability(&profile_dir),
+      CookieKeyPortability::MachineBound
+    );
+
+    // A key file that doesn't match the cookies is no better than none.
+    std::fs::write(profile_dir.join("os_crypt_key"), b"some-other-key").unwrap();
+    assert_eq!(
+      CookieManager::cookie_key_portability(&profile_dir),
+      CookieKeyPortability::MachineBound
+    );
+    let _ = std::fs::remove_dir_all(&profile_dir);
+  }
+
+  #[test]
+  #[cfg(target_os = "macos")]
+  fn test_cookie_key_portability_with_profile_key() {
+    let (profile_dir, db) = wayfern_profile_with_cookie_db();
+    insert_encrypted_cookie(&db, SYNTHETIC_COOKIE_HOST, &synthetic_encrypted_cookie());
+    std::fs::write(
+      profile_dir.join("os_crypt_key"),
+      SYNTHETIC_OS_CRYPT_PASSWORD,
+    )
+    .unwrap();
+    assert_eq!(
+      CookieManager::cookie_key_portability(&profile_dir),
+      CookieKeyPortability::Portable
+    );
+    let _ = std::fs::remove_dir_all(&profile_dir);
+  }
+
   #[test]
   fn test_write_chrome_cookies_stores_plaintext_values() {
     let tmp = std::env::temp_dir().join(format!("donut_cookie_test_{}.db", uuid::Uuid::new_v4()));
@@ -0,0 +1,187 @@
+//! `donut://` action links, so OS-level shortcuts can drive a profile without
+//! going through the profile selector:
+//!
+//! - `donut://launch-profile/<uuid>[?url=<url>]`
+//! - `donut://kill-profile/<uuid>`
+
+use crate::profile::BrowserProfile;
+
+pub const SCHEME: &str = "donut";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeepLinkAction {
+  LaunchProfile {
+    profile_id: uuid::Uuid,
+    url: Option<String>,
+  },
+  KillProfile {
+    profile_id: uuid::Uuid,
+  },
+}
+
+impl DeepLinkAction {
+  fn profile_id(&self) -> uuid::Uuid {
+    match self {
+      DeepLinkAction::LaunchProfile { profile_id, .. }
+      | DeepLinkAction::KillProfile { profile_id } => *profile_id,
+    }
+  }
+}
+
+/// Whether `link` uses our scheme, as opposed to an http(s) URL to open.
+pub fn is_action_link(link: &str) -> bool {
+  link
+    .get(..SCHEME.len() + 3)
+    .is_some_and(|prefix| prefix.eq_ignore_ascii_case(&format!("{SCHEME}://")))
+}
+
+/// Parses a `donut://` link. `None` means it isn't an action this build knows
+/// (or not our scheme at all) and should take the regular URL-open path.
+pub fn parse(link: &str) -> Option<Result<DeepLinkAction, String>> {
+  let url = url::Url::parse(link).ok()?;
+  if url.scheme() != SCHEME {
+    return None;
+  }
+  // For `donut://launch-profile/<id>` the action is parsed as the host.
+  let action = url.host_str()?.to_ascii_lowercase();
+  let profile_id = || {
+    uuid::Uuid::parse_str(url.path().trim_matches('/'))
+      .map_err(|_| serde_json::json!({ "code": "INVALID_PROFILE_ID" }).to_string())
+  };
+
+  match action.as_str() {
+    "launch-profile" => Some(profile_id().and_then(|profile_id| {
+      let target = url
+        .query_pairs()
+        .find(|(key, _)| key == "url")
+        .map(|(_, value)| value.into_owned());
+      let url = match target {
+        Some(target) => crate::profile::manager::validate_startup_urls(vec![target])?
+          .into_iter()
+          .next(),
+        None => None,
+      };
+      Ok(DeepLinkAction::LaunchProfile { profile_id, url })
+    })),
+    "kill-profile" => {
+      Some(profile_id().map(|profile_id| DeepLinkAction::KillProfile { profile_id }))
+    }
+    _ => None,
+  }
+}
+
+fn find_profile(profile_id: uuid::Uuid) -> Result<BrowserProfile, String> {
+  crate::profile::ProfileManager::instance()
+    .list_profiles()
+    .map_err(|e| format!("Failed to list profiles: {e}"))?
+    .into_iter()
+    .find(|p| p.id == profile_id)
+    .ok_or_else(|| serde_json::json!({ "code": "PROFILE_NOT_FOUND" }).to_string())
+}
+
+async fn perform(app_handle: &tauri::AppHandle, action: DeepLinkAction) -> Result<(), String> {
+  let profile = find_profile(action.profile_id())?;
+  match action {
+    DeepLinkAction::LaunchProfile { url, .. } => {
+      log::info!("Deep link launching profile {}", profile.id);
+      crate::browser_runner::launch_browser_profile_impl(
+        app_handle.into(),
+        profile,
+        url,
+        None,
+        false,
+        false,
+        crate::launch_queue::LaunchPriority::Interactive,
+      )
+      .await
+      .map(|_| ())
+    }
+    DeepLinkAction::KillProfile { .. } => {
+      log::info!("Deep link stopping profile {}", profile.id);
+      crate::browser_runner::kill_browser_profile(app_handle.into(), profile).await
+    }
+  }
+}
+
+/// Runs a parsed action, reporting a failure to the frontend instead of
+/// returning it: nobody is waiting on a link opened from the OS.
+pub async fn handle_action(
+  app_handle: &tauri::AppHandle,
+  link: &str,
+  parsed: Result<DeepLinkAction, String>,
+) {
+  let result = match parsed {
+    Ok(action) => perform(app_handle, action).await,
+    Err(e) => Err(e),
+  };
+  if let Err(error) = result {
+    log::error!("Deep link action failed: {error}");
+    let payload = crate::events::payloads::DeepLinkActionFailed {
+      link: link.to_string(),
+      error,
+    };
+    if let Err(e) = crate::events::emit(&payload) {
+      log::warn!("Failed to emit deep-link-action-failed: {e}");
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const ID: &str = "3f2b8c1e-5d4a-4b6f-9e7d-1a2b3c4d5e6f";
+
+  #[test]
+  fn parses_launch_with_and_without_url() {
+    let profile_id = uuid::Uuid::parse_str(ID).unwrap();
+    assert_eq!(
+      parse(&format!("donut://launch-profile/{ID}"))
+        .unwrap()
+        .unwrap(),
+      DeepLinkAction::LaunchProfile {
+        profile_id,
+        url: None
+      }
+    );
+    assert_eq!(
+      parse(&format!(
+        "donut://launch-profile/{ID}?url=https%3A%2F%2Fexample.com%2Fa%3Fb%3D1"
+      ))
+      .unwrap()
+      .unwrap(),
+      DeepLinkAction::LaunchProfile {
+        profile_id,
+        url: Some("https://example.com/a?b=1".to_string())
+      }
+    );
+    assert_eq!(
+      parse(&format!("donut://kill-profile/{ID}/"))
+        .unwrap()
+        .unwrap(),
+      DeepLinkAction::KillProfile { profile_id }
+    );
+  }
+
+  #[test]
+  fn rejects_bad_ids_and_urls() {
+    let bad_id = parse("donut://kill-profile/not-a-uuid")
+      .unwrap()
+      .unwrap_err();
+    assert!(bad_id.contains("INVALID_PROFILE_ID"));
+    let bad_url = parse(&format!(
+      "donut://launch-profile/{ID}?url=file:///etc/passwd"
+    ))
+    .unwrap()
+    .unwrap_err();
+    assert!(bad_url.contains("INVALID_STARTUP_URL"));
+  }
+
+  #[test]
+  fn other_links_fall_through() {
+    assert!(parse("https://example.com").is_none());
+    assert!(parse(&format!("donut://open-settings/{ID}")).is_none());
+    assert!(is_action_link("DONUT://kill-profile/x"));
+    assert!(!is_action_link("https://example.com"));
+  }
+}
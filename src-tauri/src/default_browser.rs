@@ -378,140 +378,233 @@ mod windows {
 }
 
 #[cfg(target_os = "linux")]
-mod linux {
+pub(crate) mod linux {
+  use std::path::{Path, PathBuf};
   use std::process::Command;
 
-  const APP_DESKTOP_NAME: &str = "donutbrowser.desktop";
+  const DESKTOP_TEMPLATE: &str = include_str!("../donutbrowser.desktop");
+  const SCHEMES: [&str; 2] = ["http", "https"];
 
-  pub fn is_default_browser() -> Result<bool, String> {
-    // Check if xdg-mime is available
-    if !is_xdg_mime_available() {
-      return Err("xdg-mime utility not found. Please install xdg-utils package.".to_string());
+  /// Desktop file name the app registers under. Dev builds use their own so
+  /// they never take over the installed release's associations.
+  pub fn desktop_file_name() -> &'static str {
+    if cfg!(debug_assertions) {
+      "donutbrowser-dev.desktop"
+    } else {
+      "donutbrowser.desktop"
     }
+  }
 
-    let schemes = ["http", "https"];
+  fn user_applications_dir() -> Option<PathBuf> {
+    std::env::var_os("XDG_DATA_HOME")
+      .filter(|v| !v.is_empty())
+      .map(PathBuf::from)
+      .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".local/share")))
+      .map(|data| data.join("applications"))
+  }
 
-    for scheme in schemes {
-      let mime_type = format!("x-scheme-handler/{}", scheme);
+  fn desktop_file_locations() -> Vec<PathBuf> {
+    let mut dirs: Vec<PathBuf> = user_applications_dir().into_iter().collect();
+    let data_dirs = std::env::var("XDG_DATA_DIRS")
+      .ok()
+      .filter(|v| !v.is_empty())
+      .unwrap_or_else(|| "/usr/local/share:/usr/share".to_string());
+    dirs.extend(
+      data_dirs
+        .split(':')
+        .filter(|d| !d.is_empty())
+        .map(|d| Path::new(d).join("applications")),
+    );
+    dirs.push(PathBuf::from("/var/lib/flatpak/exports/share/applications"));
+    if let Some(home) = std::env::var_os("HOME") {
+      dirs.push(PathBuf::from(home).join(".local/share/flatpak/exports/share/applications"));
+    }
+    dirs
+  }
 
-      // Query the current default handler for this scheme
-      let output = Command::new("xdg-mime")
-        .args(["query", "default", &mime_type])
-        .output()
-        .map_err(|e| format!("Failed to query default handler for {}: {}", scheme, e))?;
+  fn desktop_file_installed() -> bool {
+    desktop_file_locations()
+      .iter()
+      .any(|dir| dir.join(desktop_file_name()).exists())
+  }
 
-      if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        return Err(format!("xdg-mime query failed for {}: {}", scheme, stderr));
+  /// Quote an Exec= argument per the Desktop Entry spec.
+  fn quote_exec_arg(arg: &str) -> String {
+    let mut quoted = String::with_capacity(arg.len() + 2);
+    quoted.push('"');
+    for c in arg.chars() {
+      if matches!(c, '"' | '`' | '$' | '\\') {
+        quoted.push('\\');
       }
+      quoted.push(c);
+    }
+    quoted.push('"');
+    quoted
+  }
 
-      let current_handler = String::from_utf8_lossy(&output.stdout).trim().to_string();
-
-      // Check if our app is the default handler
-      if current_handler != APP_DESKTOP_NAME {
-        return Ok(false);
+  /// The shipped desktop entry with `Exec=` pointing at `exec_path`.
+  fn desktop_entry_contents(exec_path: &str) -> String {
+    let exec = format!("Exec={} %u", quote_exec_arg(exec_path));
+    let mut out = String::with_capacity(DESKTOP_TEMPLATE.len() + exec.len());
+    for line in DESKTOP_TEMPLATE.lines() {
+      if line.starts_with("Exec=") {
+        out.push_str(&exec);
+      } else if cfg!(debug_assertions) && line.starts_with("Name=") {
+        out.push_str("Name=Donut Dev");
+      } else {
+        out.push_str(line);
       }
+      out.push('\n');
     }
+    out
+  }
 
-    Ok(true)
+  /// Path the desktop entry should launch: the AppImage itself when running
+  /// from one (the current exe lives in a throwaway mount), else this binary.
+  fn launch_path() -> Result<String, String> {
+    if let Some(appimage) = std::env::var_os("APPIMAGE").filter(|v| !v.is_empty()) {
+      return Ok(appimage.to_string_lossy().into_owned());
+    }
+    std::env::current_exe()
+      .map(|p| p.to_string_lossy().into_owned())
+      .map_err(|e| format!("Failed to get current executable path: {e}"))
   }
 
-  pub fn set_as_default_browser() -> Result<(), String> {
-    // Check if xdg-mime is available
-    if !is_xdg_mime_available() {
-      return Err("xdg-mime utility not found. Please install xdg-utils package.".to_string());
+  /// Write our desktop entry into the user's applications dir. Done for
+  /// AppImages (which ship no installed entry) and for any install whose
+  /// entry is missing, so xdg can resolve the handler at all.
+  pub fn install_desktop_entry() -> Result<PathBuf, String> {
+    let dir = user_applications_dir()
+      .ok_or_else(|| "Could not determine the user applications directory".to_string())?;
+    std::fs::create_dir_all(&dir)
+      .map_err(|e| format!("Failed to create {}: {e}", dir.display()))?;
+    let path = dir.join(desktop_file_name());
+    let contents = desktop_entry_contents(&launch_path()?);
+    if std::fs::read_to_string(&path).ok().as_deref() != Some(contents.as_str()) {
+      std::fs::write(&path, contents)
+        .map_err(|e| format!("Failed to write {}: {e}", path.display()))?;
+      // Refresh the MIME cache so x-scheme-handler lookups see the entry.
+      let _ = Command::new("update-desktop-database").arg(&dir).output();
+    }
+    Ok(path)
+  }
+
+  /// Keep an AppImage's desktop entry current so links opened while the app
+  /// isn't running still launch it. Never changes the default browser.
+  pub fn ensure_appimage_desktop_entry() {
+    if std::env::var_os("APPIMAGE").is_none() {
+      return;
+    }
+    if let Err(e) = install_desktop_entry() {
+      log::warn!("Failed to install AppImage desktop entry: {e}");
     }
+  }
 
-    // Check if the desktop file exists in common locations
-    if !check_desktop_file_exists() {
+  fn command_available(name: &str) -> bool {
+    Command::new("which")
+      .arg(name)
+      .output()
+      .map(|output| output.status.success())
+      .unwrap_or(false)
+  }
+
+  fn run(cmd: &str, args: &[&str]) -> Result<String, String> {
+    let output = Command::new(cmd)
+      .args(args)
+      .output()
+      .map_err(|e| format!("Failed to run {cmd}: {e}"))?;
+    if !output.status.success() {
       return Err(format!(
-        "Desktop file '{}' not found in standard locations. Please ensure the application is properly installed. You can manually set Donut Browser as the default browser in your system settings.",
-        APP_DESKTOP_NAME
+        "{cmd} {} failed: {}",
+        args.join(" "),
+        String::from_utf8_lossy(&output.stderr).trim()
       ));
     }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+  }
 
-    let schemes = ["http", "https"];
-    let mut all_succeeded = true;
-    let mut error_messages = Vec::new();
+  pub fn is_default_browser() -> Result<bool, String> {
+    let name = desktop_file_name();
 
-    for scheme in schemes {
-      let mime_type = format!("x-scheme-handler/{}", scheme);
-
-      // Set our app as the default handler for this scheme
-      let output = Command::new("xdg-mime")
-        .args(["default", APP_DESKTOP_NAME, &mime_type])
-        .output()
-        .map_err(|e| format!("Failed to set default handler for {}: {}", scheme, e))?;
-
-      if !output.status.success() {
-        all_succeeded = false;
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        error_messages.push(format!("Failed to set default for {}: {}", scheme, stderr));
+    // xdg-settings asks the desktop environment itself, which is what
+    // actually decides where links open on GNOME/KDE.
+    if command_available("xdg-settings") {
+      if let Ok(current) = run("xdg-settings", &["get", "default-web-browser"]) {
+        return Ok(current == name);
       }
     }
 
-    if !all_succeeded {
-      return Err(format!(
-        "Some xdg-mime commands failed:\n{}\n\nYou may need to:\n1. Run with appropriate permissions\n2. Manually set the default browser in your desktop environment settings\n3. Restart your desktop session",
-        error_messages.join("\n")
-      ));
+    if !command_available("xdg-mime") {
+      return Err("xdg-utils not found. Please install the xdg-utils package.".to_string());
+    }
+    for scheme in SCHEMES {
+      let current = run(
+        "xdg-mime",
+        &["query", "default", &format!("x-scheme-handler/{scheme}")],
+      )?;
+      if current != name {
+        return Ok(false);
+      }
+    }
+    Ok(true)
+  }
+
+  pub fn set_as_default_browser() -> Result<(), String> {
+    let name = desktop_file_name();
+    let has_settings = command_available("xdg-settings");
+    let has_mime = command_available("xdg-mime");
+    if !has_settings && !has_mime {
+      return Err("xdg-utils not found. Please install the xdg-utils package.".to_string());
+    }
+
+    if std::env::var_os("APPIMAGE").is_some() || !desktop_file_installed() {
+      install_desktop_entry()?;
     }
 
-    // Give the system a moment to process the changes
-    std::thread::sleep(std::time::Duration::from_millis(500));
+    let settings_result = if has_settings {
+      run("xdg-settings", &["set", "default-web-browser", name]).map(|_| ())
+    } else {
+      Err("xdg-settings not available".to_string())
+    };
+
+    if let Err(e) = settings_result {
+      log::warn!("xdg-settings could not set default browser, falling back to xdg-mime: {e}");
+      if !has_mime {
+        return Err(e);
+      }
+      for scheme in SCHEMES {
+        run(
+          "xdg-mime",
+          &["default", name, &format!("x-scheme-handler/{scheme}")],
+        )?;
+      }
+      for mime in ["text/html", "application/xhtml+xml"] {
+        let _ = run("xdg-mime", &["default", name, mime]);
+      }
+    }
 
-    // Verify the changes took effect
     match is_default_browser() {
       Ok(true) => Ok(()),
-      Ok(false) => {
-        // This is the common case where commands succeed but verification fails
-        Err(format!(
-          "The xdg-mime commands completed successfully, but Donut Browser is not yet set as the default. This is common on some Linux distributions. Please try one of these options:\n\n1. Restart your desktop session and try again\n2. Log out and log back in\n3. Manually set Donut Browser as the default in your system settings:\n   - GNOME: Settings > Default Applications > Web\n   - KDE: System Settings > Applications > Default Applications > Web Browser\n   - XFCE: Settings > Preferred Applications > Web Browser\n   - Or run: xdg-settings set default-web-browser {}\n\nThe changes may take effect automatically after a desktop restart.",
-          APP_DESKTOP_NAME
-        ))
-      }
+      Ok(false) => Err(format!(
+        "Donut Browser was registered, but your desktop environment still reports another default browser. Please set it manually:\n   - GNOME: Settings > Default Applications > Web\n   - KDE: System Settings > Applications > Default Applications > Web Browser\n   - XFCE: Settings > Preferred Applications > Web Browser\n   - Or run: xdg-settings set default-web-browser {name}"
+      )),
       Err(e) => Err(format!(
-        "Set as default completed, but verification failed: {}. The changes may still be in effect after restarting your desktop session.",
-        e
-      ))
+        "Set as default completed, but verification failed: {e}. The change may still take effect after restarting your desktop session."
+      )),
     }
   }
 
-  fn is_xdg_mime_available() -> bool {
-    Command::new("which")
-      .arg("xdg-mime")
-      .output()
-      .map(|output| output.status.success())
-      .unwrap_or(false)
-  }
+  #[cfg(test)]
+  mod tests {
+    use super::*;
 
-  fn check_desktop_file_exists() -> bool {
-    let desktop_locations = [
-      "~/.local/share/applications/",
-      "/usr/share/applications/",
-      "/usr/local/share/applications/",
-      "/var/lib/flatpak/exports/share/applications/",
-      "~/.local/share/flatpak/exports/share/applications/",
-    ];
-
-    for location in &desktop_locations {
-      let path = if location.starts_with('~') {
-        if let Ok(home) = std::env::var("HOME") {
-          location.replace('~', &home)
-        } else {
-          continue;
-        }
-      } else {
-        location.to_string()
-      };
-
-      let full_path = format!("{}{}", path, APP_DESKTOP_NAME);
-      if std::path::Path::new(&full_path).exists() {
-        return true;
-      }
+    #[test]
+    fn desktop_entry_points_exec_at_launch_path() {
+      let contents = desktop_entry_contents("/home/u/Apps/Donut $1.AppImage");
+      assert!(contents.contains("Exec=\"/home/u/Apps/Donut \\$1.AppImage\" %u\n"));
+      assert!(!contents.contains("Exec=donutbrowser"));
+      assert!(contents.contains("MimeType=x-scheme-handler/http;x-scheme-handler/https;"));
     }
-
-    false
   }
 }
 
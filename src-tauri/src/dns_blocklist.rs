@@ -494,6 +494,9 @@ impl BlocklistManager {
   }
 
   pub async fn refresh_all_stale(&self) {
+    if crate::offline::is_offline() {
+      return;
+    }
     for &level in BlocklistLevel::all_downloadable() {
       if !Self::is_cache_fresh(level) {
         if let Err(e) = Self::fetch_blocklist(level).await {
@@ -501,13 +504,10 @@ impl BlocklistManager {
             "[dns-blocklist] Failed to refresh {}: {e}",
             level.display_name()
           );
-          let _ = crate::events::emit(
-            "dns-blocklist-refresh-failed",
-            serde_json::json!({
-              "level": level.as_str(),
-              "error": e,
-            }),
-          );
+          let _ = crate::events::emit(&crate::events::payloads::DnsBlocklistRefreshFailed {
+            level: level.as_str(),
+            error: e,
+          });
         }
       }
     }
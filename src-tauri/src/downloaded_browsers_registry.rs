@@ -1,8 +1,11 @@
+use futures_util::stream::{Stream, StreamExt};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fs;
+use std::future::Future;
 use std::path::PathBuf;
-use std::sync::Mutex;
+use std::sync::{Arc, Mutex};
+use tokio_util::sync::CancellationToken;
 
 use crate::geoip_downloader::GeoIPDownloader;
 use crate::profile::{BrowserProfile, ProfileManager};
@@ -14,9 +17,219 @@ pub struct DownloadedBrowserInfo {
   pub file_path: PathBuf,
 }
 
+/// What an auto-update replaced, so it can be reverted. Kept per browser;
+/// a newer update for the same browser supersedes it.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct RollbackPointer {
+  pub new_version: String,
+  pub previous_versions: HashMap<String, String>, // profile id -> version before the update
+  /// Set by the first successful launch on `new_version`. Until then the
+  /// previous versions are never garbage-collected.
+  #[serde(default)]
+  pub launched_new_version: bool,
+}
+
+/// Integrity record for an installed version, written after a download
+/// passes verification.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BinaryDigest {
+  /// SHA-256 of the downloaded archive; only set when it matched a published
+  /// checksum.
+  pub archive_sha256: Option<String>,
+  /// Digest of the extracted install directory (`integrity::sha256_tree`),
+  /// re-checked by `verify_downloaded_browsers`.
+  pub tree_sha256: String,
+}
+
+/// Outcome of re-verifying one installed version.
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct BinaryVerification {
+  pub browser: String,
+  pub version: String,
+  pub status: String, // "verified" | "corrupted" | "unverified" | "in_use"
+}
+
+/// How many browser downloads `ensure_all_binaries_exist` runs at once.
+const ENSURE_CONCURRENCY: usize = 2;
+
+/// A browser version some profiles need but that isn't installed.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct MissingBinary {
+  pub browser: String,
+  pub version: String,
+  /// Names of the profiles waiting on this version.
+  #[serde(default)]
+  pub profiles: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Clone, PartialEq, Eq)]
+pub struct FailedBinary {
+  pub browser: String,
+  pub version: String,
+  pub error: String,
+}
+
+/// Published as `binaries-ensure-progress` for each planned download.
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct BinaryEnsureProgress {
+  pub browser: String,
+  pub version: String,
+  /// "queued" | "downloading" | "extracting" | "done" | "failed" | "skipped"
+  pub status: String,
+  /// Set while downloading.
+  pub percentage: Option<f64>,
+  /// Archive size, once the server has reported it.
+  pub total_bytes: Option<u64>,
+  pub error: Option<String>,
+}
+
+/// What an `ensure_all_binaries_exist` run did. `failed` can be passed back
+/// as `only` to retry just those versions.
+#[derive(Debug, Serialize, Clone, Default, PartialEq)]
+pub struct EnsureBinariesSummary {
+  pub downloaded: Vec<MissingBinary>,
+  pub failed: Vec<FailedBinary>,
+  /// Never started because the run was cancelled.
+  pub skipped: Vec<MissingBinary>,
+  pub geoip_downloaded: bool,
+}
+
+/// One download per missing version, in the order profiles first need them,
+/// narrowed to `only` when given.
+fn build_ensure_plan(
+  missing: Vec<(String, String, String)>,
+  only: Option<&[MissingBinary]>,
+) -> Vec<MissingBinary> {
+  let mut plan: Vec<MissingBinary> = Vec::new();
+  for (profile_name, browser, version) in missing {
+    if only.is_some_and(|only| {
+      !only
+        .iter()
+        .any(|o| o.browser == browser && o.version == version)
+    }) {
+      continue;
+    }
+    match plan
+      .iter_mut()
+      .find(|item| item.browser == browser && item.version == version)
+    {
+      Some(item) => item.profiles.push(profile_name),
+      None => plan.push(MissingBinary {
+        browser,
+        version,
+        profiles: vec![profile_name],
+      }),
+    }
+  }
+  plan
+}
+
+/// Run `download` over the plan, `ENSURE_CONCURRENCY` at a time, yielding
+/// outcomes as they finish. An item is only started once a slot frees up, so
+/// after `cancel` fires the running downloads complete and every item not yet
+/// started comes back as `None`.
+fn run_ensure_plan<F, Fut>(
+  plan: Vec<MissingBinary>,
+  cancel: CancellationToken,
+  download: F,
+) -> impl Stream<Item = (MissingBinary, Option<Result<(), String>>)>
+where
+  F: Fn(MissingBinary) -> Fut,
+  Fut: Future<Output = Result<(), String>>,
+{
+  futures_util::stream::iter(plan)
+    .map(move |item| {
+      let started = (!cancel.is_cancelled()).then(|| download(item.clone()));
+      async move {
+        match started {
+          Some(download) => {
+            let outcome = download.await;
+            (item, Some(outcome))
+          }
+          None => (item, None),
+        }
+      }
+    })
+    .buffer_unordered(ENSURE_CONCURRENCY)
+}
+
+fn emit_ensure_progress(item: &MissingBinary, status: &str, error: Option<String>) {
+  let _ = crate::events::emit(&BinaryEnsureProgress {
+    browser: item.browser.clone(),
+    version: item.version.clone(),
+    status: status.to_string(),
+    percentage: None,
+    total_bytes: None,
+    error,
+  });
+}
+
+/// Re-publish the downloader's `download-progress` events for planned items
+/// as `binaries-ensure-progress`. Items in `finished` are past their last
+/// download event and are left alone, so a late event can't follow "done".
+fn relay_download_progress(
+  plan: &[MissingBinary],
+  finished: Arc<Mutex<HashSet<(String, String)>>>,
+) -> tokio::task::JoinHandle<()> {
+  let planned: HashSet<(String, String)> = plan
+    .iter()
+    .map(|item| (item.browser.clone(), item.version.clone()))
+    .collect();
+  let mut events = crate::events::subscribe_local();
+  tokio::spawn(async move {
+    loop {
+      let event = match events.recv().await {
+        Ok(event) => event,
+        Err(tokio::sync::broadcast::error::RecvError::Lagged(_)) => continue,
+        Err(tokio::sync::broadcast::error::RecvError::Closed) => break,
+      };
+      if event.event != "download-progress" {
+        continue;
+      }
+      let Ok(progress) =
+        serde_json::from_value::<crate::downloader::DownloadProgress>(event.payload)
+      else {
+        continue;
+      };
+      let key = (progress.browser.clone(), progress.version.clone());
+      if !planned.contains(&key) {
+        continue;
+      }
+      let status = match progress.stage.as_str() {
+        "downloading" => "downloading",
+        "extracting" | "verifying" => "extracting",
+        _ => continue,
+      };
+      let finished = finished.lock().unwrap();
+      if finished.contains(&key) {
+        continue;
+      }
+      let _ = crate::events::emit(&BinaryEnsureProgress {
+        browser: progress.browser,
+        version: progress.version,
+        status: status.to_string(),
+        percentage: (status == "downloading").then_some(progress.percentage),
+        total_bytes: progress.total_bytes,
+        error: None,
+      });
+    }
+  })
+}
+
+lazy_static::lazy_static! {
+  /// One ensure run at a time; a second caller waits for the first.
+  static ref ENSURE_RUN: tokio::sync::Mutex<()> = tokio::sync::Mutex::new(());
+  /// Cancels the ensure run in progress, if any.
+  static ref ENSURE_CANCEL: Mutex<Option<CancellationToken>> = Mutex::new(None);
+}
+
 #[derive(Debug, Serialize, Deserialize, Default)]
 struct RegistryData {
   pub browsers: HashMap<String, HashMap<String, DownloadedBrowserInfo>>, // browser -> version -> info
+  #[serde(default)]
+  pub rollback: HashMap<String, RollbackPointer>,     // browser -> pointer
+  #[serde(default)]
+  pub digests: HashMap<String, HashMap<String, BinaryDigest>>, // browser -> version -> digest
 }
 
 pub struct DownloadedBrowsersRegistry {
@@ -70,7 +283,7 @@ impl DownloadedBrowsersRegistry {
   }
 
   fn get_registry_path() -> Result<PathBuf, Box<dyn std::error::Error + Send + Sync>> {
-    Ok(crate::app_dirs::data_subdir().join("downloaded_browsers.json"))
+    Ok(crate::app_dirs::binaries_registry_file())
   }
 
   pub fn add_browser(&self, info: DownloadedBrowserInfo) {
@@ -83,10 +296,210 @@ impl DownloadedBrowsersRegistry {
   }
 
   pub fn remove_browser(&self, browser: &str, version: &str) -> Option<DownloadedBrowserInfo> {
+    crate::binaries_usage::forget_size(browser, version);
     let mut data = self.data.lock().unwrap();
+    if let Some(digests) = data.digests.get_mut(browser) {
+      digests.remove(version);
+    }
     data.browsers.get_mut(browser)?.remove(version)
   }
 
+  /// Install dir of a registered version.
+  pub fn install_path(&self, browser: &str, version: &str) -> Option<PathBuf> {
+    let data = self.data.lock().unwrap();
+    Some(data.browsers.get(browser)?.get(version)?.file_path.clone())
+  }
+
+  /// Every registered version as (browser, version, install dir).
+  pub fn installed_versions(&self) -> Vec<(String, String, PathBuf)> {
+    let data = self.data.lock().unwrap();
+    data
+      .browsers
+      .values()
+      .flat_map(|versions| versions.values())
+      .map(|info| {
+        (
+          info.browser.clone(),
+          info.version.clone(),
+          info.file_path.clone(),
+        )
+      })
+      .collect()
+  }
+
+  pub fn record_digest(&self, browser: &str, version: &str, digest: BinaryDigest) {
+    let mut data = self.data.lock().unwrap();
+    data
+      .digests
+      .entry(browser.to_string())
+      .or_default()
+      .insert(version.to_string(), digest);
+  }
+
+  pub fn get_digest(&self, browser: &str, version: &str) -> Option<BinaryDigest> {
+    let data = self.data.lock().unwrap();
+    data.digests.get(browser)?.get(version).cloned()
+  }
+
+  /// Re-hash every installed version that has a recorded digest. A version
+  /// whose tree no longer matches is dropped from the registry and deleted
+  /// from disk so the next `ensure_all_binaries_exist` downloads it fresh.
+  /// Versions a running profile uses are left alone.
+  pub fn verify_installed_binaries(&self) -> Vec<BinaryVerification> {
+    let running: std::collections::HashSet<(String, String)> = self
+      .profile_manager
+      .list_profiles()
+      .map(|profiles| self.get_running_browser_versions(&profiles))
+      .unwrap_or_default()
+      .into_iter()
+      .collect();
+
+    let installed: Vec<DownloadedBrowserInfo> = {
+      let data = self.data.lock().unwrap();
+      data
+        .browsers
+        .values()
+        .flat_map(|versions| versions.values().cloned())
+        .collect()
+    };
+
+    let mut results = Vec::new();
+    let mut removed_any = false;
+    for info in installed {
+      let key = (info.browser.clone(), info.version.clone());
+      let status = if running.contains(&key) {
+        "in_use"
+      } else {
+        match self.get_digest(&info.browser, &info.version) {
+          None => "unverified",
+          Some(digest) => match crate::integrity::sha256_tree(&info.file_path) {
+            Ok(actual) if actual == digest.tree_sha256 => "verified",
+            result => {
+              log::error!(
+                "Integrity check failed for {} {} ({}): {:?}",
+                info.browser,
+                info.version,
+                info.file_path.display(),
+                result.map_err(|e| e.to_string())
+              );
+              self.remove_browser(&info.browser, &info.version);
+              if info.file_path.exists() {
+                if let Err(e) = fs::remove_dir_all(&info.file_path) {
+                  log::warn!(
+                    "Failed to remove corrupted install {}: {e}",
+                    info.file_path.display()
+                  );
+                }
+              }
+              removed_any = true;
+              "corrupted"
+            }
+          },
+        }
+      };
+      results.push(BinaryVerification {
+        browser: info.browser,
+        version: info.version,
+        status: status.to_string(),
+      });
+    }
+
+    if removed_any {
+      if let Err(e) = self.save() {
+        log::warn!("Failed to save registry after integrity check: {e}");
+      }
+    }
+    results
+  }
+
+  /// Remember that `profile_id` moved from `previous_version` to
+  /// `new_version` through an auto-update. The first version a profile was on
+  /// for this update is kept, so repeated bumps to the same version don't
+  /// overwrite the real rollback target.
+  pub fn record_rollback_candidate(
+    &self,
+    browser: &str,
+    profile_id: &str,
+    previous_version: &str,
+    new_version: &str,
+  ) {
+    if previous_version == new_version {
+      return;
+    }
+    {
+      let mut data = self.data.lock().unwrap();
+      let pointer = data.rollback.entry(browser.to_string()).or_default();
+      if pointer.new_version != new_version {
+        *pointer = RollbackPointer {
+          new_version: new_version.to_string(),
+          ..Default::default()
+        };
+      }
+      pointer
+        .previous_versions
+        .entry(profile_id.to_string())
+        .or_insert_with(|| previous_version.to_string());
+    }
+    if let Err(e) = self.save() {
+      log::warn!("Failed to persist rollback pointer for {browser}: {e}");
+    }
+  }
+
+  pub fn get_rollback_pointer(&self, browser: &str) -> Option<RollbackPointer> {
+    let data = self.data.lock().unwrap();
+    data.rollback.get(browser).cloned()
+  }
+
+  pub fn clear_rollback_pointer(&self, browser: &str) -> Option<RollbackPointer> {
+    let removed = {
+      let mut data = self.data.lock().unwrap();
+      data.rollback.remove(browser)
+    };
+    if removed.is_some() {
+      if let Err(e) = self.save() {
+        log::warn!("Failed to persist rollback pointer removal for {browser}: {e}");
+      }
+    }
+    removed
+  }
+
+  /// Record a successful launch. The first one on an auto-updated version
+  /// releases the rollback candidate to normal binary cleanup.
+  pub fn mark_version_launched(&self, browser: &str, version: &str) {
+    let changed = {
+      let mut data = self.data.lock().unwrap();
+      match data.rollback.get_mut(browser) {
+        Some(pointer) if pointer.new_version == version && !pointer.launched_new_version => {
+          pointer.launched_new_version = true;
+          true
+        }
+        _ => false,
+      }
+    };
+    if changed {
+      if let Err(e) = self.save() {
+        log::warn!("Failed to persist rollback launch marker for {browser}: {e}");
+      }
+    }
+  }
+
+  /// (browser, version) pairs that must survive cleanup because the update
+  /// that replaced them hasn't had a successful launch yet.
+  fn protected_rollback_versions(&self) -> std::collections::HashSet<(String, String)> {
+    let data = self.data.lock().unwrap();
+    data
+      .rollback
+      .iter()
+      .filter(|(_, pointer)| !pointer.launched_new_version)
+      .flat_map(|(browser, pointer)| {
+        pointer
+          .previous_versions
+          .values()
+          .map(|version| (browser.clone(), version.clone()))
+      })
+      .collect()
+  }
+
   /// Check if browser is registered in the registry (without disk validation)
   /// This method only checks the in-memory registry and does not validate file existence
   pub fn is_browser_registered(&self, browser: &str, version: &str) -> bool {
@@ -141,6 +554,14 @@ impl DownloadedBrowsersRegistry {
       .unwrap_or_default()
   }
 
+  /// Newest downloaded version of `browser` by semver, if any is installed.
+  pub fn get_latest_downloaded_version(&self, browser: &str) -> Option<String> {
+    self
+      .get_downloaded_versions(browser)
+      .into_iter()
+      .max_by(|a, b| crate::api_client::compare_versions(a, b))
+  }
+
   pub fn mark_download_started(&self, browser: &str, version: &str, file_path: PathBuf) {
     // Only mark download started, don't add to registry yet
     // The browser will be added to registry only after verification succeeds
@@ -251,6 +672,8 @@ impl DownloadedBrowsersRegistry {
       }
     };
 
+    let rollback_set = self.protected_rollback_versions();
+
     // Collect all downloaded browsers that are not in active profiles
     let mut to_remove = Vec::new();
     {
@@ -271,6 +694,13 @@ impl DownloadedBrowsersRegistry {
             continue;
           }
 
+          // Don't remove the pre-update version until the update has launched
+          // successfully at least once — it's the only way back if it hasn't
+          if rollback_set.contains(&browser_version) {
+            log::info!("Keeping: {browser} {version} (rollback candidate)");
+            continue;
+          }
+
           // Don't remove if this version has a pending update for a running profile
           // This handles the case where a running profile has an update downloaded but not yet applied
           if pending_updates.contains(&browser_version) {
@@ -336,7 +766,12 @@ impl DownloadedBrowsersRegistry {
     }
 
     // Remove unused binaries and their version folders
+    let mut removals = Vec::new();
     for (browser, version) in to_remove {
+      let size_bytes = self
+        .install_path(&browser, &version)
+        .map(|path| crate::binaries_usage::install_size(&browser, &version, &path))
+        .unwrap_or(0);
       if let Err(e) = self.cleanup_failed_download(&browser, &version) {
         log::error!("Failed to cleanup unused binary {browser}:{version}: {e}");
       } else {
@@ -346,8 +781,15 @@ impl DownloadedBrowsersRegistry {
         }
         cleaned_up.push(format!("{browser} {version}"));
         log::info!("Successfully removed unused binary: {browser} {version}");
+        removals.push(crate::binaries_usage::BinaryRemoval {
+          browser,
+          version,
+          size_bytes,
+          reason: "unused".to_string(),
+        });
       }
     }
+    crate::binaries_usage::record_cleanup(removals);
 
     if cleaned_up.is_empty() {
       log::info!("No unused binaries found to clean up");
@@ -358,7 +800,9 @@ impl DownloadedBrowsersRegistry {
     Ok(cleaned_up)
   }
 
-  /// Get all browsers and versions referenced by active profiles
+  /// Get all browsers and versions referenced by active profiles.
+  /// Every profile counts, pinned ones included, so a pinned profile's binary
+  /// stays on disk however many newer versions are installed.
   pub fn get_active_browser_versions(
     &self,
     profiles: &[crate::profile::BrowserProfile],
@@ -680,7 +1124,7 @@ impl DownloadedBrowsersRegistry {
   /// Consolidate browser versions - keep only the latest version per browser
   pub fn consolidate_browser_versions(
     &self,
-    app_handle: &tauri::AppHandle,
+    app_handle: &crate::app_handle::AppHandle,
   ) -> Result<Vec<String>, Box<dyn std::error::Error + Send + Sync>> {
     log::info!("Starting browser version consolidation...");
 
@@ -690,7 +1134,9 @@ impl DownloadedBrowsersRegistry {
       .map_err(|e| format!("Failed to list profiles: {e}"))?;
 
     let binaries_dir = self.profile_manager.get_binaries_dir();
+    let shared_versions = crate::workspaces::shared_binary_versions_elsewhere();
     let mut consolidated = Vec::new();
+    let mut removals = Vec::new();
 
     // Group profiles by browser
     let mut browser_profiles: std::collections::HashMap<String, Vec<&BrowserProfile>> =
@@ -779,11 +1225,27 @@ impl DownloadedBrowsersRegistry {
 
         // Remove older version binaries that are no longer needed
         for old_version in &older_versions_to_remove {
+          if shared_versions.contains(&(browser_name.clone(), old_version.clone())) {
+            log::info!("Keeping: {browser_name} {old_version} (in use by another workspace)");
+            continue;
+          }
           log::info!("Consolidating: removing old version {browser_name} {old_version}");
+          // None once an earlier pass already removed it.
+          let size_bytes = self
+            .install_path(browser_name, old_version)
+            .map(|path| crate::binaries_usage::install_size(browser_name, old_version, &path));
           match self.cleanup_failed_download(browser_name, old_version) {
             Ok(_) => {
               consolidated.push(format!("Removed old version: {browser_name} {old_version}"));
               log::info!("Successfully removed old version: {browser_name} {old_version}");
+              if let Some(size_bytes) = size_bytes {
+                removals.push(crate::binaries_usage::BinaryRemoval {
+                  browser: browser_name.clone(),
+                  version: old_version.clone(),
+                  size_bytes,
+                  reason: "superseded".to_string(),
+                });
+              }
             }
             Err(e) => {
               log::error!("Failed to cleanup old version {browser_name} {old_version}: {e}");
@@ -793,6 +1255,8 @@ impl DownloadedBrowsersRegistry {
       }
     }
 
+    crate::binaries_usage::record_cleanup(removals);
+
     // Save registry after consolidation
     self
       .save()
@@ -848,11 +1312,29 @@ impl DownloadedBrowsersRegistry {
     Ok(missing_binaries)
   }
 
-  /// Automatically download missing binaries for all profiles
+  /// Download the binaries profiles need but that are missing, reporting each
+  /// version through `binaries-ensure-progress`. `only` restricts the run to
+  /// those versions (a retry of earlier failures). `cancel_ensure_binaries`
+  /// stops the run once the downloads in flight finish.
   pub async fn ensure_all_binaries_exist(
     &self,
-    app_handle: &tauri::AppHandle,
-  ) -> Result<Vec<String>, Box<dyn std::error::Error + Send + Sync>> {
+    app_handle: &crate::app_handle::AppHandle,
+    only: Option<&[MissingBinary]>,
+  ) -> Result<EnsureBinariesSummary, Box<dyn std::error::Error + Send + Sync>> {
+    let _run = ENSURE_RUN.lock().await;
+    let cancel = CancellationToken::new();
+    *ENSURE_CANCEL.lock().unwrap() = Some(cancel.clone());
+    let result = self.ensure_binaries_run(app_handle, only, cancel).await;
+    *ENSURE_CANCEL.lock().unwrap() = None;
+    result
+  }
+
+  async fn ensure_binaries_run(
+    &self,
+    app_handle: &crate::app_handle::AppHandle,
+    only: Option<&[MissingBinary]>,
+    cancel: CancellationToken,
+  ) -> Result<EnsureBinariesSummary, Box<dyn std::error::Error + Send + Sync>> {
     // First, clean up any stale registry entries
     if let Ok(cleaned_up) = self.verify_and_cleanup_stale_entries() {
       if !cleaned_up.is_empty() {
@@ -874,27 +1356,47 @@ impl DownloadedBrowsersRegistry {
       }
     }
 
-    let missing_binaries = self.check_missing_binaries().await?;
-    let mut downloaded = Vec::new();
-
-    for (profile_name, browser, version) in missing_binaries {
-      log::info!("Downloading missing binary for profile '{profile_name}': {browser} {version}");
+    let plan = build_ensure_plan(self.check_missing_binaries().await?, only);
+    let mut summary = EnsureBinariesSummary::default();
+    for item in &plan {
+      emit_ensure_progress(item, "queued", None);
+    }
 
-      match crate::downloader::download_browser(
-        app_handle.clone(),
-        browser.clone(),
-        version.clone(),
-      )
-      .await
+    let finished = Arc::new(Mutex::new(HashSet::new()));
+    let relay = relay_download_progress(&plan, finished.clone());
+    let mut outcomes = std::pin::pin!(run_ensure_plan(plan, cancel.clone(), |item| {
+      let app_handle = app_handle.clone();
+      async move {
+        log::info!(
+          "Downloading missing binary {} {} for profiles: {}",
+          item.browser,
+          item.version,
+          item.profiles.join(", ")
+        );
+        emit_ensure_progress(&item, "downloading", None);
+        crate::downloader::download_browser(app_handle, item.browser, item.version)
+          .await
+          .map(|_| ())
+      }
+    }));
+
+    // Outcomes are handled one at a time, so profile updates never race.
+    while let Some((item, outcome)) = outcomes.next().await {
+      let (status, error) = match &outcome {
+        Some(Ok(())) => ("done", None),
+        Some(Err(error)) => ("failed", Some(error.clone())),
+        None => ("skipped", None),
+      };
       {
-        Ok(_) => {
-          downloaded.push(format!(
-            "{browser} {version} (for profile '{profile_name}')"
-          ));
-
-          // After successful download, update profiles that use this browser to the new version
+        let mut finished = finished.lock().unwrap();
+        finished.insert((item.browser.clone(), item.version.clone()));
+        emit_ensure_progress(&item, status, error);
+      }
+      match outcome {
+        Some(Ok(())) => {
+          // After a successful download, move profiles on this browser to it
           match self
-            .update_profiles_to_version(app_handle, &browser, &version)
+            .update_profiles_to_version(app_handle, &item.browser, &item.version)
             .await
           {
             Ok(updated_profiles) => {
@@ -902,7 +1404,7 @@ impl DownloadedBrowsersRegistry {
                 log::info!(
                   "Successfully updated {} profiles to version {}:",
                   updated_profiles.len(),
-                  version
+                  item.version
                 );
                 for update_msg in updated_profiles {
                   log::info!("  {update_msg}");
@@ -910,16 +1412,39 @@ impl DownloadedBrowsersRegistry {
               }
             }
             Err(e) => {
-              log::error!("CRITICAL: Failed to update profiles to version {version}: {e}");
+              log::error!(
+                "CRITICAL: Failed to update profiles to version {}: {e}",
+                item.version
+              );
               log::error!("This may cause profile version inconsistencies and cleanup issues");
             }
           }
+          summary.downloaded.push(item);
         }
-        Err(e) => {
-          log::error!("Failed to download {browser} {version} for profile '{profile_name}': {e}");
+        Some(Err(error)) => {
+          log::error!(
+            "Failed to download {} {}: {error}",
+            item.browser,
+            item.version
+          );
+          summary.failed.push(FailedBinary {
+            browser: item.browser,
+            version: item.version,
+            error,
+          });
         }
+        None => summary.skipped.push(item),
       }
     }
+    relay.abort();
+
+    if cancel.is_cancelled() {
+      log::info!(
+        "Binary download run cancelled; {} version(s) skipped",
+        summary.skipped.len()
+      );
+      return Ok(summary);
+    }
 
     // Check if GeoIP database is missing for Wayfern profiles
     if self.geoip_downloader.check_missing_geoip_database()? {
@@ -931,7 +1456,7 @@ impl DownloadedBrowsersRegistry {
         .await
       {
         Ok(_) => {
-          downloaded.push("GeoIP database".to_string());
+          summary.geoip_downloaded = true;
           log::info!("GeoIP database downloaded successfully");
         }
         Err(e) => {
@@ -941,13 +1466,13 @@ impl DownloadedBrowsersRegistry {
       }
     }
 
-    Ok(downloaded)
+    Ok(summary)
   }
 
   /// Update all profiles using a specific browser to a new version
   async fn update_profiles_to_version(
     &self,
-    app_handle: &tauri::AppHandle,
+    app_handle: &crate::app_handle::AppHandle,
     browser: &str,
     version: &str,
   ) -> Result<Vec<String>, Box<dyn std::error::Error + Send + Sync>> {
@@ -960,6 +1485,15 @@ impl DownloadedBrowsersRegistry {
 
     for profile in profiles {
       if profile.browser == browser && profile.version != version {
+        if profile.pin_browser_version {
+          log::info!(
+            "Skipping version update for pinned profile: {} ({})",
+            profile.name,
+            profile.version
+          );
+          continue;
+        }
+
         // Check if profile is currently running
         if profile.process_id.is_some() {
           log::info!(
@@ -1012,8 +1546,10 @@ impl DownloadedBrowsersRegistry {
       .list_profiles()
       .map_err(|e| format!("Failed to list profiles: {e}"))?;
 
-    // Get active browser versions (all profiles)
-    let active_versions = self.get_active_browser_versions(&profiles);
+    // Get active browser versions (all profiles, including those of other
+    // workspaces sharing this binaries dir)
+    let mut active_versions = self.get_active_browser_versions(&profiles);
+    active_versions.extend(crate::workspaces::shared_binary_versions_elsewhere());
 
     // Get running browser versions (only running profiles)
     let running_versions = self.get_running_browser_versions(&profiles);
@@ -1180,6 +1716,8 @@ mod tests {
 
   #[test]
   fn test_last_version_kept_during_cleanup() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let _guard = crate::app_dirs::set_test_data_dir(temp_dir.path().to_path_buf());
     let registry = DownloadedBrowsersRegistry::new();
 
     // Add a single version for "testbrowser"
@@ -1230,6 +1768,112 @@ mod tests {
     );
   }
 
+  #[test]
+  fn test_rollback_candidate_kept_until_new_version_launches() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let _guard = crate::app_dirs::set_test_data_dir(temp_dir.path().to_path_buf());
+    let registry = DownloadedBrowsersRegistry::new();
+    for version in ["120.0", "121.0"] {
+      registry.add_browser(DownloadedBrowserInfo {
+        browser: "rollbacktest".to_string(),
+        version: version.to_string(),
+        file_path: PathBuf::from(format!("/test/rollbacktest/{version}")),
+      });
+    }
+    registry.record_rollback_candidate("rollbacktest", "p1", "120.0", "121.0");
+    let active = [("rollbacktest".to_string(), "121.0".to_string())];
+
+    let result = registry
+      .cleanup_unused_binaries_internal(&active, &[])
+      .expect("cleanup should succeed");
+    assert!(!result.contains(&"rollbacktest 120.0".to_string()));
+    assert!(registry.is_browser_registered("rollbacktest", "120.0"));
+
+    // A launch on some other version doesn't count
+    registry.mark_version_launched("rollbacktest", "120.0");
+    assert!(
+      !registry
+        .get_rollback_pointer("rollbacktest")
+        .unwrap()
+        .launched_new_version
+    );
+
+    registry.mark_version_launched("rollbacktest", "121.0");
+    let result = registry
+      .cleanup_unused_binaries_internal(&active, &[])
+      .expect("cleanup should succeed");
+    assert!(result.contains(&"rollbacktest 120.0".to_string()));
+
+    let log = crate::binaries_usage::load_cleanup_log();
+    let removed = &log.last().expect("cleanup should be logged").removed;
+    assert!(removed
+      .iter()
+      .any(|r| r.browser == "rollbacktest" && r.version == "120.0" && r.reason == "unused"));
+  }
+
+  #[test]
+  fn test_rollback_pointer_keeps_first_previous_version() {
+    let registry = DownloadedBrowsersRegistry::new();
+    registry.record_rollback_candidate("rollbacktest2", "p1", "120.0", "122.0");
+    registry.record_rollback_candidate("rollbacktest2", "p1", "121.0", "122.0");
+    let pointer = registry.get_rollback_pointer("rollbacktest2").unwrap();
+    assert_eq!(
+      pointer.previous_versions.get("p1").map(String::as_str),
+      Some("120.0")
+    );
+
+    // A newer update supersedes the pointer entirely
+    registry.record_rollback_candidate("rollbacktest2", "p1", "122.0", "123.0");
+    let pointer = registry.get_rollback_pointer("rollbacktest2").unwrap();
+    assert_eq!(pointer.new_version, "123.0");
+    assert_eq!(
+      pointer.previous_versions.get("p1").map(String::as_str),
+      Some("122.0")
+    );
+  }
+
+  #[test]
+  fn test_verify_installed_binaries_drops_tampered_install() {
+    let registry = DownloadedBrowsersRegistry::new();
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let good = temp_dir.path().join("good");
+    let bad = temp_dir.path().join("bad");
+    for dir in [&good, &bad] {
+      fs::create_dir_all(dir).unwrap();
+      fs::write(dir.join("chrome"), b"binary").unwrap();
+    }
+    for (version, dir) in [("1.0", &good), ("2.0", &bad)] {
+      registry.add_browser(DownloadedBrowserInfo {
+        browser: "integritytest".to_string(),
+        version: version.to_string(),
+        file_path: dir.clone(),
+      });
+      registry.record_digest(
+        "integritytest",
+        version,
+        BinaryDigest {
+          archive_sha256: None,
+          tree_sha256: crate::integrity::sha256_tree(dir).unwrap(),
+        },
+      );
+    }
+    registry.add_browser(DownloadedBrowserInfo {
+      browser: "integritytest".to_string(),
+      version: "0.9".to_string(),
+      file_path: temp_dir.path().join("legacy"),
+    });
+    fs::write(bad.join("chrome"), b"tampered").unwrap();
+
+    let mut results = registry.verify_installed_binaries();
+    results.sort_by(|a, b| a.version.cmp(&b.version));
+    let statuses: Vec<&str> = results.iter().map(|r| r.status.as_str()).collect();
+    assert_eq!(statuses, ["unverified", "verified", "corrupted"]);
+    assert!(registry.is_browser_registered("integritytest", "1.0"));
+    assert!(!registry.is_browser_registered("integritytest", "2.0"));
+    assert!(registry.get_digest("integritytest", "2.0").is_none());
+    assert!(!bad.exists());
+  }
+
   #[test]
   fn test_is_browser_registered_vs_downloaded() {
     let registry = DownloadedBrowsersRegistry::new();
@@ -1255,11 +1899,90 @@ mod tests {
       "Browser should not be considered downloaded when files don't exist on disk"
     );
   }
+
+  fn missing(profile: &str, version: &str) -> (String, String, String) {
+    (
+      profile.to_string(),
+      "wayfern".to_string(),
+      version.to_string(),
+    )
+  }
+
+  #[test]
+  fn test_ensure_plan_groups_profiles_and_honours_only() {
+    let triples = vec![
+      missing("a", "140.0"),
+      missing("b", "141.0"),
+      missing("c", "140.0"),
+    ];
+    let plan = build_ensure_plan(triples.clone(), None);
+    assert_eq!(plan.len(), 2);
+    assert_eq!(plan[0].version, "140.0");
+    assert_eq!(plan[0].profiles, ["a", "c"]);
+
+    let only = [MissingBinary {
+      browser: "wayfern".to_string(),
+      version: "141.0".to_string(),
+      profiles: Vec::new(),
+    }];
+    let retry = build_ensure_plan(triples, Some(&only));
+    assert_eq!(retry.len(), 1);
+    assert_eq!(retry[0].profiles, ["b"]);
+  }
+
+  #[tokio::test]
+  async fn test_ensure_plan_runs_two_at_a_time_and_stops_on_cancel() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    let plan = build_ensure_plan(
+      (0..6).map(|i| missing("p", &format!("{i}.0"))).collect(),
+      None,
+    );
+    let cancel = CancellationToken::new();
+    let running = Arc::new(AtomicUsize::new(0));
+    let peak = Arc::new(AtomicUsize::new(0));
+    let started = Arc::new(AtomicUsize::new(0));
+
+    let outcomes = run_ensure_plan(plan, cancel.clone(), |item| {
+      // The third download to start is where the user hits cancel.
+      if started.fetch_add(1, Ordering::SeqCst) == 2 {
+        cancel.cancel();
+      }
+      let (running, peak) = (running.clone(), peak.clone());
+      async move {
+        let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+        peak.fetch_max(now, Ordering::SeqCst);
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        running.fetch_sub(1, Ordering::SeqCst);
+        if item.version == "1.0" {
+          Err("network down".to_string())
+        } else {
+          Ok(())
+        }
+      }
+    })
+    .collect::<Vec<_>>()
+    .await;
+
+    assert_eq!(peak.load(Ordering::SeqCst), 2);
+    assert_eq!(outcomes.len(), 6);
+    let done = outcomes
+      .iter()
+      .filter(|(_, o)| matches!(o, Some(Ok(()))))
+      .count();
+    let failed: Vec<&str> = outcomes
+      .iter()
+      .filter(|(_, o)| matches!(o, Some(Err(_))))
+      .map(|(item, _)| item.version.as_str())
+      .collect();
+    let skipped = outcomes.iter().filter(|(_, o)| o.is_none()).count();
+    assert_eq!((done, failed, skipped), (2, vec!["1.0"], 3));
+  }
 }
 
 #[tauri::command]
 pub async fn ensure_active_browsers_downloaded(
-  app_handle: tauri::AppHandle,
+  app_handle: crate::app_handle::AppHandle,
 ) -> Result<Vec<String>, String> {
   #[cfg(feature = "e2e")]
   if crate::e2e_automation_enabled()
@@ -1372,7 +2095,7 @@ pub async fn ensure_active_browsers_downloaded(
             eta_seconds: None,
             stage: "error".to_string(),
           };
-          let _ = crate::events::emit("download-progress", &progress);
+          let _ = crate::events::emit(&progress);
         }
       }
 
@@ -1414,21 +2137,57 @@ pub async fn check_missing_binaries() -> Result<Vec<(String, String, String)>, S
     .map_err(|e| format!("Failed to check missing binaries: {e}"))
 }
 
+/// Re-verify installed browsers against the digests recorded at download time
+/// and re-download whatever a profile still needs if something was corrupted.
+#[tauri::command]
+pub async fn verify_downloaded_browsers(
+  app_handle: crate::app_handle::AppHandle,
+) -> Result<Vec<BinaryVerification>, String> {
+  let registry = DownloadedBrowsersRegistry::instance();
+  let results = tokio::task::spawn_blocking(|| registry.verify_installed_binaries())
+    .await
+    .map_err(|e| format!("Integrity check task failed: {e}"))?;
+
+  if results.iter().any(|r| r.status == "corrupted") {
+    registry
+      .ensure_all_binaries_exist(&app_handle, None)
+      .await
+      .map_err(|e| format!("Failed to re-download corrupted browsers: {e}"))?;
+  }
+  Ok(results)
+}
+
+/// Download every binary a profile needs but is missing. Pass a previous
+/// run's `failed` list as `only` to retry just those versions.
 #[tauri::command]
 pub async fn ensure_all_binaries_exist(
-  app_handle: tauri::AppHandle,
-) -> Result<Vec<String>, String> {
+  app_handle: crate::app_handle::AppHandle,
+  only: Option<Vec<MissingBinary>>,
+) -> Result<EnsureBinariesSummary, String> {
   #[cfg(feature = "e2e")]
   if crate::e2e_automation_enabled()
     && std::env::var_os("DONUT_E2E_DISABLE_STARTUP_NETWORK").is_some()
   {
     log::info!("E2E: skipping proactive binary and GeoIP downloads");
-    return Ok(Vec::new());
+    return Ok(EnsureBinariesSummary::default());
   }
 
   let registry = DownloadedBrowsersRegistry::instance();
   registry
-    .ensure_all_binaries_exist(&app_handle)
+    .ensure_all_binaries_exist(&app_handle, only.as_deref())
     .await
     .map_err(|e| format!("Failed to ensure all binaries exist: {e}"))
 }
+
+/// Stop the running `ensure_all_binaries_exist` after the downloads in
+/// flight. Returns whether a run was in progress.
+#[tauri::command]
+pub fn cancel_ensure_binaries() -> bool {
+  match ENSURE_CANCEL.lock().unwrap().as_ref() {
+    Some(token) => {
+      token.cancel();
+      true
+    }
+    None => false,
+  }
+}
@@ -21,6 +21,10 @@ lazy_static::lazy_static! {
     std::sync::Arc::new(Mutex::new(std::collections::HashSet::new()));
   static ref DOWNLOAD_CANCELLATION_TOKENS: std::sync::Arc<Mutex<std::collections::HashMap<String, CancellationToken>>> =
     std::sync::Arc::new(Mutex::new(std::collections::HashMap::new()));
+  /// Last transfer progress of each in-flight download, for callers that
+  /// can't subscribe to `download-progress`.
+  static ref DOWNLOAD_PROGRESS: Mutex<std::collections::HashMap<String, DownloadProgress>> =
+    Mutex::new(std::collections::HashMap::new());
 }
 
 /// Clears a browser-version pair from the in-flight download maps on every
@@ -36,7 +40,20 @@ impl Drop for InFlightDownload {
     if let Ok(mut tokens) = DOWNLOAD_CANCELLATION_TOKENS.lock() {
       tokens.remove(&self.0);
     }
+    if let Ok(mut progress) = DOWNLOAD_PROGRESS.lock() {
+      progress.remove(&self.0);
+    }
+  }
+}
+
+fn emit_transfer_progress(progress: DownloadProgress) {
+  if let Ok(mut latest) = DOWNLOAD_PROGRESS.lock() {
+    latest.insert(
+      format!("{}-{}", progress.browser, progress.version),
+      progress.clone(),
+    );
   }
+  let _ = events::emit(&progress);
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -185,6 +202,44 @@ impl Downloader {
 
         Ok(download_url)
       }
+      #[cfg(feature = "test-browser")]
+      BrowserType::Stub => Err("The stub browser is installed by tests, not downloaded".into()),
+    }
+  }
+
+  /// The SHA-256 the publisher lists for this download, if it lists one.
+  /// Wayfern publishes per-platform digests in wayfern.json; failing that, a
+  /// `<url>.sha256` sidecar next to the asset is tried.
+  async fn fetch_expected_sha256(
+    &self,
+    browser_type: BrowserType,
+    version: &str,
+  ) -> Option<String> {
+    match browser_type {
+      BrowserType::Wayfern => {
+        let info = self
+          .api_client
+          .fetch_wayfern_version_with_caching(true)
+          .await
+          .ok()?;
+        if info.version != version {
+          return None;
+        }
+        if let Some(digest) = self.api_client.get_wayfern_checksum(&info) {
+          return Some(digest);
+        }
+        let url = self.api_client.get_wayfern_download_url(&info)?;
+        let response = self
+          .client
+          .get(format!("{url}.sha256"))
+          .send()
+          .await
+          .ok()
+          .filter(|r| r.status().is_success())?;
+        crate::integrity::parse_sha256(&response.text().await.ok()?)
+      }
+      #[cfg(feature = "test-browser")]
+      BrowserType::Stub => None,
     }
   }
 
@@ -211,9 +266,9 @@ impl Downloader {
     (os.to_string(), arch.to_string())
   }
 
-  pub async fn download_browser<R: tauri::Runtime>(
+  pub async fn download_browser(
     &self,
-    _app_handle: &tauri::AppHandle<R>,
+    _app_handle: &crate::app_handle::AppHandle,
     browser_type: BrowserType,
     version: &str,
     download_info: &DownloadInfo,
@@ -391,19 +446,16 @@ impl Downloader {
         Some(total) if total > 0 => (existing_size as f64 / total as f64) * 100.0,
         _ => 0.0,
       };
-      let _ = events::emit(
-        "download-progress",
-        &DownloadProgress {
-          browser: browser_type.as_str().to_string(),
-          version: version.to_string(),
-          downloaded_bytes: existing_size,
-          total_bytes: total_size,
-          percentage: initial_percentage,
-          speed_bytes_per_sec: 0.0,
-          eta_seconds: None,
-          stage: "downloading".to_string(),
-        },
-      );
+      emit_transfer_progress(DownloadProgress {
+        browser: browser_type.as_str().to_string(),
+        version: version.to_string(),
+        downloaded_bytes: existing_size,
+        total_bytes: total_size,
+        percentage: initial_percentage,
+        speed_bytes_per_sec: 0.0,
+        eta_seconds: None,
+        stage: "downloading".to_string(),
+      });
 
       // Open file in append mode (resuming) or create new.
       // Wrap in BufWriter with a large buffer to reduce the number of disk writes,
@@ -474,19 +526,16 @@ impl Downloader {
             None
           };
 
-          let _ = events::emit(
-            "download-progress",
-            &DownloadProgress {
-              browser: browser_type.as_str().to_string(),
-              version: version.to_string(),
-              downloaded_bytes: downloaded,
-              total_bytes: total_size,
-              percentage,
-              speed_bytes_per_sec: speed,
-              eta_seconds: eta,
-              stage: "downloading".to_string(),
-            },
-          );
+          emit_transfer_progress(DownloadProgress {
+            browser: browser_type.as_str().to_string(),
+            version: version.to_string(),
+            downloaded_bytes: downloaded,
+            total_bytes: total_size,
+            percentage,
+            speed_bytes_per_sec: speed,
+            eta_seconds: eta,
+            stage: "downloading".to_string(),
+          });
           last_update = now;
         }
       }
@@ -528,7 +577,7 @@ impl Downloader {
   /// Download a browser binary, verify it, and register it in the downloaded browsers registry
   pub async fn download_browser_full(
     &self,
-    app_handle: &tauri::AppHandle,
+    app_handle: &crate::app_handle::AppHandle,
     browser_str: String,
     version: String,
   ) -> Result<String, Box<dyn std::error::Error + Send + Sync>> {
@@ -680,12 +729,62 @@ impl Downloader {
           eta_seconds: None,
           stage: stage.to_string(),
         };
-        let _ = events::emit("download-progress", &progress);
+        let _ = events::emit(&progress);
 
         return Err(format!("Failed to download browser: {e}").into());
       }
     };
 
+    // Verify the archive against the published digest before anything is
+    // extracted from it. A mismatch removes the archive and the whole version
+    // directory, so no partial install is left for a later check to accept.
+    let expected_sha256 = self
+      .fetch_expected_sha256(browser_type.clone(), &version)
+      .await;
+    let archive_sha256 = {
+      let path = download_path.clone();
+      tokio::task::spawn_blocking(move || crate::integrity::sha256_file(&path))
+        .await
+        .map_err(|e| format!("Checksum task failed: {e}"))?
+        .map_err(|e| format!("Failed to hash downloaded archive: {e}"))?
+    };
+    match expected_sha256.as_deref() {
+      Some(expected) if expected != archive_sha256 => {
+        log::error!(
+          "Checksum mismatch for {browser_str} {version}: expected {expected}, got {archive_sha256}"
+        );
+        let _ = std::fs::remove_file(&download_path);
+        let _ = std::fs::remove_dir_all(&browser_dir);
+        let _ = self.registry.remove_browser(&browser_str, &version);
+        let _ = self.registry.save();
+
+        let progress = DownloadProgress {
+          browser: browser_str.clone(),
+          version: version.clone(),
+          downloaded_bytes: 0,
+          total_bytes: None,
+          percentage: 0.0,
+          speed_bytes_per_sec: 0.0,
+          eta_seconds: None,
+          stage: "error".to_string(),
+        };
+        let _ = events::emit(&progress);
+
+        return Err(
+          serde_json::json!({
+            "code": "BROWSER_CHECKSUM_MISMATCH",
+            "params": { "browser": browser_str, "version": version }
+          })
+          .to_string()
+          .into(),
+        );
+      }
+      Some(_) => log::info!("Checksum verified for {browser_str} {version}: {archive_sha256}"),
+      None => {
+        log::warn!("No published checksum for {browser_str} {version}; installing unverified")
+      }
+    }
+
     // Use the extraction module
     if download_info.is_archive {
       match self
@@ -710,6 +809,9 @@ impl Downloader {
             log::info!("Deleting corrupt archive: {}", download_path.display());
             let _ = std::fs::remove_file(&download_path);
           }
+          // Drop whatever was extracted too, so a half-copied or unsigned
+          // binary is never reported as downloaded.
+          let _ = std::fs::remove_dir_all(&browser_dir);
 
           let _ = self.registry.remove_browser(&browser_str, &version);
           let _ = self.registry.save();
@@ -725,9 +827,9 @@ impl Downloader {
             eta_seconds: None,
             stage: "error".to_string(),
           };
-          let _ = events::emit("download-progress", &progress);
+          let _ = events::emit(&progress);
 
-          return Err(format!("Failed to extract browser: {e}").into());
+          return Err(crate::wrap_backend_error(e, "Failed to extract browser").into());
         }
       }
 
@@ -746,7 +848,7 @@ impl Downloader {
       eta_seconds: None,
       stage: "verifying".to_string(),
     };
-    let _ = events::emit("download-progress", &progress);
+    let _ = events::emit(&progress);
 
     // Verify the browser was downloaded correctly
     log::info!("Verifying download for browser: {browser_str}, version: {version}");
@@ -793,7 +895,7 @@ impl Downloader {
         eta_seconds: None,
         stage: "error".to_string(),
       };
-      let _ = events::emit("download-progress", &progress);
+      let _ = events::emit(&progress);
 
       return Err(error_details.into());
     }
@@ -821,6 +923,27 @@ impl Downloader {
       }
     }
 
+    // Record what was installed so verify_downloaded_browsers can detect
+    // later tampering or disk corruption.
+    let tree_dir = browser_dir.clone();
+    match tokio::task::spawn_blocking(move || crate::integrity::sha256_tree(&tree_dir)).await {
+      Ok(Ok(tree_sha256)) => {
+        self.registry.record_digest(
+          &browser_str,
+          &version,
+          crate::downloaded_browsers_registry::BinaryDigest {
+            archive_sha256: expected_sha256.map(|_| archive_sha256),
+            tree_sha256,
+          },
+        );
+        if let Err(e) = self.registry.save() {
+          log::warn!("Failed to save install digest for {browser_str} {version}: {e}");
+        }
+      }
+      Ok(Err(e)) => log::warn!("Failed to hash install of {browser_str} {version}: {e}"),
+      Err(e) => log::warn!("Install hash task failed for {browser_str} {version}: {e}"),
+    }
+
     // Emit completion
     let progress = DownloadProgress {
       browser: browser_str.clone(),
@@ -832,7 +955,7 @@ impl Downloader {
       eta_seconds: Some(0.0),
       stage: "completed".to_string(),
     };
-    let _ = events::emit("download-progress", &progress);
+    let _ = events::emit(&progress);
 
     // Auto-update non-running profiles to the latest installed version and cleanup unused binaries
     {
@@ -895,11 +1018,14 @@ pub fn clear_download_state_for_browser(browser: &str) {
     let mut tokens = DOWNLOAD_CANCELLATION_TOKENS.lock().unwrap();
     tokens.retain(|key, _| !key.starts_with(&prefix));
   }
+  if let Ok(mut progress) = DOWNLOAD_PROGRESS.lock() {
+    progress.retain(|key, _| !key.starts_with(&prefix));
+  }
 }
 
 #[tauri::command]
 pub async fn download_browser(
-  app_handle: tauri::AppHandle,
+  app_handle: crate::app_handle::AppHandle,
   browser_str: String,
   version: String,
 ) -> Result<String, String> {
@@ -910,6 +1036,23 @@ pub async fn download_browser(
     .map_err(|e| crate::wrap_backend_error(e, "Failed to download browser"))
 }
 
+/// Whether a download of this browser version is running right now.
+pub fn is_download_in_progress(browser: &str, version: &str) -> bool {
+  DOWNLOADING_BROWSERS
+    .lock()
+    .map(|downloading| downloading.contains(&format!("{browser}-{version}")))
+    .unwrap_or(false)
+}
+
+/// Last transfer progress of a running download, once it has started
+/// receiving data.
+pub fn download_progress(browser: &str, version: &str) -> Option<DownloadProgress> {
+  DOWNLOAD_PROGRESS
+    .lock()
+    .ok()
+    .and_then(|progress| progress.get(&format!("{browser}-{version}")).cloned())
+}
+
 #[tauri::command]
 pub async fn cancel_download(browser_str: String, version: String) -> Result<(), String> {
   let download_key = format!("{browser_str}-{version}");
@@ -0,0 +1,288 @@
+//! Which profiles reference each stored proxy, VPN and group, so the UI can
+//! show "in use by N profiles" and deletions can refuse to orphan profiles.
+
+use crate::profile::BrowserProfile;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ProfileRef {
+  pub id: String,
+  pub name: String,
+}
+
+/// The profiles referencing one proxy, VPN or group.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct EntityUsage {
+  pub id: String,
+  pub profiles: Vec<ProfileRef>,
+  /// At least one referencing profile has a browser running.
+  pub any_running: bool,
+  /// At least one referencing profile is sync-enabled, so the entity must stay
+  /// synced too.
+  pub any_sync_enabled: bool,
+}
+
+/// Usage of each of `ids`, in order, where `reference` reads the ID a profile
+/// points at.
+fn collect_usage(
+  ids: Vec<String>,
+  profiles: &[BrowserProfile],
+  reference: impl Fn(&BrowserProfile) -> Option<&str>,
+) -> Vec<EntityUsage> {
+  ids
+    .into_iter()
+    .map(|id| {
+      let users: Vec<&BrowserProfile> = profiles
+        .iter()
+        .filter(|p| reference(p) == Some(id.as_str()))
+        .collect();
+      EntityUsage {
+        any_running: users.iter().any(|p| p.process_id.is_some()),
+        any_sync_enabled: users.iter().any(|p| p.is_sync_enabled()),
+        profiles: users
+          .iter()
+          .map(|p| ProfileRef {
+            id: p.id.to_string(),
+            name: p.name.clone(),
+          })
+          .collect(),
+        id,
+      }
+    })
+    .collect()
+}
+
+/// Narrows `all` to `only` when given, failing if it isn't one of them.
+fn select_ids(all: Vec<String>, only: Option<&str>, kind: &str) -> Result<Vec<String>, String> {
+  match only {
+    None => Ok(all),
+    Some(id) if all.iter().any(|existing| existing == id) => Ok(vec![id.to_string()]),
+    Some(id) => Err(format!("{kind} with ID '{id}' not found")),
+  }
+}
+
+fn list_profiles() -> Result<Vec<BrowserProfile>, String> {
+  crate::profile::ProfileManager::instance()
+    .list_profiles()
+    .map_err(|e| format!("Failed to list profiles: {e}"))
+}
+
+/// Usage of every stored proxy, or only `proxy_id`.
+pub fn proxy_usage(proxy_id: Option<&str>) -> Result<Vec<EntityUsage>, String> {
+  let all = crate::proxy_manager::PROXY_MANAGER
+    .get_stored_proxies()
+    .into_iter()
+    .map(|p| p.id)
+    .collect();
+  let ids = select_ids(all, proxy_id, "Proxy")?;
+  Ok(collect_usage(ids, &list_profiles()?, |p| {
+    p.proxy_id.as_deref()
+  }))
+}
+
+/// Usage of every VPN config, or only `vpn_id`.
+pub fn vpn_usage(vpn_id: Option<&str>) -> Result<Vec<EntityUsage>, String> {
+  let all = {
+    let storage = crate::vpn::VPN_STORAGE
+      .lock()
+      .map_err(|e| format!("Failed to lock VPN storage: {e}"))?;
+    storage
+      .list_configs()
+      .map_err(|e| format!("Failed to list VPN configs: {e}"))?
+      .into_iter()
+      .map(|c| c.id)
+      .collect()
+  };
+  let ids = select_ids(all, vpn_id, "VPN")?;
+  Ok(collect_usage(ids, &list_profiles()?, |p| {
+    p.vpn_id.as_deref()
+  }))
+}
+
+/// Usage of every profile group, or only `group_id`.
+pub fn group_usage(group_id: Option<&str>) -> Result<Vec<EntityUsage>, String> {
+  let all = crate::group_manager::GROUP_MANAGER
+    .lock()
+    .unwrap()
+    .get_all_groups()
+    .map_err(|e| format!("Failed to get profile groups: {e}"))?
+    .into_iter()
+    .map(|g| g.id)
+    .collect();
+  let ids = select_ids(all, group_id, "Group")?;
+  Ok(collect_usage(ids, &list_profiles()?, |p| {
+    p.group_id.as_deref()
+  }))
+}
+
+/// Deletes a stored proxy. A proxy still assigned to profiles is refused with
+/// `PROXY_IN_USE` unless `force` is set, in which case those profiles are
+/// left without a proxy.
+pub fn delete_stored_proxy(
+  app_handle: &crate::app_handle::AppHandle,
+  proxy_id: &str,
+  force: bool,
+) -> Result<(), String> {
+  let profiles = list_profiles()?;
+  let users: Vec<&BrowserProfile> = profiles
+    .iter()
+    .filter(|p| p.proxy_id.as_deref() == Some(proxy_id))
+    .collect();
+
+  if !users.is_empty() && !force {
+    return Err(
+      serde_json::json!({
+        "code": "PROXY_IN_USE",
+        "params": { "count": users.len() }
+      })
+      .to_string(),
+    );
+  }
+
+  crate::proxy_manager::PROXY_MANAGER.delete_stored_proxy(app_handle, proxy_id)?;
+
+  if users.is_empty() {
+    return Ok(());
+  }
+  let manager = crate::profile::ProfileManager::instance();
+  for profile in users {
+    let mut detached = profile.clone();
+    detached.proxy_id = None;
+    detached.updated_at = Some(crate::proxy_manager::now_secs());
+    if let Err(e) = manager.save_profile(&detached) {
+      log::warn!(
+        "Failed to clear deleted proxy {proxy_id} from profile {}: {e}",
+        profile.id
+      );
+      continue;
+    }
+    crate::sync::queue_profile_sync_if_eligible(&detached);
+  }
+  if let Err(e) = crate::events::emit_empty("profiles-changed") {
+    log::warn!("Warning: Failed to emit profiles-changed event: {e}");
+  }
+  Ok(())
+}
+
+/// Deletes a proxy pool. Like [`delete_stored_proxy`], a pool still assigned to
+/// profiles is refused with `PROXY_POOL_IN_USE` unless `force` is set.
+pub fn delete_proxy_pool(
+  app_handle: &crate::app_handle::AppHandle,
+  pool_id: &str,
+  force: bool,
+) -> Result<(), String> {
+  let profiles = list_profiles()?;
+  let users: Vec<&BrowserProfile> = profiles
+    .iter()
+    .filter(|p| p.proxy_pool_id.as_deref() == Some(pool_id))
+    .collect();
+
+  if !users.is_empty() && !force {
+    return Err(
+      serde_json::json!({
+        "code": "PROXY_POOL_IN_USE",
+        "params": { "count": users.len() }
+      })
+      .to_string(),
+    );
+  }
+
+  crate::proxy_manager::PROXY_MANAGER.delete_proxy_pool(app_handle, pool_id)?;
+
+  if users.is_empty() {
+    return Ok(());
+  }
+  let manager = crate::profile::ProfileManager::instance();
+  for profile in users {
+    let mut detached = profile.clone();
+    detached.proxy_pool_id = None;
+    detached.updated_at = Some(crate::proxy_manager::now_secs());
+    if let Err(e) = manager.save_profile(&detached) {
+      log::warn!(
+        "Failed to clear deleted proxy pool {pool_id} from profile {}: {e}",
+        profile.id
+      );
+      continue;
+    }
+    crate::sync::queue_profile_sync_if_eligible(&detached);
+  }
+  if let Err(e) = crate::events::emit_empty("profiles-changed") {
+    log::warn!("Warning: Failed to emit profiles-changed event: {e}");
+  }
+  Ok(())
+}
+
+#[tauri::command]
+pub fn get_proxy_usage(proxy_id: Option<String>) -> Result<Vec<EntityUsage>, String> {
+  proxy_usage(proxy_id.as_deref())
+}
+
+#[tauri::command]
+pub fn get_vpn_usage(vpn_id: Option<String>) -> Result<Vec<EntityUsage>, String> {
+  vpn_usage(vpn_id.as_deref())
+}
+
+#[tauri::command]
+pub fn get_group_usage(group_id: Option<String>) -> Result<Vec<EntityUsage>, String> {
+  group_usage(group_id.as_deref())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn profile(name: &str, proxy_id: Option<&str>, running: bool, synced: bool) -> BrowserProfile {
+    BrowserProfile {
+      id: uuid::Uuid::new_v4(),
+      name: name.to_string(),
+      browser: "wayfern".to_string(),
+      proxy_id: proxy_id.map(str::to_string),
+      process_id: running.then_some(4242),
+      sync_mode: if synced {
+        crate::profile::types::SyncMode::Regular
+      } else {
+        crate::profile::types::SyncMode::Disabled
+      },
+      ..Default::default()
+    }
+  }
+
+  #[test]
+  fn collect_usage_groups_profiles_by_reference() {
+    let profiles = vec![
+      profile("a", Some("p1"), false, true),
+      profile("b", Some("p1"), true, false),
+      profile("c", Some("p2"), false, false),
+      profile("d", None, true, true),
+    ];
+    let usage = collect_usage(
+      vec!["p1".to_string(), "p2".to_string(), "p3".to_string()],
+      &profiles,
+      |p| p.proxy_id.as_deref(),
+    );
+
+    let names: Vec<Vec<&str>> = usage
+      .iter()
+      .map(|u| u.profiles.iter().map(|p| p.name.as_str()).collect())
+      .collect();
+    assert_eq!(names, vec![vec!["a", "b"], vec!["c"], vec![]]);
+    assert!(usage[0].any_running && usage[0].any_sync_enabled);
+    assert!(!usage[1].any_running && !usage[1].any_sync_enabled);
+    assert!(!usage[2].any_running && !usage[2].any_sync_enabled);
+  }
+
+  #[test]
+  fn select_ids_rejects_unknown_id() {
+    let all = vec!["a".to_string(), "b".to_string()];
+    assert_eq!(select_ids(all.clone(), None, "Proxy").unwrap(), all);
+    assert_eq!(
+      select_ids(all.clone(), Some("b"), "Proxy").unwrap(),
+      vec!["b"]
+    );
+    assert_eq!(
+      select_ids(all, Some("z"), "Proxy").unwrap_err(),
+      "Proxy with ID 'z' not found"
+    );
+  }
+}
@@ -260,8 +260,10 @@ mod tests {
       version: "1.0".to_string(),
       proxy_id: None,
       vpn_id: None,
+      proxy_pool_id: None,
       launch_hook: None,
       process_id: None,
+      cdp_port: None,
       last_launch: None,
       release_type: "stable".to_string(),
       wayfern_config: None,
@@ -281,6 +283,17 @@ mod tests {
       dns_blocklist: None,
       password_protected: false,
       clear_on_close: false,
+      startup_urls: Vec::new(),
+      session_restore: Default::default(),
+      preference_overrides: Default::default(),
+      metadata: Default::default(),
+      pin_browser_version: false,
+      restart_policy: Default::default(),
+      dns_mode: Default::default(),
+      exit_mismatch_action: Default::default(),
+      resource_limits: Default::default(),
+      applied_resource_limits: None,
+      archived: false,
       created_at: None,
       updated_at: None,
     }
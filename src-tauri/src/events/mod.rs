@@ -1,14 +1,25 @@
+pub mod payloads;
+
 use serde::Serialize;
 use std::sync::Arc;
+use tokio::sync::broadcast;
+
+pub use payloads::Event;
 
 /// Trait for emitting events to the frontend.
 ///
 /// Note: This trait uses `serde_json::Value` to be dyn-compatible.
-/// Use the convenience functions `emit()` and `emit_empty()` which accept
-/// any Serialize type.
+/// Use the convenience functions `emit()` and `emit_empty()`, which take a
+/// typed [`Event`] payload or a signal name.
 pub trait EventEmitter: Send + Sync {
   /// Emit an event with a JSON value payload.
   fn emit_value(&self, event: &str, payload: serde_json::Value) -> Result<(), String>;
+
+  /// Subscribe to emitted events. Only emitters without a frontend of their
+  /// own (see [`DaemonEmitter`]) support this.
+  fn subscribe(&self) -> Option<broadcast::Receiver<DaemonEvent>> {
+    None
+  }
 }
 
 /// Tauri-based event emitter for GUI mode.
@@ -44,6 +55,44 @@ impl EventEmitter for NoopEmitter {
   }
 }
 
+/// An event as delivered to [`DaemonEmitter`] subscribers.
+#[derive(Clone, Debug, Serialize)]
+pub struct DaemonEvent {
+  pub event: String,
+  pub payload: serde_json::Value,
+}
+
+/// Emitter for headless daemon mode.
+/// There is no webview to deliver to, so events are broadcast to whoever is
+/// subscribed (the API server's `/v1/events` WebSocket). Events emitted while
+/// nobody is listening are dropped, and slow subscribers skip ahead.
+#[derive(Clone)]
+pub struct DaemonEmitter {
+  sender: broadcast::Sender<DaemonEvent>,
+}
+
+impl DaemonEmitter {
+  pub fn new(capacity: usize) -> Self {
+    let (sender, _) = broadcast::channel(capacity);
+    Self { sender }
+  }
+}
+
+impl EventEmitter for DaemonEmitter {
+  fn emit_value(&self, event: &str, payload: serde_json::Value) -> Result<(), String> {
+    // A send error only means there are no subscribers right now.
+    let _ = self.sender.send(DaemonEvent {
+      event: event.to_string(),
+      payload,
+    });
+    Ok(())
+  }
+
+  fn subscribe(&self) -> Option<broadcast::Receiver<DaemonEvent>> {
+    Some(self.sender.subscribe())
+  }
+}
+
 /// Global event emitter that can be set at runtime.
 /// This allows managers to emit events without holding an AppHandle directly.
 static GLOBAL_EMITTER: std::sync::OnceLock<Arc<dyn EventEmitter>> = std::sync::OnceLock::new();
@@ -64,19 +113,49 @@ pub fn global_emitter() -> Arc<dyn EventEmitter> {
     .unwrap_or_else(|| Arc::new(NoopEmitter))
 }
 
+/// Copy of every event sent through [`emit`] / [`emit_empty`] for listeners
+/// inside the backend (the MCP server's change notifications). Unlike
+/// [`subscribe`] this works in GUI mode too.
+static LOCAL_EVENTS: std::sync::LazyLock<broadcast::Sender<DaemonEvent>> =
+  std::sync::LazyLock::new(|| broadcast::channel(256).0);
+
 /// Emit an event using the global emitter.
-/// This is a convenience function for use in managers.
-/// Accepts any type that implements Serialize.
-pub fn emit<S: Serialize>(event: &str, payload: S) -> Result<(), String> {
+/// This is the only way to send a payload: the event name comes from the
+/// payload's [`Event`] impl, see [`payloads`].
+pub fn emit<E: Event>(payload: &E) -> Result<(), String> {
   let value = serde_json::to_value(payload).map_err(|e| e.to_string())?;
-  global_emitter().emit_value(event, value)
+  publish_local(E::NAME, &value);
+  global_emitter().emit_value(E::NAME, value)
 }
 
-/// Emit an event with no payload using the global emitter.
+/// Emit an event with no payload using the global emitter. `event` must be
+/// listed in [`payloads::SIGNALS`].
 pub fn emit_empty(event: &str) -> Result<(), String> {
+  publish_local(event, &serde_json::Value::Null);
   global_emitter().emit_value(event, serde_json::Value::Null)
 }
 
+fn publish_local(event: &str, payload: &serde_json::Value) {
+  // Nobody listening is the common case and not an error.
+  if LOCAL_EVENTS.receiver_count() > 0 {
+    let _ = LOCAL_EVENTS.send(DaemonEvent {
+      event: event.to_string(),
+      payload: payload.clone(),
+    });
+  }
+}
+
+/// Subscribe to events emitted anywhere in this process, whatever the
+/// emitter. Slow subscribers skip ahead.
+pub fn subscribe_local() -> broadcast::Receiver<DaemonEvent> {
+  LOCAL_EVENTS.subscribe()
+}
+
+/// Subscribe to the global event stream, if the global emitter supports it.
+pub fn subscribe() -> Option<broadcast::Receiver<DaemonEvent>> {
+  global_emitter().subscribe()
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -89,12 +168,60 @@ mod tests {
       .is_ok());
   }
 
+  #[derive(Serialize)]
+  struct LocalTestEvent {
+    id: &'static str,
+  }
+
+  impl Event for LocalTestEvent {
+    const NAME: &'static str = "local-test-event";
+  }
+
   #[test]
   fn test_emit_convenience_function() {
-    // Test that emit() works with various types
-    assert!(emit("test", "string").is_ok());
-    assert!(emit("test", 42).is_ok());
-    assert!(emit("test", serde_json::json!({"key": "value"})).is_ok());
+    assert!(emit(&payloads::OfflineModeChanged(false)).is_ok());
+    assert!(emit(&payloads::ProfileRunningChanged {
+      id: "p1".to_string(),
+      is_running: true,
+    })
+    .is_ok());
     assert!(emit_empty("test").is_ok());
   }
+
+  #[test]
+  fn test_local_subscribers_see_emitted_events() {
+    let mut rx = subscribe_local();
+    emit(&LocalTestEvent { id: "p1" }).unwrap();
+    emit_empty("local-test-empty").unwrap();
+    // Other tests emit concurrently, so look for ours among whatever arrived.
+    let mut seen = Vec::new();
+    while let Ok(event) = rx.try_recv() {
+      seen.push((event.event, event.payload));
+    }
+    assert!(seen.contains(&(
+      "local-test-event".to_string(),
+      serde_json::json!({"id": "p1"})
+    )));
+    assert!(seen.contains(&("local-test-empty".to_string(), serde_json::Value::Null)));
+  }
+
+  #[test]
+  fn test_daemon_emitter_broadcasts_to_subscribers() {
+    let emitter = DaemonEmitter::new(8);
+    // Emitting with no subscribers is not an error.
+    assert!(emitter
+      .emit_value("dropped", serde_json::Value::Null)
+      .is_ok());
+
+    let mut rx = emitter
+      .subscribe()
+      .expect("daemon emitter supports subscribe");
+    emitter
+      .emit_value("profile-updated", serde_json::json!({"id": "p1"}))
+      .unwrap();
+    let event = rx.try_recv().unwrap();
+    assert_eq!(event.event, "profile-updated");
+    assert_eq!(event.payload, serde_json::json!({"id": "p1"}));
+    assert!(NoopEmitter.subscribe().is_none());
+  }
 }
@@ -0,0 +1,417 @@
+//! The payload type of every event sent to the frontend.
+//!
+//! An event with a payload is emitted through [`super::emit`], which takes
+//! the payload and reads the event name from its [`Event`] impl, so an event
+//! name can't be sent with two different payload shapes. Change
+//! notifications that carry nothing go through [`super::emit_empty`] and are
+//! listed in [`SIGNALS`]. Payloads that are also returned by commands stay
+//! next to those commands and are registered in the table at the bottom.
+
+use serde::Serialize;
+
+use crate::profile::BrowserProfile;
+
+/// A payload together with the name of the event it is sent as.
+pub trait Event: Serialize {
+  const NAME: &'static str;
+}
+
+/// Events sent without a payload: "reload this list" notifications.
+pub const SIGNALS: &[&str] = &[
+  "close-confirm-requested",
+  "cloud-auth-changed",
+  "cloud-auth-expired",
+  "custom-dns-changed",
+  "e2e-rollover-completed",
+  "e2e-rollover-started",
+  "extensions-changed",
+  "groups-changed",
+  "profile-sync-e2e-password-required",
+  "profiles-changed",
+  "proxies-changed",
+  "proxy-pools-changed",
+  "saved-views-changed",
+  "stored-proxies-changed",
+  "traffic-stats-changed",
+  "vpn-configs-changed",
+  "wayfern-paid-blocked",
+];
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ProfileRunningChanged {
+  pub id: String,
+  pub is_running: bool,
+}
+
+/// A profile's `metadata.json` was corrupt and was restored from its backup,
+/// so changes from its last save may be missing.
+#[derive(Debug, Clone, Serialize)]
+pub struct ProfileMetadataRecovered {
+  pub id: String,
+  pub name: String,
+}
+
+/// The profile as just saved.
+#[derive(Debug, Clone, Serialize)]
+#[serde(transparent)]
+pub struct ProfileUpdated<'a>(pub &'a BrowserProfile);
+
+/// Sent to the detached profile window when it should show another profile.
+#[derive(Debug, Clone, Serialize)]
+#[serde(transparent)]
+pub struct DetachedProfileSelected(pub String);
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ProfileCrashed {
+  pub id: String,
+  pub name: String,
+  pub reason: Option<String>,
+  pub restarting: bool,
+  pub restart_limit_reached: bool,
+  pub max_restarts_per_hour: u32,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ProfileRestarted {
+  pub id: String,
+  pub name: String,
+  pub reason: Option<String>,
+  pub restarts_this_hour: usize,
+}
+
+/// A team lock on a profile was taken or given up by this device.
+#[derive(Debug, Clone, Serialize)]
+pub struct ProfileLockChanged {
+  #[serde(rename = "profileId")]
+  pub profile_id: String,
+  /// "acquired" | "released"
+  pub action: &'static str,
+}
+
+/// The frontend shows this as a toast.
+#[derive(Debug, Clone, Serialize)]
+pub struct DeepLinkActionFailed {
+  pub link: String,
+  pub error: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ProfileShortcutFailed {
+  pub profile_id: String,
+  pub error: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ProfileImportProgress {
+  pub total: usize,
+  pub completed: usize,
+  pub index: usize,
+  pub name: String,
+  /// "importing" | "copying" | "imported" | "skipped" | "failed"
+  pub status: String,
+  /// The category being copied while status is "copying".
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub category: Option<crate::profile_importer::ImportCategory>,
+}
+
+/// A profile's sync state. The scheduler doesn't know the profile name and
+/// leaves it out.
+#[derive(Debug, Clone, Serialize)]
+pub struct ProfileSyncStatus {
+  pub profile_id: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub profile_name: Option<String>,
+  /// "waiting" | "syncing" | "synced" | "error" | "disabled"
+  pub status: String,
+  /// Set on a synced status when some files failed to upload; the next sync
+  /// retries them.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub partial: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub failed_files: Option<Vec<String>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub error: Option<String>,
+}
+
+impl ProfileSyncStatus {
+  pub fn new(profile_id: &str, status: &str) -> Self {
+    Self {
+      profile_id: profile_id.to_string(),
+      profile_name: None,
+      status: status.to_string(),
+      partial: None,
+      failed_files: None,
+      error: None,
+    }
+  }
+
+  pub fn named(mut self, profile_name: &str) -> Self {
+    self.profile_name = Some(profile_name.to_string());
+    self
+  }
+
+  pub fn failed(profile_id: &str, error: impl Into<String>) -> Self {
+    Self {
+      error: Some(error.into()),
+      ..Self::new(profile_id, "error")
+    }
+  }
+}
+
+/// Transfer progress of one profile sync. The "started" phase only carries
+/// the totals.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ProfileSyncProgress {
+  pub profile_id: String,
+  pub profile_name: String,
+  /// "started" | "uploading" | "downloading"
+  pub phase: String,
+  pub completed_files: u64,
+  pub total_files: u64,
+  pub completed_bytes: u64,
+  pub total_bytes: u64,
+  pub speed_bytes_per_sec: u64,
+  /// Live rate across every profile syncing now, after the bandwidth limit.
+  pub throughput_bytes_per_sec: u64,
+  pub eta_seconds: u64,
+  pub failed_count: u64,
+}
+
+macro_rules! entity_sync_status {
+  ($($ty:ident => $name:literal),* $(,)?) => {$(
+    /// Sync state of one entity: "waiting" | "syncing" | "synced" | "error" |
+    /// "disabled".
+    #[derive(Debug, Clone, Serialize)]
+    pub struct $ty {
+      pub id: String,
+      pub status: &'static str,
+      #[serde(skip_serializing_if = "Option::is_none")]
+      pub error: Option<String>,
+    }
+
+    impl $ty {
+      pub fn new(id: &str, status: &'static str) -> Self {
+        Self { id: id.to_string(), status, error: None }
+      }
+
+      pub fn failed(id: &str, error: impl Into<String>) -> Self {
+        Self { id: id.to_string(), status: "error", error: Some(error.into()) }
+      }
+    }
+
+    impl Event for $ty {
+      const NAME: &'static str = $name;
+    }
+  )*};
+}
+
+entity_sync_status! {
+  ProxySyncStatus => "proxy-sync-status",
+  GroupSyncStatus => "group-sync-status",
+  VpnSyncStatus => "vpn-sync-status",
+  ExtensionSyncStatus => "extension-sync-status",
+}
+
+/// Progress of re-encrypting every synced entity under a new E2E password.
+#[derive(Debug, Clone, Serialize)]
+pub struct E2eRolloverProgress {
+  /// "profiles" | "proxies" | "proxy_pools" | "groups" | "vpns" |
+  /// "extensions" | "extension_groups"
+  pub stage: &'static str,
+  pub done: usize,
+  pub total: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DnsBlocklistRefreshFailed {
+  pub level: &'static str,
+  pub error: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct VpnStatusChanged {
+  pub vpn_id: String,
+  pub connected: bool,
+}
+
+/// Every connected tunnel's status, for the live throughput graph.
+#[derive(Debug, Clone, Serialize)]
+#[serde(transparent)]
+pub struct VpnStats(pub Vec<crate::vpn::VpnStatus>);
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(transparent)]
+pub struct LaunchQueueChanged(pub Vec<crate::launch_queue::QueuedLaunch>);
+
+/// Extension updates held back until the profiles using them are stopped.
+#[derive(Debug, Clone, Serialize)]
+#[serde(transparent)]
+pub struct ExtensionsUpdatePending(pub Vec<crate::extension_updater::ExtensionUpdateResult>);
+
+/// Message shown when the API server had to fall back to a random port.
+#[derive(Debug, Clone, Serialize)]
+#[serde(transparent)]
+pub struct ApiPortConflict(pub String);
+
+/// The version that gets installed on restart.
+#[derive(Debug, Clone, Serialize)]
+#[serde(transparent)]
+pub struct AppUpdateReady(pub String);
+
+/// Unix timestamp of the first launch.
+#[derive(Debug, Clone, Serialize)]
+#[serde(transparent)]
+pub struct FirstLaunchRecorded(pub u64);
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(transparent)]
+pub struct OfflineModeChanged(pub bool);
+
+/// A URL to open, for which the user picks a profile.
+#[derive(Debug, Clone, Serialize)]
+#[serde(transparent)]
+pub struct ShowProfileSelector(pub String);
+
+/// Id of the synchronizer session that ended.
+#[derive(Debug, Clone, Serialize)]
+#[serde(transparent)]
+pub struct SyncSessionEnded(pub String);
+
+macro_rules! events {
+  ($($ty:ty => $name:literal),* $(,)?) => {
+    $(impl Event for $ty {
+      const NAME: &'static str = $name;
+    })*
+
+    /// Every event sent with a payload, and its payload type.
+    #[cfg(test)]
+    const PAYLOAD_EVENTS: &[(&str, &str)] = &[
+      ("proxy-sync-status", "ProxySyncStatus"),
+      ("group-sync-status", "GroupSyncStatus"),
+      ("vpn-sync-status", "VpnSyncStatus"),
+      ("extension-sync-status", "ExtensionSyncStatus"),
+      $(($name, stringify!($ty))),*
+    ];
+  };
+}
+
+events! {
+  ApiPortConflict => "api-port-conflict",
+  AppUpdateReady => "app-update-ready",
+  DeepLinkActionFailed => "deep-link-action-failed",
+  DetachedProfileSelected => "detached-profile-selected",
+  DnsBlocklistRefreshFailed => "dns-blocklist-refresh-failed",
+  E2eRolloverProgress => "e2e-rollover-progress",
+  ExtensionsUpdatePending => "extensions-update-pending",
+  FirstLaunchRecorded => "first-launch-recorded",
+  LaunchQueueChanged => "launch-queue-changed",
+  OfflineModeChanged => "offline-mode-changed",
+  ProfileCrashed => "profile-crashed",
+  ProfileImportProgress => "profile-import-progress",
+  ProfileLockChanged => "profile-lock-changed",
+  ProfileMetadataRecovered => "profile-metadata-recovered",
+  ProfileRestarted => "profile-restarted",
+  ProfileRunningChanged => "profile-running-changed",
+  ProfileShortcutFailed => "profile-shortcut-failed",
+  ProfileSyncProgress => "profile-sync-progress",
+  ProfileSyncStatus => "profile-sync-status",
+  ProfileUpdated<'_> => "profile-updated",
+  ShowProfileSelector => "show-profile-selector",
+  SyncSessionEnded => "sync-session-ended",
+  VpnStats => "vpn-stats",
+  VpnStatusChanged => "vpn-status-changed",
+  crate::api_server::ToastPayload => "show-toast",
+  crate::app_auto_updater::AppUpdateInfo => "app-update-available",
+  crate::auto_updater::RollbackResult => "update-rolled-back",
+  crate::browser_version_manager::BrowserVersionsUpdated => "browser-versions-updated",
+  crate::downloaded_browsers_registry::BinaryEnsureProgress => "binaries-ensure-progress",
+  crate::downloader::DownloadProgress => "download-progress",
+  crate::exit_check::ExitMismatch => "exit-mismatch",
+  crate::extension_manager::ExtensionsLoadFailed => "extensions-load-failed",
+  crate::geoip_downloader::GeoIPDownloadProgress => "geoip-download-progress",
+  crate::launch_timings::SlowLaunch => "launch-slow",
+  crate::orphan_cleanup::OrphanCleanupSummary => "orphans-cleaned",
+  crate::profile::disk_usage::ProfileDiskUsage => "profile-disk-usage",
+  crate::sync::scheduler::SyncQueueStatus => "sync-queue-changed",
+  crate::sync::subscription::SyncSubscriptionStatus => "sync-subscription-status",
+  crate::synchronizer::SyncSessionInfo => "sync-session-changed",
+  crate::version_updater::VersionUpdateProgress => "version-update-progress",
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::collections::HashMap;
+  use std::path::Path;
+
+  fn rust_sources(dir: &Path, out: &mut Vec<(String, String)>) {
+    for entry in std::fs::read_dir(dir).unwrap().flatten() {
+      let path = entry.path();
+      if path.is_dir() {
+        rust_sources(&path, out);
+      } else if path.extension().is_some_and(|ext| ext == "rs") {
+        let source = std::fs::read_to_string(&path).unwrap();
+        out.push((path.display().to_string(), source));
+      }
+    }
+  }
+
+  /// The string literal each `needle(` call in `source` starts with.
+  fn literal_args<'a>(source: &'a str, needle: &str) -> Vec<&'a str> {
+    source
+      .match_indices(needle)
+      .filter_map(|(i, _)| {
+        let rest = source[i + needle.len()..].trim_start().strip_prefix('"')?;
+        rest.split('"').next()
+      })
+      .collect()
+  }
+
+  #[test]
+  fn every_event_name_has_a_single_payload_type() {
+    let mut types: HashMap<&str, &str> = HashMap::new();
+    for (name, ty) in PAYLOAD_EVENTS {
+      if let Some(other) = types.insert(name, ty) {
+        panic!("{name} is registered for both {other} and {ty}");
+      }
+      assert!(!SIGNALS.contains(name), "{name} is both a signal and {ty}");
+    }
+    let mut signals = SIGNALS.to_vec();
+    signals.sort_unstable();
+    signals.dedup();
+    assert_eq!(signals.len(), SIGNALS.len(), "duplicate signal names");
+  }
+
+  #[test]
+  fn every_emitted_event_is_registered() {
+    let mut sources = Vec::new();
+    rust_sources(
+      &Path::new(env!("CARGO_MANIFEST_DIR")).join("src"),
+      &mut sources,
+    );
+    assert!(!sources.is_empty());
+
+    for (path, source) in &sources {
+      if Path::new(path)
+        .parent()
+        .is_some_and(|dir| dir.ends_with("events"))
+      {
+        continue;
+      }
+      for name in literal_args(source, "emit_empty(") {
+        assert!(
+          SIGNALS.contains(&name),
+          "{path}: signal {name} is missing from payloads::SIGNALS"
+        );
+      }
+      // Straight to the webview, bypassing the global emitter and so the
+      // daemon's subscribers.
+      assert!(
+        !source
+          .lines()
+          .any(|line| line.trim_start().starts_with("use tauri::") && line.contains("Emitter")),
+        "{path}: emit through crate::events instead of tauri::Emitter"
+      );
+    }
+  }
+}
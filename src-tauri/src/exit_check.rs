@@ -0,0 +1,424 @@
+//! Pre-launch check that a proxy or VPN still exits where the user expects.
+//! A stored proxy or VPN can carry an `expected_country` and/or an
+//! `expected_ip_prefix`; before the browser starts, the exit IP is resolved
+//! through the freshly started local proxy, geolocated, and compared. The
+//! profile's `exit_mismatch_action` decides whether a mismatch only warns (an
+//! `exit-mismatch` event the GUI shows as a toast) or refuses the launch.
+
+use crate::browser::ProxySettings;
+use crate::profile::types::{BrowserProfile, ExitMismatchAction};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Long enough that a batch launch through one proxy resolves its exit once,
+/// short enough that a rotated exit is caught by the next launch after it.
+const EXIT_CACHE_TTL: Duration = Duration::from_secs(60);
+
+/// Where a proxy or VPN is expected to exit. Both parts are optional; an
+/// empty expectation skips the check.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ExitExpectation {
+  /// Upper-case ISO 3166-1 alpha-2 code.
+  pub country: Option<String>,
+  /// CIDR block (`203.0.113.0/24`) or leading part of the address
+  /// (`203.0.113`).
+  pub ip_prefix: Option<String>,
+}
+
+impl ExitExpectation {
+  fn is_empty(&self) -> bool {
+    self.country.is_none() && self.ip_prefix.is_none()
+  }
+
+  fn describe(&self) -> String {
+    [self.country.as_deref(), self.ip_prefix.as_deref()]
+      .into_iter()
+      .flatten()
+      .collect::<Vec<_>>()
+      .join(", ")
+  }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ExitLocation {
+  ip: String,
+  country_code: Option<String>,
+}
+
+impl ExitLocation {
+  fn describe(&self) -> String {
+    match &self.country_code {
+      Some(cc) => format!("{} ({cc})", self.ip),
+      None => self.ip.clone(),
+    }
+  }
+}
+
+/// Payload of `exit-mismatch`, emitted when a profile launched anyway.
+#[derive(Debug, Clone, Serialize)]
+pub struct ExitMismatch {
+  pub profile_id: String,
+  pub profile_name: String,
+  /// Name of the proxy or VPN whose exit was checked.
+  pub route_name: String,
+  pub expected_country: Option<String>,
+  pub expected_ip_prefix: Option<String>,
+  pub exit_ip: Option<String>,
+  pub exit_country: Option<String>,
+  /// Set when the exit could not be resolved at all.
+  pub error: Option<String>,
+}
+
+/// One slot per route, locked while its exit is resolved so launches that
+/// start together wait for the first lookup instead of repeating it.
+type CacheSlot = Arc<tokio::sync::Mutex<Option<(Instant, ExitLocation)>>>;
+
+lazy_static::lazy_static! {
+  static ref EXIT_CACHE: Mutex<HashMap<String, CacheSlot>> = Mutex::new(HashMap::new());
+}
+
+fn expectation_error(code: &str, value: &str) -> String {
+  serde_json::json!({ "code": code, "params": { "value": value } }).to_string()
+}
+
+/// Trim and validate user-entered expectations; blank fields become `None`.
+pub fn normalize_exit_expectation(
+  country: Option<String>,
+  ip_prefix: Option<String>,
+) -> Result<ExitExpectation, String> {
+  let country = country
+    .map(|c| c.trim().to_string())
+    .filter(|c| !c.is_empty())
+    .map(|c| {
+      if c.len() == 2 && c.chars().all(|ch| ch.is_ascii_alphabetic()) {
+        Ok(c.to_ascii_uppercase())
+      } else {
+        Err(expectation_error("INVALID_EXPECTED_COUNTRY", &c))
+      }
+    })
+    .transpose()?;
+
+  let ip_prefix = ip_prefix
+    .map(|p| p.trim().to_ascii_lowercase())
+    .filter(|p| !p.is_empty())
+    .map(|p| {
+      if is_valid_prefix(&p) {
+        Ok(p)
+      } else {
+        Err(expectation_error("INVALID_EXPECTED_IP_PREFIX", &p))
+      }
+    })
+    .transpose()?;
+
+  Ok(ExitExpectation { country, ip_prefix })
+}
+
+fn is_valid_prefix(prefix: &str) -> bool {
+  match prefix.split_once('/') {
+    Some((addr, bits)) => parse_cidr(addr, bits).is_some(),
+    None => prefix
+      .chars()
+      .all(|c| c.is_ascii_hexdigit() || c == '.' || c == ':'),
+  }
+}
+
+fn parse_cidr(addr: &str, bits: &str) -> Option<(IpAddr, u32)> {
+  let addr: IpAddr = addr.parse().ok()?;
+  let bits: u32 = bits.parse().ok()?;
+  let max = if addr.is_ipv4() { 32 } else { 128 };
+  (bits <= max).then_some((addr, bits))
+}
+
+fn ip_bits(ip: IpAddr) -> (u128, u32) {
+  match ip {
+    IpAddr::V4(v4) => (u32::from(v4) as u128, 32),
+    IpAddr::V6(v6) => (u128::from(v6), 128),
+  }
+}
+
+/// Whether `ip` falls in `prefix`. A plain prefix matches whole address
+/// groups, so `10.1` matches `10.1.2.3` but not `10.12.0.1`.
+fn ip_matches_prefix(ip: &str, prefix: &str) -> bool {
+  let ip = ip.trim().to_ascii_lowercase();
+  match prefix.split_once('/') {
+    Some((addr, bits)) => {
+      let (Some((network, bits)), Ok(ip)) = (parse_cidr(addr, bits), ip.parse::<IpAddr>()) else {
+        return false;
+      };
+      let ((network, width), (ip, ip_width)) = (ip_bits(network), ip_bits(ip));
+      if width != ip_width {
+        return false;
+      }
+      let shift = width - bits;
+      shift == width || (network >> shift) == (ip >> shift)
+    }
+    None => {
+      ip == prefix
+        || ip
+          .strip_prefix(prefix)
+          .is_some_and(|rest| prefix.ends_with(['.', ':']) || rest.starts_with(['.', ':']))
+    }
+  }
+}
+
+/// The parts of `expected` that `location` does not satisfy. An exit whose
+/// country could not be determined fails a country expectation.
+fn mismatches(expected: &ExitExpectation, location: &ExitLocation) -> Vec<&'static str> {
+  let mut mismatches = Vec::new();
+  if let Some(country) = &expected.country {
+    if !location
+      .country_code
+      .as_deref()
+      .is_some_and(|cc| cc.eq_ignore_ascii_case(country))
+    {
+      mismatches.push("country");
+    }
+  }
+  if let Some(prefix) = &expected.ip_prefix {
+    if !ip_matches_prefix(&location.ip, prefix) {
+      mismatches.push("ip_prefix");
+    }
+  }
+  mismatches
+}
+
+/// The proxy or VPN the profile launches through, if it has an expectation:
+/// (cache key, display name, expectation).
+fn launch_route(
+  profile: &BrowserProfile,
+  upstream: Option<&ProxySettings>,
+) -> Option<(String, String, ExitExpectation)> {
+  let upstream_key = upstream
+    .map(crate::proxy_manager::ProxyManager::build_proxy_url)
+    .unwrap_or_default();
+
+  if let Some(proxy_id) = &profile.proxy_id {
+    let proxy = crate::proxy_manager::PROXY_MANAGER.get_stored_proxy(proxy_id)?;
+    let expected = ExitExpectation {
+      country: proxy.expected_country,
+      ip_prefix: proxy.expected_ip_prefix,
+    };
+    return (!expected.is_empty()).then(|| {
+      (
+        format!("proxy:{proxy_id}|{upstream_key}"),
+        proxy.name,
+        expected,
+      )
+    });
+  }
+
+  let vpn_id = profile.vpn_id.as_ref()?;
+  let config = crate::vpn::VPN_STORAGE
+    .lock()
+    .ok()?
+    .list_configs()
+    .ok()?
+    .into_iter()
+    .find(|c| &c.id == vpn_id)?;
+  let expected = ExitExpectation {
+    country: config.expected_country,
+    ip_prefix: config.expected_ip_prefix,
+  };
+  (!expected.is_empty()).then(|| {
+    (
+      format!("vpn:{vpn_id}|{}", config.updated_at.unwrap_or_default()),
+      config.name,
+      expected,
+    )
+  })
+}
+
+/// The exit seen through `local_proxy_url`, cached per route for
+/// `EXIT_CACHE_TTL`. Geolocates with the bundled MaxMind database and falls
+/// back to ip-api when it is missing.
+async fn resolve_exit(cache_key: &str, local_proxy_url: &str) -> Result<ExitLocation, String> {
+  let slot = EXIT_CACHE
+    .lock()
+    .unwrap()
+    .entry(cache_key.to_string())
+    .or_default()
+    .clone();
+  let mut cached = slot.lock().await;
+  if let Some((at, location)) = cached.as_ref() {
+    if at.elapsed() < EXIT_CACHE_TTL {
+      return Ok(location.clone());
+    }
+  }
+
+  let ip = crate::ip_utils::fetch_public_ip(Some(local_proxy_url))
+    .await
+    .map_err(|e| e.to_string())?;
+  let country_code = match crate::geolocation::get_geolocation(&ip) {
+    Ok(geo) => geo.locale.region,
+    Err(_) => crate::proxy_manager::ProxyManager::get_ip_geolocation(&ip)
+      .await
+      .ok()
+      .and_then(|(_, _, cc)| cc),
+  };
+  let location = ExitLocation { ip, country_code };
+  *cached = Some((Instant::now(), location.clone()));
+  Ok(location)
+}
+
+/// Public IP the profile exits from through `local_proxy_url`, for launch
+/// settings that must follow the current route. Shares the exit cache with
+/// the expectation check.
+pub async fn launch_exit_ip(
+  profile: &BrowserProfile,
+  upstream: Option<&ProxySettings>,
+  local_proxy_url: &str,
+) -> Result<String, String> {
+  let cache_key = match launch_route(profile, upstream) {
+    Some((cache_key, _, _)) => cache_key,
+    None => format!(
+      "route:{}|{}",
+      profile
+        .proxy_id
+        .as_deref()
+        .or(profile.vpn_id.as_deref())
+        .unwrap_or("direct"),
+      upstream
+        .map(crate::proxy_manager::ProxyManager::build_proxy_url)
+        .unwrap_or_default()
+    ),
+  };
+  resolve_exit(&cache_key, local_proxy_url)
+    .await
+    .map(|location| location.ip)
+}
+
+/// Compare the profile's exit, reached through the local proxy at
+/// `local_proxy_url`, against its proxy's or VPN's expectation. `upstream` is
+/// what the local proxy dials, so a changed endpoint is never served from the
+/// cache. Errors only when the profile blocks on mismatch.
+pub async fn check_launch_exit(
+  profile: &BrowserProfile,
+  upstream: Option<&ProxySettings>,
+  local_proxy_url: &str,
+) -> Result<(), String> {
+  let Some((cache_key, route_name, expected)) = launch_route(profile, upstream) else {
+    return Ok(());
+  };
+
+  let resolved = resolve_exit(&cache_key, local_proxy_url).await;
+  if let Ok(location) = &resolved {
+    if mismatches(&expected, location).is_empty() {
+      return Ok(());
+    }
+  }
+
+  if profile.exit_mismatch_action == ExitMismatchAction::Block {
+    return Err(
+      match &resolved {
+        Ok(location) => serde_json::json!({
+          "code": "EXIT_LOCATION_MISMATCH",
+          "params": {
+            "name": route_name,
+            "expected": expected.describe(),
+            "actual": location.describe(),
+          }
+        }),
+        Err(error) => serde_json::json!({
+          "code": "EXIT_CHECK_FAILED",
+          "params": { "name": route_name, "error": error }
+        }),
+      }
+      .to_string(),
+    );
+  }
+
+  log::warn!(
+    "Exit of {route_name} for profile {} does not match {}: {}",
+    profile.name,
+    expected.describe(),
+    match &resolved {
+      Ok(location) => location.describe(),
+      Err(error) => error.clone(),
+    }
+  );
+  let (location, error) = match resolved {
+    Ok(location) => (Some(location), None),
+    Err(error) => (None, Some(error)),
+  };
+  let payload = ExitMismatch {
+    profile_id: profile.id.to_string(),
+    profile_name: profile.name.clone(),
+    route_name,
+    expected_country: expected.country,
+    expected_ip_prefix: expected.ip_prefix,
+    exit_ip: location.as_ref().map(|l| l.ip.clone()),
+    exit_country: location.and_then(|l| l.country_code),
+    error,
+  };
+  if let Err(e) = crate::events::emit(&payload) {
+    log::warn!("Failed to emit exit-mismatch event: {e}");
+  }
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn normalizes_and_rejects_expectations() {
+    let expected =
+      normalize_exit_expectation(Some(" de ".into()), Some(" 2001:DB8::/32 ".into())).unwrap();
+    assert_eq!(expected.country.as_deref(), Some("DE"));
+    assert_eq!(expected.ip_prefix.as_deref(), Some("2001:db8::/32"));
+
+    let blank = normalize_exit_expectation(Some("  ".into()), Some(String::new())).unwrap();
+    assert!(blank.is_empty());
+
+    for country in ["DEU", "D1", "ü"] {
+      assert!(normalize_exit_expectation(Some(country.into()), None)
+        .unwrap_err()
+        .contains("INVALID_EXPECTED_COUNTRY"));
+    }
+    for prefix in ["10.0.0.0/33", "not-an-ip", "10.0.0/8"] {
+      assert!(normalize_exit_expectation(None, Some(prefix.into()))
+        .unwrap_err()
+        .contains("INVALID_EXPECTED_IP_PREFIX"));
+    }
+  }
+
+  #[test]
+  fn prefixes_match_whole_groups_and_cidr_blocks() {
+    assert!(ip_matches_prefix("10.1.2.3", "10.1"));
+    assert!(ip_matches_prefix("10.1.2.3", "10.1."));
+    assert!(!ip_matches_prefix("10.12.0.1", "10.1"));
+    assert!(ip_matches_prefix("10.1.2.3", "10.1.2.3"));
+
+    assert!(ip_matches_prefix("203.0.113.77", "203.0.113.0/24"));
+    assert!(!ip_matches_prefix("203.0.114.1", "203.0.113.0/24"));
+    assert!(ip_matches_prefix("198.51.100.1", "0.0.0.0/0"));
+    assert!(ip_matches_prefix("2001:db8::1", "2001:db8::/32"));
+    assert!(!ip_matches_prefix("203.0.113.77", "2001:db8::/32"));
+  }
+
+  #[test]
+  fn reports_each_unmet_part() {
+    let expected = ExitExpectation {
+      country: Some("DE".into()),
+      ip_prefix: Some("203.0.113.0/24".into()),
+    };
+    let exit = |ip: &str, cc: Option<&str>| ExitLocation {
+      ip: ip.into(),
+      country_code: cc.map(str::to_string),
+    };
+
+    assert!(mismatches(&expected, &exit("203.0.113.9", Some("de"))).is_empty());
+    assert_eq!(
+      mismatches(&expected, &exit("198.51.100.1", Some("US"))),
+      ["country", "ip_prefix"]
+    );
+    // An exit that can't be placed doesn't satisfy a country expectation.
+    assert_eq!(
+      mismatches(&expected, &exit("203.0.113.9", None)),
+      ["country"]
+    );
+  }
+}
@@ -27,6 +27,46 @@ pub struct Extension {
   pub author: Option<String>,
   #[serde(default)]
   pub homepage_url: Option<String>,
+  /// Where `check_extension_updates` fetches new versions from. `None` for
+  /// extensions that were only ever uploaded by hand.
+  #[serde(default)]
+  pub source: Option<ExtensionSource>,
+  /// Directory loaded in place for `file_type == "unpacked"` extensions.
+  #[serde(default)]
+  pub unpacked_path: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ExtensionSource {
+  ChromeWebStore { id: String },
+  Amo { slug: String },
+  Url { url: String },
+}
+
+impl ExtensionSource {
+  fn validate(&self) -> Result<(), String> {
+    let valid = match self {
+      // Web Store ids are 32 characters from the a-p alphabet.
+      Self::ChromeWebStore { id } => {
+        id.len() == 32 && id.bytes().all(|b| (b'a'..=b'p').contains(&b))
+      }
+      Self::Amo { slug } => {
+        !slug.is_empty()
+          && slug
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.' | '@' | '{' | '}'))
+      }
+      Self::Url { url } => url::Url::parse(url)
+        .map(|u| matches!(u.scheme(), "http" | "https"))
+        .unwrap_or(false),
+    };
+    if valid {
+      Ok(())
+    } else {
+      Err(serde_json::json!({ "code": "EXTENSION_SOURCE_INVALID" }).to_string())
+    }
+  }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -62,6 +102,8 @@ fn extension_groups_file() -> PathBuf {
   crate::app_dirs::data_subdir().join("extension_groups.json")
 }
 
+pub(crate) const UNPACKED_FILE_TYPE: &str = "unpacked";
+
 fn determine_browser_compatibility(file_type: &str) -> Vec<String> {
   match file_type {
     "crx" | "zip" => vec!["chromium".to_string()],
@@ -87,7 +129,7 @@ fn find_zip_start(data: &[u8]) -> usize {
 }
 
 #[allow(clippy::type_complexity)]
-fn extract_manifest_metadata(
+pub(crate) fn extract_manifest_metadata(
   file_data: &[u8],
   file_type: &str,
 ) -> (
@@ -125,11 +167,22 @@ fn extract_manifest_metadata(
     None => return (None, None, None, None, None),
   };
 
-  let manifest: serde_json::Value = match serde_json::from_str(&manifest_content) {
-    Ok(v) => v,
-    Err(_) => return (None, None, None, None, None),
-  };
+  match serde_json::from_str(&manifest_content) {
+    Ok(manifest) => manifest_metadata(&manifest),
+    Err(_) => (None, None, None, None, None),
+  }
+}
 
+#[allow(clippy::type_complexity)]
+fn manifest_metadata(
+  manifest: &serde_json::Value,
+) -> (
+  Option<String>,
+  Option<String>,
+  Option<String>,
+  Option<String>,
+  Option<String>,
+) {
   let name = manifest
     .get("name")
     .and_then(|v| v.as_str())
@@ -155,6 +208,47 @@ fn extract_manifest_metadata(
   (name, version, description, author, homepage_url)
 }
 
+/// Largest icon declared in the manifest, falling back to the toolbar action
+/// icon.
+fn manifest_icon_path(manifest: &serde_json::Value) -> Option<String> {
+  let mut best_path: Option<String> = None;
+  let mut best_size: u32 = 0;
+
+  if let Some(icons) = manifest.get("icons").and_then(|v| v.as_object()) {
+    for (size_str, path_val) in icons {
+      if let (Ok(size), Some(path)) = (size_str.parse::<u32>(), path_val.as_str()) {
+        if size > best_size {
+          best_size = size;
+          best_path = Some(path.to_string());
+        }
+      }
+    }
+  }
+
+  if best_path.is_none() {
+    for key in &["action", "browser_action"] {
+      if let Some(action) = manifest.get(*key) {
+        if let Some(icon) = action.get("default_icon") {
+          if let Some(path) = icon.as_str() {
+            best_path = Some(path.to_string());
+          } else if let Some(icons) = icon.as_object() {
+            for (size_str, path_val) in icons {
+              if let (Ok(size), Some(path)) = (size_str.parse::<u32>(), path_val.as_str()) {
+                if size > best_size {
+                  best_size = size;
+                  best_path = Some(path.to_string());
+                }
+              }
+            }
+          }
+        }
+      }
+    }
+  }
+
+  best_path
+}
+
 fn extract_icon_from_archive(file_data: &[u8], file_type: &str) -> Option<(Vec<u8>, String)> {
   let zip_start = if file_type == "crx" {
     find_zip_start(file_data)
@@ -182,43 +276,7 @@ fn extract_icon_from_archive(file_data: &[u8], file_type: &str) -> Option<(Vec<u
 
     let manifest_content = manifest_content?;
     let manifest: serde_json::Value = serde_json::from_str(&manifest_content).ok()?;
-
-    let mut best_path: Option<String> = None;
-    let mut best_size: u32 = 0;
-
-    if let Some(icons) = manifest.get("icons").and_then(|v| v.as_object()) {
-      for (size_str, path_val) in icons {
-        if let (Ok(size), Some(path)) = (size_str.parse::<u32>(), path_val.as_str()) {
-          if size > best_size {
-            best_size = size;
-            best_path = Some(path.to_string());
-          }
-        }
-      }
-    }
-
-    if best_path.is_none() {
-      for key in &["action", "browser_action"] {
-        if let Some(action) = manifest.get(*key) {
-          if let Some(icon) = action.get("default_icon") {
-            if let Some(path) = icon.as_str() {
-              best_path = Some(path.to_string());
-            } else if let Some(icons) = icon.as_object() {
-              for (size_str, path_val) in icons {
-                if let (Ok(size), Some(path)) = (size_str.parse::<u32>(), path_val.as_str()) {
-                  if size > best_size {
-                    best_size = size;
-                    best_path = Some(path.to_string());
-                  }
-                }
-              }
-            }
-          }
-        }
-      }
-    }
-
-    best_path
+    manifest_icon_path(&manifest)
   };
 
   let icon_path = icon_path?;
@@ -237,6 +295,309 @@ fn extract_icon_from_archive(file_data: &[u8], file_type: &str) -> Option<(Vec<u
   Some((data, ext))
 }
 
+fn backend_error(code: &str, params: serde_json::Value) -> Box<dyn std::error::Error> {
+  serde_json::json!({ "code": code, "params": params })
+    .to_string()
+    .into()
+}
+
+/// Parses `manifest.json` of an unpacked extension directory.
+fn read_unpacked_manifest(
+  dir: &std::path::Path,
+) -> Result<serde_json::Value, Box<dyn std::error::Error>> {
+  if !dir.is_dir() {
+    return Err(backend_error(
+      "EXTENSION_DIRECTORY_NOT_FOUND",
+      serde_json::json!({ "path": dir.to_string_lossy() }),
+    ));
+  }
+  let manifest = fs::read_to_string(dir.join("manifest.json"))
+    .map_err(|e| e.to_string())
+    .and_then(|c| serde_json::from_str::<serde_json::Value>(&c).map_err(|e| e.to_string()))
+    .map_err(|detail| {
+      backend_error(
+        "EXTENSION_MANIFEST_INVALID",
+        serde_json::json!({ "detail": detail }),
+      )
+    })?;
+  match manifest.get("manifest_version").and_then(|v| v.as_u64()) {
+    Some(2) | Some(3) => Ok(manifest),
+    _ => Err(backend_error(
+      "EXTENSION_MANIFEST_INVALID",
+      serde_json::json!({ "detail": "manifest_version must be 2 or 3" }),
+    )),
+  }
+}
+
+/// Wayfern follows a Chromium release that no longer loads Manifest V2, so
+/// only MV3 directories count as Chromium-compatible.
+fn unpacked_browser_compatibility(manifest: &serde_json::Value) -> Vec<String> {
+  match manifest.get("manifest_version").and_then(|v| v.as_u64()) {
+    Some(3) => vec!["chromium".to_string()],
+    _ => vec![],
+  }
+}
+
+/// Zips an unpacked extension directory for sync, skipping VCS metadata.
+fn zip_directory(dir: &std::path::Path) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+  fn add_dir(
+    zip: &mut zip::ZipWriter<std::io::Cursor<Vec<u8>>>,
+    root: &std::path::Path,
+    dir: &std::path::Path,
+  ) -> Result<(), Box<dyn std::error::Error>> {
+    let options =
+      zip::write::FileOptions::<()>::default().compression_method(zip::CompressionMethod::Deflated);
+    for entry in fs::read_dir(dir)? {
+      let entry = entry?;
+      let path = entry.path();
+      if entry.file_name() == ".git" {
+        continue;
+      }
+      let rel = path
+        .strip_prefix(root)?
+        .to_string_lossy()
+        .replace('\\', "/");
+      if entry.file_type()?.is_dir() {
+        zip.add_directory(format!("{rel}/"), options)?;
+        add_dir(zip, root, &path)?;
+      } else {
+        zip.start_file(rel, options)?;
+        std::io::Write::write_all(zip, &fs::read(&path)?)?;
+      }
+    }
+    Ok(())
+  }
+
+  let mut zip = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+  add_dir(&mut zip, dir, dir)?;
+  Ok(zip.finish()?.into_inner())
+}
+
+/// Why an extension assigned to a profile didn't load.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExtensionLoadFailureReason {
+  /// The extension or its file is gone.
+  Missing,
+  /// The CRX or ZIP could not be extracted.
+  UnpackFailed,
+  /// No readable `manifest.json` after unpacking.
+  ManifestInvalid,
+  /// Manifest V2, which Wayfern no longer loads.
+  ManifestV2,
+  /// The path can't be passed to `--load-extension`, which splits on commas.
+  UnsupportedPath,
+  /// Passed to the browser, but it never showed up as loaded.
+  NotLoaded,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ExtensionLoadFailure {
+  pub extension_id: String,
+  pub name: String,
+  pub reason: ExtensionLoadFailureReason,
+  pub detail: Option<String>,
+}
+
+impl ExtensionLoadFailure {
+  fn new(
+    extension_id: &str,
+    name: &str,
+    reason: ExtensionLoadFailureReason,
+    detail: Option<String>,
+  ) -> Self {
+    Self {
+      extension_id: extension_id.to_string(),
+      name: name.to_string(),
+      reason,
+      detail,
+    }
+  }
+}
+
+/// Payload of `extensions-load-failed`.
+#[derive(Debug, Clone, Serialize)]
+pub struct ExtensionsLoadFailed {
+  pub profile_id: String,
+  pub profile_name: String,
+  pub failures: Vec<ExtensionLoadFailure>,
+}
+
+/// An extension ready to be passed to `--load-extension`.
+#[derive(Debug, Clone)]
+pub struct PreparedExtension {
+  pub extension_id: String,
+  pub name: String,
+  /// Absolute directory, resolved the way Chromium resolves it.
+  pub path: String,
+  /// The ID Chromium gives the extension, used to verify the launch.
+  pub chromium_id: String,
+}
+
+#[derive(Debug, Default)]
+pub struct PreparedExtensions {
+  pub extensions: Vec<PreparedExtension>,
+  pub failures: Vec<ExtensionLoadFailure>,
+}
+
+impl PreparedExtensions {
+  pub fn paths(&self) -> Vec<String> {
+    self.extensions.iter().map(|e| e.path.clone()).collect()
+  }
+
+  /// Failures plus the prepared extensions whose Chromium ID is not in
+  /// `loaded_ids`.
+  pub fn failures_after_launch(
+    &self,
+    loaded_ids: &std::collections::HashSet<String>,
+  ) -> Vec<ExtensionLoadFailure> {
+    let mut failures = self.failures.clone();
+    failures.extend(
+      self
+        .extensions
+        .iter()
+        .filter(|e| !loaded_ids.contains(&e.chromium_id))
+        .map(|e| {
+          ExtensionLoadFailure::new(
+            &e.extension_id,
+            &e.name,
+            ExtensionLoadFailureReason::NotLoaded,
+            None,
+          )
+        }),
+    );
+    failures
+  }
+}
+
+/// Where a profile's packed extensions are unpacked for launch.
+fn profile_extensions_cache_dir(profile_id: &str) -> PathBuf {
+  extensions_base_dir().join("unpacked").join(profile_id)
+}
+
+/// Drops the unpacked extensions of a deleted profile.
+pub fn remove_profile_extensions_cache(profile_id: &str) {
+  let dir = profile_extensions_cache_dir(profile_id);
+  if dir.exists() {
+    if let Err(e) = fs::remove_dir_all(&dir) {
+      log::warn!("Failed to remove extension cache for profile {profile_id}: {e}");
+    }
+  }
+}
+
+/// Checks an unpacked directory and resolves the path and ID Chromium will
+/// use for it.
+fn prepare_extension_dir(
+  ext: &Extension,
+  dir: &std::path::Path,
+) -> Result<PreparedExtension, ExtensionLoadFailure> {
+  let fail =
+    |reason, detail: Option<String>| ExtensionLoadFailure::new(&ext.id, &ext.name, reason, detail);
+  if !dir.is_dir() {
+    return Err(fail(
+      ExtensionLoadFailureReason::Missing,
+      Some(dir.to_string_lossy().to_string()),
+    ));
+  }
+  let manifest = read_unpacked_manifest(dir).map_err(|e| {
+    fail(
+      ExtensionLoadFailureReason::ManifestInvalid,
+      Some(e.to_string()),
+    )
+  })?;
+  if unpacked_browser_compatibility(&manifest).is_empty() {
+    return Err(fail(ExtensionLoadFailureReason::ManifestV2, None));
+  }
+  let path = chromium_load_path(dir)
+    .map_err(|e| fail(ExtensionLoadFailureReason::Missing, Some(e.to_string())))?;
+  let path_str = path.to_string_lossy().to_string();
+  if path_str.contains(',') {
+    return Err(fail(
+      ExtensionLoadFailureReason::UnsupportedPath,
+      Some(path_str),
+    ));
+  }
+  Ok(PreparedExtension {
+    extension_id: ext.id.clone(),
+    name: ext.name.clone(),
+    chromium_id: chromium_extension_id(&manifest, &path),
+    path: path_str,
+  })
+}
+
+/// Absolute form of `dir` as Chromium resolves `--load-extension` entries.
+/// Windows keeps the plain absolute path, as canonicalizing there adds a
+/// `\\?\` prefix Chromium doesn't use.
+fn chromium_load_path(dir: &std::path::Path) -> std::io::Result<PathBuf> {
+  #[cfg(windows)]
+  {
+    std::path::absolute(dir)
+  }
+  #[cfg(not(windows))]
+  {
+    fs::canonicalize(dir)
+  }
+}
+
+/// The ID Chromium assigns an unpacked extension: a hash of the manifest
+/// `key` when it has one, otherwise of its absolute path, written with the
+/// letters a-p.
+fn chromium_extension_id(manifest: &serde_json::Value, path: &std::path::Path) -> String {
+  use base64::Engine;
+  use sha2::{Digest, Sha256};
+
+  let key = manifest.get("key").and_then(|k| k.as_str()).and_then(|k| {
+    base64::engine::general_purpose::STANDARD
+      .decode(k.trim())
+      .ok()
+  });
+  let hash = match key {
+    Some(key) => Sha256::digest(&key),
+    None => Sha256::digest(chromium_path_bytes(path)),
+  };
+  hash[..16]
+    .iter()
+    .flat_map(|b| [b >> 4, b & 0x0f])
+    .map(|n| (b'a' + n) as char)
+    .collect()
+}
+
+/// Chromium hashes the native path string: UTF-16 with an upper-case drive
+/// letter on Windows, the raw bytes elsewhere.
+#[cfg(windows)]
+fn chromium_path_bytes(path: &std::path::Path) -> Vec<u8> {
+  let mut path = path.to_string_lossy().to_string();
+  if path.as_bytes().get(1) == Some(&b':') {
+    path[..1].make_ascii_uppercase();
+  }
+  path.encode_utf16().flat_map(|u| u.to_le_bytes()).collect()
+}
+
+#[cfg(not(windows))]
+fn chromium_path_bytes(path: &std::path::Path) -> Vec<u8> {
+  path.as_os_str().as_encoded_bytes().to_vec()
+}
+
+/// Extension IDs Chromium recorded in the profile's preference files.
+/// Extensions without a background page or worker never show up as CDP
+/// targets, so this is how those are confirmed.
+pub fn extension_ids_in_prefs(
+  user_data_dir: &std::path::Path,
+) -> std::collections::HashSet<String> {
+  ["Preferences", "Secure Preferences"]
+    .iter()
+    .filter_map(|file| fs::read_to_string(user_data_dir.join("Default").join(file)).ok())
+    .filter_map(|content| serde_json::from_str::<serde_json::Value>(&content).ok())
+    .filter_map(|prefs| {
+      prefs
+        .pointer("/extensions/settings")
+        .and_then(|s| s.as_object())
+        .map(|settings| settings.keys().cloned().collect::<Vec<_>>())
+    })
+    .flatten()
+    .collect()
+}
+
 pub struct ExtensionManager;
 
 impl ExtensionManager {
@@ -256,10 +617,6 @@ impl ExtensionManager {
     self.get_extension_dir(ext_id).join("file")
   }
 
-  pub fn get_file_dir_public(&self, ext_id: &str) -> PathBuf {
-    self.get_file_dir(ext_id)
-  }
-
   // Extension CRUD
 
   pub fn add_extension(
@@ -309,6 +666,8 @@ impl ExtensionManager {
       description,
       author,
       homepage_url,
+      source: None,
+      unpacked_path: None,
     };
 
     let file_dir = self.get_file_dir(&ext.id);
@@ -342,6 +701,94 @@ impl ExtensionManager {
     Ok(ext)
   }
 
+  pub fn add_unpacked_extension(
+    &self,
+    name: String,
+    path: &std::path::Path,
+  ) -> Result<Extension, Box<dyn std::error::Error>> {
+    let dir = fs::canonicalize(path).map_err(|_| {
+      backend_error(
+        "EXTENSION_DIRECTORY_NOT_FOUND",
+        serde_json::json!({ "path": path.to_string_lossy() }),
+      )
+    })?;
+    let manifest = read_unpacked_manifest(&dir)?;
+    let (manifest_name, version, description, author, homepage_url) = manifest_metadata(&manifest);
+
+    // Localized names ("__MSG_appName__") are useless as labels.
+    let final_name = match manifest_name {
+      Some(n) if !n.trim().is_empty() && !n.starts_with("__MSG_") => n,
+      _ => name,
+    };
+    if final_name.trim().is_empty() {
+      return Err(
+        serde_json::json!({ "code": "NAME_CANNOT_BE_EMPTY" })
+          .to_string()
+          .into(),
+      );
+    }
+
+    let now = now_secs();
+    let ext = Extension {
+      id: uuid::Uuid::new_v4().to_string(),
+      name: final_name,
+      file_name: dir
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_else(|| "extension".to_string()),
+      file_type: UNPACKED_FILE_TYPE.to_string(),
+      browser_compatibility: unpacked_browser_compatibility(&manifest),
+      created_at: now,
+      updated_at: now,
+      sync_enabled: crate::sync::is_sync_configured(),
+      last_sync: None,
+      version,
+      description,
+      author,
+      homepage_url,
+      source: None,
+      unpacked_path: Some(dir.to_string_lossy().to_string()),
+    };
+
+    fs::create_dir_all(self.get_extension_dir(&ext.id))?;
+    self.copy_unpacked_icon(&ext.id, &dir, &manifest);
+
+    let json = serde_json::to_string_pretty(&ext)?;
+    fs::write(self.get_metadata_path(&ext.id), json)?;
+
+    if let Err(e) = events::emit_empty("extensions-changed") {
+      log::error!("Failed to emit extensions-changed event: {e}");
+    }
+
+    if ext.sync_enabled {
+      if let Some(scheduler) = crate::sync::get_global_scheduler() {
+        let id = ext.id.clone();
+        tauri::async_runtime::spawn(async move {
+          scheduler.queue_extension_sync(id).await;
+        });
+      }
+    }
+
+    Ok(ext)
+  }
+
+  fn copy_unpacked_icon(&self, ext_id: &str, dir: &std::path::Path, manifest: &serde_json::Value) {
+    let Some(icon_path) = manifest_icon_path(manifest) else {
+      return;
+    };
+    let icon_path = icon_path.trim_start_matches('/');
+    let Ok(data) = fs::read(dir.join(icon_path)) else {
+      return;
+    };
+    let icon_ext = icon_path.rsplit('.').next().unwrap_or("png").to_lowercase();
+    let _ = fs::write(
+      self
+        .get_extension_dir(ext_id)
+        .join(format!("icon.{icon_ext}")),
+      data,
+    );
+  }
+
   pub fn get_extension(&self, id: &str) -> Result<Extension, Box<dyn std::error::Error>> {
     let metadata_path = self.get_metadata_path(id);
     if !metadata_path.exists() {
@@ -404,6 +851,7 @@ impl ExtensionManager {
 
       ext.file_name = new_file_name;
       ext.file_type = new_file_type.clone();
+      ext.unpacked_path = None;
       ext.browser_compatibility = determine_browser_compatibility(&new_file_type);
 
       let (manifest_name, version, description, author, homepage_url) =
@@ -454,9 +902,50 @@ impl ExtensionManager {
     Ok(ext)
   }
 
+  pub fn set_extension_source(
+    &self,
+    id: &str,
+    source: Option<ExtensionSource>,
+  ) -> Result<Extension, Box<dyn std::error::Error>> {
+    let source = source.map(|s| match s {
+      ExtensionSource::ChromeWebStore { id } => ExtensionSource::ChromeWebStore {
+        id: id.trim().to_lowercase(),
+      },
+      ExtensionSource::Amo { slug } => ExtensionSource::Amo {
+        slug: slug.trim().to_string(),
+      },
+      ExtensionSource::Url { url } => ExtensionSource::Url {
+        url: url.trim().to_string(),
+      },
+    });
+    if let Some(source) = &source {
+      source.validate()?;
+    }
+
+    let mut ext = self.get_extension(id)?;
+    ext.source = source;
+    ext.updated_at = now_secs();
+    self.update_extension_internal(&ext)?;
+
+    if let Err(e) = events::emit_empty("extensions-changed") {
+      log::error!("Failed to emit extensions-changed event: {e}");
+    }
+
+    if ext.sync_enabled {
+      if let Some(scheduler) = crate::sync::get_global_scheduler() {
+        let eid = ext.id.clone();
+        tauri::async_runtime::spawn(async move {
+          scheduler.queue_extension_sync(eid).await;
+        });
+      }
+    }
+
+    Ok(ext)
+  }
+
   pub fn delete_extension(
     &self,
-    app_handle: &tauri::AppHandle,
+    app_handle: &crate::app_handle::AppHandle,
     id: &str,
   ) -> Result<(), Box<dyn std::error::Error>> {
     let ext = self.get_extension(id)?;
@@ -638,7 +1127,7 @@ impl ExtensionManager {
 
   pub fn delete_group(
     &self,
-    app_handle: &tauri::AppHandle,
+    app_handle: &crate::app_handle::AppHandle,
     id: &str,
   ) -> Result<(), Box<dyn std::error::Error>> {
     let mut data = self.load_groups_data()?;
@@ -771,6 +1260,64 @@ impl ExtensionManager {
 
   // Sync helpers
 
+  /// Bytes stored remotely for an extension: the packaged file as-is, or a
+  /// zip of the directory for unpacked extensions.
+  pub fn read_sync_payload(
+    &self,
+    ext: &Extension,
+  ) -> Result<Option<Vec<u8>>, Box<dyn std::error::Error>> {
+    if ext.file_type == UNPACKED_FILE_TYPE {
+      return match ext.unpacked_path.as_deref().map(std::path::Path::new) {
+        Some(dir) if dir.is_dir() => Ok(Some(zip_directory(dir)?)),
+        _ => Ok(None),
+      };
+    }
+    let file_path = self.get_file_dir(&ext.id).join(&ext.file_name);
+    if file_path.exists() {
+      Ok(Some(fs::read(file_path)?))
+    } else {
+      Ok(None)
+    }
+  }
+
+  /// Inverse of `read_sync_payload`. Unpacked extensions are extracted into
+  /// the app's own storage and `unpacked_path` is pointed there, unless this
+  /// device already has a working directory for the extension, which is
+  /// never overwritten.
+  pub fn write_sync_payload(
+    &self,
+    ext: &mut Extension,
+    data: &[u8],
+  ) -> Result<(), Box<dyn std::error::Error>> {
+    let file_dir = self.get_file_dir(&ext.id);
+    if ext.file_type != UNPACKED_FILE_TYPE {
+      fs::create_dir_all(&file_dir)?;
+      fs::write(file_dir.join(&ext.file_name), data)?;
+      return Ok(());
+    }
+
+    let managed_dir = file_dir.join(&ext.file_name);
+    let local_dir = self
+      .get_extension(&ext.id)
+      .ok()
+      .and_then(|local| local.unpacked_path)
+      .map(PathBuf::from)
+      .filter(|dir| dir.is_dir() && !dir.starts_with(&file_dir));
+    if let Some(dir) = local_dir {
+      ext.unpacked_path = Some(dir.to_string_lossy().to_string());
+      return Ok(());
+    }
+
+    if file_dir.exists() {
+      fs::remove_dir_all(&file_dir)?;
+    }
+    fs::create_dir_all(&managed_dir)?;
+    let mut archive = zip::ZipArchive::new(std::io::Cursor::new(data))?;
+    archive.extract(&managed_dir)?;
+    ext.unpacked_path = Some(managed_dir.to_string_lossy().to_string());
+    Ok(())
+  }
+
   pub fn update_extension_internal(
     &self,
     ext: &Extension,
@@ -860,7 +1407,20 @@ impl ExtensionManager {
     };
 
     for ext_id in &group.extension_ids {
-      let ext = self.get_extension(ext_id)?;
+      let mut ext = self.get_extension(ext_id)?;
+      if ext.file_type == UNPACKED_FILE_TYPE {
+        // The directory may have changed since it was added, so check the
+        // manifest as it is now.
+        let dir = PathBuf::from(ext.unpacked_path.clone().unwrap_or_default());
+        let manifest = read_unpacked_manifest(&dir)?;
+        if manifest.get("manifest_version").and_then(|v| v.as_u64()) == Some(2) {
+          return Err(backend_error(
+            "EXTENSION_MANIFEST_V2_UNSUPPORTED",
+            serde_json::json!({ "name": ext.name }),
+          ));
+        }
+        ext.browser_compatibility = unpacked_browser_compatibility(&manifest);
+      }
       if !ext
         .browser_compatibility
         .contains(&browser_type.to_string())
@@ -880,58 +1440,96 @@ impl ExtensionManager {
 
   // Launch-time installation
 
+  /// Unpacks the profile's Chromium extensions into its own cache directory
+  /// and returns what to pass to `--load-extension`. Extensions that can't be
+  /// prepared are returned as failures instead of failing the launch.
   pub fn install_extensions_for_profile(
     &self,
     profile: &crate::profile::BrowserProfile,
-    _profile_data_path: &std::path::Path,
-  ) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+  ) -> Result<PreparedExtensions, Box<dyn std::error::Error>> {
+    let mut prepared = PreparedExtensions::default();
     let group_id = match &profile.extension_group_id {
       Some(id) => id,
-      None => return Ok(Vec::new()),
+      None => return Ok(prepared),
     };
 
     let group = self.get_group(group_id)?;
     if group.extension_ids.is_empty() {
-      return Ok(Vec::new());
+      return Ok(prepared);
     }
 
     if profile.browser.as_str() != "wayfern" {
-      return Ok(Vec::new());
+      return Ok(prepared);
     }
 
-    let mut extension_paths = Vec::new();
-
-    // Unpack Chromium extensions and return paths for --load-extension
-    let unpacked_base = extensions_base_dir().join("unpacked");
-    if unpacked_base.exists() {
-      fs::remove_dir_all(&unpacked_base)?;
+    // Per profile, so launching one profile never pulls the files from under
+    // another one that is running.
+    let cache_dir = profile_extensions_cache_dir(&profile.id.to_string());
+    if cache_dir.exists() {
+      fs::remove_dir_all(&cache_dir)?;
     }
-    fs::create_dir_all(&unpacked_base)?;
+    fs::create_dir_all(&cache_dir)?;
 
     for ext_id in &group.extension_ids {
-      if let Ok(ext) = self.get_extension(ext_id) {
-        if !ext.browser_compatibility.contains(&"chromium".to_string()) {
+      let ext = match self.get_extension(ext_id) {
+        Ok(ext) => ext,
+        Err(_) => {
+          prepared.failures.push(ExtensionLoadFailure::new(
+            ext_id,
+            ext_id,
+            ExtensionLoadFailureReason::Missing,
+            None,
+          ));
           continue;
         }
+      };
+      let result = if ext.file_type == UNPACKED_FILE_TYPE {
+        // Loaded in place so edits show up on the next launch. The manifest
+        // is re-read because the directory may have moved to MV3 (or back)
+        // since it was added.
+        let dir = PathBuf::from(ext.unpacked_path.clone().unwrap_or_default());
+        prepare_extension_dir(&ext, &dir)
+      } else if !ext.browser_compatibility.contains(&"chromium".to_string()) {
+        continue;
+      } else {
         let src_file = self.get_file_dir(ext_id).join(&ext.file_name);
-        if src_file.exists() {
-          let unpack_dir = unpacked_base.join(ext_id);
+        let unpack_dir = cache_dir.join(ext_id);
+        if !src_file.exists() {
+          Err(ExtensionLoadFailure::new(
+            &ext.id,
+            &ext.name,
+            ExtensionLoadFailureReason::Missing,
+            None,
+          ))
+        } else {
           fs::create_dir_all(&unpack_dir)?;
-
-          // Extract .crx or .zip
-          match Self::unpack_extension(&src_file, &unpack_dir) {
-            Ok(()) => {
-              extension_paths.push(unpack_dir.to_string_lossy().to_string());
-            }
-            Err(e) => {
-              log::warn!("Failed to unpack extension '{}': {}", ext.name, e);
-            }
-          }
+          Self::unpack_extension(&src_file, &unpack_dir)
+            .map_err(|e| {
+              ExtensionLoadFailure::new(
+                &ext.id,
+                &ext.name,
+                ExtensionLoadFailureReason::UnpackFailed,
+                Some(e.to_string()),
+              )
+            })
+            .and_then(|()| prepare_extension_dir(&ext, &unpack_dir))
+        }
+      };
+      match result {
+        Ok(extension) => prepared.extensions.push(extension),
+        Err(failure) => {
+          log::warn!(
+            "Extension '{}' not loaded: {:?} {}",
+            failure.name,
+            failure.reason,
+            failure.detail.as_deref().unwrap_or("")
+          );
+          prepared.failures.push(failure);
         }
       }
     }
 
-    Ok(extension_paths)
+    Ok(prepared)
   }
 
   fn unpack_extension(
@@ -1108,6 +1706,14 @@ pub async fn add_extension(
     .map_err(|e| crate::wrap_backend_error(e, "Failed to add extension"))
 }
 
+#[tauri::command]
+pub async fn add_unpacked_extension(name: String, path: String) -> Result<Extension, String> {
+  let mgr = EXTENSION_MANAGER.lock().unwrap();
+  mgr
+    .add_unpacked_extension(name, std::path::Path::new(&path))
+    .map_err(|e| crate::wrap_backend_error(e, "Failed to add unpacked extension"))
+}
+
 #[tauri::command]
 pub async fn update_extension(
   extension_id: String,
@@ -1121,9 +1727,20 @@ pub async fn update_extension(
     .map_err(|e| format!("Failed to update extension: {e}"))
 }
 
+#[tauri::command]
+pub async fn set_extension_source(
+  extension_id: String,
+  source: Option<ExtensionSource>,
+) -> Result<Extension, String> {
+  let mgr = EXTENSION_MANAGER.lock().unwrap();
+  mgr
+    .set_extension_source(&extension_id, source)
+    .map_err(|e| crate::wrap_backend_error(e, "Failed to set extension source"))
+}
+
 #[tauri::command]
 pub async fn delete_extension(
-  app_handle: tauri::AppHandle,
+  app_handle: crate::app_handle::AppHandle,
   extension_id: String,
 ) -> Result<(), String> {
   let mgr = EXTENSION_MANAGER.lock().unwrap();
@@ -1162,7 +1779,7 @@ pub async fn update_extension_group(
 
 #[tauri::command]
 pub async fn delete_extension_group(
-  app_handle: tauri::AppHandle,
+  app_handle: crate::app_handle::AppHandle,
   group_id: String,
 ) -> Result<(), String> {
   let mgr = EXTENSION_MANAGER.lock().unwrap();
@@ -1272,6 +1889,27 @@ mod tests {
     assert_eq!(determine_browser_compatibility("xpi"), Vec::<String>::new());
   }
 
+  #[test]
+  fn test_extension_source_validation() {
+    let cws = |id: &str| ExtensionSource::ChromeWebStore { id: id.to_string() };
+    assert!(cws("cjpalhdlnbpafiamejdnhcphjbkeiagm").validate().is_ok());
+    assert!(cws("cjpalhdlnbpafiamejdnhcphjbkeiagz").validate().is_err());
+    assert!(cws("short").validate().is_err());
+
+    let amo = |slug: &str| ExtensionSource::Amo {
+      slug: slug.to_string(),
+    };
+    assert!(amo("ublock-origin").validate().is_ok());
+    assert!(amo("../etc").validate().is_err());
+    assert!(amo("").validate().is_err());
+
+    let url = |url: &str| ExtensionSource::Url {
+      url: url.to_string(),
+    };
+    assert!(url("https://example.com/ext.crx").validate().is_ok());
+    assert!(url("file:///tmp/ext.crx").validate().is_err());
+  }
+
   #[test]
   fn test_extension_manager_crud() {
     let tmp = tempfile::tempdir().unwrap();
@@ -1377,6 +2015,149 @@ mod tests {
       .is_ok());
   }
 
+  #[test]
+  fn test_unpacked_extension_validation_and_sync_payload() {
+    let tmp = tempfile::tempdir().unwrap();
+    let _guard = crate::app_dirs::set_test_data_dir(tmp.path().join("data"));
+
+    let src = tmp.path().join("my-ext");
+    fs::create_dir_all(src.join("js")).unwrap();
+    fs::write(
+      src.join("manifest.json"),
+      r#"{"manifest_version": 3, "name": "Dev Ext", "version": "0.1.0"}"#,
+    )
+    .unwrap();
+    fs::write(src.join("js/background.js"), "console.log(1);").unwrap();
+
+    let mgr = ExtensionManager::new();
+    let ext = mgr
+      .add_unpacked_extension("Fallback".to_string(), &src)
+      .unwrap();
+    assert_eq!(ext.name, "Dev Ext");
+    assert_eq!(ext.file_type, UNPACKED_FILE_TYPE);
+    assert_eq!(ext.browser_compatibility, vec!["chromium".to_string()]);
+
+    let group = mgr.create_group("Dev".to_string()).unwrap();
+    mgr.add_extension_to_group(&group.id, &ext.id).unwrap();
+    assert!(mgr
+      .validate_group_compatibility(&group.id, "wayfern")
+      .is_ok());
+
+    // Downgrading the directory to MV2 is caught at assignment time.
+    fs::write(
+      src.join("manifest.json"),
+      r#"{"manifest_version": 2, "name": "Dev Ext", "version": "0.1.0"}"#,
+    )
+    .unwrap();
+    let err = mgr
+      .validate_group_compatibility(&group.id, "wayfern")
+      .unwrap_err()
+      .to_string();
+    assert!(err.contains("EXTENSION_MANIFEST_V2_UNSUPPORTED"));
+
+    // A device without the source directory gets an extracted copy.
+    let payload = mgr.read_sync_payload(&ext).unwrap().unwrap();
+    let mut remote = ext.clone();
+    remote.id = "remote-ext".to_string();
+    remote.unpacked_path = Some("/nonexistent/my-ext".to_string());
+    mgr.write_sync_payload(&mut remote, &payload).unwrap();
+    let extracted = PathBuf::from(remote.unpacked_path.unwrap());
+    assert!(extracted.starts_with(mgr.get_file_dir("remote-ext")));
+    assert_eq!(
+      fs::read_to_string(extracted.join("js/background.js")).unwrap(),
+      "console.log(1);"
+    );
+
+    assert!(mgr
+      .add_unpacked_extension("Missing".to_string(), &tmp.path().join("nope"))
+      .unwrap_err()
+      .to_string()
+      .contains("EXTENSION_DIRECTORY_NOT_FOUND"));
+  }
+
+  #[test]
+  fn test_launch_preparation_with_spaces_and_corrupt_crx() {
+    let tmp = tempfile::tempdir().unwrap();
+    let _guard = crate::app_dirs::set_test_data_dir(tmp.path().join("data dir with spaces"));
+
+    let src = tmp.path().join("good ext");
+    fs::create_dir_all(&src).unwrap();
+    fs::write(
+      src.join("manifest.json"),
+      r#"{"manifest_version": 3, "name": "Good", "version": "1.0"}"#,
+    )
+    .unwrap();
+    let good_zip = zip_directory(&src).unwrap();
+    let mut good_crx = b"Cr24\x03\x00\x00\x00\x08\x00\x00\x00headerxx".to_vec();
+    good_crx.extend_from_slice(&good_zip);
+
+    let mgr = ExtensionManager::new();
+    let good = mgr
+      .add_extension("Good".to_string(), "good.crx".to_string(), good_crx)
+      .unwrap();
+    let corrupt = mgr
+      .add_extension(
+        "Corrupt".to_string(),
+        "corrupt.crx".to_string(),
+        b"Cr24\x03\x00\x00\x00not a zip archive".to_vec(),
+      )
+      .unwrap();
+    let group = mgr.create_group("Launch".to_string()).unwrap();
+    mgr.add_extension_to_group(&group.id, &good.id).unwrap();
+    mgr.add_extension_to_group(&group.id, &corrupt.id).unwrap();
+
+    let profile = crate::profile::BrowserProfile {
+      id: uuid::Uuid::new_v4(),
+      browser: "wayfern".to_string(),
+      extension_group_id: Some(group.id.clone()),
+      ..Default::default()
+    };
+    let prepared = mgr.install_extensions_for_profile(&profile).unwrap();
+
+    assert_eq!(prepared.extensions.len(), 1);
+    let loaded = &prepared.extensions[0];
+    assert_eq!(loaded.extension_id, good.id);
+    let path = std::path::Path::new(&loaded.path);
+    assert!(path.is_absolute());
+    assert!(loaded.path.contains("data dir with spaces"));
+    assert!(path.join("manifest.json").exists());
+    assert_eq!(prepared.paths(), vec![loaded.path.clone()]);
+
+    assert_eq!(prepared.failures.len(), 1);
+    assert_eq!(prepared.failures[0].extension_id, corrupt.id);
+    assert_eq!(
+      prepared.failures[0].reason,
+      ExtensionLoadFailureReason::UnpackFailed
+    );
+
+    // Verification flags the prepared extension until its ID shows up.
+    let none = std::collections::HashSet::new();
+    let after = prepared.failures_after_launch(&none);
+    assert_eq!(after.len(), 2);
+    assert_eq!(after[1].reason, ExtensionLoadFailureReason::NotLoaded);
+    let seen = std::collections::HashSet::from([loaded.chromium_id.clone()]);
+    assert_eq!(prepared.failures_after_launch(&seen).len(), 1);
+  }
+
+  #[test]
+  fn test_chromium_extension_id() {
+    let manifest = serde_json::json!({ "manifest_version": 3 });
+    let id = chromium_extension_id(&manifest, std::path::Path::new("/tmp/a b/ext"));
+    assert_eq!(id.len(), 32);
+    assert!(id.chars().all(|c| ('a'..='p').contains(&c)));
+    assert_ne!(
+      id,
+      chromium_extension_id(&manifest, std::path::Path::new("/tmp/a b/other"))
+    );
+
+    // A manifest key pins the ID regardless of where the files are.
+    let keyed = serde_json::json!({ "manifest_version": 3, "key": "AAEC" });
+    assert_eq!(
+      chromium_extension_id(&keyed, std::path::Path::new("/one")),
+      chromium_extension_id(&keyed, std::path::Path::new("/two"))
+    );
+  }
+
   #[test]
   fn test_find_zip_start() {
     let data = vec![0x00, 0x00, 0x50, 0x4B, 0x03, 0x04, 0xFF];
@@ -0,0 +1,386 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::time::Duration;
+
+use crate::events;
+use crate::extension_manager::{extract_manifest_metadata, Extension, ExtensionSource};
+
+/// Chromium version reported to the Web Store update service. The service
+/// only serves packages whose `minimum_chrome_version` fits, so this should
+/// track the Wayfern major roughly.
+const CWS_PRODVERSION: &str = "140.0.0.0";
+
+pub const EXTENSION_UPDATE_INTERVAL_SECS: u64 = 24 * 60 * 60;
+
+lazy_static::lazy_static! {
+  // Serializes the background check with manual checks so two runs never
+  // swap the same extension file concurrently.
+  static ref CHECK_LOCK: tokio::sync::Mutex<()> = tokio::sync::Mutex::new(());
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExtensionUpdateStatus {
+  UpToDate,
+  Updated,
+  /// A newer version exists but a running profile uses the extension.
+  Deferred,
+  Failed,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExtensionUpdateResult {
+  pub extension_id: String,
+  pub name: String,
+  pub status: ExtensionUpdateStatus,
+  pub current_version: Option<String>,
+  pub available_version: Option<String>,
+  pub error: Option<String>,
+}
+
+struct RemoteRelease {
+  /// Known up front for the stores; `None` for direct URLs, where the
+  /// version is read from the downloaded manifest.
+  version: Option<String>,
+  download_url: String,
+}
+
+fn http_client() -> Result<reqwest::Client, String> {
+  reqwest::Client::builder()
+    .timeout(Duration::from_secs(60))
+    .user_agent(format!("DonutBrowser/{}", env!("CARGO_PKG_VERSION")))
+    .build()
+    .map_err(|e| e.to_string())
+}
+
+/// Pulls `name="value"` out of the first tag in `xml` carrying that
+/// attribute. The update2 response is small and flat, so this avoids an XML
+/// dependency.
+fn xml_attr<'a>(xml: &'a str, tag: &str, name: &str) -> Option<&'a str> {
+  let start = xml.find(&format!("<{tag} "))?;
+  let end = start + xml[start..].find('>')?;
+  let element = &xml[start..end];
+  let needle = format!(" {name}=\"");
+  let value_start = element.find(&needle)? + needle.len();
+  let value_len = element[value_start..].find('"')?;
+  Some(&element[value_start..value_start + value_len])
+}
+
+fn parse_cws_response(xml: &str) -> Result<Option<RemoteRelease>, String> {
+  match xml_attr(xml, "updatecheck", "status") {
+    Some("ok") => {}
+    Some("noupdate") => return Ok(None),
+    Some(other) => return Err(format!("Chrome Web Store returned status '{other}'")),
+    None => return Err("Malformed Chrome Web Store update response".to_string()),
+  }
+  let download_url = xml_attr(xml, "updatecheck", "codebase")
+    .ok_or("Chrome Web Store response has no download URL")?
+    .replace("&amp;", "&");
+  Ok(Some(RemoteRelease {
+    version: xml_attr(xml, "updatecheck", "version").map(str::to_string),
+    download_url,
+  }))
+}
+
+async fn fetch_release(
+  client: &reqwest::Client,
+  source: &ExtensionSource,
+) -> Result<Option<RemoteRelease>, String> {
+  match source {
+    ExtensionSource::ChromeWebStore { id } => {
+      // v=0.0.0.0 makes the service always answer with the latest release;
+      // the comparison against the stored version happens locally.
+      let url = format!(
+        "https://clients2.google.com/service/update2/crx?response=updatecheck&acceptformat=crx3&prodversion={CWS_PRODVERSION}&x=id%3D{id}%26v%3D0.0.0.0%26uc"
+      );
+      let body = client
+        .get(url)
+        .send()
+        .await
+        .and_then(|r| r.error_for_status())
+        .map_err(|e| e.to_string())?
+        .text()
+        .await
+        .map_err(|e| e.to_string())?;
+      parse_cws_response(&body)
+    }
+    ExtensionSource::Amo { slug } => {
+      let url = format!("https://addons.mozilla.org/api/v5/addons/addon/{slug}/");
+      let body: serde_json::Value = client
+        .get(url)
+        .send()
+        .await
+        .and_then(|r| r.error_for_status())
+        .map_err(|e| e.to_string())?
+        .json()
+        .await
+        .map_err(|e| e.to_string())?;
+      let current = &body["current_version"];
+      let download_url = current["file"]["url"]
+        .as_str()
+        .ok_or("AMO response has no download URL")?
+        .to_string();
+      Ok(Some(RemoteRelease {
+        version: current["version"].as_str().map(str::to_string),
+        download_url,
+      }))
+    }
+    ExtensionSource::Url { url } => Ok(Some(RemoteRelease {
+      version: None,
+      download_url: url.clone(),
+    })),
+  }
+}
+
+async fn download(client: &reqwest::Client, url: &str) -> Result<Vec<u8>, String> {
+  let bytes = client
+    .get(url)
+    .send()
+    .await
+    .and_then(|r| r.error_for_status())
+    .map_err(|e| e.to_string())?
+    .bytes()
+    .await
+    .map_err(|e| e.to_string())?;
+  Ok(bytes.to_vec())
+}
+
+/// Dotted-numeric comparison; falls back to plain inequality when either
+/// side isn't purely numeric.
+fn is_newer_version(candidate: &str, current: Option<&str>) -> bool {
+  let Some(current) = current else {
+    return true;
+  };
+  let parse = |v: &str| {
+    v.split('.')
+      .map(|p| p.parse::<u64>())
+      .collect::<Result<Vec<_>, _>>()
+  };
+  match (parse(candidate), parse(current)) {
+    (Ok(mut a), Ok(mut b)) => {
+      let len = a.len().max(b.len());
+      a.resize(len, 0);
+      b.resize(len, 0);
+      a > b
+    }
+    _ => candidate != current,
+  }
+}
+
+/// Names the downloaded package after its contents: CRX3 packages start with
+/// the `Cr24` magic, everything else (including AMO's .xpi) is a plain zip.
+fn package_file_name(ext: &Extension, data: &[u8]) -> String {
+  let stem = ext
+    .file_name
+    .rsplit_once('.')
+    .map(|(stem, _)| stem)
+    .unwrap_or(&ext.file_name);
+  let file_type = if data.starts_with(b"Cr24") {
+    "crx"
+  } else {
+    "zip"
+  };
+  format!("{stem}.{file_type}")
+}
+
+/// Extension ids referenced by the extension group of any running profile.
+fn extensions_in_use() -> HashSet<String> {
+  let profiles = crate::profile::ProfileManager::instance()
+    .list_profiles()
+    .unwrap_or_default();
+  let mgr = crate::extension_manager::EXTENSION_MANAGER.lock().unwrap();
+  profiles
+    .iter()
+    .filter(|p| p.process_id.is_some())
+    .filter_map(|p| p.extension_group_id.as_deref())
+    .filter_map(|group_id| mgr.get_group(group_id).ok())
+    .flat_map(|group| group.extension_ids)
+    .collect()
+}
+
+async fn update_one(
+  client: &reqwest::Client,
+  ext: &Extension,
+  source: &ExtensionSource,
+  in_use: bool,
+  result: &mut ExtensionUpdateResult,
+) -> Result<(), String> {
+  let Some(release) = fetch_release(client, source).await? else {
+    return Ok(());
+  };
+  if let Some(version) = &release.version {
+    result.available_version = Some(version.clone());
+    if !is_newer_version(version, ext.version.as_deref()) {
+      return Ok(());
+    }
+    if in_use {
+      result.status = ExtensionUpdateStatus::Deferred;
+      return Ok(());
+    }
+  }
+
+  let data = download(client, &release.download_url).await?;
+  let file_name = package_file_name(ext, &data);
+  let file_type = if file_name.ends_with(".crx") {
+    "crx"
+  } else {
+    "zip"
+  };
+  let (_, manifest_version, ..) = extract_manifest_metadata(&data, file_type);
+  let version = manifest_version
+    .or(release.version)
+    .ok_or("Downloaded package has no manifest version")?;
+  result.available_version = Some(version.clone());
+  if !is_newer_version(&version, ext.version.as_deref()) {
+    return Ok(());
+  }
+  if in_use {
+    result.status = ExtensionUpdateStatus::Deferred;
+    return Ok(());
+  }
+
+  // update_extension swaps the file, re-reads the manifest and re-queues
+  // sync for sync-enabled extensions. The name is passed through so a
+  // store-side rename doesn't overwrite the user's label.
+  let mgr = crate::extension_manager::EXTENSION_MANAGER.lock().unwrap();
+  mgr
+    .update_extension(&ext.id, Some(ext.name.clone()), Some(file_name), Some(data))
+    .map_err(|e| e.to_string())?;
+  result.status = ExtensionUpdateStatus::Updated;
+  Ok(())
+}
+
+/// Checks every extension with a source (or just `extension_ids`) for a newer
+/// release and installs it. Extensions used by a running profile are left
+/// untouched and reported through `extensions-update-pending`; the next check
+/// after those profiles stop picks them up.
+pub async fn check_extension_updates_impl(
+  extension_ids: Option<Vec<String>>,
+) -> Result<Vec<ExtensionUpdateResult>, String> {
+  let _guard = CHECK_LOCK.lock().await;
+
+  let extensions = {
+    let mgr = crate::extension_manager::EXTENSION_MANAGER.lock().unwrap();
+    mgr
+      .list_extensions()
+      .map_err(|e| format!("Failed to list extensions: {e}"))?
+  };
+  let in_use = extensions_in_use();
+  let client = http_client()?;
+
+  let mut results = Vec::new();
+  for ext in extensions {
+    if let Some(ids) = &extension_ids {
+      if !ids.contains(&ext.id) {
+        continue;
+      }
+    }
+    if ext.file_type == crate::extension_manager::UNPACKED_FILE_TYPE {
+      continue;
+    }
+    let Some(source) = ext.source.clone() else {
+      continue;
+    };
+
+    let mut result = ExtensionUpdateResult {
+      extension_id: ext.id.clone(),
+      name: ext.name.clone(),
+      status: ExtensionUpdateStatus::UpToDate,
+      current_version: ext.version.clone(),
+      available_version: None,
+      error: None,
+    };
+    if let Err(e) = update_one(
+      &client,
+      &ext,
+      &source,
+      in_use.contains(&ext.id),
+      &mut result,
+    )
+    .await
+    {
+      log::warn!("Failed to update extension '{}': {}", ext.name, e);
+      result.status = ExtensionUpdateStatus::Failed;
+      result.error = Some(e);
+    }
+    results.push(result);
+  }
+
+  let deferred: Vec<ExtensionUpdateResult> = results
+    .iter()
+    .filter(|r| r.status == ExtensionUpdateStatus::Deferred)
+    .cloned()
+    .collect();
+  if !deferred.is_empty() {
+    if let Err(e) = events::emit(&events::payloads::ExtensionsUpdatePending(deferred)) {
+      log::error!("Failed to emit extensions-update-pending event: {e}");
+    }
+  }
+
+  Ok(results)
+}
+
+/// Runs the periodic check when `auto_update_extensions` is enabled.
+pub async fn run_background_check() {
+  if crate::offline::is_offline() {
+    return;
+  }
+  let enabled = crate::settings_manager::SettingsManager::instance()
+    .load_settings()
+    .map(|s| s.auto_update_extensions)
+    .unwrap_or(false);
+  if !enabled {
+    return;
+  }
+  match check_extension_updates_impl(None).await {
+    Ok(results) => {
+      let updated = results
+        .iter()
+        .filter(|r| r.status == ExtensionUpdateStatus::Updated)
+        .count();
+      log::info!("Extension update check finished, {updated} updated");
+    }
+    Err(e) => log::error!("Extension update check failed: {e}"),
+  }
+}
+
+#[tauri::command]
+pub async fn check_extension_updates(
+  extension_ids: Option<Vec<String>>,
+) -> Result<Vec<ExtensionUpdateResult>, String> {
+  check_extension_updates_impl(extension_ids).await
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_is_newer_version() {
+    assert!(is_newer_version("1.2.0", Some("1.1.9")));
+    assert!(is_newer_version("1.10", Some("1.9.5")));
+    assert!(!is_newer_version("1.2", Some("1.2.0")));
+    assert!(!is_newer_version("1.0.0", Some("1.0.1")));
+    assert!(is_newer_version("1.0.0", None));
+    assert!(is_newer_version("2024-beta", Some("2023-beta")));
+    assert!(!is_newer_version("beta", Some("beta")));
+  }
+
+  #[test]
+  fn test_parse_cws_response() {
+    let xml = r#"<?xml version="1.0" encoding="UTF-8"?><gupdate xmlns="http://www.google.com/update2/response" protocol="2.0"><app appid="cjpalhdlnbpafiamejdnhcphjbkeiagm" status="ok"><updatecheck codebase="https://clients2.googleusercontent.com/crx/blobs/abc/ext.crx?a=1&amp;b=2" fp="1.x" hash_sha256="00" size="10" status="ok" version="1.62.0"/></app></gupdate>"#;
+    let release = parse_cws_response(xml).unwrap().unwrap();
+    assert_eq!(release.version.as_deref(), Some("1.62.0"));
+    assert_eq!(
+      release.download_url,
+      "https://clients2.googleusercontent.com/crx/blobs/abc/ext.crx?a=1&b=2"
+    );
+
+    let none =
+      r#"<gupdate><app appid="x" status="ok"><updatecheck status="noupdate"/></app></gupdate>"#;
+    assert!(parse_cws_response(none).unwrap().is_none());
+
+    let bad = r#"<gupdate><app appid="x" status="error-unknownApplication"><updatecheck status="error-unknownApplication"/></app></gupdate>"#;
+    assert!(parse_cws_response(bad).is_err());
+  }
+}
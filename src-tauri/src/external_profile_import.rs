@@ -0,0 +1,699 @@
+//! Import profiles exported from other anti-detect browsers (AdsPower and
+//! Multilogin-style JSON). Each exported profile becomes a Wayfern profile
+//! whose fingerprint is generated from the exported constraints (OS, screen,
+//! locale) rather than copying the user agent verbatim.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::browser::ProxySettings;
+use crate::profile::ProfileManager;
+use crate::profile_importer::{emit_import_progress, error_to_code_string, resolve_duplicate_name};
+use crate::proxy_manager::PROXY_MANAGER;
+use crate::wayfern_manager::WayfernConfig;
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ExternalFormat {
+  AdsPower,
+  Multilogin,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ExternalProfileImportResult {
+  pub name: String,
+  pub source_file: String,
+  pub format: Option<ExternalFormat>,
+  /// "imported" | "failed"
+  pub status: String,
+  pub profile_id: Option<String>,
+  pub proxy_id: Option<String>,
+  pub cookies_imported: usize,
+  /// Structured `{"code": …}` strings for data that could not be carried over.
+  pub warnings: Vec<String>,
+  /// Structured `{"code": …}` error string when status is "failed".
+  pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ExternalImportResult {
+  pub imported_count: usize,
+  pub failed_count: usize,
+  pub proxies_created: usize,
+  pub results: Vec<ExternalProfileImportResult>,
+}
+
+/// One exported profile, normalized across formats.
+#[derive(Debug, Default)]
+struct ExternalProfile {
+  name: String,
+  proxy: Option<ProxySettings>,
+  config: WayfernConfig,
+  cookies: Option<String>,
+  tags: Vec<String>,
+  note: Option<String>,
+  warnings: Vec<String>,
+}
+
+fn warning(code: &str, params: Value) -> String {
+  serde_json::json!({ "code": code, "params": params }).to_string()
+}
+
+fn str_field<'a>(value: &'a Value, keys: &[&str]) -> Option<&'a str> {
+  keys
+    .iter()
+    .find_map(|k| value.get(*k).and_then(|v| v.as_str()))
+    .map(str::trim)
+    .filter(|s| !s.is_empty())
+}
+
+/// Numbers show up as strings in AdsPower exports and as numbers in
+/// Multilogin's.
+fn num_field(value: &Value, key: &str) -> Option<u64> {
+  match value.get(key)? {
+    Value::Number(n) => n.as_u64(),
+    Value::String(s) => s.trim().parse().ok(),
+    _ => None,
+  }
+}
+
+fn port_field(value: &Value, key: &str) -> Option<u16> {
+  num_field(value, key).and_then(|p| u16::try_from(p).ok())
+}
+
+fn os_from_user_agent(ua: &str) -> Option<&'static str> {
+  if ua.contains("Windows") {
+    Some("windows")
+  } else if ua.contains("Macintosh") || ua.contains("Mac OS X") {
+    Some("macos")
+  } else if ua.contains("Linux") && !ua.contains("Android") {
+    Some("linux")
+  } else {
+    None
+  }
+}
+
+fn normalize_os(os: &str) -> Option<&'static str> {
+  match os.to_lowercase().as_str() {
+    "win" | "windows" => Some("windows"),
+    "mac" | "macos" | "osx" => Some("macos"),
+    "lin" | "linux" => Some("linux"),
+    _ => None,
+  }
+}
+
+/// Parses "1920_1080", "1920x1080" or "1920*1080".
+fn parse_resolution(raw: &str) -> Option<(u32, u32)> {
+  let (w, h) = raw.split_once(['_', 'x', 'X', '*'])?;
+  Some((w.trim().parse().ok()?, h.trim().parse().ok()?))
+}
+
+fn apply_screen(config: &mut WayfernConfig, (width, height): (u32, u32)) {
+  config.screen_min_width = Some(width);
+  config.screen_max_width = Some(width);
+  config.screen_min_height = Some(height);
+  config.screen_max_height = Some(height);
+}
+
+fn proxy_from(
+  proxy_type: Option<&str>,
+  host: Option<&str>,
+  port: Option<u16>,
+  username: Option<&str>,
+  password: Option<&str>,
+  warnings: &mut Vec<String>,
+) -> Option<ProxySettings> {
+  let proxy_type = proxy_type?.to_lowercase();
+  if matches!(proxy_type.as_str(), "" | "none" | "no_proxy" | "direct") {
+    return None;
+  }
+  if !matches!(proxy_type.as_str(), "http" | "https" | "socks4" | "socks5") {
+    warnings.push(warning(
+      "IMPORT_UNSUPPORTED_PROXY_TYPE",
+      serde_json::json!({ "type": proxy_type }),
+    ));
+    return None;
+  }
+  Some(ProxySettings {
+    proxy_type,
+    host: host?.to_string(),
+    port: port?,
+    username: username.map(str::to_string),
+    password: password.map(str::to_string),
+    client_cert_path: None,
+    client_key_path: None,
+  })
+}
+
+/// Cookies are either an inline JSON array or a string holding one.
+fn cookies_from(value: Option<&Value>) -> Option<String> {
+  match value? {
+    Value::Array(arr) if !arr.is_empty() => serde_json::to_string(arr).ok(),
+    Value::String(s) if s.trim().starts_with('[') => Some(s.trim().to_string()),
+    _ => None,
+  }
+}
+
+fn detect_format(entry: &Value) -> Option<ExternalFormat> {
+  if entry.get("user_proxy_config").is_some() || entry.get("fingerprint_config").is_some() {
+    Some(ExternalFormat::AdsPower)
+  } else if entry.get("navigator").is_some()
+    || entry.get("proxy").is_some_and(|p| p.is_object())
+    || entry.get("os").is_some()
+  {
+    Some(ExternalFormat::Multilogin)
+  } else {
+    None
+  }
+}
+
+fn parse_adspower(entry: &Value) -> ExternalProfile {
+  let mut profile = ExternalProfile {
+    name: str_field(entry, &["name", "serial_number", "user_id"])
+      .unwrap_or_default()
+      .to_string(),
+    note: str_field(entry, &["remark"]).map(str::to_string),
+    cookies: cookies_from(entry.get("cookie")),
+    ..Default::default()
+  };
+  if let Some(group) = str_field(entry, &["group_name"]) {
+    profile.tags.push(group.to_string());
+  }
+
+  if let Some(proxy) = entry.get("user_proxy_config") {
+    let soft = str_field(proxy, &["proxy_soft"]).unwrap_or("other");
+    let proxy_type = if soft == "no_proxy" {
+      None
+    } else {
+      str_field(proxy, &["proxy_type"])
+    };
+    profile.proxy = proxy_from(
+      proxy_type,
+      str_field(proxy, &["proxy_host"]),
+      port_field(proxy, "proxy_port"),
+      str_field(proxy, &["proxy_user"]),
+      str_field(proxy, &["proxy_password"]),
+      &mut profile.warnings,
+    );
+  }
+
+  if let Some(fp) = entry.get("fingerprint_config") {
+    profile.config.os = str_field(fp, &["ua"])
+      .and_then(os_from_user_agent)
+      .map(str::to_string);
+    if let Some(resolution) = str_field(fp, &["screen_resolution"]).and_then(parse_resolution) {
+      apply_screen(&mut profile.config, resolution);
+    }
+    if let Some(lang) = fp
+      .get("language")
+      .and_then(|l| l.as_array())
+      .and_then(|l| l.first())
+      .and_then(|l| l.as_str())
+    {
+      profile.config.locale_override = Some(lang.to_string());
+    }
+    if str_field(fp, &["webrtc"]) == Some("disabled") {
+      profile.config.block_webrtc = Some(true);
+    }
+    if str_field(fp, &["automatic_timezone"]) == Some("0") && str_field(fp, &["timezone"]).is_some()
+    {
+      profile
+        .warnings
+        .push(warning("IMPORT_TIMEZONE_IGNORED", serde_json::json!({})));
+    }
+  }
+
+  profile
+}
+
+fn parse_multilogin(entry: &Value) -> ExternalProfile {
+  let mut profile = ExternalProfile {
+    name: str_field(entry, &["name", "title"])
+      .unwrap_or_default()
+      .to_string(),
+    note: str_field(entry, &["notes", "note"]).map(str::to_string),
+    cookies: cookies_from(entry.get("cookies")),
+    tags: entry
+      .get("tags")
+      .and_then(|t| t.as_array())
+      .map(|t| {
+        t.iter()
+          .filter_map(|v| v.as_str().map(str::to_string))
+          .collect()
+      })
+      .unwrap_or_default(),
+    ..Default::default()
+  };
+
+  if let Some(proxy) = entry.get("proxy").filter(|p| p.is_object()) {
+    profile.proxy = proxy_from(
+      str_field(proxy, &["type"]),
+      str_field(proxy, &["host"]),
+      port_field(proxy, "port"),
+      str_field(proxy, &["username"]),
+      str_field(proxy, &["password"]),
+      &mut profile.warnings,
+    );
+  }
+
+  let navigator = entry.get("navigator");
+  profile.config.os = str_field(entry, &["os"])
+    .and_then(normalize_os)
+    .or_else(|| {
+      navigator
+        .and_then(|n| str_field(n, &["userAgent"]))
+        .and_then(os_from_user_agent)
+    })
+    .map(str::to_string);
+  if let Some(lang) = navigator.and_then(|n| str_field(n, &["language"])) {
+    // "en-US,en;q=0.9" -> "en-US"
+    let primary = lang.split(',').next().unwrap_or(lang).trim();
+    profile.config.locale_override = Some(primary.to_string());
+  }
+
+  if let Some(screen) = entry.get("screen") {
+    let dimension = |key: &str| num_field(screen, key).and_then(|v| u32::try_from(v).ok());
+    let resolution = match (dimension("width"), dimension("height")) {
+      (Some(w), Some(h)) => Some((w, h)),
+      _ => str_field(screen, &["resolution"]).and_then(parse_resolution),
+    };
+    if let Some(resolution) = resolution {
+      apply_screen(&mut profile.config, resolution);
+    }
+  }
+
+  if entry
+    .get("webRTC")
+    .and_then(|w| str_field(w, &["mode"]))
+    .is_some_and(|m| m.eq_ignore_ascii_case("disabled"))
+  {
+    profile.config.block_webrtc = Some(true);
+  }
+  if entry
+    .get("timezone")
+    .and_then(|tz| str_field(tz, &["value"]))
+    .is_some()
+  {
+    profile
+      .warnings
+      .push(warning("IMPORT_TIMEZONE_IGNORED", serde_json::json!({})));
+  }
+
+  profile
+}
+
+/// Unwraps the containers exports come in: a bare profile, an array, or a
+/// `{"list": [...]}` / `{"data": {"list": [...]}}` / `{"profiles": [...]}` envelope.
+fn export_entries(root: Value) -> Vec<Value> {
+  match root {
+    Value::Array(arr) => arr,
+    Value::Object(ref obj) => {
+      let list = obj
+        .get("profiles")
+        .or_else(|| obj.get("list"))
+        .or_else(|| obj.get("data").and_then(|d| d.get("list")));
+      match list.and_then(|l| l.as_array()) {
+        Some(arr) => arr.clone(),
+        None => vec![root],
+      }
+    }
+    _ => Vec::new(),
+  }
+}
+
+fn export_files(path: &Path) -> Result<Vec<PathBuf>, String> {
+  if !path.exists() {
+    return Err(serde_json::json!({ "code": "IMPORT_SOURCE_NOT_FOUND" }).to_string());
+  }
+  if path.is_file() {
+    return Ok(vec![path.to_path_buf()]);
+  }
+  let mut files: Vec<PathBuf> = fs::read_dir(path)
+    .map_err(|e| error_to_code_string(e.into()))?
+    .filter_map(|e| e.ok().map(|e| e.path()))
+    .filter(|p| {
+      p.is_file()
+        && p
+          .extension()
+          .is_some_and(|ext| ext.eq_ignore_ascii_case("json"))
+    })
+    .collect();
+  files.sort();
+  Ok(files)
+}
+
+fn same_proxy(a: &ProxySettings, b: &ProxySettings) -> bool {
+  a.proxy_type.eq_ignore_ascii_case(&b.proxy_type)
+    && a.host.eq_ignore_ascii_case(&b.host)
+    && a.port == b.port
+    && a.username == b.username
+    && a.password == b.password
+}
+
+/// Returns the id of a stored proxy matching `settings`, creating one when no
+/// identical proxy exists yet. The second value is true when one was created.
+fn resolve_proxy(
+  app_handle: &crate::app_handle::AppHandle,
+  settings: &ProxySettings,
+) -> Result<(String, bool), String> {
+  let stored = PROXY_MANAGER.get_stored_proxies();
+  if let Some(existing) = stored
+    .iter()
+    .find(|p| same_proxy(&p.proxy_settings, settings))
+  {
+    return Ok((existing.id.clone(), false));
+  }
+
+  let mut taken: HashSet<String> = stored.iter().map(|p| p.name.to_lowercase()).collect();
+  let name = resolve_duplicate_name(
+    &crate::ip_utils::host_port(&settings.host, settings.port),
+    &mut taken,
+  );
+  let proxy = PROXY_MANAGER.create_stored_proxy(app_handle, name, settings.clone())?;
+  Ok((proxy.id, true))
+}
+
+async fn import_one(
+  app_handle: &crate::app_handle::AppHandle,
+  external: ExternalProfile,
+  group_id: Option<String>,
+  version: &Result<String, String>,
+  result: &mut ExternalProfileImportResult,
+  proxies_created: &mut usize,
+) -> Result<(), String> {
+  // Checked per profile so a missing Wayfern download still yields a
+  // report entry for every exported profile.
+  let version = version.clone()?;
+
+  let mut config = external.config;
+  if let Some(os) = config.os.as_deref() {
+    if !crate::cloud_auth::CLOUD_AUTH
+      .is_fingerprint_os_allowed(Some(os))
+      .await
+    {
+      result.warnings.push(warning(
+        "IMPORT_OS_REQUIRES_PRO",
+        serde_json::json!({ "os": os }),
+      ));
+      config.os = None;
+    }
+  }
+
+  let proxy_id = match &external.proxy {
+    Some(settings) => match resolve_proxy(app_handle, settings) {
+      Ok((id, created)) => {
+        if created {
+          *proxies_created += 1;
+        }
+        Some(id)
+      }
+      Err(e) => {
+        result.warnings.push(warning(
+          "IMPORT_PROXY_FAILED",
+          serde_json::json!({ "detail": e }),
+        ));
+        None
+      }
+    },
+    None => None,
+  };
+  result.proxy_id = proxy_id.clone();
+
+  let profile_manager = ProfileManager::instance();
+  let profile = profile_manager
+    .create_profile_with_group(
+      app_handle,
+      &result.name,
+      "wayfern",
+      &version,
+      "stable",
+      proxy_id,
+      None,
+      Some(config),
+      group_id,
+      false,
+      None,
+      None,
+    )
+    .await
+    .map_err(error_to_code_string)?;
+  let profile_id = profile.id.to_string();
+  result.profile_id = Some(profile_id.clone());
+
+  if !external.tags.is_empty() {
+    if let Err(e) = profile_manager.update_profile_tags(app_handle, &profile_id, external.tags) {
+      log::warn!("Failed to set tags on imported profile {profile_id}: {e}");
+    }
+  }
+  if external.note.is_some() {
+    if let Err(e) = profile_manager.update_profile_note(app_handle, &profile_id, external.note) {
+      log::warn!("Failed to set note on imported profile {profile_id}: {e}");
+    }
+  }
+
+  if let Some(cookies) = external.cookies {
+    match crate::cookie_manager::CookieManager::import_cookies(app_handle, &profile_id, &cookies)
+      .await
+    {
+      Ok(imported) => result.cookies_imported = imported.cookies_imported,
+      Err(e) => result.warnings.push(warning(
+        "IMPORT_COOKIES_FAILED",
+        serde_json::json!({ "detail": e }),
+      )),
+    }
+  }
+
+  Ok(())
+}
+
+/// Import every profile found in an AdsPower or Multilogin-style export
+/// (a JSON file or a folder of them). Profiles are isolated: a bad entry is
+/// reported in its result instead of aborting the batch.
+pub async fn import_external_profiles_impl(
+  app_handle: &crate::app_handle::AppHandle,
+  path: &Path,
+  group_id: Option<String>,
+) -> Result<ExternalImportResult, String> {
+  let mut pending: Vec<(String, Result<(ExternalFormat, ExternalProfile), String>)> = Vec::new();
+  for file in export_files(path)? {
+    let source_file = file.to_string_lossy().to_string();
+    let root = match fs::read_to_string(&file)
+      .map_err(|e| e.to_string())
+      .and_then(|c| serde_json::from_str::<Value>(&c).map_err(|e| e.to_string()))
+    {
+      Ok(root) => root,
+      Err(e) => {
+        pending.push((
+          source_file,
+          Err(
+            serde_json::json!({ "code": "IMPORT_UNKNOWN_FORMAT", "params": { "detail": e } })
+              .to_string(),
+          ),
+        ));
+        continue;
+      }
+    };
+    for entry in export_entries(root) {
+      let parsed = match detect_format(&entry) {
+        Some(ExternalFormat::AdsPower) => Ok((ExternalFormat::AdsPower, parse_adspower(&entry))),
+        Some(ExternalFormat::Multilogin) => {
+          Ok((ExternalFormat::Multilogin, parse_multilogin(&entry)))
+        }
+        None => Err(
+          serde_json::json!({ "code": "IMPORT_UNKNOWN_FORMAT", "params": { "detail": "" } })
+            .to_string(),
+        ),
+      };
+      pending.push((source_file.clone(), parsed));
+    }
+  }
+
+  if pending.is_empty() {
+    return Err(serde_json::json!({ "code": "IMPORT_NO_ITEMS" }).to_string());
+  }
+
+  if let Some(ref gid) = group_id {
+    let groups = crate::group_manager::GroupManager::new()
+      .get_all_groups()
+      .map_err(error_to_code_string)?;
+    if !groups.iter().any(|g| &g.id == gid) {
+      return Err(serde_json::json!({ "code": "GROUP_NOT_FOUND" }).to_string());
+    }
+  }
+
+  let version = crate::profile_importer::ProfileImporter::instance()
+    .get_default_version_for_browser("wayfern")
+    .map_err(error_to_code_string);
+
+  let mut taken_names: HashSet<String> = ProfileManager::instance()
+    .list_profiles()
+    .map_err(error_to_code_string)?
+    .iter()
+    .map(|p| p.name.to_lowercase())
+    .collect();
+
+  let total = pending.len();
+  let mut results = Vec::with_capacity(total);
+  let mut proxies_created = 0usize;
+
+  for (index, (source_file, parsed)) in pending.into_iter().enumerate() {
+    let mut result = ExternalProfileImportResult {
+      name: String::new(),
+      source_file,
+      format: None,
+      status: "failed".to_string(),
+      profile_id: None,
+      proxy_id: None,
+      cookies_imported: 0,
+      warnings: Vec::new(),
+      error: None,
+    };
+
+    let outcome = match parsed {
+      Err(e) => Err(e),
+      Ok((format, mut external)) => {
+        result.format = Some(format);
+        result.warnings = std::mem::take(&mut external.warnings);
+        if external.name.is_empty() {
+          Err(serde_json::json!({ "code": "NAME_CANNOT_BE_EMPTY" }).to_string())
+        } else {
+          result.name = resolve_duplicate_name(&external.name, &mut taken_names);
+          emit_import_progress(total, index, index, &result.name, "importing");
+          import_one(
+            app_handle,
+            external,
+            group_id.clone(),
+            &version,
+            &mut result,
+            &mut proxies_created,
+          )
+          .await
+        }
+      }
+    };
+
+    match outcome {
+      Ok(()) => {
+        result.status = "imported".to_string();
+        let _ = crate::events::emit_empty("profiles-changed");
+      }
+      Err(e) => {
+        if result.profile_id.is_none() && !result.name.is_empty() {
+          taken_names.remove(&result.name.to_lowercase());
+        }
+        result.error = Some(e);
+      }
+    }
+    emit_import_progress(total, index + 1, index, &result.name, &result.status);
+    results.push(result);
+  }
+
+  let imported_count = results.iter().filter(|r| r.status == "imported").count();
+  Ok(ExternalImportResult {
+    imported_count,
+    failed_count: results.len() - imported_count,
+    proxies_created,
+    results,
+  })
+}
+
+#[tauri::command]
+pub async fn import_external_profiles(
+  app_handle: crate::app_handle::AppHandle,
+  path: String,
+  group_id: Option<String>,
+) -> Result<ExternalImportResult, String> {
+  import_external_profiles_impl(&app_handle, Path::new(&path), group_id).await
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn parses_adspower_export() {
+    let entry = serde_json::json!({
+      "name": "shop-01",
+      "group_name": "clientA",
+      "remark": "main account",
+      "user_proxy_config": {
+        "proxy_soft": "other",
+        "proxy_type": "socks5",
+        "proxy_host": "10.0.0.1",
+        "proxy_port": "1080",
+        "proxy_user": "u",
+        "proxy_password": "p"
+      },
+      "fingerprint_config": {
+        "ua": "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36",
+        "screen_resolution": "1920_1080",
+        "language": ["de-DE", "de"],
+        "automatic_timezone": "0",
+        "timezone": "Europe/Berlin",
+        "webrtc": "disabled"
+      },
+      "cookie": "[{\"name\":\"sid\",\"value\":\"1\",\"domain\":\".example.com\"}]"
+    });
+    assert_eq!(detect_format(&entry), Some(ExternalFormat::AdsPower));
+    let profile = parse_adspower(&entry);
+    assert_eq!(profile.name, "shop-01");
+    assert_eq!(profile.tags, vec!["clientA"]);
+    let proxy = profile.proxy.expect("proxy");
+    assert_eq!((proxy.proxy_type.as_str(), proxy.port), ("socks5", 1080));
+    assert_eq!(profile.config.os.as_deref(), Some("windows"));
+    assert_eq!(profile.config.screen_max_width, Some(1920));
+    assert_eq!(profile.config.screen_min_height, Some(1080));
+    assert_eq!(profile.config.locale_override.as_deref(), Some("de-DE"));
+    assert_eq!(profile.config.block_webrtc, Some(true));
+    assert!(profile.cookies.is_some());
+    assert_eq!(profile.warnings.len(), 1);
+  }
+
+  #[test]
+  fn parses_multilogin_export_and_envelopes() {
+    let root = serde_json::json!({
+      "profiles": [{
+        "name": "ml-1",
+        "os": "mac",
+        "navigator": { "language": "en-GB,en;q=0.9" },
+        "screen": { "resolution": "1440x900" },
+        "proxy": { "type": "NONE" },
+        "cookies": [],
+        "tags": ["a"]
+      }]
+    });
+    let entries = export_entries(root);
+    assert_eq!(entries.len(), 1);
+    assert_eq!(detect_format(&entries[0]), Some(ExternalFormat::Multilogin));
+    let profile = parse_multilogin(&entries[0]);
+    assert_eq!(profile.config.os.as_deref(), Some("macos"));
+    assert_eq!(profile.config.screen_max_width, Some(1440));
+    assert_eq!(profile.config.locale_override.as_deref(), Some("en-GB"));
+    assert!(profile.proxy.is_none());
+    assert!(profile.cookies.is_none());
+
+    assert_eq!(detect_format(&serde_json::json!({ "foo": 1 })), None);
+  }
+
+  #[test]
+  fn identical_proxies_match_case_insensitively() {
+    let a = ProxySettings {
+      proxy_type: "HTTP".to_string(),
+      host: "Proxy.example".to_string(),
+      port: 8080,
+      username: Some("u".to_string()),
+      password: Some("p".to_string()),
+      client_cert_path: None,
+      client_key_path: None,
+    };
+    let mut b = a.clone();
+    b.proxy_type = "http".to_string();
+    b.host = "proxy.example".to_string();
+    assert!(same_proxy(&a, &b));
+    b.password = Some("other".to_string());
+    assert!(!same_proxy(&a, &b));
+  }
+}
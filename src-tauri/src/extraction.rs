@@ -45,6 +45,23 @@ fn has_quarantine_attr(path: &Path) -> bool {
   result >= 0
 }
 
+/// Runs `codesign --verify --deep --strict` on a copied bundle and returns
+/// its diagnostics on failure.
+#[cfg(target_os = "macos")]
+async fn verify_code_signature(app_path: &Path) -> Result<(), String> {
+  let output = Command::new("codesign")
+    .args(["--verify", "--deep", "--strict"])
+    .arg(app_path)
+    .output()
+    .await
+    .map_err(|e| format!("Failed to run codesign: {e}"))?;
+  if output.status.success() {
+    Ok(())
+  } else {
+    Err(String::from_utf8_lossy(&output.stderr).trim().to_string())
+  }
+}
+
 /// Best-effort recursive size of a file tree. Uses `symlink_metadata` so
 /// symlinks inside .app bundles are not followed (`cp -R` copies them as
 /// links, so following them would overcount and could loop).
@@ -130,7 +147,7 @@ impl ExtractionReporter {
       eta_seconds: None,
       stage: "extracting".to_string(),
     };
-    let _ = events::emit("download-progress", &progress);
+    let _ = events::emit(&progress);
   }
 }
 
@@ -176,6 +193,22 @@ fn progress_file_reader(
   }))
 }
 
+/// The copied .app failed `codesign --verify`; the copy has already been
+/// removed. Surfaced as `BROWSER_SIGNATURE_INVALID` by `extract_browser`.
+#[cfg(target_os = "macos")]
+#[derive(Debug)]
+pub struct InvalidSignature(pub String);
+
+#[cfg(target_os = "macos")]
+impl std::fmt::Display for InvalidSignature {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "Code signature verification failed: {}", self.0)
+  }
+}
+
+#[cfg(target_os = "macos")]
+impl std::error::Error for InvalidSignature {}
+
 pub struct Extractor;
 
 impl Extractor {
@@ -257,7 +290,7 @@ impl Extractor {
 
   pub async fn extract_browser(
     &self,
-    _app_handle: &tauri::AppHandle,
+    _app_handle: &crate::app_handle::AppHandle,
     browser_type: BrowserType,
     version: &str,
     archive_path: &Path,
@@ -274,7 +307,7 @@ impl Extractor {
       eta_seconds: None,
       stage: "extracting".to_string(),
     };
-    let _ = events::emit("download-progress", &progress);
+    let _ = events::emit(&progress);
 
     // Reports incremental extraction progress to the UI. Formats without a
     // measurable byte stream (MSI, plain EXE/AppImage copies) simply never
@@ -303,7 +336,16 @@ impl Extractor {
         #[cfg(target_os = "macos")]
         {
           self.extract_dmg(archive_path, dest_dir, Some(&reporter)).await.map_err(|e| {
-            format!("DMG extraction failed for {} {}: {}", browser_type.as_str(), version, e).into()
+            if let Some(InvalidSignature(detail)) = e.downcast_ref::<InvalidSignature>() {
+              serde_json::json!({
+                "code": "BROWSER_SIGNATURE_INVALID",
+                "params": { "browser": browser_type.as_str(), "version": version, "detail": detail }
+              })
+              .to_string()
+              .into()
+            } else {
+              format!("DMG extraction failed for {} {}: {}", browser_type.as_str(), version, e).into()
+            }
           })
         }
 
@@ -332,6 +374,11 @@ impl Extractor {
           format!("TAR.GZ extraction failed for {} {}: {}", browser_type.as_str(), version, e).into()
         })
       }
+      "tar.zst" => {
+        self.extract_tar_zst(archive_path, dest_dir, Some(&reporter)).await.map_err(|e| {
+          format!("TAR.ZST extraction failed for {} {}: {}", browser_type.as_str(), version, e).into()
+        })
+      }
       "msi" => {
         self.extract_msi(archive_path, dest_dir).await.map_err(|e| {
           format!("MSI extraction failed for {} {}: {}", browser_type.as_str(), version, e).into()
@@ -463,6 +510,11 @@ impl Extractor {
       return Ok("tar.gz".to_string());
     }
 
+    // Check for Zstandard compressed files
+    if buffer[0..4] == [0x28, 0xB5, 0x2F, 0xFD] {
+      return Ok("tar.zst".to_string());
+    }
+
     // Fallback to file extension
     if let Some(ext) = file_path.extension().and_then(|ext| ext.to_str()) {
       match ext.to_lowercase().as_str() {
@@ -505,6 +557,19 @@ impl Extractor {
             Ok("gz".to_string())
           }
         }
+        "zst" => {
+          if file_path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or("")
+            .ends_with(".tar.zst")
+          {
+            Ok("tar.zst".to_string())
+          } else {
+            Ok("zst".to_string())
+          }
+        }
+        "tzst" => Ok("tar.zst".to_string()),
         "exe" => Ok("exe".to_string()),
         "appimage" => Ok("appimage".to_string()),
         _ => Ok("unknown".to_string()),
@@ -602,17 +667,16 @@ impl Extractor {
       0
     };
 
-    // `-X` strips extended attributes (notably com.apple.quarantine) during
-    // the copy itself. Without it, `cp -R` preserves quarantine from the
-    // mounted DMG, which then has to be removed with `xattr -dr` — and that
-    // removexattr syscall on a signed .app bundle trips macOS Sequoia's App
-    // Management TCC notification ("Donut.app was prevented from modifying
-    // apps on your Mac"). Stripping at copy time is silent.
+    // `ditto` keeps symlinks, resource forks and extended attributes intact.
+    // Some bundles store the signature of non-Mach-O files in xattrs, so the
+    // old `cp -RX` copy could leave an .app that Gatekeeper reports as
+    // "damaged". Archives we download ourselves carry no quarantine, so the
+    // gated `xattr -dr` below stays a no-op in the common case.
     let copy_src = app_entry.to_str().unwrap().to_string();
     let copy_dst = app_path.to_str().unwrap().to_string();
     let mut copy_task = tokio::spawn(async move {
-      Command::new("cp")
-        .args(["-RX", &copy_src, &copy_dst])
+      Command::new("ditto")
+        .args(["--rsrc", "--extattr", &copy_src, &copy_dst])
         .output()
         .await
     });
@@ -679,6 +743,18 @@ impl Extractor {
     // Clean up mount point directory
     let _ = fs::remove_dir_all(&mount_point);
 
+    // A bundle that fails verification would only be blocked by Gatekeeper
+    // at launch, so drop it now and let the download be retried.
+    if let Err(detail) = verify_code_signature(&app_path).await {
+      log::error!(
+        "Code signature verification failed for {}: {detail}",
+        app_path.display()
+      );
+      let _ = fs::remove_dir_all(&app_path);
+      return Err(Box::new(InvalidSignature(detail)));
+    }
+    log::info!("Code signature verified");
+
     Ok(app_path)
   }
 
@@ -950,6 +1026,31 @@ impl Extractor {
     self.find_extracted_executable(dest_dir).await
   }
 
+  pub async fn extract_tar_zst(
+    &self,
+    tar_path: &Path,
+    dest_dir: &Path,
+    progress: Option<&ExtractionReporter>,
+  ) -> Result<PathBuf, Box<dyn std::error::Error + Send + Sync>> {
+    log::info!("Extracting tar.zst archive: {}", tar_path.display());
+    std::fs::create_dir_all(dest_dir)?;
+
+    let file = File::open(tar_path)?;
+    let counted = progress_file_reader(file, progress)?;
+    let zst_decoder = zstd::stream::read::Decoder::new(BufReader::new(counted))?;
+    let mut archive = tar::Archive::new(zst_decoder);
+
+    archive.unpack(dest_dir)?;
+
+    // Set executable permissions for extracted files
+    self.set_executable_permissions_recursive(dest_dir).await?;
+
+    log::info!("tar.zst extraction completed.");
+    self.flatten_single_directory_archive(dest_dir)?;
+    log::info!("Searching for executable...");
+    self.find_extracted_executable(dest_dir).await
+  }
+
   pub async fn extract_msi(
     &self,
     msi_path: &Path,
@@ -1021,7 +1122,9 @@ impl Extractor {
   ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     let entries: Vec<_> = fs::read_dir(dest_dir)?.filter_map(|e| e.ok()).collect();
 
-    let archive_extensions = ["zip", "tar", "xz", "gz", "bz2", "dmg", "msi", "exe"];
+    let archive_extensions = [
+      "zip", "tar", "xz", "gz", "bz2", "zst", "tzst", "dmg", "msi", "exe",
+    ];
 
     let mut dirs = Vec::new();
     let mut has_non_archive_files = false;
@@ -1640,6 +1743,41 @@ mod tests {
     assert_eq!(result.unwrap(), "tar.xz");
   }
 
+  #[test]
+  fn test_format_detection_tar_zst() {
+    let extractor = Extractor::instance();
+    let temp_dir = TempDir::new().unwrap();
+    let tar_zst_path = temp_dir.path().join("test.tar.zst");
+
+    // Create a file with zstd magic
+    let mut file = File::create(&tar_zst_path).unwrap();
+    file.write_all(&[0x28, 0xB5, 0x2F, 0xFD]).unwrap(); // zstd magic
+    file.write_all(&[0; 8]).unwrap(); // padding
+
+    let result = extractor.detect_file_format(&tar_zst_path);
+    assert!(result.is_ok());
+    assert_eq!(result.unwrap(), "tar.zst");
+  }
+
+  #[test]
+  fn test_format_detection_tar_zst_by_extension() {
+    let extractor = Extractor::instance();
+    let temp_dir = TempDir::new().unwrap();
+
+    for (name, expected) in [
+      ("test.tar.zst", "tar.zst"),
+      ("test.tzst", "tar.zst"),
+      ("test.zst", "zst"),
+    ] {
+      let path = temp_dir.path().join(name);
+      let mut file = File::create(&path).unwrap();
+      file.write_all(b"not really zstd data").unwrap();
+
+      let result = extractor.detect_file_format(&path);
+      assert_eq!(result.unwrap(), expected, "{name}");
+    }
+  }
+
   #[test]
   fn test_format_detection_msi() {
     let extractor = Extractor::instance();
@@ -1970,6 +2108,148 @@ mod tests {
     }
   }
 
+  #[tokio::test]
+  async fn test_extract_tar_zst_with_test_archive() {
+    let extractor = Extractor::instance();
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let dest_dir = temp_dir.path().join("extracted");
+
+    // Create a test tar.zst archive
+    let tar_zst_path = temp_dir.path().join("test.tar.zst");
+    {
+      let tar_zst_file =
+        std::fs::File::create(&tar_zst_path).expect("Failed to create test tar.zst file");
+      let enc = zstd::stream::write::Encoder::new(tar_zst_file, 0)
+        .expect("Failed to create zstd encoder")
+        .auto_finish();
+      let mut tar = tar::Builder::new(enc);
+
+      let mut header = tar::Header::new_gnu();
+      header.set_path("test.txt").expect("Failed to set tar path");
+      header.set_size(13); // "Hello, World!" length
+      header.set_cksum();
+
+      tar
+        .append(&header, "Hello, World!".as_bytes())
+        .expect("Failed to append to tar");
+      tar.finish().expect("Failed to finish tar");
+    }
+
+    assert_eq!(
+      extractor.detect_file_format(&tar_zst_path).unwrap(),
+      "tar.zst"
+    );
+
+    let result = extractor
+      .extract_tar_zst(&tar_zst_path, &dest_dir, None)
+      .await;
+
+    // Check if the file was extracted
+    let extracted_file = dest_dir.join("test.txt");
+    assert!(extracted_file.exists(), "Extracted file should exist");
+
+    let content = std::fs::read_to_string(&extracted_file).expect("Failed to read extracted file");
+    assert_eq!(
+      content.trim(),
+      "Hello, World!",
+      "Extracted content should match"
+    );
+
+    // If the result is an error, it should be because no executable was found, not extraction failure
+    if let Err(e) = result {
+      let error_msg = e.to_string();
+      assert!(
+        error_msg.contains("No executable found")
+          || error_msg.contains("executable")
+          || error_msg.contains("No .app found")
+          || error_msg.contains("app not found"),
+        "Error should be about missing executable/app, not extraction failure: {error_msg}"
+      );
+    }
+  }
+
+  /// Builds a DMG around an ad-hoc signed .app whose executable is a shell
+  /// script, so its signature lives in extended attributes. `tamper` edits
+  /// the bundle after signing.
+  #[cfg(target_os = "macos")]
+  fn build_signed_app_dmg(temp_dir: &Path, tamper: bool) -> PathBuf {
+    let src = temp_dir.join("dmg-src");
+    let macos_dir = src.join("Fixture.app/Contents/MacOS");
+    create_dir_all(&macos_dir).unwrap();
+    std::fs::write(
+      src.join("Fixture.app/Contents/Info.plist"),
+      r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">
+<plist version="1.0"><dict>
+<key>CFBundleExecutable</key><string>fixture</string>
+<key>CFBundleIdentifier</key><string>com.donutbrowser.fixture</string>
+</dict></plist>
+"#,
+    )
+    .unwrap();
+    let exe = macos_dir.join("fixture");
+    std::fs::write(&exe, "#!/bin/sh\necho fixture\n").unwrap();
+    {
+      use std::os::unix::fs::PermissionsExt;
+      std::fs::set_permissions(&exe, std::fs::Permissions::from_mode(0o755)).unwrap();
+    }
+
+    let app = src.join("Fixture.app");
+    let status = std::process::Command::new("codesign")
+      .args(["--force", "--sign", "-"])
+      .arg(&app)
+      .status()
+      .unwrap();
+    assert!(status.success(), "ad-hoc signing failed");
+    if tamper {
+      std::fs::write(&exe, "#!/bin/sh\necho tampered\n").unwrap();
+    }
+
+    let dmg = temp_dir.join("fixture.dmg");
+    let status = std::process::Command::new("hdiutil")
+      .args(["create", "-quiet", "-format", "UDZO", "-srcfolder"])
+      .arg(&src)
+      .arg(&dmg)
+      .status()
+      .unwrap();
+    assert!(status.success(), "hdiutil create failed");
+    dmg
+  }
+
+  #[cfg(target_os = "macos")]
+  #[tokio::test]
+  async fn test_extract_dmg_keeps_code_signature() {
+    let extractor = Extractor::instance();
+    let temp_dir = TempDir::new().unwrap();
+    let dmg = build_signed_app_dmg(temp_dir.path(), false);
+    let dest_dir = temp_dir.path().join("extracted");
+    create_dir_all(&dest_dir).unwrap();
+
+    let app = extractor
+      .extract_dmg(&dmg, &dest_dir, None)
+      .await
+      .expect("DMG extraction failed");
+    assert_eq!(app, dest_dir.join("Fixture.app"));
+    assert!(verify_code_signature(&app).await.is_ok());
+  }
+
+  #[cfg(target_os = "macos")]
+  #[tokio::test]
+  async fn test_extract_dmg_rejects_broken_signature() {
+    let extractor = Extractor::instance();
+    let temp_dir = TempDir::new().unwrap();
+    let dmg = build_signed_app_dmg(temp_dir.path(), true);
+    let dest_dir = temp_dir.path().join("extracted");
+    create_dir_all(&dest_dir).unwrap();
+
+    let err = extractor
+      .extract_dmg(&dmg, &dest_dir, None)
+      .await
+      .expect_err("tampered bundle should fail verification");
+    assert!(err.downcast_ref::<InvalidSignature>().is_some());
+    assert!(!dest_dir.join("Fixture.app").exists());
+  }
+
   #[test]
   fn test_unsupported_archive_format() {
     let extractor = Extractor::instance();
@@ -51,6 +51,10 @@ pub struct ConsistencyResult {
   pub fingerprint_language: Option<String>,
   /// One of "timezone", "language" — the dimensions that disagree.
   pub mismatches: Vec<String>,
+  /// Dimensions that disagree because the user pinned them (a timezone
+  /// override). Reported, but they don't make the result inconsistent.
+  #[serde(default)]
+  pub warnings: Vec<String>,
 }
 
 impl ConsistencyResult {
@@ -64,6 +68,7 @@ impl ConsistencyResult {
       fingerprint_timezone: None,
       fingerprint_language: None,
       mismatches: Vec::new(),
+      warnings: Vec::new(),
     }
   }
 }
@@ -183,14 +188,35 @@ pub async fn check_profile_consistency(
 
   let (fp_tz, fp_lang) = fingerprint_locale(profile);
   let mut mismatches = Vec::new();
+  let mut warnings = Vec::new();
+
+  // A pinned timezone is meant to differ from the exit, so the mismatch is
+  // only worth a warning.
+  let timezone_pinned = profile
+    .wayfern_config
+    .as_ref()
+    .and_then(|c| c.timezone_override.as_deref())
+    .is_some_and(|tz| !tz.trim().is_empty());
 
   if let (Some(exit), Some(fp)) = (&exit_tz, &fp_tz) {
     if !exit.eq_ignore_ascii_case(fp) {
-      mismatches.push("timezone".to_string());
+      if timezone_pinned {
+        warnings.push("timezone".to_string());
+      } else {
+        mismatches.push("timezone".to_string());
+      }
     }
   }
 
-  if let (Some(cc), Some(lang)) = (&exit_cc, &fp_lang) {
+  // A pinned locale is a deliberate mismatch — don't warn about the choice the
+  // user made on purpose.
+  let locale_pinned = profile
+    .wayfern_config
+    .as_ref()
+    .and_then(|c| c.locale_override.as_deref())
+    .is_some_and(|l| !l.trim().is_empty());
+
+  if let (Some(cc), Some(lang), false) = (&exit_cc, &fp_lang, locale_pinned) {
     if language_matches_country(cc, lang) == Some(false) {
       mismatches.push("language".to_string());
     }
@@ -205,6 +231,7 @@ pub async fn check_profile_consistency(
     fingerprint_timezone: fp_tz,
     fingerprint_language: fp_lang,
     mismatches,
+    warnings,
   })
 }
 
@@ -256,6 +283,8 @@ pub async fn match_profile_fingerprint_to_exit(
     &fingerprint,
     None,
     Some(&geoip_override),
+    config.locale_override.as_deref(),
+    config.timezone_override.as_deref(),
   )
   .await
   .ok_or_else(|| serde_json::json!({ "code": "FINGERPRINT_MATCH_FAILED" }).to_string())?;
@@ -339,6 +368,8 @@ mod tests {
       port: 8080,
       username: Some("u".into()),
       password: Some("p".into()),
+      client_cert_path: None,
+      client_key_path: None,
     };
     assert_eq!(proxy_url(&http).as_deref(), Some("http://u:p@h:8080"));
 
@@ -350,6 +381,8 @@ mod tests {
       port: 8080,
       username: Some("user".into()),
       password: Some("ab/cd@ef".into()),
+      client_cert_path: None,
+      client_key_path: None,
     };
     assert_eq!(
       proxy_url(&reserved).as_deref(),
@@ -363,6 +396,8 @@ mod tests {
       port: 1080,
       username: Some("justuser".into()),
       password: None,
+      client_cert_path: None,
+      client_key_path: None,
     };
     assert_eq!(
       proxy_url(&user_only).as_deref(),
@@ -375,6 +410,8 @@ mod tests {
       port: 8080,
       username: None,
       password: None,
+      client_cert_path: None,
+      client_key_path: None,
     };
     assert_eq!(proxy_url(&ss), None);
   }
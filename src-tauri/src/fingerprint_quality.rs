@@ -0,0 +1,519 @@
+//! Internal consistency checks over a generated Wayfern fingerprint. Each
+//! field Wayfern samples is plausible on its own, but anti-bot scripts compare
+//! them against each other: a `Chrome/131` user agent next to brand version
+//! `129`, a `Win32` platform with a Mac font list, a touch-enabled MacBook.
+//! [`validate_fingerprint`] runs those cross-checks and returns a scored
+//! report; generation can reject samples with error-level findings.
+
+use serde::{Deserialize, Serialize};
+
+use crate::profile::BrowserProfile;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+  /// Unusual but seen on real devices.
+  Warning,
+  /// A combination no real device reports.
+  Error,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Finding {
+  /// Stable identifier of the check, e.g. `"ua_platform"`.
+  pub check: String,
+  pub severity: Severity,
+  pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FingerprintReport {
+  /// 100 for a clean fingerprint; each error costs 30 points and each
+  /// warning 10.
+  pub score: u8,
+  pub findings: Vec<Finding>,
+}
+
+impl FingerprintReport {
+  pub fn has_errors(&self) -> bool {
+    self.findings.iter().any(|f| f.severity == Severity::Error)
+  }
+}
+
+fn str_field<'a>(fp: &'a serde_json::Value, key: &str) -> Option<&'a str> {
+  fp.get(key)
+    .and_then(|v| v.as_str())
+    .map(str::trim)
+    .filter(|s| !s.is_empty())
+}
+
+fn num_field(fp: &serde_json::Value, key: &str) -> Option<f64> {
+  let v = fp.get(key)?;
+  v.as_f64()
+    .or_else(|| v.as_str().and_then(|s| s.trim().parse::<f64>().ok()))
+}
+
+/// A list field Wayfern stores either as an array or as a JSON-encoded string.
+fn list_field(fp: &serde_json::Value, key: &str) -> Vec<String> {
+  let items = match fp.get(key) {
+    Some(serde_json::Value::Array(items)) => items.clone(),
+    Some(serde_json::Value::String(s)) => match serde_json::from_str(s) {
+      Ok(serde_json::Value::Array(items)) => items,
+      _ => s
+        .split(',')
+        .map(|item| serde_json::Value::String(item.to_string()))
+        .collect(),
+    },
+    _ => Vec::new(),
+  };
+  items
+    .iter()
+    .filter_map(|item| {
+      item
+        .as_str()
+        .or_else(|| item.get("name").and_then(|n| n.as_str()))
+    })
+    .map(|s| s.trim().to_string())
+    .filter(|s| !s.is_empty())
+    .collect()
+}
+
+/// Major version of the Chromium token in a user agent (`Chrome/131.0.0.0`).
+fn ua_major_version(user_agent: &str) -> Option<u32> {
+  let rest = user_agent.split("Chrome/").nth(1)?;
+  rest.split('.').next()?.parse().ok()
+}
+
+/// The OS a user agent string claims, in `WayfernConfig::os` terms.
+fn ua_os(user_agent: &str) -> Option<&'static str> {
+  if user_agent.contains("Android") {
+    Some("android")
+  } else if user_agent.contains("iPhone") || user_agent.contains("iPad") {
+    Some("ios")
+  } else if user_agent.contains("Windows NT") {
+    Some("windows")
+  } else if user_agent.contains("Macintosh") || user_agent.contains("Mac OS X") {
+    Some("macos")
+  } else if user_agent.contains("Linux") || user_agent.contains("X11") {
+    Some("linux")
+  } else {
+    None
+  }
+}
+
+/// Whether `navigator.platform` is what Chromium reports on `os`.
+fn platform_matches_os(platform: &str, os: &str) -> bool {
+  match os {
+    "windows" => platform == "Win32",
+    "macos" => platform == "MacIntel",
+    "linux" => platform.starts_with("Linux") && !platform.contains("arm"),
+    "android" => platform.starts_with("Linux") || platform.is_empty(),
+    "ios" => platform == "iPhone" || platform == "iPad",
+    _ => true,
+  }
+}
+
+/// Fonts that ship with exactly one desktop OS. A font list carrying another
+/// OS's system fonts and none of its own gives the platform away.
+fn os_exclusive_fonts(os: &str) -> &'static [&'static str] {
+  match os {
+    "windows" => &["Segoe UI", "Calibri", "Consolas", "Tahoma"],
+    "macos" => &["Helvetica Neue", "Menlo", "Apple Color Emoji", "Geneva"],
+    "linux" => &[
+      "DejaVu Sans",
+      "Liberation Sans",
+      "Ubuntu",
+      "Noto Color Emoji",
+    ],
+    _ => &[],
+  }
+}
+
+/// Run every cross-field check over `fingerprint` (the bare object, or the
+/// legacy `{ "fingerprint": {...} }` wrapper). `os` is the OS the fingerprint
+/// was generated for; when unset it is inferred from the user agent. Checks
+/// whose fields are missing are skipped rather than reported.
+pub fn validate_fingerprint(
+  os: Option<&str>,
+  fingerprint: &serde_json::Value,
+) -> FingerprintReport {
+  let fp = fingerprint.get("fingerprint").unwrap_or(fingerprint);
+  let mut findings = Vec::new();
+  let mut report = |check: &str, severity: Severity, message: String| {
+    findings.push(Finding {
+      check: check.to_string(),
+      severity,
+      message,
+    });
+  };
+
+  let user_agent = str_field(fp, "userAgent");
+  let claimed_os = user_agent.and_then(ua_os);
+  let os = os.or(claimed_os);
+
+  // User agent vs brand version vs appVersion.
+  if let Some(ua) = user_agent {
+    let ua_major = ua_major_version(ua);
+    let brand_major = str_field(fp, "brandVersion")
+      .and_then(|v| v.split('.').next())
+      .and_then(|v| v.parse::<u32>().ok());
+    if let (Some(ua_major), Some(brand_major)) = (ua_major, brand_major) {
+      if ua_major != brand_major {
+        report(
+          "ua_brand_version",
+          Severity::Error,
+          format!("User agent is Chrome {ua_major} but userAgentData reports {brand_major}"),
+        );
+      }
+    }
+    if let Some(app_version) = str_field(fp, "appVersion") {
+      if ua.strip_prefix("Mozilla/") != Some(app_version) {
+        report(
+          "ua_app_version",
+          Severity::Error,
+          "appVersion does not match the user agent".to_string(),
+        );
+      }
+    }
+    if let (Some(os), Some(claimed)) = (os, claimed_os) {
+      if os != claimed {
+        report(
+          "ua_os",
+          Severity::Error,
+          format!("User agent claims {claimed} on a {os} fingerprint"),
+        );
+      }
+    }
+  }
+
+  if let Some(os) = os {
+    // Platform vs OS.
+    if let Some(platform) = str_field(fp, "platform") {
+      if !platform_matches_os(platform, os) {
+        report(
+          "ua_platform",
+          Severity::Error,
+          format!("navigator.platform {platform:?} is not reported on {os}"),
+        );
+      }
+    }
+
+    // WebGL vendor/renderer vs OS.
+    if let Some(renderer) = str_field(fp, "webglRenderer") {
+      let vendor = str_field(fp, "webglVendor").unwrap_or("");
+      if !crate::webgl::renderer_family_allowed(os, vendor, renderer) {
+        report(
+          "webgl_os",
+          Severity::Error,
+          format!("WebGL renderer {renderer:?} does not exist on {os}"),
+        );
+      }
+    }
+
+    // Fonts vs OS.
+    let fonts = list_field(fp, "fonts");
+    let own = os_exclusive_fonts(os);
+    if !fonts.is_empty() && !own.is_empty() {
+      let has = |names: &[&str]| fonts.iter().any(|f| names.contains(&f.as_str()));
+      let foreign = ["windows", "macos", "linux"]
+        .into_iter()
+        .filter(|other| *other != os)
+        .find(|other| has(os_exclusive_fonts(other)));
+      if let Some(foreign) = foreign {
+        if !has(own) {
+          report(
+            "fonts_os",
+            Severity::Warning,
+            format!("Font list looks like {foreign}, not {os}"),
+          );
+        }
+      }
+    }
+
+    // Touch points vs device type.
+    if let Some(touch_points) = num_field(fp, "maxTouchPoints") {
+      match os {
+        "android" | "ios" if touch_points == 0.0 => report(
+          "touch_points",
+          Severity::Error,
+          format!("A {os} device reports no touch support"),
+        ),
+        "macos" if touch_points > 0.0 => report(
+          "touch_points",
+          Severity::Error,
+          "Macs have no touchscreen but maxTouchPoints is set".to_string(),
+        ),
+        "windows" | "linux" if touch_points > 0.0 => report(
+          "touch_points",
+          Severity::Warning,
+          format!("Touchscreen reported on a {os} desktop"),
+        ),
+        _ => {}
+      }
+    }
+  }
+
+  // language vs languages[0]. Accept-Language is derived from `languages` at
+  // launch, so agreement here keeps the header consistent too.
+  let languages = list_field(fp, "languages");
+  if let (Some(language), Some(first)) = (str_field(fp, "language"), languages.first()) {
+    if language != first {
+      report(
+        "language",
+        Severity::Error,
+        format!("navigator.language is {language} but languages starts with {first}"),
+      );
+    }
+  }
+
+  // Screen vs available area vs window.
+  let screen = (num_field(fp, "screenWidth"), num_field(fp, "screenHeight"));
+  if let (Some(width), Some(height)) = screen {
+    let avail = (
+      num_field(fp, "screenAvailWidth"),
+      num_field(fp, "screenAvailHeight"),
+    );
+    if avail.0.is_some_and(|w| w > width) || avail.1.is_some_and(|h| h > height) {
+      report(
+        "screen_avail",
+        Severity::Error,
+        "Available screen area is larger than the screen".to_string(),
+      );
+    }
+    let window = (
+      num_field(fp, "windowOuterWidth"),
+      num_field(fp, "windowOuterHeight"),
+    );
+    if window.0.is_some_and(|w| w > width) || window.1.is_some_and(|h| h > height) {
+      report(
+        "window_screen",
+        Severity::Warning,
+        "Window is larger than the screen".to_string(),
+      );
+    }
+  }
+
+  let errors = findings
+    .iter()
+    .filter(|f| f.severity == Severity::Error)
+    .count();
+  let warnings = findings.len() - errors;
+  let penalty = errors * 30 + warnings * 10;
+  FingerprintReport {
+    score: 100usize.saturating_sub(penalty) as u8,
+    findings,
+  }
+}
+
+/// Fields Wayfern needs in every fingerprint, with whether each is numeric.
+/// One left out would launch with the host's real value.
+const REQUIRED_FIELDS: &[(&str, bool)] = &[
+  ("userAgent", false),
+  ("platform", false),
+  ("screenWidth", true),
+  ("screenHeight", true),
+];
+
+/// Check that `fingerprint` has the shape Wayfern applies: an object (or the
+/// legacy wrapper) carrying every required field with the right type.
+pub fn check_structure(fingerprint: &serde_json::Value) -> Result<(), String> {
+  let fp = fingerprint.get("fingerprint").unwrap_or(fingerprint);
+  if !fp.is_object() {
+    return Err("Fingerprint must be a JSON object".to_string());
+  }
+  let invalid: Vec<&str> = REQUIRED_FIELDS
+    .iter()
+    .filter(|(key, numeric)| {
+      if *numeric {
+        num_field(fp, key).is_none()
+      } else {
+        str_field(fp, key).is_none()
+      }
+    })
+    .map(|(key, _)| *key)
+    .collect();
+  if invalid.is_empty() {
+    Ok(())
+  } else {
+    Err(format!(
+      "Fingerprint is missing or has invalid fields: {}",
+      invalid.join(", ")
+    ))
+  }
+}
+
+/// What a fingerprint presents as, for callers verifying a change.
+#[derive(Debug, Clone, PartialEq, Serialize, utoipa::ToSchema)]
+pub struct FingerprintSummary {
+  pub user_agent: Option<String>,
+  pub platform: Option<String>,
+  /// The configured OS, or the one the user agent claims when unset.
+  pub os: Option<String>,
+  pub screen_width: Option<u32>,
+  pub screen_height: Option<u32>,
+}
+
+pub fn summarize(os: Option<&str>, fingerprint: &serde_json::Value) -> FingerprintSummary {
+  let fp = fingerprint.get("fingerprint").unwrap_or(fingerprint);
+  let user_agent = str_field(fp, "userAgent");
+  FingerprintSummary {
+    user_agent: user_agent.map(str::to_string),
+    platform: str_field(fp, "platform").map(str::to_string),
+    os: os
+      .or_else(|| user_agent.and_then(ua_os))
+      .map(str::to_string),
+    screen_width: num_field(fp, "screenWidth").map(|w| w as u32),
+    screen_height: num_field(fp, "screenHeight").map(|h| h as u32),
+  }
+}
+
+fn validate_profile(profile: &BrowserProfile) -> Result<FingerprintReport, String> {
+  let config = profile.wayfern_config.as_ref();
+  let fingerprint = config
+    .and_then(|c| c.fingerprint.as_deref())
+    .ok_or("Profile has no fingerprint to validate")?;
+  let parsed: serde_json::Value =
+    serde_json::from_str(fingerprint).map_err(|e| format!("Invalid fingerprint JSON: {e}"))?;
+  Ok(validate_fingerprint(
+    config.and_then(|c| c.os.as_deref()),
+    &parsed,
+  ))
+}
+
+#[tauri::command]
+pub async fn validate_profile_fingerprint(profile_id: String) -> Result<FingerprintReport, String> {
+  let profiles = crate::profile::ProfileManager::instance()
+    .list_profiles()
+    .map_err(|e| e.to_string())?;
+  let profile = profiles
+    .into_iter()
+    .find(|p| p.id.to_string() == profile_id)
+    .ok_or_else(|| serde_json::json!({ "code": "PROFILE_NOT_FOUND" }).to_string())?;
+  validate_profile(&profile)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use serde_json::json;
+
+  fn windows_fingerprint() -> serde_json::Value {
+    json!({
+      "userAgent": "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/131.0.0.0 Safari/537.36",
+      "brandVersion": "131.0.6778.86",
+      "platform": "Win32",
+      "maxTouchPoints": 0,
+      "webglVendor": "Google Inc. (NVIDIA)",
+      "webglRenderer": "ANGLE (NVIDIA, NVIDIA GeForce GTX 1660 Direct3D11 vs_5_0 ps_5_0, D3D11)",
+      "fonts": "[\"Arial\",\"Segoe UI\",\"Calibri\"]",
+      "language": "en-US",
+      "languages": ["en-US", "en"],
+      "screenWidth": 1920,
+      "screenHeight": 1080,
+      "screenAvailWidth": 1920,
+      "screenAvailHeight": 1040,
+      "windowOuterWidth": 1280,
+      "windowOuterHeight": 900
+    })
+  }
+
+  fn checks(report: &FingerprintReport) -> Vec<&str> {
+    report.findings.iter().map(|f| f.check.as_str()).collect()
+  }
+
+  #[test]
+  fn consistent_fingerprint_scores_full_marks() {
+    let report = validate_fingerprint(Some("windows"), &windows_fingerprint());
+    assert!(report.findings.is_empty(), "{:?}", report.findings);
+    assert_eq!(report.score, 100);
+    assert!(!report.has_errors());
+  }
+
+  #[test]
+  fn version_and_platform_mismatches_are_errors() {
+    let mut fp = windows_fingerprint();
+    fp["brandVersion"] = json!("129.0.0.0");
+    fp["platform"] = json!("MacIntel");
+    let report = validate_fingerprint(Some("windows"), &fp);
+    assert_eq!(checks(&report), vec!["ua_brand_version", "ua_platform"]);
+    assert!(report.has_errors());
+    assert_eq!(report.score, 40);
+  }
+
+  #[test]
+  fn user_agent_must_match_configured_os() {
+    let report = validate_fingerprint(Some("macos"), &windows_fingerprint());
+    assert!(checks(&report).contains(&"ua_os"));
+    // Without a configured OS the user agent's own claim is used.
+    assert!(!checks(&validate_fingerprint(None, &windows_fingerprint())).contains(&"ua_os"));
+  }
+
+  #[test]
+  fn language_screen_and_touch_checks() {
+    let mut fp = windows_fingerprint();
+    fp["language"] = json!("de-DE");
+    fp["screenAvailWidth"] = json!(2560);
+    fp["windowOuterHeight"] = json!(1200);
+    fp["maxTouchPoints"] = json!(10);
+    let report = validate_fingerprint(Some("windows"), &fp);
+    assert_eq!(
+      checks(&report),
+      vec!["touch_points", "language", "screen_avail", "window_screen"]
+    );
+    let warnings: Vec<_> = report
+      .findings
+      .iter()
+      .filter(|f| f.severity == Severity::Warning)
+      .map(|f| f.check.as_str())
+      .collect();
+    assert_eq!(warnings, vec!["touch_points", "window_screen"]);
+  }
+
+  #[test]
+  fn foreign_font_list_is_a_warning() {
+    let mut fp = windows_fingerprint();
+    fp["fonts"] = json!(["Helvetica Neue", "Menlo", "Arial"]);
+    let report = validate_fingerprint(Some("windows"), &fp);
+    assert_eq!(checks(&report), vec!["fonts_os"]);
+    assert!(!report.has_errors());
+    assert_eq!(report.score, 90);
+  }
+
+  #[test]
+  fn missing_fields_are_skipped() {
+    let report = validate_fingerprint(Some("macos"), &json!({}));
+    assert!(report.findings.is_empty());
+    let wrapped = json!({ "fingerprint": windows_fingerprint() });
+    assert!(validate_fingerprint(None, &wrapped).findings.is_empty());
+  }
+
+  #[test]
+  fn structure_requires_the_applied_fields() {
+    assert!(check_structure(&windows_fingerprint()).is_ok());
+    assert!(check_structure(&json!({ "fingerprint": windows_fingerprint() })).is_ok());
+    assert!(check_structure(&json!("not an object")).is_err());
+
+    let mut fp = windows_fingerprint();
+    fp["screenWidth"] = json!("wide");
+    fp.as_object_mut().unwrap().remove("platform");
+    let err = check_structure(&fp).unwrap_err();
+    assert!(err.contains("platform, screenWidth"), "{err}");
+  }
+
+  #[test]
+  fn summary_falls_back_to_the_user_agent_os() {
+    let summary = summarize(None, &windows_fingerprint());
+    assert_eq!(summary.os.as_deref(), Some("windows"));
+    assert_eq!(summary.platform.as_deref(), Some("Win32"));
+    assert_eq!(
+      (summary.screen_width, summary.screen_height),
+      (Some(1920), Some(1080))
+    );
+    assert_eq!(
+      summarize(Some("linux"), &windows_fingerprint())
+        .os
+        .as_deref(),
+      Some("linux")
+    );
+  }
+}
@@ -0,0 +1,409 @@
+//! Font lists for generated fingerprints. Each OS reports its stock fonts
+//! plus the language packs a user of the fingerprint's locale would have
+//! installed, minus a few optional fonts picked per profile, so profiles that
+//! share an OS and locale still don't share the exact same list.
+
+/// Writing systems that ship their own fonts as an OS add-on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FontScript {
+  Japanese,
+  SimplifiedChinese,
+  TraditionalChinese,
+  Korean,
+  Cyrillic,
+  Arabic,
+  Thai,
+}
+
+const WINDOWS_FONTS: &[&str] = &[
+  "Arial",
+  "Arial Black",
+  "Bahnschrift",
+  "Calibri",
+  "Cambria",
+  "Cambria Math",
+  "Candara",
+  "Comic Sans MS",
+  "Consolas",
+  "Constantia",
+  "Corbel",
+  "Courier New",
+  "Ebrima",
+  "Franklin Gothic Medium",
+  "Gabriola",
+  "Gadugi",
+  "Georgia",
+  "Impact",
+  "Ink Free",
+  "Javanese Text",
+  "Lucida Console",
+  "Lucida Sans Unicode",
+  "Marlett",
+  "Microsoft Himalaya",
+  "Microsoft Sans Serif",
+  "MV Boli",
+  "Myanmar Text",
+  "Nirmala UI",
+  "Palatino Linotype",
+  "Segoe MDL2 Assets",
+  "Segoe Print",
+  "Segoe Script",
+  "Segoe UI",
+  "Segoe UI Emoji",
+  "Segoe UI Symbol",
+  "Sitka Text",
+  "Sylfaen",
+  "Symbol",
+  "Tahoma",
+  "Times New Roman",
+  "Trebuchet MS",
+  "Verdana",
+  "Webdings",
+  "Wingdings",
+];
+
+// macOS installs its CJK, Arabic and Thai system fonts everywhere, so they
+// are part of the base set rather than a pack.
+const MACOS_FONTS: &[&str] = &[
+  "American Typewriter",
+  "Andale Mono",
+  "Apple Color Emoji",
+  "Apple SD Gothic Neo",
+  "Arial",
+  "Arial Black",
+  "Avenir",
+  "Avenir Next",
+  "Baskerville",
+  "Chalkboard",
+  "Comic Sans MS",
+  "Copperplate",
+  "Courier",
+  "Courier New",
+  "Didot",
+  "Futura",
+  "Geeza Pro",
+  "Geneva",
+  "Georgia",
+  "Gill Sans",
+  "Helvetica",
+  "Helvetica Neue",
+  "Hiragino Mincho ProN",
+  "Hiragino Sans",
+  "Impact",
+  "Lucida Grande",
+  "Marker Felt",
+  "Menlo",
+  "Monaco",
+  "Noteworthy",
+  "Optima",
+  "Palatino",
+  "Papyrus",
+  "PingFang HK",
+  "PingFang SC",
+  "PingFang TC",
+  "Rockwell",
+  "Skia",
+  "Thonburi",
+  "Times",
+  "Times New Roman",
+  "Trebuchet MS",
+  "Verdana",
+  "Zapfino",
+];
+
+const LINUX_FONTS: &[&str] = &[
+  "Cantarell",
+  "DejaVu Sans",
+  "DejaVu Sans Mono",
+  "DejaVu Serif",
+  "FreeMono",
+  "FreeSans",
+  "FreeSerif",
+  "Liberation Mono",
+  "Liberation Sans",
+  "Liberation Sans Narrow",
+  "Liberation Serif",
+  "Noto Color Emoji",
+  "Noto Mono",
+  "Noto Sans",
+  "Noto Sans Mono",
+  "Noto Serif",
+  "Ubuntu",
+  "Ubuntu Condensed",
+  "Ubuntu Mono",
+  "URW Bookman",
+  "URW Gothic",
+  "Nimbus Mono PS",
+  "Nimbus Roman",
+  "Nimbus Sans",
+];
+
+/// Fonts that are never dropped: the ones sites probe for first, and the
+/// OS-exclusive fonts the quality checks expect to find.
+const CRITICAL_FONTS: &[&str] = &[
+  "Arial",
+  "Courier New",
+  "Times New Roman",
+  "Segoe UI",
+  "Calibri",
+  "Consolas",
+  "Tahoma",
+  "Helvetica",
+  "Helvetica Neue",
+  "Menlo",
+  "Apple Color Emoji",
+  "Geneva",
+  "Hiragino Sans",
+  "PingFang SC",
+  "DejaVu Sans",
+  "Liberation Sans",
+  "Ubuntu",
+  "Noto Color Emoji",
+];
+
+/// Stock fonts of `os` (`windows`, `macos` or `linux`); empty for others.
+pub fn get_fonts_for_os(os: &str) -> &'static [&'static str] {
+  match os {
+    "windows" => WINDOWS_FONTS,
+    "macos" => MACOS_FONTS,
+    "linux" => LINUX_FONTS,
+    _ => &[],
+  }
+}
+
+/// Fonts the language pack for `script` adds on `os`.
+pub fn get_language_pack_fonts(os: &str, script: FontScript) -> &'static [&'static str] {
+  use FontScript::*;
+  match (os, script) {
+    ("windows", Japanese) => &[
+      "BIZ UDGothic",
+      "Meiryo",
+      "Meiryo UI",
+      "MS Gothic",
+      "MS Mincho",
+      "MS PGothic",
+      "MS PMincho",
+      "MS UI Gothic",
+      "Yu Gothic",
+      "Yu Gothic UI",
+      "Yu Mincho",
+    ],
+    ("windows", SimplifiedChinese) => &[
+      "DengXian",
+      "FangSong",
+      "KaiTi",
+      "Microsoft YaHei",
+      "Microsoft YaHei UI",
+      "NSimSun",
+      "SimHei",
+      "SimSun",
+    ],
+    ("windows", TraditionalChinese) => &[
+      "DFKai-SB",
+      "Microsoft JhengHei",
+      "Microsoft JhengHei UI",
+      "MingLiU",
+      "MingLiU_HKSCS",
+      "PMingLiU",
+    ],
+    ("windows", Korean) => &[
+      "Batang",
+      "BatangChe",
+      "Dotum",
+      "DotumChe",
+      "Gulim",
+      "GulimChe",
+      "Gungsuh",
+      "Malgun Gothic",
+    ],
+    ("windows", Arabic) => &[
+      "Aldhabi",
+      "Andalus",
+      "Arabic Typesetting",
+      "Sakkal Majalla",
+      "Simplified Arabic",
+      "Simplified Arabic Fixed",
+      "Traditional Arabic",
+      "Urdu Typesetting",
+    ],
+    ("windows", Thai) => &[
+      "Angsana New",
+      "AngsanaUPC",
+      "Browallia New",
+      "BrowalliaUPC",
+      "Cordia New",
+      "CordiaUPC",
+      "DilleniaUPC",
+      "IrisUPC",
+      "JasmineUPC",
+      "Leelawadee",
+    ],
+    ("macos", Japanese) => &["Klee", "Osaka", "Tsukushi A Round Gothic"],
+    ("macos", SimplifiedChinese) => &["Kaiti SC", "Songti SC", "Yuanti SC"],
+    ("macos", TraditionalChinese) => &["Kaiti TC", "Songti TC", "Yuanti TC"],
+    ("macos", Korean) => &["Nanum Gothic", "Nanum Myeongjo"],
+    ("linux", Japanese) => &[
+      "IPAGothic",
+      "IPAPGothic",
+      "Noto Sans CJK JP",
+      "Noto Serif CJK JP",
+      "TakaoPGothic",
+    ],
+    ("linux", SimplifiedChinese) => &[
+      "AR PL UMing CN",
+      "Noto Sans CJK SC",
+      "Noto Serif CJK SC",
+      "WenQuanYi Micro Hei",
+      "WenQuanYi Zen Hei",
+    ],
+    ("linux", TraditionalChinese) => &["AR PL UMing TW", "Noto Sans CJK TC", "Noto Serif CJK TC"],
+    ("linux", Korean) => &[
+      "NanumGothic",
+      "NanumMyeongjo",
+      "Noto Sans CJK KR",
+      "Noto Serif CJK KR",
+      "UnDotum",
+    ],
+    ("linux", Cyrillic) => &["PT Mono", "PT Sans", "PT Serif"],
+    ("linux", Arabic) => &["KacstOne", "Noto Naskh Arabic", "Noto Sans Arabic"],
+    ("linux", Thai) => &["Garuda", "Loma", "Noto Sans Thai", "Norasi", "Tlwg Typo"],
+    // The stock Windows and macOS fonts already cover Cyrillic, and macOS
+    // ships its Arabic and Thai fonts with every install.
+    _ => &[],
+  }
+}
+
+/// The add-on script a BCP 47 tag such as `ja-JP` or `zh-Hant-TW` needs.
+pub fn script_for_language(language: &str) -> Option<FontScript> {
+  let mut parts = language.split(['-', '_']);
+  let primary = parts.next()?.to_ascii_lowercase();
+  let rest: Vec<String> = parts.map(|p| p.to_ascii_lowercase()).collect();
+  let script = match primary.as_str() {
+    "ja" => FontScript::Japanese,
+    "ko" => FontScript::Korean,
+    "zh" => {
+      let traditional = rest
+        .iter()
+        .any(|p| matches!(p.as_str(), "hant" | "tw" | "hk" | "mo"));
+      if traditional {
+        FontScript::TraditionalChinese
+      } else {
+        FontScript::SimplifiedChinese
+      }
+    }
+    "ru" | "uk" | "be" | "bg" | "mk" | "kk" | "ky" | "mn" | "tg" => FontScript::Cyrillic,
+    "sr" if !rest.iter().any(|p| p == "latn") => FontScript::Cyrillic,
+    "ar" | "fa" | "ur" | "ps" => FontScript::Arabic,
+    "th" => FontScript::Thai,
+    _ => return None,
+  };
+  Some(script)
+}
+
+/// Stable seed for a profile's font subset.
+pub fn seed_from_id(id: &uuid::Uuid) -> u64 {
+  let value = id.as_u128();
+  (value as u64) ^ ((value >> 64) as u64)
+}
+
+/// SplitMix64, so the same seed picks the same fonts on every build.
+fn next_random(state: &mut u64) -> u64 {
+  *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+  let mut z = *state;
+  z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+  z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+  z ^ (z >> 31)
+}
+
+/// The font list a fingerprint for `os` and `language` reports: the stock
+/// fonts and the language pack, with 2-5 non-critical stock fonts dropped
+/// as picked by `seed`.
+pub fn fonts_for_profile(os: &str, language: Option<&str>, seed: u64) -> Vec<String> {
+  let base = get_fonts_for_os(os);
+  let mut state = seed;
+
+  let mut optional: Vec<usize> = (0..base.len())
+    .filter(|&i| !CRITICAL_FONTS.contains(&base[i]))
+    .collect();
+  let drop_count = (2 + next_random(&mut state) % 4) as usize;
+  let mut dropped = Vec::with_capacity(drop_count);
+  for _ in 0..drop_count.min(optional.len()) {
+    let pick = (next_random(&mut state) % optional.len() as u64) as usize;
+    dropped.push(optional.swap_remove(pick));
+  }
+
+  let mut fonts: Vec<String> = base
+    .iter()
+    .enumerate()
+    .filter(|(i, _)| !dropped.contains(i))
+    .map(|(_, font)| font.to_string())
+    .collect();
+  if let Some(script) = language.and_then(script_for_language) {
+    fonts.extend(
+      get_language_pack_fonts(os, script)
+        .iter()
+        .map(|font| font.to_string()),
+    );
+  }
+  fonts.sort_by_key(|font| font.to_lowercase());
+  fonts
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn japanese_windows_profiles_get_the_japanese_pack() {
+    let seed = seed_from_id(&uuid::Uuid::new_v4());
+    let ja = fonts_for_profile("windows", Some("ja-JP"), seed);
+    assert!(ja.iter().any(|f| f == "Meiryo"));
+    assert!(ja.iter().any(|f| f == "Yu Gothic"));
+
+    let en = fonts_for_profile("windows", Some("en-US"), seed);
+    assert!(!en.iter().any(|f| f == "Meiryo"));
+    assert!(!en.iter().any(|f| f == "Yu Gothic"));
+  }
+
+  #[test]
+  fn subset_is_stable_per_profile_and_keeps_critical_fonts() {
+    let id = uuid::Uuid::new_v4();
+    let first = fonts_for_profile("windows", Some("en-US"), seed_from_id(&id));
+    assert_eq!(
+      first,
+      fonts_for_profile("windows", Some("en-US"), seed_from_id(&id))
+    );
+
+    let dropped = WINDOWS_FONTS.len() - first.len();
+    assert!((2..=5).contains(&dropped), "dropped {dropped}");
+    for font in ["Arial", "Segoe UI", "Calibri", "Consolas", "Tahoma"] {
+      assert!(first.iter().any(|f| f == font), "{font} was dropped");
+    }
+
+    let distinct: std::collections::HashSet<Vec<String>> = (0..20u64)
+      .map(|seed| fonts_for_profile("windows", Some("en-US"), seed))
+      .collect();
+    assert!(distinct.len() > 1);
+  }
+
+  #[test]
+  fn languages_map_to_their_script() {
+    assert_eq!(
+      script_for_language("zh-CN"),
+      Some(FontScript::SimplifiedChinese)
+    );
+    assert_eq!(
+      script_for_language("zh-TW"),
+      Some(FontScript::TraditionalChinese)
+    );
+    assert_eq!(
+      script_for_language("zh-Hant"),
+      Some(FontScript::TraditionalChinese)
+    );
+    assert_eq!(script_for_language("ru"), Some(FontScript::Cyrillic));
+    assert_eq!(script_for_language("sr-Latn-RS"), None);
+    assert_eq!(script_for_language("ar-SA"), Some(FontScript::Arabic));
+    assert_eq!(script_for_language("th_TH"), Some(FontScript::Thai));
+    assert_eq!(script_for_language("en-US"), None);
+  }
+}
@@ -109,7 +109,7 @@ impl GeoIPDownloader {
 
   pub async fn download_geoip_database(
     &self,
-    _app_handle: &tauri::AppHandle,
+    _app_handle: &crate::app_handle::AppHandle,
   ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     if DOWNLOAD_IN_PROGRESS
       .compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst)
@@ -125,21 +125,18 @@ impl GeoIPDownloader {
 
   async fn download_geoip_database_inner(
     &self,
-    _app_handle: &tauri::AppHandle,
+    _app_handle: &crate::app_handle::AppHandle,
   ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     // Emit initial progress
-    let _ = events::emit(
-      "geoip-download-progress",
-      GeoIPDownloadProgress {
-        stage: "downloading".to_string(),
-        percentage: 0.0,
-        message: "Starting GeoIP database download".to_string(),
-        downloaded_bytes: Some(0),
-        total_bytes: None,
-        speed_bytes_per_sec: Some(0.0),
-        eta_seconds: None,
-      },
-    );
+    let _ = events::emit(&GeoIPDownloadProgress {
+      stage: "downloading".to_string(),
+      percentage: 0.0,
+      message: "Starting GeoIP database download".to_string(),
+      downloaded_bytes: Some(0),
+      total_bytes: None,
+      speed_bytes_per_sec: Some(0.0),
+      eta_seconds: None,
+    });
 
     #[cfg(feature = "e2e")]
     let fixture_url = std::env::var("DONUT_E2E_GEOIP_DOWNLOAD_URL")
@@ -217,18 +214,15 @@ impl GeoIPDownloader {
           None
         };
 
-        let _ = events::emit(
-          "geoip-download-progress",
-          GeoIPDownloadProgress {
-            stage: "downloading".to_string(),
-            percentage,
-            message: format!("Downloaded {downloaded} / {total_size} bytes"),
-            downloaded_bytes: Some(downloaded),
-            total_bytes: Some(total_size),
-            speed_bytes_per_sec: Some(speed),
-            eta_seconds: eta,
-          },
-        );
+        let _ = events::emit(&GeoIPDownloadProgress {
+          stage: "downloading".to_string(),
+          percentage,
+          message: format!("Downloaded {downloaded} / {total_size} bytes"),
+          downloaded_bytes: Some(downloaded),
+          total_bytes: Some(total_size),
+          speed_bytes_per_sec: Some(speed),
+          eta_seconds: eta,
+        });
         last_update = now;
       }
     }
@@ -251,18 +245,15 @@ impl GeoIPDownloader {
     let _ = fs::write(&timestamp_path, now.to_string()).await;
 
     // Emit completion
-    let _ = events::emit(
-      "geoip-download-progress",
-      GeoIPDownloadProgress {
-        stage: "completed".to_string(),
-        percentage: 100.0,
-        message: "GeoIP database download completed".to_string(),
-        downloaded_bytes: Some(downloaded),
-        total_bytes: Some(total_size),
-        speed_bytes_per_sec: Some(0.0),
-        eta_seconds: Some(0.0),
-      },
-    );
+    let _ = events::emit(&GeoIPDownloadProgress {
+      stage: "completed".to_string(),
+      percentage: 100.0,
+      message: "GeoIP database download completed".to_string(),
+      downloaded_bytes: Some(downloaded),
+      total_bytes: Some(total_size),
+      speed_bytes_per_sec: Some(0.0),
+      eta_seconds: Some(0.0),
+    });
 
     Ok(())
   }
@@ -8,10 +8,12 @@ use quick_xml::Reader as XmlReader;
 use rand::RngExt;
 use std::collections::HashMap;
 use std::net::IpAddr;
-use std::str::FromStr;
 use std::sync::OnceLock;
 
 const TERRITORY_INFO_XML: &str = include_str!("territory_info.xml");
+/// tzdb's `zone.tab`: one row per (country, IANA zone) with the coordinates of
+/// the zone's principal city.
+const ZONE_TAB: &str = include_str!("zone.tab");
 
 pub use crate::ip_utils::IpError;
 
@@ -63,6 +65,207 @@ pub fn locale_selector() -> Option<&'static LocaleSelector> {
     .as_ref()
 }
 
+/// Build the bundled CLDR and zone.tab tables on a blocking thread. Called at
+/// startup so the first fingerprint generation doesn't parse them inline on an
+/// async launch path.
+pub fn warm_up() {
+  tauri::async_runtime::spawn_blocking(|| {
+    let started = std::time::Instant::now();
+    locale_selector();
+    zone_entries();
+    log::debug!("Geolocation tables ready in {:?}", started.elapsed());
+  });
+}
+
+struct ZoneEntry {
+  country: String,
+  latitude: f64,
+  longitude: f64,
+  timezone: String,
+}
+
+fn zone_entries() -> &'static [ZoneEntry] {
+  static ZONES: OnceLock<Vec<ZoneEntry>> = OnceLock::new();
+  ZONES.get_or_init(|| {
+    ZONE_TAB
+      .lines()
+      .filter(|line| !line.starts_with('#'))
+      .filter_map(|line| {
+        let mut cols = line.split('\t');
+        let country = cols.next()?.to_uppercase();
+        let (latitude, longitude) = parse_iso6709(cols.next()?)?;
+        let timezone = cols.next()?.to_string();
+        Some(ZoneEntry {
+          country,
+          latitude,
+          longitude,
+          timezone,
+        })
+      })
+      .collect()
+  })
+}
+
+/// Parse the ISO 6709 `±DDMM[SS]±DDDMM[SS]` coordinates used by `zone.tab`.
+fn parse_iso6709(coords: &str) -> Option<(f64, f64)> {
+  let split = coords.get(1..)?.find(['+', '-'])? + 1;
+  let (lat, lon) = coords.split_at(split);
+
+  let component = |s: &str, degree_digits: usize| -> Option<f64> {
+    let sign = if s.starts_with('-') { -1.0 } else { 1.0 };
+    let digits = &s[1..];
+    if digits.len() < degree_digits + 2 {
+      return None;
+    }
+    let degrees: f64 = digits[..degree_digits].parse().ok()?;
+    let minutes: f64 = digits[degree_digits..degree_digits + 2].parse().ok()?;
+    let seconds: f64 = match digits.get(degree_digits + 2..degree_digits + 4) {
+      Some(sec) => sec.parse().ok()?,
+      None => 0.0,
+    };
+    Some(sign * (degrees + minutes / 60.0 + seconds / 3600.0))
+  };
+
+  Some((component(lat, 2)?, component(lon, 3)?))
+}
+
+/// Great-circle distance in kilometres.
+fn haversine_km(lat1: f64, lon1: f64, lat2: f64, lon2: f64) -> f64 {
+  let (lat1, lon1, lat2, lon2) = (
+    lat1.to_radians(),
+    lon1.to_radians(),
+    lat2.to_radians(),
+    lon2.to_radians(),
+  );
+  let a = ((lat2 - lat1) / 2.0).sin().powi(2)
+    + lat1.cos() * lat2.cos() * ((lon2 - lon1) / 2.0).sin().powi(2);
+  6371.0 * 2.0 * a.sqrt().asin()
+}
+
+/// IANA timezone for a point inside `country`, from the bundled tzdb table.
+///
+/// Single-zone countries resolve directly. Multi-zone ones (US, RU, BR, ...)
+/// resolve to the zone whose principal city is nearest, which is exact for the
+/// metro areas proxy exits actually sit in and only approximate near zone
+/// borders. Returns `None` for a country tzdb doesn't list.
+pub fn timezone_for_location(country: &str, latitude: f64, longitude: f64) -> Option<String> {
+  let country = country.to_uppercase();
+  zone_entries()
+    .iter()
+    .filter(|z| z.country == country)
+    .min_by(|a, b| {
+      let da = haversine_km(latitude, longitude, a.latitude, a.longitude);
+      let db = haversine_km(latitude, longitude, b.latitude, b.longitude);
+      da.total_cmp(&db)
+    })
+    .map(|z| z.timezone.clone())
+}
+
+/// Whether tzdb places `timezone` in `country` at all.
+pub fn timezone_in_country(country: &str, timezone: &str) -> bool {
+  let country = country.to_uppercase();
+  zone_entries()
+    .iter()
+    .any(|z| z.country == country && z.timezone.eq_ignore_ascii_case(timezone))
+}
+
+/// Canonical tzdb name for a user-supplied timezone (`"europe/berlin"` →
+/// `"Europe/Berlin"`). Matching ignores case and surrounding whitespace;
+/// `None` if it names no zone.
+pub fn parse_timezone(name: &str) -> Option<&'static str> {
+  let name = name.trim();
+  chrono_tz::TZ_VARIANTS
+    .iter()
+    .map(|tz| tz.name())
+    .find(|zone| zone.eq_ignore_ascii_case(name))
+}
+
+/// The `limit` tzdb names closest to a mistyped `name`, best first. A zone is
+/// scored on its full name and on its city alone, so `"Tokio"` still finds
+/// `"Asia/Tokyo"`.
+pub fn closest_timezones(name: &str, limit: usize) -> Vec<&'static str> {
+  let name = name.trim().to_lowercase();
+  let mut scored: Vec<(usize, &'static str)> = chrono_tz::TZ_VARIANTS
+    .iter()
+    .map(|tz| {
+      let zone = tz.name();
+      let lower = zone.to_lowercase();
+      let city = lower.rsplit('/').next().unwrap_or(&lower);
+      let distance = edit_distance(&name, &lower).min(edit_distance(&name, city));
+      (distance, zone)
+    })
+    .collect();
+  scored.sort();
+  scored
+    .into_iter()
+    .take(limit)
+    .map(|(_, zone)| zone)
+    .collect()
+}
+
+/// Levenshtein distance over chars.
+fn edit_distance(a: &str, b: &str) -> usize {
+  let b: Vec<char> = b.chars().collect();
+  let mut prev: Vec<usize> = (0..=b.len()).collect();
+  for (i, ca) in a.chars().enumerate() {
+    let mut row = vec![i + 1; b.len() + 1];
+    for (j, cb) in b.iter().enumerate() {
+      let substitute = prev[j] + usize::from(ca != *cb);
+      row[j + 1] = substitute.min(prev[j + 1] + 1).min(row[j] + 1);
+    }
+    prev = row;
+  }
+  prev[b.len()]
+}
+
+/// Parse a user-supplied BCP 47 tag (`"de-DE"`, `"pt_BR"`, `"fr"`) into a
+/// [`Locale`]. Returns `None` for an empty or non-alphabetic language subtag.
+pub fn parse_locale(tag: &str) -> Option<Locale> {
+  let tag = tag.trim().replace('_', "-");
+  let language = primary_subtag(&tag);
+  if language.len() < 2 || !language.chars().all(|c| c.is_ascii_alphabetic()) {
+    return None;
+  }
+  Some(normalize_locale(&tag))
+}
+
+/// Parse a locale override: one tag or an ordered, comma-separated list
+/// (`"de-DE, en-US"`). Returns `None` if it is empty or any entry is invalid.
+pub fn parse_locale_list(spec: &str) -> Option<LanguagePreference> {
+  let locales = spec
+    .split(',')
+    .map(parse_locale)
+    .collect::<Option<Vec<Locale>>>()?;
+  let first = locales.first()?;
+  let mut languages: Vec<String> = Vec::new();
+  for language in locales.iter().flat_map(Locale::languages) {
+    if !languages.contains(&language) {
+      languages.push(language);
+    }
+  }
+  Some(LanguagePreference {
+    language: first.as_string(),
+    languages,
+  })
+}
+
+/// What a fingerprint reports as `navigator.language`/`navigator.languages`;
+/// the browser's `Accept-Language` is derived from the same list.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LanguagePreference {
+  pub language: String,
+  pub languages: Vec<String>,
+}
+
+impl From<&Locale> for LanguagePreference {
+  fn from(locale: &Locale) -> Self {
+    Self {
+      language: locale.as_string(),
+      languages: locale.languages(),
+    }
+  }
+}
+
 #[derive(Debug, Clone)]
 pub struct Locale {
   pub language: String,
@@ -77,6 +280,17 @@ impl Locale {
       self.language.clone()
     }
   }
+
+  /// `navigator.languages` for this locale: the full tag, then the bare
+  /// language as a fallback — the shape real browsers ship by default.
+  pub fn languages(&self) -> Vec<String> {
+    let full = self.as_string();
+    if full == self.language {
+      vec![full]
+    } else {
+      vec![full, self.language.clone()]
+    }
+  }
 }
 
 #[derive(Debug, Clone)]
@@ -272,8 +486,10 @@ pub fn get_geolocation(ip: &str) -> Result<Geolocation, GeolocationError> {
   let reader =
     Reader::open_readfile(&mmdb_path).map_err(|e| GeolocationError::DatabaseOpen(e.to_string()))?;
 
+  // Tolerates bracketed and zone-scoped IPv6 exits, and looks IPv4-mapped
+  // addresses up as the IPv4 address they carry.
   let ip_addr: IpAddr =
-    IpAddr::from_str(ip).map_err(|_| GeolocationError::InvalidIP(ip.to_string()))?;
+    crate::ip_utils::parse_ip(ip).ok_or_else(|| GeolocationError::InvalidIP(ip.to_string()))?;
 
   let lookup_result = reader
     .lookup(ip_addr)
@@ -291,10 +507,6 @@ pub fn get_geolocation(ip: &str) -> Result<Geolocation, GeolocationError> {
   let latitude = location
     .latitude
     .ok_or_else(|| GeolocationError::LocationNotFound("No latitude".to_string()))?;
-  let timezone = location
-    .time_zone
-    .ok_or_else(|| GeolocationError::LocationNotFound("No timezone".to_string()))?
-    .to_string();
 
   let country = &city.country;
   let iso_code = country
@@ -302,6 +514,15 @@ pub fn get_geolocation(ip: &str) -> Result<Geolocation, GeolocationError> {
     .ok_or_else(|| GeolocationError::LocationNotFound("No country code".to_string()))?
     .to_uppercase();
 
+  // GeoLite2 omits the zone for some (mostly country-level) records. Fall back
+  // to the tzdb zone nearest the coordinates rather than failing the lookup —
+  // failing here leaves the fingerprint on the generator's US default.
+  let timezone = match location.time_zone {
+    Some(tz) => tz.to_string(),
+    None => timezone_for_location(&iso_code, latitude, longitude)
+      .ok_or_else(|| GeolocationError::LocationNotFound("No timezone".to_string()))?,
+  };
+
   let selector = LocaleSelector::new()?;
   let locale = selector.from_region(&iso_code)?;
 
@@ -367,4 +588,116 @@ mod tests {
     assert_eq!(zh_hant_us.language, "zh");
     assert_eq!(zh_hant_us.region, Some("US".to_string()));
   }
+
+  #[test]
+  fn test_parse_iso6709() {
+    let (lat, lon) = parse_iso6709("+4230+00131").unwrap();
+    assert!((lat - 42.5).abs() < 1e-9);
+    assert!((lon - (1.0 + 31.0 / 60.0)).abs() < 1e-9);
+
+    let (lat, lon) = parse_iso6709("+404251-0740023").unwrap();
+    assert!((lat - (40.0 + 42.0 / 60.0 + 51.0 / 3600.0)).abs() < 1e-9);
+    assert!((lon + (74.0 + 23.0 / 3600.0)).abs() < 1e-9);
+
+    assert!(parse_iso6709("garbage").is_none());
+  }
+
+  #[test]
+  fn test_timezone_for_single_zone_countries() {
+    // Berlin, Paris, Tokyo.
+    assert_eq!(
+      timezone_for_location("DE", 52.52, 13.40).as_deref(),
+      Some("Europe/Berlin")
+    );
+    assert_eq!(
+      timezone_for_location("fr", 48.85, 2.35).as_deref(),
+      Some("Europe/Paris")
+    );
+    assert_eq!(
+      timezone_for_location("JP", 35.68, 139.69).as_deref(),
+      Some("Asia/Tokyo")
+    );
+  }
+
+  #[test]
+  fn test_timezone_for_multi_zone_countries() {
+    // New York, Chicago, Los Angeles.
+    assert_eq!(
+      timezone_for_location("US", 40.71, -74.01).as_deref(),
+      Some("America/New_York")
+    );
+    assert_eq!(
+      timezone_for_location("US", 41.88, -87.63).as_deref(),
+      Some("America/Chicago")
+    );
+    assert_eq!(
+      timezone_for_location("US", 34.05, -118.24).as_deref(),
+      Some("America/Los_Angeles")
+    );
+    // Moscow, Novosibirsk, Vladivostok.
+    assert_eq!(
+      timezone_for_location("RU", 55.76, 37.62).as_deref(),
+      Some("Europe/Moscow")
+    );
+    assert_eq!(
+      timezone_for_location("RU", 55.03, 82.92).as_deref(),
+      Some("Asia/Novosibirsk")
+    );
+    assert_eq!(
+      timezone_for_location("RU", 43.12, 131.89).as_deref(),
+      Some("Asia/Vladivostok")
+    );
+  }
+
+  #[test]
+  fn test_timezone_never_crosses_the_country() {
+    // A point right by Berlin must still resolve inside Poland when the
+    // country says Poland.
+    assert_eq!(
+      timezone_for_location("PL", 52.52, 13.40).as_deref(),
+      Some("Europe/Warsaw")
+    );
+    assert!(timezone_for_location("ZZ", 0.0, 0.0).is_none());
+    assert!(timezone_in_country("US", "America/Chicago"));
+    assert!(!timezone_in_country("DE", "America/New_York"));
+  }
+
+  #[test]
+  fn test_parse_locale_and_languages() {
+    let de = parse_locale("de_DE").unwrap();
+    assert_eq!(de.as_string(), "de-DE");
+    assert_eq!(de.languages(), vec!["de-DE".to_string(), "de".to_string()]);
+
+    let fr = parse_locale(" fr ").unwrap();
+    assert_eq!(fr.languages(), vec!["fr".to_string()]);
+
+    assert!(parse_locale("").is_none());
+    assert!(parse_locale("1-US").is_none());
+  }
+
+  #[test]
+  fn test_parse_locale_list_keeps_order_and_dedupes() {
+    let prefs = parse_locale_list("de-DE, en-US,en").unwrap();
+    assert_eq!(prefs.language, "de-DE");
+    assert_eq!(prefs.languages, vec!["de-DE", "de", "en-US", "en"]);
+
+    assert_eq!(
+      parse_locale_list("ja").unwrap(),
+      LanguagePreference::from(&parse_locale("ja").unwrap())
+    );
+    assert!(parse_locale_list("de-DE,,en").is_none());
+    assert!(parse_locale_list(" ").is_none());
+  }
+
+  #[test]
+  fn test_parse_timezone_and_suggestions() {
+    assert_eq!(parse_timezone(" europe/berlin "), Some("Europe/Berlin"));
+    assert_eq!(parse_timezone("UTC"), Some("UTC"));
+    assert!(parse_timezone("Mars/Olympus").is_none());
+    assert!(parse_timezone("").is_none());
+
+    assert_eq!(closest_timezones("Europe/Berln", 3)[0], "Europe/Berlin");
+    assert_eq!(closest_timezones("tokio", 3)[0], "Asia/Tokyo");
+    assert_eq!(closest_timezones("x", 5).len(), 5);
+  }
 }
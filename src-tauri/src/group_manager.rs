@@ -17,17 +17,26 @@ pub struct ProfileGroup {
   /// conflict resolution (last-write-wins); bumped on edits only.
   #[serde(default)]
   pub updated_at: Option<u64>,
+  /// Profiles in a protected group are kept from automated deletion, such as
+  /// the MCP `delete_profile` tool without `force`.
+  #[serde(default)]
+  pub protected: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GroupWithCount {
   pub id: String,
   pub name: String,
+  /// Members that aren't archived.
   pub count: usize,
   #[serde(default)]
+  pub archived_count: usize,
+  #[serde(default)]
   pub sync_enabled: bool,
   #[serde(default)]
   pub last_sync: Option<u64>,
+  #[serde(default)]
+  pub protected: bool,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -53,9 +62,7 @@ impl GroupManager {
       return Ok(GroupsData { groups: Vec::new() });
     }
 
-    let content = fs::read_to_string(groups_file)?;
-    let groups_data: GroupsData = serde_json::from_str(&content)?;
-    Ok(groups_data)
+    Ok(crate::json_file::read(&groups_file)?.value)
   }
 
   fn save_groups_data(&self, groups_data: &GroupsData) -> Result<(), Box<dyn std::error::Error>> {
@@ -67,7 +74,7 @@ impl GroupManager {
     }
 
     let json = serde_json::to_string_pretty(groups_data)?;
-    fs::write(groups_file, json)?;
+    crate::json_file::write(&groups_file, json.as_bytes(), false)?;
     Ok(())
   }
 
@@ -78,7 +85,7 @@ impl GroupManager {
 
   pub fn create_group(
     &self,
-    _app_handle: &tauri::AppHandle,
+    _app_handle: &crate::app_handle::AppHandle,
     name: String,
   ) -> Result<ProfileGroup, Box<dyn std::error::Error>> {
     if name.trim().is_empty() {
@@ -107,6 +114,7 @@ impl GroupManager {
       sync_enabled,
       last_sync: None,
       updated_at: Some(crate::proxy_manager::now_secs()),
+      protected: false,
     };
 
     groups_data.groups.push(group.clone());
@@ -131,9 +139,10 @@ impl GroupManager {
 
   pub fn update_group(
     &self,
-    _app_handle: &tauri::AppHandle,
+    _app_handle: &crate::app_handle::AppHandle,
     id: String,
     name: String,
+    protected: Option<bool>,
   ) -> Result<ProfileGroup, Box<dyn std::error::Error>> {
     if name.trim().is_empty() {
       return Err(
@@ -165,6 +174,9 @@ impl GroupManager {
       .ok_or_else(|| serde_json::json!({ "code": "GROUP_NOT_FOUND" }).to_string())?;
 
     group.name = name;
+    if let Some(protected) = protected {
+      group.protected = protected;
+    }
     group.updated_at = Some(crate::proxy_manager::now_secs());
     let updated_group = group.clone();
 
@@ -198,6 +210,7 @@ impl GroupManager {
       existing.sync_enabled = group.sync_enabled;
       existing.last_sync = group.last_sync;
       existing.updated_at = group.updated_at;
+      existing.protected = group.protected;
       self.save_groups_data(&groups_data)?;
     }
 
@@ -215,6 +228,7 @@ impl GroupManager {
       existing.sync_enabled = group.sync_enabled;
       existing.last_sync = group.last_sync;
       existing.updated_at = group.updated_at;
+      existing.protected = group.protected;
     } else {
       groups_data.groups.push(group.clone());
     }
@@ -240,7 +254,7 @@ impl GroupManager {
 
   pub fn delete_group(
     &self,
-    app_handle: &tauri::AppHandle,
+    app_handle: &crate::app_handle::AppHandle,
     id: String,
   ) -> Result<(), Box<dyn std::error::Error>> {
     let mut groups_data = self.load_groups_data()?;
@@ -300,11 +314,17 @@ impl GroupManager {
   ) -> Result<Vec<GroupWithCount>, Box<dyn std::error::Error>> {
     let groups = self.get_all_groups()?;
     let mut group_counts = HashMap::new();
+    let mut archived_counts = HashMap::new();
 
-    // Count profiles in each group
+    // Count profiles in each group, archived members separately
     for profile in profiles {
       if let Some(group_id) = &profile.group_id {
-        *group_counts.entry(group_id.clone()).or_insert(0) += 1;
+        let counts = if profile.archived {
+          &mut archived_counts
+        } else {
+          &mut group_counts
+        };
+        *counts.entry(group_id.clone()).or_insert(0) += 1;
       }
     }
 
@@ -314,12 +334,15 @@ impl GroupManager {
     let mut result = Vec::new();
     for group in groups {
       let count = group_counts.get(&group.id).copied().unwrap_or(0);
+      let archived_count = archived_counts.get(&group.id).copied().unwrap_or(0);
       result.push(GroupWithCount {
         id: group.id,
         name: group.name,
         count,
+        archived_count,
         sync_enabled: group.sync_enabled,
         last_sync: group.last_sync,
+        protected: group.protected,
       });
     }
 
@@ -360,7 +383,7 @@ pub async fn get_groups_with_profile_counts() -> Result<Vec<GroupWithCount>, Str
 
 #[tauri::command]
 pub async fn create_profile_group(
-  app_handle: tauri::AppHandle,
+  app_handle: crate::app_handle::AppHandle,
   name: String,
 ) -> Result<ProfileGroup, String> {
   let group_manager = GROUP_MANAGER.lock().unwrap();
@@ -371,19 +394,20 @@ pub async fn create_profile_group(
 
 #[tauri::command]
 pub async fn update_profile_group(
-  app_handle: tauri::AppHandle,
+  app_handle: crate::app_handle::AppHandle,
   group_id: String,
   name: String,
+  protected: Option<bool>,
 ) -> Result<ProfileGroup, String> {
   let group_manager = GROUP_MANAGER.lock().unwrap();
   group_manager
-    .update_group(&app_handle, group_id, name)
+    .update_group(&app_handle, group_id, name, protected)
     .map_err(|e| e.to_string())
 }
 
 #[tauri::command]
 pub async fn delete_profile_group(
-  app_handle: tauri::AppHandle,
+  app_handle: crate::app_handle::AppHandle,
   group_id: String,
 ) -> Result<(), String> {
   let group_manager = GROUP_MANAGER.lock().unwrap();
@@ -394,7 +418,7 @@ pub async fn delete_profile_group(
 
 #[tauri::command]
 pub async fn assign_profiles_to_group(
-  app_handle: tauri::AppHandle,
+  app_handle: crate::app_handle::AppHandle,
   profile_ids: Vec<String>,
   group_id: Option<String>,
 ) -> Result<(), String> {
@@ -406,11 +430,14 @@ pub async fn assign_profiles_to_group(
 
 #[tauri::command]
 pub async fn delete_selected_profiles(
-  app_handle: tauri::AppHandle,
+  app_handle: crate::app_handle::AppHandle,
   profile_ids: Vec<String>,
-) -> Result<(), String> {
+  force: Option<bool>,
+) -> Result<crate::profile::types::BulkDeleteResult, String> {
   let profile_manager = crate::profile::ProfileManager::instance();
-  profile_manager
-    .delete_multiple_profiles(&app_handle, profile_ids)
-    .map_err(|e| format!("Failed to delete profiles: {e}"))
+  Ok(
+    profile_manager
+      .delete_multiple_profiles(&app_handle, profile_ids, force.unwrap_or(false))
+      .await,
+  )
 }
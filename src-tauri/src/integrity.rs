@@ -0,0 +1,136 @@
+//! SHA-256 helpers shared by the app updater and the browser downloader:
+//! hashing a downloaded archive, hashing an extracted install tree so it can
+//! be re-verified later, and parsing the digest formats releases publish.
+
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+fn to_hex(digest: &[u8]) -> String {
+  let mut hex = String::with_capacity(digest.len() * 2);
+  for byte in digest {
+    use std::fmt::Write;
+    let _ = write!(hex, "{byte:02x}");
+  }
+  hex
+}
+
+fn hash_reader(
+  hasher: &mut Sha256,
+  mut reader: impl Read,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+  let mut buf = vec![0u8; 1024 * 1024];
+  loop {
+    let n = reader.read(&mut buf)?;
+    if n == 0 {
+      return Ok(());
+    }
+    hasher.update(&buf[..n]);
+  }
+}
+
+pub fn sha256_file(path: &Path) -> Result<String, Box<dyn std::error::Error + Send + Sync>> {
+  let mut hasher = Sha256::new();
+  hash_reader(&mut hasher, fs::File::open(path)?)?;
+  Ok(to_hex(&hasher.finalize()))
+}
+
+/// Digest of a whole directory tree: every file's path relative to `root`
+/// (sorted, `/`-separated) followed by its contents, and every symlink's path
+/// followed by its target. Stable across machines for the same extracted
+/// archive, so it can be recorded once and re-checked later.
+pub fn sha256_tree(root: &Path) -> Result<String, Box<dyn std::error::Error + Send + Sync>> {
+  let mut entries: Vec<(String, PathBuf)> = Vec::new();
+  let mut stack = vec![root.to_path_buf()];
+  while let Some(dir) = stack.pop() {
+    for entry in fs::read_dir(&dir)? {
+      let entry = entry?;
+      let path = entry.path();
+      if entry.file_type()?.is_dir() {
+        stack.push(path);
+        continue;
+      }
+      let relative = path
+        .strip_prefix(root)?
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/");
+      entries.push((relative, path));
+    }
+  }
+  entries.sort();
+
+  let mut hasher = Sha256::new();
+  for (relative, path) in entries {
+    hasher.update(relative.as_bytes());
+    hasher.update([0u8]);
+    if fs::symlink_metadata(&path)?.file_type().is_symlink() {
+      hasher.update(fs::read_link(&path)?.to_string_lossy().as_bytes());
+    } else {
+      hash_reader(&mut hasher, fs::File::open(&path)?)?;
+    }
+    hasher.update([0u8]);
+  }
+  Ok(to_hex(&hasher.finalize()))
+}
+
+/// Extract a SHA-256 hex digest from the formats releases publish it in:
+/// a bare hex string, GitHub's `sha256:<hex>` asset digest, or a
+/// `sha256sum`-style `<hex>  <filename>` line. Returned lowercase.
+pub fn parse_sha256(text: &str) -> Option<String> {
+  let token = text.split_whitespace().next()?;
+  let hex = token.strip_prefix("sha256:").unwrap_or(token);
+  (hex.len() == 64 && hex.bytes().all(|b| b.is_ascii_hexdigit())).then(|| hex.to_ascii_lowercase())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_sha256_file_matches_known_digest() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let path = temp_dir.path().join("data.bin");
+    std::fs::write(&path, b"hello world").unwrap();
+    assert_eq!(
+      sha256_file(&path).unwrap(),
+      // sha256 of "hello world"
+      "b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9"
+    );
+  }
+
+  #[test]
+  fn test_sha256_tree_detects_content_and_layout_changes() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let root = temp_dir.path();
+    fs::create_dir_all(root.join("bin")).unwrap();
+    fs::write(root.join("bin/chrome"), b"binary").unwrap();
+    fs::write(root.join("resources.pak"), b"resources").unwrap();
+
+    let original = sha256_tree(root).unwrap();
+    assert_eq!(sha256_tree(root).unwrap(), original);
+
+    fs::write(root.join("bin/chrome"), b"tampered").unwrap();
+    let tampered = sha256_tree(root).unwrap();
+    assert_ne!(tampered, original);
+
+    fs::write(root.join("bin/chrome"), b"binary").unwrap();
+    fs::rename(root.join("resources.pak"), root.join("bin/resources.pak")).unwrap();
+    assert_ne!(sha256_tree(root).unwrap(), original);
+  }
+
+  #[test]
+  fn test_parse_sha256_formats() {
+    let hex = "b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9";
+    assert_eq!(parse_sha256(hex).as_deref(), Some(hex));
+    assert_eq!(parse_sha256(&format!("sha256:{hex}")).as_deref(), Some(hex));
+    assert_eq!(
+      parse_sha256(&format!("{}  wayfern.tar.xz\n", hex.to_uppercase())).as_deref(),
+      Some(hex)
+    );
+    assert_eq!(parse_sha256("not-a-digest"), None);
+    assert_eq!(parse_sha256(""), None);
+  }
+}
@@ -1,9 +1,11 @@
 //! IP address utilities shared across the application.
 //!
-//! Provides IP validation and public IP fetching functionality.
+//! Provides IP validation, host/port formatting that keeps IPv6 literals
+//! intact, and public IP fetching functionality.
 
 use std::net::IpAddr;
 use std::str::FromStr;
+use url::Url;
 
 /// IP utility error type.
 #[derive(Debug, thiserror::Error)]
@@ -17,6 +19,97 @@ pub fn validate_ip(ip: &str) -> bool {
   IpAddr::from_str(ip).is_ok()
 }
 
+/// Parse an IP address the way users and URLs write it: surrounding brackets
+/// and an IPv6 zone ID (`fe80::1%eth0`) are tolerated, and IPv4-mapped IPv6
+/// addresses come back as plain IPv4 so GeoIP lookups hit the IPv4 records.
+pub fn parse_ip(ip: &str) -> Option<IpAddr> {
+  let ip = unbracket(ip.trim());
+  let ip = ip.split_once('%').map_or(ip, |(addr, _zone)| addr);
+  IpAddr::from_str(ip).ok().map(|ip| ip.to_canonical())
+}
+
+/// Strip the brackets `Url::host_str` and `http::Uri::host` put around IPv6
+/// literals. Anything else comes back unchanged.
+pub fn unbracket(host: &str) -> &str {
+  host
+    .strip_prefix('[')
+    .and_then(|h| h.strip_suffix(']'))
+    .unwrap_or(host)
+}
+
+/// `host:port`, bracketing IPv6 literals so the result parses back as a socket
+/// address or URL authority.
+pub fn host_port(host: &str, port: u16) -> String {
+  let host = unbracket(host);
+  if host.contains(':') {
+    format!("[{host}]:{port}")
+  } else {
+    format!("{host}:{port}")
+  }
+}
+
+/// Split `host:port` or `[v6]:port` into an unbracketed host and the port.
+/// A bare IPv6 literal without brackets is ambiguous and yields `None`.
+pub fn split_host_port(authority: &str) -> Option<(&str, u16)> {
+  if let Some(rest) = authority.strip_prefix('[') {
+    let (host, rest) = rest.split_once(']')?;
+    let port = rest.strip_prefix(':')?.parse().ok()?;
+    return Some((host, port));
+  }
+  let (host, port) = authority.rsplit_once(':')?;
+  if host.is_empty() || host.contains(':') {
+    return None;
+  }
+  Some((host, port.parse().ok()?))
+}
+
+/// A URL's host ready for dialing: IPv6 literals without their brackets.
+pub fn url_host(url: &Url) -> Option<String> {
+  match url.host()? {
+    url::Host::Ipv6(addr) => Some(addr.to_string()),
+    url::Host::Ipv4(addr) => Some(addr.to_string()),
+    url::Host::Domain(domain) => Some(domain.to_string()),
+  }
+}
+
+/// Normalize a proxy host as entered: trims it, drops IPv6 brackets and
+/// rejects anything that is neither an IP literal nor a plausible hostname.
+/// Zone IDs are rejected too — the upstream is handed to the proxy worker as a
+/// URL, and URLs cannot carry one.
+pub fn normalize_proxy_host(host: &str) -> Result<String, String> {
+  let trimmed = host.trim();
+  let bare = unbracket(trimmed);
+  let invalid =
+    |code: &str| serde_json::json!({ "code": code, "params": { "host": trimmed } }).to_string();
+  if bare.contains('%') && bare.contains(':') {
+    return Err(invalid("PROXY_HOST_ZONE_ID"));
+  }
+  if let Some(ip) = parse_ip(bare) {
+    return Ok(ip.to_string());
+  }
+  let is_hostname = bare == trimmed
+    && !bare.is_empty()
+    && bare.len() <= 253
+    && bare
+      .strip_suffix('.')
+      .unwrap_or(bare)
+      .split('.')
+      .all(|label| {
+        !label.is_empty()
+          && label.len() <= 63
+          && !label.starts_with('-')
+          && !label.ends_with('-')
+          && label
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '-' || c == '_')
+      });
+  if is_hostname {
+    Ok(bare.to_string())
+  } else {
+    Err(invalid("INVALID_PROXY_HOST"))
+  }
+}
+
 /// Fetch public IP address, optionally through a proxy.
 pub async fn fetch_public_ip(proxy: Option<&str>) -> Result<String, IpError> {
   let urls = [
@@ -26,6 +119,8 @@ pub async fn fetch_public_ip(proxy: Option<&str>) -> Result<String, IpError> {
     "https://icanhazip.com",
     "https://ifconfig.co/ip",
     "https://ipecho.net/plain",
+    // Dual-stack, so an IPv6-only exit still gets an answer.
+    "https://api64.ipify.org",
   ];
 
   // 10s rather than 5s: residential proxies that allocate an exit on first
@@ -64,9 +159,10 @@ pub async fn fetch_public_ip(proxy: Option<&str>) -> Result<String, IpError> {
       match client.get(*url).send().await {
         Ok(response) if response.status().is_success() => match response.text().await {
           Ok(text) => {
-            let ip = text.trim().to_string();
-            if validate_ip(&ip) {
-              Ok(ip)
+            // Canonical form, so an IPv4-mapped answer from a dual-stack
+            // endpoint still geolocates against the IPv4 records.
+            if let Some(ip) = parse_ip(&text) {
+              Ok(ip.to_string())
             } else {
               Err(format!("{}: response is not an IP address", url))
             }
@@ -110,4 +206,94 @@ mod tests {
     assert!(!validate_ip("invalid"));
     assert!(!validate_ip("256.256.256.256"));
   }
+
+  #[test]
+  fn test_parse_ip_tolerates_brackets_zones_and_mapped_v4() {
+    assert_eq!(
+      parse_ip("[2001:db8::1]"),
+      Some("2001:db8::1".parse().unwrap())
+    );
+    assert_eq!(parse_ip("fe80::1%eth0"), Some("fe80::1".parse().unwrap()));
+    assert_eq!(parse_ip("[fe80::1%25]"), Some("fe80::1".parse().unwrap()));
+    assert_eq!(
+      parse_ip(" ::ffff:8.8.8.8\n"),
+      Some("8.8.8.8".parse().unwrap())
+    );
+    assert_eq!(parse_ip("example.com"), None);
+  }
+
+  #[test]
+  fn test_host_port_brackets_ipv6() {
+    assert_eq!(host_port("127.0.0.1", 8080), "127.0.0.1:8080");
+    assert_eq!(
+      host_port("proxy.example.com", 1080),
+      "proxy.example.com:1080"
+    );
+    assert_eq!(host_port("2001:db8::1", 3128), "[2001:db8::1]:3128");
+    assert_eq!(host_port("[2001:db8::1]", 3128), "[2001:db8::1]:3128");
+    assert_eq!(host_port("fe80::1%eth0", 80), "[fe80::1%eth0]:80");
+    assert!(host_port("::1", 1080)
+      .parse::<std::net::SocketAddr>()
+      .is_ok());
+  }
+
+  #[test]
+  fn test_split_host_port() {
+    assert_eq!(
+      split_host_port("example.com:443"),
+      Some(("example.com", 443))
+    );
+    assert_eq!(
+      split_host_port("[2001:db8::1]:443"),
+      Some(("2001:db8::1", 443))
+    );
+    assert_eq!(
+      split_host_port("[fe80::1%eth0]:80"),
+      Some(("fe80::1%eth0", 80))
+    );
+    // Unbracketed IPv6 cannot be told apart from its port.
+    assert_eq!(split_host_port("2001:db8::1:443"), None);
+    assert_eq!(split_host_port("[2001:db8::1]"), None);
+    assert_eq!(split_host_port("example.com"), None);
+  }
+
+  #[test]
+  fn test_url_host_drops_brackets() {
+    let url = Url::parse("socks5://user:pass@[2001:db8::1]:1080").unwrap();
+    assert_eq!(url.host_str(), Some("[2001:db8::1]"));
+    assert_eq!(url_host(&url).as_deref(), Some("2001:db8::1"));
+    let url = Url::parse("http://proxy.example.com:8080").unwrap();
+    assert_eq!(url_host(&url).as_deref(), Some("proxy.example.com"));
+  }
+
+  #[test]
+  fn test_normalize_proxy_host() {
+    assert_eq!(normalize_proxy_host(" 10.0.0.1 ").unwrap(), "10.0.0.1");
+    assert_eq!(
+      normalize_proxy_host("[2001:DB8::1]").unwrap(),
+      "2001:db8::1"
+    );
+    assert_eq!(normalize_proxy_host("2001:db8::1").unwrap(), "2001:db8::1");
+    assert_eq!(
+      normalize_proxy_host("v6-only.example.com").unwrap(),
+      "v6-only.example.com"
+    );
+    assert_eq!(
+      normalize_proxy_host("example.com.").unwrap(),
+      "example.com."
+    );
+
+    let zone = normalize_proxy_host("fe80::1%eth0").unwrap_err();
+    assert!(zone.contains("PROXY_HOST_ZONE_ID"));
+    for bad in [
+      "",
+      "exa mple.com",
+      "[example.com]",
+      "-bad.example",
+      "host:8080",
+    ] {
+      let err = normalize_proxy_host(bad).unwrap_err();
+      assert!(err.contains("INVALID_PROXY_HOST"), "{bad}: {err}");
+    }
+  }
 }
@@ -0,0 +1,221 @@
+//! Crash-safe JSON files for the metadata stores (profiles, groups, proxies).
+//!
+//! A write goes to a uniquely named temp file in the same directory, is
+//! fsynced and then renamed over the target, so a power loss leaves either
+//! the old or the new version, never a truncated one. The version being
+//! replaced is kept as `<file>.bak`, and a load whose primary file can't be
+//! read or parsed falls back to it. Writes to the same path are serialized,
+//! so the scheduler, the API and the GUI saving one entity at once can't
+//! interleave.
+
+use serde::de::DeserializeOwned;
+use std::collections::HashMap;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+
+static TMP_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// A value read by [`read`].
+#[derive(Debug)]
+pub struct Loaded<T> {
+  pub value: T,
+  /// The primary file was unreadable and `value` came from its backup, which
+  /// has since been copied back in place.
+  pub recovered: bool,
+}
+
+fn path_lock(path: &Path) -> Arc<Mutex<()>> {
+  static LOCKS: OnceLock<Mutex<HashMap<PathBuf, Arc<Mutex<()>>>>> = OnceLock::new();
+  LOCKS
+    .get_or_init(|| Mutex::new(HashMap::new()))
+    .lock()
+    .unwrap_or_else(|poisoned| poisoned.into_inner())
+    .entry(path.to_path_buf())
+    .or_default()
+    .clone()
+}
+
+fn with_suffix(path: &Path, suffix: &str) -> PathBuf {
+  let mut name = path.file_name().unwrap_or_default().to_os_string();
+  name.push(suffix);
+  path.with_file_name(name)
+}
+
+fn backup_path(path: &Path) -> PathBuf {
+  with_suffix(path, ".bak")
+}
+
+/// A temp path next to `path` that no other writer, in this process or
+/// another, is using.
+fn tmp_path(path: &Path) -> PathBuf {
+  let n = TMP_COUNTER.fetch_add(1, Ordering::Relaxed);
+  with_suffix(path, &format!(".{}.{n}.tmp", std::process::id()))
+}
+
+/// Makes a rename in `dir` durable. Best-effort: not every platform lets a
+/// directory be opened for syncing.
+fn sync_dir(dir: &Path) {
+  #[cfg(unix)]
+  {
+    if let Ok(dir) = fs::File::open(dir) {
+      let _ = dir.sync_all();
+    }
+  }
+  #[cfg(not(unix))]
+  {
+    let _ = dir;
+  }
+}
+
+/// Atomically replaces `path` with `data`, keeping the previous version as
+/// `<path>.bak`. `owner_only` creates the file with `0600` on Unix, for
+/// files holding credentials.
+pub fn write(path: &Path, data: &[u8], owner_only: bool) -> io::Result<()> {
+  let lock = path_lock(path);
+  let _guard = lock.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+
+  let tmp = tmp_path(path);
+  let written = (|| -> io::Result<()> {
+    let mut file = if owner_only {
+      crate::app_dirs::create_owner_only(&tmp)?
+    } else {
+      fs::File::create(&tmp)?
+    };
+    file.write_all(data)?;
+    file.sync_all()?;
+    if path.exists() {
+      // fs::copy carries the permissions over, so a credential file's backup
+      // stays private too.
+      fs::copy(path, backup_path(path))?;
+    }
+    fs::rename(&tmp, path)
+  })();
+  if let Err(e) = written {
+    let _ = fs::remove_file(&tmp);
+    return Err(e);
+  }
+  if let Some(dir) = path.parent() {
+    sync_dir(dir);
+  }
+  Ok(())
+}
+
+fn parse<T: DeserializeOwned>(path: &Path) -> io::Result<T> {
+  let content = fs::read_to_string(path)?;
+  Ok(serde_json::from_str(&content)?)
+}
+
+/// Reads and parses `path`, falling back to `<path>.bak` when the primary
+/// file can't be read or parsed. A recovered backup is copied back over the
+/// primary so the next read doesn't warn again. Fails with the primary's
+/// error when there is no usable backup.
+pub fn read<T: DeserializeOwned>(path: &Path) -> io::Result<Loaded<T>> {
+  let primary_error = match parse(path) {
+    Ok(value) => {
+      return Ok(Loaded {
+        value,
+        recovered: false,
+      })
+    }
+    Err(e) => e,
+  };
+  let backup = backup_path(path);
+  let Ok(value) = parse(&backup) else {
+    return Err(primary_error);
+  };
+  log::warn!(
+    "Recovered {} from its backup after a corrupt write: {primary_error}",
+    path.display()
+  );
+
+  let lock = path_lock(path);
+  let _guard = lock.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+  let tmp = tmp_path(path);
+  let restored = fs::copy(&backup, &tmp).and_then(|_| fs::rename(&tmp, path));
+  if let Err(e) = restored {
+    let _ = fs::remove_file(&tmp);
+    log::warn!("Failed to restore {} from its backup: {e}", path.display());
+  }
+  Ok(Loaded {
+    value,
+    recovered: true,
+  })
+}
+
+/// Removes `path` and its backup. A missing file is not an error.
+pub fn remove(path: &Path) -> io::Result<()> {
+  let lock = path_lock(path);
+  let _guard = lock.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+  let _ = fs::remove_file(backup_path(path));
+  match fs::remove_file(path) {
+    Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e),
+    _ => Ok(()),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[derive(Debug, serde::Serialize, serde::Deserialize)]
+  struct Entry {
+    name: String,
+  }
+
+  fn entry(name: &str) -> Vec<u8> {
+    serde_json::to_vec(&Entry {
+      name: name.to_string(),
+    })
+    .unwrap()
+  }
+
+  #[test]
+  fn write_keeps_previous_version_as_backup() {
+    let tmp = tempfile::tempdir().unwrap();
+    let path = tmp.path().join("entry.json");
+    write(&path, &entry("one"), false).unwrap();
+    write(&path, &entry("two"), false).unwrap();
+
+    assert_eq!(read::<Entry>(&path).unwrap().value.name, "two");
+    assert_eq!(fs::read(backup_path(&path)).unwrap(), entry("one"));
+    assert_eq!(fs::read_dir(tmp.path()).unwrap().count(), 2);
+  }
+
+  #[test]
+  fn truncated_file_is_recovered_from_backup() {
+    let tmp = tempfile::tempdir().unwrap();
+    let path = tmp.path().join("entry.json");
+    write(&path, &entry("one"), false).unwrap();
+    write(&path, &entry("two"), false).unwrap();
+    let full = fs::read(&path).unwrap();
+    fs::write(&path, &full[..full.len() / 2]).unwrap();
+
+    let loaded = read::<Entry>(&path).unwrap();
+    assert!(loaded.recovered);
+    assert_eq!(loaded.value.name, "one");
+    let again = read::<Entry>(&path).unwrap();
+    assert!(!again.recovered, "the primary is restored after recovery");
+  }
+
+  #[test]
+  fn corrupt_file_without_backup_fails() {
+    let tmp = tempfile::tempdir().unwrap();
+    let path = tmp.path().join("entry.json");
+    fs::write(&path, b"{\"name\":").unwrap();
+    assert!(read::<Entry>(&path).is_err());
+  }
+
+  #[test]
+  fn remove_drops_the_backup_too() {
+    let tmp = tempfile::tempdir().unwrap();
+    let path = tmp.path().join("entry.json");
+    write(&path, &entry("one"), true).unwrap();
+    write(&path, &entry("two"), true).unwrap();
+    remove(&path).unwrap();
+    remove(&path).unwrap();
+    assert_eq!(fs::read_dir(tmp.path()).unwrap().count(), 0);
+  }
+}
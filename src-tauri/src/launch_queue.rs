@@ -0,0 +1,403 @@
+//! Global queue in front of browser launches. At most
+//! `max_concurrent_launches` launches run their startup phase (proxy worker,
+//! fingerprint, process spawn) at once; the rest wait in FIFO order, with
+//! launches started from the GUI ahead of API, MCP and supervisor ones. A
+//! launch leaves the queue once its process is spawned or it fails, so a
+//! later limit change never touches a browser that is already starting.
+
+use serde::{Deserialize, Serialize};
+use std::sync::{Arc, Mutex};
+use tokio::sync::oneshot;
+
+/// Who asked for the launch; interactive launches are dispatched first.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum LaunchPriority {
+  /// Started by the user in the GUI or through a deep link.
+  Interactive,
+  /// Started through the API, MCP or the restart supervisor.
+  Background,
+}
+
+/// One waiting launch, as published in `launch-queue-changed`.
+#[derive(Debug, Serialize, Clone, PartialEq, Eq)]
+pub struct QueuedLaunch {
+  pub profile_id: String,
+  /// Launches that will be dispatched before this one.
+  pub ahead: usize,
+  pub priority: LaunchPriority,
+}
+
+struct Waiter {
+  ticket: u64,
+  profile_id: String,
+  priority: LaunchPriority,
+  wake: oneshot::Sender<LaunchPermit>,
+}
+
+struct QueueState {
+  /// 0 disables the cap.
+  limit: u32,
+  active: usize,
+  /// Dispatch order: by priority, then arrival.
+  waiting: Vec<Waiter>,
+  next_ticket: u64,
+}
+
+impl QueueState {
+  fn has_room(&self) -> bool {
+    self.limit == 0 || self.active < self.limit as usize
+  }
+
+  fn snapshot(&self) -> Vec<QueuedLaunch> {
+    self
+      .waiting
+      .iter()
+      .enumerate()
+      .map(|(ahead, w)| QueuedLaunch {
+        profile_id: w.profile_id.clone(),
+        ahead,
+        priority: w.priority,
+      })
+      .collect()
+  }
+}
+
+struct Inner {
+  state: Mutex<QueueState>,
+  emit_events: bool,
+}
+
+impl Inner {
+  /// Hand free slots to the head of the queue. Permits whose waiter has gone
+  /// away come back from `send` and are returned to the pool.
+  fn dispatch(self: &Arc<Self>, state: &mut QueueState) {
+    while state.has_room() && !state.waiting.is_empty() {
+      let waiter = state.waiting.remove(0);
+      state.active += 1;
+      let permit = LaunchPermit {
+        inner: Some(self.clone()),
+      };
+      if let Err(mut permit) = waiter.wake.send(permit) {
+        permit.inner = None;
+        state.active -= 1;
+      }
+    }
+  }
+
+  fn publish(&self, snapshot: Vec<QueuedLaunch>) {
+    if !self.emit_events {
+      return;
+    }
+    if let Err(e) = crate::events::emit(&crate::events::payloads::LaunchQueueChanged(snapshot)) {
+      log::warn!("Failed to emit launch-queue-changed event: {e}");
+    }
+  }
+
+  fn release(self: &Arc<Self>) {
+    let snapshot = {
+      let mut state = self.state.lock().unwrap();
+      state.active = state.active.saturating_sub(1);
+      self.dispatch(&mut state);
+      state.snapshot()
+    };
+    self.publish(snapshot);
+  }
+
+  fn remove_waiter(&self, ticket: u64) -> bool {
+    let snapshot = {
+      let mut state = self.state.lock().unwrap();
+      let before = state.waiting.len();
+      state.waiting.retain(|w| w.ticket != ticket);
+      if state.waiting.len() == before {
+        return false;
+      }
+      state.snapshot()
+    };
+    self.publish(snapshot);
+    true
+  }
+}
+
+/// A launch slot, returned to the queue on drop.
+pub struct LaunchPermit {
+  inner: Option<Arc<Inner>>,
+}
+
+impl Drop for LaunchPermit {
+  fn drop(&mut self) {
+    if let Some(inner) = self.inner.take() {
+      inner.release();
+    }
+  }
+}
+
+/// Takes a waiter back out of the queue if its launch request is dropped
+/// before a slot came up.
+struct WaitGuard<'a> {
+  inner: &'a Arc<Inner>,
+  ticket: u64,
+}
+
+impl Drop for WaitGuard<'_> {
+  fn drop(&mut self) {
+    self.inner.remove_waiter(self.ticket);
+  }
+}
+
+pub struct LaunchQueue {
+  inner: Arc<Inner>,
+}
+
+impl LaunchQueue {
+  /// `emit_events` publishes `launch-queue-changed` on every change.
+  pub fn new(limit: u32, emit_events: bool) -> Self {
+    Self {
+      inner: Arc::new(Inner {
+        state: Mutex::new(QueueState {
+          limit,
+          active: 0,
+          waiting: Vec::new(),
+          next_ticket: 0,
+        }),
+        emit_events,
+      }),
+    }
+  }
+
+  /// Wait for a launch slot. Fails with `LAUNCH_CANCELLED` when
+  /// `cancel_profile` removes the request first.
+  pub async fn acquire(
+    &self,
+    profile_id: &str,
+    priority: LaunchPriority,
+  ) -> Result<LaunchPermit, String> {
+    let (ticket, rx, snapshot) = {
+      let mut state = self.inner.state.lock().unwrap();
+      if state.waiting.is_empty() && state.has_room() {
+        state.active += 1;
+        return Ok(LaunchPermit {
+          inner: Some(self.inner.clone()),
+        });
+      }
+
+      let ticket = state.next_ticket;
+      state.next_ticket += 1;
+      let (wake, rx) = oneshot::channel();
+      let position = state
+        .waiting
+        .iter()
+        .position(|w| w.priority > priority)
+        .unwrap_or(state.waiting.len());
+      state.waiting.insert(
+        position,
+        Waiter {
+          ticket,
+          profile_id: profile_id.to_string(),
+          priority,
+          wake,
+        },
+      );
+      (ticket, rx, state.snapshot())
+    };
+    self.inner.publish(snapshot);
+    log::info!("Launch of profile {profile_id} queued");
+
+    let _guard = WaitGuard {
+      inner: &self.inner,
+      ticket,
+    };
+    rx.await
+      .map_err(|_| serde_json::json!({ "code": "LAUNCH_CANCELLED" }).to_string())
+  }
+
+  /// Drop the profile's queued launches; returns whether there were any.
+  /// Launches that already hold a slot are not affected.
+  pub fn cancel_profile(&self, profile_id: &str) -> bool {
+    let snapshot = {
+      let mut state = self.inner.state.lock().unwrap();
+      let before = state.waiting.len();
+      state.waiting.retain(|w| w.profile_id != profile_id);
+      if state.waiting.len() == before {
+        return false;
+      }
+      state.snapshot()
+    };
+    self.inner.publish(snapshot);
+    true
+  }
+
+  /// Change the cap. Raising it dispatches waiters right away; lowering it
+  /// only holds back launches that have not started yet.
+  pub fn set_limit(&self, limit: u32) {
+    let snapshot = {
+      let mut state = self.inner.state.lock().unwrap();
+      if state.limit == limit {
+        return;
+      }
+      state.limit = limit;
+      self.inner.dispatch(&mut state);
+      state.snapshot()
+    };
+    self.inner.publish(snapshot);
+  }
+
+  pub fn queued(&self) -> Vec<QueuedLaunch> {
+    self.inner.state.lock().unwrap().snapshot()
+  }
+}
+
+lazy_static::lazy_static! {
+  /// Starts at the default cap; setup and `save_app_settings` apply the
+  /// configured one.
+  pub static ref LAUNCH_QUEUE: LaunchQueue =
+    LaunchQueue::new(crate::settings_manager::DEFAULT_MAX_CONCURRENT_LAUNCHES, true);
+}
+
+/// Launches waiting for a slot, in dispatch order.
+#[tauri::command]
+pub fn get_launch_queue() -> Vec<QueuedLaunch> {
+  LAUNCH_QUEUE.queued()
+}
+
+/// Remove the profile's launch from the queue before it starts.
+#[tauri::command]
+pub fn cancel_queued_launch(profile_id: String) -> bool {
+  LAUNCH_QUEUE.cancel_profile(&profile_id)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::sync::atomic::{AtomicUsize, Ordering};
+  use std::time::Duration;
+
+  #[tokio::test]
+  async fn limit_of_two_drains_ten_queued_launches() {
+    let queue = Arc::new(LaunchQueue::new(2, false));
+    let running = Arc::new(AtomicUsize::new(0));
+    let peak = Arc::new(AtomicUsize::new(0));
+
+    let tasks: Vec<_> = (0..10)
+      .map(|i| {
+        let (queue, running, peak) = (queue.clone(), running.clone(), peak.clone());
+        tokio::spawn(async move {
+          let _permit = queue
+            .acquire(&format!("profile-{i}"), LaunchPriority::Background)
+            .await?;
+          let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+          peak.fetch_max(now, Ordering::SeqCst);
+          tokio::time::sleep(Duration::from_millis(10)).await;
+          running.fetch_sub(1, Ordering::SeqCst);
+          // Every third stub launch fails; its slot must still come back.
+          if i % 3 == 0 {
+            return Err(format!("launch {i} failed"));
+          }
+          Ok(())
+        })
+      })
+      .collect();
+
+    let mut failed = 0;
+    for task in tasks {
+      if tokio::time::timeout(Duration::from_secs(5), task)
+        .await
+        .expect("queue stalled")
+        .unwrap()
+        .is_err()
+      {
+        failed += 1;
+      }
+    }
+    assert_eq!(failed, 4);
+    assert_eq!(peak.load(Ordering::SeqCst), 2);
+    assert!(queue.queued().is_empty());
+    assert_eq!(queue.inner.state.lock().unwrap().active, 0);
+  }
+
+  #[tokio::test]
+  async fn interactive_launches_jump_background_ones() {
+    let queue = Arc::new(LaunchQueue::new(1, false));
+    let held = queue
+      .acquire("running", LaunchPriority::Background)
+      .await
+      .unwrap();
+
+    let order = Arc::new(Mutex::new(Vec::new()));
+    let mut tasks = Vec::new();
+    for (id, priority) in [
+      ("api-1", LaunchPriority::Background),
+      ("api-2", LaunchPriority::Background),
+      ("gui", LaunchPriority::Interactive),
+    ] {
+      let (queue, order) = (queue.clone(), order.clone());
+      tasks.push(tokio::spawn(async move {
+        let _permit = queue.acquire(id, priority).await.unwrap();
+        order.lock().unwrap().push(id);
+      }));
+      while queue.queued().iter().all(|q| q.profile_id != id) {
+        tokio::task::yield_now().await;
+      }
+    }
+
+    let positions: Vec<(String, usize)> = queue
+      .queued()
+      .into_iter()
+      .map(|q| (q.profile_id, q.ahead))
+      .collect();
+    assert_eq!(
+      positions,
+      [
+        ("gui".to_string(), 0),
+        ("api-1".to_string(), 1),
+        ("api-2".to_string(), 2)
+      ]
+    );
+
+    drop(held);
+    for task in tasks {
+      task.await.unwrap();
+    }
+    assert_eq!(*order.lock().unwrap(), ["gui", "api-1", "api-2"]);
+  }
+
+  #[tokio::test]
+  async fn cancel_and_limit_changes_only_touch_queued_launches() {
+    let queue = Arc::new(LaunchQueue::new(1, false));
+    let held = queue
+      .acquire("running", LaunchPriority::Interactive)
+      .await
+      .unwrap();
+
+    let waiting = {
+      let queue = queue.clone();
+      tokio::spawn(async move {
+        queue
+          .acquire("queued", LaunchPriority::Interactive)
+          .await
+          .map(|_| ())
+      })
+    };
+    while queue.queued().is_empty() {
+      tokio::task::yield_now().await;
+    }
+    assert!(queue.cancel_profile("queued"));
+    assert!(!queue.cancel_profile("queued"));
+    assert!(waiting
+      .await
+      .unwrap()
+      .unwrap_err()
+      .contains("LAUNCH_CANCELLED"));
+
+    // Lifting the cap lets the next launch through without a release;
+    // lowering it again leaves both started launches alone.
+    queue.set_limit(0);
+    let unlimited = queue.acquire("extra", LaunchPriority::Background).await;
+    assert!(unlimited.is_ok());
+    queue.set_limit(1);
+    assert_eq!(queue.inner.state.lock().unwrap().active, 2);
+    drop(unlimited);
+    drop(held);
+    assert_eq!(queue.inner.state.lock().unwrap().active, 0);
+  }
+}
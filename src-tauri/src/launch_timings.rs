@@ -0,0 +1,241 @@
+//! Per-phase timing of browser launches. Every Wayfern launch records how
+//! long its startup phases took; the last record per profile is kept for
+//! `get_last_launch_timings` and summarized in one log line, and the slowest
+//! phase over the configured threshold is announced as `launch-slow` so the
+//! GUI can point at the likely cause.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum LaunchPhase {
+  /// Resolving the proxy (or starting the VPN) and starting the local worker.
+  ProxyStart,
+  /// Looking up the exit IP and its location through the worker.
+  GeoipLookup,
+  /// Generating a fresh fingerprint for randomize-on-launch profiles.
+  Fingerprint,
+  /// Preparing the profile directory, extensions and browser arguments.
+  ConfigBuild,
+  /// Spawning the browser process.
+  ProcessSpawn,
+  /// Until DevTools answers and the fingerprint and start URLs are applied.
+  CdpReady,
+}
+
+#[derive(Debug, Serialize, Clone, PartialEq, Eq)]
+pub struct PhaseTiming {
+  pub phase: LaunchPhase,
+  pub duration_ms: u64,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct LaunchTimings {
+  pub profile_id: String,
+  /// Unix seconds.
+  pub started_at: u64,
+  pub total_ms: u64,
+  /// In the order the phases ran.
+  pub phases: Vec<PhaseTiming>,
+}
+
+/// Payload of `launch-slow`.
+#[derive(Debug, Serialize, Clone)]
+pub struct SlowLaunch {
+  pub profile_id: String,
+  pub profile_name: String,
+  pub phase: LaunchPhase,
+  pub duration_ms: u64,
+  pub threshold_ms: u64,
+}
+
+/// Times one launch. `mark` closes the phase that ran since the previous
+/// mark; a phase marked twice accumulates.
+pub struct LaunchTimer {
+  started: Instant,
+  started_at: u64,
+  last: Instant,
+  phases: Vec<PhaseTiming>,
+}
+
+impl LaunchTimer {
+  pub fn start() -> Self {
+    let now = Instant::now();
+    Self {
+      started: now,
+      started_at: SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0),
+      last: now,
+      phases: Vec::new(),
+    }
+  }
+
+  pub fn mark(&mut self, phase: LaunchPhase) {
+    let now = Instant::now();
+    self.add(phase, now - self.last);
+    self.last = now;
+  }
+
+  /// Take over phases timed inside a callee, which cover the time since the
+  /// previous mark.
+  pub fn extend(&mut self, phases: &[PhaseTiming]) {
+    for timing in phases {
+      self.add(timing.phase, Duration::from_millis(timing.duration_ms));
+    }
+    self.last = Instant::now();
+  }
+
+  fn add(&mut self, phase: LaunchPhase, duration: Duration) {
+    let ms = duration.as_millis() as u64;
+    match self.phases.iter_mut().find(|t| t.phase == phase) {
+      Some(timing) => timing.duration_ms += ms,
+      None => self.phases.push(PhaseTiming {
+        phase,
+        duration_ms: ms,
+      }),
+    }
+  }
+
+  fn into_timings(self, profile_id: &str) -> LaunchTimings {
+    LaunchTimings {
+      profile_id: profile_id.to_string(),
+      started_at: self.started_at,
+      total_ms: self.started.elapsed().as_millis() as u64,
+      phases: self.phases,
+    }
+  }
+
+  /// Store, log and check the finished launch.
+  pub fn finish(self, profile: &crate::profile::BrowserProfile) {
+    let profile_id = profile.id.to_string();
+    let timings = self.into_timings(&profile_id);
+
+    let summary: Vec<String> = timings
+      .phases
+      .iter()
+      .map(|t| format!("{:?}={}ms", t.phase, t.duration_ms))
+      .collect();
+    log::info!(
+      "Launch timings for profile {profile_id}: total={}ms {}",
+      timings.total_ms,
+      summary.join(" ")
+    );
+
+    let threshold_secs = crate::settings_manager::SettingsManager::instance()
+      .load_settings()
+      .map(|s| s.slow_launch_threshold_secs)
+      .unwrap_or(crate::settings_manager::DEFAULT_SLOW_LAUNCH_THRESHOLD_SECS);
+    if let Some(slowest) = slowest_over(&timings.phases, u64::from(threshold_secs) * 1000) {
+      log::warn!(
+        "Launch of profile {profile_id} was slow in {:?} ({}ms)",
+        slowest.phase,
+        slowest.duration_ms
+      );
+      let payload = SlowLaunch {
+        profile_id: profile_id.clone(),
+        profile_name: profile.name.clone(),
+        phase: slowest.phase,
+        duration_ms: slowest.duration_ms,
+        threshold_ms: u64::from(threshold_secs) * 1000,
+      };
+      if let Err(e) = crate::events::emit(&payload) {
+        log::warn!("Failed to emit launch-slow event: {e}");
+      }
+    }
+
+    LAST_LAUNCH_TIMINGS
+      .lock()
+      .unwrap()
+      .insert(profile_id, timings);
+  }
+}
+
+/// The slowest phase that took longer than `threshold_ms`; 0 disables.
+fn slowest_over(phases: &[PhaseTiming], threshold_ms: u64) -> Option<&PhaseTiming> {
+  if threshold_ms == 0 {
+    return None;
+  }
+  phases
+    .iter()
+    .filter(|t| t.duration_ms > threshold_ms)
+    .max_by_key(|t| t.duration_ms)
+}
+
+lazy_static::lazy_static! {
+  static ref LAST_LAUNCH_TIMINGS: Mutex<HashMap<String, LaunchTimings>> =
+    Mutex::new(HashMap::new());
+}
+
+/// Timings of the profile's most recent successful launch in this session.
+#[tauri::command]
+pub fn get_last_launch_timings(profile_id: String) -> Option<LaunchTimings> {
+  LAST_LAUNCH_TIMINGS
+    .lock()
+    .unwrap()
+    .get(&profile_id)
+    .cloned()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn repeated_and_callee_phases_accumulate_in_order() {
+    let mut timer = LaunchTimer::start();
+    timer.mark(LaunchPhase::ProxyStart);
+    timer.mark(LaunchPhase::ConfigBuild);
+    timer.extend(&[
+      PhaseTiming {
+        phase: LaunchPhase::ConfigBuild,
+        duration_ms: 40,
+      },
+      PhaseTiming {
+        phase: LaunchPhase::ProcessSpawn,
+        duration_ms: 5,
+      },
+    ]);
+    let timings = timer.into_timings("p1");
+
+    let phases: Vec<LaunchPhase> = timings.phases.iter().map(|t| t.phase).collect();
+    assert_eq!(
+      phases,
+      [
+        LaunchPhase::ProxyStart,
+        LaunchPhase::ConfigBuild,
+        LaunchPhase::ProcessSpawn
+      ]
+    );
+    assert!(timings.phases[1].duration_ms >= 40);
+    assert_eq!(timings.phases[2].duration_ms, 5);
+  }
+
+  #[test]
+  fn only_the_slowest_phase_over_the_threshold_is_reported() {
+    let phases = [
+      PhaseTiming {
+        phase: LaunchPhase::ProxyStart,
+        duration_ms: 12_000,
+      },
+      PhaseTiming {
+        phase: LaunchPhase::GeoipLookup,
+        duration_ms: 25_000,
+      },
+      PhaseTiming {
+        phase: LaunchPhase::CdpReady,
+        duration_ms: 2_000,
+      },
+    ];
+    assert_eq!(
+      slowest_over(&phases, 10_000).map(|t| t.phase),
+      Some(LaunchPhase::GeoipLookup)
+    );
+    assert_eq!(slowest_over(&phases, 30_000), None);
+    assert_eq!(slowest_over(&phases, 0), None);
+  }
+}
@@ -1,15 +1,11 @@
 // Learn more about Tauri commands at https://tauri.app/develop/calling-rust/
 use std::env;
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::Mutex;
-use tauri::{Emitter, Manager, Runtime, WebviewUrl, WebviewWindow, WebviewWindowBuilder};
+use tauri::{Manager, Runtime, WebviewUrl, WebviewWindow, WebviewWindowBuilder};
 #[cfg(not(feature = "e2e"))]
 use tauri_plugin_deep_link::DeepLinkExt;
 use tauri_plugin_log::{Target, TargetKind};
 
-// Store pending URLs that need to be handled when the window is ready
-static PENDING_URLS: Mutex<Vec<String>> = Mutex::new(Vec::new());
-
 // Set to true once the user has confirmed they want to quit, so the close
 // interceptor lets the next CloseRequested through instead of looping back
 // to the confirmation dialog.
@@ -35,65 +31,105 @@ fn e2e_automation_profile_dir() -> Option<std::path::PathBuf> {
 }
 
 mod api_client;
+mod api_listener;
 mod api_server;
+mod api_tokens;
 mod app_auto_updater;
 pub mod app_dirs;
+pub mod app_handle;
+mod audit;
 mod auto_updater;
+mod binaries_usage;
 mod browser;
 mod browser_runner;
 mod browser_version_manager;
+mod client_hints;
+mod deep_link;
 mod default_browser;
 pub mod dns_blocklist;
 mod downloaded_browsers_registry;
 mod downloader;
+mod entity_usage;
 mod ephemeral_dirs;
+mod exit_check;
 mod extension_manager;
+mod extension_updater;
+mod external_profile_import;
 mod extraction;
 mod fingerprint_consistency;
+mod fingerprint_quality;
+mod fonts;
 mod geoip_downloader;
 mod geolocation;
 mod group_manager;
 mod human_typing;
+mod integrity;
 mod ip_utils;
+mod json_file;
+mod launch_queue;
+mod launch_timings;
+mod log_buffer;
 mod log_redaction;
+mod observer_mode;
+mod offline;
+mod orphan_cleanup;
+mod pending_urls;
 mod platform_browser;
+mod preflight;
+mod process_registry;
+mod process_tree;
 mod profile;
 mod profile_importer;
+mod profile_shortcuts;
+pub mod proxy_client_cert;
 mod proxy_manager;
 pub mod proxy_runner;
 pub mod proxy_server;
+mod proxy_sources;
 pub mod proxy_storage;
+mod resource_limits;
 mod settings_manager;
 pub mod socks5_local;
 pub mod sync;
 mod synchronizer;
 pub mod traffic_stats;
+mod warm_pool;
 mod wayfern_manager;
 mod wayfern_terms;
+mod webgl;
 // mod theme_detector; // removed: theme detection handled in webview via CSS prefers-color-scheme
 pub mod cloud_auth;
 mod commercial_license;
 mod cookie_manager;
 pub mod events;
 mod mcp_integrations;
+mod mcp_schema;
 mod mcp_server;
 mod tag_manager;
 mod team_lock;
+#[cfg(feature = "test-browser")]
+pub mod test_browser;
 mod version_updater;
 pub mod vpn;
 pub mod vpn_worker_runner;
 pub mod vpn_worker_storage;
+mod workspace;
+mod workspaces;
 
 use browser_runner::{
-  check_browser_exists, kill_browser_profile, launch_browser_profile, open_url_with_profile,
+  check_browser_exists, get_profile_cdp_endpoint, kill_browser_profile, launch_browser_profile,
+  open_url_with_profile,
 };
 
 use profile::manager::{
   check_browser_status, clone_profile, create_browser_profile_new, delete_profile,
-  list_browser_profiles, rename_profile, update_profile_clear_on_close,
-  update_profile_dns_blocklist, update_profile_launch_hook, update_profile_note,
-  update_profile_proxy, update_profile_proxy_bypass_rules, update_profile_tags, update_profile_vpn,
-  update_profile_window_color, update_wayfern_config,
+  list_browser_profiles, merge_tags, pin_profile_version, rename_profile, rename_tag,
+  unpin_profile_version, update_profile_clear_on_close, update_profile_dns_blocklist,
+  update_profile_dns_mode, update_profile_exit_mismatch_action, update_profile_launch_hook,
+  update_profile_metadata, update_profile_note, update_profile_preferences, update_profile_proxy,
+  update_profile_proxy_bypass_rules, update_profile_proxy_pool, update_profile_resource_limits,
+  update_profile_restart_policy, update_profile_startup, update_profile_tags, update_profile_vpn,
+  update_profile_webrtc_mode, update_profile_window_color, update_wayfern_config,
 };
 
 use profile::password::{
@@ -108,26 +144,28 @@ use browser_version_manager::{
 };
 
 use downloaded_browsers_registry::{
-  check_missing_binaries, ensure_active_browsers_downloaded, ensure_all_binaries_exist,
-  get_downloaded_browser_versions,
+  cancel_ensure_binaries, check_missing_binaries, ensure_active_browsers_downloaded,
+  ensure_all_binaries_exist, get_downloaded_browser_versions, verify_downloaded_browsers,
 };
 
 use downloader::{cancel_download, download_browser};
 
 use settings_manager::{
-  complete_onboarding, dismiss_window_resize_warning, get_app_settings, get_onboarding_completed,
-  get_sync_settings, get_system_info, get_system_language, get_table_sorting_settings,
-  get_window_resize_warning_dismissed, open_log_directory, read_log_files, save_app_settings,
-  save_sync_settings, save_table_sorting_settings,
+  complete_onboarding, create_saved_view, delete_saved_view, dismiss_window_resize_warning,
+  get_app_settings, get_onboarding_completed, get_sync_settings, get_system_info,
+  get_system_language, get_table_sorting_settings, get_window_resize_warning_dismissed,
+  list_saved_views, open_log_directory, read_log_files, save_app_settings, save_sync_settings,
+  save_table_sorting_settings, update_saved_view,
 };
 
 use sync::{
-  cancel_profile_sync, check_has_e2e_password, delete_e2e_password, enable_sync_for_all_entities,
-  get_unsynced_entity_counts, is_group_in_use_by_synced_profile, is_proxy_in_use_by_synced_profile,
-  is_vpn_in_use_by_synced_profile, request_profile_sync, rollover_encryption_for_all_entities,
-  set_e2e_password, set_extension_group_sync_enabled, set_extension_sync_enabled,
-  set_group_sync_enabled, set_profile_sync_mode, set_proxy_sync_enabled, set_vpn_sync_enabled,
-  verify_e2e_password,
+  cancel_profile_sync, change_e2e_password, check_e2e_rotation_pending, check_has_e2e_password,
+  delete_e2e_password, enable_sync_for_all_entities, get_sync_queue_status,
+  get_sync_subscription_status, get_unsynced_entity_counts, is_group_in_use_by_synced_profile,
+  is_proxy_in_use_by_synced_profile, is_vpn_in_use_by_synced_profile, preview_profile_sync,
+  request_profile_sync, rollover_encryption_for_all_entities, set_e2e_password,
+  set_extension_group_sync_enabled, set_extension_sync_enabled, set_group_sync_enabled,
+  set_profile_sync_mode, set_proxy_sync_enabled, set_vpn_sync_enabled, verify_e2e_password,
 };
 
 use tag_manager::get_all_tags;
@@ -141,6 +179,7 @@ use version_updater::{
 
 use auto_updater::{
   check_for_browser_updates, complete_browser_update_with_auto_update, dismiss_update_notification,
+  rollback_browser_update,
 };
 
 use app_auto_updater::{
@@ -148,17 +187,20 @@ use app_auto_updater::{
   restart_application,
 };
 
+use external_profile_import::import_external_profiles;
+
 use profile_importer::{
   cleanup_profile_import_scratch, detect_existing_profiles, import_browser_profiles,
   scan_folder_for_profiles, scan_profile_archive,
 };
 
 use extension_manager::{
-  add_extension, add_extension_to_group, assign_extension_group_to_profile, create_extension_group,
-  delete_extension, delete_extension_group, get_extension_group_for_profile, get_extension_icon,
-  list_extension_groups, list_extensions, remove_extension_from_group, update_extension,
-  update_extension_group,
+  add_extension, add_extension_to_group, add_unpacked_extension, assign_extension_group_to_profile,
+  create_extension_group, delete_extension, delete_extension_group,
+  get_extension_group_for_profile, get_extension_icon, list_extension_groups, list_extensions,
+  remove_extension_from_group, set_extension_source, update_extension, update_extension_group,
 };
+use extension_updater::check_extension_updates;
 
 use group_manager::{
   assign_profiles_to_group, create_profile_group, delete_profile_group, delete_selected_profiles,
@@ -169,11 +211,12 @@ use geoip_downloader::{check_missing_geoip_database, GeoIPDownloader};
 
 use browser_version_manager::get_browser_release_types;
 
-use api_server::{get_api_server_status, start_api_server, stop_api_server};
+use api_server::{get_api_endpoint, get_api_server_status, start_api_server, stop_api_server};
+use api_tokens::{generate_api_token, list_api_tokens, revoke_api_token};
 
 // Trait to extend WebviewWindow with transparent titlebar functionality
 pub trait WindowExt {
-  #[cfg(target_os = "macos")]
+  #[cfg(any(target_os = "macos", target_os = "windows"))]
   fn set_transparent_titlebar(&self, transparent: bool) -> Result<(), String>;
   #[cfg(target_os = "macos")]
   fn disable_native_fullscreen(&self) -> Result<(), String>;
@@ -243,32 +286,153 @@ impl<R: Runtime> WindowExt for WebviewWindow<R> {
 
     Ok(())
   }
-}
 
-// Called internally for deep-link / startup URL handling — not invoked from the
-// frontend, so it is intentionally not a `#[tauri::command]`.
-async fn handle_url_open(app: tauri::AppHandle, url: String) -> Result<(), String> {
-  log::info!("Handling URL open request");
+  #[cfg(target_os = "windows")]
+  fn set_transparent_titlebar(&self, transparent: bool) -> Result<(), String> {
+    use windows::Win32::Foundation::HWND;
+    use windows::Win32::Graphics::Dwm::{
+      DwmExtendFrameIntoClientArea, DwmSetWindowAttribute, DWMSBT_MAINWINDOW,
+      DWMWA_SYSTEMBACKDROP_TYPE,
+    };
+    use windows::Win32::UI::Controls::MARGINS;
+    use windows::Win32::UI::Shell::{RemoveWindowSubclass, SetWindowSubclass};
+    use windows::Win32::UI::WindowsAndMessaging::{
+      SetWindowPos, SWP_FRAMECHANGED, SWP_NOMOVE, SWP_NOSIZE, SWP_NOZORDER,
+    };
+
+    // tauri hands out its own `windows` crate's HWND; rewrap the raw handle.
+    let hwnd = HWND(self.hwnd().map_err(|e| e.to_string())?.0);
 
-  // Check if the main window exists and is ready
-  if let Some(window) = app.get_webview_window("main") {
-    log::debug!("Main window exists");
+    unsafe {
+      if transparent {
+        if !SetWindowSubclass(
+          hwnd,
+          Some(win_chrome::subclass_proc),
+          win_chrome::SUBCLASS_ID,
+          0,
+        )
+        .as_bool()
+        {
+          return Err("Failed to subclass main window".to_string());
+        }
 
-    // Try to show and focus the window first
-    let _ = window.show();
-    let _ = window.set_focus();
-    let _ = window.unminimize();
+        // A one-pixel top margin keeps DWM drawing the native shadow and
+        // snap animations even though the caption is gone from the client
+        // area.
+        let margins = MARGINS {
+          cxLeftWidth: 0,
+          cxRightWidth: 0,
+          cyTopHeight: 1,
+          cyBottomHeight: 0,
+        };
+        DwmExtendFrameIntoClientArea(hwnd, &margins)
+          .map_err(|e| format!("DwmExtendFrameIntoClientArea failed: {e}"))?;
+
+        // Mica only exists on Windows 11 22H2+. Older builds reject the
+        // attribute, which just leaves the regular opaque frame in place.
+        let backdrop = DWMSBT_MAINWINDOW;
+        if let Err(e) = DwmSetWindowAttribute(
+          hwnd,
+          DWMWA_SYSTEMBACKDROP_TYPE,
+          &backdrop as *const _ as *const std::ffi::c_void,
+          std::mem::size_of_val(&backdrop) as u32,
+        ) {
+          log::debug!("System backdrop unavailable, keeping default frame: {e}");
+        }
+      } else {
+        let _ = RemoveWindowSubclass(
+          hwnd,
+          Some(win_chrome::subclass_proc),
+          win_chrome::SUBCLASS_ID,
+        );
+        let margins = MARGINS::default();
+        DwmExtendFrameIntoClientArea(hwnd, &margins)
+          .map_err(|e| format!("DwmExtendFrameIntoClientArea failed: {e}"))?;
+      }
 
-    events::emit("show-profile-selector", url.clone())
-      .map_err(|e| format!("Failed to emit URL open event: {e}"))?;
-  } else {
-    // Window doesn't exist yet - add to pending URLs
-    log::debug!("Main window doesn't exist, adding URL to pending list");
-    let mut pending = PENDING_URLS.lock().unwrap();
-    pending.push(url);
+      // Make Windows re-run WM_NCCALCSIZE so the new frame takes effect now
+      // rather than on the next resize.
+      SetWindowPos(
+        hwnd,
+        None,
+        0,
+        0,
+        0,
+        0,
+        SWP_FRAMECHANGED | SWP_NOMOVE | SWP_NOSIZE | SWP_NOZORDER,
+      )
+      .map_err(|e| format!("SetWindowPos failed: {e}"))?;
+    }
+
+    Ok(())
+  }
+}
+
+/// Window procedure pieces for the Windows custom chrome: the caption is
+/// folded into the client area (so the webview header sits at the very top)
+/// while the resize borders and shadow stay native.
+#[cfg(target_os = "windows")]
+mod win_chrome {
+  use windows::Win32::Foundation::{HWND, LPARAM, LRESULT, RECT, WPARAM};
+  use windows::Win32::UI::HiDpi::{GetDpiForWindow, GetSystemMetricsForDpi};
+  use windows::Win32::UI::Shell::DefSubclassProc;
+  use windows::Win32::UI::WindowsAndMessaging::{
+    GetWindowRect, IsZoomed, HTCLIENT, HTTOP, NCCALCSIZE_PARAMS, SM_CXPADDEDBORDER, SM_CYFRAME,
+    WM_NCCALCSIZE, WM_NCHITTEST,
+  };
+
+  pub const SUBCLASS_ID: usize = 0x646f_6e75; // "donu"
+
+  /// Height of the sizing border at the current DPI.
+  unsafe fn resize_border(hwnd: HWND) -> i32 {
+    let dpi = GetDpiForWindow(hwnd);
+    GetSystemMetricsForDpi(SM_CYFRAME, dpi) + GetSystemMetricsForDpi(SM_CXPADDEDBORDER, dpi)
   }
 
-  Ok(())
+  pub unsafe extern "system" fn subclass_proc(
+    hwnd: HWND,
+    msg: u32,
+    wparam: WPARAM,
+    lparam: LPARAM,
+    _id: usize,
+    _data: usize,
+  ) -> LRESULT {
+    match msg {
+      WM_NCCALCSIZE if wparam.0 != 0 => {
+        // Let Windows compute the normal frame, then hand the caption strip
+        // back to the client area by restoring the original top edge.
+        let params = &mut *(lparam.0 as *mut NCCALCSIZE_PARAMS);
+        let original_top = params.rgrc[0].top;
+        let result = DefSubclassProc(hwnd, msg, wparam, lparam);
+        if result.0 != 0 {
+          return result;
+        }
+        params.rgrc[0].top = original_top;
+        // A maximized window is positioned so its frame hangs off-screen;
+        // without this inset the top of the webview would be clipped.
+        if IsZoomed(hwnd).as_bool() {
+          params.rgrc[0].top += resize_border(hwnd);
+        }
+        LRESULT(0)
+      }
+      WM_NCHITTEST => {
+        let hit = DefSubclassProc(hwnd, msg, wparam, lparam);
+        if hit.0 != HTCLIENT as isize || IsZoomed(hwnd).as_bool() {
+          return hit;
+        }
+        // The top sizing border now lies inside the client area, so the
+        // default hit test reports it as client; restore the resize edge.
+        // Everything else stays client so the webview's drag region works.
+        let y = ((lparam.0 as u32 >> 16) as u16 as i16) as i32;
+        let mut rect = RECT::default();
+        if GetWindowRect(hwnd, &mut rect).is_ok() && y < rect.top + resize_border(hwnd) {
+          return LRESULT(HTTOP as isize);
+        }
+        hit
+      }
+      _ => DefSubclassProc(hwnd, msg, wparam, lparam),
+    }
+  }
 }
 
 /// Prefix a command error with context, but pass structured `{"code": ...}`
@@ -285,17 +449,42 @@ pub(crate) fn wrap_backend_error(e: impl std::fmt::Display, context: &str) -> St
 
 #[tauri::command]
 async fn create_stored_proxy(
-  app_handle: tauri::AppHandle,
+  app_handle: crate::app_handle::AppHandle,
   name: String,
   proxy_settings: Option<crate::browser::ProxySettings>,
+  chain: Option<Vec<crate::browser::ProxySettings>>,
 ) -> Result<crate::proxy_manager::StoredProxy, String> {
-  if let Some(settings) = proxy_settings {
-    crate::proxy_manager::PROXY_MANAGER
-      .create_stored_proxy(&app_handle, name, settings)
-      .map_err(|e| wrap_backend_error(e, "Failed to create stored proxy"))
-  } else {
-    Err("proxy_settings is required".to_string())
-  }
+  audit::audited!(
+    audit::AuditActor::Gui,
+    "create",
+    "proxy",
+    None,
+    async {
+      // A chain is validated before anything is stored; its exit hop doubles as
+      // the proxy's settings.
+      if let Some(ref hops) = chain {
+        crate::proxy_manager::validate_proxy_chain(hops)?;
+      }
+      let Some(settings) = chain
+        .as_ref()
+        .and_then(|hops| hops.last().cloned())
+        .or(proxy_settings)
+      else {
+        return Err("proxy_settings is required".to_string());
+      };
+
+      let proxy = crate::proxy_manager::PROXY_MANAGER
+        .create_stored_proxy(&app_handle, name, settings)
+        .map_err(|e| wrap_backend_error(e, "Failed to create stored proxy"))?;
+      match chain {
+        Some(hops) => crate::proxy_manager::PROXY_MANAGER
+          .set_stored_proxy_chain(&proxy.id, Some(hops))
+          .map_err(|e| wrap_backend_error(e, "Failed to create stored proxy")),
+        None => Ok(proxy),
+      }
+    }
+    .await
+  )
 }
 
 #[tauri::command]
@@ -305,21 +494,103 @@ async fn get_stored_proxies() -> Result<Vec<crate::proxy_manager::StoredProxy>,
 
 #[tauri::command]
 async fn update_stored_proxy(
-  app_handle: tauri::AppHandle,
+  app_handle: crate::app_handle::AppHandle,
   proxy_id: String,
   name: Option<String>,
   proxy_settings: Option<crate::browser::ProxySettings>,
+  chain: Option<Vec<crate::browser::ProxySettings>>,
+) -> Result<crate::proxy_manager::StoredProxy, String> {
+  audit::audited!(
+    audit::AuditActor::Gui,
+    "update",
+    "proxy",
+    Some(proxy_id.clone()),
+    async {
+      // `chain: None` leaves any chain as is; an empty list removes it.
+      let chain = chain.map(|hops| (!hops.is_empty()).then_some(hops));
+      if let Some(Some(ref hops)) = chain {
+        crate::proxy_manager::validate_proxy_chain(hops)?;
+      }
+
+      let proxy = crate::proxy_manager::PROXY_MANAGER
+        .update_stored_proxy(&app_handle, &proxy_id, name, proxy_settings)
+        .map_err(|e| wrap_backend_error(e, "Failed to update stored proxy"))?;
+      match chain {
+        Some(hops) => crate::proxy_manager::PROXY_MANAGER
+          .set_stored_proxy_chain(&proxy_id, hops)
+          .map_err(|e| wrap_backend_error(e, "Failed to update stored proxy")),
+        None => Ok(proxy),
+      }
+    }
+    .await
+  )
+}
+
+/// Set the country and/or IP prefix the proxy should exit in; blank values
+/// clear them.
+#[tauri::command]
+async fn update_stored_proxy_exit_expectation(
+  proxy_id: String,
+  expected_country: Option<String>,
+  expected_ip_prefix: Option<String>,
 ) -> Result<crate::proxy_manager::StoredProxy, String> {
   crate::proxy_manager::PROXY_MANAGER
-    .update_stored_proxy(&app_handle, &proxy_id, name, proxy_settings)
+    .set_stored_proxy_exit_expectation(&proxy_id, expected_country, expected_ip_prefix)
     .map_err(|e| wrap_backend_error(e, "Failed to update stored proxy"))
 }
 
 #[tauri::command]
-async fn delete_stored_proxy(app_handle: tauri::AppHandle, proxy_id: String) -> Result<(), String> {
+async fn delete_stored_proxy(
+  app_handle: crate::app_handle::AppHandle,
+  proxy_id: String,
+  force: Option<bool>,
+) -> Result<(), String> {
+  audit::audited!(
+    audit::AuditActor::Gui,
+    "delete",
+    "proxy",
+    Some(proxy_id.clone()),
+    crate::entity_usage::delete_stored_proxy(&app_handle, &proxy_id, force.unwrap_or(false))
+      .map_err(|e| wrap_backend_error(e, "Failed to delete stored proxy"))
+  )
+}
+
+#[tauri::command]
+fn get_proxy_pools() -> Vec<crate::proxy_manager::ProxyPool> {
+  crate::proxy_manager::PROXY_MANAGER.get_proxy_pools()
+}
+
+#[tauri::command]
+fn create_proxy_pool(
+  name: String,
+  proxy_ids: Vec<String>,
+  policy: Option<crate::proxy_manager::PoolSelectionPolicy>,
+) -> Result<crate::proxy_manager::ProxyPool, String> {
+  crate::proxy_manager::PROXY_MANAGER
+    .create_proxy_pool(name, proxy_ids, policy.unwrap_or_default())
+    .map_err(|e| wrap_backend_error(e, "Failed to create proxy pool"))
+}
+
+#[tauri::command]
+fn update_proxy_pool(
+  pool_id: String,
+  name: Option<String>,
+  proxy_ids: Option<Vec<String>>,
+  policy: Option<crate::proxy_manager::PoolSelectionPolicy>,
+) -> Result<crate::proxy_manager::ProxyPool, String> {
   crate::proxy_manager::PROXY_MANAGER
-    .delete_stored_proxy(&app_handle, &proxy_id)
-    .map_err(|e| format!("Failed to delete stored proxy: {e}"))
+    .update_proxy_pool(&pool_id, name, proxy_ids, policy)
+    .map_err(|e| wrap_backend_error(e, "Failed to update proxy pool"))
+}
+
+#[tauri::command]
+fn delete_proxy_pool(
+  app_handle: crate::app_handle::AppHandle,
+  pool_id: String,
+  force: Option<bool>,
+) -> Result<(), String> {
+  crate::entity_usage::delete_proxy_pool(&app_handle, &pool_id, force.unwrap_or(false))
+    .map_err(|e| wrap_backend_error(e, "Failed to delete proxy pool"))
 }
 
 #[tauri::command]
@@ -355,7 +626,7 @@ fn export_proxies(format: String) -> Result<String, String> {
 
 #[tauri::command]
 async fn import_proxies_json(
-  app_handle: tauri::AppHandle,
+  app_handle: crate::app_handle::AppHandle,
   content: String,
 ) -> Result<crate::proxy_manager::ProxyImportResult, String> {
   crate::proxy_manager::PROXY_MANAGER
@@ -370,7 +641,7 @@ fn parse_txt_proxies(content: String) -> Vec<crate::proxy_manager::ProxyParseRes
 
 #[tauri::command]
 async fn import_proxies_from_parsed(
-  app_handle: tauri::AppHandle,
+  app_handle: crate::app_handle::AppHandle,
   parsed_proxies: Vec<crate::proxy_manager::ParsedProxyLine>,
   name_prefix: Option<String>,
 ) -> Result<crate::proxy_manager::ProxyImportResult, String> {
@@ -399,7 +670,7 @@ async fn get_profile_cookie_stats(
 
 #[tauri::command]
 async fn copy_profile_cookies(
-  app_handle: tauri::AppHandle,
+  app_handle: crate::app_handle::AppHandle,
   request: cookie_manager::CookieCopyRequest,
 ) -> Result<Vec<cookie_manager::CookieCopyResult>, String> {
   let target_ids = request.target_profile_ids.clone();
@@ -433,7 +704,7 @@ async fn copy_profile_cookies(
 
 #[tauri::command]
 async fn import_cookies_from_file(
-  app_handle: tauri::AppHandle,
+  app_handle: crate::app_handle::AppHandle,
   profile_id: String,
   content: String,
 ) -> Result<cookie_manager::CookieImportResult, String> {
@@ -482,7 +753,7 @@ async fn accept_wayfern_terms() -> Result<(), String> {
 
 #[tauri::command]
 async fn get_commercial_trial_status(
-  app_handle: tauri::AppHandle,
+  app_handle: crate::app_handle::AppHandle,
 ) -> Result<commercial_license::TrialStatus, String> {
   commercial_license::CommercialLicenseManager::instance()
     .get_trial_status(&app_handle)
@@ -490,19 +761,23 @@ async fn get_commercial_trial_status(
 }
 
 #[tauri::command]
-async fn acknowledge_trial_expiration(app_handle: tauri::AppHandle) -> Result<(), String> {
+async fn acknowledge_trial_expiration(
+  app_handle: crate::app_handle::AppHandle,
+) -> Result<(), String> {
   commercial_license::CommercialLicenseManager::instance()
     .acknowledge_expiration(&app_handle)
     .await
 }
 
 #[tauri::command]
-fn has_acknowledged_trial_expiration(app_handle: tauri::AppHandle) -> Result<bool, String> {
+fn has_acknowledged_trial_expiration(
+  app_handle: crate::app_handle::AppHandle,
+) -> Result<bool, String> {
   commercial_license::CommercialLicenseManager::instance().has_acknowledged(&app_handle)
 }
 
 #[tauri::command]
-async fn start_mcp_server(app_handle: tauri::AppHandle) -> Result<u16, String> {
+async fn start_mcp_server(app_handle: crate::app_handle::AppHandle) -> Result<u16, String> {
   mcp_server::McpServer::instance().start(app_handle).await
 }
 
@@ -523,7 +798,9 @@ struct McpConfig {
 }
 
 #[tauri::command]
-async fn get_mcp_config(app_handle: tauri::AppHandle) -> Result<Option<McpConfig>, String> {
+async fn get_mcp_config(
+  app_handle: crate::app_handle::AppHandle,
+) -> Result<Option<McpConfig>, String> {
   let mcp_server = mcp_server::McpServer::instance();
   if !mcp_server.is_running() {
     return Ok(None);
@@ -580,7 +857,9 @@ fn is_mcp_in_claude_desktop_internal() -> bool {
   dir.join("manifest.json").exists()
 }
 
-async fn add_mcp_to_claude_desktop_internal(app_handle: &tauri::AppHandle) -> Result<(), String> {
+async fn add_mcp_to_claude_desktop_internal(
+  app_handle: &crate::app_handle::AppHandle,
+) -> Result<(), String> {
   let mcp_server = mcp_server::McpServer::instance();
   let port = mcp_server.get_port().ok_or("MCP server is not running")?;
 
@@ -737,7 +1016,7 @@ fn update_claude_extensions_registry(
   Ok(())
 }
 
-async fn current_mcp_url(app_handle: &tauri::AppHandle) -> Result<String, String> {
+async fn current_mcp_url(app_handle: &crate::app_handle::AppHandle) -> Result<String, String> {
   let mcp_server = mcp_server::McpServer::instance();
   let port = mcp_server.get_port().ok_or("MCP server is not running")?;
   let settings_manager = settings_manager::SettingsManager::instance();
@@ -759,7 +1038,10 @@ async fn list_mcp_agents() -> Result<Vec<mcp_integrations::McpAgentInfo>, String
 }
 
 #[tauri::command]
-async fn add_mcp_to_agent(app_handle: tauri::AppHandle, agent_id: String) -> Result<(), String> {
+async fn add_mcp_to_agent(
+  app_handle: crate::app_handle::AppHandle,
+  agent_id: String,
+) -> Result<(), String> {
   if !mcp_integrations::agent_exists(&agent_id) {
     return Err(format!("Unknown agent: {agent_id}"));
   }
@@ -826,7 +1108,7 @@ async fn get_traffic_stats_for_period(
 }
 
 #[tauri::command]
-async fn download_geoip_database(app_handle: tauri::AppHandle) -> Result<(), String> {
+async fn download_geoip_database(app_handle: crate::app_handle::AppHandle) -> Result<(), String> {
   let downloader = GeoIPDownloader::instance();
   downloader
     .download_geoip_database(&app_handle)
@@ -896,7 +1178,10 @@ async fn get_vpn_config(vpn_id: String) -> Result<vpn::VpnConfig, String> {
 }
 
 #[tauri::command]
-async fn delete_vpn_config(app_handle: tauri::AppHandle, vpn_id: String) -> Result<(), String> {
+async fn delete_vpn_config(
+  app_handle: crate::app_handle::AppHandle,
+  vpn_id: String,
+) -> Result<(), String> {
   // First disconnect if connected (stop VPN worker)
   let _ = vpn_worker_runner::stop_vpn_worker_by_vpn_id(&vpn_id).await;
 
@@ -942,7 +1227,7 @@ async fn delete_vpn_config(app_handle: tauri::AppHandle, vpn_id: String) -> Resu
     });
   }
 
-  let _ = events::emit("vpn-configs-changed", ());
+  let _ = events::emit_empty("vpn-configs-changed");
 
   Ok(())
 }
@@ -999,6 +1284,66 @@ async fn update_vpn_config(vpn_id: String, name: String) -> Result<vpn::VpnConfi
   Ok(config)
 }
 
+#[tauri::command]
+async fn update_vpn_auto_disconnect(
+  vpn_id: String,
+  auto_disconnect: bool,
+) -> Result<vpn::VpnConfig, String> {
+  let config = {
+    let storage = vpn::VPN_STORAGE
+      .lock()
+      .map_err(|e| format!("Failed to lock VPN storage: {e}"))?;
+
+    storage
+      .update_auto_disconnect(&vpn_id, auto_disconnect)
+      .map_err(|e| format!("Failed to update VPN config: {e}"))?
+  };
+
+  if config.sync_enabled {
+    if let Some(scheduler) = sync::get_global_scheduler() {
+      let id = config.id.clone();
+      tauri::async_runtime::spawn(async move {
+        scheduler.queue_vpn_sync(id).await;
+      });
+    }
+  }
+
+  let _ = events::emit_empty("vpn-configs-changed");
+  Ok(config)
+}
+
+/// Set the country and/or IP prefix the tunnel should exit in; blank values
+/// clear them.
+#[tauri::command]
+async fn update_vpn_exit_expectation(
+  vpn_id: String,
+  expected_country: Option<String>,
+  expected_ip_prefix: Option<String>,
+) -> Result<vpn::VpnConfig, String> {
+  let expected = exit_check::normalize_exit_expectation(expected_country, expected_ip_prefix)?;
+  let config = {
+    let storage = vpn::VPN_STORAGE
+      .lock()
+      .map_err(|e| format!("Failed to lock VPN storage: {e}"))?;
+
+    storage
+      .update_exit_expectation(&vpn_id, expected.country, expected.ip_prefix)
+      .map_err(|e| format!("Failed to update VPN config: {e}"))?
+  };
+
+  if config.sync_enabled {
+    if let Some(scheduler) = sync::get_global_scheduler() {
+      let id = config.id.clone();
+      tauri::async_runtime::spawn(async move {
+        scheduler.queue_vpn_sync(id).await;
+      });
+    }
+  }
+
+  let _ = events::emit_empty("vpn-configs-changed");
+  Ok(config)
+}
+
 #[tauri::command]
 async fn check_vpn_validity(
   vpn_id: String,
@@ -1168,28 +1513,7 @@ async fn disconnect_vpn(vpn_id: String) -> Result<(), String> {
 
 #[tauri::command]
 async fn get_vpn_status(vpn_id: String) -> Result<vpn::VpnStatus, String> {
-  use crate::proxy_storage::is_process_running;
-
-  if let Some(worker) = vpn_worker_storage::find_vpn_worker_by_vpn_id(&vpn_id) {
-    let connected = worker.pid.map(is_process_running).unwrap_or(false);
-    Ok(vpn::VpnStatus {
-      connected,
-      vpn_id,
-      connected_at: None,
-      bytes_sent: None,
-      bytes_received: None,
-      last_handshake: None,
-    })
-  } else {
-    Ok(vpn::VpnStatus {
-      connected: false,
-      vpn_id,
-      connected_at: None,
-      bytes_sent: None,
-      bytes_received: None,
-      last_handshake: None,
-    })
-  }
+  Ok(vpn_worker_runner::vpn_status(&vpn_id))
 }
 
 #[tauri::command]
@@ -1215,44 +1539,13 @@ async fn list_active_vpn_connections() -> Result<Vec<vpn::VpnStatus>, String> {
 
 #[tauri::command]
 async fn generate_sample_fingerprint(
-  app_handle: tauri::AppHandle,
+  app_handle: crate::app_handle::AppHandle,
   browser: String,
   version: String,
   config_json: String,
 ) -> Result<String, String> {
-  let temp_profile = crate::profile::BrowserProfile {
-    id: uuid::Uuid::new_v4(),
-    name: "temp_fingerprint_gen".to_string(),
-    browser: browser.clone(),
-    version: version.clone(),
-    process_id: None,
-    proxy_id: None,
-    vpn_id: None,
-    launch_hook: None,
-    last_launch: None,
-    release_type: "stable".to_string(),
-    wayfern_config: None,
-    group_id: None,
-    tags: Vec::new(),
-    note: None,
-    window_color: None,
-    sync_mode: crate::profile::types::SyncMode::Disabled,
-    encryption_salt: None,
-    last_sync: None,
-    host_os: None,
-    ephemeral: false,
-    extension_group_id: None,
-    proxy_bypass_rules: Vec::new(),
-    created_by_id: None,
-    created_by_email: None,
-    dns_blocklist: None,
-    password_protected: false,
-    clear_on_close: false,
-    created_at: None,
-    updated_at: None,
-  };
-
   if browser == "wayfern" {
+    let temp_profile = crate::wayfern_manager::fingerprint_generation_profile(&version);
     let config: crate::wayfern_manager::WayfernConfig =
       serde_json::from_str(&config_json).map_err(|e| format!("Failed to parse config: {e}"))?;
     let manager = crate::wayfern_manager::WayfernManager::instance();
@@ -1284,6 +1577,48 @@ fn hide_to_tray(app_handle: tauri::AppHandle) -> Result<(), String> {
   Ok(())
 }
 
+/// Label of the detached profile-details window. There is only ever one, so
+/// the window-state plugin keeps a single saved geometry for it, separate from
+/// the main window's.
+const PROFILE_WINDOW_LABEL: &str = "profile-detail";
+
+/// Show one profile's details in their own window so they can sit next to the
+/// profile table. If the window is already open it switches to `profile_id`.
+#[tauri::command]
+fn open_detached_profile_window(
+  app_handle: tauri::AppHandle,
+  profile_id: String,
+) -> Result<(), String> {
+  let profile = profile::ProfileManager::instance()
+    .list_profiles()
+    .map_err(|e| e.to_string())?
+    .into_iter()
+    .find(|p| p.id.to_string() == profile_id)
+    .ok_or_else(|| serde_json::json!({ "code": "PROFILE_NOT_FOUND" }).to_string())?;
+
+  if let Some(window) = app_handle.get_webview_window(PROFILE_WINDOW_LABEL) {
+    events::emit(&events::payloads::DetachedProfileSelected(profile_id))?;
+    let _ = window.set_title(&profile.name);
+    let _ = window.show();
+    let _ = window.unminimize();
+    let _ = window.set_focus();
+    return Ok(());
+  }
+
+  // Profile IDs are UUIDs, so they need no escaping in the query string.
+  let url = WebviewUrl::App(format!("profile-window?id={profile_id}").into());
+  WebviewWindowBuilder::new(&app_handle, PROFILE_WINDOW_LABEL, url)
+    .title(&profile.name)
+    .inner_size(720.0, 640.0)
+    .min_inner_size(480.0, 400.0)
+    .resizable(true)
+    .fullscreen(false)
+    .focused(true)
+    .build()
+    .map_err(|e| format!("Failed to open profile window: {e}"))?;
+  Ok(())
+}
+
 #[cfg(not(feature = "e2e"))]
 fn show_main_window(app_handle: &tauri::AppHandle) {
   if let Some(window) = app_handle.get_webview_window("main") {
@@ -1389,93 +1724,678 @@ fn setup_system_tray(app: &tauri::AppHandle) -> Result<(), Box<dyn std::error::E
   Ok(())
 }
 
-#[cfg_attr(mobile, tauri::mobile_entry_point)]
-pub fn run() {
-  run_with_builder(|builder| builder);
+/// Clear stale process IDs from profiles (processes that died while the app
+/// was closed).
+fn clear_stale_profile_pids() {
+  let profile_manager = crate::profile::ProfileManager::instance();
+  if let Ok(profiles) = profile_manager.list_profiles() {
+    for profile in profiles {
+      if let Some(pid) = profile.process_id {
+        if !crate::process_registry::process_exists(pid) {
+          log::info!(
+            "Clearing stale process_id {} for profile {}",
+            pid,
+            profile.name
+          );
+          let mut updated = profile.clone();
+          updated.process_id = None;
+          updated.cdp_port = None;
+          updated.applied_resource_limits = None;
+          let _ = profile_manager.save_profile(&updated);
+        }
+      }
+    }
+  }
 }
 
-#[doc(hidden)]
-pub fn run_with_builder(
-  configure_builder: impl FnOnce(tauri::Builder<tauri::Wry>) -> tauri::Builder<tauri::Wry>,
-) {
-  let args: Vec<String> = env::args().collect();
-  let startup_url = args.iter().find(|arg| arg.starts_with("http")).cloned();
+/// Kill orphaned proxy and VPN worker processes from previous app runs.
+/// Since active_proxies is an in-memory map that starts empty, any running
+/// donut-proxy workers on disk must be orphans the current app can't track.
+/// Without this cleanup, users on Windows accumulate dozens of idle workers
+/// (one per profile launch) that the periodic cleanup won't touch because
+/// profile-associated workers are deliberately skipped to avoid regressions.
+///
+/// Preserves workers whose associated profile still has a running browser
+/// process — if the app crashed while a browser was running, its detached
+/// browser keeps going and needs the proxy/VPN worker to stay alive.
+fn spawn_orphan_worker_cleanup() {
+  tauri::async_runtime::spawn(async move {
+    use crate::proxy_storage::{delete_proxy_config, is_process_running, list_proxy_configs};
+    use crate::vpn_worker_storage::{delete_vpn_worker_config, list_vpn_worker_configs};
+
+    // Build sets of (profile_id, vpn_id) whose browsers are still running
+    let profile_manager = crate::profile::ProfileManager::instance();
+    let profiles = profile_manager.list_profiles().unwrap_or_default();
+
+    let running_profile_ids: std::collections::HashSet<String> = profiles
+      .iter()
+      .filter(|p| p.process_id.is_some_and(is_process_running))
+      .map(|p| p.id.to_string())
+      .collect();
+
+    let running_vpn_ids: std::collections::HashSet<String> = profiles
+      .iter()
+      .filter(|p| p.process_id.is_some_and(is_process_running))
+      .filter_map(|p| p.vpn_id.clone())
+      .collect();
+
+    for config in list_proxy_configs() {
+      let has_running_browser = config
+        .profile_id
+        .as_ref()
+        .is_some_and(|pid| running_profile_ids.contains(pid));
+      if has_running_browser {
+        log::info!(
+          "Startup: preserving proxy worker {} (profile browser still running)",
+          config.id
+        );
+        continue;
+      }
 
-  if let Some(url) = startup_url.clone() {
-    log::info!("Found startup URL in command line");
-    let mut pending = PENDING_URLS.lock().unwrap();
-    pending.push(url.clone());
-  }
+      if let Some(pid) = config.pid {
+        if is_process_running(pid) {
+          log::info!(
+            "Startup: killing orphaned proxy worker {} (PID {})",
+            config.id,
+            pid
+          );
+          let _ = crate::proxy_runner::stop_proxy_process(&config.id).await;
+          continue;
+        }
+      }
+      delete_proxy_config(&config.id);
+    }
 
-  let log_file_name = app_dirs::app_name();
+    for worker in list_vpn_worker_configs() {
+      if running_vpn_ids.contains(&worker.vpn_id) {
+        log::info!(
+          "Startup: preserving VPN worker {} (profile browser using vpn_id {} still running)",
+          worker.id,
+          worker.vpn_id
+        );
+        continue;
+      }
 
-  // Honor DONUTBROWSER_DATA_ROOT: when set, logs go to <root>/logs instead of
-  // the platform default app log dir, so all on-disk state lives under one root.
-  let file_log_target = match app_dirs::log_dir_override() {
-    Some(path) => Target::new(TargetKind::Folder {
-      path,
-      file_name: Some(log_file_name.to_string()),
-    }),
-    None => Target::new(TargetKind::LogDir {
-      file_name: Some(log_file_name.to_string()),
-    }),
-  };
+      if let Some(pid) = worker.pid {
+        if is_process_running(pid) {
+          log::info!(
+            "Startup: killing orphaned VPN worker {} (PID {})",
+            worker.id,
+            pid
+          );
+          let _ = crate::vpn_worker_runner::stop_vpn_worker(&worker.id).await;
+          continue;
+        }
+      }
+      delete_vpn_worker_config(&worker.id);
+    }
+  });
+}
 
-  let builder = configure_builder(tauri::Builder::default());
+/// Every 30s, stop proxies whose browser has exited and drop exited workers
+/// from the process registry.
+fn spawn_proxy_cleanup_task(app_handle: crate::app_handle::AppHandle) {
+  tauri::async_runtime::spawn(async move {
+    let mut interval = tokio::time::interval(tokio::time::Duration::from_secs(30));
 
-  let builder = builder.plugin(
-    tauri_plugin_log::Builder::new()
-      .clear_targets() // Clear default targets to avoid duplicates
-      .target(Target::new(TargetKind::Stdout))
-      .target(Target::new(TargetKind::Webview))
-      .target(file_log_target)
-      // Keep enough context for customer support without letting a long-running
-      // installation accumulate logs without bound.
-      .max_file_size(5 * 1024 * 1024)
-      .rotation_strategy(tauri_plugin_log::RotationStrategy::KeepSome(10))
-      .level(log::LevelFilter::Info)
-      .format(|out, message, record| {
-        use chrono::Local;
-        let now = Local::now();
-        let timestamp = format!(
-          "{}.{:03}",
-          now.format("%Y-%m-%d %H:%M:%S"),
-          now.timestamp_subsec_millis()
-        );
-        out.finish(format_args!(
-          "[{}][{}][{}] {}",
-          timestamp,
-          record.target(),
-          record.level(),
-          message
-        ))
-      })
-      .build(),
-  );
+    loop {
+      interval.tick().await;
 
-  #[cfg(not(feature = "e2e"))]
-  let builder = builder.plugin(tauri_plugin_single_instance::init(
-    |app_handle, args, _cwd| {
-      log::info!("Single instance triggered with args: {args:?}");
-      if let Some(window) = app_handle.get_webview_window("main") {
-        let _ = window.show();
-        let _ = window.set_focus();
-        let _ = window.unminimize();
+      match crate::proxy_manager::PROXY_MANAGER
+        .cleanup_dead_proxies(app_handle.clone())
+        .await
+      {
+        Ok(dead_pids) => {
+          if !dead_pids.is_empty() {
+            log::info!(
+              "Cleaned up proxies for {} dead browser processes",
+              dead_pids.len()
+            );
+          }
+        }
+        Err(e) => {
+          log::error!("Error during proxy cleanup: {e}");
+        }
       }
-    },
-  ));
 
-  let builder = builder
-    .plugin(tauri_plugin_deep_link::init())
-    .plugin(tauri_plugin_fs::init())
-    .plugin(tauri_plugin_opener::init())
-    .plugin(tauri_plugin_shell::init())
-    .plugin(tauri_plugin_dialog::init())
-    .plugin(tauri_plugin_macos_permissions::init())
-    .plugin(tauri_plugin_clipboard_manager::init());
+      let swept = crate::process_registry::sweep_dead_children();
+      if swept > 0 {
+        log::debug!("Dropped {swept} exited worker(s) from the process registry");
+      }
+    }
+  });
+}
 
-  #[cfg(not(feature = "e2e"))]
-  let builder = builder
+/// Track which profiles are running, emitting status changes and running the
+/// post-exit work (proxy teardown, VPN auto-disconnect) when one stops.
+fn spawn_browser_status_task(app_handle: crate::app_handle::AppHandle) {
+  tauri::async_runtime::spawn(async move {
+    use crate::profile::status_checker;
+
+    let mut current_interval = status_checker::IDLE_INTERVAL;
+    let mut interval = tokio::time::interval(current_interval);
+    interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+    let mut last_running_states: std::collections::HashMap<String, bool> =
+      std::collections::HashMap::new();
+    // Re-read from disk only after a profile event, not on every tick.
+    let mut index = status_checker::ProfileIndex::new();
+
+    loop {
+      interval.tick().await;
+
+      let runner = crate::browser_runner::BrowserRunner::instance();
+      let profiles = match index.profiles(std::time::Instant::now(), || {
+        runner
+          .profile_manager
+          .list_profiles()
+          .map_err(|e| e.to_string())
+      }) {
+        Ok(p) => p,
+        Err(e) => {
+          log::warn!("Failed to list profiles in status checker: {e}");
+          continue;
+        }
+      };
+
+      // Only profiles that either have a stored PID or that we last saw as
+      // running are checked — for users with hundreds of idle profiles this
+      // keeps a tick O(running). The Rust launch path always emits
+      // profile-running-changed when a profile STARTS, so newly-running
+      // profiles still get tracked here.
+      let plan = status_checker::plan_tick(
+        profiles,
+        &last_running_states,
+        status_checker::launch_in_flight(),
+      );
+      if plan.next_interval != current_interval {
+        current_interval = plan.next_interval;
+        interval = tokio::time::interval(current_interval);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+      }
+      if plan.to_check.is_empty() {
+        continue;
+      }
+
+      // (profile id, vpn id, has stored PID) for every VPN-assigned
+      // profile, so an exit can tell whether the tunnel is still in use.
+      let vpn_users: Vec<(String, String, bool)> = profiles
+        .iter()
+        .filter_map(|p| {
+          p.vpn_id
+            .clone()
+            .map(|vpn_id| (p.id.to_string(), vpn_id, p.process_id.is_some()))
+        })
+        .collect();
+      let profiles_to_check: Vec<_> = plan.to_check.into_iter().cloned().collect();
+
+      // One process-table scan serves every profile this tick.
+      let snapshot = crate::process_registry::ProcessSnapshot::capture();
+
+      for profile in profiles_to_check {
+        let had_pid = profile.process_id.is_some();
+        // Check browser status and track changes
+        match runner
+          .profile_manager
+          .check_browser_status_in(app_handle.clone(), &profile, Some(&snapshot))
+          .await
+        {
+          Ok(is_running) => {
+            let profile_id = profile.id.to_string();
+            let last_state = last_running_states
+              .get(&profile_id)
+              .copied()
+              .unwrap_or(false);
+
+            // Emit when the running state changed, or when we still had a
+            // stored PID but the browser is gone — the launch path sets the
+            // frontend to "running" immediately, and a missed transition
+            // here leaves the stop button stuck.
+            let should_emit = last_state != is_running || (!is_running && had_pid);
+
+            if should_emit {
+              log::debug!(
+                "Status checker detected change for profile {}: {} -> {}",
+                profile.name,
+                last_state,
+                is_running
+              );
+
+              let payload = events::payloads::ProfileRunningChanged {
+                id: profile_id.clone(),
+                is_running,
+              };
+
+              if let Err(e) = events::emit(&payload) {
+                log::warn!("Failed to emit profile running changed event: {e}");
+              } else {
+                log::debug!(
+                  "Status checker emitted profile-running-changed event for {}: running={}",
+                  profile.name,
+                  is_running
+                );
+              }
+
+              // Re-encrypt password-protected profiles when the browser
+              // exits naturally (user closing the window) — the explicit
+              // kill path in browser_runner.rs handles app-driven stops.
+              // Must run BEFORE `mark_profile_stopped` because that
+              // releases any queued sync run, and a sync that picks up
+              // the on-disk dir before re-encryption finishes uploads
+              // the previous snapshot (issue: encrypted profiles not
+              // syncing fresh data).
+              if !is_running && profile.password_protected {
+                crate::profile::password::complete_after_quit_and_wait(&profile).await;
+              }
+
+              // Clear-on-close for natural exits (user closed the window).
+              // The explicit kill path in browser_runner.rs handles
+              // app-driven stops. Must also run before
+              // `mark_profile_stopped` so a queued sync sees the cleared
+              // dir rather than re-uploading the wiped browsing data.
+              if !is_running {
+                crate::profile::clear_on_close::clear_profile_browsing_data(&profile).await;
+              }
+
+              // Notify sync scheduler of running state changes
+              if let Some(scheduler) = sync::get_global_scheduler() {
+                if is_running {
+                  scheduler.mark_profile_running(&profile_id).await;
+                } else {
+                  // Sync was queued at launch; mark_profile_stopped triggers it
+                  scheduler.mark_profile_stopped(&profile_id).await;
+                }
+              }
+
+              // Release the cloud team lock when the browser exits naturally
+              // (window closed by the user). The explicit kill path in
+              // browser_runner.rs already releases it, but this branch did
+              // not — leaking the lock, which the 30s heartbeat then renews
+              // indefinitely. No-op for non-sync/non-paid
+              // profiles thanks to the guards inside the helper.
+              if !is_running {
+                crate::team_lock::release_team_lock_if_needed(&profile).await;
+              }
+
+              // Last running profile on this VPN exited: let the tunnel
+              // go if the VPN opted into auto-disconnect.
+              if !is_running {
+                if let Some(vpn_id) = profile.vpn_id.as_deref() {
+                  let still_in_use = vpn_users.iter().any(|(id, v, has_pid)| {
+                    v == vpn_id
+                      && id != &profile_id
+                      && (*has_pid || last_running_states.get(id).copied().unwrap_or(false))
+                  });
+                  if !still_in_use {
+                    crate::vpn_worker_runner::disconnect_if_auto(vpn_id).await;
+                  }
+                }
+              }
+
+              // Only exits of a browser we saw running this session are
+              // considered for a restart; a stale PID found at startup is not.
+              if !is_running && last_state {
+                crate::profile::restart_supervisor::handle_exit(
+                  app_handle.clone(),
+                  &profile,
+                  profile.process_id,
+                );
+              }
+
+              last_running_states.insert(profile_id, is_running);
+            } else {
+              // Update the state even if unchanged to ensure we have it tracked
+              last_running_states.insert(profile_id, is_running);
+            }
+          }
+          Err(e) => {
+            log::warn!("Status check failed for profile {}: {}", profile.name, e);
+            continue;
+          }
+        }
+      }
+    }
+  });
+}
+
+/// Start the sync subscription and scheduler if sync is configured.
+fn spawn_sync_tasks(app_handle: crate::app_handle::AppHandle) {
+  tauri::async_runtime::spawn(async move {
+    use std::sync::Arc;
+
+    let mut subscription_manager = sync::SubscriptionManager::new();
+    let work_rx = subscription_manager.take_work_receiver();
+
+    if let Err(e) = subscription_manager.start(app_handle.clone()).await {
+      log::warn!("Failed to start sync subscription: {e}");
+    }
+
+    if let Some(work_rx) = work_rx {
+      let scheduler = Arc::new(sync::SyncScheduler::new());
+
+      // Set the global scheduler so commands can access it
+      sync::set_global_scheduler(scheduler.clone());
+
+      // Start initial sync for all enabled profiles
+      scheduler.sync_all_enabled_profiles(&app_handle).await;
+
+      // Check for missing synced profiles (deleted locally but exist remotely).
+      // When offline, leaving offline mode runs this reconciliation instead.
+      if !offline::is_offline() {
+        match sync::SyncEngine::create_from_settings(&app_handle).await {
+          Ok(engine) => {
+            if let Err(e) = engine.check_for_missing_synced_profiles(&app_handle).await {
+              log::warn!("Failed to check for missing profiles: {}", e);
+            }
+            if let Err(e) = engine.check_for_missing_synced_entities(&app_handle).await {
+              log::warn!("Failed to check for missing entities: {}", e);
+            }
+          }
+          Err(e) => {
+            log::warn!("Sync not configured, skipping missing profile check: {}", e);
+          }
+        }
+      }
+
+      scheduler.clone().start(app_handle.clone(), work_rx).await;
+      log::info!("Sync scheduler started");
+    }
+  });
+}
+
+/// Refresh cloud credentials on startup and keep the sync token fresh.
+fn spawn_cloud_auth_task(app_handle: crate::app_handle::AppHandle) {
+  tauri::async_runtime::spawn(async move {
+    // On startup, refresh sync token, proxy config, and wayfern token in
+    // PARALLEL. Previously they were awaited sequentially, so the wayfern
+    // token request didn't even start until the earlier two API calls had
+    // finished. Wayfern launch can race with this task — a few seconds of
+    // serialized API calls translates directly into a slow first launch
+    // because launch_wayfern blocks waiting for the token to land.
+    // api_call_with_retry handles 401/refresh internally — no direct
+    // refresh_access_token call needed.
+    if cloud_auth::CLOUD_AUTH.is_logged_in().await && !offline::is_offline() {
+      let sync_token_fut = async {
+        if let Err(e) = cloud_auth::CLOUD_AUTH.get_or_refresh_sync_token().await {
+          log::warn!("Failed to refresh cloud sync token on startup: {e}");
+        }
+      };
+      let proxy_fut = async {
+        cloud_auth::CLOUD_AUTH.sync_cloud_proxy().await;
+      };
+      let wayfern_fut = async {
+        if cloud_auth::CLOUD_AUTH.has_active_paid_subscription().await {
+          if let Err(e) = cloud_auth::CLOUD_AUTH.request_wayfern_token().await {
+            log::warn!("Failed to request wayfern token on startup: {e}");
+          }
+        }
+      };
+      tokio::join!(sync_token_fut, proxy_fut, wayfern_fut);
+    }
+    cloud_auth::CloudAuthManager::start_sync_token_refresh_loop(app_handle).await;
+  });
+}
+
+/// Run the profile manager without the GUI, for headless servers.
+///
+/// Starts the same background work as the desktop app (process tracking,
+/// proxy cleanup, sync, cloud auth) plus the API server and, if enabled, the
+/// MCP server. No webview or tray is created: events go to a
+/// [`events::DaemonEmitter`] and are streamed to clients over the API's
+/// `/v1/events` WebSocket. Blocks until Ctrl+C / SIGTERM.
+#[cfg(feature = "daemon")]
+pub fn run_daemon() {
+  env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info"))
+    .format(|buf, record| {
+      use std::io::Write;
+      log_buffer::push(record);
+      writeln!(
+        buf,
+        "[{} {} {}] {}",
+        buf.timestamp_millis(),
+        record.level(),
+        record.target(),
+        record.args()
+      )
+    })
+    .init();
+
+  let emitter = std::sync::Arc::new(events::DaemonEmitter::new(1024));
+  if let Err(e) = events::set_global_emitter(emitter) {
+    log::warn!("Failed to set global event emitter: {e}");
+  }
+
+  let app_handle = crate::app_handle::AppHandle::Headless;
+
+  tauri::async_runtime::block_on(async move {
+    ephemeral_dirs::recover_ephemeral_dirs();
+    clear_stale_profile_pids();
+    spawn_orphan_worker_cleanup();
+    orphan_cleanup::spawn_startup_cleanup();
+    geolocation::warm_up();
+    spawn_proxy_cleanup_task(app_handle.clone());
+    spawn_browser_status_task(app_handle.clone());
+    spawn_sync_tasks(app_handle.clone());
+    spawn_cloud_auth_task(app_handle.clone());
+
+    let settings_mgr = settings_manager::SettingsManager::instance();
+    let settings = settings_mgr.load_settings().unwrap_or_default();
+
+    // The API is the daemon's only interface, so it always starts. Make sure
+    // there is a token to authenticate with and tell the operator once.
+    match settings_mgr.get_api_token(&app_handle).await {
+      Ok(Some(_)) => {}
+      Ok(None) => match settings_mgr.generate_api_token(&app_handle).await {
+        Ok(token) => println!("Generated API token: {token}"),
+        Err(e) => log::error!("Failed to generate API token: {e}"),
+      },
+      Err(e) => log::error!("Failed to read API token: {e}"),
+    }
+
+    match api_server::start_api_server_internal(
+      settings.api_port,
+      &settings.api_listen,
+      &app_handle,
+    )
+    .await
+    {
+      Ok(endpoint) => log::info!("API server listening on {endpoint}"),
+      Err(e) => {
+        log::error!("Failed to start API server: {e}");
+        return;
+      }
+    }
+
+    if settings.mcp_enabled {
+      match mcp_server::McpServer::instance()
+        .start(app_handle.clone())
+        .await
+      {
+        Ok(port) => log::info!("MCP server listening on port {port}"),
+        Err(e) => log::warn!("Failed to start MCP server: {e}"),
+      }
+    }
+
+    wait_for_shutdown_signal().await;
+    log::info!("Shutting down daemon");
+
+    let _ = api_server::stop_api_server().await;
+    let _ = mcp_server::McpServer::instance().stop().await;
+  });
+}
+
+#[cfg(feature = "daemon")]
+async fn wait_for_shutdown_signal() {
+  #[cfg(unix)]
+  {
+    use tokio::signal::unix::{signal, SignalKind};
+    match signal(SignalKind::terminate()) {
+      Ok(mut sigterm) => {
+        tokio::select! {
+          _ = tokio::signal::ctrl_c() => {},
+          _ = sigterm.recv() => {},
+        }
+        return;
+      }
+      Err(e) => log::warn!("Failed to listen for SIGTERM: {e}"),
+    }
+  }
+  let _ = tokio::signal::ctrl_c().await;
+}
+
+/// `donutbrowser --mcp-stdio`: serve MCP over stdin/stdout for clients that
+/// can't use the HTTP transport, then exit when the client closes stdin. No
+/// window is created, and logs go to stderr so stdout carries only JSON-RPC.
+pub fn run_mcp_stdio() {
+  env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info"))
+    .target(env_logger::Target::Stderr)
+    .format(|buf, record| {
+      use std::io::Write;
+      log_buffer::push(record);
+      writeln!(
+        buf,
+        "[{} {} {}] {}",
+        buf.timestamp_millis(),
+        record.level(),
+        record.target(),
+        record.args()
+      )
+    })
+    .init();
+
+  let app_handle = crate::app_handle::AppHandle::Headless;
+  let exit_code = tauri::async_runtime::block_on(async move {
+    match mcp_server::McpServer::instance()
+      .run_stdio(app_handle)
+      .await
+    {
+      Ok(()) => 0,
+      Err(e) => {
+        log::error!("MCP stdio transport stopped: {e}");
+        1
+      }
+    }
+  });
+  std::process::exit(exit_code);
+}
+
+#[cfg_attr(mobile, tauri::mobile_entry_point)]
+pub fn run() {
+  run_with_builder(|builder| builder);
+}
+
+#[doc(hidden)]
+pub fn run_with_builder(
+  configure_builder: impl FnOnce(tauri::Builder<tauri::Wry>) -> tauri::Builder<tauri::Wry>,
+) {
+  let args: Vec<String> = env::args().collect();
+  if args.iter().any(|arg| arg == "--mcp-stdio") {
+    run_mcp_stdio();
+    return;
+  }
+  if args.iter().any(|arg| arg == observer_mode::OBSERVER_FLAG) {
+    observer_mode::enable();
+  }
+  // An observer never opens links: that would launch a profile.
+  let startup_url = args
+    .iter()
+    .find(|arg| arg.starts_with("http") || deep_link::is_action_link(arg))
+    .filter(|_| !observer_mode::is_enabled())
+    .cloned();
+
+  if let Some(url) = startup_url {
+    log::info!("Found startup URL in command line");
+    pending_urls::push(url);
+  }
+
+  let log_file_name = app_dirs::app_name();
+
+  // Honor DONUTBROWSER_DATA_ROOT: when set, logs go to <root>/logs instead of
+  // the platform default app log dir, so all on-disk state lives under one root.
+  let file_log_target = match app_dirs::log_dir_override() {
+    Some(path) => Target::new(TargetKind::Folder {
+      path,
+      file_name: Some(log_file_name.to_string()),
+    }),
+    None => Target::new(TargetKind::LogDir {
+      file_name: Some(log_file_name.to_string()),
+    }),
+  };
+
+  let builder = configure_builder(tauri::Builder::default());
+
+  let builder = builder.plugin(
+    tauri_plugin_log::Builder::new()
+      .clear_targets() // Clear default targets to avoid duplicates
+      .target(Target::new(TargetKind::Stdout))
+      .target(Target::new(TargetKind::Webview))
+      .target(file_log_target)
+      // Keep enough context for customer support without letting a long-running
+      // installation accumulate logs without bound.
+      .max_file_size(5 * 1024 * 1024)
+      .rotation_strategy(tauri_plugin_log::RotationStrategy::KeepSome(10))
+      .level(log::LevelFilter::Info)
+      .format(|out, message, record| {
+        use chrono::Local;
+        log_buffer::push(record);
+        let now = Local::now();
+        let timestamp = format!(
+          "{}.{:03}",
+          now.format("%Y-%m-%d %H:%M:%S"),
+          now.timestamp_subsec_millis()
+        );
+        out.finish(format_args!(
+          "[{}][{}][{}] {}",
+          timestamp,
+          record.target(),
+          record.level(),
+          message
+        ))
+      })
+      .build(),
+  );
+
+  // An observer runs next to the owning instance instead of handing its
+  // arguments over to it.
+  #[cfg(not(feature = "e2e"))]
+  let builder = if observer_mode::is_enabled() {
+    builder
+  } else {
+    builder.plugin(tauri_plugin_single_instance::init(
+      |app_handle, args, _cwd| {
+        log::info!("Single instance triggered with args: {args:?}");
+        let links: Vec<String> = args
+          .iter()
+          .skip(1)
+          .filter(|arg| arg.starts_with("http") || deep_link::is_action_link(arg))
+          .cloned()
+          .collect();
+        // Links decide for themselves whether the window comes forward.
+        if links.is_empty() {
+          if let Some(window) = app_handle.get_webview_window("main") {
+            let _ = window.show();
+            let _ = window.set_focus();
+            let _ = window.unminimize();
+          }
+        }
+        for link in links {
+          pending_urls::submit(app_handle, link);
+        }
+      },
+    ))
+  };
+
+  let builder = builder
+    .plugin(tauri_plugin_deep_link::init())
+    .plugin(tauri_plugin_fs::init())
+    .plugin(tauri_plugin_opener::init())
+    .plugin(tauri_plugin_shell::init())
+    .plugin(tauri_plugin_dialog::init())
+    .plugin(tauri_plugin_macos_permissions::init())
+    .plugin(tauri_plugin_clipboard_manager::init())
+    .plugin(profile_shortcuts::plugin());
+
+  #[cfg(not(feature = "e2e"))]
+  let builder = builder
     // Persist window size/position across restarts. VISIBLE is excluded
     // because the app hides to tray: restoring visibility would otherwise
     // relaunch with an invisible window after quitting from the tray while
@@ -1493,11 +2413,22 @@ pub fn run_with_builder(
     );
 
   builder.setup(|app| {
+      // Every event goes through the global emitter; install it before
+      // anything below can emit.
+      let emitter = std::sync::Arc::new(events::TauriEmitter::new(app.handle().clone()));
+      if let Err(e) = events::set_global_emitter(emitter) {
+        log::warn!("Failed to set global event emitter: {e}");
+      }
+
+      let observer = observer_mode::is_enabled();
+
       // Recover ephemeral dir mappings from RAM-backed storage (tmpfs/ramdisk)
-      ephemeral_dirs::recover_ephemeral_dirs();
+      if !observer {
+        ephemeral_dirs::recover_ephemeral_dirs();
+      }
 
       // Extract icons and metadata for existing extensions that don't have them yet
-      {
+      if !observer {
         let mgr = extension_manager::ExtensionManager::new();
         mgr.ensure_icons_extracted();
       }
@@ -1505,7 +2436,11 @@ pub fn run_with_builder(
       // Create the main window programmatically
       #[allow(unused_variables)]
       let win_builder = WebviewWindowBuilder::new(app, "main", WebviewUrl::default())
-        .title("Donut Browser")
+        .title(if observer {
+          "Donut Browser (Observer)"
+        } else {
+          "Donut Browser"
+        })
         .inner_size(880.0, 500.0)
         .min_inner_size(640.0, 400.0)
         .resizable(true)
@@ -1527,9 +2462,6 @@ pub fn run_with_builder(
           None => win_builder,
       };
 
-      #[cfg(target_os = "windows")]
-      let win_builder = win_builder.decorations(false);
-
       #[allow(unused_variables)]
       let window = win_builder.build().unwrap();
 
@@ -1555,13 +2487,24 @@ pub fn run_with_builder(
               return;
             }
             api.prevent_close();
-            if let Err(e) = app_handle.emit("close-confirm-requested", ()) {
+            if let Err(e) = events::emit_empty("close-confirm-requested") {
               log::warn!("Failed to emit close-confirm-requested: {e}");
             }
           }
         });
       }
 
+      // Fold the native titlebar into the webview on Windows so the custom
+      // header and window controls replace it. If DWM refuses, drop the
+      // decorations entirely rather than showing two titlebars.
+      #[cfg(target_os = "windows")]
+      {
+        if let Err(e) = window.set_transparent_titlebar(true) {
+          log::warn!("Failed to set transparent titlebar: {e}");
+          let _ = window.set_decorations(false);
+        }
+      }
+
       // Set transparent titlebar for macOS
       #[cfg(target_os = "macos")]
       {
@@ -1578,10 +2521,12 @@ pub fn run_with_builder(
       // Set up deep link handler
       let handle = app.handle().clone();
 
-      // Initialize the global event emitter for the events module
-      let emitter = std::sync::Arc::new(events::TauriEmitter::new(handle.clone()));
-      if let Err(e) = events::set_global_emitter(emitter) {
-        log::warn!("Failed to set global event emitter: {e}");
+      // The owning instance runs everything below; an observer only watches
+      // the shared data dir.
+      if observer {
+        log::info!("Running in observer mode: commands that modify data are disabled");
+        observer_mode::spawn_status_watch();
+        return Ok(());
       }
 
       #[cfg(all(windows, not(feature = "e2e")))]
@@ -1602,6 +2547,13 @@ pub fn run_with_builder(
         }
       }
 
+      // AppImages ship no installed desktop entry, so without one xdg has no
+      // way to launch the app for a link while it isn't already running.
+      #[cfg(all(target_os = "linux", not(feature = "e2e")))]
+      {
+        default_browser::linux::ensure_appimage_desktop_entry();
+      }
+
       #[cfg(not(feature = "e2e"))]
       {
         app.deep_link().on_open_url({
@@ -1611,33 +2563,16 @@ pub fn run_with_builder(
             log::info!("Deep link event received with {} URLs", urls.len());
 
             for url in urls {
-              let url_string = url.to_string();
               log::info!("Processing deep link URL");
-              let handle_clone = handle.clone();
-
-              tauri::async_runtime::spawn(async move {
-                if let Err(e) = handle_url_open(handle_clone, url_string.clone()).await {
-                  log::error!("Failed to handle deep link URL: {e}");
-                }
-              });
+              pending_urls::submit(&handle, url.to_string());
             }
           }
         });
       }
 
-      if let Some(startup_url) = startup_url {
-        let handle_clone = handle.clone();
-        tauri::async_runtime::spawn(async move {
-          log::info!("Processing startup URL from command line");
-          if let Err(e) = handle_url_open(handle_clone, startup_url.clone()).await {
-            log::error!("Failed to handle startup URL: {e}");
-          }
-        });
-      }
-
       if !e2e_automation_enabled() {
         // Initialize and start background version updater
-        let app_handle = app.handle().clone();
+        let app_handle = crate::app_handle::AppHandle::from(app.handle());
         tauri::async_runtime::spawn(async move {
           let version_updater = get_version_updater();
 
@@ -1659,12 +2594,17 @@ pub fn run_with_builder(
         });
       }
 
+      if let Ok(settings) = settings_manager::SettingsManager::instance().load_settings() {
+        launch_queue::LAUNCH_QUEUE.set_limit(settings.max_concurrent_launches);
+        sync::bandwidth::apply_limits(&settings);
+      }
+
       // Auto-start MCP server if it was previously enabled. Always log the
       // decision so customer logs reveal whether MCP is actually running —
       // "automation features don't work" is otherwise indistinguishable from
       // "MCP server isn't enabled" without this line.
       {
-        let mcp_handle = app.handle().clone();
+        let mcp_handle = crate::app_handle::AppHandle::from(app.handle());
         let settings_mgr = settings_manager::SettingsManager::instance();
         match settings_mgr.load_settings() {
           Ok(settings) => {
@@ -1688,118 +2628,17 @@ pub fn run_with_builder(
         }
       }
 
-      // Clear stale process IDs from profiles (processes that died while app was closed)
-      {
-        let profile_manager = crate::profile::ProfileManager::instance();
-        if let Ok(profiles) = profile_manager.list_profiles() {
-          let system = sysinfo::System::new_with_specifics(
-            sysinfo::RefreshKind::nothing()
-              .with_processes(sysinfo::ProcessRefreshKind::everything()),
-          );
-          for profile in profiles {
-            if let Some(pid) = profile.process_id {
-              let sysinfo_pid = sysinfo::Pid::from_u32(pid);
-              if system.process(sysinfo_pid).is_none() {
-                log::info!(
-                  "Clearing stale process_id {} for profile {}",
-                  pid,
-                  profile.name
-                );
-                let mut updated = profile.clone();
-                updated.process_id = None;
-                let _ = profile_manager.save_profile(&updated);
-              }
-            }
-          }
-        }
-      }
-
-      // Kill orphaned proxy and VPN worker processes from previous app runs.
-      // Since active_proxies is an in-memory map that starts empty, any running
-      // donut-proxy workers on disk must be orphans the current app can't track.
-      // Without this cleanup, users on Windows accumulate dozens of idle workers
-      // (one per profile launch) that the periodic cleanup won't touch because
-      // profile-associated workers are deliberately skipped to avoid regressions.
-      //
-      // Preserves workers whose associated profile still has a running browser
-      // process — if the app crashed while a browser was running, its detached
-      // browser keeps going and needs the proxy/VPN worker to stay alive.
-      tauri::async_runtime::spawn(async move {
-        use crate::proxy_storage::{delete_proxy_config, is_process_running, list_proxy_configs};
-        use crate::vpn_worker_storage::{delete_vpn_worker_config, list_vpn_worker_configs};
-
-        // Build sets of (profile_id, vpn_id) whose browsers are still running
-        let profile_manager = crate::profile::ProfileManager::instance();
-        let profiles = profile_manager.list_profiles().unwrap_or_default();
+      clear_stale_profile_pids();
 
-        let running_profile_ids: std::collections::HashSet<String> = profiles
-          .iter()
-          .filter(|p| p.process_id.is_some_and(is_process_running))
-          .map(|p| p.id.to_string())
-          .collect();
-
-        let running_vpn_ids: std::collections::HashSet<String> = profiles
-          .iter()
-          .filter(|p| p.process_id.is_some_and(is_process_running))
-          .filter_map(|p| p.vpn_id.clone())
-          .collect();
-
-        for config in list_proxy_configs() {
-          let has_running_browser = config
-            .profile_id
-            .as_ref()
-            .is_some_and(|pid| running_profile_ids.contains(pid));
-          if has_running_browser {
-            log::info!(
-              "Startup: preserving proxy worker {} (profile browser still running)",
-              config.id
-            );
-            continue;
-          }
-
-          if let Some(pid) = config.pid {
-            if is_process_running(pid) {
-              log::info!(
-                "Startup: killing orphaned proxy worker {} (PID {})",
-                config.id,
-                pid
-              );
-              let _ = crate::proxy_runner::stop_proxy_process(&config.id).await;
-              continue;
-            }
-          }
-          delete_proxy_config(&config.id);
-        }
-
-        for worker in list_vpn_worker_configs() {
-          if running_vpn_ids.contains(&worker.vpn_id) {
-            log::info!(
-              "Startup: preserving VPN worker {} (profile browser using vpn_id {} still running)",
-              worker.id,
-              worker.vpn_id
-            );
-            continue;
-          }
-
-          if let Some(pid) = worker.pid {
-            if is_process_running(pid) {
-              log::info!(
-                "Startup: killing orphaned VPN worker {} (PID {})",
-                worker.id,
-                pid
-              );
-              let _ = crate::vpn_worker_runner::stop_vpn_worker(&worker.id).await;
-              continue;
-            }
-          }
-          delete_vpn_worker_config(&worker.id);
-        }
-      });
+      // Kill proxy and VPN workers orphaned by a previous run.
+      spawn_orphan_worker_cleanup();
+      // Stop untracked browsers and clear their stale profile locks.
+      orphan_cleanup::spawn_startup_cleanup();
 
       // Immediately bump non-running profiles to the latest installed browser version.
       // This runs synchronously before any network calls so profiles are updated on launch.
       {
-        let app_handle_bump = app.handle().clone();
+        let app_handle_bump = crate::app_handle::AppHandle::from(app.handle());
         match auto_updater::AutoUpdater::instance()
           .update_profiles_to_latest_installed(&app_handle_bump)
         {
@@ -1819,33 +2658,12 @@ pub fn run_with_builder(
       }
 
       if !e2e_automation_enabled() {
-        let app_handle_auto_updater = app.handle().clone();
+        let app_handle_auto_updater = crate::app_handle::AppHandle::from(app.handle());
         tauri::async_runtime::spawn(async move {
           auto_updater::check_for_updates_with_progress(app_handle_auto_updater).await;
         });
       }
 
-      // Handle any pending URLs that were received before the window was ready
-      let handle_pending = handle.clone();
-      tauri::async_runtime::spawn(async move {
-        // Wait a bit for the window to be fully ready
-        tokio::time::sleep(tokio::time::Duration::from_millis(1000)).await;
-
-        let pending_urls = {
-          let mut pending = PENDING_URLS.lock().unwrap();
-          let urls = pending.clone();
-          pending.clear();
-          urls
-        };
-
-        for url in pending_urls {
-          log::info!("Processing pending URL");
-          if let Err(e) = handle_url_open(handle_pending.clone(), url).await {
-            log::error!("Failed to handle pending URL: {e}");
-          }
-        }
-      });
-
       if !e2e_automation_enabled() {
         // Start periodic cleanup task for unused binaries.
         tauri::async_runtime::spawn(async move {
@@ -1866,6 +2684,58 @@ pub fn run_with_builder(
             } else {
               log::debug!("Periodic cleanup completed successfully");
             }
+
+            let retention = crate::settings_manager::SettingsManager::instance()
+              .load_settings()
+              .map(|s| s.snapshot_retention)
+              .unwrap_or(5);
+            if retention > 0 {
+              let pruned = profile::snapshots::prune_snapshots(retention as usize);
+              if pruned > 0 {
+                log::info!("Pruned {pruned} old profile snapshots");
+              }
+            }
+          }
+        });
+
+        tauri::async_runtime::spawn(async move {
+          let mut interval = tokio::time::interval(tokio::time::Duration::from_secs(
+            vpn_worker_runner::VPN_STATS_EVENT_INTERVAL_SECS,
+          ));
+          loop {
+            interval.tick().await;
+            vpn_worker_runner::emit_vpn_stats();
+          }
+        });
+
+        tauri::async_runtime::spawn(async move {
+          let mut interval = tokio::time::interval(tokio::time::Duration::from_secs(
+            warm_pool::MAINTENANCE_INTERVAL_SECS,
+          ));
+          loop {
+            interval.tick().await;
+            warm_pool::run_maintenance().await;
+          }
+        });
+
+        tauri::async_runtime::spawn(async move {
+          let mut interval = tokio::time::interval(tokio::time::Duration::from_secs(
+            extension_updater::EXTENSION_UPDATE_INTERVAL_SECS,
+          ));
+          loop {
+            interval.tick().await;
+            extension_updater::run_background_check().await;
+          }
+        });
+
+        let proxy_source_handle = crate::app_handle::AppHandle::from(app.handle());
+        tauri::async_runtime::spawn(async move {
+          let mut interval = tokio::time::interval(tokio::time::Duration::from_secs(
+            proxy_sources::PROXY_SOURCE_CHECK_INTERVAL_SECS,
+          ));
+          loop {
+            interval.tick().await;
+            proxy_sources::refresh_due_sources(&proxy_source_handle).await;
           }
         });
 
@@ -1891,7 +2761,7 @@ pub fn run_with_builder(
                   update_info.current_version,
                   update_info.new_version
                 );
-                if let Err(e) = events::emit("app-update-available", &update_info) {
+                if let Err(e) = events::emit(&update_info) {
                   log::error!("Failed to emit app update event: {e}");
                 }
               }
@@ -1901,9 +2771,13 @@ pub fn run_with_builder(
           }
         });
 
-        let app_handle_geoip = app.handle().clone();
+        let app_handle_geoip = crate::app_handle::AppHandle::from(app.handle());
         tauri::async_runtime::spawn(async move {
           tokio::time::sleep(tokio::time::Duration::from_secs(2)).await;
+          if offline::is_offline() {
+            log::info!("Offline mode, skipping GeoIP database check");
+            return;
+          }
           let geoip_downloader = crate::geoip_downloader::GeoIPDownloader::instance();
           match geoip_downloader.check_missing_geoip_database() {
             Ok(true) => {
@@ -1926,249 +2800,57 @@ pub fn run_with_builder(
         });
       }
 
-      // Start proxy cleanup task for dead browser processes
-      let app_handle_proxy_cleanup = app.handle().clone();
-      tauri::async_runtime::spawn(async move {
-        let mut interval = tokio::time::interval(tokio::time::Duration::from_secs(30));
-
-        loop {
-          interval.tick().await;
+      // Parse the bundled locale/timezone tables before the first launch
+      // needs them.
+      geolocation::warm_up();
 
-          match crate::proxy_manager::PROXY_MANAGER
-            .cleanup_dead_proxies(app_handle_proxy_cleanup.clone())
-            .await
-          {
-            Ok(dead_pids) => {
-              if !dead_pids.is_empty() {
-                log::info!(
-                  "Cleaned up proxies for {} dead browser processes",
-                  dead_pids.len()
-                );
-              }
-            }
-            Err(e) => {
-              log::error!("Error during proxy cleanup: {e}");
-            }
-          }
-        }
-      });
+      // Start proxy cleanup task for dead browser processes
+      spawn_proxy_cleanup_task(crate::app_handle::AppHandle::from(app.handle()));
 
       // Periodically broadcast browser running status to the frontend.
       // When no profiles have stored PIDs (nothing was ever launched this
       // session), we use a long interval (30s) to avoid burning CPU on
       // full process-table scans via sysinfo. Once any profile is running
       // we switch to the fast interval (5s) for responsive UI updates.
-      let app_handle_status = app.handle().clone();
-      tauri::async_runtime::spawn(async move {
-        const FAST_INTERVAL_SECS: u64 = 5;
-        const IDLE_INTERVAL_SECS: u64 = 30;
-
-        let mut interval =
-          tokio::time::interval(tokio::time::Duration::from_secs(FAST_INTERVAL_SECS));
-        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
-        let mut last_running_states: std::collections::HashMap<String, bool> =
-          std::collections::HashMap::new();
-        let mut current_interval_secs = FAST_INTERVAL_SECS;
-
-        loop {
-          interval.tick().await;
-
-          let runner = crate::browser_runner::BrowserRunner::instance();
-          let profiles = match runner.profile_manager.list_profiles() {
-            Ok(p) => p,
-            Err(e) => {
-              log::warn!("Failed to list profiles in status checker: {e}");
-              continue;
-            }
-          };
-
-          // If no profile has a stored PID and we have no previously-known
-          // running states, there's nothing to check — skip the expensive
-          // process scan entirely.
-          let any_has_pid = profiles.iter().any(|p| p.process_id.is_some());
-          let any_was_running = last_running_states.values().any(|&v| v);
-
-          if !any_has_pid && !any_was_running {
-            // Switch to the idle interval to reduce CPU
-            if current_interval_secs != IDLE_INTERVAL_SECS {
-              current_interval_secs = IDLE_INTERVAL_SECS;
-              interval =
-                tokio::time::interval(tokio::time::Duration::from_secs(IDLE_INTERVAL_SECS));
-              interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
-            }
-            continue;
-          }
-
-          // At least one profile might be running — use the fast interval
-          if current_interval_secs != FAST_INTERVAL_SECS {
-            current_interval_secs = FAST_INTERVAL_SECS;
-            interval = tokio::time::interval(tokio::time::Duration::from_secs(FAST_INTERVAL_SECS));
-            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
-          }
-
-          // Only walk profiles that either have a stored PID or that we last
-          // saw as running — for users with hundreds of idle profiles this
-          // turns an O(N) sysinfo scan into an O(running) scan. The Rust
-          // launch path always emits profile-running-changed when a profile
-          // STARTS, so newly-running profiles still get tracked here.
-          let profiles_to_check: Vec<_> = profiles
-            .into_iter()
-            .filter(|p| {
-              p.process_id.is_some()
-                || last_running_states
-                  .get(&p.id.to_string())
-                  .copied()
-                  .unwrap_or(false)
-            })
-            .collect();
-
-          for profile in profiles_to_check {
-            let had_pid = profile.process_id.is_some();
-            // Check browser status and track changes
-            match runner
-              .check_browser_status(app_handle_status.clone(), &profile)
-              .await
-            {
-              Ok(is_running) => {
-                let profile_id = profile.id.to_string();
-                let last_state = last_running_states
-                  .get(&profile_id)
-                  .copied()
-                  .unwrap_or(false);
-
-                // Emit when the running state changed, or when we still had a
-                // stored PID but the browser is gone — the launch path sets the
-                // frontend to "running" immediately, and a missed transition
-                // here leaves the stop button stuck.
-                let should_emit =
-                  last_state != is_running || (!is_running && had_pid);
-
-                if should_emit {
-                  log::debug!(
-                    "Status checker detected change for profile {}: {} -> {}",
-                    profile.name,
-                    last_state,
-                    is_running
-                  );
-
-                  #[derive(serde::Serialize)]
-                  struct RunningChangedPayload {
-                    id: String,
-                    is_running: bool,
-                  }
-
-                  let payload = RunningChangedPayload {
-                    id: profile_id.clone(),
-                    is_running,
-                  };
-
-                  if let Err(e) = events::emit("profile-running-changed", &payload) {
-                    log::warn!("Failed to emit profile running changed event: {e}");
-                  } else {
-                    log::debug!(
-                      "Status checker emitted profile-running-changed event for {}: running={}",
-                      profile.name,
-                      is_running
-                    );
-                  }
-
-                  // Re-encrypt password-protected profiles when the browser
-                  // exits naturally (user closing the window) — the explicit
-                  // kill path in browser_runner.rs handles app-driven stops.
-                  // Must run BEFORE `mark_profile_stopped` because that
-                  // releases any queued sync run, and a sync that picks up
-                  // the on-disk dir before re-encryption finishes uploads
-                  // the previous snapshot (issue: encrypted profiles not
-                  // syncing fresh data).
-                  if !is_running && profile.password_protected {
-                    crate::profile::password::complete_after_quit_and_wait(&profile)
-                      .await;
-                  }
-
-                  // Clear-on-close for natural exits (user closed the window).
-                  // The explicit kill path in browser_runner.rs handles
-                  // app-driven stops. Must also run before
-                  // `mark_profile_stopped` so a queued sync sees the cleared
-                  // dir rather than re-uploading the wiped browsing data.
-                  if !is_running {
-                    crate::profile::clear_on_close::clear_profile_browsing_data(&profile)
-                      .await;
-                  }
-
-                  // Notify sync scheduler of running state changes
-                  if let Some(scheduler) = sync::get_global_scheduler() {
-                    if is_running {
-                      scheduler.mark_profile_running(&profile_id).await;
-                    } else {
-                      // Sync was queued at launch; mark_profile_stopped triggers it
-                      scheduler.mark_profile_stopped(&profile_id).await;
-                    }
-                  }
-
-                  // Release the cloud team lock when the browser exits naturally
-                  // (window closed by the user). The explicit kill path in
-                  // browser_runner.rs already releases it, but this branch did
-                  // not — leaking the lock, which the 30s heartbeat then renews
-                  // indefinitely. No-op for non-sync/non-paid
-                  // profiles thanks to the guards inside the helper.
-                  if !is_running {
-                    crate::team_lock::release_team_lock_if_needed(&profile).await;
-                  }
-
-                  last_running_states.insert(profile_id, is_running);
-                } else {
-                  // Update the state even if unchanged to ensure we have it tracked
-                  last_running_states.insert(profile_id, is_running);
-                }
-              }
-              Err(e) => {
-                log::warn!("Status check failed for profile {}: {}", profile.name, e);
-                continue;
-              }
-            }
-          }
-        }
-      });
+      spawn_browser_status_task(crate::app_handle::AppHandle::from(app.handle()));
 
       // Nodecar warm-up is now triggered from the frontend to allow UI blocking overlay
 
       // Start API server if enabled in settings
-      let app_handle_api = app.handle().clone();
+      let app_handle_api = crate::app_handle::AppHandle::from(app.handle());
       tauri::async_runtime::spawn(async move {
         match crate::settings_manager::get_app_settings(app_handle_api.clone()).await {
           Ok(settings) => {
             if settings.api_enabled {
               log::info!("API is enabled in settings, starting API server...");
-              match crate::api_server::start_api_server_internal(settings.api_port, &app_handle_api)
-                .await
+              match crate::api_server::start_api_server_internal(
+                settings.api_port,
+                &settings.api_listen,
+                &app_handle_api,
+              )
+              .await
               {
-                Ok(port) => {
-                  log::info!("API server started successfully on port {port}");
+                Ok(endpoint) => {
+                  log::info!("API server started successfully on {endpoint}");
                   // Emit success toast to frontend
-                  if let Err(e) = events::emit(
-                    "show-toast",
-                    crate::api_server::ToastPayload {
-                      message: "API server started successfully".to_string(),
-                      variant: "success".to_string(),
-                      title: "Local API Started".to_string(),
-                      description: Some(format!("API server running on port {port}")),
-                    },
-                  ) {
+                  if let Err(e) = events::emit(&crate::api_server::ToastPayload {
+                    message: "API server started successfully".to_string(),
+                    variant: "success".to_string(),
+                    title: "Local API Started".to_string(),
+                    description: Some(format!("API server running on {endpoint}")),
+                  }) {
                     log::error!("Failed to emit API start toast: {e}");
                   }
                 }
                 Err(e) => {
                   log::error!("Failed to start API server at startup: {e}");
                   // Emit error toast to frontend
-                  if let Err(toast_err) = events::emit(
-                    "show-toast",
-                    crate::api_server::ToastPayload {
-                      message: "Failed to start API server".to_string(),
-                      variant: "error".to_string(),
-                      title: "Failed to Start Local API".to_string(),
-                      description: Some(format!("Error: {e}")),
-                    },
-                  ) {
+                  if let Err(toast_err) = events::emit(&crate::api_server::ToastPayload {
+                    message: "Failed to start API server".to_string(),
+                    variant: "error".to_string(),
+                    title: "Failed to Start Local API".to_string(),
+                    description: Some(format!("Error: {e}")),
+                  }) {
                     log::error!("Failed to emit API error toast: {toast_err}");
                   }
                 }
@@ -2182,92 +2864,21 @@ pub fn run_with_builder(
       });
 
       // Start sync subscription and scheduler if configured
-      let app_handle_sync = app.handle().clone();
-      tauri::async_runtime::spawn(async move {
-        use std::sync::Arc;
-
-        let mut subscription_manager = sync::SubscriptionManager::new();
-        let work_rx = subscription_manager.take_work_receiver();
-
-        if let Err(e) = subscription_manager.start(app_handle_sync.clone()).await {
-          log::warn!("Failed to start sync subscription: {e}");
-        }
-
-        if let Some(work_rx) = work_rx {
-          let scheduler = Arc::new(sync::SyncScheduler::new());
-
-          // Set the global scheduler so commands can access it
-          sync::set_global_scheduler(scheduler.clone());
-
-          // Start initial sync for all enabled profiles
-          scheduler.sync_all_enabled_profiles(&app_handle_sync).await;
-
-          // Check for missing synced profiles (deleted locally but exist remotely)
-          match sync::SyncEngine::create_from_settings(&app_handle_sync).await {
-            Ok(engine) => {
-              if let Err(e) = engine
-                .check_for_missing_synced_profiles(&app_handle_sync)
-                .await
-              {
-                log::warn!("Failed to check for missing profiles: {}", e);
-              }
-              if let Err(e) = engine
-                .check_for_missing_synced_entities(&app_handle_sync)
-                .await
-              {
-                log::warn!("Failed to check for missing entities: {}", e);
-              }
-            }
-            Err(e) => {
-              log::warn!("Sync not configured, skipping missing profile check: {}", e);
-            }
-          }
-
-          scheduler
-            .clone()
-            .start(app_handle_sync.clone(), work_rx)
-            .await;
-          log::info!("Sync scheduler started");
-        }
-      });
+      spawn_sync_tasks(crate::app_handle::AppHandle::from(app.handle()));
 
       // Start cloud auth background refresh loop
-      let app_handle_cloud = app.handle().clone();
-      tauri::async_runtime::spawn(async move {
-        // On startup, refresh sync token, proxy config, and wayfern token in
-        // PARALLEL. Previously they were awaited sequentially, so the wayfern
-        // token request didn't even start until the earlier two API calls had
-        // finished. Wayfern launch can race with this task — a few seconds of
-        // serialized API calls translates directly into a slow first launch
-        // because launch_wayfern blocks waiting for the token to land.
-        // api_call_with_retry handles 401/refresh internally — no direct
-        // refresh_access_token call needed.
-        if cloud_auth::CLOUD_AUTH.is_logged_in().await {
-          let sync_token_fut = async {
-            if let Err(e) = cloud_auth::CLOUD_AUTH.get_or_refresh_sync_token().await {
-              log::warn!("Failed to refresh cloud sync token on startup: {e}");
-            }
-          };
-          let proxy_fut = async {
-            cloud_auth::CLOUD_AUTH.sync_cloud_proxy().await;
-          };
-          let wayfern_fut = async {
-            if cloud_auth::CLOUD_AUTH.has_active_paid_subscription().await {
-              if let Err(e) = cloud_auth::CLOUD_AUTH.request_wayfern_token().await {
-                log::warn!("Failed to request wayfern token on startup: {e}");
-              }
-            }
-          };
-          tokio::join!(sync_token_fut, proxy_fut, wayfern_fut);
-        }
-        cloud_auth::CloudAuthManager::start_sync_token_refresh_loop(app_handle_cloud).await;
-      });
+      spawn_cloud_auth_task(crate::app_handle::AppHandle::from(app.handle()));
+
+      // Global shortcuts that launch or focus a profile
+      profile_shortcuts::register_all(app.handle());
 
       Ok(())
     })
-    .invoke_handler(tauri::generate_handler![
+    .invoke_handler(observer_mode::guard(tauri::generate_handler![
       confirm_quit,
       hide_to_tray,
+      pending_urls::frontend_ready,
+      open_detached_profile_window,
       update_tray_menu,
       get_supported_browsers,
       is_browser_supported_on_platform,
@@ -2279,6 +2890,7 @@ pub fn run_with_builder(
       create_browser_profile_new,
       list_browser_profiles,
       launch_browser_profile,
+      get_profile_cdp_endpoint,
       fetch_browser_versions_with_count,
       fetch_browser_versions_cached_first,
       fetch_browser_versions_with_count_cached_first,
@@ -2286,13 +2898,34 @@ pub fn run_with_builder(
       get_all_tags,
       get_browser_release_types,
       update_profile_proxy,
+      update_profile_proxy_pool,
       update_profile_vpn,
       update_profile_tags,
+      rename_tag,
+      merge_tags,
       update_profile_note,
       update_profile_clear_on_close,
+      profile::disk_usage::get_profile_disk_usage,
+      profile::disk_usage::clear_profile_cache,
+      profile::snapshots::create_profile_snapshot,
+      profile::snapshots::list_profile_snapshots,
+      profile::snapshots::restore_profile_snapshot,
+      profile::archive::archive_profile,
+      profile::archive::unarchive_profile,
+      profile::refresh::refresh_profile,
       update_profile_launch_hook,
       update_profile_window_color,
       update_profile_proxy_bypass_rules,
+      update_profile_startup,
+      update_profile_preferences,
+      update_profile_metadata,
+      pin_profile_version,
+      unpin_profile_version,
+      update_profile_restart_policy,
+      update_profile_dns_mode,
+      update_profile_exit_mismatch_action,
+      update_profile_resource_limits,
+      update_profile_webrtc_mode,
       update_profile_dns_blocklist,
       check_browser_status,
       kill_browser_profile,
@@ -2301,8 +2934,15 @@ pub fn run_with_builder(
       save_app_settings,
       read_log_files,
       open_log_directory,
+      log_buffer::get_recent_logs,
+      audit::get_audit_log,
+      offline::set_offline_mode,
       get_table_sorting_settings,
       save_table_sorting_settings,
+      list_saved_views,
+      create_saved_view,
+      update_saved_view,
+      delete_saved_view,
       get_system_language,
       get_system_info,
       dismiss_window_resize_warning,
@@ -2318,29 +2958,53 @@ pub fn run_with_builder(
       check_for_browser_updates,
       dismiss_update_notification,
       complete_browser_update_with_auto_update,
+      rollback_browser_update,
       check_for_app_updates,
       check_for_app_updates_manual,
       download_and_prepare_app_update,
       restart_application,
       detect_existing_profiles,
       import_browser_profiles,
+      import_external_profiles,
+      workspace::export_workspace,
+      workspace::import_workspace,
+      workspaces::list_workspaces,
+      workspaces::create_workspace,
+      workspaces::switch_workspace,
       scan_folder_for_profiles,
       scan_profile_archive,
       cleanup_profile_import_scratch,
       check_missing_binaries,
       check_missing_geoip_database,
       ensure_all_binaries_exist,
+      cancel_ensure_binaries,
+      verify_downloaded_browsers,
       ensure_active_browsers_downloaded,
+      binaries_usage::list_downloaded_browsers,
+      binaries_usage::delete_downloaded_browser,
+      binaries_usage::get_binaries_disk_usage,
       create_stored_proxy,
       get_stored_proxies,
       update_stored_proxy,
+      update_stored_proxy_exit_expectation,
       delete_stored_proxy,
+      get_proxy_pools,
+      create_proxy_pool,
+      update_proxy_pool,
+      delete_proxy_pool,
       check_proxy_validity,
       get_cached_proxy_check,
       export_proxies,
       import_proxies_json,
       parse_txt_proxies,
       import_proxies_from_parsed,
+      proxy_sources::list_proxy_sources,
+      proxy_sources::add_proxy_source,
+      proxy_sources::refresh_proxy_source,
+      proxy_sources::remove_proxy_source,
+      entity_usage::get_proxy_usage,
+      entity_usage::get_vpn_usage,
+      entity_usage::get_group_usage,
       update_wayfern_config,
       generate_sample_fingerprint,
       get_profile_groups,
@@ -2355,6 +3019,9 @@ pub fn run_with_builder(
       add_extension,
       update_extension,
       delete_extension,
+      add_unpacked_extension,
+      set_extension_source,
+      check_extension_updates,
       list_extension_groups,
       create_extension_group,
       update_extension_group,
@@ -2368,6 +3035,10 @@ pub fn run_with_builder(
       start_api_server,
       stop_api_server,
       get_api_server_status,
+      get_api_endpoint,
+      list_api_tokens,
+      generate_api_token,
+      revoke_api_token,
       get_all_traffic_snapshots,
       get_profile_traffic_snapshot,
       clear_all_traffic_stats,
@@ -2375,11 +3046,20 @@ pub fn run_with_builder(
       get_traffic_stats_for_period,
       fingerprint_consistency::check_profile_fingerprint_consistency,
       fingerprint_consistency::match_profile_fingerprint_to_exit,
+      fingerprint_quality::validate_profile_fingerprint,
+      wayfern_manager::generate_fingerprint,
       get_sync_settings,
       save_sync_settings,
       set_profile_sync_mode,
       cancel_profile_sync,
       request_profile_sync,
+      preview_profile_sync,
+      launch_queue::get_launch_queue,
+      launch_queue::cancel_queued_launch,
+      launch_timings::get_last_launch_timings,
+      profile::exit_history::get_profile_exit_history,
+      orphan_cleanup::cleanup_orphan_processes,
+      preflight::preflight_profile_launch,
       set_proxy_sync_enabled,
       set_group_sync_enabled,
       is_proxy_in_use_by_synced_profile,
@@ -2389,10 +3069,14 @@ pub fn run_with_builder(
       set_extension_sync_enabled,
       set_extension_group_sync_enabled,
       get_unsynced_entity_counts,
+      get_sync_queue_status,
+      get_sync_subscription_status,
       enable_sync_for_all_entities,
       set_e2e_password,
       check_has_e2e_password,
       verify_e2e_password,
+      change_e2e_password,
+      check_e2e_rotation_pending,
       delete_e2e_password,
       rollover_encryption_for_all_entities,
       read_profile_cookies,
@@ -2420,6 +3104,8 @@ pub fn run_with_builder(
       delete_vpn_config,
       create_vpn_config_manual,
       update_vpn_config,
+      update_vpn_auto_disconnect,
+      update_vpn_exit_expectation,
       check_vpn_validity,
       connect_vpn,
       disconnect_vpn,
@@ -2462,10 +3148,17 @@ pub fn run_with_builder(
       unlock_profile,
       lock_profile,
       is_profile_locked,
-    ])
+      observer_mode::get_observer_mode,
+      profile_shortcuts::set_profile_shortcut,
+    ]))
     .build(tauri::generate_context!())
     .expect("error while building tauri application")
     .run(|_app_handle, _event| {
+      // Parked browsers have no windows, so nothing would ever stop them
+      // once the app is gone.
+      if let tauri::RunEvent::Exit = _event {
+        tauri::async_runtime::block_on(warm_pool::evict_all());
+      }
       #[cfg(target_os = "macos")]
       if let tauri::RunEvent::Reopen { .. } = _event {
         if let Some(window) = _app_handle.get_webview_window("main") {
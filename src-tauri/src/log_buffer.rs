@@ -0,0 +1,200 @@
+//! In-memory copy of the most recent log records, filled from the log
+//! formatter (the log plugin's in the GUI, env_logger's in the daemon). The
+//! API and the settings panel read recent logs from here instead of parsing
+//! the rotated log files. Messages are redacted on the way out, like the
+//! copied log bundle.
+
+use std::collections::VecDeque;
+use std::str::FromStr;
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Records kept in memory; the oldest are dropped first.
+const CAPACITY: usize = 5000;
+const DEFAULT_LIMIT: usize = 200;
+/// Upper bound on records returned by a single query.
+pub const MAX_LIMIT: usize = 1000;
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct LogRecord {
+  /// Epoch milliseconds.
+  pub timestamp: u64,
+  pub level: String,
+  pub target: String,
+  pub message: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct LogQuery {
+  /// Minimum severity: `error`, `warn`, `info`, `debug` or `trace`.
+  pub level: Option<String>,
+  /// Only records whose target starts with this, e.g. `donutbrowser_lib::sync`.
+  pub module: Option<String>,
+  /// Only records at or after this epoch-milliseconds timestamp.
+  pub since: Option<u64>,
+  /// Most recent matching records to return; capped at [`MAX_LIMIT`].
+  pub limit: Option<usize>,
+}
+
+static BUFFER: Mutex<VecDeque<LogRecord>> = Mutex::new(VecDeque::new());
+
+/// Called from the log formatter for every record that passes the level
+/// filter. Must never log itself.
+pub fn push(record: &log::Record) {
+  let entry = LogRecord {
+    timestamp: chrono::Utc::now().timestamp_millis().max(0) as u64,
+    level: record.level().to_string(),
+    target: record.target().to_string(),
+    message: record.args().to_string(),
+  };
+  let Ok(mut buffer) = BUFFER.lock() else {
+    return;
+  };
+  if buffer.len() == CAPACITY {
+    buffer.pop_front();
+  }
+  buffer.push_back(entry);
+}
+
+/// Matching records, oldest first.
+pub fn query(query: &LogQuery) -> Result<Vec<LogRecord>, String> {
+  let min_level = match query.level.as_deref() {
+    Some(level) => {
+      Some(log::Level::from_str(level).map_err(|_| format!("Invalid log level: {level}"))?)
+    }
+    None => None,
+  };
+  let limit = query.limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT);
+
+  let buffer = BUFFER
+    .lock()
+    .map_err(|_| "Log buffer unavailable".to_string())?;
+  let mut records: Vec<LogRecord> = buffer
+    .iter()
+    .rev()
+    .filter(|r| query.since.is_none_or(|since| r.timestamp >= since))
+    .filter(|r| {
+      query
+        .module
+        .as_deref()
+        .is_none_or(|prefix| r.target.starts_with(prefix))
+    })
+    .filter(|r| {
+      min_level.is_none_or(|min| log::Level::from_str(&r.level).is_ok_and(|level| level <= min))
+    })
+    .take(limit)
+    .map(|r| LogRecord {
+      message: crate::log_redaction::text(&r.message),
+      ..r.clone()
+    })
+    .collect();
+  records.reverse();
+  Ok(records)
+}
+
+#[tauri::command]
+pub fn get_recent_logs(
+  level: Option<String>,
+  module: Option<String>,
+  since: Option<u64>,
+  limit: Option<usize>,
+) -> Result<Vec<LogRecord>, String> {
+  query(&LogQuery {
+    level,
+    module,
+    since,
+    limit,
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // The buffer is process-wide, so each test logs under its own target and
+  // filters on it.
+  fn log_as(target: &str, level: log::Level, message: &str) {
+    push(
+      &log::Record::builder()
+        .target(target)
+        .level(level)
+        .args(format_args!("{message}"))
+        .build(),
+    );
+  }
+
+  fn for_module(module: &str) -> LogQuery {
+    LogQuery {
+      module: Some(module.to_string()),
+      ..Default::default()
+    }
+  }
+
+  #[test]
+  fn filters_by_minimum_level() {
+    let target = "log_buffer_test::level";
+    log_as(target, log::Level::Info, "info");
+    log_as(target, log::Level::Warn, "warn");
+    log_as(target, log::Level::Error, "error");
+
+    let records = query(&LogQuery {
+      level: Some("WARN".to_string()),
+      ..for_module(target)
+    })
+    .unwrap();
+    let messages: Vec<&str> = records.iter().map(|r| r.message.as_str()).collect();
+    assert_eq!(messages, ["warn", "error"]);
+  }
+
+  #[test]
+  fn module_prefix_and_since_narrow_results() {
+    log_as("log_buffer_test::sync::engine", log::Level::Info, "old");
+    let since = chrono::Utc::now().timestamp_millis() as u64 + 1;
+    std::thread::sleep(std::time::Duration::from_millis(5));
+    log_as("log_buffer_test::sync::engine", log::Level::Info, "new");
+    log_as("log_buffer_test::proxy", log::Level::Info, "other");
+
+    let records = query(&LogQuery {
+      since: Some(since),
+      ..for_module("log_buffer_test::sync")
+    })
+    .unwrap();
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].message, "new");
+  }
+
+  #[test]
+  fn returns_most_recent_records_oldest_first() {
+    let target = "log_buffer_test::limit";
+    for i in 0..5 {
+      log_as(target, log::Level::Info, &i.to_string());
+    }
+    let records = query(&LogQuery {
+      limit: Some(2),
+      ..for_module(target)
+    })
+    .unwrap();
+    let messages: Vec<&str> = records.iter().map(|r| r.message.as_str()).collect();
+    assert_eq!(messages, ["3", "4"]);
+  }
+
+  #[test]
+  fn redacts_messages() {
+    let target = "log_buffer_test::redact";
+    log_as(target, log::Level::Info, "proxy password=hunter2 failed");
+    let records = query(&for_module(target)).unwrap();
+    assert!(!records[0].message.contains("hunter2"));
+  }
+
+  #[test]
+  fn rejects_unknown_level() {
+    let err = query(&LogQuery {
+      level: Some("loud".to_string()),
+      ..Default::default()
+    })
+    .unwrap_err();
+    assert!(err.contains("loud"));
+  }
+}
@@ -0,0 +1,200 @@
+//! Minimal JSON Schema check for MCP tool arguments. Covers what the tool
+//! schemas in `mcp_server` actually use: `type` (single or a list),
+//! `required`, `properties`, `items` and `enum`. Anything else in a schema is
+//! ignored rather than rejected.
+
+use serde_json::Value;
+
+/// Every way `value` breaks `schema`, one message per violation, each naming
+/// the offending field by path (`items[0].source_path`). Empty when valid.
+///
+/// An optional property set to `null` counts as absent: clients commonly send
+/// `null` for "not provided", and the handlers read it that way too.
+pub fn validate(schema: &Value, value: &Value) -> Vec<String> {
+  let mut violations = Vec::new();
+  check(schema, value, "", &mut violations);
+  violations
+}
+
+fn check(schema: &Value, value: &Value, path: &str, violations: &mut Vec<String>) {
+  if let Some(expected) = schema.get("type") {
+    let types: Vec<&str> = match expected {
+      Value::String(t) => vec![t.as_str()],
+      Value::Array(ts) => ts.iter().filter_map(Value::as_str).collect(),
+      _ => Vec::new(),
+    };
+    if !types.is_empty() && !types.iter().any(|t| matches_type(t, value)) {
+      violations.push(format!(
+        "{} must be {}, got {}",
+        describe(path),
+        types.join(" or "),
+        type_name(value)
+      ));
+      return;
+    }
+  }
+
+  if let Some(allowed) = schema.get("enum").and_then(Value::as_array) {
+    if !allowed.contains(value) {
+      let options: Vec<String> = allowed.iter().map(Value::to_string).collect();
+      violations.push(format!(
+        "{} must be one of {}",
+        describe(path),
+        options.join(", ")
+      ));
+    }
+  }
+
+  if let Value::Object(fields) = value {
+    let required: Vec<&str> = schema
+      .get("required")
+      .and_then(Value::as_array)
+      .map(|r| r.iter().filter_map(Value::as_str).collect())
+      .unwrap_or_default();
+    for name in &required {
+      if fields.get(*name).is_none_or(Value::is_null) {
+        violations.push(format!("{} is required", describe(&join(path, name))));
+      }
+    }
+    if let Some(properties) = schema.get("properties").and_then(Value::as_object) {
+      for (name, field) in fields {
+        let Some(field_schema) = properties.get(name) else {
+          continue;
+        };
+        // A null required field was already reported as missing above.
+        if field.is_null() {
+          continue;
+        }
+        check(field_schema, field, &join(path, name), violations);
+      }
+    }
+  }
+
+  if let (Value::Array(elements), Some(item_schema)) = (value, schema.get("items")) {
+    for (i, element) in elements.iter().enumerate() {
+      check(item_schema, element, &format!("{path}[{i}]"), violations);
+    }
+  }
+}
+
+fn matches_type(expected: &str, value: &Value) -> bool {
+  match expected {
+    "object" => value.is_object(),
+    "array" => value.is_array(),
+    "string" => value.is_string(),
+    "boolean" => value.is_boolean(),
+    "number" => value.is_number(),
+    "integer" => value.is_i64() || value.is_u64(),
+    "null" => value.is_null(),
+    _ => true,
+  }
+}
+
+fn type_name(value: &Value) -> &'static str {
+  match value {
+    Value::Null => "null",
+    Value::Bool(_) => "boolean",
+    Value::Number(n) if n.is_f64() => "number",
+    Value::Number(_) => "integer",
+    Value::String(_) => "string",
+    Value::Array(_) => "array",
+    Value::Object(_) => "object",
+  }
+}
+
+fn join(path: &str, name: &str) -> String {
+  if path.is_empty() {
+    name.to_string()
+  } else {
+    format!("{path}.{name}")
+  }
+}
+
+fn describe(path: &str) -> String {
+  if path.is_empty() {
+    "arguments".to_string()
+  } else {
+    format!("`{path}`")
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use serde_json::json;
+
+  fn schema() -> Value {
+    json!({
+      "type": "object",
+      "properties": {
+        "name": { "type": "string" },
+        "port": { "type": "integer" },
+        "format": { "type": "string", "enum": ["json", "txt"] },
+        "items": {
+          "type": "array",
+          "items": {
+            "type": "object",
+            "properties": { "path": { "type": "string" } },
+            "required": ["path"]
+          }
+        }
+      },
+      "required": ["name"]
+    })
+  }
+
+  #[test]
+  fn accepts_valid_arguments_and_null_optionals() {
+    let args = json!({
+      "name": "a",
+      "port": 8080,
+      "format": "txt",
+      "items": [{ "path": "/tmp" }],
+      "unknown": true
+    });
+    assert!(validate(&schema(), &args).is_empty());
+    assert!(validate(&schema(), &json!({ "name": "a", "port": null })).is_empty());
+  }
+
+  #[test]
+  fn reports_every_violation_by_path() {
+    let args = json!({
+      "port": "8080",
+      "format": "xml",
+      "items": [{ "path": 1 }, {}]
+    });
+    // Field order follows the map's iteration order, so compare sorted.
+    let mut violations = validate(&schema(), &args);
+    violations.sort();
+    assert_eq!(
+      violations,
+      vec![
+        "`format` must be one of \"json\", \"txt\"",
+        "`items[0].path` must be string, got integer",
+        "`items[1].path` is required",
+        "`name` is required",
+        "`port` must be integer, got string",
+      ]
+    );
+  }
+
+  #[test]
+  fn rejects_non_object_arguments_and_fractional_integers() {
+    assert_eq!(
+      validate(&schema(), &json!("name")),
+      vec!["arguments must be object, got string"]
+    );
+    assert_eq!(
+      validate(&schema(), &json!({ "name": "a", "port": 1.5 })),
+      vec!["`port` must be integer, got number"]
+    );
+  }
+
+  #[test]
+  fn required_null_is_missing() {
+    assert_eq!(
+      validate(&schema(), &json!({ "name": null })),
+      vec!["`name` is required"]
+    );
+  }
+}
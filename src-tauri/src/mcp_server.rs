@@ -1,9 +1,13 @@
+use crate::app_handle::AppHandle;
 use axum::{
   body::Body,
   extract::State,
   http::{header, Request, StatusCode},
   middleware::{self, Next},
-  response::{IntoResponse, Response},
+  response::{
+    sse::{Event, KeepAlive, Sse},
+    IntoResponse, Response,
+  },
   routing::{get, post},
   Json, Router,
 };
@@ -12,7 +16,6 @@ use std::collections::HashMap;
 use std::net::SocketAddr;
 use std::sync::atomic::{AtomicBool, AtomicU16, Ordering};
 use std::sync::Arc;
-use tauri::AppHandle;
 use tokio::net::TcpListener;
 use tokio::sync::Mutex as AsyncMutex;
 use uuid::Uuid;
@@ -106,11 +109,29 @@ pub struct McpError {
 }
 
 const DEFAULT_MCP_PORT: u16 = 51080;
+/// How long `create_profile` with `auto_download` waits for a browser
+/// download by default, and at most.
+const DEFAULT_DOWNLOAD_WAIT_SECS: u64 = 600;
+const MAX_DOWNLOAD_WAIT_SECS: u64 = 1800;
 
 struct McpSession {
   initialized: bool,
+  /// Server-to-client messages for the session's open GET (SSE) stream, if
+  /// the client opened one. A newer stream replaces an older one.
+  notifier: Option<tokio::sync::mpsc::UnboundedSender<String>>,
 }
 
+/// Internal events that change what `resources/list` returns.
+const RESOURCE_EVENTS: &[&str] = &[
+  "profiles-changed",
+  "proxies-changed",
+  "stored-proxies-changed",
+];
+
+/// How long to wait for a burst of change events (a bulk import fires one per
+/// item) to settle before telling clients the resource list changed.
+const RESOURCE_CHANGE_DEBOUNCE: std::time::Duration = std::time::Duration::from_millis(200);
+
 struct McpServerInner {
   app_handle: Option<AppHandle>,
   token: Option<String>,
@@ -127,6 +148,8 @@ struct McpHttpState {
 pub struct McpServer {
   inner: Arc<AsyncMutex<McpServerInner>>,
   is_running: AtomicBool,
+  /// A stdio client is being served (see [`McpServer::run_stdio`]).
+  stdio_active: AtomicBool,
   port: AtomicU16,
 }
 
@@ -140,6 +163,7 @@ impl McpServer {
         sessions: HashMap::new(),
       })),
       is_running: AtomicBool::new(false),
+      stdio_active: AtomicBool::new(false),
       port: AtomicU16::new(0),
     }
   }
@@ -152,6 +176,11 @@ impl McpServer {
     self.is_running.load(Ordering::SeqCst)
   }
 
+  /// Whether requests should be served, over HTTP or stdio.
+  fn is_serving(&self) -> bool {
+    self.is_running() || self.stdio_active.load(Ordering::SeqCst)
+  }
+
   /// Gate an MCP tool on a capability the caller already resolved (e.g.
   /// `CLOUD_AUTH.can_use_browser_automation().await`). Logs the rejected gate
   /// with enough state for support to diagnose, without leaking secrets.
@@ -272,6 +301,7 @@ impl McpServer {
     state: McpHttpState,
     shutdown_rx: tokio::sync::oneshot::Receiver<()>,
   ) {
+    let server_instance = state.server;
     let app = Router::new()
       .route(
         "/mcp/{token}",
@@ -313,6 +343,7 @@ impl McpServer {
 
     tokio::select! {
       _ = server => {},
+      _ = Self::forward_resource_changes(server_instance) => {},
       _ = shutdown_rx => {
         log::info!("[mcp] Server shutting down");
       },
@@ -377,12 +408,86 @@ impl McpServer {
       "server": SERVER_NAME,
       "version": SERVER_VERSION,
       "protocolVersion": PROTOCOL_VERSION,
+      "auditWriteFailures": crate::audit::write_failures(),
+      "offline": crate::offline::is_offline(),
     }))
   }
 
-  async fn handle_mcp_get() -> impl IntoResponse {
-    // We don't support server-initiated SSE streams
-    StatusCode::METHOD_NOT_ALLOWED
+  /// Server-initiated SSE stream for an initialized session. Only carries
+  /// `notifications/resources/list_changed`.
+  async fn handle_mcp_get(State(state): State<McpHttpState>, req: Request<Body>) -> Response {
+    let Some(session_id) = req
+      .headers()
+      .get("mcp-session-id")
+      .and_then(|h| h.to_str().ok())
+    else {
+      return (StatusCode::BAD_REQUEST, "Missing mcp-session-id").into_response();
+    };
+
+    let (tx, rx) = tokio::sync::mpsc::unbounded_channel::<String>();
+    {
+      let mut inner = state.server.inner.lock().await;
+      let Some(session) = inner.sessions.get_mut(session_id) else {
+        return StatusCode::NOT_FOUND.into_response();
+      };
+      session.notifier = Some(tx);
+    }
+    log::debug!("[mcp] Notification stream opened for session {session_id}");
+
+    let stream = futures_util::stream::unfold(rx, |mut rx| async move {
+      let message = rx.recv().await?;
+      Some((
+        Ok::<_, std::convert::Infallible>(Event::default().event("message").data(message)),
+        rx,
+      ))
+    });
+    Sse::new(stream)
+      .keep_alive(KeepAlive::default())
+      .into_response()
+  }
+
+  /// Relay internal change events to clients as
+  /// `notifications/resources/list_changed`, coalescing bursts.
+  async fn forward_resource_changes(server: &'static McpServer) {
+    use tokio::sync::broadcast::error::{RecvError, TryRecvError};
+
+    let mut rx = crate::events::subscribe_local();
+    loop {
+      match rx.recv().await {
+        Ok(event) if RESOURCE_EVENTS.contains(&event.event.as_str()) => {}
+        Ok(_) => continue,
+        // Missed events may have included changes; notify to be safe.
+        Err(RecvError::Lagged(_)) => {}
+        Err(RecvError::Closed) => return,
+      }
+      tokio::time::sleep(RESOURCE_CHANGE_DEBOUNCE).await;
+      loop {
+        match rx.try_recv() {
+          Ok(_) | Err(TryRecvError::Lagged(_)) => continue,
+          Err(_) => break,
+        }
+      }
+      server
+        .notify_sessions("notifications/resources/list_changed")
+        .await;
+    }
+  }
+
+  /// Send a parameterless notification to every initialized session with an
+  /// open stream. Streams the client has closed are forgotten.
+  async fn notify_sessions(&self, method: &str) {
+    let message = serde_json::json!({ "jsonrpc": "2.0", "method": method }).to_string();
+    let mut inner = self.inner.lock().await;
+    for session in inner.sessions.values_mut() {
+      if !session.initialized {
+        continue;
+      }
+      if let Some(notifier) = &session.notifier {
+        if notifier.send(message.clone()).is_err() {
+          session.notifier = None;
+        }
+      }
+    }
   }
 
   async fn handle_mcp_delete(
@@ -487,7 +592,10 @@ impl McpServer {
     }
 
     let mut inner = self.inner.lock().await;
-    inner.app_handle = None;
+    // A stdio client in this process keeps using the handle.
+    if !self.stdio_active.load(Ordering::SeqCst) {
+      inner.app_handle = None;
+    }
     inner.token = None;
     inner.sessions.clear();
 
@@ -604,19 +712,58 @@ impl McpServer {
       },
       McpTool {
         name: "create_profile".to_string(),
-        description: "Create a new browser profile".to_string(),
+        description: "Create a new browser profile and return it. If the browser version isn't downloaded yet its download is started; the call then fails with a retry-later error unless auto_download is set, in which case it waits for the download".to_string(),
         input_schema: serde_json::json!({
           "type": "object",
           "properties": {
             "name": {
               "type": "string",
-              "description": "Name for the new profile"
+              "description": "Name for the new profile; must be unique (case-insensitive)"
             },
             "browser": {
               "type": "string",
               "enum": ["wayfern"],
               "description": "Browser engine to use"
             },
+            "version": {
+              "type": "string",
+              "description": "Browser version. Defaults to the newest downloaded version, or the newest available one when none is downloaded"
+            },
+            "auto_download": {
+              "type": "boolean",
+              "description": "Wait for a missing browser version to download instead of failing right away (default false)"
+            },
+            "download_timeout_secs": {
+              "type": "integer",
+              "minimum": 1,
+              "maximum": 1800,
+              "description": "How long auto_download waits before giving up, in seconds (default 600). The download keeps running afterwards"
+            },
+            "os": {
+              "type": "string",
+              "enum": ["windows", "macos", "linux"],
+              "description": "Operating system for fingerprint generation (default: this machine's)"
+            },
+            "screen_min_width": {
+              "type": "integer",
+              "minimum": 1,
+              "description": "Smallest screen width the fingerprint may use, in pixels"
+            },
+            "screen_min_height": {
+              "type": "integer",
+              "minimum": 1,
+              "description": "Smallest screen height the fingerprint may use, in pixels"
+            },
+            "screen_max_width": {
+              "type": "integer",
+              "minimum": 1,
+              "description": "Largest screen width the fingerprint may use, in pixels"
+            },
+            "screen_max_height": {
+              "type": "integer",
+              "minimum": 1,
+              "description": "Largest screen height the fingerprint may use, in pixels"
+            },
             "proxy_id": {
               "type": "string",
               "description": "Optional proxy UUID to assign"
@@ -640,7 +787,7 @@ impl McpServer {
       },
       McpTool {
         name: "detect_browser_profiles".to_string(),
-        description: "Detect importable Chromium-family browser profiles (Chrome, Chromium, Brave) on this machine, or scan a custom folder for profile directories".to_string(),
+        description: "Detect importable Chromium-family browser profiles (Chrome, Chromium, Brave) on this machine, or scan a custom folder for profile directories. Each profile lists its size, the data categories it holds and any that are bound to this machine".to_string(),
         input_schema: serde_json::json!({
           "type": "object",
           "properties": {
@@ -677,6 +824,14 @@ impl McpServer {
                   "vpn_id": {
                     "type": "string",
                     "description": "Optional VPN UUID to assign to this profile"
+                  },
+                  "categories": {
+                    "type": "array",
+                    "items": {
+                      "type": "string",
+                      "enum": ["cookies", "logins", "bookmarks", "history", "extensions"]
+                    },
+                    "description": "Copy only these kinds of data into a fresh profile (default: copy the whole profile folder). Saved passwords bound to the source machine's keychain are always left out"
                   }
                 },
                 "required": ["source_path", "new_profile_name"]
@@ -746,13 +901,17 @@ impl McpServer {
       },
       McpTool {
         name: "delete_profile".to_string(),
-        description: "Delete a browser profile and all its data".to_string(),
+        description: "Delete a browser profile and all its data. Refuses profiles that are running, synced or in a protected group unless force is set".to_string(),
         input_schema: serde_json::json!({
           "type": "object",
           "properties": {
             "profile_id": {
               "type": "string",
               "description": "The UUID of the profile to delete"
+            },
+            "force": {
+              "type": "boolean",
+              "description": "Stop the browser and its local proxy, delete the synced copy and delete the profile even if its group is protected (default: false)"
             }
           },
           "required": ["profile_id"]
@@ -760,13 +919,38 @@ impl McpServer {
       },
       McpTool {
         name: "list_tags".to_string(),
-        description: "List all tags used across profiles".to_string(),
+        description: "List all tags used across profiles, with how many profiles carry each"
+          .to_string(),
         input_schema: serde_json::json!({
           "type": "object",
           "properties": {},
           "required": []
         }),
       },
+      McpTool {
+        name: "update_profile_tags".to_string(),
+        description: "Set or add tags on a profile".to_string(),
+        input_schema: serde_json::json!({
+          "type": "object",
+          "properties": {
+            "profile_id": {
+              "type": "string",
+              "description": "The UUID of the profile to tag"
+            },
+            "tags": {
+              "type": "array",
+              "items": { "type": "string" },
+              "description": "Tags to apply"
+            },
+            "mode": {
+              "type": "string",
+              "enum": ["replace", "merge"],
+              "description": "replace swaps the profile's tags for these; merge adds them to the existing ones (default: replace)"
+            }
+          },
+          "required": ["profile_id", "tags"]
+        }),
+      },
       McpTool {
         name: "list_proxies".to_string(),
         description: "List all configured proxies".to_string(),
@@ -790,6 +974,20 @@ impl McpServer {
           "required": ["profile_id"]
         }),
       },
+      McpTool {
+        name: "preview_profile_sync".to_string(),
+        description: "Report how many files and bytes syncing a profile would upload, download and delete, without transferring anything".to_string(),
+        input_schema: serde_json::json!({
+          "type": "object",
+          "properties": {
+            "profile_id": {
+              "type": "string",
+              "description": "The UUID of the profile to preview"
+            }
+          },
+          "required": ["profile_id"]
+        }),
+      },
       // Group management tools
       McpTool {
         name: "list_groups".to_string(),
@@ -879,6 +1077,25 @@ impl McpServer {
           "required": ["profile_ids"]
         }),
       },
+      McpTool {
+        name: "batch_assign_group".to_string(),
+        description: "Move several profiles into a group at once. Every profile and the group are checked before anything is changed".to_string(),
+        input_schema: serde_json::json!({
+          "type": "object",
+          "properties": {
+            "profile_ids": {
+              "type": "array",
+              "items": { "type": "string" },
+              "description": "Array of profile UUIDs to move"
+            },
+            "group_id": {
+              "type": "string",
+              "description": "The UUID of the target group (empty string to remove from their group)"
+            }
+          },
+          "required": ["profile_ids", "group_id"]
+        }),
+      },
       // Full proxy management tools
       McpTool {
         name: "get_proxy".to_string(),
@@ -924,6 +1141,14 @@ impl McpServer {
             "password": {
               "type": "string",
               "description": "Optional password for authentication (for regular proxies)"
+            },
+            "client_cert_path": {
+              "type": "string",
+              "description": "Path to a PEM client certificate presented to an https proxy. Requires client_key_path"
+            },
+            "client_key_path": {
+              "type": "string",
+              "description": "Path to the unencrypted PKCS#8 PEM private key for client_cert_path"
             }
           },
           "required": ["name", "proxy_type", "host", "port"]
@@ -963,6 +1188,14 @@ impl McpServer {
             "password": {
               "type": "string",
               "description": "Optional password for authentication (for regular proxies)"
+            },
+            "client_cert_path": {
+              "type": "string",
+              "description": "Path to a PEM client certificate presented to an https proxy. Requires client_key_path"
+            },
+            "client_key_path": {
+              "type": "string",
+              "description": "Path to the unencrypted PKCS#8 PEM private key for client_cert_path"
             }
           },
           "required": ["proxy_id"]
@@ -977,6 +1210,10 @@ impl McpServer {
             "proxy_id": {
               "type": "string",
               "description": "The UUID of the proxy to delete"
+            },
+            "force": {
+              "type": "boolean",
+              "description": "Delete even if profiles use the proxy; they are left without one"
             }
           },
           "required": ["proxy_id"]
@@ -1096,7 +1333,7 @@ impl McpServer {
       },
       McpTool {
         name: "get_vpn_status".to_string(),
-        description: "Get the connection status of a VPN".to_string(),
+        description: "Get the connection status of a VPN, with bytes sent/received and the last handshake time while connected".to_string(),
         input_schema: serde_json::json!({
           "type": "object",
           "properties": {
@@ -1148,6 +1385,18 @@ impl McpServer {
             "randomize_fingerprint_on_launch": {
               "type": "boolean",
               "description": "Whether to generate a new fingerprint on every launch"
+            },
+            "locale": {
+              "type": "string",
+              "description": "Locale to pin regardless of the exit country, e.g. \"de-DE\" or an ordered list \"de-DE, en-US\". An empty string clears it"
+            },
+            "timezone": {
+              "type": "string",
+              "description": "IANA timezone to pin regardless of the exit IP, e.g. \"Asia/Tokyo\". An empty string clears it"
+            },
+            "force_locale": {
+              "type": "boolean",
+              "description": "Rewrite the stored fingerprint's languages when they disagree with the locale instead of failing"
             }
           },
           "required": ["profile_id"]
@@ -1630,33 +1879,160 @@ impl McpServer {
     let session_id = Uuid::new_v4().to_string();
     {
       let mut inner = self.inner.lock().await;
-      inner
-        .sessions
-        .insert(session_id.clone(), McpSession { initialized: false });
+      inner.sessions.insert(
+        session_id.clone(),
+        McpSession {
+          initialized: false,
+          notifier: None,
+        },
+      );
     }
 
-    let result = serde_json::json!({
+    log::info!("[mcp] New session initialized: {}", session_id);
+    Ok((session_id, (id, Self::initialize_result())))
+  }
+
+  fn initialize_result() -> serde_json::Value {
+    serde_json::json!({
       "protocolVersion": PROTOCOL_VERSION,
       "capabilities": {
         "tools": {
           "listChanged": false
+        },
+        "resources": {
+          "subscribe": false,
+          "listChanged": true
         }
       },
       "serverInfo": {
         "name": SERVER_NAME,
         "version": SERVER_VERSION,
       },
-      "instructions": "Donut Browser MCP server. Use tools/list to discover available browser automation tools."
-    });
+      "instructions": "Donut Browser MCP server. Use tools/list to discover available browser automation tools, and resources/list for profiles and proxies as JSON."
+    })
+  }
 
-    log::info!("[mcp] New session initialized: {}", session_id);
-    Ok((session_id, (id, result)))
+  /// Serve MCP over stdin/stdout for clients that only speak stdio. There is
+  /// no token to check on this transport, so it additionally requires the
+  /// `mcp_stdio_enabled` setting. Returns when stdin closes.
+  pub async fn run_stdio(&self, app_handle: AppHandle) -> Result<(), String> {
+    if !WayfernTermsManager::instance().is_terms_accepted() {
+      return Err(
+        "Wayfern Terms and Conditions must be accepted before starting MCP server".to_string(),
+      );
+    }
+    let enabled = SettingsManager::instance()
+      .load_settings()
+      .map(|s| s.mcp_stdio_enabled)
+      .unwrap_or(false);
+    if !enabled {
+      return Err("MCP over stdio is disabled. Enable it in Integrations > MCP first.".to_string());
+    }
+
+    log::info!("[mcp] Serving MCP over stdio");
+    self
+      .serve_stdio(app_handle, tokio::io::stdin(), tokio::io::stdout())
+      .await
+  }
+
+  /// Line-delimited JSON-RPC over any reader/writer pair, answered by the same
+  /// [`McpServer::handle_request`] as HTTP. EOF on `reader` or a closed
+  /// `writer` ends the loop cleanly.
+  async fn serve_stdio<R, W>(
+    &self,
+    app_handle: AppHandle,
+    reader: R,
+    mut writer: W,
+  ) -> Result<(), String>
+  where
+    R: tokio::io::AsyncRead + Unpin,
+    W: tokio::io::AsyncWrite + Unpin,
+  {
+    use tokio::io::{AsyncBufReadExt, AsyncWriteExt};
+
+    {
+      let mut inner = self.inner.lock().await;
+      if inner.app_handle.is_none() {
+        inner.app_handle = Some(app_handle);
+      }
+    }
+    self.stdio_active.store(true, Ordering::SeqCst);
+
+    let mut lines = tokio::io::BufReader::new(reader).lines();
+    let result = loop {
+      let line = match lines.next_line().await {
+        Ok(Some(line)) => line,
+        Ok(None) => {
+          log::info!("[mcp] stdin closed, stopping stdio transport");
+          break Ok(());
+        }
+        Err(e) => break Err(format!("Failed to read stdin: {e}")),
+      };
+      if line.trim().is_empty() {
+        continue;
+      }
+      let Some(response) = self.handle_stdio_message(&line).await else {
+        continue;
+      };
+
+      let mut out = match serde_json::to_vec(&response) {
+        Ok(out) => out,
+        Err(e) => break Err(format!("Failed to serialize response: {e}")),
+      };
+      out.push(b'\n');
+      let written = match writer.write_all(&out).await {
+        Ok(()) => writer.flush().await,
+        Err(e) => Err(e),
+      };
+      match written {
+        Ok(()) => {}
+        Err(e) if e.kind() == std::io::ErrorKind::BrokenPipe => {
+          log::info!("[mcp] stdout closed, stopping stdio transport");
+          break Ok(());
+        }
+        Err(e) => break Err(format!("Failed to write stdout: {e}")),
+      }
+    };
+
+    self.stdio_active.store(false, Ordering::SeqCst);
+    result
+  }
+
+  /// The reply to one stdio line, or `None` for notifications.
+  async fn handle_stdio_message(&self, line: &str) -> Option<McpResponse> {
+    let request: McpRequest = match serde_json::from_str(line) {
+      Ok(request) => request,
+      Err(e) => {
+        return Some(McpResponse {
+          jsonrpc: "2.0".to_string(),
+          id: Some(serde_json::Value::Null),
+          result: None,
+          error: Some(McpError {
+            code: -32700,
+            message: format!("Parse error: {e}"),
+          }),
+        });
+      }
+    };
+
+    // One client per process, so there is no session to track and
+    // notifications (notifications/initialized, cancellations) need no reply.
+    request.id.as_ref()?;
+    if request.method == "initialize" {
+      return Some(McpResponse {
+        jsonrpc: "2.0".to_string(),
+        id: request.id,
+        result: Some(Self::initialize_result()),
+        error: None,
+      });
+    }
+    Some(self.handle_request(request).await)
   }
 
   pub async fn handle_request(&self, request: McpRequest) -> McpResponse {
     let id = request.id.clone().unwrap_or(serde_json::Value::Null);
 
-    if !self.is_running() {
+    if !self.is_serving() {
       return McpResponse {
         jsonrpc: "2.0".to_string(),
         id: Some(id),
@@ -1672,6 +2048,8 @@ impl McpServer {
       "ping" => Ok(serde_json::json!({})),
       "tools/list" => self.handle_tools_list().await,
       "tools/call" => self.handle_tool_call(request.params).await,
+      "resources/list" => self.handle_resources_list().await,
+      "resources/read" => self.handle_resources_read(request.params).await,
       _ => Err(McpError {
         code: -32601,
         message: format!("Method not found: {}", request.method),
@@ -1694,6 +2072,93 @@ impl McpServer {
     }
   }
 
+  async fn handle_resources_list(&self) -> Result<serde_json::Value, McpError> {
+    let profiles = ProfileManager::instance()
+      .list_profiles()
+      .map_err(|e| McpError {
+        code: -32000,
+        message: format!("Failed to list profiles: {e}"),
+      })?;
+
+    let mut resources: Vec<serde_json::Value> = profiles
+      .iter()
+      .filter(|p| p.browser == "wayfern")
+      .map(|p| {
+        serde_json::json!({
+          "uri": format!("donut://profile/{}", p.id),
+          "name": p.name,
+          "description": "Wayfern browser profile",
+          "mimeType": "application/json",
+        })
+      })
+      .collect();
+    resources.extend(PROXY_MANAGER.get_stored_proxies().iter().map(|p| {
+      serde_json::json!({
+        "uri": format!("donut://proxy/{}", p.id),
+        "name": p.name,
+        "description": "Stored proxy",
+        "mimeType": "application/json",
+      })
+    }));
+
+    Ok(serde_json::json!({ "resources": resources }))
+  }
+
+  async fn handle_resources_read(
+    &self,
+    params: Option<serde_json::Value>,
+  ) -> Result<serde_json::Value, McpError> {
+    let uri = params
+      .as_ref()
+      .and_then(|p| p.get("uri"))
+      .and_then(|v| v.as_str())
+      .ok_or_else(|| McpError {
+        code: -32602,
+        message: "Missing uri".to_string(),
+      })?;
+    let not_found = || McpError {
+      code: -32002,
+      message: format!("Resource not found: {uri}"),
+    };
+
+    let content = match parse_resource_uri(uri).ok_or_else(not_found)? {
+      ("profile", id) => {
+        let profiles = ProfileManager::instance()
+          .list_profiles()
+          .map_err(|e| McpError {
+            code: -32000,
+            message: format!("Failed to list profiles: {e}"),
+          })?;
+        let profile = profiles
+          .into_iter()
+          .find(|p| p.id.to_string() == id && p.browser == "wayfern")
+          .ok_or_else(not_found)?;
+        serde_json::to_string_pretty(&profile)
+      }
+      ("proxy", id) => {
+        let proxy = PROXY_MANAGER
+          .get_stored_proxies()
+          .into_iter()
+          .find(|p| p.id == id)
+          .ok_or_else(not_found)?;
+        serde_json::to_string_pretty(&proxy)
+      }
+      _ => return Err(not_found()),
+    }
+    .map_err(|e| McpError {
+      code: -32000,
+      message: format!("Failed to serialize resource: {e}"),
+    })?;
+
+    Ok(serde_json::json!({
+      "contents": [{
+        "uri": uri,
+        "mimeType": "application/json",
+        "text": content,
+      }]
+    }))
+  }
+
   async fn handle_tools_list(&self) -> Result<serde_json::Value, McpError> {
     Ok(serde_json::json!({
       "tools": self.get_tools()
@@ -1733,8 +2198,25 @@ impl McpServer {
     log::info!("[mcp] tools/call name={tool_name} profile_id={profile_id}");
 
     let started = std::time::Instant::now();
-    let result = self.dispatch_tool_call(tool_name, &arguments).await;
+    let result = match self.validate_tool_arguments(tool_name, &arguments) {
+      Ok(()) => self.dispatch_tool_call(tool_name, &arguments).await,
+      Err(e) => Err(e),
+    };
     let elapsed_ms = started.elapsed().as_millis();
+    if let Some((action, entity_type, id_argument)) = audited_tool(tool_name) {
+      crate::audit::record(
+        crate::audit::AuditActor::Mcp {
+          tool: tool_name.to_string(),
+        },
+        action,
+        entity_type,
+        arguments
+          .get(id_argument)
+          .and_then(|v| v.as_str())
+          .map(str::to_string),
+        result.as_ref().err().map(|e| e.message.clone()),
+      );
+    }
     match &result {
       Ok(_) => {
         log::info!(
@@ -1752,6 +2234,30 @@ impl McpServer {
     result
   }
 
+  /// Check `arguments` against the tool's declared `input_schema` so a wrong
+  /// type fails here, with every offending field listed, instead of deep in a
+  /// handler. Unknown tools pass through; dispatch reports them.
+  fn validate_tool_arguments(
+    &self,
+    tool_name: &str,
+    arguments: &serde_json::Value,
+  ) -> Result<(), McpError> {
+    let Some(tool) = self.get_tools().into_iter().find(|t| t.name == tool_name) else {
+      return Ok(());
+    };
+    let violations = crate::mcp_schema::validate(&tool.input_schema, arguments);
+    if violations.is_empty() {
+      return Ok(());
+    }
+    Err(McpError {
+      code: -32602,
+      message: format!(
+        "Invalid arguments for {tool_name}: {}",
+        violations.join("; ")
+      ),
+    })
+  }
+
   async fn dispatch_tool_call(
     &self,
     tool_name: &str,
@@ -1799,8 +2305,10 @@ impl McpServer {
       "update_profile" => self.handle_update_profile(arguments).await,
       "delete_profile" => self.handle_delete_profile(arguments).await,
       "list_tags" => self.handle_list_tags().await,
+      "update_profile_tags" => self.handle_update_profile_tags(arguments).await,
       "list_proxies" => self.handle_list_proxies().await,
       "get_profile_status" => self.handle_get_profile_status(arguments).await,
+      "preview_profile_sync" => self.handle_preview_profile_sync(arguments).await,
       // Group management
       "list_groups" => self.handle_list_groups().await,
       "get_group" => self.handle_get_group(arguments).await,
@@ -1808,6 +2316,7 @@ impl McpServer {
       "update_group" => self.handle_update_group(arguments).await,
       "delete_group" => self.handle_delete_group(arguments).await,
       "assign_profiles_to_group" => self.handle_assign_profiles_to_group(arguments).await,
+      "batch_assign_group" => self.handle_batch_assign_group(arguments).await,
       // Full proxy management
       "get_proxy" => self.handle_get_proxy(arguments).await,
       "create_proxy" => self.handle_create_proxy(arguments).await,
@@ -2095,6 +2604,7 @@ impl McpServer {
       None,
       headless,
       true,
+      crate::launch_queue::LaunchPriority::Background,
     )
     .await
     .map_err(|e| McpError {
@@ -2253,6 +2763,7 @@ impl McpServer {
         None,
         headless,
         true,
+        crate::launch_queue::LaunchPriority::Background,
       )
       .await
       {
@@ -2368,18 +2879,21 @@ impl McpServer {
       });
     }
 
-    let proxy_id = arguments
-      .get("proxy_id")
-      .and_then(|v| v.as_str())
-      .map(|s| s.to_string());
-    let launch_hook = arguments
-      .get("launch_hook")
-      .and_then(|v| v.as_str())
-      .map(|s| s.to_string());
-    let group_id = arguments
-      .get("group_id")
-      .and_then(|v| v.as_str())
-      .map(|s| s.to_string());
+    let string_arg = |key: &str| {
+      arguments
+        .get(key)
+        .and_then(|v| v.as_str())
+        .map(|s| s.to_string())
+    };
+    let screen_arg = |key: &str| {
+      arguments
+        .get(key)
+        .and_then(|v| v.as_u64())
+        .and_then(|v| u32::try_from(v).ok())
+    };
+    let proxy_id = string_arg("proxy_id");
+    let launch_hook = string_arg("launch_hook");
+    let group_id = string_arg("group_id");
     let tags: Option<Vec<String>> = arguments.get("tags").and_then(|v| {
       v.as_array().map(|arr| {
         arr
@@ -2388,46 +2902,70 @@ impl McpServer {
           .collect()
       })
     });
+    let wayfern_config = crate::wayfern_manager::WayfernConfig {
+      os: string_arg("os"),
+      screen_min_width: screen_arg("screen_min_width"),
+      screen_min_height: screen_arg("screen_min_height"),
+      screen_max_width: screen_arg("screen_max_width"),
+      screen_max_height: screen_arg("screen_max_height"),
+      ..Default::default()
+    };
+    let download_wait = arguments
+      .get("auto_download")
+      .and_then(|v| v.as_bool())
+      .unwrap_or(false)
+      .then(|| {
+        let secs = arguments
+          .get("download_timeout_secs")
+          .and_then(|v| v.as_u64())
+          .unwrap_or(DEFAULT_DOWNLOAD_WAIT_SECS)
+          .clamp(1, MAX_DOWNLOAD_WAIT_SECS);
+        std::time::Duration::from_secs(secs)
+      });
 
-    // Pick the latest downloaded version for this browser
-    let registry = crate::downloaded_browsers_registry::DownloadedBrowsersRegistry::instance();
-    let versions = registry.get_downloaded_versions(browser);
-    let version = versions.first().ok_or_else(|| McpError {
-      code: -32000,
-      message: format!("No downloaded version found for {browser}. Download it first."),
-    })?;
-
-    let inner = self.inner.lock().await;
-    let app_handle = inner.app_handle.as_ref().ok_or_else(|| McpError {
-      code: -32000,
-      message: "MCP server not properly initialized".to_string(),
-    })?;
-
-    let mut profile = ProfileManager::instance()
-      .create_profile_with_group(
-        app_handle,
-        name,
-        browser,
-        version,
-        "stable",
-        proxy_id,
-        None,
-        None,
-        group_id,
-        false,
-        None,
-        launch_hook,
-      )
+    // Cloned so the lock isn't held through a download or fingerprint
+    // generation.
+    let app_handle = self
+      .inner
+      .lock()
       .await
-      .map_err(|e| McpError {
+      .app_handle
+      .clone()
+      .ok_or_else(|| McpError {
         code: -32000,
-        message: format!("Failed to create profile: {e}"),
+        message: "MCP server not properly initialized".to_string(),
       })?;
 
+    let version = Self::resolve_profile_version(browser, string_arg("version")).await?;
+    Self::ensure_browser_downloaded(&app_handle, browser, &version, download_wait).await?;
+
+    let mut profile = crate::profile::manager::create_browser_profile_new(
+      app_handle.clone(),
+      name.to_string(),
+      browser.to_string(),
+      version.clone(),
+      crate::browser_version_manager::release_type(&version).to_string(),
+      proxy_id,
+      None,
+      Some(wayfern_config),
+      group_id,
+      None,
+      None,
+      launch_hook,
+    )
+    .await
+    .map_err(|e| McpError {
+      code: -32000,
+      message: format!("Failed to create profile: {e}"),
+    })?;
+
     if let Some(tags) = tags {
-      let _ =
-        ProfileManager::instance().update_profile_tags(app_handle, &profile.name, tags.clone());
-      profile.tags = tags;
+      profile = ProfileManager::instance()
+        .update_profile_tags(&app_handle, &profile.id.to_string(), tags)
+        .map_err(|e| McpError {
+          code: -32000,
+          message: format!("Profile created but failed to apply tags: {e}"),
+        })?;
       if let Ok(profiles) = ProfileManager::instance().list_profiles() {
         let _ = crate::tag_manager::TAG_MANAGER
           .lock()
@@ -2438,11 +2976,142 @@ impl McpServer {
     Ok(serde_json::json!({
       "content": [{
         "type": "text",
-        "text": format!("Profile '{}' created (id: {})", profile.name, profile.id)
+        "text": serde_json::to_string_pretty(&profile).unwrap_or_default()
       }]
     }))
   }
 
+  /// The requested version, else the newest downloaded one, else the newest
+  /// available one. A version that isn't downloaded must be the newest
+  /// available, since only that one can be fetched.
+  async fn resolve_profile_version(
+    browser: &str,
+    requested: Option<String>,
+  ) -> Result<String, McpError> {
+    let registry = crate::downloaded_browsers_registry::DownloadedBrowsersRegistry::instance();
+    let requested = requested.filter(|v| !v.is_empty() && v != "latest");
+    if let Some(version) = requested
+      .clone()
+      .or_else(|| registry.get_latest_downloaded_version(browser))
+    {
+      if registry.is_browser_downloaded(browser, &version) {
+        return Ok(version);
+      }
+    }
+
+    let available = crate::browser_version_manager::fetch_browser_versions_with_count_cached_first(
+      browser.to_string(),
+    )
+    .await
+    .map_err(|e| McpError {
+      code: -32000,
+      message: format!("Failed to look up {browser} versions: {e}"),
+    })?;
+    let latest = available
+      .versions
+      .into_iter()
+      .next()
+      .ok_or_else(|| McpError {
+        code: -32000,
+        message: format!("No {browser} version is available for download"),
+      })?;
+    match requested {
+      Some(version) if version != latest => Err(McpError {
+        code: -32602,
+        message: format!(
+          "{browser} {version} is not downloaded and can't be downloaded anymore; the current version is {latest}"
+        ),
+      }),
+      _ => Ok(latest),
+    }
+  }
+
+  /// Makes sure `version` is installed, starting its download if nothing is
+  /// fetching it yet. Download progress isn't reported over MCP: without
+  /// `wait` a missing version fails right away with a retry-later error,
+  /// with `wait` the call blocks until the download finishes or the wait
+  /// runs out. Either way a started download keeps running.
+  async fn ensure_browser_downloaded(
+    app_handle: &crate::app_handle::AppHandle,
+    browser: &str,
+    version: &str,
+    wait: Option<std::time::Duration>,
+  ) -> Result<(), McpError> {
+    let registry = crate::downloaded_browsers_registry::DownloadedBrowsersRegistry::instance();
+    if registry.is_browser_downloaded(browser, version) {
+      return Ok(());
+    }
+
+    let started = if crate::downloader::is_download_in_progress(browser, version) {
+      None
+    } else {
+      log::info!("[mcp] Downloading {browser} {version} for create_profile");
+      let (app_handle, task_browser, task_version) =
+        (app_handle.clone(), browser.to_string(), version.to_string());
+      Some(tauri::async_runtime::spawn(async move {
+        let result = crate::downloader::download_browser(
+          app_handle,
+          task_browser.clone(),
+          task_version.clone(),
+        )
+        .await;
+        if let Err(e) = &result {
+          log::error!("[mcp] Download of {task_browser} {task_version} failed: {e}");
+        }
+        result
+      }))
+    };
+
+    let still_downloading = || {
+      let progress = crate::downloader::download_progress(browser, version);
+      let status = match progress.and_then(|p| p.total_bytes.map(|total| (p.percentage, total))) {
+        Some((percentage, total)) => format!(
+          "is downloading ({percentage:.0}% of about {} MB)",
+          total.div_ceil(1024 * 1024)
+        ),
+        None => "is downloading".to_string(),
+      };
+      McpError {
+        code: -32000,
+        message: format!(
+          "{browser} {version} {status}. Progress isn't reported over MCP; retry create_profile in a few minutes, or pass auto_download to wait for it"
+        ),
+      }
+    };
+    let failed = |error: String| McpError {
+      code: -32000,
+      message: format!("Failed to download {browser} {version}: {error}"),
+    };
+
+    let Some(wait) = wait else {
+      return Err(still_downloading());
+    };
+    match started {
+      Some(task) => match tokio::time::timeout(wait, task).await {
+        Ok(Ok(Ok(_))) => Ok(()),
+        Ok(Ok(Err(e))) => Err(failed(e)),
+        Ok(Err(e)) => Err(failed(e.to_string())),
+        Err(_) => Err(still_downloading()),
+      },
+      // Someone else's download: watch it finish.
+      None => {
+        let deadline = tokio::time::Instant::now() + wait;
+        loop {
+          tokio::time::sleep(std::time::Duration::from_secs(1)).await;
+          if registry.is_browser_downloaded(browser, version) {
+            return Ok(());
+          }
+          if !crate::downloader::is_download_in_progress(browser, version) {
+            return Err(failed("the download did not complete".to_string()));
+          }
+          if tokio::time::Instant::now() >= deadline {
+            return Err(still_downloading());
+          }
+        }
+      }
+    }
+  }
+
   async fn handle_update_profile(
     &self,
     arguments: &serde_json::Value,
@@ -2566,12 +3235,164 @@ impl McpServer {
     Ok(serde_json::json!({
       "content": [{
         "type": "text",
-        "text": format!("Profile '{profile_id}' updated successfully")
+        "text": format!("Profile '{profile_id}' updated successfully")
+      }]
+    }))
+  }
+
+  async fn handle_delete_profile(
+    &self,
+    arguments: &serde_json::Value,
+  ) -> Result<serde_json::Value, McpError> {
+    let profile_id = arguments
+      .get("profile_id")
+      .and_then(|v| v.as_str())
+      .ok_or_else(|| McpError {
+        code: -32602,
+        message: "Missing profile_id".to_string(),
+      })?;
+    let force = arguments
+      .get("force")
+      .and_then(|v| v.as_bool())
+      .unwrap_or(false);
+
+    let app_handle = {
+      let inner = self.inner.lock().await;
+      inner.app_handle.clone().ok_or_else(|| McpError {
+        code: -32000,
+        message: "MCP server not properly initialized".to_string(),
+      })?
+    };
+
+    let profile = ProfileManager::instance()
+      .list_profiles()
+      .map_err(|e| McpError {
+        code: -32000,
+        message: format!("Failed to list profiles: {e}"),
+      })?
+      .into_iter()
+      .find(|p| p.id.to_string() == profile_id)
+      .ok_or_else(|| McpError {
+        code: -32000,
+        message: format!("Profile not found: {profile_id}"),
+      })?;
+
+    let is_running = profile.process_id.is_some() && !profile.is_cross_os();
+    let was_sync_enabled = profile.is_sync_enabled();
+    let protected_group = profile.group_id.as_deref().and_then(|group_id| {
+      GROUP_MANAGER
+        .lock()
+        .ok()?
+        .get_all_groups()
+        .ok()?
+        .into_iter()
+        .find(|g| g.id == group_id && g.protected)
+        .map(|g| g.name)
+    });
+
+    if !force {
+      let mut reasons = Vec::new();
+      if is_running {
+        reasons.push("its browser is running".to_string());
+      }
+      if was_sync_enabled {
+        reasons.push("it is synced, so the remote copy would be deleted too".to_string());
+      }
+      if let Some(group) = &protected_group {
+        reasons.push(format!("it is in the protected group '{group}'"));
+      }
+      if !reasons.is_empty() {
+        return Err(McpError {
+          code: -32000,
+          message: format!(
+            "Refusing to delete profile '{}': {}. Pass force: true to delete it anyway",
+            profile.name,
+            reasons.join("; ")
+          ),
+        });
+      }
+    }
+
+    let mut steps = Vec::new();
+    let had_proxy = PROXY_MANAGER.has_proxy_for_profile(profile_id);
+    if is_running {
+      crate::browser_runner::BrowserRunner::instance()
+        .kill_browser_process(app_handle.clone(), &profile)
+        .await
+        .map_err(|e| McpError {
+          code: -32000,
+          message: format!("Failed to stop browser: {e}"),
+        })?;
+      crate::team_lock::release_team_lock_if_needed(&profile).await;
+      steps.push("stopped the browser".to_string());
+    }
+    // Stopping the browser normally takes its proxy down with it; this also
+    // covers a proxy left behind by a browser that exited on its own.
+    if PROXY_MANAGER.has_proxy_for_profile(profile_id) {
+      PROXY_MANAGER
+        .stop_proxy_by_profile_id(app_handle.clone(), profile_id)
+        .await
+        .map_err(|e| McpError {
+          code: -32000,
+          message: format!("Failed to stop local proxy: {e}"),
+        })?;
+    }
+    if had_proxy {
+      steps.push("stopped the local proxy".to_string());
+    }
+
+    // Also queues SyncEngine::delete_profile when sync was enabled.
+    ProfileManager::instance()
+      .delete_profile(&app_handle, profile_id)
+      .map_err(|e| McpError {
+        code: -32000,
+        message: format!("Failed to delete profile: {e}"),
+      })?;
+    if was_sync_enabled {
+      steps.push("queued deletion of the synced copy".to_string());
+    }
+    steps.push("deleted the profile data".to_string());
+
+    Ok(serde_json::json!({
+      "content": [{
+        "type": "text",
+        "text": format!(
+          "Profile '{}' deleted: {}",
+          profile.name,
+          steps.join(", ")
+        )
+      }]
+    }))
+  }
+
+  async fn handle_list_tags(&self) -> Result<serde_json::Value, McpError> {
+    let tags = crate::tag_manager::TAG_MANAGER
+      .lock()
+      .map_err(|e| McpError {
+        code: -32000,
+        message: format!("Failed to access tag manager: {e}"),
+      })?
+      .get_all_tags()
+      .map_err(|e| McpError {
+        code: -32000,
+        message: format!("Failed to get tags: {e}"),
+      })?;
+    let profiles = ProfileManager::instance()
+      .list_profiles()
+      .map_err(|e| McpError {
+        code: -32000,
+        message: format!("Failed to list profiles: {e}"),
+      })?;
+
+    Ok(serde_json::json!({
+      "content": [{
+        "type": "text",
+        "text": serde_json::to_string_pretty(&tag_usage(&tags, &profiles)).unwrap_or_default()
       }]
     }))
   }
 
-  async fn handle_delete_profile(
+  async fn handle_preview_profile_sync(
     &self,
     arguments: &serde_json::Value,
   ) -> Result<serde_json::Value, McpError> {
@@ -2583,44 +3404,95 @@ impl McpServer {
         message: "Missing profile_id".to_string(),
       })?;
 
-    let inner = self.inner.lock().await;
-    let app_handle = inner.app_handle.as_ref().ok_or_else(|| McpError {
-      code: -32000,
-      message: "MCP server not properly initialized".to_string(),
-    })?;
+    let app_handle = {
+      let inner = self.inner.lock().await;
+      inner.app_handle.clone().ok_or_else(|| McpError {
+        code: -32000,
+        message: "MCP server not properly initialized".to_string(),
+      })?
+    };
 
-    ProfileManager::instance()
-      .delete_profile(app_handle, profile_id)
+    let preview = crate::sync::preview_profile_sync_impl(&app_handle, profile_id)
+      .await
       .map_err(|e| McpError {
         code: -32000,
-        message: format!("Failed to delete profile: {e}"),
+        message: format!("Failed to preview sync: {e}"),
       })?;
 
     Ok(serde_json::json!({
       "content": [{
         "type": "text",
-        "text": format!("Profile '{profile_id}' deleted successfully")
+        "text": serde_json::to_string_pretty(&preview).unwrap_or_default()
       }]
     }))
   }
 
-  async fn handle_list_tags(&self) -> Result<serde_json::Value, McpError> {
-    let tags = crate::tag_manager::TAG_MANAGER
-      .lock()
-      .map_err(|e| McpError {
-        code: -32000,
-        message: format!("Failed to access tag manager: {e}"),
+  async fn handle_update_profile_tags(
+    &self,
+    arguments: &serde_json::Value,
+  ) -> Result<serde_json::Value, McpError> {
+    let profile_id = arguments
+      .get("profile_id")
+      .and_then(|v| v.as_str())
+      .ok_or_else(|| McpError {
+        code: -32602,
+        message: "Missing profile_id".to_string(),
+      })?;
+    let tags: Vec<String> = arguments
+      .get("tags")
+      .and_then(|v| v.as_array())
+      .ok_or_else(|| McpError {
+        code: -32602,
+        message: "Missing tags".to_string(),
       })?
-      .get_all_tags()
+      .iter()
+      .filter_map(|v| v.as_str().map(|s| s.to_string()))
+      .collect();
+    let merge = arguments.get("mode").and_then(|v| v.as_str()) == Some("merge");
+
+    let inner = self.inner.lock().await;
+    let app_handle = inner.app_handle.as_ref().ok_or_else(|| McpError {
+      code: -32000,
+      message: "MCP server not properly initialized".to_string(),
+    })?;
+    let pm = ProfileManager::instance();
+
+    let profiles = pm.list_profiles().map_err(|e| McpError {
+      code: -32000,
+      message: format!("Failed to list profiles: {e}"),
+    })?;
+    let profile = profiles
+      .iter()
+      .find(|p| p.id.to_string() == profile_id)
+      .ok_or_else(|| McpError {
+        code: -32000,
+        message: format!("Profile not found: {profile_id}"),
+      })?;
+    if profile.browser != "wayfern" {
+      return Err(McpError {
+        code: -32000,
+        message: "MCP only supports Wayfern profiles".to_string(),
+      });
+    }
+
+    let tags = if merge {
+      profile.tags.iter().cloned().chain(tags).collect()
+    } else {
+      tags
+    };
+    // The manager dedupes, refreshes tag suggestions and emits
+    // profiles-changed, same as the GUI's tag editor.
+    let updated = pm
+      .update_profile_tags(app_handle, profile_id, tags)
       .map_err(|e| McpError {
         code: -32000,
-        message: format!("Failed to get tags: {e}"),
+        message: format!("Failed to update tags: {e}"),
       })?;
 
     Ok(serde_json::json!({
       "content": [{
         "type": "text",
-        "text": serde_json::to_string_pretty(&tags).unwrap_or_default()
+        "text": serde_json::to_string_pretty(&updated.tags).unwrap_or_default()
       }]
     }))
   }
@@ -2817,7 +3689,7 @@ impl McpServer {
         code: -32000,
         message: format!("Failed to lock group manager: {e}"),
       })?
-      .update_group(app_handle, group_id.to_string(), name.to_string())
+      .update_group(app_handle, group_id.to_string(), name.to_string(), None)
       .map_err(|e| McpError {
         code: -32000,
         message: format!("Failed to update group: {e}"),
@@ -2911,6 +3783,103 @@ impl McpServer {
     }))
   }
 
+  async fn handle_batch_assign_group(
+    &self,
+    arguments: &serde_json::Value,
+  ) -> Result<serde_json::Value, McpError> {
+    let profile_ids: Vec<String> = arguments
+      .get("profile_ids")
+      .and_then(|v| v.as_array())
+      .ok_or_else(|| McpError {
+        code: -32602,
+        message: "Missing profile_ids".to_string(),
+      })?
+      .iter()
+      .filter_map(|v| v.as_str().map(|s| s.to_string()))
+      .collect();
+    let group_id = arguments
+      .get("group_id")
+      .and_then(|v| v.as_str())
+      .ok_or_else(|| McpError {
+        code: -32602,
+        message: "Missing group_id".to_string(),
+      })?;
+    let group_id = (!group_id.is_empty()).then(|| group_id.to_string());
+
+    let inner = self.inner.lock().await;
+    let app_handle = inner.app_handle.as_ref().ok_or_else(|| McpError {
+      code: -32000,
+      message: "MCP server not properly initialized".to_string(),
+    })?;
+
+    let group_name = match &group_id {
+      Some(id) => {
+        let groups = GROUP_MANAGER
+          .lock()
+          .map_err(|e| McpError {
+            code: -32000,
+            message: format!("Failed to lock group manager: {e}"),
+          })?
+          .get_all_groups()
+          .map_err(|e| McpError {
+            code: -32000,
+            message: format!("Failed to get groups: {e}"),
+          })?;
+        groups
+          .into_iter()
+          .find(|g| &g.id == id)
+          .map(|g| g.name)
+          .ok_or_else(|| McpError {
+            code: -32000,
+            message: format!("Group not found: {id}"),
+          })?
+      }
+      None => "default".to_string(),
+    };
+
+    // Check every profile up front so a bad ID doesn't leave the batch
+    // half-applied.
+    let profiles = ProfileManager::instance()
+      .list_profiles()
+      .map_err(|e| McpError {
+        code: -32000,
+        message: format!("Failed to list profiles: {e}"),
+      })?;
+    for profile_id in &profile_ids {
+      let profile = profiles
+        .iter()
+        .find(|p| &p.id.to_string() == profile_id)
+        .ok_or_else(|| McpError {
+          code: -32000,
+          message: format!("Profile not found: {profile_id}"),
+        })?;
+      if profile.browser != "wayfern" {
+        return Err(McpError {
+          code: -32000,
+          message: format!("MCP only supports Wayfern profiles: {profile_id}"),
+        });
+      }
+    }
+
+    ProfileManager::instance()
+      .assign_profiles_to_group(app_handle, profile_ids.clone(), group_id)
+      .map_err(|e| McpError {
+        code: -32000,
+        message: format!("Failed to assign profiles to group: {e}"),
+      })?;
+    // Group profile counts changed along with the profiles.
+    if let Err(e) = crate::events::emit_empty("groups-changed") {
+      log::warn!("Failed to emit groups-changed event: {e}");
+    }
+
+    Ok(serde_json::json!({
+      "content": [{
+        "type": "text",
+        "text": format!("{} profile(s) moved to group '{}'", profile_ids.len(), group_name)
+      }]
+    }))
+  }
+
   // Full proxy management handlers
   async fn handle_get_proxy(
     &self,
@@ -3000,6 +3969,14 @@ impl McpServer {
       .get("password")
       .and_then(|v| v.as_str())
       .map(|s| s.to_string());
+    let client_cert_path = arguments
+      .get("client_cert_path")
+      .and_then(|v| v.as_str())
+      .map(|s| s.to_string());
+    let client_key_path = arguments
+      .get("client_key_path")
+      .and_then(|v| v.as_str())
+      .map(|s| s.to_string());
 
     let proxy_settings = ProxySettings {
       proxy_type: proxy_type.to_string(),
@@ -3007,6 +3984,8 @@ impl McpServer {
       port,
       username,
       password,
+      client_cert_path,
+      client_key_path,
     };
 
     let proxy = PROXY_MANAGER
@@ -3042,9 +4021,15 @@ impl McpServer {
       .map(|s| s.to_string());
 
     // Build proxy_settings if any settings fields are provided
-    let has_settings = arguments.get("proxy_type").is_some()
-      || arguments.get("host").is_some()
-      || arguments.get("port").is_some();
+    let has_settings = [
+      "proxy_type",
+      "host",
+      "port",
+      "client_cert_path",
+      "client_key_path",
+    ]
+    .iter()
+    .any(|field| arguments.get(field).is_some());
 
     let proxy_settings = if has_settings {
       // Get existing proxy to use as defaults
@@ -3087,12 +4072,27 @@ impl McpServer {
         .map(|s| s.to_string())
         .or_else(|| existing.proxy_settings.password.clone());
 
+      // An empty string removes the certificate.
+      let path_arg = |field: &str, current: &Option<String>| {
+        let value = arguments.get(field).and_then(|v| v.as_str());
+        match value {
+          Some("") => None,
+          Some(path) => Some(path.to_string()),
+          None => current.clone(),
+        }
+      };
+      let current = &existing.proxy_settings;
+      let client_cert_path = path_arg("client_cert_path", &current.client_cert_path);
+      let client_key_path = path_arg("client_key_path", &current.client_key_path);
+
       Some(ProxySettings {
         proxy_type,
         host,
         port,
         username,
         password,
+        client_cert_path,
+        client_key_path,
       })
     } else {
       None
@@ -3137,12 +4137,16 @@ impl McpServer {
       message: "MCP server not properly initialized".to_string(),
     })?;
 
-    PROXY_MANAGER
-      .delete_stored_proxy(app_handle, proxy_id)
-      .map_err(|e| McpError {
+    let force = arguments
+      .get("force")
+      .and_then(|v| v.as_bool())
+      .unwrap_or(false);
+    crate::entity_usage::delete_stored_proxy(app_handle, proxy_id, force).map_err(|e| {
+      McpError {
         code: -32000,
         message: format!("Failed to delete proxy: {e}"),
-      })?;
+      }
+    })?;
 
     Ok(serde_json::json!({
       "content": [{
@@ -3610,24 +4614,7 @@ impl McpServer {
         message: "Missing vpn_id".to_string(),
       })?;
 
-    let connected =
-      if let Some(worker) = crate::vpn_worker_storage::find_vpn_worker_by_vpn_id(vpn_id) {
-        worker
-          .pid
-          .map(crate::proxy_storage::is_process_running)
-          .unwrap_or(false)
-      } else {
-        false
-      };
-
-    let status = crate::vpn::VpnStatus {
-      connected,
-      vpn_id: vpn_id.to_string(),
-      connected_at: None,
-      bytes_sent: None,
-      bytes_received: None,
-      last_handshake: None,
-    };
+    let status = crate::vpn_worker_runner::vpn_status(vpn_id);
 
     Ok(serde_json::json!({
       "content": [{
@@ -3673,6 +4660,8 @@ impl McpServer {
           "fingerprint": config.fingerprint,
           "os": config.os,
           "randomize_fingerprint_on_launch": config.randomize_fingerprint_on_launch,
+          "locale": config.locale_override,
+          "timezone": config.timezone_override,
           "screen_max_width": config.screen_max_width,
           "screen_max_height": config.screen_max_height,
           "screen_min_width": config.screen_min_width,
@@ -3719,6 +4708,12 @@ impl McpServer {
     let randomize = arguments
       .get("randomize_fingerprint_on_launch")
       .and_then(|v| v.as_bool());
+    let locale = arguments.get("locale").and_then(|v| v.as_str());
+    let timezone = arguments.get("timezone").and_then(|v| v.as_str());
+    let force_locale = arguments
+      .get("force_locale")
+      .and_then(|v| v.as_bool())
+      .unwrap_or(false);
 
     if let Some(os_val) = os {
       if !CLOUD_AUTH.is_fingerprint_os_allowed(Some(os_val)).await {
@@ -3765,8 +4760,14 @@ impl McpServer {
         if let Some(r) = randomize {
           config.randomize_fingerprint_on_launch = Some(r);
         }
+        if let Some(locale) = locale {
+          config.locale_override = Some(locale.trim().to_string()).filter(|l| !l.is_empty());
+        }
+        if let Some(timezone) = timezone {
+          config.timezone_override = Some(timezone.trim().to_string()).filter(|t| !t.is_empty());
+        }
         ProfileManager::instance()
-          .update_wayfern_config(app_handle.clone(), profile_id, config)
+          .update_wayfern_config(app_handle.clone(), profile_id, config, force_locale)
           .await
           .map_err(|e| McpError {
             code: -32000,
@@ -5442,6 +6443,71 @@ impl McpServer {
   }
 }
 
+/// Audit action, entity type and the argument holding the entity id for tools
+/// that change state. Reads and page automation are not audited.
+fn audited_tool(tool_name: &str) -> Option<(&'static str, &'static str, &'static str)> {
+  Some(match tool_name {
+    "run_profile" | "batch_run_profiles" => ("launch", "profile", "profile_id"),
+    "kill_profile" | "batch_stop_profiles" => ("kill", "profile", "profile_id"),
+    "create_profile" => ("create", "profile", "profile_id"),
+    "import_browser_profiles" => ("import", "profile", "profile_id"),
+    "update_profile"
+    | "update_profile_tags"
+    | "update_profile_fingerprint"
+    | "update_profile_proxy_bypass_rules"
+    | "update_profile_dns_blocklist"
+    | "import_profile_cookies"
+    | "assign_extension_group_to_profile" => ("update", "profile", "profile_id"),
+    "delete_profile" => ("delete", "profile", "profile_id"),
+    "create_group" => ("create", "group", "group_id"),
+    "update_group" | "assign_profiles_to_group" | "batch_assign_group" => {
+      ("update", "group", "group_id")
+    }
+    "delete_group" => ("delete", "group", "group_id"),
+    "create_proxy" => ("create", "proxy", "proxy_id"),
+    "update_proxy" => ("update", "proxy", "proxy_id"),
+    "delete_proxy" => ("delete", "proxy", "proxy_id"),
+    "import_proxies" => ("import", "proxy", "proxy_id"),
+    "import_vpn" => ("import", "vpn", "vpn_id"),
+    "delete_vpn" => ("delete", "vpn", "vpn_id"),
+    "connect_vpn" => ("connect", "vpn", "vpn_id"),
+    "disconnect_vpn" => ("disconnect", "vpn", "vpn_id"),
+    "delete_extension" => ("delete", "extension", "extension_id"),
+    "create_extension_group" => ("create", "extension_group", "extension_group_id"),
+    "delete_extension_group" => ("delete", "extension_group", "extension_group_id"),
+    "start_sync_session" => ("start", "sync_session", "leader_profile_id"),
+    "stop_sync_session" => ("stop", "sync_session", "session_id"),
+    "remove_sync_follower" => ("update", "sync_session", "session_id"),
+    _ => return None,
+  })
+}
+
+/// Split `donut://{kind}/{id}` into `(kind, id)`.
+fn parse_resource_uri(uri: &str) -> Option<(&str, &str)> {
+  let (kind, id) = uri.strip_prefix("donut://")?.split_once('/')?;
+  if id.is_empty() || id.contains('/') {
+    return None;
+  }
+  Some((kind, id))
+}
+
+/// Tag suggestions with the number of profiles carrying each, sorted by tag.
+/// Tags on profiles that the suggestion set hasn't caught up with yet are
+/// included too.
+fn tag_usage(suggestions: &[String], profiles: &[BrowserProfile]) -> Vec<serde_json::Value> {
+  let mut counts: std::collections::BTreeMap<&str, usize> =
+    suggestions.iter().map(|t| (t.as_str(), 0)).collect();
+  for profile in profiles {
+    for tag in &profile.tags {
+      *counts.entry(tag.as_str()).or_default() += 1;
+    }
+  }
+  counts
+    .into_iter()
+    .map(|(tag, count)| serde_json::json!({ "tag": tag, "count": count }))
+    .collect()
+}
+
 lazy_static::lazy_static! {
   static ref MCP_SERVER: McpServer = McpServer::new();
 }
@@ -5450,6 +6516,40 @@ lazy_static::lazy_static! {
 mod tests {
   use super::*;
 
+  #[test]
+  fn audited_tools_exist_and_reads_are_not_audited() {
+    let server = McpServer::new();
+    let tools = server.get_tools();
+    let audited: Vec<&str> = tools
+      .iter()
+      .map(|t| t.name.as_str())
+      .filter(|name| audited_tool(name).is_some())
+      .collect();
+    for name in [
+      "run_profile",
+      "kill_profile",
+      "delete_profile",
+      "update_proxy",
+    ] {
+      assert!(audited.contains(&name), "{name} should be audited");
+    }
+    assert!(audited
+      .iter()
+      .all(|name| !name.starts_with("get_") && !name.starts_with("list_")));
+    for name in [
+      "run_profile",
+      "batch_stop_profiles",
+      "update_profile_dns_blocklist",
+      "delete_extension_group",
+      "remove_sync_follower",
+    ] {
+      assert!(
+        tools.iter().any(|t| t.name == name),
+        "{name} is not a registered tool"
+      );
+    }
+  }
+
   #[test]
   fn test_mcp_tools_count() {
     let server = McpServer::new();
@@ -5465,7 +6565,9 @@ mod tests {
     assert!(tool_names.contains(&"get_profile"));
     assert!(tool_names.contains(&"run_profile"));
     assert!(tool_names.contains(&"kill_profile"));
+    assert!(tool_names.contains(&"delete_profile"));
     assert!(tool_names.contains(&"get_profile_status"));
+    assert!(tool_names.contains(&"preview_profile_sync"));
     // Profile import tools
     assert!(tool_names.contains(&"detect_browser_profiles"));
     assert!(tool_names.contains(&"import_browser_profiles"));
@@ -5476,6 +6578,10 @@ mod tests {
     assert!(tool_names.contains(&"update_group"));
     assert!(tool_names.contains(&"delete_group"));
     assert!(tool_names.contains(&"assign_profiles_to_group"));
+    assert!(tool_names.contains(&"batch_assign_group"));
+    // Tag tools
+    assert!(tool_names.contains(&"list_tags"));
+    assert!(tool_names.contains(&"update_profile_tags"));
     // Proxy tools
     assert!(tool_names.contains(&"list_proxies"));
     assert!(tool_names.contains(&"get_proxy"));
@@ -5523,6 +6629,258 @@ mod tests {
     assert!(tool_names.contains(&"get_page_info"));
   }
 
+  #[test]
+  fn test_tag_usage_counts_profiles_per_tag() {
+    let profile = |tags: &[&str]| BrowserProfile {
+      tags: tags.iter().map(|t| t.to_string()).collect(),
+      ..Default::default()
+    };
+    let suggestions = vec!["shop".to_string(), "stale".to_string()];
+    let profiles = vec![profile(&["shop", "ads"]), profile(&["shop"])];
+
+    assert_eq!(
+      tag_usage(&suggestions, &profiles),
+      vec![
+        serde_json::json!({ "tag": "ads", "count": 1 }),
+        serde_json::json!({ "tag": "shop", "count": 2 }),
+        serde_json::json!({ "tag": "stale", "count": 0 }),
+      ]
+    );
+  }
+
+  fn valid_value_for(schema: &serde_json::Value) -> serde_json::Value {
+    if let Some(first) = schema
+      .get("enum")
+      .and_then(|e| e.as_array())
+      .and_then(|e| e.first())
+    {
+      return first.clone();
+    }
+    match schema.get("type").and_then(|t| t.as_str()) {
+      Some("string") => serde_json::json!("x"),
+      Some("integer") | Some("number") => serde_json::json!(1),
+      Some("boolean") => serde_json::json!(true),
+      Some("array") => serde_json::json!([]),
+      _ => serde_json::json!({}),
+    }
+  }
+
+  fn wrong_value_for(schema: &serde_json::Value) -> serde_json::Value {
+    match schema.get("type").and_then(|t| t.as_str()) {
+      Some("string") => serde_json::json!(42),
+      _ => serde_json::json!("wrong type"),
+    }
+  }
+
+  #[tokio::test]
+  async fn test_tool_calls_reject_malformed_arguments_by_field() {
+    let server = McpServer::new();
+    for tool in server.get_tools() {
+      let schema = &tool.input_schema;
+      let properties = schema["properties"]
+        .as_object()
+        .unwrap_or_else(|| panic!("{} has no properties", tool.name))
+        .clone();
+      let required: Vec<String> = schema
+        .get("required")
+        .and_then(|r| r.as_array())
+        .map(|r| {
+          r.iter()
+            .filter_map(|v| v.as_str().map(str::to_string))
+            .collect()
+        })
+        .unwrap_or_default();
+
+      let mut valid = serde_json::Map::new();
+      for name in &required {
+        let field_schema = properties
+          .get(name)
+          .unwrap_or_else(|| panic!("{}: required `{name}` is not declared", tool.name));
+        valid.insert(name.clone(), valid_value_for(field_schema));
+      }
+      let valid = serde_json::Value::Object(valid);
+      assert!(
+        crate::mcp_schema::validate(schema, &valid).is_empty(),
+        "{}: minimal arguments should validate",
+        tool.name
+      );
+
+      let mut malformed = Vec::new();
+      for (name, field_schema) in &properties {
+        assert!(
+          field_schema.get("type").is_some(),
+          "{}: `{name}` has no declared type",
+          tool.name
+        );
+        let mut args = valid.clone();
+        args[name.as_str()] = wrong_value_for(field_schema);
+        malformed.push((name.clone(), args));
+      }
+      for name in &required {
+        let mut args = valid.clone();
+        args.as_object_mut().unwrap().remove(name);
+        malformed.push((name.clone(), args));
+      }
+
+      for (field, arguments) in malformed {
+        let err = server
+          .handle_tool_call(Some(serde_json::json!({
+            "name": tool.name,
+            "arguments": arguments,
+          })))
+          .await
+          .expect_err("malformed arguments must be rejected");
+        assert_eq!(err.code, -32602, "{}: {}", tool.name, err.message);
+        assert!(
+          err.message.contains(&format!("`{field}`")),
+          "{}: error should name `{field}`: {}",
+          tool.name,
+          err.message
+        );
+      }
+    }
+  }
+
+  #[test]
+  fn test_parse_resource_uri() {
+    assert_eq!(
+      parse_resource_uri("donut://profile/abc-123"),
+      Some(("profile", "abc-123"))
+    );
+    assert_eq!(
+      parse_resource_uri("donut://proxy/p1"),
+      Some(("proxy", "p1"))
+    );
+    assert_eq!(parse_resource_uri("donut://proxy/"), None);
+    assert_eq!(parse_resource_uri("donut://proxy/a/b"), None);
+    assert_eq!(parse_resource_uri("file:///etc/passwd"), None);
+  }
+
+  #[tokio::test]
+  async fn test_notifications_reach_initialized_sessions_with_a_stream() {
+    let server = McpServer::new();
+    let (ready_tx, mut ready_rx) = tokio::sync::mpsc::unbounded_channel();
+    let (pending_tx, mut pending_rx) = tokio::sync::mpsc::unbounded_channel();
+    let (closed_tx, closed_rx) = tokio::sync::mpsc::unbounded_channel::<String>();
+    drop(closed_rx);
+    {
+      let mut inner = server.inner.lock().await;
+      for (id, initialized, notifier) in [
+        ("ready", true, Some(ready_tx)),
+        ("pending", false, Some(pending_tx)),
+        ("closed", true, Some(closed_tx)),
+        ("no-stream", true, None),
+      ] {
+        inner.sessions.insert(
+          id.to_string(),
+          McpSession {
+            initialized,
+            notifier,
+          },
+        );
+      }
+    }
+
+    server
+      .notify_sessions("notifications/resources/list_changed")
+      .await;
+
+    let message: serde_json::Value = serde_json::from_str(&ready_rx.try_recv().unwrap()).unwrap();
+    assert_eq!(message["method"], "notifications/resources/list_changed");
+    assert!(message.get("id").is_none());
+    assert!(pending_rx.try_recv().is_err());
+    let inner = server.inner.lock().await;
+    assert!(inner.sessions["closed"].notifier.is_none());
+  }
+
+  #[tokio::test]
+  async fn test_stdio_round_trips_tools_list_and_call() {
+    use tokio::io::{AsyncBufReadExt, AsyncWriteExt};
+
+    let server = McpServer::new();
+    let (client, transport) = tokio::io::duplex(64 * 1024);
+    let (server_read, server_write) = tokio::io::split(transport);
+
+    let client_side = async move {
+      let (read, mut write) = tokio::io::split(client);
+      let mut lines = tokio::io::BufReader::new(read).lines();
+      let mut responses = Vec::new();
+      for message in [
+        r#"{"jsonrpc":"2.0","id":1,"method":"initialize","params":{}}"#,
+        r#"{"jsonrpc":"2.0","method":"notifications/initialized"}"#,
+        r#"{"jsonrpc":"2.0","id":2,"method":"tools/list","params":{}}"#,
+        r#"{"jsonrpc":"2.0","id":3,"method":"tools/call","params":{"name":"get_sync_sessions","arguments":{}}}"#,
+        "not json",
+      ] {
+        write.write_all(message.as_bytes()).await.unwrap();
+        write.write_all(b"\n").await.unwrap();
+        if message.contains("notifications/") {
+          continue;
+        }
+        let line = lines.next_line().await.unwrap().unwrap();
+        responses.push(serde_json::from_str::<serde_json::Value>(&line).unwrap());
+      }
+      // Dropping both halves closes the client end: the server sees EOF.
+      responses
+    };
+
+    let (served, responses) = tokio::join!(
+      server.serve_stdio(AppHandle::Headless, server_read, server_write),
+      client_side
+    );
+    assert!(served.is_ok());
+    assert!(!server.stdio_active.load(Ordering::SeqCst));
+
+    assert_eq!(responses[0]["id"], 1);
+    assert_eq!(responses[0]["result"]["protocolVersion"], PROTOCOL_VERSION);
+    assert_eq!(responses[1]["id"], 2);
+    assert!(responses[1]["result"]["tools"]
+      .as_array()
+      .unwrap()
+      .iter()
+      .any(|tool| tool["name"] == "list_profiles"));
+    assert_eq!(responses[2]["id"], 3);
+    assert!(responses[2].get("error").is_none(), "{}", responses[2]);
+    assert_eq!(responses[2]["result"]["content"][0]["type"], "text");
+    assert_eq!(responses[3]["error"]["code"], -32700);
+  }
+
+  struct ClosedPipe;
+
+  impl tokio::io::AsyncWrite for ClosedPipe {
+    fn poll_write(
+      self: std::pin::Pin<&mut Self>,
+      _cx: &mut std::task::Context<'_>,
+      _buf: &[u8],
+    ) -> std::task::Poll<std::io::Result<usize>> {
+      std::task::Poll::Ready(Err(std::io::ErrorKind::BrokenPipe.into()))
+    }
+
+    fn poll_flush(
+      self: std::pin::Pin<&mut Self>,
+      _cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<std::io::Result<()>> {
+      std::task::Poll::Ready(Ok(()))
+    }
+
+    fn poll_shutdown(
+      self: std::pin::Pin<&mut Self>,
+      _cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<std::io::Result<()>> {
+      std::task::Poll::Ready(Ok(()))
+    }
+  }
+
+  #[tokio::test]
+  async fn test_stdio_stops_cleanly_on_broken_pipe() {
+    let server = McpServer::new();
+    let input: &[u8] = b"{\"jsonrpc\":\"2.0\",\"id\":1,\"method\":\"ping\"}\n";
+    let served = server
+      .serve_stdio(AppHandle::Headless, input, ClosedPipe)
+      .await;
+    assert!(served.is_ok());
+  }
+
   #[test]
   fn test_mcp_server_initial_state() {
     let server = McpServer::new();
@@ -0,0 +1,246 @@
+//! Read-only observer mode (`--observer`). A second GUI instance on the same
+//! data dir lists profiles and their status but never writes shared state:
+//! the single-instance handoff, background tasks, API/MCP servers and sync are
+//! skipped, and every command that could mutate is rejected with
+//! `OBSERVER_MODE` before it runs.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+pub const OBSERVER_FLAG: &str = "--observer";
+
+static OBSERVER: AtomicBool = AtomicBool::new(false);
+
+pub fn enable() {
+  OBSERVER.store(true, Ordering::SeqCst);
+}
+
+pub fn is_enabled() -> bool {
+  OBSERVER.load(Ordering::SeqCst)
+}
+
+/// Command name prefixes that only read state (or write caches and files the
+/// user picked, never shared app data).
+const READ_PREFIXES: &[&str] = &[
+  "get_",
+  "list_",
+  "is_",
+  "has_",
+  "check_",
+  "detect_",
+  "preview_",
+  "validate_",
+  "fetch_",
+  "export_",
+  "read_",
+  "cloud_get_",
+];
+
+/// Commands outside those prefixes that only touch this instance's window.
+const READ_COMMANDS: &[&str] = &[
+  "confirm_quit",
+  "hide_to_tray",
+  "update_tray_menu",
+  "open_detached_profile_window",
+  "open_log_directory",
+  "generate_sample_fingerprint",
+  "parse_txt_proxies",
+];
+
+/// Whether an observer may run `command`.
+pub fn allows(command: &str) -> bool {
+  READ_COMMANDS.contains(&command) || READ_PREFIXES.iter().any(|p| command.starts_with(p))
+}
+
+pub fn rejection(command: &str) -> String {
+  serde_json::json!({ "code": "OBSERVER_MODE", "params": { "command": command } }).to_string()
+}
+
+/// Wrap the app's command handler so an observer rejects every command it
+/// doesn't allow before the command runs.
+pub fn guard<R: tauri::Runtime>(
+  handler: impl Fn(tauri::ipc::Invoke<R>) -> bool + Send + Sync + 'static,
+) -> impl Fn(tauri::ipc::Invoke<R>) -> bool + Send + Sync + 'static {
+  move |invoke| {
+    if is_enabled() {
+      let command = invoke.message.command().to_string();
+      if !allows(&command) {
+        invoke.resolver.reject(rejection(&command));
+        return true;
+      }
+    }
+    handler(invoke)
+  }
+}
+
+/// Whether the profile's recorded browser is alive. Unlike the status
+/// checker this never corrects the stored PID; that is the owning
+/// instance's job.
+pub fn is_profile_running(profile: &crate::profile::BrowserProfile) -> bool {
+  profile
+    .process_id
+    .is_some_and(crate::process_registry::process_exists)
+}
+
+/// The owning instance's events don't reach this one, so changes are picked
+/// up from disk instead: `profiles-changed` when any profile file changed and
+/// `profile-running-changed` when a recorded browser started or exited.
+pub fn spawn_status_watch() {
+  tauri::async_runtime::spawn(async move {
+    let mut interval = tokio::time::interval(Duration::from_secs(3));
+    let mut last_profiles: Option<String> = None;
+    let mut last_running: HashMap<String, bool> = HashMap::new();
+    loop {
+      interval.tick().await;
+      let Ok(profiles) = crate::profile::ProfileManager::instance().list_profiles() else {
+        continue;
+      };
+
+      let serialized = serde_json::to_string(&profiles).unwrap_or_default();
+      if last_profiles
+        .as_ref()
+        .is_some_and(|last| *last != serialized)
+      {
+        let _ = crate::events::emit_empty("profiles-changed");
+      }
+      last_profiles = Some(serialized);
+
+      for (id, is_running) in running_changes(&profiles, &mut last_running) {
+        let _ =
+          crate::events::emit(&crate::events::payloads::ProfileRunningChanged { id, is_running });
+      }
+    }
+  });
+}
+
+fn running_changes(
+  profiles: &[crate::profile::BrowserProfile],
+  last: &mut HashMap<String, bool>,
+) -> Vec<(String, bool)> {
+  profiles
+    .iter()
+    .filter_map(|profile| {
+      let id = profile.id.to_string();
+      let running = is_profile_running(profile);
+      let was_running = last.insert(id.clone(), running).unwrap_or(false);
+      (running != was_running).then_some((id, running))
+    })
+    .collect()
+}
+
+/// Whether this instance was started with `--observer`.
+#[tauri::command]
+pub fn get_observer_mode() -> bool {
+  is_enabled()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn registered_commands() -> Vec<String> {
+    let lib = std::fs::read_to_string("src/lib.rs").unwrap();
+    let start = lib.find("tauri::generate_handler![").unwrap() + 25;
+    let end = start + lib[start..].find(']').unwrap();
+    lib[start..end]
+      .lines()
+      .filter(|line| !line.trim_start().starts_with("//"))
+      .flat_map(|line| line.split(','))
+      .map(|c| c.trim().rsplit("::").next().unwrap_or_default().to_string())
+      .filter(|c| !c.is_empty())
+      .collect()
+  }
+
+  #[test]
+  fn mutating_commands_are_rejected_and_listing_is_allowed() {
+    for command in [
+      "launch_browser_profile",
+      "kill_browser_profile",
+      "open_url_with_profile",
+      "delete_profile",
+      "create_stored_proxy",
+      "update_stored_proxy",
+      "save_app_settings",
+      "start_api_server",
+      "start_mcp_server",
+      "request_profile_sync",
+      "connect_vpn",
+      "cleanup_orphan_processes",
+    ] {
+      assert!(!allows(command), "{command} must be rejected");
+    }
+    for command in [
+      "list_browser_profiles",
+      "get_app_settings",
+      "get_stored_proxies",
+      "check_browser_status",
+      "get_vpn_status",
+      "get_observer_mode",
+    ] {
+      assert!(allows(command), "{command} must be allowed");
+    }
+    assert!(rejection("delete_profile").contains("OBSERVER_MODE"));
+  }
+
+  #[test]
+  fn every_registered_write_command_is_rejected() {
+    let commands = registered_commands();
+    assert!(commands.iter().any(|c| c == "list_browser_profiles"));
+    let write_prefixes = [
+      "launch_",
+      "kill_",
+      "create_",
+      "update_",
+      "delete_",
+      "save_",
+      "set_",
+      "start_",
+      "stop_",
+      "import_",
+      "remove_",
+      "rename_",
+      "restore_",
+      "clone_",
+      "connect_",
+      "disconnect_",
+    ];
+    for command in commands
+      .iter()
+      .filter(|c| write_prefixes.iter().any(|p| c.starts_with(p)))
+      .filter(|c| *c != "update_tray_menu")
+    {
+      assert!(!allows(command), "{command} must be rejected");
+    }
+  }
+
+  #[test]
+  fn running_changes_report_only_transitions() {
+    let mut last = HashMap::new();
+    let alive = crate::profile::BrowserProfile {
+      id: uuid::Uuid::new_v4(),
+      process_id: Some(std::process::id()),
+      ..Default::default()
+    };
+    let idle = crate::profile::BrowserProfile {
+      id: uuid::Uuid::new_v4(),
+      ..Default::default()
+    };
+
+    let profiles = [alive.clone(), idle];
+    assert_eq!(
+      running_changes(&profiles, &mut last),
+      vec![(alive.id.to_string(), true)]
+    );
+    assert!(running_changes(&profiles, &mut last).is_empty());
+
+    let exited = crate::profile::BrowserProfile {
+      process_id: None,
+      ..alive.clone()
+    };
+    assert_eq!(
+      running_changes(&[exited], &mut last),
+      vec![(alive.id.to_string(), false)]
+    );
+  }
+}
@@ -0,0 +1,94 @@
+//! Offline mode. While it is on, background work that reaches the network
+//! (version and app update checks, the GeoIP download, sync, the sync
+//! subscription, cloud auth refreshes, proxy source, extension and blocklist
+//! refreshes) stands down right away instead of failing after timeouts.
+//! Actions the user starts, such as launching a profile through its proxy,
+//! are unaffected. Turning it off runs one version check and one sync
+//! reconciliation to catch up.
+
+use std::sync::LazyLock;
+use tokio::sync::watch;
+
+use crate::settings_manager::SettingsManager;
+
+static OFFLINE: LazyLock<watch::Sender<bool>> = LazyLock::new(|| {
+  let offline = SettingsManager::instance()
+    .load_settings()
+    .map(|s| s.offline_mode)
+    .unwrap_or(false);
+  watch::channel(offline).0
+});
+
+pub fn is_offline() -> bool {
+  *OFFLINE.borrow()
+}
+
+/// Resolves once offline mode is off.
+pub async fn wait_until_online() {
+  let mut rx = OFFLINE.subscribe();
+  let _ = rx.wait_for(|offline| !offline).await;
+}
+
+/// Resolves once offline mode is on.
+pub async fn wait_until_offline() {
+  let mut rx = OFFLINE.subscribe();
+  let _ = rx.wait_for(|offline| *offline).await;
+}
+
+async fn catch_up(app_handle: crate::app_handle::AppHandle) {
+  log::info!("Offline mode turned off, catching up on version checks and sync");
+  let versions = async {
+    let updater = crate::version_updater::get_version_updater();
+    let updater = updater.lock().await;
+    if let Err(e) = updater.trigger_manual_update(&app_handle).await {
+      log::warn!("Catch-up version check failed: {e}");
+    }
+  };
+  let sync = async {
+    if crate::sync::is_sync_configured() {
+      crate::sync::subscription::reconcile(app_handle.clone()).await;
+    }
+  };
+  tokio::join!(versions, sync);
+}
+
+async fn set_offline_mode_impl(
+  app_handle: crate::app_handle::AppHandle,
+  enabled: bool,
+) -> Result<(), String> {
+  let manager = SettingsManager::instance();
+  let mut settings = manager
+    .load_settings()
+    .map_err(|e| format!("Failed to load settings: {e}"))?;
+  settings.offline_mode = enabled;
+  manager
+    .save_settings(&settings)
+    .map_err(|e| format!("Failed to save settings: {e}"))?;
+
+  let was_offline = OFFLINE.send_replace(enabled);
+  log::info!(
+    "Offline mode {}",
+    if enabled { "enabled" } else { "disabled" }
+  );
+  if let Err(e) = crate::events::emit(&crate::events::payloads::OfflineModeChanged(enabled)) {
+    log::warn!("Failed to emit offline-mode-changed event: {e}");
+  }
+  if was_offline && !enabled {
+    tauri::async_runtime::spawn(catch_up(app_handle));
+  }
+  Ok(())
+}
+
+#[tauri::command]
+pub async fn set_offline_mode(
+  app_handle: crate::app_handle::AppHandle,
+  enabled: bool,
+) -> Result<(), String> {
+  crate::audit::audited!(
+    crate::audit::AuditActor::Gui,
+    "update",
+    "settings",
+    None,
+    set_offline_mode_impl(app_handle, enabled).await
+  )
+}
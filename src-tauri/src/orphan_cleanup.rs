@@ -0,0 +1,365 @@
+//! Recovery from a crashed previous run. Browsers and proxy/VPN workers that
+//! nothing tracks anymore are stopped, and Chromium's singleton locks are
+//! removed from profiles whose browser is gone, so the next launch isn't
+//! refused with "profile in use".
+
+use crate::profile::BrowserProfile;
+use serde::Serialize;
+use std::collections::HashSet;
+use std::path::Path;
+use std::time::Duration;
+
+/// Chromium's per-user-data-dir locks; `lockfile` is the Windows one.
+const LOCK_FILES: &[&str] = &[
+  "SingletonLock",
+  "SingletonSocket",
+  "SingletonCookie",
+  "lockfile",
+];
+
+/// Processes younger than this are left alone: a launch that is still
+/// starting may not have recorded its PID on the profile yet.
+const MIN_ORPHAN_AGE: Duration = Duration::from_secs(60);
+
+/// Published as `orphans-cleaned` after every pass.
+#[derive(Debug, Default, Clone, Serialize, PartialEq, Eq)]
+pub struct OrphanCleanupSummary {
+  pub browsers_killed: usize,
+  pub workers_killed: usize,
+  pub locks_removed: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum OrphanKind {
+  Browser,
+  Worker,
+}
+
+#[derive(Debug, Clone)]
+struct ProcessInfo {
+  pid: u32,
+  args: Vec<String>,
+  age: Duration,
+}
+
+fn list_processes() -> Vec<ProcessInfo> {
+  crate::process_registry::with_process_table(|system| {
+    system
+      .processes()
+      .iter()
+      .map(|(pid, process)| ProcessInfo {
+        pid: pid.as_u32(),
+        args: process
+          .cmd()
+          .iter()
+          .map(|a| a.to_string_lossy().into_owned())
+          .collect(),
+        age: Duration::from_secs(process.run_time()),
+      })
+      .collect()
+  })
+}
+
+/// The profile a process works on, from any argument that points inside
+/// `profiles_dir` (`--user-data-dir=<profiles_dir>/<id>/profile`).
+fn profile_id_in_args(args: &[String], profiles_dir: &str) -> Option<String> {
+  args.iter().find_map(|arg| {
+    let start = arg.find(profiles_dir)? + profiles_dir.len();
+    let id = arg[start..]
+      .trim_start_matches(['/', '\\'])
+      .split(['/', '\\'])
+      .next()?;
+    (!id.is_empty()).then(|| id.to_string())
+  })
+}
+
+/// The `--id` of a `donut-proxy proxy-worker|vpn-worker start` process.
+fn worker_id_in_args(args: &[String]) -> Option<&str> {
+  let exe = Path::new(args.first()?).file_name()?.to_string_lossy();
+  if !exe.starts_with("donut-proxy") {
+    return None;
+  }
+  if !matches!(
+    args.get(1).map(String::as_str),
+    Some("proxy-worker" | "vpn-worker")
+  ) {
+    return None;
+  }
+  let id_at = args.iter().position(|a| a == "--id")? + 1;
+  args.get(id_at).map(String::as_str)
+}
+
+/// Processes from a previous run: browsers on a profile that isn't recorded
+/// as running, and workers whose config is gone.
+fn find_orphans(
+  processes: &[ProcessInfo],
+  profiles_dir: &Path,
+  running_profile_ids: &HashSet<String>,
+  worker_ids: &HashSet<String>,
+  min_age: Duration,
+) -> Vec<(u32, OrphanKind)> {
+  let profiles_dir = profiles_dir.to_string_lossy();
+  let own_pid = std::process::id();
+  processes
+    .iter()
+    .filter(|p| p.pid != own_pid && p.age >= min_age)
+    .filter_map(|p| {
+      if let Some(id) = worker_id_in_args(&p.args) {
+        return (!worker_ids.contains(id)).then_some((p.pid, OrphanKind::Worker));
+      }
+      // Renderer, GPU and other helpers exit with their main process.
+      if p.args.iter().any(|a| a.starts_with("--type=")) {
+        return None;
+      }
+      let profile_id = profile_id_in_args(&p.args, &profiles_dir)?;
+      (!running_profile_ids.contains(&profile_id)).then_some((p.pid, OrphanKind::Browser))
+    })
+    .collect()
+}
+
+/// Names of the singleton locks present in `data_dir`.
+pub fn present_locks(data_dir: &Path) -> Vec<&'static str> {
+  LOCK_FILES
+    .iter()
+    .copied()
+    // SingletonLock is a dangling symlink once its process is gone, so
+    // `exists()` would miss it.
+    .filter(|name| data_dir.join(name).symlink_metadata().is_ok())
+    .collect()
+}
+
+/// Delete the singleton locks in `data_dir`. Returns how many were removed.
+fn remove_stale_locks(data_dir: &Path) -> usize {
+  present_locks(data_dir)
+    .into_iter()
+    .map(|name| data_dir.join(name))
+    .filter(|path| match std::fs::remove_file(path) {
+      Ok(()) => true,
+      Err(e) => {
+        log::warn!("Failed to remove stale lock {}: {e}", path.display());
+        false
+      }
+    })
+    .count()
+}
+
+/// `list` reads the process table; tests narrow it to their own dummies so
+/// a test run never touches real browsers or workers.
+async fn cleanup(
+  list: impl Fn() -> Vec<ProcessInfo>,
+  profiles_dir: &Path,
+  profiles: &[BrowserProfile],
+  worker_ids: &HashSet<String>,
+  kill: bool,
+  min_age: Duration,
+) -> OrphanCleanupSummary {
+  let mut summary = OrphanCleanupSummary::default();
+  let running_profile_ids: HashSet<String> = profiles
+    .iter()
+    .filter(|p| {
+      p.process_id
+        .is_some_and(crate::process_registry::process_exists)
+    })
+    .map(|p| p.id.to_string())
+    .collect();
+
+  if kill {
+    let orphans = find_orphans(
+      &list(),
+      profiles_dir,
+      &running_profile_ids,
+      worker_ids,
+      min_age,
+    );
+    for (pid, kind) in orphans {
+      log::info!("Stopping orphaned {kind:?} process (PID {pid})");
+      if crate::process_registry::terminate(pid, Duration::from_secs(3)).await {
+        match kind {
+          OrphanKind::Browser => summary.browsers_killed += 1,
+          OrphanKind::Worker => summary.workers_killed += 1,
+        }
+      }
+    }
+  }
+
+  // A lock still held by a live process (running profile, or an orphan left
+  // alive because killing is off) is not stale.
+  let dir = profiles_dir.to_string_lossy();
+  let in_use: HashSet<String> = list()
+    .iter()
+    .filter_map(|p| profile_id_in_args(&p.args, &dir))
+    .chain(running_profile_ids)
+    .collect();
+  for profile in profiles {
+    if !in_use.contains(&profile.id.to_string()) {
+      summary.locks_removed += remove_stale_locks(&profile.get_profile_data_path(profiles_dir));
+    }
+  }
+
+  summary
+}
+
+/// Run one pass over the app's profiles and workers. `kill` off only clears
+/// locks nothing holds.
+pub async fn cleanup_orphans(kill: bool) -> OrphanCleanupSummary {
+  let profile_manager = crate::profile::ProfileManager::instance();
+  let profiles = profile_manager.list_profiles().unwrap_or_default();
+  let worker_ids: HashSet<String> = crate::proxy_storage::list_proxy_configs()
+    .into_iter()
+    .map(|c| c.id)
+    .chain(
+      crate::vpn_worker_storage::list_vpn_worker_configs()
+        .into_iter()
+        .map(|w| w.id),
+    )
+    .collect();
+
+  let summary = cleanup(
+    list_processes,
+    &profile_manager.get_profiles_dir(),
+    &profiles,
+    &worker_ids,
+    kill,
+    MIN_ORPHAN_AGE,
+  )
+  .await;
+
+  log::info!(
+    "Orphan cleanup: {} browser(s) and {} worker(s) stopped, {} stale lock(s) removed",
+    summary.browsers_killed,
+    summary.workers_killed,
+    summary.locks_removed
+  );
+  if let Err(e) = crate::events::emit(&summary) {
+    log::warn!("Failed to emit orphans-cleaned event: {e}");
+  }
+  summary
+}
+
+/// Startup pass; killing can be turned off in settings.
+pub fn spawn_startup_cleanup() {
+  tauri::async_runtime::spawn(async move {
+    let kill = crate::settings_manager::SettingsManager::instance()
+      .load_settings()
+      .map(|s| !s.disable_orphan_process_cleanup)
+      .unwrap_or(true);
+    cleanup_orphans(kill).await;
+  });
+}
+
+#[tauri::command]
+pub async fn cleanup_orphan_processes() -> Result<OrphanCleanupSummary, String> {
+  Ok(cleanup_orphans(true).await)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn process(pid: u32, args: &[&str]) -> ProcessInfo {
+    ProcessInfo {
+      pid,
+      args: args.iter().map(|a| a.to_string()).collect(),
+      age: Duration::from_secs(600),
+    }
+  }
+
+  #[test]
+  fn finds_untracked_browsers_and_workers() {
+    let profiles_dir = Path::new("/data/profiles");
+    let processes = vec![
+      process(
+        10,
+        &["wayfern", "--user-data-dir=/data/profiles/running/profile"],
+      ),
+      process(
+        11,
+        &["wayfern", "--user-data-dir=/data/profiles/crashed/profile"],
+      ),
+      process(
+        12,
+        &[
+          "wayfern",
+          "--type=renderer",
+          "--user-data-dir=/data/profiles/crashed/profile",
+        ],
+      ),
+      process(
+        13,
+        &["donut-proxy", "proxy-worker", "start", "--id", "known"],
+      ),
+      process(14, &["donut-proxy", "vpn-worker", "start", "--id", "gone"]),
+      process(15, &["chrome", "--user-data-dir=/home/me/.config/chrome"]),
+      ProcessInfo {
+        age: Duration::from_secs(5),
+        ..process(
+          16,
+          &["wayfern", "--user-data-dir=/data/profiles/starting/profile"],
+        )
+      },
+    ];
+    let running = HashSet::from(["running".to_string()]);
+    let workers = HashSet::from(["known".to_string()]);
+
+    assert_eq!(
+      find_orphans(&processes, profiles_dir, &running, &workers, MIN_ORPHAN_AGE),
+      vec![(11, OrphanKind::Browser), (14, OrphanKind::Worker)]
+    );
+  }
+
+  #[test]
+  fn removes_only_lock_files() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("SingletonCookie"), "").unwrap();
+    std::fs::write(dir.path().join("lockfile"), "").unwrap();
+    std::fs::write(dir.path().join("Local State"), "{}").unwrap();
+    #[cfg(unix)]
+    std::os::unix::fs::symlink("host-12345", dir.path().join("SingletonLock")).unwrap();
+
+    let expected = if cfg!(unix) { 3 } else { 2 };
+    assert_eq!(remove_stale_locks(dir.path()), expected);
+    assert!(dir.path().join("Local State").exists());
+    assert_eq!(remove_stale_locks(dir.path()), 0);
+  }
+
+  #[cfg(unix)]
+  #[tokio::test]
+  async fn kills_orphaned_browser_and_clears_its_locks() {
+    let profiles_dir = tempfile::tempdir().unwrap();
+    let profile = BrowserProfile {
+      id: uuid::Uuid::new_v4(),
+      ..Default::default()
+    };
+    let data_dir = profile.get_profile_data_path(profiles_dir.path());
+    std::fs::create_dir_all(&data_dir).unwrap();
+    std::os::unix::fs::symlink("host-1", data_dir.join("SingletonLock")).unwrap();
+
+    // A shell whose argv looks like a Wayfern main process on the profile.
+    let mut dummy = std::process::Command::new("sh")
+      .args(["-c", "sleep 30 & wait", "wayfern"])
+      .arg(format!("--user-data-dir={}", data_dir.display()))
+      .spawn()
+      .unwrap();
+
+    let dummy_pid = dummy.id();
+    let summary = cleanup(
+      || {
+        list_processes()
+          .into_iter()
+          .filter(|p| p.pid == dummy_pid)
+          .collect()
+      },
+      profiles_dir.path(),
+      std::slice::from_ref(&profile),
+      &HashSet::new(),
+      true,
+      Duration::ZERO,
+    )
+    .await;
+    let _ = dummy.wait();
+
+    assert_eq!(summary.browsers_killed, 1);
+    assert_eq!(summary.locks_removed, 1);
+    assert!(data_dir.join("SingletonLock").symlink_metadata().is_err());
+  }
+}
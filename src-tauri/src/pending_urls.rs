@@ -0,0 +1,178 @@
+//! URLs opened from outside the app: startup arguments, OS deep links and
+//! links handed over by a second instance. They are queued until the
+//! frontend reports with `frontend_ready` that its listeners are registered,
+//! then handed out one at a time in arrival order. Identical URLs arriving
+//! within a short window are dropped, so a double-clicked link opens the
+//! selector once.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tauri::Manager;
+
+/// Identical URLs arriving within this window are handled once.
+const DEDUPE_WINDOW: Duration = Duration::from_secs(5);
+/// Queued URLs nobody picked up within this time are dropped.
+const MAX_AGE: Duration = Duration::from_secs(5 * 60);
+/// The oldest URL is dropped once this many are waiting.
+const MAX_QUEUED: usize = 32;
+
+struct PendingUrls {
+  frontend_ready: bool,
+  queue: VecDeque<(String, Instant)>,
+  /// When each URL was last accepted, for deduplication.
+  recent: HashMap<String, Instant>,
+}
+
+impl PendingUrls {
+  fn new() -> Self {
+    Self {
+      frontend_ready: false,
+      queue: VecDeque::new(),
+      recent: HashMap::new(),
+    }
+  }
+
+  /// Queue `url` unless the same URL was accepted within the dedupe window.
+  fn push(&mut self, url: String, now: Instant) -> bool {
+    self
+      .recent
+      .retain(|_, at| now.duration_since(*at) < DEDUPE_WINDOW);
+    if self.recent.contains_key(&url) {
+      return false;
+    }
+    self.recent.insert(url.clone(), now);
+    self.queue.push_back((url, now));
+    while self.queue.len() > MAX_QUEUED {
+      self.queue.pop_front();
+      log::warn!("Pending URL queue is full, dropped the oldest URL");
+    }
+    true
+  }
+
+  /// The oldest URL that hasn't expired.
+  fn pop(&mut self, now: Instant) -> Option<String> {
+    while let Some((url, at)) = self.queue.pop_front() {
+      if now.duration_since(at) <= MAX_AGE {
+        return Some(url);
+      }
+      log::info!("Dropping a pending URL that waited too long");
+    }
+    None
+  }
+}
+
+lazy_static::lazy_static! {
+  static ref PENDING: Mutex<PendingUrls> = Mutex::new(PendingUrls::new());
+  /// Held while draining so concurrent drains can't reorder URLs.
+  static ref DRAIN_LOCK: tokio::sync::Mutex<()> = tokio::sync::Mutex::new(());
+}
+
+/// Queue a URL without handling it, for URLs known before the app is set up.
+pub fn push(url: String) {
+  if !PENDING.lock().unwrap().push(url, Instant::now()) {
+    log::debug!("Ignoring a URL that was just opened");
+  }
+}
+
+/// Queue a URL and, once the frontend is ready, hand it out.
+pub fn submit(app: &tauri::AppHandle, url: String) {
+  let ready = {
+    let mut pending = PENDING.lock().unwrap();
+    if !pending.push(url, Instant::now()) {
+      log::debug!("Ignoring a URL that was just opened");
+      return;
+    }
+    pending.frontend_ready
+  };
+  if ready {
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move { drain(app).await });
+  } else {
+    log::debug!("Frontend not ready yet, keeping URL queued");
+  }
+}
+
+async fn drain(app: tauri::AppHandle) {
+  let _guard = DRAIN_LOCK.lock().await;
+  loop {
+    let Some(url) = PENDING.lock().unwrap().pop(Instant::now()) else {
+      break;
+    };
+    if let Err(e) = open(&app, &url).await {
+      log::error!("Failed to handle URL open request: {e}");
+    }
+  }
+}
+
+async fn open(app: &tauri::AppHandle, url: &str) -> Result<(), String> {
+  log::info!("Handling URL open request");
+
+  // donut:// actions run directly; the window stays where it is.
+  if let Some(parsed) = crate::deep_link::parse(url) {
+    crate::deep_link::handle_action(app, url, parsed).await;
+    return Ok(());
+  }
+
+  if let Some(window) = app.get_webview_window("main") {
+    let _ = window.show();
+    let _ = window.set_focus();
+    let _ = window.unminimize();
+  }
+
+  crate::events::emit(&crate::events::payloads::ShowProfileSelector(
+    url.to_string(),
+  ))
+  .map_err(|e| format!("Failed to emit URL open event: {e}"))
+}
+
+/// Called by the frontend once its URL listeners are registered. Hands out
+/// everything queued so far; later URLs are handed out as they arrive.
+#[tauri::command]
+pub async fn frontend_ready(app: tauri::AppHandle) -> Result<(), String> {
+  PENDING.lock().unwrap().frontend_ready = true;
+  drain(app).await;
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn duplicates_within_the_window_are_dropped() {
+    let mut pending = PendingUrls::new();
+    let start = Instant::now();
+    assert!(pending.push("https://a.example".to_string(), start));
+    assert!(!pending.push(
+      "https://a.example".to_string(),
+      start + Duration::from_secs(1)
+    ));
+    assert!(pending.push(
+      "https://b.example".to_string(),
+      start + Duration::from_secs(1)
+    ));
+    assert!(pending.push("https://a.example".to_string(), start + DEDUPE_WINDOW));
+    let now = start + DEDUPE_WINDOW;
+    assert_eq!(pending.pop(now).as_deref(), Some("https://a.example"));
+    assert_eq!(pending.pop(now).as_deref(), Some("https://b.example"));
+    assert_eq!(pending.pop(now).as_deref(), Some("https://a.example"));
+    assert_eq!(pending.pop(now), None);
+  }
+
+  #[test]
+  fn queue_is_capped_and_old_entries_expire() {
+    let mut pending = PendingUrls::new();
+    let start = Instant::now();
+    for i in 0..MAX_QUEUED + 3 {
+      pending.push(format!("https://{i}.example"), start);
+    }
+    assert_eq!(pending.queue.len(), MAX_QUEUED);
+    assert_eq!(pending.pop(start).as_deref(), Some("https://3.example"));
+
+    pending.push("https://late.example".to_string(), start + MAX_AGE);
+    let later = start + MAX_AGE + Duration::from_secs(1);
+    assert_eq!(pending.pop(later).as_deref(), Some("https://late.example"));
+    assert_eq!(pending.pop(later), None);
+  }
+}
@@ -3,47 +3,11 @@ use crate::profile::BrowserProfile;
 use std::path::Path;
 use std::process::Command;
 
-/// True if a process command line refers to `profile_path` as a real browser
-/// profile/data-dir argument, NOT merely a substring. A bare `contains` match
-/// force-killed unrelated processes that happened to mention the path (editors,
-/// `tail`, a terminal that `cd`'d there, or another profile whose path has this
-/// one as a prefix). Mirrors the precise matching in browser_runner/wayfern_manager.
-///
-/// Only the macOS and Linux process-kill paths use this; Windows has no
-/// `find_processes_by_profile_path`, so gate it to avoid a dead-code error there.
-#[cfg(any(target_os = "macos", target_os = "linux"))]
-fn cmd_matches_profile_path(cmd: &[std::ffi::OsString], profile_path: &str) -> bool {
-  let args: Vec<&str> = cmd.iter().filter_map(|a| a.to_str()).collect();
-  for (i, arg) in args.iter().enumerate() {
-    // Exact argument equality (some launchers pass the path as its own arg).
-    if *arg == profile_path {
-      return true;
-    }
-    // `--user-data-dir=<path>` (Chromium/Wayfern) or `-profile=<path>`.
-    if let Some(val) = arg
-      .strip_prefix("--user-data-dir=")
-      .or_else(|| arg.strip_prefix("-profile="))
-    {
-      if val == profile_path {
-        return true;
-      }
-    }
-    // Flag followed by the path as the next argument.
-    if (*arg == "-profile" || *arg == "--user-data-dir")
-      && args.get(i + 1).is_some_and(|next| *next == profile_path)
-    {
-      return true;
-    }
-  }
-  false
-}
-
 // Platform-specific modules
 #[cfg(target_os = "macos")]
 #[allow(dead_code)]
 pub mod macos {
   use super::*;
-  use sysinfo::{Pid, System};
 
   pub async fn launch_browser_process(
     executable_path: &std::path::Path,
@@ -84,157 +48,6 @@ pub mod macos {
     }
   }
 
-  pub async fn kill_browser_process_impl(
-    pid: u32,
-    profile_data_path: Option<&str>,
-  ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-    log::info!("Attempting to kill browser process with PID: {pid}");
-
-    let mut pids_to_kill = vec![pid];
-
-    let descendants = get_all_descendant_pids(pid).await;
-    pids_to_kill.extend(descendants);
-
-    if let Some(profile_path) = profile_data_path {
-      let additional_pids = find_processes_by_profile_path(profile_path).await;
-      for p in additional_pids {
-        if !pids_to_kill.contains(&p) {
-          log::info!("Found additional process {} using profile path", p);
-          pids_to_kill.push(p);
-        }
-      }
-    }
-
-    log::info!("Total processes to kill: {:?}", pids_to_kill);
-
-    for &p in &pids_to_kill {
-      log::info!("Sending SIGKILL to PID: {p}");
-      let _ = Command::new("kill")
-        .args(["-KILL", &p.to_string()])
-        .output();
-    }
-
-    let pid_str = pid.to_string();
-
-    let _ = Command::new("pkill")
-      .args(["-KILL", "-P", &pid_str])
-      .output();
-
-    let _ = Command::new("pkill")
-      .args(["-KILL", "-g", &pid_str])
-      .output();
-
-    for &p in &pids_to_kill {
-      let system = System::new_all();
-      if system.process(Pid::from(p as usize)).is_some() {
-        log::info!("Process {p} still running, retrying kill");
-        let _ = Command::new("kill")
-          .args(["-KILL", &p.to_string()])
-          .output();
-      }
-    }
-
-    tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
-
-    let system = System::new_all();
-    let mut still_running = Vec::new();
-    for &p in &pids_to_kill {
-      if system.process(Pid::from(p as usize)).is_some() {
-        still_running.push(p);
-      }
-    }
-
-    if !still_running.is_empty() {
-      log::info!(
-        "Processes {:?} still running, trying final termination",
-        still_running
-      );
-
-      for p in &still_running {
-        let _ = Command::new("/bin/kill")
-          .args(["-KILL", &p.to_string()])
-          .output();
-      }
-
-      tokio::time::sleep(tokio::time::Duration::from_millis(1000)).await;
-
-      let system = System::new_all();
-      let mut final_still_running = Vec::new();
-      for &p in &pids_to_kill {
-        if system.process(Pid::from(p as usize)).is_some() {
-          final_still_running.push(p);
-        }
-      }
-
-      if !final_still_running.is_empty() {
-        log::error!(
-          "ERROR: Processes {:?} could not be terminated despite aggressive attempts",
-          final_still_running
-        );
-        return Err(
-          format!(
-            "Failed to terminate browser processes {:?} - still running",
-            final_still_running
-          )
-          .into(),
-        );
-      }
-    }
-
-    log::info!("Browser termination completed for PID: {pid}");
-    Ok(())
-  }
-
-  async fn find_processes_by_profile_path(profile_path: &str) -> Vec<u32> {
-    use sysinfo::System;
-
-    let mut pids = Vec::new();
-    let system = System::new_all();
-
-    for (pid, process) in system.processes() {
-      let cmd = process.cmd();
-      if cmd.is_empty() {
-        continue;
-      }
-
-      if cmd_matches_profile_path(cmd, profile_path) {
-        pids.push(pid.as_u32());
-      }
-    }
-
-    pids
-  }
-
-  // Recursively find all descendant processes
-  async fn get_all_descendant_pids(parent_pid: u32) -> Vec<u32> {
-    use sysinfo::System;
-
-    let system = System::new_all();
-    let mut descendants = Vec::new();
-    let mut to_check = vec![parent_pid];
-    let mut checked = std::collections::HashSet::new();
-
-    while let Some(current_pid) = to_check.pop() {
-      if checked.contains(&current_pid) {
-        continue;
-      }
-      checked.insert(current_pid);
-
-      // Find direct children of current_pid
-      for (pid, process) in system.processes() {
-        let pid_u32 = pid.as_u32();
-        if let Some(parent) = process.parent() {
-          if parent.as_u32() == current_pid && !checked.contains(&pid_u32) {
-            descendants.push(pid_u32);
-            to_check.push(pid_u32);
-          }
-        }
-      }
-    }
-
-    descendants
-  }
-
   pub async fn open_url_in_existing_browser_chromium(
     profile: &BrowserProfile,
     url: &str,
@@ -383,50 +196,6 @@ pub mod windows {
     let _child = cmd.spawn()?;
     Ok(())
   }
-
-  pub async fn kill_browser_process_impl(
-    pid: u32,
-  ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-    // First try using sysinfo (cross-platform approach)
-    use sysinfo::{Pid, System};
-    let system = System::new_all();
-    if let Some(process) = system.process(Pid::from(pid as usize)) {
-      if process.kill() {
-        log::info!("Successfully killed browser process with PID: {pid}");
-        return Ok(());
-      }
-    }
-
-    // Fallback to Windows-specific process termination
-    use std::process::Command;
-
-    // Try taskkill command as fallback
-    use std::os::windows::process::CommandExt;
-    const CREATE_NO_WINDOW: u32 = 0x08000000;
-    let output = Command::new("taskkill")
-      .args(["/F", "/PID", &pid.to_string()])
-      .creation_flags(CREATE_NO_WINDOW)
-      .output();
-
-    match output {
-      Ok(result) => {
-        if result.status.success() {
-          log::info!("Successfully killed browser process with PID: {pid} using taskkill");
-          Ok(())
-        } else {
-          Err(
-            format!(
-              "Failed to kill process {} with taskkill: {}",
-              pid,
-              String::from_utf8_lossy(&result.stderr)
-            )
-            .into(),
-          )
-        }
-      }
-      Err(e) => Err(format!("Failed to execute taskkill for process {}: {}", pid, e).into()),
-    }
-  }
 }
 
 #[cfg(target_os = "linux")]
@@ -592,153 +361,4 @@ pub mod linux {
 
     Ok(())
   }
-
-  pub async fn kill_browser_process_impl(
-    pid: u32,
-    profile_data_path: Option<&str>,
-  ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-    use sysinfo::{Pid, ProcessRefreshKind, RefreshKind, System};
-
-    log::info!("Attempting to kill browser process with PID: {pid}");
-
-    let mut pids_to_kill = vec![pid];
-
-    // Find all descendant processes
-    let descendants = get_all_descendant_pids(pid);
-    pids_to_kill.extend(descendants);
-
-    // Find additional processes using the same profile path
-    if let Some(profile_path) = profile_data_path {
-      let additional_pids = find_processes_by_profile_path(profile_path);
-      for p in additional_pids {
-        if !pids_to_kill.contains(&p) {
-          log::info!("Found additional process {} using profile path", p);
-          pids_to_kill.push(p);
-        }
-      }
-    }
-
-    log::info!("Total processes to kill: {:?}", pids_to_kill);
-
-    // Send SIGKILL to all identified processes
-    for &p in &pids_to_kill {
-      log::info!("Sending SIGKILL to PID: {p}");
-      let _ = Command::new("kill")
-        .args(["-KILL", &p.to_string()])
-        .output();
-    }
-
-    // Also kill by process group and parent PID
-    let pid_str = pid.to_string();
-    let _ = Command::new("pkill")
-      .args(["-KILL", "-P", &pid_str])
-      .output();
-
-    tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
-
-    // Verify processes are dead
-    let system = System::new_with_specifics(
-      RefreshKind::nothing().with_processes(ProcessRefreshKind::everything()),
-    );
-    let mut still_running = Vec::new();
-    for &p in &pids_to_kill {
-      if system.process(Pid::from(p as usize)).is_some() {
-        still_running.push(p);
-      }
-    }
-
-    if !still_running.is_empty() {
-      log::info!(
-        "Processes {:?} still running, trying final termination",
-        still_running
-      );
-
-      for p in &still_running {
-        let _ = Command::new("kill")
-          .args(["-KILL", &p.to_string()])
-          .output();
-      }
-
-      tokio::time::sleep(tokio::time::Duration::from_millis(1000)).await;
-
-      let system = System::new_with_specifics(
-        RefreshKind::nothing().with_processes(ProcessRefreshKind::everything()),
-      );
-      let mut final_still_running = Vec::new();
-      for &p in &pids_to_kill {
-        if system.process(Pid::from(p as usize)).is_some() {
-          final_still_running.push(p);
-        }
-      }
-
-      if !final_still_running.is_empty() {
-        log::error!(
-          "ERROR: Processes {:?} could not be terminated despite aggressive attempts",
-          final_still_running
-        );
-        return Err(
-          format!(
-            "Failed to terminate browser processes {:?} - still running",
-            final_still_running
-          )
-          .into(),
-        );
-      }
-    }
-
-    log::info!("Browser termination completed for PID: {pid}");
-    Ok(())
-  }
-
-  fn find_processes_by_profile_path(profile_path: &str) -> Vec<u32> {
-    use sysinfo::{ProcessRefreshKind, RefreshKind, System};
-
-    let mut pids = Vec::new();
-    let system = System::new_with_specifics(
-      RefreshKind::nothing().with_processes(ProcessRefreshKind::everything()),
-    );
-
-    for (pid, process) in system.processes() {
-      let cmd = process.cmd();
-      if cmd.is_empty() {
-        continue;
-      }
-
-      if cmd_matches_profile_path(cmd, profile_path) {
-        pids.push(pid.as_u32());
-      }
-    }
-
-    pids
-  }
-
-  fn get_all_descendant_pids(parent_pid: u32) -> Vec<u32> {
-    use sysinfo::{ProcessRefreshKind, RefreshKind, System};
-
-    let system = System::new_with_specifics(
-      RefreshKind::nothing().with_processes(ProcessRefreshKind::everything()),
-    );
-    let mut descendants = Vec::new();
-    let mut to_check = vec![parent_pid];
-    let mut checked = std::collections::HashSet::new();
-
-    while let Some(current_pid) = to_check.pop() {
-      if checked.contains(&current_pid) {
-        continue;
-      }
-      checked.insert(current_pid);
-
-      for (pid, process) in system.processes() {
-        let pid_u32 = pid.as_u32();
-        if let Some(parent) = process.parent() {
-          if parent.as_u32() == current_pid && !checked.contains(&pid_u32) {
-            descendants.push(pid_u32);
-            to_check.push(pid_u32);
-          }
-        }
-      }
-    }
-
-    descendants
-  }
 }
@@ -0,0 +1,350 @@
+//! Launch pre-flight: every check that can predict a failed launch, run
+//! without starting anything. Each result names the fix the UI can offer,
+//! so a missing binary or GeoIP database is one click away. With
+//! `preflight_before_launch` on, launches run it first and refuse on a
+//! failed check.
+
+use serde::Serialize;
+use std::path::Path;
+use std::time::Duration;
+use utoipa::ToSchema;
+
+use crate::profile::BrowserProfile;
+
+const PROXY_CONNECT_TIMEOUT: Duration = Duration::from_secs(3);
+/// Below this much free space a launch may still work, but the profile can
+/// no longer grow its cache or save a session reliably.
+const MIN_FREE_DISK_BYTES: u64 = 512 * 1024 * 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PreflightCheckKind {
+  WayfernTerms,
+  Binary,
+  Executable,
+  Proxy,
+  Vpn,
+  Geoip,
+  DiskSpace,
+  ProfileLocks,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PreflightStatus {
+  Pass,
+  /// The launch will most likely still work.
+  Warn,
+  /// The launch will fail.
+  Fail,
+  /// Not relevant to this profile.
+  Skipped,
+}
+
+/// The one-click fix for a check, named after the command that performs it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PreflightFix {
+  AcceptWayfernTerms,
+  DownloadBrowser,
+  DownloadGeoipDatabase,
+  CleanupOrphanProcesses,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct PreflightCheck {
+  pub check: PreflightCheckKind,
+  pub status: PreflightStatus,
+  pub detail: Option<String>,
+  pub fixable_by: Option<PreflightFix>,
+}
+
+impl PreflightCheck {
+  fn new(check: PreflightCheckKind, status: PreflightStatus) -> Self {
+    Self {
+      check,
+      status,
+      detail: None,
+      fixable_by: None,
+    }
+  }
+
+  fn detail(mut self, detail: impl Into<String>) -> Self {
+    self.detail = Some(detail.into());
+    self
+  }
+
+  fn fix(mut self, fix: PreflightFix) -> Self {
+    self.fixable_by = Some(fix);
+    self
+  }
+}
+
+fn check_terms(profile: &BrowserProfile) -> PreflightCheck {
+  use PreflightCheckKind::WayfernTerms;
+  if profile.browser != "wayfern" {
+    return PreflightCheck::new(WayfernTerms, PreflightStatus::Skipped);
+  }
+  if crate::wayfern_terms::WayfernTermsManager::instance().is_terms_accepted() {
+    PreflightCheck::new(WayfernTerms, PreflightStatus::Pass)
+  } else {
+    PreflightCheck::new(WayfernTerms, PreflightStatus::Fail).fix(PreflightFix::AcceptWayfernTerms)
+  }
+}
+
+/// The binary check and, when it passes, the executable check.
+fn check_binary(profile: &BrowserProfile) -> [PreflightCheck; 2] {
+  use PreflightCheckKind::{Binary, Executable};
+  let registry = crate::downloaded_browsers_registry::DownloadedBrowsersRegistry::instance();
+  if !registry.is_browser_downloaded(&profile.browser, &profile.version) {
+    return [
+      PreflightCheck::new(Binary, PreflightStatus::Fail)
+        .detail(format!(
+          "{} {} is not downloaded",
+          profile.browser, profile.version
+        ))
+        .fix(PreflightFix::DownloadBrowser),
+      PreflightCheck::new(Executable, PreflightStatus::Skipped),
+    ];
+  }
+  let binary = PreflightCheck::new(Binary, PreflightStatus::Pass);
+  let executable =
+    match crate::browser_runner::BrowserRunner::instance().get_browser_executable_path(profile) {
+      Ok(path) => match executable_problem(&path) {
+        None => PreflightCheck::new(Executable, PreflightStatus::Pass),
+        Some(problem) => PreflightCheck::new(Executable, PreflightStatus::Fail)
+          .detail(format!("{}: {problem}", path.display()))
+          .fix(PreflightFix::DownloadBrowser),
+      },
+      Err(e) => PreflightCheck::new(Executable, PreflightStatus::Fail)
+        .detail(e.to_string())
+        .fix(PreflightFix::DownloadBrowser),
+    };
+  [binary, executable]
+}
+
+fn executable_problem(path: &Path) -> Option<&'static str> {
+  let Ok(metadata) = std::fs::metadata(path) else {
+    return Some("missing");
+  };
+  if !metadata.is_file() {
+    return Some("not a file");
+  }
+  #[cfg(unix)]
+  {
+    use std::os::unix::fs::PermissionsExt;
+    if metadata.permissions().mode() & 0o111 == 0 {
+      return Some("not executable");
+    }
+  }
+  None
+}
+
+/// Whether the proxy's first hop accepts a TCP connection. A pool passes as
+/// soon as one member does, since launches fail over between members.
+async fn check_proxy(profile: &BrowserProfile) -> PreflightCheck {
+  use crate::proxy_manager::PROXY_MANAGER;
+  use PreflightCheckKind::Proxy;
+
+  let proxy_ids = if let Some(pool_id) = &profile.proxy_pool_id {
+    match PROXY_MANAGER.pool_launch_order(pool_id, &profile.id.to_string()) {
+      Ok(ids) => ids,
+      Err(e) => return PreflightCheck::new(Proxy, PreflightStatus::Fail).detail(e),
+    }
+  } else if let Some(proxy_id) = &profile.proxy_id {
+    vec![proxy_id.clone()]
+  } else {
+    return PreflightCheck::new(Proxy, PreflightStatus::Skipped);
+  };
+
+  let mut last_error = None;
+  for proxy_id in &proxy_ids {
+    let Some(stored) = PROXY_MANAGER.get_stored_proxy(proxy_id) else {
+      last_error = Some(format!("proxy {proxy_id} no longer exists"));
+      continue;
+    };
+    if stored.dynamic_proxy_url.is_some() {
+      return PreflightCheck::new(Proxy, PreflightStatus::Skipped)
+        .detail("dynamic proxies are resolved at launch");
+    }
+    let first_hop = PROXY_MANAGER
+      .get_proxy_entry_hops(proxy_id)
+      .into_iter()
+      .next()
+      .unwrap_or(stored.proxy_settings);
+    let address = crate::ip_utils::host_port(&first_hop.host, first_hop.port);
+    match tokio::time::timeout(
+      PROXY_CONNECT_TIMEOUT,
+      tokio::net::TcpStream::connect(address.as_str()),
+    )
+    .await
+    {
+      Ok(Ok(_)) => return PreflightCheck::new(Proxy, PreflightStatus::Pass),
+      Ok(Err(e)) => last_error = Some(format!("{address}: {e}")),
+      Err(_) => last_error = Some(format!("{address}: timed out")),
+    }
+  }
+  PreflightCheck::new(Proxy, PreflightStatus::Fail).detail(last_error.unwrap_or_default())
+}
+
+fn check_vpn(profile: &BrowserProfile) -> PreflightCheck {
+  use PreflightCheckKind::Vpn;
+  let Some(vpn_id) = &profile.vpn_id else {
+    return PreflightCheck::new(Vpn, PreflightStatus::Skipped);
+  };
+  let loaded = crate::vpn::VPN_STORAGE
+    .lock()
+    .map_err(|e| e.to_string())
+    .and_then(|storage| storage.load_config(vpn_id).map_err(|e| e.to_string()))
+    .and_then(|config| match config.vpn_type {
+      crate::vpn::VpnType::WireGuard => crate::vpn::parse_wireguard_config(&config.config_data)
+        .map(|_| ())
+        .map_err(|e| e.to_string()),
+    });
+  match loaded {
+    Ok(()) => PreflightCheck::new(Vpn, PreflightStatus::Pass),
+    Err(e) => PreflightCheck::new(Vpn, PreflightStatus::Fail).detail(e),
+  }
+}
+
+/// Without the database the fingerprint's location falls back to the host's,
+/// so this warns rather than fails.
+fn check_geoip(profile: &BrowserProfile) -> PreflightCheck {
+  use PreflightCheckKind::Geoip;
+  let geoip = profile
+    .wayfern_config
+    .as_ref()
+    .and_then(|config| config.geoip.as_ref());
+  if matches!(geoip, None | Some(serde_json::Value::Bool(false))) {
+    return PreflightCheck::new(Geoip, PreflightStatus::Skipped);
+  }
+  if crate::geoip_downloader::GeoIPDownloader::is_geoip_database_available() {
+    PreflightCheck::new(Geoip, PreflightStatus::Pass)
+  } else {
+    PreflightCheck::new(Geoip, PreflightStatus::Warn).fix(PreflightFix::DownloadGeoipDatabase)
+  }
+}
+
+/// Free space on the disk holding `path`: the one with the longest mount
+/// point that contains it.
+fn available_space(path: &Path) -> Option<u64> {
+  let disks = sysinfo::Disks::new_with_refreshed_list();
+  disks
+    .list()
+    .iter()
+    .filter(|disk| path.starts_with(disk.mount_point()))
+    .max_by_key(|disk| disk.mount_point().as_os_str().len())
+    .map(|disk| disk.available_space())
+}
+
+fn check_disk_space(profile_path: &Path) -> PreflightCheck {
+  use PreflightCheckKind::DiskSpace;
+  match available_space(profile_path) {
+    Some(bytes) if bytes < MIN_FREE_DISK_BYTES => {
+      PreflightCheck::new(DiskSpace, PreflightStatus::Warn)
+        .detail(format!("{} MB free", bytes / (1024 * 1024)))
+    }
+    Some(_) => PreflightCheck::new(DiskSpace, PreflightStatus::Pass),
+    None => PreflightCheck::new(DiskSpace, PreflightStatus::Skipped),
+  }
+}
+
+/// Chromium refuses a user-data-dir with a leftover singleton lock, but the
+/// lock belongs there while the profile runs.
+fn check_locks(profile: &BrowserProfile, profile_path: &Path) -> PreflightCheck {
+  use PreflightCheckKind::ProfileLocks;
+  if profile
+    .process_id
+    .is_some_and(crate::process_registry::process_exists)
+  {
+    return PreflightCheck::new(ProfileLocks, PreflightStatus::Skipped);
+  }
+  let locks = crate::orphan_cleanup::present_locks(profile_path);
+  if locks.is_empty() {
+    PreflightCheck::new(ProfileLocks, PreflightStatus::Pass)
+  } else {
+    PreflightCheck::new(ProfileLocks, PreflightStatus::Warn)
+      .detail(locks.join(", "))
+      .fix(PreflightFix::CleanupOrphanProcesses)
+  }
+}
+
+pub async fn run_preflight(profile: &BrowserProfile) -> Vec<PreflightCheck> {
+  let profiles_dir = crate::profile::ProfileManager::instance().get_profiles_dir();
+  let profile_path = crate::ephemeral_dirs::get_effective_profile_path(profile, &profiles_dir);
+
+  let mut checks = vec![check_terms(profile)];
+  checks.extend(check_binary(profile));
+  checks.push(check_proxy(profile).await);
+  checks.push(check_vpn(profile));
+  checks.push(check_geoip(profile));
+  checks.push(check_disk_space(&profile_path));
+  checks.push(check_locks(profile, &profile_path));
+  checks
+}
+
+/// Error for a launch that `preflight_before_launch` stopped, or `None` when
+/// no check failed.
+pub fn launch_blocker(checks: &[PreflightCheck]) -> Option<String> {
+  let failed: Vec<String> = checks
+    .iter()
+    .filter(|c| c.status == PreflightStatus::Fail)
+    .filter_map(|c| serde_json::to_value(c.check).ok())
+    .filter_map(|v| v.as_str().map(str::to_string))
+    .collect();
+  (!failed.is_empty()).then(|| {
+    serde_json::json!({ "code": "PREFLIGHT_FAILED", "params": { "checks": failed.join(", ") } })
+      .to_string()
+  })
+}
+
+pub async fn preflight_profile_launch_impl(
+  profile_id: &str,
+) -> Result<Vec<PreflightCheck>, String> {
+  let profile = crate::profile::ProfileManager::instance()
+    .list_profiles()
+    .map_err(|e| format!("Failed to list profiles: {e}"))?
+    .into_iter()
+    .find(|p| p.id.to_string() == profile_id)
+    .ok_or_else(|| serde_json::json!({ "code": "PROFILE_NOT_FOUND" }).to_string())?;
+  Ok(run_preflight(&profile).await)
+}
+
+#[tauri::command]
+pub async fn preflight_profile_launch(profile_id: String) -> Result<Vec<PreflightCheck>, String> {
+  preflight_profile_launch_impl(&profile_id).await
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn launch_blocker_lists_only_failed_checks() {
+    let checks = vec![
+      PreflightCheck::new(PreflightCheckKind::Binary, PreflightStatus::Fail),
+      PreflightCheck::new(PreflightCheckKind::Geoip, PreflightStatus::Warn),
+      PreflightCheck::new(PreflightCheckKind::Proxy, PreflightStatus::Fail),
+    ];
+    let error = launch_blocker(&checks).unwrap();
+    let value: serde_json::Value = serde_json::from_str(&error).unwrap();
+    assert_eq!(value["code"], "PREFLIGHT_FAILED");
+    assert_eq!(value["params"]["checks"], "binary, proxy");
+    assert!(launch_blocker(&checks[1..2]).is_none());
+  }
+
+  #[cfg(unix)]
+  #[test]
+  fn executable_problem_checks_mode_bits() {
+    use std::os::unix::fs::PermissionsExt;
+    let tmp = tempfile::tempdir().unwrap();
+    let exe = tmp.path().join("wayfern");
+    assert_eq!(executable_problem(&exe), Some("missing"));
+    std::fs::write(&exe, b"").unwrap();
+    std::fs::set_permissions(&exe, std::fs::Permissions::from_mode(0o644)).unwrap();
+    assert_eq!(executable_problem(&exe), Some("not executable"));
+    std::fs::set_permissions(&exe, std::fs::Permissions::from_mode(0o755)).unwrap();
+    assert_eq!(executable_problem(&exe), None);
+    assert_eq!(executable_problem(tmp.path()), Some("not a file"));
+  }
+}
@@ -0,0 +1,458 @@
+//! Shared process-table access and a registry of sidecar worker processes.
+//!
+//! Status checks and kill verification used to build a fresh
+//! `sysinfo::System` per call. Under a tight launch/kill loop that churn adds
+//! up, so a single long-lived `System` ([`ProcessWatcher`]) is refreshed in
+//! place instead, and full scans are shared between callers. Worker
+//! processes spawned on behalf of a profile are tracked here so kill paths can
+//! reap leftovers even when the browser crashed and the normal proxy stop
+//! never ran.
+
+use lazy_static::lazy_static;
+use std::collections::{HashMap, HashSet};
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, RwLock};
+use std::time::{Duration, Instant};
+use sysinfo::{Pid, ProcessRefreshKind, ProcessStatus, ProcessesToUpdate, Signal, System};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChildKind {
+  ProxyWorker,
+  VpnWorker,
+}
+
+#[derive(Debug, Clone)]
+struct TrackedChild {
+  kind: ChildKind,
+  profile_id: Option<String>,
+}
+
+/// Full process-table scans are shared: callers within this long of the last
+/// one read it instead of scanning again.
+const TABLE_REFRESH_INTERVAL: Duration = Duration::from_millis(300);
+
+/// A process as of the last table refresh.
+#[derive(Debug, Clone)]
+pub struct WatchedProcess {
+  pub pid: u32,
+  pub parent: Option<u32>,
+  pub name: String,
+  pub cmd: Vec<OsString>,
+}
+
+impl WatchedProcess {
+  fn from_sysinfo(pid: Pid, process: &sysinfo::Process) -> Self {
+    Self {
+      pid: pid.as_u32(),
+      parent: process.parent().map(Pid::as_u32),
+      name: process.name().to_string_lossy().into_owned(),
+      cmd: process.cmd().to_vec(),
+    }
+  }
+}
+
+/// The one `System` every process query goes through. Full-table reads are
+/// rate limited to one scan per [`TABLE_REFRESH_INTERVAL`] however many
+/// callers ask at once; single-PID checks refresh just that entry.
+pub struct ProcessWatcher {
+  system: RwLock<System>,
+  /// Held across a full refresh so concurrent callers wait for it and reuse
+  /// the result instead of scanning again.
+  refreshed_at: Mutex<Option<Instant>>,
+  refresh_interval: Duration,
+  refreshes: AtomicU64,
+}
+
+impl ProcessWatcher {
+  fn new(refresh_interval: Duration) -> Self {
+    Self {
+      system: RwLock::new(System::new()),
+      refreshed_at: Mutex::new(None),
+      refresh_interval,
+      refreshes: AtomicU64::new(0),
+    }
+  }
+
+  fn write(&self) -> std::sync::RwLockWriteGuard<'_, System> {
+    self
+      .system
+      .write()
+      .unwrap_or_else(|poisoned| poisoned.into_inner())
+  }
+
+  fn refresh_table_if_stale(&self) {
+    let mut refreshed_at = self
+      .refreshed_at
+      .lock()
+      .unwrap_or_else(|poisoned| poisoned.into_inner());
+    if refreshed_at.is_some_and(|at| at.elapsed() < self.refresh_interval) {
+      return;
+    }
+    // Dead processes are dropped on every refresh so the table doesn't grow
+    // with each launched and exited browser.
+    self.write().refresh_processes_specifics(
+      ProcessesToUpdate::All,
+      true,
+      ProcessRefreshKind::everything(),
+    );
+    *refreshed_at = Some(Instant::now());
+    self.refreshes.fetch_add(1, Ordering::Relaxed);
+  }
+
+  /// Hands the process table (name, cmd, exe), at most one refresh interval
+  /// old, to `f`.
+  pub fn with_table<R>(&self, f: impl FnOnce(&System) -> R) -> R {
+    self.refresh_table_if_stale();
+    let system = self
+      .system
+      .read()
+      .unwrap_or_else(|poisoned| poisoned.into_inner());
+    f(&system)
+  }
+
+  /// `pid` as of the last table refresh.
+  pub fn process(&self, pid: u32) -> Option<WatchedProcess> {
+    let pid = Pid::from_u32(pid);
+    self.with_table(|system| {
+      system
+        .process(pid)
+        .map(|process| WatchedProcess::from_sysinfo(pid, process))
+    })
+  }
+
+  /// Processes with a command-line argument containing `path`. Callers
+  /// decide which of these really run on it.
+  pub fn find_by_path(&self, path: &str) -> Vec<WatchedProcess> {
+    self.with_table(|system| {
+      system
+        .processes()
+        .iter()
+        .filter(|(_, process)| {
+          process
+            .cmd()
+            .iter()
+            .any(|arg| arg.to_string_lossy().contains(path))
+        })
+        .map(|(pid, process)| WatchedProcess::from_sysinfo(*pid, process))
+        .collect()
+    })
+  }
+
+  /// Whether `pid` exists right now. Refreshes only that entry.
+  pub fn is_alive(&self, pid: u32) -> bool {
+    let pid = Pid::from_u32(pid);
+    let mut system = self.write();
+    system.refresh_processes_specifics(
+      ProcessesToUpdate::Some(&[pid]),
+      true,
+      ProcessRefreshKind::nothing(),
+    );
+    system.process(pid).is_some()
+  }
+
+  /// Refreshes the given PIDs and hands the table to `f`, for callers that
+  /// act on specific processes (signals, zombie checks).
+  fn with_fresh<R>(&self, pids: &[Pid], f: impl FnOnce(&System) -> R) -> R {
+    let mut system = self.write();
+    system.refresh_processes_specifics(
+      ProcessesToUpdate::Some(pids),
+      true,
+      ProcessRefreshKind::nothing(),
+    );
+    f(&system)
+  }
+
+  /// Full table scans done so far.
+  pub fn refresh_count(&self) -> u64 {
+    self.refreshes.load(Ordering::Relaxed)
+  }
+}
+
+lazy_static! {
+  pub static ref PROCESS_WATCHER: ProcessWatcher = ProcessWatcher::new(TABLE_REFRESH_INTERVAL);
+  static ref CHILDREN: Mutex<HashMap<u32, TrackedChild>> = Mutex::new(HashMap::new());
+}
+
+fn lock_children() -> std::sync::MutexGuard<'static, HashMap<u32, TrackedChild>> {
+  CHILDREN
+    .lock()
+    .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Existence check for a single PID. Refreshes only that entry.
+pub fn process_exists(pid: u32) -> bool {
+  PROCESS_WATCHER.is_alive(pid)
+}
+
+/// Hands the shared process table to `f`; see [`ProcessWatcher::with_table`].
+pub fn with_process_table<R>(f: impl FnOnce(&System) -> R) -> R {
+  PROCESS_WATCHER.with_table(f)
+}
+
+/// Kills `pid` outright. Returns whether a signal was sent.
+pub fn kill_process(pid: u32) -> bool {
+  let pid = Pid::from_u32(pid);
+  PROCESS_WATCHER.with_fresh(&[pid], |system| {
+    system.process(pid).is_some_and(|process| process.kill())
+  })
+}
+
+/// A running Chromium-family main process, keyed in [`ProcessSnapshot`] by
+/// its `--user-data-dir`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BrowserProcess {
+  pub pid: u32,
+  pub cdp_port: Option<u16>,
+}
+
+/// One refresh of the process table, reduced to what status checks need, so a
+/// pass over many profiles costs a single scan instead of one per profile.
+#[derive(Debug, Default)]
+pub struct ProcessSnapshot {
+  alive: HashSet<u32>,
+  browsers: HashMap<PathBuf, BrowserProcess>,
+}
+
+impl ProcessSnapshot {
+  pub fn capture() -> Self {
+    with_process_table(|system| {
+      let mut snapshot = Self::default();
+      for (pid, process) in system.processes() {
+        snapshot.alive.insert(pid.as_u32());
+
+        let exe_name = process.name().to_string_lossy().to_lowercase();
+        if !(exe_name.contains("wayfern")
+          || exe_name.contains("chromium")
+          || exe_name.contains("chrome"))
+        {
+          continue;
+        }
+        let args: Vec<&str> = process.cmd().iter().filter_map(|a| a.to_str()).collect();
+        // Child processes (renderer, GPU, utility, zygote, etc.) carry
+        // --type=; only the main browser process lacks it.
+        if args.iter().any(|a| a.starts_with("--type=")) {
+          continue;
+        }
+        let Some(dir) = args.iter().find_map(|a| a.strip_prefix("--user-data-dir=")) else {
+          continue;
+        };
+        let cdp_port = args
+          .iter()
+          .find_map(|a| a.strip_prefix("--remote-debugging-port="))
+          .and_then(|port| port.parse().ok());
+        snapshot.browsers.insert(
+          canonical(Path::new(dir)),
+          BrowserProcess {
+            pid: pid.as_u32(),
+            cdp_port,
+          },
+        );
+      }
+      snapshot
+    })
+  }
+
+  pub fn is_alive(&self, pid: u32) -> bool {
+    self.alive.contains(&pid)
+  }
+
+  /// The browser running on `user_data_dir`, if any.
+  pub fn browser_for(&self, user_data_dir: &Path) -> Option<BrowserProcess> {
+    self.browsers.get(&canonical(user_data_dir)).copied()
+  }
+
+  #[cfg(test)]
+  pub fn insert_browser(&mut self, user_data_dir: &Path, browser: BrowserProcess) {
+    self.alive.insert(browser.pid);
+    self.browsers.insert(canonical(user_data_dir), browser);
+  }
+}
+
+fn canonical(path: &Path) -> PathBuf {
+  path.canonicalize().unwrap_or_else(|_| path.to_path_buf())
+}
+
+pub fn register_child(pid: u32, kind: ChildKind, profile_id: Option<&str>) {
+  if pid == 0 {
+    return;
+  }
+  lock_children().insert(
+    pid,
+    TrackedChild {
+      kind,
+      profile_id: profile_id.map(str::to_string),
+    },
+  );
+}
+
+pub fn unregister_child(pid: u32) {
+  lock_children().remove(&pid);
+}
+
+/// Drops registry entries whose process has exited. Returns how many were removed.
+pub fn sweep_dead_children() -> usize {
+  let pids: Vec<u32> = lock_children().keys().copied().collect();
+  let dead: Vec<u32> = pids
+    .into_iter()
+    .filter(|pid| !process_exists(*pid))
+    .collect();
+  let mut children = lock_children();
+  for pid in &dead {
+    children.remove(pid);
+  }
+  dead.len()
+}
+
+/// Kills any worker still registered to `profile_id`. Called from the kill
+/// paths after the regular proxy stop, so a worker whose stop failed (or was
+/// never attempted because the browser had already crashed) doesn't outlive
+/// the profile session. Returns how many processes were signalled.
+pub fn reap_profile_children(profile_id: &str) -> usize {
+  let owned: Vec<(u32, ChildKind)> = lock_children()
+    .iter()
+    .filter(|(_, child)| child.profile_id.as_deref() == Some(profile_id))
+    .map(|(pid, child)| (*pid, child.kind))
+    .collect();
+  if owned.is_empty() {
+    return 0;
+  }
+
+  let pids: Vec<Pid> = owned.iter().map(|(pid, _)| Pid::from_u32(*pid)).collect();
+  let signalled = PROCESS_WATCHER.with_fresh(&pids, |system| {
+    let mut signalled = 0;
+    for (pid, kind) in &owned {
+      if let Some(process) = system.process(Pid::from_u32(*pid)) {
+        log::info!("Reaping leftover {kind:?} (PID {pid}) for profile {profile_id}");
+        if process.kill() {
+          signalled += 1;
+        }
+      }
+    }
+    signalled
+  });
+
+  let mut children = lock_children();
+  for (pid, _) in owned {
+    children.remove(&pid);
+  }
+  signalled
+}
+
+/// Like [`process_exists`], but an exited child its parent hasn't reaped yet
+/// (a zombie) counts as gone: it holds no files and can't be signalled.
+pub(crate) fn process_alive(pid: u32) -> bool {
+  let pid = Pid::from_u32(pid);
+  PROCESS_WATCHER.with_fresh(&[pid], |system| {
+    system
+      .process(pid)
+      .is_some_and(|process| process.status() != ProcessStatus::Zombie)
+  })
+}
+
+fn signal(pid: u32, force: bool) {
+  let pid = Pid::from_u32(pid);
+  PROCESS_WATCHER.with_fresh(&[pid], |system| {
+    if let Some(process) = system.process(pid) {
+      // SIGTERM isn't available everywhere (Windows); fall back to a hard kill.
+      if force || process.kill_with(Signal::Term).is_none() {
+        process.kill();
+      }
+    }
+  });
+}
+
+async fn wait_for_exit(pid: u32, timeout: Duration) -> bool {
+  let deadline = tokio::time::Instant::now() + timeout;
+  loop {
+    if !process_alive(pid) {
+      return true;
+    }
+    if tokio::time::Instant::now() >= deadline {
+      return false;
+    }
+    tokio::time::sleep(Duration::from_millis(100)).await;
+  }
+}
+
+/// Asks `pid` to exit, then kills it if it is still around after `grace`.
+/// Returns whether the process is gone.
+pub async fn terminate(pid: u32, grace: Duration) -> bool {
+  if !process_alive(pid) {
+    return true;
+  }
+  signal(pid, false);
+  if wait_for_exit(pid, grace).await {
+    return true;
+  }
+  log::warn!("Process {pid} ignored termination request, killing it");
+  signal(pid, true);
+  wait_for_exit(pid, Duration::from_secs(2)).await
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_process_exists_for_self_and_missing_pid() {
+    assert!(process_exists(std::process::id()));
+    assert!(!process_exists(u32::MAX - 1));
+  }
+
+  #[test]
+  fn test_concurrent_table_reads_share_one_refresh() {
+    let watcher = ProcessWatcher::new(Duration::from_secs(60));
+    let me = std::process::id();
+    std::thread::scope(|scope| {
+      for _ in 0..16 {
+        scope.spawn(|| {
+          assert!(watcher.process(me).is_some());
+          watcher.with_table(|system| assert!(!system.processes().is_empty()));
+        });
+      }
+    });
+    assert_eq!(watcher.refresh_count(), 1);
+  }
+
+  #[test]
+  fn test_table_refreshes_again_once_stale() {
+    let watcher = ProcessWatcher::new(Duration::from_millis(20));
+    watcher.with_table(|_| ());
+    watcher.with_table(|_| ());
+    assert_eq!(watcher.refresh_count(), 1);
+    std::thread::sleep(Duration::from_millis(30));
+    watcher.with_table(|_| ());
+    assert_eq!(watcher.refresh_count(), 2);
+  }
+
+  #[test]
+  fn test_find_by_path_matches_command_line() {
+    let watcher = ProcessWatcher::new(Duration::ZERO);
+    let exe = std::env::current_exe().unwrap();
+    let needle = exe.file_name().unwrap().to_string_lossy().into_owned();
+    assert!(watcher
+      .find_by_path(&needle)
+      .iter()
+      .any(|p| p.pid == std::process::id()));
+  }
+
+  #[test]
+  fn test_sweep_drops_exited_children() {
+    let mut child = std::process::Command::new(if cfg!(windows) { "cmd" } else { "true" })
+      .args(if cfg!(windows) {
+        vec!["/C", "exit"]
+      } else {
+        vec![]
+      })
+      .spawn()
+      .expect("spawn short-lived child");
+    let pid = child.id();
+    child.wait().expect("child exits");
+
+    register_child(pid, ChildKind::ProxyWorker, Some("sweep-test"));
+    assert!(sweep_dead_children() >= 1);
+    assert_eq!(reap_profile_children("sweep-test"), 0);
+  }
+}
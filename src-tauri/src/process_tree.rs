@@ -0,0 +1,276 @@
+//! Killing a browser together with every process it started.
+//!
+//! Chromium's renderer, GPU and network-service processes outlive a killed
+//! main process on Windows, and on Unix when the main process was wedged.
+//! They keep the profile's singleton locks and the proxy connections open,
+//! so a kill has to take the whole tree: the process group on Unix when we
+//! spawned the browser into its own group, `taskkill /T` on Windows, and a
+//! parent-PID scan of the process table for adopted browsers.
+
+use std::path::Path;
+use std::time::Duration;
+
+/// How often the exit and lock waits re-check.
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// True if a process command line refers to `profile_path` as a real browser
+/// profile/data-dir argument, NOT merely a substring. A bare `contains` match
+/// force-killed unrelated processes that happened to mention the path
+/// (editors, `tail`, a terminal that `cd`'d there, or another profile whose
+/// path has this one as a prefix).
+fn cmd_matches_profile_path(cmd: &[std::ffi::OsString], profile_path: &str) -> bool {
+  let args: Vec<&str> = cmd.iter().filter_map(|a| a.to_str()).collect();
+  for (i, arg) in args.iter().enumerate() {
+    // Exact argument equality (some launchers pass the path as its own arg).
+    if *arg == profile_path {
+      return true;
+    }
+    // `--user-data-dir=<path>` (Chromium/Wayfern) or `-profile=<path>`.
+    if let Some(val) = arg
+      .strip_prefix("--user-data-dir=")
+      .or_else(|| arg.strip_prefix("-profile="))
+    {
+      if val == profile_path {
+        return true;
+      }
+    }
+    // Flag followed by the path as the next argument.
+    if (*arg == "-profile" || *arg == "--user-data-dir")
+      && args.get(i + 1).is_some_and(|next| *next == profile_path)
+    {
+      return true;
+    }
+  }
+  false
+}
+
+/// Processes running on `profile_path`, from the shared process table.
+pub fn profile_pids(profile_path: &str) -> Vec<u32> {
+  crate::process_registry::PROCESS_WATCHER
+    .find_by_path(profile_path)
+    .into_iter()
+    .filter(|process| cmd_matches_profile_path(&process.cmd, profile_path))
+    .map(|process| process.pid)
+    .collect()
+}
+
+/// Every descendant of `parent_pid`, from the shared process table.
+pub fn descendant_pids(parent_pid: u32) -> Vec<u32> {
+  crate::process_registry::with_process_table(|system| {
+    let mut descendants = Vec::new();
+    let mut to_check = vec![parent_pid];
+    let mut checked = std::collections::HashSet::new();
+
+    while let Some(current_pid) = to_check.pop() {
+      if !checked.insert(current_pid) {
+        continue;
+      }
+      for (pid, process) in system.processes() {
+        let pid_u32 = pid.as_u32();
+        if process
+          .parent()
+          .is_some_and(|parent| parent.as_u32() == current_pid)
+          && !checked.contains(&pid_u32)
+        {
+          descendants.push(pid_u32);
+          to_check.push(pid_u32);
+        }
+      }
+    }
+
+    descendants
+  })
+}
+
+/// `pid`, its descendants and, with `profile_path`, anything else running on
+/// that profile. Collect this before stopping the browser: once the main
+/// process is gone its children are re-parented and the chain is lost.
+pub fn tree_pids(pid: u32, profile_path: Option<&str>) -> Vec<u32> {
+  let mut pids = vec![pid];
+  pids.extend(descendant_pids(pid));
+  for extra in profile_path.map(profile_pids).unwrap_or_default() {
+    if !pids.contains(&extra) {
+      pids.push(extra);
+    }
+  }
+  pids
+}
+
+/// Force-kills the tree rooted at `pid` plus every PID in `known`. With
+/// `own_group` the browser leads its own process group (we spawned it), so
+/// the whole group is signalled first; adopted browsers only get the
+/// per-PID kills.
+pub fn kill_tree(pid: u32, own_group: bool, known: &[u32]) {
+  #[cfg(unix)]
+  if own_group {
+    use nix::sys::signal::{killpg, Signal};
+    use nix::unistd::{getpgid, Pid};
+    let root = Pid::from_raw(pid as i32);
+    // Only a group the browser still leads; a recycled PID's group is not ours.
+    if getpgid(Some(root)) == Ok(root) {
+      let _ = killpg(root, Signal::SIGKILL);
+    }
+  }
+  #[cfg(windows)]
+  {
+    use std::os::windows::process::CommandExt;
+    const CREATE_NO_WINDOW: u32 = 0x08000000;
+    // `/T` walks the tree from the live parent chain, group or not.
+    let _ = own_group;
+    let _ = std::process::Command::new("taskkill")
+      .args(["/F", "/T", "/PID", &pid.to_string()])
+      .creation_flags(CREATE_NO_WINDOW)
+      .output();
+  }
+
+  for p in std::iter::once(pid).chain(known.iter().copied()) {
+    if crate::process_registry::process_exists(p) {
+      crate::process_registry::kill_process(p);
+    }
+  }
+}
+
+/// Waits up to `timeout` for every PID in `pids` to exit (zombies count as
+/// exited). Returns the ones still running.
+pub async fn wait_for_exit(pids: &[u32], timeout: Duration) -> Vec<u32> {
+  let deadline = tokio::time::Instant::now() + timeout;
+  loop {
+    let alive: Vec<u32> = pids
+      .iter()
+      .copied()
+      .filter(|p| crate::process_registry::process_alive(*p))
+      .collect();
+    if alive.is_empty() || tokio::time::Instant::now() >= deadline {
+      return alive;
+    }
+    tokio::time::sleep(POLL_INTERVAL).await;
+  }
+}
+
+/// Whether no live process holds the Chromium singleton lock of `data_dir`.
+/// A lock left behind by a dead process doesn't count; the next launch
+/// replaces it.
+pub fn locks_released(data_dir: &Path) -> bool {
+  #[cfg(unix)]
+  {
+    // `SingletonLock` is a symlink to `<hostname>-<pid>`.
+    let Ok(target) = std::fs::read_link(data_dir.join("SingletonLock")) else {
+      return true;
+    };
+    target
+      .to_string_lossy()
+      .rsplit('-')
+      .next()
+      .and_then(|pid| pid.parse::<u32>().ok())
+      .is_none_or(|pid| !crate::process_registry::process_alive(pid))
+  }
+  #[cfg(windows)]
+  {
+    // Chromium keeps `lockfile` open without sharing; opening it only
+    // succeeds once that handle is closed.
+    let path = data_dir.join("lockfile");
+    !path.exists() || std::fs::OpenOptions::new().write(true).open(&path).is_ok()
+  }
+}
+
+/// Waits up to `timeout` for [`locks_released`].
+pub async fn wait_for_locks_released(data_dir: &Path, timeout: Duration) -> bool {
+  let deadline = tokio::time::Instant::now() + timeout;
+  loop {
+    if locks_released(data_dir) {
+      return true;
+    }
+    if tokio::time::Instant::now() >= deadline {
+      return false;
+    }
+    tokio::time::sleep(POLL_INTERVAL).await;
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn os(args: &[&str]) -> Vec<std::ffi::OsString> {
+    args.iter().map(std::ffi::OsString::from).collect()
+  }
+
+  #[test]
+  fn cmd_match_requires_the_exact_profile_argument() {
+    let path = "/data/profiles/abc/profile";
+    assert!(cmd_matches_profile_path(
+      &os(&["wayfern", &format!("--user-data-dir={path}")]),
+      path
+    ));
+    assert!(cmd_matches_profile_path(
+      &os(&["wayfern", "--user-data-dir", path]),
+      path
+    ));
+    assert!(!cmd_matches_profile_path(
+      &os(&["wayfern", &format!("--user-data-dir={path}2")]),
+      path
+    ));
+    assert!(!cmd_matches_profile_path(
+      &os(&["tail", "-f", &format!("{path}/log")]),
+      path
+    ));
+  }
+
+  /// A stub browser: a shell that starts two long-running children.
+  #[cfg(unix)]
+  fn spawn_stub_parent(own_group: bool) -> (std::process::Child, Vec<u32>) {
+    use std::os::unix::process::CommandExt;
+    let mut command = std::process::Command::new("sh");
+    command.args(["-c", "sleep 60 & sleep 60 & wait"]);
+    if own_group {
+      command.process_group(0);
+    }
+    let child = command.spawn().unwrap();
+    let pid = child.id();
+    let deadline = std::time::Instant::now() + Duration::from_secs(5);
+    loop {
+      let descendants = descendant_pids(pid);
+      if descendants.len() >= 2 {
+        return (child, descendants);
+      }
+      assert!(
+        std::time::Instant::now() < deadline,
+        "stub children never showed up"
+      );
+      std::thread::sleep(Duration::from_millis(100));
+    }
+  }
+
+  #[cfg(unix)]
+  #[tokio::test]
+  async fn kill_tree_takes_down_every_child() {
+    for own_group in [true, false] {
+      let (mut parent, children) = spawn_stub_parent(own_group);
+      let tree = tree_pids(parent.id(), None);
+      kill_tree(parent.id(), own_group, &tree);
+      // The shell is our child; reap it so it doesn't linger as a zombie.
+      let _ = parent.wait();
+      assert!(
+        wait_for_exit(&children, Duration::from_secs(5))
+          .await
+          .is_empty(),
+        "children survived (own_group: {own_group})"
+      );
+    }
+  }
+
+  #[cfg(unix)]
+  #[test]
+  fn singleton_lock_of_a_dead_process_counts_as_released() {
+    let tmp = tempfile::tempdir().unwrap();
+    assert!(locks_released(tmp.path()));
+
+    let lock = tmp.path().join("SingletonLock");
+    std::os::unix::fs::symlink(format!("host-{}", std::process::id()), &lock).unwrap();
+    assert!(!locks_released(tmp.path()));
+
+    std::fs::remove_file(&lock).unwrap();
+    std::os::unix::fs::symlink("host-4000000000", &lock).unwrap();
+    assert!(locks_released(tmp.path()));
+  }
+}
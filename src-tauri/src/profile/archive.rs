@@ -0,0 +1,212 @@
+//! Archiving: cold storage for profiles that are kept but no longer used.
+//!
+//! An archived profile is hidden from the default profile list, skipped by
+//! the status checker and only has its metadata synced, so the flag still
+//! reaches other devices. Archiving can optionally pack the `profile/` data
+//! dir into `profile.tar.gz` (minus regenerable caches) next to
+//! `metadata.json`; unarchiving unpacks it again.
+
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use std::fs;
+use std::io::{self, Write};
+use std::path::Path;
+
+use crate::profile::snapshots::{append_dir, ensure_stopped, find_profile};
+use crate::profile::{BrowserProfile, ProfileManager};
+
+pub const ARCHIVE_FILE: &str = "profile.tar.gz";
+
+/// Staging dir for an unpack, inside the profile dir so the final swap is a
+/// rename on the same filesystem.
+const UNPACK_STAGING_DIR: &str = ".archive-unpack";
+
+/// Packs `profile_dir/profile` into [`ARCHIVE_FILE`] and removes the dir.
+/// Returns false when there was no data dir to pack.
+fn pack_data_dir(profile_dir: &Path) -> io::Result<bool> {
+  let data_dir = profile_dir.join("profile");
+  if !data_dir.is_dir() {
+    return Ok(false);
+  }
+  let archive = profile_dir.join(ARCHIVE_FILE);
+  let partial = profile_dir.join(format!("{ARCHIVE_FILE}.part"));
+
+  let written = (|| -> io::Result<()> {
+    let file = io::BufWriter::new(fs::File::create(&partial)?);
+    let mut tar = tar::Builder::new(GzEncoder::new(file, flate2::Compression::default()));
+    tar.follow_symlinks(false);
+    append_dir(&mut tar, &data_dir, Path::new("profile"), false)?;
+    let mut file = tar.into_inner()?.finish()?;
+    file.flush()?;
+    fs::rename(&partial, &archive)
+  })();
+  if let Err(e) = written {
+    let _ = fs::remove_file(&partial);
+    return Err(e);
+  }
+  fs::remove_dir_all(&data_dir)?;
+  Ok(true)
+}
+
+/// Unpacks [`ARCHIVE_FILE`] back into `profile_dir/profile`. A no-op when the
+/// profile was archived without compression.
+fn unpack_data_dir(profile_dir: &Path) -> Result<(), String> {
+  let archive = profile_dir.join(ARCHIVE_FILE);
+  if !archive.exists() {
+    return Ok(());
+  }
+  let staging = profile_dir.join(UNPACK_STAGING_DIR);
+  let _ = fs::remove_dir_all(&staging);
+
+  let file = io::BufReader::new(
+    fs::File::open(&archive).map_err(|e| format!("Failed to open profile archive: {e}"))?,
+  );
+  if let Err(e) = tar::Archive::new(GzDecoder::new(file)).unpack(&staging) {
+    let _ = fs::remove_dir_all(&staging);
+    return Err(format!("Failed to unpack profile archive: {e}"));
+  }
+
+  // Whatever is left here is a partial removal from an interrupted pack;
+  // the archive is the complete copy.
+  let data_dir = profile_dir.join("profile");
+  if data_dir.exists() {
+    fs::remove_dir_all(&data_dir)
+      .map_err(|e| format!("Failed to clear leftover profile data: {e}"))?;
+  }
+  let restored = staging.join("profile");
+  let swapped = if restored.exists() {
+    fs::rename(&restored, &data_dir)
+  } else {
+    fs::create_dir_all(&data_dir)
+  };
+  let _ = fs::remove_dir_all(&staging);
+  swapped.map_err(|e| format!("Failed to restore profile data: {e}"))?;
+  let _ = fs::remove_file(&archive);
+  Ok(())
+}
+
+fn save_and_notify(profile: &mut BrowserProfile) -> Result<(), String> {
+  profile.updated_at = Some(crate::proxy_manager::now_secs());
+  ProfileManager::instance()
+    .save_profile(profile)
+    .map_err(|e| format!("Failed to save profile: {e}"))?;
+  crate::sync::queue_profile_sync_if_eligible(profile);
+  if let Err(e) = crate::events::emit_empty("profiles-changed") {
+    log::warn!("Warning: Failed to emit profiles-changed event: {e}");
+  }
+  Ok(())
+}
+
+async fn archive_profile_impl(profile_id: &str, compress: bool) -> Result<BrowserProfile, String> {
+  let mut profile = find_profile(profile_id)?;
+  ensure_stopped(&profile)?;
+
+  if compress && !profile.ephemeral {
+    let profile_dir = ProfileManager::instance()
+      .get_profiles_dir()
+      .join(profile.id.to_string());
+    let packed = tokio::task::spawn_blocking(move || pack_data_dir(&profile_dir))
+      .await
+      .map_err(|e| format!("Archive task failed: {e}"))?
+      .map_err(|e| format!("Failed to compress profile data: {e}"))?;
+    if packed {
+      crate::profile::disk_usage::invalidate_usage(profile_id);
+    }
+  }
+
+  if !profile.archived {
+    profile.archived = true;
+    save_and_notify(&mut profile)?;
+    log::info!("Archived profile {profile_id}");
+  }
+  Ok(profile)
+}
+
+async fn unarchive_profile_impl(profile_id: &str) -> Result<BrowserProfile, String> {
+  let mut profile = find_profile(profile_id)?;
+  let profile_dir = ProfileManager::instance()
+    .get_profiles_dir()
+    .join(profile.id.to_string());
+  tokio::task::spawn_blocking(move || unpack_data_dir(&profile_dir))
+    .await
+    .map_err(|e| format!("Unarchive task failed: {e}"))??;
+  crate::profile::disk_usage::invalidate_usage(profile_id);
+
+  if profile.archived {
+    profile.archived = false;
+    save_and_notify(&mut profile)?;
+    log::info!("Unarchived profile {profile_id}");
+  }
+  Ok(profile)
+}
+
+/// Moves a stopped profile to cold storage, optionally compressing its
+/// browser data into a single archive file.
+#[tauri::command]
+pub async fn archive_profile(profile_id: String, compress: bool) -> Result<BrowserProfile, String> {
+  crate::audit::audited!(
+    crate::audit::AuditActor::Gui,
+    "archive",
+    "profile",
+    Some(profile_id.clone()),
+    archive_profile_impl(&profile_id, compress).await
+  )
+}
+
+#[tauri::command]
+pub async fn unarchive_profile(profile_id: String) -> Result<BrowserProfile, String> {
+  crate::audit::audited!(
+    crate::audit::AuditActor::Gui,
+    "unarchive",
+    "profile",
+    Some(profile_id.clone()),
+    unarchive_profile_impl(&profile_id).await
+  )
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn write(path: &Path, contents: &[u8]) {
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, contents).unwrap();
+  }
+
+  #[test]
+  fn pack_and_unpack_round_trip_without_caches() {
+    let tmp = tempfile::tempdir().unwrap();
+    let profile_dir = tmp.path();
+    write(&profile_dir.join("metadata.json"), b"{}");
+    write(&profile_dir.join("profile/Default/Cookies"), b"cookies");
+    write(&profile_dir.join("profile/Default/Cache/data_0"), b"cache");
+
+    assert!(pack_data_dir(profile_dir).unwrap());
+    assert!(!profile_dir.join("profile").exists());
+    assert!(profile_dir.join(ARCHIVE_FILE).is_file());
+    assert!(profile_dir.join("metadata.json").is_file());
+
+    unpack_data_dir(profile_dir).unwrap();
+    assert!(!profile_dir.join(ARCHIVE_FILE).exists());
+    assert_eq!(
+      fs::read(profile_dir.join("profile/Default/Cookies")).unwrap(),
+      b"cookies"
+    );
+    assert!(!profile_dir.join("profile/Default/Cache").exists());
+  }
+
+  #[test]
+  fn unpack_replaces_leftovers_and_skips_uncompressed() {
+    let tmp = tempfile::tempdir().unwrap();
+    let profile_dir = tmp.path();
+    assert!(!pack_data_dir(profile_dir).unwrap());
+    unpack_data_dir(profile_dir).unwrap();
+
+    write(&profile_dir.join("profile/Default/Cookies"), b"cookies");
+    pack_data_dir(profile_dir).unwrap();
+    write(&profile_dir.join("profile/Default/stale"), b"partial");
+    unpack_data_dir(profile_dir).unwrap();
+    assert!(!profile_dir.join("profile/Default/stale").exists());
+    assert!(profile_dir.join("profile/Default/Cookies").is_file());
+  }
+}
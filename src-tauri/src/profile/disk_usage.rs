@@ -0,0 +1,275 @@
+//! Per-profile disk usage reporting and on-demand browser cache cleanup.
+//!
+//! Walking a large profiles directory can take seconds, so measurements run on
+//! the blocking pool, are emitted one profile at a time as they finish, and are
+//! cached for a few minutes so reopening the view doesn't re-walk everything.
+
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::events;
+use crate::profile::{BrowserProfile, ProfileManager};
+
+/// Directory names that only ever hold regenerable browser cache. Anything
+/// with one of these names, at any depth, counts towards `cache_bytes` and is
+/// what `clear_profile_cache` removes.
+const CACHE_DIR_NAMES: &[&str] = &[
+  "Cache",
+  "Code Cache",
+  "GPUCache",
+  "jumpListCache",
+  "startupCache",
+];
+
+/// Subtrees never touched by cache cleanup (nor counted as cache): extensions
+/// may ship their own directories named `Cache`, and those are part of the
+/// extension.
+pub(crate) const CLEAR_SKIP_DIR_NAMES: &[&str] = &["Extensions"];
+
+const CACHE_TTL: Duration = Duration::from_secs(5 * 60);
+
+/// Also published as `profile-disk-usage`, one profile at a time as soon as
+/// it's measured.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct ProfileDiskUsage {
+  pub profile_id: String,
+  pub total_bytes: u64,
+  pub cache_bytes: u64,
+  pub file_count: u64,
+}
+
+lazy_static::lazy_static! {
+  static ref USAGE_CACHE: Mutex<HashMap<String, (Instant, ProfileDiskUsage)>> =
+    Mutex::new(HashMap::new());
+}
+
+pub(crate) fn is_cache_dir_name(name: &str) -> bool {
+  CACHE_DIR_NAMES.contains(&name)
+}
+
+/// Sum the apparent size of every file under `dir`. Symlinks are counted as
+/// links, never followed, so a profile can't drag an unrelated tree into its
+/// total.
+fn measure_dir(profile_id: &str, dir: &Path) -> ProfileDiskUsage {
+  let mut usage = ProfileDiskUsage {
+    profile_id: profile_id.to_string(),
+    total_bytes: 0,
+    cache_bytes: 0,
+    file_count: 0,
+  };
+
+  // (dir, inside a cache dir, inside a subtree cleanup skips)
+  let mut stack: Vec<(PathBuf, bool, bool)> = vec![(dir.to_path_buf(), false, false)];
+  while let Some((current, in_cache, in_skipped)) = stack.pop() {
+    let Ok(entries) = fs::read_dir(&current) else {
+      continue;
+    };
+    for entry in entries.flatten() {
+      let Ok(meta) = entry.metadata() else { continue };
+      if meta.is_dir() {
+        let name = entry.file_name();
+        let name = name.to_str().unwrap_or_default();
+        let skipped = in_skipped || CLEAR_SKIP_DIR_NAMES.contains(&name);
+        let cache = !skipped && (in_cache || is_cache_dir_name(name));
+        stack.push((entry.path(), cache, skipped));
+        continue;
+      }
+      usage.total_bytes += meta.len();
+      usage.file_count += 1;
+      if in_cache {
+        usage.cache_bytes += meta.len();
+      }
+    }
+  }
+  usage
+}
+
+/// Remove every cache directory under `dir`, returning the bytes they held.
+fn clear_cache_dirs(dir: &Path) -> u64 {
+  let mut freed = 0u64;
+  let mut stack = vec![dir.to_path_buf()];
+  while let Some(current) = stack.pop() {
+    let Ok(entries) = fs::read_dir(&current) else {
+      continue;
+    };
+    for entry in entries.flatten() {
+      let Ok(file_type) = entry.file_type() else {
+        continue;
+      };
+      if !file_type.is_dir() {
+        continue;
+      }
+      let name = entry.file_name();
+      let Some(name) = name.to_str() else { continue };
+      if CLEAR_SKIP_DIR_NAMES.contains(&name) {
+        continue;
+      }
+      let path = entry.path();
+      if is_cache_dir_name(name) {
+        let bytes = measure_dir("", &path).total_bytes;
+        match fs::remove_dir_all(&path) {
+          Ok(()) => freed += bytes,
+          Err(e) => log::warn!("Failed to remove cache dir {}: {e}", path.display()),
+        }
+      } else {
+        stack.push(path);
+      }
+    }
+  }
+  freed
+}
+
+fn profile_root(profile: &BrowserProfile) -> PathBuf {
+  ProfileManager::instance()
+    .get_profiles_dir()
+    .join(profile.id.to_string())
+}
+
+fn cached_usage(profile_id: &str) -> Option<ProfileDiskUsage> {
+  let cache = USAGE_CACHE.lock().ok()?;
+  cache
+    .get(profile_id)
+    .filter(|(at, _)| at.elapsed() < CACHE_TTL)
+    .map(|(_, usage)| usage.clone())
+}
+
+fn store_usage(usage: &ProfileDiskUsage) {
+  if let Ok(mut cache) = USAGE_CACHE.lock() {
+    cache.insert(usage.profile_id.clone(), (Instant::now(), usage.clone()));
+  }
+}
+
+pub(crate) fn invalidate_usage(profile_id: &str) {
+  if let Ok(mut cache) = USAGE_CACHE.lock() {
+    cache.remove(profile_id);
+  }
+}
+
+/// Disk usage for every profile, or just `profile_id` when given. Cached
+/// results younger than a few minutes are reused unless `force_refresh` is
+/// set; every result, cached or fresh, is also emitted as a
+/// `profile-disk-usage` event so the UI can fill in rows progressively.
+#[tauri::command]
+pub async fn get_profile_disk_usage(
+  profile_id: Option<String>,
+  force_refresh: Option<bool>,
+) -> Result<Vec<ProfileDiskUsage>, String> {
+  let profiles = ProfileManager::instance()
+    .list_profiles()
+    .map_err(|e| format!("Failed to list profiles: {e}"))?;
+  let targets: Vec<(String, PathBuf)> = profiles
+    .iter()
+    .filter(|p| {
+      profile_id
+        .as_deref()
+        .is_none_or(|id| p.id.to_string() == id)
+    })
+    .map(|p| (p.id.to_string(), profile_root(p)))
+    .collect();
+  if profile_id.is_some() {
+    if targets.is_empty() {
+      return Err(serde_json::json!({ "code": "PROFILE_NOT_FOUND" }).to_string());
+    }
+  }
+
+  let force_refresh = force_refresh.unwrap_or(false);
+  tokio::task::spawn_blocking(move || {
+    targets
+      .into_iter()
+      .map(|(id, root)| {
+        let usage = match cached_usage(&id).filter(|_| !force_refresh) {
+          Some(usage) => usage,
+          None => {
+            let usage = measure_dir(&id, &root);
+            store_usage(&usage);
+            usage
+          }
+        };
+        let _ = events::emit(&usage);
+        usage
+      })
+      .collect()
+  })
+  .await
+  .map_err(|e| format!("Disk usage task failed: {e}"))
+}
+
+/// Delete the known cache directories of a stopped profile and return how many
+/// bytes that freed. Cookies, history, storage and extensions are untouched.
+#[tauri::command]
+pub async fn clear_profile_cache(profile_id: String) -> Result<u64, String> {
+  let profile = ProfileManager::instance()
+    .list_profiles()
+    .map_err(|e| format!("Failed to list profiles: {e}"))?
+    .into_iter()
+    .find(|p| p.id.to_string() == profile_id)
+    .ok_or_else(|| serde_json::json!({ "code": "PROFILE_NOT_FOUND" }).to_string())?;
+
+  if profile
+    .process_id
+    .is_some_and(crate::proxy_storage::is_process_running)
+  {
+    return Err(serde_json::json!({ "code": "PROFILE_RUNNING" }).to_string());
+  }
+
+  let root = profile_root(&profile);
+  let freed = tokio::task::spawn_blocking(move || clear_cache_dirs(&root))
+    .await
+    .map_err(|e| format!("Cache cleanup task failed: {e}"))?;
+  invalidate_usage(&profile_id);
+  log::info!("Cleared {freed} bytes of cache from profile {profile_id}");
+  Ok(freed)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn write(path: &Path, bytes: usize) {
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, vec![0u8; bytes]).unwrap();
+  }
+
+  #[test]
+  fn measure_splits_cache_from_profile_data() {
+    let tmp = tempfile::tempdir().unwrap();
+    let root = tmp.path();
+    write(&root.join("profile/Default/Cookies"), 100);
+    write(&root.join("profile/Default/Cache/Cache_Data/data_0"), 1000);
+    write(&root.join("profile/Default/Code Cache/js/index"), 200);
+    write(&root.join("profile/GPUCache/data_1"), 50);
+
+    let usage = measure_dir("p", root);
+    assert_eq!(usage.total_bytes, 1350);
+    assert_eq!(usage.cache_bytes, 1250);
+    assert_eq!(usage.file_count, 4);
+  }
+
+  #[test]
+  fn clear_removes_only_cache_dirs() {
+    let tmp = tempfile::tempdir().unwrap();
+    let root = tmp.path();
+    write(&root.join("profile/Default/Cookies"), 100);
+    write(&root.join("profile/Default/Cache/Cache_Data/data_0"), 1000);
+    write(&root.join("profile/Default/Code Cache/js/index"), 200);
+    write(
+      &root.join("profile/Default/Extensions/abc/1.0/Cache/keep"),
+      10,
+    );
+
+    assert_eq!(clear_cache_dirs(root), 1200);
+    assert!(root.join("profile/Default/Cookies").exists());
+    assert!(!root.join("profile/Default/Cache").exists());
+    assert!(!root.join("profile/Default/Code Cache").exists());
+    assert!(root
+      .join("profile/Default/Extensions/abc/1.0/Cache/keep")
+      .exists());
+
+    let usage = measure_dir("p", root);
+    assert_eq!(usage.total_bytes, 110);
+  }
+}
@@ -0,0 +1,109 @@
+//! How a profile's browser exited, kept per profile so a stopped profile can
+//! show whether it was closed, killed or crashed. The restart supervisor
+//! classifies every exit the status checker observes and records it here.
+//! The history is a local-only file next to the profile's metadata.
+
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+const HISTORY_FILE: &str = ".exit-history.json";
+/// Older entries are dropped once a profile has this many.
+const MAX_ENTRIES: usize = 50;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, utoipa::ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ProfileExitKind {
+  /// The browser exited with status 0, e.g. its last window was closed.
+  UserClosed,
+  /// Stopped through the app, the API or MCP.
+  Killed,
+  /// Exited with a failure status or a signal.
+  Crashed,
+  /// Gone without an observable exit status, e.g. a browser adopted from a
+  /// previous app session.
+  Unknown,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct ProfileExit {
+  /// Epoch seconds when the exit was observed.
+  pub timestamp: u64,
+  pub exit_kind: ProfileExitKind,
+  pub exit_code: Option<i32>,
+  /// Terminating signal, Unix only.
+  pub signal: Option<i32>,
+  /// Seconds since the launch, when the launch happened in this app session.
+  pub uptime_secs: Option<u64>,
+}
+
+fn history_path(profile_id: &str) -> PathBuf {
+  crate::profile::ProfileManager::instance()
+    .get_profiles_dir()
+    .join(profile_id)
+    .join(HISTORY_FILE)
+}
+
+/// Recorded exits, newest first.
+pub fn load(profile_id: &str) -> Vec<ProfileExit> {
+  std::fs::read_to_string(history_path(profile_id))
+    .ok()
+    .and_then(|content| serde_json::from_str(&content).ok())
+    .unwrap_or_default()
+}
+
+pub fn last_exit(profile_id: &str) -> Option<ProfileExit> {
+  load(profile_id).into_iter().next()
+}
+
+fn push_bounded(history: &mut Vec<ProfileExit>, exit: ProfileExit) {
+  history.insert(0, exit);
+  history.truncate(MAX_ENTRIES);
+}
+
+pub fn record(profile_id: &str, exit: ProfileExit) {
+  let path = history_path(profile_id);
+  // The profile was deleted while its browser was running.
+  if !path.parent().is_some_and(|dir| dir.is_dir()) {
+    return;
+  }
+  let mut history = load(profile_id);
+  push_bounded(&mut history, exit);
+  match serde_json::to_string(&history) {
+    Ok(json) => {
+      if let Err(e) = std::fs::write(&path, json) {
+        log::warn!("Failed to write exit history for profile {profile_id}: {e}");
+      }
+    }
+    Err(e) => log::warn!("Failed to encode exit history for profile {profile_id}: {e}"),
+  }
+}
+
+#[tauri::command]
+pub fn get_profile_exit_history(profile_id: String) -> Vec<ProfileExit> {
+  load(&profile_id)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn history_keeps_the_newest_entries() {
+    let mut history = Vec::new();
+    for timestamp in 0..(MAX_ENTRIES as u64 + 5) {
+      push_bounded(
+        &mut history,
+        ProfileExit {
+          timestamp,
+          exit_kind: ProfileExitKind::UserClosed,
+          exit_code: Some(0),
+          signal: None,
+          uptime_secs: None,
+        },
+      );
+    }
+    assert_eq!(history.len(), MAX_ENTRIES);
+    assert_eq!(history[0].timestamp, MAX_ENTRIES as u64 + 4);
+    assert_eq!(history[MAX_ENTRIES - 1].timestamp, 5);
+  }
+}
@@ -2,36 +2,35 @@ use crate::browser::{create_browser, BrowserType};
 use crate::cloud_auth::CLOUD_AUTH;
 use crate::downloaded_browsers_registry::DownloadedBrowsersRegistry;
 use crate::events;
-use crate::profile::types::{get_host_os, BrowserProfile, SyncMode};
+use crate::events::payloads::{ProfileMetadataRecovered, ProfileUpdated};
+use crate::profile::types::{
+  get_host_os, BrowserProfile, BulkDeleteResult, DnsMode, ExitMismatchAction,
+  ProfileDeletionFailure, ResourceLimits, RestartPolicy, SessionRestore, SyncMode,
+  MAX_RESTARTS_PER_HOUR,
+};
 use crate::proxy_manager::PROXY_MANAGER;
-use crate::wayfern_manager::WayfernConfig;
+use crate::wayfern_manager::{WayfernConfig, WebRtcMode};
+use std::collections::HashMap;
 use std::fs::{self, create_dir_all};
-use std::path::{Path, PathBuf};
-use sysinfo::{Pid, ProcessRefreshKind, RefreshKind, System};
+use std::path::PathBuf;
+use sysinfo::Pid;
 use url::Url;
 
-fn atomic_write(path: &Path, data: &[u8]) -> std::io::Result<()> {
-  let tmp = path.with_extension(match path.extension().and_then(|e| e.to_str()) {
-    Some(ext) => format!("{ext}.tmp"),
-    None => "tmp".to_string(),
-  });
-  {
-    let mut f = fs::File::create(&tmp)?;
-    use std::io::Write;
-    f.write_all(data)?;
-    f.sync_all()?;
-  }
-  fs::rename(&tmp, path)
-}
+/// How long a browser gets to exit on SIGTERM before a bulk delete kills it.
+const DELETE_KILL_GRACE: std::time::Duration = std::time::Duration::from_secs(3);
 
 pub struct ProfileManager {
   wayfern_manager: &'static crate::wayfern_manager::WayfernManager,
+  /// Serializes metadata read-modify-write so concurrent updates to different
+  /// keys of one profile don't overwrite each other.
+  metadata_lock: std::sync::Mutex<()>,
 }
 
 impl ProfileManager {
   fn new() -> Self {
     Self {
       wayfern_manager: crate::wayfern_manager::WayfernManager::instance(),
+      metadata_lock: std::sync::Mutex::new(()),
     }
   }
 
@@ -69,7 +68,7 @@ impl ProfileManager {
   #[allow(clippy::too_many_arguments)]
   pub async fn create_profile_with_group(
     &self,
-    app_handle: &tauri::AppHandle,
+    app_handle: &crate::app_handle::AppHandle,
     name: &str,
     browser: &str,
     version: &str,
@@ -186,15 +185,18 @@ impl ProfileManager {
         log::info!("Generating fingerprint for Wayfern profile: {name}");
 
         // Create a temporary profile for fingerprint generation
+        // Shares the new profile's id, which seeds its font subset.
         let temp_profile = BrowserProfile {
-          id: uuid::Uuid::new_v4(),
+          id: profile_id,
           name: name.to_string(),
           browser: browser.to_string(),
           version: version.to_string(),
           proxy_id: proxy_id.clone(),
           vpn_id: None,
+          proxy_pool_id: None,
           launch_hook: launch_hook.clone(),
           process_id: None,
+          cdp_port: None,
           last_launch: None,
           release_type: release_type.to_string(),
           wayfern_config: None,
@@ -214,6 +216,17 @@ impl ProfileManager {
           dns_blocklist: None,
           password_protected: false,
           clear_on_close: false,
+          startup_urls: Vec::new(),
+          session_restore: Default::default(),
+          preference_overrides: Default::default(),
+          metadata: Default::default(),
+          pin_browser_version: false,
+          restart_policy: Default::default(),
+          dns_mode: Default::default(),
+          exit_mismatch_action: Default::default(),
+          resource_limits: Default::default(),
+          applied_resource_limits: None,
+          archived: false,
           created_at: None,
           updated_at: None,
         };
@@ -278,8 +291,10 @@ impl ProfileManager {
       version: version.to_string(),
       proxy_id: proxy_id.clone(),
       vpn_id: vpn_id.clone(),
+      proxy_pool_id: None,
       launch_hook,
       process_id: None,
+      cdp_port: None,
       last_launch: None,
       release_type: release_type.to_string(),
       wayfern_config: final_wayfern_config,
@@ -301,6 +316,17 @@ impl ProfileManager {
       dns_blocklist,
       password_protected: false,
       clear_on_close: false,
+      startup_urls: Vec::new(),
+      session_restore: Default::default(),
+      preference_overrides: Default::default(),
+      metadata: Default::default(),
+      pin_browser_version: false,
+      restart_policy: Default::default(),
+      dns_mode: Default::default(),
+      exit_mismatch_action: Default::default(),
+      resource_limits: Default::default(),
+      applied_resource_limits: None,
+      archived: false,
       created_at: Some(
         std::time::SystemTime::now()
           .duration_since(std::time::UNIX_EPOCH)
@@ -337,7 +363,7 @@ impl ProfileManager {
     create_dir_all(&profile_uuid_dir)?;
 
     let json = serde_json::to_string_pretty(profile)?;
-    atomic_write(&profile_file, json.as_bytes())?;
+    crate::json_file::write(&profile_file, json.as_bytes(), false)?;
 
     // Update tag suggestions after any save
     let _ = crate::tag_manager::TAG_MANAGER.lock().map(|tm| {
@@ -362,21 +388,19 @@ impl ProfileManager {
       if path.is_dir() {
         let metadata_file = path.join("metadata.json");
         if metadata_file.exists() {
-          let content = match fs::read_to_string(&metadata_file) {
-            Ok(c) => c,
-            Err(e) => {
-              log::warn!(
-                "Skipping profile at {}: failed to read metadata.json: {e}",
-                path.display()
-              );
-              continue;
+          let mut profile: BrowserProfile = match crate::json_file::read(&metadata_file) {
+            Ok(loaded) => {
+              if loaded.recovered {
+                let _ = events::emit(&ProfileMetadataRecovered {
+                  id: loaded.value.id.to_string(),
+                  name: loaded.value.name.clone(),
+                });
+              }
+              loaded.value
             }
-          };
-          let mut profile: BrowserProfile = match serde_json::from_str(&content) {
-            Ok(p) => p,
             Err(e) => {
               log::warn!(
-                "Skipping profile at {}: invalid metadata.json: {e}",
+                "Skipping profile at {}: unreadable metadata.json: {e}",
                 path.display()
               );
               continue;
@@ -390,7 +414,7 @@ impl ProfileManager {
             if let Some(os) = inferred_os {
               profile.host_os = Some(os);
               if let Ok(json) = serde_json::to_string_pretty(&profile) {
-                let _ = atomic_write(&metadata_file, json.as_bytes());
+                let _ = crate::json_file::write(&metadata_file, json.as_bytes(), false);
               }
             }
           }
@@ -405,7 +429,7 @@ impl ProfileManager {
 
   pub fn rename_profile(
     &self,
-    _app_handle: &tauri::AppHandle,
+    _app_handle: &crate::app_handle::AppHandle,
     profile_id: &str,
     new_name: &str,
   ) -> Result<BrowserProfile, Box<dyn std::error::Error>> {
@@ -458,7 +482,7 @@ impl ProfileManager {
 
   pub fn delete_profile(
     &self,
-    app_handle: &tauri::AppHandle,
+    app_handle: &crate::app_handle::AppHandle,
     profile_id: &str,
   ) -> Result<(), Box<dyn std::error::Error>> {
     log::info!("Attempting to delete profile with ID: {profile_id}");
@@ -479,6 +503,8 @@ impl ProfileManager {
       );
     }
 
+    crate::warm_pool::invalidate(profile_id);
+
     // Remember sync mode before deleting local files
     let was_sync_enabled = profile.is_sync_enabled();
 
@@ -503,6 +529,9 @@ impl ProfileManager {
       profile_id
     );
 
+    crate::profile_shortcuts::forget_profile(profile_id);
+    crate::extension_manager::remove_profile_extensions_cache(profile_id);
+
     // If sync was enabled, also delete from S3
     if was_sync_enabled {
       let profile_id_owned = profile_id.to_string();
@@ -557,6 +586,8 @@ impl ProfileManager {
       fs::remove_dir_all(&profile_dir)?;
       log::info!("Deleted local profile {} (tombstoned remotely)", profile_id);
     }
+    crate::profile_shortcuts::forget_profile(profile_id);
+    crate::extension_manager::remove_profile_extensions_cache(profile_id);
 
     if let Err(e) = crate::downloaded_browsers_registry::DownloadedBrowsersRegistry::instance()
       .cleanup_unused_binaries()
@@ -570,7 +601,7 @@ impl ProfileManager {
 
   pub fn update_profile_version(
     &self,
-    _app_handle: &tauri::AppHandle,
+    _app_handle: &crate::app_handle::AppHandle,
     profile_id: &str,
     version: &str,
   ) -> Result<BrowserProfile, Box<dyn std::error::Error>> {
@@ -618,7 +649,7 @@ impl ProfileManager {
 
   pub fn assign_profiles_to_group(
     &self,
-    _app_handle: &tauri::AppHandle,
+    _app_handle: &crate::app_handle::AppHandle,
     profile_ids: Vec<String>,
     group_id: Option<String>,
   ) -> Result<(), Box<dyn std::error::Error>> {
@@ -675,7 +706,7 @@ impl ProfileManager {
 
   pub fn update_profile_tags(
     &self,
-    _app_handle: &tauri::AppHandle,
+    _app_handle: &crate::app_handle::AppHandle,
     profile_id: &str,
     tags: Vec<String>,
   ) -> Result<BrowserProfile, Box<dyn std::error::Error>> {
@@ -716,9 +747,70 @@ impl ProfileManager {
     Ok(profile)
   }
 
+  /// Replace every tag in `sources` with `destination` across all profiles,
+  /// deduplicating each profile's list. Tags are metadata only, so running
+  /// profiles are updated too. If any save fails, profiles already written are
+  /// restored so the rename never lands half-applied. Returns the number of
+  /// profiles changed.
+  pub fn merge_tags(
+    &self,
+    sources: &[String],
+    destination: &str,
+  ) -> Result<usize, Box<dyn std::error::Error>> {
+    let destination = destination.trim();
+    if destination.is_empty() {
+      return Err(
+        serde_json::json!({ "code": "NAME_CANNOT_BE_EMPTY" })
+          .to_string()
+          .into(),
+      );
+    }
+
+    let now = crate::proxy_manager::now_secs();
+    let mut originals = Vec::new();
+    let mut updated = Vec::new();
+    for profile in self.list_profiles()? {
+      if let Some(tags) = merge_tag_list(&profile.tags, sources, destination) {
+        let mut changed = profile.clone();
+        changed.tags = tags;
+        changed.updated_at = Some(now);
+        originals.push(profile);
+        updated.push(changed);
+      }
+    }
+
+    for (i, profile) in updated.iter().enumerate() {
+      if let Err(e) = self.save_profile(profile) {
+        for original in &originals[..i] {
+          if let Err(restore_err) = self.save_profile(original) {
+            log::error!(
+              "Failed to restore tags for profile {} after aborted merge: {restore_err}",
+              original.id
+            );
+          }
+        }
+        return Err(e);
+      }
+    }
+
+    for profile in &updated {
+      crate::sync::queue_profile_sync_if_eligible(profile);
+    }
+
+    let _ = crate::tag_manager::TAG_MANAGER.lock().map(|tm| {
+      let _ = tm.rebuild_from_profiles(&self.list_profiles().unwrap_or_default());
+    });
+
+    if let Err(e) = events::emit_empty("profiles-changed") {
+      log::warn!("Warning: Failed to emit profiles-changed event: {e}");
+    }
+
+    Ok(updated.len())
+  }
+
   pub fn update_profile_note(
     &self,
-    _app_handle: &tauri::AppHandle,
+    _app_handle: &crate::app_handle::AppHandle,
     profile_id: &str,
     note: Option<String>,
   ) -> Result<BrowserProfile, Box<dyn std::error::Error>> {
@@ -750,7 +842,7 @@ impl ProfileManager {
 
   pub fn update_profile_clear_on_close(
     &self,
-    _app_handle: &tauri::AppHandle,
+    _app_handle: &crate::app_handle::AppHandle,
     profile_id: &str,
     clear_on_close: bool,
   ) -> Result<BrowserProfile, Box<dyn std::error::Error>> {
@@ -788,7 +880,7 @@ impl ProfileManager {
 
   pub fn update_profile_window_color(
     &self,
-    _app_handle: &tauri::AppHandle,
+    _app_handle: &crate::app_handle::AppHandle,
     profile_id: &str,
     window_color: Option<String>,
   ) -> Result<BrowserProfile, Box<dyn std::error::Error>> {
@@ -820,7 +912,7 @@ impl ProfileManager {
 
   pub fn update_profile_launch_hook(
     &self,
-    _app_handle: &tauri::AppHandle,
+    _app_handle: &crate::app_handle::AppHandle,
     profile_id: &str,
     launch_hook: Option<String>,
   ) -> Result<BrowserProfile, Box<dyn std::error::Error>> {
@@ -839,7 +931,7 @@ impl ProfileManager {
 
     crate::sync::queue_profile_sync_if_eligible(&profile);
 
-    if let Err(e) = events::emit("profile-updated", &profile) {
+    if let Err(e) = events::emit(&ProfileUpdated(&profile)) {
       log::warn!("Warning: Failed to emit profile update event: {e}");
     }
 
@@ -852,7 +944,7 @@ impl ProfileManager {
 
   pub fn update_profile_proxy_bypass_rules(
     &self,
-    _app_handle: &tauri::AppHandle,
+    _app_handle: &crate::app_handle::AppHandle,
     profile_id: &str,
     rules: Vec<String>,
   ) -> Result<BrowserProfile, Box<dyn std::error::Error>> {
@@ -878,20 +970,24 @@ impl ProfileManager {
     Ok(profile)
   }
 
-  pub fn update_profile_dns_blocklist(
+  pub fn update_profile_startup(
     &self,
     profile_id: &str,
-    dns_blocklist: Option<String>,
+    startup_urls: Vec<String>,
+    session_restore: SessionRestore,
   ) -> Result<BrowserProfile, Box<dyn std::error::Error>> {
+    let urls = validate_startup_urls(startup_urls)?;
+
     let profile_uuid =
       uuid::Uuid::parse_str(profile_id).map_err(|_| format!("Invalid profile ID: {profile_id}"))?;
-    let profiles = self.list_profiles()?;
-    let mut profile = profiles
+    let mut profile = self
+      .list_profiles()?
       .into_iter()
       .find(|p| p.id == profile_uuid)
       .ok_or_else(|| format!("Profile with ID '{profile_id}' not found"))?;
 
-    profile.dns_blocklist = dns_blocklist;
+    profile.startup_urls = urls;
+    profile.session_restore = session_restore;
     profile.updated_at = Some(crate::proxy_manager::now_secs());
 
     self.save_profile(&profile)?;
@@ -905,250 +1001,706 @@ impl ProfileManager {
     Ok(profile)
   }
 
-  pub fn delete_multiple_profiles(
+  pub fn update_profile_preferences(
     &self,
-    app_handle: &tauri::AppHandle,
-    profile_ids: Vec<String>,
-  ) -> Result<(), Box<dyn std::error::Error>> {
-    let profiles = self.list_profiles()?;
-    let mut sync_enabled_ids: Vec<String> = Vec::new();
-
-    for profile_id in profile_ids {
-      let profile_uuid = uuid::Uuid::parse_str(&profile_id)
-        .map_err(|_| format!("Invalid profile ID: {profile_id}"))?;
-      let profile = profiles
-        .iter()
-        .find(|p| p.id == profile_uuid)
-        .ok_or_else(|| format!("Profile with ID '{profile_id}' not found"))?;
-
-      // Check if browser is running (cross-OS profiles can't be running locally)
-      if profile.process_id.is_some() && !profile.is_cross_os() {
-        return Err(
-          format!(
-            "Cannot delete profile '{}' while browser is running. Please stop the browser first.",
-            profile.name
-          )
-          .into(),
-        );
-      }
+    profile_id: &str,
+    preferences: HashMap<String, serde_json::Value>,
+  ) -> Result<BrowserProfile, Box<dyn std::error::Error>> {
+    let unsupported = crate::profile::preferences::unsupported_preferences(&preferences);
+    if !unsupported.is_empty() {
+      return Err(
+        serde_json::json!({
+          "code": "UNSUPPORTED_PREFERENCES",
+          "params": { "keys": unsupported.join(", ") }
+        })
+        .to_string()
+        .into(),
+      );
+    }
 
-      // Track sync-enabled profiles for remote deletion
-      if profile.is_sync_enabled() {
-        sync_enabled_ids.push(profile_id.clone());
-      }
+    let profile_uuid =
+      uuid::Uuid::parse_str(profile_id).map_err(|_| format!("Invalid profile ID: {profile_id}"))?;
+    let mut profile = self
+      .list_profiles()?
+      .into_iter()
+      .find(|p| p.id == profile_uuid)
+      .ok_or_else(|| format!("Profile with ID '{profile_id}' not found"))?;
 
-      // Delete the profile
-      let profiles_dir = self.get_profiles_dir();
-      let profile_uuid_dir = profiles_dir.join(profile.id.to_string());
+    profile.preference_overrides = preferences;
+    profile.updated_at = Some(crate::proxy_manager::now_secs());
 
-      if profile_uuid_dir.exists() {
-        std::fs::remove_dir_all(&profile_uuid_dir)?;
-      }
-    }
+    self.save_profile(&profile)?;
 
-    // Delete sync-enabled profiles from S3
-    if !sync_enabled_ids.is_empty() {
-      let app_handle_clone = app_handle.clone();
-      tauri::async_runtime::spawn(async move {
-        if let Ok(engine) = crate::sync::SyncEngine::create_from_settings(&app_handle_clone).await {
-          for profile_id in sync_enabled_ids {
-            if let Err(e) = engine.delete_profile(&profile_id).await {
-              log::warn!("Failed to delete profile {} from sync: {}", profile_id, e);
-            }
-          }
-        }
-      });
-    }
+    crate::sync::queue_profile_sync_if_eligible(&profile);
 
-    // Emit profile deletion event
     if let Err(e) = events::emit_empty("profiles-changed") {
       log::warn!("Warning: Failed to emit profiles-changed event: {e}");
     }
 
-    Ok(())
+    Ok(profile)
   }
 
-  fn generate_clone_name(&self, original_name: &str) -> Result<String, Box<dyn std::error::Error>> {
-    let profiles = self.list_profiles()?;
-    let existing_names: std::collections::HashSet<String> =
-      profiles.iter().map(|p| p.name.clone()).collect();
+  pub fn update_profile_metadata(
+    &self,
+    profile_id: &str,
+    update: crate::profile::metadata::MetadataUpdate,
+  ) -> Result<BrowserProfile, Box<dyn std::error::Error>> {
+    let profile_uuid =
+      uuid::Uuid::parse_str(profile_id).map_err(|_| format!("Invalid profile ID: {profile_id}"))?;
 
-    let candidate = format!("{original_name} (Copy)");
-    if !existing_names.contains(&candidate) {
-      return Ok(candidate);
-    }
+    let profile = {
+      let _guard = self
+        .metadata_lock
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+      let mut profile = self
+        .list_profiles()?
+        .into_iter()
+        .find(|p| p.id == profile_uuid)
+        .ok_or_else(|| format!("Profile with ID '{profile_id}' not found"))?;
 
-    for i in 2.. {
-      let candidate = format!("{original_name} (Copy {i})");
-      if !existing_names.contains(&candidate) {
-        return Ok(candidate);
-      }
+      profile.metadata =
+        crate::profile::metadata::apply_metadata_update(&profile.metadata, update)?;
+      profile.updated_at = Some(crate::proxy_manager::now_secs());
+      self.save_profile(&profile)?;
+      profile
+    };
+
+    crate::sync::queue_profile_sync_if_eligible(&profile);
+
+    if let Err(e) = events::emit_empty("profiles-changed") {
+      log::warn!("Warning: Failed to emit profiles-changed event: {e}");
     }
 
-    unreachable!()
+    Ok(profile)
   }
 
-  pub fn clone_profile(
+  pub fn set_profile_version_pinned(
     &self,
     profile_id: &str,
-    custom_name: Option<String>,
+    pinned: bool,
   ) -> Result<BrowserProfile, Box<dyn std::error::Error>> {
     let profile_uuid =
       uuid::Uuid::parse_str(profile_id).map_err(|_| format!("Invalid profile ID: {profile_id}"))?;
-    let profiles = self.list_profiles()?;
-    let source = profiles
+    let mut profile = self
+      .list_profiles()?
       .into_iter()
       .find(|p| p.id == profile_uuid)
       .ok_or_else(|| format!("Profile with ID '{profile_id}' not found"))?;
 
-    if source.process_id.is_some() {
-      return Err(
-        "Cannot clone profile while browser is running. Please stop the browser first.".into(),
-      );
-    }
-
-    let new_id = uuid::Uuid::new_v4();
-    let clone_name = match custom_name {
-      Some(name) if !name.trim().is_empty() => name.trim().to_string(),
-      _ => self.generate_clone_name(&source.name)?,
-    };
-
-    let profiles_dir = self.get_profiles_dir();
-    let source_dir = profiles_dir.join(source.id.to_string());
-    let dest_dir = profiles_dir.join(new_id.to_string());
-
-    if source_dir.exists() {
-      crate::profile_importer::ProfileImporter::copy_directory_recursive(&source_dir, &dest_dir)?;
-    } else {
-      fs::create_dir_all(&dest_dir)?;
-    }
-
-    let mut new_profile = BrowserProfile {
-      id: new_id,
-      name: clone_name,
-      browser: source.browser,
-      version: source.version,
-      proxy_id: source.proxy_id,
-      vpn_id: source.vpn_id,
-      launch_hook: source.launch_hook,
-      process_id: None,
-      last_launch: None,
-      release_type: source.release_type,
-      wayfern_config: source.wayfern_config,
-      group_id: source.group_id,
-      tags: source.tags,
-      note: source.note,
-      window_color: source.window_color,
-      sync_mode: SyncMode::Disabled,
-      encryption_salt: None,
-      last_sync: None,
-      host_os: Some(get_host_os()),
-      ephemeral: false,
-      extension_group_id: source.extension_group_id,
-      proxy_bypass_rules: source.proxy_bypass_rules,
-      created_by_id: None,
-      created_by_email: None,
-      dns_blocklist: source.dns_blocklist,
-      password_protected: false,
-      clear_on_close: false,
-      created_at: Some(
-        std::time::SystemTime::now()
-          .duration_since(std::time::UNIX_EPOCH)
-          .map(|d| d.as_secs())
-          .unwrap_or(0),
-      ),
-      updated_at: Some(crate::proxy_manager::now_secs()),
-    };
+    profile.pin_browser_version = pinned;
+    profile.updated_at = Some(crate::proxy_manager::now_secs());
 
-    // Donut: a clone must NOT be linkable to its source. The source
-    // wayfern_config embeds the persisted fingerprint JSON (including the
-    // canvas_noise_seed), so copying it verbatim makes the clone emit
-    // BYTE-IDENTICAL canvas/WebGL/audio readback hashes and identical device
-    // signals as the source — trivially linkable if both run concurrently. Clear
-    // the fingerprint so the launch path mints a fresh one (a new
-    // canvas_noise_seed via RandBytes + an independent device fingerprint),
-    // exactly as create_profile does when fingerprint.is_none(). NOTE: the
-    // user-data-dir copy above still duplicates cookies/localStorage/TLS state —
-    // a separate storage-linkage vector the user must clear if they want full
-    // isolation between a clone and its source.
-    if let Some(cfg) = new_profile.wayfern_config.as_mut() {
-      cfg.fingerprint = None;
-    }
+    self.save_profile(&profile)?;
 
-    self.save_profile(&new_profile)?;
+    crate::sync::queue_profile_sync_if_eligible(&profile);
 
     if let Err(e) = events::emit_empty("profiles-changed") {
       log::warn!("Warning: Failed to emit profiles-changed event: {e}");
     }
 
-    Ok(new_profile)
+    Ok(profile)
   }
 
-  pub async fn update_wayfern_config(
+  pub fn update_profile_restart_policy(
     &self,
-    app_handle: tauri::AppHandle,
     profile_id: &str,
-    config: WayfernConfig,
-  ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-    // Find the profile by ID
-    let profile_uuid = uuid::Uuid::parse_str(profile_id).map_err(
-      |_| -> Box<dyn std::error::Error + Send + Sync> {
-        format!("Invalid profile ID: {profile_id}").into()
-      },
-    )?;
-    let profiles =
-      self
-        .list_profiles()
-        .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
-          format!("Failed to list profiles: {e}").into()
-        })?;
-    let mut profile = profiles
-      .into_iter()
-      .find(|p| p.id == profile_uuid)
-      .ok_or_else(|| -> Box<dyn std::error::Error + Send + Sync> {
-        format!("Profile with ID '{profile_id}' not found").into()
-      })?;
-
-    // Check if the browser is currently running using the comprehensive status check
-    let is_running = self
-      .check_browser_status(app_handle.clone(), &profile)
-      .await?;
-
-    if is_running {
+    policy: RestartPolicy,
+  ) -> Result<BrowserProfile, Box<dyn std::error::Error>> {
+    if !(1..=MAX_RESTARTS_PER_HOUR).contains(&policy.max_restarts_per_hour) {
       return Err(
-        "Cannot update Wayfern configuration while browser is running. Please stop the browser first.".into(),
+        serde_json::json!({
+          "code": "INVALID_RESTART_LIMIT",
+          "params": { "max": MAX_RESTARTS_PER_HOUR }
+        })
+        .to_string()
+        .into(),
       );
     }
 
-    // Update the Wayfern configuration
-    profile.wayfern_config = Some(config);
+    let profile_uuid =
+      uuid::Uuid::parse_str(profile_id).map_err(|_| format!("Invalid profile ID: {profile_id}"))?;
+    let mut profile = self
+      .list_profiles()?
+      .into_iter()
+      .find(|p| p.id == profile_uuid)
+      .ok_or_else(|| format!("Profile with ID '{profile_id}' not found"))?;
 
-    // Save the updated profile
-    self
-      .save_profile(&profile)
-      .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
-        format!("Failed to save profile: {e}").into()
-      })?;
+    profile.restart_policy = policy;
+    profile.updated_at = Some(crate::proxy_manager::now_secs());
 
-    crate::sync::queue_profile_sync_if_eligible(&profile);
+    self.save_profile(&profile)?;
 
-    log::info!(
-      "Wayfern configuration updated for profile '{}' (ID: {}).",
-      profile.name,
-      profile_id
-    );
+    crate::sync::queue_profile_sync_if_eligible(&profile);
 
-    // Emit profile config update event
     if let Err(e) = events::emit_empty("profiles-changed") {
       log::warn!("Warning: Failed to emit profiles-changed event: {e}");
     }
 
-    Ok(())
+    Ok(profile)
+  }
+
+  pub fn update_profile_dns_mode(
+    &self,
+    profile_id: &str,
+    dns_mode: DnsMode,
+  ) -> Result<BrowserProfile, Box<dyn std::error::Error>> {
+    let dns_mode = match dns_mode {
+      DnsMode::Doh { resolver_url } => DnsMode::Doh {
+        resolver_url: normalize_doh_resolver(&resolver_url)?,
+      },
+      other => other,
+    };
+
+    let profile_uuid =
+      uuid::Uuid::parse_str(profile_id).map_err(|_| format!("Invalid profile ID: {profile_id}"))?;
+    let mut profile = self
+      .list_profiles()?
+      .into_iter()
+      .find(|p| p.id == profile_uuid)
+      .ok_or_else(|| format!("Profile with ID '{profile_id}' not found"))?;
+
+    profile.dns_mode = dns_mode;
+    profile.updated_at = Some(crate::proxy_manager::now_secs());
+
+    self.save_profile(&profile)?;
+
+    crate::sync::queue_profile_sync_if_eligible(&profile);
+
+    if let Err(e) = events::emit_empty("profiles-changed") {
+      log::warn!("Warning: Failed to emit profiles-changed event: {e}");
+    }
+
+    Ok(profile)
+  }
+
+  pub fn update_profile_exit_mismatch_action(
+    &self,
+    profile_id: &str,
+    action: ExitMismatchAction,
+  ) -> Result<BrowserProfile, Box<dyn std::error::Error>> {
+    let profile_uuid =
+      uuid::Uuid::parse_str(profile_id).map_err(|_| format!("Invalid profile ID: {profile_id}"))?;
+    let mut profile = self
+      .list_profiles()?
+      .into_iter()
+      .find(|p| p.id == profile_uuid)
+      .ok_or_else(|| format!("Profile with ID '{profile_id}' not found"))?;
+
+    profile.exit_mismatch_action = action;
+    profile.updated_at = Some(crate::proxy_manager::now_secs());
+
+    self.save_profile(&profile)?;
+
+    crate::sync::queue_profile_sync_if_eligible(&profile);
+
+    if let Err(e) = events::emit_empty("profiles-changed") {
+      log::warn!("Warning: Failed to emit profiles-changed event: {e}");
+    }
+
+    Ok(profile)
+  }
+
+  pub fn update_profile_resource_limits(
+    &self,
+    profile_id: &str,
+    limits: ResourceLimits,
+  ) -> Result<BrowserProfile, Box<dyn std::error::Error>> {
+    crate::resource_limits::validate(&limits)?;
+
+    let profile_uuid =
+      uuid::Uuid::parse_str(profile_id).map_err(|_| format!("Invalid profile ID: {profile_id}"))?;
+    let mut profile = self
+      .list_profiles()?
+      .into_iter()
+      .find(|p| p.id == profile_uuid)
+      .ok_or_else(|| format!("Profile with ID '{profile_id}' not found"))?;
+
+    profile.resource_limits = limits;
+    profile.updated_at = Some(crate::proxy_manager::now_secs());
+
+    self.save_profile(&profile)?;
+
+    crate::sync::queue_profile_sync_if_eligible(&profile);
+
+    if let Err(e) = events::emit_empty("profiles-changed") {
+      log::warn!("Warning: Failed to emit profiles-changed event: {e}");
+    }
+
+    Ok(profile)
+  }
+
+  /// Refused while the browser runs: WebRTC is set up at launch, so a change
+  /// would only show up after a restart.
+  pub fn update_profile_webrtc_mode(
+    &self,
+    profile_id: &str,
+    mode: Option<WebRtcMode>,
+  ) -> Result<BrowserProfile, Box<dyn std::error::Error>> {
+    let mode = match mode {
+      Some(WebRtcMode::Mock { ip }) => {
+        let ip = ip
+          .map(|ip| ip.trim().to_string())
+          .filter(|ip| !ip.is_empty());
+        if let Some(ip) = &ip {
+          if ip.parse::<std::net::IpAddr>().is_err() {
+            return Err(
+              serde_json::json!({ "code": "INVALID_WEBRTC_IP", "params": { "value": ip } })
+                .to_string()
+                .into(),
+            );
+          }
+        }
+        Some(WebRtcMode::Mock { ip })
+      }
+      other => other,
+    };
+
+    let profile_uuid =
+      uuid::Uuid::parse_str(profile_id).map_err(|_| format!("Invalid profile ID: {profile_id}"))?;
+    let mut profile = self
+      .list_profiles()?
+      .into_iter()
+      .find(|p| p.id == profile_uuid)
+      .ok_or_else(|| format!("Profile with ID '{profile_id}' not found"))?;
+
+    if profile
+      .process_id
+      .is_some_and(crate::proxy_storage::is_process_running)
+    {
+      return Err(
+        serde_json::json!({
+          "code": "WEBRTC_MODE_PROFILE_RUNNING",
+          "params": { "name": profile.name }
+        })
+        .to_string()
+        .into(),
+      );
+    }
+
+    let mut config = profile.wayfern_config.clone().unwrap_or_default();
+    config.webrtc_mode = mode;
+    // Superseded by `webrtc_mode`; left set it would turn "browser default"
+    // back into "disabled".
+    config.block_webrtc = None;
+    profile.wayfern_config = Some(config);
+    profile.updated_at = Some(crate::proxy_manager::now_secs());
+
+    self.save_profile(&profile)?;
+
+    crate::sync::queue_profile_sync_if_eligible(&profile);
+
+    if let Err(e) = events::emit_empty("profiles-changed") {
+      log::warn!("Warning: Failed to emit profiles-changed event: {e}");
+    }
+
+    Ok(profile)
+  }
+
+  pub fn update_profile_dns_blocklist(
+    &self,
+    profile_id: &str,
+    dns_blocklist: Option<String>,
+  ) -> Result<BrowserProfile, Box<dyn std::error::Error>> {
+    let profile_uuid =
+      uuid::Uuid::parse_str(profile_id).map_err(|_| format!("Invalid profile ID: {profile_id}"))?;
+    let profiles = self.list_profiles()?;
+    let mut profile = profiles
+      .into_iter()
+      .find(|p| p.id == profile_uuid)
+      .ok_or_else(|| format!("Profile with ID '{profile_id}' not found"))?;
+
+    profile.dns_blocklist = dns_blocklist;
+    profile.updated_at = Some(crate::proxy_manager::now_secs());
+
+    self.save_profile(&profile)?;
+
+    crate::sync::queue_profile_sync_if_eligible(&profile);
+
+    if let Err(e) = events::emit_empty("profiles-changed") {
+      log::warn!("Warning: Failed to emit profiles-changed event: {e}");
+    }
+
+    Ok(profile)
+  }
+
+  /// Deletes each profile in turn, stopping whatever still uses it first. A
+  /// profile that can't be cleaned up is reported in `failed` and the rest
+  /// carry on. With `force`, remote sync data that can't be deleted (sync
+  /// unreachable) no longer blocks the local deletion.
+  pub async fn delete_multiple_profiles(
+    &self,
+    app_handle: &crate::app_handle::AppHandle,
+    profile_ids: Vec<String>,
+    force: bool,
+  ) -> BulkDeleteResult {
+    let mut result = BulkDeleteResult::default();
+    let profiles = match self.list_profiles() {
+      Ok(profiles) => profiles,
+      Err(e) => {
+        let error = format!("Failed to list profiles: {e}");
+        result.failed = profile_ids
+          .into_iter()
+          .map(|profile_id| ProfileDeletionFailure {
+            profile_id,
+            profile_name: None,
+            error: error.clone(),
+          })
+          .collect();
+        return result;
+      }
+    };
+
+    for profile_id in profile_ids {
+      let profile = uuid::Uuid::parse_str(&profile_id)
+        .ok()
+        .and_then(|uuid| profiles.iter().find(|p| p.id == uuid));
+      let Some(profile) = profile else {
+        result.failed.push(ProfileDeletionFailure {
+          profile_id: profile_id.clone(),
+          profile_name: None,
+          error: format!("Profile with ID '{profile_id}' not found"),
+        });
+        continue;
+      };
+
+      match self
+        .delete_profile_with_cleanup(app_handle, profile, force)
+        .await
+      {
+        Ok(()) => result.deleted.push(profile_id),
+        Err(error) => {
+          log::warn!("Failed to delete profile '{}': {error}", profile.name);
+          result.failed.push(ProfileDeletionFailure {
+            profile_id,
+            profile_name: Some(profile.name.clone()),
+            error,
+          });
+        }
+      }
+    }
+
+    if result.deleted.is_empty() {
+      return result;
+    }
+
+    let _ = crate::tag_manager::TAG_MANAGER.lock().map(|tm| {
+      let _ = tm.rebuild_from_profiles(&self.list_profiles().unwrap_or_default());
+    });
+
+    if let Err(e) = DownloadedBrowsersRegistry::instance().cleanup_unused_binaries() {
+      log::warn!("Warning: Failed to cleanup unused binaries after profile deletion: {e}");
+    }
+
+    if let Err(e) = events::emit_empty("profiles-changed") {
+      log::warn!("Warning: Failed to emit profiles-changed event: {e}");
+    }
+
+    result
+  }
+
+  /// Stops the browser and everything it holds, detaches the profile from its
+  /// group, removes the remote sync copy, then deletes the directory. Stops at
+  /// the first step that fails so the profile is never half-deleted.
+  async fn delete_profile_with_cleanup(
+    &self,
+    app_handle: &crate::app_handle::AppHandle,
+    profile: &BrowserProfile,
+    force: bool,
+  ) -> Result<(), String> {
+    let profile_id = profile.id.to_string();
+    Self::stop_profile_for_deletion(app_handle, profile).await?;
+
+    // Persist the stopped, ungrouped state first: if a later step fails the
+    // profile stays listed, and it shouldn't claim a dead PID or a group slot.
+    if profile.process_id.is_some() || profile.group_id.is_some() {
+      let mut detached = profile.clone();
+      detached.process_id = None;
+      detached.group_id = None;
+      self
+        .save_profile(&detached)
+        .map_err(|e| format!("Failed to update profile before deletion: {e}"))?;
+    }
+
+    if profile.is_sync_enabled() {
+      match crate::sync::SyncEngine::create_from_settings(app_handle).await {
+        Ok(engine) => {
+          if let Err(e) = engine.delete_profile(&profile_id).await {
+            if !force {
+              return Err(format!(
+                "Failed to delete remote sync data: {e}. Retry with force to delete it locally only"
+              ));
+            }
+            log::warn!(
+              "Force-deleting profile {profile_id} locally; remote sync deletion failed: {e}"
+            );
+          }
+        }
+        Err(e) => {
+          log::debug!("Sync not configured, skipping remote deletion: {e}");
+        }
+      }
+    }
+
+    let profile_uuid_dir = self.get_profiles_dir().join(&profile_id);
+    if profile_uuid_dir.exists() {
+      fs::remove_dir_all(&profile_uuid_dir)
+        .map_err(|e| format!("Failed to delete profile directory: {e}"))?;
+    }
+    if profile_uuid_dir.exists() {
+      return Err(format!(
+        "Failed to completely delete profile '{}'",
+        profile.name
+      ));
+    }
+
+    log::info!(
+      "Profile '{}' (ID: {}) deleted successfully",
+      profile.name,
+      profile_id
+    );
+    Ok(())
+  }
+
+  /// Kills the profile's browser (a regular stop first, then by PID if it
+  /// survives) and stops the local proxy and any workers it left behind.
+  async fn stop_profile_for_deletion(
+    app_handle: &crate::app_handle::AppHandle,
+    profile: &BrowserProfile,
+  ) -> Result<(), String> {
+    let profile_id = profile.id.to_string();
+
+    // Cross-OS profiles can't be running locally; their PID belongs elsewhere.
+    if let Some(pid) = profile.process_id.filter(|_| !profile.is_cross_os()) {
+      if crate::process_registry::process_exists(pid) {
+        if let Err(e) = crate::browser_runner::BrowserRunner::instance()
+          .kill_browser_process(app_handle.clone(), profile)
+          .await
+        {
+          log::warn!(
+            "Stopping profile '{}' failed, killing PID {pid}: {e}",
+            profile.name
+          );
+        }
+        if !crate::process_registry::terminate(pid, DELETE_KILL_GRACE).await {
+          return Err(format!("Browser process {pid} is still running"));
+        }
+      }
+      crate::team_lock::release_team_lock_if_needed(profile).await;
+    }
+
+    if let Err(e) = PROXY_MANAGER
+      .stop_proxy_by_profile_id(app_handle.clone(), &profile_id)
+      .await
+    {
+      log::warn!("Failed to stop proxy for profile {profile_id}: {e}");
+    }
+    crate::process_registry::reap_profile_children(&profile_id);
+    Ok(())
+  }
+
+  fn generate_clone_name(&self, original_name: &str) -> Result<String, Box<dyn std::error::Error>> {
+    let profiles = self.list_profiles()?;
+    let existing_names: std::collections::HashSet<String> =
+      profiles.iter().map(|p| p.name.clone()).collect();
+
+    let candidate = format!("{original_name} (Copy)");
+    if !existing_names.contains(&candidate) {
+      return Ok(candidate);
+    }
+
+    for i in 2.. {
+      let candidate = format!("{original_name} (Copy {i})");
+      if !existing_names.contains(&candidate) {
+        return Ok(candidate);
+      }
+    }
+
+    unreachable!()
+  }
+
+  pub fn clone_profile(
+    &self,
+    profile_id: &str,
+    custom_name: Option<String>,
+  ) -> Result<BrowserProfile, Box<dyn std::error::Error>> {
+    let profile_uuid =
+      uuid::Uuid::parse_str(profile_id).map_err(|_| format!("Invalid profile ID: {profile_id}"))?;
+    let profiles = self.list_profiles()?;
+    let source = profiles
+      .into_iter()
+      .find(|p| p.id == profile_uuid)
+      .ok_or_else(|| format!("Profile with ID '{profile_id}' not found"))?;
+
+    if source.process_id.is_some() {
+      return Err(
+        "Cannot clone profile while browser is running. Please stop the browser first.".into(),
+      );
+    }
+    if source.archived {
+      return Err("Cannot clone an archived profile. Please unarchive it first.".into());
+    }
+
+    let new_id = uuid::Uuid::new_v4();
+    let clone_name = match custom_name {
+      Some(name) if !name.trim().is_empty() => name.trim().to_string(),
+      _ => self.generate_clone_name(&source.name)?,
+    };
+
+    let profiles_dir = self.get_profiles_dir();
+    let source_dir = profiles_dir.join(source.id.to_string());
+    let dest_dir = profiles_dir.join(new_id.to_string());
+
+    if source_dir.exists() {
+      crate::profile_importer::ProfileImporter::copy_directory_recursive(&source_dir, &dest_dir)?;
+    } else {
+      fs::create_dir_all(&dest_dir)?;
+    }
+
+    let mut new_profile = BrowserProfile {
+      id: new_id,
+      name: clone_name,
+      browser: source.browser,
+      version: source.version,
+      proxy_id: source.proxy_id,
+      vpn_id: source.vpn_id,
+      proxy_pool_id: source.proxy_pool_id,
+      launch_hook: source.launch_hook,
+      process_id: None,
+      cdp_port: None,
+      last_launch: None,
+      release_type: source.release_type,
+      wayfern_config: source.wayfern_config,
+      group_id: source.group_id,
+      tags: source.tags,
+      note: source.note,
+      window_color: source.window_color,
+      sync_mode: SyncMode::Disabled,
+      encryption_salt: None,
+      last_sync: None,
+      host_os: Some(get_host_os()),
+      ephemeral: false,
+      extension_group_id: source.extension_group_id,
+      proxy_bypass_rules: source.proxy_bypass_rules,
+      created_by_id: None,
+      created_by_email: None,
+      dns_blocklist: source.dns_blocklist,
+      password_protected: false,
+      clear_on_close: false,
+      startup_urls: source.startup_urls,
+      session_restore: source.session_restore,
+      preference_overrides: source.preference_overrides,
+      metadata: source.metadata,
+      pin_browser_version: source.pin_browser_version,
+      restart_policy: source.restart_policy,
+      dns_mode: source.dns_mode,
+      exit_mismatch_action: source.exit_mismatch_action,
+      resource_limits: source.resource_limits,
+      applied_resource_limits: None,
+      archived: false,
+      created_at: Some(
+        std::time::SystemTime::now()
+          .duration_since(std::time::UNIX_EPOCH)
+          .map(|d| d.as_secs())
+          .unwrap_or(0),
+      ),
+      updated_at: Some(crate::proxy_manager::now_secs()),
+    };
+
+    // Donut: a clone must NOT be linkable to its source. The source
+    // wayfern_config embeds the persisted fingerprint JSON (including the
+    // canvas_noise_seed), so copying it verbatim makes the clone emit
+    // BYTE-IDENTICAL canvas/WebGL/audio readback hashes and identical device
+    // signals as the source — trivially linkable if both run concurrently. Clear
+    // the fingerprint so the launch path mints a fresh one (a new
+    // canvas_noise_seed via RandBytes + an independent device fingerprint),
+    // exactly as create_profile does when fingerprint.is_none(). NOTE: the
+    // user-data-dir copy above still duplicates cookies/localStorage/TLS state —
+    // a separate storage-linkage vector the user must clear if they want full
+    // isolation between a clone and its source.
+    if let Some(cfg) = new_profile.wayfern_config.as_mut() {
+      cfg.fingerprint = None;
+    }
+
+    self.save_profile(&new_profile)?;
+
+    if let Err(e) = events::emit_empty("profiles-changed") {
+      log::warn!("Warning: Failed to emit profiles-changed event: {e}");
+    }
+
+    Ok(new_profile)
+  }
+
+  pub async fn update_wayfern_config(
+    &self,
+    app_handle: crate::app_handle::AppHandle,
+    profile_id: &str,
+    mut config: WayfernConfig,
+    force_locale: bool,
+  ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    // Find the profile by ID
+    let profile_uuid = uuid::Uuid::parse_str(profile_id).map_err(
+      |_| -> Box<dyn std::error::Error + Send + Sync> {
+        format!("Invalid profile ID: {profile_id}").into()
+      },
+    )?;
+    let profiles =
+      self
+        .list_profiles()
+        .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
+          format!("Failed to list profiles: {e}").into()
+        })?;
+    let mut profile = profiles
+      .into_iter()
+      .find(|p| p.id == profile_uuid)
+      .ok_or_else(|| -> Box<dyn std::error::Error + Send + Sync> {
+        format!("Profile with ID '{profile_id}' not found").into()
+      })?;
+
+    // Check if the browser is currently running using the comprehensive status check
+    let is_running = self
+      .check_browser_status(app_handle.clone(), &profile)
+      .await?;
+
+    if is_running {
+      return Err(
+        "Cannot update Wayfern configuration while browser is running. Please stop the browser first.".into(),
+      );
+    }
+
+    reconcile_locale_override(&mut config, force_locale)?;
+    reconcile_timezone_override(&mut config)?;
+
+    // Update the Wayfern configuration
+    profile.wayfern_config = Some(config);
+
+    // Save the updated profile
+    self
+      .save_profile(&profile)
+      .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
+        format!("Failed to save profile: {e}").into()
+      })?;
+    crate::warm_pool::invalidate(profile_id);
+
+    crate::sync::queue_profile_sync_if_eligible(&profile);
+
+    log::info!(
+      "Wayfern configuration updated for profile '{}' (ID: {}).",
+      profile.name,
+      profile_id
+    );
+
+    // Emit profile config update event
+    if let Err(e) = events::emit_empty("profiles-changed") {
+      log::warn!("Warning: Failed to emit profiles-changed event: {e}");
+    }
+
+    Ok(())
   }
 
   pub async fn update_profile_proxy(
     &self,
-    _app_handle: tauri::AppHandle,
+    _app_handle: crate::app_handle::AppHandle,
     profile_id: &str,
     proxy_id: Option<String>,
   ) -> Result<BrowserProfile, Box<dyn std::error::Error + Send + Sync>> {
@@ -1175,9 +1727,10 @@ impl ProfileManager {
     // Remember old proxy_id for cleanup (not used yet, but may be needed for cleanup)
     let _old_proxy_id = profile.proxy_id.clone();
 
-    // Update proxy settings and clear VPN (mutual exclusion)
+    // Update proxy settings and clear VPN and proxy pool (mutual exclusion)
     profile.proxy_id = proxy_id.clone();
     profile.vpn_id = None;
+    profile.proxy_pool_id = None;
     profile.updated_at = Some(crate::proxy_manager::now_secs());
 
     // Save the updated profile
@@ -1186,6 +1739,7 @@ impl ProfileManager {
       .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
         format!("Failed to save profile: {e}").into()
       })?;
+    crate::warm_pool::invalidate(profile_id);
 
     crate::sync::queue_profile_sync_if_eligible(&profile);
 
@@ -1200,7 +1754,7 @@ impl ProfileManager {
     }
 
     // Emit profile update event so frontend UIs can refresh immediately (e.g. proxy manager)
-    if let Err(e) = events::emit("profile-updated", &profile) {
+    if let Err(e) = events::emit(&ProfileUpdated(&profile)) {
       log::warn!("Warning: Failed to emit profile update event: {e}");
     }
 
@@ -1212,17 +1766,89 @@ impl ProfileManager {
     Ok(profile)
   }
 
-  pub async fn update_profile_vpn(
+  pub async fn update_profile_vpn(
+    &self,
+    _app_handle: crate::app_handle::AppHandle,
+    profile_id: &str,
+    vpn_id: Option<String>,
+  ) -> Result<BrowserProfile, Box<dyn std::error::Error + Send + Sync>> {
+    let profile_uuid = uuid::Uuid::parse_str(profile_id).map_err(
+      |_| -> Box<dyn std::error::Error + Send + Sync> {
+        format!("Invalid profile ID: {profile_id}").into()
+      },
+    )?;
+    let profiles =
+      self
+        .list_profiles()
+        .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
+          format!("Failed to list profiles: {e}").into()
+        })?;
+
+    let mut profile = profiles
+      .into_iter()
+      .find(|p| p.id == profile_uuid)
+      .ok_or_else(|| -> Box<dyn std::error::Error + Send + Sync> {
+        format!("Profile with ID '{profile_id}' not found").into()
+      })?;
+
+    // Update VPN and clear proxy and proxy pool (mutual exclusion)
+    profile.vpn_id = vpn_id.clone();
+    profile.proxy_id = None;
+    profile.proxy_pool_id = None;
+    profile.updated_at = Some(crate::proxy_manager::now_secs());
+
+    self
+      .save_profile(&profile)
+      .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
+        format!("Failed to save profile: {e}").into()
+      })?;
+    crate::warm_pool::invalidate(profile_id);
+
+    crate::sync::queue_profile_sync_if_eligible(&profile);
+
+    // Auto-enable sync for the new VPN if profile has sync enabled.
+    if profile.is_sync_enabled() {
+      if let Some(ref new_vpn_id) = vpn_id {
+        let _ = crate::sync::enable_vpn_sync_if_needed(new_vpn_id).await;
+        if let Some(scheduler) = crate::sync::get_global_scheduler() {
+          scheduler.queue_vpn_sync(new_vpn_id.clone()).await;
+        }
+      }
+    }
+
+    if let Err(e) = events::emit(&ProfileUpdated(&profile)) {
+      log::warn!("Warning: Failed to emit profile update event: {e}");
+    }
+
+    if let Err(e) = events::emit_empty("profiles-changed") {
+      log::warn!("Warning: Failed to emit profiles-changed event: {e}");
+    }
+
+    Ok(profile)
+  }
+
+  pub async fn update_profile_proxy_pool(
     &self,
-    _app_handle: tauri::AppHandle,
     profile_id: &str,
-    vpn_id: Option<String>,
+    proxy_pool_id: Option<String>,
   ) -> Result<BrowserProfile, Box<dyn std::error::Error + Send + Sync>> {
     let profile_uuid = uuid::Uuid::parse_str(profile_id).map_err(
       |_| -> Box<dyn std::error::Error + Send + Sync> {
         format!("Invalid profile ID: {profile_id}").into()
       },
     )?;
+    if let Some(ref pool_id) = proxy_pool_id {
+      if crate::proxy_manager::PROXY_MANAGER
+        .get_proxy_pool(pool_id)
+        .is_none()
+      {
+        return Err(
+          serde_json::json!({ "code": "PROXY_POOL_NOT_FOUND", "params": { "id": pool_id } })
+            .to_string()
+            .into(),
+        );
+      }
+    }
     let profiles =
       self
         .list_profiles()
@@ -1237,9 +1863,10 @@ impl ProfileManager {
         format!("Profile with ID '{profile_id}' not found").into()
       })?;
 
-    // Update VPN and clear proxy (mutual exclusion)
-    profile.vpn_id = vpn_id.clone();
+    // Update the pool and clear proxy and VPN (mutual exclusion)
+    profile.proxy_pool_id = proxy_pool_id.clone();
     profile.proxy_id = None;
+    profile.vpn_id = None;
     profile.updated_at = Some(crate::proxy_manager::now_secs());
 
     self
@@ -1247,20 +1874,21 @@ impl ProfileManager {
       .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
         format!("Failed to save profile: {e}").into()
       })?;
+    crate::warm_pool::invalidate(profile_id);
 
     crate::sync::queue_profile_sync_if_eligible(&profile);
 
-    // Auto-enable sync for the new VPN if profile has sync enabled.
+    // Auto-enable sync for the pool and its members if profile has sync enabled.
     if profile.is_sync_enabled() {
-      if let Some(ref new_vpn_id) = vpn_id {
-        let _ = crate::sync::enable_vpn_sync_if_needed(new_vpn_id).await;
+      if let Some(ref new_pool_id) = proxy_pool_id {
+        let _ = crate::sync::enable_proxy_pool_sync_if_needed(new_pool_id).await;
         if let Some(scheduler) = crate::sync::get_global_scheduler() {
-          scheduler.queue_vpn_sync(new_vpn_id.clone()).await;
+          scheduler.queue_proxy_pool_sync(new_pool_id.clone()).await;
         }
       }
     }
 
-    if let Err(e) = events::emit("profile-updated", &profile) {
+    if let Err(e) = events::emit(&ProfileUpdated(&profile)) {
       log::warn!("Warning: Failed to emit profile update event: {e}");
     }
 
@@ -1304,7 +1932,7 @@ impl ProfileManager {
       }
     }
 
-    if let Err(e) = events::emit("profile-updated", &profile) {
+    if let Err(e) = events::emit(&ProfileUpdated(&profile)) {
       log::warn!("Failed to emit profile update event: {e}");
     }
     if let Err(e) = events::emit_empty("profiles-changed") {
@@ -1316,65 +1944,45 @@ impl ProfileManager {
 
   pub async fn check_browser_status(
     &self,
-    app_handle: tauri::AppHandle,
+    app_handle: crate::app_handle::AppHandle,
+    profile: &BrowserProfile,
+  ) -> Result<bool, Box<dyn std::error::Error + Send + Sync>> {
+    self
+      .check_browser_status_in(app_handle, profile, None)
+      .await
+  }
+
+  /// Like [`Self::check_browser_status`], looking the browser up in
+  /// `snapshot` when given, so checking many profiles scans processes once.
+  pub async fn check_browser_status_in(
+    &self,
+    app_handle: crate::app_handle::AppHandle,
     profile: &BrowserProfile,
+    snapshot: Option<&crate::process_registry::ProcessSnapshot>,
   ) -> Result<bool, Box<dyn std::error::Error + Send + Sync>> {
+    // The checks below correct the stored PID; an observer only reads it.
+    if crate::observer_mode::is_enabled() {
+      return Ok(crate::observer_mode::is_profile_running(profile));
+    }
+
     // Handle Wayfern profiles using WayfernManager-based status checking
     if profile.browser == "wayfern" {
-      return self.check_wayfern_status(&app_handle, profile).await;
+      return self
+        .check_wayfern_status(&app_handle, profile, snapshot)
+        .await;
     }
 
     // For non-wayfern browsers, use the existing PID-based logic
     let inner_profile = profile.clone();
-    let system = System::new_with_specifics(
-      RefreshKind::nothing().with_processes(ProcessRefreshKind::everything()),
-    );
-    let mut is_running = false;
-    let mut found_pid: Option<u32> = None;
-
-    // First check if the stored PID is still valid
-    if let Some(pid) = profile.process_id {
-      if let Some(process) = system.process(Pid::from(pid as usize)) {
-        let cmd = process.cmd();
-        // Verify this process is actually our browser with the correct profile
-        let profiles_dir = self.get_profiles_dir();
-        let profile_data_path = profile.get_profile_data_path(&profiles_dir);
-        let profile_data_path_str = profile_data_path.to_string_lossy();
-        let profile_path_match = cmd.iter().any(|s| {
-          let arg = s.to_str().unwrap_or("");
-          // Match the Chromium --user-data-dir flag or an exact profile path argument
-          arg.contains(&format!("--user-data-dir={profile_data_path_str}"))
-            || arg == profile_data_path_str
-        });
-
-        if profile_path_match {
-          is_running = true;
-          found_pid = Some(pid);
-        }
-      }
-    }
-
-    // If we didn't find the browser with the stored PID, search all processes
-    if !is_running {
-      for (pid, process) in system.processes() {
-        let cmd = process.cmd();
-        if cmd.len() >= 2 {
-          // Check if this is the right browser executable first
-          let exe_name = process.name().to_string_lossy().to_lowercase();
-          let is_correct_browser = match profile.browser.as_str() {
-            "wayfern" => {
-              exe_name.contains("wayfern")
-                || exe_name.contains("chromium")
-                || exe_name.contains("chrome")
-            }
-            _ => false,
-          };
-
-          if !is_correct_browser {
-            continue;
-          }
-
-          // Check for profile path match
+    let (is_running, found_pid) = crate::process_registry::with_process_table(|system| {
+      let mut is_running = false;
+      let mut found_pid: Option<u32> = None;
+
+      // First check if the stored PID is still valid
+      if let Some(pid) = profile.process_id {
+        if let Some(process) = system.process(Pid::from(pid as usize)) {
+          let cmd = process.cmd();
+          // Verify this process is actually our browser with the correct profile
           let profiles_dir = self.get_profiles_dir();
           let profile_data_path = profile.get_profile_data_path(&profiles_dir);
           let profile_data_path_str = profile_data_path.to_string_lossy();
@@ -1386,19 +1994,63 @@ impl ProfileManager {
           });
 
           if profile_path_match {
-            // Found a matching process
-            found_pid = Some(pid.as_u32());
             is_running = true;
-            log::info!(
-              "Found browser process with PID: {} for profile: {}",
-              pid.as_u32(),
-              profile.name
-            );
-            break;
+            found_pid = Some(pid);
           }
         }
       }
-    }
+
+      // If we didn't find the browser with the stored PID, search all processes
+      if !is_running {
+        for (pid, process) in system.processes() {
+          let cmd = process.cmd();
+          if cmd.len() >= 2 {
+            // Check if this is the right browser executable first
+            let exe_name = process.name().to_string_lossy().to_lowercase();
+            let is_correct_browser = match profile.browser.as_str() {
+              "wayfern" => {
+                exe_name.contains("wayfern")
+                  || exe_name.contains("chromium")
+                  || exe_name.contains("chrome")
+              }
+              // A shell script; the user-data-dir match below decides.
+              #[cfg(feature = "test-browser")]
+              crate::test_browser::STUB_BROWSER => true,
+              _ => false,
+            };
+
+            if !is_correct_browser {
+              continue;
+            }
+
+            // Check for profile path match
+            let profiles_dir = self.get_profiles_dir();
+            let profile_data_path = profile.get_profile_data_path(&profiles_dir);
+            let profile_data_path_str = profile_data_path.to_string_lossy();
+            let profile_path_match = cmd.iter().any(|s| {
+              let arg = s.to_str().unwrap_or("");
+              // Match the Chromium --user-data-dir flag or an exact profile path argument
+              arg.contains(&format!("--user-data-dir={profile_data_path_str}"))
+                || arg == profile_data_path_str
+            });
+
+            if profile_path_match {
+              // Found a matching process
+              found_pid = Some(pid.as_u32());
+              is_running = true;
+              log::info!(
+                "Found browser process with PID: {} for profile: {}",
+                pid.as_u32(),
+                profile.name
+              );
+              break;
+            }
+          }
+        }
+      }
+
+      (is_running, found_pid)
+    });
 
     // Only persist status changes if the profile metadata still exists on disk
     let profiles_dir = self.get_profiles_dir();
@@ -1408,9 +2060,9 @@ impl ProfileManager {
 
     if metadata_exists {
       // Load the latest profile from disk to avoid overwriting fields like proxy_id
-      let latest_profile: BrowserProfile = match std::fs::read_to_string(&metadata_file)
+      let latest_profile: BrowserProfile = match crate::json_file::read(&metadata_file)
         .ok()
-        .and_then(|s| serde_json::from_str(&s).ok())
+        .map(|loaded| loaded.value)
       {
         Some(p) => p,
         None => inner_profile.clone(),
@@ -1433,6 +2085,8 @@ impl ProfileManager {
       } else if merged.process_id.is_some() {
         // Clear the PID if no process found
         merged.process_id = None;
+        merged.cdp_port = None;
+        merged.applied_resource_limits = None;
         if let Err(e) = self.save_profile(&merged) {
           log::warn!("Warning: Failed to clear profile PID: {e}");
         }
@@ -1448,7 +2102,7 @@ impl ProfileManager {
       }
 
       // Emit profile update event to frontend
-      if let Err(e) = events::emit("profile-updated", &merged) {
+      if let Err(e) = events::emit(&ProfileUpdated(&merged)) {
         log::warn!("Warning: Failed to emit profile update event: {e}");
       }
     }
@@ -1459,8 +2113,9 @@ impl ProfileManager {
   // Check Wayfern status using WayfernManager
   async fn check_wayfern_status(
     &self,
-    app_handle: &tauri::AppHandle,
+    app_handle: &crate::app_handle::AppHandle,
     profile: &BrowserProfile,
+    snapshot: Option<&crate::process_registry::ProcessSnapshot>,
   ) -> Result<bool, Box<dyn std::error::Error + Send + Sync>> {
     let manager = self.wayfern_manager;
     let profiles_dir = self.get_profiles_dir();
@@ -1469,7 +2124,10 @@ impl ProfileManager {
     let profile_path_str = profile_data_path.to_string_lossy();
 
     // Check if there's a running Wayfern instance for this profile
-    match manager.find_wayfern_by_profile(&profile_path_str).await {
+    match manager
+      .find_wayfern_by_profile_in(&profile_path_str, snapshot)
+      .await
+    {
       Some(wayfern_process) => {
         // Found a running instance, update profile with process info if changed
         let profiles_dir = self.get_profiles_dir();
@@ -1479,26 +2137,42 @@ impl ProfileManager {
 
         if metadata_exists {
           // Load latest to avoid overwriting other fields
-          let mut latest: BrowserProfile = match std::fs::read_to_string(&metadata_file)
+          let mut latest: BrowserProfile = match crate::json_file::read(&metadata_file)
             .ok()
-            .and_then(|s| serde_json::from_str(&s).ok())
+            .map(|loaded| loaded.value)
           {
             Some(p) => p,
             None => profile.clone(),
           };
 
-          if latest.process_id != wayfern_process.processId {
+          if latest.process_id != wayfern_process.processId
+            || latest.cdp_port != wayfern_process.cdp_port
+          {
             let old_pid = latest.process_id;
             latest.process_id = wayfern_process.processId;
+            latest.cdp_port = wayfern_process.cdp_port;
             if let Err(e) = self.save_profile(&latest) {
               log::warn!("Warning: Failed to update Wayfern profile with process info: {e}");
             }
-            if let (Some(prev), Some(new)) = (old_pid, wayfern_process.processId) {
-              let _ = crate::proxy_manager::PROXY_MANAGER.update_proxy_pid(prev, new);
+            if let Some(new) = wayfern_process.processId {
+              let remapped = old_pid.is_some_and(|prev| {
+                crate::proxy_manager::PROXY_MANAGER
+                  .update_proxy_pid(prev, new)
+                  .is_ok()
+              });
+              // A process started outside this session has no mapping to
+              // carry over; attach the profile's live worker, if any.
+              if !remapped
+                && wayfern_process.is_recovered()
+                && (latest.proxy_id.is_some() || latest.vpn_id.is_some())
+              {
+                crate::proxy_manager::PROXY_MANAGER
+                  .adopt_proxy_for_browser(&latest.id.to_string(), new);
+              }
             }
 
             // Emit profile update event to frontend
-            if let Err(e) = events::emit("profile-updated", &latest) {
+            if let Err(e) = events::emit(&ProfileUpdated(&latest)) {
               log::warn!("Warning: Failed to emit profile update event: {e}");
             }
 
@@ -1523,9 +2197,9 @@ impl ProfileManager {
         let metadata_exists = metadata_file.exists();
 
         if metadata_exists {
-          let mut latest: BrowserProfile = match std::fs::read_to_string(&metadata_file)
+          let mut latest: BrowserProfile = match crate::json_file::read(&metadata_file)
             .ok()
-            .and_then(|s| serde_json::from_str(&s).ok())
+            .map(|loaded| loaded.value)
           {
             Some(p) => p,
             None => profile.clone(),
@@ -1533,6 +2207,8 @@ impl ProfileManager {
 
           if latest.process_id.is_some() {
             latest.process_id = None;
+            latest.cdp_port = None;
+            latest.applied_resource_limits = None;
             if let Err(e) = self.save_profile(&latest) {
               log::warn!("Warning: Failed to clear Wayfern profile process info: {e}");
             }
@@ -1543,7 +2219,7 @@ impl ProfileManager {
               latest = updated;
             }
 
-            if let Err(e) = events::emit("profile-updated", &latest) {
+            if let Err(e) = events::emit(&ProfileUpdated(&latest)) {
               log::warn!("Warning: Failed to emit profile update event: {e}");
             }
           }
@@ -1627,6 +2303,21 @@ mod tests {
     );
   }
 
+  #[test]
+  fn test_merge_tag_list_replaces_and_dedupes() {
+    let tags: Vec<String> = ["clinetA", "vip", "clientA", "x"]
+      .iter()
+      .map(|t| t.to_string())
+      .collect();
+    let merged = merge_tag_list(&tags, &["clinetA".to_string()], "clientA").unwrap();
+    assert_eq!(merged, vec!["clientA", "vip", "x"]);
+
+    let merged = merge_tag_list(&tags, &["vip".to_string(), "x".to_string()], "important").unwrap();
+    assert_eq!(merged, vec!["clinetA", "important", "clientA"]);
+
+    assert!(merge_tag_list(&tags, &["missing".to_string()], "y").is_none());
+  }
+
   #[test]
   fn test_normalize_launch_hook_clears_empty_values() {
     let result = ProfileManager::normalize_launch_hook(Some("   ".to_string())).unwrap();
@@ -1640,6 +2331,44 @@ mod tests {
     assert!(err.to_string().contains("http or https"));
   }
 
+  #[test]
+  fn test_validate_startup_urls_trims_and_skips_blank() {
+    let urls = super::validate_startup_urls(vec![
+      " https://example.com ".to_string(),
+      "".to_string(),
+      "about:blank".to_string(),
+    ])
+    .unwrap();
+    assert_eq!(urls, vec!["https://example.com", "about:blank"]);
+  }
+
+  #[test]
+  fn test_validate_startup_urls_rejects_other_schemes_with_code() {
+    let err = super::validate_startup_urls(vec!["javascript:alert(1)".to_string()]).unwrap_err();
+    let parsed: serde_json::Value = serde_json::from_str(&err).expect("error must be JSON");
+    assert_eq!(parsed["code"], "INVALID_STARTUP_URL");
+    assert_eq!(parsed["params"]["url"], "javascript:alert(1)");
+  }
+
+  #[test]
+  fn test_startup_urls_for_launch_prefers_explicit_url() {
+    let mut profile = BrowserProfile {
+      startup_urls: vec!["https://a.example".to_string()],
+      ..Default::default()
+    };
+    assert!(profile.startup_urls_for_launch(None).is_empty());
+
+    profile.session_restore = SessionRestore::Urls;
+    assert_eq!(
+      profile.startup_urls_for_launch(None),
+      vec!["https://a.example"]
+    );
+    assert_eq!(
+      profile.startup_urls_for_launch(Some("https://b.example")),
+      vec!["https://b.example"]
+    );
+  }
+
   #[test]
   fn test_validate_launch_hook_accepts_https_url() {
     let result = super::validate_launch_hook(Some("https://example.com/track")).unwrap();
@@ -1671,12 +2400,254 @@ mod tests {
     let result_none = super::validate_launch_hook(None).unwrap();
     assert!(result_none.is_none());
   }
+
+  #[test]
+  fn test_normalize_doh_resolver_requires_https() {
+    assert_eq!(
+      normalize_doh_resolver(" https://dns.example/dns-query ").unwrap(),
+      "https://dns.example/dns-query"
+    );
+    for invalid in ["http://dns.example/dns-query", "dns.example", ""] {
+      let err = normalize_doh_resolver(invalid).unwrap_err();
+      let parsed: serde_json::Value = serde_json::from_str(&err).unwrap();
+      assert_eq!(parsed["code"], "INVALID_DOH_RESOLVER_URL");
+    }
+  }
+
+  #[test]
+  fn test_dns_mode_serializes_with_mode_tag() {
+    let doh = DnsMode::Doh {
+      resolver_url: "https://dns.example/dns-query".to_string(),
+    };
+    assert_eq!(
+      serde_json::to_value(&doh).unwrap(),
+      serde_json::json!({ "mode": "doh", "resolver_url": "https://dns.example/dns-query" })
+    );
+    let legacy: BrowserProfile =
+      serde_json::from_value(serde_json::json!({ "id": uuid::Uuid::new_v4(), "name": "p", "browser": "wayfern", "version": "1" }))
+        .unwrap();
+    assert_eq!(legacy.dns_mode, DnsMode::System);
+  }
+
+  fn stub_profile(name: &str, process_id: Option<u32>) -> BrowserProfile {
+    BrowserProfile {
+      id: uuid::Uuid::new_v4(),
+      name: name.to_string(),
+      browser: "stub".to_string(),
+      version: "1.0".to_string(),
+      release_type: "stable".to_string(),
+      process_id,
+      group_id: Some("group-1".to_string()),
+      ..Default::default()
+    }
+  }
+
+  #[cfg(unix)]
+  #[tokio::test]
+  async fn test_delete_multiple_profiles_kills_running_browser() {
+    let temp_dir = TempDir::new().unwrap();
+    let _guard = crate::app_dirs::set_test_data_dir(temp_dir.path().to_path_buf());
+    let manager = ProfileManager::instance();
+
+    let mut stub = std::process::Command::new("sleep")
+      .arg("30")
+      .spawn()
+      .expect("spawn stub browser");
+    let pid = stub.id();
+    // Reap the stub once it's killed so it doesn't linger as a zombie.
+    let reaper = std::thread::spawn(move || stub.wait());
+
+    let running = stub_profile("Running", Some(pid));
+    let idle = stub_profile("Idle", None);
+    manager.save_profile(&running).unwrap();
+    manager.save_profile(&idle).unwrap();
+    let missing = uuid::Uuid::new_v4().to_string();
+
+    let result = manager
+      .delete_multiple_profiles(
+        &crate::app_handle::AppHandle::Headless,
+        vec![running.id.to_string(), missing.clone(), idle.id.to_string()],
+        false,
+      )
+      .await;
+
+    assert_eq!(
+      result.deleted,
+      vec![running.id.to_string(), idle.id.to_string()]
+    );
+    assert_eq!(result.failed.len(), 1);
+    assert_eq!(result.failed[0].profile_id, missing);
+    assert!(result.failed[0].profile_name.is_none());
+
+    let status = reaper.join().unwrap().expect("wait for stub");
+    assert!(!status.success(), "stub should have been killed");
+    assert!(manager.list_profiles().unwrap().is_empty());
+    assert!(!temp_dir
+      .path()
+      .join("profiles")
+      .join(running.id.to_string())
+      .exists());
+  }
+
+  #[cfg(unix)]
+  #[tokio::test]
+  async fn test_delete_multiple_profiles_skips_already_exited_browser() {
+    let temp_dir = TempDir::new().unwrap();
+    let _guard = crate::app_dirs::set_test_data_dir(temp_dir.path().to_path_buf());
+    let manager = ProfileManager::instance();
+
+    let mut stub = std::process::Command::new("true")
+      .spawn()
+      .expect("spawn stub browser");
+    let pid = stub.id();
+    stub.wait().unwrap();
+
+    let stale = stub_profile("Stale PID", Some(pid));
+    manager.save_profile(&stale).unwrap();
+
+    let result = manager
+      .delete_multiple_profiles(
+        &crate::app_handle::AppHandle::Headless,
+        vec![stale.id.to_string()],
+        false,
+      )
+      .await;
+
+    assert_eq!(result.deleted, vec![stale.id.to_string()]);
+    assert!(result.failed.is_empty());
+  }
+
+  #[test]
+  fn test_concurrent_metadata_merges_keep_every_key() {
+    use crate::profile::metadata::MetadataUpdate;
+
+    let temp_dir = TempDir::new().unwrap();
+    let _guard = crate::app_dirs::set_test_data_dir(temp_dir.path().to_path_buf());
+    let manager = ProfileManager::instance();
+    let profile = stub_profile("Metadata", None);
+    manager.save_profile(&profile).unwrap();
+    let profile_id = profile.id.to_string();
+
+    const WRITERS: usize = 16;
+    std::thread::scope(|scope| {
+      for i in 0..WRITERS {
+        let data_dir = temp_dir.path().to_path_buf();
+        let profile_id = &profile_id;
+        scope.spawn(move || {
+          // The test data dir is thread-local.
+          let _guard = crate::app_dirs::set_test_data_dir(data_dir);
+          let update = MetadataUpdate::Merge {
+            metadata: HashMap::from([(format!("key_{i}"), i.to_string())]),
+          };
+          manager.update_profile_metadata(profile_id, update).unwrap();
+        });
+      }
+    });
+
+    let saved = manager
+      .list_profiles()
+      .unwrap()
+      .into_iter()
+      .find(|p| p.id == profile.id)
+      .unwrap();
+    assert_eq!(saved.metadata.len(), WRITERS);
+    assert_eq!(saved.metadata.get("key_3").map(String::as_str), Some("3"));
+  }
+
+  #[test]
+  fn test_truncated_metadata_is_recovered_from_backup() {
+    let temp_dir = TempDir::new().unwrap();
+    let _guard = crate::app_dirs::set_test_data_dir(temp_dir.path().to_path_buf());
+    let manager = ProfileManager::instance();
+    let mut profile = stub_profile("Before", None);
+    manager.save_profile(&profile).unwrap();
+    profile.name = "After".to_string();
+    manager.save_profile(&profile).unwrap();
+
+    let metadata_file = manager
+      .get_profiles_dir()
+      .join(profile.id.to_string())
+      .join("metadata.json");
+    let full = fs::read(&metadata_file).unwrap();
+    fs::write(&metadata_file, &full[..full.len() / 2]).unwrap();
+
+    let profiles = manager.list_profiles().unwrap();
+    assert_eq!(profiles.len(), 1);
+    assert_eq!(profiles[0].name, "Before");
+    assert!(serde_json::from_slice::<BrowserProfile>(&fs::read(&metadata_file).unwrap()).is_ok());
+  }
+
+  #[test]
+  fn test_concurrent_saves_never_corrupt_metadata() {
+    let temp_dir = TempDir::new().unwrap();
+    let _guard = crate::app_dirs::set_test_data_dir(temp_dir.path().to_path_buf());
+    let manager = ProfileManager::instance();
+    let profile = stub_profile("Writer", None);
+    manager.save_profile(&profile).unwrap();
+
+    const WRITERS: usize = 10;
+    std::thread::scope(|scope| {
+      for i in 0..WRITERS {
+        let data_dir = temp_dir.path().to_path_buf();
+        let mut profile = profile.clone();
+        scope.spawn(move || {
+          let _guard = crate::app_dirs::set_test_data_dir(data_dir);
+          for round in 0..20 {
+            profile.name = format!("writer-{i}-{round}");
+            manager.save_profile(&profile).unwrap();
+          }
+        });
+      }
+    });
+
+    let profile_dir = manager.get_profiles_dir().join(profile.id.to_string());
+    let saved: BrowserProfile =
+      serde_json::from_slice(&fs::read(profile_dir.join("metadata.json")).unwrap()).unwrap();
+    assert!(saved.name.ends_with("-19"));
+    let backup: BrowserProfile =
+      serde_json::from_slice(&fs::read(profile_dir.join("metadata.json.bak")).unwrap()).unwrap();
+    assert!(backup.name.starts_with("writer-"));
+    let leftovers: Vec<_> = fs::read_dir(&profile_dir)
+      .unwrap()
+      .flatten()
+      .filter(|e| e.file_name().to_string_lossy().ends_with(".tmp"))
+      .collect();
+    assert!(leftovers.is_empty());
+  }
+
+  #[test]
+  fn timezone_override_is_canonicalized_and_rewrites_the_fingerprint() {
+    let mut config = WayfernConfig {
+      timezone_override: Some(" asia/tokyo".to_string()),
+      fingerprint: Some(r#"{"timezone":"America/New_York","userAgent":"x"}"#.to_string()),
+      ..Default::default()
+    };
+    reconcile_timezone_override(&mut config).unwrap();
+    assert_eq!(config.timezone_override.as_deref(), Some("Asia/Tokyo"));
+    let fp: serde_json::Value =
+      serde_json::from_str(config.fingerprint.as_deref().unwrap()).unwrap();
+    assert_eq!(fp["timezone"], "Asia/Tokyo");
+    assert_eq!(fp["timezoneOffset"], -540);
+    assert_eq!(fp["userAgent"], "x");
+
+    let mut garbage = WayfernConfig {
+      timezone_override: Some("Asia/Tokio".to_string()),
+      ..Default::default()
+    };
+    let err: serde_json::Value =
+      serde_json::from_str(&reconcile_timezone_override(&mut garbage).unwrap_err()).unwrap();
+    assert_eq!(err["code"], "INVALID_TIMEZONE");
+    assert!(err["params"]["suggestions"]
+      .as_str()
+      .unwrap()
+      .starts_with("Asia/Tokyo"));
+  }
 }
 
 #[allow(clippy::too_many_arguments)]
 #[tauri::command]
 pub async fn create_browser_profile_with_group(
-  app_handle: tauri::AppHandle,
+  app_handle: crate::app_handle::AppHandle,
   name: String,
   browser: String,
   version: String,
@@ -1709,43 +2680,102 @@ pub async fn create_browser_profile_with_group(
     .map_err(|e| crate::wrap_backend_error(e, "Failed to create profile"))
 }
 
+/// Archived profiles are left out unless `include_archived` is set.
 #[tauri::command]
-pub fn list_browser_profiles() -> Result<Vec<BrowserProfile>, String> {
+pub fn list_browser_profiles(
+  include_archived: Option<bool>,
+) -> Result<Vec<BrowserProfile>, String> {
   let profile_manager = ProfileManager::instance();
-  profile_manager
+  let mut profiles = profile_manager
     .list_profiles()
-    .map_err(|e| format!("Failed to list profiles: {e}"))
+    .map_err(|e| format!("Failed to list profiles: {e}"))?;
+  if !include_archived.unwrap_or(false) {
+    profiles.retain(|p| !p.archived);
+  }
+  Ok(profiles)
 }
 
 #[tauri::command]
 pub async fn update_profile_proxy(
-  app_handle: tauri::AppHandle,
+  app_handle: crate::app_handle::AppHandle,
   profile_id: String,
   proxy_id: Option<String>,
 ) -> Result<BrowserProfile, String> {
   let profile_manager = ProfileManager::instance();
-  profile_manager
-    .update_profile_proxy(app_handle, &profile_id, proxy_id)
-    .await
-    .map_err(|e| format!("Failed to update profile: {e}"))
+  crate::audit::audited!(
+    crate::audit::AuditActor::Gui,
+    "update_proxy",
+    "profile",
+    Some(profile_id.clone()),
+    profile_manager
+      .update_profile_proxy(app_handle, &profile_id, proxy_id)
+      .await
+      .map_err(|e| format!("Failed to update profile: {e}"))
+  )
 }
 
 #[tauri::command]
 pub async fn update_profile_vpn(
-  app_handle: tauri::AppHandle,
+  app_handle: crate::app_handle::AppHandle,
   profile_id: String,
   vpn_id: Option<String>,
 ) -> Result<BrowserProfile, String> {
   let profile_manager = ProfileManager::instance();
-  profile_manager
-    .update_profile_vpn(app_handle, &profile_id, vpn_id)
-    .await
-    .map_err(|e| format!("Failed to update profile VPN: {e}"))
+  crate::audit::audited!(
+    crate::audit::AuditActor::Gui,
+    "update_vpn",
+    "profile",
+    Some(profile_id.clone()),
+    profile_manager
+      .update_profile_vpn(app_handle, &profile_id, vpn_id)
+      .await
+      .map_err(|e| format!("Failed to update profile VPN: {e}"))
+  )
+}
+
+#[tauri::command]
+pub async fn update_profile_proxy_pool(
+  profile_id: String,
+  proxy_pool_id: Option<String>,
+) -> Result<BrowserProfile, String> {
+  let profile_manager = ProfileManager::instance();
+  crate::audit::audited!(
+    crate::audit::AuditActor::Gui,
+    "update_proxy_pool",
+    "profile",
+    Some(profile_id.clone()),
+    profile_manager
+      .update_profile_proxy_pool(&profile_id, proxy_pool_id)
+      .await
+      .map_err(|e| crate::wrap_backend_error(e, "Failed to update profile proxy pool"))
+  )
+}
+
+/// `tags` with every entry in `sources` replaced by `destination` (kept at the
+/// position of the first replaced tag) and duplicates dropped, or `None` when
+/// none of `sources` is present.
+fn merge_tag_list(tags: &[String], sources: &[String], destination: &str) -> Option<Vec<String>> {
+  if !tags.iter().any(|t| sources.contains(t)) {
+    return None;
+  }
+  let mut seen = std::collections::HashSet::new();
+  let merged: Vec<String> = tags
+    .iter()
+    .map(|t| {
+      if sources.contains(t) {
+        destination.to_string()
+      } else {
+        t.clone()
+      }
+    })
+    .filter(|t| seen.insert(t.clone()))
+    .collect();
+  Some(merged)
 }
 
 #[tauri::command]
 pub fn update_profile_tags(
-  app_handle: tauri::AppHandle,
+  app_handle: crate::app_handle::AppHandle,
   profile_id: String,
   tags: Vec<String>,
 ) -> Result<BrowserProfile, String> {
@@ -1755,9 +2785,23 @@ pub fn update_profile_tags(
     .map_err(|e| format!("Failed to update profile tags: {e}"))
 }
 
+#[tauri::command]
+pub fn rename_tag(from: String, to: String) -> Result<usize, String> {
+  ProfileManager::instance()
+    .merge_tags(&[from], &to)
+    .map_err(|e| crate::wrap_backend_error(e, "Failed to rename tag"))
+}
+
+#[tauri::command]
+pub fn merge_tags(sources: Vec<String>, destination: String) -> Result<usize, String> {
+  ProfileManager::instance()
+    .merge_tags(&sources, &destination)
+    .map_err(|e| crate::wrap_backend_error(e, "Failed to merge tags"))
+}
+
 #[tauri::command]
 pub fn update_profile_note(
-  app_handle: tauri::AppHandle,
+  app_handle: crate::app_handle::AppHandle,
   profile_id: String,
   note: Option<String>,
 ) -> Result<BrowserProfile, String> {
@@ -1769,7 +2813,7 @@ pub fn update_profile_note(
 
 #[tauri::command]
 pub fn update_profile_window_color(
-  app_handle: tauri::AppHandle,
+  app_handle: crate::app_handle::AppHandle,
   profile_id: String,
   window_color: Option<String>,
 ) -> Result<BrowserProfile, String> {
@@ -1780,7 +2824,7 @@ pub fn update_profile_window_color(
 
 #[tauri::command]
 pub fn update_profile_clear_on_close(
-  app_handle: tauri::AppHandle,
+  app_handle: crate::app_handle::AppHandle,
   profile_id: String,
   clear_on_close: bool,
 ) -> Result<BrowserProfile, String> {
@@ -1810,9 +2854,48 @@ pub(crate) fn validate_launch_hook(launch_hook: Option<&str>) -> Result<Option<S
   Ok(Some(trimmed.to_string()))
 }
 
+/// Trims startup URLs, drops blank entries and rejects anything that is not
+/// an http(s), about: or chrome: URL.
+pub(crate) fn validate_startup_urls(startup_urls: Vec<String>) -> Result<Vec<String>, String> {
+  let mut urls = Vec::with_capacity(startup_urls.len());
+  for raw in startup_urls {
+    let trimmed = raw.trim();
+    if trimmed.is_empty() {
+      continue;
+    }
+    let ok = url::Url::parse(trimmed)
+      .map(|u| matches!(u.scheme(), "http" | "https" | "about" | "chrome"))
+      .unwrap_or(false);
+    if !ok {
+      return Err(
+        serde_json::json!({
+          "code": "INVALID_STARTUP_URL",
+          "params": { "url": trimmed }
+        })
+        .to_string(),
+      );
+    }
+    urls.push(trimmed.to_string());
+  }
+  Ok(urls)
+}
+
+/// Trims a DNS-over-HTTPS resolver URL and requires it to be https with a
+/// host, or returns the `INVALID_DOH_RESOLVER_URL` code payload.
+pub(crate) fn normalize_doh_resolver(raw: &str) -> Result<String, String> {
+  let trimmed = raw.trim();
+  let ok = url::Url::parse(trimmed)
+    .ok()
+    .is_some_and(|u| u.scheme() == "https" && u.host_str().is_some());
+  if !ok {
+    return Err(serde_json::json!({ "code": "INVALID_DOH_RESOLVER_URL" }).to_string());
+  }
+  Ok(trimmed.to_string())
+}
+
 #[tauri::command]
 pub fn update_profile_launch_hook(
-  app_handle: tauri::AppHandle,
+  app_handle: crate::app_handle::AppHandle,
   profile_id: String,
   launch_hook: Option<String>,
 ) -> Result<BrowserProfile, String> {
@@ -1825,7 +2908,7 @@ pub fn update_profile_launch_hook(
 
 #[tauri::command]
 pub fn update_profile_proxy_bypass_rules(
-  app_handle: tauri::AppHandle,
+  app_handle: crate::app_handle::AppHandle,
   profile_id: String,
   rules: Vec<String>,
 ) -> Result<BrowserProfile, String> {
@@ -1835,6 +2918,127 @@ pub fn update_profile_proxy_bypass_rules(
     .map_err(|e| format!("Failed to update proxy bypass rules: {e}"))
 }
 
+#[tauri::command]
+pub fn update_profile_startup(
+  profile_id: String,
+  startup_urls: Vec<String>,
+  session_restore: SessionRestore,
+) -> Result<BrowserProfile, String> {
+  ProfileManager::instance()
+    .update_profile_startup(&profile_id, startup_urls, session_restore)
+    .map_err(|e| crate::wrap_backend_error(e, "Failed to update profile startup"))
+}
+
+/// Replace a profile's preference overrides. Keys outside the supported
+/// Chromium subset are rejected as a whole with `UNSUPPORTED_PREFERENCES`.
+#[tauri::command]
+pub fn update_profile_preferences(
+  profile_id: String,
+  preferences: HashMap<String, serde_json::Value>,
+) -> Result<BrowserProfile, String> {
+  ProfileManager::instance()
+    .update_profile_preferences(&profile_id, preferences)
+    .map_err(|e| crate::wrap_backend_error(e, "Failed to update profile preferences"))
+}
+
+/// Set, merge into, or delete keys from the profile's key/value metadata.
+#[tauri::command]
+pub fn update_profile_metadata(
+  profile_id: String,
+  update: crate::profile::metadata::MetadataUpdate,
+) -> Result<BrowserProfile, String> {
+  ProfileManager::instance()
+    .update_profile_metadata(&profile_id, update)
+    .map_err(|e| crate::wrap_backend_error(e, "Failed to update profile metadata"))
+}
+
+/// Set when the status checker relaunches this profile after its browser
+/// exits on its own.
+#[tauri::command]
+pub fn update_profile_restart_policy(
+  profile_id: String,
+  policy: RestartPolicy,
+) -> Result<BrowserProfile, String> {
+  ProfileManager::instance()
+    .update_profile_restart_policy(&profile_id, policy)
+    .map_err(|e| crate::wrap_backend_error(e, "Failed to update restart policy"))
+}
+
+/// Where the profile's browser resolves hostnames; applied on next launch.
+#[tauri::command]
+pub fn update_profile_dns_mode(
+  profile_id: String,
+  dns_mode: DnsMode,
+) -> Result<BrowserProfile, String> {
+  ProfileManager::instance()
+    .update_profile_dns_mode(&profile_id, dns_mode)
+    .map_err(|e| crate::wrap_backend_error(e, "Failed to update DNS mode"))
+}
+
+/// Whether a launch whose exit is not where the proxy or VPN expects it warns
+/// or is refused.
+#[tauri::command]
+pub fn update_profile_exit_mismatch_action(
+  profile_id: String,
+  action: ExitMismatchAction,
+) -> Result<BrowserProfile, String> {
+  ProfileManager::instance()
+    .update_profile_exit_mismatch_action(&profile_id, action)
+    .map_err(|e| crate::wrap_backend_error(e, "Failed to update exit mismatch action"))
+}
+
+/// How the profile's browser exposes WebRTC; `None` restores the browser
+/// default. Rejected while the profile is running.
+#[tauri::command]
+pub fn update_profile_webrtc_mode(
+  profile_id: String,
+  mode: Option<WebRtcMode>,
+) -> Result<BrowserProfile, String> {
+  ProfileManager::instance()
+    .update_profile_webrtc_mode(&profile_id, mode)
+    .map_err(|e| crate::wrap_backend_error(e, "Failed to update WebRTC mode"))
+}
+
+/// Priority and memory limit for the profile's browser; applied on next
+/// launch. Unset fields use the app-wide defaults.
+#[tauri::command]
+pub fn update_profile_resource_limits(
+  profile_id: String,
+  limits: ResourceLimits,
+) -> Result<BrowserProfile, String> {
+  ProfileManager::instance()
+    .update_profile_resource_limits(&profile_id, limits)
+    .map_err(|e| crate::wrap_backend_error(e, "Failed to update resource limits"))
+}
+
+/// Keep a profile on its current browser version; auto-updates skip it.
+#[tauri::command]
+pub fn pin_profile_version(profile_id: String) -> Result<BrowserProfile, String> {
+  ProfileManager::instance()
+    .set_profile_version_pinned(&profile_id, true)
+    .map_err(|e| format!("Failed to pin profile version: {e}"))
+}
+
+/// Opt a pinned profile back into auto-updates and move it to the latest
+/// downloaded version right away. A running profile is moved when it stops.
+#[tauri::command]
+pub fn unpin_profile_version(
+  app_handle: crate::app_handle::AppHandle,
+  profile_id: String,
+) -> Result<BrowserProfile, String> {
+  let profile = ProfileManager::instance()
+    .set_profile_version_pinned(&profile_id, false)
+    .map_err(|e| format!("Failed to unpin profile version: {e}"))?;
+  if profile.process_id.is_some() {
+    return Ok(profile);
+  }
+  Ok(
+    crate::auto_updater::AutoUpdater::instance()
+      .update_profile_to_latest_installed(&app_handle, &profile)
+      .unwrap_or(profile),
+  )
+}
+
 #[tauri::command]
 pub fn update_profile_dns_blocklist(
   profile_id: String,
@@ -1848,7 +3052,7 @@ pub fn update_profile_dns_blocklist(
 
 #[tauri::command]
 pub async fn check_browser_status(
-  app_handle: tauri::AppHandle,
+  app_handle: crate::app_handle::AppHandle,
   profile: BrowserProfile,
 ) -> Result<bool, String> {
   let profile_manager = ProfileManager::instance();
@@ -1860,7 +3064,7 @@ pub async fn check_browser_status(
 
 #[tauri::command]
 pub fn rename_profile(
-  app_handle: tauri::AppHandle,
+  app_handle: crate::app_handle::AppHandle,
   profile_id: String,
   new_name: String,
 ) -> Result<BrowserProfile, String> {
@@ -1873,7 +3077,7 @@ pub fn rename_profile(
 #[allow(clippy::too_many_arguments)]
 #[tauri::command]
 pub async fn create_browser_profile_new(
-  app_handle: tauri::AppHandle,
+  app_handle: crate::app_handle::AppHandle,
   name: String,
   browser_str: String,
   version: String,
@@ -1918,11 +3122,78 @@ pub async fn create_browser_profile_new(
   .await
 }
 
+/// Checks a pinned `locale_override` against the stored fingerprint, which
+/// otherwise keeps reporting its old languages until the next location
+/// refresh. A mismatch is rejected with `LOCALE_CONFLICTS_WITH_FINGERPRINT`
+/// unless `force` is set, in which case only the fingerprint's language fields
+/// are rewritten to the override.
+fn reconcile_locale_override(config: &mut WayfernConfig, force: bool) -> Result<(), String> {
+  let Some(spec) = config.locale_override.as_deref() else {
+    return Ok(());
+  };
+  let prefs = crate::geolocation::parse_locale_list(spec).ok_or_else(|| {
+    serde_json::json!({ "code": "INVALID_LOCALE", "params": { "locale": spec } }).to_string()
+  })?;
+  let Some(fingerprint) = config.fingerprint.as_deref() else {
+    return Ok(());
+  };
+  if crate::wayfern_manager::WayfernManager::fingerprint_matches_locale(fingerprint, &prefs) {
+    return Ok(());
+  }
+  if !force {
+    return Err(
+      serde_json::json!({
+        "code": "LOCALE_CONFLICTS_WITH_FINGERPRINT",
+        "params": { "locale": prefs.language }
+      })
+      .to_string(),
+    );
+  }
+  config.fingerprint =
+    crate::wayfern_manager::WayfernManager::apply_locale_to_fingerprint(fingerprint, &prefs)
+      .ok_or("Stored fingerprint is not valid JSON")?
+      .into();
+  Ok(())
+}
+
+/// Validates a pinned `timezone_override` against the tzdb names, storing its
+/// canonical spelling, and moves the stored fingerprint onto it. An unknown
+/// zone is rejected with `INVALID_TIMEZONE`, carrying the closest valid names.
+fn reconcile_timezone_override(config: &mut WayfernConfig) -> Result<(), String> {
+  let Some(spec) = config
+    .timezone_override
+    .as_deref()
+    .filter(|tz| !tz.trim().is_empty())
+  else {
+    config.timezone_override = None;
+    return Ok(());
+  };
+  let timezone = crate::geolocation::parse_timezone(spec).ok_or_else(|| {
+    serde_json::json!({
+      "code": "INVALID_TIMEZONE",
+      "params": {
+        "timezone": spec,
+        "suggestions": crate::geolocation::closest_timezones(spec, 3).join(", "),
+      }
+    })
+    .to_string()
+  })?;
+  config.timezone_override = Some(timezone.to_string());
+  if let Some(fingerprint) = config.fingerprint.as_deref() {
+    config.fingerprint =
+      crate::wayfern_manager::WayfernManager::apply_timezone_to_fingerprint(fingerprint, timezone)
+        .ok_or("Stored fingerprint is not valid JSON")?
+        .into();
+  }
+  Ok(())
+}
+
 #[tauri::command]
 pub async fn update_wayfern_config(
-  app_handle: tauri::AppHandle,
+  app_handle: crate::app_handle::AppHandle,
   profile_id: String,
   config: WayfernConfig,
+  force_locale: Option<bool>,
 ) -> Result<(), String> {
   if config.fingerprint.is_some()
     && !crate::cloud_auth::CLOUD_AUTH
@@ -1941,9 +3212,14 @@ pub async fn update_wayfern_config(
 
   let profile_manager = ProfileManager::instance();
   profile_manager
-    .update_wayfern_config(app_handle, &profile_id, config)
+    .update_wayfern_config(
+      app_handle,
+      &profile_id,
+      config,
+      force_locale.unwrap_or(false),
+    )
     .await
-    .map_err(|e| format!("Failed to update Wayfern config: {e}"))
+    .map_err(|e| crate::wrap_backend_error(e, "Failed to update Wayfern config"))
 }
 
 #[tauri::command]
@@ -1954,10 +3230,19 @@ pub fn clone_profile(profile_id: String, name: Option<String>) -> Result<Browser
 }
 
 #[tauri::command]
-pub fn delete_profile(app_handle: tauri::AppHandle, profile_id: String) -> Result<(), String> {
-  ProfileManager::instance()
-    .delete_profile(&app_handle, &profile_id)
-    .map_err(|e| format!("Failed to delete profile: {e}"))
+pub fn delete_profile(
+  app_handle: crate::app_handle::AppHandle,
+  profile_id: String,
+) -> Result<(), String> {
+  crate::audit::audited!(
+    crate::audit::AuditActor::Gui,
+    "delete",
+    "profile",
+    Some(profile_id.clone()),
+    ProfileManager::instance()
+      .delete_profile(&app_handle, &profile_id)
+      .map_err(|e| format!("Failed to delete profile: {e}"))
+  )
 }
 
 lazy_static::lazy_static! {
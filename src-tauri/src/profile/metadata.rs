@@ -0,0 +1,207 @@
+//! Free-form key/value metadata on profiles (`account_id`, `persona_name`, …)
+//! that automation writes through the API and the GUI displays and filters by.
+//! Keys are restricted so they can be matched as `metadata.<key>=<value>` in
+//! list filters; the whole map is capped so it stays small in metadata.json.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+pub const MAX_METADATA_KEYS: usize = 64;
+pub const MAX_METADATA_KEY_LEN: usize = 64;
+pub const MAX_METADATA_VALUE_LEN: usize = 1024;
+/// Sum of key and value lengths across the map.
+pub const MAX_METADATA_BYTES: usize = 16 * 1024;
+
+/// How `update_profile_metadata` changes the map.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, utoipa::ToSchema)]
+#[serde(tag = "mode", rename_all = "snake_case")]
+pub enum MetadataUpdate {
+  /// Replace the whole map.
+  Set { metadata: HashMap<String, String> },
+  /// Add or overwrite these keys, leaving the others alone.
+  Merge { metadata: HashMap<String, String> },
+  /// Remove these keys; missing ones are ignored.
+  Delete { keys: Vec<String> },
+}
+
+fn metadata_error(code: &str, key: Option<&str>) -> String {
+  match key {
+    Some(key) => serde_json::json!({ "code": code, "params": { "key": key } }).to_string(),
+    None => serde_json::json!({
+      "code": code,
+      "params": { "maxKeys": MAX_METADATA_KEYS, "maxBytes": MAX_METADATA_BYTES }
+    })
+    .to_string(),
+  }
+}
+
+/// ASCII letters, digits, `_`, `-` and `.`, starting with a letter or digit.
+pub fn validate_metadata_key(key: &str) -> Result<(), String> {
+  let valid = !key.is_empty()
+    && key.len() <= MAX_METADATA_KEY_LEN
+    && key.starts_with(|c: char| c.is_ascii_alphanumeric())
+    && key
+      .chars()
+      .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'));
+  if valid {
+    Ok(())
+  } else {
+    Err(metadata_error("INVALID_METADATA_KEY", Some(key)))
+  }
+}
+
+fn validate_entries(metadata: &HashMap<String, String>) -> Result<(), String> {
+  for (key, value) in metadata {
+    validate_metadata_key(key)?;
+    if value.len() > MAX_METADATA_VALUE_LEN {
+      return Err(metadata_error("METADATA_VALUE_TOO_LONG", Some(key)));
+    }
+  }
+  Ok(())
+}
+
+/// The map after applying `update` to `current`, or a coded error if a key is
+/// invalid or the result exceeds the caps.
+pub fn apply_metadata_update(
+  current: &HashMap<String, String>,
+  update: MetadataUpdate,
+) -> Result<HashMap<String, String>, String> {
+  let next = match update {
+    MetadataUpdate::Set { metadata } => {
+      validate_entries(&metadata)?;
+      metadata
+    }
+    MetadataUpdate::Merge { metadata } => {
+      validate_entries(&metadata)?;
+      let mut next = current.clone();
+      next.extend(metadata);
+      next
+    }
+    MetadataUpdate::Delete { keys } => {
+      let mut next = current.clone();
+      for key in &keys {
+        next.remove(key);
+      }
+      next
+    }
+  };
+
+  let bytes: usize = next.iter().map(|(k, v)| k.len() + v.len()).sum();
+  if next.len() > MAX_METADATA_KEYS || bytes > MAX_METADATA_BYTES {
+    return Err(metadata_error("METADATA_TOO_LARGE", None));
+  }
+  Ok(next)
+}
+
+/// Whether `metadata` carries every `(key, value)` pair in `filters`.
+pub fn metadata_matches(metadata: &HashMap<String, String>, filters: &[(String, String)]) -> bool {
+  filters
+    .iter()
+    .all(|(key, value)| metadata.get(key) == Some(value))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn map(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+    pairs
+      .iter()
+      .map(|(k, v)| (k.to_string(), v.to_string()))
+      .collect()
+  }
+
+  #[test]
+  fn set_merge_and_delete() {
+    let current = map(&[("account_id", "42"), ("persona_name", "Ann")]);
+
+    let merged = apply_metadata_update(
+      &current,
+      MetadataUpdate::Merge {
+        metadata: map(&[("persona_name", "Bea"), ("recovery_email", "b@x.io")]),
+      },
+    )
+    .unwrap();
+    assert_eq!(
+      merged,
+      map(&[
+        ("account_id", "42"),
+        ("persona_name", "Bea"),
+        ("recovery_email", "b@x.io")
+      ])
+    );
+
+    let deleted = apply_metadata_update(
+      &merged,
+      MetadataUpdate::Delete {
+        keys: vec!["account_id".to_string(), "missing".to_string()],
+      },
+    )
+    .unwrap();
+    assert_eq!(deleted.len(), 2);
+    assert!(!deleted.contains_key("account_id"));
+
+    let set = apply_metadata_update(
+      &deleted,
+      MetadataUpdate::Set {
+        metadata: map(&[("only", "1")]),
+      },
+    )
+    .unwrap();
+    assert_eq!(set, map(&[("only", "1")]));
+  }
+
+  #[test]
+  fn rejects_bad_keys_and_oversized_maps() {
+    for key in ["", "-lead", "has space", "ünïcode", "a=b", &"k".repeat(65)] {
+      let err = apply_metadata_update(
+        &HashMap::new(),
+        MetadataUpdate::Merge {
+          metadata: map(&[(key, "v")]),
+        },
+      )
+      .unwrap_err();
+      assert!(err.contains("INVALID_METADATA_KEY"), "{key:?}: {err}");
+    }
+    assert!(validate_metadata_key("crm.account-id_2").is_ok());
+
+    let long_value = "v".repeat(MAX_METADATA_VALUE_LEN + 1);
+    assert!(apply_metadata_update(
+      &HashMap::new(),
+      MetadataUpdate::Set {
+        metadata: map(&[("k", &long_value)]),
+      },
+    )
+    .unwrap_err()
+    .contains("METADATA_VALUE_TOO_LONG"));
+
+    let too_many: HashMap<String, String> = (0..=MAX_METADATA_KEYS)
+      .map(|i| (format!("k{i}"), String::new()))
+      .collect();
+    assert!(
+      apply_metadata_update(&HashMap::new(), MetadataUpdate::Set { metadata: too_many })
+        .unwrap_err()
+        .contains("METADATA_TOO_LARGE")
+    );
+  }
+
+  #[test]
+  fn update_modes_deserialize_from_tagged_json() {
+    let update: MetadataUpdate =
+      serde_json::from_str(r#"{"mode":"delete","keys":["account_id"]}"#).unwrap();
+    assert_eq!(
+      update,
+      MetadataUpdate::Delete {
+        keys: vec!["account_id".to_string()]
+      }
+    );
+    assert!(metadata_matches(
+      &map(&[("a", "1"), ("b", "2")]),
+      &[("a".to_string(), "1".to_string())]
+    ));
+    assert!(!metadata_matches(
+      &map(&[("a", "1")]),
+      &[("a".to_string(), "2".to_string())]
+    ));
+  }
+}
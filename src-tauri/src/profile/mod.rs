@@ -1,7 +1,16 @@
+pub mod archive;
 pub mod clear_on_close;
+pub mod disk_usage;
 pub mod encryption;
+pub mod exit_history;
 pub mod manager;
+pub mod metadata;
 pub mod password;
+pub mod preferences;
+pub mod refresh;
+pub mod restart_supervisor;
+pub mod snapshots;
+pub mod status_checker;
 pub mod types;
 
 pub use manager::ProfileManager;
@@ -0,0 +1,227 @@
+//! Per-profile browser preference overrides. Wayfern is Chromium, so overrides
+//! are written into the `Default/Preferences` JSON before every launch. Only a
+//! documented subset of keys is accepted; anything else is reported back to the
+//! caller instead of being silently dropped.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use serde_json::{Map, Value};
+
+/// Chromium preference paths (dotted, as they appear in `Preferences`) that a
+/// profile may pin. Anything the fingerprint or another profile setting already
+/// controls (languages, startup pages, proxy) is deliberately absent.
+pub const SUPPORTED_PREFERENCES: &[&str] = &[
+  "alternate_error_pages.enabled",
+  "autofill.credit_card_enabled",
+  "autofill.profile_enabled",
+  "bookmark_bar.show_on_all_tabs",
+  "browser.show_home_button",
+  "credentials_enable_service",
+  "download.default_directory",
+  "download.prompt_for_download",
+  "enable_do_not_track",
+  "profile.block_third_party_cookies",
+  "profile.cookie_controls_mode",
+  "profile.default_content_setting_values.geolocation",
+  "profile.default_content_setting_values.images",
+  "profile.default_content_setting_values.javascript",
+  "profile.default_content_setting_values.media_stream_camera",
+  "profile.default_content_setting_values.media_stream_mic",
+  "profile.default_content_setting_values.notifications",
+  "profile.default_content_setting_values.popups",
+  "profile.password_manager_enabled",
+  "safebrowsing.enabled",
+  "search.suggest_enabled",
+  "translate.enabled",
+  "webrtc.ip_handling_policy",
+  "webrtc.multiple_routes_enabled",
+  "webrtc.nonproxied_udp_enabled",
+];
+
+/// Top-level `Preferences` key listing the paths we wrote on the last launch.
+/// Chromium keeps unknown keys, so this marks our section of the file: a path
+/// dropped from the overrides is removed again instead of lingering forever.
+const MANAGED_KEYS_FIELD: &str = "donut_managed_preferences";
+
+/// Keys in `preferences` that are not in [`SUPPORTED_PREFERENCES`], sorted.
+pub fn unsupported_preferences(preferences: &HashMap<String, Value>) -> Vec<String> {
+  let mut keys: Vec<String> = preferences
+    .keys()
+    .filter(|k| !SUPPORTED_PREFERENCES.contains(&k.as_str()))
+    .cloned()
+    .collect();
+  keys.sort();
+  keys
+}
+
+/// Merge `preferences` into `<user_data_dir>/Default/Preferences`, leaving
+/// every key we don't manage untouched.
+pub fn apply_preference_overrides(
+  user_data_dir: &Path,
+  preferences: &HashMap<String, Value>,
+) -> Result<(), String> {
+  let profile_dir = user_data_dir.join("Default");
+  let prefs_path = profile_dir.join("Preferences");
+
+  let mut root = match fs::read_to_string(&prefs_path) {
+    Ok(content) => serde_json::from_str::<Value>(&content)
+      .map_err(|e| format!("Failed to parse {}: {e}", prefs_path.display()))?,
+    Err(e) if e.kind() == std::io::ErrorKind::NotFound => Value::Object(Map::new()),
+    Err(e) => return Err(format!("Failed to read {}: {e}", prefs_path.display())),
+  };
+  let Some(object) = root.as_object_mut() else {
+    return Err(format!("{} is not a JSON object", prefs_path.display()));
+  };
+
+  let previously_managed: Vec<String> = object
+    .remove(MANAGED_KEYS_FIELD)
+    .and_then(|v| serde_json::from_value(v).ok())
+    .unwrap_or_default();
+  if preferences.is_empty() && previously_managed.is_empty() {
+    return Ok(());
+  }
+
+  for key in &previously_managed {
+    if !preferences.contains_key(key) {
+      remove_path(object, key);
+    }
+  }
+
+  let mut managed: Vec<String> = Vec::with_capacity(preferences.len());
+  for (key, value) in preferences {
+    if !SUPPORTED_PREFERENCES.contains(&key.as_str()) {
+      log::warn!("Skipping unsupported preference override: {key}");
+      continue;
+    }
+    set_path(object, key, value.clone());
+    managed.push(key.clone());
+  }
+  managed.sort();
+  if !managed.is_empty() {
+    object.insert(MANAGED_KEYS_FIELD.to_string(), Value::from(managed));
+  }
+
+  fs::create_dir_all(&profile_dir)
+    .map_err(|e| format!("Failed to create {}: {e}", profile_dir.display()))?;
+  let serialized = serde_json::to_string(&root).map_err(|e| e.to_string())?;
+  fs::write(&prefs_path, serialized)
+    .map_err(|e| format!("Failed to write {}: {e}", prefs_path.display()))
+}
+
+fn set_path(object: &mut Map<String, Value>, path: &str, value: Value) {
+  let mut segments = path.split('.').peekable();
+  let mut current = object;
+  while let Some(segment) = segments.next() {
+    if segments.peek().is_none() {
+      current.insert(segment.to_string(), value);
+      return;
+    }
+    let entry = current
+      .entry(segment.to_string())
+      .or_insert_with(|| Value::Object(Map::new()));
+    if !entry.is_object() {
+      *entry = Value::Object(Map::new());
+    }
+    current = entry.as_object_mut().expect("just ensured an object");
+  }
+}
+
+fn remove_path(object: &mut Map<String, Value>, path: &str) {
+  match path.split_once('.') {
+    None => {
+      object.remove(path);
+    }
+    Some((head, rest)) => {
+      if let Some(Value::Object(child)) = object.get_mut(head) {
+        remove_path(child, rest);
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use serde_json::json;
+  use tempfile::TempDir;
+
+  fn read_prefs(dir: &Path) -> Value {
+    let content = fs::read_to_string(dir.join("Default").join("Preferences")).unwrap();
+    serde_json::from_str(&content).unwrap()
+  }
+
+  #[test]
+  fn reports_unsupported_keys_sorted() {
+    let prefs = HashMap::from([
+      ("webrtc.ip_handling_policy".to_string(), json!("default")),
+      ("privacy.resistFingerprinting".to_string(), json!(true)),
+      ("network.trr.mode".to_string(), json!(3)),
+    ]);
+    assert_eq!(
+      unsupported_preferences(&prefs),
+      vec!["network.trr.mode", "privacy.resistFingerprinting"]
+    );
+  }
+
+  #[test]
+  fn merges_without_touching_other_keys() {
+    let tmp = TempDir::new().unwrap();
+    let default = tmp.path().join("Default");
+    fs::create_dir_all(&default).unwrap();
+    fs::write(
+      default.join("Preferences"),
+      r#"{"extensions":{"settings":{"abc":{}}},"profile":{"name":"Person 1"}}"#,
+    )
+    .unwrap();
+
+    let prefs = HashMap::from([
+      (
+        "profile.default_content_setting_values.notifications".to_string(),
+        json!(2),
+      ),
+      ("enable_do_not_track".to_string(), json!(true)),
+    ]);
+    apply_preference_overrides(tmp.path(), &prefs).unwrap();
+
+    let written = read_prefs(tmp.path());
+    assert_eq!(written["extensions"]["settings"]["abc"], json!({}));
+    assert_eq!(written["profile"]["name"], "Person 1");
+    assert_eq!(
+      written["profile"]["default_content_setting_values"]["notifications"],
+      2
+    );
+    assert_eq!(written["enable_do_not_track"], true);
+  }
+
+  #[test]
+  fn removes_overrides_dropped_since_last_launch() {
+    let tmp = TempDir::new().unwrap();
+    let first = HashMap::from([
+      ("translate.enabled".to_string(), json!(false)),
+      ("search.suggest_enabled".to_string(), json!(false)),
+    ]);
+    apply_preference_overrides(tmp.path(), &first).unwrap();
+
+    let second = HashMap::from([("translate.enabled".to_string(), json!(false))]);
+    apply_preference_overrides(tmp.path(), &second).unwrap();
+
+    let written = read_prefs(tmp.path());
+    assert_eq!(written["translate"]["enabled"], false);
+    assert!(written["search"].get("suggest_enabled").is_none());
+    assert_eq!(written[MANAGED_KEYS_FIELD], json!(["translate.enabled"]));
+
+    apply_preference_overrides(tmp.path(), &HashMap::new()).unwrap();
+    let cleared = read_prefs(tmp.path());
+    assert!(cleared["translate"].get("enabled").is_none());
+    assert!(cleared.get(MANAGED_KEYS_FIELD).is_none());
+  }
+
+  #[test]
+  fn leaves_fresh_profiles_alone_without_overrides() {
+    let tmp = TempDir::new().unwrap();
+    apply_preference_overrides(tmp.path(), &HashMap::new()).unwrap();
+    assert!(!tmp.path().join("Default").exists());
+  }
+}
@@ -0,0 +1,277 @@
+//! Profile refresh: a clean slate for a profile whose browser data is in a
+//! broken state (corrupt storage, conflicting extensions), like Firefox's
+//! "Refresh". Only what the sites a profile uses know it by survives —
+//! cookies, saved logins, bookmarks, history and the os_crypt key that
+//! decrypts them. The fingerprint lives in the profile metadata and is left
+//! alone; preference overrides are written again on the next launch.
+//!
+//! The old data dir is not deleted but moved to
+//! `backups/<profile id>/<timestamp>.backup`, where the periodic cleanup keeps
+//! the newest `snapshot_retention` of them, as it does for snapshots.
+
+use serde_json::{Map, Value};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::profile::clear_on_close::is_profile_dir_name;
+use crate::profile::snapshots::{ensure_stopped, find_profile, snapshots_dir};
+use crate::profile::ProfileManager;
+
+/// Files kept from each Chromium profile directory, relative to it. SQLite
+/// journals travel with their databases.
+const PROFILE_KEEP: &[&str] = &[
+  "Bookmarks",
+  "Cookies",
+  "Cookies-journal",
+  "History",
+  "History-journal",
+  "Login Data",
+  "Login Data-journal",
+  "Login Data For Account",
+  "Login Data For Account-journal",
+  // Newer Chromium keeps the cookie store under Network/.
+  "Network/Cookies",
+  "Network/Cookies-journal",
+];
+
+const LOCAL_STATE: &str = "Local State";
+
+/// `Local State` keys kept. `os_crypt` holds the key cookies and saved
+/// passwords are encrypted with; without it both are unreadable.
+const LOCAL_STATE_KEEP: &[&str] = &["os_crypt"];
+
+/// Staging dir for the fresh data dir, inside the profile dir so the swap is
+/// a rename on the same filesystem.
+const REFRESH_STAGING_DIR: &str = ".refresh-staging";
+
+const BACKUP_EXT: &str = "backup";
+
+fn code(code: &str) -> String {
+  serde_json::json!({ "code": code }).to_string()
+}
+
+/// Copies the [`PROFILE_KEEP`] files present in `from` to `to`. Returns how
+/// many were copied.
+fn copy_kept(from: &Path, to: &Path) -> io::Result<usize> {
+  let mut copied = 0;
+  for name in PROFILE_KEEP {
+    let source = from.join(name);
+    if !source.is_file() {
+      continue;
+    }
+    let target = to.join(name);
+    if let Some(parent) = target.parent() {
+      fs::create_dir_all(parent)?;
+    }
+    fs::copy(&source, &target)?;
+    copied += 1;
+  }
+  Ok(copied)
+}
+
+/// `Local State` reduced to [`LOCAL_STATE_KEEP`], or None when there is no
+/// readable one.
+fn filtered_local_state(path: &Path) -> Option<Value> {
+  let state: Value = serde_json::from_str(&fs::read_to_string(path).ok()?).ok()?;
+  let kept: Map<String, Value> = state
+    .as_object()?
+    .iter()
+    .filter(|(key, _)| LOCAL_STATE_KEEP.contains(&key.as_str()))
+    .map(|(key, value)| (key.clone(), value.clone()))
+    .collect();
+  Some(Value::Object(kept))
+}
+
+/// Writes the refreshed copy of a Wayfern user-data dir into `staging`.
+/// Handles both layouts `clear_on_close` does: profile content at the root
+/// (imported profiles) and `Default` / `Profile N` subdirs. Returns how many
+/// files were kept.
+fn build_fresh_data_dir(data_dir: &Path, staging: &Path) -> io::Result<usize> {
+  fs::create_dir_all(staging)?;
+  let mut kept = copy_kept(data_dir, staging)?;
+  for entry in fs::read_dir(data_dir)?.flatten() {
+    let name = entry.file_name();
+    let Some(name) = name.to_str() else { continue };
+    let path = entry.path();
+    if path.is_dir() && (is_profile_dir_name(name) || path.join("Preferences").exists()) {
+      kept += copy_kept(&path, &staging.join(name))?;
+    }
+  }
+  if let Some(state) = filtered_local_state(&data_dir.join(LOCAL_STATE)) {
+    fs::write(staging.join(LOCAL_STATE), serde_json::to_vec(&state)?)?;
+    kept += 1;
+  }
+  Ok(kept)
+}
+
+/// Swaps `profile_dir/profile` for a refreshed copy and moves the old one to
+/// `backup`. Returns how many files were kept.
+fn refresh_data_dir(profile_dir: &Path, backup: &Path) -> Result<usize, String> {
+  let data_dir = profile_dir.join("profile");
+  let staging = profile_dir.join(REFRESH_STAGING_DIR);
+  let _ = fs::remove_dir_all(&staging);
+
+  let kept = match build_fresh_data_dir(&data_dir, &staging) {
+    Ok(kept) => kept,
+    Err(e) => {
+      let _ = fs::remove_dir_all(&staging);
+      return Err(format!("Failed to copy profile data: {e}"));
+    }
+  };
+
+  if let Some(parent) = backup.parent() {
+    fs::create_dir_all(parent).map_err(|e| format!("Failed to create backups dir: {e}"))?;
+  }
+  if let Err(e) = fs::rename(&data_dir, backup) {
+    let _ = fs::remove_dir_all(&staging);
+    return Err(format!("Failed to move profile data to its backup: {e}"));
+  }
+  if let Err(e) = fs::rename(&staging, &data_dir) {
+    let _ = fs::rename(backup, &data_dir);
+    let _ = fs::remove_dir_all(&staging);
+    return Err(format!("Failed to swap in refreshed profile data: {e}"));
+  }
+  Ok(kept)
+}
+
+fn backup_path(profile_id: &str) -> PathBuf {
+  let stamp = chrono::Utc::now().format("%Y%m%d-%H%M%S-%3f");
+  snapshots_dir(profile_id).join(format!("{stamp}.{BACKUP_EXT}"))
+}
+
+/// Deletes all but the newest `keep` refresh backups in one profile's backups
+/// dir. Returns how many were removed.
+pub(crate) fn prune_backups_in(dir: &Path, keep: usize) -> usize {
+  let Ok(entries) = fs::read_dir(dir) else {
+    return 0;
+  };
+  let mut backups: Vec<PathBuf> = entries
+    .flatten()
+    .map(|e| e.path())
+    .filter(|p| p.is_dir() && p.extension().is_some_and(|ext| ext == BACKUP_EXT))
+    .collect();
+  // Names are timestamps, so the oldest sort first.
+  backups.sort();
+  let excess = backups.len().saturating_sub(keep);
+  backups
+    .into_iter()
+    .take(excess)
+    .filter(|p| fs::remove_dir_all(p).is_ok())
+    .count()
+}
+
+/// Replaces a stopped profile's browser data with a fresh dir holding only
+/// its cookies, logins, bookmarks, history and os_crypt key. A synced profile
+/// is queued for a full rehash so the refreshed state replaces the remote one.
+#[tauri::command]
+pub async fn refresh_profile(profile_id: String) -> Result<(), String> {
+  let profile = find_profile(&profile_id)?;
+  if profile.ephemeral {
+    return Err(code("PROFILE_EPHEMERAL"));
+  }
+  // Sealed file names hide which files to keep.
+  if profile.password_protected {
+    return Err(code("PROFILE_PASSWORD_PROTECTED"));
+  }
+  ensure_stopped(&profile)?;
+
+  let profile_dir = ProfileManager::instance()
+    .get_profiles_dir()
+    .join(&profile_id);
+  if !profile_dir.join("profile").is_dir() {
+    return Ok(());
+  }
+  let backup = backup_path(&profile_id);
+  let kept = tokio::task::spawn_blocking(move || refresh_data_dir(&profile_dir, &backup))
+    .await
+    .map_err(|e| format!("Refresh task failed: {e}"))??;
+
+  crate::profile::disk_usage::invalidate_usage(&profile_id);
+  crate::sync::queue_profile_rehash_if_eligible(&profile);
+  log::info!("Refreshed profile {profile_id}, keeping {kept} files");
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn write(path: &Path, contents: &[u8]) {
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, contents).unwrap();
+  }
+
+  #[test]
+  fn refresh_keeps_identity_files_and_backs_up_the_rest() {
+    let tmp = tempfile::tempdir().unwrap();
+    let profile_dir = tmp.path().join("profile-dir");
+    let data = profile_dir.join("profile");
+    write(
+      &data.join(LOCAL_STATE),
+      br#"{"os_crypt":{"encrypted_key":"k"},"browser":{"enabled_labs_experiments":[]}}"#,
+    );
+    write(&data.join("Default/Preferences"), b"{}");
+    write(&data.join("Default/Network/Cookies"), b"cookies");
+    write(&data.join("Default/Login Data"), b"logins");
+    write(&data.join("Default/Bookmarks"), b"bookmarks");
+    write(&data.join("Default/Extensions/x/manifest.json"), b"{}");
+    write(&data.join("Default/Local Storage/leveldb/000003.log"), b"x");
+    write(&data.join("ShaderCache/data_0"), b"x");
+    let backup = tmp.path().join("backups/20260101-000000-000.backup");
+
+    assert_eq!(refresh_data_dir(&profile_dir, &backup).unwrap(), 4);
+
+    assert_eq!(
+      fs::read(data.join("Default/Network/Cookies")).unwrap(),
+      b"cookies"
+    );
+    assert!(data.join("Default/Login Data").exists());
+    assert!(data.join("Default/Bookmarks").exists());
+    assert!(!data.join("Default/Preferences").exists());
+    assert!(!data.join("Default/Extensions").exists());
+    assert!(!data.join("Default/Local Storage").exists());
+    assert!(!data.join("ShaderCache").exists());
+    let state: Value =
+      serde_json::from_str(&fs::read_to_string(data.join(LOCAL_STATE)).unwrap()).unwrap();
+    assert_eq!(state["os_crypt"]["encrypted_key"], "k");
+    assert!(state.get("browser").is_none());
+
+    assert!(backup.join("Default/Extensions/x/manifest.json").exists());
+    assert!(!profile_dir.join(REFRESH_STAGING_DIR).exists());
+  }
+
+  #[test]
+  fn refresh_handles_profile_content_at_the_root() {
+    let tmp = tempfile::tempdir().unwrap();
+    let profile_dir = tmp.path().join("profile-dir");
+    let data = profile_dir.join("profile");
+    write(&data.join("Preferences"), b"{}");
+    write(&data.join("History"), b"history");
+    write(&data.join("Cache/data_0"), b"x");
+
+    refresh_data_dir(&profile_dir, &tmp.path().join("old.backup")).unwrap();
+
+    assert!(data.join("History").exists());
+    assert!(!data.join("Preferences").exists());
+    assert!(!data.join("Cache").exists());
+  }
+
+  #[test]
+  fn prune_keeps_newest_backups() {
+    let tmp = tempfile::tempdir().unwrap();
+    for name in [
+      "20260101-000000-000.backup",
+      "20260301-000000-000.backup",
+      "20260201-000000-000.backup",
+    ] {
+      fs::create_dir_all(tmp.path().join(name)).unwrap();
+    }
+    fs::write(tmp.path().join("snapshot.json"), b"{}").unwrap();
+
+    assert_eq!(prune_backups_in(tmp.path(), 2), 1);
+    assert!(!tmp.path().join("20260101-000000-000.backup").exists());
+    assert!(tmp.path().join("20260201-000000-000.backup").exists());
+    assert!(tmp.path().join("snapshot.json").exists());
+  }
+}
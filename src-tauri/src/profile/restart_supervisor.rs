@@ -0,0 +1,426 @@
+//! Crash detection and automatic relaunch for running profiles.
+//!
+//! The status checker only sees a browser disappear. To tell a crash from an
+//! intentional stop, `kill_browser_process` records the stop here before it
+//! kills anything, and the Wayfern launch path hands us the spawned child so
+//! its exit status can be read. Every classified exit is appended to the
+//! profile's exit history. When a profile's [`RestartPolicy`] asks for it,
+//! the profile is relaunched with the options it was last started with.
+//! Restarts are capped per rolling hour so a crash loop ends instead of
+//! relaunching forever.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use lazy_static::lazy_static;
+
+use crate::events;
+use crate::events::payloads::{ProfileCrashed, ProfileRestarted};
+use crate::profile::exit_history::{self, ProfileExit, ProfileExitKind};
+use crate::profile::types::{BrowserProfile, RestartMode, RestartPolicy};
+
+const RESTART_WINDOW_SECS: u64 = 3600;
+/// Exit statuses nobody asked for (the profile was deleted, or its exit was
+/// never noticed) are dropped after this long.
+const EXIT_RECORD_TTL: Duration = Duration::from_secs(600);
+/// Grace period before relaunching, so the dead browser's profile lock and
+/// local proxy are gone before the new instance starts.
+const RESTART_DELAY: Duration = Duration::from_secs(2);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExitKind {
+  /// `kill_browser_process` stopped it.
+  Requested,
+  /// The browser exited with status 0, e.g. its last window was closed.
+  Clean,
+  /// Anything else, including exits whose status we could not observe.
+  Crashed,
+}
+
+/// How a profile was last launched, replayed on restart.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LaunchOptions {
+  pub remote_debugging_port: Option<u16>,
+  pub headless: bool,
+}
+
+#[derive(Debug, PartialEq)]
+struct ObservedExit {
+  success: bool,
+  code: Option<i32>,
+  signal: Option<i32>,
+  reason: String,
+  at: Instant,
+}
+
+#[derive(Default)]
+struct SupervisorState {
+  stop_requested: HashSet<String>,
+  exits: HashMap<u32, ObservedExit>,
+  launch_options: HashMap<String, LaunchOptions>,
+  launched_at: HashMap<String, Instant>,
+  restarts: HashMap<String, VecDeque<u64>>,
+}
+
+lazy_static! {
+  static ref STATE: Mutex<SupervisorState> = Mutex::new(SupervisorState::default());
+}
+
+fn lock_state() -> std::sync::MutexGuard<'static, SupervisorState> {
+  STATE
+    .lock()
+    .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+fn restarts_after(mode: RestartMode, kind: ExitKind) -> bool {
+  match mode {
+    RestartMode::Never => false,
+    RestartMode::OnCrash => kind == ExitKind::Crashed,
+    RestartMode::Always => kind != ExitKind::Requested,
+  }
+}
+
+/// Called before an app-driven stop so the exit is not mistaken for a crash.
+pub fn note_stop_requested(profile_id: &str) {
+  lock_state().stop_requested.insert(profile_id.to_string());
+}
+
+/// The stop failed and the browser is still running.
+pub fn clear_stop_requested(profile_id: &str) {
+  lock_state().stop_requested.remove(profile_id);
+}
+
+/// Record a successful launch. Also forgets any stop request that never
+/// reached the status checker (e.g. the profile wasn't actually running).
+pub fn remember_launch(profile_id: &str, options: LaunchOptions) {
+  let mut state = lock_state();
+  state.stop_requested.remove(profile_id);
+  state.launch_options.insert(profile_id.to_string(), options);
+  state
+    .launched_at
+    .insert(profile_id.to_string(), Instant::now());
+}
+
+/// Wait for a spawned browser to exit and keep its exit status for the
+/// status checker. Replaces dropping the child handle after launch.
+pub fn watch_browser_exit(mut child: tokio::process::Child) {
+  let Some(pid) = child.id() else {
+    return;
+  };
+  tauri::async_runtime::spawn(async move {
+    match child.wait().await {
+      Ok(status) => record_exit(pid, observe_exit(status)),
+      Err(e) => log::debug!("Failed to wait for browser process {pid}: {e}"),
+    }
+  });
+}
+
+fn record_exit(pid: u32, exit: ObservedExit) {
+  let mut state = lock_state();
+  let now = exit.at;
+  state
+    .exits
+    .retain(|_, exit| now.duration_since(exit.at) < EXIT_RECORD_TTL);
+  state.exits.insert(pid, exit);
+}
+
+fn observe_exit(status: std::process::ExitStatus) -> ObservedExit {
+  #[cfg(unix)]
+  let signal = {
+    use std::os::unix::process::ExitStatusExt;
+    status.signal()
+  };
+  #[cfg(not(unix))]
+  let signal = None;
+  let code = status.code();
+  let reason = match (signal, code) {
+    (Some(signal), _) => format!("terminated by signal {signal}"),
+    (None, Some(code)) => format!("exited with code {code}"),
+    (None, None) => "exited".to_string(),
+  };
+  ObservedExit {
+    success: status.success(),
+    code,
+    signal,
+    reason,
+    at: Instant::now(),
+  }
+}
+
+/// Classify an exit the status checker just observed. Consumes the stop
+/// request and exit status recorded for it.
+fn classify_exit(profile_id: &str, pid: Option<u32>) -> (ExitKind, Option<ObservedExit>) {
+  let mut state = lock_state();
+  let exit = pid.and_then(|pid| state.exits.remove(&pid));
+  if state.stop_requested.remove(profile_id) {
+    return (ExitKind::Requested, exit);
+  }
+  match exit {
+    Some(exit) if exit.success => (ExitKind::Clean, Some(exit)),
+    exit => (ExitKind::Crashed, exit),
+  }
+}
+
+/// The history entry for a classified exit. A crash verdict without an
+/// observed status is reported as unknown rather than as a crash.
+fn history_entry(
+  kind: ExitKind,
+  exit: Option<&ObservedExit>,
+  launched_at: Option<Instant>,
+) -> ProfileExit {
+  let exit_kind = match (kind, exit) {
+    (ExitKind::Requested, _) => ProfileExitKind::Killed,
+    (ExitKind::Clean, _) => ProfileExitKind::UserClosed,
+    (ExitKind::Crashed, Some(_)) => ProfileExitKind::Crashed,
+    (ExitKind::Crashed, None) => ProfileExitKind::Unknown,
+  };
+  let now = Instant::now();
+  ProfileExit {
+    timestamp: crate::proxy_manager::now_secs(),
+    exit_kind,
+    exit_code: exit.and_then(|e| e.code),
+    signal: exit.and_then(|e| e.signal),
+    uptime_secs: launched_at.map(|at| now.duration_since(at).as_secs()),
+  }
+}
+
+/// Count a restart against the rolling hourly cap. Returns the number of
+/// restarts in the window including this one, or `None` when the cap is hit.
+fn try_count_restart(
+  history: &mut VecDeque<u64>,
+  max_per_hour: u32,
+  now_secs: u64,
+) -> Option<usize> {
+  while history
+    .front()
+    .is_some_and(|&at| now_secs.saturating_sub(at) >= RESTART_WINDOW_SECS)
+  {
+    history.pop_front();
+  }
+  if history.len() >= max_per_hour as usize {
+    return None;
+  }
+  history.push_back(now_secs);
+  Some(history.len())
+}
+
+/// Called by the status checker once the post-exit cleanup for a profile it
+/// had seen running is done. `pid` is the PID the profile had before exiting.
+pub fn handle_exit(
+  app_handle: crate::app_handle::AppHandle,
+  profile: &BrowserProfile,
+  pid: Option<u32>,
+) {
+  let profile_id = profile.id.to_string();
+  let (kind, exit) = classify_exit(&profile_id, pid);
+  let launched_at = lock_state().launched_at.remove(&profile_id);
+  exit_history::record(&profile_id, history_entry(kind, exit.as_ref(), launched_at));
+  if kind == ExitKind::Requested {
+    return;
+  }
+  let reason = exit.map(|e| e.reason);
+
+  let policy: RestartPolicy = profile.restart_policy;
+  let wants_restart = restarts_after(policy.mode, kind);
+  let restarts_this_hour = if wants_restart {
+    let mut state = lock_state();
+    let history = state.restarts.entry(profile_id.clone()).or_default();
+    try_count_restart(
+      history,
+      policy.max_restarts_per_hour,
+      crate::proxy_manager::now_secs(),
+    )
+  } else {
+    None
+  };
+  let restarting = restarts_this_hour.is_some();
+  let restart_limit_reached = wants_restart && !restarting;
+
+  match kind {
+    ExitKind::Crashed => log::warn!(
+      "Browser for profile {} exited unexpectedly ({})",
+      profile.name,
+      reason.as_deref().unwrap_or("exit status unknown")
+    ),
+    _ => log::info!(
+      "Browser for profile {} exited ({})",
+      profile.name,
+      reason.as_deref().unwrap_or("exit status unknown")
+    ),
+  }
+  if restart_limit_reached {
+    log::warn!(
+      "Not restarting profile {}: {} restarts in the last hour",
+      profile.name,
+      policy.max_restarts_per_hour
+    );
+  }
+
+  if kind == ExitKind::Crashed || restart_limit_reached {
+    let payload = ProfileCrashed {
+      id: profile_id.clone(),
+      name: profile.name.clone(),
+      reason: reason.clone(),
+      restarting,
+      restart_limit_reached,
+      max_restarts_per_hour: policy.max_restarts_per_hour,
+    };
+    if let Err(e) = events::emit(&payload) {
+      log::warn!("Failed to emit profile-crashed event: {e}");
+    }
+  }
+
+  let Some(restarts_this_hour) = restarts_this_hour else {
+    return;
+  };
+  let options = lock_state()
+    .launch_options
+    .get(&profile_id)
+    .copied()
+    .unwrap_or_default();
+  let profile_name = profile.name.clone();
+  tauri::async_runtime::spawn(async move {
+    tokio::time::sleep(RESTART_DELAY).await;
+    match relaunch(app_handle, &profile_id, options).await {
+      Ok(()) => {
+        log::info!("Restarted profile {profile_name} after it exited");
+        let payload = ProfileRestarted {
+          id: profile_id,
+          name: profile_name,
+          reason,
+          restarts_this_hour,
+        };
+        if let Err(e) = events::emit(&payload) {
+          log::warn!("Failed to emit profile-restarted event: {e}");
+        }
+      }
+      Err(e) => log::error!("Failed to restart profile {profile_name}: {e}"),
+    }
+  });
+}
+
+async fn relaunch(
+  app_handle: crate::app_handle::AppHandle,
+  profile_id: &str,
+  options: LaunchOptions,
+) -> Result<(), String> {
+  // Re-read the profile: it may have been deleted, or its policy turned off,
+  // in the grace period.
+  let profile = crate::profile::ProfileManager::instance()
+    .list_profiles()
+    .map_err(|e| format!("Failed to list profiles: {e}"))?
+    .into_iter()
+    .find(|p| p.id.to_string() == profile_id)
+    .ok_or_else(|| "profile no longer exists".to_string())?;
+  if profile.restart_policy.mode == RestartMode::Never {
+    return Err("restart policy was disabled".to_string());
+  }
+  if profile.process_id.is_some() {
+    return Err("profile is already running again".to_string());
+  }
+
+  let force_new = options.headless || options.remote_debugging_port.is_some();
+  crate::browser_runner::launch_browser_profile_impl(
+    app_handle,
+    profile,
+    None,
+    options.remote_debugging_port,
+    options.headless,
+    force_new,
+    crate::launch_queue::LaunchPriority::Background,
+  )
+  .await
+  .map(|_| ())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn restart_modes_follow_exit_kind() {
+    for kind in [ExitKind::Requested, ExitKind::Clean, ExitKind::Crashed] {
+      assert!(!restarts_after(RestartMode::Never, kind));
+    }
+    assert!(restarts_after(RestartMode::OnCrash, ExitKind::Crashed));
+    assert!(!restarts_after(RestartMode::OnCrash, ExitKind::Clean));
+    assert!(restarts_after(RestartMode::Always, ExitKind::Clean));
+    assert!(!restarts_after(RestartMode::Always, ExitKind::Requested));
+  }
+
+  #[test]
+  fn hourly_cap_breaks_crash_loops() {
+    let mut history = VecDeque::new();
+    assert_eq!(try_count_restart(&mut history, 2, 1_000), Some(1));
+    assert_eq!(try_count_restart(&mut history, 2, 1_100), Some(2));
+    assert_eq!(try_count_restart(&mut history, 2, 1_200), None);
+    // The first restart ages out of the window.
+    assert_eq!(try_count_restart(&mut history, 2, 4_600), Some(2));
+  }
+
+  fn exit(success: bool, code: Option<i32>, signal: Option<i32>, reason: &str) -> ObservedExit {
+    ObservedExit {
+      success,
+      code,
+      signal,
+      reason: reason.to_string(),
+      at: Instant::now(),
+    }
+  }
+
+  #[test]
+  fn requested_stops_are_not_crashes() {
+    let profile_id = "restart-supervisor-test-requested";
+    record_exit(
+      90_001,
+      exit(false, None, Some(15), "terminated by signal 15"),
+    );
+    note_stop_requested(profile_id);
+    let (kind, observed) = classify_exit(profile_id, Some(90_001));
+    assert_eq!(kind, ExitKind::Requested);
+    let entry = history_entry(kind, observed.as_ref(), None);
+    assert_eq!(entry.exit_kind, ProfileExitKind::Killed);
+    assert_eq!(entry.signal, Some(15));
+    // Consumed: the next exit is judged on its own.
+    assert_eq!(classify_exit(profile_id, None), (ExitKind::Crashed, None));
+  }
+
+  #[test]
+  fn exit_status_separates_clean_exits_from_crashes() {
+    let profile_id = "restart-supervisor-test-status";
+    record_exit(90_002, exit(true, Some(0), None, "exited with code 0"));
+    assert_eq!(classify_exit(profile_id, Some(90_002)).0, ExitKind::Clean);
+    record_exit(
+      90_003,
+      exit(false, None, Some(11), "terminated by signal 11"),
+    );
+    let (kind, observed) = classify_exit(profile_id, Some(90_003));
+    assert_eq!(kind, ExitKind::Crashed);
+    assert_eq!(
+      observed.as_ref().map(|e| e.reason.as_str()),
+      Some("terminated by signal 11")
+    );
+    assert_eq!(
+      history_entry(kind, observed.as_ref(), None).exit_kind,
+      ProfileExitKind::Crashed
+    );
+  }
+
+  #[test]
+  fn exits_without_a_status_are_unknown_in_history() {
+    let launched_at = Instant::now() - Duration::from_secs(90);
+    let entry = history_entry(ExitKind::Crashed, None, Some(launched_at));
+    assert_eq!(entry.exit_kind, ProfileExitKind::Unknown);
+    assert_eq!(entry.exit_code, None);
+    assert_eq!(entry.uptime_secs, Some(90));
+  }
+
+  #[test]
+  fn launching_forgets_stale_stop_requests() {
+    let profile_id = "restart-supervisor-test-launch";
+    note_stop_requested(profile_id);
+    remember_launch(profile_id, LaunchOptions::default());
+    assert_eq!(classify_exit(profile_id, None).0, ExitKind::Crashed);
+  }
+}
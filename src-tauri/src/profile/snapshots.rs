@@ -0,0 +1,583 @@
+//! Local point-in-time backups of a profile, taken before risky operations
+//! (browser updates, cookie imports) independently of sync.
+//!
+//! A snapshot is `backups/<profile id>/<snapshot id>.tar.gz`, holding the
+//! profile's `metadata.json` and its `profile/` data dir minus regenerable
+//! caches, next to a `<snapshot id>.json` manifest. Snapshots of
+//! encrypted-sync profiles are sealed with the profile's sync key instead
+//! (`.tar.gz.enc`). The periodic cleanup task keeps the newest
+//! `snapshot_retention` snapshots per profile.
+
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+
+use crate::profile::disk_usage::{is_cache_dir_name, CLEAR_SKIP_DIR_NAMES};
+use crate::profile::{BrowserProfile, ProfileManager};
+use crate::sync::encryption;
+
+const ARCHIVE_EXT: &str = "tar.gz";
+const SEALED_ARCHIVE_EXT: &str = "tar.gz.enc";
+
+/// Plaintext bytes per sealed record, so a large profile is encrypted as a
+/// stream instead of in memory.
+const SEAL_CHUNK_SIZE: usize = 1 << 20;
+
+/// Staging dir for a restore, inside the profile dir so the final swap is a
+/// rename on the same filesystem.
+const RESTORE_STAGING_DIR: &str = ".snapshot-restore";
+const PRE_RESTORE_DIR: &str = ".profile-pre-restore";
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ProfileSnapshot {
+  pub id: String,
+  pub profile_id: String,
+  #[serde(default)]
+  pub label: Option<String>,
+  /// Epoch seconds.
+  pub created_at: u64,
+  pub browser: String,
+  pub browser_version: String,
+  /// Size of the archive on disk.
+  pub size_bytes: u64,
+  /// Sealed with the profile's encrypted-sync key.
+  #[serde(default)]
+  pub encrypted: bool,
+}
+
+fn code(code: &str) -> String {
+  serde_json::json!({ "code": code }).to_string()
+}
+
+pub(crate) fn find_profile(profile_id: &str) -> Result<BrowserProfile, String> {
+  ProfileManager::instance()
+    .list_profiles()
+    .map_err(|e| format!("Failed to list profiles: {e}"))?
+    .into_iter()
+    .find(|p| p.id.to_string() == profile_id)
+    .ok_or_else(|| code("PROFILE_NOT_FOUND"))
+}
+
+pub(crate) fn ensure_stopped(profile: &BrowserProfile) -> Result<(), String> {
+  if profile
+    .process_id
+    .is_some_and(crate::proxy_storage::is_process_running)
+  {
+    return Err(code("PROFILE_RUNNING"));
+  }
+  Ok(())
+}
+
+pub(crate) fn snapshots_dir(profile_id: &str) -> PathBuf {
+  crate::app_dirs::backups_dir().join(profile_id)
+}
+
+fn manifest_path(dir: &Path, snapshot_id: &str) -> PathBuf {
+  dir.join(format!("{snapshot_id}.json"))
+}
+
+fn archive_path(dir: &Path, snapshot: &ProfileSnapshot) -> PathBuf {
+  let ext = if snapshot.encrypted {
+    SEALED_ARCHIVE_EXT
+  } else {
+    ARCHIVE_EXT
+  };
+  dir.join(format!("{}.{ext}", snapshot.id))
+}
+
+/// The key an encrypted-sync profile's files are sealed with.
+fn sync_key(profile: &BrowserProfile) -> Result<[u8; 32], String> {
+  let password = encryption::load_e2e_password()?.ok_or_else(|| code("NO_E2E_PASSWORD_SET"))?;
+  let salt = profile
+    .encryption_salt
+    .as_deref()
+    .ok_or("Encryption salt missing on encrypted profile")?;
+  encryption::derive_profile_key(&password, salt)
+}
+
+/// Encrypts everything written to it as a sequence of
+/// `[u32 BE length][nonce || ciphertext]` records of at most
+/// `SEAL_CHUNK_SIZE` plaintext bytes each.
+struct SealingWriter<W: Write> {
+  inner: W,
+  key: [u8; 32],
+  buf: Vec<u8>,
+}
+
+impl<W: Write> SealingWriter<W> {
+  fn new(inner: W, key: [u8; 32]) -> Self {
+    Self {
+      inner,
+      key,
+      buf: Vec::with_capacity(SEAL_CHUNK_SIZE),
+    }
+  }
+
+  fn seal(&mut self, len: usize) -> io::Result<()> {
+    let chunk: Vec<u8> = self.buf.drain(..len).collect();
+    let sealed = encryption::encrypt_bytes(&self.key, &chunk).map_err(io::Error::other)?;
+    self.inner.write_all(&(sealed.len() as u32).to_be_bytes())?;
+    self.inner.write_all(&sealed)
+  }
+
+  fn finish(mut self) -> io::Result<W> {
+    if !self.buf.is_empty() {
+      self.seal(self.buf.len())?;
+    }
+    self.inner.flush()?;
+    Ok(self.inner)
+  }
+}
+
+impl<W: Write> Write for SealingWriter<W> {
+  fn write(&mut self, data: &[u8]) -> io::Result<usize> {
+    self.buf.extend_from_slice(data);
+    while self.buf.len() >= SEAL_CHUNK_SIZE {
+      self.seal(SEAL_CHUNK_SIZE)?;
+    }
+    Ok(data.len())
+  }
+
+  fn flush(&mut self) -> io::Result<()> {
+    self.inner.flush()
+  }
+}
+
+/// Reads back what [`SealingWriter`] wrote.
+struct OpeningReader<R: Read> {
+  inner: R,
+  key: [u8; 32],
+  plain: Vec<u8>,
+  pos: usize,
+}
+
+impl<R: Read> OpeningReader<R> {
+  fn new(inner: R, key: [u8; 32]) -> Self {
+    Self {
+      inner,
+      key,
+      plain: Vec::new(),
+      pos: 0,
+    }
+  }
+}
+
+impl<R: Read> Read for OpeningReader<R> {
+  fn read(&mut self, out: &mut [u8]) -> io::Result<usize> {
+    if self.pos == self.plain.len() {
+      let mut len = [0u8; 4];
+      match self.inner.read_exact(&mut len) {
+        Ok(()) => {}
+        // A truncated archive surfaces as a gzip error further up.
+        Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => return Ok(0),
+        Err(e) => return Err(e),
+      }
+      let mut sealed = vec![0u8; u32::from_be_bytes(len) as usize];
+      self.inner.read_exact(&mut sealed)?;
+      self.plain = encryption::decrypt_bytes(&self.key, &sealed).map_err(io::Error::other)?;
+      self.pos = 0;
+    }
+    let n = out.len().min(self.plain.len() - self.pos);
+    out[..n].copy_from_slice(&self.plain[self.pos..self.pos + n]);
+    self.pos += n;
+    Ok(n)
+  }
+}
+
+/// Adds `dir` to the archive as `name`, skipping cache dirs the same way
+/// cache cleanup does. Symlinks are stored as links, never followed.
+pub(crate) fn append_dir<W: Write>(
+  tar: &mut tar::Builder<W>,
+  dir: &Path,
+  name: &Path,
+  in_skipped: bool,
+) -> io::Result<()> {
+  tar.append_dir(name, dir)?;
+  for entry in fs::read_dir(dir)? {
+    let entry = entry?;
+    let file_name = entry.file_name();
+    let child = name.join(&file_name);
+    if entry.file_type()?.is_dir() {
+      let dir_name = file_name.to_str().unwrap_or_default();
+      let skipped = in_skipped || CLEAR_SKIP_DIR_NAMES.contains(&dir_name);
+      if !skipped && is_cache_dir_name(dir_name) {
+        continue;
+      }
+      append_dir(tar, &entry.path(), &child, skipped)?;
+    } else {
+      tar.append_path_with_name(entry.path(), &child)?;
+    }
+  }
+  Ok(())
+}
+
+/// Writes the gzipped tar of a profile dir (`metadata.json` + `profile/`).
+fn write_archive<W: Write>(profile_dir: &Path, out: W) -> io::Result<W> {
+  let mut tar = tar::Builder::new(GzEncoder::new(out, flate2::Compression::default()));
+  tar.follow_symlinks(false);
+  tar.append_path_with_name(profile_dir.join("metadata.json"), "metadata.json")?;
+  let data_dir = profile_dir.join("profile");
+  if data_dir.is_dir() {
+    append_dir(&mut tar, &data_dir, Path::new("profile"), false)?;
+  }
+  tar.into_inner()?.finish()
+}
+
+fn read_manifest(path: &Path) -> Option<ProfileSnapshot> {
+  let json = fs::read_to_string(path).ok()?;
+  serde_json::from_str(&json).ok()
+}
+
+/// Snapshots in `dir`, newest first.
+fn list_in(dir: &Path) -> Vec<ProfileSnapshot> {
+  let Ok(entries) = fs::read_dir(dir) else {
+    return Vec::new();
+  };
+  let mut snapshots: Vec<ProfileSnapshot> = entries
+    .flatten()
+    .map(|e| e.path())
+    .filter(|p| p.extension().is_some_and(|ext| ext == "json"))
+    .filter_map(|p| read_manifest(&p))
+    .collect();
+  snapshots.sort_by(|a, b| b.created_at.cmp(&a.created_at).then(b.id.cmp(&a.id)));
+  snapshots
+}
+
+fn create_snapshot_blocking(
+  profile: &BrowserProfile,
+  label: Option<String>,
+  key: Option<[u8; 32]>,
+) -> Result<ProfileSnapshot, String> {
+  let profile_dir = ProfileManager::instance()
+    .get_profiles_dir()
+    .join(profile.id.to_string());
+  let dir = snapshots_dir(&profile.id.to_string());
+  fs::create_dir_all(&dir).map_err(|e| format!("Failed to create backups dir: {e}"))?;
+
+  let mut snapshot = ProfileSnapshot {
+    id: uuid::Uuid::new_v4().to_string(),
+    profile_id: profile.id.to_string(),
+    label,
+    created_at: crate::proxy_manager::now_secs(),
+    browser: profile.browser.clone(),
+    browser_version: profile.version.clone(),
+    size_bytes: 0,
+    encrypted: key.is_some(),
+  };
+  let final_path = archive_path(&dir, &snapshot);
+  let partial_path = final_path.with_extension("part");
+
+  let written = (|| -> io::Result<()> {
+    let file = io::BufWriter::new(fs::File::create(&partial_path)?);
+    let mut file = match key {
+      Some(key) => write_archive(&profile_dir, SealingWriter::new(file, key))?.finish()?,
+      None => write_archive(&profile_dir, file)?,
+    };
+    file.flush()?;
+    fs::rename(&partial_path, &final_path)
+  })();
+  if let Err(e) = written {
+    let _ = fs::remove_file(&partial_path);
+    return Err(format!("Failed to write snapshot: {e}"));
+  }
+
+  snapshot.size_bytes = fs::metadata(&final_path).map(|m| m.len()).unwrap_or(0);
+  let manifest = serde_json::to_vec_pretty(&snapshot).map_err(|e| e.to_string())?;
+  if let Err(e) = fs::write(manifest_path(&dir, &snapshot.id), manifest) {
+    let _ = fs::remove_file(&final_path);
+    return Err(format!("Failed to write snapshot manifest: {e}"));
+  }
+  Ok(snapshot)
+}
+
+/// Unpacks `snapshot` over the profile's data dir and returns the profile
+/// metadata it was taken with.
+fn restore_snapshot_blocking(
+  profile_dir: &Path,
+  archive: &Path,
+  key: Option<[u8; 32]>,
+) -> Result<BrowserProfile, String> {
+  let staging = profile_dir.join(RESTORE_STAGING_DIR);
+  let previous = profile_dir.join(PRE_RESTORE_DIR);
+  let _ = fs::remove_dir_all(&staging);
+  let _ = fs::remove_dir_all(&previous);
+
+  let file = io::BufReader::new(
+    fs::File::open(archive).map_err(|e| format!("Failed to open snapshot: {e}"))?,
+  );
+  let reader: Box<dyn Read> = match key {
+    Some(key) => Box::new(OpeningReader::new(file, key)),
+    None => Box::new(file),
+  };
+  if let Err(e) = tar::Archive::new(GzDecoder::new(reader)).unpack(&staging) {
+    let _ = fs::remove_dir_all(&staging);
+    return Err(format!("Failed to unpack snapshot: {e}"));
+  }
+
+  let snapshot_profile: BrowserProfile = fs::read_to_string(staging.join("metadata.json"))
+    .ok()
+    .and_then(|json| serde_json::from_str(&json).ok())
+    .ok_or_else(|| {
+      let _ = fs::remove_dir_all(&staging);
+      "Snapshot is missing its profile metadata".to_string()
+    })?;
+
+  let data_dir = profile_dir.join("profile");
+  let restored = staging.join("profile");
+  if data_dir.exists() {
+    fs::rename(&data_dir, &previous)
+      .map_err(|e| format!("Failed to move current profile data aside: {e}"))?;
+  }
+  let swapped = if restored.exists() {
+    fs::rename(&restored, &data_dir)
+  } else {
+    fs::create_dir_all(&data_dir)
+  };
+  if let Err(e) = swapped {
+    if previous.exists() {
+      let _ = fs::rename(&previous, &data_dir);
+    }
+    let _ = fs::remove_dir_all(&staging);
+    return Err(format!("Failed to restore profile data: {e}"));
+  }
+
+  let _ = fs::remove_dir_all(&previous);
+  let _ = fs::remove_dir_all(&staging);
+  Ok(snapshot_profile)
+}
+
+/// Deletes all but the newest `keep` snapshots, and refresh backups, of every
+/// profile. Returns how many were removed.
+pub fn prune_snapshots(keep: usize) -> usize {
+  let Ok(entries) = fs::read_dir(crate::app_dirs::backups_dir()) else {
+    return 0;
+  };
+  let mut removed = 0;
+  for dir in entries.flatten().map(|e| e.path()).filter(|p| p.is_dir()) {
+    for snapshot in list_in(&dir).into_iter().skip(keep) {
+      let _ = fs::remove_file(archive_path(&dir, &snapshot));
+      if fs::remove_file(manifest_path(&dir, &snapshot.id)).is_ok() {
+        removed += 1;
+      }
+    }
+    removed += crate::profile::refresh::prune_backups_in(&dir, keep);
+  }
+  removed
+}
+
+#[tauri::command]
+pub async fn create_profile_snapshot(
+  profile_id: String,
+  label: Option<String>,
+) -> Result<ProfileSnapshot, String> {
+  let profile = find_profile(&profile_id)?;
+  if profile.ephemeral {
+    return Err(code("PROFILE_EPHEMERAL"));
+  }
+  // A copy of a live profile can catch its SQLite databases mid-write.
+  ensure_stopped(&profile)?;
+  let key = if profile.is_encrypted_sync() {
+    Some(sync_key(&profile)?)
+  } else {
+    None
+  };
+  let label = label
+    .map(|l| l.trim().to_string())
+    .filter(|l| !l.is_empty());
+
+  let snapshot =
+    tokio::task::spawn_blocking(move || create_snapshot_blocking(&profile, label, key))
+      .await
+      .map_err(|e| format!("Snapshot task failed: {e}"))??;
+  log::info!(
+    "Created snapshot {} of profile {profile_id} ({} bytes)",
+    snapshot.id,
+    snapshot.size_bytes
+  );
+  Ok(snapshot)
+}
+
+#[tauri::command]
+pub fn list_profile_snapshots(profile_id: String) -> Result<Vec<ProfileSnapshot>, String> {
+  let profile = find_profile(&profile_id)?;
+  Ok(list_in(&snapshots_dir(&profile.id.to_string())))
+}
+
+/// Replaces a stopped profile's browser data with a snapshot and restores the
+/// fingerprint configuration it was taken with.
+#[tauri::command]
+pub async fn restore_profile_snapshot(
+  profile_id: String,
+  snapshot_id: String,
+) -> Result<(), String> {
+  let mut profile = find_profile(&profile_id)?;
+  ensure_stopped(&profile)?;
+
+  // The ID becomes a file name, so anything but a UUID is never ours.
+  if uuid::Uuid::parse_str(&snapshot_id).is_err() {
+    return Err(code("SNAPSHOT_NOT_FOUND"));
+  }
+  let dir = snapshots_dir(&profile_id);
+  let snapshot = read_manifest(&manifest_path(&dir, &snapshot_id))
+    .filter(|s| s.profile_id == profile_id)
+    .ok_or_else(|| code("SNAPSHOT_NOT_FOUND"))?;
+  let key = if snapshot.encrypted {
+    Some(sync_key(&profile)?)
+  } else {
+    None
+  };
+
+  let profile_dir = ProfileManager::instance()
+    .get_profiles_dir()
+    .join(&profile_id);
+  let archive = archive_path(&dir, &snapshot);
+  let snapshot_profile =
+    tokio::task::spawn_blocking(move || restore_snapshot_blocking(&profile_dir, &archive, key))
+      .await
+      .map_err(|e| format!("Restore task failed: {e}"))??;
+
+  profile.wayfern_config = snapshot_profile.wayfern_config;
+  profile.updated_at = Some(crate::proxy_manager::now_secs());
+  ProfileManager::instance()
+    .save_profile(&profile)
+    .map_err(|e| format!("Failed to save profile: {e}"))?;
+  crate::profile::disk_usage::invalidate_usage(&profile_id);
+  crate::sync::queue_profile_sync_if_eligible(&profile);
+  if let Err(e) = crate::events::emit_empty("profiles-changed") {
+    log::warn!("Warning: Failed to emit profiles-changed event: {e}");
+  }
+  log::info!("Restored profile {profile_id} from snapshot {snapshot_id}");
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn write(path: &Path, contents: &[u8]) {
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, contents).unwrap();
+  }
+
+  fn profile_dir_fixture(root: &Path) -> PathBuf {
+    let dir = root.join("profile-dir");
+    write(&dir.join("metadata.json"), b"{}");
+    write(&dir.join("profile/Default/Cookies"), b"cookies");
+    write(&dir.join("profile/Default/Cache/data_0"), b"cache");
+    write(&dir.join("profile/Default/Extensions/x/Cache/keep"), b"ext");
+    dir
+  }
+
+  fn entries(archive: &[u8], key: Option<[u8; 32]>) -> Vec<String> {
+    let reader: Box<dyn Read + '_> = match key {
+      Some(key) => Box::new(OpeningReader::new(archive, key)),
+      None => Box::new(archive),
+    };
+    let mut tar = tar::Archive::new(GzDecoder::new(reader));
+    let mut names: Vec<String> = tar
+      .entries()
+      .unwrap()
+      .map(|e| e.unwrap().path().unwrap().to_string_lossy().into_owned())
+      .map(|p| p.trim_end_matches('/').to_string())
+      .collect();
+    names.sort();
+    names
+  }
+
+  #[test]
+  fn archive_skips_caches_but_not_extension_contents() {
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = profile_dir_fixture(tmp.path());
+    let archive = write_archive(&dir, Vec::new()).unwrap();
+    let names = entries(&archive, None);
+    assert!(names.contains(&"metadata.json".to_string()));
+    assert!(names.contains(&"profile/Default/Cookies".to_string()));
+    assert!(names.contains(&"profile/Default/Extensions/x/Cache/keep".to_string()));
+    assert!(!names.iter().any(|n| n.starts_with("profile/Default/Cache")));
+  }
+
+  #[test]
+  fn sealed_archive_round_trips_across_chunks() {
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = profile_dir_fixture(tmp.path());
+    // Incompressible data larger than one chunk.
+    let big: Vec<u8> = (0..(SEAL_CHUNK_SIZE * 2 + 17) as u32)
+      .map(|i| (i.wrapping_mul(2_654_435_761) >> 13) as u8)
+      .collect();
+    write(&dir.join("profile/Default/big.bin"), &big);
+    let key = [7u8; 32];
+
+    let sealed = write_archive(&dir, SealingWriter::new(Vec::new(), key))
+      .unwrap()
+      .finish()
+      .unwrap();
+    assert!(entries(&sealed, Some(key)).contains(&"profile/Default/big.bin".to_string()));
+
+    let mut wrong = OpeningReader::new(sealed.as_slice(), [8u8; 32]);
+    assert!(wrong.read(&mut [0u8; 16]).is_err());
+  }
+
+  #[test]
+  fn restore_replaces_data_dir_and_returns_snapshot_metadata() {
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = tmp.path().join("profile-dir");
+    let snapshot_profile = BrowserProfile {
+      name: "Snap".to_string(),
+      ..Default::default()
+    };
+    write(
+      &dir.join("metadata.json"),
+      serde_json::to_string(&snapshot_profile).unwrap().as_bytes(),
+    );
+    write(&dir.join("profile/Default/Cookies"), b"before");
+    let archive_file = tmp.path().join("snap.tar.gz");
+    fs::write(&archive_file, write_archive(&dir, Vec::new()).unwrap()).unwrap();
+
+    write(&dir.join("profile/Default/Cookies"), b"after");
+    write(&dir.join("profile/Default/New"), b"new");
+    let restored = restore_snapshot_blocking(&dir, &archive_file, None).unwrap();
+
+    assert_eq!(restored.name, "Snap");
+    assert_eq!(
+      fs::read(dir.join("profile/Default/Cookies")).unwrap(),
+      b"before"
+    );
+    assert!(!dir.join("profile/Default/New").exists());
+    assert!(!dir.join(RESTORE_STAGING_DIR).exists());
+    assert!(!dir.join(PRE_RESTORE_DIR).exists());
+  }
+
+  #[test]
+  fn prune_keeps_newest_per_profile() {
+    let tmp = tempfile::tempdir().unwrap();
+    let _guard = crate::app_dirs::set_test_data_dir(tmp.path().to_path_buf());
+    let dir = snapshots_dir("p1");
+    fs::create_dir_all(&dir).unwrap();
+    for (id, created_at) in [("a", 1), ("b", 3), ("c", 2)] {
+      let snapshot = ProfileSnapshot {
+        id: id.to_string(),
+        profile_id: "p1".to_string(),
+        label: None,
+        created_at,
+        browser: "wayfern".to_string(),
+        browser_version: "1".to_string(),
+        size_bytes: 0,
+        encrypted: false,
+      };
+      fs::write(archive_path(&dir, &snapshot), b"").unwrap();
+      fs::write(
+        manifest_path(&dir, id),
+        serde_json::to_vec(&snapshot).unwrap(),
+      )
+      .unwrap();
+    }
+
+    assert_eq!(prune_snapshots(2), 1);
+    let left: Vec<String> = list_in(&dir).into_iter().map(|s| s.id).collect();
+    assert_eq!(left, vec!["b", "c"]);
+    assert!(!dir.join("a.tar.gz").exists());
+  }
+}
@@ -0,0 +1,262 @@
+//! Bookkeeping for the background browser status checker: an in-memory
+//! profile index that is only re-read from disk when profiles change, which
+//! profiles need a process check on a tick, and how soon the next tick is.
+
+use crate::events::DaemonEvent;
+use crate::profile::BrowserProfile;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+use tokio::sync::broadcast::{self, error::TryRecvError};
+
+/// Tick interval while a browser is running or a launch is in flight.
+pub const FAST_INTERVAL: Duration = Duration::from_millis(500);
+pub const IDLE_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Re-read from disk at least this often, for writers that don't emit an event.
+const MAX_INDEX_AGE: Duration = Duration::from_secs(60);
+
+/// Events after which the profiles on disk may differ from the index.
+const INVALIDATING_EVENTS: &[&str] = &[
+  "profiles-changed",
+  "profile-updated",
+  "profile-running-changed",
+];
+
+static LAUNCHES_IN_FLIGHT: AtomicUsize = AtomicUsize::new(0);
+
+/// Held for the duration of a launch so the checker polls at the fast rate
+/// until the new browser shows up.
+pub struct LaunchGuard(());
+
+impl Drop for LaunchGuard {
+  fn drop(&mut self) {
+    LAUNCHES_IN_FLIGHT.fetch_sub(1, Ordering::SeqCst);
+  }
+}
+
+pub fn track_launch() -> LaunchGuard {
+  LAUNCHES_IN_FLIGHT.fetch_add(1, Ordering::SeqCst);
+  LaunchGuard(())
+}
+
+pub fn launch_in_flight() -> bool {
+  LAUNCHES_IN_FLIGHT.load(Ordering::SeqCst) > 0
+}
+
+/// The profile list as of the last change event, so ticks don't re-read every
+/// metadata file.
+pub struct ProfileIndex {
+  profiles: Vec<BrowserProfile>,
+  loaded_at: Option<Instant>,
+  events: broadcast::Receiver<DaemonEvent>,
+}
+
+impl Default for ProfileIndex {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl ProfileIndex {
+  pub fn new() -> Self {
+    Self {
+      profiles: Vec::new(),
+      loaded_at: None,
+      events: crate::events::subscribe_local(),
+    }
+  }
+
+  fn drain_events(&mut self) {
+    loop {
+      match self.events.try_recv() {
+        Ok(event) if INVALIDATING_EVENTS.contains(&event.event.as_str()) => self.loaded_at = None,
+        Ok(_) => {}
+        // Missed events may have included a change.
+        Err(TryRecvError::Lagged(_)) => self.loaded_at = None,
+        Err(TryRecvError::Empty | TryRecvError::Closed) => break,
+      }
+    }
+  }
+
+  /// The indexed profiles, calling `load` only when a profile event arrived
+  /// since the last load or the index has aged out.
+  pub fn profiles(
+    &mut self,
+    now: Instant,
+    load: impl FnOnce() -> Result<Vec<BrowserProfile>, String>,
+  ) -> Result<&[BrowserProfile], String> {
+    self.drain_events();
+    let fresh = self
+      .loaded_at
+      .is_some_and(|at| now.duration_since(at) < MAX_INDEX_AGE);
+    if !fresh {
+      self.profiles = load()?;
+      self.loaded_at = Some(now);
+    }
+    Ok(&self.profiles)
+  }
+}
+
+/// What one tick has to do.
+pub struct TickPlan<'a> {
+  /// Profiles that have a stored PID or were last seen running; idle and
+  /// archived profiles are never scanned for.
+  pub to_check: Vec<&'a BrowserProfile>,
+  pub next_interval: Duration,
+}
+
+pub fn plan_tick<'a>(
+  profiles: &'a [BrowserProfile],
+  last_running_states: &HashMap<String, bool>,
+  launching: bool,
+) -> TickPlan<'a> {
+  let to_check: Vec<&BrowserProfile> = profiles
+    .iter()
+    .filter(|p| !p.archived)
+    .filter(|p| {
+      p.process_id.is_some()
+        || last_running_states
+          .get(&p.id.to_string())
+          .copied()
+          .unwrap_or(false)
+    })
+    .collect();
+  let next_interval = if launching || !to_check.is_empty() {
+    FAST_INTERVAL
+  } else {
+    IDLE_INTERVAL
+  };
+  TickPlan {
+    to_check,
+    next_interval,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::process_registry::{BrowserProcess, ProcessSnapshot};
+
+  fn synthetic_profiles(count: usize, running: usize) -> Vec<BrowserProfile> {
+    (0..count)
+      .map(|i| BrowserProfile {
+        id: uuid::Uuid::new_v4(),
+        name: format!("profile-{i}"),
+        browser: "wayfern".to_string(),
+        process_id: (i < running).then_some(10_000 + i as u32),
+        ..Default::default()
+      })
+      .collect()
+  }
+
+  #[test]
+  fn test_plan_tick_checks_only_live_profiles() {
+    let profiles = synthetic_profiles(4, 1);
+    let mut last = HashMap::new();
+    last.insert(profiles[2].id.to_string(), true);
+    last.insert(profiles[3].id.to_string(), false);
+
+    let plan = plan_tick(&profiles, &last, false);
+    let names: Vec<&str> = plan.to_check.iter().map(|p| p.name.as_str()).collect();
+    assert_eq!(names, vec!["profile-0", "profile-2"]);
+    assert_eq!(plan.next_interval, FAST_INTERVAL);
+
+    let mut archived = synthetic_profiles(1, 0);
+    archived[0].archived = true;
+    last.insert(archived[0].id.to_string(), true);
+    assert!(plan_tick(&archived, &last, false).to_check.is_empty());
+
+    let idle = synthetic_profiles(3, 0);
+    assert_eq!(
+      plan_tick(&idle, &HashMap::new(), false).next_interval,
+      IDLE_INTERVAL
+    );
+    assert_eq!(
+      plan_tick(&idle, &HashMap::new(), true).next_interval,
+      FAST_INTERVAL
+    );
+  }
+
+  #[test]
+  fn test_launch_guard_tracks_in_flight_launches() {
+    let guard = track_launch();
+    assert!(launch_in_flight());
+    drop(guard);
+  }
+
+  #[test]
+  fn test_index_reloads_only_after_profile_events() {
+    let mut index = ProfileIndex::new();
+    let now = Instant::now();
+    let mut loads = 0;
+    for _ in 0..3 {
+      index
+        .profiles(now, || {
+          loads += 1;
+          Ok(synthetic_profiles(2, 0))
+        })
+        .unwrap();
+    }
+    // Other tests emit profile events concurrently, so only bound the count.
+    assert!(loads >= 1);
+
+    crate::events::emit_empty("profiles-changed").unwrap();
+    let before = loads;
+    index
+      .profiles(now, || {
+        loads += 1;
+        Ok(Vec::new())
+      })
+      .unwrap();
+    assert_eq!(loads, before + 1);
+
+    index
+      .profiles(now + MAX_INDEX_AGE, || {
+        loads += 1;
+        Ok(Vec::new())
+      })
+      .unwrap();
+    assert_eq!(loads, before + 2);
+  }
+
+  /// Benchmark-style: a tick over 500 profiles, 25 of them running, must stay
+  /// far below the fast interval and must not touch the loader once indexed.
+  #[test]
+  fn test_tick_cost_with_500_profiles() {
+    let dir = tempfile::tempdir().unwrap();
+    let profiles = synthetic_profiles(500, 25);
+    let mut snapshot = ProcessSnapshot::default();
+    for (i, profile) in profiles.iter().take(25).enumerate() {
+      snapshot.insert_browser(
+        &dir.path().join(profile.id.to_string()),
+        BrowserProcess {
+          pid: 10_000 + i as u32,
+          cdp_port: None,
+        },
+      );
+    }
+
+    let mut index = ProfileIndex::new();
+    let mut last = HashMap::new();
+    let now = Instant::now();
+    const TICKS: u32 = 200;
+    let started = Instant::now();
+    for _ in 0..TICKS {
+      let indexed = index.profiles(now, || Ok(profiles.clone())).unwrap();
+      let plan = plan_tick(indexed, &last, false);
+      for profile in &plan.to_check {
+        let running = snapshot
+          .browser_for(&dir.path().join(profile.id.to_string()))
+          .is_some();
+        last.insert(profile.id.to_string(), running);
+      }
+      assert_eq!(plan.to_check.len(), 25);
+    }
+    let per_tick = started.elapsed() / TICKS;
+    assert!(
+      per_tick < Duration::from_millis(25),
+      "status tick took {per_tick:?} with 500 profiles"
+    );
+  }
+}
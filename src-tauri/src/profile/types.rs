@@ -1,5 +1,6 @@
 use crate::wayfern_manager::WayfernConfig;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
@@ -20,6 +21,140 @@ pub enum SyncMode {
   Encrypted,
 }
 
+/// What the browser shows when a profile starts without an explicit URL.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum SessionRestore {
+  /// The browser's own start page.
+  #[default]
+  Homepage,
+  /// Reopen the tabs from the previous session.
+  Restore,
+  /// Open `startup_urls`.
+  Urls,
+}
+
+/// Whether the status checker relaunches a profile whose browser went away
+/// without `kill_browser_profile` being called.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum RestartMode {
+  #[default]
+  Never,
+  /// Only when the browser died abnormally (non-zero exit, signal, or an exit
+  /// status we could not observe).
+  OnCrash,
+  /// Also when the browser exited cleanly, e.g. its last window was closed.
+  Always,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub struct RestartPolicy {
+  #[serde(default)]
+  pub mode: RestartMode,
+  /// Restarts allowed within any rolling hour before the supervisor gives up.
+  #[serde(default = "default_max_restarts_per_hour")]
+  pub max_restarts_per_hour: u32,
+}
+
+impl Default for RestartPolicy {
+  fn default() -> Self {
+    Self {
+      mode: RestartMode::Never,
+      max_restarts_per_hour: default_max_restarts_per_hour(),
+    }
+  }
+}
+
+/// Where the browser resolves hostnames.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Default)]
+#[serde(tag = "mode", rename_all = "snake_case")]
+pub enum DnsMode {
+  /// The browser's default: the OS resolver when no proxy is assigned.
+  #[default]
+  System,
+  /// Never resolve locally; hostnames go to the proxy unresolved. Has no
+  /// effect on a profile without a proxy.
+  ProxyRemote,
+  /// Resolve over DNS-over-HTTPS against `resolver_url`, with no fallback to
+  /// the OS resolver.
+  Doh { resolver_url: String },
+}
+
+/// What a launch does when the proxy or VPN exits somewhere other than its
+/// `expected_country` / `expected_ip_prefix`.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ExitMismatchAction {
+  /// Launch anyway and emit `exit-mismatch`.
+  #[default]
+  Warn,
+  /// Refuse to launch with `EXIT_LOCATION_MISMATCH`.
+  Block,
+}
+
+/// OS scheduling priority of a profile's browser and every process it spawns.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default, utoipa::ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ProcessPriority {
+  Low,
+  #[default]
+  Normal,
+  High,
+}
+
+/// Limits applied to a launched browser. Unset fields fall back to the
+/// app-wide `default_resource_limits` setting.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ResourceLimits {
+  #[serde(default)]
+  pub priority: Option<ProcessPriority>,
+  /// Cap on the memory of the browser and its child processes together.
+  /// Enforced with a cgroup on Linux and a Job Object on Windows; not
+  /// supported on macOS.
+  #[serde(default)]
+  pub memory_limit_mb: Option<u64>,
+}
+
+impl ResourceLimits {
+  /// Profile values, falling back to `defaults` field by field.
+  pub fn or(self, defaults: ResourceLimits) -> ResourceLimits {
+    ResourceLimits {
+      priority: self.priority.or(defaults.priority),
+      memory_limit_mb: self.memory_limit_mb.or(defaults.memory_limit_mb),
+    }
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.priority.is_none() && self.memory_limit_mb.is_none()
+  }
+}
+
+/// Smallest accepted `memory_limit_mb`; below this Chromium can't start.
+pub const MIN_MEMORY_LIMIT_MB: u64 = 256;
+
+/// What a launch actually enforced, recorded on the running profile.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Default, utoipa::ToSchema)]
+pub struct AppliedResourceLimits {
+  pub priority: Option<ProcessPriority>,
+  pub priority_applied: bool,
+  pub memory_limit_mb: Option<u64>,
+  pub memory_limit_applied: bool,
+  /// `systemd_scope`, `cgroupfs` or `job_object`; `None` when no memory
+  /// limit was enforced.
+  pub mechanism: Option<String>,
+  /// Why a requested limit could not be applied.
+  #[serde(default)]
+  pub errors: Vec<String>,
+}
+
+/// Upper bound accepted for `max_restarts_per_hour`.
+pub const MAX_RESTARTS_PER_HOUR: u32 = 60;
+
+fn default_max_restarts_per_hour() -> u32 {
+  3
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
 pub struct BrowserProfile {
   pub id: uuid::Uuid,
@@ -30,10 +165,17 @@ pub struct BrowserProfile {
   pub proxy_id: Option<String>, // Reference to stored proxy
   #[serde(default)]
   pub vpn_id: Option<String>, // Reference to stored VPN config
+  /// Stored proxy pool to pick an exit from at each launch. Mutually
+  /// exclusive with `proxy_id` and `vpn_id`.
+  #[serde(default)]
+  pub proxy_pool_id: Option<String>,
   #[serde(default)]
   pub launch_hook: Option<String>,
   #[serde(default)]
   pub process_id: Option<u32>,
+  /// Remote debugging port of the running instance; cleared with `process_id`.
+  #[serde(default)]
+  pub cdp_port: Option<u16>,
   #[serde(default)]
   pub last_launch: Option<u64>,
   #[serde(default = "default_release_type")]
@@ -76,6 +218,40 @@ pub struct BrowserProfile {
   /// exits. Ignored for ephemeral and password-protected profiles.
   #[serde(default)]
   pub clear_on_close: bool,
+  /// Tabs opened on launch when `session_restore` is `Urls`.
+  #[serde(default)]
+  pub startup_urls: Vec<String>,
+  #[serde(default)]
+  pub session_restore: SessionRestore,
+  /// Browser preferences pinned for this profile, keyed by Chromium
+  /// preference path. Written into `Preferences` on every launch.
+  #[serde(default)]
+  pub preference_overrides: HashMap<String, serde_json::Value>,
+  /// Free-form key/value fields set by scripts or the user; see
+  /// [`crate::profile::metadata`] for the key rules and size caps.
+  #[serde(default)]
+  pub metadata: HashMap<String, String>,
+  /// Keep this profile on its current browser version: auto-updates skip it
+  /// and its binary is never garbage-collected while it's pinned.
+  #[serde(default)]
+  pub pin_browser_version: bool,
+  #[serde(default)]
+  pub restart_policy: RestartPolicy,
+  #[serde(default)]
+  pub dns_mode: DnsMode,
+  #[serde(default)]
+  pub exit_mismatch_action: ExitMismatchAction,
+  #[serde(default)]
+  pub resource_limits: ResourceLimits,
+  /// Limits the running instance was launched with; cleared with
+  /// `process_id`.
+  #[serde(default)]
+  pub applied_resource_limits: Option<AppliedResourceLimits>,
+  /// Kept out of the profile list, the status checker and file sync until
+  /// unarchived. The data dir may be packed into `profile.tar.gz`; see
+  /// [`crate::profile::archive`].
+  #[serde(default)]
+  pub archived: bool,
   /// Profile creation timestamp (epoch seconds, UTC). `None` for legacy
   /// profiles that pre-date this field — those are treated as ancient by
   /// any staleness check.
@@ -89,6 +265,21 @@ pub struct BrowserProfile {
   pub updated_at: Option<u64>,
 }
 
+/// A profile a bulk delete left in place, and why.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ProfileDeletionFailure {
+  pub profile_id: String,
+  /// `None` when the ID didn't match any profile.
+  pub profile_name: Option<String>,
+  pub error: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct BulkDeleteResult {
+  pub deleted: Vec<String>,
+  pub failed: Vec<ProfileDeletionFailure>,
+}
+
 pub fn default_release_type() -> String {
   "stable".to_string()
 }
@@ -128,6 +319,17 @@ impl BrowserProfile {
     }
   }
 
+  /// URLs to open on launch, in tab order. An explicit URL (from
+  /// `launch_browser_profile` or `open_url_with_profile`) replaces the stored
+  /// list; session restore still applies alongside it.
+  pub fn startup_urls_for_launch(&self, explicit_url: Option<&str>) -> Vec<String> {
+    match explicit_url {
+      Some(url) => vec![url.to_string()],
+      None if self.session_restore == SessionRestore::Urls => self.startup_urls.clone(),
+      None => Vec::new(),
+    }
+  }
+
   /// Returns true if sync is enabled (either Regular or Encrypted mode).
   pub fn is_sync_enabled(&self) -> bool {
     self.sync_mode != SyncMode::Disabled
@@ -1,4 +1,5 @@
 use directories::BaseDirs;
+use rusqlite::{Connection, OpenFlags};
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 use std::fs::{self, create_dir_all, File};
@@ -7,6 +8,7 @@ use std::path::{Path, PathBuf};
 
 use crate::downloaded_browsers_registry::DownloadedBrowsersRegistry;
 use crate::events;
+use crate::events::payloads::ProfileImportProgress;
 use crate::profile::types::{get_host_os, BrowserProfile, SyncMode};
 use crate::profile::ProfileManager;
 use crate::proxy_manager::PROXY_MANAGER;
@@ -16,13 +18,92 @@ use crate::wayfern_manager::WayfernConfig;
 /// refuses to delete anything outside the system temp dir with this prefix.
 const IMPORT_SCRATCH_PREFIX: &str = "donutbrowser-profile-import-";
 
-#[derive(Debug, Serialize, Deserialize, Clone, utoipa::ToSchema)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, utoipa::ToSchema)]
 pub struct DetectedProfile {
   pub browser: String,
   pub mapped_browser: String,
   pub name: String,
   pub path: String,
   pub description: String,
+  /// Bytes on disk, caches included.
+  #[serde(default)]
+  pub size_bytes: u64,
+  /// Categories with data in the profile.
+  #[serde(default)]
+  pub data_categories: Vec<ImportCategory>,
+  /// Categories whose data is tied to this machine and would not survive
+  /// the import (saved passwords encrypted by the OS keychain).
+  #[serde(default)]
+  pub non_portable_categories: Vec<ImportCategory>,
+}
+
+/// A slice of a Chromium profile that can be imported on its own.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash, utoipa::ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ImportCategory {
+  Cookies,
+  Logins,
+  Bookmarks,
+  History,
+  Extensions,
+}
+
+impl ImportCategory {
+  pub const ALL: [ImportCategory; 5] = [
+    ImportCategory::Cookies,
+    ImportCategory::Logins,
+    ImportCategory::Bookmarks,
+    ImportCategory::History,
+    ImportCategory::Extensions,
+  ];
+
+  /// Files and directories, relative to the profile dir, that hold the
+  /// category. SQLite journals travel with their database.
+  fn entries(self) -> &'static [&'static str] {
+    match self {
+      Self::Cookies => &[
+        "Cookies",
+        "Cookies-journal",
+        "Network/Cookies",
+        "Network/Cookies-journal",
+      ],
+      Self::Logins => &[
+        "Login Data",
+        "Login Data-journal",
+        "Login Data For Account",
+        "Login Data For Account-journal",
+      ],
+      Self::Bookmarks => &["Bookmarks", "Bookmarks.bak"],
+      Self::History => &[
+        "History",
+        "History-journal",
+        "Favicons",
+        "Favicons-journal",
+        "Top Sites",
+        "Top Sites-journal",
+        "Visited Links",
+      ],
+      // Preferences carry the extension registry; without them the copied
+      // extensions are not loaded.
+      Self::Extensions => &[
+        "Extensions",
+        "Extension State",
+        "Extension Rules",
+        "Extension Scripts",
+        "Local Extension Settings",
+        "Managed Extension Settings",
+        "Preferences",
+        "Secure Preferences",
+      ],
+    }
+  }
+
+  fn is_present(self, profile_dir: &Path) -> bool {
+    self
+      .entries()
+      .iter()
+      .any(|entry| profile_dir.join(entry).exists())
+  }
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, utoipa::ToSchema)]
@@ -35,6 +116,10 @@ pub struct ImportProfileItem {
   pub proxy_id: Option<String>,
   #[serde(default)]
   pub vpn_id: Option<String>,
+  /// Copy only these categories into a fresh profile. `None` copies the
+  /// whole profile directory.
+  #[serde(default)]
+  pub categories: Option<Vec<ImportCategory>>,
 }
 
 fn default_import_browser_type() -> String {
@@ -61,6 +146,10 @@ pub struct ProfileImportItemResult {
   pub profile_id: Option<String>,
   /// Structured `{"code": …}` error string when status is "failed".
   pub error: Option<String>,
+  /// Structured codes for source data that was left out, e.g. saved
+  /// passwords that only decrypt on the source machine.
+  #[serde(default)]
+  pub warnings: Vec<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, utoipa::ToSchema)]
@@ -79,16 +168,6 @@ pub struct ArchiveScanResult {
   pub profiles: Vec<DetectedProfile>,
 }
 
-#[derive(Debug, Serialize, Clone)]
-struct ProfileImportProgress {
-  total: usize,
-  completed: usize,
-  index: usize,
-  name: String,
-  /// "importing" | "imported" | "skipped" | "failed"
-  status: String,
-}
-
 fn map_browser_type(_browser: &str) -> &str {
   // Every import source maps to Wayfern — the only launchable engine.
   "wayfern"
@@ -107,7 +186,7 @@ pub fn error_to_code_string(e: Box<dyn std::error::Error>) -> String {
 
 /// Resolve a requested profile name against the set of taken (lowercased)
 /// names by appending ` (2)`, ` (3)`, … . The chosen name is added to `taken`.
-fn resolve_duplicate_name(requested: &str, taken: &mut HashSet<String>) -> String {
+pub(crate) fn resolve_duplicate_name(requested: &str, taken: &mut HashSet<String>) -> String {
   if taken.insert(requested.to_lowercase()) {
     return requested.to_string();
   }
@@ -121,17 +200,139 @@ fn resolve_duplicate_name(requested: &str, taken: &mut HashSet<String>) -> Strin
   }
 }
 
-fn emit_import_progress(total: usize, completed: usize, index: usize, name: &str, status: &str) {
-  let _ = events::emit(
-    "profile-import-progress",
-    &ProfileImportProgress {
-      total,
-      completed,
-      index,
-      name: name.to_string(),
-      status: status.to_string(),
-    },
-  );
+pub(crate) fn emit_import_progress(
+  total: usize,
+  completed: usize,
+  index: usize,
+  name: &str,
+  status: &str,
+) {
+  let _ = events::emit(&ProfileImportProgress {
+    total,
+    completed,
+    index,
+    name: name.to_string(),
+    status: status.to_string(),
+    category: None,
+  });
+}
+
+fn emit_category_progress(
+  total: usize,
+  completed: usize,
+  index: usize,
+  name: &str,
+  category: ImportCategory,
+) {
+  let _ = events::emit(&ProfileImportProgress {
+    total,
+    completed,
+    index,
+    name: name.to_string(),
+    status: "copying".to_string(),
+    category: Some(category),
+  });
+}
+
+/// Bytes under `path`, without following symlinks.
+fn directory_size(path: &Path) -> u64 {
+  let Ok(entries) = fs::read_dir(path) else {
+    return 0;
+  };
+  entries
+    .flatten()
+    .map(|entry| match entry.metadata() {
+      Ok(meta) if meta.is_dir() => directory_size(&entry.path()),
+      Ok(meta) => meta.len(),
+      Err(_) => 0,
+    })
+    .sum()
+}
+
+/// Whether the login database holds at least one saved password. A database
+/// that exists but can't be read counts as holding some.
+fn has_saved_passwords(db_path: &Path) -> bool {
+  if !db_path.exists() {
+    return false;
+  }
+  let Ok(conn) = Connection::open_with_flags(db_path, OpenFlags::SQLITE_OPEN_READ_ONLY) else {
+    return true;
+  };
+  conn
+    .query_row(
+      "SELECT EXISTS(SELECT 1 FROM logins WHERE length(password_value) > 0)",
+      [],
+      |row| row.get::<_, bool>(0),
+    )
+    .unwrap_or(true)
+}
+
+/// Chromium encrypts saved passwords with a key held by the OS keychain,
+/// DPAPI or the desktop keyring, none of which comes along with the files.
+/// Only a profile that carries its own `os_crypt_key`, as Wayfern's do, keeps
+/// them readable after the copy.
+fn logins_portable(profile_dir: &Path) -> bool {
+  let carries_key = [Some(profile_dir), profile_dir.parent()]
+    .into_iter()
+    .flatten()
+    .any(|dir| dir.join("os_crypt_key").is_file());
+  carries_key
+    || !["Login Data", "Login Data For Account"]
+      .iter()
+      .any(|db| has_saved_passwords(&profile_dir.join(db)))
+}
+
+/// Fill in the size and data categories of detected profiles.
+fn describe_profile_data(profiles: &mut [DetectedProfile]) {
+  for profile in profiles {
+    let dir = Path::new(&profile.path);
+    profile.size_bytes = directory_size(dir);
+    profile.data_categories = ImportCategory::ALL
+      .into_iter()
+      .filter(|c| c.is_present(dir))
+      .collect();
+    profile.non_portable_categories =
+      if profile.data_categories.contains(&ImportCategory::Logins) && !logins_portable(dir) {
+        vec![ImportCategory::Logins]
+      } else {
+        Vec::new()
+      };
+  }
+}
+
+/// Copy one category's entries from `source` into `destination`, keeping
+/// their relative paths.
+fn copy_category(
+  source: &Path,
+  destination: &Path,
+  category: ImportCategory,
+) -> Result<(), Box<dyn std::error::Error>> {
+  for entry in category.entries() {
+    let from = source.join(entry);
+    let to = destination.join(entry);
+    if from.is_dir() {
+      ProfileImporter::copy_directory_recursive(&from, &to)?;
+    } else if from.is_file() {
+      if let Some(parent) = to.parent() {
+        create_dir_all(parent)?;
+      }
+      fs::copy(&from, &to)?;
+    }
+  }
+  Ok(())
+}
+
+/// Run a profile copy off the async runtime. Profile dirs can be multiple
+/// GB.
+async fn run_copy<F>(copy: F) -> Result<(), String>
+where
+  F: FnOnce() -> Result<(), Box<dyn std::error::Error>> + Send + 'static,
+{
+  match tokio::task::spawn_blocking(move || copy().map_err(|e| e.to_string())).await {
+    Ok(result) => result,
+    // The copy task died (panic, or runtime shutdown mid-import).
+    Err(e) => Err(format!("Profile copy task failed: {e}")),
+  }
 }
 
 /// A known Chromium-family browser install location.
@@ -185,6 +386,7 @@ impl ProfileImporter {
             ),
             path: source.dir.to_string_lossy().to_string(),
             description: "Default profile".to_string(),
+            ..Default::default()
           });
         }
         // Newer Opera builds keep extra profiles under _side_profiles/.
@@ -208,6 +410,7 @@ impl ProfileImporter {
                 ),
                 path: path.to_string_lossy().to_string(),
                 description: format!("Side profile {dir_name}"),
+                ..Default::default()
               });
             }
           }
@@ -218,10 +421,11 @@ impl ProfileImporter {
     }
 
     let mut seen_paths = HashSet::new();
-    let unique_profiles: Vec<DetectedProfile> = detected_profiles
+    let mut unique_profiles: Vec<DetectedProfile> = detected_profiles
       .into_iter()
       .filter(|profile| seen_paths.insert(profile.path.clone()))
       .collect();
+    describe_profile_data(&mut unique_profiles);
 
     Ok(unique_profiles)
   }
@@ -405,13 +609,16 @@ impl ProfileImporter {
         .and_then(|n| n.to_str())
         .unwrap_or("Imported profile")
         .to_string();
-      return Ok(vec![DetectedProfile {
+      let mut profiles = vec![DetectedProfile {
         browser: "chromium".to_string(),
         mapped_browser: map_browser_type("chromium").to_string(),
         name,
         path: folder.to_string_lossy().to_string(),
         description: "Chromium profile".to_string(),
-      }]);
+        ..Default::default()
+      }];
+      describe_profile_data(&mut profiles);
+      return Ok(profiles);
     }
 
     let mut profiles = self.scan_chrome_profiles_dir(folder, "chromium")?;
@@ -438,6 +645,7 @@ impl ProfileImporter {
               name: dir_name,
               path: path_str,
               description: "Chromium profile".to_string(),
+              ..Default::default()
             });
           }
         } else {
@@ -452,6 +660,7 @@ impl ProfileImporter {
         }
       }
     }
+    describe_profile_data(&mut profiles);
 
     Ok(profiles)
   }
@@ -579,6 +788,7 @@ impl ProfileImporter {
         ),
         path: default_profile.to_string_lossy().to_string(),
         description: "Default profile".to_string(),
+        ..Default::default()
       });
     }
 
@@ -600,6 +810,7 @@ impl ProfileImporter {
               ),
               path: path.to_string_lossy().to_string(),
               description: format!("Profile {profile_number}"),
+              ..Default::default()
             });
           }
         }
@@ -637,7 +848,7 @@ impl ProfileImporter {
   /// the rest. Emits `profile-import-progress` events around each item.
   pub async fn import_profiles(
     &self,
-    app_handle: &tauri::AppHandle,
+    app_handle: &crate::app_handle::AppHandle,
     items: Vec<ImportProfileItem>,
     group_id: Option<String>,
     duplicate_strategy: DuplicateStrategy,
@@ -693,6 +904,17 @@ impl ProfileImporter {
       );
     }
 
+    if items
+      .iter()
+      .any(|i| i.categories.as_ref().is_some_and(Vec::is_empty))
+    {
+      return Err(
+        serde_json::json!({ "code": "IMPORT_NO_CATEGORIES" })
+          .to_string()
+          .into(),
+      );
+    }
+
     let mut taken_names: HashSet<String> = self
       .profile_manager
       .list_profiles()?
@@ -719,6 +941,7 @@ impl ProfileImporter {
           status: "failed".to_string(),
           profile_id: None,
           error: Some(serde_json::json!({ "code": "NAME_CANNOT_BE_EMPTY" }).to_string()),
+          warnings: Vec::new(),
         });
         continue;
       }
@@ -735,6 +958,7 @@ impl ProfileImporter {
               status: "skipped".to_string(),
               profile_id: None,
               error: None,
+              warnings: Vec::new(),
             });
             continue;
           }
@@ -747,6 +971,8 @@ impl ProfileImporter {
 
       emit_import_progress(total, completed, index, &final_name, "importing");
 
+      let on_category =
+        |category| emit_category_progress(total, completed, index, &final_name, category);
       match self
         .import_profile(
           app_handle,
@@ -757,10 +983,12 @@ impl ProfileImporter {
           item.vpn_id.clone(),
           group_id.clone(),
           wayfern_config.clone(),
+          item.categories.as_deref(),
+          &on_category,
         )
         .await
       {
-        Ok(profile) => {
+        Ok((profile, left_out)) => {
           imported_count += 1;
           completed += 1;
           emit_import_progress(total, completed, index, &final_name, "imported");
@@ -771,6 +999,13 @@ impl ProfileImporter {
             status: "imported".to_string(),
             profile_id: Some(profile.id.to_string()),
             error: None,
+            warnings: left_out
+              .iter()
+              .map(|category| {
+                serde_json::json!({ "code": "IMPORT_CATEGORY_NOT_PORTABLE", "params": { "category": category } })
+                  .to_string()
+              })
+              .collect(),
           });
         }
         Err(e) => {
@@ -785,6 +1020,7 @@ impl ProfileImporter {
             status: "failed".to_string(),
             profile_id: None,
             error: Some(error_to_code_string(e)),
+            warnings: Vec::new(),
           });
         }
       }
@@ -798,10 +1034,14 @@ impl ProfileImporter {
     })
   }
 
+  /// Import one profile. `categories` limits the copy to those categories;
+  /// `on_category` is called before each one is copied. Returns the new
+  /// profile and the categories left out because their data is bound to the
+  /// source machine.
   #[allow(clippy::too_many_arguments)]
   pub async fn import_profile(
     &self,
-    app_handle: &tauri::AppHandle,
+    app_handle: &crate::app_handle::AppHandle,
     source_path: &str,
     browser_type: &str,
     new_profile_name: &str,
@@ -809,7 +1049,9 @@ impl ProfileImporter {
     vpn_id: Option<String>,
     group_id: Option<String>,
     wayfern_config: Option<WayfernConfig>,
-  ) -> Result<BrowserProfile, Box<dyn std::error::Error>> {
+    categories: Option<&[ImportCategory]>,
+    on_category: &(dyn Fn(ImportCategory) + Sync),
+  ) -> Result<(BrowserProfile, Vec<ImportCategory>), Box<dyn std::error::Error>> {
     let source_path = Path::new(source_path);
     if !source_path.exists() {
       return Err(
@@ -847,32 +1089,45 @@ impl ProfileImporter {
     create_dir_all(&new_profile_uuid_dir)?;
     create_dir_all(&new_profile_data_dir)?;
 
-    // Profile dirs can be multiple GB — keep the copy off the async runtime.
-    let copy_source = source_path.to_path_buf();
-    let copy_dest = new_profile_data_dir.clone();
-    let copy_result = match tokio::task::spawn_blocking(move || {
-      Self::copy_directory_recursive(&copy_source, &copy_dest).map_err(|e| e.to_string())
-    })
-    .await
+    // Saved passwords that only decrypt on the source machine would import
+    // as unusable rows, so they are left out and reported instead.
+    let left_out: Vec<ImportCategory> = if categories
+      .is_none_or(|c| c.contains(&ImportCategory::Logins))
+      && !logins_portable(source_path)
     {
-      Ok(r) => r,
-      Err(e) => {
-        // The copy task died (panic, or runtime shutdown mid-import). Clean up
-        // like every other error path here, or the half-copied — possibly
-        // multi-GB — directory is orphaned with no metadata pointing at it, so
-        // nothing ever reclaims it.
-        let _ = fs::remove_dir_all(&new_profile_uuid_dir);
-        return Err(
-          serde_json::json!({
-            "code": "INTERNAL_ERROR",
-            "params": { "detail": format!("Profile copy task failed: {e}") },
-          })
-          .to_string()
-          .into(),
-        );
+      vec![ImportCategory::Logins]
+    } else {
+      Vec::new()
+    };
+
+    let copy_result = match categories {
+      None => {
+        let copy_source = source_path.to_path_buf();
+        let copy_dest = new_profile_data_dir.clone();
+        let result =
+          run_copy(move || Self::copy_directory_recursive(&copy_source, &copy_dest)).await;
+        for entry in left_out.iter().flat_map(|c| c.entries()) {
+          let _ = fs::remove_file(new_profile_data_dir.join(entry));
+        }
+        result
+      }
+      Some(categories) => {
+        let mut result = Ok(());
+        for &category in categories.iter().filter(|c| !left_out.contains(c)) {
+          on_category(category);
+          let copy_source = source_path.to_path_buf();
+          let copy_dest = new_profile_data_dir.clone();
+          result = run_copy(move || copy_category(&copy_source, &copy_dest, category)).await;
+          if result.is_err() {
+            break;
+          }
+        }
+        result
       }
     };
     if let Err(e) = copy_result {
+      // Clean up, or the half-copied — possibly multi-GB — directory is
+      // orphaned with no metadata pointing at it, so nothing ever reclaims it.
       let _ = fs::remove_dir_all(&new_profile_uuid_dir);
       return Err(
         serde_json::json!({ "code": "INTERNAL_ERROR", "params": { "detail": e } })
@@ -925,8 +1180,10 @@ impl ProfileImporter {
           version: version.clone(),
           proxy_id: proxy_id.clone(),
           vpn_id: None,
+          proxy_pool_id: None,
           launch_hook: None,
           process_id: None,
+          cdp_port: None,
           last_launch: None,
           release_type: "stable".to_string(),
           wayfern_config: None,
@@ -946,6 +1203,17 @@ impl ProfileImporter {
           dns_blocklist: None,
           password_protected: false,
           clear_on_close: false,
+          startup_urls: Vec::new(),
+          session_restore: Default::default(),
+          preference_overrides: Default::default(),
+          metadata: Default::default(),
+          pin_browser_version: false,
+          restart_policy: Default::default(),
+          dns_mode: Default::default(),
+          exit_mismatch_action: Default::default(),
+          resource_limits: Default::default(),
+          applied_resource_limits: None,
+          archived: false,
           created_at: None,
           updated_at: None,
         };
@@ -985,8 +1253,10 @@ impl ProfileImporter {
       version,
       proxy_id,
       vpn_id,
+      proxy_pool_id: None,
       launch_hook: None,
       process_id: None,
+      cdp_port: None,
       last_launch: None,
       release_type: "stable".to_string(),
       wayfern_config: final_wayfern_config,
@@ -1006,6 +1276,17 @@ impl ProfileImporter {
       dns_blocklist: None,
       password_protected: false,
       clear_on_close: false,
+      startup_urls: Vec::new(),
+      session_restore: Default::default(),
+      preference_overrides: Default::default(),
+      metadata: Default::default(),
+      pin_browser_version: false,
+      restart_policy: Default::default(),
+      dns_mode: Default::default(),
+      exit_mismatch_action: Default::default(),
+      resource_limits: Default::default(),
+      applied_resource_limits: None,
+      archived: false,
       created_at: Some(
         std::time::SystemTime::now()
           .duration_since(std::time::UNIX_EPOCH)
@@ -1023,10 +1304,10 @@ impl ProfileImporter {
       source_path.display()
     );
 
-    Ok(profile)
+    Ok((profile, left_out))
   }
 
-  fn get_default_version_for_browser(
+  pub(crate) fn get_default_version_for_browser(
     &self,
     browser_type: &str,
   ) -> Result<String, Box<dyn std::error::Error>> {
@@ -1104,7 +1385,7 @@ pub async fn cleanup_profile_import_scratch(extracted_dir: String) -> Result<(),
 
 #[tauri::command]
 pub async fn import_browser_profiles(
-  app_handle: tauri::AppHandle,
+  app_handle: crate::app_handle::AppHandle,
   items: Vec<ImportProfileItem>,
   group_id: Option<String>,
   duplicate_strategy: Option<DuplicateStrategy>,
@@ -1337,4 +1618,68 @@ mod tests {
     ProfileImporter::cleanup_scratch_dir(&scratch.to_string_lossy()).unwrap();
     assert!(!scratch.exists(), "scratch dir should be removed");
   }
+
+  #[test]
+  fn test_describe_profile_data_flags_keychain_logins() {
+    let temp_dir = TempDir::new().unwrap();
+    let profile_dir = temp_dir.path().join("Default");
+    fs::create_dir_all(profile_dir.join("Network")).unwrap();
+    fs::write(profile_dir.join("Preferences"), "{}").unwrap();
+    fs::write(profile_dir.join("Bookmarks"), "{}").unwrap();
+    fs::write(profile_dir.join("Network").join("Cookies"), "").unwrap();
+    let conn = Connection::open(profile_dir.join("Login Data")).unwrap();
+    conn
+      .execute_batch(
+        "CREATE TABLE logins (origin_url TEXT, password_value BLOB);
+         INSERT INTO logins VALUES ('https://example.com', x'763130deadbeef');",
+      )
+      .unwrap();
+    drop(conn);
+
+    let mut profiles = vec![DetectedProfile {
+      path: profile_dir.to_string_lossy().to_string(),
+      ..Default::default()
+    }];
+    describe_profile_data(&mut profiles);
+    assert!(profiles[0].size_bytes > 0);
+    assert_eq!(
+      profiles[0].data_categories,
+      vec![
+        ImportCategory::Cookies,
+        ImportCategory::Logins,
+        ImportCategory::Bookmarks,
+        ImportCategory::Extensions,
+      ]
+    );
+    assert_eq!(
+      profiles[0].non_portable_categories,
+      vec![ImportCategory::Logins]
+    );
+
+    // A user-data dir that carries its own key keeps passwords readable.
+    fs::write(temp_dir.path().join("os_crypt_key"), "key").unwrap();
+    describe_profile_data(&mut profiles);
+    assert!(profiles[0].non_portable_categories.is_empty());
+  }
+
+  #[test]
+  fn test_copy_category_copies_only_its_entries() {
+    let temp_dir = TempDir::new().unwrap();
+    let source = temp_dir.path().join("source");
+    fs::create_dir_all(source.join("Network")).unwrap();
+    fs::write(source.join("Network").join("Cookies"), "cookies").unwrap();
+    fs::write(source.join("History"), "history").unwrap();
+    fs::write(source.join("Bookmarks"), "bookmarks").unwrap();
+
+    let dest = temp_dir.path().join("dest");
+    copy_category(&source, &dest, ImportCategory::Cookies).unwrap();
+    copy_category(&source, &dest, ImportCategory::Bookmarks).unwrap();
+
+    assert_eq!(
+      fs::read_to_string(dest.join("Network").join("Cookies")).unwrap(),
+      "cookies"
+    );
+    assert!(dest.join("Bookmarks").exists());
+    assert!(!dest.join("History").exists());
+  }
 }
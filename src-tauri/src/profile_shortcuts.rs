@@ -0,0 +1,337 @@
+//! OS-wide shortcuts that bring up a profile without opening the Donut
+//! window: the browser is launched when it isn't running and its window is
+//! brought to the front when it is. Accelerators are kept per profile in
+//! `AppSettings::profile_shortcuts` and registered at startup.
+
+use crate::profile::{BrowserProfile, ProfileManager};
+use crate::settings_manager::SettingsManager;
+use std::collections::HashMap;
+use std::sync::OnceLock;
+use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut, ShortcutEvent, ShortcutState};
+
+/// Set when the shortcuts are registered, so profile deletion can
+/// unregister without a handle of its own.
+static APP_HANDLE: OnceLock<tauri::AppHandle> = OnceLock::new();
+
+fn invalid(accelerator: &str) -> String {
+  serde_json::json!({ "code": "INVALID_SHORTCUT", "params": { "accelerator": accelerator } })
+    .to_string()
+}
+
+/// Parse an accelerator such as `Ctrl+Alt+1`. A key without modifiers would
+/// be swallowed from every other app, so one is required.
+fn parse(accelerator: &str) -> Result<Shortcut, String> {
+  let shortcut: Shortcut = accelerator
+    .trim()
+    .parse()
+    .map_err(|_| invalid(accelerator))?;
+  if shortcut.mods.is_empty() {
+    return Err(invalid(accelerator));
+  }
+  Ok(shortcut)
+}
+
+/// The other profile already bound to `shortcut`, however its accelerator
+/// was spelled.
+fn conflicting_profile<'a>(
+  shortcuts: &'a HashMap<String, String>,
+  profile_id: &str,
+  shortcut: &Shortcut,
+) -> Option<&'a str> {
+  shortcuts
+    .iter()
+    .filter(|(id, _)| id.as_str() != profile_id)
+    .find(|(_, accelerator)| parse(accelerator).is_ok_and(|s| s == *shortcut))
+    .map(|(id, _)| id.as_str())
+}
+
+fn find_profile(profile_id: &str) -> Result<BrowserProfile, String> {
+  ProfileManager::instance()
+    .list_profiles()
+    .map_err(|e| format!("Failed to list profiles: {e}"))?
+    .into_iter()
+    .find(|p| p.id.to_string() == profile_id)
+    .ok_or_else(|| serde_json::json!({ "code": "PROFILE_NOT_FOUND" }).to_string())
+}
+
+/// The plugin with the handler every profile shortcut goes through.
+pub fn plugin() -> tauri::plugin::TauriPlugin<tauri::Wry> {
+  tauri_plugin_global_shortcut::Builder::new()
+    .with_handler(|app, shortcut, event: ShortcutEvent| {
+      if event.state() != ShortcutState::Pressed {
+        return;
+      }
+      let Ok(settings) = SettingsManager::instance().load_settings() else {
+        return;
+      };
+      let Some(profile_id) = settings
+        .profile_shortcuts
+        .iter()
+        .find(|(_, accelerator)| parse(accelerator).is_ok_and(|s| s == *shortcut))
+        .map(|(id, _)| id.clone())
+      else {
+        return;
+      };
+      let app = app.clone();
+      tauri::async_runtime::spawn(async move {
+        if let Err(error) = launch_or_focus(&app, &profile_id).await {
+          log::error!("Shortcut for profile {profile_id} failed: {error}");
+          let payload = crate::events::payloads::ProfileShortcutFailed { profile_id, error };
+          if let Err(e) = crate::events::emit(&payload) {
+            log::warn!("Failed to emit profile-shortcut-failed event: {e}");
+          }
+        }
+      });
+    })
+    .build()
+}
+
+async fn launch_or_focus(app: &tauri::AppHandle, profile_id: &str) -> Result<(), String> {
+  let profile = find_profile(profile_id)?;
+  let running = ProfileManager::instance()
+    .check_browser_status(app.into(), &profile)
+    .await
+    .map_err(|e| format!("Failed to check browser status: {e}"))?;
+
+  if !running {
+    log::info!("Shortcut launching profile {profile_id}");
+    return crate::browser_runner::launch_browser_profile_impl(
+      app.into(),
+      profile,
+      None,
+      None,
+      false,
+      false,
+      crate::launch_queue::LaunchPriority::Interactive,
+    )
+    .await
+    .map(|_| ());
+  }
+
+  // The status check may have replaced the stored PID.
+  let pid = find_profile(profile_id)?.process_id;
+  match pid {
+    Some(pid) if focus_process_window(pid).await => Ok(()),
+    _ => Err(serde_json::json!({ "code": "WINDOW_FOCUS_FAILED" }).to_string()),
+  }
+}
+
+/// Register every saved shortcut. One that is taken by another app is
+/// logged and skipped; the rest still work.
+pub fn register_all(app: &tauri::AppHandle) {
+  let _ = APP_HANDLE.set(app.clone());
+  let Ok(settings) = SettingsManager::instance().load_settings() else {
+    return;
+  };
+  for (profile_id, accelerator) in &settings.profile_shortcuts {
+    let result = parse(accelerator).and_then(|shortcut| {
+      app
+        .global_shortcut()
+        .register(shortcut)
+        .map_err(|e| e.to_string())
+    });
+    if let Err(e) = result {
+      log::warn!("Failed to register shortcut {accelerator} for profile {profile_id}: {e}");
+    }
+  }
+}
+
+/// Drop a deleted profile's shortcut.
+pub fn forget_profile(profile_id: &str) {
+  let manager = SettingsManager::instance();
+  let Ok(mut settings) = manager.load_settings() else {
+    return;
+  };
+  let Some(accelerator) = settings.profile_shortcuts.remove(profile_id) else {
+    return;
+  };
+  if let (Some(app), Ok(shortcut)) = (APP_HANDLE.get(), parse(&accelerator)) {
+    let _ = app.global_shortcut().unregister(shortcut);
+  }
+  if let Err(e) = manager.save_settings(&settings) {
+    log::warn!("Failed to remove shortcut of deleted profile {profile_id}: {e}");
+  }
+}
+
+/// Bind `accelerator` to the profile, or remove its shortcut when `None`.
+#[tauri::command]
+pub async fn set_profile_shortcut(
+  app_handle: tauri::AppHandle,
+  profile_id: String,
+  accelerator: Option<String>,
+) -> Result<(), String> {
+  let manager = SettingsManager::instance();
+  let mut settings = manager
+    .load_settings()
+    .map_err(|e| format!("Failed to load settings: {e}"))?;
+  let shortcuts = app_handle.global_shortcut();
+  let previous = settings
+    .profile_shortcuts
+    .get(&profile_id)
+    .and_then(|a| parse(a).ok());
+
+  match accelerator.as_deref().map(str::trim) {
+    None | Some("") => {
+      if let Some(previous) = previous {
+        let _ = shortcuts.unregister(previous);
+      }
+      settings.profile_shortcuts.remove(&profile_id);
+    }
+    Some(accelerator) => {
+      find_profile(&profile_id)?;
+      let shortcut = parse(accelerator)?;
+      if let Some(other) = conflicting_profile(&settings.profile_shortcuts, &profile_id, &shortcut)
+      {
+        let name = find_profile(other)
+          .map(|p| p.name)
+          .unwrap_or_else(|_| other.to_string());
+        return Err(
+          serde_json::json!({ "code": "SHORTCUT_IN_USE", "params": { "profile": name } })
+            .to_string(),
+        );
+      }
+
+      if previous != Some(shortcut) {
+        // Taken by another app, or already one of ours.
+        if shortcuts.is_registered(shortcut) || shortcuts.register(shortcut).is_err() {
+          return Err(
+            serde_json::json!({
+              "code": "SHORTCUT_UNAVAILABLE",
+              "params": { "accelerator": accelerator }
+            })
+            .to_string(),
+          );
+        }
+        if let Some(previous) = previous {
+          let _ = shortcuts.unregister(previous);
+        }
+      }
+      settings
+        .profile_shortcuts
+        .insert(profile_id, accelerator.to_string());
+    }
+  }
+
+  manager
+    .save_settings(&settings)
+    .map_err(|e| format!("Failed to save settings: {e}"))
+}
+
+/// Bring the first visible top-level window of `pid` to the front.
+async fn focus_process_window(pid: u32) -> bool {
+  #[cfg(target_os = "windows")]
+  {
+    windows_focus::focus(pid)
+  }
+
+  // Activating through NSRunningApplication is not an Apple Event, so it
+  // raises the browser without the "control other apps" permission prompt.
+  #[cfg(target_os = "macos")]
+  {
+    use objc2_app_kit::{NSApplicationActivationOptions, NSRunningApplication};
+
+    #[allow(unused_unsafe)]
+    unsafe {
+      NSRunningApplication::runningApplicationWithProcessIdentifier(pid as i32).is_some_and(|app| {
+        app.activateWithOptions(NSApplicationActivationOptions::ActivateAllWindows)
+      })
+    }
+  }
+
+  #[cfg(target_os = "linux")]
+  {
+    tokio::process::Command::new("xdotool")
+      .args([
+        "search",
+        "--pid",
+        &pid.to_string(),
+        "--onlyvisible",
+        "windowactivate",
+      ])
+      .output()
+      .await
+      .is_ok_and(|output| output.status.success())
+  }
+
+  #[cfg(not(any(target_os = "windows", target_os = "macos", target_os = "linux")))]
+  {
+    let _ = pid;
+    false
+  }
+}
+
+#[cfg(target_os = "windows")]
+mod windows_focus {
+  use windows::core::BOOL;
+  use windows::Win32::Foundation::{HWND, LPARAM};
+  use windows::Win32::UI::WindowsAndMessaging::{
+    EnumWindows, GetWindow, GetWindowThreadProcessId, IsIconic, IsWindowVisible,
+    SetForegroundWindow, ShowWindow, GW_OWNER, SW_RESTORE,
+  };
+
+  struct Search {
+    pid: u32,
+    found: Option<HWND>,
+  }
+
+  unsafe extern "system" fn visit(hwnd: HWND, lparam: LPARAM) -> BOOL {
+    let search = &mut *(lparam.0 as *mut Search);
+    let mut owner_pid = 0u32;
+    GetWindowThreadProcessId(hwnd, Some(&mut owner_pid));
+    // Owned windows are popups and dialogs; the browser window has none.
+    if owner_pid == search.pid
+      && IsWindowVisible(hwnd).as_bool()
+      && GetWindow(hwnd, GW_OWNER).is_err()
+    {
+      search.found = Some(hwnd);
+      return BOOL(0);
+    }
+    BOOL(1)
+  }
+
+  /// Windows only lets a process take the foreground while it handles
+  /// input, which a hotkey press counts as.
+  pub(super) fn focus(pid: u32) -> bool {
+    let mut search = Search { pid, found: None };
+    unsafe {
+      // Stopping early reports an error; `found` says whether it worked.
+      let _ = EnumWindows(Some(visit), LPARAM(&mut search as *mut Search as isize));
+      let Some(hwnd) = search.found else {
+        return false;
+      };
+      if IsIconic(hwnd).as_bool() {
+        let _ = ShowWindow(hwnd, SW_RESTORE);
+      }
+      SetForegroundWindow(hwnd).as_bool()
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn accelerators_need_a_modifier_and_a_known_key() {
+    assert!(parse("Ctrl+Alt+1").is_ok());
+    assert!(parse(" CommandOrControl+Shift+P ").is_ok());
+    assert!(parse("1").unwrap_err().contains("INVALID_SHORTCUT"));
+    assert!(parse("Ctrl+Nope").unwrap_err().contains("INVALID_SHORTCUT"));
+  }
+
+  #[test]
+  fn conflicts_match_the_key_not_the_spelling() {
+    let shortcuts = HashMap::from([
+      ("a".to_string(), "ctrl+alt+1".to_string()),
+      ("b".to_string(), "Ctrl+Alt+2".to_string()),
+    ]);
+    let shortcut = parse("Control+Alt+1").unwrap();
+    assert_eq!(conflicting_profile(&shortcuts, "b", &shortcut), Some("a"));
+    // Rebinding a profile to its own shortcut is not a conflict.
+    assert_eq!(conflicting_profile(&shortcuts, "a", &shortcut), None);
+    assert_eq!(
+      conflicting_profile(&shortcuts, "b", &parse("Ctrl+Alt+3").unwrap()),
+      None
+    );
+  }
+}
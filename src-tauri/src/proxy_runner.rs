@@ -1,3 +1,4 @@
+use crate::proxy_client_cert::ClientCertPaths;
 use crate::proxy_storage::{
   delete_proxy_config, generate_proxy_id, get_proxy_config, is_process_running, list_proxy_configs,
   save_proxy_config, ProxyConfig,
@@ -272,7 +273,18 @@ pub async fn start_proxy_process(
   upstream_url: Option<String>,
   port: Option<u16>,
 ) -> Result<ProxyConfig, Box<dyn std::error::Error>> {
-  start_proxy_process_with_profile(upstream_url, port, None, Vec::new(), None, false, None).await
+  start_proxy_process_with_profile(
+    upstream_url,
+    port,
+    None,
+    Vec::new(),
+    None,
+    false,
+    None,
+    Vec::new(),
+    None,
+  )
+  .await
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -284,6 +296,8 @@ pub async fn start_proxy_process_with_profile(
   blocklist_file: Option<String>,
   dns_allowlist_mode: bool,
   local_protocol: Option<String>,
+  upstream_chain: Vec<String>,
+  client_cert: Option<ClientCertPaths>,
 ) -> Result<ProxyConfig, Box<dyn std::error::Error>> {
   ensure_sidecar_version().await?;
 
@@ -302,7 +316,9 @@ pub async fn start_proxy_process_with_profile(
     .with_bypass_rules(bypass_rules)
     .with_blocklist_file(blocklist_file)
     .with_dns_allowlist_mode(dns_allowlist_mode)
-    .with_local_protocol(local_protocol);
+    .with_local_protocol(local_protocol)
+    .with_upstream_chain(upstream_chain)
+    .with_client_cert(client_cert);
   save_proxy_config(&config)?;
 
   // Log profile_id for debugging
@@ -332,6 +348,7 @@ pub async fn start_proxy_process_with_profile(
     cmd.arg(&id);
     cmd.env_remove("DONUT_PROXY_USERNAME");
     cmd.env_remove("DONUT_PROXY_PASSWORD");
+    cmd.env_remove("DONUT_PROXY_CHAIN");
 
     cmd.stdin(Stdio::null());
     cmd.stdout(Stdio::null());
@@ -410,6 +427,7 @@ pub async fn start_proxy_process_with_profile(
     cmd.arg(&id);
     cmd.env_remove("DONUT_PROXY_USERNAME");
     cmd.env_remove("DONUT_PROXY_PASSWORD");
+    cmd.env_remove("DONUT_PROXY_CHAIN");
 
     cmd.stdin(Stdio::null());
     cmd.stdout(Stdio::null());
@@ -1,3 +1,5 @@
+use crate::ip_utils::{host_port, split_host_port, unbracket, url_host};
+use crate::proxy_client_cert::{load_client_identity, ClientIdentityPem};
 use crate::proxy_storage::ProxyConfig;
 use crate::traffic_stats::{get_traffic_tracker, init_traffic_tracker, LiveTrafficTracker};
 use http_body_util::{BodyExt, Full};
@@ -211,13 +213,13 @@ impl<S: AsyncWrite + Unpin> AsyncWrite for CountingStream<S> {
 }
 
 // Wrapper to prepend consumed bytes to a stream
-struct PrependReader {
+struct PrependReader<S> {
   prepended: Vec<u8>,
   prepended_pos: usize,
-  inner: TcpStream,
+  inner: S,
 }
 
-impl AsyncRead for PrependReader {
+impl<S: AsyncRead + Unpin> AsyncRead for PrependReader<S> {
   fn poll_read(
     mut self: Pin<&mut Self>,
     cx: &mut Context<'_>,
@@ -237,7 +239,7 @@ impl AsyncRead for PrependReader {
   }
 }
 
-impl AsyncWrite for PrependReader {
+impl<S: AsyncWrite + Unpin> AsyncWrite for PrependReader<S> {
   fn poll_write(
     mut self: Pin<&mut Self>,
     cx: &mut Context<'_>,
@@ -387,6 +389,7 @@ async fn connect_via_socks(
     // SOCKS5 connection using async_socks5
     use async_socks5::{connect, AddrKind, Auth};
 
+    let target_host = unbracket(target_host);
     let target = if let Ok(ip) = target_host.parse::<std::net::IpAddr>() {
       AddrKind::Ip(std::net::SocketAddr::new(ip, target_port))
     } else {
@@ -620,13 +623,13 @@ async fn handle_http_via_socks4(
     }
   };
 
-  let socks_host = upstream.host_str().unwrap_or("127.0.0.1");
+  let socks_host = url_host(&upstream).unwrap_or_else(|| "127.0.0.1".to_string());
   let socks_port = upstream.port().unwrap_or(1080);
-  let socks_addr = format!("{}:{}", socks_host, socks_port);
+  let socks_addr = host_port(&socks_host, socks_port);
 
   // Parse target from request URI
   let target_uri = req.uri();
-  let target_host = target_uri.host().unwrap_or("localhost");
+  let target_host = unbracket(target_uri.host().unwrap_or("localhost"));
   let target_port = target_uri.port_u16().unwrap_or(80);
 
   // Connect to SOCKS4 proxy
@@ -716,7 +719,22 @@ async fn handle_http_via_socks4(
     return Ok(response);
   }
 
-  // Now send the HTTP request through the SOCKS4 connection
+  forward_http_over_stream(req, &mut socks_stream, &domain, "SOCKS4").await
+}
+
+/// Write `req` over an already-established tunnel to its origin and read the
+/// response back. Used for upstreams reqwest cannot speak to directly; `via`
+/// names the upstream in logs.
+async fn forward_http_over_stream<S: AsyncRead + AsyncWrite + Unpin>(
+  req: Request<hyper::body::Incoming>,
+  stream: &mut S,
+  domain: &str,
+  via: &str,
+) -> Result<Response<Full<Bytes>>, Infallible> {
+  let target_uri = req.uri().clone();
+  let target_host = target_uri.host().unwrap_or("localhost");
+  let target_port = target_uri.port_u16().unwrap_or(80);
+
   // Build HTTP request line
   let method = req.method().as_str();
   let path = target_uri
@@ -774,8 +792,8 @@ async fn handle_http_via_socks4(
   http_request.push_str("\r\n");
 
   // Send HTTP request
-  if let Err(e) = socks_stream.write_all(http_request.as_bytes()).await {
-    log::error!("Failed to send HTTP request through SOCKS4: {}", e);
+  if let Err(e) = stream.write_all(http_request.as_bytes()).await {
+    log::error!("Failed to send HTTP request through {}: {}", via, e);
     let mut response = Response::new(Full::new(Bytes::from(format!(
       "Failed to send HTTP request: {}",
       e
@@ -786,8 +804,8 @@ async fn handle_http_via_socks4(
 
   // Send body if present
   if !body_bytes.is_empty() {
-    if let Err(e) = socks_stream.write_all(&body_bytes).await {
-      log::error!("Failed to send HTTP body through SOCKS4: {}", e);
+    if let Err(e) = stream.write_all(&body_bytes).await {
+      log::error!("Failed to send HTTP body through {}: {}", via, e);
       let mut response = Response::new(Full::new(Bytes::from(format!(
         "Failed to send HTTP body: {}",
         e
@@ -802,13 +820,13 @@ async fn handle_http_via_socks4(
   // permit) forever.
   let buffered = match tokio::time::timeout(
     PLAIN_HTTP_EXCHANGE_TIMEOUT,
-    read_http_response_buffer(&mut socks_stream),
+    read_http_response_buffer(stream),
   )
   .await
   {
     Ok(buffer) => buffer,
     Err(_) => {
-      log::error!("HTTP response via SOCKS4 timed out");
+      log::error!("HTTP response via {via} timed out");
       let mut response = Response::new(Full::new(Bytes::from("Upstream response timed out")));
       *response.status_mut() = StatusCode::GATEWAY_TIMEOUT;
       return Ok(response);
@@ -819,7 +837,7 @@ async fn handle_http_via_socks4(
   // browser a complete-looking short response, so fail the request instead.
   if buffered.truncated {
     log::error!(
-      "HTTP response via SOCKS4 for {domain} exceeded the buffer cap; refusing to forward a truncated body"
+      "HTTP response via {via} for {domain} exceeded the buffer cap; refusing to forward a truncated body"
     );
     let mut response = Response::new(Full::new(Bytes::from(
       "Upstream response too large to buffer",
@@ -851,7 +869,7 @@ async fn handle_http_via_socks4(
   // Record request in traffic tracker
   let response_size = body.len() as u64;
   if let Some(tracker) = get_traffic_tracker() {
-    tracker.record_request(&domain, body_bytes.len() as u64, response_size);
+    tracker.record_request(domain, body_bytes.len() as u64, response_size);
   }
 
   let mut hyper_response = Response::new(Full::new(Bytes::from(body)));
@@ -860,6 +878,54 @@ async fn handle_http_via_socks4(
   Ok(hyper_response)
 }
 
+/// Handle plain HTTP requests when the upstream sits behind a proxy chain.
+/// reqwest only talks to a single proxy, so open a tunnel to the origin
+/// through the chain and write the request over it.
+async fn handle_http_via_chain(
+  req: Request<hyper::body::Incoming>,
+  upstream: &Url,
+) -> Result<Response<Full<Bytes>>, Infallible> {
+  let domain = req
+    .uri()
+    .host()
+    .map(|h| h.to_string())
+    .unwrap_or_else(|| "unknown".to_string());
+  let target_port = req.uri().port_u16().unwrap_or(80);
+
+  let mut stream =
+    match connect_through_chain(upstream_chain(), upstream, &domain, target_port).await {
+      Ok(stream) => stream,
+      Err(e) => {
+        log::error!("Failed to reach {domain} through proxy chain: {e}");
+        let mut response = Response::new(Full::new(Bytes::from(format!(
+          "Failed to connect through proxy chain: {e}"
+        ))));
+        *response.status_mut() = StatusCode::BAD_GATEWAY;
+        return Ok(response);
+      }
+    };
+
+  forward_http_over_stream(req, &mut stream, &domain, "proxy chain").await
+}
+
+/// Shadowsocks server address. An IP literal must be passed as a socket
+/// address; as a domain name it would be handed to the resolver verbatim.
+fn ss_server_addr(host: &str, port: u16) -> shadowsocks::config::ServerAddr {
+  match unbracket(host).parse::<std::net::IpAddr>() {
+    Ok(ip) => shadowsocks::config::ServerAddr::SocketAddr(SocketAddr::new(ip, port)),
+    Err(_) => shadowsocks::config::ServerAddr::DomainName(host.to_string(), port),
+  }
+}
+
+/// Destination inside a Shadowsocks tunnel, with IPv6 literals sent as
+/// addresses rather than as bracketed names the server cannot resolve.
+fn ss_target_addr(host: &str, port: u16) -> shadowsocks::relay::Address {
+  match unbracket(host).parse::<std::net::IpAddr>() {
+    Ok(ip) => shadowsocks::relay::Address::SocketAddress(SocketAddr::new(ip, port)),
+    Err(_) => shadowsocks::relay::Address::DomainNameAddress(host.to_string(), port),
+  }
+}
+
 /// Handle plain HTTP requests through a Shadowsocks upstream.
 /// reqwest doesn't support SS natively, so we connect through the SS tunnel
 /// manually and forward the HTTP request/response.
@@ -874,7 +940,7 @@ async fn handle_http_via_shadowsocks(
     .unwrap_or_else(|| "unknown".to_string());
   let port = req.uri().port_u16().unwrap_or(80);
 
-  let ss_host = upstream.host_str().unwrap_or("127.0.0.1");
+  let ss_host = url_host(upstream).unwrap_or_else(|| "127.0.0.1".to_string());
   let ss_port = upstream.port().unwrap_or(8388);
   let method_str = urlencoding::decode(upstream.username())
     .unwrap_or_default()
@@ -896,7 +962,7 @@ async fn handle_http_via_shadowsocks(
 
   let context = shadowsocks::context::Context::new_shared(shadowsocks::config::ServerType::Local);
   let svr_cfg = match shadowsocks::config::ServerConfig::new(
-    shadowsocks::config::ServerAddr::from((ss_host.to_string(), ss_port)),
+    ss_server_addr(&ss_host, ss_port),
     &password,
     cipher,
   ) {
@@ -908,7 +974,7 @@ async fn handle_http_via_shadowsocks(
     }
   };
 
-  let target_addr = shadowsocks::relay::Address::DomainNameAddress(domain.clone(), port);
+  let target_addr = ss_target_addr(&domain, port);
 
   let mut stream = match shadowsocks::relay::tcprelay::proxy_stream::ProxyClientStream::connect(
     context,
@@ -1016,6 +1082,9 @@ async fn handle_http(
     if let Some(ref upstream) = upstream_url {
       if upstream != "DIRECT" {
         if let Ok(url) = Url::parse(upstream) {
+          if upstream_is_chained() {
+            return handle_http_via_chain(req, &url).await;
+          }
           match url.scheme() {
             "socks4" => {
               return handle_http_via_socks4(req, upstream).await;
@@ -1182,7 +1251,7 @@ fn build_reqwest_client_with_proxy(
 ) -> Result<reqwest::Client, Box<dyn std::error::Error>> {
   use reqwest::Proxy;
 
-  let client_builder = reqwest::Client::builder()
+  let mut client_builder = reqwest::Client::builder()
     .connect_timeout(UPSTREAM_DIAL_TIMEOUT)
     .read_timeout(PLAIN_HTTP_EXCHANGE_TIMEOUT);
 
@@ -1190,6 +1259,13 @@ fn build_reqwest_client_with_proxy(
   let url = Url::parse(upstream_url)?;
   let scheme = url.scheme();
 
+  if let (Some(client_cert), "https") = (UPSTREAM_CLIENT_CERT.get(), scheme) {
+    client_builder = client_builder.identity(reqwest::Identity::from_pkcs8_pem(
+      &client_cert.pem.cert,
+      &client_cert.pem.key,
+    )?);
+  }
+
   let proxy = match scheme {
     "http" | "https" => {
       // For HTTP/HTTPS proxies, reqwest handles them directly
@@ -1393,6 +1469,41 @@ pub async fn run_proxy_server(config: ProxyConfig) -> Result<(), Box<dyn std::er
   // This can be called multiple times to update the tracker.
   init_traffic_tracker(config.id.clone(), config.profile_id.clone());
 
+  if !config.upstream_chain.is_empty() {
+    let hops = config
+      .upstream_chain
+      .iter()
+      .map(|hop| Url::parse(hop))
+      .collect::<Result<Vec<_>, _>>()
+      .map_err(|e| format!("Invalid proxy chain hop: {e}"))?;
+    log::info!(
+      "Upstream reached through chain: {} -> {}",
+      hops
+        .iter()
+        .map(|hop| redacted_upstream(hop.as_str()))
+        .collect::<Vec<_>>()
+        .join(" -> "),
+      redacted_upstream(&config.upstream_url)
+    );
+    let _ = UPSTREAM_CHAIN.set(hops);
+  }
+
+  if let Some(ref paths) = config.client_cert {
+    let pem =
+      load_client_identity(paths).map_err(|e| format!("Invalid proxy client certificate: {e}"))?;
+    let connector = native_tls::TlsConnector::builder()
+      .identity(pem.native_tls_identity()?)
+      .build()?;
+    log::info!(
+      "Presenting client certificate {} to the upstream",
+      paths.cert_path
+    );
+    let _ = UPSTREAM_CLIENT_CERT.set(UpstreamClientCert {
+      pem,
+      connector: connector.into(),
+    });
+  }
+
   // Determine the bind address
   let bind_addr = SocketAddr::from(([127, 0, 0, 1], config.local_port.unwrap_or(0)));
 
@@ -1445,6 +1556,10 @@ pub async fn run_proxy_server(config: ProxyConfig) -> Result<(), Box<dyn std::er
 
   log::info!("Successfully bound to port {}", actual_port);
 
+  // Serve the same port on the IPv6 loopback for clients that resolve
+  // `localhost` to ::1. Best effort: hosts without IPv6 keep 127.0.0.1 only.
+  let listener_v6 = bind_ipv6_loopback(actual_port);
+
   // Protocol served to the browser: "socks5" (Wayfern) or "http" (default).
   let local_protocol = config.local_protocol_or_default();
   let serve_socks5 = local_protocol == "socks5";
@@ -1640,7 +1755,14 @@ pub async fn run_proxy_server(config: ProxyConfig) -> Result<(), Box<dyn std::er
   // Keep the runtime alive with an infinite loop
   // This ensures the process doesn't exit even if there are no active connections
   loop {
-    match listener.accept().await {
+    let accepted = match &listener_v6 {
+      Some(listener_v6) => tokio::select! {
+        r = listener.accept() => r,
+        r = listener_v6.accept() => r,
+      },
+      None => listener.accept().await,
+    };
+    match accepted {
       Ok((stream, _peer_addr)) => {
         // The semaphore is never closed, so acquire cannot fail.
         let permit = conn_semaphore
@@ -1673,6 +1795,23 @@ pub async fn run_proxy_server(config: ProxyConfig) -> Result<(), Box<dyn std::er
   }
 }
 
+fn bind_ipv6_loopback(port: u16) -> Option<tokio::net::TcpListener> {
+  let addr = SocketAddr::from((std::net::Ipv6Addr::LOCALHOST, port));
+  let socket = tokio::net::TcpSocket::new_v6().ok()?;
+  let _ = socket.set_reuseaddr(true);
+  let bound = socket.bind(addr).and_then(|()| socket.listen(1024));
+  match bound {
+    Ok(listener) => {
+      log::info!("Also listening on {}", addr);
+      Some(listener)
+    }
+    Err(e) => {
+      log::info!("Not listening on {}: {}", addr, e);
+      None
+    }
+  }
+}
+
 async fn handle_connect_from_buffer(
   mut client_stream: TcpStream,
   request_buffer: Vec<u8>,
@@ -1701,13 +1840,7 @@ async fn handle_connect_from_buffer(
   }
 
   let target = parts[1];
-  let (target_host, target_port) = if let Some(colon_pos) = target.find(':') {
-    let host = &target[..colon_pos];
-    let port: u16 = target[colon_pos + 1..].parse().unwrap_or(443);
-    (host, port)
-  } else {
-    (target, 443)
-  };
+  let (target_host, target_port) = split_host_port(target).unwrap_or((unbracket(target), 443));
 
   // Block if domain is in the DNS blocklist (before any connection)
   if blocklist_matcher.is_blocked(target_host) {
@@ -1923,8 +2056,8 @@ pub(crate) fn log_throttle(key: &str) -> Option<u64> {
 /// and the terminating CRLFCRLF can arrive with destination payload appended
 /// (those bytes belong to the tunnel). Reads until the header terminator and
 /// returns `(headers, bytes_after_headers)`.
-async fn read_upstream_connect_response(
-  stream: &mut TcpStream,
+async fn read_upstream_connect_response<S: AsyncRead + Unpin>(
+  stream: &mut S,
 ) -> Result<(String, Vec<u8>), Box<dyn std::error::Error>> {
   let mut buffer = Vec::with_capacity(1024);
   let mut chunk = [0u8; 4096];
@@ -1957,6 +2090,167 @@ async fn read_upstream_connect_response(
   }
 }
 
+/// Proxies dialed, in order, ahead of the upstream when the stored proxy is a
+/// chain. Set once per worker from its config; empty for a plain upstream.
+static UPSTREAM_CHAIN: OnceLock<Vec<Url>> = OnceLock::new();
+
+/// Client certificate for an "https" upstream, loaded once per worker from
+/// its config.
+struct UpstreamClientCert {
+  pem: ClientIdentityPem,
+  connector: tokio_native_tls::TlsConnector,
+}
+
+static UPSTREAM_CLIENT_CERT: OnceLock<UpstreamClientCert> = OnceLock::new();
+
+/// Wrap a fresh connection to an "https" upstream in TLS, presenting the
+/// client certificate. Without a certificate the upstream is spoken to in
+/// plain text as before, since many providers label plain CONNECT endpoints
+/// "https".
+async fn upstream_tls_handshake(
+  stream: TcpStream,
+  proxy_host: &str,
+) -> Result<BoxedAsyncStream, Box<dyn std::error::Error>> {
+  let Some(client_cert) = UPSTREAM_CLIENT_CERT.get() else {
+    return Ok(Box::new(stream));
+  };
+  let tls = tokio::time::timeout(
+    UPSTREAM_DIAL_TIMEOUT,
+    client_cert.connector.connect(proxy_host, stream),
+  )
+  .await
+  .map_err(|_| format!("upstream proxy TLS handshake with {proxy_host} timed out"))??;
+  Ok(Box::new(tls))
+}
+
+fn upstream_chain() -> &'static [Url] {
+  UPSTREAM_CHAIN.get().map(Vec::as_slice).unwrap_or(&[])
+}
+
+/// True when this worker reaches its upstream through entry hops. UDP cannot
+/// follow a chain, so the local SOCKS5 server refuses UDP ASSOCIATE then.
+pub(crate) fn upstream_is_chained() -> bool {
+  !upstream_chain().is_empty()
+}
+
+/// `host:port` a chain hop listens on, with the same default ports the
+/// single-upstream dialers use.
+fn chain_hop_address(hop: &Url) -> Result<(String, u16), Box<dyn std::error::Error>> {
+  let host = url_host(hop).ok_or("proxy chain hop has no host")?;
+  let default_port = if hop.scheme() == "socks5" { 1080 } else { 8080 };
+  Ok((host, hop.port().unwrap_or(default_port)))
+}
+
+/// Ask the proxy at the far end of `stream` to open a tunnel to
+/// `target_host:target_port` and return the stream now carrying it. Only HTTP
+/// CONNECT and SOCKS5 CONNECT nest over an existing stream, which is why those
+/// are the only hop types a chain accepts.
+async fn open_tunnel_through_hop(
+  mut stream: BoxedAsyncStream,
+  hop: &Url,
+  target_host: &str,
+  target_port: u16,
+) -> Result<BoxedAsyncStream, Box<dyn std::error::Error>> {
+  match hop.scheme() {
+    "http" | "https" => {
+      let authority = host_port(target_host, target_port);
+      let mut connect_req = format!("CONNECT {authority} HTTP/1.1\r\nHost: {authority}\r\n");
+      let (username, password) = upstream_userpass(hop);
+      if !username.is_empty() {
+        use base64::{engine::general_purpose, Engine as _};
+        let auth = general_purpose::STANDARD.encode(format!("{}:{}", username, password));
+        connect_req.push_str(&format!("Proxy-Authorization: Basic {}\r\n", auth));
+      }
+      connect_req.push_str("\r\n");
+      stream.write_all(connect_req.as_bytes()).await?;
+
+      let (response_headers, coalesced) = read_upstream_connect_response(&mut stream).await?;
+      if !response_headers.starts_with("HTTP/1.1 200")
+        && !response_headers.starts_with("HTTP/1.0 200")
+      {
+        let status_line = response_headers.lines().next().unwrap_or("");
+        return Err(
+          format!(
+            "Chained proxy {} refused CONNECT to {}:{}: {}",
+            redacted_upstream(hop.as_str()),
+            target_host,
+            target_port,
+            status_line
+          )
+          .into(),
+        );
+      }
+      if coalesced.is_empty() {
+        Ok(stream)
+      } else {
+        Ok(Box::new(PrependReader {
+          prepended: coalesced,
+          prepended_pos: 0,
+          inner: stream,
+        }))
+      }
+    }
+    "socks5" => {
+      use async_socks5::{connect, AddrKind, Auth};
+
+      let target_host = unbracket(target_host);
+      let target = if let Ok(ip) = target_host.parse::<std::net::IpAddr>() {
+        AddrKind::Ip(std::net::SocketAddr::new(ip, target_port))
+      } else {
+        AddrKind::Domain(target_host.to_string(), target_port)
+      };
+      let (username, password) = upstream_userpass(hop);
+      let auth = (!username.is_empty()).then_some(Auth { username, password });
+
+      // Buffered for the same reason as `connect_via_socks`: async_socks5
+      // writes every field separately.
+      let mut buffered = tokio::io::BufStream::new(stream);
+      tokio::time::timeout(UPSTREAM_DIAL_TIMEOUT, connect(&mut buffered, target, auth))
+        .await
+        .map_err(|_| "chained SOCKS5 handshake timed out")??;
+      Ok(buffered.into_inner())
+    }
+    other => Err(format!("Unsupported proxy chain hop scheme: {other}").into()),
+  }
+}
+
+/// Dial `target_host:target_port` through `entry_hops` and then `exit`: TCP to
+/// the first hop, then one nested CONNECT per hop addressed to the next hop,
+/// the exit's addressed to the target. The target only ever sees the exit.
+pub(crate) async fn connect_through_chain(
+  entry_hops: &[Url],
+  exit: &Url,
+  target_host: &str,
+  target_port: u16,
+) -> Result<BoxedAsyncStream, Box<dyn std::error::Error>> {
+  let hops: Vec<&Url> = entry_hops.iter().chain(std::iter::once(exit)).collect();
+  let (first_host, first_port) = chain_hop_address(hops[0])?;
+  let first = tokio::time::timeout(
+    UPSTREAM_DIAL_TIMEOUT,
+    TcpStream::connect((first_host.as_str(), first_port)),
+  )
+  .await
+  .map_err(|_| format!("proxy chain connect to {first_host}:{first_port} timed out"))??;
+  let _ = first.set_nodelay(true);
+
+  let mut stream: BoxedAsyncStream = Box::new(first);
+  for (index, hop) in hops.iter().enumerate() {
+    let (next_host, next_port) = match hops.get(index + 1) {
+      Some(next) => chain_hop_address(next)?,
+      None => (target_host.to_string(), target_port),
+    };
+    stream = open_tunnel_through_hop(stream, hop, &next_host, next_port).await?;
+  }
+
+  log::debug!(
+    "Tunnel to {}:{} opened through {} chained proxies",
+    target_host,
+    target_port,
+    hops.len()
+  );
+  Ok(stream)
+}
+
 /// Establish a stream to `target_host:target_port`, either directly or through
 /// the configured upstream proxy. Shared by the HTTP CONNECT path and the
 /// local SOCKS5 server so every upstream type (direct, HTTP/HTTPS CONNECT,
@@ -1978,13 +2272,18 @@ pub(crate) async fn connect_to_target_via_upstream(
     _ if should_bypass => Box::new(dial_direct(target_host, target_port).await?),
     Some(upstream_url_str) => {
       let upstream = Url::parse(upstream_url_str)?;
+      let chain = upstream_chain();
+      if !chain.is_empty() {
+        return connect_through_chain(chain, &upstream, target_host, target_port).await;
+      }
       let scheme = upstream.scheme();
 
       match scheme {
         "http" | "https" => {
-          let proxy_host = upstream.host_str().unwrap_or("127.0.0.1");
+          let proxy_host = url_host(&upstream).unwrap_or_else(|| "127.0.0.1".to_string());
+          let proxy_host = proxy_host.as_str();
           let proxy_port = upstream.port().unwrap_or(8080);
-          let mut proxy_stream = tokio::time::timeout(
+          let tcp_stream = tokio::time::timeout(
             UPSTREAM_DIAL_TIMEOUT,
             TcpStream::connect((proxy_host, proxy_port)),
           )
@@ -1992,12 +2291,15 @@ pub(crate) async fn connect_to_target_via_upstream(
           .map_err(|_| {
             format!("upstream proxy connect to {proxy_host}:{proxy_port} timed out")
           })??;
-          configure_tcp(&proxy_stream);
+          configure_tcp(&tcp_stream);
+          let mut proxy_stream: BoxedAsyncStream = if scheme == "https" {
+            upstream_tls_handshake(tcp_stream, proxy_host).await?
+          } else {
+            Box::new(tcp_stream)
+          };
 
-          let mut connect_req = format!(
-            "CONNECT {}:{} HTTP/1.1\r\nHost: {}:{}\r\n",
-            target_host, target_port, target_host, target_port
-          );
+          let authority = host_port(target_host, target_port);
+          let mut connect_req = format!("CONNECT {authority} HTTP/1.1\r\nHost: {authority}\r\n");
 
           let (username, password) = upstream_userpass(&upstream);
           if !username.is_empty() {
@@ -2038,7 +2340,7 @@ pub(crate) async fn connect_to_target_via_upstream(
           );
 
           if coalesced.is_empty() {
-            Box::new(proxy_stream)
+            proxy_stream
           } else {
             // The upstream packed the destination's first bytes into the same
             // segment as its 200. They are tunnel payload, not proxy protocol:
@@ -2057,9 +2359,9 @@ pub(crate) async fn connect_to_target_via_upstream(
           }
         }
         "socks4" | "socks5" => {
-          let socks_host = upstream.host_str().unwrap_or("127.0.0.1");
+          let socks_host = url_host(&upstream).unwrap_or_else(|| "127.0.0.1".to_string());
           let socks_port = upstream.port().unwrap_or(1080);
-          let socks_addr = format!("{}:{}", socks_host, socks_port);
+          let socks_addr = host_port(&socks_host, socks_port);
 
           let (username, password) = upstream_userpass(&upstream);
           let auth = (!username.is_empty()).then_some((username.as_str(), password.as_str()));
@@ -2078,7 +2380,7 @@ pub(crate) async fn connect_to_target_via_upstream(
           // Shadowsocks: URL format is ss://method:password@host:port
           // where "method" is the cipher (e.g. aes-256-gcm, chacha20-ietf-poly1305)
           // and "password" is the SS server password.
-          let ss_host = upstream.host_str().unwrap_or("127.0.0.1");
+          let ss_host = url_host(&upstream).unwrap_or_else(|| "127.0.0.1".to_string());
           let ss_port = upstream.port().unwrap_or(8388);
 
           // The "username" field carries the cipher method
@@ -2103,14 +2405,13 @@ pub(crate) async fn connect_to_target_via_upstream(
           let context =
             shadowsocks::context::Context::new_shared(shadowsocks::config::ServerType::Local);
           let svr_cfg = shadowsocks::config::ServerConfig::new(
-            shadowsocks::config::ServerAddr::from((ss_host.to_string(), ss_port)),
+            ss_server_addr(&ss_host, ss_port),
             &password,
             cipher,
           )
           .map_err(|e| format!("Invalid Shadowsocks config: {e}"))?;
 
-          let target_addr =
-            shadowsocks::relay::Address::DomainNameAddress(target_host.to_string(), target_port);
+          let target_addr = ss_target_addr(target_host, target_port);
 
           let stream = tokio::time::timeout(
             UPSTREAM_DIAL_TIMEOUT,
@@ -2247,6 +2548,32 @@ mod tests {
     assert_eq!(redacted_upstream(""), "none");
   }
 
+  #[test]
+  fn ipv6_upstreams_are_dialed_without_brackets() {
+    assert_eq!(
+      redacted_upstream("socks5://user:pass@[2001:db8::1]:1080"),
+      "socks5://[2001:db8::1]:1080"
+    );
+
+    let hop = Url::parse("http://[2001:db8::1]:3128").unwrap();
+    let (host, port) = chain_hop_address(&hop).unwrap();
+    assert_eq!((host.as_str(), port), ("2001:db8::1", 3128));
+    assert!(std::net::ToSocketAddrs::to_socket_addrs(&(host.as_str(), port)).is_ok());
+
+    assert!(matches!(
+      ss_server_addr("2001:db8::1", 8388),
+      shadowsocks::config::ServerAddr::SocketAddr(addr) if addr.is_ipv6()
+    ));
+    assert!(matches!(
+      ss_target_addr("[2001:db8::2]", 443),
+      shadowsocks::relay::Address::SocketAddress(addr) if addr.port() == 443
+    ));
+    assert!(matches!(
+      ss_target_addr("example.com", 443),
+      shadowsocks::relay::Address::DomainNameAddress(ref host, 443) if host == "example.com"
+    ));
+  }
+
   #[test]
   fn test_blocklist_exact_match() {
     let mut matcher = BlocklistMatcher::new();
@@ -2443,6 +2770,126 @@ mod tests {
     assert!(result.is_err(), "a CONNECT with no reply must not succeed");
   }
 
+  /// Minimal HTTP CONNECT proxy: records each request line, dials the
+  /// requested address and splices the two sockets together.
+  async fn spawn_mock_connect_proxy() -> (SocketAddr, Arc<Mutex<Vec<String>>>) {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let recorded = seen.clone();
+    tokio::spawn(async move {
+      while let Ok((mut client, _)) = listener.accept().await {
+        let recorded = recorded.clone();
+        tokio::spawn(async move {
+          let (request, _) = read_upstream_connect_response(&mut client).await.unwrap();
+          let request_line = request.lines().next().unwrap_or("").to_string();
+          recorded.lock().unwrap().push(request_line.clone());
+          let target = request_line
+            .split_whitespace()
+            .nth(1)
+            .unwrap_or("")
+            .to_string();
+          let Ok(mut upstream) = TcpStream::connect(target).await else {
+            let _ = client.write_all(b"HTTP/1.1 502 Bad Gateway\r\n\r\n").await;
+            return;
+          };
+          client
+            .write_all(b"HTTP/1.1 200 Connection Established\r\n\r\n")
+            .await
+            .unwrap();
+          let _ = tokio::io::copy_bidirectional(&mut client, &mut upstream).await;
+        });
+      }
+    });
+    (addr, seen)
+  }
+
+  async fn spawn_echo_server() -> SocketAddr {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+      while let Ok((mut socket, _)) = listener.accept().await {
+        tokio::spawn(async move {
+          let (mut reader, mut writer) = socket.split();
+          let _ = tokio::io::copy(&mut reader, &mut writer).await;
+        });
+      }
+    });
+    addr
+  }
+
+  #[tokio::test]
+  async fn connect_through_chain_nests_connect_in_hop_order() {
+    let target = spawn_echo_server().await;
+    let (entry_addr, entry_seen) = spawn_mock_connect_proxy().await;
+    let (exit_addr, exit_seen) = spawn_mock_connect_proxy().await;
+    let entry = Url::parse(&format!("http://{entry_addr}")).unwrap();
+    let exit = Url::parse(&format!("http://{exit_addr}")).unwrap();
+
+    let mut stream = connect_through_chain(&[entry], &exit, "127.0.0.1", target.port())
+      .await
+      .unwrap();
+    stream.write_all(b"ping").await.unwrap();
+    let mut echoed = [0u8; 4];
+    stream.read_exact(&mut echoed).await.unwrap();
+    assert_eq!(&echoed, b"ping");
+
+    // The entry hop is only ever asked for the exit; the exit is the one that
+    // opens the connection to the target.
+    assert_eq!(
+      *entry_seen.lock().unwrap(),
+      vec![format!("CONNECT {exit_addr} HTTP/1.1")]
+    );
+    assert_eq!(
+      *exit_seen.lock().unwrap(),
+      vec![format!("CONNECT 127.0.0.1:{} HTTP/1.1", target.port())]
+    );
+  }
+
+  #[tokio::test]
+  async fn connect_via_upstream_forwards_hostname_unresolved() {
+    let (proxy_addr, seen) = spawn_mock_connect_proxy().await;
+    let upstream = format!("http://{proxy_addr}");
+
+    // `.invalid` never resolves, so reaching the proxy at all shows the worker
+    // didn't try to resolve it first. The mock then fails to dial it.
+    let result = connect_to_target_via_upstream(
+      "remote-dns.invalid",
+      443,
+      Some(&upstream),
+      &BypassMatcher::new(&[]),
+    )
+    .await;
+    assert!(result.is_err());
+    assert_eq!(
+      *seen.lock().unwrap(),
+      vec!["CONNECT remote-dns.invalid:443 HTTP/1.1".to_string()]
+    );
+  }
+
+  #[tokio::test]
+  async fn connect_through_chain_fails_when_a_hop_refuses() {
+    let (entry_addr, _) = spawn_mock_connect_proxy().await;
+    let refusing = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let refusing_addr = refusing.local_addr().unwrap();
+    tokio::spawn(async move {
+      let (mut s, _) = refusing.accept().await.unwrap();
+      let _ = read_upstream_connect_response(&mut s).await;
+      let _ = s
+        .write_all(b"HTTP/1.1 407 Proxy Authentication Required\r\n\r\n")
+        .await;
+      tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+    });
+
+    let entry = Url::parse(&format!("http://{entry_addr}")).unwrap();
+    let exit = Url::parse(&format!("http://{refusing_addr}")).unwrap();
+    let err = connect_through_chain(&[entry], &exit, "example.com", 443)
+      .await
+      .err()
+      .expect("a refused hop must fail the chain");
+    assert!(err.to_string().contains("407"), "{err}");
+  }
+
   #[tokio::test]
   async fn read_http_response_buffer_caps_endless_header_stream() {
     let (mut writer, mut reader) = tokio::io::duplex(16 * 1024);
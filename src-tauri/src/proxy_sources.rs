@@ -0,0 +1,539 @@
+//! Proxy lists imported from a URL and kept up to date. Each refresh fetches
+//! the list, parses it like a pasted TXT import and diffs it against the
+//! proxies previously imported from the same source (tracked through
+//! `StoredProxy::source_id`): new entries are added, entries that reappeared
+//! lose their stale mark, and vanished ones are deleted, or only marked stale
+//! while a profile still uses them.
+
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::browser::ProxySettings;
+use crate::proxy_manager::{now_secs, ProxyManager, ProxyParseResult, StoredProxy, PROXY_MANAGER};
+
+/// How often the background task looks for sources that are due.
+pub const PROXY_SOURCE_CHECK_INTERVAL_SECS: u64 = 60;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProxySource {
+  pub id: String,
+  pub url: String,
+  /// Imported proxies are named `"{name_prefix} {host}:{port}"`.
+  pub name_prefix: String,
+  /// Minutes between automatic refreshes; `None` refreshes only on demand.
+  #[serde(default)]
+  pub refresh_interval_mins: Option<u32>,
+  /// Unix seconds of the last refresh attempt, successful or not.
+  #[serde(default)]
+  pub last_refreshed_at: Option<u64>,
+  /// Why the last refresh attempt failed, if it did.
+  #[serde(default)]
+  pub last_error: Option<String>,
+  pub created_at: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProxySourceLineError {
+  pub line: String,
+  pub reason: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProxySourceRefreshResult {
+  pub source_id: String,
+  pub added: usize,
+  pub removed: usize,
+  pub stale: usize,
+  pub unchanged: usize,
+  pub errors: Vec<ProxySourceLineError>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Default)]
+struct ProxySourcesData {
+  sources: Vec<ProxySource>,
+}
+
+/// What a refresh changes, computed before anything is written.
+#[derive(Debug, Default)]
+struct RefreshPlan {
+  add: Vec<ProxySettings>,
+  /// Stale proxies that are listed again.
+  revive: Vec<String>,
+  /// Vanished proxies still used by a profile.
+  mark_stale: Vec<String>,
+  /// Vanished proxies nobody uses.
+  remove: Vec<String>,
+  unchanged: usize,
+}
+
+lazy_static::lazy_static! {
+  static ref HTTP_CLIENT: reqwest::Client = reqwest::Client::builder()
+    .timeout(Duration::from_secs(30))
+    .build()
+    .expect("Failed to create HTTP client");
+  // A manual refresh racing the background task would import the same new
+  // proxies twice.
+  static ref REFRESH_LOCK: tokio::sync::Mutex<()> = tokio::sync::Mutex::new(());
+}
+
+fn sources_file_path() -> std::path::PathBuf {
+  crate::app_dirs::data_subdir().join("proxy_sources.json")
+}
+
+fn load_sources() -> Vec<ProxySource> {
+  match fs::read_to_string(sources_file_path()) {
+    Ok(content) => serde_json::from_str::<ProxySourcesData>(&content)
+      .map(|data| data.sources)
+      .unwrap_or_default(),
+    Err(_) => Vec::new(),
+  }
+}
+
+fn save_sources(sources: &[ProxySource]) -> Result<(), String> {
+  let path = sources_file_path();
+  if let Some(parent) = path.parent() {
+    fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+  }
+  let json = serde_json::to_string_pretty(&ProxySourcesData {
+    sources: sources.to_vec(),
+  })
+  .map_err(|e| e.to_string())?;
+  fs::write(path, json).map_err(|e| e.to_string())
+}
+
+fn update_source(source_id: &str, update: impl FnOnce(&mut ProxySource)) -> Result<(), String> {
+  let mut sources = load_sources();
+  let source = sources
+    .iter_mut()
+    .find(|s| s.id == source_id)
+    .ok_or_else(|| format!("Proxy source '{source_id}' not found"))?;
+  update(source);
+  save_sources(&sources)
+}
+
+fn is_due(source: &ProxySource, now: u64) -> bool {
+  let Some(mins) = source.refresh_interval_mins else {
+    return false;
+  };
+  source
+    .last_refreshed_at
+    .is_none_or(|last| now.saturating_sub(last) >= u64::from(mins) * 60)
+}
+
+/// Split parse results into proxies and per-line errors. Ambiguous lines are
+/// errors here: there is nobody to pick a format during a background refresh.
+fn collect_parsed(
+  results: Vec<ProxyParseResult>,
+) -> (Vec<ProxySettings>, Vec<ProxySourceLineError>) {
+  let mut proxies = Vec::new();
+  let mut errors = Vec::new();
+  for result in results {
+    match result {
+      ProxyParseResult::Parsed(parsed) => proxies.push(ProxySettings {
+        proxy_type: parsed.proxy_type,
+        host: parsed.host,
+        port: parsed.port,
+        username: parsed.username,
+        password: parsed.password,
+        client_cert_path: None,
+        client_key_path: None,
+      }),
+      ProxyParseResult::Ambiguous {
+        line,
+        possible_formats,
+      } => errors.push(ProxySourceLineError {
+        line,
+        reason: format!("Ambiguous format: {}", possible_formats.join(", ")),
+      }),
+      ProxyParseResult::Invalid { line, reason } => {
+        errors.push(ProxySourceLineError { line, reason })
+      }
+    }
+  }
+  (proxies, errors)
+}
+
+/// Diff the fetched list against the proxies already imported from the
+/// source. Proxies are matched on their full URL, credentials included.
+fn plan_refresh(
+  existing: &[StoredProxy],
+  fetched: Vec<ProxySettings>,
+  referenced: &HashSet<String>,
+) -> RefreshPlan {
+  let mut plan = RefreshPlan::default();
+  let mut fetched_by_url: HashMap<String, ProxySettings> = HashMap::new();
+  let mut fetched_order = Vec::new();
+  for settings in fetched {
+    let url = ProxyManager::build_proxy_url(&settings);
+    if !fetched_by_url.contains_key(&url) {
+      fetched_order.push(url.clone());
+      fetched_by_url.insert(url, settings);
+    }
+  }
+
+  for proxy in existing {
+    let url = ProxyManager::build_proxy_url(&proxy.proxy_settings);
+    if fetched_by_url.remove(&url).is_some() {
+      if proxy.is_stale {
+        plan.revive.push(proxy.id.clone());
+      } else {
+        plan.unchanged += 1;
+      }
+    } else if referenced.contains(&proxy.id) {
+      plan.mark_stale.push(proxy.id.clone());
+    } else {
+      plan.remove.push(proxy.id.clone());
+    }
+  }
+
+  plan.add = fetched_order
+    .into_iter()
+    .filter_map(|url| fetched_by_url.remove(&url))
+    .collect();
+  plan
+}
+
+fn unique_name(base: String, taken: &mut HashSet<String>) -> String {
+  let mut name = base.clone();
+  let mut n = 2;
+  while taken.contains(&name) {
+    name = format!("{base} ({n})");
+    n += 1;
+  }
+  taken.insert(name.clone());
+  name
+}
+
+pub async fn refresh_source(
+  app_handle: &crate::app_handle::AppHandle,
+  source_id: &str,
+) -> Result<ProxySourceRefreshResult, String> {
+  let _guard = REFRESH_LOCK.lock().await;
+
+  let source = load_sources()
+    .into_iter()
+    .find(|s| s.id == source_id)
+    .ok_or_else(|| format!("Proxy source '{source_id}' not found"))?;
+
+  let body = match fetch_list(&source.url).await {
+    Ok(body) => body,
+    Err(e) => {
+      update_source(source_id, |s| {
+        s.last_refreshed_at = Some(now_secs());
+        s.last_error = Some(e.clone());
+      })?;
+      return Err(e);
+    }
+  };
+
+  let (fetched, errors) = collect_parsed(ProxyManager::parse_txt_proxies(&body));
+  let stored = PROXY_MANAGER.get_stored_proxies();
+  let existing: Vec<StoredProxy> = stored
+    .iter()
+    .filter(|p| p.source_id.as_deref() == Some(source_id))
+    .cloned()
+    .collect();
+  let referenced: HashSet<String> = crate::profile::ProfileManager::instance()
+    .list_profiles()
+    .map_err(|e| format!("Failed to list profiles: {e}"))?
+    .into_iter()
+    .filter_map(|p| p.proxy_id)
+    .collect();
+
+  let plan = plan_refresh(&existing, fetched, &referenced);
+  let mut taken: HashSet<String> = stored.into_iter().map(|p| p.name).collect();
+  let mut added = 0;
+  for settings in plan.add {
+    let name = unique_name(
+      format!(
+        "{} {}",
+        source.name_prefix,
+        crate::ip_utils::host_port(&settings.host, settings.port)
+      ),
+      &mut taken,
+    );
+    let proxy = PROXY_MANAGER.create_stored_proxy(app_handle, name, settings)?;
+    PROXY_MANAGER.set_stored_proxy_source_state(&proxy.id, Some(source_id.to_string()), false)?;
+    added += 1;
+  }
+  for id in &plan.revive {
+    PROXY_MANAGER.set_stored_proxy_source_state(id, Some(source_id.to_string()), false)?;
+  }
+  for id in &plan.mark_stale {
+    PROXY_MANAGER.set_stored_proxy_source_state(id, Some(source_id.to_string()), true)?;
+  }
+  for id in &plan.remove {
+    PROXY_MANAGER.delete_stored_proxy(app_handle, id)?;
+  }
+
+  update_source(source_id, |s| {
+    s.last_refreshed_at = Some(now_secs());
+    s.last_error = None;
+  })?;
+
+  if let Err(e) = crate::events::emit_empty("stored-proxies-changed") {
+    log::error!("Failed to emit stored-proxies-changed event: {e}");
+  }
+
+  log::info!(
+    "Refreshed proxy source {source_id}: {added} added, {} removed, {} stale",
+    plan.remove.len(),
+    plan.mark_stale.len()
+  );
+
+  Ok(ProxySourceRefreshResult {
+    source_id: source_id.to_string(),
+    added,
+    removed: plan.remove.len(),
+    stale: plan.mark_stale.len(),
+    unchanged: plan.unchanged + plan.revive.len(),
+    errors,
+  })
+}
+
+async fn fetch_list(url: &str) -> Result<String, String> {
+  let response = HTTP_CLIENT
+    .get(url)
+    .send()
+    .await
+    .map_err(|e| format!("Failed to fetch proxy list: {e}"))?;
+  if !response.status().is_success() {
+    return Err(format!("HTTP {} when fetching {url}", response.status()));
+  }
+  response
+    .text()
+    .await
+    .map_err(|e| format!("Failed to read proxy list: {e}"))
+}
+
+/// Refresh every source whose interval has elapsed. Called periodically from
+/// the background task.
+pub async fn refresh_due_sources(app_handle: &crate::app_handle::AppHandle) {
+  if crate::offline::is_offline() {
+    return;
+  }
+  let now = now_secs();
+  let due: Vec<String> = load_sources()
+    .into_iter()
+    .filter(|s| is_due(s, now))
+    .map(|s| s.id)
+    .collect();
+  for id in due {
+    if let Err(e) = refresh_source(app_handle, &id).await {
+      log::warn!("Failed to refresh proxy source {id}: {e}");
+    }
+  }
+}
+
+fn add_source(
+  url: String,
+  name_prefix: String,
+  refresh_interval_mins: Option<u32>,
+) -> Result<ProxySource, String> {
+  let url = url.trim().to_string();
+  let valid_url = url::Url::parse(&url)
+    .map(|u| matches!(u.scheme(), "http" | "https"))
+    .unwrap_or(false);
+  if !valid_url {
+    return Err(serde_json::json!({ "code": "PROXY_SOURCE_INVALID_URL" }).to_string());
+  }
+  let name_prefix = name_prefix.trim().to_string();
+  if name_prefix.is_empty() {
+    return Err(serde_json::json!({ "code": "NAME_CANNOT_BE_EMPTY" }).to_string());
+  }
+
+  let source = ProxySource {
+    id: uuid::Uuid::new_v4().to_string(),
+    url,
+    name_prefix,
+    refresh_interval_mins: refresh_interval_mins.filter(|mins| *mins > 0),
+    last_refreshed_at: None,
+    last_error: None,
+    created_at: now_secs(),
+  };
+  let mut sources = load_sources();
+  sources.push(source.clone());
+  save_sources(&sources)?;
+  Ok(source)
+}
+
+/// Forget a source. Its proxies are kept as ordinary proxies.
+fn remove_source(source_id: &str) -> Result<(), String> {
+  let mut sources = load_sources();
+  let before = sources.len();
+  sources.retain(|s| s.id != source_id);
+  if sources.len() == before {
+    return Err(format!("Proxy source '{source_id}' not found"));
+  }
+  save_sources(&sources)?;
+
+  for proxy in PROXY_MANAGER.get_stored_proxies() {
+    if proxy.source_id.as_deref() == Some(source_id) {
+      PROXY_MANAGER.set_stored_proxy_source_state(&proxy.id, None, proxy.is_stale)?;
+    }
+  }
+  if let Err(e) = crate::events::emit_empty("stored-proxies-changed") {
+    log::error!("Failed to emit stored-proxies-changed event: {e}");
+  }
+  Ok(())
+}
+
+#[tauri::command]
+pub fn list_proxy_sources() -> Vec<ProxySource> {
+  load_sources()
+}
+
+#[tauri::command]
+pub fn add_proxy_source(
+  url: String,
+  name_prefix: String,
+  refresh_interval_mins: Option<u32>,
+) -> Result<ProxySource, String> {
+  add_source(url, name_prefix, refresh_interval_mins)
+}
+
+#[tauri::command]
+pub async fn refresh_proxy_source(
+  app_handle: crate::app_handle::AppHandle,
+  source_id: String,
+) -> Result<ProxySourceRefreshResult, String> {
+  refresh_source(&app_handle, &source_id).await
+}
+
+#[tauri::command]
+pub fn remove_proxy_source(source_id: String) -> Result<(), String> {
+  remove_source(&source_id)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn settings(host: &str, port: u16) -> ProxySettings {
+    ProxySettings {
+      proxy_type: "http".to_string(),
+      host: host.to_string(),
+      port,
+      username: None,
+      password: None,
+      client_cert_path: None,
+      client_key_path: None,
+    }
+  }
+
+  fn imported(host: &str, port: u16, is_stale: bool) -> StoredProxy {
+    let mut proxy = StoredProxy::new(format!("Src {host}:{port}"), settings(host, port));
+    proxy.source_id = Some("src".to_string());
+    proxy.is_stale = is_stale;
+    proxy
+  }
+
+  #[test]
+  fn plan_adds_new_and_keeps_listed_proxies() {
+    let kept = imported("a.example", 8080, false);
+    let plan = plan_refresh(
+      std::slice::from_ref(&kept),
+      vec![
+        settings("a.example", 8080),
+        settings("b.example", 8080),
+        settings("b.example", 8080),
+      ],
+      &HashSet::new(),
+    );
+    let added: Vec<&str> = plan.add.iter().map(|s| s.host.as_str()).collect();
+    assert_eq!(added, ["b.example"]);
+    assert_eq!(plan.unchanged, 1);
+    assert!(plan.remove.is_empty() && plan.mark_stale.is_empty());
+  }
+
+  #[test]
+  fn plan_marks_referenced_vanished_proxies_stale_and_removes_the_rest() {
+    let used = imported("used.example", 1080, false);
+    let unused = imported("unused.example", 1080, false);
+    let back = imported("back.example", 1080, true);
+    let referenced = HashSet::from([used.id.clone()]);
+    let plan = plan_refresh(
+      &[used.clone(), unused.clone(), back.clone()],
+      vec![settings("back.example", 1080)],
+      &referenced,
+    );
+    assert_eq!(plan.mark_stale, vec![used.id]);
+    assert_eq!(plan.remove, vec![unused.id]);
+    assert_eq!(plan.revive, vec![back.id]);
+    assert!(plan.add.is_empty());
+  }
+
+  #[test]
+  fn collects_per_line_errors() {
+    let (proxies, errors) = collect_parsed(ProxyManager::parse_txt_proxies(
+      "http://one.example:8080\nnot a proxy\n# comment\n",
+    ));
+    assert_eq!(proxies.len(), 1);
+    assert_eq!(proxies[0].host, "one.example");
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].line, "not a proxy");
+  }
+
+  #[test]
+  fn unique_name_appends_a_counter() {
+    let mut taken = HashSet::from(["Src h:1".to_string()]);
+    assert_eq!(
+      unique_name("Src h:1".to_string(), &mut taken),
+      "Src h:1 (2)"
+    );
+    assert_eq!(
+      unique_name("Src h:1".to_string(), &mut taken),
+      "Src h:1 (3)"
+    );
+  }
+
+  #[test]
+  fn sources_persist_and_validate_urls() {
+    let temp = tempfile::TempDir::new().unwrap();
+    let _guard = crate::app_dirs::set_test_data_dir(temp.path().to_path_buf());
+
+    let err = add_source(
+      "ftp://lists.example/p.txt".to_string(),
+      "List".to_string(),
+      None,
+    )
+    .unwrap_err();
+    assert!(err.contains("PROXY_SOURCE_INVALID_URL"));
+
+    let source = add_source(
+      " https://lists.example/p.txt ".to_string(),
+      "List".to_string(),
+      Some(0),
+    )
+    .unwrap();
+    assert_eq!(source.url, "https://lists.example/p.txt");
+    assert_eq!(source.refresh_interval_mins, None);
+
+    let listed = load_sources();
+    assert_eq!(listed.len(), 1);
+    assert_eq!(listed[0].id, source.id);
+  }
+
+  #[test]
+  fn only_sources_with_an_elapsed_interval_are_due() {
+    let mut source = ProxySource {
+      id: "s".to_string(),
+      url: "https://lists.example/p.txt".to_string(),
+      name_prefix: "List".to_string(),
+      refresh_interval_mins: None,
+      last_refreshed_at: None,
+      last_error: None,
+      created_at: 0,
+    };
+    assert!(!is_due(&source, 10_000));
+    source.refresh_interval_mins = Some(30);
+    assert!(is_due(&source, 10_000));
+    source.last_refreshed_at = Some(10_000 - 29 * 60);
+    assert!(!is_due(&source, 10_000));
+    source.last_refreshed_at = Some(10_000 - 30 * 60);
+    assert!(is_due(&source, 10_000));
+  }
+}
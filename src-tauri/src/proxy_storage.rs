@@ -1,3 +1,4 @@
+use crate::proxy_client_cert::ClientCertPaths;
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::PathBuf;
@@ -32,6 +33,13 @@ pub struct ProxyConfig {
   /// it is `None`).
   #[serde(default)]
   pub browser_pid: Option<u32>,
+  /// Proxy URLs dialed, in order, before `upstream_url` when the stored proxy
+  /// is a chain. `upstream_url` is always the exit hop.
+  #[serde(default)]
+  pub upstream_chain: Vec<String>,
+  /// Client certificate presented to an "https" upstream.
+  #[serde(default)]
+  pub client_cert: Option<ClientCertPaths>,
 }
 
 impl ProxyConfig {
@@ -49,6 +57,8 @@ impl ProxyConfig {
       dns_allowlist_mode: false,
       local_protocol: None,
       browser_pid: None,
+      upstream_chain: Vec::new(),
+      client_cert: None,
     }
   }
 
@@ -77,6 +87,16 @@ impl ProxyConfig {
     self
   }
 
+  pub fn with_upstream_chain(mut self, upstream_chain: Vec<String>) -> Self {
+    self.upstream_chain = upstream_chain;
+    self
+  }
+
+  pub fn with_client_cert(mut self, client_cert: Option<ClientCertPaths>) -> Self {
+    self.client_cert = client_cert;
+    self
+  }
+
   /// "socks5" or "http" (default). Lowercased for case-insensitive matching.
   pub fn local_protocol_or_default(&self) -> String {
     self
@@ -104,9 +124,7 @@ pub fn build_proxy_url(
   } else if let Some(user) = username {
     url.push_str(&format!("{}@", urlencoding::encode(user)));
   }
-  url.push_str(host);
-  url.push(':');
-  url.push_str(&port.to_string());
+  url.push_str(&crate::ip_utils::host_port(host, port));
   url
 }
 
@@ -203,18 +221,9 @@ pub fn generate_proxy_id() -> String {
 }
 
 pub fn is_process_running(pid: u32) -> bool {
-  use sysinfo::{ProcessRefreshKind, ProcessesToUpdate, System};
-  let pid = sysinfo::Pid::from_u32(pid);
-  // Refresh only the queried PID with the minimal refresh kind: this is a
-  // pure existence check, and callers (worker supervisors every 15s, GUI
-  // cleanup loops) must not pay for a full system process-table scan.
-  let mut system = System::new();
-  system.refresh_processes_specifics(
-    ProcessesToUpdate::Some(&[pid]),
-    true,
-    ProcessRefreshKind::nothing(),
-  );
-  system.process(pid).is_some()
+  // Pure existence check against the shared process table: callers (worker
+  // supervisors every 15s, GUI cleanup loops) must not pay for a full scan.
+  crate::process_registry::process_exists(pid)
 }
 
 #[cfg(test)]
@@ -0,0 +1,479 @@
+//! Priority and memory limits for launched browsers. Both cover the processes
+//! the browser spawns, not just the main one:
+//!
+//! - Priority is a nice value set before exec on Linux and macOS (inherited by
+//!   every child), and a priority class on Windows, held for the whole tree by
+//!   the Job Object.
+//! - Memory limits use a transient systemd scope on Linux, falling back to a
+//!   cgroup created directly in cgroupfs when the systemd user manager is not
+//!   reachable. Windows uses a Job Object. macOS has no equivalent.
+//!
+//! Anything that can't be applied is reported in [`AppliedResourceLimits`]
+//! instead of failing the launch.
+
+use std::path::Path;
+use tokio::process::Command as TokioCommand;
+
+use crate::profile::types::{
+  AppliedResourceLimits, BrowserProfile, ProcessPriority, ResourceLimits, MIN_MEMORY_LIMIT_MB,
+};
+use crate::settings_manager::SettingsManager;
+
+pub fn validate(limits: &ResourceLimits) -> Result<(), String> {
+  match limits.memory_limit_mb {
+    Some(mb) if mb < MIN_MEMORY_LIMIT_MB => Err(
+      serde_json::json!({
+        "code": "INVALID_MEMORY_LIMIT",
+        "params": { "min": MIN_MEMORY_LIMIT_MB }
+      })
+      .to_string(),
+    ),
+    _ => Ok(()),
+  }
+}
+
+/// The profile's limits, with unset fields taken from the app settings.
+pub fn effective_limits(profile: &BrowserProfile) -> ResourceLimits {
+  let defaults = SettingsManager::instance()
+    .load_settings()
+    .map(|s| s.default_resource_limits)
+    .unwrap_or_default();
+  profile.resource_limits.or(defaults)
+}
+
+#[cfg(unix)]
+fn nice_value(priority: ProcessPriority) -> libc::c_int {
+  match priority {
+    ProcessPriority::Low => 10,
+    ProcessPriority::Normal => 0,
+    ProcessPriority::High => -5,
+  }
+}
+
+#[cfg(target_os = "linux")]
+fn cpu_weight(priority: Option<ProcessPriority>) -> u32 {
+  match priority {
+    Some(ProcessPriority::Low) => 50,
+    Some(ProcessPriority::High) => 200,
+    _ => 100,
+  }
+}
+
+/// How a launch's memory limit gets enforced on Linux.
+#[cfg(target_os = "linux")]
+enum LinuxCgroup {
+  /// `systemd-run --user --scope` wraps the browser command.
+  SystemdScope { unit: String },
+  /// The child moves itself into this cgroup before exec.
+  Cgroupfs { dir: std::path::PathBuf },
+}
+
+/// Limits resolved for one launch. Build the browser command with
+/// [`LaunchLimits::command`], call [`LaunchLimits::apply_after_spawn`] right
+/// after spawning it, and [`LaunchLimits::report`] once it is up.
+pub struct LaunchLimits {
+  limits: ResourceLimits,
+  errors: Vec<String>,
+  #[cfg(target_os = "linux")]
+  cgroup: Option<LinuxCgroup>,
+  #[cfg(windows)]
+  job_applied: bool,
+  #[cfg(windows)]
+  priority_applied: bool,
+}
+
+impl LaunchLimits {
+  pub async fn prepare(profile: &BrowserProfile) -> LaunchLimits {
+    let limits = effective_limits(profile);
+    #[allow(unused_mut)]
+    let mut launch = LaunchLimits {
+      limits,
+      errors: Vec::new(),
+      #[cfg(target_os = "linux")]
+      cgroup: None,
+      #[cfg(windows)]
+      job_applied: false,
+      #[cfg(windows)]
+      priority_applied: false,
+    };
+
+    if let Some(mb) = limits.memory_limit_mb {
+      #[cfg(not(target_os = "linux"))]
+      let _ = mb;
+      #[cfg(target_os = "linux")]
+      {
+        let id = profile.id.to_string();
+        if systemd_run_available().await {
+          let suffix = uuid::Uuid::new_v4().simple().to_string();
+          launch.cgroup = Some(LinuxCgroup::SystemdScope {
+            unit: format!("donutbrowser-{id}-{}", &suffix[..8]),
+          });
+        } else {
+          match create_cgroup(&id, mb, cpu_weight(limits.priority)) {
+            Ok(dir) => launch.cgroup = Some(LinuxCgroup::Cgroupfs { dir }),
+            Err(e) => {
+              log::warn!("Memory limit for profile {id} not applied: {e}");
+              launch.errors.push(e);
+            }
+          }
+        }
+      }
+      #[cfg(target_os = "macos")]
+      launch
+        .errors
+        .push("Memory limits are not supported on macOS".to_string());
+    }
+
+    launch
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.limits.is_empty()
+  }
+
+  /// The command that starts `executable` with `args` under these limits.
+  pub fn command(&self, executable: &Path, args: &[String]) -> TokioCommand {
+    #[cfg(target_os = "linux")]
+    let mut command = match (&self.cgroup, self.limits.memory_limit_mb) {
+      (Some(LinuxCgroup::SystemdScope { unit }), Some(mb)) => {
+        let mut command = TokioCommand::new("systemd-run");
+        command
+          .args(["--user", "--scope", "--quiet", "--collect"])
+          .arg(format!("--unit={unit}"))
+          .arg("-p")
+          .arg(format!("MemoryMax={mb}M"))
+          .arg("-p")
+          .arg(format!("CPUWeight={}", cpu_weight(self.limits.priority)))
+          .arg("--")
+          .arg(executable)
+          .args(args);
+        command
+      }
+      _ => {
+        let mut command = TokioCommand::new(executable);
+        command.args(args);
+        command
+      }
+    };
+    #[cfg(not(target_os = "linux"))]
+    let mut command = {
+      let mut command = TokioCommand::new(executable);
+      command.args(args);
+      command
+    };
+
+    #[cfg(unix)]
+    {
+      // Its own process group, so stopping the profile can signal every
+      // renderer and helper at once; see `process_tree::kill_tree`.
+      command.process_group(0);
+
+      let nice = self
+        .limits
+        .priority
+        .map(nice_value)
+        .filter(|nice| *nice != 0);
+      #[cfg(target_os = "linux")]
+      let procs = match &self.cgroup {
+        Some(LinuxCgroup::Cgroupfs { dir }) => {
+          std::ffi::CString::new(dir.join("cgroup.procs").as_os_str().as_encoded_bytes()).ok()
+        }
+        _ => None,
+      };
+      #[cfg(not(target_os = "linux"))]
+      let procs: Option<std::ffi::CString> = None;
+
+      if nice.is_some() || procs.is_some() {
+        // Runs in the child between fork and exec, so everything the browser
+        // later spawns inherits the nice value and the cgroup. Only
+        // async-signal-safe calls here; failures are picked up by `report`.
+        unsafe {
+          command.pre_exec(move || {
+            if let Some(nice) = nice {
+              let _ = libc::setpriority(libc::PRIO_PROCESS, 0, nice);
+            }
+            if let Some(procs) = &procs {
+              let fd = libc::open(procs.as_ptr(), libc::O_WRONLY);
+              if fd >= 0 {
+                let _ = libc::write(fd, b"0".as_ptr().cast(), 1);
+                let _ = libc::close(fd);
+              }
+            }
+            Ok(())
+          });
+        }
+      }
+    }
+
+    command
+  }
+
+  /// Puts the new browser into a Job Object on Windows. Processes it starts
+  /// before this runs stay outside the job, so call it straight after spawn.
+  pub fn apply_after_spawn(&mut self, _pid: Option<u32>) {
+    #[cfg(windows)]
+    if let Some(pid) = _pid {
+      if !self.is_empty() {
+        match assign_job_object(pid, &self.limits) {
+          Ok(()) => {
+            self.job_applied = self.limits.memory_limit_mb.is_some();
+            self.priority_applied = self.limits.priority.is_some();
+          }
+          Err(e) => {
+            log::warn!("Failed to apply resource limits to browser {pid}: {e}");
+            self.errors.push(e);
+          }
+        }
+      }
+    }
+  }
+
+  /// What ended up enforced on the running browser `pid`. `None` when no
+  /// limits were requested.
+  pub fn report(self, pid: Option<u32>) -> Option<AppliedResourceLimits> {
+    if self.is_empty() {
+      return None;
+    }
+    let mut report = AppliedResourceLimits {
+      priority: self.limits.priority,
+      memory_limit_mb: self.limits.memory_limit_mb,
+      errors: self.errors,
+      ..Default::default()
+    };
+
+    #[cfg(unix)]
+    if let (Some(priority), Some(pid)) = (self.limits.priority, pid) {
+      let expected = if priority == ProcessPriority::Normal {
+        current_nice(None)
+      } else {
+        Some(nice_value(priority))
+      };
+      report.priority_applied = expected.is_some() && current_nice(Some(pid)) == expected;
+      if !report.priority_applied {
+        report.errors.push(format!(
+          "Could not set the nice value for {priority:?} priority"
+        ));
+      }
+    }
+
+    #[cfg(target_os = "linux")]
+    if let (Some(cgroup), Some(pid)) = (&self.cgroup, pid) {
+      let (mechanism, name) = match cgroup {
+        LinuxCgroup::SystemdScope { unit } => ("systemd_scope", format!("{unit}.scope")),
+        LinuxCgroup::Cgroupfs { dir } => (
+          "cgroupfs",
+          dir
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default(),
+        ),
+      };
+      let joined = std::fs::read_to_string(format!("/proc/{pid}/cgroup"))
+        .map(|c| {
+          c.lines()
+            .filter_map(|l| l.strip_prefix("0::"))
+            .any(|path| path.trim_end().ends_with(&format!("/{name}")))
+        })
+        .unwrap_or(false);
+      if joined {
+        report.memory_limit_applied = true;
+        report.mechanism = Some(mechanism.to_string());
+      } else {
+        report
+          .errors
+          .push(format!("The browser did not join cgroup {name}"));
+      }
+    }
+
+    #[cfg(windows)]
+    {
+      let _ = pid;
+      report.priority_applied = self.priority_applied;
+      if self.job_applied {
+        report.memory_limit_applied = true;
+        report.mechanism = Some("job_object".to_string());
+      }
+    }
+
+    Some(report)
+  }
+}
+
+/// Nice value of `pid`, or of this process when `None`.
+#[cfg(unix)]
+fn current_nice(pid: Option<u32>) -> Option<libc::c_int> {
+  // -1 is a valid nice value, so errors are told apart through errno.
+  nix::errno::Errno::clear();
+  let nice = unsafe { libc::getpriority(libc::PRIO_PROCESS, pid.unwrap_or(0) as libc::id_t) };
+  if nice == -1 && nix::errno::Errno::last_raw() != 0 {
+    None
+  } else {
+    Some(nice)
+  }
+}
+
+/// Whether `systemd-run --user --scope` works here, probed once per run.
+#[cfg(target_os = "linux")]
+async fn systemd_run_available() -> bool {
+  static AVAILABLE: tokio::sync::OnceCell<bool> = tokio::sync::OnceCell::const_new();
+  *AVAILABLE
+    .get_or_init(|| async {
+      let available = TokioCommand::new("systemd-run")
+        .args(["--user", "--scope", "--quiet", "--collect", "true"])
+        .stdin(std::process::Stdio::null())
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .status()
+        .await
+        .map(|s| s.success())
+        .unwrap_or(false);
+      log::info!("systemd-run user scopes available: {available}");
+      available
+    })
+    .await
+}
+
+/// Creates `donutbrowser-<profile id>` next to this process's own cgroup,
+/// which needs cgroup v2 with the memory controller delegated to the parent.
+/// A leftover cgroup from the profile's previous launch is reused.
+#[cfg(target_os = "linux")]
+fn create_cgroup(
+  profile_id: &str,
+  memory_limit_mb: u64,
+  cpu_weight: u32,
+) -> Result<std::path::PathBuf, String> {
+  let root = Path::new("/sys/fs/cgroup");
+  let own = std::fs::read_to_string("/proc/self/cgroup")
+    .map_err(|e| format!("Failed to read /proc/self/cgroup: {e}"))?;
+  let own_path = own
+    .lines()
+    .find_map(|l| l.strip_prefix("0::"))
+    .ok_or_else(|| "cgroup v2 is not available".to_string())?;
+  let own_dir = root.join(own_path.trim().trim_start_matches('/'));
+  let parent = own_dir
+    .parent()
+    .filter(|p| p.starts_with(root))
+    .ok_or_else(|| "This process runs in the root cgroup".to_string())?;
+
+  let controllers = std::fs::read_to_string(parent.join("cgroup.subtree_control"))
+    .map_err(|e| format!("Failed to read cgroup controllers: {e}"))?;
+  if !controllers.split_whitespace().any(|c| c == "memory") {
+    return Err("The memory cgroup controller is not delegated to this app".to_string());
+  }
+
+  let dir = parent.join(format!("donutbrowser-{profile_id}"));
+  std::fs::create_dir_all(&dir).map_err(|e| format!("Failed to create {}: {e}", dir.display()))?;
+  std::fs::write(
+    dir.join("memory.max"),
+    (memory_limit_mb * 1024 * 1024).to_string(),
+  )
+  .map_err(|e| format!("Failed to set memory.max: {e}"))?;
+  if controllers.split_whitespace().any(|c| c == "cpu") {
+    let _ = std::fs::write(dir.join("cpu.weight"), cpu_weight.to_string());
+  }
+  Ok(dir)
+}
+
+#[cfg(windows)]
+fn assign_job_object(pid: u32, limits: &ResourceLimits) -> Result<(), String> {
+  use windows::core::PCWSTR;
+  use windows::Win32::Foundation::CloseHandle;
+  use windows::Win32::System::JobObjects::{
+    AssignProcessToJobObject, CreateJobObjectW, JobObjectExtendedLimitInformation,
+    SetInformationJobObject, JOBOBJECT_EXTENDED_LIMIT_INFORMATION, JOB_OBJECT_LIMIT_JOB_MEMORY,
+    JOB_OBJECT_LIMIT_PRIORITY_CLASS,
+  };
+  use windows::Win32::System::Threading::{
+    OpenProcess, SetPriorityClass, ABOVE_NORMAL_PRIORITY_CLASS, BELOW_NORMAL_PRIORITY_CLASS,
+    NORMAL_PRIORITY_CLASS, PROCESS_SET_INFORMATION, PROCESS_SET_QUOTA, PROCESS_TERMINATE,
+  };
+
+  let priority_class = limits.priority.map(|p| match p {
+    ProcessPriority::Low => BELOW_NORMAL_PRIORITY_CLASS,
+    ProcessPriority::Normal => NORMAL_PRIORITY_CLASS,
+    ProcessPriority::High => ABOVE_NORMAL_PRIORITY_CLASS,
+  });
+
+  unsafe {
+    let process = OpenProcess(
+      PROCESS_SET_QUOTA | PROCESS_TERMINATE | PROCESS_SET_INFORMATION,
+      false,
+      pid,
+    )
+    .map_err(|e| format!("Failed to open browser process: {e}"))?;
+
+    let result = (|| {
+      if let Some(class) = priority_class {
+        SetPriorityClass(process, class).map_err(|e| format!("Failed to set priority: {e}"))?;
+      }
+
+      let job = CreateJobObjectW(None, PCWSTR::null())
+        .map_err(|e| format!("Failed to create Job Object: {e}"))?;
+      let mut info = JOBOBJECT_EXTENDED_LIMIT_INFORMATION::default();
+      if let Some(class) = priority_class {
+        info.BasicLimitInformation.LimitFlags |= JOB_OBJECT_LIMIT_PRIORITY_CLASS;
+        info.BasicLimitInformation.PriorityClass = class.0;
+      }
+      if let Some(mb) = limits.memory_limit_mb {
+        info.BasicLimitInformation.LimitFlags |= JOB_OBJECT_LIMIT_JOB_MEMORY;
+        info.JobMemoryLimit = (mb * 1024 * 1024) as usize;
+      }
+      let assigned = SetInformationJobObject(
+        job,
+        JobObjectExtendedLimitInformation,
+        &info as *const _ as *const std::ffi::c_void,
+        std::mem::size_of::<JOBOBJECT_EXTENDED_LIMIT_INFORMATION>() as u32,
+      )
+      .map_err(|e| format!("Failed to set Job Object limits: {e}"))
+      .and_then(|_| {
+        AssignProcessToJobObject(job, process)
+          .map_err(|e| format!("Failed to assign browser to Job Object: {e}"))
+      });
+      // The job lives on for as long as the browser's processes are in it.
+      let _ = CloseHandle(job);
+      assigned
+    })();
+
+    let _ = CloseHandle(process);
+    result
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn profile_limits_fall_back_to_defaults_per_field() {
+    let profile = ResourceLimits {
+      priority: Some(ProcessPriority::High),
+      memory_limit_mb: None,
+    };
+    let defaults = ResourceLimits {
+      priority: Some(ProcessPriority::Low),
+      memory_limit_mb: Some(4096),
+    };
+    assert_eq!(
+      profile.or(defaults),
+      ResourceLimits {
+        priority: Some(ProcessPriority::High),
+        memory_limit_mb: Some(4096),
+      }
+    );
+    assert!(ResourceLimits::default()
+      .or(ResourceLimits::default())
+      .is_empty());
+  }
+
+  #[test]
+  fn memory_limit_below_minimum_is_rejected() {
+    let limits = |mb| ResourceLimits {
+      priority: None,
+      memory_limit_mb: mb,
+    };
+    assert!(validate(&limits(None)).is_ok());
+    assert!(validate(&limits(Some(MIN_MEMORY_LIMIT_MB))).is_ok());
+    let err = validate(&limits(Some(64))).unwrap_err();
+    assert!(err.contains("INVALID_MEMORY_LIMIT"));
+  }
+}
@@ -24,6 +24,61 @@ impl Default for TableSortingSettings {
   }
 }
 
+/// Filter half of a saved table view. Every populated field must match for a
+/// profile to be shown; `None`/empty means "don't filter on this".
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct SavedViewFilter {
+  #[serde(default)]
+  pub tags: Vec<String>, // Profile must carry all of these tags
+  #[serde(default)]
+  pub group_id: Option<String>, // "default" selects ungrouped profiles
+  #[serde(default)]
+  pub browser: Option<String>,
+  #[serde(default)]
+  pub running: Option<bool>,
+  #[serde(default)]
+  pub has_proxy: Option<bool>, // Proxy or VPN assigned
+  #[serde(default)]
+  pub metadata: std::collections::HashMap<String, String>, // Exact value per metadata key
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SavedView {
+  pub id: String,
+  pub name: String,
+  #[serde(default)]
+  pub filter: SavedViewFilter,
+  #[serde(default)]
+  pub sorting: TableSortingSettings,
+  #[serde(default)]
+  pub visible_columns: Option<Vec<String>>, // None shows every column
+  #[serde(default)]
+  pub updated_at: Option<u64>,
+}
+
+/// Remote representation of the saved views list. Views are synced as one
+/// document with last-write-wins on `updated_at`.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct SavedViewsDocument {
+  #[serde(default)]
+  pub views: Vec<SavedView>,
+  #[serde(default)]
+  pub updated_at: u64,
+}
+
+/// Transport the local API is served on.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Default)]
+#[serde(tag = "mode", rename_all = "snake_case")]
+pub enum ApiListen {
+  /// Loopback TCP on `api_port`.
+  #[default]
+  Tcp,
+  /// Unix domain socket at `path`, readable by the owner only.
+  Unix { path: String },
+  /// Windows named pipe `\\.\pipe\<name>`.
+  WindowsPipe { name: String },
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct AppSettings {
   #[serde(default)]
@@ -37,7 +92,20 @@ pub struct AppSettings {
   #[serde(default = "default_api_port")]
   pub api_port: u16,
   #[serde(default)]
+  pub api_listen: ApiListen,
+  #[serde(default)]
   pub api_token: Option<String>, // Displayed token for user to copy
+  /// Sustained requests per second allowed per API token; 0 disables the limit.
+  #[serde(default = "default_api_rate_limit_per_second")]
+  pub api_rate_limit_per_second: u32,
+  /// Requests a token may make in a burst before the per-second rate applies.
+  #[serde(default = "default_api_rate_limit_burst")]
+  pub api_rate_limit_burst: u32,
+  /// Concurrent launch/import/download requests across all tokens; 0 disables the cap.
+  #[serde(default = "default_api_max_concurrent_launches")]
+  pub api_max_concurrent_launches: u32,
+  #[serde(default = "default_api_max_body_bytes")]
+  pub api_max_body_bytes: u64,
   #[serde(default)]
   pub sync_server_url: Option<String>, // URL of the sync server
   #[serde(default)]
@@ -51,6 +119,8 @@ pub struct AppSettings {
   #[serde(default)]
   pub mcp_token: Option<String>, // Displayed token for user to copy (not persisted, loaded from encrypted file)
   #[serde(default)]
+  pub mcp_stdio_enabled: bool, // Allow `--mcp-stdio` clients, which connect without a token
+  #[serde(default)]
   pub language: Option<String>, // ISO 639-1: "en", "es", "pt", "fr", "zh", "ja", "ko", "ru", or None for system default
   #[serde(default)]
   pub window_resize_warning_dismissed: bool,
@@ -63,6 +133,68 @@ pub struct AppSettings {
   /// copy is always re-encrypted regardless of this flag.
   #[serde(default)]
   pub keep_decrypted_profiles_in_ram: bool,
+  /// Check extensions with a store or URL source for new versions every 24h.
+  #[serde(default)]
+  pub auto_update_extensions: bool,
+  /// Local snapshots kept per profile by the periodic cleanup; 0 keeps all.
+  #[serde(default = "default_snapshot_retention")]
+  pub snapshot_retention: u32,
+  /// Browser launches allowed to start at once; the rest queue. 0 disables
+  /// the cap.
+  #[serde(default = "default_max_concurrent_launches")]
+  pub max_concurrent_launches: u32,
+  /// Leave browsers and workers from a crashed run alone at startup instead
+  /// of stopping them. Stale profile locks are cleared either way.
+  #[serde(default)]
+  pub disable_orphan_process_cleanup: bool,
+  /// Run the launch pre-flight checks before every launch and refuse one
+  /// that a failed check predicts.
+  #[serde(default)]
+  pub preflight_before_launch: bool,
+  /// A launch phase taking longer than this emits `launch-slow`. 0 disables.
+  #[serde(default = "default_slow_launch_threshold_secs")]
+  pub slow_launch_threshold_secs: u32,
+  /// Sync upload/download rate limits in KB/s, shared by all transfers.
+  /// 0 means unlimited.
+  #[serde(default)]
+  pub sync_upload_limit_kbps: u64,
+  #[serde(default)]
+  pub sync_download_limit_kbps: u64,
+  /// Local time range in which profile files may sync. Metadata syncs run
+  /// at any time.
+  #[serde(default)]
+  pub sync_window: Option<SyncWindow>,
+  /// Global accelerator per profile ID, managed by `set_profile_shortcut`.
+  #[serde(default)]
+  pub profile_shortcuts: std::collections::HashMap<String, String>,
+  /// `None` until the views have been seeded from `table_sorting.json`.
+  #[serde(default)]
+  pub saved_views: Option<Vec<SavedView>>,
+  #[serde(default)]
+  pub saved_views_updated_at: Option<u64>,
+  /// Background network work stands down while set; see `offline.rs`.
+  /// Changed through `set_offline_mode`.
+  #[serde(default)]
+  pub offline_mode: bool,
+  /// Fail a profile sync, leaving the remote manifest untouched, when any
+  /// file can't be uploaded. Otherwise those files are left out of the
+  /// manifest and the sync is reported as partial.
+  #[serde(default)]
+  pub strict_sync_uploads: bool,
+  /// Applied to launched browsers whose profile leaves a limit unset.
+  #[serde(default)]
+  pub default_resource_limits: crate::profile::types::ResourceLimits,
+  /// Keeps stopped browsers running without windows for faster relaunches;
+  /// see `warm_pool.rs`.
+  #[serde(default)]
+  pub warm_pool: crate::warm_pool::WarmPoolSettings,
+}
+
+/// `"HH:MM"` bounds; a window whose end is before its start spans midnight.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct SyncWindow {
+  pub start: String,
+  pub end: String,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
@@ -79,6 +211,38 @@ fn default_api_port() -> u16 {
   10108
 }
 
+fn default_api_rate_limit_per_second() -> u32 {
+  30
+}
+
+fn default_api_rate_limit_burst() -> u32 {
+  60
+}
+
+fn default_api_max_concurrent_launches() -> u32 {
+  4
+}
+
+fn default_api_max_body_bytes() -> u64 {
+  10 * 1024 * 1024
+}
+
+fn default_snapshot_retention() -> u32 {
+  5
+}
+
+pub const DEFAULT_MAX_CONCURRENT_LAUNCHES: u32 = 3;
+
+fn default_max_concurrent_launches() -> u32 {
+  DEFAULT_MAX_CONCURRENT_LAUNCHES
+}
+
+pub const DEFAULT_SLOW_LAUNCH_THRESHOLD_SECS: u32 = 15;
+
+fn default_slow_launch_threshold_secs() -> u32 {
+  DEFAULT_SLOW_LAUNCH_THRESHOLD_SECS
+}
+
 impl Default for AppSettings {
   fn default() -> Self {
     Self {
@@ -87,18 +251,40 @@ impl Default for AppSettings {
       custom_theme: None,
       api_enabled: false,
       api_port: 10108,
+      api_listen: ApiListen::default(),
       api_token: None,
+      api_rate_limit_per_second: default_api_rate_limit_per_second(),
+      api_rate_limit_burst: default_api_rate_limit_burst(),
+      api_max_concurrent_launches: default_api_max_concurrent_launches(),
+      api_max_body_bytes: default_api_max_body_bytes(),
       sync_server_url: None,
       first_launch_timestamp: None,
       commercial_trial_acknowledged: false,
       mcp_enabled: false,
       mcp_port: None,
       mcp_token: None,
+      mcp_stdio_enabled: false,
       language: None,
       window_resize_warning_dismissed: false,
       onboarding_completed: false,
       disable_auto_updates: false,
       keep_decrypted_profiles_in_ram: false,
+      auto_update_extensions: false,
+      snapshot_retention: default_snapshot_retention(),
+      max_concurrent_launches: default_max_concurrent_launches(),
+      disable_orphan_process_cleanup: false,
+      preflight_before_launch: false,
+      slow_launch_threshold_secs: default_slow_launch_threshold_secs(),
+      sync_upload_limit_kbps: 0,
+      sync_download_limit_kbps: 0,
+      sync_window: None,
+      profile_shortcuts: std::collections::HashMap::new(),
+      saved_views: None,
+      saved_views_updated_at: None,
+      offline_mode: false,
+      strict_sync_uploads: false,
+      default_resource_limits: Default::default(),
+      warm_pool: Default::default(),
     }
   }
 }
@@ -184,13 +370,57 @@ impl SettingsManager {
     Ok(())
   }
 
+  /// Saved views are stored in the settings file. The first read migrates the
+  /// legacy global sort into a single "Default" view so nothing is lost.
+  pub fn load_saved_views(&self) -> Result<Vec<SavedView>, Box<dyn std::error::Error>> {
+    let mut settings = self.load_settings()?;
+    if let Some(views) = settings.saved_views {
+      return Ok(views);
+    }
+
+    let views = if self.get_table_sorting_file().exists() {
+      vec![SavedView {
+        id: uuid::Uuid::new_v4().to_string(),
+        name: "Default".to_string(),
+        filter: SavedViewFilter::default(),
+        sorting: self.load_table_sorting().unwrap_or_default(),
+        visible_columns: None,
+        updated_at: Some(now_secs()),
+      }]
+    } else {
+      Vec::new()
+    };
+
+    settings.saved_views = Some(views.clone());
+    settings.saved_views_updated_at = Some(now_secs());
+    self.save_settings(&settings)?;
+    Ok(views)
+  }
+
+  pub fn save_saved_views(
+    &self,
+    views: Vec<SavedView>,
+    updated_at: u64,
+  ) -> Result<(), Box<dyn std::error::Error>> {
+    let mut settings = self.load_settings()?;
+    settings.saved_views = Some(views);
+    settings.saved_views_updated_at = Some(updated_at);
+    self.save_settings(&settings)
+  }
+
+  pub fn saved_views_document(&self) -> Result<SavedViewsDocument, Box<dyn std::error::Error>> {
+    let views = self.load_saved_views()?;
+    let updated_at = self.load_settings()?.saved_views_updated_at.unwrap_or(0);
+    Ok(SavedViewsDocument { views, updated_at })
+  }
+
   fn get_vault_password() -> String {
     env!("DONUT_BROWSER_VAULT_PASSWORD").to_string()
   }
 
   pub async fn generate_api_token(
     &self,
-    app_handle: &tauri::AppHandle,
+    app_handle: &crate::app_handle::AppHandle,
   ) -> Result<String, Box<dyn std::error::Error>> {
     // Generate a secure random token (base64 encoded for URL safety)
     let token_bytes: [u8; 32] = {
@@ -211,7 +441,7 @@ impl SettingsManager {
 
   pub async fn store_api_token(
     &self,
-    _app_handle: &tauri::AppHandle,
+    _app_handle: &crate::app_handle::AppHandle,
     token: &str,
   ) -> Result<(), Box<dyn std::error::Error>> {
     // Store token in an encrypted file using Argon2 + AES-GCM
@@ -277,7 +507,7 @@ impl SettingsManager {
 
   pub async fn get_api_token(
     &self,
-    _app_handle: &tauri::AppHandle,
+    _app_handle: &crate::app_handle::AppHandle,
   ) -> Result<Option<String>, Box<dyn std::error::Error>> {
     let token_file = self.get_settings_dir().join("api_token.dat");
 
@@ -372,7 +602,7 @@ impl SettingsManager {
 
   pub async fn remove_api_token(
     &self,
-    _app_handle: &tauri::AppHandle,
+    _app_handle: &crate::app_handle::AppHandle,
   ) -> Result<(), Box<dyn std::error::Error>> {
     let token_file = self.get_settings_dir().join("api_token.dat");
 
@@ -385,7 +615,7 @@ impl SettingsManager {
 
   pub async fn generate_mcp_token(
     &self,
-    app_handle: &tauri::AppHandle,
+    app_handle: &crate::app_handle::AppHandle,
   ) -> Result<String, Box<dyn std::error::Error>> {
     let token_bytes: [u8; 32] = {
       use rand::Rng;
@@ -402,7 +632,7 @@ impl SettingsManager {
 
   pub async fn store_mcp_token(
     &self,
-    _app_handle: &tauri::AppHandle,
+    _app_handle: &crate::app_handle::AppHandle,
     token: &str,
   ) -> Result<(), Box<dyn std::error::Error>> {
     let token_file = self.get_settings_dir().join("mcp_token.dat");
@@ -449,7 +679,7 @@ impl SettingsManager {
 
   pub async fn get_mcp_token(
     &self,
-    _app_handle: &tauri::AppHandle,
+    _app_handle: &crate::app_handle::AppHandle,
   ) -> Result<Option<String>, Box<dyn std::error::Error>> {
     let token_file = self.get_settings_dir().join("mcp_token.dat");
 
@@ -532,7 +762,7 @@ impl SettingsManager {
 
   pub async fn remove_mcp_token(
     &self,
-    _app_handle: &tauri::AppHandle,
+    _app_handle: &crate::app_handle::AppHandle,
   ) -> Result<(), Box<dyn std::error::Error>> {
     let token_file = self.get_settings_dir().join("mcp_token.dat");
 
@@ -543,9 +773,125 @@ impl SettingsManager {
     Ok(())
   }
 
+  /// Writes `plaintext` to `file_name` in the settings dir, encrypted with the
+  /// same Argon2 + AES-GCM vault scheme as the API and MCP tokens.
+  pub(crate) fn write_vault_file(
+    &self,
+    file_name: &str,
+    header: &[u8; 5],
+    plaintext: &[u8],
+  ) -> Result<(), Box<dyn std::error::Error>> {
+    let path = self.get_settings_dir().join(file_name);
+    if let Some(parent) = path.parent() {
+      std::fs::create_dir_all(parent)?;
+    }
+
+    let vault_password = Self::get_vault_password();
+    let salt_bytes: [u8; 16] = rand::rng().random();
+    let salt =
+      SaltString::encode_b64(&salt_bytes).map_err(|e| format!("Failed to encode salt: {e}"))?;
+    let argon2 = Argon2::default();
+    let password_hash = argon2
+      .hash_password(vault_password.as_bytes(), &salt)
+      .map_err(|e| format!("Argon2 key derivation failed: {e}"))?;
+    let hash_value = password_hash.hash.unwrap();
+    let hash_bytes = hash_value.as_bytes();
+    let key_bytes: [u8; 32] = hash_bytes[..32]
+      .try_into()
+      .map_err(|_| "Invalid key length")?;
+    let key = Key::<Aes256Gcm>::from(key_bytes);
+    let cipher = Aes256Gcm::new(&key);
+    let nonce_bytes: [u8; 12] = rand::rng().random();
+    let nonce = Nonce::from(nonce_bytes);
+    let ciphertext = cipher
+      .encrypt(&nonce, plaintext)
+      .map_err(|e| format!("Encryption failed: {e}"))?;
+
+    let mut file_data = Vec::new();
+    file_data.extend_from_slice(header);
+    file_data.push(2u8); // Version 2 (Argon2 + AES-GCM)
+    let salt_str = salt.as_str();
+    file_data.push(salt_str.len() as u8);
+    file_data.extend_from_slice(salt_str.as_bytes());
+    file_data.extend_from_slice(&nonce);
+    file_data.extend_from_slice(&(ciphertext.len() as u32).to_le_bytes());
+    file_data.extend_from_slice(&ciphertext);
+
+    std::fs::write(&path, file_data)?;
+    crate::app_dirs::restrict_to_owner(&path);
+    Ok(())
+  }
+
+  /// Reads a file written by [`Self::write_vault_file`]. Returns `None` when
+  /// the file is missing or has an unexpected header or layout.
+  pub(crate) fn read_vault_file(
+    &self,
+    file_name: &str,
+    header: &[u8; 5],
+  ) -> Result<Option<Vec<u8>>, Box<dyn std::error::Error>> {
+    let path = self.get_settings_dir().join(file_name);
+    if !path.exists() {
+      return Ok(None);
+    }
+
+    let file_data = std::fs::read(path)?;
+    if file_data.len() < 6 || &file_data[0..5] != header || file_data[5] != 2 {
+      return Ok(None);
+    }
+
+    let mut offset = 6;
+    if offset >= file_data.len() {
+      return Ok(None);
+    }
+    let salt_len = file_data[offset] as usize;
+    offset += 1;
+    if offset + salt_len + 12 + 4 > file_data.len() {
+      return Ok(None);
+    }
+    let salt_str = std::str::from_utf8(&file_data[offset..offset + salt_len])
+      .map_err(|_| "Invalid salt encoding")?;
+    let salt = SaltString::from_b64(salt_str).map_err(|_| "Invalid salt format")?;
+    offset += salt_len;
+
+    let nonce_bytes: [u8; 12] = file_data[offset..offset + 12]
+      .try_into()
+      .map_err(|_| "Invalid nonce length")?;
+    let nonce = Nonce::from(nonce_bytes);
+    offset += 12;
+
+    let ciphertext_len = u32::from_le_bytes([
+      file_data[offset],
+      file_data[offset + 1],
+      file_data[offset + 2],
+      file_data[offset + 3],
+    ]) as usize;
+    offset += 4;
+    if offset + ciphertext_len > file_data.len() {
+      return Ok(None);
+    }
+    let ciphertext = &file_data[offset..offset + ciphertext_len];
+
+    let vault_password = Self::get_vault_password();
+    let argon2 = Argon2::default();
+    let password_hash = argon2
+      .hash_password(vault_password.as_bytes(), &salt)
+      .map_err(|e| format!("Argon2 key derivation failed: {e}"))?;
+    let hash_value = password_hash.hash.unwrap();
+    let hash_bytes = hash_value.as_bytes();
+    let key_bytes: [u8; 32] = hash_bytes[..32]
+      .try_into()
+      .map_err(|_| "Invalid key length")?;
+    let key = Key::<Aes256Gcm>::from(key_bytes);
+    let cipher = Aes256Gcm::new(&key);
+    let plaintext = cipher
+      .decrypt(&nonce, ciphertext)
+      .map_err(|_| "Decryption failed")?;
+    Ok(Some(plaintext))
+  }
+
   pub async fn store_sync_token(
     &self,
-    _app_handle: &tauri::AppHandle,
+    _app_handle: &crate::app_handle::AppHandle,
     token: &str,
   ) -> Result<(), Box<dyn std::error::Error>> {
     let token_file = self.get_settings_dir().join("sync_token.dat");
@@ -592,7 +938,7 @@ impl SettingsManager {
 
   pub async fn get_sync_token(
     &self,
-    _app_handle: &tauri::AppHandle,
+    _app_handle: &crate::app_handle::AppHandle,
   ) -> Result<Option<String>, Box<dyn std::error::Error>> {
     let token_file = self.get_settings_dir().join("sync_token.dat");
 
@@ -675,7 +1021,7 @@ impl SettingsManager {
 
   pub async fn remove_sync_token(
     &self,
-    _app_handle: &tauri::AppHandle,
+    _app_handle: &crate::app_handle::AppHandle,
   ) -> Result<(), Box<dyn std::error::Error>> {
     let token_file = self.get_settings_dir().join("sync_token.dat");
 
@@ -705,7 +1051,9 @@ impl SettingsManager {
 }
 
 #[tauri::command]
-pub async fn get_app_settings(app_handle: tauri::AppHandle) -> Result<AppSettings, String> {
+pub async fn get_app_settings(
+  app_handle: crate::app_handle::AppHandle,
+) -> Result<AppSettings, String> {
   let manager = SettingsManager::instance();
   let mut settings = manager
     .load_settings()
@@ -727,9 +1075,23 @@ pub async fn get_app_settings(app_handle: tauri::AppHandle) -> Result<AppSetting
 
 #[tauri::command]
 pub async fn save_app_settings(
-  app_handle: tauri::AppHandle,
+  app_handle: crate::app_handle::AppHandle,
+  settings: AppSettings,
+) -> Result<AppSettings, String> {
+  crate::audit::audited!(
+    crate::audit::AuditActor::Gui,
+    "update",
+    "settings",
+    None,
+    save_app_settings_impl(app_handle, settings).await
+  )
+}
+
+async fn save_app_settings_impl(
+  app_handle: crate::app_handle::AppHandle,
   mut settings: AppSettings,
 ) -> Result<AppSettings, String> {
+  crate::resource_limits::validate(&settings.default_resource_limits)?;
   let manager = SettingsManager::instance();
 
   // Handle API token
@@ -762,6 +1124,8 @@ pub async fn save_app_settings(
     settings.api_token = None;
   }
 
+  crate::api_tokens::sync_default_token(&app_handle, settings.api_token.as_deref()).await?;
+
   // Handle MCP token
   if settings.mcp_enabled {
     if let Some(ref token) = settings.mcp_token {
@@ -797,6 +1161,10 @@ pub async fn save_app_settings(
   if let Ok(content) = std::fs::read_to_string(manager.get_settings_file()) {
     if let Ok(current) = serde_json::from_str::<AppSettings>(&content) {
       settings.window_resize_warning_dismissed = current.window_resize_warning_dismissed;
+      settings.saved_views = current.saved_views;
+      settings.saved_views_updated_at = current.saved_views_updated_at;
+      settings.profile_shortcuts = current.profile_shortcuts;
+      settings.offline_mode = current.offline_mode;
     }
   }
 
@@ -818,6 +1186,11 @@ pub async fn save_app_settings(
     .save_settings(&persist_settings)
     .map_err(|e| format!("Failed to save settings: {e}"))?;
 
+  crate::api_server::apply_api_limits(&settings);
+  crate::launch_queue::LAUNCH_QUEUE.set_limit(settings.max_concurrent_launches);
+  crate::warm_pool::apply_settings(&settings.warm_pool);
+  crate::sync::bandwidth::apply_limits(&settings);
+
   Ok(settings)
 }
 
@@ -826,7 +1199,7 @@ pub async fn save_app_settings(
 /// reader sees fresh context at the bottom of the buffer. Capped at 5 MB to
 /// keep clipboard payloads sane.
 #[tauri::command]
-pub async fn read_log_files(app_handle: tauri::AppHandle) -> Result<String, String> {
+pub async fn read_log_files(app_handle: crate::app_handle::AppHandle) -> Result<String, String> {
   let dir = crate::app_dirs::log_dir(&app_handle);
   if !dir.exists() {
     return Err("Log directory does not exist yet".to_string());
@@ -895,7 +1268,7 @@ pub async fn read_log_files(app_handle: tauri::AppHandle) -> Result<String, Stri
 
 /// Reveal the log directory in the OS file manager.
 #[tauri::command]
-pub async fn open_log_directory(app_handle: tauri::AppHandle) -> Result<(), String> {
+pub async fn open_log_directory(app_handle: crate::app_handle::AppHandle) -> Result<(), String> {
   let dir = crate::app_dirs::log_dir(&app_handle);
   if !dir.exists() {
     std::fs::create_dir_all(&dir).map_err(|e| format!("Failed to create log dir: {e}"))?;
@@ -942,8 +1315,140 @@ pub async fn save_table_sorting_settings(sorting: TableSortingSettings) -> Resul
     .map_err(|e| format!("Failed to save table sorting settings: {e}"))
 }
 
+fn now_secs() -> u64 {
+  std::time::SystemTime::now()
+    .duration_since(std::time::UNIX_EPOCH)
+    .unwrap_or_default()
+    .as_secs()
+}
+
+fn normalize_saved_view_name(name: &str) -> Result<String, String> {
+  let name = name.trim();
+  if name.is_empty() {
+    return Err(serde_json::json!({ "code": "NAME_CANNOT_BE_EMPTY" }).to_string());
+  }
+  Ok(name.to_string())
+}
+
+fn saved_view_not_found(id: &str) -> String {
+  serde_json::json!({ "code": "SAVED_VIEW_NOT_FOUND", "params": { "id": id } }).to_string()
+}
+
+fn persist_saved_views(
+  app_handle: &crate::app_handle::AppHandle,
+  views: Vec<SavedView>,
+) -> Result<(), String> {
+  SettingsManager::instance()
+    .save_saved_views(views, now_secs())
+    .map_err(|e| format!("Failed to save saved views: {e}"))?;
+
+  if let Err(e) = crate::events::emit_empty("saved-views-changed") {
+    log::error!("Failed to emit saved-views-changed event: {e}");
+  }
+
+  if crate::sync::is_sync_configured() {
+    let app_handle_clone = app_handle.clone();
+    tauri::async_runtime::spawn(async move {
+      match crate::sync::SyncEngine::create_from_settings(&app_handle_clone).await {
+        Ok(engine) => {
+          if let Err(e) = engine.sync_saved_views(Some(&app_handle_clone)).await {
+            log::warn!("Failed to sync saved views: {e}");
+          }
+        }
+        Err(e) => {
+          log::debug!("Sync not configured, skipping saved views sync: {e}");
+        }
+      }
+    });
+  }
+
+  Ok(())
+}
+
+/// Only reads the settings file, so the table can call this on every mount.
+#[tauri::command]
+pub async fn list_saved_views() -> Result<Vec<SavedView>, String> {
+  SettingsManager::instance()
+    .load_saved_views()
+    .map_err(|e| format!("Failed to load saved views: {e}"))
+}
+
+#[tauri::command]
+pub async fn create_saved_view(
+  app_handle: crate::app_handle::AppHandle,
+  name: String,
+  filter: SavedViewFilter,
+  sorting: TableSortingSettings,
+  visible_columns: Option<Vec<String>>,
+) -> Result<SavedView, String> {
+  let name = normalize_saved_view_name(&name)?;
+  let mut views = list_saved_views().await?;
+  if views.iter().any(|v| v.name == name) {
+    return Err(
+      serde_json::json!({ "code": "SAVED_VIEW_NAME_EXISTS", "params": { "name": name } })
+        .to_string(),
+    );
+  }
+
+  let view = SavedView {
+    id: uuid::Uuid::new_v4().to_string(),
+    name,
+    filter,
+    sorting,
+    visible_columns,
+    updated_at: Some(now_secs()),
+  };
+  views.push(view.clone());
+  persist_saved_views(&app_handle, views)?;
+  Ok(view)
+}
+
+#[tauri::command]
+pub async fn update_saved_view(
+  app_handle: crate::app_handle::AppHandle,
+  view: SavedView,
+) -> Result<SavedView, String> {
+  let name = normalize_saved_view_name(&view.name)?;
+  let mut views = list_saved_views().await?;
+  if views.iter().any(|v| v.id != view.id && v.name == name) {
+    return Err(
+      serde_json::json!({ "code": "SAVED_VIEW_NAME_EXISTS", "params": { "name": name } })
+        .to_string(),
+    );
+  }
+
+  let existing = views
+    .iter_mut()
+    .find(|v| v.id == view.id)
+    .ok_or_else(|| saved_view_not_found(&view.id))?;
+  *existing = SavedView {
+    name,
+    updated_at: Some(now_secs()),
+    ..view
+  };
+  let updated = existing.clone();
+  persist_saved_views(&app_handle, views)?;
+  Ok(updated)
+}
+
 #[tauri::command]
-pub async fn get_sync_settings(app_handle: tauri::AppHandle) -> Result<SyncSettings, String> {
+pub async fn delete_saved_view(
+  app_handle: crate::app_handle::AppHandle,
+  id: String,
+) -> Result<(), String> {
+  let mut views = list_saved_views().await?;
+  let before = views.len();
+  views.retain(|v| v.id != id);
+  if views.len() == before {
+    return Err(saved_view_not_found(&id));
+  }
+  persist_saved_views(&app_handle, views)
+}
+
+#[tauri::command]
+pub async fn get_sync_settings(
+  app_handle: crate::app_handle::AppHandle,
+) -> Result<SyncSettings, String> {
   // Cloud auth takes priority over self-hosted settings
   if crate::cloud_auth::CLOUD_AUTH.is_logged_in().await {
     let sync_token = crate::cloud_auth::CLOUD_AUTH
@@ -972,7 +1477,7 @@ pub async fn get_sync_settings(app_handle: tauri::AppHandle) -> Result<SyncSetti
 
 #[tauri::command]
 pub async fn save_sync_settings(
-  app_handle: tauri::AppHandle,
+  app_handle: crate::app_handle::AppHandle,
   sync_server_url: Option<String>,
   sync_token: Option<String>,
 ) -> Result<SyncSettings, String> {
@@ -1181,18 +1686,40 @@ mod tests {
       custom_theme: None,
       api_enabled: false,
       api_port: 10108,
+      api_listen: ApiListen::default(),
       api_token: None,
+      api_rate_limit_per_second: default_api_rate_limit_per_second(),
+      api_rate_limit_burst: default_api_rate_limit_burst(),
+      api_max_concurrent_launches: default_api_max_concurrent_launches(),
+      api_max_body_bytes: default_api_max_body_bytes(),
       sync_server_url: None,
       first_launch_timestamp: None,
       commercial_trial_acknowledged: false,
       mcp_enabled: false,
       mcp_port: None,
       mcp_token: None,
+      mcp_stdio_enabled: false,
       language: None,
       window_resize_warning_dismissed: false,
       onboarding_completed: false,
       disable_auto_updates: false,
       keep_decrypted_profiles_in_ram: false,
+      auto_update_extensions: false,
+      snapshot_retention: 3,
+      max_concurrent_launches: default_max_concurrent_launches(),
+      disable_orphan_process_cleanup: false,
+      preflight_before_launch: false,
+      slow_launch_threshold_secs: default_slow_launch_threshold_secs(),
+      sync_upload_limit_kbps: 0,
+      sync_download_limit_kbps: 0,
+      sync_window: None,
+      profile_shortcuts: std::collections::HashMap::new(),
+      saved_views: None,
+      saved_views_updated_at: None,
+      offline_mode: false,
+      strict_sync_uploads: false,
+      default_resource_limits: Default::default(),
+      warm_pool: Default::default(),
     };
 
     let save_result = manager.save_settings(&test_settings);
@@ -1305,4 +1832,34 @@ mod tests {
       "Sorting file should end with table_sorting.json"
     );
   }
+
+  #[test]
+  fn test_saved_views_migrate_from_table_sorting() {
+    let (manager, _temp_dir, _guard) = create_test_settings_manager();
+
+    manager
+      .save_table_sorting(&TableSortingSettings {
+        column: "browser".to_string(),
+        direction: "desc".to_string(),
+      })
+      .unwrap();
+
+    let views = manager.load_saved_views().unwrap();
+    assert_eq!(views.len(), 1, "Legacy sorting should seed one view");
+    assert_eq!(views[0].sorting.column, "browser");
+    assert_eq!(views[0].sorting.direction, "desc");
+
+    // Migration runs once; deleting the seeded view must stick.
+    manager.save_saved_views(Vec::new(), 1).unwrap();
+    assert!(manager.load_saved_views().unwrap().is_empty());
+  }
+
+  #[test]
+  fn test_saved_views_without_legacy_sorting() {
+    let (manager, _temp_dir, _guard) = create_test_settings_manager();
+
+    let views = manager.load_saved_views().unwrap();
+    assert!(views.is_empty(), "Fresh installs start without views");
+    assert!(manager.load_settings().unwrap().saved_views.is_some());
+  }
 }
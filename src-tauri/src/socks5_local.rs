@@ -430,7 +430,13 @@ async fn handle_udp_associate(
   upstream_url: Option<String>,
   blocklist_matcher: BlocklistMatcher,
 ) {
-  let mode = udp_mode(upstream_url.as_deref());
+  // Datagrams can't be nested through a proxy chain's entry hops, and sending
+  // them straight to the exit would skip them.
+  let mode = if crate::proxy_server::upstream_is_chained() {
+    UdpMode::Refuse
+  } else {
+    udp_mode(upstream_url.as_deref())
+  };
 
   if mode == UdpMode::Refuse {
     log::info!(
@@ -471,6 +477,9 @@ async fn handle_udp_associate(
           return;
         }
       };
+      // IPv6 destinations need their own socket; a host without IPv6 simply
+      // drops those datagrams, as it would without the relay.
+      let out6 = UdpSocket::bind((Ipv6Addr::UNSPECIFIED, 0)).await.ok();
       if send_reply(&mut control, REP_SUCCEEDED, relay_addr)
         .await
         .is_err()
@@ -478,7 +487,14 @@ async fn handle_udp_associate(
         return;
       }
       log::info!("SOCKS5 UDP ASSOCIATE (direct) relaying on {relay_addr}");
-      run_udp_relay_direct(control, relay, out, UdpRelayContext::new(blocklist_matcher)).await;
+      run_udp_relay_direct(
+        control,
+        relay,
+        out,
+        out6,
+        UdpRelayContext::new(blocklist_matcher),
+      )
+      .await;
     }
     UdpMode::Socks5Upstream => {
       // Establish the upstream association FIRST; if the upstream refuses UDP,
@@ -518,7 +534,8 @@ async fn associate_upstream(
   upstream_url: &str,
 ) -> Result<SocksDatagram<TcpStream>, Box<dyn std::error::Error + Send + Sync>> {
   let upstream = Url::parse(upstream_url)?;
-  let host = upstream.host_str().unwrap_or("127.0.0.1");
+  let host = crate::ip_utils::url_host(&upstream).unwrap_or_else(|| "127.0.0.1".to_string());
+  let host = host.as_str();
   let port = upstream.port().unwrap_or(1080);
   let auth = if !upstream.username().is_empty() {
     Some(Auth {
@@ -535,7 +552,13 @@ async fn associate_upstream(
   )
   .await
   .map_err(|_| format!("upstream SOCKS5 connect to {host}:{port} timed out"))??;
-  let bind_sock = UdpSocket::bind((Ipv4Addr::UNSPECIFIED, 0)).await?;
+  // The upstream's UDP relay is on the same address family it was reached over.
+  let bind_ip: IpAddr = if proxy_stream.peer_addr()?.is_ipv6() {
+    Ipv6Addr::UNSPECIFIED.into()
+  } else {
+    Ipv4Addr::UNSPECIFIED.into()
+  };
+  let bind_sock = UdpSocket::bind((bind_ip, 0)).await?;
   // association_addr None => 0.0.0.0:0 (we accept replies from any peer).
   let datagram = tokio::time::timeout(
     crate::proxy_server::UPSTREAM_DIAL_TIMEOUT,
@@ -621,11 +644,13 @@ async fn run_udp_relay_direct(
   mut control: TcpStream,
   relay: UdpSocket,
   out: UdpSocket,
+  out6: Option<UdpSocket>,
   mut ctx: UdpRelayContext,
 ) {
   let mut client_addr: Option<SocketAddr> = None;
   let mut from_client = vec![0u8; UDP_BUF];
   let mut from_target = vec![0u8; UDP_BUF];
+  let mut from_target6 = vec![0u8; UDP_BUF];
   let mut ctrl_buf = [0u8; 256];
 
   loop {
@@ -658,7 +683,12 @@ async fn run_udp_relay_direct(
           Some(d) => d,
           None => continue,
         };
-        if out.send_to(payload, dst).await.is_ok() {
+        let egress = match (dst.is_ipv6(), &out6) {
+          (false, _) => &out,
+          (true, Some(out6)) => out6,
+          (true, None) => continue,
+        };
+        if egress.send_to(payload, dst).await.is_ok() {
           ctx.record_sent(&host, dst.to_string(), payload.len() as u64);
         }
       }
@@ -672,12 +702,32 @@ async fn run_udp_relay_direct(
           }
         }
       }
+      r = recv_from_optional(out6.as_ref(), &mut from_target6) => {
+        let Ok((n, peer)) = r else { continue };
+        if let Some(client) = client_addr {
+          let resp = build_udp_response(peer, &from_target6[..n]);
+          if relay.send_to(&resp, client).await.is_ok() {
+            ctx.record_received(&peer.to_string(), &peer.ip().to_string(), n as u64);
+          }
+        }
+      }
     }
   }
 
   ctx.flush();
 }
 
+/// `recv_from` on a socket that may not exist; without one it never resolves.
+async fn recv_from_optional(
+  socket: Option<&UdpSocket>,
+  buf: &mut [u8],
+) -> std::io::Result<(usize, SocketAddr)> {
+  match socket {
+    Some(socket) => socket.recv_from(buf).await,
+    None => std::future::pending().await,
+  }
+}
+
 /// UDP relay tunneled through a SOCKS5 upstream that granted UDP ASSOCIATE.
 async fn run_udp_relay_socks5(
   mut control: TcpStream,
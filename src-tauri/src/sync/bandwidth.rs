@@ -0,0 +1,164 @@
+//! Rate limits for sync transfers. Every presigned upload and download goes
+//! through a token bucket per direction, shared by all profiles syncing at
+//! once. Limits come from `AppSettings` in KB/s (0 = unlimited) and are read
+//! on every wait, so a change reaches in-flight transfers within a fraction
+//! of a second.
+
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Body chunk size for rate-limited uploads.
+pub(crate) const UPLOAD_CHUNK_BYTES: usize = 16 * 1024;
+/// Longest single wait, so a lowered or lifted limit is picked up quickly.
+const MAX_WAIT: Duration = Duration::from_millis(250);
+/// Window the reported throughput is averaged over.
+const METER_WINDOW: Duration = Duration::from_secs(2);
+
+struct Bucket {
+  /// Negative after a chunk larger than the balance; later chunks wait it off.
+  tokens: f64,
+  refilled_at: Instant,
+}
+
+pub struct Throttle {
+  bytes_per_sec: AtomicU64,
+  bucket: Mutex<Bucket>,
+  meter: Mutex<VecDeque<(Instant, u64)>>,
+}
+
+impl Throttle {
+  fn new() -> Self {
+    Self {
+      bytes_per_sec: AtomicU64::new(0),
+      bucket: Mutex::new(Bucket {
+        tokens: 0.0,
+        refilled_at: Instant::now(),
+      }),
+      meter: Mutex::new(VecDeque::new()),
+    }
+  }
+
+  pub fn set_limit_kbps(&self, kbps: u64) {
+    self
+      .bytes_per_sec
+      .store(kbps.saturating_mul(1024), Ordering::SeqCst);
+  }
+
+  pub fn limit_bytes_per_sec(&self) -> u64 {
+    self.bytes_per_sec.load(Ordering::SeqCst)
+  }
+
+  /// How long to wait before `bytes` may go out, or `None` to send now (the
+  /// bytes are then taken from the bucket).
+  fn reserve(&self, bytes: u64, now: Instant) -> Option<Duration> {
+    let rate = self.limit_bytes_per_sec();
+    let mut bucket = self.bucket.lock().unwrap();
+    if rate == 0 {
+      bucket.tokens = 0.0;
+      bucket.refilled_at = now;
+      return None;
+    }
+    let rate = rate as f64;
+    let elapsed = now.duration_since(bucket.refilled_at).as_secs_f64();
+    // At most one second of burst.
+    bucket.tokens = (bucket.tokens + elapsed * rate).min(rate);
+    bucket.refilled_at = now;
+    if bucket.tokens < 0.0 {
+      return Some(Duration::from_secs_f64(-bucket.tokens / rate).min(MAX_WAIT));
+    }
+    bucket.tokens -= bytes as f64;
+    None
+  }
+
+  /// Wait until `bytes` may be transferred and count them toward throughput.
+  pub async fn acquire(&self, bytes: usize) {
+    while let Some(wait) = self.reserve(bytes as u64, Instant::now()) {
+      tokio::time::sleep(wait).await;
+    }
+    self.record(bytes as u64, Instant::now());
+  }
+
+  fn record(&self, bytes: u64, now: Instant) {
+    let mut meter = self.meter.lock().unwrap();
+    meter.push_back((now, bytes));
+    while meter
+      .front()
+      .is_some_and(|(at, _)| now.duration_since(*at) > METER_WINDOW)
+    {
+      meter.pop_front();
+    }
+  }
+
+  /// Bytes per second moved over the last couple of seconds.
+  pub fn throughput(&self) -> u64 {
+    let now = Instant::now();
+    let meter = self.meter.lock().unwrap();
+    let bytes: u64 = meter
+      .iter()
+      .filter(|(at, _)| now.duration_since(*at) <= METER_WINDOW)
+      .map(|(_, bytes)| bytes)
+      .sum();
+    (bytes as f64 / METER_WINDOW.as_secs_f64()) as u64
+  }
+}
+
+lazy_static::lazy_static! {
+  pub static ref UPLOAD: Throttle = Throttle::new();
+  pub static ref DOWNLOAD: Throttle = Throttle::new();
+}
+
+/// Apply the sync rate limits from `settings`. Called at startup and by
+/// `save_app_settings`.
+pub fn apply_limits(settings: &crate::settings_manager::AppSettings) {
+  UPLOAD.set_limit_kbps(settings.sync_upload_limit_kbps);
+  DOWNLOAD.set_limit_kbps(settings.sync_download_limit_kbps);
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn unlimited_never_waits() {
+    let throttle = Throttle::new();
+    let now = Instant::now();
+    for _ in 0..100 {
+      assert_eq!(throttle.reserve(1024 * 1024, now), None);
+    }
+  }
+
+  #[test]
+  fn limit_spreads_transfers_over_time() {
+    let throttle = Throttle::new();
+    throttle.set_limit_kbps(16);
+    let start = Instant::now();
+    // The bucket starts empty and fills at 16 KiB/s.
+    assert!(throttle.reserve(16 * 1024, start).is_none());
+    let wait = throttle.reserve(16 * 1024, start).expect("must wait");
+    assert!(wait <= MAX_WAIT);
+    // A second later the debt is paid off.
+    assert!(throttle
+      .reserve(16 * 1024, start + Duration::from_secs(1))
+      .is_none());
+  }
+
+  #[test]
+  fn lifting_the_limit_releases_waiting_transfers() {
+    let throttle = Throttle::new();
+    throttle.set_limit_kbps(1);
+    let now = Instant::now();
+    assert!(throttle.reserve(64 * 1024, now).is_none());
+    assert!(throttle.reserve(1024, now).is_some());
+    throttle.set_limit_kbps(0);
+    assert!(throttle.reserve(1024, now).is_none());
+  }
+
+  #[test]
+  fn throughput_counts_recent_bytes() {
+    let throttle = Throttle::new();
+    throttle.record(4096, Instant::now());
+    assert_eq!(throttle.throughput(), 2048);
+  }
+}
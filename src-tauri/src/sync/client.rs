@@ -1,4 +1,6 @@
+use super::bandwidth;
 use super::types::*;
+use futures_util::StreamExt;
 use reqwest::Client;
 
 #[derive(Clone)]
@@ -31,7 +33,7 @@ impl SyncClient {
       })
       .send()
       .await
-      .map_err(|e| SyncError::NetworkError(e.to_string()))?;
+      .map_err(SyncError::from_send)?;
 
     if response.status().is_client_error() {
       let status = response.status();
@@ -77,7 +79,7 @@ impl SyncClient {
       })
       .send()
       .await
-      .map_err(|e| SyncError::NetworkError(e.to_string()))?;
+      .map_err(SyncError::from_send)?;
 
     if response.status().is_client_error() {
       let status = response.status();
@@ -102,7 +104,7 @@ impl SyncClient {
       })
       .send()
       .await
-      .map_err(|e| SyncError::NetworkError(e.to_string()))?;
+      .map_err(SyncError::from_send)?;
 
     if response.status().is_client_error() {
       let status = response.status();
@@ -128,7 +130,7 @@ impl SyncClient {
       })
       .send()
       .await
-      .map_err(|e| SyncError::NetworkError(e.to_string()))?;
+      .map_err(SyncError::from_send)?;
 
     if response.status().is_client_error() {
       let status = response.status();
@@ -162,7 +164,7 @@ impl SyncClient {
       })
       .send()
       .await
-      .map_err(|e| SyncError::NetworkError(e.to_string()))?;
+      .map_err(SyncError::from_send)?;
 
     if response.status().is_client_error() {
       let status = response.status();
@@ -218,11 +220,21 @@ impl SyncClient {
     content_type: Option<&str>,
     metadata: Option<&std::collections::HashMap<String, String>>,
   ) -> SyncResult<()> {
+    // Sent in chunks so the upload limit can pace the body. The explicit
+    // Content-Length keeps it a plain (non-chunked) PUT, which S3 requires.
+    let chunks: Vec<Vec<u8>> = data
+      .chunks(bandwidth::UPLOAD_CHUNK_BYTES)
+      .map(<[u8]>::to_vec)
+      .collect();
+    let body = futures_util::stream::iter(chunks).then(|chunk| async move {
+      bandwidth::UPLOAD.acquire(chunk.len()).await;
+      Ok::<_, std::io::Error>(chunk)
+    });
     let mut req = self
       .client
       .put(presigned_url)
       .header("Content-Length", data.len().to_string())
-      .body(data.to_vec());
+      .body(reqwest::Body::wrap_stream(body));
 
     if let Some(ct) = content_type {
       req = req.header("Content-Type", ct);
@@ -234,10 +246,7 @@ impl SyncClient {
       }
     }
 
-    let response = req
-      .send()
-      .await
-      .map_err(|e| SyncError::NetworkError(e.to_string()))?;
+    let response = req.send().await.map_err(SyncError::from_send)?;
 
     if !response.status().is_success() {
       let status = response.status();
@@ -256,7 +265,7 @@ impl SyncClient {
       .get(presigned_url)
       .send()
       .await
-      .map_err(|e| SyncError::NetworkError(e.to_string()))?;
+      .map_err(SyncError::from_send)?;
 
     if !response.status().is_success() {
       return Err(SyncError::NetworkError(format!(
@@ -265,11 +274,14 @@ impl SyncClient {
       )));
     }
 
-    response
-      .bytes()
-      .await
-      .map(|b| b.to_vec())
-      .map_err(|e| SyncError::NetworkError(e.to_string()))
+    let mut data = Vec::with_capacity(response.content_length().unwrap_or(0) as usize);
+    let mut stream = response.bytes_stream();
+    while let Some(chunk) = stream.next().await {
+      let chunk = chunk.map_err(|e| SyncError::NetworkError(e.to_string()))?;
+      bandwidth::DOWNLOAD.acquire(chunk.len()).await;
+      data.extend_from_slice(&chunk);
+    }
+    Ok(data)
   }
 
   pub async fn presign_upload_batch(
@@ -298,7 +310,7 @@ impl SyncClient {
         .json(&request)
         .send()
         .await
-        .map_err(|e| SyncError::NetworkError(e.to_string()))?;
+        .map_err(SyncError::from_send)?;
 
       if response.status().is_client_error() {
         let status = response.status();
@@ -337,7 +349,7 @@ impl SyncClient {
         .json(&request)
         .send()
         .await
-        .map_err(|e| SyncError::NetworkError(e.to_string()))?;
+        .map_err(SyncError::from_send)?;
 
       if response.status().is_client_error() {
         let status = response.status();
@@ -372,7 +384,7 @@ impl SyncClient {
       })
       .send()
       .await
-      .map_err(|e| SyncError::NetworkError(e.to_string()))?;
+      .map_err(SyncError::from_send)?;
 
     if response.status().is_client_error() {
       let status = response.status();
@@ -96,7 +96,25 @@ pub fn store_e2e_password(password: &str) -> Result<(), String> {
   Ok(())
 }
 
+/// Password used for uploads while `change_e2e_password` re-encrypts remote
+/// data. The stored password stays the old one until every object has moved.
+static ROTATION_PASSWORD: Mutex<Option<String>> = Mutex::new(None);
+
+pub(crate) fn set_rotation_password(password: Option<String>) {
+  if let Ok(mut guard) = ROTATION_PASSWORD.lock() {
+    *guard = password;
+  }
+}
+
+/// The password sync should encrypt and decrypt with right now.
 pub fn load_e2e_password() -> Result<Option<String>, String> {
+  if let Some(password) = ROTATION_PASSWORD.lock().ok().and_then(|g| g.clone()) {
+    return Ok(Some(password));
+  }
+  load_stored_e2e_password()
+}
+
+fn load_stored_e2e_password() -> Result<Option<String>, String> {
   let file_path = get_e2e_password_path();
   if !file_path.exists() {
     return Ok(None);
@@ -194,6 +212,69 @@ pub fn remove_e2e_password() -> Result<(), String> {
   Ok(())
 }
 
+fn get_rotation_progress_path() -> std::path::PathBuf {
+  crate::app_dirs::settings_dir().join("e2e_rotation.json")
+}
+
+/// On-disk marker for an interrupted password change. Holds no password:
+/// `verifier` is a hash of the new password's derived key, so a resumed
+/// attempt with a different new password starts over instead of skipping
+/// profiles that were uploaded under another key.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct RotationProgress {
+  pub verifier_salt: String,
+  pub verifier: String,
+  #[serde(default)]
+  pub completed_profiles: Vec<String>,
+}
+
+impl RotationProgress {
+  pub fn new(new_password: &str) -> Result<Self, String> {
+    let verifier_salt = generate_salt();
+    let verifier = Self::verifier_for(new_password, &verifier_salt)?;
+    Ok(Self {
+      verifier_salt,
+      verifier,
+      completed_profiles: Vec::new(),
+    })
+  }
+
+  fn verifier_for(password: &str, salt: &str) -> Result<String, String> {
+    use sha2::{Digest, Sha256};
+    let key = derive_profile_key(password, salt)?;
+    Ok(BASE64.encode(Sha256::digest(key)))
+  }
+
+  pub fn matches(&self, new_password: &str) -> bool {
+    Self::verifier_for(new_password, &self.verifier_salt).is_ok_and(|v| v == self.verifier)
+  }
+}
+
+pub fn load_rotation_progress() -> Option<RotationProgress> {
+  let data = std::fs::read(get_rotation_progress_path()).ok()?;
+  serde_json::from_slice(&data).ok()
+}
+
+pub fn save_rotation_progress(progress: &RotationProgress) -> Result<(), String> {
+  let path = get_rotation_progress_path();
+  if let Some(parent) = path.parent() {
+    std::fs::create_dir_all(parent).map_err(|e| format!("Failed to create directory: {e}"))?;
+  }
+  let json = serde_json::to_vec_pretty(progress)
+    .map_err(|e| format!("Failed to serialize rotation progress: {e}"))?;
+  std::fs::write(&path, json).map_err(|e| format!("Failed to write rotation progress: {e}"))
+}
+
+pub fn clear_rotation_progress() {
+  let _ = std::fs::remove_file(get_rotation_progress_path());
+}
+
+/// True while a password change is unfinished. Sync holds off so it doesn't
+/// mix objects sealed under the old and new passwords.
+pub fn rotation_in_progress() -> bool {
+  get_rotation_progress_path().exists()
+}
+
 /// Derive a per-profile encryption key using Argon2id, with an in-process
 /// cache keyed on `(sha256(password), salt)`. Repeated calls with the same
 /// password+salt are O(1); a password change calls `invalidate_key_cache`
@@ -352,12 +433,17 @@ pub fn check_has_e2e_password() -> bool {
 
 #[tauri::command]
 pub fn verify_e2e_password(password: String) -> Result<bool, String> {
-  match load_e2e_password()? {
+  match load_stored_e2e_password()? {
     Some(stored) => Ok(stored == password),
     None => Err(serde_json::json!({ "code": "NO_E2E_PASSWORD_SET" }).to_string()),
   }
 }
 
+#[tauri::command]
+pub fn check_e2e_rotation_pending() -> bool {
+  rotation_in_progress()
+}
+
 #[tauri::command]
 pub async fn delete_e2e_password() -> Result<(), String> {
   enforce_team_owner_for_encryption_change().await?;
@@ -366,7 +452,7 @@ pub async fn delete_e2e_password() -> Result<(), String> {
 
 /// On Team plans, only the team owner is allowed to flip the E2E password
 /// state — otherwise members could lock each other out by changing the key.
-async fn enforce_team_owner_for_encryption_change() -> Result<(), String> {
+pub(crate) async fn enforce_team_owner_for_encryption_change() -> Result<(), String> {
   use crate::cloud_auth::CLOUD_AUTH;
   if let Some(state) = CLOUD_AUTH.get_user().await {
     if state.user.plan == "team" && state.user.team_role.as_deref() != Some("owner") {
@@ -479,6 +565,14 @@ mod tests {
     assert!(!has_e2e_password());
   }
 
+  #[test]
+  fn test_rotation_progress_verifier() {
+    let progress = RotationProgress::new("new-password-123").unwrap();
+    assert!(progress.matches("new-password-123"));
+    assert!(!progress.matches("other-password-123"));
+    assert!(!progress.verifier.contains("new-password"));
+  }
+
   #[test]
   fn test_decrypt_too_short_data() {
     let key = [1u8; 32];
@@ -1,8 +1,14 @@
 use super::client::SyncClient;
 use super::encryption;
-use super::manifest::{compute_diff, generate_manifest, get_cache_path, HashCache, SyncManifest};
+use super::manifest::{
+  compute_diff, generate_manifest, get_cache_path, HashCache, SyncManifest, SyncPreview,
+};
 use super::types::*;
 use crate::events;
+use crate::events::payloads::{
+  E2eRolloverProgress, GroupSyncStatus, ProfileSyncProgress, ProfileSyncStatus, ProxySyncStatus,
+  VpnSyncStatus,
+};
 use crate::profile::types::{BrowserProfile, SyncMode};
 use crate::profile::ProfileManager;
 use crate::settings_manager::SettingsManager;
@@ -57,12 +63,24 @@ pub async fn cancel_profile_sync(profile_id: String) -> Result<bool, String> {
   Ok(request_sync_cancel(&profile_id))
 }
 
+/// How long a sync preview waits for the remote manifest.
+const PREVIEW_REMOTE_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(20);
+
 /// Upload/download concurrency limit
 const SYNC_CONCURRENCY: usize = 32;
 
 /// Max retries for individual file uploads/downloads
 const MAX_FILE_RETRIES: u32 = 3;
 
+/// Extra passes, each with freshly presigned URLs, over files whose upload or
+/// post-upload check still failed after their per-file retries.
+const UPLOAD_RETRY_ROUNDS: u32 = 2;
+
+/// Up to this many uploaded files, every one is checked with `stat` before the
+/// manifest is written; above it, a random sample of `UPLOAD_VERIFY_SAMPLE`.
+const UPLOAD_VERIFY_ALL_THRESHOLD: usize = 200;
+const UPLOAD_VERIFY_SAMPLE: usize = 100;
+
 /// Critical file patterns — if any of these fail to upload/download, the sync is aborted.
 const CRITICAL_FILE_PATTERNS: &[&str] = &[
   "Cookies",
@@ -109,6 +127,27 @@ fn is_safe_manifest_path(path: &str) -> bool {
     .all(|c| matches!(c, Component::Normal(_) | Component::CurDir))
 }
 
+/// Whether `profile` holds a Wayfern cookie store that only this machine can
+/// decrypt. The profile-scoped `os_crypt_key` is synced like any other file,
+/// so a store it decrypts arrives intact; anything else comes back empty.
+fn has_machine_bound_cookies(profile: &BrowserProfile, profile_dir: &Path) -> bool {
+  use crate::cookie_manager::{CookieKeyPortability, CookieManager};
+  profile.browser == "wayfern"
+    && CookieManager::cookie_key_portability(&profile_dir.join("profile"))
+      == CookieKeyPortability::MachineBound
+}
+
+/// After a download, confirm the synced `os_crypt_key` decrypts the synced
+/// cookies, so the pre-launch decryption check is expected to pass.
+fn verify_downloaded_cookie_key(profile: &BrowserProfile, profile_dir: &Path) {
+  if has_machine_bound_cookies(profile, profile_dir) {
+    log::warn!(
+      "Profile {} sync: downloaded cookies can't be decrypted with the synced os_crypt_key",
+      profile.id
+    );
+  }
+}
+
 /// Checkpoint all SQLite WAL files in a profile directory.
 ///
 /// When a browser crashes or is killed, SQLite WAL files may contain
@@ -222,6 +261,79 @@ impl SyncResumeState {
   }
 }
 
+/// What the upload phase of a profile sync left undone.
+#[derive(Debug, Default)]
+struct UploadOutcome {
+  /// Gone from disk between manifest generation and upload.
+  vanished: Vec<String>,
+  /// Non-critical files still not on the server after every retry round.
+  failed: Vec<String>,
+}
+
+#[derive(Debug, Default)]
+struct UploadRound {
+  uploaded: Vec<String>,
+  vanished: Vec<String>,
+  /// `(path, message, critical)`.
+  failures: Vec<(String, String, bool)>,
+}
+
+enum FileUploaded {
+  Uploaded(String),
+  Vanished(String),
+}
+
+type FileUploadResult = Result<FileUploaded, (String, String, bool)>;
+
+/// The uploaded paths `verify_uploaded` checks.
+fn verification_sample(uploaded: &[String]) -> Vec<String> {
+  use rand::RngExt;
+
+  let mut paths = uploaded.to_vec();
+  if paths.len() <= UPLOAD_VERIFY_ALL_THRESHOLD {
+    return paths;
+  }
+  let mut rng = rand::rng();
+  for i in 0..UPLOAD_VERIFY_SAMPLE {
+    let j = rng.random_range(i..paths.len());
+    paths.swap(i, j);
+  }
+  paths.truncate(UPLOAD_VERIFY_SAMPLE);
+  paths
+}
+
+/// Keeps files the upload phase could not deliver out of `manifest`. A failed
+/// file the remote manifest already lists keeps that entry: a failed PUT
+/// leaves the previous object in place, so peers go on seeing the last good
+/// copy. Vanished files are dropped.
+fn exclude_failed_uploads(
+  manifest: &mut SyncManifest,
+  outcome: &UploadOutcome,
+  remote: Option<&SyncManifest>,
+) {
+  let failed: HashSet<&str> = outcome.failed.iter().map(String::as_str).collect();
+  let vanished: HashSet<&str> = outcome.vanished.iter().map(String::as_str).collect();
+  let previous: HashMap<&str, &super::manifest::ManifestFileEntry> = remote
+    .map(|r| r.files.iter().map(|f| (f.path.as_str(), f)).collect())
+    .unwrap_or_default();
+
+  manifest.files.retain_mut(|entry| {
+    if vanished.contains(entry.path.as_str()) {
+      return false;
+    }
+    if !failed.contains(entry.path.as_str()) {
+      return true;
+    }
+    match previous.get(entry.path.as_str()) {
+      Some(prev) => {
+        *entry = (*prev).clone();
+        true
+      }
+      None => false,
+    }
+  });
+}
+
 /// Tracks live sync progress and emits throttled events to the frontend
 struct SyncProgressTracker {
   profile_id: String,
@@ -270,6 +382,19 @@ impl SyncProgressTracker {
     self.maybe_emit();
   }
 
+  /// Rewinds the counters before a retry pass so only the files about to be
+  /// retried count as outstanding.
+  fn reset_remaining(&self, files: u64, bytes: u64) {
+    self
+      .completed_files
+      .store(self.total_files.saturating_sub(files), Ordering::Relaxed);
+    self
+      .completed_bytes
+      .store(self.total_bytes.saturating_sub(bytes), Ordering::Relaxed);
+    self.failed_count.store(0, Ordering::Relaxed);
+    self.emit_progress();
+  }
+
   fn maybe_emit(&self) {
     let Ok(mut last) = self.last_emit.try_lock() else {
       return;
@@ -291,22 +416,26 @@ impl SyncProgressTracker {
     let speed = (completed_bytes as f64 / elapsed) as u64;
     let remaining_bytes = self.total_bytes.saturating_sub(completed_bytes);
     let eta = remaining_bytes.checked_div(speed).unwrap_or(0);
+    // Live rate across every profile syncing now, after the bandwidth limit.
+    let throughput = if self.phase == "uploading" {
+      super::bandwidth::UPLOAD.throughput()
+    } else {
+      super::bandwidth::DOWNLOAD.throughput()
+    };
 
-    let _ = events::emit(
-      "profile-sync-progress",
-      serde_json::json!({
-        "profile_id": self.profile_id,
-        "profile_name": self.profile_name,
-        "phase": self.phase,
-        "completed_files": self.completed_files.load(Ordering::Relaxed),
-        "total_files": self.total_files,
-        "completed_bytes": completed_bytes,
-        "total_bytes": self.total_bytes,
-        "speed_bytes_per_sec": speed,
-        "eta_seconds": eta,
-        "failed_count": self.failed_count.load(Ordering::Relaxed),
-      }),
-    );
+    let _ = events::emit(&ProfileSyncProgress {
+      profile_id: self.profile_id.clone(),
+      profile_name: self.profile_name.clone(),
+      phase: self.phase.clone(),
+      completed_files: self.completed_files.load(Ordering::Relaxed),
+      total_files: self.total_files,
+      completed_bytes,
+      total_bytes: self.total_bytes,
+      speed_bytes_per_sec: speed,
+      throughput_bytes_per_sec: throughput,
+      eta_seconds: eta,
+      failed_count: self.failed_count.load(Ordering::Relaxed),
+    });
   }
 }
 
@@ -336,7 +465,9 @@ impl SyncEngine {
     }
   }
 
-  pub async fn create_from_settings(app_handle: &tauri::AppHandle) -> Result<Self, String> {
+  pub async fn create_from_settings(
+    app_handle: &crate::app_handle::AppHandle,
+  ) -> Result<Self, String> {
     // Cloud auth takes priority
     if crate::cloud_auth::CLOUD_AUTH.is_logged_in().await {
       let url = crate::cloud_auth::CLOUD_SYNC_URL.to_string();
@@ -447,8 +578,21 @@ impl SyncEngine {
 
   pub async fn sync_profile(
     &self,
-    app_handle: &tauri::AppHandle,
+    app_handle: &crate::app_handle::AppHandle,
+    profile: &BrowserProfile,
+  ) -> SyncResult<()> {
+    self
+      .sync_profile_with_rehash(app_handle, profile, false)
+      .await
+  }
+
+  /// `force_rehash` ignores the local hash cache and hashes every file again,
+  /// to repair a cache that let a change slip through.
+  pub async fn sync_profile_with_rehash(
+    &self,
+    app_handle: &crate::app_handle::AppHandle,
     profile: &BrowserProfile,
+    force_rehash: bool,
   ) -> SyncResult<()> {
     if profile.is_cross_os() {
       log::info!(
@@ -500,7 +644,7 @@ impl SyncEngine {
       let password = encryption::load_e2e_password()
         .map_err(|e| SyncError::InvalidData(format!("Failed to load E2E password: {e}")))?
         .ok_or_else(|| {
-          let _ = events::emit("profile-sync-e2e-password-required", ());
+          let _ = events::emit_empty("profile-sync-e2e-password-required");
           SyncError::InvalidData("E2E password not set".to_string())
         })?;
       let salt = profile.encryption_salt.as_deref().ok_or_else(|| {
@@ -535,14 +679,7 @@ impl SyncEngine {
       }
     );
 
-    let _ = events::emit(
-      "profile-sync-status",
-      serde_json::json!({
-        "profile_id": profile_id,
-        "profile_name": profile.name,
-        "status": "syncing"
-      }),
-    );
+    let _ = events::emit(&ProfileSyncStatus::new(&profile_id, "syncing").named(&profile.name));
 
     // Ensure profile directory exists
     fs::create_dir_all(&profile_dir).map_err(|e| {
@@ -558,7 +695,12 @@ impl SyncEngine {
 
     // Load or create hash cache
     let cache_path = get_cache_path(&profile_dir);
-    let mut hash_cache = HashCache::load(&cache_path);
+    let mut hash_cache = if force_rehash {
+      log::info!("Rehashing all files of profile {profile_id}, ignoring the hash cache");
+      HashCache::default()
+    } else {
+      HashCache::load(&cache_path)
+    };
 
     // Generate local manifest
     let local_manifest = generate_manifest(&profile_id, &profile_dir, &mut hash_cache)?;
@@ -595,14 +737,7 @@ impl SyncEngine {
 
     if diff.is_empty() {
       log::info!("Profile {} is already in sync", profile_id);
-      let _ = events::emit(
-        "profile-sync-status",
-        serde_json::json!({
-          "profile_id": profile_id,
-          "profile_name": profile.name,
-          "status": "synced"
-        }),
-      );
+      let _ = events::emit(&ProfileSyncStatus::new(&profile_id, "synced").named(&profile.name));
       return Ok(());
     }
 
@@ -622,38 +757,58 @@ impl SyncEngine {
       diff.files_to_delete_remote.len()
     );
 
-    let _ = events::emit(
-      "profile-sync-progress",
-      serde_json::json!({
-        "profile_id": profile_id,
-        "profile_name": profile.name,
-        "phase": "started",
-        "total_files": total_files,
-        "total_bytes": upload_bytes + download_bytes
-      }),
-    );
+    let _ = events::emit(&ProfileSyncProgress {
+      profile_id: profile_id.clone(),
+      profile_name: profile.name.clone(),
+      phase: "started".to_string(),
+      total_files: total_files as u64,
+      total_bytes: upload_bytes + download_bytes,
+      ..Default::default()
+    });
 
-    // Perform uploads
-    if !diff.files_to_upload.is_empty() {
-      self
-        .upload_profile_files(
-          app_handle,
-          &profile_id,
-          &profile.name,
-          &profile_dir,
-          &diff.files_to_upload,
-          encryption_key.as_ref(),
-          &key_prefix,
-          &cancel_flag,
-        )
-        .await?;
+    // A cookie store encrypted with key material outside the profile directory
+    // would reach every other device as a jar of empty values.
+    if diff
+      .files_to_upload
+      .iter()
+      .any(|f| f.path.ends_with("Cookies"))
+      && has_machine_bound_cookies(profile, &profile_dir)
+    {
+      return Err(SyncError::InvalidData(
+        "Cookies are encrypted with a key bound to this device; clear the profile's cookies before syncing it"
+          .to_string(),
+      ));
     }
 
+    // Perform uploads
+    let upload_outcome = self
+      .upload_profile_files(
+        &profile_id,
+        &profile.name,
+        &profile_dir,
+        &diff.files_to_upload,
+        encryption_key.as_ref(),
+        &key_prefix,
+        &cancel_flag,
+      )
+      .await?;
+
     if cancel_flag.load(Ordering::Relaxed) {
       log::info!("Sync cancelled for profile {} after uploads", profile_id);
       return Err(SyncError::Cancelled);
     }
 
+    let strict_uploads = SettingsManager::instance()
+      .load_settings()
+      .map(|s| s.strict_sync_uploads)
+      .unwrap_or(false);
+    if strict_uploads && !upload_outcome.failed.is_empty() {
+      return Err(SyncError::NetworkError(format!(
+        "Files failed to upload: {}. The remote manifest was left unchanged.",
+        upload_outcome.failed.join(", ")
+      )));
+    }
+
     // Perform downloads
     if !diff.files_to_download.is_empty() {
       self
@@ -674,6 +829,9 @@ impl SyncEngine {
       log::info!("Sync cancelled for profile {} after downloads", profile_id);
       return Err(SyncError::Cancelled);
     }
+    if !diff.files_to_download.is_empty() {
+      verify_downloaded_cookie_key(profile, &profile_dir);
+    }
 
     // Delete local files that don't exist remotely (when remote is newer)
     for path in &diff.files_to_delete_local {
@@ -709,7 +867,7 @@ impl SyncEngine {
     // on-disk state is unchanged and the original manifest is still accurate.
     let local_changed =
       !diff.files_to_download.is_empty() || !diff.files_to_delete_local.is_empty();
-    let final_manifest = if local_changed {
+    let mut final_manifest = if local_changed {
       let mut new_cache = HashCache::load(&cache_path);
       let mut regenerated = generate_manifest(&profile_id, &profile_dir, &mut new_cache)?;
       new_cache.save(&cache_path)?;
@@ -721,6 +879,13 @@ impl SyncEngine {
       m
     };
 
+    // Never advertise a file the server doesn't have.
+    exclude_failed_uploads(
+      &mut final_manifest,
+      &upload_outcome,
+      remote_manifest.as_ref(),
+    );
+
     // Upload manifest.json last for atomicity
     self
       .upload_manifest(
@@ -734,10 +899,13 @@ impl SyncEngine {
     // Sync completed successfully — clean up resume state
     SyncResumeState::delete(&profile_dir);
 
-    // Sync associated proxy, group, and VPN
+    // Sync associated proxy, proxy pool, group, and VPN
     if let Some(proxy_id) = &profile.proxy_id {
       let _ = self.sync_proxy(proxy_id, Some(app_handle)).await;
     }
+    if let Some(pool_id) = &profile.proxy_pool_id {
+      let _ = self.sync_proxy_pool(pool_id, Some(app_handle)).await;
+    }
     if let Some(group_id) = &profile.group_id {
       let _ = self.sync_group(group_id, Some(app_handle)).await;
     }
@@ -757,21 +925,73 @@ impl SyncEngine {
       .map_err(|e| {
         SyncError::IoError(format!("Failed to save reconciled profile metadata: {e}"))
       })?;
-    let _ = events::emit("profiles-changed", ());
-
-    let _ = events::emit(
-      "profile-sync-status",
-      serde_json::json!({
-        "profile_id": profile_id,
-        "profile_name": profile.name,
-        "status": "synced"
-      }),
-    );
+    let _ = events::emit_empty("profiles-changed");
+
+    // A partial sync left `failed_files` out of the remote manifest; the next
+    // sync retries them.
+    let _ = events::emit(&ProfileSyncStatus {
+      partial: Some(!upload_outcome.failed.is_empty()),
+      failed_files: Some(upload_outcome.failed),
+      ..ProfileSyncStatus::new(&profile_id, "synced").named(&profile.name)
+    });
 
     log::info!("Profile {} synced successfully", profile_id);
     Ok(())
   }
 
+  /// Run the manifest and diff steps of `sync_profile_with_rehash` and stop
+  /// before any transfer. Hashing works on a copy of the hash cache, so a
+  /// preview never rewrites it.
+  pub async fn preview_profile_sync(&self, profile: &BrowserProfile) -> SyncResult<SyncPreview> {
+    if profile.is_cross_os() {
+      return Err(SyncError::InvalidData(
+        "Cross-OS profiles only sync metadata".to_string(),
+      ));
+    }
+
+    let encryption_key = if profile.is_encrypted_sync() {
+      let password = encryption::load_e2e_password()
+        .map_err(|e| SyncError::InvalidData(format!("Failed to load E2E password: {e}")))?
+        .ok_or_else(|| SyncError::InvalidData("E2E password not set".to_string()))?;
+      let salt = profile.encryption_salt.as_deref().ok_or_else(|| {
+        SyncError::InvalidData("Encryption salt missing on encrypted profile".to_string())
+      })?;
+      let key = encryption::derive_profile_key(&password, salt)
+        .map_err(|e| SyncError::InvalidData(format!("Key derivation failed: {e}")))?;
+      Some(key)
+    } else {
+      None
+    };
+
+    let profile_id = profile.id.to_string();
+    let profile_dir = ProfileManager::instance()
+      .get_profiles_dir()
+      .join(&profile_id);
+    if profile.process_id.is_none() {
+      checkpoint_sqlite_wal_files(&profile_dir);
+    }
+
+    let mut hash_cache = HashCache::load(&get_cache_path(&profile_dir));
+    let local_manifest = generate_manifest(&profile_id, &profile_dir, &mut hash_cache)?;
+
+    let key_prefix = Self::get_team_key_prefix(profile).await;
+    let remote_manifest_key = format!("{}profiles/{}/manifest.json", key_prefix, profile_id);
+    let remote_manifest = tokio::time::timeout(
+      PREVIEW_REMOTE_TIMEOUT,
+      self.download_manifest(&remote_manifest_key, encryption_key.as_ref()),
+    )
+    .await
+    .map_err(|_| {
+      SyncError::ConnectionError(format!(
+        "Sync server did not answer within {}s",
+        PREVIEW_REMOTE_TIMEOUT.as_secs()
+      ))
+    })??;
+
+    let diff = compute_diff(&local_manifest, remote_manifest.as_ref());
+    Ok(diff.preview(remote_manifest.is_some()))
+  }
+
   async fn download_manifest(
     &self,
     key: &str,
@@ -891,6 +1111,7 @@ impl SyncEngine {
     let mut remote = self.download_profile_metadata(&remote_key).await?;
     // Process state is device-local and deliberately stripped from uploads.
     remote.process_id = profile.process_id;
+    remote.cdp_port = profile.cdp_port;
     remote.last_launch = profile.last_launch;
     remote.last_sync = profile.last_sync;
     ProfileManager::instance()
@@ -899,34 +1120,42 @@ impl SyncEngine {
     Ok(remote)
   }
 
-  /// Sync only metadata for cross-OS profiles (tags, notes, proxies, groups).
-  /// No browser files are synced.
-  async fn sync_cross_os_metadata(
+  /// Reconcile metadata.json and the profile's proxy/group without touching
+  /// browser files. Safe while the browser is running.
+  pub async fn sync_profile_metadata(
     &self,
-    app_handle: &tauri::AppHandle,
+    app_handle: &crate::app_handle::AppHandle,
     profile: &BrowserProfile,
-  ) -> SyncResult<()> {
-    let profile_id = profile.id.to_string();
+  ) -> SyncResult<BrowserProfile> {
     let reconciled_profile = self.reconcile_profile_metadata(profile).await?;
-    let profile = &reconciled_profile;
 
     // Sync associated entities
-    if let Some(proxy_id) = &profile.proxy_id {
+    if let Some(proxy_id) = &reconciled_profile.proxy_id {
       let _ = self.sync_proxy(proxy_id, Some(app_handle)).await;
     }
-    if let Some(group_id) = &profile.group_id {
+    if let Some(pool_id) = &reconciled_profile.proxy_pool_id {
+      let _ = self.sync_proxy_pool(pool_id, Some(app_handle)).await;
+    }
+    if let Some(group_id) = &reconciled_profile.group_id {
       let _ = self.sync_group(group_id, Some(app_handle)).await;
     }
 
-    let _ = events::emit("profiles-changed", ());
-    let _ = events::emit(
-      "profile-sync-status",
-      serde_json::json!({
-        "profile_id": profile_id,
-        "profile_name": profile.name,
-        "status": "synced"
-      }),
-    );
+    Ok(reconciled_profile)
+  }
+
+  /// Sync only metadata for cross-OS profiles (tags, notes, proxies, groups).
+  /// No browser files are synced.
+  async fn sync_cross_os_metadata(
+    &self,
+    app_handle: &crate::app_handle::AppHandle,
+    profile: &BrowserProfile,
+  ) -> SyncResult<()> {
+    let profile_id = profile.id.to_string();
+    let reconciled_profile = self.sync_profile_metadata(app_handle, profile).await?;
+    let profile = &reconciled_profile;
+
+    let _ = events::emit_empty("profiles-changed");
+    let _ = events::emit(&ProfileSyncStatus::new(&profile_id, "synced").named(&profile.name));
 
     log::info!("Cross-OS profile {} metadata synced", profile_id);
     Ok(())
@@ -940,6 +1169,8 @@ impl SyncEngine {
   ) -> SyncResult<()> {
     let mut sanitized = profile.clone();
     sanitized.process_id = None;
+    sanitized.cdp_port = None;
+    sanitized.applied_resource_limits = None;
     sanitized.last_launch = None;
     sanitized.last_sync = None; // Avoid triggering sync loop on timestamp change
 
@@ -954,10 +1185,13 @@ impl SyncEngine {
     Ok(())
   }
 
+  /// Uploads `files`, then checks with `stat` that they arrived. Files that
+  /// fail either step go through up to `UPLOAD_RETRY_ROUNDS` more passes with
+  /// freshly presigned URLs. A critical file that still fails aborts the sync;
+  /// other leftovers are returned for the caller to handle.
   #[allow(clippy::too_many_arguments)]
   async fn upload_profile_files(
     &self,
-    _app_handle: &tauri::AppHandle,
     profile_id: &str,
     profile_name: &str,
     profile_dir: &Path,
@@ -965,9 +1199,10 @@ impl SyncEngine {
     encryption_key: Option<&[u8; 32]>,
     key_prefix: &str,
     cancel_flag: &Arc<AtomicBool>,
-  ) -> SyncResult<()> {
+  ) -> SyncResult<UploadOutcome> {
+    let mut outcome = UploadOutcome::default();
     if files.is_empty() {
-      return Ok(());
+      return Ok(outcome);
     }
 
     // Load resume state to skip already-uploaded files
@@ -1001,7 +1236,7 @@ impl SyncEngine {
     );
 
     if files_to_process.is_empty() {
-      return Ok(());
+      return Ok(outcome);
     }
 
     // Initialize resume state if not resuming
@@ -1015,27 +1250,6 @@ impl SyncEngine {
     }
     let resume_state = Arc::new(TokioMutex::new(resume_state.unwrap()));
 
-    // Get batch presigned URLs
-    let items: Vec<(String, Option<String>)> = files_to_process
-      .iter()
-      .map(|f| {
-        let key = format!("{}profiles/{}/files/{}", key_prefix, profile_id, f.path);
-        let content_type = mime_guess::from_path(&f.path)
-          .first()
-          .map(|m| m.to_string());
-        (key, content_type)
-      })
-      .collect();
-
-    let batch_response = self.client.presign_upload_batch(items).await?;
-
-    // Build URL map
-    let url_map: HashMap<String, String> = batch_response
-      .items
-      .into_iter()
-      .map(|item| (item.key, item.url))
-      .collect();
-
     let total_bytes: u64 = files.iter().map(|f| f.size).sum();
     let already_bytes: u64 = files
       .iter()
@@ -1059,19 +1273,159 @@ impl SyncEngine {
       .store(already_bytes, Ordering::Relaxed);
     tracker.emit_final();
 
+    // Reject paths that would escape the profile dir (path traversal /
+    // absolute path). On download the manifest is remote-controlled, so this
+    // is the load-bearing containment check; on upload it is defense-in-depth.
+    // Legitimate profile files are always plain relative paths, so a real file
+    // is never skipped.
+    let mut pending: Vec<&super::manifest::ManifestFileEntry> = files_to_process
+      .into_iter()
+      .filter(|f| {
+        let safe = is_safe_manifest_path(&f.path);
+        if !safe {
+          log::warn!("Skipping file with unsafe relative path: {:?}", f.path);
+        }
+        safe
+      })
+      .collect();
+
+    let mut failures: Vec<(String, String, bool)> = Vec::new();
+    for round in 0..=UPLOAD_RETRY_ROUNDS {
+      if round > 0 {
+        log::info!(
+          "Retrying {} failed uploads for profile {} (round {}/{})",
+          pending.len(),
+          profile_id,
+          round,
+          UPLOAD_RETRY_ROUNDS
+        );
+        tokio::time::sleep(std::time::Duration::from_secs(round as u64)).await;
+        tracker.reset_remaining(pending.len() as u64, pending.iter().map(|f| f.size).sum());
+      }
+
+      let round_result = self
+        .upload_round(
+          profile_id,
+          profile_dir,
+          &pending,
+          encryption_key,
+          key_prefix,
+          cancel_flag,
+          &tracker,
+          &resume_state,
+        )
+        .await?;
+      outcome.vanished.extend(round_result.vanished);
+      failures = round_result.failures;
+
+      if cancel_flag.load(Ordering::Relaxed) {
+        break;
+      }
+
+      for path in self
+        .verify_uploaded(profile_id, key_prefix, &round_result.uploaded)
+        .await
+      {
+        resume_state.lock().await.completed_files.remove(&path);
+        let critical = is_critical_file(&path);
+        failures.push((
+          path.clone(),
+          format!("{path} is missing on the server after upload"),
+          critical,
+        ));
+      }
+
+      if failures.is_empty() {
+        break;
+      }
+      let failed: HashSet<&str> = failures.iter().map(|(p, _, _)| p.as_str()).collect();
+      pending.retain(|f| failed.contains(f.path.as_str()));
+    }
+
+    // Final resume state save
+    {
+      let state = resume_state.lock().await;
+      let _ = state.save(profile_dir);
+    }
+
+    tracker.emit_final();
+
+    let mut critical_failures = Vec::new();
+    for (path, msg, critical) in failures {
+      log::warn!("Upload failed for profile {}: {}", profile_id, msg);
+      if critical {
+        critical_failures.push(path);
+      } else {
+        outcome.failed.push(path);
+      }
+    }
+
+    if !outcome.failed.is_empty() {
+      log::warn!(
+        "Upload completed with {} non-critical failures for profile {}",
+        outcome.failed.len(),
+        profile_id
+      );
+    }
+
+    if !critical_failures.is_empty() {
+      return Err(SyncError::IoError(format!(
+        "Critical files failed to upload: {}. Sync aborted to prevent data loss.",
+        critical_failures.join(", ")
+      )));
+    }
+
+    Ok(outcome)
+  }
+
+  /// One presign-and-upload pass over `files`.
+  #[allow(clippy::too_many_arguments)]
+  async fn upload_round(
+    &self,
+    profile_id: &str,
+    profile_dir: &Path,
+    files: &[&super::manifest::ManifestFileEntry],
+    encryption_key: Option<&[u8; 32]>,
+    key_prefix: &str,
+    cancel_flag: &Arc<AtomicBool>,
+    tracker: &Arc<SyncProgressTracker>,
+    resume_state: &Arc<TokioMutex<SyncResumeState>>,
+  ) -> SyncResult<UploadRound> {
+    let mut round = UploadRound::default();
+
+    // Get batch presigned URLs
+    let items: Vec<(String, Option<String>)> = files
+      .iter()
+      .map(|f| {
+        let key = format!("{}profiles/{}/files/{}", key_prefix, profile_id, f.path);
+        let content_type = mime_guess::from_path(&f.path)
+          .first()
+          .map(|m| m.to_string());
+        (key, content_type)
+      })
+      .collect();
+
+    let batch_response = self.client.presign_upload_batch(items).await?;
+
+    // Build URL map
+    let url_map: HashMap<String, String> = batch_response
+      .items
+      .into_iter()
+      .map(|item| (item.key, item.url))
+      .collect();
+
     let semaphore = Arc::new(Semaphore::new(SYNC_CONCURRENCY));
     let client = self.client.clone();
     let profile_dir = profile_dir.to_path_buf();
     let profile_id_owned = profile_id.to_string();
     let enc_key = encryption_key.copied();
 
-    type FileResult = Result<String, (String, String, bool)>;
-    let mut handles: Vec<tokio::task::JoinHandle<FileResult>> = Vec::new();
+    let mut handles: Vec<tokio::task::JoinHandle<FileUploadResult>> = Vec::new();
 
     // Counter for batching resume state saves
     let save_counter = Arc::new(AtomicU64::new(0));
 
-    for file in &files_to_process {
+    for file in files {
       if cancel_flag.load(Ordering::Relaxed) {
         log::info!(
           "Upload cancelled for profile {} before scheduling more files",
@@ -1079,15 +1433,6 @@ impl SyncEngine {
         );
         break;
       }
-      // Reject paths that would escape the profile dir (path traversal /
-      // absolute path). On download the manifest is remote-controlled, so this
-      // is the load-bearing containment check; on upload it is defense-in-depth.
-      // Legitimate profile files are always plain relative paths, so a real file
-      // is never skipped.
-      if !is_safe_manifest_path(&file.path) {
-        log::warn!("Skipping file with unsafe relative path: {:?}", file.path);
-        continue;
-      }
       let sem = semaphore.clone();
       let file_path = profile_dir.join(&file.path);
       let relative_path = file.path.clone();
@@ -1096,21 +1441,19 @@ impl SyncEngine {
         "{}profiles/{}/files/{}",
         key_prefix, profile_id_owned, file.path
       );
-      let url = url_map.get(&remote_key).cloned();
       let critical = is_critical_file(&file.path);
 
-      if url.is_none() {
+      let Some(url) = url_map.get(&remote_key).cloned() else {
         log::warn!("No presigned URL for {}", remote_key);
-        if critical {
-          return Err(SyncError::NetworkError(format!(
-            "No presigned URL for critical file: {}",
-            file.path
-          )));
-        }
+        tracker.record_failure();
+        round.failures.push((
+          relative_path,
+          format!("No presigned URL for {}", file.path),
+          critical,
+        ));
         continue;
-      }
+      };
 
-      let url = url.unwrap();
       let client = client.clone();
       let tracker = tracker.clone();
       let resume_state = resume_state.clone();
@@ -1133,7 +1476,7 @@ impl SyncEngine {
           Err(e) if e.kind() == std::io::ErrorKind::NotFound && !critical => {
             log::debug!("File disappeared, skipping: {}", file_path.display());
             tracker.record_success(0);
-            return Ok(relative_path);
+            return Ok(FileUploaded::Vanished(relative_path));
           }
           Err(e) => {
             let msg = format!("Failed to read {}: {}", file_path.display(), e);
@@ -1177,7 +1520,7 @@ impl SyncEngine {
                 }
               }
 
-              return Ok(relative_path);
+              return Ok(FileUploaded::Uploaded(relative_path));
             }
             Err(e) => {
               last_err = format!("{}", e);
@@ -1206,52 +1549,63 @@ impl SyncEngine {
       }));
     }
 
-    // Collect results
-    let mut critical_failures = Vec::new();
-    let mut non_critical_failures = Vec::new();
-
     for handle in handles {
       match handle.await {
-        Ok(Ok(_)) => {}
-        Ok(Err((path, msg, true))) => critical_failures.push((path, msg)),
-        Ok(Err((path, msg, false))) => non_critical_failures.push((path, msg)),
+        Ok(Ok(FileUploaded::Uploaded(path))) => round.uploaded.push(path),
+        Ok(Ok(FileUploaded::Vanished(path))) => round.vanished.push(path),
+        Ok(Err(failure)) => round.failures.push(failure),
         Err(e) => {
           log::warn!("Upload task panicked: {}", e);
         }
       }
     }
 
-    // Final resume state save
-    {
-      let state = resume_state.lock().await;
-      let _ = state.save(&profile_dir);
-    }
+    Ok(round)
+  }
 
-    tracker.emit_final();
+  /// Paths among `uploaded` that `stat` can't find on the server. Checks all
+  /// of them up to `UPLOAD_VERIFY_ALL_THRESHOLD` files, a random sample of
+  /// `UPLOAD_VERIFY_SAMPLE` above that. A failed `stat` counts as missing.
+  async fn verify_uploaded(
+    &self,
+    profile_id: &str,
+    key_prefix: &str,
+    uploaded: &[String],
+  ) -> Vec<String> {
+    use futures_util::StreamExt;
+
+    let sample = verification_sample(uploaded);
+    let missing: Vec<String> = futures_util::stream::iter(sample)
+      .map(|path| async move {
+        let key = format!("{}profiles/{}/files/{}", key_prefix, profile_id, path);
+        match self.client.stat(&key).await {
+          Ok(stat) if stat.exists => None,
+          Ok(_) => Some(path),
+          Err(e) => {
+            log::warn!("Failed to verify upload of {}: {}", path, e);
+            Some(path)
+          }
+        }
+      })
+      .buffer_unordered(SYNC_CONCURRENCY)
+      .filter_map(std::future::ready)
+      .collect()
+      .await;
 
-    if !non_critical_failures.is_empty() {
+    if !missing.is_empty() {
       log::warn!(
-        "Upload completed with {} non-critical failures for profile {}",
-        non_critical_failures.len(),
-        profile_id_owned
+        "{} uploaded files of profile {} are missing on the server",
+        missing.len(),
+        profile_id
       );
     }
-
-    if !critical_failures.is_empty() {
-      let file_list: Vec<&str> = critical_failures.iter().map(|(p, _)| p.as_str()).collect();
-      return Err(SyncError::IoError(format!(
-        "Critical files failed to upload: {}. Sync aborted to prevent data loss.",
-        file_list.join(", ")
-      )));
-    }
-
-    Ok(())
+    missing
   }
 
   #[allow(clippy::too_many_arguments)]
   async fn download_profile_files(
     &self,
-    _app_handle: &tauri::AppHandle,
+    _app_handle: &crate::app_handle::AppHandle,
     profile_id: &str,
     profile_name: &str,
     profile_dir: &Path,
@@ -1527,7 +1881,7 @@ impl SyncEngine {
   async fn sync_proxy(
     &self,
     proxy_id: &str,
-    app_handle: Option<&tauri::AppHandle>,
+    app_handle: Option<&crate::app_handle::AppHandle>,
   ) -> SyncResult<()> {
     let proxy_manager = &crate::proxy_manager::PROXY_MANAGER;
     let proxies = proxy_manager.get_stored_proxies();
@@ -1585,7 +1939,7 @@ impl SyncEngine {
     // Update local proxy with new last_sync (always write plaintext locally)
     let proxy_manager = &crate::proxy_manager::PROXY_MANAGER;
     let proxy_file = proxy_manager.get_proxy_file_path(&proxy.id);
-    fs::write(&proxy_file, &json).map_err(|e| {
+    crate::json_file::write(&proxy_file, json.as_bytes(), true).map_err(|e| {
       SyncError::IoError(format!(
         "Failed to update proxy file {}: {e}",
         proxy_file.display()
@@ -1599,7 +1953,7 @@ impl SyncEngine {
   async fn download_proxy(
     &self,
     proxy_id: &str,
-    app_handle: Option<&tauri::AppHandle>,
+    app_handle: Option<&crate::app_handle::AppHandle>,
   ) -> SyncResult<()> {
     let remote_key = format!("proxies/{}.json", proxy_id);
     let presign = self.client.presign_download(&remote_key).await?;
@@ -1631,7 +1985,7 @@ impl SyncEngine {
 
     let json = serde_json::to_string_pretty(&proxy)
       .map_err(|e| SyncError::SerializationError(format!("Failed to serialize proxy: {e}")))?;
-    fs::write(&proxy_file, &json).map_err(|e| {
+    crate::json_file::write(&proxy_file, json.as_bytes(), true).map_err(|e| {
       SyncError::IoError(format!(
         "Failed to write proxy file {}: {e}",
         proxy_file.display()
@@ -1645,26 +1999,148 @@ impl SyncEngine {
     // download_vpn/download_extension.
     proxy_manager.upsert_stored_proxy(proxy.clone());
 
-    // Emit event for UI update
+    // Emit event for UI update
+    if let Some(_handle) = app_handle {
+      let _ = events::emit_empty("stored-proxies-changed");
+      let _ = events::emit(&ProxySyncStatus::new(proxy_id, "synced"));
+    }
+
+    log::info!("Proxy {} downloaded", proxy_id);
+    Ok(())
+  }
+
+  /// Sync a proxy pool like a stored proxy, then its members, so the pool is
+  /// usable on every device that has it.
+  async fn sync_proxy_pool(
+    &self,
+    pool_id: &str,
+    app_handle: Option<&crate::app_handle::AppHandle>,
+  ) -> SyncResult<()> {
+    let proxy_manager = &crate::proxy_manager::PROXY_MANAGER;
+    let local_pool = proxy_manager.get_proxy_pool(pool_id);
+
+    let remote_key = format!("proxy_pools/{}.json", pool_id);
+    let stat = self.client.stat(&remote_key).await?;
+
+    match (local_pool, stat.exists) {
+      (Some(pool), true) => {
+        let local_updated = pool.updated_at.unwrap_or(0);
+        let remote_updated = self.remote_updated_at(&stat, &remote_key).await;
+
+        if remote_updated > local_updated {
+          self.download_proxy_pool(pool_id, app_handle).await?;
+        } else if local_updated > remote_updated {
+          self.upload_proxy_pool(&pool).await?;
+        }
+      }
+      (Some(pool), false) => {
+        self.upload_proxy_pool(&pool).await?;
+      }
+      (None, true) => {
+        self.download_proxy_pool(pool_id, app_handle).await?;
+      }
+      (None, false) => {
+        log::debug!("Proxy pool {} not found locally or remotely", pool_id);
+      }
+    }
+
+    if let Some(pool) = proxy_manager.get_proxy_pool(pool_id) {
+      for proxy_id in &pool.proxy_ids {
+        let _ = self.sync_proxy(proxy_id, app_handle).await;
+      }
+    }
+
+    Ok(())
+  }
+
+  async fn upload_proxy_pool(&self, pool: &crate::proxy_manager::ProxyPool) -> SyncResult<()> {
+    let mut updated_pool = pool.clone();
+    updated_pool.last_sync = Some(
+      std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs(),
+    );
+
+    let json = serde_json::to_string_pretty(&updated_pool)
+      .map_err(|e| SyncError::SerializationError(format!("Failed to serialize proxy pool: {e}")))?;
+
+    let remote_key = format!("proxy_pools/{}.json", pool.id);
+    self
+      .upload_config_json(&remote_key, &json, updated_pool.updated_at.unwrap_or(0))
+      .await?;
+
+    let proxy_manager = &crate::proxy_manager::PROXY_MANAGER;
+    let pool_file = proxy_manager.get_pool_file_path(&pool.id);
+    crate::json_file::write(&pool_file, json.as_bytes(), false).map_err(|e| {
+      SyncError::IoError(format!(
+        "Failed to update proxy pool file {}: {e}",
+        pool_file.display()
+      ))
+    })?;
+    proxy_manager.upsert_proxy_pool(updated_pool);
+
+    log::info!("Proxy pool {} uploaded", pool.id);
+    Ok(())
+  }
+
+  async fn download_proxy_pool(
+    &self,
+    pool_id: &str,
+    app_handle: Option<&crate::app_handle::AppHandle>,
+  ) -> SyncResult<()> {
+    let remote_key = format!("proxy_pools/{}.json", pool_id);
+    let presign = self.client.presign_download(&remote_key).await?;
+    let raw = self.client.download_bytes(&presign.url).await?;
+
+    let data = encryption::maybe_unseal_after_download(&raw)
+      .map_err(|e| SyncError::InvalidData(format!("Failed to unseal proxy pool: {e}")))?;
+
+    let mut pool: crate::proxy_manager::ProxyPool = serde_json::from_slice(&data).map_err(|e| {
+      SyncError::SerializationError(format!("Failed to parse proxy pool JSON: {e}"))
+    })?;
+
+    pool.last_sync = Some(
+      std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs(),
+    );
+
+    let proxy_manager = &crate::proxy_manager::PROXY_MANAGER;
+    let pool_file = proxy_manager.get_pool_file_path(&pool.id);
+    if let Some(parent) = pool_file.parent() {
+      fs::create_dir_all(parent).map_err(|e| {
+        SyncError::IoError(format!(
+          "Failed to create proxy pool directory {}: {e}",
+          parent.display()
+        ))
+      })?;
+    }
+
+    let json = serde_json::to_string_pretty(&pool)
+      .map_err(|e| SyncError::SerializationError(format!("Failed to serialize proxy pool: {e}")))?;
+    crate::json_file::write(&pool_file, json.as_bytes(), false).map_err(|e| {
+      SyncError::IoError(format!(
+        "Failed to write proxy pool file {}: {e}",
+        pool_file.display()
+      ))
+    })?;
+
+    proxy_manager.upsert_proxy_pool(pool);
+
     if let Some(_handle) = app_handle {
-      let _ = events::emit("stored-proxies-changed", ());
-      let _ = events::emit(
-        "proxy-sync-status",
-        serde_json::json!({
-          "id": proxy_id,
-          "status": "synced"
-        }),
-      );
+      let _ = events::emit_empty("proxy-pools-changed");
     }
 
-    log::info!("Proxy {} downloaded", proxy_id);
+    log::info!("Proxy pool {} downloaded", pool_id);
     Ok(())
   }
 
   async fn sync_group(
     &self,
     group_id: &str,
-    app_handle: Option<&tauri::AppHandle>,
+    app_handle: Option<&crate::app_handle::AppHandle>,
   ) -> SyncResult<()> {
     let local_group = {
       let group_manager = crate::group_manager::GROUP_MANAGER.lock().unwrap();
@@ -1736,7 +2212,7 @@ impl SyncEngine {
   async fn download_group(
     &self,
     group_id: &str,
-    app_handle: Option<&tauri::AppHandle>,
+    app_handle: Option<&crate::app_handle::AppHandle>,
   ) -> SyncResult<()> {
     let remote_key = format!("groups/{}.json", group_id);
     let presign = self.client.presign_download(&remote_key).await?;
@@ -1765,20 +2241,62 @@ impl SyncEngine {
 
     // Emit event for UI update
     if let Some(_handle) = app_handle {
-      let _ = events::emit("groups-changed", ());
-      let _ = events::emit(
-        "group-sync-status",
-        serde_json::json!({
-          "id": group_id,
-          "status": "synced"
-        }),
-      );
+      let _ = events::emit_empty("groups-changed");
+      let _ = events::emit(&GroupSyncStatus::new(group_id, "synced"));
     }
 
     log::info!("Group {} downloaded", group_id);
     Ok(())
   }
 
+  /// Saved table views are synced as a single document; the newer side wins.
+  pub async fn sync_saved_views(
+    &self,
+    app_handle: Option<&crate::app_handle::AppHandle>,
+  ) -> SyncResult<()> {
+    let manager = SettingsManager::instance();
+    let local = manager
+      .saved_views_document()
+      .map_err(|e| SyncError::InvalidData(format!("Failed to load saved views: {e}")))?;
+
+    let remote_key = "settings/saved_views.json";
+    let stat = self.client.stat(remote_key).await?;
+    let remote_updated = if stat.exists {
+      self.remote_updated_at(&stat, remote_key).await
+    } else {
+      0
+    };
+
+    if stat.exists && remote_updated > local.updated_at {
+      let presign = self.client.presign_download(remote_key).await?;
+      let raw = self.client.download_bytes(&presign.url).await?;
+      let data = encryption::maybe_unseal_after_download(&raw)
+        .map_err(|e| SyncError::InvalidData(format!("Failed to unseal saved views: {e}")))?;
+      let remote: crate::settings_manager::SavedViewsDocument = serde_json::from_slice(&data)
+        .map_err(|e| {
+          SyncError::SerializationError(format!("Failed to parse saved views JSON: {e}"))
+        })?;
+
+      manager
+        .save_saved_views(remote.views, remote.updated_at)
+        .map_err(|e| SyncError::InvalidData(format!("Failed to save saved views: {e}")))?;
+      if app_handle.is_some() {
+        let _ = events::emit_empty("saved-views-changed");
+      }
+      log::info!("Saved views downloaded");
+    } else if local.updated_at > remote_updated {
+      let json = serde_json::to_string_pretty(&local).map_err(|e| {
+        SyncError::SerializationError(format!("Failed to serialize saved views: {e}"))
+      })?;
+      self
+        .upload_config_json(remote_key, &json, local.updated_at)
+        .await?;
+      log::info!("Saved views uploaded");
+    }
+
+    Ok(())
+  }
+
   pub async fn sync_proxy_by_id(&self, proxy_id: &str) -> SyncResult<()> {
     self.sync_proxy(proxy_id, None).await
   }
@@ -1786,11 +2304,19 @@ impl SyncEngine {
   pub async fn sync_proxy_by_id_with_handle(
     &self,
     proxy_id: &str,
-    app_handle: &tauri::AppHandle,
+    app_handle: &crate::app_handle::AppHandle,
   ) -> SyncResult<()> {
     self.sync_proxy(proxy_id, Some(app_handle)).await
   }
 
+  pub async fn sync_proxy_pool_by_id_with_handle(
+    &self,
+    pool_id: &str,
+    app_handle: &crate::app_handle::AppHandle,
+  ) -> SyncResult<()> {
+    self.sync_proxy_pool(pool_id, Some(app_handle)).await
+  }
+
   pub async fn sync_group_by_id(&self, group_id: &str) -> SyncResult<()> {
     self.sync_group(group_id, None).await
   }
@@ -1798,7 +2324,7 @@ impl SyncEngine {
   pub async fn sync_group_by_id_with_handle(
     &self,
     group_id: &str,
-    app_handle: &tauri::AppHandle,
+    app_handle: &crate::app_handle::AppHandle,
   ) -> SyncResult<()> {
     self.sync_group(group_id, Some(app_handle)).await
   }
@@ -1853,6 +2379,19 @@ impl SyncEngine {
     Ok(())
   }
 
+  pub async fn delete_proxy_pool(&self, pool_id: &str) -> SyncResult<()> {
+    let remote_key = format!("proxy_pools/{}.json", pool_id);
+    let tombstone_key = format!("tombstones/proxy_pools/{}.json", pool_id);
+
+    self
+      .client
+      .delete(&remote_key, Some(&tombstone_key))
+      .await?;
+
+    log::info!("Proxy pool {} deleted from sync", pool_id);
+    Ok(())
+  }
+
   pub async fn delete_group(&self, group_id: &str) -> SyncResult<()> {
     let remote_key = format!("groups/{}.json", group_id);
     let tombstone_key = format!("tombstones/groups/{}.json", group_id);
@@ -1866,7 +2405,11 @@ impl SyncEngine {
     Ok(())
   }
 
-  async fn sync_vpn(&self, vpn_id: &str, app_handle: Option<&tauri::AppHandle>) -> SyncResult<()> {
+  async fn sync_vpn(
+    &self,
+    vpn_id: &str,
+    app_handle: Option<&crate::app_handle::AppHandle>,
+  ) -> SyncResult<()> {
     let local_vpn = {
       let storage = crate::vpn::VPN_STORAGE.lock().unwrap();
       storage.load_config(vpn_id).ok()
@@ -1933,7 +2476,7 @@ impl SyncEngine {
   async fn download_vpn(
     &self,
     vpn_id: &str,
-    app_handle: Option<&tauri::AppHandle>,
+    app_handle: Option<&crate::app_handle::AppHandle>,
   ) -> SyncResult<()> {
     let remote_key = format!("vpns/{}.json", vpn_id);
     let presign = self.client.presign_download(&remote_key).await?;
@@ -1963,14 +2506,8 @@ impl SyncEngine {
 
     // Emit event for UI update
     if let Some(_handle) = app_handle {
-      let _ = events::emit("vpn-configs-changed", ());
-      let _ = events::emit(
-        "vpn-sync-status",
-        serde_json::json!({
-          "id": vpn_id,
-          "status": "synced"
-        }),
-      );
+      let _ = events::emit_empty("vpn-configs-changed");
+      let _ = events::emit(&VpnSyncStatus::new(vpn_id, "synced"));
     }
 
     log::info!("VPN {} downloaded", vpn_id);
@@ -1980,7 +2517,7 @@ impl SyncEngine {
   pub async fn sync_vpn_by_id_with_handle(
     &self,
     vpn_id: &str,
-    app_handle: &tauri::AppHandle,
+    app_handle: &crate::app_handle::AppHandle,
   ) -> SyncResult<()> {
     self.sync_vpn(vpn_id, Some(app_handle)).await
   }
@@ -2003,7 +2540,7 @@ impl SyncEngine {
   async fn sync_extension(
     &self,
     ext_id: &str,
-    app_handle: Option<&tauri::AppHandle>,
+    app_handle: Option<&crate::app_handle::AppHandle>,
   ) -> SyncResult<()> {
     let local_ext = {
       let manager = crate::extension_manager::EXTENSION_MANAGER.lock().unwrap();
@@ -2058,20 +2595,15 @@ impl SyncEngine {
 
     // Also upload the extension file data — encrypted as a sealed envelope
     // when E2E is on (the binary is the secret here, not just the metadata).
-    let file_path = {
+    // Unpacked extensions travel as a zip of their directory.
+    let file_data = {
       let manager = crate::extension_manager::EXTENSION_MANAGER.lock().unwrap();
-      let file_dir = manager.get_file_dir_public(&ext.id);
-      file_dir.join(&ext.file_name)
+      manager
+        .read_sync_payload(ext)
+        .map_err(|e| SyncError::IoError(format!("Failed to read extension {} data: {e}", ext.id)))?
     };
 
-    if file_path.exists() {
-      let file_data = fs::read(&file_path).map_err(|e| {
-        SyncError::IoError(format!(
-          "Failed to read extension file {}: {e}",
-          file_path.display()
-        ))
-      })?;
-
+    if let Some(file_data) = file_data {
       let (file_payload, file_content_type) = encryption::maybe_seal_for_upload(&file_data)
         .map_err(|e| SyncError::InvalidData(format!("Failed to seal extension file: {e}")))?;
 
@@ -2101,7 +2633,7 @@ impl SyncEngine {
   async fn download_extension(
     &self,
     ext_id: &str,
-    app_handle: Option<&tauri::AppHandle>,
+    app_handle: Option<&crate::app_handle::AppHandle>,
   ) -> SyncResult<()> {
     let remote_key = format!("extensions/{}.json", ext_id);
     let presign = self.client.presign_download(&remote_key).await?;
@@ -2130,22 +2662,11 @@ impl SyncEngine {
         .map_err(|e| SyncError::InvalidData(format!("Failed to unseal extension file: {e}")))?;
 
       let manager = crate::extension_manager::EXTENSION_MANAGER.lock().unwrap();
-      let file_dir = manager.get_file_dir_public(&ext.id);
-      drop(manager);
-
-      fs::create_dir_all(&file_dir).map_err(|e| {
-        SyncError::IoError(format!(
-          "Failed to create extension file dir {}: {e}",
-          file_dir.display()
-        ))
-      })?;
-      let file_path = file_dir.join(&ext.file_name);
-      fs::write(&file_path, &file_data).map_err(|e| {
-        SyncError::IoError(format!(
-          "Failed to write extension file {}: {e}",
-          file_path.display()
-        ))
-      })?;
+      manager
+        .write_sync_payload(&mut ext, &file_data)
+        .map_err(|e| {
+          SyncError::IoError(format!("Failed to write extension {} data: {e}", ext.id))
+        })?;
     }
 
     // Save or update local extension
@@ -2157,7 +2678,7 @@ impl SyncEngine {
     }
 
     if let Some(_handle) = app_handle {
-      let _ = events::emit("extensions-changed", ());
+      let _ = events::emit_empty("extensions-changed");
     }
 
     log::info!("Extension {} downloaded", ext_id);
@@ -2167,7 +2688,7 @@ impl SyncEngine {
   pub async fn sync_extension_by_id_with_handle(
     &self,
     ext_id: &str,
-    app_handle: &tauri::AppHandle,
+    app_handle: &crate::app_handle::AppHandle,
   ) -> SyncResult<()> {
     self.sync_extension(ext_id, Some(app_handle)).await
   }
@@ -2195,7 +2716,7 @@ impl SyncEngine {
   async fn sync_extension_group(
     &self,
     group_id: &str,
-    app_handle: Option<&tauri::AppHandle>,
+    app_handle: Option<&crate::app_handle::AppHandle>,
   ) -> SyncResult<()> {
     let local_group = {
       let manager = crate::extension_manager::EXTENSION_MANAGER.lock().unwrap();
@@ -2267,7 +2788,7 @@ impl SyncEngine {
   async fn download_extension_group(
     &self,
     group_id: &str,
-    app_handle: Option<&tauri::AppHandle>,
+    app_handle: Option<&crate::app_handle::AppHandle>,
   ) -> SyncResult<()> {
     let remote_key = format!("extension_groups/{}.json", group_id);
     let presign = self.client.presign_download(&remote_key).await?;
@@ -2298,7 +2819,7 @@ impl SyncEngine {
     }
 
     if let Some(_handle) = app_handle {
-      let _ = events::emit("extensions-changed", ());
+      let _ = events::emit_empty("extensions-changed");
     }
 
     log::info!("Extension group {} downloaded", group_id);
@@ -2308,7 +2829,7 @@ impl SyncEngine {
   pub async fn sync_extension_group_by_id_with_handle(
     &self,
     group_id: &str,
-    app_handle: &tauri::AppHandle,
+    app_handle: &crate::app_handle::AppHandle,
   ) -> SyncResult<()> {
     self.sync_extension_group(group_id, Some(app_handle)).await
   }
@@ -2329,7 +2850,7 @@ impl SyncEngine {
   /// Download a profile from S3 if it exists remotely but not locally
   pub async fn download_profile_if_missing(
     &self,
-    app_handle: &tauri::AppHandle,
+    app_handle: &crate::app_handle::AppHandle,
     profile_id: &str,
     key_prefix: &str,
   ) -> SyncResult<bool> {
@@ -2385,7 +2906,7 @@ impl SyncEngine {
     // this device. Plaintext (legacy) metadata passes through.
     let metadata_data = encryption::maybe_unseal_after_download(&metadata_raw).map_err(|e| {
       if e.contains("ENCRYPTION_PASSWORD_REQUIRED") {
-        let _ = events::emit("profile-sync-e2e-password-required", ());
+        let _ = events::emit_empty("profile-sync-e2e-password-required");
       }
       SyncError::InvalidData(format!("Failed to unseal profile metadata: {e}"))
     })?;
@@ -2421,15 +2942,8 @@ impl SyncEngine {
         .save_profile(&profile)
         .map_err(|e| SyncError::IoError(format!("Failed to save cross-OS profile: {e}")))?;
 
-      let _ = events::emit("profiles-changed", ());
-      let _ = events::emit(
-        "profile-sync-status",
-        serde_json::json!({
-          "profile_id": profile_id,
-          "profile_name": profile.name,
-          "status": "synced"
-        }),
-      );
+      let _ = events::emit_empty("profiles-changed");
+      let _ = events::emit(&ProfileSyncStatus::new(profile_id, "synced").named(&profile.name));
 
       log::info!(
         "Cross-OS profile {} metadata downloaded successfully",
@@ -2444,7 +2958,7 @@ impl SyncEngine {
       let password = encryption::load_e2e_password()
         .map_err(|e| SyncError::InvalidData(format!("Failed to load E2E password: {e}")))?
         .ok_or_else(|| {
-          let _ = events::emit("profile-sync-e2e-password-required", ());
+          let _ = events::emit_empty("profile-sync-e2e-password-required");
           SyncError::InvalidData(
             "Remote profile is encrypted but no E2E password is set".to_string(),
           )
@@ -2543,6 +3057,7 @@ impl SyncEngine {
         profile_id
       );
     }
+    verify_downloaded_cookie_key(&profile, &profile_dir);
     if cookies_path.exists() {
       let cookies_meta = fs::metadata(&cookies_path).unwrap_or_else(|_| fs::metadata(".").unwrap());
       log::info!(
@@ -2576,15 +3091,8 @@ impl SyncEngine {
       .save_profile(&profile)
       .map_err(|e| SyncError::IoError(format!("Failed to save downloaded profile: {e}")))?;
 
-    let _ = events::emit("profiles-changed", ());
-    let _ = events::emit(
-      "profile-sync-status",
-      serde_json::json!({
-        "profile_id": profile_id,
-        "profile_name": profile.name,
-        "status": "synced"
-      }),
-    );
+    let _ = events::emit_empty("profiles-changed");
+    let _ = events::emit(&ProfileSyncStatus::new(profile_id, "synced").named(&profile.name));
 
     log::info!("Profile {} downloaded successfully", profile_id);
     Ok(true)
@@ -2593,7 +3101,7 @@ impl SyncEngine {
   /// Check for profiles that exist remotely but not locally and download them
   pub async fn check_for_missing_synced_profiles(
     &self,
-    app_handle: &tauri::AppHandle,
+    app_handle: &crate::app_handle::AppHandle,
   ) -> SyncResult<Vec<String>> {
     log::info!("Checking for missing synced profiles...");
 
@@ -2839,7 +3347,7 @@ impl SyncEngine {
           _ => {}
         }
       }
-      let _ = events::emit("profiles-changed", ());
+      let _ = events::emit_empty("profiles-changed");
     }
 
     Ok(downloaded)
@@ -2848,7 +3356,7 @@ impl SyncEngine {
   /// Check for remote entities (proxies, groups, VPNs) not present locally and download them
   pub async fn check_for_missing_synced_entities(
     &self,
-    app_handle: &tauri::AppHandle,
+    app_handle: &crate::app_handle::AppHandle,
   ) -> SyncResult<()> {
     log::info!("Checking for missing synced entities...");
 
@@ -2882,6 +3390,35 @@ impl SyncEngine {
       }
     }
 
+    // Check for remote proxy pools not present locally
+    let remote_pools = self.client.list("proxy_pools/").await?;
+    for obj in &remote_pools.objects {
+      if let Some(pool_id) = obj
+        .key
+        .strip_prefix("proxy_pools/")
+        .and_then(|s| s.strip_suffix(".json"))
+      {
+        if crate::proxy_manager::PROXY_MANAGER
+          .get_proxy_pool(pool_id)
+          .is_none()
+        {
+          let tombstone_key = format!("tombstones/proxy_pools/{}.json", pool_id);
+          if let Ok(stat) = self.client.stat(&tombstone_key).await {
+            if stat.exists {
+              continue;
+            }
+          }
+          log::info!(
+            "Proxy pool {} exists remotely but not locally, downloading...",
+            pool_id
+          );
+          if let Err(e) = self.download_proxy_pool(pool_id, Some(app_handle)).await {
+            log::warn!("Failed to download missing proxy pool {}: {}", pool_id, e);
+          }
+        }
+      }
+    }
+
     // Check for remote groups not present locally
     let remote_groups = self.client.list("groups/").await?;
     for obj in &remote_groups.objects {
@@ -3025,6 +3562,10 @@ impl SyncEngine {
       }
     }
 
+    if let Err(e) = self.sync_saved_views(Some(app_handle)).await {
+      log::warn!("Failed to sync saved views: {}", e);
+    }
+
     log::info!("Missing synced entities check complete");
     Ok(())
   }
@@ -3065,13 +3606,34 @@ pub async fn enable_proxy_sync_if_needed(proxy_id: &str) -> Result<(), String> {
 
   if !proxy.sync_enabled {
     proxy_manager.set_stored_proxy_sync_state(proxy_id, true, proxy.last_sync)?;
-    let _ = events::emit("stored-proxies-changed", ());
+    let _ = events::emit_empty("stored-proxies-changed");
     log::info!("Auto-enabled sync for proxy {}", proxy_id);
   }
 
   Ok(())
 }
 
+/// Enable sync for a proxy pool and its member proxies if not already enabled
+pub async fn enable_proxy_pool_sync_if_needed(pool_id: &str) -> Result<(), String> {
+  let proxy_manager = &crate::proxy_manager::PROXY_MANAGER;
+  let pool = proxy_manager
+    .get_proxy_pool(pool_id)
+    .ok_or_else(|| format!("Proxy pool with ID '{pool_id}' not found"))?;
+
+  if !pool.sync_enabled {
+    proxy_manager.set_proxy_pool_sync_state(pool_id, true, pool.last_sync)?;
+    let _ = events::emit_empty("proxy-pools-changed");
+    log::info!("Auto-enabled sync for proxy pool {}", pool_id);
+  }
+  for proxy_id in &pool.proxy_ids {
+    if let Err(e) = enable_proxy_sync_if_needed(proxy_id).await {
+      log::warn!("Failed to enable sync for pool member {}: {}", proxy_id, e);
+    }
+  }
+
+  Ok(())
+}
+
 /// Check if VPN is used by any synced profile
 pub fn is_vpn_used_by_synced_profile(vpn_id: &str) -> bool {
   let profile_manager = ProfileManager::instance();
@@ -3099,7 +3661,7 @@ pub async fn enable_vpn_sync_if_needed(vpn_id: &str) -> Result<(), String> {
       .update_sync_fields(vpn_id, true, None)
       .map_err(|e| format!("Failed to enable VPN sync: {e}"))?;
 
-    let _ = events::emit("vpn-configs-changed", ());
+    let _ = events::emit_empty("vpn-configs-changed");
     log::info!("Auto-enabled sync for VPN {}", vpn_id);
   }
 
@@ -3129,7 +3691,7 @@ pub async fn enable_group_sync_if_needed(group_id: &str) -> Result<(), String> {
       }
     }
 
-    let _ = events::emit("groups-changed", ());
+    let _ = events::emit_empty("groups-changed");
     log::info!("Auto-enabled sync for group {}", group_id);
   }
 
@@ -3162,7 +3724,7 @@ pub async fn enable_extension_group_sync_if_needed(extension_group_id: &str) ->
         .update_group_internal(&updated_group)
         .map_err(|e| format!("Failed to update extension group sync: {e}"))?;
     }
-    let _ = events::emit("extensions-changed", ());
+    let _ = events::emit_empty("extensions-changed");
     log::info!(
       "Auto-enabled sync for extension group {}",
       extension_group_id
@@ -3210,7 +3772,22 @@ pub async fn enable_extension_group_sync_if_needed(extension_group_id: &str) ->
 
 #[tauri::command]
 pub async fn set_profile_sync_mode(
-  app_handle: tauri::AppHandle,
+  app_handle: crate::app_handle::AppHandle,
+  profile_id: String,
+  sync_mode: String,
+) -> Result<(), String> {
+  crate::audit::audited!(
+    crate::audit::AuditActor::Gui,
+    "update_sync_mode",
+    "profile",
+    Some(profile_id.clone()),
+    set_profile_sync_mode_impl(app_handle, profile_id.clone(), sync_mode).await
+  )
+}
+
+/// [`set_profile_sync_mode`] without the GUI audit record, for the API.
+pub async fn set_profile_sync_mode_impl(
+  app_handle: crate::app_handle::AppHandle,
   profile_id: String,
   sync_mode: String,
 ) -> Result<(), String> {
@@ -3246,6 +3823,15 @@ pub async fn set_profile_sync_mode(
     return Err("Cannot enable sync for an ephemeral profile".to_string());
   }
 
+  if enabling_now
+    && has_machine_bound_cookies(
+      &profile,
+      &profile_manager.get_profiles_dir().join(&profile_id),
+    )
+  {
+    return Err(serde_json::json!({ "code": "COOKIE_KEY_NOT_PORTABLE" }).to_string());
+  }
+
   let old_mode = profile.sync_mode;
   let enabling = new_mode != SyncMode::Disabled;
 
@@ -3260,13 +3846,11 @@ pub async fn set_profile_sync_mode(
 
       if settings.sync_server_url.is_none() {
         let _ = events::emit(
-          "profile-sync-status",
-          serde_json::json!({
-            "profile_id": profile_id,
-            "profile_name": profile.name,
-            "status": "error",
-            "error": "Sync server not configured. Please configure sync settings first."
-          }),
+          &ProfileSyncStatus::failed(
+            &profile_id,
+            "Sync server not configured. Please configure sync settings first.",
+          )
+          .named(&profile.name),
         );
         return Err(
           "Sync server not configured. Please configure sync settings first.".to_string(),
@@ -3276,13 +3860,11 @@ pub async fn set_profile_sync_mode(
       let token = manager.get_sync_token(&app_handle).await.ok().flatten();
       if token.is_none() {
         let _ = events::emit(
-          "profile-sync-status",
-          serde_json::json!({
-            "profile_id": profile_id,
-            "profile_name": profile.name,
-            "status": "error",
-            "error": "Sync token not configured. Please configure sync settings first."
-          }),
+          &ProfileSyncStatus::failed(
+            &profile_id,
+            "Sync token not configured. Please configure sync settings first.",
+          )
+          .named(&profile.name),
         );
         return Err("Sync token not configured. Please configure sync settings first.".to_string());
       }
@@ -3321,7 +3903,7 @@ pub async fn set_profile_sync_mode(
     .save_profile(&profile)
     .map_err(|e| format!("Failed to save profile: {e}"))?;
 
-  let _ = events::emit("profiles-changed", ());
+  let _ = events::emit_empty("profiles-changed");
 
   // When (re-)enabling sync, clear any stale tombstone from a previous
   // disable on this device. Otherwise the next reconcile on another
@@ -3343,12 +3925,8 @@ pub async fn set_profile_sync_mode(
     let is_running = profile.process_id.is_some();
 
     let _ = events::emit(
-      "profile-sync-status",
-      serde_json::json!({
-        "profile_id": profile_id,
-        "profile_name": profile.name,
-        "status": if is_running { "waiting" } else { "syncing" }
-      }),
+      &ProfileSyncStatus::new(&profile_id, if is_running { "waiting" } else { "syncing" })
+        .named(&profile.name),
     );
 
     if let Some(scheduler) = super::get_global_scheduler() {
@@ -3363,6 +3941,13 @@ pub async fn set_profile_sync_mode(
           scheduler.queue_proxy_sync(proxy_id.clone()).await;
         }
       }
+      if let Some(ref pool_id) = profile.proxy_pool_id {
+        if let Err(e) = enable_proxy_pool_sync_if_needed(pool_id).await {
+          log::warn!("Failed to enable sync for proxy pool {}: {}", pool_id, e);
+        } else {
+          scheduler.queue_proxy_pool_sync(pool_id.clone()).await;
+        }
+      }
       if let Some(ref group_id) = profile.group_id {
         if let Err(e) = enable_group_sync_if_needed(group_id).await {
           log::warn!("Failed to enable sync for group {}: {}", group_id, e);
@@ -3415,14 +4000,7 @@ pub async fn set_profile_sync_mode(
       }
     }
 
-    let _ = events::emit(
-      "profile-sync-status",
-      serde_json::json!({
-        "profile_id": profile_id,
-        "profile_name": profile.name,
-        "status": "disabled"
-      }),
-    );
+    let _ = events::emit(&ProfileSyncStatus::new(&profile_id, "disabled").named(&profile.name));
   }
 
   if crate::cloud_auth::CLOUD_AUTH.is_logged_in().await {
@@ -3446,8 +4024,9 @@ pub async fn set_profile_sync_mode(
 
 #[tauri::command]
 pub async fn request_profile_sync(
-  _app_handle: tauri::AppHandle,
+  _app_handle: crate::app_handle::AppHandle,
   profile_id: String,
+  force_rehash: Option<bool>,
 ) -> Result<(), String> {
   // Validate profile exists and sync is enabled
   let profile_manager = ProfileManager::instance();
@@ -3470,15 +4049,15 @@ pub async fn request_profile_sync(
   if let Some(scheduler) = super::get_global_scheduler() {
     let is_running = profile.process_id.is_some();
     let _ = events::emit(
-      "profile-sync-status",
-      serde_json::json!({
-        "profile_id": profile_id,
-        "profile_name": profile.name,
-        "status": if is_running { "waiting" } else { "syncing" }
-      }),
+      &ProfileSyncStatus::new(&profile_id, if is_running { "waiting" } else { "syncing" })
+        .named(&profile.name),
     );
 
-    scheduler.queue_profile_sync_immediate(profile_id).await;
+    if force_rehash.unwrap_or(false) {
+      scheduler.queue_profile_rehash(profile_id).await;
+    } else {
+      scheduler.queue_profile_sync_immediate(profile_id).await;
+    }
     Ok(())
   } else {
     Err("Sync scheduler not initialized".to_string())
@@ -3486,13 +4065,58 @@ pub async fn request_profile_sync(
 }
 
 #[tauri::command]
-pub async fn sync_profile(app_handle: tauri::AppHandle, profile_id: String) -> Result<(), String> {
+pub async fn sync_profile(
+  app_handle: crate::app_handle::AppHandle,
+  profile_id: String,
+) -> Result<(), String> {
   trigger_sync_for_profile(app_handle, profile_id).await
 }
 
+/// Report what syncing the profile would upload, download and delete, without
+/// transferring anything.
+#[tauri::command]
+pub async fn preview_profile_sync(
+  app_handle: crate::app_handle::AppHandle,
+  profile_id: String,
+) -> Result<SyncPreview, String> {
+  preview_profile_sync_impl(&app_handle, &profile_id).await
+}
+
+/// Shared by the Tauri command, the REST API and MCP.
+pub async fn preview_profile_sync_impl(
+  app_handle: &crate::app_handle::AppHandle,
+  profile_id: &str,
+) -> Result<SyncPreview, String> {
+  let profile_uuid =
+    uuid::Uuid::parse_str(profile_id).map_err(|_| format!("Invalid profile ID: {profile_id}"))?;
+  let profile = ProfileManager::instance()
+    .list_profiles()
+    .map_err(|e| format!("Failed to list profiles: {e}"))?
+    .into_iter()
+    .find(|p| p.id == profile_uuid)
+    .ok_or_else(|| format!("Profile with ID '{profile_id}' not found"))?;
+
+  ensure_sync_configured(app_handle).await?;
+  let engine = SyncEngine::create_from_settings(app_handle)
+    .await
+    .map_err(|e| format!("Failed to create sync engine: {e}"))?;
+
+  engine
+    .preview_profile_sync(&profile)
+    .await
+    .map_err(|e| match e {
+      SyncError::ConnectionError(detail) => serde_json::json!({
+        "code": "SYNC_SERVER_UNREACHABLE",
+        "params": { "detail": detail }
+      })
+      .to_string(),
+      e => format!("Sync preview failed: {e}"),
+    })
+}
+
 /// Ensure the device has either a cloud login or a self-hosted server URL + token.
 /// Returns a JSON error code string consumable by the frontend translator.
-async fn ensure_sync_configured(app_handle: &tauri::AppHandle) -> Result<(), String> {
+async fn ensure_sync_configured(app_handle: &crate::app_handle::AppHandle) -> Result<(), String> {
   let cloud_logged_in = crate::cloud_auth::CLOUD_AUTH.is_logged_in().await;
   if cloud_logged_in {
     return Ok(());
@@ -3513,7 +4137,7 @@ async fn ensure_sync_configured(app_handle: &tauri::AppHandle) -> Result<(), Str
 }
 
 pub async fn trigger_sync_for_profile(
-  app_handle: tauri::AppHandle,
+  app_handle: crate::app_handle::AppHandle,
   profile_id: String,
 ) -> Result<(), String> {
   let engine = SyncEngine::create_from_settings(&app_handle)
@@ -3542,7 +4166,7 @@ pub async fn trigger_sync_for_profile(
 
 #[tauri::command]
 pub async fn set_proxy_sync_enabled(
-  app_handle: tauri::AppHandle,
+  app_handle: crate::app_handle::AppHandle,
   proxy_id: String,
   enabled: bool,
 ) -> Result<(), String> {
@@ -3575,28 +4199,16 @@ pub async fn set_proxy_sync_enabled(
       serde_json::json!({ "code": "INTERNAL_ERROR", "params": { "detail": e } }).to_string()
     })?;
 
-  let _ = events::emit("stored-proxies-changed", ());
+  let _ = events::emit_empty("stored-proxies-changed");
 
   if enabled {
-    let _ = events::emit(
-      "proxy-sync-status",
-      serde_json::json!({
-        "id": proxy_id,
-        "status": "syncing"
-      }),
-    );
+    let _ = events::emit(&ProxySyncStatus::new(&proxy_id, "syncing"));
 
     if let Some(scheduler) = super::get_global_scheduler() {
       scheduler.queue_proxy_sync(proxy_id).await;
     }
   } else {
-    let _ = events::emit(
-      "proxy-sync-status",
-      serde_json::json!({
-        "id": proxy_id,
-        "status": "disabled"
-      }),
-    );
+    let _ = events::emit(&ProxySyncStatus::new(&proxy_id, "disabled"));
   }
 
   Ok(())
@@ -3604,7 +4216,7 @@ pub async fn set_proxy_sync_enabled(
 
 #[tauri::command]
 pub async fn set_group_sync_enabled(
-  app_handle: tauri::AppHandle,
+  app_handle: crate::app_handle::AppHandle,
   group_id: String,
   enabled: bool,
 ) -> Result<(), String> {
@@ -3645,28 +4257,16 @@ pub async fn set_group_sync_enabled(
     }
   }
 
-  let _ = events::emit("groups-changed", ());
+  let _ = events::emit_empty("groups-changed");
 
   if enabled {
-    let _ = events::emit(
-      "group-sync-status",
-      serde_json::json!({
-        "id": group_id,
-        "status": "syncing"
-      }),
-    );
+    let _ = events::emit(&GroupSyncStatus::new(&group_id, "syncing"));
 
     if let Some(scheduler) = super::get_global_scheduler() {
       scheduler.queue_group_sync(group_id).await;
     }
   } else {
-    let _ = events::emit(
-      "group-sync-status",
-      serde_json::json!({
-        "id": group_id,
-        "status": "disabled"
-      }),
-    );
+    let _ = events::emit(&GroupSyncStatus::new(&group_id, "disabled"));
   }
 
   Ok(())
@@ -3684,7 +4284,7 @@ pub fn is_group_in_use_by_synced_profile(group_id: String) -> bool {
 
 #[tauri::command]
 pub async fn set_vpn_sync_enabled(
-  app_handle: tauri::AppHandle,
+  app_handle: crate::app_handle::AppHandle,
   vpn_id: String,
   enabled: bool,
 ) -> Result<(), String> {
@@ -3717,28 +4317,16 @@ pub async fn set_vpn_sync_enabled(
       })?;
   }
 
-  let _ = events::emit("vpn-configs-changed", ());
+  let _ = events::emit_empty("vpn-configs-changed");
 
   if enabled {
-    let _ = events::emit(
-      "vpn-sync-status",
-      serde_json::json!({
-        "id": vpn_id,
-        "status": "syncing"
-      }),
-    );
+    let _ = events::emit(&VpnSyncStatus::new(&vpn_id, "syncing"));
 
     if let Some(scheduler) = super::get_global_scheduler() {
       scheduler.queue_vpn_sync(vpn_id).await;
     }
   } else {
-    let _ = events::emit(
-      "vpn-sync-status",
-      serde_json::json!({
-        "id": vpn_id,
-        "status": "disabled"
-      }),
-    );
+    let _ = events::emit(&VpnSyncStatus::new(&vpn_id, "disabled"));
   }
 
   Ok(())
@@ -3810,7 +4398,9 @@ pub fn get_unsynced_entity_counts() -> Result<UnsyncedEntityCounts, String> {
 }
 
 #[tauri::command]
-pub async fn enable_sync_for_all_entities(app_handle: tauri::AppHandle) -> Result<(), String> {
+pub async fn enable_sync_for_all_entities(
+  app_handle: crate::app_handle::AppHandle,
+) -> Result<(), String> {
   // Intentionally excludes profiles: enabling profile sync uploads the entire
   // browser data dir per profile, which is destructive if the user expected
   // an opt-in. Profile sync stays under explicit per-profile control via
@@ -3828,6 +4418,17 @@ pub async fn enable_sync_for_all_entities(app_handle: tauri::AppHandle) -> Resul
     }
   }
 
+  // Enable sync for all unsynced proxy pools
+  for pool in crate::proxy_manager::PROXY_MANAGER.get_proxy_pools() {
+    if !pool.sync_enabled {
+      if let Err(e) = enable_proxy_pool_sync_if_needed(&pool.id).await {
+        log::warn!("Failed to enable sync for proxy pool {}: {e}", pool.id);
+      } else if let Some(scheduler) = super::get_global_scheduler() {
+        scheduler.queue_proxy_pool_sync(pool.id.clone()).await;
+      }
+    }
+  }
+
   // Enable sync for all unsynced groups
   {
     let groups = {
@@ -3903,7 +4504,7 @@ pub async fn enable_sync_for_all_entities(app_handle: tauri::AppHandle) -> Resul
 
 #[tauri::command]
 pub async fn set_extension_sync_enabled(
-  app_handle: tauri::AppHandle,
+  app_handle: crate::app_handle::AppHandle,
   extension_id: String,
   enabled: bool,
 ) -> Result<(), String> {
@@ -3934,7 +4535,7 @@ pub async fn set_extension_sync_enabled(
       })?;
   }
 
-  let _ = events::emit("extensions-changed", ());
+  let _ = events::emit_empty("extensions-changed");
 
   if enabled {
     if let Some(scheduler) = super::get_global_scheduler() {
@@ -3947,7 +4548,7 @@ pub async fn set_extension_sync_enabled(
 
 #[tauri::command]
 pub async fn set_extension_group_sync_enabled(
-  app_handle: tauri::AppHandle,
+  app_handle: crate::app_handle::AppHandle,
   extension_group_id: String,
   enabled: bool,
 ) -> Result<(), String> {
@@ -3976,7 +4577,7 @@ pub async fn set_extension_group_sync_enabled(
     })?;
   }
 
-  let _ = events::emit("extensions-changed", ());
+  let _ = events::emit_empty("extensions-changed");
 
   if enabled {
     if let Some(scheduler) = super::get_global_scheduler() {
@@ -4002,9 +4603,9 @@ pub async fn set_extension_group_sync_enabled(
 /// Progress is emitted via `e2e-rollover-progress` events with `{ stage, done, total }`.
 #[tauri::command]
 pub async fn rollover_encryption_for_all_entities(
-  app_handle: tauri::AppHandle,
+  app_handle: crate::app_handle::AppHandle,
 ) -> Result<(), String> {
-  let _ = events::emit("e2e-rollover-started", ());
+  let _ = events::emit_empty("e2e-rollover-started");
 
   let internal_error = |detail: String| {
     serde_json::json!({ "code": "INTERNAL_ERROR", "params": { "detail": detail } }).to_string()
@@ -4055,16 +4656,158 @@ pub async fn rollover_encryption_for_all_entities(
       .sync_profile(&app_handle, profile)
       .await
       .map_err(|e| internal_error(format!("Failed to roll over profile {id_str}: {e}")))?;
-    let _ = events::emit(
-      "e2e-rollover-progress",
-      serde_json::json!({
-        "stage": "profiles",
-        "done": i + 1,
-        "total": total_profiles,
-      }),
-    );
+    let _ = events::emit(&E2eRolloverProgress {
+      stage: "profiles",
+      done: i + 1,
+      total: total_profiles,
+    });
+  }
+
+  reupload_synced_entities(&engine).await?;
+
+  let _ = events::emit_empty("e2e-rollover-completed");
+  Ok(())
+}
+
+/// Change the E2E password and move all synced data onto it. Each synced
+/// profile is first synced under the old password so the local copy is
+/// current, then re-uploaded under the new one (encrypted profiles also get a
+/// fresh salt). All other synced entities follow. The stored password is
+/// replaced only after every object has moved.
+///
+/// Progress is persisted after each profile and sync is paused until the
+/// change finishes, so calling this again with the same passwords after an
+/// interruption resumes where it stopped. Emits the `e2e-rollover-*` events.
+#[tauri::command]
+pub async fn change_e2e_password(
+  app_handle: crate::app_handle::AppHandle,
+  old_password: String,
+  new_password: String,
+) -> Result<(), String> {
+  if new_password.len() < 8 {
+    return Err("Password must be at least 8 characters".to_string());
+  }
+  encryption::enforce_team_owner_for_encryption_change().await?;
+  if !encryption::verify_e2e_password(old_password)? {
+    return Err(serde_json::json!({ "code": "E2E_PASSWORD_INCORRECT" }).to_string());
+  }
+
+  let internal_error = |detail: String| {
+    serde_json::json!({ "code": "INTERNAL_ERROR", "params": { "detail": detail } }).to_string()
+  };
+  let engine = SyncEngine::create_from_settings(&app_handle)
+    .await
+    .map_err(&internal_error)?;
+  let profiles: Vec<BrowserProfile> = ProfileManager::instance()
+    .list_profiles()
+    .map_err(|e| internal_error(format!("Failed to list profiles: {e}")))?
+    .into_iter()
+    .filter(|p| p.sync_mode != SyncMode::Disabled)
+    .collect();
+  if profiles.iter().any(|profile| profile.process_id.is_some()) {
+    return Err(serde_json::json!({ "code": "PROFILE_RUNNING" }).to_string());
+  }
+
+  let mut progress = match encryption::load_rotation_progress() {
+    Some(progress) if progress.matches(&new_password) => progress,
+    _ => encryption::RotationProgress::new(&new_password)?,
+  };
+  encryption::save_rotation_progress(&progress)?;
+  let _ = events::emit_empty("e2e-rollover-started");
+
+  let result = rotate_synced_data(
+    &app_handle,
+    &engine,
+    &profiles,
+    &mut progress,
+    &new_password,
+  )
+  .await;
+  encryption::set_rotation_password(None);
+  result?;
+
+  encryption::store_e2e_password(&new_password)?;
+  encryption::clear_rotation_progress();
+  let _ = events::emit_empty("e2e-rollover-completed");
+  Ok(())
+}
+
+async fn rotate_synced_data(
+  app_handle: &crate::app_handle::AppHandle,
+  engine: &SyncEngine,
+  profiles: &[BrowserProfile],
+  progress: &mut encryption::RotationProgress,
+  new_password: &str,
+) -> Result<(), String> {
+  let internal_error = |detail: String| {
+    serde_json::json!({ "code": "INTERNAL_ERROR", "params": { "detail": detail } }).to_string()
+  };
+
+  let total = profiles.len();
+  for (i, profile) in profiles.iter().enumerate() {
+    let id_str = profile.id.to_string();
+    if !progress.completed_profiles.contains(&id_str) {
+      encryption::set_rotation_password(None);
+      engine
+        .sync_profile(app_handle, profile)
+        .await
+        .map_err(|e| internal_error(format!("Failed to sync profile {id_str}: {e}")))?;
+
+      let mut current = ProfileManager::instance()
+        .list_profiles()
+        .ok()
+        .and_then(|all| all.into_iter().find(|p| p.id == profile.id))
+        .unwrap_or_else(|| profile.clone());
+      if current.is_encrypted_sync() {
+        current.encryption_salt = Some(encryption::generate_salt());
+        current.updated_at = Some(crate::proxy_manager::now_secs());
+        ProfileManager::instance()
+          .save_profile(&current)
+          .map_err(|e| internal_error(format!("Failed to save profile {id_str}: {e}")))?;
+      }
+
+      encryption::set_rotation_password(Some(new_password.to_string()));
+      let key_prefix = SyncEngine::get_team_key_prefix(&current).await;
+      engine
+        .upload_profile_metadata(&id_str, &current, &key_prefix)
+        .await
+        .map_err(|e| {
+          internal_error(format!(
+            "Failed to re-encrypt profile metadata {id_str}: {e}"
+          ))
+        })?;
+      let manifest_key = format!("{key_prefix}profiles/{id_str}/manifest.json");
+      engine
+        .client
+        .delete(&manifest_key, None)
+        .await
+        .map_err(|e| internal_error(format!("Failed to reset profile manifest: {e}")))?;
+      engine
+        .sync_profile(app_handle, &current)
+        .await
+        .map_err(|e| internal_error(format!("Failed to re-encrypt profile {id_str}: {e}")))?;
+
+      progress.completed_profiles.push(id_str);
+      encryption::save_rotation_progress(progress)?;
+    }
+    let _ = events::emit(&E2eRolloverProgress {
+      stage: "profiles",
+      done: i + 1,
+      total: total,
+    });
   }
 
+  encryption::set_rotation_password(Some(new_password.to_string()));
+  reupload_synced_entities(engine).await
+}
+
+/// Re-upload every sync-enabled proxy, group, VPN, extension and extension
+/// group so each is sealed under the password currently in effect.
+async fn reupload_synced_entities(engine: &SyncEngine) -> Result<(), String> {
+  let internal_error = |detail: String| {
+    serde_json::json!({ "code": "INTERNAL_ERROR", "params": { "detail": detail } }).to_string()
+  };
+
   let proxies = crate::proxy_manager::PROXY_MANAGER.get_stored_proxies();
   let synced_proxies: Vec<_> = proxies.iter().filter(|p| p.sync_enabled).collect();
   let total_proxies = synced_proxies.len();
@@ -4073,10 +4816,26 @@ pub async fn rollover_encryption_for_all_entities(
       .upload_proxy(proxy)
       .await
       .map_err(|e| internal_error(format!("Failed to roll over proxy {}: {e}", proxy.id)))?;
-    let _ = events::emit(
-      "e2e-rollover-progress",
-      serde_json::json!({"stage": "proxies", "done": i + 1, "total": total_proxies}),
-    );
+    let _ = events::emit(&E2eRolloverProgress {
+      stage: "proxies",
+      done: i + 1,
+      total: total_proxies,
+    });
+  }
+
+  let pools = crate::proxy_manager::PROXY_MANAGER.get_proxy_pools();
+  let synced_pools: Vec<_> = pools.iter().filter(|p| p.sync_enabled).collect();
+  let total_pools = synced_pools.len();
+  for (i, pool) in synced_pools.iter().enumerate() {
+    engine
+      .upload_proxy_pool(pool)
+      .await
+      .map_err(|e| internal_error(format!("Failed to roll over proxy pool {}: {e}", pool.id)))?;
+    let _ = events::emit(&E2eRolloverProgress {
+      stage: "proxy_pools",
+      done: i + 1,
+      total: total_pools,
+    });
   }
 
   let groups = {
@@ -4091,10 +4850,11 @@ pub async fn rollover_encryption_for_all_entities(
       .upload_group(group)
       .await
       .map_err(|e| internal_error(format!("Failed to roll over group {}: {e}", group.id)))?;
-    let _ = events::emit(
-      "e2e-rollover-progress",
-      serde_json::json!({"stage": "groups", "done": i + 1, "total": total_groups}),
-    );
+    let _ = events::emit(&E2eRolloverProgress {
+      stage: "groups",
+      done: i + 1,
+      total: total_groups,
+    });
   }
 
   let vpns = {
@@ -4110,10 +4870,11 @@ pub async fn rollover_encryption_for_all_entities(
       .upload_vpn(config)
       .await
       .map_err(|e| internal_error(format!("Failed to roll over VPN {}: {e}", config.id)))?;
-    let _ = events::emit(
-      "e2e-rollover-progress",
-      serde_json::json!({"stage": "vpns", "done": i + 1, "total": total_vpns}),
-    );
+    let _ = events::emit(&E2eRolloverProgress {
+      stage: "vpns",
+      done: i + 1,
+      total: total_vpns,
+    });
   }
 
   let extensions = {
@@ -4128,10 +4889,11 @@ pub async fn rollover_encryption_for_all_entities(
       .upload_extension(ext)
       .await
       .map_err(|e| internal_error(format!("Failed to roll over extension {}: {e}", ext.id)))?;
-    let _ = events::emit(
-      "e2e-rollover-progress",
-      serde_json::json!({"stage": "extensions", "done": i + 1, "total": total_exts}),
-    );
+    let _ = events::emit(&E2eRolloverProgress {
+      stage: "extensions",
+      done: i + 1,
+      total: total_exts,
+    });
   }
 
   let ext_groups = {
@@ -4148,13 +4910,13 @@ pub async fn rollover_encryption_for_all_entities(
         group.id
       ))
     })?;
-    let _ = events::emit(
-      "e2e-rollover-progress",
-      serde_json::json!({"stage": "extension_groups", "done": i + 1, "total": total_eg}),
-    );
+    let _ = events::emit(&E2eRolloverProgress {
+      stage: "extension_groups",
+      done: i + 1,
+      total: total_eg,
+    });
   }
 
-  let _ = events::emit("e2e-rollover-completed", ());
   Ok(())
 }
 
@@ -4313,4 +5075,172 @@ mod tests {
       .unwrap();
     assert_eq!(count, 1);
   }
+
+  fn manifest_entry(path: &str, hash: &str) -> super::super::manifest::ManifestFileEntry {
+    super::super::manifest::ManifestFileEntry {
+      path: path.to_string(),
+      size: 4,
+      mtime: 0,
+      hash: hash.to_string(),
+    }
+  }
+
+  #[tokio::test]
+  async fn failed_upload_is_left_out_of_the_manifest() {
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+      .and(path("/v1/objects/presign-upload-batch"))
+      .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+        "items": [
+          {
+            "key": "profiles/p1/files/good.txt",
+            "url": format!("{}/put/good", server.uri()),
+            "expiresAt": "2099-01-01T00:00:00Z"
+          },
+          {
+            "key": "profiles/p1/files/bad.txt",
+            "url": format!("{}/put/bad", server.uri()),
+            "expiresAt": "2099-01-01T00:00:00Z"
+          }
+        ]
+      })))
+      .mount(&server)
+      .await;
+    Mock::given(method("PUT"))
+      .and(path("/put/good"))
+      .respond_with(ResponseTemplate::new(200))
+      .mount(&server)
+      .await;
+    Mock::given(method("PUT"))
+      .and(path("/put/bad"))
+      .respond_with(ResponseTemplate::new(500))
+      .mount(&server)
+      .await;
+    Mock::given(method("POST"))
+      .and(path("/v1/objects/stat"))
+      .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({ "exists": true })))
+      .mount(&server)
+      .await;
+
+    let dir = tempfile::TempDir::new().unwrap();
+    fs::write(dir.path().join("good.txt"), b"good").unwrap();
+    fs::write(dir.path().join("bad.txt"), b"bad!").unwrap();
+    let files = vec![
+      manifest_entry("good.txt", "new-good"),
+      manifest_entry("bad.txt", "new-bad"),
+    ];
+
+    let engine = SyncEngine::new(server.uri(), "token".to_string());
+    let outcome = engine
+      .upload_profile_files(
+        "p1",
+        "Profile",
+        dir.path(),
+        &files,
+        None,
+        "",
+        &Arc::new(AtomicBool::new(false)),
+      )
+      .await
+      .unwrap();
+    assert_eq!(outcome.failed, vec!["bad.txt".to_string()]);
+    assert!(outcome.vanished.is_empty());
+
+    // Every retry round asked for a fresh URL and tried the bad file again.
+    let bad_puts = server
+      .received_requests()
+      .await
+      .unwrap()
+      .iter()
+      .filter(|r| r.url.path() == "/put/bad")
+      .count();
+    assert_eq!(
+      bad_puts,
+      ((UPLOAD_RETRY_ROUNDS + 1) * MAX_FILE_RETRIES) as usize
+    );
+
+    // Nothing remote yet: the file is dropped from the manifest.
+    let mut manifest = SyncManifest::new("p1".to_string(), Vec::new());
+    manifest.files = files.clone();
+    exclude_failed_uploads(&mut manifest, &outcome, None);
+    let paths: Vec<&str> = manifest.files.iter().map(|f| f.path.as_str()).collect();
+    assert_eq!(paths, vec!["good.txt"]);
+
+    // Already remote: the manifest keeps pointing at the old, intact object.
+    let mut remote = SyncManifest::new("p1".to_string(), Vec::new());
+    remote.files = vec![manifest_entry("bad.txt", "old-bad")];
+    let mut manifest = SyncManifest::new("p1".to_string(), Vec::new());
+    manifest.files = files;
+    exclude_failed_uploads(&mut manifest, &outcome, Some(&remote));
+    let bad = manifest.files.iter().find(|f| f.path == "bad.txt").unwrap();
+    assert_eq!(bad.hash, "old-bad");
+  }
+
+  #[tokio::test]
+  async fn upload_missing_on_the_server_counts_as_failed() {
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+      .and(path("/v1/objects/presign-upload-batch"))
+      .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+        "items": [{
+          "key": "profiles/p1/files/lost.txt",
+          "url": format!("{}/put/lost", server.uri()),
+          "expiresAt": "2099-01-01T00:00:00Z"
+        }]
+      })))
+      .mount(&server)
+      .await;
+    Mock::given(method("PUT"))
+      .respond_with(ResponseTemplate::new(200))
+      .mount(&server)
+      .await;
+    Mock::given(method("POST"))
+      .and(path("/v1/objects/stat"))
+      .respond_with(
+        ResponseTemplate::new(200).set_body_json(serde_json::json!({ "exists": false })),
+      )
+      .mount(&server)
+      .await;
+
+    let dir = tempfile::TempDir::new().unwrap();
+    fs::write(dir.path().join("lost.txt"), b"lost").unwrap();
+
+    let engine = SyncEngine::new(server.uri(), "token".to_string());
+    let outcome = engine
+      .upload_profile_files(
+        "p1",
+        "Profile",
+        dir.path(),
+        &[manifest_entry("lost.txt", "h")],
+        None,
+        "",
+        &Arc::new(AtomicBool::new(false)),
+      )
+      .await
+      .unwrap();
+    assert_eq!(outcome.failed, vec!["lost.txt".to_string()]);
+    // The resume state must not claim it was uploaded.
+    let resume = SyncResumeState::load(dir.path()).unwrap();
+    assert!(!resume.completed_files.contains("lost.txt"));
+  }
+
+  #[test]
+  fn verification_checks_everything_below_the_threshold() {
+    let few: Vec<String> = (0..10).map(|i| i.to_string()).collect();
+    assert_eq!(verification_sample(&few), few);
+
+    let many: Vec<String> = (0..1000).map(|i| i.to_string()).collect();
+    let sample = verification_sample(&many);
+    assert_eq!(sample.len(), UPLOAD_VERIFY_SAMPLE);
+    assert_eq!(
+      sample.iter().collect::<HashSet<_>>().len(),
+      UPLOAD_VERIFY_SAMPLE
+    );
+  }
 }
@@ -3,7 +3,7 @@ use globset::{Glob, GlobSet, GlobSetBuilder};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fs::{self, File};
-use std::io::{BufReader, Read};
+use std::io::{BufReader, Read, Seek, SeekFrom};
 use std::path::Path;
 use std::time::SystemTime;
 
@@ -64,11 +64,21 @@ pub const DEFAULT_EXCLUDE_PATTERNS: &[&str] = &[
   // it in the browser-file manifest creates two competing sync mechanisms and
   // lets a stale in-memory profile overwrite a metadata download.
   "metadata.json",
+  // Its crash-safety backup and in-flight temp files.
+  "metadata.json.bak",
+  "metadata.json.*.tmp",
   ".donut-sync/**",
   // Orphaned local-only marker from earlier rollover-based fingerprint
   // regeneration. Keep excluding it so any markers left on disk from
   // prior builds never get uploaded.
   ".last-fp-refresh",
+  // Local-only exit history of this machine's browser runs.
+  ".exit-history.json",
+  // Local-only packed data dir of an archived profile.
+  "profile.tar.gz",
+  "profile.tar.gz.part",
+  // Fresh data dir being assembled by a profile refresh.
+  ".refresh-staging/**",
 ];
 
 /// A single file entry in the manifest
@@ -118,16 +128,50 @@ impl SyncManifest {
   }
 }
 
+/// Bumped whenever `HashCacheEntry` changes meaning; caches written under
+/// another version are discarded on load.
+pub const HASH_CACHE_VERSION: u32 = 2;
+
+/// Bytes hashed from each end of a file for its cache fingerprint. Files up to
+/// twice this size are always hashed in full instead.
+const SAMPLE_BYTES: u64 = 64 * 1024;
+
 /// Local hash cache to avoid re-hashing unchanged files
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HashCache {
+  /// Missing in caches written before versioning, which load as 0.
+  #[serde(default)]
+  pub version: u32,
   pub entries: HashMap<String, HashCacheEntry>,
 }
 
+impl Default for HashCache {
+  fn default() -> Self {
+    Self {
+      version: HASH_CACHE_VERSION,
+      entries: HashMap::new(),
+    }
+  }
+}
+
+/// What a cached hash is trusted against. mtime alone isn't enough: restore
+/// tools preserve it, and a clock change can leave a stale file looking
+/// current, so the file's identity and a sample of its content must match too.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileFingerprint {
+  pub size: u64,
+  /// Nanoseconds since the epoch, so same-second rewrites still differ.
+  pub mtime_ns: i64,
+  /// Inode on Unix; `None` where the platform doesn't expose a stable ID.
+  pub file_id: Option<u64>,
+  /// blake3 of the first and last `SAMPLE_BYTES`.
+  pub sample: String,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HashCacheEntry {
-  pub size: u64,
-  pub mtime: i64,
+  #[serde(flatten)]
+  pub fingerprint: FileFingerprint,
   pub hash: String,
 }
 
@@ -137,10 +181,19 @@ impl HashCache {
       return Self::default();
     }
 
-    match fs::read_to_string(cache_path) {
+    let cache: Self = match fs::read_to_string(cache_path) {
       Ok(content) => serde_json::from_str(&content).unwrap_or_default(),
       Err(_) => Self::default(),
+    };
+    if cache.version != HASH_CACHE_VERSION {
+      log::info!(
+        "Discarding hash cache {} (version {}, expected {HASH_CACHE_VERSION})",
+        cache_path.display(),
+        cache.version
+      );
+      return Self::default();
     }
+    cache
   }
 
   pub fn save(&self, cache_path: &Path) -> SyncResult<()> {
@@ -166,9 +219,9 @@ impl HashCache {
     Ok(())
   }
 
-  pub fn get(&self, path: &str, size: u64, mtime: i64) -> Option<&str> {
+  pub fn get(&self, path: &str, fingerprint: &FileFingerprint) -> Option<&str> {
     self.entries.get(path).and_then(|entry| {
-      if entry.size == size && entry.mtime == mtime {
+      if entry.fingerprint == *fingerprint {
         Some(entry.hash.as_str())
       } else {
         None
@@ -176,10 +229,10 @@ impl HashCache {
     })
   }
 
-  pub fn insert(&mut self, path: String, size: u64, mtime: i64, hash: String) {
+  pub fn insert(&mut self, path: String, fingerprint: FileFingerprint, hash: String) {
     self
       .entries
-      .insert(path, HashCacheEntry { size, mtime, hash });
+      .insert(path, HashCacheEntry { fingerprint, hash });
   }
 }
 
@@ -227,6 +280,59 @@ fn hash_file(path: &Path) -> Result<Option<String>, SyncError> {
   Ok(Some(hasher.finalize().to_hex().to_string()))
 }
 
+#[cfg(unix)]
+fn file_id(metadata: &fs::Metadata) -> Option<u64> {
+  use std::os::unix::fs::MetadataExt;
+  Some(metadata.ino())
+}
+
+#[cfg(not(unix))]
+fn file_id(_metadata: &fs::Metadata) -> Option<u64> {
+  None
+}
+
+/// Fingerprint of a file too large to rehash on every sync. Returns None if
+/// the file disappeared.
+fn fingerprint_file(path: &Path, metadata: &fs::Metadata) -> SyncResult<Option<FileFingerprint>> {
+  let mut file = match File::open(path) {
+    Ok(f) => f,
+    Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+    Err(e) => {
+      return Err(SyncError::IoError(format!(
+        "Failed to open {}: {e}",
+        path.display()
+      )));
+    }
+  };
+  let read_err =
+    |e: std::io::Error| SyncError::IoError(format!("Failed to read {}: {e}", path.display()));
+
+  let size = metadata.len();
+  let mut hasher = blake3::Hasher::new();
+  let mut buffer = vec![0u8; SAMPLE_BYTES as usize];
+  file.read_exact(&mut buffer).map_err(read_err)?;
+  hasher.update(&buffer);
+  file
+    .seek(SeekFrom::Start(size.saturating_sub(SAMPLE_BYTES)))
+    .map_err(read_err)?;
+  file.read_exact(&mut buffer).map_err(read_err)?;
+  hasher.update(&buffer);
+
+  let mtime_ns = metadata
+    .modified()
+    .ok()
+    .and_then(|m| m.duration_since(SystemTime::UNIX_EPOCH).ok())
+    .map(|d| d.as_nanos() as i64)
+    .unwrap_or(0);
+
+  Ok(Some(FileFingerprint {
+    size,
+    mtime_ns,
+    file_id: file_id(metadata),
+    sample: hasher.finalize().to_hex().to_string(),
+  }))
+}
+
 /// Get mtime as unix timestamp
 /// Returns None if the file doesn't exist (was deleted)
 fn get_mtime(path: &Path) -> Result<Option<i64>, SyncError> {
@@ -341,13 +447,40 @@ pub fn generate_manifest(
 
         *max_mtime = (*max_mtime).max(mtime);
 
-        // Check cache for existing hash
-        let hash = if let Some(cached_hash) = cache.get(&relative_path, size, mtime) {
+        // Small files are cheaper to hash than to fingerprint; larger ones
+        // reuse the cached hash while their fingerprint is unchanged.
+        let fingerprint = if size > 2 * SAMPLE_BYTES {
+          match fingerprint_file(&path, &metadata) {
+            Ok(Some(fingerprint)) => Some(fingerprint),
+            Ok(None) => {
+              log::debug!(
+                "File disappeared during manifest generation, skipping: {}",
+                path.display()
+              );
+              continue;
+            }
+            // Truncated while sampling; the full hash below will settle it.
+            Err(_) => None,
+          }
+        } else {
+          None
+        };
+        let cached = fingerprint
+          .as_ref()
+          .and_then(|fp| cache.get(&relative_path, fp));
+        let hash = if let Some(cached_hash) = cached {
           cached_hash.to_string()
         } else {
           match hash_file(&path)? {
             Some(computed_hash) => {
-              cache.insert(relative_path.clone(), size, mtime, computed_hash.clone());
+              match fingerprint {
+                Some(fingerprint) => {
+                  cache.insert(relative_path.clone(), fingerprint, computed_hash.clone())
+                }
+                None => {
+                  cache.entries.remove(&relative_path);
+                }
+              }
               computed_hash
             }
             None => {
@@ -496,6 +629,72 @@ pub fn compute_diff(local: &SyncManifest, remote: Option<&SyncManifest>) -> Mani
   diff
 }
 
+/// Files listed in `SyncPreview::largest_files`.
+const PREVIEW_LARGEST_FILES: usize = 20;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, utoipa::ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum TransferDirection {
+  Upload,
+  Download,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, utoipa::ToSchema)]
+pub struct PreviewFile {
+  pub path: String,
+  pub size: u64,
+  pub direction: TransferDirection,
+}
+
+/// What a sync would transfer, without transferring anything.
+#[derive(Debug, Clone, PartialEq, Serialize, utoipa::ToSchema)]
+pub struct SyncPreview {
+  /// False when the profile has never been synced, so everything uploads.
+  pub remote_manifest_found: bool,
+  pub upload_files: usize,
+  pub upload_bytes: u64,
+  pub download_files: usize,
+  pub download_bytes: u64,
+  pub delete_local_files: usize,
+  pub delete_remote_files: usize,
+  /// Biggest transfers in either direction, largest first.
+  pub largest_files: Vec<PreviewFile>,
+}
+
+impl ManifestDiff {
+  pub fn preview(&self, remote_manifest_found: bool) -> SyncPreview {
+    let mut largest: Vec<PreviewFile> = self
+      .files_to_upload
+      .iter()
+      .map(|f| (f, TransferDirection::Upload))
+      .chain(
+        self
+          .files_to_download
+          .iter()
+          .map(|f| (f, TransferDirection::Download)),
+      )
+      .map(|(f, direction)| PreviewFile {
+        path: f.path.clone(),
+        size: f.size,
+        direction,
+      })
+      .collect();
+    largest.sort_by(|a, b| b.size.cmp(&a.size).then_with(|| a.path.cmp(&b.path)));
+    largest.truncate(PREVIEW_LARGEST_FILES);
+
+    SyncPreview {
+      remote_manifest_found,
+      upload_files: self.files_to_upload.len(),
+      upload_bytes: self.files_to_upload.iter().map(|f| f.size).sum(),
+      download_files: self.files_to_download.len(),
+      download_bytes: self.files_to_download.iter().map(|f| f.size).sum(),
+      delete_local_files: self.files_to_delete_local.len(),
+      delete_remote_files: self.files_to_delete_remote.len(),
+      largest_files: largest,
+    }
+  }
+}
+
 /// Get the path to the hash cache file for a profile
 pub fn get_cache_path(profile_dir: &Path) -> std::path::PathBuf {
   profile_dir.join(".donut-sync").join("cache.json")
@@ -506,6 +705,15 @@ mod tests {
   use super::*;
   use tempfile::TempDir;
 
+  fn fingerprint(size: u64, mtime_ns: i64) -> FileFingerprint {
+    FileFingerprint {
+      size,
+      mtime_ns,
+      file_id: Some(7),
+      sample: "sample".to_string(),
+    }
+  }
+
   #[test]
   fn test_hash_cache_operations() {
     let cache_dir = TempDir::new().unwrap();
@@ -514,19 +722,124 @@ mod tests {
     let mut cache = HashCache::default();
     cache.insert(
       "test.txt".to_string(),
-      100,
-      1234567890,
+      fingerprint(100, 1234567890),
       "abc123".to_string(),
     );
 
-    assert_eq!(cache.get("test.txt", 100, 1234567890), Some("abc123"));
-    assert_eq!(cache.get("test.txt", 100, 999), None); // Different mtime
-    assert_eq!(cache.get("test.txt", 50, 1234567890), None); // Different size
+    assert_eq!(
+      cache.get("test.txt", &fingerprint(100, 1234567890)),
+      Some("abc123")
+    );
+    assert_eq!(cache.get("test.txt", &fingerprint(100, 999)), None); // Different mtime
+    assert_eq!(cache.get("test.txt", &fingerprint(50, 1234567890)), None); // Different size
+    let moved = FileFingerprint {
+      file_id: Some(8),
+      ..fingerprint(100, 1234567890)
+    };
+    assert_eq!(cache.get("test.txt", &moved), None); // Replaced file
 
     cache.save(&cache_path).unwrap();
 
     let loaded = HashCache::load(&cache_path);
-    assert_eq!(loaded.get("test.txt", 100, 1234567890), Some("abc123"));
+    assert_eq!(
+      loaded.get("test.txt", &fingerprint(100, 1234567890)),
+      Some("abc123")
+    );
+  }
+
+  #[test]
+  fn test_hash_cache_discards_other_versions() {
+    let cache_dir = TempDir::new().unwrap();
+    let cache_path = cache_dir.path().join("cache.json");
+    fs::write(
+      &cache_path,
+      r#"{"entries":{"a":{"size":1,"mtime":2,"hash":"h"}}}"#,
+    )
+    .unwrap();
+
+    let loaded = HashCache::load(&cache_path);
+    assert_eq!(loaded.version, HASH_CACHE_VERSION);
+    assert!(loaded.entries.is_empty());
+  }
+
+  /// Rewrites `path` with `content`, then puts the previous mtime back the way
+  /// backup/restore tools do.
+  fn write_keeping_mtime(path: &Path, content: &[u8]) {
+    let mtime = fs::metadata(path).unwrap().modified().unwrap();
+    fs::write(path, content).unwrap();
+    File::options()
+      .write(true)
+      .open(path)
+      .unwrap()
+      .set_modified(mtime)
+      .unwrap();
+  }
+
+  fn hash_of(manifest: &SyncManifest, path: &str) -> String {
+    manifest
+      .files
+      .iter()
+      .find(|f| f.path == path)
+      .unwrap()
+      .hash
+      .clone()
+  }
+
+  #[test]
+  fn test_mtime_preserving_change_is_detected() {
+    let temp_dir = TempDir::new().unwrap();
+    let profile_dir = temp_dir.path().join("profile");
+    fs::create_dir_all(&profile_dir).unwrap();
+    let large = vec![b'a'; 3 * SAMPLE_BYTES as usize];
+    fs::write(profile_dir.join("small"), "before").unwrap();
+    fs::write(profile_dir.join("large"), &large).unwrap();
+
+    let mut cache = HashCache::default();
+    let before = generate_manifest("p", &profile_dir, &mut cache).unwrap();
+    assert!(cache.entries.contains_key("large"));
+    assert!(!cache.entries.contains_key("small"));
+
+    write_keeping_mtime(&profile_dir.join("small"), b"after!");
+    let mut changed = large.clone();
+    changed[10] = b'b';
+    write_keeping_mtime(&profile_dir.join("large"), &changed);
+
+    let after = generate_manifest("p", &profile_dir, &mut cache).unwrap();
+    assert_ne!(hash_of(&before, "small"), hash_of(&after, "small"));
+    assert_ne!(hash_of(&before, "large"), hash_of(&after, "large"));
+    assert!(!compute_diff(&after, Some(&before)).is_empty());
+  }
+
+  #[cfg(unix)]
+  #[test]
+  fn test_replaced_file_is_rehashed() {
+    let temp_dir = TempDir::new().unwrap();
+    let profile_dir = temp_dir.path().join("profile");
+    fs::create_dir_all(&profile_dir).unwrap();
+    let path = profile_dir.join("large");
+    let original = vec![b'a'; 3 * SAMPLE_BYTES as usize];
+    fs::write(&path, &original).unwrap();
+
+    let mut cache = HashCache::default();
+    let before = generate_manifest("p", &profile_dir, &mut cache).unwrap();
+
+    // A restore that only differs in the middle, with the old mtime, written
+    // to a new file and renamed into place.
+    let mtime = fs::metadata(&path).unwrap().modified().unwrap();
+    let mut restored = original.clone();
+    restored[SAMPLE_BYTES as usize + 10] = b'b';
+    let staged = temp_dir.path().join("staged");
+    fs::write(&staged, &restored).unwrap();
+    File::options()
+      .write(true)
+      .open(&staged)
+      .unwrap()
+      .set_modified(mtime)
+      .unwrap();
+    fs::rename(&staged, &path).unwrap();
+
+    let after = generate_manifest("p", &profile_dir, &mut cache).unwrap();
+    assert_ne!(hash_of(&before, "large"), hash_of(&after, "large"));
   }
 
   #[test]
@@ -674,6 +987,36 @@ mod tests {
     assert!(diff.files_to_delete_remote.is_empty());
   }
 
+  #[test]
+  fn test_preview_totals_and_largest_files() {
+    let mut local = SyncManifest::new("test".to_string(), vec![]);
+    local.files = (0..25u64)
+      .map(|i| ManifestFileEntry {
+        path: format!("file{i:02}.bin"),
+        size: i * 100,
+        mtime: 1000,
+        hash: format!("h{i}"),
+      })
+      .collect();
+
+    let preview = compute_diff(&local, None).preview(false);
+
+    assert!(!preview.remote_manifest_found);
+    assert_eq!(preview.upload_files, 25);
+    assert_eq!(
+      preview.upload_bytes,
+      (0..25u64).map(|i| i * 100).sum::<u64>()
+    );
+    assert_eq!(preview.download_files, 0);
+    assert_eq!(preview.largest_files.len(), PREVIEW_LARGEST_FILES);
+    assert_eq!(preview.largest_files[0].path, "file24.bin");
+    assert_eq!(
+      preview.largest_files[0].direction,
+      TransferDirection::Upload
+    );
+    assert_eq!(preview.largest_files[19].size, 500);
+  }
+
   #[test]
   fn test_compute_diff_detect_changes() {
     let old_time = "2024-01-01T00:00:00Z";
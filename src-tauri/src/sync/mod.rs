@@ -1,3 +1,4 @@
+pub mod bandwidth;
 mod client;
 pub mod encryption;
 mod engine;
@@ -8,21 +9,28 @@ pub mod types;
 
 pub use client::SyncClient;
 pub use encryption::{
-  check_has_e2e_password, delete_e2e_password, set_e2e_password, verify_e2e_password,
+  check_e2e_rotation_pending, check_has_e2e_password, delete_e2e_password, set_e2e_password,
+  verify_e2e_password,
 };
 pub use engine::{
-  cancel_profile_sync, enable_extension_group_sync_if_needed, enable_group_sync_if_needed,
-  enable_proxy_sync_if_needed, enable_sync_for_all_entities, enable_vpn_sync_if_needed,
-  get_unsynced_entity_counts, is_group_in_use_by_synced_profile, is_group_used_by_synced_profile,
+  cancel_profile_sync, change_e2e_password, enable_extension_group_sync_if_needed,
+  enable_group_sync_if_needed, enable_proxy_pool_sync_if_needed, enable_proxy_sync_if_needed,
+  enable_sync_for_all_entities, enable_vpn_sync_if_needed, get_unsynced_entity_counts,
+  is_group_in_use_by_synced_profile, is_group_used_by_synced_profile,
   is_proxy_in_use_by_synced_profile, is_proxy_used_by_synced_profile, is_sync_configured,
-  is_vpn_in_use_by_synced_profile, is_vpn_used_by_synced_profile, request_profile_sync,
-  rollover_encryption_for_all_entities, set_extension_group_sync_enabled,
-  set_extension_sync_enabled, set_group_sync_enabled, set_profile_sync_mode,
-  set_proxy_sync_enabled, set_vpn_sync_enabled, sync_profile, trigger_sync_for_profile, SyncEngine,
+  is_vpn_in_use_by_synced_profile, is_vpn_used_by_synced_profile, preview_profile_sync,
+  preview_profile_sync_impl, request_profile_sync, rollover_encryption_for_all_entities,
+  set_extension_group_sync_enabled, set_extension_sync_enabled, set_group_sync_enabled,
+  set_profile_sync_mode, set_profile_sync_mode_impl, set_proxy_sync_enabled, set_vpn_sync_enabled,
+  sync_profile, trigger_sync_for_profile, SyncEngine,
 };
-pub use manifest::{compute_diff, generate_manifest, HashCache, ManifestDiff, SyncManifest};
-pub use scheduler::{get_global_scheduler, set_global_scheduler, SyncScheduler};
-pub use subscription::{SubscriptionManager, SyncWorkItem};
+pub use manifest::{
+  compute_diff, generate_manifest, HashCache, ManifestDiff, SyncManifest, SyncPreview,
+};
+pub use scheduler::{
+  get_global_scheduler, get_sync_queue_status, set_global_scheduler, SyncScheduler,
+};
+pub use subscription::{get_sync_subscription_status, SubscriptionManager, SyncWorkItem};
 pub use types::{SyncError, SyncResult};
 
 /// Queue a profile sync if the profile has sync enabled. No-op otherwise.
@@ -42,3 +50,17 @@ pub fn queue_profile_sync_if_eligible(profile: &crate::profile::BrowserProfile)
     }
   });
 }
+
+/// Like [`queue_profile_sync_if_eligible`], but every file is hashed again
+/// instead of trusting the hash cache, for when the whole data dir changed.
+pub fn queue_profile_rehash_if_eligible(profile: &crate::profile::BrowserProfile) {
+  if !profile.is_sync_enabled() {
+    return;
+  }
+  let profile_id = profile.id.to_string();
+  tauri::async_runtime::spawn(async move {
+    if let Some(scheduler) = get_global_scheduler() {
+      scheduler.queue_profile_rehash(profile_id).await;
+    }
+  });
+}
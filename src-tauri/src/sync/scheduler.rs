@@ -1,8 +1,15 @@
 use super::engine::SyncEngine;
 use super::subscription::SyncWorkItem;
+use super::types::SyncResult;
 use crate::events;
+use crate::events::payloads::{
+  ExtensionSyncStatus, GroupSyncStatus, ProfileSyncStatus, ProxySyncStatus, VpnSyncStatus,
+};
 use crate::profile::ProfileManager;
+use crate::settings_manager::SyncWindow;
+use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
@@ -22,17 +29,343 @@ pub fn set_global_scheduler(scheduler: Arc<SyncScheduler>) {
   }
 }
 
+/// Wait this long after a browser closes before uploading its files, so
+/// Chromium has flushed and released its SQLite databases.
+const CLOSE_QUIESCENCE: Duration = Duration::from_secs(5);
+/// A profile that stays open this long after a change still gets its
+/// metadata synced; the file sync keeps waiting for the browser to close.
+const RUNNING_METADATA_SYNC_AFTER: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ProfileSyncAction {
+  Wait,
+  MetadataOnly,
+  Full,
+}
+
 #[derive(Debug, Clone)]
-struct ProfileStopTime {
-  #[allow(dead_code)]
-  stopped_at: Instant,
-  queued: bool,
+struct PendingProfileSync {
+  queued_at: Instant,
+  /// `None` while the browser is open: the file sync is pending-close.
+  stopped_at: Option<Instant>,
+  metadata_synced: bool,
+  /// Ignore the hash cache on the next full sync.
+  force_rehash: bool,
+}
+
+impl PendingProfileSync {
+  fn ready_now(now: Instant) -> Self {
+    Self {
+      queued_at: now,
+      stopped_at: Some(now.checked_sub(CLOSE_QUIESCENCE).unwrap_or(now)),
+      metadata_synced: false,
+      force_rehash: false,
+    }
+  }
+
+  fn pending_close(now: Instant) -> Self {
+    Self {
+      queued_at: now,
+      stopped_at: None,
+      metadata_synced: false,
+      force_rehash: false,
+    }
+  }
+
+  fn next_action(&mut self, running: bool, now: Instant) -> ProfileSyncAction {
+    if running {
+      return if !self.metadata_synced
+        && now.duration_since(self.queued_at) >= RUNNING_METADATA_SYNC_AFTER
+      {
+        ProfileSyncAction::MetadataOnly
+      } else {
+        ProfileSyncAction::Wait
+      };
+    }
+    match self.stopped_at {
+      // The close was never reported (e.g. the browser died between status
+      // checks); start the quiescence window now.
+      None => {
+        self.stopped_at = Some(now);
+        ProfileSyncAction::Wait
+      }
+      Some(stopped_at) if now.duration_since(stopped_at) < CLOSE_QUIESCENCE => {
+        ProfileSyncAction::Wait
+      }
+      Some(_) => ProfileSyncAction::Full,
+    }
+  }
+}
+
+/// Whether profile files may sync at local time `now` under `window`. An
+/// unparsable window doesn't block syncing.
+fn file_sync_allowed(window: Option<&SyncWindow>, now: chrono::NaiveTime) -> bool {
+  let Some(window) = window else {
+    return true;
+  };
+  let parse = |t: &str| chrono::NaiveTime::parse_from_str(t.trim(), "%H:%M").ok();
+  let (Some(start), Some(end)) = (parse(&window.start), parse(&window.end)) else {
+    log::warn!(
+      "Ignoring invalid sync window {}-{}",
+      window.start,
+      window.end
+    );
+    return true;
+  };
+  match start.cmp(&end) {
+    std::cmp::Ordering::Less => start <= now && now < end,
+    std::cmp::Ordering::Greater => now >= start || now < end,
+    std::cmp::Ordering::Equal => true,
+  }
+}
+
+fn file_sync_window_open() -> bool {
+  let window = crate::settings_manager::SettingsManager::instance()
+    .load_settings()
+    .ok()
+    .and_then(|s| s.sync_window);
+  file_sync_allowed(window.as_ref(), chrono::Local::now().time())
+}
+
+fn emit_profile_sync_status(profile_id: &str, status: &str) {
+  let _ = events::emit(&ProfileSyncStatus::new(profile_id, status));
+}
+
+const OFFLINE_QUEUE_FILE: &str = "sync_offline_queue.json";
+/// Backoff between retries of work held while the server is unreachable,
+/// doubling per failed round.
+const OFFLINE_RETRY_MIN: Duration = Duration::from_secs(5);
+const OFFLINE_RETRY_MAX: Duration = Duration::from_secs(300);
+
+static SERVER_REACHABLE: AtomicBool = AtomicBool::new(true);
+/// Set on the unreachable -> reachable transition so the next tick retries
+/// held work without waiting out the backoff.
+static SERVER_RECONNECTED: AtomicBool = AtomicBool::new(false);
+
+/// Records whether the sync server answered the last request. Fed by the
+/// subscription stream and by the scheduler's own sync calls.
+pub fn set_server_reachable(reachable: bool) {
+  let was = SERVER_REACHABLE.swap(reachable, Ordering::SeqCst);
+  if reachable && !was {
+    log::info!("Sync server is reachable again, retrying queued changes");
+    SERVER_RECONNECTED.store(true, Ordering::SeqCst);
+  } else if !reachable && was {
+    log::warn!("Sync server is unreachable, holding changes until it returns");
+  }
+}
+
+pub fn is_server_reachable() -> bool {
+  SERVER_REACHABLE.load(Ordering::SeqCst)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum QueuedSyncOperation {
+  Sync,
+  /// Metadata-only sync of a profile whose browser is still open.
+  SyncMetadata,
+}
+
+/// A sync that failed because the server couldn't be reached.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QueuedSyncItem {
+  pub entity_type: String,
+  pub entity_id: String,
+  pub operation: QueuedSyncOperation,
+  pub queued_at: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SyncQueueStatus {
+  pub pending: Vec<QueuedSyncItem>,
+  pub server_reachable: bool,
+}
+
+/// Work held while the server is unreachable, persisted so it survives a
+/// restart. Holds at most one item per entity.
+struct OfflineQueue {
+  path: Option<PathBuf>,
+  items: Vec<QueuedSyncItem>,
+  failed_rounds: u32,
+  next_retry_at: Option<Instant>,
+}
+
+impl OfflineQueue {
+  fn load(path: PathBuf) -> Self {
+    let items = match std::fs::read_to_string(&path) {
+      Ok(content) => serde_json::from_str(&content).unwrap_or_else(|e| {
+        log::warn!("Ignoring unreadable sync offline queue: {e}");
+        Vec::new()
+      }),
+      Err(_) => Vec::new(),
+    };
+    let mut queue = Self {
+      path: Some(path),
+      items,
+      failed_rounds: 0,
+      next_retry_at: None,
+    };
+    if !queue.items.is_empty() {
+      // Retry on the first tick after startup.
+      queue.next_retry_at = Some(Instant::now());
+    }
+    queue
+  }
+
+  fn save(&self) {
+    let Some(path) = &self.path else {
+      return;
+    };
+    let result = serde_json::to_vec_pretty(&self.items)
+      .map_err(|e| e.to_string())
+      .and_then(|json| {
+        if let Some(parent) = path.parent() {
+          std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+        }
+        std::fs::write(path, json).map_err(|e| e.to_string())
+      });
+    if let Err(e) = result {
+      log::warn!("Failed to save sync offline queue: {e}");
+    }
+  }
+
+  fn backoff(failed_rounds: u32) -> Duration {
+    OFFLINE_RETRY_MIN
+      .saturating_mul(1u32 << failed_rounds.min(16))
+      .min(OFFLINE_RETRY_MAX)
+  }
+
+  /// Holds `entity_id` for a later retry. A full sync supersedes a queued
+  /// metadata-only one. Returns whether the queue changed.
+  fn defer(
+    &mut self,
+    entity_type: &str,
+    entity_id: &str,
+    operation: QueuedSyncOperation,
+    now: Instant,
+  ) -> bool {
+    let changed = match self
+      .items
+      .iter_mut()
+      .find(|i| i.entity_type == entity_type && i.entity_id == entity_id)
+    {
+      Some(item) => {
+        if item.operation == QueuedSyncOperation::SyncMetadata
+          && operation == QueuedSyncOperation::Sync
+        {
+          item.operation = operation;
+          true
+        } else {
+          false
+        }
+      }
+      None => {
+        self.items.push(QueuedSyncItem {
+          entity_type: entity_type.to_string(),
+          entity_id: entity_id.to_string(),
+          operation,
+          queued_at: crate::proxy_manager::now_secs(),
+        });
+        true
+      }
+    };
+    if self.next_retry_at.is_none() {
+      self.next_retry_at = Some(now + Self::backoff(self.failed_rounds));
+    }
+    if changed {
+      self.save();
+    }
+    changed
+  }
+
+  /// Drops `entity_id` once a sync of it finished (or failed for a reason a
+  /// retry won't fix). Returns whether the queue changed.
+  fn resolve(&mut self, entity_type: &str, entity_id: &str) -> bool {
+    let before = self.items.len();
+    self
+      .items
+      .retain(|i| !(i.entity_type == entity_type && i.entity_id == entity_id));
+    if self.items.len() == before {
+      return false;
+    }
+    if self.items.is_empty() {
+      self.failed_rounds = 0;
+      self.next_retry_at = None;
+    }
+    self.save();
+    true
+  }
+
+  /// The items to retry now: when the backoff has elapsed, or right away
+  /// after the server came back. Items stay queued until resolved.
+  fn take_due(&mut self, now: Instant, reconnected: bool) -> Vec<QueuedSyncItem> {
+    if self.items.is_empty() {
+      return Vec::new();
+    }
+    if reconnected {
+      self.failed_rounds = 0;
+    } else if self.next_retry_at.is_some_and(|at| now < at) {
+      return Vec::new();
+    }
+    self.next_retry_at = Some(now + Self::backoff(self.failed_rounds + 1));
+    self.failed_rounds = self.failed_rounds.saturating_add(1);
+    self.items.clone()
+  }
+}
+
+fn emit_sync_queue_changed(items: &[QueuedSyncItem]) {
+  let _ = events::emit(&SyncQueueStatus {
+    pending: items.to_vec(),
+    server_reachable: is_server_reachable(),
+  });
+}
+
+/// Settles a finished sync against the offline queue: connection failures
+/// hold the entity for a retry, anything else releases it. Returns whether
+/// the sync was deferred.
+async fn settle_sync(
+  offline: &Mutex<OfflineQueue>,
+  entity_type: &str,
+  entity_id: &str,
+  operation: QueuedSyncOperation,
+  result: &SyncResult<()>,
+) -> bool {
+  let deferred = matches!(result, Err(e) if e.is_connectivity());
+  if deferred {
+    set_server_reachable(false);
+  } else if result.is_ok() {
+    set_server_reachable(true);
+  }
+  let mut queue = offline.lock().await;
+  let changed = if deferred {
+    log::info!("Holding {entity_type} {entity_id} until the sync server is reachable");
+    queue.defer(entity_type, entity_id, operation, Instant::now())
+  } else {
+    queue.resolve(entity_type, entity_id)
+  };
+  if changed {
+    emit_sync_queue_changed(&queue.items);
+  }
+  deferred
+}
+
+/// Nothing was attempted without an engine; report it as a connection failure
+/// when the server is known to be down (e.g. the token refresh couldn't reach
+/// it), so the work is held rather than dropped.
+fn engine_unavailable(e: String) -> super::types::SyncError {
+  log::error!("Failed to create sync engine: {}", e);
+  if is_server_reachable() {
+    super::types::SyncError::NotConfigured
+  } else {
+    super::types::SyncError::ConnectionError(e)
+  }
 }
 
 pub struct SyncScheduler {
   running: Arc<AtomicBool>,
-  pending_profiles: Arc<Mutex<HashMap<String, ProfileStopTime>>>,
+  pending_profiles: Arc<Mutex<HashMap<String, PendingProfileSync>>>,
   pending_proxies: Arc<Mutex<HashSet<String>>>,
+  pending_proxy_pools: Arc<Mutex<HashSet<String>>>,
   pending_groups: Arc<Mutex<HashSet<String>>>,
   pending_vpns: Arc<Mutex<HashSet<String>>>,
   pending_extensions: Arc<Mutex<HashSet<String>>>,
@@ -40,6 +373,7 @@ pub struct SyncScheduler {
   pending_tombstones: Arc<Mutex<Vec<(String, String)>>>,
   running_profiles: Arc<Mutex<HashSet<String>>>,
   in_flight_profiles: Arc<Mutex<HashSet<String>>>,
+  offline_queue: Arc<Mutex<OfflineQueue>>,
 }
 
 impl Default for SyncScheduler {
@@ -54,6 +388,7 @@ impl SyncScheduler {
       running: Arc::new(AtomicBool::new(false)),
       pending_profiles: Arc::new(Mutex::new(HashMap::new())),
       pending_proxies: Arc::new(Mutex::new(HashSet::new())),
+      pending_proxy_pools: Arc::new(Mutex::new(HashSet::new())),
       pending_groups: Arc::new(Mutex::new(HashSet::new())),
       pending_vpns: Arc::new(Mutex::new(HashSet::new())),
       pending_extensions: Arc::new(Mutex::new(HashSet::new())),
@@ -61,6 +396,9 @@ impl SyncScheduler {
       pending_tombstones: Arc::new(Mutex::new(Vec::new())),
       running_profiles: Arc::new(Mutex::new(HashSet::new())),
       in_flight_profiles: Arc::new(Mutex::new(HashSet::new())),
+      offline_queue: Arc::new(Mutex::new(OfflineQueue::load(
+        crate::app_dirs::settings_dir().join(OFFLINE_QUEUE_FILE),
+      ))),
     }
   }
 
@@ -92,6 +430,12 @@ impl SyncScheduler {
     }
     drop(pending_proxies);
 
+    let pending_proxy_pools = self.pending_proxy_pools.lock().await;
+    if !pending_proxy_pools.is_empty() {
+      return true;
+    }
+    drop(pending_proxy_pools);
+
     let pending_groups = self.pending_groups.lock().await;
     if !pending_groups.is_empty() {
       return true;
@@ -128,26 +472,29 @@ impl SyncScheduler {
     let mut running = self.running_profiles.lock().await;
     running.insert(profile_id.to_string());
     log::debug!("Marked profile {} as running", profile_id);
+    drop(running);
+
+    let mut pending = self.pending_profiles.lock().await;
+    if let Some(entry) = pending.get_mut(profile_id) {
+      entry.stopped_at = None;
+      drop(pending);
+      emit_profile_sync_status(profile_id, "waiting");
+    }
   }
 
   pub async fn mark_profile_stopped(&self, profile_id: &str) {
     let mut running = self.running_profiles.lock().await;
     running.remove(profile_id);
     log::debug!("Marked profile {} as stopped", profile_id);
+    drop(running);
 
     let mut pending = self.pending_profiles.lock().await;
-    if pending.contains_key(profile_id) {
-      // Set stopped_at to past so it syncs immediately
-      pending.insert(
-        profile_id.to_string(),
-        ProfileStopTime {
-          stopped_at: Instant::now() - Duration::from_secs(3),
-          queued: true,
-        },
-      );
+    if let Some(entry) = pending.get_mut(profile_id) {
+      entry.stopped_at = Some(Instant::now());
       log::debug!(
-        "Profile {} has pending sync, will execute immediately",
-        profile_id
+        "Profile {} has pending sync, will execute after {}s quiescence",
+        profile_id,
+        CLOSE_QUIESCENCE.as_secs()
       );
     }
   }
@@ -183,32 +530,47 @@ impl SyncScheduler {
     self.queue_profile_sync_internal(profile_id).await;
   }
 
+  /// Queues a full sync that rehashes every file instead of trusting the
+  /// hash cache.
+  pub async fn queue_profile_rehash(&self, profile_id: String) {
+    self.queue_profile_sync_internal(profile_id.clone()).await;
+    if let Some(entry) = self.pending_profiles.lock().await.get_mut(&profile_id) {
+      entry.force_rehash = true;
+    }
+  }
+
   async fn queue_profile_sync_internal(&self, profile_id: String) {
     let is_running = self.is_profile_running(&profile_id).await;
+    let now = Instant::now();
     let mut pending = self.pending_profiles.lock().await;
 
     if is_running {
-      // Profile is running - queue for after it stops
-      pending.insert(
-        profile_id.clone(),
-        ProfileStopTime {
-          stopped_at: Instant::now(),
-          queued: true,
-        },
-      );
+      // Profile is running - defer the file sync until it closes. Keep the
+      // original queued_at so a steady stream of changes can't postpone the
+      // metadata sync forever.
+      let entry = pending
+        .entry(profile_id.clone())
+        .or_insert_with(|| PendingProfileSync::pending_close(now));
+      if entry.metadata_synced {
+        entry.metadata_synced = false;
+        entry.queued_at = now;
+      }
+      entry.stopped_at = None;
+      drop(pending);
       log::debug!(
         "Profile {} is running, queued sync for after stop",
         profile_id
       );
+      emit_profile_sync_status(&profile_id, "waiting");
     } else {
-      // Profile is not running - sync immediately (set stopped_at to past)
-      pending.insert(
-        profile_id.clone(),
-        ProfileStopTime {
-          stopped_at: Instant::now() - Duration::from_secs(3),
-          queued: true,
-        },
-      );
+      // Profile is not running - sync on the next tick unless it only just
+      // closed and is still inside the quiescence window.
+      let entry = pending
+        .entry(profile_id.clone())
+        .or_insert_with(|| PendingProfileSync::ready_now(now));
+      if entry.stopped_at.is_none() {
+        *entry = PendingProfileSync::ready_now(now);
+      }
       log::debug!("Profile {} queued for immediate sync", profile_id);
     }
   }
@@ -218,6 +580,11 @@ impl SyncScheduler {
     pending.insert(proxy_id);
   }
 
+  pub async fn queue_proxy_pool_sync(&self, pool_id: String) {
+    let mut pending = self.pending_proxy_pools.lock().await;
+    pending.insert(pool_id);
+  }
+
   pub async fn queue_vpn_sync(&self, vpn_id: String) {
     let mut pending = self.pending_vpns.lock().await;
     pending.insert(vpn_id);
@@ -248,7 +615,62 @@ impl SyncScheduler {
     }
   }
 
-  pub async fn sync_all_enabled_profiles(&self, _app_handle: &tauri::AppHandle) {
+  pub async fn queue_status(&self) -> SyncQueueStatus {
+    SyncQueueStatus {
+      pending: self.offline_queue.lock().await.items.clone(),
+      server_reachable: is_server_reachable(),
+    }
+  }
+
+  /// Puts held work back into the pending sets once its retry is due.
+  async fn process_offline_queue(&self) {
+    let reconnected = SERVER_RECONNECTED.swap(false, Ordering::SeqCst);
+    let due = self
+      .offline_queue
+      .lock()
+      .await
+      .take_due(Instant::now(), reconnected);
+    if due.is_empty() {
+      return;
+    }
+    log::info!("Retrying {} queued sync item(s)", due.len());
+    for item in due {
+      match item.entity_type.as_str() {
+        "profile" => self.queue_profile_sync(item.entity_id).await,
+        "proxy" => self.queue_proxy_sync(item.entity_id).await,
+        "proxy_pool" => self.queue_proxy_pool_sync(item.entity_id).await,
+        "group" => self.queue_group_sync(item.entity_id).await,
+        "vpn" => self.queue_vpn_sync(item.entity_id).await,
+        "extension" => self.queue_extension_sync(item.entity_id).await,
+        "extension_group" => self.queue_extension_group_sync(item.entity_id).await,
+        other => {
+          log::warn!("Dropping queued sync for unknown entity type {other}");
+          let mut queue = self.offline_queue.lock().await;
+          if queue.resolve(other, &item.entity_id) {
+            emit_sync_queue_changed(&queue.items);
+          }
+        }
+      }
+    }
+  }
+
+  /// Holds `ids` when the engine couldn't be created because the server is
+  /// down.
+  async fn hold_if_offline(&self, entity_type: &str, ids: &[String]) {
+    if is_server_reachable() {
+      return;
+    }
+    let mut queue = self.offline_queue.lock().await;
+    let mut changed = false;
+    for id in ids {
+      changed |= queue.defer(entity_type, id, QueuedSyncOperation::Sync, Instant::now());
+    }
+    if changed {
+      emit_sync_queue_changed(&queue.items);
+    }
+  }
+
+  pub async fn sync_all_enabled_profiles(&self, _app_handle: &crate::app_handle::AppHandle) {
     log::info!("Starting initial sync for all enabled profiles...");
 
     let profiles = {
@@ -264,7 +686,7 @@ impl SyncScheduler {
 
     let sync_enabled_profiles: Vec<_> = profiles
       .into_iter()
-      .filter(|p| p.is_sync_enabled())
+      .filter(|p| p.is_sync_enabled() && !p.archived)
       .collect();
 
     if sync_enabled_profiles.is_empty() {
@@ -303,13 +725,10 @@ impl SyncScheduler {
       }
 
       // Emit initial status
-      let _ = events::emit(
-        "profile-sync-status",
-        serde_json::json!({
-          "profile_id": profile_id,
-          "status": if should_wait { "waiting" } else { "syncing" }
-        }),
-      );
+      let _ = events::emit(&ProfileSyncStatus::new(
+        &profile_id,
+        if should_wait { "waiting" } else { "syncing" },
+      ));
 
       // Queue for sync — running profiles will be deferred by the scheduler
       self.queue_profile_sync_immediate(profile_id).await;
@@ -318,7 +737,7 @@ impl SyncScheduler {
 
   pub async fn start(
     self: Arc<Self>,
-    app_handle: tauri::AppHandle,
+    app_handle: crate::app_handle::AppHandle,
     mut work_rx: mpsc::UnboundedReceiver<SyncWorkItem>,
   ) {
     if self.running.swap(true, Ordering::SeqCst) {
@@ -335,6 +754,7 @@ impl SyncScheduler {
             match work_item {
               SyncWorkItem::Profile(id) => scheduler.queue_profile_sync(id).await,
               SyncWorkItem::Proxy(id) => scheduler.queue_proxy_sync(id).await,
+              SyncWorkItem::ProxyPool(id) => scheduler.queue_proxy_pool_sync(id).await,
               SyncWorkItem::Group(id) => scheduler.queue_group_sync(id).await,
               SyncWorkItem::Vpn(id) => scheduler.queue_vpn_sync(id).await,
               SyncWorkItem::Extension(id) => scheduler.queue_extension_sync(id).await,
@@ -354,9 +774,20 @@ impl SyncScheduler {
     });
   }
 
-  async fn process_pending(&self, app_handle: &tauri::AppHandle) {
+  async fn process_pending(&self, app_handle: &crate::app_handle::AppHandle) {
+    // Hold queued work while a password change is moving remote data to the
+    // new key; syncing now would mix objects sealed under both passwords.
+    if super::encryption::rotation_in_progress() {
+      return;
+    }
+    // Queued work waits out offline mode.
+    if crate::offline::is_offline() {
+      return;
+    }
+    self.process_offline_queue().await;
     self.process_pending_profiles(app_handle).await;
     self.process_pending_proxies(app_handle).await;
+    self.process_pending_proxy_pools(app_handle).await;
     self.process_pending_groups(app_handle).await;
     self.process_pending_vpns(app_handle).await;
     self.process_pending_extensions(app_handle).await;
@@ -364,23 +795,60 @@ impl SyncScheduler {
     self.process_pending_tombstones(app_handle).await;
   }
 
-  async fn process_pending_profiles(&self, app_handle: &tauri::AppHandle) {
-    let profiles_to_sync: Vec<String> = {
+  async fn process_pending_profiles(&self, app_handle: &crate::app_handle::AppHandle) {
+    if self.pending_profiles.lock().await.is_empty() {
+      return;
+    }
+
+    // A stored process_id means Chromium may still hold write locks on the
+    // profile's SQLite files, even if the status checker hasn't reported the
+    // launch yet. Archived profiles are held back the same way: their
+    // metadata (and with it the archived flag) syncs, their files wait until
+    // they're unarchived.
+    let mut running: HashSet<String> = self.running_profiles.lock().await.clone();
+    if let Ok(profiles) = ProfileManager::instance().list_profiles() {
+      running.extend(
+        profiles
+          .iter()
+          .filter(|p| p.process_id.is_some() || p.archived)
+          .map(|p| p.id.to_string()),
+      );
+    }
+
+    let files_allowed = file_sync_window_open();
+    let profiles_to_sync: Vec<(String, ProfileSyncAction, bool)> = {
       let mut pending = self.pending_profiles.lock().await;
-      let running = self.running_profiles.lock().await;
       let in_flight = self.in_flight_profiles.lock().await;
+      let now = Instant::now();
 
-      // Sync immediately if not running and not in-flight (no delay check)
-      let ready: Vec<String> = pending
-        .iter()
-        .filter(|(id, stop_time)| {
-          !running.contains(*id) && !in_flight.contains(*id) && stop_time.queued
-        })
-        .map(|(id, _)| id.clone())
-        .collect();
-
-      for id in &ready {
-        pending.remove(id);
+      let mut ready = Vec::new();
+      for (id, entry) in pending.iter_mut() {
+        if in_flight.contains(id) {
+          continue;
+        }
+        match entry.next_action(running.contains(id), now) {
+          ProfileSyncAction::Wait => {}
+          ProfileSyncAction::MetadataOnly => {
+            entry.metadata_synced = true;
+            ready.push((id.clone(), ProfileSyncAction::MetadataOnly, false));
+          }
+          // Outside the sync window the files stay queued until it opens.
+          ProfileSyncAction::Full if !files_allowed => {
+            if !entry.metadata_synced {
+              entry.metadata_synced = true;
+              ready.push((id.clone(), ProfileSyncAction::MetadataOnly, false));
+            }
+          }
+          ProfileSyncAction::Full => {
+            ready.push((id.clone(), ProfileSyncAction::Full, entry.force_rehash))
+          }
+        }
+      }
+
+      for (id, action, _) in &ready {
+        if *action == ProfileSyncAction::Full {
+          pending.remove(id);
+        }
       }
 
       ready
@@ -388,30 +856,64 @@ impl SyncScheduler {
 
     // Mark all profiles as in-flight and filter out duplicates
     let mut to_sync = Vec::new();
-    for profile_id in profiles_to_sync {
+    for (profile_id, action, force_rehash) in profiles_to_sync {
       let mut in_flight = self.in_flight_profiles.lock().await;
       if in_flight.contains(&profile_id) {
         log::debug!("Profile {} already in-flight, skipping", profile_id);
         continue;
       }
       in_flight.insert(profile_id.clone());
-      to_sync.push(profile_id);
+      to_sync.push((profile_id, action, force_rehash));
     }
 
     // Sync all profiles in parallel
     let mut sync_set = tokio::task::JoinSet::new();
-    for profile_id in to_sync {
+    for (profile_id, action, force_rehash) in to_sync {
       let app = app_handle.clone();
       let in_flight = self.in_flight_profiles.clone();
+      let offline = self.offline_queue.clone();
       sync_set.spawn(async move {
+        if action == ProfileSyncAction::MetadataOnly {
+          log::info!(
+            "Syncing metadata only for profile {}, files are deferred",
+            profile_id
+          );
+          let profile = ProfileManager::instance()
+            .list_profiles()
+            .ok()
+            .and_then(|profiles| {
+              profiles
+                .into_iter()
+                .find(|p| p.id.to_string() == profile_id && p.is_sync_enabled())
+            });
+          if let Some(profile) = profile {
+            let result = match SyncEngine::create_from_settings(&app).await {
+              Ok(engine) => engine
+                .sync_profile_metadata(&app, &profile)
+                .await
+                .map(|_| ()),
+              Err(e) => Err(engine_unavailable(e)),
+            };
+            settle_sync(
+              &offline,
+              "profile",
+              &profile_id,
+              QueuedSyncOperation::SyncMetadata,
+              &result,
+            )
+            .await;
+            if let Err(e) = result {
+              log::error!("Failed to sync metadata for profile {}: {}", profile_id, e);
+            }
+          }
+          in_flight.lock().await.remove(&profile_id);
+          // Files are still pending-close.
+          emit_profile_sync_status(&profile_id, "waiting");
+          return;
+        }
+
         log::info!("Executing queued sync for profile {}", profile_id);
-        let _ = events::emit(
-          "profile-sync-status",
-          serde_json::json!({
-            "profile_id": profile_id,
-            "status": "syncing"
-          }),
-        );
+        emit_profile_sync_status(&profile_id, "syncing");
 
         let profile_to_sync = {
           let profile_manager = ProfileManager::instance();
@@ -425,15 +927,22 @@ impl SyncScheduler {
         let Some(profile) = profile_to_sync else {
           let mut inf = in_flight.lock().await;
           inf.remove(&profile_id);
+          drop(inf);
+          // Deleted or no longer synced: nothing left to retry.
+          let mut queue = offline.lock().await;
+          if queue.resolve("profile", &profile_id) {
+            emit_sync_queue_changed(&queue.items);
+          }
           return;
         };
 
         let result = match SyncEngine::create_from_settings(&app).await {
-          Ok(engine) => engine.sync_profile(&app, &profile).await,
-          Err(e) => {
-            log::error!("Failed to create sync engine: {}", e);
-            Err(super::types::SyncError::NotConfigured)
+          Ok(engine) => {
+            engine
+              .sync_profile_with_rehash(&app, &profile, force_rehash)
+              .await
           }
+          Err(e) => Err(engine_unavailable(e)),
         };
 
         {
@@ -441,27 +950,27 @@ impl SyncScheduler {
           inf.remove(&profile_id);
         }
 
+        if settle_sync(
+          &offline,
+          "profile",
+          &profile_id,
+          QueuedSyncOperation::Sync,
+          &result,
+        )
+        .await
+        {
+          emit_profile_sync_status(&profile_id, "waiting");
+          return;
+        }
+
         match result {
           Ok(()) => {
             log::info!("Profile {} synced successfully", profile_id);
-            let _ = events::emit(
-              "profile-sync-status",
-              serde_json::json!({
-                "profile_id": profile_id,
-                "status": "synced"
-              }),
-            );
+            let _ = events::emit(&ProfileSyncStatus::new(&profile_id, "synced"));
           }
           Err(e) => {
             log::error!("Failed to sync profile {}: {}", profile_id, e);
-            let _ = events::emit(
-              "profile-sync-status",
-              serde_json::json!({
-                "profile_id": profile_id,
-                "status": "error",
-                "error": e.to_string()
-              }),
-            );
+            let _ = events::emit(&ProfileSyncStatus::failed(&profile_id, e.to_string()));
           }
         }
       });
@@ -477,7 +986,7 @@ impl SyncScheduler {
     }
   }
 
-  async fn process_pending_proxies(&self, app_handle: &tauri::AppHandle) {
+  async fn process_pending_proxies(&self, app_handle: &crate::app_handle::AppHandle) {
     let proxies_to_sync: Vec<String> = {
       let mut pending = self.pending_proxies.lock().await;
       let list: Vec<String> = pending.drain().collect();
@@ -492,36 +1001,29 @@ impl SyncScheduler {
       Ok(engine) => {
         for proxy_id in proxies_to_sync {
           log::info!("Syncing proxy {}", proxy_id);
-          let _ = events::emit(
-            "proxy-sync-status",
-            serde_json::json!({
-              "id": proxy_id,
-              "status": "syncing"
-            }),
-          );
-          match engine
+          let _ = events::emit(&ProxySyncStatus::new(&proxy_id, "syncing"));
+          let result = engine
             .sync_proxy_by_id_with_handle(&proxy_id, app_handle)
-            .await
+            .await;
+          if settle_sync(
+            &self.offline_queue,
+            "proxy",
+            &proxy_id,
+            QueuedSyncOperation::Sync,
+            &result,
+          )
+          .await
           {
+            let _ = events::emit(&ProxySyncStatus::new(&proxy_id, "waiting"));
+            continue;
+          }
+          match result {
             Ok(()) => {
-              let _ = events::emit(
-                "proxy-sync-status",
-                serde_json::json!({
-                  "id": proxy_id,
-                  "status": "synced"
-                }),
-              );
+              let _ = events::emit(&ProxySyncStatus::new(&proxy_id, "synced"));
             }
             Err(e) => {
               log::error!("Failed to sync proxy {}: {}", proxy_id, e);
-              let _ = events::emit(
-                "proxy-sync-status",
-                serde_json::json!({
-                  "id": proxy_id,
-                  "status": "error",
-                  "error": e.to_string()
-                }),
-              );
+              let _ = events::emit(&ProxySyncStatus::failed(&proxy_id, e.to_string()));
             }
           }
         }
@@ -530,11 +1032,52 @@ impl SyncScheduler {
       }
       Err(e) => {
         log::error!("Failed to create sync engine: {}", e);
+        self.hold_if_offline("proxy", &proxies_to_sync).await;
       }
     }
   }
 
-  async fn process_pending_groups(&self, app_handle: &tauri::AppHandle) {
+  async fn process_pending_proxy_pools(&self, app_handle: &crate::app_handle::AppHandle) {
+    let pools_to_sync: Vec<String> = {
+      let mut pending = self.pending_proxy_pools.lock().await;
+      pending.drain().collect()
+    };
+
+    if pools_to_sync.is_empty() {
+      return;
+    }
+
+    match SyncEngine::create_from_settings(app_handle).await {
+      Ok(engine) => {
+        for pool_id in pools_to_sync {
+          log::info!("Syncing proxy pool {}", pool_id);
+          let result = engine
+            .sync_proxy_pool_by_id_with_handle(&pool_id, app_handle)
+            .await;
+          if settle_sync(
+            &self.offline_queue,
+            "proxy_pool",
+            &pool_id,
+            QueuedSyncOperation::Sync,
+            &result,
+          )
+          .await
+          {
+            continue;
+          }
+          if let Err(e) = result {
+            log::error!("Failed to sync proxy pool {}: {}", pool_id, e);
+          }
+        }
+      }
+      Err(e) => {
+        log::error!("Failed to create sync engine: {}", e);
+        self.hold_if_offline("proxy_pool", &pools_to_sync).await;
+      }
+    }
+  }
+
+  async fn process_pending_groups(&self, app_handle: &crate::app_handle::AppHandle) {
     let groups_to_sync: Vec<String> = {
       let mut pending = self.pending_groups.lock().await;
       let list: Vec<String> = pending.drain().collect();
@@ -549,36 +1092,29 @@ impl SyncScheduler {
       Ok(engine) => {
         for group_id in groups_to_sync {
           log::info!("Syncing group {}", group_id);
-          let _ = events::emit(
-            "group-sync-status",
-            serde_json::json!({
-              "id": group_id,
-              "status": "syncing"
-            }),
-          );
-          match engine
+          let _ = events::emit(&GroupSyncStatus::new(&group_id, "syncing"));
+          let result = engine
             .sync_group_by_id_with_handle(&group_id, app_handle)
-            .await
+            .await;
+          if settle_sync(
+            &self.offline_queue,
+            "group",
+            &group_id,
+            QueuedSyncOperation::Sync,
+            &result,
+          )
+          .await
           {
+            let _ = events::emit(&GroupSyncStatus::new(&group_id, "waiting"));
+            continue;
+          }
+          match result {
             Ok(()) => {
-              let _ = events::emit(
-                "group-sync-status",
-                serde_json::json!({
-                  "id": group_id,
-                  "status": "synced"
-                }),
-              );
+              let _ = events::emit(&GroupSyncStatus::new(&group_id, "synced"));
             }
             Err(e) => {
               log::error!("Failed to sync group {}: {}", group_id, e);
-              let _ = events::emit(
-                "group-sync-status",
-                serde_json::json!({
-                  "id": group_id,
-                  "status": "error",
-                  "error": e.to_string()
-                }),
-              );
+              let _ = events::emit(&GroupSyncStatus::failed(&group_id, e.to_string()));
             }
           }
         }
@@ -587,11 +1123,12 @@ impl SyncScheduler {
       }
       Err(e) => {
         log::error!("Failed to create sync engine: {}", e);
+        self.hold_if_offline("group", &groups_to_sync).await;
       }
     }
   }
 
-  async fn process_pending_vpns(&self, app_handle: &tauri::AppHandle) {
+  async fn process_pending_vpns(&self, app_handle: &crate::app_handle::AppHandle) {
     let vpns_to_sync: Vec<String> = {
       let mut pending = self.pending_vpns.lock().await;
       let list: Vec<String> = pending.drain().collect();
@@ -606,44 +1143,39 @@ impl SyncScheduler {
       Ok(engine) => {
         for vpn_id in vpns_to_sync {
           log::info!("Syncing VPN {}", vpn_id);
-          let _ = events::emit(
-            "vpn-sync-status",
-            serde_json::json!({
-              "id": vpn_id,
-              "status": "syncing"
-            }),
-          );
-          match engine.sync_vpn_by_id_with_handle(&vpn_id, app_handle).await {
+          let _ = events::emit(&VpnSyncStatus::new(&vpn_id, "syncing"));
+          let result = engine.sync_vpn_by_id_with_handle(&vpn_id, app_handle).await;
+          if settle_sync(
+            &self.offline_queue,
+            "vpn",
+            &vpn_id,
+            QueuedSyncOperation::Sync,
+            &result,
+          )
+          .await
+          {
+            let _ = events::emit(&VpnSyncStatus::new(&vpn_id, "waiting"));
+            continue;
+          }
+          match result {
             Ok(()) => {
-              let _ = events::emit(
-                "vpn-sync-status",
-                serde_json::json!({
-                  "id": vpn_id,
-                  "status": "synced"
-                }),
-              );
+              let _ = events::emit(&VpnSyncStatus::new(&vpn_id, "synced"));
             }
             Err(e) => {
               log::error!("Failed to sync VPN {}: {}", vpn_id, e);
-              let _ = events::emit(
-                "vpn-sync-status",
-                serde_json::json!({
-                  "id": vpn_id,
-                  "status": "error",
-                  "error": e.to_string()
-                }),
-              );
+              let _ = events::emit(&VpnSyncStatus::failed(&vpn_id, e.to_string()));
             }
           }
         }
       }
       Err(e) => {
         log::error!("Failed to create sync engine: {}", e);
+        self.hold_if_offline("vpn", &vpns_to_sync).await;
       }
     }
   }
 
-  async fn process_pending_extensions(&self, app_handle: &tauri::AppHandle) {
+  async fn process_pending_extensions(&self, app_handle: &crate::app_handle::AppHandle) {
     let extensions_to_sync: Vec<String> = {
       let mut pending = self.pending_extensions.lock().await;
       let list: Vec<String> = pending.drain().collect();
@@ -658,34 +1190,38 @@ impl SyncScheduler {
       Ok(engine) => {
         for ext_id in extensions_to_sync {
           log::info!("Syncing extension {}", ext_id);
-          let _ = events::emit(
-            "extension-sync-status",
-            serde_json::json!({ "id": ext_id, "status": "syncing" }),
-          );
-          if let Err(e) = engine
+          let _ = events::emit(&ExtensionSyncStatus::new(&ext_id, "syncing"));
+          let result = engine
             .sync_extension_by_id_with_handle(&ext_id, app_handle)
-            .await
+            .await;
+          if settle_sync(
+            &self.offline_queue,
+            "extension",
+            &ext_id,
+            QueuedSyncOperation::Sync,
+            &result,
+          )
+          .await
           {
+            let _ = events::emit(&ExtensionSyncStatus::new(&ext_id, "waiting"));
+            continue;
+          }
+          if let Err(e) = result {
             log::error!("Failed to sync extension {}: {}", ext_id, e);
-            let _ = events::emit(
-              "extension-sync-status",
-              serde_json::json!({ "id": ext_id, "status": "error" }),
-            );
+            let _ = events::emit(&ExtensionSyncStatus::new(&ext_id, "error"));
           } else {
-            let _ = events::emit(
-              "extension-sync-status",
-              serde_json::json!({ "id": ext_id, "status": "synced" }),
-            );
+            let _ = events::emit(&ExtensionSyncStatus::new(&ext_id, "synced"));
           }
         }
       }
       Err(e) => {
         log::error!("Failed to create sync engine: {}", e);
+        self.hold_if_offline("extension", &extensions_to_sync).await;
       }
     }
   }
 
-  async fn process_pending_extension_groups(&self, app_handle: &tauri::AppHandle) {
+  async fn process_pending_extension_groups(&self, app_handle: &crate::app_handle::AppHandle) {
     let groups_to_sync: Vec<String> = {
       let mut pending = self.pending_extension_groups.lock().await;
       let list: Vec<String> = pending.drain().collect();
@@ -700,34 +1236,40 @@ impl SyncScheduler {
       Ok(engine) => {
         for group_id in groups_to_sync {
           log::info!("Syncing extension group {}", group_id);
-          let _ = events::emit(
-            "extension-sync-status",
-            serde_json::json!({ "id": group_id, "status": "syncing" }),
-          );
-          if let Err(e) = engine
+          let _ = events::emit(&ExtensionSyncStatus::new(&group_id, "syncing"));
+          let result = engine
             .sync_extension_group_by_id_with_handle(&group_id, app_handle)
-            .await
+            .await;
+          if settle_sync(
+            &self.offline_queue,
+            "extension_group",
+            &group_id,
+            QueuedSyncOperation::Sync,
+            &result,
+          )
+          .await
           {
+            let _ = events::emit(&ExtensionSyncStatus::new(&group_id, "waiting"));
+            continue;
+          }
+          if let Err(e) = result {
             log::error!("Failed to sync extension group {}: {}", group_id, e);
-            let _ = events::emit(
-              "extension-sync-status",
-              serde_json::json!({ "id": group_id, "status": "error" }),
-            );
+            let _ = events::emit(&ExtensionSyncStatus::new(&group_id, "error"));
           } else {
-            let _ = events::emit(
-              "extension-sync-status",
-              serde_json::json!({ "id": group_id, "status": "synced" }),
-            );
+            let _ = events::emit(&ExtensionSyncStatus::new(&group_id, "synced"));
           }
         }
       }
       Err(e) => {
         log::error!("Failed to create sync engine: {}", e);
+        self
+          .hold_if_offline("extension_group", &groups_to_sync)
+          .await;
       }
     }
   }
 
-  async fn process_pending_tombstones(&self, _app_handle: &tauri::AppHandle) {
+  async fn process_pending_tombstones(&self, _app_handle: &crate::app_handle::AppHandle) {
     let tombstones: Vec<(String, String)> = {
       let mut pending = self.pending_tombstones.lock().await;
       std::mem::take(&mut *pending)
@@ -774,12 +1316,23 @@ impl SyncScheduler {
           if let Some(proxy) = proxies.iter().find(|p| p.id == entity_id) {
             if proxy.sync_enabled {
               log::info!("Proxy {} was deleted remotely, deleting locally", entity_id);
-              let proxy_file = proxy_manager.get_proxy_file_path(&entity_id);
-              if proxy_file.exists() {
-                let _ = std::fs::remove_file(&proxy_file);
-              }
+              let _ = crate::json_file::remove(&proxy_manager.get_proxy_file_path(&entity_id));
               proxy_manager.remove_from_memory(&entity_id);
-              let _ = events::emit("stored-proxies-changed", ());
+              let _ = events::emit_empty("stored-proxies-changed");
+            }
+          }
+        }
+        "proxy_pool" => {
+          let proxy_manager = &crate::proxy_manager::PROXY_MANAGER;
+          if let Some(pool) = proxy_manager.get_proxy_pool(&entity_id) {
+            if pool.sync_enabled {
+              log::info!(
+                "Proxy pool {} was deleted remotely, deleting locally",
+                entity_id
+              );
+              let _ = crate::json_file::remove(&proxy_manager.get_pool_file_path(&entity_id));
+              proxy_manager.remove_proxy_pool_from_memory(&entity_id);
+              let _ = events::emit_empty("proxy-pools-changed");
             }
           }
         }
@@ -790,7 +1343,7 @@ impl SyncScheduler {
             if group.sync_enabled {
               log::info!("Group {} was deleted remotely, deleting locally", entity_id);
               let _ = group_manager.delete_group_internal(&entity_id);
-              let _ = events::emit("groups-changed", ());
+              let _ = events::emit_empty("groups-changed");
             }
           }
         }
@@ -800,7 +1353,7 @@ impl SyncScheduler {
             if vpn.sync_enabled {
               log::info!("VPN {} was deleted remotely, deleting locally", entity_id);
               let _ = storage.delete_config(&entity_id);
-              let _ = events::emit("vpn-configs-changed", ());
+              let _ = events::emit_empty("vpn-configs-changed");
             }
           }
         }
@@ -813,7 +1366,7 @@ impl SyncScheduler {
                 entity_id
               );
               let _ = manager.delete_extension_internal(&entity_id);
-              let _ = events::emit("extensions-changed", ());
+              let _ = events::emit_empty("extensions-changed");
             }
           }
         }
@@ -826,7 +1379,7 @@ impl SyncScheduler {
                 entity_id
               );
               let _ = manager.delete_group_internal(&entity_id);
-              let _ = events::emit("extensions-changed", ());
+              let _ = events::emit_empty("extensions-changed");
             }
           }
         }
@@ -835,3 +1388,146 @@ impl SyncScheduler {
     }
   }
 }
+
+/// Changes held back while the sync server is unreachable, so the UI can show
+/// how many are waiting.
+#[tauri::command]
+pub async fn get_sync_queue_status() -> Result<SyncQueueStatus, String> {
+  if let Some(scheduler) = get_global_scheduler() {
+    return Ok(scheduler.queue_status().await);
+  }
+  let queue = OfflineQueue::load(crate::app_dirs::settings_dir().join(OFFLINE_QUEUE_FILE));
+  Ok(SyncQueueStatus {
+    pending: queue.items,
+    server_reachable: is_server_reachable(),
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_running_profile_defers_files_until_metadata_bound() {
+    let now = Instant::now();
+    let mut entry = PendingProfileSync::pending_close(now);
+    assert_eq!(entry.next_action(true, now), ProfileSyncAction::Wait);
+
+    let later = now + RUNNING_METADATA_SYNC_AFTER;
+    assert_eq!(
+      entry.next_action(true, later),
+      ProfileSyncAction::MetadataOnly
+    );
+    entry.metadata_synced = true;
+    assert_eq!(entry.next_action(true, later), ProfileSyncAction::Wait);
+  }
+
+  #[test]
+  fn test_sync_window_bounds() {
+    let at = |t: &str| chrono::NaiveTime::parse_from_str(t, "%H:%M").unwrap();
+    let window = |start: &str, end: &str| SyncWindow {
+      start: start.to_string(),
+      end: end.to_string(),
+    };
+    assert!(file_sync_allowed(None, at("12:00")));
+
+    let night = window("01:00", "07:00");
+    assert!(file_sync_allowed(Some(&night), at("01:00")));
+    assert!(file_sync_allowed(Some(&night), at("06:59")));
+    assert!(!file_sync_allowed(Some(&night), at("07:00")));
+    assert!(!file_sync_allowed(Some(&night), at("12:00")));
+
+    let overnight = window("22:00", "06:00");
+    assert!(file_sync_allowed(Some(&overnight), at("23:30")));
+    assert!(file_sync_allowed(Some(&overnight), at("05:00")));
+    assert!(!file_sync_allowed(Some(&overnight), at("12:00")));
+
+    assert!(file_sync_allowed(
+      Some(&window("nope", "07:00")),
+      at("12:00")
+    ));
+  }
+
+  #[test]
+  fn test_closed_profile_waits_for_quiescence() {
+    let now = Instant::now();
+    let mut entry = PendingProfileSync::pending_close(now);
+    entry.stopped_at = Some(now);
+    assert_eq!(entry.next_action(false, now), ProfileSyncAction::Wait);
+    assert_eq!(
+      entry.next_action(false, now + CLOSE_QUIESCENCE),
+      ProfileSyncAction::Full
+    );
+  }
+
+  #[test]
+  fn test_unreported_close_starts_quiescence() {
+    let now = Instant::now();
+    let mut entry = PendingProfileSync::pending_close(now);
+    assert_eq!(entry.next_action(false, now), ProfileSyncAction::Wait);
+    assert_eq!(entry.stopped_at, Some(now));
+
+    let idle = PendingProfileSync::ready_now(now);
+    assert_eq!(
+      idle.clone().next_action(false, now),
+      ProfileSyncAction::Full
+    );
+  }
+
+  fn queue() -> OfflineQueue {
+    OfflineQueue {
+      path: None,
+      items: Vec::new(),
+      failed_rounds: 0,
+      next_retry_at: None,
+    }
+  }
+
+  #[test]
+  fn test_offline_queue_dedupes_and_upgrades() {
+    let now = Instant::now();
+    let mut q = queue();
+    assert!(q.defer("profile", "a", QueuedSyncOperation::SyncMetadata, now));
+    assert!(!q.defer("profile", "a", QueuedSyncOperation::SyncMetadata, now));
+    assert!(q.defer("profile", "a", QueuedSyncOperation::Sync, now));
+    assert!(!q.defer("profile", "a", QueuedSyncOperation::SyncMetadata, now));
+    assert!(q.defer("proxy", "a", QueuedSyncOperation::Sync, now));
+    assert_eq!(q.items.len(), 2);
+    assert_eq!(q.items[0].operation, QueuedSyncOperation::Sync);
+
+    assert!(q.resolve("profile", "a"));
+    assert!(!q.resolve("profile", "a"));
+    assert_eq!(q.items.len(), 1);
+  }
+
+  #[test]
+  fn test_offline_queue_backs_off_until_reconnect() {
+    let now = Instant::now();
+    let mut q = queue();
+    q.defer("vpn", "v", QueuedSyncOperation::Sync, now);
+    assert!(q.take_due(now, false).is_empty());
+
+    let first = now + OFFLINE_RETRY_MIN;
+    assert_eq!(q.take_due(first, false).len(), 1);
+    // The next round waits twice as long.
+    assert!(q.take_due(first + OFFLINE_RETRY_MIN, false).is_empty());
+    assert_eq!(q.take_due(first + OFFLINE_RETRY_MIN * 2, false).len(), 1);
+    // Coming back online skips the backoff.
+    assert_eq!(q.take_due(first + OFFLINE_RETRY_MIN * 2, true).len(), 1);
+
+    assert_eq!(OfflineQueue::backoff(30), OFFLINE_RETRY_MAX);
+  }
+
+  #[test]
+  fn test_offline_queue_persists_across_restarts() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join(OFFLINE_QUEUE_FILE);
+    let mut q = OfflineQueue::load(path.clone());
+    q.defer("group", "g", QueuedSyncOperation::Sync, Instant::now());
+
+    let reloaded = OfflineQueue::load(path);
+    assert_eq!(reloaded.items.len(), 1);
+    assert_eq!(reloaded.items[0].entity_id, "g");
+    assert!(reloaded.next_retry_at.is_some());
+  }
+}
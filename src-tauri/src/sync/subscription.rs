@@ -1,13 +1,104 @@
 use crate::events;
 use crate::settings_manager::SettingsManager;
 use reqwest::Client;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::Arc;
-use std::time::Duration;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use tokio::sync::mpsc;
 use tokio::time::sleep;
 
+/// Reconnect backoff, doubling per failed attempt up to the cap, with jitter
+/// so clients that lost the server together don't all return at once.
+const RECONNECT_MIN: Duration = Duration::from_secs(2);
+const RECONNECT_MAX: Duration = Duration::from_secs(120);
+/// A connection that stays up this long counts as stable and resets the
+/// backoff.
+const STABLE_AFTER: Duration = Duration::from_secs(60);
+/// Failed attempts in a row after which the subscription reports degraded.
+const DEGRADED_AFTER_FAILURES: u32 = 3;
+/// A reconnect after a gap this long reconciles missing profiles and
+/// entities, since change events from the gap may never be replayed.
+const RECONCILE_AFTER_GAP: Duration = Duration::from_secs(5 * 60);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SubscriptionState {
+  /// Sync is not configured or the subscription was stopped.
+  Disconnected,
+  Connected,
+  Reconnecting,
+  /// Several reconnects in a row have failed; changes from other devices are
+  /// not arriving.
+  Degraded,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SyncSubscriptionStatus {
+  pub state: SubscriptionState,
+  /// Epoch seconds of the last event from the server, pings included.
+  pub last_event_at: Option<u64>,
+  /// Epoch seconds the current connection was opened.
+  pub connected_since: Option<u64>,
+  /// Connection attempts that failed in a row.
+  pub failed_attempts: u32,
+}
+
+static STATUS: Mutex<SyncSubscriptionStatus> = Mutex::new(SyncSubscriptionStatus {
+  state: SubscriptionState::Disconnected,
+  last_event_at: None,
+  connected_since: None,
+  failed_attempts: 0,
+});
+
+/// Apply `change` and publish `sync-subscription-status` when the state or
+/// connection changed. Event timestamps alone are not broadcast; the UI reads
+/// them on demand.
+fn update_status(change: impl FnOnce(&mut SyncSubscriptionStatus)) {
+  let (status, notify) = {
+    let mut status = STATUS.lock().unwrap();
+    let before = (status.state, status.connected_since, status.failed_attempts);
+    change(&mut status);
+    let notify = before != (status.state, status.connected_since, status.failed_attempts);
+    (status.clone(), notify)
+  };
+  if notify {
+    if let Err(e) = events::emit(&status) {
+      log::warn!("Failed to emit sync-subscription-status event: {e}");
+    }
+  }
+}
+
+/// Wait before the next reconnect, `attempt` reconnects into a streak (0
+/// after a stable connection). `jitter` in `[0, 1)` picks a point in the
+/// upper half of the window.
+fn reconnect_delay(attempt: u32, jitter: f64) -> Duration {
+  let exp = attempt.saturating_sub(1).min(16);
+  let window = RECONNECT_MIN.saturating_mul(1 << exp).min(RECONNECT_MAX);
+  window / 2 + window.mul_f64(jitter.clamp(0.0, 1.0) / 2.0)
+}
+
+/// Fetch whatever other devices changed while the stream was down.
+pub async fn reconcile(app_handle: crate::app_handle::AppHandle) {
+  match super::SyncEngine::create_from_settings(&app_handle).await {
+    Ok(engine) => {
+      if let Err(e) = engine.check_for_missing_synced_profiles(&app_handle).await {
+        log::warn!("Failed to check for missing profiles: {e}");
+      }
+      if let Err(e) = engine.check_for_missing_synced_entities(&app_handle).await {
+        log::warn!("Failed to check for missing entities: {e}");
+      }
+    }
+    Err(e) => log::warn!("Sync not configured, skipping reconciliation: {e}"),
+  }
+}
+
+/// State of the live change stream from the sync server.
+#[tauri::command]
+pub fn get_sync_subscription_status() -> SyncSubscriptionStatus {
+  STATUS.lock().unwrap().clone()
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct SubscribeEvent {
   #[serde(rename = "type")]
@@ -22,6 +113,7 @@ pub struct SubscribeEvent {
 pub enum SyncWorkItem {
   Profile(String),
   Proxy(String),
+  ProxyPool(String),
   Group(String),
   Vpn(String),
   Extension(String),
@@ -64,7 +156,7 @@ impl SyncSubscription {
   }
 
   pub async fn create_from_settings(
-    app_handle: &tauri::AppHandle,
+    app_handle: &crate::app_handle::AppHandle,
     work_tx: mpsc::UnboundedSender<SyncWorkItem>,
   ) -> Result<Option<Self>, String> {
     // Cloud auth takes priority
@@ -115,7 +207,7 @@ impl SyncSubscription {
     self.running.store(false, Ordering::SeqCst);
   }
 
-  pub async fn start(&self, app_handle: tauri::AppHandle) {
+  pub async fn start(&self, app_handle: crate::app_handle::AppHandle) {
     if self.running.swap(true, Ordering::SeqCst) {
       return;
     }
@@ -128,21 +220,95 @@ impl SyncSubscription {
     let mut token = self.token.clone();
 
     tokio::spawn(async move {
+      let mut failed_attempts: u32 = 0;
+      let mut backoff_attempt: u32 = 0;
+      let mut last_event_id: Option<String> = None;
+      // When the stream was lost; taken by the next successful connect.
+      let mut gap_started: Option<Instant> = None;
+
       while running.load(Ordering::SeqCst) {
-        match Self::connect_and_listen(&client, &base_url, &token, &work_tx, &running, &app_handle)
-          .await
-        {
-          Ok(()) => {
-            log::info!("SSE connection closed gracefully");
+        if crate::offline::is_offline() {
+          update_status(|status| {
+            status.state = SubscriptionState::Disconnected;
+            status.connected_since = None;
+            status.failed_attempts = 0;
+          });
+          crate::offline::wait_until_online().await;
+          // Leaving offline mode reconciles on its own.
+          gap_started = None;
+          failed_attempts = 0;
+          backoff_attempt = 0;
+          continue;
+        }
+
+        match Self::connect(&client, &base_url, &token, last_event_id.as_deref()).await {
+          Ok(response) => {
+            let connected_at = Instant::now();
+            log::info!("Connected to sync subscription");
+            failed_attempts = 0;
+            update_status(|status| {
+              status.state = SubscriptionState::Connected;
+              status.connected_since = Some(crate::proxy_manager::now_secs());
+              status.failed_attempts = 0;
+            });
+
+            if gap_started
+              .take()
+              .is_some_and(|gap| gap.elapsed() >= RECONCILE_AFTER_GAP)
+            {
+              log::info!("Sync subscription resumed after a long gap, reconciling");
+              tauri::async_runtime::spawn(reconcile(app_handle.clone()));
+            }
+
+            let result = Self::listen(response, &work_tx, &running, &mut last_event_id).await;
+            gap_started = Some(Instant::now());
+            // A connection that held for a while resets the backoff; one that
+            // drops right after connecting keeps growing it.
+            backoff_attempt = if connected_at.elapsed() >= STABLE_AFTER {
+              0
+            } else {
+              backoff_attempt.saturating_add(1)
+            };
+            match result {
+              Ok(()) => log::info!("SSE connection closed"),
+              Err(e) => log::warn!("SSE connection lost: {e}"),
+            }
           }
           Err(e) => {
-            log::warn!("SSE connection error: {e}, reconnecting in 5s");
-            sleep(Duration::from_secs(5)).await;
+            gap_started.get_or_insert_with(Instant::now);
+            failed_attempts = failed_attempts.saturating_add(1);
+            backoff_attempt = backoff_attempt.saturating_add(1);
+            // Only the first failure of a streak and the switch to degraded
+            // are worth a warning; the rest would flood the log on a flaky
+            // network.
+            if failed_attempts == 1 || failed_attempts == DEGRADED_AFTER_FAILURES {
+              log::warn!("SSE connect failed ({failed_attempts} in a row): {e}");
+            } else {
+              log::debug!("SSE connect failed ({failed_attempts} in a row): {e}");
+            }
           }
         }
 
+        if !running.load(Ordering::SeqCst) {
+          break;
+        }
+        if crate::offline::is_offline() {
+          continue;
+        }
+
+        let delay = reconnect_delay(backoff_attempt, rand::random::<f64>());
+        update_status(|status| {
+          status.state = if failed_attempts >= DEGRADED_AFTER_FAILURES {
+            SubscriptionState::Degraded
+          } else {
+            SubscriptionState::Reconnecting
+          };
+          status.connected_since = None;
+          status.failed_attempts = failed_attempts;
+        });
+        sleep(delay).await;
+
         if running.load(Ordering::SeqCst) {
-          sleep(Duration::from_secs(1)).await;
           // Refresh the sync token before reconnecting. The token may have
           // expired while the stream was open (tokens last ~15 min); reusing
           // the construction-time token otherwise produces an endless 401
@@ -160,6 +326,10 @@ impl SyncSubscription {
         }
       }
 
+      update_status(|status| {
+        status.state = SubscriptionState::Disconnected;
+        status.connected_since = None;
+      });
       log::info!("Sync subscription stopped");
     });
   }
@@ -168,7 +338,7 @@ impl SyncSubscription {
   /// created from, so reconnects never reuse a stale (expired) token.
   async fn fetch_sync_token(
     source: TokenSource,
-    app_handle: &tauri::AppHandle,
+    app_handle: &crate::app_handle::AppHandle,
   ) -> Result<Option<String>, String> {
     match source {
       TokenSource::Cloud => crate::cloud_auth::CLOUD_AUTH
@@ -182,23 +352,30 @@ impl SyncSubscription {
     }
   }
 
-  async fn connect_and_listen(
+  /// Open the event stream, resuming after `last_event_id` when the server
+  /// tagged its events with ids.
+  async fn connect(
     client: &Client,
     base_url: &str,
     token: &str,
-    work_tx: &mpsc::UnboundedSender<SyncWorkItem>,
-    running: &Arc<AtomicBool>,
-    _app_handle: &tauri::AppHandle,
-  ) -> Result<(), String> {
+    last_event_id: Option<&str>,
+  ) -> Result<reqwest::Response, String> {
     let url = format!("{base_url}/v1/objects/subscribe");
 
-    let response = client
+    let mut request = client
       .get(&url)
       .header("Authorization", format!("Bearer {token}"))
-      .header("Accept", "text/event-stream")
-      .send()
-      .await
-      .map_err(|e| format!("Failed to connect to SSE: {e}"))?;
+      .header("Accept", "text/event-stream");
+    if let Some(id) = last_event_id {
+      request = request.header("Last-Event-ID", id);
+    }
+    let response = request.send().await.map_err(|e| {
+      super::scheduler::set_server_reachable(false);
+      format!("Failed to connect to SSE: {e}")
+    })?;
+
+    // Any answer, even a rejected token, means the server itself is up.
+    super::scheduler::set_server_reachable(true);
 
     if !response.status().is_success() {
       return Err(format!(
@@ -206,17 +383,26 @@ impl SyncSubscription {
         response.status()
       ));
     }
+    Ok(response)
+  }
 
-    log::info!("Connected to sync subscription");
-    let _ = events::emit("sync-subscription-status", "connected");
-
+  async fn listen(
+    response: reqwest::Response,
+    work_tx: &mpsc::UnboundedSender<SyncWorkItem>,
+    running: &Arc<AtomicBool>,
+    last_event_id: &mut Option<String>,
+  ) -> Result<(), String> {
     let mut buffer = String::new();
     let mut bytes_stream = response.bytes_stream();
 
     use futures_util::StreamExt;
 
     while running.load(Ordering::SeqCst) {
-      match tokio::time::timeout(Duration::from_secs(60), bytes_stream.next()).await {
+      let next = tokio::select! {
+        next = tokio::time::timeout(Duration::from_secs(60), bytes_stream.next()) => next,
+        _ = crate::offline::wait_until_offline() => return Ok(()),
+      };
+      match next {
         Ok(Some(Ok(bytes))) => {
           let chunk = String::from_utf8_lossy(&bytes);
           buffer.push_str(&chunk);
@@ -225,12 +411,19 @@ impl SyncSubscription {
             let event_str = buffer[..event_end].to_string();
             buffer = buffer[event_end + 2..].to_string();
 
+            if let Some(id) = Self::parse_sse_id(&event_str) {
+              *last_event_id = Some(id);
+            }
             if let Some(event) = Self::parse_sse_event(&event_str) {
+              update_status(|status| {
+                status.last_event_at = Some(crate::proxy_manager::now_secs());
+              });
               Self::handle_event(&event, work_tx);
             }
           }
         }
         Ok(Some(Err(e))) => {
+          super::scheduler::set_server_reachable(false);
           return Err(format!("SSE stream error: {e}"));
         }
         Ok(None) => {
@@ -245,6 +438,16 @@ impl SyncSubscription {
     Ok(())
   }
 
+  /// The event's `id:` field. Empty ids are ignored rather than resetting
+  /// the resume point.
+  fn parse_sse_id(event_str: &str) -> Option<String> {
+    event_str
+      .lines()
+      .filter_map(|line| line.strip_prefix("id:"))
+      .map(|id| id.trim().to_string())
+      .rfind(|id| !id.is_empty())
+  }
+
   fn parse_sse_event(event_str: &str) -> Option<SubscribeEvent> {
     let mut data_line = None;
 
@@ -297,6 +500,11 @@ impl SyncSubscription {
         .strip_prefix("proxies/")
         .and_then(|s| s.strip_suffix(".json"))
         .map(|s| SyncWorkItem::Proxy(s.to_string()))
+    } else if key.starts_with("proxy_pools/") {
+      key
+        .strip_prefix("proxy_pools/")
+        .and_then(|s| s.strip_suffix(".json"))
+        .map(|s| SyncWorkItem::ProxyPool(s.to_string()))
     } else if key.starts_with("groups/") {
       key
         .strip_prefix("groups/")
@@ -329,6 +537,11 @@ impl SyncSubscription {
             .strip_prefix("proxies/")
             .and_then(|s| s.strip_suffix(".json"))
             .map(|id| SyncWorkItem::Tombstone("proxy".to_string(), id.to_string()))
+        } else if rest.starts_with("proxy_pools/") {
+          rest
+            .strip_prefix("proxy_pools/")
+            .and_then(|s| s.strip_suffix(".json"))
+            .map(|id| SyncWorkItem::Tombstone("proxy_pool".to_string(), id.to_string()))
         } else if rest.starts_with("groups/") {
           rest
             .strip_prefix("groups/")
@@ -394,7 +607,7 @@ impl SubscriptionManager {
     self.work_rx.take()
   }
 
-  pub async fn start(&mut self, app_handle: tauri::AppHandle) -> Result<(), String> {
+  pub async fn start(&mut self, app_handle: crate::app_handle::AppHandle) -> Result<(), String> {
     if self.subscription.is_some() {
       return Ok(());
     }
@@ -425,3 +638,32 @@ impl SubscriptionManager {
     self.subscription.as_ref().is_some_and(|s| s.is_running())
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn reconnect_delay_grows_with_jitter_and_caps() {
+    assert_eq!(reconnect_delay(0, 0.0), Duration::from_secs(1));
+    assert_eq!(reconnect_delay(1, 0.0), Duration::from_secs(1));
+    assert_eq!(reconnect_delay(3, 0.0), Duration::from_secs(4));
+    assert_eq!(reconnect_delay(3, 0.5), Duration::from_secs(6));
+    assert!(reconnect_delay(3, 0.999) < Duration::from_secs(8));
+    assert_eq!(reconnect_delay(30, 0.0), RECONNECT_MAX / 2);
+    assert!(reconnect_delay(u32::MAX, 0.999) <= RECONNECT_MAX);
+  }
+
+  #[test]
+  fn parses_last_event_id() {
+    assert_eq!(
+      SyncSubscription::parse_sse_id("id: 42\ndata: {\"type\":\"ping\"}"),
+      Some("42".to_string())
+    );
+    assert_eq!(
+      SyncSubscription::parse_sse_id("id:\ndata: {\"type\":\"ping\"}"),
+      None
+    );
+    assert_eq!(SyncSubscription::parse_sse_id("data: {}"), None);
+  }
+}
@@ -175,6 +175,9 @@ pub struct DeletePrefixResponse {
 pub enum SyncError {
   NotConfigured,
   NetworkError(String),
+  /// The server couldn't be reached at all (refused, DNS, timeout), as
+  /// opposed to answering with an error.
+  ConnectionError(String),
   AuthError(String),
   IoError(String),
   SerializationError(String),
@@ -188,6 +191,7 @@ impl std::fmt::Display for SyncError {
     match self {
       SyncError::NotConfigured => write!(f, "Sync not configured"),
       SyncError::NetworkError(msg) => write!(f, "Network error: {msg}"),
+      SyncError::ConnectionError(msg) => write!(f, "Connection error: {msg}"),
       SyncError::AuthError(msg) => write!(f, "Authentication error: {msg}"),
       SyncError::IoError(msg) => write!(f, "IO error: {msg}"),
       SyncError::SerializationError(msg) => write!(f, "Serialization error: {msg}"),
@@ -200,4 +204,21 @@ impl std::fmt::Display for SyncError {
 
 impl std::error::Error for SyncError {}
 
+impl SyncError {
+  /// Classifies a failed request: transport-level failures become
+  /// `ConnectionError` so the scheduler can hold the work until the server is
+  /// back.
+  pub fn from_send(e: reqwest::Error) -> Self {
+    if e.is_connect() || e.is_timeout() {
+      SyncError::ConnectionError(e.to_string())
+    } else {
+      SyncError::NetworkError(e.to_string())
+    }
+  }
+
+  pub fn is_connectivity(&self) -> bool {
+    matches!(self, SyncError::ConnectionError(_))
+  }
+}
+
 pub type SyncResult<T> = Result<T, SyncError>;
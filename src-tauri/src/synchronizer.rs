@@ -2,7 +2,6 @@ use std::collections::HashMap;
 use std::sync::Arc;
 
 use serde::{Deserialize, Serialize};
-use tauri::Emitter;
 use tokio::sync::Mutex as AsyncMutex;
 
 use crate::profile::manager::ProfileManager;
@@ -95,7 +94,7 @@ impl SynchronizerManager {
   /// Start a new sync session. Launches all profiles and begins event capture.
   pub async fn start_session(
     &self,
-    app_handle: tauri::AppHandle,
+    app_handle: crate::app_handle::AppHandle,
     leader_profile_id: String,
     follower_profile_ids: Vec<String>,
   ) -> Result<SyncSessionInfo, String> {
@@ -115,15 +114,13 @@ impl SynchronizerManager {
     }
 
     // Check leader is not already running
-    if leader.process_id.is_some() {
-      let sys = sysinfo::System::new_all();
-      if let Some(pid) = leader.process_id {
-        if sys.process(sysinfo::Pid::from(pid as usize)).is_some() {
-          return Err(
-            "Leader profile is already running. Stop it first to start a sync session.".to_string(),
-          );
-        }
-      }
+    if leader
+      .process_id
+      .is_some_and(crate::process_registry::process_exists)
+    {
+      return Err(
+        "Leader profile is already running. Stop it first to start a sync session.".to_string(),
+      );
     }
 
     let mut follower_profiles: Vec<BrowserProfile> = Vec::new();
@@ -247,7 +244,7 @@ impl SynchronizerManager {
     }
 
     // Emit initial session event
-    let _ = app_handle.emit("sync-session-changed", &info);
+    let _ = crate::events::emit(&info);
 
     // Spawn the CDP listener task with a readiness signal
     let manager = self.inner.clone();
@@ -287,7 +284,7 @@ impl SynchronizerManager {
       // Session ended — clean up
       let mut inner = manager.lock().await;
       inner.sessions.remove(&sid);
-      let _ = ah.emit("sync-session-ended", &sid);
+      let _ = crate::events::emit(&crate::events::payloads::SyncSessionEnded(sid));
     });
 
     // Wait for the CDP session to be ready (or fail)
@@ -336,7 +333,7 @@ impl SynchronizerManager {
 
   /// Core session loop: inject capture script on leader, listen for events, replay on followers.
   async fn run_session_loop(
-    app_handle: tauri::AppHandle,
+    app_handle: crate::app_handle::AppHandle,
     manager: Arc<AsyncMutex<SynchronizerInner>>,
     session_id: String,
     leader_profile_id: String,
@@ -641,7 +638,7 @@ impl SynchronizerManager {
   /// Handle a single CDP event from the leader
   async fn handle_cdp_event(
     value: &serde_json::Value,
-    _app_handle: &tauri::AppHandle,
+    _app_handle: &crate::app_handle::AppHandle,
     _manager: &Arc<AsyncMutex<SynchronizerInner>>,
     _session_id: &str,
     follower_senders: &HashMap<String, tokio::sync::mpsc::UnboundedSender<CapturedEvent>>,
@@ -712,7 +709,7 @@ impl SynchronizerManager {
     follower_id: String,
     manager: Arc<AsyncMutex<SynchronizerInner>>,
     session_id: String,
-    app_handle: tauri::AppHandle,
+    app_handle: crate::app_handle::AppHandle,
   ) {
     use futures_util::sink::SinkExt;
     use tokio_tungstenite::tungstenite::Message;
@@ -813,7 +810,7 @@ impl SynchronizerManager {
                 leader_profile_name: session.leader_profile_name.clone(),
                 followers: session.followers.values().cloned().collect(),
               };
-              let _ = app_handle.emit("sync-session-changed", &info);
+              let _ = crate::events::emit(&info);
             }
           }
           break;
@@ -827,7 +824,7 @@ impl SynchronizerManager {
   /// Stop a sync session by ID. Kills all followers.
   pub async fn stop_session(
     &self,
-    app_handle: tauri::AppHandle,
+    app_handle: crate::app_handle::AppHandle,
     session_id: &str,
   ) -> Result<(), String> {
     let mut inner = self.inner.lock().await;
@@ -851,14 +848,16 @@ impl SynchronizerManager {
       let _ = crate::browser_runner::kill_browser_profile(app_handle.clone(), leader).await;
     }
 
-    let _ = app_handle.emit("sync-session-ended", session_id);
+    let _ = crate::events::emit(&crate::events::payloads::SyncSessionEnded(
+      session_id.to_string(),
+    ));
     Ok(())
   }
 
   /// Remove a single follower from an active session (user clicked stop on follower).
   pub async fn remove_follower(
     &self,
-    app_handle: tauri::AppHandle,
+    app_handle: crate::app_handle::AppHandle,
     session_id: &str,
     follower_profile_id: &str,
   ) -> Result<(), String> {
@@ -882,7 +881,7 @@ impl SynchronizerManager {
       leader_profile_name: session.leader_profile_name.clone(),
       followers: session.followers.values().cloned().collect(),
     };
-    let _ = app_handle.emit("sync-session-changed", &info);
+    let _ = crate::events::emit(&info);
 
     Ok(())
   }
@@ -970,7 +969,7 @@ impl SynchronizerManager {
 
 #[tauri::command]
 pub async fn start_sync_session(
-  app_handle: tauri::AppHandle,
+  app_handle: crate::app_handle::AppHandle,
   leader_profile_id: String,
   follower_profile_ids: Vec<String>,
 ) -> Result<SyncSessionInfo, String> {
@@ -981,7 +980,7 @@ pub async fn start_sync_session(
 
 #[tauri::command]
 pub async fn stop_sync_session(
-  app_handle: tauri::AppHandle,
+  app_handle: crate::app_handle::AppHandle,
   session_id: String,
 ) -> Result<(), String> {
   SynchronizerManager::instance()
@@ -991,7 +990,7 @@ pub async fn stop_sync_session(
 
 #[tauri::command]
 pub async fn remove_sync_follower(
-  app_handle: tauri::AppHandle,
+  app_handle: crate::app_handle::AppHandle,
   session_id: String,
   follower_profile_id: String,
 ) -> Result<(), String> {
@@ -139,10 +139,10 @@ impl ProfileLockManager {
       );
     }
 
-    let _ = crate::events::emit(
-      "profile-lock-changed",
-      serde_json::json!({ "profileId": profile_id, "action": "acquired" }),
-    );
+    let _ = crate::events::emit(&crate::events::payloads::ProfileLockChanged {
+      profile_id: profile_id.to_string(),
+      action: "acquired",
+    });
 
     Ok(())
   }
@@ -164,10 +164,10 @@ impl ProfileLockManager {
       locks.remove(profile_id);
     }
 
-    let _ = crate::events::emit(
-      "profile-lock-changed",
-      serde_json::json!({ "profileId": profile_id, "action": "released" }),
-    );
+    let _ = crate::events::emit(&crate::events::payloads::ProfileLockChanged {
+      profile_id: profile_id.to_string(),
+      action: "released",
+    });
 
     Ok(())
   }
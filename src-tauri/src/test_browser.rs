@@ -0,0 +1,160 @@
+//! A stand-in browser for integration tests (`--features test-browser`).
+//! [`install`] writes a tiny script that accepts Wayfern's launch arguments,
+//! records them in `launch-args.txt` next to itself and stays alive until it
+//! is killed. Stub profiles go through the real launch/kill/status paths of
+//! `browser_runner` (local proxy worker, PID bookkeeping, events) without a
+//! browser download.
+
+use crate::app_handle::AppHandle;
+use crate::browser::{Browser, ProxySettings, WayfernBrowser};
+use crate::browser_runner::BrowserRunner;
+use crate::profile::{BrowserProfile, ProfileManager};
+use std::path::{Path, PathBuf};
+
+/// `BrowserProfile::browser` of stub profiles.
+pub const STUB_BROWSER: &str = "stub";
+
+#[cfg(unix)]
+const SCRIPT_NAME: &str = "stub-browser";
+#[cfg(windows)]
+const SCRIPT_NAME: &str = "stub-browser.cmd";
+
+#[cfg(unix)]
+const SCRIPT: &str = r#"#!/bin/sh
+printf '%s\n' "$@" > "$(dirname "$0")/launch-args.txt"
+trap 'exit 0' TERM INT
+while :; do sleep 1; done
+"#;
+#[cfg(windows)]
+const SCRIPT: &str = "@echo off\r\necho %* > \"%~dp0launch-args.txt\"\r\n:wait\r\nping -n 2 127.0.0.1 > nul\r\ngoto wait\r\n";
+
+const ARGS_FILE: &str = "launch-args.txt";
+
+pub struct StubBrowser;
+
+impl Browser for StubBrowser {
+  fn get_executable_path(&self, install_dir: &Path) -> Result<PathBuf, Box<dyn std::error::Error>> {
+    let path = install_dir.join(SCRIPT_NAME);
+    if path.is_file() {
+      Ok(path)
+    } else {
+      Err(format!("Stub browser not installed in {}", install_dir.display()).into())
+    }
+  }
+
+  fn create_launch_args(
+    &self,
+    profile_path: &str,
+    proxy_settings: Option<&ProxySettings>,
+    url: Option<String>,
+    remote_debugging_port: Option<u16>,
+    headless: bool,
+  ) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+    WayfernBrowser::new().create_launch_args(
+      profile_path,
+      proxy_settings,
+      url,
+      remote_debugging_port,
+      headless,
+    )
+  }
+
+  fn is_version_downloaded(&self, version: &str, binaries_dir: &Path) -> bool {
+    binaries_dir
+      .join(STUB_BROWSER)
+      .join(version)
+      .join(SCRIPT_NAME)
+      .is_file()
+  }
+
+  fn prepare_executable(&self, executable_path: &Path) -> Result<(), Box<dyn std::error::Error>> {
+    #[cfg(unix)]
+    {
+      use std::os::unix::fs::PermissionsExt;
+      std::fs::set_permissions(executable_path, std::fs::Permissions::from_mode(0o755))?;
+    }
+    #[cfg(not(unix))]
+    let _ = executable_path;
+    Ok(())
+  }
+}
+
+fn install_dir(version: &str) -> PathBuf {
+  crate::app_dirs::binaries_dir()
+    .join(STUB_BROWSER)
+    .join(version)
+}
+
+/// Write the stub as `binaries/stub/<version>/` and return its path.
+pub fn install(version: &str) -> Result<PathBuf, String> {
+  let dir = install_dir(version);
+  std::fs::create_dir_all(&dir).map_err(|e| format!("Failed to create {}: {e}", dir.display()))?;
+  let path = dir.join(SCRIPT_NAME);
+  std::fs::write(&path, SCRIPT).map_err(|e| format!("Failed to write stub browser: {e}"))?;
+  StubBrowser
+    .prepare_executable(&path)
+    .map_err(|e| format!("Failed to make stub browser executable: {e}"))?;
+  Ok(path)
+}
+
+/// Arguments the stub of `version` was last started with, as it wrote them.
+pub fn last_launch_args(version: &str) -> Option<String> {
+  std::fs::read_to_string(install_dir(version).join(ARGS_FILE)).ok()
+}
+
+/// Save a stub profile, optionally routed through a stored proxy.
+pub fn create_profile(
+  name: &str,
+  version: &str,
+  proxy_id: Option<String>,
+) -> Result<BrowserProfile, String> {
+  let profile = BrowserProfile {
+    id: uuid::Uuid::new_v4(),
+    name: name.to_string(),
+    browser: STUB_BROWSER.to_string(),
+    version: version.to_string(),
+    proxy_id,
+    ..Default::default()
+  };
+  ProfileManager::instance()
+    .save_profile(&profile)
+    .map_err(|e| format!("Failed to save stub profile: {e}"))?;
+  Ok(profile)
+}
+
+pub async fn launch(profile: &BrowserProfile) -> Result<BrowserProfile, String> {
+  BrowserRunner::instance()
+    .launch_browser(AppHandle::Headless, profile, None, None, false)
+    .await
+    .map_err(|e| e.to_string())
+}
+
+pub async fn is_running(profile: &BrowserProfile) -> Result<bool, String> {
+  BrowserRunner::instance()
+    .check_browser_status(AppHandle::Headless, profile)
+    .await
+    .map_err(|e| e.to_string())
+}
+
+pub async fn kill(profile: &BrowserProfile) -> Result<(), String> {
+  BrowserRunner::instance()
+    .kill_browser_process(AppHandle::Headless, profile)
+    .await
+    .map_err(|e| e.to_string())
+}
+
+/// The stored profile, with the process info the runner saved.
+pub fn load_profile(profile_id: uuid::Uuid) -> Option<BrowserProfile> {
+  ProfileManager::instance()
+    .list_profiles()
+    .ok()?
+    .into_iter()
+    .find(|p| p.id == profile_id)
+}
+
+/// ID of the local proxy worker mapped to the browser process `pid`.
+pub fn proxy_for_browser(pid: u32) -> Option<String> {
+  crate::proxy_manager::PROXY_MANAGER
+    .get_active_proxy(pid)
+    .map(|proxy| proxy.id)
+}
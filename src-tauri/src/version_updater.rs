@@ -49,7 +49,7 @@ impl Default for BackgroundUpdateState {
 pub struct VersionUpdater {
   browser_version_manager: &'static BrowserVersionManager,
   auto_updater: &'static AutoUpdater,
-  app_handle: Option<tauri::AppHandle>,
+  app_handle: Option<crate::app_handle::AppHandle>,
 }
 
 impl VersionUpdater {
@@ -61,7 +61,7 @@ impl VersionUpdater {
     }
   }
 
-  pub fn set_app_handle(&mut self, app_handle: tauri::AppHandle) {
+  pub fn set_app_handle(&mut self, app_handle: crate::app_handle::AppHandle) {
     self.app_handle = Some(app_handle);
   }
 
@@ -143,6 +143,11 @@ impl VersionUpdater {
   pub async fn check_and_run_startup_update(
     &self,
   ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    if crate::offline::is_offline() {
+      log::info!("Offline mode, skipping startup version update");
+      return Ok(());
+    }
+
     // Always check for updates on launch
     if let Some(ref app_handle) = self.app_handle {
       log::info!("Running startup version update...");
@@ -196,7 +201,7 @@ impl VersionUpdater {
       update_interval.tick().await;
 
       // Check if we should run an update based on persistent state
-      if !Self::should_run_background_update() {
+      if crate::offline::is_offline() || !Self::should_run_background_update() {
         continue;
       }
 
@@ -241,7 +246,7 @@ impl VersionUpdater {
               browser_new_versions: 0,
               status: "error".to_string(),
             };
-            let _ = events::emit("version-update-progress", &progress);
+            let _ = events::emit(&progress);
           }
         }
       }
@@ -250,7 +255,7 @@ impl VersionUpdater {
 
   async fn update_all_browser_versions(
     &self,
-    app_handle: &tauri::AppHandle,
+    app_handle: &crate::app_handle::AppHandle,
   ) -> Result<Vec<BackgroundUpdateResult>, Box<dyn std::error::Error + Send + Sync>> {
     let supported_browsers = self.browser_version_manager.get_supported_browsers();
 
@@ -284,7 +289,7 @@ impl VersionUpdater {
       status: "updating".to_string(),
     };
 
-    if let Err(e) = events::emit("version-update-progress", &initial_progress) {
+    if let Err(e) = events::emit(&initial_progress) {
       log::error!("Failed to emit initial progress: {e}");
     }
 
@@ -301,7 +306,7 @@ impl VersionUpdater {
         status: "updating".to_string(),
       };
 
-      if let Err(e) = events::emit("version-update-progress", &progress) {
+      if let Err(e) = events::emit(&progress) {
         log::error!("Failed to emit progress for {browser}: {e}");
       }
 
@@ -327,7 +332,7 @@ impl VersionUpdater {
             status: "updating".to_string(),
           };
 
-          if let Err(e) = events::emit("version-update-progress", &progress) {
+          if let Err(e) = events::emit(&progress) {
             log::error!("Failed to emit progress with versions for {browser}: {e}");
           }
         }
@@ -353,7 +358,7 @@ impl VersionUpdater {
       status: "completed".to_string(),
     };
 
-    if let Err(e) = events::emit("version-update-progress", &final_progress) {
+    if let Err(e) = events::emit(&final_progress) {
       eprintln!("Failed to emit completion progress: {e}");
     }
 
@@ -382,7 +387,7 @@ impl VersionUpdater {
 
   pub async fn trigger_manual_update(
     &self,
-    app_handle: &tauri::AppHandle,
+    app_handle: &crate::app_handle::AppHandle,
   ) -> Result<Vec<BackgroundUpdateResult>, Box<dyn std::error::Error + Send + Sync>> {
     let results = self.update_all_browser_versions(app_handle).await?;
 
@@ -434,7 +439,7 @@ pub fn get_version_updater() -> Arc<Mutex<VersionUpdater>> {
 
 #[tauri::command]
 pub async fn trigger_manual_version_update(
-  app_handle: tauri::AppHandle,
+  app_handle: crate::app_handle::AppHandle,
 ) -> Result<Vec<BackgroundUpdateResult>, String> {
   let updater = get_version_updater();
   let updater_guard = updater.lock().await;
@@ -458,7 +463,7 @@ pub async fn get_version_update_status() -> Result<(Option<u64>, u64), String> {
 
 #[tauri::command]
 pub async fn clear_all_version_cache_and_refetch(
-  app_handle: tauri::AppHandle,
+  app_handle: crate::app_handle::AppHandle,
 ) -> Result<(), String> {
   let api_client = crate::api_client::ApiClient::instance();
   let version_updater = VersionUpdater::new();
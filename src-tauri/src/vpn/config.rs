@@ -56,6 +56,16 @@ pub struct VpnConfig {
   /// conflict resolution (last-write-wins); bumped on config edits only.
   #[serde(default)]
   pub updated_at: Option<u64>,
+  /// Stop the tunnel once the last running profile assigned to it exits.
+  #[serde(default)]
+  pub auto_disconnect: bool,
+  /// ISO country code the tunnel's exit is expected in; checked before each
+  /// launch.
+  #[serde(default)]
+  pub expected_country: Option<String>,
+  /// CIDR block or leading part of the address the exit IP is expected in.
+  #[serde(default)]
+  pub expected_ip_prefix: Option<String>,
 }
 
 /// Parsed WireGuard configuration
@@ -83,13 +93,17 @@ pub struct VpnImportResult {
 }
 
 /// VPN connection status
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
 pub struct VpnStatus {
   pub connected: bool,
   pub vpn_id: String,
+  /// Unix seconds the tunnel came up.
   pub connected_at: Option<i64>,
+  /// Payload bytes through the tunnel since it came up; `None` while
+  /// disconnected.
   pub bytes_sent: Option<u64>,
   pub bytes_received: Option<u64>,
+  /// Unix seconds of the last completed WireGuard handshake.
   pub last_handshake: Option<i64>,
 }
 
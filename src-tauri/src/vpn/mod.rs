@@ -4,6 +4,11 @@
 //! - WireGuard config parsing (`.conf` files)
 //! - Encrypted storage for VPN configurations
 //! - Tunnel management with userspace WireGuard (boringtun) routed through smoltcp
+//!
+//! Tunnels never change host routing. Each VPN runs as a `vpn-worker` process
+//! exposing a local SOCKS5 listener, and only the local proxies of profiles
+//! assigned to that VPN use it as their upstream. Different profiles can
+//! therefore exit through different VPNs (or none) at the same time.
 
 mod config;
 pub mod socks5_server;
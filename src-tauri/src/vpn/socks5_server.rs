@@ -1,4 +1,5 @@
 use super::config::{VpnError, WireGuardConfig};
+use crate::vpn_worker_storage::{save_vpn_worker_stats, vpn_worker_stats_path_in, VpnWorkerStats};
 use boringtun::noise::{Tunn, TunnResult};
 use boringtun::x25519::{PublicKey, StaticSecret};
 use smoltcp::iface::{Config as IfaceConfig, Interface, SocketHandle, SocketSet};
@@ -17,6 +18,21 @@ const SMOLTCP_TCP_RX_BUF: usize = 65536;
 const SMOLTCP_TCP_TX_BUF: usize = 65536;
 const SMOLTCP_UDP_BUF: usize = 65536;
 
+/// How often the worker refreshes its stats file for the GUI.
+const STATS_WRITE_INTERVAL: std::time::Duration = std::time::Duration::from_secs(1);
+
+/// The tunnel's counters as published to the GUI.
+fn tunnel_stats(tunn: &Tunn, connected_at: i64, now: i64) -> VpnWorkerStats {
+  let (since_handshake, bytes_sent, bytes_received, _loss, _rtt) = tunn.stats();
+  VpnWorkerStats {
+    connected_at: Some(connected_at),
+    bytes_sent: bytes_sent as u64,
+    bytes_received: bytes_received as u64,
+    last_handshake: since_handshake.map(|elapsed| now - elapsed.as_secs() as i64),
+    updated_at: now,
+  }
+}
+
 /// Parse an RFC 1928 §7 UDP request header. Returns the destination endpoint
 /// and the payload offset, or None if malformed, fragmented, or domain-typed.
 /// Only literal IPs are routed through the tunnel: resolving a domain on the
@@ -596,6 +612,16 @@ impl WireGuardSocks5Server {
     let mut connections: Vec<Connection> = Vec::new();
     let mut timer_counter: u64 = 0;
 
+    // Written beside the worker config, which is where the GUI looks.
+    let stats_dir = config_path
+      .as_deref()
+      .and_then(std::path::Path::parent)
+      .map(std::path::Path::to_path_buf)
+      .unwrap_or_else(crate::proxy_storage::get_storage_dir);
+    let stats_path = vpn_worker_stats_path_in(&stats_dir, &config_id);
+    let connected_at = chrono::Utc::now().timestamp();
+    let mut last_stats_write: Option<std::time::Instant> = None;
+
     loop {
       // Accept new SOCKS5 connections (non-blocking via short timeout)
       if let Ok(Ok((stream, _addr))) =
@@ -1116,6 +1142,15 @@ impl WireGuardSocks5Server {
         device.tick_timers();
       }
 
+      if last_stats_write.is_none_or(|at| at.elapsed() >= STATS_WRITE_INTERVAL) {
+        last_stats_write = Some(std::time::Instant::now());
+        let now = chrono::Utc::now().timestamp();
+        let stats = tunnel_stats(&tunn_arc.lock().unwrap(), connected_at, now);
+        if let Err(e) = save_vpn_worker_stats(&stats, &stats_path) {
+          log::debug!("[vpn-worker] Failed to write tunnel stats: {e}");
+        }
+      }
+
       // Small sleep to avoid busy-spinning
       tokio::time::sleep(tokio::time::Duration::from_millis(1)).await;
     }
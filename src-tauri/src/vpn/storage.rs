@@ -38,6 +38,12 @@ struct StoredVpnConfig {
   last_sync: Option<u64>,
   #[serde(default)]
   updated_at: Option<u64>,
+  #[serde(default)]
+  auto_disconnect: bool,
+  #[serde(default)]
+  expected_country: Option<String>,
+  #[serde(default)]
+  expected_ip_prefix: Option<String>,
 }
 
 /// VPN storage manager with encryption
@@ -247,6 +253,9 @@ impl VpnStorage {
       sync_enabled: config.sync_enabled,
       last_sync: config.last_sync,
       updated_at: config.updated_at,
+      auto_disconnect: config.auto_disconnect,
+      expected_country: config.expected_country.clone(),
+      expected_ip_prefix: config.expected_ip_prefix.clone(),
     };
 
     // Update existing or add new
@@ -281,6 +290,9 @@ impl VpnStorage {
       sync_enabled: stored.sync_enabled,
       last_sync: stored.last_sync,
       updated_at: stored.updated_at,
+      auto_disconnect: stored.auto_disconnect,
+      expected_country: stored.expected_country.clone(),
+      expected_ip_prefix: stored.expected_ip_prefix.clone(),
     })
   }
 
@@ -302,6 +314,9 @@ impl VpnStorage {
           sync_enabled: stored.sync_enabled,
           last_sync: stored.last_sync,
           updated_at: stored.updated_at,
+          auto_disconnect: stored.auto_disconnect,
+          expected_country: stored.expected_country.clone(),
+          expected_ip_prefix: stored.expected_ip_prefix.clone(),
         })
         .collect(),
     )
@@ -359,6 +374,9 @@ impl VpnStorage {
       sync_enabled,
       last_sync: None,
       updated_at: Some(crate::proxy_manager::now_secs()),
+      auto_disconnect: false,
+      expected_country: None,
+      expected_ip_prefix: None,
     };
 
     self.save_config(&config)?;
@@ -375,6 +393,34 @@ impl VpnStorage {
     Ok(config)
   }
 
+  /// Toggle whether the tunnel stops after its last profile exits
+  pub fn update_auto_disconnect(
+    &self,
+    id: &str,
+    auto_disconnect: bool,
+  ) -> Result<VpnConfig, VpnError> {
+    let mut config = self.load_config(id)?;
+    config.auto_disconnect = auto_disconnect;
+    config.updated_at = Some(crate::proxy_manager::now_secs());
+    self.save_config(&config)?;
+    Ok(config)
+  }
+
+  /// Set where the tunnel's exit is expected; `None` clears a field
+  pub fn update_exit_expectation(
+    &self,
+    id: &str,
+    expected_country: Option<String>,
+    expected_ip_prefix: Option<String>,
+  ) -> Result<VpnConfig, VpnError> {
+    let mut config = self.load_config(id)?;
+    config.expected_country = expected_country;
+    config.expected_ip_prefix = expected_ip_prefix;
+    config.updated_at = Some(crate::proxy_manager::now_secs());
+    self.save_config(&config)?;
+    Ok(config)
+  }
+
   /// Update sync fields on a VPN config
   pub fn update_sync_fields(
     &self,
@@ -425,6 +471,9 @@ impl VpnStorage {
       sync_enabled,
       last_sync: None,
       updated_at: Some(crate::proxy_manager::now_secs()),
+      auto_disconnect: false,
+      expected_country: None,
+      expected_ip_prefix: None,
     };
 
     self.save_config(&config)?;
@@ -469,6 +518,9 @@ mod tests {
       sync_enabled: false,
       last_sync: None,
       updated_at: None,
+      auto_disconnect: false,
+      expected_country: None,
+      expected_ip_prefix: None,
     };
 
     storage.save_config(&config).unwrap();
@@ -494,6 +546,9 @@ mod tests {
       sync_enabled: false,
       last_sync: None,
       updated_at: None,
+      auto_disconnect: false,
+      expected_country: None,
+      expected_ip_prefix: None,
     };
 
     let config2 = VpnConfig {
@@ -506,6 +561,9 @@ mod tests {
       sync_enabled: false,
       last_sync: None,
       updated_at: None,
+      auto_disconnect: false,
+      expected_country: None,
+      expected_ip_prefix: None,
     };
 
     storage.save_config(&config1).unwrap();
@@ -533,6 +591,9 @@ mod tests {
       sync_enabled: false,
       last_sync: None,
       updated_at: None,
+      auto_disconnect: false,
+      expected_country: None,
+      expected_ip_prefix: None,
     };
 
     storage.save_config(&config).unwrap();
@@ -542,6 +603,33 @@ mod tests {
     assert!(storage.load_config("delete-me").is_err());
   }
 
+  #[test]
+  fn test_update_auto_disconnect_persists() {
+    let (storage, _temp) = create_test_storage();
+
+    let config = VpnConfig {
+      id: "auto-off".to_string(),
+      name: "Auto".to_string(),
+      vpn_type: VpnType::WireGuard,
+      config_data: "data".to_string(),
+      created_at: 1000,
+      last_used: None,
+      sync_enabled: false,
+      last_sync: None,
+      updated_at: None,
+      auto_disconnect: false,
+      expected_country: None,
+      expected_ip_prefix: None,
+    };
+    storage.save_config(&config).unwrap();
+
+    let updated = storage.update_auto_disconnect("auto-off", true).unwrap();
+    assert!(updated.auto_disconnect);
+    assert!(updated.updated_at.is_some());
+    assert!(storage.list_configs().unwrap()[0].auto_disconnect);
+    assert!(storage.update_auto_disconnect("missing", true).is_err());
+  }
+
   #[test]
   fn test_load_nonexistent_config() {
     let (storage, _temp) = create_test_storage();
@@ -2,8 +2,8 @@ use crate::proxy_runner::find_sidecar_executable;
 use crate::proxy_storage::is_process_running;
 use crate::vpn_worker_storage::{
   delete_vpn_worker_config, find_vpn_worker_by_vpn_id, generate_vpn_worker_id,
-  get_vpn_worker_config, list_vpn_worker_configs, save_vpn_worker_config, vpn_worker_config_path,
-  VpnWorkerConfig,
+  get_vpn_worker_config, get_vpn_worker_stats, list_vpn_worker_configs, save_vpn_worker_config,
+  vpn_worker_config_path, VpnWorkerConfig,
 };
 use std::process::Stdio;
 
@@ -33,6 +33,15 @@ async fn vpn_worker_accepting_connections(config: &VpnWorkerConfig) -> bool {
   )
 }
 
+fn emit_vpn_status(vpn_id: &str, connected: bool) {
+  if let Err(e) = crate::events::emit(&crate::events::payloads::VpnStatusChanged {
+    vpn_id: vpn_id.to_string(),
+    connected,
+  }) {
+    log::debug!("Failed to emit vpn-status-changed: {e}");
+  }
+}
+
 fn worker_log_path(id: &str) -> std::path::PathBuf {
   std::env::temp_dir().join(format!("donut-vpn-{}.log", id))
 }
@@ -212,6 +221,11 @@ pub async fn start_vpn_worker(vpn_id: &str) -> Result<VpnWorkerConfig, Box<dyn s
 
     let child = cmd.spawn()?;
     let pid = child.id();
+    crate::process_registry::register_child(
+      pid,
+      crate::process_registry::ChildKind::VpnWorker,
+      None,
+    );
 
     let mut config_with_pid = config.clone();
     config_with_pid.pid = Some(pid);
@@ -254,6 +268,11 @@ pub async fn start_vpn_worker(vpn_id: &str) -> Result<VpnWorkerConfig, Box<dyn s
 
     let child = cmd.spawn()?;
     let pid = child.id();
+    crate::process_registry::register_child(
+      pid,
+      crate::process_registry::ChildKind::VpnWorker,
+      None,
+    );
 
     let mut config_with_pid = config.clone();
     config_with_pid.pid = Some(pid);
@@ -263,7 +282,9 @@ pub async fn start_vpn_worker(vpn_id: &str) -> Result<VpnWorkerConfig, Box<dyn s
     drop(child);
   }
 
-  wait_for_vpn_worker_ready(&id).await
+  let ready = wait_for_vpn_worker_ready(&id).await?;
+  emit_vpn_status(vpn_id, true);
+  Ok(ready)
 }
 
 pub async fn stop_vpn_worker(id: &str) -> Result<bool, Box<dyn std::error::Error>> {
@@ -291,18 +312,45 @@ pub async fn stop_vpn_worker(id: &str) -> Result<bool, Box<dyn std::error::Error
       }
 
       tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
+      crate::process_registry::unregister_child(pid);
     }
 
     // Clean up temp config file
     let _ = std::fs::remove_file(&config.config_file_path);
 
     delete_vpn_worker_config(id);
+    emit_vpn_status(&config.vpn_id, false);
     return Ok(true);
   }
 
   Ok(false)
 }
 
+/// Called by the status checker once no running profile uses `vpn_id`.
+/// Stops the tunnel only when the VPN opted into `auto_disconnect`.
+pub async fn disconnect_if_auto(vpn_id: &str) -> bool {
+  let auto_disconnect = crate::vpn::VPN_STORAGE
+    .lock()
+    .ok()
+    .and_then(|storage| storage.load_config(vpn_id).ok())
+    .is_some_and(|config| config.auto_disconnect);
+  if !auto_disconnect {
+    return false;
+  }
+  match stop_vpn_worker_by_vpn_id(vpn_id).await {
+    Ok(stopped) => {
+      if stopped {
+        log::info!("Auto-disconnected VPN {vpn_id} after its last profile exited");
+      }
+      stopped
+    }
+    Err(e) => {
+      log::warn!("Failed to auto-disconnect VPN {vpn_id}: {e}");
+      false
+    }
+  }
+}
+
 pub async fn stop_vpn_worker_by_vpn_id(vpn_id: &str) -> Result<bool, Box<dyn std::error::Error>> {
   if let Some(config) = find_vpn_worker_by_vpn_id(vpn_id) {
     return stop_vpn_worker(&config.id).await;
@@ -317,3 +365,113 @@ pub async fn stop_all_vpn_workers() -> Result<(), Box<dyn std::error::Error>> {
   }
   Ok(())
 }
+
+/// How long a status read from a worker's stats file is reused. The worker
+/// refreshes the file every second, so polling faster gains nothing.
+const VPN_STATUS_CACHE_TTL: std::time::Duration = std::time::Duration::from_millis(1500);
+
+/// How often `vpn-stats` is emitted while any tunnel is up.
+pub const VPN_STATS_EVENT_INTERVAL_SECS: u64 = 10;
+
+fn read_vpn_status(vpn_id: &str) -> crate::vpn::VpnStatus {
+  let worker = find_vpn_worker_by_vpn_id(vpn_id);
+  let connected = worker
+    .as_ref()
+    .and_then(|w| w.pid)
+    .is_some_and(is_process_running);
+  let stats = worker
+    .filter(|_| connected)
+    .and_then(|w| get_vpn_worker_stats(&w.id));
+  crate::vpn::VpnStatus {
+    connected,
+    vpn_id: vpn_id.to_string(),
+    connected_at: stats.as_ref().and_then(|s| s.connected_at),
+    bytes_sent: stats.as_ref().map(|s| s.bytes_sent),
+    bytes_received: stats.as_ref().map(|s| s.bytes_received),
+    last_handshake: stats.and_then(|s| s.last_handshake),
+  }
+}
+
+/// Status of the tunnel for `vpn_id`, with the counters its worker last
+/// published. Shared by the Tauri command, the MCP tool and the API.
+pub fn vpn_status(vpn_id: &str) -> crate::vpn::VpnStatus {
+  static CACHE: std::sync::OnceLock<
+    std::sync::Mutex<
+      std::collections::HashMap<String, (std::time::Instant, crate::vpn::VpnStatus)>,
+    >,
+  > = std::sync::OnceLock::new();
+  let cache = CACHE.get_or_init(Default::default);
+  if let Some((read_at, status)) = cache.lock().unwrap().get(vpn_id) {
+    if read_at.elapsed() < VPN_STATUS_CACHE_TTL {
+      return status.clone();
+    }
+  }
+  let status = read_vpn_status(vpn_id);
+  cache.lock().unwrap().insert(
+    vpn_id.to_string(),
+    (std::time::Instant::now(), status.clone()),
+  );
+  status
+}
+
+/// Emit `vpn-stats` with every connected tunnel's status, for the live
+/// throughput graph. Nothing is emitted while no tunnel is up.
+pub fn emit_vpn_stats() {
+  let statuses: Vec<crate::vpn::VpnStatus> = list_vpn_worker_configs()
+    .iter()
+    .map(|worker| vpn_status(&worker.vpn_id))
+    .filter(|status| status.connected)
+    .collect();
+  if statuses.is_empty() {
+    return;
+  }
+  if let Err(e) = crate::events::emit(&crate::events::payloads::VpnStats(statuses)) {
+    log::debug!("Failed to emit vpn-stats: {e}");
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::vpn_worker_storage::{
+    save_vpn_worker_stats, vpn_worker_stats_path_in, VpnWorkerStats,
+  };
+
+  #[test]
+  fn status_carries_the_workers_published_stats() {
+    let dir = tempfile::tempdir().unwrap();
+    let _guard = crate::app_dirs::set_test_cache_dir(dir.path().to_path_buf());
+
+    let vpn_id = "vpn-stats-test";
+    let mut worker = VpnWorkerConfig::new(
+      generate_vpn_worker_id(),
+      vpn_id.to_string(),
+      "wireguard".to_string(),
+      String::new(),
+    );
+    worker.pid = Some(std::process::id());
+    save_vpn_worker_config(&worker).unwrap();
+    let stats = VpnWorkerStats {
+      connected_at: Some(1_700_000_000),
+      bytes_sent: 4096,
+      bytes_received: 65536,
+      last_handshake: Some(1_700_000_100),
+      updated_at: 1_700_000_101,
+    };
+    let stats_path = vpn_worker_stats_path_in(&crate::proxy_storage::get_storage_dir(), &worker.id);
+    save_vpn_worker_stats(&stats, &stats_path).unwrap();
+
+    let status = read_vpn_status(vpn_id);
+    assert!(status.connected);
+    assert_eq!(status.bytes_sent, Some(4096));
+    assert_eq!(status.bytes_received, Some(65536));
+    assert_eq!(status.last_handshake, Some(1_700_000_100));
+    assert_eq!(status.connected_at, Some(1_700_000_000));
+
+    delete_vpn_worker_config(&worker.id);
+    assert!(!stats_path.exists());
+    let status = read_vpn_status(vpn_id);
+    assert!(!status.connected);
+    assert_eq!(status.bytes_sent, None);
+  }
+}
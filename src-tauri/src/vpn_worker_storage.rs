@@ -32,6 +32,40 @@ pub fn vpn_worker_config_path(id: &str) -> std::path::PathBuf {
   get_storage_dir().join(format!("vpn_worker_{}.json", id))
 }
 
+/// Tunnel counters a worker publishes for the GUI, which has no other way to
+/// see the worker's WireGuard state.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VpnWorkerStats {
+  pub connected_at: Option<i64>,
+  /// Payload bytes through the tunnel, excluding WireGuard overhead.
+  pub bytes_sent: u64,
+  pub bytes_received: u64,
+  pub last_handshake: Option<i64>,
+  pub updated_at: i64,
+}
+
+/// Stats file of worker `id` in `dir`. Named apart from `vpn_worker_*.json`
+/// so `list_vpn_worker_configs` never tries to read it as a config.
+pub fn vpn_worker_stats_path_in(dir: &Path, id: &str) -> std::path::PathBuf {
+  dir.join(format!("vpn_stats_{}.json", id))
+}
+
+/// Replace the stats file in one step so a reader never sees half of it.
+pub fn save_vpn_worker_stats(
+  stats: &VpnWorkerStats,
+  path: &Path,
+) -> Result<(), Box<dyn std::error::Error>> {
+  let tmp = path.with_extension("json.tmp");
+  fs::write(&tmp, serde_json::to_string(stats)?)?;
+  fs::rename(&tmp, path)?;
+  Ok(())
+}
+
+pub fn get_vpn_worker_stats(id: &str) -> Option<VpnWorkerStats> {
+  let content = fs::read_to_string(vpn_worker_stats_path_in(&get_storage_dir(), id)).ok()?;
+  serde_json::from_str(&content).ok()
+}
+
 pub fn save_vpn_worker_config(config: &VpnWorkerConfig) -> Result<(), Box<dyn std::error::Error>> {
   let storage_dir = get_storage_dir();
   fs::create_dir_all(&storage_dir)?;
@@ -83,6 +117,8 @@ pub fn delete_vpn_worker_config(id: &str) -> bool {
   let storage_dir = get_storage_dir();
   let file_path = storage_dir.join(format!("vpn_worker_{}.json", id));
 
+  let _ = fs::remove_file(vpn_worker_stats_path_in(&storage_dir, id));
+
   if !file_path.exists() {
     return false;
   }
@@ -0,0 +1,420 @@
+//! Warm pool for Wayfern. With it on, stopping an eligible profile closes its
+//! windows but leaves the browser process and its local proxy worker running
+//! ("parked"), so the next launch of that profile only has to open a window.
+//!
+//! A parked browser is reused only while the launch signature (version,
+//! fingerprint config, proxy or VPN, extensions and other launch inputs) is
+//! unchanged; otherwise it is stopped and the launch starts fresh. Parked
+//! browsers are also stopped when idle for too long, when the pool is over
+//! its instance or memory cap, when their profile's config or proxy changes,
+//! and when the app exits.
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex};
+use std::time::{Duration, Instant};
+use sysinfo::{Pid, System};
+
+use crate::profile::types::{AppliedResourceLimits, BrowserProfile, RestartMode, SessionRestore};
+use crate::proxy_manager::PROXY_MANAGER;
+use crate::settings_manager::SettingsManager;
+use crate::wayfern_manager::WayfernManager;
+
+/// How often idle, dead and over-cap parked browsers are cleared out.
+pub const MAINTENANCE_INTERVAL_SECS: u64 = 60;
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(default)]
+pub struct WarmPoolSettings {
+  pub enabled: bool,
+  /// Parked browsers kept at once; parking one more stops the oldest.
+  pub max_instances: u32,
+  /// Parked browsers unused for this long are stopped. 0 keeps them.
+  pub idle_timeout_minutes: u32,
+  /// Combined memory of the parked browsers; the oldest are stopped to stay
+  /// under it. 0 disables the cap.
+  pub max_memory_mb: u64,
+}
+
+impl Default for WarmPoolSettings {
+  fn default() -> Self {
+    Self {
+      enabled: false,
+      max_instances: 3,
+      idle_timeout_minutes: 30,
+      max_memory_mb: 2048,
+    }
+  }
+}
+
+pub struct ParkedBrowser {
+  pub instance_id: String,
+  pub pid: u32,
+  pub resource_limits: Option<AppliedResourceLimits>,
+  signature: [u8; 32],
+  parked_at: Instant,
+}
+
+static POOL: LazyLock<Mutex<HashMap<String, ParkedBrowser>>> =
+  LazyLock::new(|| Mutex::new(HashMap::new()));
+
+fn lock_pool() -> std::sync::MutexGuard<'static, HashMap<String, ParkedBrowser>> {
+  POOL.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+fn current_settings() -> WarmPoolSettings {
+  SettingsManager::instance()
+    .load_settings()
+    .map(|s| s.warm_pool)
+    .unwrap_or_default()
+}
+
+/// Profiles whose stop must really end the browser: their data is encrypted,
+/// wiped, synced or restored on close, a fresh fingerprint is wanted on every
+/// launch, or a pool proxy picks a different member per launch.
+fn is_eligible(profile: &BrowserProfile) -> bool {
+  profile.browser == "wayfern"
+    && !profile.ephemeral
+    && !profile.password_protected
+    && !profile.clear_on_close
+    && !profile.is_sync_enabled()
+    && profile.proxy_pool_id.is_none()
+    && profile.session_restore != SessionRestore::Restore
+    && profile.restart_policy.mode != RestartMode::Always
+    && profile
+      .wayfern_config
+      .as_ref()
+      .and_then(|c| c.randomize_fingerprint_on_launch)
+      != Some(true)
+}
+
+/// Whether a launch of `profile` should start a browser that can be parked.
+pub fn keeps_alive(profile: &BrowserProfile, headless: bool) -> bool {
+  !headless && is_eligible(profile) && current_settings().enabled
+}
+
+/// Hash of everything a parked browser was started with that a reused one
+/// can't pick up afterwards.
+fn launch_signature(profile: &BrowserProfile) -> [u8; 32] {
+  let proxy = profile.proxy_id.as_deref().map(|id| {
+    let stored = PROXY_MANAGER.get_stored_proxy(id);
+    serde_json::json!({
+      "settings": stored.as_ref().map(|p| &p.proxy_settings),
+      "dynamic_url": stored.as_ref().and_then(|p| p.dynamic_proxy_url.as_ref()),
+      "entry_hops": PROXY_MANAGER.get_proxy_entry_hops(id),
+    })
+  });
+  let inputs = serde_json::json!({
+    "name": profile.name,
+    "version": profile.version,
+    "wayfern_config": profile.wayfern_config,
+    "proxy_id": profile.proxy_id,
+    "proxy": proxy,
+    "vpn_id": profile.vpn_id,
+    "proxy_bypass_rules": profile.proxy_bypass_rules,
+    "dns_blocklist": profile.dns_blocklist,
+    "dns_mode": profile.dns_mode,
+    "extension_group_id": profile.extension_group_id,
+    "preference_overrides": profile.preference_overrides,
+    "resource_limits": profile.resource_limits,
+    "window_color": profile.window_color,
+  });
+  Sha256::digest(inputs.to_string().as_bytes()).into()
+}
+
+/// Parks the profile's browser instead of stopping it, when the pool is on
+/// and the profile qualifies. Returns whether it was parked.
+pub async fn park(profile: &BrowserProfile, profile_path: &str) -> bool {
+  let settings = current_settings();
+  if !settings.enabled || settings.max_instances == 0 || !is_eligible(profile) {
+    return false;
+  }
+  let Some((instance_id, pid)) = WayfernManager::instance().park_wayfern(profile_path).await else {
+    return false;
+  };
+
+  lock_pool().insert(
+    profile.id.to_string(),
+    ParkedBrowser {
+      instance_id,
+      pid,
+      resource_limits: profile.applied_resource_limits.clone(),
+      signature: launch_signature(profile),
+      parked_at: Instant::now(),
+    },
+  );
+  log::info!(
+    "Parked browser for profile {} (PID: {pid}) in the warm pool",
+    profile.name
+  );
+  enforce(&settings).await;
+  true
+}
+
+/// Takes the profile's parked browser out of the pool for reuse. A browser
+/// that has died or was started with different launch inputs is stopped and
+/// `None` returned, so the caller launches a fresh one.
+pub async fn take(profile: &BrowserProfile) -> Option<ParkedBrowser> {
+  let profile_id = profile.id.to_string();
+  let parked = lock_pool().remove(&profile_id)?;
+  if !crate::process_registry::process_exists(parked.pid) {
+    log::info!("Parked browser for profile {} has exited", profile.name);
+  } else if parked.signature != launch_signature(profile) {
+    log::info!(
+      "Launch settings of profile {} changed since it was parked, starting fresh",
+      profile.name
+    );
+  } else if !current_settings().enabled {
+    log::info!("Warm pool is off, not reusing parked browser");
+  } else {
+    return Some(parked);
+  }
+  teardown(&profile_id, parked).await;
+  None
+}
+
+/// Hands back a browser from [`take`] that couldn't be revived.
+pub async fn discard(profile_id: &str, parked: ParkedBrowser) {
+  teardown(profile_id, parked).await;
+}
+
+/// Stops the profile's parked browser, if any. For changes that would make
+/// it unusable anyway, so it doesn't hold memory until the next launch.
+pub fn invalidate(profile_id: &str) {
+  let Some(parked) = lock_pool().remove(profile_id) else {
+    return;
+  };
+  let profile_id = profile_id.to_string();
+  tauri::async_runtime::spawn(async move {
+    teardown(&profile_id, parked).await;
+  });
+}
+
+/// Stops every parked browser.
+pub async fn evict_all() {
+  let drained: Vec<_> = lock_pool().drain().collect();
+  for (profile_id, parked) in drained {
+    teardown(&profile_id, parked).await;
+  }
+}
+
+/// Applies changed settings right away instead of at the next sweep.
+pub fn apply_settings(settings: &WarmPoolSettings) {
+  let settings = settings.clone();
+  tauri::async_runtime::spawn(async move {
+    enforce(&settings).await;
+  });
+}
+
+/// One maintenance pass; run every [`MAINTENANCE_INTERVAL_SECS`].
+pub async fn run_maintenance() {
+  enforce(&current_settings()).await;
+}
+
+async fn teardown(profile_id: &str, parked: ParkedBrowser) {
+  log::info!(
+    "Stopping parked browser for profile {profile_id} (PID: {})",
+    parked.pid
+  );
+  if let Err(e) = WayfernManager::instance()
+    .stop_wayfern(&parked.instance_id)
+    .await
+  {
+    log::warn!("Failed to stop parked browser {}: {e}", parked.instance_id);
+  }
+  // A fresh launch may follow, and it can't start while this one still holds
+  // the profile directory.
+  if !crate::process_registry::terminate(parked.pid, Duration::from_secs(5)).await {
+    log::warn!("Parked browser {} did not exit", parked.pid);
+  }
+  PROXY_MANAGER.release_browser_proxy(parked.pid, None).await;
+  crate::process_registry::reap_profile_children(profile_id);
+}
+
+struct PoolEntry {
+  profile_id: String,
+  parked_at: Instant,
+  memory_bytes: u64,
+  alive: bool,
+}
+
+/// Profiles to stop: dead ones, then, keeping the most recently parked
+/// first, any idle past the timeout or past the instance or memory cap.
+fn select_evictions(
+  entries: &mut [PoolEntry],
+  settings: &WarmPoolSettings,
+  now: Instant,
+) -> Vec<String> {
+  if !settings.enabled {
+    return entries.iter().map(|e| e.profile_id.clone()).collect();
+  }
+  let idle_timeout = Duration::from_secs(u64::from(settings.idle_timeout_minutes) * 60);
+  let memory_cap = settings.max_memory_mb * 1024 * 1024;
+  entries.sort_by_key(|e| std::cmp::Reverse(e.parked_at));
+
+  let mut evicted = Vec::new();
+  let mut kept = 0u32;
+  let mut kept_memory = 0u64;
+  for entry in entries.iter() {
+    let idle = !idle_timeout.is_zero() && now.duration_since(entry.parked_at) >= idle_timeout;
+    let over_memory = memory_cap > 0 && kept_memory + entry.memory_bytes > memory_cap;
+    if !entry.alive || idle || kept >= settings.max_instances || over_memory {
+      evicted.push(entry.profile_id.clone());
+    } else {
+      kept += 1;
+      kept_memory += entry.memory_bytes;
+    }
+  }
+  evicted
+}
+
+/// Memory of `root` and every process below it. Chromium runs each renderer,
+/// GPU and utility process as a descendant of the browser process.
+fn tree_memory_bytes(system: &System, root: Pid) -> u64 {
+  system
+    .processes()
+    .iter()
+    .filter(|(pid, _)| {
+      let mut current = Some(**pid);
+      // Bounded walk, in case the table holds a parent cycle from PID reuse.
+      for _ in 0..16 {
+        match current {
+          Some(pid) if pid == root => return true,
+          Some(pid) => current = system.process(pid).and_then(|p| p.parent()),
+          None => return false,
+        }
+      }
+      false
+    })
+    .map(|(_, process)| process.memory())
+    .sum()
+}
+
+async fn enforce(settings: &WarmPoolSettings) {
+  let mut entries: Vec<PoolEntry> = {
+    let pool = lock_pool();
+    if pool.is_empty() {
+      return;
+    }
+    crate::process_registry::with_process_table(|system| {
+      pool
+        .iter()
+        .map(|(profile_id, parked)| {
+          let root = Pid::from_u32(parked.pid);
+          PoolEntry {
+            profile_id: profile_id.clone(),
+            parked_at: parked.parked_at,
+            memory_bytes: tree_memory_bytes(system, root),
+            alive: system.process(root).is_some(),
+          }
+        })
+        .collect()
+    })
+  };
+
+  let evicted: Vec<_> = {
+    let mut pool = lock_pool();
+    select_evictions(&mut entries, settings, Instant::now())
+      .into_iter()
+      .filter_map(|id| pool.remove(&id).map(|parked| (id, parked)))
+      .collect()
+  };
+  for (profile_id, parked) in evicted {
+    teardown(&profile_id, parked).await;
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn entry(id: &str, parked_at: Instant, memory_mb: u64) -> PoolEntry {
+    PoolEntry {
+      profile_id: id.to_string(),
+      parked_at,
+      memory_bytes: memory_mb * 1024 * 1024,
+      alive: true,
+    }
+  }
+
+  #[test]
+  fn oldest_are_evicted_past_the_instance_cap() {
+    let now = Instant::now();
+    let mut entries = vec![
+      entry("old", now - Duration::from_secs(30), 100),
+      entry("new", now, 100),
+      entry("mid", now - Duration::from_secs(10), 100),
+    ];
+    let settings = WarmPoolSettings {
+      enabled: true,
+      max_instances: 2,
+      ..Default::default()
+    };
+    assert_eq!(select_evictions(&mut entries, &settings, now), ["old"]);
+  }
+
+  #[test]
+  fn idle_dead_and_over_memory_entries_are_evicted() {
+    let now = Instant::now();
+    let mut dead = entry("dead", now, 100);
+    dead.alive = false;
+    let mut entries = vec![
+      entry("idle", now - Duration::from_secs(31 * 60), 100),
+      dead,
+      entry("big", now - Duration::from_secs(5), 1500),
+      entry("fresh", now, 600),
+    ];
+    let settings = WarmPoolSettings {
+      enabled: true,
+      max_instances: 10,
+      idle_timeout_minutes: 30,
+      max_memory_mb: 2000,
+    };
+    let mut evicted = select_evictions(&mut entries, &settings, now);
+    evicted.sort();
+    assert_eq!(evicted, ["big", "dead", "idle"]);
+  }
+
+  #[test]
+  fn disabling_the_pool_evicts_everything() {
+    let now = Instant::now();
+    let mut entries = vec![entry("a", now, 1), entry("b", now, 1)];
+    let settings = WarmPoolSettings::default();
+    assert_eq!(select_evictions(&mut entries, &settings, now).len(), 2);
+  }
+
+  #[test]
+  fn signature_follows_launch_inputs_only() {
+    let mut profile = crate::wayfern_manager::fingerprint_generation_profile("1.0.0");
+    let signature = launch_signature(&profile);
+
+    profile.note = Some("renamed tabs".to_string());
+    profile.last_launch = Some(42);
+    assert_eq!(launch_signature(&profile), signature);
+
+    profile.proxy_id = Some("proxy_1".to_string());
+    assert_ne!(launch_signature(&profile), signature);
+  }
+
+  #[test]
+  fn profiles_that_must_really_stop_are_not_eligible() {
+    let profile = crate::wayfern_manager::fingerprint_generation_profile("1.0.0");
+    assert!(is_eligible(&profile));
+
+    let mut ephemeral = profile.clone();
+    ephemeral.ephemeral = true;
+    assert!(!is_eligible(&ephemeral));
+
+    let mut restoring = profile.clone();
+    restoring.session_restore = SessionRestore::Restore;
+    assert!(!is_eligible(&restoring));
+
+    let mut randomized = profile;
+    randomized.wayfern_config = Some(crate::wayfern_manager::WayfernConfig {
+      randomize_fingerprint_on_launch: Some(true),
+      ..Default::default()
+    });
+    assert!(!is_eligible(&randomized));
+  }
+}
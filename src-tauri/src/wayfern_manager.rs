@@ -1,18 +1,50 @@
+use crate::app_handle::AppHandle;
 use crate::browser_runner::BrowserRunner;
+use crate::launch_timings::{LaunchPhase, PhaseTiming};
+use crate::profile::types::{AppliedResourceLimits, DnsMode};
 use crate::profile::BrowserProfile;
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
 use serde_json::json;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
 use std::process::Stdio;
 use std::sync::Arc;
-use std::time::Duration;
-use tauri::AppHandle;
+use std::time::{Duration, Instant};
 use tokio::process::Command as TokioCommand;
 use tokio::sync::Mutex as AsyncMutex;
 use tokio_tungstenite::{connect_async, tungstenite::Message};
 
+/// How many times fingerprint generation re-samples to get a WebGL renderer
+/// consistent with the OS and hardware tier (and, when requested, a sample
+/// free of error-level quality findings) before settling for the last one.
+const MAX_FINGERPRINT_RESAMPLES: u32 = 8;
+
+/// How long a CDP port handed to a launch stays reserved before the launch
+/// registers its instance. Covers launches that fail before registering.
+const CDP_PORT_RESERVATION_TTL: Duration = Duration::from_secs(120);
+
+/// Most fingerprints a single standalone generation request may ask for. Each
+/// one is a CDP round trip (plus re-samples) against the same headless browser.
+pub const MAX_GENERATED_FINGERPRINTS: usize = 50;
+
+/// How the profile's browser exposes WebRTC.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "mode", rename_all = "snake_case")]
+pub enum WebRtcMode {
+  /// No peer connections at all.
+  Disabled,
+  /// ICE only over the proxy, so no host or STUN candidate reveals the real
+  /// address.
+  RelayOnly,
+  /// Candidates report `ip` as the public address. Unset uses the exit IP
+  /// seen through the local proxy at launch, so it follows the current route.
+  Mock {
+    #[serde(default)]
+    ip: Option<String>,
+  },
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct WayfernConfig {
   #[serde(default)]
@@ -45,6 +77,38 @@ pub struct WayfernConfig {
   /// location can be refreshed instead of showing stale data.
   #[serde(default)]
   pub geo_proxy_signature: Option<String>,
+  /// BCP 47 locale (e.g. `"en-US"`) the user pinned for this profile. When
+  /// set, geolocation still drives timezone and coordinates but leaves
+  /// `language`/`languages` on this locale, for users who deliberately want a
+  /// language that doesn't match the exit country.
+  #[serde(default)]
+  pub locale_override: Option<String>,
+  /// IANA zone (e.g. `"Asia/Tokyo"`) the user pinned for this profile. When
+  /// set it replaces the geolocated timezone in the fingerprint and the
+  /// browser's `TZ`, while coordinates and language still follow the exit.
+  #[serde(default)]
+  pub timezone_override: Option<String>,
+  /// GPU class the sampled WebGL renderer should fall in. When unset it is
+  /// derived from the fingerprint's `hardwareConcurrency`/`deviceMemory`.
+  #[serde(default)]
+  pub gpu_tier: Option<crate::webgl::GpuTier>,
+  /// Re-sample generated fingerprints that fail an error-level check in
+  /// [`crate::fingerprint_quality::validate_fingerprint`].
+  #[serde(default)]
+  pub reject_inconsistent_fingerprints: Option<bool>,
+  /// Unset leaves Chromium's default WebRTC behaviour.
+  #[serde(default)]
+  pub webrtc_mode: Option<WebRtcMode>,
+}
+
+impl WayfernConfig {
+  /// `webrtc_mode`, falling back to the older `block_webrtc` flag.
+  pub fn effective_webrtc_mode(&self) -> Option<WebRtcMode> {
+    self
+      .webrtc_mode
+      .clone()
+      .or_else(|| (self.block_webrtc == Some(true)).then_some(WebRtcMode::Disabled))
+  }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -63,18 +127,63 @@ pub struct WayfernLaunchResult {
   /// only — the caller persists it to the profile; never sent to the frontend.
   #[serde(default, skip_serializing)]
   pub used_fingerprint: Option<String>,
+  /// Phases timed inside `launch_wayfern`, for the caller's launch timings.
+  #[serde(skip)]
+  pub phase_timings: Vec<PhaseTiming>,
+  /// Priority and memory limits the browser was started under.
+  #[serde(skip)]
+  pub resource_limits: Option<AppliedResourceLimits>,
+}
+
+/// Id prefix for instances adopted from a process-table scan: started outside
+/// this app session (by hand, or by a previous run of the app).
+const RECOVERED_INSTANCE_PREFIX: &str = "recovered_";
+
+impl WayfernLaunchResult {
+  /// True when this app session did not launch the process itself.
+  pub fn is_recovered(&self) -> bool {
+    self.id.starts_with(RECOVERED_INSTANCE_PREFIX)
+  }
 }
 
 struct WayfernInstance {
   id: String,
   process_id: Option<u32>,
+  profile_id: Option<String>,
   profile_path: Option<String>,
   url: Option<String>,
   cdp_port: Option<u16>,
+  /// Started with `--keep-alive-for-test`, so it outlives its windows.
+  keep_alive: bool,
+  /// Windowless and held by the warm pool; the profile counts as stopped.
+  parked: bool,
 }
 
 struct WayfernManagerInner {
   instances: HashMap<String, WayfernInstance>,
+  /// CDP ports allocated to launches still in flight, so two concurrent
+  /// launches can't both be handed the same free port.
+  reserved_ports: HashMap<u16, Instant>,
+}
+
+/// Where automation tools can attach to a running profile.
+#[derive(Debug, Clone, Serialize, utoipa::ToSchema)]
+pub struct CdpEndpoint {
+  pub port: u16,
+  /// `http://127.0.0.1:<port>/json/version`
+  pub http_url: String,
+  /// Browser-level `webSocketDebuggerUrl` from `/json/version`.
+  pub ws_url: String,
+  /// `User-Agent` the browser reports in `/json/version`.
+  pub user_agent: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct CdpVersion {
+  #[serde(rename = "webSocketDebuggerUrl")]
+  websocket_debugger_url: String,
+  #[serde(rename = "User-Agent", default)]
+  user_agent: String,
 }
 
 pub struct WayfernManager {
@@ -88,6 +197,10 @@ struct CdpTarget {
   target_type: String,
   #[serde(rename = "webSocketDebuggerUrl")]
   websocket_debugger_url: Option<String>,
+  #[serde(default)]
+  id: String,
+  #[serde(default)]
+  url: String,
 }
 
 impl WayfernManager {
@@ -95,6 +208,7 @@ impl WayfernManager {
     Self {
       inner: Arc::new(AsyncMutex::new(WayfernManagerInner {
         instances: HashMap::new(),
+        reserved_ports: HashMap::new(),
       })),
       // CDP is always on loopback. Disable env/system proxies so a Windows
       // WinHTTP/IE proxy (or HTTP_PROXY) cannot intercept /json/version and
@@ -128,6 +242,51 @@ impl WayfernManager {
     Ok(port)
   }
 
+  /// Pick the CDP port for a launch and reserve it until the instance is
+  /// registered. `requested` of `None` or `Some(0)` auto-picks a free port;
+  /// an explicit port must not belong to another instance or be bound.
+  async fn allocate_cdp_port(
+    &self,
+    requested: Option<u16>,
+  ) -> Result<u16, Box<dyn std::error::Error + Send + Sync>> {
+    let mut inner = self.inner.lock().await;
+    inner
+      .reserved_ports
+      .retain(|_, at| at.elapsed() < CDP_PORT_RESERVATION_TTL);
+    let taken: HashSet<u16> = inner
+      .instances
+      .values()
+      .filter_map(|i| i.cdp_port)
+      .chain(inner.reserved_ports.keys().copied())
+      .collect();
+
+    let port = match requested.filter(|p| *p != 0) {
+      Some(port) => {
+        if taken.contains(&port) || std::net::TcpListener::bind(("127.0.0.1", port)).is_err() {
+          return Err(
+            json!({ "code": "CDP_PORT_IN_USE", "params": { "port": port.to_string() } })
+              .to_string()
+              .into(),
+          );
+        }
+        port
+      }
+      None => {
+        let mut found = None;
+        for _ in 0..16 {
+          let port = Self::find_free_port().await?;
+          if !taken.contains(&port) {
+            found = Some(port);
+            break;
+          }
+        }
+        found.ok_or("Failed to find a free CDP port")?
+      }
+    };
+    inner.reserved_ports.insert(port, Instant::now());
+    Ok(port)
+  }
+
   /// Normalize fingerprint data from Wayfern CDP format to our storage format.
   /// Wayfern returns fields like fonts, webglParameters as JSON strings which we keep as-is.
   fn normalize_fingerprint(fingerprint: serde_json::Value) -> serde_json::Value {
@@ -188,6 +347,37 @@ impl WayfernManager {
       .or_else(|| pair("screenWidth", "screenHeight"))
   }
 
+  /// The language list the browser should advertise in `Accept-Language`,
+  /// taken from the stored fingerprint's `languages` (array or the legacy
+  /// comma-separated string), falling back to `language`. `setFingerprint`
+  /// spoofs what scripts read; the header comes from the browser's own locale
+  /// settings, so without passing the same list on the command line a German
+  /// `navigator.language` would still send `Accept-Language: en-US`.
+  fn accept_languages_from_fingerprint(fingerprint_json: &str) -> Option<Vec<String>> {
+    let parsed: serde_json::Value = serde_json::from_str(fingerprint_json).ok()?;
+    let fp = parsed.get("fingerprint").unwrap_or(&parsed);
+
+    let languages: Vec<String> = match fp.get("languages") {
+      Some(serde_json::Value::Array(items)) => items
+        .iter()
+        .filter_map(|v| v.as_str())
+        .map(|s| s.trim().to_string())
+        .collect(),
+      Some(serde_json::Value::String(s)) => s.split(',').map(|l| l.trim().to_string()).collect(),
+      _ => Vec::new(),
+    };
+    let mut languages: Vec<String> = languages.into_iter().filter(|l| !l.is_empty()).collect();
+    if languages.is_empty() {
+      if let Some(language) = fp.get("language").and_then(|v| v.as_str()) {
+        if !language.trim().is_empty() {
+          languages.push(language.trim().to_string());
+        }
+      }
+    }
+
+    (!languages.is_empty()).then_some(languages)
+  }
+
   async fn wait_for_cdp_ready(
     &self,
     port: u16,
@@ -233,6 +423,22 @@ impl WayfernManager {
     Ok(targets)
   }
 
+  /// IDs of the extensions with a running background page or service worker.
+  pub async fn running_extension_ids(
+    &self,
+    port: u16,
+  ) -> Result<std::collections::HashSet<String>, Box<dyn std::error::Error + Send + Sync>> {
+    let targets = self.get_cdp_targets(port).await?;
+    Ok(
+      targets
+        .iter()
+        .filter_map(|t| t.url.strip_prefix("chrome-extension://"))
+        .filter_map(|rest| rest.split('/').next())
+        .map(str::to_string)
+        .collect(),
+    )
+  }
+
   async fn send_cdp_command(
     &self,
     ws_url: &str,
@@ -320,7 +526,18 @@ impl WayfernManager {
     fingerprint: &mut serde_json::Value,
     proxy: Option<&str>,
     geoip: Option<&serde_json::Value>,
+    locale_override: Option<&str>,
+    timezone_override: Option<&str>,
   ) -> bool {
+    let pinned_locale = locale_override.and_then(crate::geolocation::parse_locale_list);
+    if let Some(prefs) = &pinned_locale {
+      Self::apply_locale(fingerprint, prefs);
+    }
+    let pinned_timezone = timezone_override.and_then(crate::geolocation::parse_timezone);
+    if let Some(timezone) = pinned_timezone {
+      Self::apply_timezone(fingerprint, timezone);
+    }
+
     // Default to auto-detect; only an explicit `false` disables geolocation.
     let should_geolocate = !matches!(geoip, Some(serde_json::Value::Bool(false)));
     if !should_geolocate {
@@ -341,29 +558,19 @@ impl WayfernManager {
 
     match geo_result {
       Ok(geo) => {
+        let timezone = pinned_timezone.unwrap_or(geo.timezone.as_str());
+        Self::apply_timezone(fingerprint, timezone);
         if let Some(obj) = fingerprint.as_object_mut() {
-          obj.insert("timezone".to_string(), json!(geo.timezone));
-          // Calculate timezone offset from IANA timezone name
-          if let Ok(tz) = geo.timezone.parse::<chrono_tz::Tz>() {
-            use chrono::Offset;
-            let now = chrono::Utc::now().with_timezone(&tz);
-            let offset_seconds = now.offset().fix().local_minus_utc();
-            let offset_minutes = -(offset_seconds / 60);
-            obj.insert("timezoneOffset".to_string(), json!(offset_minutes));
-          }
           obj.insert("latitude".to_string(), json!(geo.latitude));
           obj.insert("longitude".to_string(), json!(geo.longitude));
-          let locale_str = geo.locale.as_string();
-          obj.insert("language".to_string(), json!(&locale_str));
-          obj.insert(
-            "languages".to_string(),
-            json!([&locale_str, &geo.locale.language]),
-          );
         }
+        // The locale follows the exit country unless the user pinned one, so
+        // navigator.language, Intl and Accept-Language agree with the timezone.
+        let prefs = pinned_locale.unwrap_or_else(|| (&geo.locale).into());
+        Self::apply_locale(fingerprint, &prefs);
         log::info!(
-          "Applied geolocation to Wayfern fingerprint: {} ({})",
-          geo.locale.as_string(),
-          geo.timezone
+          "Applied geolocation to Wayfern fingerprint: {} ({timezone})",
+          prefs.language
         );
         true
       }
@@ -382,6 +589,73 @@ impl WayfernManager {
     }
   }
 
+  /// Write `timezone` and its current UTC offset into the fingerprint.
+  fn apply_timezone(fingerprint: &mut serde_json::Value, timezone: &str) {
+    if let Some(obj) = fingerprint.as_object_mut() {
+      obj.insert("timezone".to_string(), json!(timezone));
+      if let Ok(tz) = timezone.parse::<chrono_tz::Tz>() {
+        use chrono::Offset;
+        let now = chrono::Utc::now().with_timezone(&tz);
+        let offset_seconds = now.offset().fix().local_minus_utc();
+        let offset_minutes = -(offset_seconds / 60);
+        obj.insert("timezoneOffset".to_string(), json!(offset_minutes));
+      }
+    }
+  }
+
+  /// Rewrites only the timezone fields of a stored fingerprint, keeping the
+  /// rest of it (and any `{"fingerprint": ...}` wrapper) as is.
+  pub fn apply_timezone_to_fingerprint(fingerprint_json: &str, timezone: &str) -> Option<String> {
+    let mut parsed: serde_json::Value = serde_json::from_str(fingerprint_json).ok()?;
+    if parsed.get("fingerprint").is_some_and(|fp| fp.is_object()) {
+      Self::apply_timezone(&mut parsed["fingerprint"], timezone);
+    } else {
+      Self::apply_timezone(&mut parsed, timezone);
+    }
+    serde_json::to_string(&parsed).ok()
+  }
+
+  /// Write `prefs` into the fingerprint's `language` and `languages`.
+  fn apply_locale(
+    fingerprint: &mut serde_json::Value,
+    prefs: &crate::geolocation::LanguagePreference,
+  ) {
+    if let Some(obj) = fingerprint.as_object_mut() {
+      obj.insert("language".to_string(), json!(prefs.language));
+      obj.insert("languages".to_string(), json!(prefs.languages));
+    }
+  }
+
+  /// Whether a stored fingerprint already reports exactly the languages
+  /// `prefs` pins, both to scripts and (via `--accept-lang`) in headers.
+  pub fn fingerprint_matches_locale(
+    fingerprint_json: &str,
+    prefs: &crate::geolocation::LanguagePreference,
+  ) -> bool {
+    let Ok(parsed) = serde_json::from_str::<serde_json::Value>(fingerprint_json) else {
+      return false;
+    };
+    let fp = parsed.get("fingerprint").unwrap_or(&parsed);
+    fp.get("language").and_then(|v| v.as_str()) == Some(prefs.language.as_str())
+      && Self::accept_languages_from_fingerprint(fingerprint_json).as_ref()
+        == Some(&prefs.languages)
+  }
+
+  /// Rewrites only the language fields of a stored fingerprint, keeping the
+  /// rest of it (and any `{"fingerprint": ...}` wrapper) as is.
+  pub fn apply_locale_to_fingerprint(
+    fingerprint_json: &str,
+    prefs: &crate::geolocation::LanguagePreference,
+  ) -> Option<String> {
+    let mut parsed: serde_json::Value = serde_json::from_str(fingerprint_json).ok()?;
+    if parsed.get("fingerprint").is_some_and(|fp| fp.is_object()) {
+      Self::apply_locale(&mut parsed["fingerprint"], prefs);
+    } else {
+      Self::apply_locale(&mut parsed, prefs);
+    }
+    serde_json::to_string(&parsed).ok()
+  }
+
   /// Refresh ONLY the location fields (timezone, offset, latitude/longitude,
   /// language) of an already-generated fingerprint to match the current proxy,
   /// leaving every other fingerprint field untouched. `proxy` is the local
@@ -393,9 +667,11 @@ impl WayfernManager {
     fingerprint_json: &str,
     proxy: Option<&str>,
     geoip: Option<&serde_json::Value>,
+    locale_override: Option<&str>,
+    timezone_override: Option<&str>,
   ) -> Option<String> {
     let mut fp: serde_json::Value = serde_json::from_str(fingerprint_json).ok()?;
-    if Self::apply_geolocation(&mut fp, proxy, geoip).await {
+    if Self::apply_geolocation(&mut fp, proxy, geoip, locale_override, timezone_override).await {
       serde_json::to_string(&fp).ok()
     } else {
       None
@@ -436,10 +712,27 @@ impl WayfernManager {
   /// this proxy and permanently disable the one path that can repair it.
   pub async fn generate_fingerprint_config(
     &self,
-    _app_handle: &AppHandle,
+    app_handle: &AppHandle,
     profile: &BrowserProfile,
     config: &WayfernConfig,
   ) -> Result<(String, bool), Box<dyn std::error::Error + Send + Sync>> {
+    self
+      .generate_fingerprint_configs(app_handle, profile, config, 1)
+      .await?
+      .pop()
+      .ok_or_else(|| "Wayfern returned no fingerprint".into())
+  }
+
+  /// Generate `count` independent fingerprints for `config` from a single
+  /// headless Wayfern instance. Each entry is shaped like the result of
+  /// [`Self::generate_fingerprint_config`].
+  pub async fn generate_fingerprint_configs(
+    &self,
+    _app_handle: &AppHandle,
+    profile: &BrowserProfile,
+    config: &WayfernConfig,
+    count: usize,
+  ) -> Result<Vec<(String, bool)>, Box<dyn std::error::Error + Send + Sync>> {
     let executable_path = BrowserRunner::instance()
       .get_browser_executable_path(profile)
       .map_err(|e| format!("Failed to get Wayfern executable path: {e}"))?;
@@ -509,11 +802,7 @@ impl WayfernManager {
       // Try to capture stderr from the failed process for diagnostics
       let stderr_output = if let Some(id) = child_id {
         // Check if process is still running
-        let is_running = sysinfo::System::new_with_specifics(
-          sysinfo::RefreshKind::nothing().with_processes(sysinfo::ProcessRefreshKind::nothing()),
-        )
-        .process(sysinfo::Pid::from(id as usize))
-        .is_some();
+        let is_running = crate::process_registry::process_exists(id);
 
         if !is_running {
           // Process exited — try to read its stderr
@@ -573,111 +862,197 @@ impl WayfernManager {
         .insert("wayfernToken".to_string(), json!(token));
     }
 
-    let refresh_result = self
-      .send_cdp_command(&ws_url, "Wayfern.refreshFingerprint", refresh_params)
-      .await;
+    // Every sample comes from the same headless instance: spawning Wayfern
+    // and waiting for CDP is the expensive part, re-sampling is cheap.
+    let reject_inconsistent = config.reject_inconsistent_fingerprints.unwrap_or(false);
+    let mut generated = Vec::with_capacity(count);
+    for sample in 0..count {
+      let refresh_result = self
+        .send_cdp_command(
+          &ws_url,
+          "Wayfern.refreshFingerprint",
+          refresh_params.clone(),
+        )
+        .await;
 
-    if let Err(e) = refresh_result {
-      cleanup().await;
-      return Err(format!("Failed to refresh fingerprint: {e}").into());
-    }
+      if let Err(e) = refresh_result {
+        cleanup().await;
+        return Err(format!("Failed to refresh fingerprint: {e}").into());
+      }
 
-    let get_result = self
-      .send_cdp_command(&ws_url, "Wayfern.getFingerprint", json!({}))
-      .await;
-
-    let (fingerprint, geolocation_applied) = match get_result {
-      Ok(result) => {
-        // Wayfern.getFingerprint returns { fingerprint: {...} }
-        // We need to extract just the fingerprint object
-        let fp = result.get("fingerprint").cloned().unwrap_or(result);
-        // Normalize the fingerprint: convert JSON string fields to proper types
-        let mut normalized = Self::normalize_fingerprint(fp);
-
-        // reqwest's SOCKS connector (hyper-util) corrupts its parse buffer
-        // when a proxy splits a handshake reply across TCP segments, so a
-        // socks upstream here can fail even though the proxy is healthy.
-        // Route the geolocation lookup through a temporary local donut-proxy
-        // worker — the same path the browser itself uses — and fall back to
-        // the upstream URL only if the worker can't start. Two exclusions:
-        // no worker when geolocation won't fetch through the proxy at all
-        // (disabled, or a fixed geoip IP), and none for loopback socks URLs —
-        // launch-time callers pass the already-running local worker's
-        // socks5://127.0.0.1 URL, whose single-segment replies don't trigger
-        // the bug, so chaining a second worker would only add latency.
-        let needs_proxied_geo_fetch = !matches!(
-          config.geoip.as_ref(),
-          Some(serde_json::Value::Bool(false)) | Some(serde_json::Value::String(_))
-        );
-        let remote_socks_upstream = config
-          .proxy
-          .as_deref()
-          .filter(|url| Self::is_remote_socks_url(url));
-        let (geo_proxy, temp_worker_id) = match remote_socks_upstream {
-          Some(url) if needs_proxied_geo_fetch => {
-            match crate::proxy_runner::start_proxy_process(Some(url.to_string()), None)
-              .await
-              .map_err(|e| e.to_string())
+      let mut get_result = self
+        .send_cdp_command(&ws_url, "Wayfern.getFingerprint", json!({}))
+        .await;
+
+      // Wayfern samples the WebGL renderer independently of the OS and of the
+      // navigator hardware values it just generated, so re-sample until the GPU
+      // family matches the OS and its tier matches the core count and memory.
+      // Profiles that opt in also re-sample until the quality checks pass.
+      for attempt in 1..=MAX_FINGERPRINT_RESAMPLES {
+        let problem = match &get_result {
+          Ok(result) => {
+            let fp = result.get("fingerprint").unwrap_or(result);
+            if !crate::webgl::webgl_is_plausible(os, fp, config.gpu_tier) {
+              Some("WebGL renderer implausible")
+            } else if reject_inconsistent
+              && crate::fingerprint_quality::validate_fingerprint(Some(os), fp).has_errors()
             {
-              Ok(worker) => {
-                let local_url = format!("http://127.0.0.1:{}", worker.local_port.unwrap_or(0));
-                (Some(local_url), Some(worker.id))
-              }
-              Err(e) => {
-                log::warn!(
-                  "Could not start local proxy worker for geolocation ({e}); using the socks upstream directly"
-                );
-                (config.proxy.clone(), None)
-              }
+              Some("inconsistent fields")
+            } else {
+              None
             }
           }
-          _ => (config.proxy.clone(), None),
+          Err(_) => None,
         };
-
-        // Apply timezone/geolocation for the proxy this fingerprint is being
-        // generated against. Shared with the launch-time location refresh.
-        let geolocation_applied =
-          Self::apply_geolocation(&mut normalized, geo_proxy.as_deref(), config.geoip.as_ref())
-            .await;
-
-        if let Some(worker_id) = temp_worker_id {
-          let _ = crate::proxy_runner::stop_proxy_process(&worker_id).await;
+        let Some(problem) = problem else {
+          break;
+        };
+        if attempt == MAX_FINGERPRINT_RESAMPLES {
+          log::warn!(
+            "Fingerprint for {os} still has {problem} after {MAX_FINGERPRINT_RESAMPLES} re-samples; keeping the last one"
+          );
+          break;
         }
-
-        (normalized, geolocation_applied)
-      }
-      Err(e) => {
-        cleanup().await;
-        return Err(format!("Failed to get fingerprint: {e}").into());
+        log::info!("Re-sampling fingerprint for {os}: {problem} (attempt {attempt})");
+        if let Err(e) = self
+          .send_cdp_command(
+            &ws_url,
+            "Wayfern.refreshFingerprint",
+            refresh_params.clone(),
+          )
+          .await
+        {
+          log::warn!("Failed to re-sample fingerprint: {e}");
+          break;
+        }
+        get_result = self
+          .send_cdp_command(&ws_url, "Wayfern.getFingerprint", json!({}))
+          .await;
       }
-    };
 
-    cleanup().await;
+      let (fingerprint, geolocation_applied) = match get_result {
+        Ok(result) => {
+          // Wayfern.getFingerprint returns { fingerprint: {...} }
+          // We need to extract just the fingerprint object
+          let fp = result.get("fingerprint").cloned().unwrap_or(result);
+          // Normalize the fingerprint: convert JSON string fields to proper types
+          let mut normalized = Self::normalize_fingerprint(fp);
+          // The first sample is seeded by the profile alone so its picks are
+          // the profile's own.
+          let seed = crate::fonts::seed_from_id(&profile.id).wrapping_add(sample as u64);
+          crate::client_hints::apply_client_hints(os, &mut normalized, seed);
+
+          // reqwest's SOCKS connector (hyper-util) corrupts its parse buffer
+          // when a proxy splits a handshake reply across TCP segments, so a
+          // socks upstream here can fail even though the proxy is healthy.
+          // Route the geolocation lookup through a temporary local donut-proxy
+          // worker — the same path the browser itself uses — and fall back to
+          // the upstream URL only if the worker can't start. Two exclusions:
+          // no worker when geolocation won't fetch through the proxy at all
+          // (disabled, or a fixed geoip IP), and none for loopback socks URLs —
+          // launch-time callers pass the already-running local worker's
+          // socks5://127.0.0.1 URL, whose single-segment replies don't trigger
+          // the bug, so chaining a second worker would only add latency.
+          let needs_proxied_geo_fetch = !matches!(
+            config.geoip.as_ref(),
+            Some(serde_json::Value::Bool(false)) | Some(serde_json::Value::String(_))
+          );
+          let remote_socks_upstream = config
+            .proxy
+            .as_deref()
+            .filter(|url| Self::is_remote_socks_url(url));
+          let (geo_proxy, temp_worker_id) = match remote_socks_upstream {
+            Some(url) if needs_proxied_geo_fetch => {
+              match crate::proxy_runner::start_proxy_process(Some(url.to_string()), None)
+                .await
+                .map_err(|e| e.to_string())
+              {
+                Ok(worker) => {
+                  let local_url = format!("http://127.0.0.1:{}", worker.local_port.unwrap_or(0));
+                  (Some(local_url), Some(worker.id))
+                }
+                Err(e) => {
+                  log::warn!(
+                    "Could not start local proxy worker for geolocation ({e}); using the socks upstream directly"
+                  );
+                  (config.proxy.clone(), None)
+                }
+              }
+            }
+            _ => (config.proxy.clone(), None),
+          };
+
+          // Apply timezone/geolocation for the proxy this fingerprint is being
+          // generated against. Shared with the launch-time location refresh.
+          let geolocation_applied = Self::apply_geolocation(
+            &mut normalized,
+            geo_proxy.as_deref(),
+            config.geoip.as_ref(),
+            config.locale_override.as_deref(),
+            config.timezone_override.as_deref(),
+          )
+          .await;
 
-    let fingerprint_json = serde_json::to_string(&fingerprint)
-      .map_err(|e| format!("Failed to serialize fingerprint: {e}"))?;
+          if let Some(worker_id) = temp_worker_id {
+            let _ = crate::proxy_runner::stop_proxy_process(&worker_id).await;
+          }
 
-    log::info!(
-      "Generated Wayfern fingerprint for OS: {}, fields: {:?}",
-      os,
-      fingerprint
-        .as_object()
-        .map(|o| o.keys().collect::<Vec<_>>())
-    );
+          // Fonts follow the locale just applied.
+          let language = normalized
+            .get("language")
+            .and_then(|v| v.as_str())
+            .map(str::to_string);
+          let fonts = crate::fonts::fonts_for_profile(os, language.as_deref(), seed);
+          if let Some(obj) = normalized.as_object_mut().filter(|_| !fonts.is_empty()) {
+            // Stored as a JSON string, the way Wayfern reports it.
+            obj.insert(
+              "fonts".to_string(),
+              json!(serde_json::to_string(&fonts).unwrap_or_default()),
+            );
+          }
+
+          (normalized, geolocation_applied)
+        }
+        Err(e) => {
+          cleanup().await;
+          return Err(format!("Failed to get fingerprint: {e}").into());
+        }
+      };
+
+      let fingerprint_json = match serde_json::to_string(&fingerprint) {
+        Ok(json) => json,
+        Err(e) => {
+          cleanup().await;
+          return Err(format!("Failed to serialize fingerprint: {e}").into());
+        }
+      };
 
-    // Log timezone/geolocation fields specifically for debugging
-    if let Some(obj) = fingerprint.as_object() {
       log::info!(
-        "Generated fingerprint - timezone: {:?}, timezoneOffset: {:?}, latitude: {:?}, longitude: {:?}, language: {:?}",
-        obj.get("timezone"),
-        obj.get("timezoneOffset"),
-        obj.get("latitude"),
-        obj.get("longitude"),
-        obj.get("language")
+        "Generated Wayfern fingerprint for OS: {}, fields: {:?}",
+        os,
+        fingerprint
+          .as_object()
+          .map(|o| o.keys().collect::<Vec<_>>())
       );
+
+      // Log timezone/geolocation fields specifically for debugging
+      if let Some(obj) = fingerprint.as_object() {
+        log::info!(
+          "Generated fingerprint - timezone: {:?}, timezoneOffset: {:?}, latitude: {:?}, longitude: {:?}, language: {:?}",
+          obj.get("timezone"),
+          obj.get("timezoneOffset"),
+          obj.get("latitude"),
+          obj.get("longitude"),
+          obj.get("language")
+        );
+      }
+
+      generated.push((fingerprint_json, geolocation_applied));
     }
 
-    Ok((fingerprint_json, geolocation_applied))
+    cleanup().await;
+
+    Ok(generated)
   }
 
   #[allow(clippy::too_many_arguments)]
@@ -687,21 +1062,20 @@ impl WayfernManager {
     profile: &BrowserProfile,
     profile_path: &str,
     config: &WayfernConfig,
-    url: Option<&str>,
+    startup_urls: &[String],
+    restore_last_session: bool,
     proxy_url: Option<&str>,
     ephemeral: bool,
     extension_paths: &[String],
     remote_debugging_port: Option<u16>,
     headless: bool,
   ) -> Result<WayfernLaunchResult, Box<dyn std::error::Error + Send + Sync>> {
+    let config_started = Instant::now();
     let executable_path = BrowserRunner::instance()
       .get_browser_executable_path(profile)
       .map_err(|e| format!("Failed to get Wayfern executable path: {e}"))?;
 
-    let port = match remote_debugging_port {
-      Some(p) => p,
-      None => Self::find_free_port().await?,
-    };
+    let port = self.allocate_cdp_port(remote_debugging_port).await?;
     log::info!("Launching Wayfern on CDP port {port} (detached)");
 
     // Diagnostic: verify critical profile files and test cookie decryption
@@ -806,12 +1180,10 @@ impl WayfernManager {
       "--disable-session-crashed-bubble".to_string(),
       "--hide-crash-restore-bubble".to_string(),
       "--disable-infobars".to_string(),
-      // Prefetch* / NoStatePrefetch: cross-site Speculation-Rules prefetch uses
-      // an isolated NetworkContext that defaults to DIRECT egress (real host IP
-      // leaks past the per-profile proxy). Disabling via a LAUNCH FLAG cannot be
-      // re-enabled by an imported/synced network_prediction_options pref (which a
-      // compile-time pref default could be).
-      "--disable-features=DialMediaRouteProvider,DnsOverHttps,AsyncDns,Prefetch,PrefetchProxy,SpeculationRulesPrefetchFuture,NoStatePrefetch".to_string(),
+      format!(
+        "--disable-features={}",
+        disabled_features(&profile.dns_mode)
+      ),
       "--use-mock-keychain".to_string(),
       "--password-store=basic".to_string(),
     ];
@@ -832,6 +1204,17 @@ impl WayfernManager {
       args.push("--window-position=0,0".to_string());
     }
 
+    if let Some(languages) = config
+      .fingerprint
+      .as_deref()
+      .and_then(Self::accept_languages_from_fingerprint)
+    {
+      // Keep the UI/Intl locale and the Accept-Language header on the same
+      // language list the fingerprint reports to scripts.
+      args.push(format!("--lang={}", languages[0]));
+      args.push(format!("--accept-lang={}", languages.join(",")));
+    }
+
     #[cfg(target_os = "linux")]
     {
       args.push("--no-sandbox".to_string());
@@ -847,10 +1230,23 @@ impl WayfernManager {
       args.push("--disable-sync".to_string());
     }
 
+    // One argv entry, so paths with spaces need no quoting. Chromium splits
+    // the list on commas; paths containing one are rejected beforehand.
     if !extension_paths.is_empty() {
       args.push(format!("--load-extension={}", extension_paths.join(",")));
     }
 
+    if restore_last_session {
+      args.push("--restore-last-session".to_string());
+    }
+
+    // Keeps the process up once its last window closes, so a stop can park
+    // it in the warm pool instead of ending it.
+    let keep_alive = crate::warm_pool::keeps_alive(profile, headless);
+    if keep_alive {
+      args.push("--keep-alive-for-test".to_string());
+    }
+
     // Per-profile window label + distinct frame color so concurrent profile
     // windows are easy to tell apart. Wayfern reads these in
     // BrowserView::GetWindowTitle() (label) and BrowserFrameView::GetFrameColor()
@@ -930,10 +1326,13 @@ impl WayfernManager {
       args.push(format!("--proxy-pac-url={pac_data}"));
       args.push("--dns-prefetch-disable".to_string());
     }
+    args.extend(dns_mode_args(&profile.dns_mode, proxy_url.is_some()));
+    let webrtc_mode = config.effective_webrtc_mode();
+    args.extend(webrtc_mode_args(webrtc_mode.as_ref()));
 
-    let mut command = TokioCommand::new(&executable_path);
+    let mut limits = crate::resource_limits::LaunchLimits::prepare(profile).await;
+    let mut command = limits.command(&executable_path, &args);
     command
-      .args(&args)
       .stdin(Stdio::null())
       .stdout(Stdio::null())
       .stderr(Stdio::null());
@@ -941,7 +1340,17 @@ impl WayfernManager {
       command.env("WAYFERN_TOKEN", token);
       log::info!("Wayfern authorization configured for browser process");
     }
+    // Chromium's ICU default zone comes from TZ, which covers the places the
+    // fingerprint's timezone doesn't reach (workers, crash-report timestamps).
+    let pinned_timezone = config
+      .timezone_override
+      .as_deref()
+      .and_then(crate::geolocation::parse_timezone);
+    if let Some(timezone) = pinned_timezone {
+      command.env("TZ", timezone);
+    }
 
+    let spawn_started = Instant::now();
     let child = command
       .spawn()
       .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
@@ -954,10 +1363,71 @@ impl WayfernManager {
         format!("Failed to spawn Wayfern: {e}{hint}").into()
       })?;
     let process_id = child.id();
-    drop(child);
+    limits.apply_after_spawn(process_id);
+    crate::profile::restart_supervisor::watch_browser_exit(child);
+    let spawned = Instant::now();
 
     self.wait_for_cdp_ready(port).await?;
+    let resource_limits = limits.report(process_id);
+    if let Some(applied) = &resource_limits {
+      log::info!("Resource limits for profile {}: {applied:?}", profile.name);
+    }
+
+    let used_fingerprint = self
+      .prepare_pages(port, config, startup_urls, restore_last_session)
+      .await?;
+
+    let id = uuid::Uuid::new_v4().to_string();
+    let instance = WayfernInstance {
+      id: id.clone(),
+      process_id,
+      profile_id: Some(profile.id.to_string()),
+      profile_path: Some(profile_path.to_string()),
+      url: startup_urls.first().cloned(),
+      cdp_port: Some(port),
+      keep_alive,
+      parked: false,
+    };
+
+    let mut inner = self.inner.lock().await;
+    inner.reserved_ports.remove(&port);
+    inner.instances.insert(id.clone(), instance);
 
+    Ok(WayfernLaunchResult {
+      id,
+      processId: process_id,
+      profilePath: Some(profile_path.to_string()),
+      url: startup_urls.first().cloned(),
+      cdp_port: Some(port),
+      used_fingerprint,
+      resource_limits,
+      phase_timings: vec![
+        PhaseTiming {
+          phase: LaunchPhase::ConfigBuild,
+          duration_ms: (spawn_started - config_started).as_millis() as u64,
+        },
+        PhaseTiming {
+          phase: LaunchPhase::ProcessSpawn,
+          duration_ms: (spawned - spawn_started).as_millis() as u64,
+        },
+        PhaseTiming {
+          phase: LaunchPhase::CdpReady,
+          duration_ms: spawned.elapsed().as_millis() as u64,
+        },
+      ],
+    })
+  }
+
+  /// Applies the fingerprint to the open pages, opens `startup_urls` and
+  /// clears leftover emulation. Returns the fingerprint Wayfern echoed back.
+  async fn prepare_pages(
+    &self,
+    port: u16,
+    config: &WayfernConfig,
+    startup_urls: &[String],
+    restore_last_session: bool,
+  ) -> Result<Option<String>, Box<dyn std::error::Error + Send + Sync>> {
+    let webrtc_mode = config.effective_webrtc_mode();
     let targets = self.get_cdp_targets(port).await?;
     log::info!("Found {} CDP targets", targets.len());
 
@@ -996,6 +1466,11 @@ impl WayfernManager {
           log::info!("Added default timezoneOffset to fingerprint");
         }
       }
+      // A pinned zone wins over whatever the stored fingerprint carries, so a
+      // profile edited outside the config form still launches in it.
+      if let Some(timezone) = pinned_timezone {
+        Self::apply_timezone(&mut fingerprint, timezone);
+      }
 
       // Denormalize fingerprint for Wayfern CDP (convert arrays/objects to JSON strings)
       let mut fingerprint_for_cdp = Self::denormalize_fingerprint(fingerprint);
@@ -1036,6 +1511,9 @@ impl WayfernManager {
           obj.insert("wayfernToken".to_string(), json!(token));
         }
       }
+      if let Some(obj) = fingerprint_params.as_object_mut() {
+        obj.extend(webrtc_fingerprint_fields(webrtc_mode.as_ref()));
+      }
 
       for target in &page_targets {
         if let Some(ws_url) = &target.websocket_debugger_url {
@@ -1075,19 +1553,29 @@ impl WayfernManager {
 
     // Geolocation is handled internally by the browser binary.
 
-    if let Some(url) = url {
-      log::info!("Navigating to URL via CDP");
-      if let Some(target) = page_targets.first() {
-        if let Some(ws_url) = &target.websocket_debugger_url {
-          if let Err(e) = self
-            .send_cdp_command(ws_url, "Page.navigate", json!({ "url": url }))
-            .await
-          {
-            log::error!("Failed to navigate to URL: {e}");
+    // The first URL reuses the initial blank tab; with session restore that
+    // tab holds a restored page, so every URL gets its own tab instead.
+    let mut remaining_urls = startup_urls.iter();
+    if !restore_last_session {
+      if let Some(url) = remaining_urls.next() {
+        log::info!("Navigating to URL via CDP");
+        if let Some(target) = page_targets.first() {
+          if let Some(ws_url) = &target.websocket_debugger_url {
+            if let Err(e) = self
+              .send_cdp_command(ws_url, "Page.navigate", json!({ "url": url }))
+              .await
+            {
+              log::error!("Failed to navigate to URL: {e}");
+            }
           }
         }
       }
     }
+    for url in remaining_urls {
+      if let Err(e) = self.open_tab_on_port(port, url).await {
+        log::error!("Failed to open startup tab: {e}");
+      }
+    }
 
     for target in &page_targets {
       if let Some(ws_url) = &target.websocket_debugger_url {
@@ -1111,28 +1599,134 @@ impl WayfernManager {
       }
     }
 
-    let id = uuid::Uuid::new_v4().to_string();
-    let instance = WayfernInstance {
-      id: id.clone(),
-      process_id,
-      profile_path: Some(profile_path.to_string()),
-      url: url.map(|s| s.to_string()),
-      cdp_port: Some(port),
+    Ok(used_fingerprint)
+  }
+
+  /// Closes every page of a keep-alive instance and marks it parked, leaving
+  /// the process and its proxy worker running. Returns the instance id and
+  /// PID, or `None` when there is no such instance or a page stayed open
+  /// (e.g. held by a beforeunload prompt).
+  pub async fn park_wayfern(&self, profile_path: &str) -> Option<(String, u32)> {
+    let target_path = std::path::Path::new(profile_path)
+      .canonicalize()
+      .unwrap_or_else(|_| std::path::Path::new(profile_path).to_path_buf());
+    let (id, pid, port) = {
+      let inner = self.inner.lock().await;
+      let instance = inner.instances.values().find(|i| {
+        i.keep_alive
+          && !i.parked
+          && i.profile_path.as_deref().is_some_and(|p| {
+            std::path::Path::new(p)
+              .canonicalize()
+              .unwrap_or_else(|_| std::path::Path::new(p).to_path_buf())
+              == target_path
+          })
+      })?;
+      (
+        instance.id.clone(),
+        instance.process_id?,
+        instance.cdp_port?,
+      )
     };
+    if !crate::process_registry::process_exists(pid) {
+      return None;
+    }
 
-    let mut inner = self.inner.lock().await;
-    inner.instances.insert(id.clone(), instance);
+    let targets = self.get_cdp_targets(port).await.ok()?;
+    for target in targets.iter().filter(|t| t.target_type == "page") {
+      let close_url = format!("http://127.0.0.1:{port}/json/close/{}", target.id);
+      if let Err(e) = self.http_client.get(&close_url).send().await {
+        log::warn!("Failed to close page target {}: {e}", target.id);
+      }
+    }
+
+    // Pages close asynchronously; give them a moment before giving up.
+    for _ in 0..10 {
+      tokio::time::sleep(Duration::from_millis(200)).await;
+      let targets = self.get_cdp_targets(port).await.ok()?;
+      if !targets.iter().any(|t| t.target_type == "page") {
+        let mut inner = self.inner.lock().await;
+        let instance = inner.instances.get_mut(&id)?;
+        instance.parked = true;
+        instance.url = None;
+        log::info!("Parked Wayfern instance {id} (PID: {pid})");
+        return Some((id, pid));
+      }
+    }
+    log::warn!("Wayfern instance {id} still has pages open, not parking it");
+    None
+  }
+
+  /// Opens a window in a parked instance and prepares it the way
+  /// `launch_wayfern` prepares a fresh process.
+  pub async fn revive_wayfern(
+    &self,
+    id: &str,
+    config: &WayfernConfig,
+    startup_urls: &[String],
+  ) -> Result<WayfernLaunchResult, Box<dyn std::error::Error + Send + Sync>> {
+    let started = Instant::now();
+    let (process_id, port, profile_path) = {
+      let inner = self.inner.lock().await;
+      let instance = inner
+        .instances
+        .get(id)
+        .filter(|i| i.parked)
+        .ok_or("Parked Wayfern instance not found")?;
+      (
+        instance.process_id,
+        instance
+          .cdp_port
+          .ok_or("Parked Wayfern instance has no CDP port")?,
+        instance.profile_path.clone(),
+      )
+    };
+
+    self.open_tab_on_port(port, "chrome://newtab/").await?;
+    let used_fingerprint = self
+      .prepare_pages(port, config, startup_urls, false)
+      .await?;
+
+    if let Some(instance) = self.inner.lock().await.instances.get_mut(id) {
+      instance.parked = false;
+      instance.url = startup_urls.first().cloned();
+    }
 
     Ok(WayfernLaunchResult {
-      id,
+      id: id.to_string(),
       processId: process_id,
-      profilePath: Some(profile_path.to_string()),
-      url: url.map(|s| s.to_string()),
+      profilePath: profile_path,
+      url: startup_urls.first().cloned(),
       cdp_port: Some(port),
       used_fingerprint,
+      resource_limits: None,
+      phase_timings: vec![PhaseTiming {
+        phase: LaunchPhase::CdpReady,
+        duration_ms: started.elapsed().as_millis() as u64,
+      }],
     })
   }
 
+  /// Whether a running keep-alive instance still has a page open. `None` once
+  /// it is gone or parked; an unreachable DevTools endpoint counts as open.
+  pub async fn keep_alive_has_pages(&self, id: &str) -> Option<bool> {
+    let port = {
+      let inner = self.inner.lock().await;
+      let instance = inner
+        .instances
+        .get(id)
+        .filter(|i| i.keep_alive && !i.parked)?;
+      instance.cdp_port?
+    };
+    Some(
+      self
+        .get_cdp_targets(port)
+        .await
+        .map(|targets| targets.iter().any(|t| t.target_type == "page"))
+        .unwrap_or(true),
+    )
+  }
+
   pub async fn stop_wayfern(
     &self,
     id: &str,
@@ -1153,7 +1747,7 @@ impl WayfernManager {
           use std::os::windows::process::CommandExt;
           const CREATE_NO_WINDOW: u32 = 0x08000000;
           let _ = std::process::Command::new("taskkill")
-            .args(["/PID", &pid.to_string(), "/F"])
+            .args(["/PID", &pid.to_string(), "/T", "/F"])
             .creation_flags(CREATE_NO_WINDOW)
             .output();
         }
@@ -1179,21 +1773,31 @@ impl WayfernManager {
       .instances
       .values()
       .find(|i| {
-        i.profile_path
-          .as_deref()
-          .map(|p| {
-            std::path::Path::new(p)
-              .canonicalize()
-              .unwrap_or_else(|_| std::path::Path::new(p).to_path_buf())
-              == target_path
-          })
-          .unwrap_or(false)
+        !i.parked
+          && i
+            .profile_path
+            .as_deref()
+            .map(|p| {
+              std::path::Path::new(p)
+                .canonicalize()
+                .unwrap_or_else(|_| std::path::Path::new(p).to_path_buf())
+                == target_path
+            })
+            .unwrap_or(false)
       })
       .and_then(|i| i.cdp_port)
       .ok_or("Wayfern instance (with CDP port) not found for profile")?;
     drop(inner);
 
-    // Open the URL in a new tab via the CDP HTTP convenience endpoint.
+    self.open_tab_on_port(port, url).await
+  }
+
+  /// Opens a URL in a new tab via the CDP HTTP convenience endpoint.
+  async fn open_tab_on_port(
+    &self,
+    port: u16,
+    url: &str,
+  ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     let new_tab_url = format!(
       "http://127.0.0.1:{port}/json/new?{}",
       urlencoding::encode(url)
@@ -1212,13 +1816,35 @@ impl WayfernManager {
     Ok(())
   }
 
+  /// Resolve the browser-level CDP endpoint for a running instance on `port`.
+  pub async fn get_cdp_endpoint(
+    &self,
+    port: u16,
+  ) -> Result<CdpEndpoint, Box<dyn std::error::Error + Send + Sync>> {
+    let http_url = format!("http://127.0.0.1:{port}/json/version");
+    let version: CdpVersion = self
+      .http_client
+      .get(&http_url)
+      .send()
+      .await?
+      .error_for_status()?
+      .json()
+      .await?;
+    Ok(CdpEndpoint {
+      port,
+      http_url,
+      ws_url: version.websocket_debugger_url,
+      user_agent: version.user_agent,
+    })
+  }
+
   pub async fn get_cdp_port(&self, profile_path: &str) -> Option<u16> {
     let inner = self.inner.lock().await;
     let target_path = std::path::Path::new(profile_path)
       .canonicalize()
       .unwrap_or_else(|_| std::path::Path::new(profile_path).to_path_buf());
 
-    for instance in inner.instances.values() {
+    for instance in inner.instances.values().filter(|i| !i.parked) {
       if let Some(path) = &instance.profile_path {
         let instance_path = std::path::Path::new(path)
           .canonicalize()
@@ -1232,8 +1858,16 @@ impl WayfernManager {
   }
 
   pub async fn find_wayfern_by_profile(&self, profile_path: &str) -> Option<WayfernLaunchResult> {
-    use sysinfo::{ProcessRefreshKind, RefreshKind, System};
+    self.find_wayfern_by_profile_in(profile_path, None).await
+  }
 
+  /// Like [`Self::find_wayfern_by_profile`], answering from `snapshot`
+  /// instead of querying the process table when one is given.
+  pub async fn find_wayfern_by_profile_in(
+    &self,
+    profile_path: &str,
+    snapshot: Option<&crate::process_registry::ProcessSnapshot>,
+  ) -> Option<WayfernLaunchResult> {
     let mut inner = self.inner.lock().await;
 
     // Canonicalize the target path for comparison
@@ -1259,12 +1893,14 @@ impl WayfernManager {
     if let Some(id) = found_id {
       if let Some(instance) = inner.instances.get(&id) {
         if let Some(pid) = instance.process_id {
-          let system = System::new_with_specifics(
-            RefreshKind::nothing().with_processes(ProcessRefreshKind::everything()),
-          );
-          let sysinfo_pid = sysinfo::Pid::from_u32(pid);
-
-          if system.process(sysinfo_pid).is_some() {
+          // A browser launched after the snapshot was taken isn't in it yet.
+          let alive = snapshot.is_some_and(|snapshot| snapshot.is_alive(pid))
+            || crate::process_registry::process_exists(pid);
+          if alive && instance.parked {
+            // Held by the warm pool: the process is up, the profile is not.
+            return None;
+          }
+          if alive {
             return Some(WayfernLaunchResult {
               id: id.clone(),
               processId: instance.process_id,
@@ -1272,15 +1908,18 @@ impl WayfernManager {
               url: instance.url.clone(),
               cdp_port: instance.cdp_port,
               used_fingerprint: None,
+              phase_timings: Vec::new(),
+              resource_limits: None,
             });
           } else {
+            // The process we launched is gone, but the profile may have been
+            // reopened outside the app since; fall through to the scan below.
             log::info!(
               "Wayfern process {} for profile {} is no longer running, cleaning up",
               pid,
               profile_path
             );
             inner.instances.remove(&id);
-            return None;
           }
         }
       }
@@ -1288,23 +1927,33 @@ impl WayfernManager {
 
     // If not found in in-memory instances, scan system processes.
     // This handles the case where the GUI was restarted but Wayfern is still running.
-    if let Some((pid, found_profile_path, cdp_port)) =
-      Self::find_wayfern_process_by_profile(&target_path)
-    {
+    let found = match snapshot {
+      Some(snapshot) => snapshot.browser_for(&target_path),
+      None => crate::process_registry::ProcessSnapshot::capture().browser_for(&target_path),
+    };
+    if let Some(crate::process_registry::BrowserProcess { pid, cdp_port }) = found {
+      let found_profile_path = target_path.to_string_lossy().to_string();
       log::info!(
         "Found running Wayfern process (PID: {}) for profile path via system scan",
         pid
       );
 
-      let instance_id = format!("recovered_{}", pid);
+      let instance_id = format!("{RECOVERED_INSTANCE_PREFIX}{pid}");
       inner.instances.insert(
         instance_id.clone(),
         WayfernInstance {
           id: instance_id.clone(),
           process_id: Some(pid),
+          // Profiles live at `<profiles_dir>/<id>/profile`.
+          profile_id: target_path
+            .parent()
+            .and_then(|dir| dir.file_name())
+            .map(|name| name.to_string_lossy().into_owned()),
           profile_path: Some(found_profile_path.clone()),
           url: None,
           cdp_port,
+          keep_alive: false,
+          parked: false,
         },
       );
 
@@ -1315,76 +1964,14 @@ impl WayfernManager {
         url: None,
         cdp_port,
         used_fingerprint: None,
+        phase_timings: Vec::new(),
+        resource_limits: None,
       });
     }
 
     None
   }
 
-  /// Scan system processes to find a Wayfern/Chromium process using a specific profile path
-  fn find_wayfern_process_by_profile(
-    target_path: &std::path::Path,
-  ) -> Option<(u32, String, Option<u16>)> {
-    use sysinfo::{ProcessRefreshKind, RefreshKind, System};
-
-    let system = System::new_with_specifics(
-      RefreshKind::nothing().with_processes(ProcessRefreshKind::everything()),
-    );
-
-    let target_path_str = target_path.to_string_lossy();
-
-    for (pid, process) in system.processes() {
-      let cmd = process.cmd();
-      if cmd.is_empty() {
-        continue;
-      }
-
-      let exe_name = process.name().to_string_lossy().to_lowercase();
-      let is_chromium_like = exe_name.contains("wayfern")
-        || exe_name.contains("chromium")
-        || exe_name.contains("chrome");
-
-      if !is_chromium_like {
-        continue;
-      }
-
-      // Skip child processes (renderer, GPU, utility, zygote, etc.)
-      // Only the main browser process lacks a --type= argument
-      let is_child = cmd
-        .iter()
-        .any(|a| a.to_str().is_some_and(|s| s.starts_with("--type=")));
-      if is_child {
-        continue;
-      }
-
-      let mut matched = false;
-      let mut cdp_port: Option<u16> = None;
-
-      for arg in cmd.iter() {
-        if let Some(arg_str) = arg.to_str() {
-          if let Some(dir_val) = arg_str.strip_prefix("--user-data-dir=") {
-            let cmd_path = std::path::Path::new(dir_val)
-              .canonicalize()
-              .unwrap_or_else(|_| std::path::Path::new(dir_val).to_path_buf());
-            if cmd_path == target_path {
-              matched = true;
-            }
-          }
-
-          if let Some(port_val) = arg_str.strip_prefix("--remote-debugging-port=") {
-            cdp_port = port_val.parse().ok();
-          }
-        }
-      }
-
-      if matched {
-        return Some((pid.as_u32(), target_path_str.to_string(), cdp_port));
-      }
-    }
-
-    None
-  }
-
   #[allow(dead_code)]
   pub async fn launch_wayfern_profile(
     &self,
@@ -1411,7 +1998,8 @@ impl WayfernManager {
         profile,
         &profile_path_str,
         config,
-        url,
+        &url.map(|u| vec![u.to_string()]).unwrap_or_default(),
+        false,
         proxy_url,
         profile.ephemeral,
         &[],
@@ -1421,30 +2009,41 @@ impl WayfernManager {
       .await
   }
 
-  #[allow(dead_code)]
+  /// Drop instances whose browser has exited and release their proxy workers.
+  /// Instances that haven't reported a PID yet are left alone.
   pub async fn cleanup_dead_instances(&self) {
-    use sysinfo::{ProcessRefreshKind, RefreshKind, System};
-
-    let mut inner = self.inner.lock().await;
-    let mut dead_ids = Vec::new();
-
-    let system = System::new_with_specifics(
-      RefreshKind::nothing().with_processes(ProcessRefreshKind::everything()),
-    );
+    let dead_pids: Vec<u32> = {
+      let mut inner = self.inner.lock().await;
+      let mut dead = Vec::new();
+      inner
+        .instances
+        .retain(|id, instance| match instance.process_id {
+          Some(pid) if !crate::process_registry::process_exists(pid) => {
+            log::info!("Cleaning up dead Wayfern instance: {id}");
+            dead.push(pid);
+            false
+          }
+          _ => true,
+        });
+      dead
+    };
 
-    for (id, instance) in &inner.instances {
-      if let Some(pid) = instance.process_id {
-        let pid = sysinfo::Pid::from_u32(pid);
-        if !system.processes().contains_key(&pid) {
-          dead_ids.push(id.clone());
-        }
-      }
+    for pid in dead_pids {
+      crate::proxy_manager::PROXY_MANAGER
+        .release_browser_proxy(pid, None)
+        .await;
     }
+  }
 
-    for id in dead_ids {
-      log::info!("Cleaning up dead Wayfern instance: {id}");
-      inner.instances.remove(&id);
+  /// The PIDs and profiles this launcher still tracks, for proxy cleanup.
+  pub async fn launcher_claims(&self) -> crate::proxy_manager::LauncherClaims {
+    let inner = self.inner.lock().await;
+    let mut claims = crate::proxy_manager::LauncherClaims::default();
+    for instance in inner.instances.values() {
+      claims.pids.extend(instance.process_id);
+      claims.profile_ids.extend(instance.profile_id.clone());
     }
+    claims
   }
 }
 
@@ -1452,10 +2051,184 @@ lazy_static::lazy_static! {
   static ref WAYFERN_MANAGER: WayfernManager = WayfernManager::new();
 }
 
+/// Placeholder profile that only points fingerprint generation at the Wayfern
+/// binary for `version`; it is never saved or launched as a real profile.
+pub fn fingerprint_generation_profile(version: &str) -> BrowserProfile {
+  BrowserProfile {
+    id: uuid::Uuid::new_v4(),
+    name: "temp_fingerprint_gen".to_string(),
+    browser: "wayfern".to_string(),
+    version: version.to_string(),
+    process_id: None,
+    cdp_port: None,
+    proxy_id: None,
+    vpn_id: None,
+    proxy_pool_id: None,
+    launch_hook: None,
+    last_launch: None,
+    release_type: "stable".to_string(),
+    wayfern_config: None,
+    group_id: None,
+    tags: Vec::new(),
+    note: None,
+    window_color: None,
+    sync_mode: crate::profile::types::SyncMode::Disabled,
+    encryption_salt: None,
+    last_sync: None,
+    host_os: None,
+    ephemeral: false,
+    extension_group_id: None,
+    proxy_bypass_rules: Vec::new(),
+    created_by_id: None,
+    created_by_email: None,
+    dns_blocklist: None,
+    password_protected: false,
+    clear_on_close: false,
+    startup_urls: Vec::new(),
+    session_restore: Default::default(),
+    preference_overrides: Default::default(),
+    metadata: Default::default(),
+    pin_browser_version: false,
+    restart_policy: Default::default(),
+    dns_mode: Default::default(),
+    exit_mismatch_action: Default::default(),
+    resource_limits: Default::default(),
+    applied_resource_limits: None,
+    archived: false,
+    created_at: None,
+    updated_at: None,
+  }
+}
+
+/// Generate `count` fingerprints without creating a profile. `version` defaults
+/// to the newest downloaded Wayfern; nothing is downloaded here.
+pub async fn generate_fingerprints(
+  app_handle: &AppHandle,
+  version: Option<&str>,
+  config: &WayfernConfig,
+  count: usize,
+) -> Result<Vec<serde_json::Value>, String> {
+  if count == 0 || count > MAX_GENERATED_FINGERPRINTS {
+    return Err(format!(
+      "count must be between 1 and {MAX_GENERATED_FINGERPRINTS}"
+    ));
+  }
+  let version = match version {
+    Some(v) if !v.is_empty() && v != "latest" => v.to_string(),
+    _ => crate::downloaded_browsers_registry::DownloadedBrowsersRegistry::instance()
+      .get_latest_downloaded_version("wayfern")
+      .ok_or_else(|| "No downloaded version of Wayfern is available".to_string())?,
+  };
+  let profile = fingerprint_generation_profile(&version);
+  let generated = WayfernManager::instance()
+    .generate_fingerprint_configs(app_handle, &profile, config, count)
+    .await
+    .map_err(|e| format!("Failed to generate fingerprint: {e}"))?;
+  generated
+    .into_iter()
+    .map(|(fingerprint, _geolocation_applied)| {
+      serde_json::from_str(&fingerprint).map_err(|e| format!("Invalid fingerprint JSON: {e}"))
+    })
+    .collect()
+}
+
+/// Generate one or more fingerprints for `config` without creating a profile.
+#[tauri::command]
+pub async fn generate_fingerprint(
+  app_handle: AppHandle,
+  version: Option<String>,
+  config: WayfernConfig,
+  count: Option<usize>,
+) -> Result<Vec<serde_json::Value>, String> {
+  generate_fingerprints(&app_handle, version.as_deref(), &config, count.unwrap_or(1)).await
+}
+
 /// Deterministically derive a pleasant, distinct window frame color from a
 /// profile id so concurrent profile windows are visually distinguishable even
 /// when the user has not picked a custom color. Stable per profile (same id
 /// always yields the same color). Returns "#RRGGBB".
+/// Features every launch turns off. Prefetch* / NoStatePrefetch: cross-site
+/// Speculation-Rules prefetch uses an isolated NetworkContext that defaults to
+/// DIRECT egress (real host IP leaks past the per-profile proxy). Disabling via
+/// a LAUNCH FLAG cannot be re-enabled by an imported/synced
+/// network_prediction_options pref (which a compile-time pref default could
+/// be). Chromium's own resolver (AsyncDns) and DoH stay off unless the profile
+/// asked for DoH.
+fn disabled_features(dns_mode: &DnsMode) -> String {
+  let mut features = vec!["DialMediaRouteProvider"];
+  if !matches!(dns_mode, DnsMode::Doh { .. }) {
+    features.extend(["DnsOverHttps", "AsyncDns"]);
+  }
+  features.extend([
+    "Prefetch",
+    "PrefetchProxy",
+    "SpeculationRulesPrefetchFuture",
+    "NoStatePrefetch",
+  ]);
+  features.join(",")
+}
+
+/// Launch flags for the profile's DNS mode. `ProxyRemote` maps every hostname
+/// to NOTFOUND for the local resolver, so a lookup that would bypass the proxy
+/// fails instead of leaking; proxied requests are unaffected because Chromium
+/// hands the proxy the hostname. The local proxy itself is an IP literal and
+/// stays reachable. DoH runs Chromium's resolver in secure mode (no fallback
+/// to the OS) against the configured template.
+fn dns_mode_args(dns_mode: &DnsMode, proxied: bool) -> Vec<String> {
+  match dns_mode {
+    DnsMode::System => Vec::new(),
+    DnsMode::ProxyRemote if !proxied => {
+      log::warn!("DNS mode is proxy_remote but the profile has no proxy; using system DNS");
+      Vec::new()
+    }
+    DnsMode::ProxyRemote => {
+      vec!["--host-resolver-rules=MAP * ~NOTFOUND , EXCLUDE 127.0.0.1".to_string()]
+    }
+    DnsMode::Doh { resolver_url } => {
+      // Feature params are `/`-separated, so the template must be escaped.
+      let template: String =
+        url::form_urlencoded::byte_serialize(resolver_url.as_bytes()).collect();
+      vec![format!(
+        "--enable-features=AsyncDns,DnsOverHttps:Fallback/false/Templates/{template}"
+      )]
+    }
+  }
+}
+
+/// Launch flags for the profile's WebRTC mode. Every mode keeps WebRTC off
+/// non-proxied UDP; Chromium has no switch that removes or rewrites peer
+/// connections, so the rest travels with the fingerprint (see
+/// [`webrtc_fingerprint_fields`]).
+fn webrtc_mode_args(mode: Option<&WebRtcMode>) -> Vec<String> {
+  match mode {
+    None => Vec::new(),
+    Some(_) => vec!["--force-webrtc-ip-handling-policy=disable_non_proxied_udp".to_string()],
+  }
+}
+
+/// Fields added to `Wayfern.setFingerprint` for the profile's WebRTC mode.
+fn webrtc_fingerprint_fields(
+  mode: Option<&WebRtcMode>,
+) -> serde_json::Map<String, serde_json::Value> {
+  let mut fields = serde_json::Map::new();
+  match mode {
+    None => {}
+    Some(WebRtcMode::Disabled) => {
+      fields.insert("webrtcMode".to_string(), json!("disabled"));
+    }
+    Some(WebRtcMode::RelayOnly) => {
+      fields.insert("webrtcMode".to_string(), json!("relay_only"));
+    }
+    Some(WebRtcMode::Mock { ip }) => {
+      fields.insert("webrtcMode".to_string(), json!("mock"));
+      if let Some(ip) = ip {
+        fields.insert("webrtcPublicIp".to_string(), json!(ip));
+      }
+    }
+  }
+  fields
+}
+
 pub fn derive_profile_color(id: &uuid::Uuid) -> String {
   // FNV-1a over the 16 id bytes -> hue in [0,360). The hue varies per profile
   // while saturation/lightness are fixed to a pastel band (see below).
@@ -1492,6 +2265,55 @@ fn hsl_to_rgb(h: f64, s: f64, l: f64) -> (u8, u8, u8) {
 mod tests {
   use super::*;
 
+  #[tokio::test]
+  async fn launcher_claims_cover_pending_instances() {
+    let manager = WayfernManager::new();
+    {
+      let mut inner = manager.inner.lock().await;
+      for (id, pid) in [("running", Some(4242)), ("pending", None)] {
+        inner.instances.insert(
+          id.to_string(),
+          WayfernInstance {
+            id: id.to_string(),
+            process_id: pid,
+            profile_id: Some(format!("prof_{id}")),
+            profile_path: None,
+            url: None,
+            cdp_port: None,
+            keep_alive: false,
+            parked: false,
+          },
+        );
+      }
+    }
+
+    let claims = manager.launcher_claims().await;
+    assert_eq!(claims.pids, [4242].into_iter().collect());
+    assert!(claims.profile_ids.contains("prof_running"));
+    assert!(claims.profile_ids.contains("prof_pending"));
+
+    // An instance without a PID is never treated as dead.
+    manager.cleanup_dead_instances().await;
+    assert!(manager.inner.lock().await.instances.contains_key("pending"));
+  }
+
+  #[test]
+  fn recovered_instances_are_flagged() {
+    let mut result = WayfernLaunchResult {
+      id: "4f1c".to_string(),
+      processId: Some(4242),
+      profilePath: None,
+      url: None,
+      cdp_port: None,
+      used_fingerprint: None,
+      phase_timings: Vec::new(),
+      resource_limits: None,
+    };
+    assert!(!result.is_recovered());
+    result.id = format!("{RECOVERED_INSTANCE_PREFIX}4242");
+    assert!(result.is_recovered());
+  }
+
   #[test]
   fn remote_socks_url_detection() {
     // Remote socks upstreams (the hyper-util-affected case) are detected...
@@ -1561,6 +2383,85 @@ mod tests {
     );
   }
 
+  #[test]
+  fn accept_languages_follow_fingerprint_languages() {
+    let fp = r#"{"language":"de-DE","languages":["de-DE","de"]}"#;
+    assert_eq!(
+      WayfernManager::accept_languages_from_fingerprint(fp),
+      Some(vec!["de-DE".to_string(), "de".to_string()])
+    );
+
+    let legacy = r#"{"fingerprint":{"languages":"fr-FR, fr"}}"#;
+    assert_eq!(
+      WayfernManager::accept_languages_from_fingerprint(legacy),
+      Some(vec!["fr-FR".to_string(), "fr".to_string()])
+    );
+
+    let language_only = r#"{"language":"ja-JP"}"#;
+    assert_eq!(
+      WayfernManager::accept_languages_from_fingerprint(language_only),
+      Some(vec!["ja-JP".to_string()])
+    );
+
+    assert_eq!(
+      WayfernManager::accept_languages_from_fingerprint("{}"),
+      None
+    );
+  }
+
+  #[tokio::test]
+  async fn locale_override_applies_even_without_geolocation() {
+    let mut fp = json!({ "language": "en-US", "languages": ["en-US", "en"] });
+    let applied = WayfernManager::apply_geolocation(
+      &mut fp,
+      None,
+      Some(&serde_json::Value::Bool(false)),
+      Some("pt_BR"),
+      None,
+    )
+    .await;
+    assert!(!applied);
+    assert_eq!(fp["language"], json!("pt-BR"));
+    assert_eq!(fp["languages"], json!(["pt-BR", "pt"]));
+  }
+
+  #[tokio::test]
+  async fn timezone_override_applies_even_without_geolocation() {
+    let mut fp = json!({ "timezone": "America/New_York", "timezoneOffset": 300 });
+    let applied = WayfernManager::apply_geolocation(
+      &mut fp,
+      None,
+      Some(&serde_json::Value::Bool(false)),
+      None,
+      Some("asia/kolkata"),
+    )
+    .await;
+    assert!(!applied);
+    assert_eq!(fp["timezone"], json!("Asia/Kolkata"));
+    assert_eq!(fp["timezoneOffset"], json!(-330));
+  }
+
+  #[test]
+  fn locale_override_list_keeps_navigator_and_headers_in_agreement() {
+    let prefs = crate::geolocation::parse_locale_list("de-DE, en-US").unwrap();
+    let stored =
+      r#"{"fingerprint":{"language":"en-US","languages":["en-US","en"],"userAgent":"x"}}"#;
+    assert!(!WayfernManager::fingerprint_matches_locale(stored, &prefs));
+
+    let updated = WayfernManager::apply_locale_to_fingerprint(stored, &prefs).unwrap();
+    assert!(WayfernManager::fingerprint_matches_locale(&updated, &prefs));
+    let parsed: serde_json::Value = serde_json::from_str(&updated).unwrap();
+    assert_eq!(parsed["fingerprint"]["userAgent"], json!("x"));
+    assert_eq!(parsed["fingerprint"]["language"], json!("de-DE"));
+
+    // The Accept-Language list passed on launch is navigator.languages, led
+    // by navigator.language.
+    let accept = WayfernManager::accept_languages_from_fingerprint(&updated).unwrap();
+    assert_eq!(json!(accept), parsed["fingerprint"]["languages"]);
+    assert_eq!(accept[0], "de-DE");
+    assert_eq!(accept, vec!["de-DE", "de", "en-US", "en"]);
+  }
+
   #[test]
   fn window_size_none_when_missing_or_invalid() {
     // No dimensions at all.
@@ -1586,4 +2487,83 @@ mod tests {
       None
     );
   }
+
+  #[test]
+  fn dns_mode_launch_args() {
+    let system = DnsMode::System;
+    assert!(dns_mode_args(&system, true).is_empty());
+    assert!(disabled_features(&system).contains("DnsOverHttps,AsyncDns"));
+
+    let remote = DnsMode::ProxyRemote;
+    assert_eq!(
+      dns_mode_args(&remote, true),
+      vec!["--host-resolver-rules=MAP * ~NOTFOUND , EXCLUDE 127.0.0.1"]
+    );
+    // Without a proxy there is nowhere to resolve remotely; blocking every
+    // lookup would just break the profile.
+    assert!(dns_mode_args(&remote, false).is_empty());
+    assert!(disabled_features(&remote).contains("AsyncDns"));
+
+    let doh = DnsMode::Doh {
+      resolver_url: "https://dns.example/dns-query".to_string(),
+    };
+    assert_eq!(
+      dns_mode_args(&doh, false),
+      vec![
+        "--enable-features=AsyncDns,DnsOverHttps:Fallback/false/Templates/https%3A%2F%2Fdns.example%2Fdns-query"
+      ]
+    );
+    let features = disabled_features(&doh);
+    assert!(!features.contains("DnsOverHttps"));
+    assert!(!features.contains("AsyncDns"));
+    assert!(features.contains("NoStatePrefetch"));
+  }
+
+  #[test]
+  fn webrtc_mode_launch_args_and_fields() {
+    assert!(webrtc_mode_args(None).is_empty());
+    assert!(webrtc_fingerprint_fields(None).is_empty());
+
+    let relay = WebRtcMode::RelayOnly;
+    assert_eq!(
+      webrtc_mode_args(Some(&relay)),
+      vec!["--force-webrtc-ip-handling-policy=disable_non_proxied_udp"]
+    );
+    assert_eq!(
+      webrtc_fingerprint_fields(Some(&relay)).get("webrtcMode"),
+      Some(&json!("relay_only"))
+    );
+
+    let mock = WebRtcMode::Mock {
+      ip: Some("203.0.113.7".to_string()),
+    };
+    let fields = webrtc_fingerprint_fields(Some(&mock));
+    assert_eq!(fields.get("webrtcMode"), Some(&json!("mock")));
+    assert_eq!(fields.get("webrtcPublicIp"), Some(&json!("203.0.113.7")));
+  }
+
+  #[test]
+  fn webrtc_mode_serializes_tagged_and_honours_block_webrtc() {
+    let mode: WebRtcMode = serde_json::from_str(r#"{"mode":"mock"}"#).unwrap();
+    assert_eq!(mode, WebRtcMode::Mock { ip: None });
+    assert_eq!(
+      serde_json::to_value(WebRtcMode::RelayOnly).unwrap(),
+      json!({ "mode": "relay_only" })
+    );
+
+    let legacy = WayfernConfig {
+      block_webrtc: Some(true),
+      ..Default::default()
+    };
+    assert_eq!(legacy.effective_webrtc_mode(), Some(WebRtcMode::Disabled));
+    let explicit = WayfernConfig {
+      block_webrtc: Some(true),
+      webrtc_mode: Some(WebRtcMode::RelayOnly),
+      ..Default::default()
+    };
+    assert_eq!(
+      explicit.effective_webrtc_mode(),
+      Some(WebRtcMode::RelayOnly)
+    );
+  }
 }
@@ -0,0 +1,297 @@
+//! Plausibility constraints for the WebGL vendor/renderer Wayfern samples into
+//! a fingerprint. Wayfern picks the GPU string independently of the OS and of
+//! the navigator hardware values, so an unconstrained sample can hand a macOS
+//! fingerprint an `ANGLE (NVIDIA ... Direct3D11 ...)` renderer — something no
+//! real Mac reports and a one-line check for any anti-bot script. Generation
+//! re-samples until the renderer passes [`webgl_is_plausible`].
+
+use serde::{Deserialize, Serialize};
+
+/// Rough GPU class, used to keep the renderer in line with the fingerprint's
+/// `hardwareConcurrency` and `deviceMemory`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GpuTier {
+  Low,
+  Mid,
+  High,
+}
+
+impl GpuTier {
+  /// The tier a machine with these navigator values most plausibly has.
+  /// Either value may be missing; with neither there is nothing to match.
+  pub fn from_navigator(
+    hardware_concurrency: Option<u64>,
+    device_memory: Option<f64>,
+  ) -> Option<Self> {
+    match (hardware_concurrency, device_memory) {
+      (None, None) => None,
+      (cores, memory) => {
+        let cores = cores.unwrap_or(8);
+        let memory = memory.unwrap_or(8.0);
+        Some(if cores <= 4 || memory < 8.0 {
+          GpuTier::Low
+        } else if cores >= 12 && memory >= 8.0 {
+          GpuTier::High
+        } else {
+          GpuTier::Mid
+        })
+      }
+    }
+  }
+
+  /// Classify a renderer string. `None` when the family isn't recognised, in
+  /// which case the tier check is skipped rather than guessed.
+  pub fn from_renderer(renderer: &str) -> Option<Self> {
+    let r = renderer.to_lowercase();
+
+    const HIGH: &[&str] = &[
+      "rtx 30",
+      "rtx 40",
+      "rtx 50",
+      "rtx a",
+      "rx 68",
+      "rx 69",
+      "rx 78",
+      "rx 79",
+      "rx 9070",
+      " max",
+      " ultra",
+      "radeon pro w",
+    ];
+    const LOW: &[&str] = &[
+      "intel(r) hd",
+      "intel hd",
+      "uhd graphics",
+      "llvmpipe",
+      "swiftshader",
+      "mesa intel",
+      "gt 710",
+      "gt 730",
+      "gt 1030",
+      "mali",
+      "powervr",
+    ];
+    const MID: &[&str] = &[
+      "rtx 20",
+      "gtx",
+      "rx 5",
+      "rx 6",
+      "rx 7",
+      "iris",
+      "apple m",
+      "arc",
+      "radeon pro",
+      "radeon(tm) graphics",
+      "adreno",
+    ];
+
+    if HIGH.iter().any(|k| r.contains(k)) {
+      Some(GpuTier::High)
+    } else if LOW.iter().any(|k| r.contains(k)) {
+      Some(GpuTier::Low)
+    } else if MID.iter().any(|k| r.contains(k)) {
+      Some(GpuTier::Mid)
+    } else {
+      None
+    }
+  }
+}
+
+/// Whether the vendor/renderer pair belongs to a GPU family real devices on
+/// `os` report through Chromium:
+/// - macOS / iOS: Apple GPUs (or the Intel/AMD parts older Macs shipped),
+///   always through Metal/OpenGL — never Direct3D or Vulkan.
+/// - Windows: ANGLE on Direct3D 11 (or 9 on very old machines).
+/// - Linux: Mesa, NVIDIA's proprietary driver, or a software rasterizer —
+///   never Direct3D or Metal.
+/// - Android: mobile GPUs (Adreno, Mali, PowerVR, Xclipse).
+///
+/// Unknown OS values are accepted unchanged.
+pub fn renderer_family_allowed(os: &str, vendor: &str, renderer: &str) -> bool {
+  let v = vendor.to_lowercase();
+  let r = renderer.to_lowercase();
+  let d3d = r.contains("direct3d") || r.contains("d3d11") || r.contains("d3d9");
+
+  match os {
+    "macos" => {
+      !d3d
+        && !r.contains("vulkan")
+        && !r.contains("nvidia")
+        && (r.contains("apple")
+          || r.contains("metal")
+          || r.contains("opengl engine")
+          || v.contains("apple"))
+    }
+    "ios" => r.contains("apple") || v.contains("apple"),
+    "windows" => d3d,
+    "linux" => {
+      !d3d
+        && !r.contains("metal")
+        && !r.contains("apple")
+        && (r.contains("mesa")
+          || r.contains("nvidia")
+          || r.contains("llvmpipe")
+          || r.contains("opengl")
+          || r.contains("vulkan")
+          || v.contains("mesa")
+          || v.contains("nvidia"))
+    }
+    "android" => ["adreno", "mali", "powervr", "xclipse"]
+      .iter()
+      .any(|k| r.contains(k)),
+    _ => true,
+  }
+}
+
+/// Whether the renderer's tier is within one step of `tier`. A low-end
+/// navigator with a flagship GPU (or the reverse) is the combination worth
+/// rejecting; adjacent tiers are common on real hardware.
+pub fn renderer_matches_tier(renderer: &str, tier: GpuTier) -> bool {
+  match GpuTier::from_renderer(renderer) {
+    Some(renderer_tier) => (renderer_tier as i8 - tier as i8).abs() <= 1,
+    None => true,
+  }
+}
+
+/// Check a generated fingerprint's WebGL identity against its OS and the
+/// navigator values it already carries. `gpu_tier` overrides the tier derived
+/// from `hardwareConcurrency`/`deviceMemory`. Fingerprints without WebGL
+/// fields pass — there is nothing to contradict.
+pub fn webgl_is_plausible(
+  os: &str,
+  fingerprint: &serde_json::Value,
+  gpu_tier: Option<GpuTier>,
+) -> bool {
+  let vendor = fingerprint
+    .get("webglVendor")
+    .and_then(|v| v.as_str())
+    .unwrap_or("");
+  let Some(renderer) = fingerprint.get("webglRenderer").and_then(|v| v.as_str()) else {
+    return true;
+  };
+
+  if !renderer_family_allowed(os, vendor, renderer) {
+    return false;
+  }
+
+  let tier = gpu_tier.or_else(|| {
+    GpuTier::from_navigator(
+      fingerprint
+        .get("hardwareConcurrency")
+        .and_then(|v| v.as_u64()),
+      fingerprint.get("deviceMemory").and_then(|v| v.as_f64()),
+    )
+  });
+  tier.is_none_or(|tier| renderer_matches_tier(renderer, tier))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use serde_json::json;
+
+  const NVIDIA_D3D: &str =
+    "ANGLE (NVIDIA, NVIDIA GeForce RTX 3080 Direct3D11 vs_5_0 ps_5_0, D3D11)";
+  const APPLE_M2: &str = "ANGLE (Apple, ANGLE Metal Renderer: Apple M2, Unspecified Version)";
+  const MESA_INTEL: &str = "ANGLE (Intel, Mesa Intel(R) UHD Graphics 620 (KBL GT2), OpenGL 4.6)";
+
+  #[test]
+  fn mac_never_accepts_direct3d() {
+    assert!(!renderer_family_allowed(
+      "macos",
+      "Google Inc. (NVIDIA)",
+      NVIDIA_D3D
+    ));
+    assert!(renderer_family_allowed(
+      "macos",
+      "Google Inc. (Apple)",
+      APPLE_M2
+    ));
+    assert!(!renderer_family_allowed(
+      "macos",
+      "Google Inc. (Intel)",
+      MESA_INTEL
+    ));
+  }
+
+  #[test]
+  fn windows_requires_d3d_and_linux_rejects_it() {
+    assert!(renderer_family_allowed(
+      "windows",
+      "Google Inc. (NVIDIA)",
+      NVIDIA_D3D
+    ));
+    assert!(!renderer_family_allowed(
+      "windows",
+      "Google Inc. (Apple)",
+      APPLE_M2
+    ));
+    assert!(renderer_family_allowed(
+      "linux",
+      "Google Inc. (Intel)",
+      MESA_INTEL
+    ));
+    assert!(!renderer_family_allowed(
+      "linux",
+      "Google Inc. (NVIDIA)",
+      NVIDIA_D3D
+    ));
+  }
+
+  #[test]
+  fn tier_follows_navigator_values() {
+    assert_eq!(
+      GpuTier::from_navigator(Some(4), Some(4.0)),
+      Some(GpuTier::Low)
+    );
+    assert_eq!(
+      GpuTier::from_navigator(Some(8), Some(8.0)),
+      Some(GpuTier::Mid)
+    );
+    assert_eq!(
+      GpuTier::from_navigator(Some(16), Some(8.0)),
+      Some(GpuTier::High)
+    );
+    assert_eq!(GpuTier::from_navigator(None, None), None);
+  }
+
+  #[test]
+  fn tier_mismatch_is_only_rejected_two_steps_apart() {
+    assert!(!renderer_matches_tier(NVIDIA_D3D, GpuTier::Low));
+    assert!(renderer_matches_tier(NVIDIA_D3D, GpuTier::Mid));
+    assert!(renderer_matches_tier(MESA_INTEL, GpuTier::Mid));
+    assert!(!renderer_matches_tier(MESA_INTEL, GpuTier::High));
+    assert!(renderer_matches_tier("Some Unknown GPU", GpuTier::Low));
+  }
+
+  #[test]
+  fn plausibility_combines_family_and_tier() {
+    let mac_with_d3d = json!({
+      "webglVendor": "Google Inc. (NVIDIA)",
+      "webglRenderer": NVIDIA_D3D,
+      "hardwareConcurrency": 8,
+      "deviceMemory": 8
+    });
+    assert!(!webgl_is_plausible("macos", &mac_with_d3d, None));
+
+    let low_end_windows_with_flagship = json!({
+      "webglVendor": "Google Inc. (NVIDIA)",
+      "webglRenderer": NVIDIA_D3D,
+      "hardwareConcurrency": 4,
+      "deviceMemory": 4
+    });
+    assert!(!webgl_is_plausible(
+      "windows",
+      &low_end_windows_with_flagship,
+      None
+    ));
+    assert!(webgl_is_plausible(
+      "windows",
+      &low_end_windows_with_flagship,
+      Some(GpuTier::High)
+    ));
+
+    assert!(webgl_is_plausible("macos", &json!({}), None));
+  }
+}
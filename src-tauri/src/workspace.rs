@@ -0,0 +1,761 @@
+//! Whole-workspace export for moving to another machine without a sync
+//! server. The archive is a gzipped tar holding `workspace.json` (profile
+//! metadata, groups, tags, extensions, extension groups, proxy pools and the
+//! portable part of the app settings), the extension packages under
+//! `extensions/<id>` and, when asked for, each profile's browser data under
+//! `profiles/<id>/profile/`. Stored proxies and VPN configs carry
+//! credentials, so they are sealed with a key derived from a passphrase the
+//! user picks at export time.
+//!
+//! Import never replaces an entity that already exists locally unless
+//! `force` is set, so importing the same archive twice is a no-op.
+
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::HashSet;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use crate::extension_manager::{Extension, ExtensionGroup, EXTENSION_MANAGER};
+use crate::group_manager::{ProfileGroup, GROUP_MANAGER};
+use crate::profile::{BrowserProfile, ProfileManager};
+use crate::proxy_manager::{ProxyPool, StoredProxy, PROXY_MANAGER};
+use crate::settings_manager::{AppSettings, SettingsManager};
+use crate::sync::encryption;
+use crate::vpn::{VpnConfig, VPN_STORAGE};
+
+const MANIFEST_FILE: &str = "workspace.json";
+const FORMAT_VERSION: u32 = 1;
+/// Unpacked next to the profiles dir so browser data moves in with a rename.
+const IMPORT_STAGING_DIR: &str = ".workspace-import";
+
+/// Settings that describe this machine or this installation rather than the
+/// user's preferences: the API/MCP servers and their tokens, the default
+/// browser registration, one-shot UI flags and OS-level shortcuts.
+const MACHINE_LOCAL_SETTINGS: &[&str] = &[
+  "set_as_default_browser",
+  "api_enabled",
+  "api_port",
+  "api_listen",
+  "api_token",
+  "api_rate_limit_per_second",
+  "api_rate_limit_burst",
+  "api_max_concurrent_launches",
+  "api_max_body_bytes",
+  "first_launch_timestamp",
+  "commercial_trial_acknowledged",
+  "mcp_enabled",
+  "mcp_port",
+  "mcp_token",
+  "mcp_stdio_enabled",
+  "window_resize_warning_dismissed",
+  "onboarding_completed",
+  "profile_shortcuts",
+  "offline_mode",
+];
+
+#[derive(Debug, Serialize, Deserialize)]
+struct WorkspaceManifest {
+  format_version: u32,
+  /// Epoch seconds.
+  exported_at: u64,
+  app_version: String,
+  includes_browser_data: bool,
+  profiles: Vec<BrowserProfile>,
+  groups: Vec<ProfileGroup>,
+  tags: Vec<String>,
+  extensions: Vec<Extension>,
+  extension_groups: Vec<ExtensionGroup>,
+  proxy_pools: Vec<ProxyPool>,
+  settings: Map<String, Value>,
+  secrets_salt: String,
+  /// [`WorkspaceSecrets`] sealed with the passphrase key, base64.
+  secrets: String,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct WorkspaceSecrets {
+  proxies: Vec<StoredProxy>,
+  vpn_configs: Vec<VpnConfig>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WorkspaceEntityKind {
+  Profile,
+  Group,
+  Proxy,
+  ProxyPool,
+  VpnConfig,
+  Extension,
+  ExtensionGroup,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkspaceEntity {
+  pub kind: WorkspaceEntityKind,
+  pub id: String,
+  pub name: String,
+}
+
+impl WorkspaceEntity {
+  fn new(kind: WorkspaceEntityKind, id: impl Into<String>, name: &str) -> Self {
+    Self {
+      kind,
+      id: id.into(),
+      name: name.to_string(),
+    }
+  }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkspaceImportFailure {
+  #[serde(flatten)]
+  pub entity: WorkspaceEntity,
+  pub error: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WorkspaceImportReport {
+  pub imported: Vec<WorkspaceEntity>,
+  /// Already present locally and left alone because `force` wasn't set.
+  pub skipped: Vec<WorkspaceEntity>,
+  pub failed: Vec<WorkspaceImportFailure>,
+  /// The archive's settings replace local ones only with `force`.
+  pub settings_applied: bool,
+}
+
+impl WorkspaceImportReport {
+  /// Whether `entity` should be written; records the skip otherwise.
+  fn admit(&mut self, entity: &WorkspaceEntity, exists: bool, force: bool) -> bool {
+    if exists && !force {
+      self.skipped.push(entity.clone());
+      return false;
+    }
+    true
+  }
+
+  fn finish(&mut self, entity: WorkspaceEntity, result: Result<(), String>) {
+    match result {
+      Ok(()) => self.imported.push(entity),
+      Err(error) => {
+        log::warn!(
+          "Workspace import of {:?} {} failed: {error}",
+          entity.kind,
+          entity.id
+        );
+        self.failed.push(WorkspaceImportFailure { entity, error });
+      }
+    }
+  }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkspaceExportSummary {
+  pub path: String,
+  pub profiles: usize,
+  pub groups: usize,
+  pub proxies: usize,
+  pub proxy_pools: usize,
+  pub vpn_configs: usize,
+  pub extensions: usize,
+  pub includes_browser_data: bool,
+  pub size_bytes: u64,
+}
+
+fn code(code: &str) -> String {
+  serde_json::json!({ "code": code }).to_string()
+}
+
+fn passphrase_key(passphrase: &str, salt: &str) -> Result<[u8; 32], String> {
+  if passphrase.is_empty() {
+    return Err(code("WORKSPACE_PASSPHRASE_REQUIRED"));
+  }
+  encryption::derive_profile_key(passphrase, salt)
+}
+
+fn seal_secrets(secrets: &WorkspaceSecrets, passphrase: &str) -> Result<(String, String), String> {
+  use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+  let salt = encryption::generate_salt();
+  let key = passphrase_key(passphrase, &salt)?;
+  let json = serde_json::to_vec(secrets).map_err(|e| format!("Failed to encode secrets: {e}"))?;
+  let sealed = encryption::encrypt_bytes(&key, &json)?;
+  Ok((salt, BASE64.encode(sealed)))
+}
+
+fn open_secrets(
+  manifest: &WorkspaceManifest,
+  passphrase: &str,
+) -> Result<WorkspaceSecrets, String> {
+  use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+  let key = passphrase_key(passphrase, &manifest.secrets_salt)?;
+  let sealed = BASE64
+    .decode(&manifest.secrets)
+    .map_err(|_| code("WORKSPACE_ARCHIVE_INVALID"))?;
+  let json =
+    encryption::decrypt_bytes(&key, &sealed).map_err(|_| code("WORKSPACE_PASSPHRASE_INVALID"))?;
+  serde_json::from_slice(&json).map_err(|_| code("WORKSPACE_ARCHIVE_INVALID"))
+}
+
+fn portable_settings(settings: &AppSettings) -> Result<Map<String, Value>, String> {
+  let Value::Object(mut map) =
+    serde_json::to_value(settings).map_err(|e| format!("Failed to encode settings: {e}"))?
+  else {
+    return Ok(Map::new());
+  };
+  map.retain(|key, _| !MACHINE_LOCAL_SETTINGS.contains(&key.as_str()));
+  Ok(map)
+}
+
+/// `local` with the archive's portable settings laid over it.
+fn merge_settings(
+  local: &AppSettings,
+  exported: &Map<String, Value>,
+) -> Result<AppSettings, String> {
+  let mut merged =
+    serde_json::to_value(local).map_err(|e| format!("Failed to encode settings: {e}"))?;
+  if let Value::Object(map) = &mut merged {
+    for (key, value) in exported {
+      if !MACHINE_LOCAL_SETTINGS.contains(&key.as_str()) {
+        map.insert(key.clone(), value.clone());
+      }
+    }
+  }
+  serde_json::from_value(merged).map_err(|e| format!("Invalid settings in archive: {e}"))
+}
+
+fn is_running(profile: &BrowserProfile) -> bool {
+  profile
+    .process_id
+    .is_some_and(crate::proxy_storage::is_process_running)
+}
+
+fn append_bytes<W: Write>(tar: &mut tar::Builder<W>, name: &str, data: &[u8]) -> io::Result<()> {
+  let mut header = tar::Header::new_gnu();
+  header.set_size(data.len() as u64);
+  header.set_mode(0o600);
+  header.set_mtime(crate::proxy_manager::now_secs());
+  header.set_cksum();
+  tar.append_data(&mut header, name, data)
+}
+
+fn export_workspace_blocking(
+  path: &Path,
+  passphrase: &str,
+  include_browser_data: bool,
+) -> Result<WorkspaceExportSummary, String> {
+  let profile_manager = ProfileManager::instance();
+  let profiles = profile_manager
+    .list_profiles()
+    .map_err(|e| format!("Failed to list profiles: {e}"))?;
+  // Copying the data dir of a running browser would catch it mid-write.
+  if include_browser_data {
+    if let Some(running) = profiles.iter().find(|p| is_running(p)) {
+      return Err(
+        serde_json::json!({ "code": "PROFILE_RUNNING", "params": { "name": running.name } })
+          .to_string(),
+      );
+    }
+  }
+
+  // Cloud proxies belong to the account and come back on sign-in.
+  let proxies: Vec<StoredProxy> = PROXY_MANAGER
+    .get_stored_proxies()
+    .into_iter()
+    .filter(|p| !p.is_cloud_managed && !p.is_cloud_derived)
+    .collect();
+  let vpn_configs = {
+    let storage = VPN_STORAGE.lock().unwrap();
+    let listed = storage
+      .list_configs()
+      .map_err(|e| format!("Failed to list VPN configs: {e}"))?;
+    listed
+      .iter()
+      .map(|config| storage.load_config(&config.id))
+      .collect::<Result<Vec<_>, _>>()
+      .map_err(|e| format!("Failed to read VPN config: {e}"))?
+  };
+  let groups = GROUP_MANAGER
+    .lock()
+    .unwrap()
+    .get_all_groups()
+    .map_err(|e| format!("Failed to list groups: {e}"))?;
+  let tags = crate::tag_manager::TAG_MANAGER
+    .lock()
+    .unwrap()
+    .get_all_tags()
+    .map_err(|e| format!("Failed to list tags: {e}"))?;
+  let (extensions, extension_groups, payloads) = {
+    let manager = EXTENSION_MANAGER.lock().unwrap();
+    let extensions = manager
+      .list_extensions()
+      .map_err(|e| format!("Failed to list extensions: {e}"))?;
+    let extension_groups = manager
+      .list_groups()
+      .map_err(|e| format!("Failed to list extension groups: {e}"))?;
+    let mut payloads = Vec::new();
+    for ext in &extensions {
+      match manager.read_sync_payload(ext) {
+        Ok(Some(data)) => payloads.push((ext.id.clone(), data)),
+        Ok(None) => log::warn!("Extension {} has no package to export", ext.id),
+        Err(e) => return Err(format!("Failed to read extension {}: {e}", ext.id)),
+      }
+    }
+    (extensions, extension_groups, payloads)
+  };
+  let proxy_pools = PROXY_MANAGER.get_proxy_pools();
+  let settings = SettingsManager::instance()
+    .load_settings()
+    .map_err(|e| format!("Failed to load settings: {e}"))?;
+
+  let mut summary = WorkspaceExportSummary {
+    path: path.to_string_lossy().to_string(),
+    profiles: profiles.len(),
+    groups: groups.len(),
+    proxies: proxies.len(),
+    proxy_pools: proxy_pools.len(),
+    vpn_configs: vpn_configs.len(),
+    extensions: extensions.len(),
+    includes_browser_data: include_browser_data,
+    size_bytes: 0,
+  };
+  let (secrets_salt, secrets) = seal_secrets(
+    &WorkspaceSecrets {
+      proxies,
+      vpn_configs,
+    },
+    passphrase,
+  )?;
+  let manifest = WorkspaceManifest {
+    format_version: FORMAT_VERSION,
+    exported_at: crate::proxy_manager::now_secs(),
+    app_version: env!("CARGO_PKG_VERSION").to_string(),
+    includes_browser_data: include_browser_data,
+    profiles,
+    groups,
+    tags,
+    extensions,
+    extension_groups,
+    proxy_pools,
+    settings: portable_settings(&settings)?,
+    secrets_salt,
+    secrets,
+  };
+  let manifest_json = serde_json::to_vec_pretty(&manifest)
+    .map_err(|e| format!("Failed to encode workspace manifest: {e}"))?;
+
+  let profiles_dir = profile_manager.get_profiles_dir();
+  let partial_path = PathBuf::from(format!("{}.part", path.display()));
+  let written = (|| -> io::Result<()> {
+    let file = io::BufWriter::new(fs::File::create(&partial_path)?);
+    let mut tar = tar::Builder::new(GzEncoder::new(file, flate2::Compression::default()));
+    tar.follow_symlinks(false);
+    append_bytes(&mut tar, MANIFEST_FILE, &manifest_json)?;
+    for (id, data) in &payloads {
+      append_bytes(&mut tar, &format!("extensions/{id}"), data)?;
+    }
+    if include_browser_data {
+      // Ephemeral profiles keep their data outside the profiles dir and
+      // start empty anyway.
+      for profile in manifest.profiles.iter().filter(|p| !p.ephemeral) {
+        let data_dir = profile.get_profile_data_path(&profiles_dir);
+        if data_dir.is_dir() {
+          let name = Path::new("profiles")
+            .join(profile.id.to_string())
+            .join("profile");
+          crate::profile::snapshots::append_dir(&mut tar, &data_dir, &name, false)?;
+        }
+      }
+    }
+    tar.into_inner()?.finish()?.flush()?;
+    fs::rename(&partial_path, path)
+  })();
+  if let Err(e) = written {
+    let _ = fs::remove_file(&partial_path);
+    return Err(format!("Failed to write workspace archive: {e}"));
+  }
+
+  summary.size_bytes = fs::metadata(path).map(|m| m.len()).unwrap_or(0);
+  Ok(summary)
+}
+
+fn read_manifest(staging: &Path) -> Result<WorkspaceManifest, String> {
+  let json = fs::read_to_string(staging.join(MANIFEST_FILE))
+    .map_err(|_| code("WORKSPACE_ARCHIVE_INVALID"))?;
+  let version = serde_json::from_str::<Value>(&json)
+    .ok()
+    .and_then(|v| v.get("format_version").and_then(Value::as_u64))
+    .ok_or_else(|| code("WORKSPACE_ARCHIVE_INVALID"))?;
+  if version > u64::from(FORMAT_VERSION) {
+    return Err(code("WORKSPACE_ARCHIVE_UNSUPPORTED"));
+  }
+  serde_json::from_str(&json).map_err(|_| code("WORKSPACE_ARCHIVE_INVALID"))
+}
+
+/// Moves the archived browser data of `profile` over its local data dir.
+fn restore_browser_data(staging: &Path, profile: &BrowserProfile) -> Result<(), String> {
+  let archived = staging
+    .join("profiles")
+    .join(profile.id.to_string())
+    .join("profile");
+  if !archived.is_dir() {
+    return Ok(());
+  }
+  let data_dir = profile.get_profile_data_path(&ProfileManager::instance().get_profiles_dir());
+  if let Some(parent) = data_dir.parent() {
+    fs::create_dir_all(parent).map_err(|e| format!("Failed to create profile dir: {e}"))?;
+  }
+  if data_dir.exists() {
+    fs::remove_dir_all(&data_dir)
+      .map_err(|e| format!("Failed to remove current profile data: {e}"))?;
+  }
+  fs::rename(&archived, &data_dir).map_err(|e| format!("Failed to restore profile data: {e}"))?;
+  crate::profile::disk_usage::invalidate_usage(&profile.id.to_string());
+  Ok(())
+}
+
+fn import_profile(
+  staging: &Path,
+  mut profile: BrowserProfile,
+  local: Option<&BrowserProfile>,
+) -> Result<(), String> {
+  if local.is_some_and(is_running) {
+    return Err(code("PROFILE_RUNNING"));
+  }
+  profile.process_id = None;
+  profile.cdp_port = None;
+  profile.applied_resource_limits = None;
+  profile.last_sync = None;
+  restore_browser_data(staging, &profile)?;
+  ProfileManager::instance()
+    .save_profile(&profile)
+    .map_err(|e| format!("Failed to save profile: {e}"))
+}
+
+fn import_extension(staging: &Path, mut ext: Extension) -> Result<(), String> {
+  let manager = EXTENSION_MANAGER.lock().unwrap();
+  let payload = staging.join("extensions").join(&ext.id);
+  if payload.is_file() {
+    let data = fs::read(&payload).map_err(|e| format!("Failed to read extension package: {e}"))?;
+    manager
+      .write_sync_payload(&mut ext, &data)
+      .map_err(|e| format!("Failed to write extension package: {e}"))?;
+  }
+  ext.last_sync = None;
+  manager
+    .upsert_extension_internal(&ext)
+    .map_err(|e| format!("Failed to save extension: {e}"))
+}
+
+fn import_settings(exported: &Map<String, Value>) -> Result<(), String> {
+  let manager = SettingsManager::instance();
+  let local = manager
+    .load_settings()
+    .map_err(|e| format!("Failed to load settings: {e}"))?;
+  let mut settings = merge_settings(&local, exported)?;
+  settings.api_token = None;
+  settings.mcp_token = None;
+  manager
+    .save_settings(&settings)
+    .map_err(|e| format!("Failed to save settings: {e}"))?;
+  crate::launch_queue::LAUNCH_QUEUE.set_limit(settings.max_concurrent_launches);
+  crate::sync::bandwidth::apply_limits(&settings);
+  let _ = crate::events::emit_empty("saved-views-changed");
+  Ok(())
+}
+
+fn import_entities(
+  staging: &Path,
+  manifest: WorkspaceManifest,
+  secrets: WorkspaceSecrets,
+  force: bool,
+) -> WorkspaceImportReport {
+  use WorkspaceEntityKind as Kind;
+  let mut report = WorkspaceImportReport::default();
+
+  // Referenced entities first, so profiles never point at something missing.
+  let local_ids: HashSet<String> = EXTENSION_MANAGER
+    .lock()
+    .unwrap()
+    .list_extensions()
+    .unwrap_or_default()
+    .into_iter()
+    .map(|e| e.id)
+    .collect();
+  for ext in manifest.extensions {
+    let entity = WorkspaceEntity::new(Kind::Extension, &ext.id, &ext.name);
+    if report.admit(&entity, local_ids.contains(&ext.id), force) {
+      report.finish(entity, import_extension(staging, ext));
+    }
+  }
+
+  let local_ids: HashSet<String> = EXTENSION_MANAGER
+    .lock()
+    .unwrap()
+    .list_groups()
+    .unwrap_or_default()
+    .into_iter()
+    .map(|g| g.id)
+    .collect();
+  for mut group in manifest.extension_groups {
+    let entity = WorkspaceEntity::new(Kind::ExtensionGroup, &group.id, &group.name);
+    if report.admit(&entity, local_ids.contains(&group.id), force) {
+      group.last_sync = None;
+      let result = EXTENSION_MANAGER
+        .lock()
+        .unwrap()
+        .upsert_group_internal(&group)
+        .map_err(|e| format!("Failed to save extension group: {e}"));
+      report.finish(entity, result);
+    }
+  }
+
+  let local_ids: HashSet<String> = GROUP_MANAGER
+    .lock()
+    .unwrap()
+    .get_all_groups()
+    .unwrap_or_default()
+    .into_iter()
+    .map(|g| g.id)
+    .collect();
+  for mut group in manifest.groups {
+    let entity = WorkspaceEntity::new(Kind::Group, &group.id, &group.name);
+    if report.admit(&entity, local_ids.contains(&group.id), force) {
+      group.last_sync = None;
+      let result = GROUP_MANAGER
+        .lock()
+        .unwrap()
+        .upsert_group_internal(&group)
+        .map_err(|e| format!("Failed to save group: {e}"));
+      report.finish(entity, result);
+    }
+  }
+
+  for mut proxy in secrets.proxies {
+    let entity = WorkspaceEntity::new(Kind::Proxy, &proxy.id, &proxy.name);
+    let exists = PROXY_MANAGER.get_stored_proxy(&proxy.id).is_some();
+    if report.admit(&entity, exists, force) {
+      proxy.last_sync = None;
+      report.finish(entity, PROXY_MANAGER.import_stored_proxy(proxy));
+    }
+  }
+
+  let local_ids: HashSet<String> = VPN_STORAGE
+    .lock()
+    .unwrap()
+    .list_configs()
+    .unwrap_or_default()
+    .into_iter()
+    .map(|c| c.id)
+    .collect();
+  for mut config in secrets.vpn_configs {
+    let entity = WorkspaceEntity::new(Kind::VpnConfig, &config.id, &config.name);
+    if report.admit(&entity, local_ids.contains(&config.id), force) {
+      config.last_sync = None;
+      let result = VPN_STORAGE
+        .lock()
+        .unwrap()
+        .save_config(&config)
+        .map_err(|e| format!("Failed to save VPN config: {e}"));
+      report.finish(entity, result);
+    }
+  }
+
+  for mut pool in manifest.proxy_pools {
+    let entity = WorkspaceEntity::new(Kind::ProxyPool, &pool.id, &pool.name);
+    let exists = PROXY_MANAGER.get_proxy_pool(&pool.id).is_some();
+    if report.admit(&entity, exists, force) {
+      pool.last_sync = None;
+      report.finish(entity, PROXY_MANAGER.import_proxy_pool(pool));
+    }
+  }
+
+  let local_profiles = ProfileManager::instance()
+    .list_profiles()
+    .unwrap_or_default();
+  for profile in manifest.profiles {
+    let entity = WorkspaceEntity::new(Kind::Profile, profile.id.to_string(), &profile.name);
+    let local = local_profiles.iter().find(|p| p.id == profile.id);
+    if report.admit(&entity, local.is_some(), force) {
+      report.finish(entity, import_profile(staging, profile, local));
+    }
+  }
+
+  if force {
+    match import_settings(&manifest.settings) {
+      Ok(()) => report.settings_applied = true,
+      Err(e) => log::warn!("Workspace import could not apply settings: {e}"),
+    }
+  }
+
+  report
+}
+
+fn import_workspace_blocking(
+  path: &Path,
+  passphrase: &str,
+  force: bool,
+) -> Result<WorkspaceImportReport, String> {
+  let staging = crate::app_dirs::data_dir().join(IMPORT_STAGING_DIR);
+  let _ = fs::remove_dir_all(&staging);
+  let file = fs::File::open(path).map_err(|e| format!("Failed to open workspace archive: {e}"))?;
+  if tar::Archive::new(GzDecoder::new(io::BufReader::new(file)))
+    .unpack(&staging)
+    .is_err()
+  {
+    let _ = fs::remove_dir_all(&staging);
+    return Err(code("WORKSPACE_ARCHIVE_INVALID"));
+  }
+
+  let result = read_manifest(&staging).and_then(|manifest| {
+    // Checked before anything is written, so a typo changes nothing.
+    let secrets = open_secrets(&manifest, passphrase)?;
+    Ok(import_entities(&staging, manifest, secrets, force))
+  });
+  let _ = fs::remove_dir_all(&staging);
+  let report = result?;
+
+  let profiles = ProfileManager::instance()
+    .list_profiles()
+    .unwrap_or_default();
+  if let Err(e) = crate::tag_manager::TAG_MANAGER
+    .lock()
+    .unwrap()
+    .rebuild_from_profiles(&profiles)
+  {
+    log::warn!("Failed to rebuild tags after workspace import: {e}");
+  }
+  if !report.imported.is_empty() {
+    for event in [
+      "profiles-changed",
+      "groups-changed",
+      "stored-proxies-changed",
+      "proxy-pools-changed",
+      "vpn-configs-changed",
+      "extensions-changed",
+    ] {
+      let _ = crate::events::emit_empty(event);
+    }
+  }
+  Ok(report)
+}
+
+/// Writes the workspace archive to `path`. Browser data is only included
+/// with `include_browser_data`, which requires every profile to be stopped.
+#[tauri::command]
+pub async fn export_workspace(
+  path: String,
+  passphrase: String,
+  include_browser_data: bool,
+) -> Result<WorkspaceExportSummary, String> {
+  tokio::task::spawn_blocking(move || {
+    export_workspace_blocking(Path::new(&path), &passphrase, include_browser_data)
+  })
+  .await
+  .map_err(|e| format!("Export task failed: {e}"))?
+}
+
+/// Imports a workspace archive. Entities whose IDs already exist are
+/// reported as skipped unless `force` is set, in which case they (and the
+/// portable settings) are replaced by the archived copies.
+#[tauri::command]
+pub async fn import_workspace(
+  path: String,
+  passphrase: String,
+  force: bool,
+) -> Result<WorkspaceImportReport, String> {
+  tokio::task::spawn_blocking(move || {
+    import_workspace_blocking(Path::new(&path), &passphrase, force)
+  })
+  .await
+  .map_err(|e| format!("Import task failed: {e}"))?
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn manifest_with(salt: String, secrets: String) -> WorkspaceManifest {
+    WorkspaceManifest {
+      format_version: FORMAT_VERSION,
+      exported_at: 0,
+      app_version: String::new(),
+      includes_browser_data: false,
+      profiles: Vec::new(),
+      groups: Vec::new(),
+      tags: Vec::new(),
+      extensions: Vec::new(),
+      extension_groups: Vec::new(),
+      proxy_pools: Vec::new(),
+      settings: Map::new(),
+      secrets_salt: salt,
+      secrets,
+    }
+  }
+
+  #[test]
+  fn secrets_open_only_with_the_export_passphrase() {
+    let secrets = WorkspaceSecrets {
+      proxies: vec![StoredProxy::new(
+        "office".to_string(),
+        crate::browser::ProxySettings {
+          proxy_type: "http".to_string(),
+          host: "10.0.0.1".to_string(),
+          port: 8080,
+          username: Some("user".to_string()),
+          password: Some("secret".to_string()),
+          client_cert_path: None,
+          client_key_path: None,
+        },
+      )],
+      vpn_configs: Vec::new(),
+    };
+    let (salt, sealed) = seal_secrets(&secrets, "correct horse").unwrap();
+    assert!(!sealed.contains("secret"));
+    let manifest = manifest_with(salt, sealed);
+
+    let opened = open_secrets(&manifest, "correct horse").unwrap();
+    assert_eq!(opened.proxies.len(), 1);
+    assert_eq!(
+      opened.proxies[0].proxy_settings.password.as_deref(),
+      Some("secret")
+    );
+    let wrong = open_secrets(&manifest, "wrong").unwrap_err();
+    assert!(wrong.contains("WORKSPACE_PASSPHRASE_INVALID"));
+    let empty = open_secrets(&manifest, "").unwrap_err();
+    assert!(empty.contains("WORKSPACE_PASSPHRASE_REQUIRED"));
+  }
+
+  #[test]
+  fn machine_local_settings_stay_local() {
+    let exported = AppSettings {
+      theme: "dark".to_string(),
+      api_enabled: true,
+      api_port: 1,
+      onboarding_completed: true,
+      max_concurrent_launches: 7,
+      ..Default::default()
+    };
+    let portable = portable_settings(&exported).unwrap();
+    assert!(portable.contains_key("theme"));
+    assert!(!portable.contains_key("api_enabled"));
+    assert!(!portable.contains_key("api_token"));
+
+    let local = AppSettings {
+      theme: "light".to_string(),
+      api_port: 10108,
+      ..Default::default()
+    };
+    let mut tampered = portable.clone();
+    tampered.insert("api_port".to_string(), Value::from(2));
+    let merged = merge_settings(&local, &tampered).unwrap();
+    assert_eq!(merged.theme, "dark");
+    assert_eq!(merged.max_concurrent_launches, 7);
+    assert_eq!(merged.api_port, 10108);
+    assert!(!merged.api_enabled);
+    assert!(!merged.onboarding_completed);
+  }
+}
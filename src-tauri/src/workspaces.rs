@@ -0,0 +1,337 @@
+//! Workspaces: named, fully separate sets of profiles, proxies, settings and
+//! the rest of the app data, e.g. one per client. The default workspace is
+//! the root data dir itself so existing installs keep working untouched;
+//! every other one lives under `<root>/workspaces/<id>`. The registry is a
+//! small `workspaces.json` in the root data dir.
+//!
+//! Every manager singleton derives its paths from `app_dirs` when it is
+//! first used, so switching records the new active workspace and restarts
+//! the app rather than trying to reinitialize them in place.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+pub const DEFAULT_WORKSPACE_ID: &str = "default";
+const REGISTRY_FILE: &str = "workspaces.json";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Workspace {
+  pub id: String,
+  pub name: String,
+  /// Use the browser binaries of the default workspace instead of keeping
+  /// a separate copy.
+  #[serde(default)]
+  pub shared_binaries: bool,
+  #[serde(default)]
+  pub created_at: u64,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct WorkspaceRegistry {
+  #[serde(default)]
+  active: Option<String>,
+  #[serde(default)]
+  workspaces: Vec<Workspace>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct WorkspaceList {
+  pub active: String,
+  /// The default workspace first, then the others in creation order.
+  pub workspaces: Vec<Workspace>,
+}
+
+fn registry_path(root: &Path) -> PathBuf {
+  root.join(REGISTRY_FILE)
+}
+
+fn load_registry(root: &Path) -> WorkspaceRegistry {
+  let path = registry_path(root);
+  let Ok(content) = std::fs::read_to_string(&path) else {
+    return WorkspaceRegistry::default();
+  };
+  serde_json::from_str(&content).unwrap_or_else(|e| {
+    log::warn!("Ignoring unreadable {}: {e}", path.display());
+    WorkspaceRegistry::default()
+  })
+}
+
+fn save_registry(root: &Path, registry: &WorkspaceRegistry) -> Result<(), String> {
+  std::fs::create_dir_all(root).map_err(|e| format!("Failed to create data dir: {e}"))?;
+  let content = serde_json::to_string_pretty(registry)
+    .map_err(|e| format!("Failed to serialize workspaces: {e}"))?;
+  let path = registry_path(root);
+  let tmp = path.with_extension("json.tmp");
+  std::fs::write(&tmp, content).map_err(|e| format!("Failed to write workspaces: {e}"))?;
+  std::fs::rename(&tmp, &path).map_err(|e| format!("Failed to write workspaces: {e}"))
+}
+
+/// The active workspace recorded under `root`, or `None` for the default
+/// one. An id that no longer resolves falls back to the default workspace.
+pub fn load_active(root: &Path) -> Option<Workspace> {
+  let registry = load_registry(root);
+  let active = registry.active?;
+  let workspace = registry.workspaces.into_iter().find(|w| w.id == active);
+  if workspace.is_none() && active != DEFAULT_WORKSPACE_ID {
+    log::warn!("Active workspace {active} is not registered, using the default workspace");
+  }
+  workspace
+}
+
+fn default_workspace() -> Workspace {
+  Workspace {
+    id: DEFAULT_WORKSPACE_ID.to_string(),
+    name: "Default".to_string(),
+    shared_binaries: true,
+    created_at: 0,
+  }
+}
+
+fn active_id() -> String {
+  crate::app_dirs::active_workspace()
+    .map(|w| w.id.clone())
+    .unwrap_or_else(|| DEFAULT_WORKSPACE_ID.to_string())
+}
+
+/// Browser versions that profiles in other workspaces still run from the
+/// shared binaries dir. Empty when the active workspace keeps its own.
+pub fn shared_binary_versions_elsewhere() -> HashSet<(String, String)> {
+  if crate::app_dirs::active_workspace().is_some_and(|w| !w.shared_binaries) {
+    return HashSet::new();
+  }
+  let root = crate::app_dirs::root_data_dir();
+  let active = active_id();
+  let mut data_dirs = Vec::new();
+  if active != DEFAULT_WORKSPACE_ID {
+    data_dirs.push(root.clone());
+  }
+  for workspace in load_registry(&root).workspaces {
+    if workspace.shared_binaries && workspace.id != active {
+      data_dirs.push(crate::app_dirs::workspace_data_dir(&root, &workspace.id));
+    }
+  }
+  data_dirs
+    .iter()
+    .flat_map(|dir| profile_versions(&dir.join("profiles")))
+    .collect()
+}
+
+/// `(browser, version)` of every profile stored under `profiles_dir`, read
+/// straight from the metadata files since that workspace isn't loaded.
+fn profile_versions(profiles_dir: &Path) -> Vec<(String, String)> {
+  let Ok(entries) = std::fs::read_dir(profiles_dir) else {
+    return Vec::new();
+  };
+  entries
+    .flatten()
+    .filter_map(|entry| std::fs::read_to_string(entry.path().join("metadata.json")).ok())
+    .filter_map(|content| serde_json::from_str::<serde_json::Value>(&content).ok())
+    .filter_map(|meta| {
+      Some((
+        meta.get("browser")?.as_str()?.to_string(),
+        meta.get("version")?.as_str()?.to_string(),
+      ))
+    })
+    .collect()
+}
+
+fn create_workspace_in(
+  root: &Path,
+  name: &str,
+  shared_binaries: bool,
+) -> Result<Workspace, String> {
+  let name = name.trim();
+  if name.is_empty() {
+    return Err(serde_json::json!({ "code": "NAME_CANNOT_BE_EMPTY" }).to_string());
+  }
+  let mut registry = load_registry(root);
+  let taken = name.eq_ignore_ascii_case(&default_workspace().name)
+    || registry
+      .workspaces
+      .iter()
+      .any(|w| w.name.eq_ignore_ascii_case(name));
+  if taken {
+    return Err(
+      serde_json::json!({ "code": "WORKSPACE_NAME_EXISTS", "params": { "name": name } })
+        .to_string(),
+    );
+  }
+
+  let workspace = Workspace {
+    id: uuid::Uuid::new_v4().to_string(),
+    name: name.to_string(),
+    shared_binaries,
+    created_at: std::time::SystemTime::now()
+      .duration_since(std::time::UNIX_EPOCH)
+      .map(|d| d.as_secs())
+      .unwrap_or(0),
+  };
+  std::fs::create_dir_all(crate::app_dirs::workspace_data_dir(root, &workspace.id))
+    .map_err(|e| format!("Failed to create workspace directory: {e}"))?;
+  registry.workspaces.push(workspace.clone());
+  save_registry(root, &registry)?;
+  Ok(workspace)
+}
+
+fn list_workspaces_in(root: &Path, active: String) -> WorkspaceList {
+  let mut workspaces = vec![default_workspace()];
+  workspaces.extend(load_registry(root).workspaces);
+  WorkspaceList { active, workspaces }
+}
+
+/// Everything that has to be stopped before the data dir can change under
+/// it: running profiles and the API/MCP servers.
+async fn switch_blockers() -> Option<serde_json::Value> {
+  let running_profiles: Vec<String> = crate::profile::ProfileManager::instance()
+    .list_profiles()
+    .unwrap_or_default()
+    .into_iter()
+    .filter(|p| p.process_id.is_some() && !p.is_cross_os())
+    .map(|p| p.name)
+    .collect();
+  let api_server = matches!(
+    crate::api_server::get_api_server_status().await,
+    Ok(Some(_))
+  );
+  let mcp_server = crate::mcp_server::McpServer::instance().is_running();
+
+  if running_profiles.is_empty() && !api_server && !mcp_server {
+    return None;
+  }
+  Some(serde_json::json!({
+    "running_profiles": running_profiles,
+    "api_server": api_server,
+    "mcp_server": mcp_server,
+  }))
+}
+
+/// Records `workspace_id` as active and hands back the app to restart, or
+/// `None` when it already is the active one.
+async fn switch_workspace_impl(
+  app_handle: crate::app_handle::AppHandle,
+  workspace_id: String,
+) -> Result<Option<tauri::AppHandle>, String> {
+  if workspace_id == active_id() {
+    return Ok(None);
+  }
+  let root = crate::app_dirs::root_data_dir();
+  let mut registry = load_registry(&root);
+  if workspace_id != DEFAULT_WORKSPACE_ID
+    && !registry.workspaces.iter().any(|w| w.id == workspace_id)
+  {
+    return Err(
+      serde_json::json!({ "code": "WORKSPACE_NOT_FOUND", "params": { "id": workspace_id } })
+        .to_string(),
+    );
+  }
+  if let Some(blockers) = switch_blockers().await {
+    return Err(
+      serde_json::json!({ "code": "WORKSPACE_SWITCH_BLOCKED", "params": blockers }).to_string(),
+    );
+  }
+  let Some(handle) = app_handle.tauri() else {
+    return Err("Switching workspaces requires the desktop app".to_string());
+  };
+
+  registry.active = Some(workspace_id.clone());
+  save_registry(&root, &registry)?;
+  Ok(Some(handle.clone()))
+}
+
+#[tauri::command]
+pub async fn list_workspaces() -> Result<WorkspaceList, String> {
+  Ok(list_workspaces_in(
+    &crate::app_dirs::root_data_dir(),
+    active_id(),
+  ))
+}
+
+#[tauri::command]
+pub async fn create_workspace(name: String, shared_binaries: bool) -> Result<Workspace, String> {
+  crate::audit::audited!(
+    crate::audit::AuditActor::Gui,
+    "create",
+    "workspace",
+    None,
+    create_workspace_in(&crate::app_dirs::root_data_dir(), &name, shared_binaries)
+  )
+}
+
+#[tauri::command]
+pub async fn switch_workspace(
+  app_handle: crate::app_handle::AppHandle,
+  workspace_id: String,
+) -> Result<(), String> {
+  let restart = crate::audit::audited!(
+    crate::audit::AuditActor::Gui,
+    "update",
+    "workspace",
+    Some(workspace_id.clone()),
+    switch_workspace_impl(app_handle, workspace_id.clone()).await
+  )?;
+  let Some(handle) = restart else {
+    return Ok(());
+  };
+  log::info!("Switching to workspace {workspace_id}, restarting");
+  crate::warm_pool::evict_all().await;
+  handle.restart()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn create_rejects_empty_and_duplicate_names() {
+    let tmp = tempfile::tempdir().unwrap();
+    assert!(create_workspace_in(tmp.path(), "  ", false)
+      .unwrap_err()
+      .contains("NAME_CANNOT_BE_EMPTY"));
+    create_workspace_in(tmp.path(), "Client A", true).unwrap();
+    assert!(create_workspace_in(tmp.path(), "client a", false)
+      .unwrap_err()
+      .contains("WORKSPACE_NAME_EXISTS"));
+    assert!(create_workspace_in(tmp.path(), "default", false)
+      .unwrap_err()
+      .contains("WORKSPACE_NAME_EXISTS"));
+  }
+
+  #[test]
+  fn list_puts_default_first_and_active_resolves() {
+    let tmp = tempfile::tempdir().unwrap();
+    let created = create_workspace_in(tmp.path(), "Client A", false).unwrap();
+    assert!(tmp.path().join("workspaces").join(&created.id).is_dir());
+
+    let list = list_workspaces_in(tmp.path(), DEFAULT_WORKSPACE_ID.to_string());
+    let ids: Vec<_> = list.workspaces.iter().map(|w| w.id.as_str()).collect();
+    assert_eq!(ids, [DEFAULT_WORKSPACE_ID, created.id.as_str()]);
+
+    assert!(load_active(tmp.path()).is_none());
+    let mut registry = load_registry(tmp.path());
+    registry.active = Some(created.id.clone());
+    save_registry(tmp.path(), &registry).unwrap();
+    assert_eq!(load_active(tmp.path()).unwrap().id, created.id);
+
+    registry.active = Some("missing".to_string());
+    save_registry(tmp.path(), &registry).unwrap();
+    assert!(load_active(tmp.path()).is_none());
+  }
+
+  #[test]
+  fn profile_versions_reads_metadata() {
+    let tmp = tempfile::tempdir().unwrap();
+    let profile = tmp.path().join("abc");
+    std::fs::create_dir_all(&profile).unwrap();
+    std::fs::write(
+      profile.join("metadata.json"),
+      r#"{"browser":"wayfern","version":"1.2.3","name":"x"}"#,
+    )
+    .unwrap();
+    std::fs::create_dir_all(tmp.path().join("broken")).unwrap();
+    assert_eq!(
+      profile_versions(tmp.path()),
+      vec![("wayfern".to_string(), "1.2.3".to_string())]
+    );
+  }
+}
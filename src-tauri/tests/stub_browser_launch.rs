@@ -0,0 +1,109 @@
+//! Launch and kill a `stub` profile through the real browser runner: local
+//! proxy worker, PID bookkeeping, saved process info and running events.
+//! Run with `cargo test --features test-browser --test stub_browser_launch`.
+
+use donutbrowser_lib::events::{self, DaemonEvent};
+use donutbrowser_lib::test_browser;
+use std::time::Duration;
+use tokio::sync::broadcast::{error::RecvError, Receiver};
+
+const VERSION: &str = "1.0.0";
+
+/// Build the donut-proxy sidecar the runner starts the local proxy with.
+fn ensure_proxy_binary() {
+  let manifest_dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+  let binary = manifest_dir
+    .join("target")
+    .join("debug")
+    .join(if cfg!(windows) {
+      "donut-proxy.exe"
+    } else {
+      "donut-proxy"
+    });
+  let is_current = std::process::Command::new(&binary)
+    .arg("--version")
+    .output()
+    .is_ok_and(|output| {
+      output.status.success()
+        && String::from_utf8_lossy(&output.stdout).trim()
+          == format!("donut-proxy {}", env!("BUILD_VERSION"))
+    });
+  if !is_current {
+    let status = std::process::Command::new("cargo")
+      .args(["build", "--bin", "donut-proxy"])
+      .current_dir(&manifest_dir)
+      .status()
+      .expect("failed to run cargo build");
+    assert!(status.success(), "failed to build donut-proxy");
+  }
+}
+
+async fn wait_for_running_event(events: &mut Receiver<DaemonEvent>, id: &str, running: bool) {
+  tokio::time::timeout(Duration::from_secs(10), async {
+    loop {
+      let event = match events.recv().await {
+        Ok(event) => event,
+        Err(RecvError::Lagged(_)) => continue,
+        Err(RecvError::Closed) => panic!("event channel closed"),
+      };
+      if event.event == "profile-running-changed"
+        && event.payload["id"] == id
+        && event.payload["is_running"] == running
+      {
+        return;
+      }
+    }
+  })
+  .await
+  .unwrap_or_else(|_| panic!("no profile-running-changed event with is_running={running}"));
+}
+
+async fn wait_for_launch_args() -> String {
+  for _ in 0..50 {
+    if let Some(args) = test_browser::last_launch_args(VERSION) {
+      return args;
+    }
+    tokio::time::sleep(Duration::from_millis(100)).await;
+  }
+  panic!("stub browser never recorded its launch arguments");
+}
+
+#[tokio::test]
+async fn stub_profile_launches_through_a_local_proxy_and_is_killed() {
+  let root = tempfile::tempdir().unwrap();
+  std::env::set_var("DONUTBROWSER_DATA_ROOT", root.path());
+  ensure_proxy_binary();
+
+  let mut events = events::subscribe_local();
+  test_browser::install(VERSION).unwrap();
+  let profile = test_browser::create_profile("Stub", VERSION, None).unwrap();
+  let id = profile.id.to_string();
+
+  let launched = test_browser::launch(&profile).await.unwrap();
+  let pid = launched.process_id.expect("launch must record the PID");
+  wait_for_running_event(&mut events, &id, true).await;
+  assert_eq!(
+    test_browser::load_profile(profile.id).unwrap().process_id,
+    Some(pid)
+  );
+  assert!(test_browser::is_running(&launched).await.unwrap());
+  assert!(
+    test_browser::proxy_for_browser(pid).is_some(),
+    "the local proxy must be mapped to the browser PID"
+  );
+
+  let args = wait_for_launch_args().await;
+  assert!(args.contains("--user-data-dir="), "{args}");
+  assert!(
+    args.contains("--proxy-server=http://127.0.0.1:"),
+    "the stub must be pointed at the local proxy: {args}"
+  );
+
+  test_browser::kill(&launched).await.unwrap();
+  wait_for_running_event(&mut events, &id, false).await;
+  assert_eq!(
+    test_browser::load_profile(profile.id).unwrap().process_id,
+    None
+  );
+  assert!(test_browser::proxy_for_browser(pid).is_none());
+}
@@ -136,6 +136,9 @@ fn test_vpn_storage_save_and_load() {
     sync_enabled: false,
     last_sync: None,
     updated_at: None,
+    auto_disconnect: false,
+    expected_country: None,
+    expected_ip_prefix: None,
   };
 
   let save_result = storage.save_config(&config);
@@ -176,6 +179,9 @@ fn test_vpn_storage_list() {
       sync_enabled: false,
       last_sync: None,
       updated_at: None,
+      auto_disconnect: false,
+      expected_country: None,
+      expected_ip_prefix: None,
     };
     storage.save_config(&config).unwrap();
   }
@@ -204,6 +210,9 @@ fn test_vpn_storage_delete() {
     sync_enabled: false,
     last_sync: None,
     updated_at: None,
+    auto_disconnect: false,
+    expected_country: None,
+    expected_ip_prefix: None,
   };
 
   storage.save_config(&config).unwrap();
@@ -493,6 +502,9 @@ fn new_test_vpn_config(name: &str, vpn_type: VpnType, config_data: String) -> Vp
     sync_enabled: false,
     last_sync: None,
     updated_at: None,
+    auto_disconnect: false,
+    expected_country: None,
+    expected_ip_prefix: None,
   }
 }
 
@@ -644,14 +656,36 @@ async fn wait_for_file(
   Err(format!("Timed out waiting for file: {}", path.display()).into())
 }
 
+/// Host routing table as text. The VPN worker is a userspace WireGuard peer
+/// behind a SOCKS5 listener, so bringing it up must never touch this.
+fn routing_table_snapshot() -> Option<String> {
+  if cfg!(target_os = "linux") {
+    std::fs::read_to_string("/proc/net/route").ok()
+  } else if cfg!(target_os = "macos") {
+    std::process::Command::new("netstat")
+      .args(["-rn"])
+      .output()
+      .ok()
+      .map(|output| String::from_utf8_lossy(&output.stdout).into_owned())
+  } else {
+    None
+  }
+}
+
 async fn run_proxy_feature_suite(
   binary_path: &PathBuf,
   vpn_id: &str,
   server_tunnel_ip: &str,
 ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+  let routes_before = routing_table_snapshot();
   let vpn_worker = donutbrowser_lib::vpn_worker_runner::start_vpn_worker(vpn_id)
     .await
     .map_err(|error| error.to_string())?;
+  assert_eq!(
+    routing_table_snapshot(),
+    routes_before,
+    "Starting a VPN worker must leave host routes alone; only the profile's local proxy uses it"
+  );
   let vpn_upstream = vpn_worker
     .local_url
     .clone()
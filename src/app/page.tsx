@@ -2,7 +2,6 @@
 
 import { invoke } from "@tauri-apps/api/core";
 import { listen } from "@tauri-apps/api/event";
-import { getCurrent } from "@tauri-apps/plugin-deep-link";
 import { motion } from "motion/react";
 import { useOnborda } from "onborda";
 import { useCallback, useEffect, useMemo, useRef, useState } from "react";
@@ -42,11 +41,13 @@ import { ProfileSyncDialog } from "@/components/profile-sync-dialog";
 import { ProxyAssignmentDialog } from "@/components/proxy-assignment-dialog";
 import { ProxyManagementDialog } from "@/components/proxy-management-dialog";
 import { type AppPage, RailNav } from "@/components/rail-nav";
+import { SavedViewsMenu } from "@/components/saved-views-menu";
 import { SettingsDialog } from "@/components/settings-dialog";
 import { ShortcutsPage } from "@/components/shortcuts-page";
 import { SyncAllDialog } from "@/components/sync-all-dialog";
 import { SyncConfigDialog } from "@/components/sync-config-dialog";
 import { SyncFollowerDialog } from "@/components/sync-follower-dialog";
+import { TagManagementDialog } from "@/components/tag-management-dialog";
 import { ThankYouDialog } from "@/components/thank-you-dialog";
 import { WayfernConfigDialog } from "@/components/wayfern-config-dialog";
 import { WayfernTermsDialog } from "@/components/wayfern-terms-dialog";
@@ -55,19 +56,29 @@ import { WindowResizeWarningDialog } from "@/components/window-resize-warning-di
 import { useAppUpdateNotifications } from "@/hooks/use-app-update-notifications";
 import { useCloudAuth } from "@/hooks/use-cloud-auth";
 import { useCommercialTrial } from "@/hooks/use-commercial-trial";
+import { useCrashNotifications } from "@/hooks/use-crash-notifications";
 import { useGroupEvents } from "@/hooks/use-group-events";
 import type { PermissionType } from "@/hooks/use-permissions";
 import { usePermissions } from "@/hooks/use-permissions";
 import { useProfileEvents } from "@/hooks/use-profile-events";
 import { useProxyEvents } from "@/hooks/use-proxy-events";
+import { useSavedViews } from "@/hooks/use-saved-views";
 import { useSyncSessions } from "@/hooks/use-sync-session";
 import { useUpdateNotifications } from "@/hooks/use-update-notifications";
 import { useVersionUpdater } from "@/hooks/use-version-updater";
 import { useVpnEvents } from "@/hooks/use-vpn-events";
 import { useWayfernTerms } from "@/hooks/use-wayfern-terms";
-import { translateBackendError } from "@/lib/backend-errors";
+import {
+  isBackendErrorCode,
+  translateBackendError,
+} from "@/lib/backend-errors";
 import { getEntitlements } from "@/lib/entitlements";
 import { MOTION_EASE_OUT } from "@/lib/motion";
+import {
+  matchesMetadata,
+  matchesSavedViewFilter,
+  parseMetadataQuery,
+} from "@/lib/saved-views";
 import {
   ONBOARDING_TOUR_FINISHED_EVENT,
   setOnboardingActive,
@@ -85,7 +96,17 @@ import {
   showSyncProgressToast,
   showToast,
 } from "@/lib/toast-utils";
-import type { BrowserProfile, SyncSettings, WayfernConfig } from "@/types";
+import type {
+  BrowserProfile,
+  BulkDeleteResult,
+  EnsureBinariesSummary,
+  ExitMismatch,
+  ExtensionsLoadFailed,
+  ExtensionUpdateResult,
+  SlowLaunch,
+  SyncSettings,
+  WayfernConfig,
+} from "@/types";
 
 type BrowserTypeString = "wayfern";
 
@@ -98,14 +119,16 @@ export default function Home() {
   const { t } = useTranslation();
   // Mount global version update listener/toasts
   useVersionUpdater();
+  useCrashNotifications();
 
   // Use the new profile events hook for centralized profile management
   const {
     profiles,
+    archivedProfiles,
     runningProfiles,
     isLoading: profilesLoading,
     error: profilesError,
-  } = useProfileEvents();
+  } = useProfileEvents({ includeArchived: true });
 
   // First-run onboarding tour (Onborda).
   const { startOnborda, setCurrentStep, isOnbordaVisible, currentStep } =
@@ -186,6 +209,14 @@ export default function Home() {
     })();
   }, [profilesLoading]);
 
+  useEffect(() => {
+    invoke<boolean>("get_observer_mode")
+      .then(setObserverMode)
+      .catch((err: unknown) => {
+        console.error("Failed to read observer mode:", err);
+      });
+  }, []);
+
   // Advance from the "create a profile" step to the "DNS blocking" step as soon
   // as the user's first profile exists (its DNS dropdown is now in the DOM).
   useEffect(() => {
@@ -202,6 +233,15 @@ export default function Home() {
     error: groupsError,
   } = useGroupEvents();
 
+  const { savedViews } = useSavedViews();
+  const [activeSavedViewId, setActiveSavedViewId] = useState<string | null>(
+    null,
+  );
+  const activeSavedView = useMemo(
+    () => savedViews.find((v) => v.id === activeSavedViewId) ?? null,
+    [savedViews, activeSavedViewId],
+  );
+
   const {
     storedProxies,
     isLoading: proxiesLoading,
@@ -298,6 +338,7 @@ export default function Home() {
     string[]
   >([]);
   const [selectedGroupId, setSelectedGroupId] = useState<string>("__all__");
+  const [showArchived, setShowArchived] = useState(false);
   const [selectedProfilesForGroup, setSelectedProfilesForGroup] = useState<
     string[]
   >([]);
@@ -333,6 +374,9 @@ export default function Home() {
     useState<BrowserProfile | null>(null);
   const [commandPaletteOpen, setCommandPaletteOpen] = useState(false);
   const [aboutDialogOpen, setAboutDialogOpen] = useState(false);
+  const [observerMode, setObserverMode] = useState(false);
+  const [tagManagementDialogOpen, setTagManagementDialogOpen] =
+    useState(false);
   const [consistencyWarning, setConsistencyWarning] = useState<{
     profile: BrowserProfile;
     result: ConsistencyResult;
@@ -349,6 +393,11 @@ export default function Home() {
     setSelectedProfiles([]);
   }, []);
 
+  const handleShowArchivedChange = useCallback((show: boolean) => {
+    setShowArchived(show);
+    setSelectedProfiles([]);
+  }, []);
+
   const handleRailNavigate = useCallback((page: AppPage) => {
     // Always reset every sub-page-able dialog before opening the next one,
     // so navigating from one rail item to another doesn't stack two
@@ -568,28 +617,55 @@ export default function Home() {
 
         console.log(`Downloading missing components: ${missingList}`);
 
-        try {
-          // Download missing binaries and GeoIP database sequentially to prevent conflicts
-          const downloaded = await invoke<string[]>(
-            "ensure_all_binaries_exist",
-          );
-          if (downloaded.length > 0) {
-            console.log(
-              "Successfully downloaded missing components:",
-              downloaded,
+        // Failures get a toast whose action retries just those versions.
+        const ensure = async (
+          only: { browser: string; version: string }[] | null,
+        ) => {
+          try {
+            const summary = await invoke<EnsureBinariesSummary>(
+              "ensure_all_binaries_exist",
+              { only },
+            );
+            if (summary.downloaded.length > 0 || summary.geoip_downloaded) {
+              console.log("Successfully downloaded missing components:", {
+                browsers: summary.downloaded,
+                geoip: summary.geoip_downloaded,
+              });
+            }
+            if (summary.failed.length > 0) {
+              showToast({
+                id: "ensure-binaries-failed",
+                type: "error",
+                title: t("errors.ensureBinariesFailed", {
+                  count: summary.failed.length,
+                }),
+                description: summary.failed
+                  .map(
+                    (f) =>
+                      `${f.browser} ${f.version}: ${translateBackendError(t, f.error)}`,
+                  )
+                  .join("; "),
+                action: {
+                  label: t("common.buttons.retry"),
+                  onClick: () => {
+                    void ensure(summary.failed);
+                  },
+                },
+              });
+            }
+          } catch (downloadError) {
+            console.error(
+              "Failed to download missing components:",
+              downloadError,
             );
           }
-        } catch (downloadError) {
-          console.error(
-            "Failed to download missing components:",
-            downloadError,
-          );
-        }
+        };
+        await ensure(null);
       }
     } catch (err: unknown) {
       console.error("Failed to check missing components:", err);
     }
-  }, []);
+  }, [t]);
 
   const [processingUrls, setProcessingUrls] = useState<Set<string>>(new Set());
 
@@ -629,24 +705,6 @@ export default function Home() {
 
   useAppUpdateNotifications();
 
-  // Check for startup URLs but only process them once
-  const [hasCheckedStartupUrl, setHasCheckedStartupUrl] = useState(false);
-  const checkCurrentUrl = useCallback(async () => {
-    if (hasCheckedStartupUrl) return;
-
-    try {
-      const currentUrl = await getCurrent();
-      if (currentUrl && currentUrl.length > 0) {
-        console.log("Startup URL detected:", currentUrl[0]);
-        handleUrlOpen(currentUrl[0]);
-      }
-    } catch (error) {
-      console.error("Failed to check current URL:", error);
-    } finally {
-      setHasCheckedStartupUrl(true);
-    }
-  }, [handleUrlOpen, hasCheckedStartupUrl]);
-
   // Handle profile errors from useProfileEvents hook
   useEffect(() => {
     if (profilesError) {
@@ -762,6 +820,112 @@ export default function Home() {
         }),
       );
 
+      // donut:// action links run without the UI; only failures surface here
+      unlisteners.push(
+        await listen<{ link: string; error: string }>(
+          "deep-link-action-failed",
+          (event) => {
+            showErrorToast(t("errors.deepLinkActionFailed"), {
+              description: translateBackendError(t, event.payload.error),
+            });
+          },
+        ),
+      );
+
+      // Global profile shortcuts run without the UI as well
+      unlisteners.push(
+        await listen<{ profile_id: string; error: string }>(
+          "profile-shortcut-failed",
+          (event) => {
+            showErrorToast(t("errors.profileShortcutFailed"), {
+              description: translateBackendError(t, event.payload.error),
+            });
+          },
+        ),
+      );
+
+      // A profile's metadata.json was corrupt and came back from its backup
+      unlisteners.push(
+        await listen<{ id: string; name: string }>(
+          "profile-metadata-recovered",
+          (event) => {
+            showErrorToast(
+              t("errors.profileMetadataRecovered", {
+                name: event.payload.name,
+              }),
+              { id: `profile-metadata-recovered-${event.payload.id}` },
+            );
+          },
+        ),
+      );
+
+      // A profile launched although its proxy/VPN exits somewhere unexpected
+      unlisteners.push(
+        await listen<ExitMismatch>("exit-mismatch", (event) => {
+          const mismatch = event.payload;
+          const expected = [
+            mismatch.expected_country,
+            mismatch.expected_ip_prefix,
+          ]
+            .filter(Boolean)
+            .join(", ");
+          const actual = mismatch.exit_country
+            ? `${mismatch.exit_ip} (${mismatch.exit_country})`
+            : (mismatch.exit_ip ?? "");
+          showErrorToast(
+            t("exitCheck.mismatchToast", {
+              profile: mismatch.profile_name,
+              route: mismatch.route_name,
+            }),
+            {
+              description: mismatch.error
+                ? t("exitCheck.unresolved", { error: mismatch.error })
+                : t("exitCheck.mismatchDetail", { expected, actual }),
+            },
+          );
+        }),
+      );
+
+      // A launch phase ran over the configured threshold
+      unlisteners.push(
+        await listen<SlowLaunch>("launch-slow", (event) => {
+          const slow = event.payload;
+          showErrorToast(
+            t("launchSlow.title", { profile: slow.profile_name }),
+            {
+              description: t(`launchSlow.hints.${slow.phase}`, {
+                seconds: Math.round(slow.duration_ms / 1000),
+              }),
+            },
+          );
+        }),
+      );
+
+      // Extensions assigned to a profile that didn't make it into the browser
+      unlisteners.push(
+        await listen<ExtensionsLoadFailed>(
+          "extensions-load-failed",
+          (event) => {
+            const { profile_name, failures } = event.payload;
+            showErrorToast(
+              t("extensionsLoadFailed.title", {
+                profile: profile_name,
+                count: failures.length,
+              }),
+              {
+                description: failures
+                  .map((failure) =>
+                    t(`extensionsLoadFailed.reasons.${failure.reason}`, {
+                      name: failure.name,
+                    }),
+                  )
+                  .join("; "),
+              },
+            );
+          },
+        ),
+      );
+
       // Listen for custom logo click events
       handleLogoUrlEvent = (event: CustomEvent) => {
         console.log("Received logo URL event:", event.detail);
@@ -772,6 +936,10 @@ export default function Home() {
         handleLogoUrlEvent as EventListener,
       );
 
+      // URLs opened before this point (startup arguments, deep links, a
+      // second instance) were queued by the backend until now.
+      await invoke("frontend_ready");
+
       return teardown;
     } catch (error) {
       console.error("Failed to setup URL listener:", error);
@@ -795,6 +963,26 @@ export default function Home() {
         // No need to manually reload - useProfileEvents will handle the update
         setWayfernConfigDialogOpen(false);
       } catch (err: unknown) {
+        if (isBackendErrorCode(err, "LOCALE_CONFLICTS_WITH_FINGERPRINT")) {
+          // Offer to rewrite the stored fingerprint's languages instead.
+          showToast({
+            type: "error",
+            title: translateBackendError(t, err),
+            action: {
+              label: t("fingerprint.applyLocaleToFingerprint"),
+              onClick: () => {
+                invoke("update_wayfern_config", {
+                  profileId: profile.id,
+                  config,
+                  forceLocale: true,
+                }).catch((retryErr: unknown) => {
+                  showErrorToast(translateBackendError(t, retryErr));
+                });
+              },
+            },
+          });
+          return;
+        }
         console.error("Failed to update wayfern config:", err);
         showErrorToast(
           t("errors.updateWayfernConfigFailed", { error: JSON.stringify(err) }),
@@ -949,6 +1137,8 @@ export default function Home() {
             });
         }
       } catch (err: unknown) {
+        // Taken out of the launch queue by the user; nothing went wrong.
+        if (isBackendErrorCode(err, "LAUNCH_CANCELLED")) return;
         console.error("Failed to launch browser:", err);
         const errorMessage = translateBackendError(t, err);
         showErrorToast(
@@ -1049,10 +1239,32 @@ export default function Home() {
     [t],
   );
 
+  const reportFailedDeletions = useCallback(
+    (result: BulkDeleteResult) => {
+      if (result.failed.length === 0) return;
+      showErrorToast(
+        t("errors.deleteSomeProfilesFailed", { count: result.failed.length }),
+        {
+          description: result.failed
+            .map(
+              (failure) =>
+                `${failure.profile_name ?? failure.profile_id}: ${failure.error}`,
+            )
+            .join("\n"),
+        },
+      );
+    },
+    [t],
+  );
+
   const handleDeleteSelectedProfiles = useCallback(
     async (profileIds: string[]) => {
       try {
-        await invoke("delete_selected_profiles", { profileIds });
+        const result = await invoke<BulkDeleteResult>(
+          "delete_selected_profiles",
+          { profileIds },
+        );
+        reportFailedDeletions(result);
         // No need to manually reload - useProfileEvents will handle the update
       } catch (err: unknown) {
         console.error("Failed to delete selected profiles:", err);
@@ -1063,7 +1275,7 @@ export default function Home() {
         );
       }
     },
-    [t],
+    [t, reportFailedDeletions],
   );
 
   const handleAssignProfilesToGroup = useCallback((profileIds: string[]) => {
@@ -1081,11 +1293,13 @@ export default function Home() {
 
     setIsBulkDeleting(true);
     try {
-      await invoke("delete_selected_profiles", {
-        profileIds: selectedProfiles,
-      });
+      const result = await invoke<BulkDeleteResult>(
+        "delete_selected_profiles",
+        { profileIds: selectedProfiles },
+      );
+      reportFailedDeletions(result);
       // No need to manually reload - useProfileEvents will handle the update
-      setSelectedProfiles([]);
+      setSelectedProfiles(result.failed.map((failure) => failure.profile_id));
       setShowBulkDeleteConfirmation(false);
     } catch (error) {
       console.error("Failed to delete selected profiles:", error);
@@ -1097,7 +1311,7 @@ export default function Home() {
     } finally {
       setIsBulkDeleting(false);
     }
-  }, [selectedProfiles, t]);
+  }, [selectedProfiles, t, reportFailedDeletions]);
 
   const handleBulkGroupAssignment = useCallback(() => {
     if (selectedProfiles.length === 0) return;
@@ -1263,7 +1477,9 @@ export default function Home() {
         );
       } catch (error) {
         console.error("Failed to toggle sync:", error);
-        showErrorToast(t("errors.updateSyncSettingsFailed"));
+        showErrorToast(t("errors.updateSyncSettingsFailed"), {
+          description: translateBackendError(t, error),
+        });
       }
     },
     [t],
@@ -1281,6 +1497,8 @@ export default function Home() {
           status: string;
           error?: string;
           profile_name?: string;
+          partial?: boolean;
+          failed_files?: string[];
         }>("profile-sync-status", (event) => {
           const { profile_id, status, error, profile_name } = event.payload;
           const toastId = `sync-${profile_id}`;
@@ -1288,7 +1506,18 @@ export default function Home() {
           const name =
             profile_name || profile?.name || t("common.labels.unknownProfile");
 
-          if (status === "synced") {
+          if (status === "synced" && event.payload.partial) {
+            dismissToast(toastId);
+            profilesWithTransfer.delete(profile_id);
+            const failed = event.payload.failed_files ?? [];
+            showErrorToast(
+              t("sync.toast.profileSyncPartial", {
+                name,
+                count: failed.length,
+              }),
+              { description: failed.slice(0, 5).join(", ") },
+            );
+          } else if (status === "synced") {
             dismissToast(toastId);
             if (profilesWithTransfer.has(profile_id)) {
               profilesWithTransfer.delete(profile_id);
@@ -1313,6 +1542,7 @@ export default function Home() {
           completed_files?: number;
           completed_bytes?: number;
           speed_bytes_per_sec?: number;
+          throughput_bytes_per_sec?: number;
           eta_seconds?: number;
           failed_count?: number;
           profile_name?: string;
@@ -1338,7 +1568,10 @@ export default function Home() {
                 total_files: payload.total_files ?? 0,
                 completed_bytes: payload.completed_bytes ?? 0,
                 total_bytes: payload.total_bytes ?? 0,
-                speed_bytes_per_sec: payload.speed_bytes_per_sec ?? 0,
+                speed_bytes_per_sec:
+                  payload.throughput_bytes_per_sec ??
+                  payload.speed_bytes_per_sec ??
+                  0,
                 eta_seconds: payload.eta_seconds ?? 0,
                 failed_count: payload.failed_count ?? 0,
                 phase: payload.phase,
@@ -1379,9 +1612,6 @@ export default function Home() {
       cleanup = cleanupFn;
     });
 
-    // Check for startup URLs (when app was launched as default browser)
-    void checkCurrentUrl();
-
     // Set up periodic update checks (every 30 minutes)
     const updateInterval = setInterval(
       () => {
@@ -1410,7 +1640,6 @@ export default function Home() {
   }, [
     checkForUpdates,
     listenForUrlEvents,
-    checkCurrentUrl,
     checkMissingBinaries,
     profilesLoading,
     profiles.length,
@@ -1518,6 +1747,33 @@ export default function Home() {
     };
   }, [t]);
 
+  // Extension updates held back because a running profile uses them.
+  useEffect(() => {
+    let unlisten: (() => void) | null = null;
+    const setup = async () => {
+      unlisten = await listen<ExtensionUpdateResult[]>(
+        "extensions-update-pending",
+        (event) => {
+          showToast({
+            id: "extensions-update-pending",
+            type: "success",
+            title: t("extensions.updates.pendingTitle", {
+              count: event.payload.length,
+            }),
+            description: t("extensions.updates.pendingDescription", {
+              names: event.payload.map((r) => r.name).join(", "),
+            }),
+            duration: 8000,
+          });
+        },
+      );
+    };
+    void setup();
+    return () => {
+      if (unlisten) unlisten();
+    };
+  }, [t]);
+
   // Re-check Wayfern terms when a browser download completes
   useEffect(() => {
     let unlisten: (() => void) | null = null;
@@ -1553,21 +1809,28 @@ export default function Home() {
 
   // Filter data by selected group and search query
   const filteredProfiles = useMemo(() => {
-    let filtered = profiles;
+    // Archived profiles replace the regular list rather than joining it.
+    const listed = showArchived ? archivedProfiles : profiles;
+    let filtered = listed;
 
     // Filter by group. "__all__" is a virtual filter that shows every
     // profile (including ungrouped ones). Any other value is a real
     // group id; ungrouped profiles only show through "All".
     if (!selectedGroupId || selectedGroupId === "__all__") {
-      filtered = profiles;
+      filtered = listed;
     } else {
-      filtered = profiles.filter(
+      filtered = listed.filter(
         (profile) => profile.group_id === selectedGroupId,
       );
     }
 
     // Filter by search query
-    if (searchQuery.trim()) {
+    const metadataQuery = parseMetadataQuery(searchQuery);
+    if (metadataQuery) {
+      filtered = filtered.filter((profile) =>
+        matchesMetadata(profile, metadataQuery),
+      );
+    } else if (searchQuery.trim()) {
       const query = searchQuery.toLowerCase().trim();
       filtered = filtered.filter((profile) => {
         // Search in profile name
@@ -1580,12 +1843,43 @@ export default function Home() {
         if (profile.tags?.some((tag) => tag.toLowerCase().includes(query)))
           return true;
 
+        // Search in metadata values
+        if (
+          Object.values(profile.metadata ?? {}).some((value) =>
+            value.toLowerCase().includes(query),
+          )
+        )
+          return true;
+
         return false;
       });
     }
 
+    if (activeSavedView) {
+      filtered = filtered.filter((profile) =>
+        matchesSavedViewFilter(
+          profile,
+          activeSavedView.filter,
+          runningProfiles,
+        ),
+      );
+    }
+
     return filtered;
-  }, [profiles, selectedGroupId, searchQuery]);
+  }, [
+    profiles,
+    archivedProfiles,
+    showArchived,
+    selectedGroupId,
+    searchQuery,
+    activeSavedView,
+    runningProfiles,
+  ]);
+
+  const profileBrowsers = useMemo(
+    () => [...new Set(profiles.map((p) => p.browser))].sort(),
+    [profiles],
+  );
 
   // Update loading states
   const isLoading = profilesLoading || groupsLoading || proxiesLoading;
@@ -1605,10 +1899,24 @@ export default function Home() {
         searchQuery={searchQuery}
         onSearchQueryChange={setSearchQuery}
         groups={groupsData}
-        totalProfiles={profiles.length}
+        totalProfiles={
+          showArchived ? archivedProfiles.length : profiles.length
+        }
         selectedGroupId={selectedGroupId}
         onGroupSelect={handleSelectGroup}
+        archivedCount={archivedProfiles.length}
+        showArchived={showArchived}
+        onShowArchivedChange={handleShowArchivedChange}
         pageTitle={subPageTitle}
+        savedViewsMenu={
+          <SavedViewsMenu
+            savedViews={savedViews}
+            activeViewId={activeSavedView?.id ?? null}
+            onActiveViewChange={setActiveSavedViewId}
+            currentGroupId={selectedGroupId}
+            browsers={profileBrowsers}
+          />
+        }
       />
       <div className="flex min-h-0 flex-1">
         <RailNav
@@ -1619,6 +1927,11 @@ export default function Home() {
           }}
         />
         <main className="flex min-w-0 flex-1 flex-col overflow-hidden">
+          {observerMode && (
+            <div className="mx-3 mt-2.5 rounded-md border border-border bg-muted px-3 py-2 text-sm text-muted-foreground">
+              {t("observer.banner")}
+            </div>
+          )}
           {currentPage === "profiles" && (
             <motion.div
               initial={{ opacity: 0, y: 8 }}
@@ -1648,6 +1961,7 @@ export default function Home() {
                 onDeleteSelectedProfiles={handleDeleteSelectedProfiles}
                 onAssignProfilesToGroup={handleAssignProfilesToGroup}
                 selectedGroupId={selectedGroupId}
+                savedView={activeSavedView}
                 selectedProfiles={selectedProfiles}
                 onSelectedProfilesChange={setSelectedProfiles}
                 onBulkDelete={handleBulkDelete}
@@ -1686,7 +2000,10 @@ export default function Home() {
               transition={{ duration: 0.25, ease: MOTION_EASE_OUT }}
               className="flex min-h-0 flex-1 flex-col"
             >
-              <ShortcutsPage groupTargets={orderedGroupTargets} />
+              <ShortcutsPage
+                groupTargets={orderedGroupTargets}
+                profiles={profiles}
+              />
             </motion.div>
           )}
 
@@ -1819,11 +2136,22 @@ export default function Home() {
         onCreateProfile={() => {
           setCreateProfileDialogOpen(true);
         }}
+        onManageTags={() => {
+          setTagManagementDialogOpen(true);
+        }}
         onOpenAbout={() => {
           setAboutDialogOpen(true);
         }}
       />
 
+      <TagManagementDialog
+        isOpen={tagManagementDialogOpen}
+        onClose={() => {
+          setTagManagementDialogOpen(false);
+        }}
+        profiles={profiles}
+      />
+
       <AboutDialog
         isOpen={aboutDialogOpen}
         onClose={() => {
@@ -0,0 +1,54 @@
+"use client";
+
+import { listen } from "@tauri-apps/api/event";
+import { getCurrentWindow } from "@tauri-apps/api/window";
+import { useEffect, useState } from "react";
+import { ProfileInfoDialog } from "@/components/profile-info-dialog";
+import { useProfileEvents } from "@/hooks/use-profile-events";
+import { useProxyEvents } from "@/hooks/use-proxy-events";
+import { useVpnEvents } from "@/hooks/use-vpn-events";
+import { isCrossOsProfile } from "@/lib/browser-utils";
+
+/**
+ * Detached profile details, opened by `open_detached_profile_window`. The
+ * profile comes from the `id` query parameter and is swapped in place when the
+ * main window asks to show another one.
+ */
+export default function ProfileWindow() {
+  const { profiles, runningProfiles } = useProfileEvents();
+  const { storedProxies } = useProxyEvents();
+  const { vpnConfigs } = useVpnEvents();
+  const [profileId, setProfileId] = useState<string | null>(null);
+
+  useEffect(() => {
+    setProfileId(new URLSearchParams(window.location.search).get("id"));
+    const unlisten = listen<string>("detached-profile-selected", (event) => {
+      setProfileId(event.payload);
+    });
+    return () => {
+      void unlisten.then((fn) => {
+        fn();
+      });
+    };
+  }, []);
+
+  const profile = profiles.find((p) => p.id === profileId) ?? null;
+  const isRunning = profile ? runningProfiles.has(profile.id) : false;
+  const isCrossOs = profile ? isCrossOsProfile(profile) : false;
+
+  return (
+    <ProfileInfoDialog
+      isOpen={profile !== null}
+      onClose={() => {
+        void getCurrentWindow().close();
+      }}
+      profile={profile}
+      storedProxies={storedProxies}
+      vpnConfigs={vpnConfigs}
+      isRunning={isRunning}
+      isDisabled={isRunning || isCrossOs}
+      isCrossOs={isCrossOs}
+      syncStatuses={{}}
+    />
+  );
+}
@@ -5,6 +5,7 @@ import { useCallback, useEffect, useState } from "react";
 import { useTranslation } from "react-i18next";
 import {
   LuCloud,
+  LuCloudOff,
   LuEye,
   LuEyeOff,
   LuLogOut,
@@ -24,6 +25,8 @@ import { Dialog, DialogContent } from "@/components/ui/dialog";
 import { Input } from "@/components/ui/input";
 import { Label } from "@/components/ui/label";
 import { useCloudAuth } from "@/hooks/use-cloud-auth";
+import { useSyncQueue } from "@/hooks/use-sync-queue";
+import { useSyncSubscription } from "@/hooks/use-sync-subscription";
 import { translateBackendError } from "@/lib/backend-errors";
 import { getEntitlements } from "@/lib/entitlements";
 import { showErrorToast, showSuccessToast } from "@/lib/toast-utils";
@@ -55,6 +58,9 @@ export function AccountPage({
   } = useCloudAuth();
   const [isRefreshing, setIsRefreshing] = useState(false);
   const [isLoggingOut, setIsLoggingOut] = useState(false);
+  const { pending: pendingSync, server_reachable: serverReachable } =
+    useSyncQueue();
+  const subscription = useSyncSubscription();
 
   // Self-hosted server state. Loaded once when the dialog opens and persisted
   // via `save_sync_settings` so the rest of the app picks up the new URL/token
@@ -201,6 +207,39 @@ export function AccountPage({
       <DialogContent className="flex max-h-[calc(100vh-5rem)] max-w-3xl flex-col">
         <div className="min-h-0 flex-1 overflow-y-auto">
           <div className={cn(subPage && "mx-auto w-full max-w-4xl")}>
+            {pendingSync.length > 0 && (
+              <div className="mb-3 flex items-center gap-2 rounded-md border border-border bg-muted/40 px-3 py-2 text-xs text-muted-foreground">
+                <LuCloudOff className="size-3.5 shrink-0" />
+                <span>
+                  {t("sync.queue.waiting", { count: pendingSync.length })}
+                  {!serverReachable && ` ${t("sync.queue.serverUnreachable")}`}
+                </span>
+              </div>
+            )}
+            {(subscription.state === "reconnecting" ||
+              subscription.state === "degraded") && (
+              <div
+                className={cn(
+                  "mb-3 flex items-center gap-2 rounded-md border px-3 py-2 text-xs",
+                  subscription.state === "degraded"
+                    ? "border-warning/50 bg-warning/10 text-warning"
+                    : "border-border bg-muted/40 text-muted-foreground",
+                )}
+              >
+                <LuRefreshCw className="size-3.5 shrink-0" />
+                <span>
+                  {subscription.state === "degraded"
+                    ? t("sync.subscription.degraded", {
+                        time: subscription.last_event_at
+                          ? new Date(
+                              subscription.last_event_at * 1000,
+                            ).toLocaleString()
+                          : t("sync.subscription.never"),
+                      })
+                    : t("sync.subscription.reconnecting")}
+                </span>
+              </div>
+            )}
             <AnimatedTabs defaultValue="account">
               <AnimatedTabsList>
                 <AnimatedTabsTrigger value="account">
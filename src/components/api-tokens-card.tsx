@@ -0,0 +1,172 @@
+"use client";
+
+import { invoke } from "@tauri-apps/api/core";
+import { useCallback, useEffect, useState } from "react";
+import { useTranslation } from "react-i18next";
+import { LuPlus, LuTrash2 } from "react-icons/lu";
+import { Button } from "@/components/ui/button";
+import { Checkbox } from "@/components/ui/checkbox";
+import { Input } from "@/components/ui/input";
+import { Label } from "@/components/ui/label";
+import { translateBackendError } from "@/lib/backend-errors";
+import { showErrorToast, showSuccessToast } from "@/lib/toast-utils";
+import type { ApiScope, ApiTokenInfo } from "@/types";
+import { CopyToClipboard } from "./ui/copy-to-clipboard";
+
+const API_SCOPES: ApiScope[] = [
+  "profiles:read",
+  "profiles:write",
+  "profiles:launch",
+  "proxies:read",
+  "proxies:write",
+  "settings:read",
+  "settings:write",
+  "admin",
+];
+
+interface ApiTokensCardProps {
+  /** Called after a revoke, since revoking `default` clears the main token. */
+  onRevoked?: () => void;
+}
+
+export function ApiTokensCard({ onRevoked }: ApiTokensCardProps) {
+  const { t } = useTranslation();
+  const [tokens, setTokens] = useState<ApiTokenInfo[]>([]);
+  const [name, setName] = useState("");
+  const [scopes, setScopes] = useState<ApiScope[]>(["profiles:read"]);
+  const [isCreating, setIsCreating] = useState(false);
+  const [createdToken, setCreatedToken] = useState<string | null>(null);
+
+  const loadTokens = useCallback(async () => {
+    try {
+      setTokens(await invoke<ApiTokenInfo[]>("list_api_tokens"));
+    } catch (e) {
+      console.error("Failed to load API tokens:", e);
+    }
+  }, []);
+
+  useEffect(() => {
+    void loadTokens();
+  }, [loadTokens]);
+
+  const handleCreate = async () => {
+    setIsCreating(true);
+    try {
+      const created = await invoke<ApiTokenInfo & { token: string }>(
+        "generate_api_token",
+        { name, scopes },
+      );
+      setCreatedToken(created.token);
+      setName("");
+      await loadTokens();
+    } catch (e) {
+      showErrorToast(translateBackendError(t, e));
+    } finally {
+      setIsCreating(false);
+    }
+  };
+
+  const handleRevoke = async (token: ApiTokenInfo) => {
+    try {
+      await invoke("revoke_api_token", { tokenId: token.id });
+      showSuccessToast(t("integrations.tokens.revoked", { name: token.name }));
+      await loadTokens();
+      onRevoked?.();
+    } catch (e) {
+      showErrorToast(translateBackendError(t, e));
+    }
+  };
+
+  return (
+    <div className="flex flex-col gap-3 rounded-md border bg-card p-4">
+      <div className="flex flex-col gap-1">
+        <Label className="text-[10px] tracking-wide text-muted-foreground uppercase">
+          {t("integrations.tokens.title")}
+        </Label>
+        <p className="text-xs text-muted-foreground">
+          {t("integrations.tokens.description")}
+        </p>
+      </div>
+
+      {tokens.length > 0 && (
+        <div className="flex flex-col gap-1.5">
+          {tokens.map((token) => (
+            <div
+              key={token.id}
+              className="flex items-center justify-between gap-2 rounded-md bg-muted px-3 py-1.5"
+            >
+              <div className="min-w-0">
+                <p className="truncate text-sm font-medium">{token.name}</p>
+                <p className="truncate font-mono text-[11px] text-muted-foreground">
+                  {token.scopes.join(", ")}
+                </p>
+              </div>
+              <Button
+                variant="ghost"
+                size="sm"
+                onClick={() => void handleRevoke(token)}
+                aria-label={t("integrations.tokens.revoke")}
+              >
+                <LuTrash2 className="size-4" />
+              </Button>
+            </div>
+          ))}
+        </div>
+      )}
+
+      {createdToken && (
+        <div className="flex flex-col gap-1.5 rounded-md border border-warning/40 bg-warning/10 p-3">
+          <p className="text-xs">{t("integrations.tokens.createdNotice")}</p>
+          <div className="flex items-center gap-2">
+            <Input value={createdToken} readOnly className="font-mono" />
+            <CopyToClipboard
+              text={createdToken}
+              successMessage={t("integrations.tokenCopied")}
+            />
+          </div>
+        </div>
+      )}
+
+      <div className="flex flex-col gap-2">
+        <Input
+          value={name}
+          onChange={(e) => {
+            setName(e.target.value);
+          }}
+          placeholder={t("integrations.tokens.namePlaceholder")}
+          disabled={isCreating}
+        />
+        <div className="grid grid-cols-2 gap-1.5">
+          {API_SCOPES.map((scope) => (
+            <label
+              key={scope}
+              className="flex cursor-pointer items-center gap-2 font-mono text-xs"
+            >
+              <Checkbox
+                checked={scopes.includes(scope)}
+                onCheckedChange={(checked) => {
+                  setScopes((prev) =>
+                    checked === true
+                      ? [...prev, scope]
+                      : prev.filter((s) => s !== scope),
+                  );
+                }}
+                disabled={isCreating}
+              />
+              {scope}
+            </label>
+          ))}
+        </div>
+        <Button
+          size="sm"
+          className="self-end"
+          onClick={() => void handleCreate()}
+          disabled={isCreating || !name.trim() || scopes.length === 0}
+        >
+          <LuPlus className="mr-1 size-4" />
+          {t("integrations.tokens.create")}
+        </Button>
+      </div>
+    </div>
+  );
+}
@@ -14,6 +14,7 @@ import {
   LuPlug,
   LuPlus,
   LuPuzzle,
+  LuTags,
   LuUser,
   LuUsers,
 } from "react-icons/lu";
@@ -56,6 +57,7 @@ interface CommandPaletteProps {
   onKillProfile: (profile: BrowserProfile) => void;
   onShowProfileInfo: (profile: BrowserProfile) => void;
   onCreateProfile: () => void;
+  onManageTags: () => void;
   onOpenAbout: () => void;
 }
 
@@ -127,6 +129,7 @@ export function CommandPalette({
   onKillProfile,
   onShowProfileInfo,
   onCreateProfile,
+  onManageTags,
   onOpenAbout,
 }: CommandPaletteProps) {
   const { t } = useTranslation();
@@ -265,6 +268,14 @@ export function CommandPalette({
             <LuPlus />
             <span>{t("commandPalette.actions.createProfile")}</span>
           </CommandItem>
+          <CommandItem
+            onSelect={() => {
+              dispatch(onManageTags);
+            }}
+          >
+            <LuTags />
+            <span>{t("commandPalette.actions.manageTags")}</span>
+          </CommandItem>
           {byGroup("actions").map((s) => {
             const Icon = ICONS[s.id];
             return (
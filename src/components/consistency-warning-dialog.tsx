@@ -24,6 +24,7 @@ export interface ConsistencyResult {
   fingerprint_timezone: string | null;
   fingerprint_language: string | null;
   mismatches: string[];
+  warnings: string[];
 }
 
 const GLOBAL_DISABLE_KEY = "consistency-warn-disabled";
@@ -130,6 +131,14 @@ export function ConsistencyWarningDialog({
                 </p>
               </div>
             )}
+            {result?.warnings.includes("timezone") && (
+              <p className="text-xs text-muted-foreground">
+                {t("consistencyWarning.timezonePinnedDetail", {
+                  exit: result.exit_timezone ?? "?",
+                  fingerprint: result.fingerprint_timezone ?? "?",
+                })}
+              </p>
+            )}
             {mismatches.includes("language") && (
               <div>
                 <p className="font-medium">
@@ -891,7 +891,6 @@ export function CreateProfileDialog({
                               profileVersion={
                                 getCreatableVersion("wayfern")?.version
                               }
-                              profileBrowser="wayfern"
                             />
                           </div>
                         ) : (
@@ -17,7 +17,11 @@ import { Label } from "@/components/ui/label";
 import { RadioGroup, RadioGroupItem } from "@/components/ui/radio-group";
 import { ScrollArea } from "@/components/ui/scroll-area";
 import { translateBackendError } from "@/lib/backend-errors";
-import type { BrowserProfile, ProfileGroup } from "@/types";
+import type {
+  BrowserProfile,
+  BulkDeleteResult,
+  ProfileGroup,
+} from "@/types";
 import { RippleButton } from "./ui/ripple";
 
 interface DeleteGroupDialogProps {
@@ -80,7 +84,19 @@ export function DeleteGroupDialog({
       if (deleteAction === "delete" && associatedProfiles.length > 0) {
         // Delete all associated profiles first
         const profileIds = associatedProfiles.map((p) => p.id);
-        await invoke("delete_selected_profiles", { profileIds });
+        const result = await invoke<BulkDeleteResult>(
+          "delete_selected_profiles",
+          { profileIds },
+        );
+        // Keep the group while some of its profiles are still around
+        if (result.failed.length > 0) {
+          setError(
+            t("errors.deleteSomeProfilesFailed", {
+              count: result.failed.length,
+            }),
+          );
+          return;
+        }
       } else if (deleteAction === "move" && associatedProfiles.length > 0) {
         // Move profiles to default group (null group_id)
         const profileIds = associatedProfiles.map((p) => p.id);
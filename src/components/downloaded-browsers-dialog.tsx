@@ -0,0 +1,226 @@
+"use client";
+
+import { invoke } from "@tauri-apps/api/core";
+import { useCallback, useEffect, useState } from "react";
+import { useTranslation } from "react-i18next";
+import { LoadingButton } from "@/components/loading-button";
+import { Badge } from "@/components/ui/badge";
+import {
+  Dialog,
+  DialogContent,
+  DialogDescription,
+  DialogFooter,
+  DialogHeader,
+  DialogTitle,
+} from "@/components/ui/dialog";
+import { translateBackendError } from "@/lib/backend-errors";
+import { showErrorToast, showSuccessToast } from "@/lib/toast-utils";
+import type {
+  BinariesDiskUsage,
+  BinaryCleanupRecord,
+  DownloadedBrowser,
+} from "@/types";
+import { RippleButton } from "./ui/ripple";
+
+/** Cleanup records shown; the backend keeps more. */
+const VISIBLE_CLEANUPS = 5;
+
+interface DownloadedBrowsersDialogProps {
+  isOpen: boolean;
+  onClose: () => void;
+}
+
+function formatBytes(bytes: number): string {
+  if (!Number.isFinite(bytes) || bytes <= 0) return "0 B";
+  if (bytes < 1024) return `${bytes} B`;
+  if (bytes < 1024 * 1024) return `${(bytes / 1024).toFixed(1)} KB`;
+  if (bytes < 1024 * 1024 * 1024)
+    return `${(bytes / (1024 * 1024)).toFixed(1)} MB`;
+  return `${(bytes / (1024 * 1024 * 1024)).toFixed(2)} GB`;
+}
+
+function installKey(item: DownloadedBrowser): string {
+  return `${item.browser}/${item.version}`;
+}
+
+/** "wayfern 121.0, 122.0; camoufox 135.0" */
+function describeRemovals(record: BinaryCleanupRecord): string {
+  const byBrowser = new Map<string, string[]>();
+  for (const removal of record.removed) {
+    const versions = byBrowser.get(removal.browser) ?? [];
+    versions.push(removal.version);
+    byBrowser.set(removal.browser, versions);
+  }
+  return [...byBrowser]
+    .map(([browser, versions]) => `${browser} ${versions.join(", ")}`)
+    .join("; ");
+}
+
+export function DownloadedBrowsersDialog({
+  isOpen,
+  onClose,
+}: DownloadedBrowsersDialogProps) {
+  const { t } = useTranslation();
+  const [browsers, setBrowsers] = useState<DownloadedBrowser[] | null>(null);
+  const [usage, setUsage] = useState<BinariesDiskUsage | null>(null);
+  const [deletingKey, setDeletingKey] = useState<string | null>(null);
+  // An in-use version needs a second click, which deletes with force.
+  const [confirmKey, setConfirmKey] = useState<string | null>(null);
+
+  const load = useCallback(async () => {
+    try {
+      const [list, summary] = await Promise.all([
+        invoke<DownloadedBrowser[]>("list_downloaded_browsers"),
+        invoke<BinariesDiskUsage>("get_binaries_disk_usage"),
+      ]);
+      setBrowsers(list);
+      setUsage(summary);
+    } catch (error) {
+      showErrorToast(translateBackendError(t, error));
+    }
+  }, [t]);
+
+  useEffect(() => {
+    if (isOpen) void load();
+  }, [isOpen, load]);
+
+  const handleDelete = useCallback(
+    async (item: DownloadedBrowser) => {
+      const key = installKey(item);
+      if (item.in_use && confirmKey !== key) {
+        setConfirmKey(key);
+        return;
+      }
+      setDeletingKey(key);
+      try {
+        const freed = await invoke<number>("delete_downloaded_browser", {
+          browser: item.browser,
+          version: item.version,
+          force: item.in_use,
+        });
+        showSuccessToast(
+          t("downloadedBrowsers.deleted", {
+            browser: item.browser,
+            version: item.version,
+            size: formatBytes(freed),
+          }),
+        );
+        await load();
+      } catch (error) {
+        showErrorToast(translateBackendError(t, error));
+      } finally {
+        setDeletingKey(null);
+        setConfirmKey(null);
+      }
+    },
+    [confirmKey, load, t],
+  );
+
+  const handleClose = useCallback(() => {
+    if (deletingKey) return;
+    setConfirmKey(null);
+    onClose();
+  }, [deletingKey, onClose]);
+
+  return (
+    <Dialog open={isOpen} onOpenChange={handleClose}>
+      <DialogContent className="max-w-lg">
+        <DialogHeader>
+          <DialogTitle>{t("downloadedBrowsers.title")}</DialogTitle>
+          <DialogDescription>
+            {usage
+              ? t("downloadedBrowsers.summary", {
+                  total: formatBytes(usage.total_bytes),
+                  unused: formatBytes(usage.unused_bytes),
+                })
+              : t("downloadedBrowsers.description")}
+          </DialogDescription>
+        </DialogHeader>
+
+        <div className="max-h-[50vh] space-y-4 overflow-y-auto">
+          <div className="space-y-2">
+            {browsers?.length === 0 && (
+              <p className="text-sm text-muted-foreground">
+                {t("downloadedBrowsers.empty")}
+              </p>
+            )}
+            {browsers?.map((item) => {
+              const key = installKey(item);
+              return (
+                <div
+                  key={key}
+                  className="flex items-center justify-between gap-2 rounded-md border p-2"
+                >
+                  <div className="min-w-0 space-y-0.5">
+                    <div className="flex items-center gap-2">
+                      <span className="truncate text-sm">
+                        {item.browser} {item.version}
+                      </span>
+                      <Badge variant={item.in_use ? "secondary" : "outline"}>
+                        {item.in_use
+                          ? t("downloadedBrowsers.inUse")
+                          : t("downloadedBrowsers.unused")}
+                      </Badge>
+                    </div>
+                    <p className="text-xs text-muted-foreground">
+                      {formatBytes(item.size_bytes)} ·{" "}
+                      {item.last_used
+                        ? t("downloadedBrowsers.lastUsed", {
+                            time: new Date(
+                              item.last_used * 1000,
+                            ).toLocaleString(),
+                          })
+                        : t("downloadedBrowsers.neverUsed")}
+                    </p>
+                  </div>
+                  <LoadingButton
+                    size="sm"
+                    variant={confirmKey === key ? "destructive" : "outline"}
+                    isLoading={deletingKey === key}
+                    disabled={deletingKey !== null}
+                    onClick={() => void handleDelete(item)}
+                  >
+                    {confirmKey === key
+                      ? t("downloadedBrowsers.deleteAnyway")
+                      : t("common.buttons.delete")}
+                  </LoadingButton>
+                </div>
+              );
+            })}
+            {confirmKey && (
+              <p className="text-xs text-muted-foreground">
+                {t("downloadedBrowsers.inUseWarning")}
+              </p>
+            )}
+          </div>
+
+          {usage && usage.cleanups.length > 0 && (
+            <div className="space-y-1">
+              <p className="text-sm font-medium">
+                {t("downloadedBrowsers.cleanupLog")}
+              </p>
+              {usage.cleanups.slice(0, VISIBLE_CLEANUPS).map((record) => (
+                <p
+                  key={`${record.timestamp}-${describeRemovals(record)}`}
+                  className="text-xs text-muted-foreground"
+                >
+                  {t("downloadedBrowsers.cleanupEntry", {
+                    time: new Date(record.timestamp * 1000).toLocaleString(),
+                    size: formatBytes(record.freed_bytes),
+                    versions: describeRemovals(record),
+                  })}
+                </p>
+              ))}
+            </div>
+          )}
+        </div>
+
+        <DialogFooter>
+          <RippleButton variant="outline" onClick={handleClose}>
+            {t("common.buttons.close")}
+          </RippleButton>
+        </DialogFooter>
+      </DialogContent>
+    </Dialog>
+  );
+}
@@ -0,0 +1,150 @@
+"use client";
+
+import { invoke } from "@tauri-apps/api/core";
+import { listen } from "@tauri-apps/api/event";
+import { useEffect, useState } from "react";
+import { useTranslation } from "react-i18next";
+import { LoadingButton } from "@/components/loading-button";
+import { Button } from "@/components/ui/button";
+import { Input } from "@/components/ui/input";
+import { Progress } from "@/components/ui/progress";
+import { translateBackendError } from "@/lib/backend-errors";
+import { showErrorToast, showSuccessToast } from "@/lib/toast-utils";
+
+interface E2ePasswordChangeFormProps {
+  onDone: () => void;
+}
+
+export function E2ePasswordChangeForm({ onDone }: E2ePasswordChangeFormProps) {
+  const { t } = useTranslation();
+  const [currentPassword, setCurrentPassword] = useState("");
+  const [newPassword, setNewPassword] = useState("");
+  const [confirmPassword, setConfirmPassword] = useState("");
+  const [error, setError] = useState("");
+  const [isChanging, setIsChanging] = useState(false);
+  const [isResume, setIsResume] = useState(false);
+  const [progress, setProgress] = useState<{
+    done: number;
+    total: number;
+  } | null>(null);
+
+  useEffect(() => {
+    invoke<boolean>("check_e2e_rotation_pending")
+      .then(setIsResume)
+      .catch(() => setIsResume(false));
+  }, []);
+
+  useEffect(() => {
+    let unlisten: (() => void) | undefined;
+    void (async () => {
+      unlisten = await listen<{ stage: string; done: number; total: number }>(
+        "e2e-rollover-progress",
+        (event) => {
+          if (event.payload.stage !== "profiles") return;
+          setProgress({
+            done: event.payload.done,
+            total: event.payload.total,
+          });
+        },
+      );
+    })();
+    return () => unlisten?.();
+  }, []);
+
+  const handleSubmit = async () => {
+    if (newPassword.length < 8) {
+      setError(t("settings.encryption.passwordTooShort"));
+      return;
+    }
+    if (newPassword !== confirmPassword) {
+      setError(t("settings.encryption.passwordMismatch"));
+      return;
+    }
+    setIsChanging(true);
+    setProgress(null);
+    try {
+      await invoke("change_e2e_password", {
+        oldPassword: currentPassword,
+        newPassword,
+      });
+      showSuccessToast(t("settings.encryption.changeForm.success"));
+      onDone();
+    } catch (err) {
+      setError(translateBackendError(t, err));
+    } finally {
+      setIsChanging(false);
+    }
+  };
+
+  return (
+    <div className="space-y-3">
+      {isResume && (
+        <p className="text-sm text-muted-foreground">
+          {t("settings.encryption.changeForm.resumeNotice")}
+        </p>
+      )}
+      <Input
+        type="password"
+        placeholder={t("settings.encryption.changeForm.currentPlaceholder")}
+        value={currentPassword}
+        disabled={isChanging}
+        onChange={(e) => {
+          setCurrentPassword(e.target.value);
+          setError("");
+        }}
+      />
+      <Input
+        type="password"
+        placeholder={t("settings.encryption.passwordPlaceholder")}
+        value={newPassword}
+        disabled={isChanging}
+        onChange={(e) => {
+          setNewPassword(e.target.value);
+          setError("");
+        }}
+      />
+      <Input
+        type="password"
+        placeholder={t("settings.encryption.confirmPlaceholder")}
+        value={confirmPassword}
+        disabled={isChanging}
+        onChange={(e) => {
+          setConfirmPassword(e.target.value);
+          setError("");
+        }}
+      />
+      {isChanging && progress && progress.total > 0 && (
+        <div className="space-y-1">
+          <Progress value={(progress.done / progress.total) * 100} />
+          <p className="text-xs text-muted-foreground">
+            {t("settings.encryption.changeForm.progress", {
+              done: progress.done,
+              total: progress.total,
+            })}
+          </p>
+        </div>
+      )}
+      {error && <p className="text-sm text-destructive">{error}</p>}
+      <div className="flex gap-2">
+        <Button
+          variant="outline"
+          size="sm"
+          disabled={isChanging}
+          onClick={onDone}
+        >
+          {t("common.buttons.cancel")}
+        </Button>
+        <LoadingButton
+          size="sm"
+          isLoading={isChanging}
+          disabled={currentPassword.length === 0}
+          onClick={() => void handleSubmit()}
+        >
+          {isResume
+            ? t("settings.encryption.changeForm.resume")
+            : t("settings.encryption.changeForm.submit")}
+        </LoadingButton>
+      </div>
+    </div>
+  );
+}
@@ -5,6 +5,7 @@ import { useCallback, useEffect, useState } from "react";
 import { useTranslation } from "react-i18next";
 import { toast } from "sonner";
 import { LoadingButton } from "@/components/loading-button";
+import { Checkbox } from "@/components/ui/checkbox";
 import {
   Dialog,
   DialogContent,
@@ -34,14 +35,17 @@ export function EditGroupDialog({
 }: EditGroupDialogProps) {
   const { t } = useTranslation();
   const [groupName, setGroupName] = useState("");
+  const [isProtected, setIsProtected] = useState(false);
   const [isUpdating, setIsUpdating] = useState(false);
   const [error, setError] = useState<string | null>(null);
 
   useEffect(() => {
     if (group) {
       setGroupName(group.name);
+      setIsProtected(group.protected ?? false);
     } else {
       setGroupName("");
+      setIsProtected(false);
     }
     setError(null);
   }, [group]);
@@ -55,6 +59,7 @@ export function EditGroupDialog({
       const updatedGroup = await invoke<ProfileGroup>("update_profile_group", {
         groupId: group.id,
         name: groupName.trim(),
+        protected: isProtected,
       });
 
       toast.success(t("groups.updateSuccess"));
@@ -68,7 +73,7 @@ export function EditGroupDialog({
     } finally {
       setIsUpdating(false);
     }
-  }, [group, groupName, onGroupUpdated, onClose, t]);
+  }, [group, groupName, isProtected, onGroupUpdated, onClose, t]);
 
   const handleClose = useCallback(() => {
     setError(null);
@@ -102,6 +107,25 @@ export function EditGroupDialog({
             />
           </div>
 
+          <div className="flex items-start gap-x-3 rounded-lg border p-3">
+            <Checkbox
+              id="group-protected"
+              checked={isProtected}
+              onCheckedChange={(checked) => {
+                setIsProtected(checked as boolean);
+              }}
+              disabled={isUpdating}
+            />
+            <div className="space-y-1">
+              <Label htmlFor="group-protected">
+                {t("groups.form.protected")}
+              </Label>
+              <p className="text-xs text-muted-foreground">
+                {t("groups.form.protectedDescription")}
+              </p>
+            </div>
+          </div>
+
           {error && (
             <div className="rounded-md bg-destructive/10 p-3 text-sm text-destructive">
               {error}
@@ -120,7 +144,11 @@ export function EditGroupDialog({
           <LoadingButton
             isLoading={isUpdating}
             onClick={() => void handleUpdate()}
-            disabled={!groupName.trim() || groupName === group?.name}
+            disabled={
+              !groupName.trim() ||
+              (groupName === group?.name &&
+                isProtected === (group?.protected ?? false))
+            }
           >
             {t("groups.edit")}
           </LoadingButton>
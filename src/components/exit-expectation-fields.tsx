@@ -0,0 +1,74 @@
+"use client";
+
+import { useTranslation } from "react-i18next";
+import { Input } from "@/components/ui/input";
+import { Label } from "@/components/ui/label";
+
+export interface ExitExpectation {
+  country: string;
+  ipPrefix: string;
+}
+
+export const emptyExitExpectation: ExitExpectation = {
+  country: "",
+  ipPrefix: "",
+};
+
+interface ExitExpectationFieldsProps {
+  idPrefix: string;
+  value: ExitExpectation;
+  onChange: (value: ExitExpectation) => void;
+  disabled?: boolean;
+}
+
+/** Where a proxy or VPN should exit; checked before each profile launch. */
+export function ExitExpectationFields({
+  idPrefix,
+  value,
+  onChange,
+  disabled,
+}: ExitExpectationFieldsProps) {
+  const { t } = useTranslation();
+
+  return (
+    <div className="space-y-2 rounded-lg border p-3">
+      <Label className="text-sm font-medium">
+        {t("exitCheck.fields.title")}
+      </Label>
+      <div className="grid grid-cols-3 gap-2">
+        <div className="space-y-1">
+          <Label htmlFor={`${idPrefix}-expected-country`} className="text-xs">
+            {t("exitCheck.fields.country")}
+          </Label>
+          <Input
+            id={`${idPrefix}-expected-country`}
+            value={value.country}
+            onChange={(e) => {
+              onChange({ ...value, country: e.target.value.toUpperCase() });
+            }}
+            placeholder="DE"
+            maxLength={2}
+            disabled={disabled}
+          />
+        </div>
+        <div className="col-span-2 space-y-1">
+          <Label htmlFor={`${idPrefix}-expected-prefix`} className="text-xs">
+            {t("exitCheck.fields.ipPrefix")}
+          </Label>
+          <Input
+            id={`${idPrefix}-expected-prefix`}
+            value={value.ipPrefix}
+            onChange={(e) => {
+              onChange({ ...value, ipPrefix: e.target.value });
+            }}
+            placeholder="203.0.113.0/24"
+            disabled={disabled}
+          />
+        </div>
+      </div>
+      <p className="text-xs text-muted-foreground">
+        {t("exitCheck.fields.hint")}
+      </p>
+    </div>
+  );
+}
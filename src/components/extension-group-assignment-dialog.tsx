@@ -21,6 +21,7 @@ import {
   SelectTrigger,
   SelectValue,
 } from "@/components/ui/select";
+import { parseBackendError, translateBackendError } from "@/lib/backend-errors";
 import type { BrowserProfile, ExtensionGroup } from "@/types";
 import { RippleButton } from "./ui/ripple";
 
@@ -78,8 +79,12 @@ export function ExtensionGroupAssignmentDialog({
       onClose();
     } catch (err) {
       console.error("Failed to assign extension group:", err);
-      const errorMessage =
-        err instanceof Error ? err.message : t("extensions.assignGroupFailed");
+      // Manifest problems in unpacked extensions come back as coded errors.
+      const errorMessage = parseBackendError(err)
+        ? translateBackendError(t, err)
+        : err instanceof Error
+          ? err.message
+          : t("extensions.assignGroupFailed");
       setError(errorMessage);
       toast.error(errorMessage);
     } finally {
@@ -11,6 +11,7 @@ import {
 } from "@tanstack/react-table";
 import { invoke } from "@tauri-apps/api/core";
 import { listen } from "@tauri-apps/api/event";
+import { open } from "@tauri-apps/plugin-dialog";
 import { useCallback, useEffect, useMemo, useState } from "react";
 import { useTranslation } from "react-i18next";
 import { FaChrome } from "react-icons/fa";
@@ -19,6 +20,7 @@ import {
   LuChevronDown,
   LuChevronUp,
   LuExternalLink,
+  LuFolderOpen,
   LuPencil,
   LuPuzzle,
   LuRefreshCw,
@@ -76,7 +78,12 @@ import {
 import { parseBackendError, translateBackendError } from "@/lib/backend-errors";
 import { showErrorToast, showSuccessToast } from "@/lib/toast-utils";
 import { cn } from "@/lib/utils";
-import type { Extension, ExtensionGroup } from "@/types";
+import type {
+  Extension,
+  ExtensionGroup,
+  ExtensionSource,
+  ExtensionUpdateResult,
+} from "@/types";
 import { DeleteConfirmationDialog } from "./delete-confirmation-dialog";
 import { RippleButton } from "./ui/ripple";
 
@@ -127,6 +134,33 @@ function getSyncStatusDot(
   }
 }
 
+type SourceKind = ExtensionSource["kind"] | "none";
+
+function getSourceValue(source?: ExtensionSource | null): string {
+  if (!source) return "";
+  switch (source.kind) {
+    case "chrome_web_store":
+      return source.id;
+    case "amo":
+      return source.slug;
+    case "url":
+      return source.url;
+  }
+}
+
+function buildSource(kind: SourceKind, value: string): ExtensionSource | null {
+  const trimmed = value.trim();
+  if (kind === "none" || !trimmed) return null;
+  switch (kind) {
+    case "chrome_web_store":
+      return { kind, id: trimmed };
+    case "amo":
+      return { kind, slug: trimmed };
+    case "url":
+      return { kind, url: trimmed };
+  }
+}
+
 interface ExtensionManagementDialogProps {
   isOpen: boolean;
   onClose: () => void;
@@ -196,6 +230,9 @@ export function ExtensionManagementDialog({
     name: string;
     data: number[];
   } | null>(null);
+  const [editSourceKind, setEditSourceKind] = useState<SourceKind>("none");
+  const [editSourceValue, setEditSourceValue] = useState("");
+  const [isCheckingUpdates, setIsCheckingUpdates] = useState(false);
 
   // Extension icons
   const [extensionIcons, setExtensionIcons] = useState<Record<string, string>>(
@@ -358,15 +395,67 @@ export function ExtensionManagementDialog({
         fileName: pendingUpdateFile?.name ?? null,
         fileData: pendingUpdateFile?.data ?? null,
       });
+      const source = buildSource(editSourceKind, editSourceValue);
+      if (
+        JSON.stringify(source) !==
+        JSON.stringify(editingExtension.source ?? null)
+      ) {
+        await invoke("set_extension_source", {
+          extensionId: editingExtension.id,
+          source,
+        });
+      }
       showSuccessToast(t("extensions.updateSuccess"));
       setEditingExtension(null);
       setEditExtensionName("");
       setPendingUpdateFile(null);
       void loadData();
+    } catch (err) {
+      showErrorToast(
+        parseBackendError(err)
+          ? translateBackendError(t, err)
+          : err instanceof Error
+            ? err.message
+            : String(err),
+      );
+    }
+  }, [
+    editingExtension,
+    editExtensionName,
+    editSourceKind,
+    editSourceValue,
+    pendingUpdateFile,
+    loadData,
+    t,
+  ]);
+
+  const handleCheckUpdates = useCallback(async () => {
+    setIsCheckingUpdates(true);
+    try {
+      const results = await invoke<ExtensionUpdateResult[]>(
+        "check_extension_updates",
+        { extensionIds: null },
+      );
+      const count = (status: ExtensionUpdateResult["status"]) =>
+        results.filter((r) => r.status === status).length;
+      if (results.length === 0) {
+        showSuccessToast(t("extensions.updates.noSources"));
+      } else {
+        showSuccessToast(
+          t("extensions.updates.summary", {
+            updated: count("updated"),
+            deferred: count("deferred"),
+            failed: count("failed"),
+          }),
+        );
+      }
+      void loadData();
     } catch (err) {
       showErrorToast(err instanceof Error ? err.message : String(err));
+    } finally {
+      setIsCheckingUpdates(false);
     }
-  }, [editingExtension, editExtensionName, pendingUpdateFile, loadData, t]);
+  }, [loadData, t]);
 
   const handleEditFileSelect = useCallback(
     (e: React.ChangeEvent<HTMLInputElement>) => {
@@ -430,6 +519,32 @@ export function ExtensionManagementDialog({
     [t],
   );
 
+  const handleLoadUnpacked = useCallback(async () => {
+    try {
+      const selected = await open({
+        directory: true,
+        multiple: false,
+        title: t("extensions.unpacked.selectTitle"),
+      });
+      if (typeof selected !== "string") return;
+      const baseName = selected.split(/[\\/]/).pop() ?? "";
+      await invoke("add_unpacked_extension", {
+        name: baseName,
+        path: selected,
+      });
+      showSuccessToast(t("extensions.uploadSuccess"));
+      void loadData();
+    } catch (err) {
+      showErrorToast(
+        parseBackendError(err)
+          ? translateBackendError(t, err)
+          : err instanceof Error
+            ? err.message
+            : String(err),
+      );
+    }
+  }, [loadData, t]);
+
   const handleUpload = useCallback(async () => {
     if (!pendingFile || !extensionName.trim()) return;
     setIsUploading(true);
@@ -824,6 +939,8 @@ export function ExtensionManagementDialog({
                     onClick={() => {
                       setEditingExtension(ext);
                       setEditExtensionName(ext.name);
+                      setEditSourceKind(ext.source?.kind ?? "none");
+                      setEditSourceValue(getSourceValue(ext.source));
                       setPendingUpdateFile(null);
                     }}
                   >
@@ -1159,6 +1276,32 @@ export function ExtensionManagementDialog({
                   </AnimatedTabsTrigger>
                 </AnimatedTabsList>
                 <div className="flex items-center gap-2">
+                  {activeTab === "extensions" && (
+                    <Tooltip>
+                      <TooltipTrigger asChild>
+                        <RippleButton
+                          size="sm"
+                          variant="outline"
+                          disabled={limitedMode || isCheckingUpdates}
+                          onClick={() => void handleCheckUpdates()}
+                          aria-label={t("extensions.updates.check")}
+                        >
+                          <LuRefreshCw
+                            className={cn(
+                              "size-4",
+                              isCheckingUpdates && "animate-spin",
+                            )}
+                          />
+                          <span className="hidden @2xl:inline">
+                            {t("extensions.updates.check")}
+                          </span>
+                        </RippleButton>
+                      </TooltipTrigger>
+                      <TooltipContent>
+                        {t("extensions.updates.check")}
+                      </TooltipContent>
+                    </Tooltip>
+                  )}
                   {activeTab === "extensions" && (
                     <Tooltip>
                       <TooltipTrigger asChild>
@@ -1180,6 +1323,27 @@ export function ExtensionManagementDialog({
                       <TooltipContent>{t("extensions.upload")}</TooltipContent>
                     </Tooltip>
                   )}
+                  {activeTab === "extensions" && (
+                    <Tooltip>
+                      <TooltipTrigger asChild>
+                        <RippleButton
+                          size="sm"
+                          variant="outline"
+                          disabled={limitedMode}
+                          onClick={() => void handleLoadUnpacked()}
+                          aria-label={t("extensions.unpacked.load")}
+                        >
+                          <LuFolderOpen className="size-4" />
+                          <span className="hidden @2xl:inline">
+                            {t("extensions.unpacked.load")}
+                          </span>
+                        </RippleButton>
+                      </TooltipTrigger>
+                      <TooltipContent>
+                        {t("extensions.unpacked.tooltip")}
+                      </TooltipContent>
+                    </Tooltip>
+                  )}
                   {activeTab === "groups" && (
                     <Tooltip>
                       <TooltipTrigger asChild>
@@ -1686,7 +1850,13 @@ export function ExtensionManagementDialog({
                     <span className="text-muted-foreground">
                       {t("common.labels.type")}
                     </span>
-                    <span>.{editingExtension.file_type}</span>
+                    {editingExtension.file_type === "unpacked" ? (
+                      <span className="truncate">
+                        {editingExtension.unpacked_path}
+                      </span>
+                    ) : (
+                      <span>.{editingExtension.file_type}</span>
+                    )}
                     {editingExtension.homepage_url && (
                       <>
                         <span className="text-muted-foreground">
@@ -1716,6 +1886,51 @@ export function ExtensionManagementDialog({
                   </div>
                 </div>
 
+                {/* Update source */}
+                <div className="space-y-2">
+                  <Label>{t("extensions.updates.source")}</Label>
+                  <div className="flex items-center gap-2">
+                    <Select
+                      value={editSourceKind}
+                      onValueChange={(value) => {
+                        setEditSourceKind(value as SourceKind);
+                      }}
+                    >
+                      <SelectTrigger className="w-44 shrink-0">
+                        <SelectValue />
+                      </SelectTrigger>
+                      <SelectContent>
+                        <SelectItem value="none">
+                          {t("extensions.updates.sourceNone")}
+                        </SelectItem>
+                        <SelectItem value="chrome_web_store">
+                          {t("extensions.updates.sourceChromeWebStore")}
+                        </SelectItem>
+                        <SelectItem value="amo">
+                          {t("extensions.updates.sourceAmo")}
+                        </SelectItem>
+                        <SelectItem value="url">
+                          {t("extensions.updates.sourceUrl")}
+                        </SelectItem>
+                      </SelectContent>
+                    </Select>
+                    {editSourceKind !== "none" && (
+                      <Input
+                        value={editSourceValue}
+                        onChange={(e) => {
+                          setEditSourceValue(e.target.value);
+                        }}
+                        placeholder={t(
+                          `extensions.updates.placeholder.${editSourceKind}`,
+                        )}
+                      />
+                    )}
+                  </div>
+                  <p className="text-xs text-muted-foreground">
+                    {t("extensions.updates.sourceDescription")}
+                  </p>
+                </div>
+
                 {/* Re-upload */}
                 <div className="space-y-2">
                   <Label>{t("extensions.reupload")}</Label>
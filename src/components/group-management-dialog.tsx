@@ -367,11 +367,20 @@ export function GroupManagementDialog({
       },
       {
         id: "count",
-        size: 80,
+        size: 120,
         enableSorting: false,
         header: () => t("groupManagement.profilesCol"),
         cell: ({ row }) => (
-          <Badge variant="secondary">{row.original.count}</Badge>
+          <div className="flex items-center gap-1.5">
+            <Badge variant="secondary">{row.original.count}</Badge>
+            {(row.original.archived_count ?? 0) > 0 && (
+              <span className="text-[11px] text-muted-foreground">
+                {t("groupManagement.archivedCount", {
+                  count: row.original.archived_count,
+                })}
+              </span>
+            )}
+          </div>
         ),
       },
       {
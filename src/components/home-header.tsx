@@ -1,10 +1,22 @@
 "use client";
 
 import { getCurrentWindow } from "@tauri-apps/api/window";
-import { useCallback, useEffect, useRef, useState } from "react";
+import {
+  type ReactNode,
+  useCallback,
+  useEffect,
+  useRef,
+  useState,
+} from "react";
 import { useTranslation } from "react-i18next";
 import { GoPlus } from "react-icons/go";
-import { LuChevronLeft, LuChevronRight, LuSearch, LuX } from "react-icons/lu";
+import {
+  LuArchive,
+  LuChevronLeft,
+  LuChevronRight,
+  LuSearch,
+  LuX,
+} from "react-icons/lu";
 import { getCurrentOS } from "@/lib/browser-utils";
 import { cn } from "@/lib/utils";
 import type { GroupWithCount } from "@/types";
@@ -38,7 +50,11 @@ interface Props {
   totalProfiles: number;
   selectedGroupId: string | null;
   onGroupSelect: (groupId: string) => void;
+  archivedCount: number;
+  showArchived: boolean;
+  onShowArchivedChange: (show: boolean) => void;
   pageTitle?: string;
+  savedViewsMenu?: ReactNode;
 }
 
 const HomeHeader = ({
@@ -49,7 +65,11 @@ const HomeHeader = ({
   totalProfiles,
   selectedGroupId,
   onGroupSelect,
+  archivedCount,
+  showArchived,
+  onShowArchivedChange,
   pageTitle,
+  savedViewsMenu,
 }: Props) => {
   const { t } = useTranslation();
   const [platform, setPlatform] = useState<string>("macos");
@@ -277,11 +297,33 @@ const HomeHeader = ({
                 >
                   <span className="max-w-40 truncate">{group.name}</span>
                   <span className="text-[11px] text-muted-foreground tabular-nums">
-                    {group.count}
+                    {showArchived ? (group.archived_count ?? 0) : group.count}
                   </span>
                 </button>
               );
             })}
+            {/* Archived profiles stay out of every list until toggled on. */}
+            {(archivedCount > 0 || showArchived) && (
+              <button
+                type="button"
+                aria-pressed={showArchived}
+                onClick={() => {
+                  onShowArchivedChange(!showArchived);
+                }}
+                className={cn(
+                  "flex h-7 shrink-0 items-center gap-1.5 px-1 text-xs transition-colors duration-100",
+                  showArchived
+                    ? "font-medium text-foreground"
+                    : "text-muted-foreground hover:text-foreground",
+                )}
+              >
+                <LuArchive className="size-3" />
+                <span>{t("header.archived")}</span>
+                <span className="text-[11px] text-muted-foreground tabular-nums">
+                  {archivedCount}
+                </span>
+              </button>
+            )}
           </div>
           {groupsFadeRight && (
             <button
@@ -305,6 +347,8 @@ const HomeHeader = ({
 
       {!showProfileToolbar && <div className="flex-1" />}
 
+      {showProfileToolbar && savedViewsMenu}
+
       {showProfileToolbar && (
         <div className="relative shrink-0">
           <Input
@@ -6,7 +6,7 @@ import { open } from "@tauri-apps/plugin-dialog";
 import { useReducedMotion } from "motion/react";
 import { useCallback, useEffect, useMemo, useState } from "react";
 import { useTranslation } from "react-i18next";
-import { FaFileArchive, FaFolder } from "react-icons/fa";
+import { FaFileArchive, FaFileImport, FaFolder } from "react-icons/fa";
 import { LuChevronRight } from "react-icons/lu";
 import { toast } from "sonner";
 import { LoadingButton } from "@/components/loading-button";
@@ -50,6 +50,8 @@ import { cn } from "@/lib/utils";
 import type {
   ArchiveScanResult,
   DetectedProfile,
+  ExternalImportResult,
+  ImportCategory,
   ImportProfileItem,
   ProfileImportBatchResult,
   ProfileImportProgress,
@@ -68,6 +70,23 @@ type Step = "select" | "configure" | "importing";
 type ImportMode = "auto-detect" | "manual";
 type DuplicateStrategy = "rename" | "skip";
 
+const IMPORT_CATEGORIES: ImportCategory[] = [
+  "cookies",
+  "logins",
+  "bookmarks",
+  "history",
+  "extensions",
+];
+
+function formatBytes(bytes: number): string {
+  if (!Number.isFinite(bytes) || bytes <= 0) return "0 B";
+  if (bytes < 1024) return `${bytes} B`;
+  if (bytes < 1024 * 1024) return `${(bytes / 1024).toFixed(1)} KB`;
+  if (bytes < 1024 * 1024 * 1024)
+    return `${(bytes / (1024 * 1024)).toFixed(1)} MB`;
+  return `${(bytes / (1024 * 1024 * 1024)).toFixed(2)} GB`;
+}
+
 export function ImportProfileDialog({
   isOpen,
   onClose,
@@ -100,6 +119,11 @@ export function ImportProfileDialog({
   // "none" | a VPN config id (applied to every imported profile)
   const [vpnAssignment, setVpnAssignment] = useState<string>("none");
   const [wayfernConfig, setWayfernConfig] = useState<WayfernConfig>({});
+  // Full copy by default; unchecking it picks categories into a fresh profile.
+  const [copyWholeProfile, setCopyWholeProfile] = useState(true);
+  const [importCategories, setImportCategories] = useState<
+    Set<ImportCategory>
+  >(new Set(IMPORT_CATEGORIES));
   // Fingerprint + advanced options collapse behind disclosures — the default
   // path is just names + proxy/VPN.
   const [showFingerprint, setShowFingerprint] = useState(false);
@@ -236,6 +260,64 @@ export function ImportProfileDialog({
     }
   };
 
+  // AdsPower / Multilogin exports carry their own proxy and fingerprint data,
+  // so they skip the configure step and import straight away.
+  const handleImportExternal = async () => {
+    let selected: string | string[] | null;
+    try {
+      selected = await open({
+        multiple: false,
+        title: t("importProfile.external.selectTitle"),
+        filters: [{ name: "JSON", extensions: ["json"] }],
+      });
+    } catch (error) {
+      console.error("Failed to open export dialog:", error);
+      toast.error(t("importProfile.folderDialogFailed"));
+      return;
+    }
+    if (!selected || typeof selected !== "string") return;
+
+    setCurrentStep("importing");
+    setIsImporting(true);
+    setProgress(null);
+    setResult(null);
+    try {
+      const external = await invoke<ExternalImportResult>(
+        "import_external_profiles",
+        { path: selected, groupId: null },
+      );
+      setResult({
+        imported_count: external.imported_count,
+        skipped_count: 0,
+        failed_count: external.failed_count,
+        results: external.results.map((item) => ({
+          name: item.name,
+          source_path: item.source_file,
+          status: item.status,
+          profile_id: item.profile_id,
+          error: item.error,
+          warnings: item.warnings,
+        })),
+      });
+      toast.success(
+        t("importProfile.external.summary", {
+          imported: external.imported_count,
+          failed: external.failed_count,
+          proxies: external.proxies_created,
+        }),
+      );
+      if (external.imported_count > 0 && !reducedMotion) {
+        fireSprinkleConfetti();
+      }
+    } catch (error) {
+      console.error("Failed to import external profiles:", error);
+      toast.error(translateBackendError(t, error));
+      setCurrentStep("select");
+    } finally {
+      setIsImporting(false);
+    }
+  };
+
   const togglePath = (path: string, checked: boolean) => {
     setSelectedPaths((prev) => {
       const next = new Set(prev);
@@ -295,12 +377,21 @@ export function ImportProfileDialog({
       return;
     }
 
+    if (!copyWholeProfile && importCategories.size === 0) {
+      toast.error(t("importProfile.selectAtLeastOneCategory"));
+      return;
+    }
+
+    const categories = copyWholeProfile
+      ? null
+      : IMPORT_CATEGORIES.filter((c) => importCategories.has(c));
     const items: ImportProfileItem[] = selectedProfiles.map((p, index) => ({
       source_path: p.path,
       browser_type: p.browser,
       new_profile_name: (profileNames[p.path] ?? p.name).trim(),
       proxy_id: proxyIdForIndex(index),
       vpn_id: vpnAssignment === "none" ? null : vpnAssignment,
+      categories,
     }));
 
     setCurrentStep("importing");
@@ -343,6 +434,8 @@ export function ImportProfileDialog({
     selectedGroupId,
     duplicateStrategy,
     wayfernConfig,
+    copyWholeProfile,
+    importCategories,
     reducedMotion,
     t,
   ]);
@@ -363,6 +456,8 @@ export function ImportProfileDialog({
     setProxyAssignment("none");
     setVpnAssignment("none");
     setWayfernConfig({});
+    setCopyWholeProfile(true);
+    setImportCategories(new Set(IMPORT_CATEGORIES));
     setShowFingerprint(false);
     setShowAdvanced(false);
     setProgress(null);
@@ -433,6 +528,22 @@ export function ImportProfileDialog({
                 <p className="truncate text-xs text-muted-foreground">
                   {profile.path}
                 </p>
+                <p className="truncate text-xs text-muted-foreground">
+                  {formatBytes(profile.size_bytes)}
+                  {profile.data_categories.length > 0 && (
+                    <>
+                      {" · "}
+                      {profile.data_categories
+                        .map((c) => t(`importProfile.categories.${c}`))
+                        .join(", ")}
+                    </>
+                  )}
+                </p>
+                {profile.non_portable_categories.includes("logins") && (
+                  <p className="truncate text-xs text-warning">
+                    {t("importProfile.loginsNotPortable")}
+                  </p>
+                )}
               </div>
             </label>
           );
@@ -565,6 +676,26 @@ export function ImportProfileDialog({
                       </p>
                     </div>
 
+                    <div className="flex items-center justify-between gap-4 rounded-lg border border-border p-3">
+                      <div className="space-y-1">
+                        <p className="text-sm font-medium">
+                          {t("importProfile.external.title")}
+                        </p>
+                        <p className="text-xs text-muted-foreground">
+                          {t("importProfile.external.description")}
+                        </p>
+                      </div>
+                      <Button
+                        variant="outline"
+                        size="sm"
+                        className="shrink-0"
+                        onClick={() => void handleImportExternal()}
+                      >
+                        <FaFileImport className="size-3.5" />
+                        {t("importProfile.external.button")}
+                      </Button>
+                    </div>
+
                     {scannedProfiles.length > 0 &&
                       renderProfileList(scannedProfiles)}
                   </div>
@@ -646,6 +777,54 @@ export function ImportProfileDialog({
                   </Select>
                 </div>
 
+                <div className="space-y-2">
+                  <Label>{t("importProfile.dataToImport")}</Label>
+                  <label
+                    htmlFor="import-copy-whole-profile"
+                    className="flex cursor-pointer items-center gap-2 text-sm"
+                  >
+                    <Checkbox
+                      id="import-copy-whole-profile"
+                      checked={copyWholeProfile}
+                      onCheckedChange={(checked) =>
+                        setCopyWholeProfile(checked === true)
+                      }
+                    />
+                    {t("importProfile.copyWholeProfile")}
+                  </label>
+                  {!copyWholeProfile && (
+                    <div className="grid grid-cols-2 gap-2 pl-6">
+                      {IMPORT_CATEGORIES.map((category) => (
+                        <label
+                          key={category}
+                          htmlFor={`import-category-${category}`}
+                          className="flex cursor-pointer items-center gap-2 text-sm"
+                        >
+                          <Checkbox
+                            id={`import-category-${category}`}
+                            checked={importCategories.has(category)}
+                            onCheckedChange={(checked) => {
+                              setImportCategories((prev) => {
+                                const next = new Set(prev);
+                                if (checked === true) {
+                                  next.add(category);
+                                } else {
+                                  next.delete(category);
+                                }
+                                return next;
+                              });
+                            }}
+                          />
+                          {t(`importProfile.categories.${category}`)}
+                        </label>
+                      ))}
+                    </div>
+                  )}
+                  <p className="text-xs text-muted-foreground">
+                    {t("importProfile.dataToImportHint")}
+                  </p>
+                </div>
+
                 {vpnConfigs.length > 0 && (
                   <div>
                     <Label className="mb-2">
@@ -824,6 +1003,17 @@ export function ImportProfileDialog({
                         {progress.status === "importing" && (
                           <> — {progress.name}</>
                         )}
+                        {progress.status === "copying" && progress.category && (
+                          <>
+                            {" — "}
+                            {t("importProfile.copyingCategory", {
+                              name: progress.name,
+                              category: t(
+                                `importProfile.categories.${progress.category}`,
+                              ),
+                            })}
+                          </>
+                        )}
                       </p>
                     )}
                   </div>
@@ -839,10 +1029,10 @@ export function ImportProfileDialog({
                       })}
                     </h3>
                     <div className="max-h-64 space-y-1 overflow-y-auto rounded-lg border border-border p-2">
-                      {result.results.map((item) => (
+                      {result.results.map((item, index) => (
                         <div
-                          key={item.source_path}
-                          className="flex items-center gap-2 p-1 text-sm"
+                          key={`${item.source_path}-${index}`}
+                          className="flex flex-wrap items-center gap-2 p-1 text-sm"
                         >
                           <span
                             className={cn(
@@ -868,6 +1058,14 @@ export function ImportProfileDialog({
                               {translateBackendError(t, new Error(item.error))}
                             </span>
                           )}
+                          {item.warnings?.map((warning) => (
+                            <span
+                              key={warning}
+                              className="w-full truncate pl-4 text-xs text-muted-foreground"
+                            >
+                              {translateBackendError(t, new Error(warning))}
+                            </span>
+                          ))}
                         </div>
                       ))}
                     </div>
@@ -13,6 +13,7 @@ import {
   LuTrash2,
   LuZap,
 } from "react-icons/lu";
+import { ApiTokensCard } from "@/components/api-tokens-card";
 import { AnimatedSwitch } from "@/components/ui/animated-switch";
 import {
   AnimatedTabs,
@@ -29,19 +30,33 @@ import {
 } from "@/components/ui/dialog";
 import { Input } from "@/components/ui/input";
 import { Label } from "@/components/ui/label";
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from "@/components/ui/select";
 import { useWayfernTerms } from "@/hooks/use-wayfern-terms";
 import { translateBackendError } from "@/lib/backend-errors";
 import { showErrorToast, showSuccessToast } from "@/lib/toast-utils";
 import { cn } from "@/lib/utils";
+import type { ApiEndpoint, ApiListen } from "@/types";
 import { CopyToClipboard } from "./ui/copy-to-clipboard";
 
 interface AppSettings {
   api_enabled: boolean;
   api_port: number;
+  api_listen?: ApiListen;
   api_token?: string;
+  api_rate_limit_per_second: number;
+  api_rate_limit_burst: number;
+  api_max_concurrent_launches: number;
+  api_max_body_bytes: number;
   mcp_enabled: boolean;
   mcp_port?: number;
   mcp_token?: string;
+  mcp_stdio_enabled?: boolean;
 }
 
 interface McpConfig {
@@ -67,6 +82,41 @@ interface IntegrationsDialogProps {
   initialTab?: "api" | "mcp";
 }
 
+function endpointAddress(endpoint: ApiEndpoint): string {
+  return endpoint.mode === "tcp"
+    ? `http://127.0.0.1:${endpoint.port}`
+    : endpoint.path;
+}
+
+/** Whether the running server already uses the configured transport. */
+function endpointMatches(endpoint: ApiEndpoint, listen: ApiListen): boolean {
+  switch (listen.mode) {
+    case "tcp":
+      return endpoint.mode === "tcp";
+    case "unix":
+      return endpoint.mode === "unix" && endpoint.path === listen.path;
+    case "windows_pipe":
+      return (
+        endpoint.mode === "windows_pipe" &&
+        endpoint.path.endsWith(`\\${listen.name}`)
+      );
+  }
+}
+
+function curlExample(endpoint: ApiEndpoint, token: string): string {
+  const auth = `-H "Authorization: Bearer ${token}"`;
+  switch (endpoint.mode) {
+    case "tcp":
+      return `curl ${auth} \\\n     http://127.0.0.1:${endpoint.port}/v1/profiles`;
+    case "unix":
+      return `curl --unix-socket ${endpoint.path} \\\n     ${auth} \\\n     http://localhost/v1/profiles`;
+    case "windows_pipe":
+      // curl has no named pipe support; any HTTP client that can open the
+      // pipe sends the same request.
+      return `# ${endpoint.path}\ncurl ${auth} \\\n     http://localhost/v1/profiles`;
+  }
+}
+
 function AgentIcon({ category }: { category: AgentCategory }) {
   const className = "size-4 text-muted-foreground";
   switch (category) {
@@ -97,6 +147,120 @@ function categoryLabel(
   }
 }
 
+const API_LIMIT_FIELDS = [
+  "api_rate_limit_per_second",
+  "api_rate_limit_burst",
+  "api_max_concurrent_launches",
+  "api_max_body_mb",
+] as const;
+
+type ApiLimitField = (typeof API_LIMIT_FIELDS)[number];
+
+const BYTES_PER_MB = 1024 * 1024;
+
+function ApiLimitsCard({
+  settings,
+  onSaved,
+}: {
+  settings: AppSettings;
+  onSaved: (next: AppSettings) => void;
+}) {
+  const { t } = useTranslation();
+  const initial = useCallback(
+    (): Record<ApiLimitField, string> => ({
+      api_rate_limit_per_second: String(settings.api_rate_limit_per_second),
+      api_rate_limit_burst: String(settings.api_rate_limit_burst),
+      api_max_concurrent_launches: String(
+        settings.api_max_concurrent_launches,
+      ),
+      api_max_body_mb: String(
+        Math.round(settings.api_max_body_bytes / BYTES_PER_MB),
+      ),
+    }),
+    [settings],
+  );
+  const [drafts, setDrafts] = useState(initial);
+  const [isSaving, setIsSaving] = useState(false);
+
+  useEffect(() => {
+    setDrafts(initial());
+  }, [initial]);
+
+  const parsed = Object.fromEntries(
+    API_LIMIT_FIELDS.map((field) => [
+      field,
+      Number.parseInt(drafts[field], 10),
+    ]),
+  ) as Record<ApiLimitField, number>;
+  const isValid =
+    API_LIMIT_FIELDS.every(
+      (field) => !Number.isNaN(parsed[field]) && parsed[field] >= 0,
+    ) &&
+    parsed.api_rate_limit_burst >= 1 &&
+    parsed.api_max_body_mb >= 1;
+
+  const handleSave = async () => {
+    setIsSaving(true);
+    try {
+      const next = await invoke<AppSettings>("save_app_settings", {
+        settings: {
+          ...settings,
+          api_rate_limit_per_second: parsed.api_rate_limit_per_second,
+          api_rate_limit_burst: parsed.api_rate_limit_burst,
+          api_max_concurrent_launches: parsed.api_max_concurrent_launches,
+          api_max_body_bytes: parsed.api_max_body_mb * BYTES_PER_MB,
+        },
+      });
+      onSaved(next);
+      showSuccessToast(t("integrations.limits.saved"));
+    } catch (e) {
+      showErrorToast(translateBackendError(t, e));
+    } finally {
+      setIsSaving(false);
+    }
+  };
+
+  return (
+    <div className="flex flex-col gap-3 rounded-md border bg-card p-4">
+      <div className="flex flex-col gap-1">
+        <Label className="text-[10px] tracking-wide text-muted-foreground uppercase">
+          {t("integrations.limits.title")}
+        </Label>
+        <p className="text-xs text-muted-foreground">
+          {t("integrations.limits.description")}
+        </p>
+      </div>
+      <div className="grid grid-cols-2 gap-3">
+        {API_LIMIT_FIELDS.map((field) => (
+          <div key={field} className="flex flex-col gap-1">
+            <Label className="text-xs">
+              {t(`integrations.limits.fields.${field}`)}
+            </Label>
+            <Input
+              type="number"
+              min={0}
+              value={drafts[field]}
+              onChange={(e) => {
+                setDrafts((prev) => ({ ...prev, [field]: e.target.value }));
+              }}
+              className="font-mono"
+            />
+          </div>
+        ))}
+      </div>
+      <Button
+        size="sm"
+        variant="outline"
+        className="self-end"
+        disabled={isSaving || !isValid}
+        onClick={() => void handleSave()}
+      >
+        {t("common.buttons.save")}
+      </Button>
+    </div>
+  );
+}
+
 export function IntegrationsDialog({
   isOpen,
   onClose,
@@ -108,11 +272,15 @@ export function IntegrationsDialog({
     api_enabled: false,
     api_port: 10108,
     api_token: undefined,
+    api_rate_limit_per_second: 30,
+    api_rate_limit_burst: 60,
+    api_max_concurrent_launches: 4,
+    api_max_body_bytes: 10 * 1024 * 1024,
     mcp_enabled: false,
     mcp_port: undefined,
     mcp_token: undefined,
   });
-  const [apiServerPort, setApiServerPort] = useState<number | null>(null);
+  const [apiEndpoint, setApiEndpoint] = useState<ApiEndpoint | null>(null);
   const [mcpConfig, setMcpConfig] = useState<McpConfig | null>(null);
   const [, setMcpRunning] = useState(false);
   const [showApiToken, setShowApiToken] = useState(false);
@@ -155,8 +323,10 @@ export function IntegrationsDialog({
 
   const loadApiServerStatus = useCallback(async () => {
     try {
-      const port = await invoke<number | null>("get_api_server_status");
-      setApiServerPort(port);
+      const endpoint = await invoke<ApiEndpoint | null>(
+        "get_api_server_status",
+      );
+      setApiEndpoint(endpoint);
     } catch (e) {
       console.error("Failed to get API server status:", e);
     }
@@ -188,22 +358,27 @@ export function IntegrationsDialog({
     loadAgents,
   ]);
 
+  const startedMessage = (endpoint: ApiEndpoint) =>
+    endpoint.mode === "tcp"
+      ? t("integrations.apiStarted", { port: endpoint.port })
+      : t("integrations.apiStartedAt", { address: endpoint.path });
+
   const handleApiToggle = async (enabled: boolean) => {
     setIsApiStarting(true);
     try {
       if (enabled) {
-        const port = await invoke<number>("start_api_server", {
+        const endpoint = await invoke<ApiEndpoint>("start_api_server", {
           port: settings.api_port,
         });
-        setApiServerPort(port);
+        setApiEndpoint(endpoint);
         const next = await invoke<AppSettings>("save_app_settings", {
           settings: { ...settings, api_enabled: true },
         });
         setSettings(next);
-        showSuccessToast(t("integrations.apiStarted", { port }));
+        showSuccessToast(startedMessage(endpoint));
       } else {
         await invoke("stop_api_server");
-        setApiServerPort(null);
+        setApiEndpoint(null);
         const next = await invoke<AppSettings>("save_app_settings", {
           settings: { ...settings, api_enabled: false, api_token: null },
         });
@@ -221,6 +396,55 @@ export function IntegrationsDialog({
     }
   };
 
+  /** Save the settings and restart the server on the port/transport in them. */
+  const restartApiServer = async () => {
+    const port = settings.api_port;
+    setIsApiStarting(true);
+    try {
+      await invoke("stop_api_server");
+      const next = await invoke<AppSettings>("save_app_settings", {
+        settings,
+      });
+      setSettings(next);
+      const endpoint = await invoke<ApiEndpoint>("start_api_server", {
+        port,
+      });
+      setApiEndpoint(endpoint);
+      if (endpoint.mode === "tcp" && endpoint.port !== port) {
+        showErrorToast(t("integrations.apiPortInUse", { port }), {
+          description: t("integrations.apiFallbackPort", {
+            port: endpoint.port,
+          }),
+        });
+      } else if (endpoint.mode === "tcp") {
+        showSuccessToast(t("integrations.apiRunning", { port: endpoint.port }));
+      } else {
+        showSuccessToast(
+          t("integrations.apiRunningAt", { address: endpoint.path }),
+        );
+      }
+    } catch (e) {
+      setApiEndpoint(null);
+      showErrorToast(t("integrations.apiStartFailed"), {
+        description: translateBackendError(t, e),
+      });
+    } finally {
+      setIsApiStarting(false);
+    }
+  };
+
+  const exampleEndpoint: ApiEndpoint = apiEndpoint ?? {
+    mode: "tcp",
+    port: settings.api_port,
+  };
+  const apiListen: ApiListen = settings.api_listen ?? { mode: "tcp" };
+  const apiListenValue =
+    apiListen.mode === "unix"
+      ? apiListen.path
+      : apiListen.mode === "windows_pipe"
+        ? apiListen.name
+        : "";
+
   const handleMcpToggle = async (enabled: boolean) => {
     setIsMcpStarting(true);
     try {
@@ -253,6 +477,21 @@ export function IntegrationsDialog({
     }
   };
 
+  const handleMcpStdioToggle = async (enabled: boolean) => {
+    try {
+      const next = await invoke<AppSettings>("save_app_settings", {
+        settings: { ...settings, mcp_stdio_enabled: enabled },
+      });
+      setSettings(next);
+    } catch (e) {
+      console.error("Failed to toggle MCP stdio:", e);
+      showErrorToast(t("integrations.mcpToggleFailed"), {
+        description:
+          e instanceof Error ? e.message : t("integrations.apiUnknownError"),
+      });
+    }
+  };
+
   const markAgentBusy = (id: string, busy: boolean) => {
     setBusyAgentIds((prev) => {
       const next = new Set(prev);
@@ -345,7 +584,7 @@ export function IntegrationsDialog({
                       </div>
                     </div>
                     <AnimatedSwitch
-                      checked={apiServerPort !== null}
+                      checked={apiEndpoint !== null}
                       disabled={isApiStarting}
                       onCheckedChange={(checked) =>
                         void handleApiToggle(checked)
@@ -353,14 +592,14 @@ export function IntegrationsDialog({
                     />
                   </div>
 
-                  {apiServerPort && (
+                  {apiEndpoint && (
                     <div className="flex items-center gap-2 text-xs">
                       <span className="size-1.5 rounded-full bg-success" />
                       <span className="text-muted-foreground">
                         {t("integrations.apiRunningOn")}
                       </span>
                       <code className="rounded bg-muted px-2 py-1 font-mono text-[11px]">
-                        http://127.0.0.1:{apiServerPort}
+                        {endpointAddress(apiEndpoint)}
                       </code>
                     </div>
                   )}
@@ -403,9 +642,10 @@ export function IntegrationsDialog({
                             variant="outline"
                             disabled={
                               isApiStarting ||
-                              apiServerPort === settings.api_port
+                              (apiEndpoint?.mode === "tcp" &&
+                                apiEndpoint.port === settings.api_port)
                             }
-                            onClick={async () => {
+                            onClick={() => {
                               const port = settings.api_port;
                               if (port < 1 || port > 65535) {
                                 showErrorToast(
@@ -418,49 +658,7 @@ export function IntegrationsDialog({
                                 );
                                 return;
                               }
-                              setIsApiStarting(true);
-                              try {
-                                await invoke("stop_api_server");
-                                const next = await invoke<AppSettings>(
-                                  "save_app_settings",
-                                  { settings },
-                                );
-                                setSettings(next);
-                                const actualPort = await invoke<number>(
-                                  "start_api_server",
-                                  { port },
-                                );
-                                setApiServerPort(actualPort);
-                                if (actualPort !== port) {
-                                  showErrorToast(
-                                    t("integrations.apiPortInUse", { port }),
-                                    {
-                                      description: t(
-                                        "integrations.apiFallbackPort",
-                                        { port: actualPort },
-                                      ),
-                                    },
-                                  );
-                                } else {
-                                  showSuccessToast(
-                                    t("integrations.apiRunning", {
-                                      port: actualPort,
-                                    }),
-                                  );
-                                }
-                              } catch (e) {
-                                showErrorToast(
-                                  t("integrations.apiStartFailed"),
-                                  {
-                                    description:
-                                      e instanceof Error
-                                        ? e.message
-                                        : t("integrations.apiUnknownError"),
-                                  },
-                                );
-                              } finally {
-                                setIsApiStarting(false);
-                              }
+                              void restartApiServer();
                             }}
                           >
                             {t("common.buttons.save")}
@@ -468,6 +666,79 @@ export function IntegrationsDialog({
                         </div>
                       </div>
 
+                      <div className="flex flex-col gap-2 rounded-md border bg-card p-4">
+                        <Label className="text-[10px] tracking-wide text-muted-foreground uppercase">
+                          {t("integrations.apiTransportLabel")}
+                        </Label>
+                        <div className="flex items-center gap-2">
+                          <Select
+                            value={apiListen.mode}
+                            onValueChange={(mode) => {
+                              const next: ApiListen =
+                                mode === "unix"
+                                  ? { mode, path: "" }
+                                  : mode === "windows_pipe"
+                                    ? { mode, name: "donutbrowser-api" }
+                                    : { mode: "tcp" };
+                              setSettings({ ...settings, api_listen: next });
+                            }}
+                          >
+                            <SelectTrigger className="w-36">
+                              <SelectValue />
+                            </SelectTrigger>
+                            <SelectContent>
+                              <SelectItem value="tcp">
+                                {t("integrations.apiTransport.tcp")}
+                              </SelectItem>
+                              <SelectItem value="unix">
+                                {t("integrations.apiTransport.unix")}
+                              </SelectItem>
+                              <SelectItem value="windows_pipe">
+                                {t("integrations.apiTransport.windowsPipe")}
+                              </SelectItem>
+                            </SelectContent>
+                          </Select>
+                          {apiListen.mode !== "tcp" && (
+                            <Input
+                              value={apiListenValue}
+                              onChange={(e) => {
+                                const value = e.target.value;
+                                setSettings({
+                                  ...settings,
+                                  api_listen:
+                                    apiListen.mode === "unix"
+                                      ? { mode: "unix", path: value }
+                                      : { mode: "windows_pipe", name: value },
+                                });
+                              }}
+                              placeholder={
+                                apiListen.mode === "unix"
+                                  ? "/tmp/donutbrowser-api.sock"
+                                  : "donutbrowser-api"
+                              }
+                              className="min-w-0 flex-1 font-mono"
+                            />
+                          )}
+                          <Button
+                            size="sm"
+                            variant="outline"
+                            disabled={
+                              isApiStarting ||
+                              (apiListen.mode !== "tcp" &&
+                                !apiListenValue.trim()) ||
+                              (apiEndpoint !== null &&
+                                endpointMatches(apiEndpoint, apiListen))
+                            }
+                            onClick={() => void restartApiServer()}
+                          >
+                            {t("common.buttons.save")}
+                          </Button>
+                        </div>
+                        <p className="text-xs text-muted-foreground">
+                          {t("integrations.apiTransportHint")}
+                        </p>
+                      </div>
+
                       <div className="flex flex-col gap-2 rounded-md border bg-card p-4">
                         <div className="flex items-center justify-between">
                           <Label className="text-[10px] tracking-wide text-muted-foreground uppercase">
@@ -504,6 +775,17 @@ export function IntegrationsDialog({
                           />
                         </div>
                       </div>
+
+                      {/* Keyed on the default token so the list reloads
+                          when the API toggle rotates it. */}
+                      <ApiTokensCard
+                        key={settings.api_token ?? "none"}
+                        onRevoked={() => void loadSettings()}
+                      />
+                      <ApiLimitsCard
+                        settings={settings}
+                        onSaved={setSettings}
+                      />
                     </div>
 
                     <div className="flex flex-col gap-2 rounded-md border bg-card p-4">
@@ -512,13 +794,15 @@ export function IntegrationsDialog({
                           {t("integrations.apiExampleRequest")}
                         </Label>
                         <CopyToClipboard
-                          text={`curl -H "Authorization: Bearer ${settings.api_token ?? "${TOKEN}"}" \\\n     http://127.0.0.1:${apiServerPort ?? settings.api_port}/v1/profiles`}
+                          text={curlExample(
+                            exampleEndpoint,
+                            settings.api_token ?? "${TOKEN}",
+                          )}
                           successMessage={t("common.buttons.copied")}
                         />
                       </div>
                       <pre className="overflow-x-auto rounded bg-background p-3 font-mono text-[11px] whitespace-pre">
-                        {`curl -H "Authorization: Bearer \${TOKEN}" \\
-     http://127.0.0.1:${apiServerPort ?? settings.api_port}/v1/profiles`}
+                        {curlExample(exampleEndpoint, "${TOKEN}")}
                       </pre>
                     </div>
                   </>
@@ -557,6 +841,30 @@ export function IntegrationsDialog({
                   </div>
                 </div>
 
+                <div className="flex items-start justify-between gap-3 rounded-md border bg-card p-4">
+                  <div className="flex items-start gap-3">
+                    <LuTerminal className="mt-0.5 size-5 text-muted-foreground" />
+                    <div className="flex flex-col gap-1">
+                      <Label className="text-sm font-medium">
+                        {t("integrations.mcpStdioLabel")}
+                      </Label>
+                      <p className="text-xs text-muted-foreground">
+                        {t("integrations.mcpStdioDescription")}
+                      </p>
+                      <code className="w-fit rounded bg-muted px-1.5 py-0.5 font-mono text-[11px]">
+                        donutbrowser --mcp-stdio
+                      </code>
+                    </div>
+                  </div>
+                  <AnimatedSwitch
+                    checked={settings.mcp_stdio_enabled ?? false}
+                    disabled={!termsAccepted}
+                    onCheckedChange={(checked) =>
+                      void handleMcpStdioToggle(checked)
+                    }
+                  />
+                </div>
+
                 {mcpConfig && (
                   <>
                     <div className="flex flex-col gap-2 rounded-md border bg-card p-4">
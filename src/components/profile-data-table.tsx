@@ -24,6 +24,7 @@ import {
   LuCheck,
   LuChevronDown,
   LuChevronUp,
+  LuClock,
   LuCookie,
   LuInfo,
   LuLock,
@@ -80,11 +81,13 @@ import {
 } from "@/components/ui/tooltip";
 import { useBrowserState } from "@/hooks/use-browser-state";
 import { useCloudAuth } from "@/hooks/use-cloud-auth";
+import { useLaunchQueue } from "@/hooks/use-launch-queue";
 import { useProxyEvents } from "@/hooks/use-proxy-events";
 import { useScrollFade } from "@/hooks/use-scroll-fade";
 import { useTableSorting } from "@/hooks/use-table-sorting";
 import { useTeamLocks } from "@/hooks/use-team-locks";
 import { useVpnEvents } from "@/hooks/use-vpn-events";
+import { translateBackendError } from "@/lib/backend-errors";
 import {
   getBrowserDisplayName,
   getOSDisplayName,
@@ -93,12 +96,16 @@ import {
 } from "@/lib/browser-utils";
 import { DNS_BLOCKLIST_LEVELS } from "@/lib/dns-blocklist-levels";
 import { formatRelativeTime } from "@/lib/flag-utils";
+import { SAVED_VIEW_COLUMNS } from "@/lib/saved-views";
+import { showErrorToast } from "@/lib/toast-utils";
 import { cn } from "@/lib/utils";
 import type {
   BrowserProfile,
   ExtensionGroup,
   LocationItem,
   ProxyCheckResult,
+  QueuedLaunch,
+  SavedView,
   StoredProxy,
   SyncSessionInfo,
   TrafficSnapshot,
@@ -138,6 +145,7 @@ interface TableMeta {
   runningProfiles: Set<string>;
   launchingProfiles: Set<string>;
   stoppingProfiles: Set<string>;
+  queuedLaunches: Map<string, QueuedLaunch>;
   isUpdating: (browser: string) => boolean;
   browserState: ReturnType<typeof useBrowserState>;
 
@@ -211,6 +219,7 @@ interface TableMeta {
   setStoppingProfiles: React.Dispatch<React.SetStateAction<Set<string>>>;
   onKillProfile: (profile: BrowserProfile) => void | Promise<void>;
   onLaunchProfile: (profile: BrowserProfile) => void | Promise<void>;
+  onCancelQueuedLaunch: (profileId: string) => Promise<void>;
 
   // Overflow actions
   onAssignProfilesToGroup?: (profileIds: string[]) => void;
@@ -1132,6 +1141,8 @@ interface ProfilesDataTableProps {
   onDeleteSelectedProfiles: (profileIds: string[]) => Promise<void>;
   onAssignProfilesToGroup: (profileIds: string[]) => void;
   selectedGroupId: string | null;
+  /** Active saved view; its sort is applied once and its columns hidden. */
+  savedView?: SavedView | null;
   selectedProfiles: string[];
   onSelectedProfilesChange: Dispatch<SetStateAction<string[]>>;
   onBulkDelete?: () => void;
@@ -1186,6 +1197,7 @@ export function ProfilesDataTable({
   runningProfiles,
   isUpdating,
   onAssignProfilesToGroup,
+  savedView = null,
   selectedProfiles,
   onSelectedProfilesChange,
   onBulkDelete,
@@ -1316,6 +1328,7 @@ export function ProfilesDataTable({
 
   const { storedProxies } = useProxyEvents();
   const { vpnConfigs } = useVpnEvents();
+  const { queued: queuedLaunches, cancelQueuedLaunch } = useLaunchQueue();
   const { user } = useCloudAuth();
   const { isProfileLocked, getLockInfo } = useTeamLocks(user?.id);
 
@@ -1736,6 +1749,24 @@ export function ProfilesDataTable({
     }
   }, [isLoaded, getTableSorting, browserState.isClient]);
 
+  // Switching to a saved view applies its sort once; the user can re-sort
+  // afterwards without the view snapping it back.
+  const appliedViewIdRef = React.useRef<string | null>(null);
+  React.useEffect(() => {
+    const viewId = savedView?.id ?? null;
+    if (!isLoaded || appliedViewIdRef.current === viewId) return;
+    appliedViewIdRef.current = viewId;
+    if (!savedView) return;
+    const viewSorting: SortingState = [
+      {
+        id: savedView.sorting.column,
+        desc: savedView.sorting.direction === "desc",
+      },
+    ];
+    setSorting(viewSorting);
+    updateSorting(viewSorting);
+  }, [savedView, isLoaded, updateSorting]);
+
   // Handle sorting changes
   const handleSortingChange = React.useCallback(
     (updater: React.SetStateAction<SortingState>) => {
@@ -1918,6 +1949,7 @@ export function ProfilesDataTable({
       runningProfiles,
       launchingProfiles,
       stoppingProfiles,
+      queuedLaunches,
       isUpdating,
       browserState,
 
@@ -1975,6 +2007,7 @@ export function ProfilesDataTable({
       setStoppingProfiles,
       onKillProfile,
       onLaunchProfile,
+      onCancelQueuedLaunch: cancelQueuedLaunch,
 
       // Overflow actions
       onAssignProfilesToGroup,
@@ -2029,6 +2062,7 @@ export function ProfilesDataTable({
       runningProfiles,
       launchingProfiles,
       stoppingProfiles,
+      queuedLaunches,
       isUpdating,
       browserState,
       tagsOverrides,
@@ -2059,6 +2093,7 @@ export function ProfilesDataTable({
       renameError,
       onKillProfile,
       onLaunchProfile,
+      cancelQueuedLaunch,
       onAssignProfilesToGroup,
       onCloneProfile,
       onConfigureWayfern,
@@ -2269,6 +2304,7 @@ export function ProfilesDataTable({
             meta.isClient && meta.runningProfiles.has(profile.id);
           const isLaunching = meta.launchingProfiles.has(profile.id);
           const isStopping = meta.stoppingProfiles.has(profile.id);
+          const queuedLaunch = meta.queuedLaunches.get(profile.id);
           const isLockedByAnother = meta.isProfileLockedByAnother(profile.id);
           const isSyncing = meta.syncStatuses[profile.id]?.status === "syncing";
           const canLaunch =
@@ -2276,9 +2312,13 @@ export function ProfilesDataTable({
             !isLockedByAnother &&
             !isSyncing;
           const lockEmail = meta.getProfileLockEmail(profile.id);
-          const tooltipContent = isLockedByAnother
-            ? meta.t("sync.team.cannotLaunchLocked", { email: lockEmail })
-            : meta.browserState.getLaunchTooltipContent(profile);
+          const tooltipContent = queuedLaunch
+            ? meta.t("profiles.launchQueue.queued", {
+                count: queuedLaunch.ahead,
+              })
+            : isLockedByAnother
+              ? meta.t("sync.team.cannotLaunchLocked", { email: lockEmail })
+              : meta.browserState.getLaunchTooltipContent(profile);
 
           const handleProfileStop = async (profile: BrowserProfile) => {
             meta.setStoppingProfiles((prev: Set<string>) =>
@@ -2378,11 +2418,17 @@ export function ProfilesDataTable({
                     <RippleButton
                       variant={buttonVariant}
                       size="sm"
-                      disabled={!canLaunch || isLaunching || isStopping}
+                      disabled={
+                        !canLaunch ||
+                        (isLaunching && !queuedLaunch) ||
+                        isStopping
+                      }
                       aria-label={
-                        isRunning
-                          ? meta.t("profiles.actions.stop")
-                          : meta.t("profiles.actions.launch")
+                        queuedLaunch
+                          ? meta.t("profiles.launchQueue.cancel")
+                          : isRunning
+                            ? meta.t("profiles.actions.stop")
+                            : meta.t("profiles.actions.launch")
                       }
                       className={cn(
                         "grid size-7 place-items-center p-0",
@@ -2393,13 +2439,26 @@ export function ProfilesDataTable({
                           "bg-destructive/10 text-destructive hover:bg-destructive/20",
                       )}
                       onClick={() =>
-                        isRunning
-                          ? void handleStop()
-                          : void handleProfileLaunch(profile)
+                        queuedLaunch
+                          ? void meta.onCancelQueuedLaunch(profile.id)
+                          : isRunning
+                            ? void handleStop()
+                            : void handleProfileLaunch(profile)
                       }
                     >
                       <AnimatePresence mode="wait" initial={false}>
-                        {isLaunching || isStopping ? (
+                        {queuedLaunch ? (
+                          <motion.span
+                            key="queued"
+                            initial={{ opacity: 0 }}
+                            animate={{ opacity: 1 }}
+                            exit={{ opacity: 0 }}
+                            transition={{ duration: 0.12 }}
+                            className="grid place-items-center"
+                          >
+                            <LuClock className="size-3.5" />
+                          </motion.span>
+                        ) : isLaunching || isStopping ? (
                           <motion.span
                             key="spinner"
                             initial={{ opacity: 0 }}
@@ -3055,6 +3114,15 @@ export function ProfilesDataTable({
   // dialog. Visibility (not CSS hiding) so table-fixed reclaims the width.
   const [columnVisibility, setColumnVisibility] =
     React.useState<VisibilityState>({ created_at: false });
+  const effectiveColumnVisibility = React.useMemo(() => {
+    const allowed = savedView?.visible_columns;
+    if (!allowed) return columnVisibility;
+    const next: VisibilityState = { ...columnVisibility };
+    for (const id of SAVED_VIEW_COLUMNS) {
+      if (!allowed.includes(id)) next[id] = false;
+    }
+    return next;
+  }, [columnVisibility, savedView]);
 
   // Content columns grow proportionally with the container but never drop
   // below the compact-layout floor; the name column takes the remainder.
@@ -3068,7 +3136,7 @@ export function ProfilesDataTable({
     state: {
       sorting,
       rowSelection,
-      columnVisibility,
+      columnVisibility: effectiveColumnVisibility,
     },
     onSortingChange: handleSortingChange,
     onRowSelectionChange: handleRowSelectionChange,
@@ -3424,6 +3492,14 @@ export function ProfilesDataTable({
                 setProfileForInfoDialog(null);
                 setProfileToDelete(profile);
               }}
+              onOpenInWindow={(profile) => {
+                setProfileForInfoDialog(null);
+                invoke("open_detached_profile_window", {
+                  profileId: profile.id,
+                }).catch((error: unknown) => {
+                  showErrorToast(translateBackendError(t, error));
+                });
+              }}
               crossOsUnlocked={crossOsUnlocked}
               isRunning={infoIsRunning}
               isDisabled={infoIsDisabled}
@@ -16,24 +16,33 @@ import {
   LuCopy,
   LuDownload,
   LuEraser,
+  LuExternalLink,
   LuFingerprint,
+  LuGauge,
   LuGlobe,
   LuGroup,
   LuKey,
+  LuList,
   LuLink,
   LuLock,
   LuLockOpen,
+  LuMapPin,
+  LuPin,
   LuPlus,
   LuPuzzle,
   LuRefreshCw,
+  LuRotateCcw,
   LuSettings,
   LuShield,
   LuShieldCheck,
+  LuSlidersHorizontal,
   LuTrash2,
   LuUpload,
   LuUsers,
+  LuVideo,
   LuX,
 } from "react-icons/lu";
+import { ProfilePreflightDialog } from "@/components/profile-preflight-dialog";
 import { SharedFingerprintConfigForm } from "@/components/shared-fingerprint-config-form";
 import { AnimatedSwitch } from "@/components/ui/animated-switch";
 import { Button } from "@/components/ui/button";
@@ -73,18 +82,40 @@ import {
   SelectTrigger,
   SelectValue,
 } from "@/components/ui/select";
-import { translateBackendError } from "@/lib/backend-errors";
+import {
+  isBackendErrorCode,
+  translateBackendError,
+} from "@/lib/backend-errors";
 import { getProfileIcon } from "@/lib/browser-utils";
 import { DNS_BLOCKLIST_LEVELS } from "@/lib/dns-blocklist-levels";
 import { formatRelativeTime } from "@/lib/flag-utils";
-import { showErrorToast, showSuccessToast } from "@/lib/toast-utils";
+import {
+  parsePreferenceValue,
+  SUPPORTED_PREFERENCES,
+} from "@/lib/profile-preferences";
+import {
+  showErrorToast,
+  showSuccessToast,
+  showToast,
+} from "@/lib/toast-utils";
 import { cn } from "@/lib/utils";
 import type {
   BrowserProfile,
+  DnsMode,
+  ExitMismatchAction,
+  FingerprintReport,
+  ProcessPriority,
   ProfileGroup,
+  ProfileMetadataUpdate,
+  ProfileSnapshot,
+  ResourceLimits,
+  RestartMode,
+  RestartPolicy,
+  SessionRestore,
   StoredProxy,
   VpnConfig,
   WayfernConfig,
+  WebRtcMode,
 } from "@/types";
 
 interface ProfileInfoDialogProps {
@@ -105,6 +136,7 @@ interface ProfileInfoDialogProps {
   onOpenLaunchHook?: (profile: BrowserProfile) => void;
   onCloneProfile?: (profile: BrowserProfile) => void;
   onDeleteProfile?: (profile: BrowserProfile) => void;
+  onOpenInWindow?: (profile: BrowserProfile) => void;
   onLaunchWithSync?: (profile: BrowserProfile) => void;
   onSetPassword?: (profile: BrowserProfile) => void;
   onChangePassword?: (profile: BrowserProfile) => void;
@@ -179,6 +211,948 @@ function ClearOnCloseToggle({
   );
 }
 
+function VersionPinToggle({
+  profile,
+  isDisabled,
+}: {
+  profile: BrowserProfile;
+  isDisabled: boolean;
+}) {
+  const { t } = useTranslation();
+  const [pinned, setPinned] = React.useState(
+    profile.pin_browser_version === true,
+  );
+  const [saving, setSaving] = React.useState(false);
+
+  React.useEffect(() => {
+    setPinned(profile.pin_browser_version === true);
+  }, [profile.pin_browser_version]);
+
+  const toggle = async (next: boolean) => {
+    setPinned(next);
+    setSaving(true);
+    try {
+      await invoke(next ? "pin_profile_version" : "unpin_profile_version", {
+        profileId: profile.id,
+      });
+    } catch (error) {
+      setPinned(!next);
+      showErrorToast(translateBackendError(t, error));
+    } finally {
+      setSaving(false);
+    }
+  };
+
+  return (
+    <div className="flex items-center gap-3 rounded-md border border-border bg-muted/40 px-3 py-2">
+      <LuPin className="size-4 shrink-0 text-muted-foreground" />
+      <div className="min-w-0 flex-1">
+        <p className="text-sm font-medium">{t("versionPin.label")}</p>
+        <p className="text-[11px] text-muted-foreground">
+          {t("versionPin.description", { version: profile.version })}
+        </p>
+      </div>
+      <AnimatedSwitch
+        checked={pinned}
+        disabled={saving || isDisabled}
+        onCheckedChange={(v) => void toggle(v === true)}
+        aria-label={t("versionPin.label")}
+      />
+    </div>
+  );
+}
+
+const DEFAULT_RESTART_POLICY: RestartPolicy = {
+  mode: "never",
+  max_restarts_per_hour: 3,
+};
+
+function RestartPolicyCard({
+  profile,
+  isDisabled,
+}: {
+  profile: BrowserProfile;
+  isDisabled: boolean;
+}) {
+  const { t } = useTranslation();
+  const [policy, setPolicy] = React.useState<RestartPolicy>(
+    profile.restart_policy ?? DEFAULT_RESTART_POLICY,
+  );
+  const [limit, setLimit] = React.useState(
+    String(policy.max_restarts_per_hour),
+  );
+  const [saving, setSaving] = React.useState(false);
+
+  React.useEffect(() => {
+    const next = profile.restart_policy ?? DEFAULT_RESTART_POLICY;
+    setPolicy(next);
+    setLimit(String(next.max_restarts_per_hour));
+  }, [profile.restart_policy]);
+
+  const persist = async (next: RestartPolicy) => {
+    setSaving(true);
+    try {
+      await invoke("update_profile_restart_policy", {
+        profileId: profile.id,
+        policy: next,
+      });
+      setPolicy(next);
+    } catch (error) {
+      setLimit(String(policy.max_restarts_per_hour));
+      showErrorToast(translateBackendError(t, error));
+    } finally {
+      setSaving(false);
+    }
+  };
+
+  const commitLimit = () => {
+    const parsed = Number.parseInt(limit, 10);
+    if (Number.isNaN(parsed) || parsed === policy.max_restarts_per_hour) {
+      setLimit(String(policy.max_restarts_per_hour));
+      return;
+    }
+    void persist({ ...policy, max_restarts_per_hour: parsed });
+  };
+
+  return (
+    <div className="flex flex-col gap-2 rounded-md border border-border bg-muted/40 px-3 py-2">
+      <div className="flex items-center gap-3">
+        <LuRefreshCw className="size-4 shrink-0 text-muted-foreground" />
+        <div className="min-w-0 flex-1">
+          <p className="text-sm font-medium">{t("restartPolicy.label")}</p>
+          <p className="text-[11px] text-muted-foreground">
+            {t("restartPolicy.description")}
+          </p>
+        </div>
+        <Select
+          value={policy.mode}
+          disabled={saving || isDisabled}
+          onValueChange={(v) => {
+            void persist({ ...policy, mode: v as RestartMode });
+          }}
+        >
+          <SelectTrigger className="h-8 w-44 text-xs">
+            <SelectValue />
+          </SelectTrigger>
+          <SelectContent>
+            <SelectItem value="never">
+              {t("restartPolicy.modes.never")}
+            </SelectItem>
+            <SelectItem value="on_crash">
+              {t("restartPolicy.modes.onCrash")}
+            </SelectItem>
+            <SelectItem value="always">
+              {t("restartPolicy.modes.always")}
+            </SelectItem>
+          </SelectContent>
+        </Select>
+      </div>
+      {policy.mode !== "never" && (
+        <div className="flex items-center gap-2 pl-7">
+          <Input
+            type="number"
+            min={1}
+            max={60}
+            value={limit}
+            onChange={(e) => {
+              setLimit(e.target.value);
+            }}
+            onBlur={commitLimit}
+            onKeyDown={(e) => {
+              if (e.key === "Enter") commitLimit();
+            }}
+            disabled={saving || isDisabled}
+            className="h-8 w-20 text-xs"
+            aria-label={t("restartPolicy.maxPerHour")}
+          />
+          <span className="text-[11px] text-muted-foreground">
+            {t("restartPolicy.maxPerHour")}
+          </span>
+        </div>
+      )}
+    </div>
+  );
+}
+
+function DnsModeCard({
+  profile,
+  isDisabled,
+}: {
+  profile: BrowserProfile;
+  isDisabled: boolean;
+}) {
+  const { t } = useTranslation();
+  const [dnsMode, setDnsMode] = React.useState<DnsMode>(
+    profile.dns_mode ?? { mode: "system" },
+  );
+  const [resolverUrl, setResolverUrl] = React.useState(
+    dnsMode.mode === "doh" ? dnsMode.resolver_url : "",
+  );
+  const [saving, setSaving] = React.useState(false);
+
+  React.useEffect(() => {
+    const next = profile.dns_mode ?? { mode: "system" };
+    setDnsMode(next);
+    setResolverUrl(next.mode === "doh" ? next.resolver_url : "");
+  }, [profile.dns_mode]);
+
+  const persist = async (next: DnsMode) => {
+    setSaving(true);
+    try {
+      await invoke("update_profile_dns_mode", {
+        profileId: profile.id,
+        dnsMode: next,
+      });
+      setDnsMode(next);
+    } catch (error) {
+      showErrorToast(translateBackendError(t, error));
+    } finally {
+      setSaving(false);
+    }
+  };
+
+  const commitResolver = () => {
+    const trimmed = resolverUrl.trim();
+    if (
+      !trimmed ||
+      (dnsMode.mode === "doh" && trimmed === dnsMode.resolver_url)
+    ) {
+      return;
+    }
+    void persist({ mode: "doh", resolver_url: trimmed });
+  };
+
+  return (
+    <div className="flex flex-col gap-2 rounded-md border border-border bg-muted/40 px-3 py-2">
+      <div className="flex items-center gap-3">
+        <LuShield className="size-4 shrink-0 text-muted-foreground" />
+        <div className="min-w-0 flex-1">
+          <p className="text-sm font-medium">{t("dnsMode.label")}</p>
+          <p className="text-[11px] text-muted-foreground">
+            {t("dnsMode.description")}
+          </p>
+        </div>
+        <Select
+          value={dnsMode.mode}
+          disabled={saving || isDisabled}
+          onValueChange={(v) => {
+            if (v === "doh") {
+              // Saved once a resolver URL is entered
+              setDnsMode({ mode: "doh", resolver_url: "" });
+              return;
+            }
+            setResolverUrl("");
+            void persist({ mode: v as "system" | "proxy_remote" });
+          }}
+        >
+          <SelectTrigger className="h-8 w-44 text-xs">
+            <SelectValue />
+          </SelectTrigger>
+          <SelectContent>
+            <SelectItem value="system">{t("dnsMode.modes.system")}</SelectItem>
+            <SelectItem value="proxy_remote">
+              {t("dnsMode.modes.proxyRemote")}
+            </SelectItem>
+            <SelectItem value="doh">{t("dnsMode.modes.doh")}</SelectItem>
+          </SelectContent>
+        </Select>
+      </div>
+      {dnsMode.mode === "doh" && (
+        <div className="pl-7">
+          <Input
+            value={resolverUrl}
+            placeholder="https://dns.example/dns-query"
+            onChange={(e) => {
+              setResolverUrl(e.target.value);
+            }}
+            onBlur={commitResolver}
+            onKeyDown={(e) => {
+              if (e.key === "Enter") commitResolver();
+            }}
+            disabled={saving || isDisabled}
+            className="h-8 text-xs"
+            aria-label={t("dnsMode.resolverUrl")}
+          />
+        </div>
+      )}
+    </div>
+  );
+}
+
+function WebRtcModeCard({
+  profile,
+  isDisabled,
+}: {
+  profile: BrowserProfile;
+  isDisabled: boolean;
+}) {
+  const { t } = useTranslation();
+  const stored = profile.wayfern_config?.webrtc_mode ?? null;
+  const [mode, setMode] = React.useState<WebRtcMode | null>(stored);
+  const [mockIp, setMockIp] = React.useState(
+    stored?.mode === "mock" ? (stored.ip ?? "") : "",
+  );
+  const [saving, setSaving] = React.useState(false);
+
+  React.useEffect(() => {
+    setMode(stored);
+    setMockIp(stored?.mode === "mock" ? (stored.ip ?? "") : "");
+  }, [stored]);
+
+  const persist = async (next: WebRtcMode | null) => {
+    setSaving(true);
+    try {
+      await invoke("update_profile_webrtc_mode", {
+        profileId: profile.id,
+        mode: next,
+      });
+      setMode(next);
+    } catch (error) {
+      showErrorToast(translateBackendError(t, error));
+    } finally {
+      setSaving(false);
+    }
+  };
+
+  const commitMockIp = () => {
+    const trimmed = mockIp.trim();
+    if (mode?.mode === "mock" && trimmed === (mode.ip ?? "")) return;
+    void persist({ mode: "mock", ip: trimmed || null });
+  };
+
+  return (
+    <div className="flex flex-col gap-2 rounded-md border border-border bg-muted/40 px-3 py-2">
+      <div className="flex items-center gap-3">
+        <LuVideo className="size-4 shrink-0 text-muted-foreground" />
+        <div className="min-w-0 flex-1">
+          <p className="text-sm font-medium">{t("webrtcMode.label")}</p>
+          <p className="text-[11px] text-muted-foreground">
+            {t("webrtcMode.description")}
+          </p>
+        </div>
+        <Select
+          value={mode?.mode ?? "default"}
+          disabled={saving || isDisabled}
+          onValueChange={(v) => {
+            if (v === "default") {
+              void persist(null);
+            } else if (v === "mock") {
+              void persist({ mode: "mock", ip: mockIp.trim() || null });
+            } else {
+              void persist({ mode: v as "disabled" | "relay_only" });
+            }
+          }}
+        >
+          <SelectTrigger className="h-8 w-44 text-xs">
+            <SelectValue />
+          </SelectTrigger>
+          <SelectContent>
+            <SelectItem value="default">
+              {t("webrtcMode.modes.default")}
+            </SelectItem>
+            <SelectItem value="disabled">
+              {t("webrtcMode.modes.disabled")}
+            </SelectItem>
+            <SelectItem value="relay_only">
+              {t("webrtcMode.modes.relayOnly")}
+            </SelectItem>
+            <SelectItem value="mock">{t("webrtcMode.modes.mock")}</SelectItem>
+          </SelectContent>
+        </Select>
+      </div>
+      {mode?.mode === "mock" && (
+        <div className="pl-7">
+          <Input
+            value={mockIp}
+            placeholder={t("webrtcMode.mockIpPlaceholder")}
+            onChange={(e) => {
+              setMockIp(e.target.value);
+            }}
+            onBlur={commitMockIp}
+            onKeyDown={(e) => {
+              if (e.key === "Enter") commitMockIp();
+            }}
+            disabled={saving || isDisabled}
+            className="h-8 text-xs"
+            aria-label={t("webrtcMode.mockIp")}
+          />
+        </div>
+      )}
+    </div>
+  );
+}
+
+function ResourceLimitsCard({
+  profile,
+  isDisabled,
+}: {
+  profile: BrowserProfile;
+  isDisabled: boolean;
+}) {
+  const { t } = useTranslation();
+  const [limits, setLimits] = React.useState<ResourceLimits>(
+    profile.resource_limits ?? {},
+  );
+  const [memory, setMemory] = React.useState(
+    limits.memory_limit_mb ? String(limits.memory_limit_mb) : "",
+  );
+  const [saving, setSaving] = React.useState(false);
+  const applied = profile.applied_resource_limits;
+
+  React.useEffect(() => {
+    const next = profile.resource_limits ?? {};
+    setLimits(next);
+    setMemory(next.memory_limit_mb ? String(next.memory_limit_mb) : "");
+  }, [profile.resource_limits]);
+
+  const persist = async (next: ResourceLimits) => {
+    setSaving(true);
+    try {
+      await invoke("update_profile_resource_limits", {
+        profileId: profile.id,
+        limits: next,
+      });
+      setLimits(next);
+    } catch (error) {
+      setMemory(
+        limits.memory_limit_mb ? String(limits.memory_limit_mb) : "",
+      );
+      showErrorToast(translateBackendError(t, error));
+    } finally {
+      setSaving(false);
+    }
+  };
+
+  const commitMemory = () => {
+    const trimmed = memory.trim();
+    const parsed = trimmed ? Number.parseInt(trimmed, 10) : null;
+    if (
+      Number.isNaN(parsed) ||
+      parsed === (limits.memory_limit_mb ?? null)
+    ) {
+      setMemory(
+        limits.memory_limit_mb ? String(limits.memory_limit_mb) : "",
+      );
+      return;
+    }
+    void persist({ ...limits, memory_limit_mb: parsed });
+  };
+
+  return (
+    <div className="flex flex-col gap-2 rounded-md border border-border bg-muted/40 px-3 py-2">
+      <div className="flex items-center gap-3">
+        <LuGauge className="size-4 shrink-0 text-muted-foreground" />
+        <div className="min-w-0 flex-1">
+          <p className="text-sm font-medium">{t("resourceLimits.label")}</p>
+          <p className="text-[11px] text-muted-foreground">
+            {t("resourceLimits.description")}
+          </p>
+        </div>
+        <Select
+          value={limits.priority ?? "default"}
+          disabled={saving || isDisabled}
+          onValueChange={(v) => {
+            void persist({
+              ...limits,
+              priority: v === "default" ? null : (v as ProcessPriority),
+            });
+          }}
+        >
+          <SelectTrigger
+            className="h-8 w-44 text-xs"
+            aria-label={t("resourceLimits.priority")}
+          >
+            <SelectValue />
+          </SelectTrigger>
+          <SelectContent>
+            <SelectItem value="default">
+              {t("resourceLimits.useDefault")}
+            </SelectItem>
+            <SelectItem value="low">
+              {t("resourceLimits.priorities.low")}
+            </SelectItem>
+            <SelectItem value="normal">
+              {t("resourceLimits.priorities.normal")}
+            </SelectItem>
+            <SelectItem value="high">
+              {t("resourceLimits.priorities.high")}
+            </SelectItem>
+          </SelectContent>
+        </Select>
+      </div>
+      <div className="flex items-center gap-2 pl-7">
+        <Input
+          type="number"
+          min={256}
+          value={memory}
+          placeholder={t("resourceLimits.useDefault")}
+          onChange={(e) => {
+            setMemory(e.target.value);
+          }}
+          onBlur={commitMemory}
+          onKeyDown={(e) => {
+            if (e.key === "Enter") commitMemory();
+          }}
+          disabled={saving || isDisabled}
+          className="h-8 w-28 text-xs"
+          aria-label={t("resourceLimits.memoryLimit")}
+        />
+        <span className="text-[11px] text-muted-foreground">
+          {t("resourceLimits.memoryLimit")}
+        </span>
+      </div>
+      {applied && (
+        <p className="pl-7 text-[11px] text-muted-foreground">
+          {applied.errors.length === 0
+            ? t("resourceLimits.applied")
+            : `${t("resourceLimits.notFullyApplied")} ${applied.errors.join(
+                "; ",
+              )}`}
+        </p>
+      )}
+    </div>
+  );
+}
+
+function ExitMismatchCard({
+  profile,
+  isDisabled,
+}: {
+  profile: BrowserProfile;
+  isDisabled: boolean;
+}) {
+  const { t } = useTranslation();
+  const [action, setAction] = React.useState<ExitMismatchAction>(
+    profile.exit_mismatch_action ?? "warn",
+  );
+  const [saving, setSaving] = React.useState(false);
+
+  React.useEffect(() => {
+    setAction(profile.exit_mismatch_action ?? "warn");
+  }, [profile.exit_mismatch_action]);
+
+  const persist = async (next: ExitMismatchAction) => {
+    setSaving(true);
+    try {
+      await invoke("update_profile_exit_mismatch_action", {
+        profileId: profile.id,
+        action: next,
+      });
+      setAction(next);
+    } catch (error) {
+      showErrorToast(translateBackendError(t, error));
+    } finally {
+      setSaving(false);
+    }
+  };
+
+  return (
+    <div className="flex items-center gap-3 rounded-md border border-border bg-muted/40 px-3 py-2">
+      <LuMapPin className="size-4 shrink-0 text-muted-foreground" />
+      <div className="min-w-0 flex-1">
+        <p className="text-sm font-medium">{t("exitCheck.action.label")}</p>
+        <p className="text-[11px] text-muted-foreground">
+          {t("exitCheck.action.description")}
+        </p>
+      </div>
+      <Select
+        value={action}
+        disabled={saving || isDisabled}
+        onValueChange={(v) => {
+          void persist(v as ExitMismatchAction);
+        }}
+      >
+        <SelectTrigger className="h-8 w-44 text-xs">
+          <SelectValue />
+        </SelectTrigger>
+        <SelectContent>
+          <SelectItem value="warn">{t("exitCheck.action.warn")}</SelectItem>
+          <SelectItem value="block">{t("exitCheck.action.block")}</SelectItem>
+        </SelectContent>
+      </Select>
+    </div>
+  );
+}
+
+function StartupSettingsCard({
+  profile,
+  isDisabled,
+}: {
+  profile: BrowserProfile;
+  isDisabled: boolean;
+}) {
+  const { t } = useTranslation();
+  const [mode, setMode] = React.useState<SessionRestore>(
+    profile.session_restore ?? "homepage",
+  );
+  const [urls, setUrls] = React.useState<string[]>(profile.startup_urls ?? []);
+  const [newUrl, setNewUrl] = React.useState("");
+  const [saving, setSaving] = React.useState(false);
+
+  React.useEffect(() => {
+    setMode(profile.session_restore ?? "homepage");
+    setUrls(profile.startup_urls ?? []);
+  }, [profile.session_restore, profile.startup_urls]);
+
+  const persist = async (nextMode: SessionRestore, nextUrls: string[]) => {
+    setSaving(true);
+    try {
+      await invoke("update_profile_startup", {
+        profileId: profile.id,
+        startupUrls: nextUrls,
+        sessionRestore: nextMode,
+      });
+      setMode(nextMode);
+      setUrls(nextUrls);
+      return true;
+    } catch (error) {
+      showErrorToast(translateBackendError(t, error));
+      return false;
+    } finally {
+      setSaving(false);
+    }
+  };
+
+  const handleAddUrl = async () => {
+    const trimmed = newUrl.trim();
+    if (!trimmed || urls.includes(trimmed)) return;
+    if (await persist(mode, [...urls, trimmed])) setNewUrl("");
+  };
+
+  return (
+    <div className="flex flex-col gap-2 rounded-md border border-border bg-muted/40 px-3 py-2">
+      <div className="flex items-center gap-3">
+        <LuRotateCcw className="size-4 shrink-0 text-muted-foreground" />
+        <div className="min-w-0 flex-1">
+          <p className="text-sm font-medium">{t("profileStartup.label")}</p>
+          <p className="text-[11px] text-muted-foreground">
+            {t("profileStartup.description")}
+          </p>
+        </div>
+        <Select
+          value={mode}
+          disabled={saving || isDisabled}
+          onValueChange={(v) => void persist(v as SessionRestore, urls)}
+        >
+          <SelectTrigger className="h-8 w-44 text-xs">
+            <SelectValue />
+          </SelectTrigger>
+          <SelectContent>
+            <SelectItem value="homepage">
+              {t("profileStartup.modes.homepage")}
+            </SelectItem>
+            <SelectItem value="restore">
+              {t("profileStartup.modes.restore")}
+            </SelectItem>
+            <SelectItem value="urls">
+              {t("profileStartup.modes.urls")}
+            </SelectItem>
+          </SelectContent>
+        </Select>
+      </div>
+      {mode === "urls" && (
+        <div className="flex flex-col gap-1.5 pl-7">
+          <div className="flex gap-2">
+            <Input
+              value={newUrl}
+              onChange={(e) => {
+                setNewUrl(e.target.value);
+              }}
+              onKeyDown={(e) => {
+                if (e.key === "Enter") void handleAddUrl();
+              }}
+              placeholder="https://example.com"
+              disabled={saving || isDisabled}
+              className="h-8 flex-1 text-xs"
+            />
+            <Button
+              size="sm"
+              onClick={() => void handleAddUrl()}
+              disabled={!newUrl.trim() || saving || isDisabled}
+            >
+              <LuPlus className="mr-1 size-4" />
+              {t("profileStartup.addUrl")}
+            </Button>
+          </div>
+          {urls.length === 0 ? (
+            <p className="text-[11px] text-muted-foreground">
+              {t("profileStartup.noUrls")}
+            </p>
+          ) : (
+            urls.map((url) => (
+              <div
+                key={url}
+                className="flex items-center justify-between gap-2 rounded-md bg-muted px-3 py-1.5 text-sm"
+              >
+                <span className="truncate font-mono text-xs">{url}</span>
+                <button
+                  type="button"
+                  disabled={saving || isDisabled}
+                  onClick={() => {
+                    void persist(mode, urls.filter((u) => u !== url));
+                  }}
+                  className="shrink-0 text-muted-foreground transition-colors hover:text-destructive"
+                >
+                  <LuX className="size-3.5" />
+                </button>
+              </div>
+            ))
+          )}
+        </div>
+      )}
+    </div>
+  );
+}
+
+function PreferenceOverridesCard({
+  profile,
+  isDisabled,
+}: {
+  profile: BrowserProfile;
+  isDisabled: boolean;
+}) {
+  const { t } = useTranslation();
+  const [overrides, setOverrides] = React.useState<Record<string, unknown>>(
+    profile.preference_overrides ?? {},
+  );
+  const [newKey, setNewKey] = React.useState("");
+  const [newValue, setNewValue] = React.useState("");
+  const [saving, setSaving] = React.useState(false);
+
+  React.useEffect(() => {
+    setOverrides(profile.preference_overrides ?? {});
+  }, [profile.preference_overrides]);
+
+  const persist = async (next: Record<string, unknown>) => {
+    setSaving(true);
+    try {
+      await invoke("update_profile_preferences", {
+        profileId: profile.id,
+        preferences: next,
+      });
+      setOverrides(next);
+      return true;
+    } catch (error) {
+      showErrorToast(translateBackendError(t, error));
+      return false;
+    } finally {
+      setSaving(false);
+    }
+  };
+
+  const handleAdd = async () => {
+    if (!newKey || !newValue.trim()) return;
+    const next = { ...overrides, [newKey]: parsePreferenceValue(newValue) };
+    if (await persist(next)) {
+      setNewKey("");
+      setNewValue("");
+    }
+  };
+
+  const entries = Object.entries(overrides).sort(([a], [b]) =>
+    a.localeCompare(b),
+  );
+
+  return (
+    <div className="flex flex-col gap-2 rounded-md border border-border bg-muted/40 px-3 py-2">
+      <div className="flex items-center gap-3">
+        <LuSlidersHorizontal className="size-4 shrink-0 text-muted-foreground" />
+        <div className="min-w-0 flex-1">
+          <p className="text-sm font-medium">{t("profilePreferences.label")}</p>
+          <p className="text-[11px] text-muted-foreground">
+            {t("profilePreferences.description")}
+          </p>
+        </div>
+      </div>
+      <div className="flex flex-col gap-1.5 pl-7">
+        <div className="flex gap-2">
+          <Select
+            value={newKey}
+            disabled={saving || isDisabled}
+            onValueChange={setNewKey}
+          >
+            <SelectTrigger className="h-8 flex-1 font-mono text-xs">
+              <SelectValue placeholder="webrtc.ip_handling_policy" />
+            </SelectTrigger>
+            <SelectContent>
+              {SUPPORTED_PREFERENCES.map((key) => (
+                <SelectItem key={key} value={key} className="font-mono text-xs">
+                  {key}
+                </SelectItem>
+              ))}
+            </SelectContent>
+          </Select>
+          <Input
+            value={newValue}
+            onChange={(e) => {
+              setNewValue(e.target.value);
+            }}
+            onKeyDown={(e) => {
+              if (e.key === "Enter") void handleAdd();
+            }}
+            placeholder={t("profilePreferences.valuePlaceholder")}
+            disabled={saving || isDisabled}
+            className="h-8 w-40 text-xs"
+          />
+          <Button
+            size="sm"
+            onClick={() => void handleAdd()}
+            disabled={!newKey || !newValue.trim() || saving || isDisabled}
+          >
+            <LuPlus className="mr-1 size-4" />
+            {t("profilePreferences.add")}
+          </Button>
+        </div>
+        {entries.length === 0 ? (
+          <p className="text-[11px] text-muted-foreground">
+            {t("profilePreferences.none")}
+          </p>
+        ) : (
+          entries.map(([key, value]) => (
+            <div
+              key={key}
+              className="flex items-center justify-between gap-2 rounded-md bg-muted px-3 py-1.5 text-sm"
+            >
+              <span className="truncate font-mono text-xs">
+                {key} = {JSON.stringify(value)}
+              </span>
+              <button
+                type="button"
+                disabled={saving || isDisabled}
+                onClick={() => {
+                  void persist(
+                    Object.fromEntries(entries.filter(([k]) => k !== key)),
+                  );
+                }}
+                className="shrink-0 text-muted-foreground transition-colors hover:text-destructive"
+              >
+                <LuX className="size-3.5" />
+              </button>
+            </div>
+          ))
+        )}
+      </div>
+    </div>
+  );
+}
+
+function ProfileMetadataCard({
+  profile,
+  isDisabled,
+}: {
+  profile: BrowserProfile;
+  isDisabled: boolean;
+}) {
+  const { t } = useTranslation();
+  const [newKey, setNewKey] = React.useState("");
+  const [newValue, setNewValue] = React.useState("");
+  const [saving, setSaving] = React.useState(false);
+
+  // Only the touched keys are sent, so edits from scripts in the meantime
+  // aren't overwritten.
+  const apply = async (update: ProfileMetadataUpdate) => {
+    setSaving(true);
+    try {
+      await invoke("update_profile_metadata", {
+        profileId: profile.id,
+        update,
+      });
+      return true;
+    } catch (error) {
+      showErrorToast(translateBackendError(t, error));
+      return false;
+    } finally {
+      setSaving(false);
+    }
+  };
+
+  const handleAdd = async () => {
+    const key = newKey.trim();
+    if (!key) return;
+    if (await apply({ mode: "merge", metadata: { [key]: newValue.trim() } })) {
+      setNewKey("");
+      setNewValue("");
+    }
+  };
+
+  const entries = Object.entries(profile.metadata ?? {}).sort(([a], [b]) =>
+    a.localeCompare(b),
+  );
+
+  return (
+    <div className="flex flex-col gap-2 rounded-md border border-border bg-muted/40 px-3 py-2">
+      <div className="flex items-center gap-3">
+        <LuList className="size-4 shrink-0 text-muted-foreground" />
+        <div className="min-w-0 flex-1">
+          <p className="text-sm font-medium">{t("profileMetadata.label")}</p>
+          <p className="text-[11px] text-muted-foreground">
+            {t("profileMetadata.description")}
+          </p>
+        </div>
+      </div>
+      <div className="flex flex-col gap-1.5 pl-7">
+        <div className="flex gap-2">
+          <Input
+            value={newKey}
+            onChange={(e) => {
+              setNewKey(e.target.value);
+            }}
+            placeholder="account_id"
+            disabled={saving || isDisabled}
+            className="h-8 flex-1 font-mono text-xs"
+          />
+          <Input
+            value={newValue}
+            onChange={(e) => {
+              setNewValue(e.target.value);
+            }}
+            onKeyDown={(e) => {
+              if (e.key === "Enter") void handleAdd();
+            }}
+            placeholder={t("profileMetadata.valuePlaceholder")}
+            disabled={saving || isDisabled}
+            className="h-8 w-40 text-xs"
+          />
+          <Button
+            size="sm"
+            onClick={() => void handleAdd()}
+            disabled={!newKey.trim() || saving || isDisabled}
+          >
+            <LuPlus className="mr-1 size-4" />
+            {t("profileMetadata.add")}
+          </Button>
+        </div>
+        {entries.length === 0 ? (
+          <p className="text-[11px] text-muted-foreground">
+            {t("profileMetadata.none")}
+          </p>
+        ) : (
+          entries.map(([key, value]) => (
+            <div
+              key={key}
+              className="flex items-center justify-between gap-2 rounded-md bg-muted px-3 py-1.5 text-sm"
+            >
+              <span className="truncate font-mono text-xs">
+                {key} = {value}
+              </span>
+              <button
+                type="button"
+                disabled={saving || isDisabled}
+                onClick={() => {
+                  void apply({ mode: "delete", keys: [key] });
+                }}
+                className="shrink-0 text-muted-foreground transition-colors hover:text-destructive"
+              >
+                <LuX className="size-3.5" />
+              </button>
+            </div>
+          ))
+        )}
+      </div>
+    </div>
+  );
+}
+
 function InfoCard({ label, value }: { label: string; value: string }) {
   return (
     <div className="rounded-md border bg-muted/50 px-3 py-2.5">
@@ -248,6 +1222,462 @@ function LocalDataTransferCard({
   );
 }
 
+/**
+ * On-disk size of the profile directory, with the regenerable browser cache
+ * broken out and clearable while the profile is stopped.
+ */
+function DiskUsageCard({
+  profileId,
+  isRunning,
+}: {
+  profileId: string;
+  isRunning: boolean;
+}) {
+  const { t } = useTranslation();
+  type Usage = { total_bytes: number; cache_bytes: number };
+  const [usage, setUsage] = React.useState<Usage | null>(null);
+  const [clearing, setClearing] = React.useState(false);
+
+  const load = React.useCallback(
+    async (forceRefresh: boolean) => {
+      try {
+        const [result] = await invoke<Usage[]>("get_profile_disk_usage", {
+          profileId,
+          forceRefresh,
+        });
+        setUsage(result ?? null);
+      } catch {
+        setUsage(null);
+      }
+    },
+    [profileId],
+  );
+
+  React.useEffect(() => {
+    void load(false);
+  }, [load]);
+
+  const clearCache = async () => {
+    setClearing(true);
+    try {
+      const freed = await invoke<number>("clear_profile_cache", { profileId });
+      showSuccessToast(
+        t("profileInfo.diskUsage.cleared", { size: formatBytes(freed) }),
+      );
+      await load(true);
+    } catch (error) {
+      showErrorToast(translateBackendError(t, error));
+    } finally {
+      setClearing(false);
+    }
+  };
+
+  return (
+    <div className="rounded-md border bg-muted/50 px-3 py-2.5">
+      <p className="text-xs text-muted-foreground">
+        {t("profileInfo.fields.diskUsage")}
+      </p>
+      <div className="mt-0.5 flex items-center justify-between gap-2">
+        <p className="truncate text-sm">
+          {usage
+            ? t("profileInfo.diskUsage.value", {
+                total: formatBytes(usage.total_bytes),
+                cache: formatBytes(usage.cache_bytes),
+              })
+            : "—"}
+        </p>
+        <Button
+          size="sm"
+          variant="ghost"
+          className="h-6 px-2 text-xs"
+          disabled={isRunning || clearing || !usage?.cache_bytes}
+          onClick={() => void clearCache()}
+        >
+          {t("profileInfo.diskUsage.clearCache")}
+        </Button>
+      </div>
+    </div>
+  );
+}
+
+function PreflightCard({ profile }: { profile: BrowserProfile }) {
+  const { t } = useTranslation();
+  const [isOpen, setIsOpen] = React.useState(false);
+
+  return (
+    <div className="col-span-2 flex items-center justify-between gap-2 rounded-md border bg-muted/50 px-3 py-2.5">
+      <p className="text-xs text-muted-foreground">
+        {t("profileInfo.preflight.title")}
+      </p>
+      <Button
+        size="sm"
+        variant="ghost"
+        className="h-7 shrink-0 px-2 text-xs"
+        onClick={() => setIsOpen(true)}
+      >
+        {t("profileInfo.preflight.run")}
+      </Button>
+      <ProfilePreflightDialog
+        isOpen={isOpen}
+        onClose={() => setIsOpen(false)}
+        profile={profile}
+      />
+    </div>
+  );
+}
+
+/**
+ * Cold storage: an archived profile leaves the profile list and can't be
+ * launched. Compressing packs its browser data into a single file until it's
+ * unarchived.
+ */
+function ArchiveCard({ profile }: { profile: BrowserProfile }) {
+  const { t } = useTranslation();
+  const [busy, setBusy] = React.useState(false);
+  const archived = profile.archived === true;
+
+  const run = async (command: string, args: Record<string, unknown>) => {
+    setBusy(true);
+    try {
+      await invoke<BrowserProfile>(command, { profileId: profile.id, ...args });
+      showSuccessToast(
+        t(
+          archived
+            ? "profileInfo.archive.unarchived"
+            : "profileInfo.archive.archived",
+        ),
+      );
+    } catch (error) {
+      showErrorToast(translateBackendError(t, error));
+    } finally {
+      setBusy(false);
+    }
+  };
+
+  return (
+    <div className="col-span-2 flex items-center justify-between gap-2 rounded-md border bg-muted/50 px-3 py-2.5">
+      <p className="text-xs text-muted-foreground">
+        {t(
+          archived
+            ? "profileInfo.archive.archivedDescription"
+            : "profileInfo.archive.description",
+        )}
+      </p>
+      <div className="flex shrink-0 items-center gap-1">
+        {archived ? (
+          <Button
+            size="sm"
+            variant="ghost"
+            className="h-7 px-2 text-xs"
+            disabled={busy}
+            onClick={() => void run("unarchive_profile", {})}
+          >
+            {t("profileInfo.archive.unarchive")}
+          </Button>
+        ) : (
+          <>
+            <Button
+              size="sm"
+              variant="ghost"
+              className="h-7 px-2 text-xs"
+              disabled={busy}
+              onClick={() => void run("archive_profile", { compress: false })}
+            >
+              {t("profileInfo.archive.archive")}
+            </Button>
+            {!profile.ephemeral && (
+              <Button
+                size="sm"
+                variant="ghost"
+                className="h-7 px-2 text-xs"
+                disabled={busy}
+                onClick={() => void run("archive_profile", { compress: true })}
+              >
+                {t("profileInfo.archive.archiveCompressed")}
+              </Button>
+            )}
+          </>
+        )}
+      </div>
+    </div>
+  );
+}
+
+/**
+ * Clean slate for a broken profile: everything but cookies, logins,
+ * bookmarks and history is dropped. The old data is kept as a backup, so the
+ * button asks for a second click instead of a dialog.
+ */
+function RefreshCard({ profileId }: { profileId: string }) {
+  const { t } = useTranslation();
+  const [confirming, setConfirming] = React.useState(false);
+  const [busy, setBusy] = React.useState(false);
+
+  const refresh = async () => {
+    if (!confirming) {
+      setConfirming(true);
+      return;
+    }
+    setBusy(true);
+    try {
+      await invoke("refresh_profile", { profileId });
+      showSuccessToast(t("profileInfo.refresh.refreshed"));
+    } catch (error) {
+      showErrorToast(translateBackendError(t, error));
+    } finally {
+      setBusy(false);
+      setConfirming(false);
+    }
+  };
+
+  return (
+    <div className="col-span-2 flex items-center justify-between gap-2 rounded-md border bg-muted/50 px-3 py-2.5">
+      <p className="text-xs text-muted-foreground">
+        {t("profileInfo.refresh.description")}
+      </p>
+      <Button
+        size="sm"
+        variant={confirming ? "destructive" : "ghost"}
+        className="h-7 shrink-0 px-2 text-xs"
+        disabled={busy}
+        onClick={() => void refresh()}
+      >
+        {t(
+          confirming
+            ? "profileInfo.refresh.confirm"
+            : "profileInfo.refresh.refresh",
+        )}
+      </Button>
+    </div>
+  );
+}
+
+/**
+ * Local point-in-time backups of the profile. Taking and restoring one both
+ * need the browser stopped.
+ */
+function SnapshotsCard({
+  profileId,
+  isRunning,
+}: {
+  profileId: string;
+  isRunning: boolean;
+}) {
+  const { t } = useTranslation();
+  const [snapshots, setSnapshots] = React.useState<ProfileSnapshot[]>([]);
+  const [label, setLabel] = React.useState("");
+  const [busy, setBusy] = React.useState(false);
+
+  const load = React.useCallback(async () => {
+    try {
+      setSnapshots(
+        await invoke<ProfileSnapshot[]>("list_profile_snapshots", {
+          profileId,
+        }),
+      );
+    } catch {
+      setSnapshots([]);
+    }
+  }, [profileId]);
+
+  React.useEffect(() => {
+    void load();
+  }, [load]);
+
+  const create = async () => {
+    setBusy(true);
+    try {
+      await invoke<ProfileSnapshot>("create_profile_snapshot", {
+        profileId,
+        label: label.trim() || null,
+      });
+      setLabel("");
+      showSuccessToast(t("profileInfo.snapshots.created"));
+      await load();
+    } catch (error) {
+      showErrorToast(translateBackendError(t, error));
+    } finally {
+      setBusy(false);
+    }
+  };
+
+  const restore = async (snapshotId: string) => {
+    setBusy(true);
+    try {
+      await invoke("restore_profile_snapshot", { profileId, snapshotId });
+      showSuccessToast(t("profileInfo.snapshots.restored"));
+    } catch (error) {
+      showErrorToast(translateBackendError(t, error));
+    } finally {
+      setBusy(false);
+    }
+  };
+
+  return (
+    <div className="col-span-2 rounded-md border bg-muted/50 px-3 py-2.5">
+      <p className="text-xs text-muted-foreground">
+        {t("profileInfo.fields.snapshots")}
+      </p>
+      <div className="mt-1 flex items-center gap-2">
+        <Input
+          value={label}
+          onChange={(e) => {
+            setLabel(e.target.value);
+          }}
+          placeholder={t("profileInfo.snapshots.labelPlaceholder")}
+          className="h-7 text-xs"
+        />
+        <Button
+          size="sm"
+          variant="ghost"
+          className="h-7 shrink-0 px-2 text-xs"
+          disabled={isRunning || busy}
+          onClick={() => void create()}
+        >
+          {t("profileInfo.snapshots.create")}
+        </Button>
+      </div>
+      {snapshots.length === 0 ? (
+        <p className="mt-1 text-xs text-muted-foreground">
+          {t("profileInfo.snapshots.empty")}
+        </p>
+      ) : (
+        <ul className="mt-1 space-y-1">
+          {snapshots.map((snapshot) => (
+            <li
+              key={snapshot.id}
+              className="flex items-center justify-between gap-2 text-sm"
+            >
+              <span className="truncate">
+                {snapshot.label ?? formatRelativeTime(snapshot.created_at)}
+                <span className="ml-2 text-xs text-muted-foreground">
+                  {snapshot.label
+                    ? `${formatRelativeTime(snapshot.created_at)} · `
+                    : ""}
+                  {formatBytes(snapshot.size_bytes)}
+                </span>
+              </span>
+              <Button
+                size="sm"
+                variant="ghost"
+                className="h-6 px-2 text-xs"
+                disabled={isRunning || busy}
+                onClick={() => void restore(snapshot.id)}
+              >
+                {t("profileInfo.snapshots.restore")}
+              </Button>
+            </li>
+          ))}
+        </ul>
+      )}
+    </div>
+  );
+}
+
+/**
+ * Cross-field consistency score of the stored Wayfern fingerprint, with each
+ * finding listed so the user can see which fields contradict each other.
+ */
+function FingerprintQualityCard({ profileId }: { profileId: string }) {
+  const { t } = useTranslation();
+  const [report, setReport] = React.useState<FingerprintReport | null>(null);
+
+  React.useEffect(() => {
+    let cancelled = false;
+    invoke<FingerprintReport>("validate_profile_fingerprint", { profileId })
+      .then((result) => {
+        if (!cancelled) setReport(result);
+      })
+      .catch(() => {
+        if (!cancelled) setReport(null);
+      });
+    return () => {
+      cancelled = true;
+    };
+  }, [profileId]);
+
+  return (
+    <div className="rounded-md border bg-muted/50 px-3 py-2.5">
+      <p className="text-xs text-muted-foreground">
+        {t("profileInfo.fields.fingerprintQuality")}
+      </p>
+      <p className="mt-0.5 truncate text-sm">
+        {report
+          ? t("profileInfo.fingerprintQuality.score", { score: report.score })
+          : "—"}
+      </p>
+      {report && report.findings.length > 0 && (
+        <ul className="mt-1 space-y-0.5">
+          {report.findings.map((finding) => (
+            <li
+              key={finding.check}
+              title={finding.message}
+              className={
+                finding.severity === "error"
+                  ? "text-xs text-destructive"
+                  : "text-xs text-warning"
+              }
+            >
+              {t(`profileInfo.fingerprintQuality.checks.${finding.check}`, {
+                defaultValue: finding.message,
+              })}
+            </li>
+          ))}
+        </ul>
+      )}
+    </div>
+  );
+}
+
+function CdpEndpointCard({ profileId }: { profileId: string }) {
+  const { t } = useTranslation();
+  const [wsUrl, setWsUrl] = React.useState<string | null>(null);
+
+  React.useEffect(() => {
+    let cancelled = false;
+    invoke<{ ws_url: string }>("get_profile_cdp_endpoint", { profileId })
+      .then((endpoint) => {
+        if (!cancelled) setWsUrl(endpoint.ws_url);
+      })
+      .catch(() => {
+        if (!cancelled) setWsUrl(null);
+      });
+    return () => {
+      cancelled = true;
+    };
+  }, [profileId]);
+
+  const copy = async () => {
+    if (!wsUrl) return;
+    try {
+      await navigator.clipboard.writeText(wsUrl);
+      showSuccessToast(t("profileInfo.cdpEndpoint.copied"));
+    } catch {
+      // ignore
+    }
+  };
+
+  return (
+    <div className="col-span-2 rounded-md border bg-muted/50 px-3 py-2.5">
+      <p className="text-xs text-muted-foreground">
+        {t("profileInfo.fields.cdpEndpoint")}
+      </p>
+      <div className="mt-0.5 flex items-center justify-between gap-2">
+        <p className="truncate font-mono text-xs">{wsUrl ?? "—"}</p>
+        <Button
+          size="sm"
+          variant="ghost"
+          className="h-6 px-2 text-xs"
+          disabled={!wsUrl}
+          onClick={() => void copy()}
+        >
+          {t("profileInfo.cdpEndpoint.copy")}
+        </Button>
+      </div>
+    </div>
+  );
+}
+
 // Shown only for legacy profiles that predate the feature and have no stored
 // color yet (new profiles get a backend-derived one at creation/launch).
 const DEFAULT_SWATCH_COLOR = "#94a3b8";
@@ -331,6 +1761,7 @@ export function ProfileInfoDialog({
   onOpenLaunchHook,
   onCloneProfile,
   onDeleteProfile,
+  onOpenInWindow,
   onLaunchWithSync,
   onSetPassword,
   onChangePassword,
@@ -469,7 +1900,7 @@ export function ProfileInfoDialog({
         handleAction(() => onOpenProfileSyncDialog?.(profile));
       },
       disabled: isCrossOs,
-      hidden: profile.ephemeral === true,
+      hidden: profile.ephemeral === true || !onOpenProfileSyncDialog,
     },
     {
       icon: <LuGroup className="size-4" />,
@@ -546,7 +1977,7 @@ export function ProfileInfoDialog({
       },
       disabled: isDisabled,
       runningBadge: isRunning,
-      hidden: profile.ephemeral === true,
+      hidden: profile.ephemeral === true || !onAssignExtensionGroup,
     },
     {
       icon: <LuShieldCheck className="size-4" />,
@@ -614,6 +2045,7 @@ export function ProfileInfoDialog({
       },
       disabled: isDeleteDisabled,
       destructive: true,
+      hidden: !onDeleteProfile,
     },
   ];
 
@@ -651,6 +2083,7 @@ export function ProfileInfoDialog({
           handleCopyId={handleCopyId}
           onClose={onClose}
           onCloneProfile={onCloneProfile}
+          onOpenInWindow={onOpenInWindow}
           onKillProfile={undefined}
           visibleActions={visibleActions}
           t={t}
@@ -678,6 +2111,7 @@ interface ProfileInfoLayoutProps {
   handleCopyId: () => Promise<void>;
   onClose: () => void;
   onCloneProfile?: (profile: BrowserProfile) => void;
+  onOpenInWindow?: (profile: BrowserProfile) => void;
   onKillProfile?: (profile: BrowserProfile) => void;
   visibleActions: {
     id?: string;
@@ -721,6 +2155,7 @@ function ProfileInfoLayout({
   handleCopyId,
   onClose,
   onCloneProfile,
+  onOpenInWindow,
   visibleActions,
   t,
 }: ProfileInfoLayoutProps) {
@@ -866,6 +2301,17 @@ function ProfileInfoLayout({
             {t("profileInfo.duplicate")}
           </Button>
         )}
+        {onOpenInWindow && (
+          <Button
+            variant="ghost"
+            size="sm"
+            className="h-7 gap-1.5 px-2 text-xs"
+            onClick={() => onOpenInWindow(profile)}
+          >
+            <LuExternalLink className="size-3" />
+            {t("profileInfo.openInWindow")}
+          </Button>
+        )}
         <button
           type="button"
           aria-label={t("common.buttons.close")}
@@ -1026,6 +2472,34 @@ function ProfileInfoLayout({
                     }
                   />
                   <LocalDataTransferCard profileId={profile.id} t={t} />
+                  {!profile.ephemeral && (
+                    <DiskUsageCard
+                      profileId={profile.id}
+                      isRunning={isRunning}
+                    />
+                  )}
+                  {!profile.ephemeral && (
+                    <SnapshotsCard
+                      profileId={profile.id}
+                      isRunning={isRunning}
+                    />
+                  )}
+                  {profile.browser === "wayfern" &&
+                    profile.wayfern_config?.fingerprint && (
+                      // Keyed on the fingerprint so an edit re-validates.
+                      <FingerprintQualityCard
+                        key={profile.wayfern_config.fingerprint}
+                        profileId={profile.id}
+                      />
+                    )}
+                  {isRunning && <CdpEndpointCard profileId={profile.id} />}
+                  {!isRunning && <PreflightCard profile={profile} />}
+                  {!isRunning &&
+                    !profile.ephemeral &&
+                    !profile.password_protected && (
+                      <RefreshCard profileId={profile.id} />
+                    )}
+                  {!isRunning && <ArchiveCard profile={profile} />}
                 </div>
               </div>
 
@@ -1033,6 +2507,34 @@ function ProfileInfoLayout({
                 <ClearOnCloseToggle profile={profile} isDisabled={isDisabled} />
               )}
 
+              {!profile.ephemeral && (
+                <VersionPinToggle profile={profile} isDisabled={isDisabled} />
+              )}
+
+              <RestartPolicyCard profile={profile} isDisabled={isDisabled} />
+
+              <ResourceLimitsCard profile={profile} isDisabled={isDisabled} />
+
+              <DnsModeCard profile={profile} isDisabled={isDisabled} />
+
+              <WebRtcModeCard
+                profile={profile}
+                isDisabled={isDisabled || isRunning}
+              />
+
+              {(profile.proxy_id || profile.vpn_id) && (
+                <ExitMismatchCard profile={profile} isDisabled={isDisabled} />
+              )}
+
+              <StartupSettingsCard profile={profile} isDisabled={isDisabled} />
+
+              <PreferenceOverridesCard
+                profile={profile}
+                isDisabled={isDisabled}
+              />
+
+              <ProfileMetadataCard profile={profile} isDisabled={isCrossOs} />
+
               {profile.created_by_email && (
                 <div className="rounded-md border border-border bg-muted/40 px-3 py-2">
                   <p className="text-[10px] tracking-wide text-muted-foreground uppercase">
@@ -1885,7 +3387,7 @@ function FingerprintSectionInline({
     setSuccess(null);
   };
 
-  const onSave = async () => {
+  const onSave = async (forceLocale = false) => {
     setIsSaving(true);
     setError(null);
     setSuccess(null);
@@ -1893,11 +3395,22 @@ function FingerprintSectionInline({
       await invoke("update_wayfern_config", {
         profileId: profile.id,
         config: wayfernConfig,
+        forceLocale,
       });
       setSuccess(t("common.buttons.saved"));
       onSaved();
     } catch (e) {
       setError(translateBackendError(t as never, e));
+      if (isBackendErrorCode(e, "LOCALE_CONFLICTS_WITH_FINGERPRINT")) {
+        showToast({
+          type: "error",
+          title: translateBackendError(t as never, e),
+          action: {
+            label: t("fingerprint.applyLocaleToFingerprint"),
+            onClick: () => void onSave(true),
+          },
+        });
+      }
     } finally {
       setIsSaving(false);
     }
@@ -1925,7 +3438,6 @@ function FingerprintSectionInline({
         crossOsUnlocked={crossOsUnlocked}
         limitedMode={false}
         profileVersion={profile.version}
-        profileBrowser={profile.browser}
       />
 
       {error && <p className="text-xs text-destructive">{error}</p>}
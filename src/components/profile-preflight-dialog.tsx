@@ -0,0 +1,164 @@
+"use client";
+
+import { invoke } from "@tauri-apps/api/core";
+import { useCallback, useEffect, useState } from "react";
+import { useTranslation } from "react-i18next";
+import { LuCheck, LuMinus, LuTriangleAlert, LuX } from "react-icons/lu";
+import { LoadingButton } from "@/components/loading-button";
+import {
+  Dialog,
+  DialogContent,
+  DialogDescription,
+  DialogFooter,
+  DialogHeader,
+  DialogTitle,
+} from "@/components/ui/dialog";
+import { translateBackendError } from "@/lib/backend-errors";
+import { showErrorToast } from "@/lib/toast-utils";
+import type {
+  BrowserProfile,
+  PreflightCheck,
+  PreflightFix,
+  PreflightStatus,
+} from "@/types";
+import { RippleButton } from "./ui/ripple";
+
+const STATUS_ICONS: Record<PreflightStatus, React.ReactNode> = {
+  pass: <LuCheck className="size-4 text-success" />,
+  warn: <LuTriangleAlert className="size-4 text-warning" />,
+  fail: <LuX className="size-4 text-destructive" />,
+  skipped: <LuMinus className="size-4 text-muted-foreground" />,
+};
+
+/** Fixes run in place; accepting the Wayfern terms needs the terms dialog. */
+const ONE_CLICK_FIXES: PreflightFix[] = [
+  "download_browser",
+  "download_geoip_database",
+  "cleanup_orphan_processes",
+];
+
+interface ProfilePreflightDialogProps {
+  isOpen: boolean;
+  onClose: () => void;
+  profile: BrowserProfile | null;
+}
+
+export function ProfilePreflightDialog({
+  isOpen,
+  onClose,
+  profile,
+}: ProfilePreflightDialogProps) {
+  const { t } = useTranslation();
+  const [checks, setChecks] = useState<PreflightCheck[] | null>(null);
+  const [isChecking, setIsChecking] = useState(false);
+  const [fixing, setFixing] = useState<PreflightFix | null>(null);
+
+  const runChecks = useCallback(async () => {
+    if (!profile) return;
+    setIsChecking(true);
+    try {
+      setChecks(
+        await invoke<PreflightCheck[]>("preflight_profile_launch", {
+          profileId: profile.id,
+        }),
+      );
+    } catch (error) {
+      showErrorToast(translateBackendError(t, error));
+    } finally {
+      setIsChecking(false);
+    }
+  }, [profile, t]);
+
+  useEffect(() => {
+    if (isOpen) {
+      setChecks(null);
+      void runChecks();
+    }
+  }, [isOpen, runChecks]);
+
+  const applyFix = useCallback(
+    async (fix: PreflightFix) => {
+      if (!profile) return;
+      setFixing(fix);
+      try {
+        if (fix === "download_browser") {
+          await invoke("download_browser", {
+            browserStr: profile.browser,
+            version: profile.version,
+          });
+        } else {
+          await invoke(fix);
+        }
+        await runChecks();
+      } catch (error) {
+        showErrorToast(translateBackendError(t, error));
+      } finally {
+        setFixing(null);
+      }
+    },
+    [profile, runChecks, t],
+  );
+
+  return (
+    <Dialog open={isOpen} onOpenChange={(open) => !open && onClose()}>
+      <DialogContent className="sm:max-w-lg">
+        <DialogHeader>
+          <DialogTitle>{t("profileInfo.preflight.title")}</DialogTitle>
+          <DialogDescription>
+            {t("profileInfo.preflight.description")}
+          </DialogDescription>
+        </DialogHeader>
+
+        <div className="space-y-2">
+          {checks?.map((check) => {
+            const fix = check.fixable_by;
+            return (
+              <div
+                key={check.check}
+                className="flex items-center justify-between gap-2 rounded-md border p-2"
+              >
+                <div className="flex min-w-0 items-start gap-2">
+                  <span className="mt-0.5">{STATUS_ICONS[check.status]}</span>
+                  <div className="min-w-0">
+                    <p className="text-sm">
+                      {t(`profileInfo.preflight.checks.${check.check}`)}
+                    </p>
+                    {check.detail && (
+                      <p className="break-all text-xs text-muted-foreground">
+                        {check.detail}
+                      </p>
+                    )}
+                  </div>
+                </div>
+                {fix && ONE_CLICK_FIXES.includes(fix) && (
+                  <LoadingButton
+                    size="sm"
+                    variant="outline"
+                    isLoading={fixing === fix}
+                    disabled={fixing !== null || isChecking}
+                    onClick={() => void applyFix(fix)}
+                  >
+                    {t(`profileInfo.preflight.fixes.${fix}`)}
+                  </LoadingButton>
+                )}
+              </div>
+            );
+          })}
+        </div>
+
+        <DialogFooter>
+          <RippleButton variant="outline" onClick={onClose}>
+            {t("common.buttons.close")}
+          </RippleButton>
+          <LoadingButton
+            isLoading={isChecking}
+            disabled={fixing !== null}
+            onClick={() => void runChecks()}
+          >
+            {t("profileInfo.preflight.rerun")}
+          </LoadingButton>
+        </DialogFooter>
+      </DialogContent>
+    </Dialog>
+  );
+}
@@ -17,6 +17,7 @@ import {
 import { Label } from "@/components/ui/label";
 import { RadioGroup, RadioGroupItem } from "@/components/ui/radio-group";
 import { useCloudAuth } from "@/hooks/use-cloud-auth";
+import { translateBackendError } from "@/lib/backend-errors";
 import { getEntitlements } from "@/lib/entitlements";
 import { showErrorToast, showSuccessToast } from "@/lib/toast-utils";
 import type { BrowserProfile, SyncMode, SyncSettings } from "@/types";
@@ -124,7 +125,7 @@ export function ProfileSyncDialog({
         );
       } catch (error) {
         console.error("Failed to set sync mode:", error);
-        showErrorToast(String(error));
+        showErrorToast(translateBackendError(t, error));
       } finally {
         setIsSaving(false);
       }
@@ -140,27 +141,33 @@ export function ProfileSyncDialog({
     ],
   );
 
-  const handleSyncNow = useCallback(async () => {
-    if (!profile) return;
+  const handleSyncNow = useCallback(
+    async (forceRehash = false) => {
+      if (!profile) return;
 
-    if (!hasConfig) {
-      showErrorToast(t("sync.mode.noPasswordWarning"));
-      onSyncConfigOpen();
-      onClose();
-      return;
-    }
+      if (!hasConfig) {
+        showErrorToast(t("sync.mode.noPasswordWarning"));
+        onSyncConfigOpen();
+        onClose();
+        return;
+      }
 
-    setIsSyncing(true);
-    try {
-      await invoke("request_profile_sync", { profileId: profile.id });
-      showSuccessToast(t("sync.mode.syncQueued"));
-    } catch (error) {
-      console.error("Failed to queue sync:", error);
-      showErrorToast(String(error));
-    } finally {
-      setIsSyncing(false);
-    }
-  }, [profile, hasConfig, onSyncConfigOpen, onClose, t]);
+      setIsSyncing(true);
+      try {
+        await invoke("request_profile_sync", {
+          profileId: profile.id,
+          forceRehash,
+        });
+        showSuccessToast(t("sync.mode.syncQueued"));
+      } catch (error) {
+        console.error("Failed to queue sync:", error);
+        showErrorToast(String(error));
+      } finally {
+        setIsSyncing(false);
+      }
+    },
+    [profile, hasConfig, onSyncConfigOpen, onClose, t],
+  );
 
   const formatLastSync = (timestamp?: number) => {
     if (!timestamp) return t("common.labels.never");
@@ -300,9 +307,22 @@ export function ProfileSyncDialog({
             {t("common.buttons.close")}
           </Button>
           {hasConfig && isSyncEnabled(profile) && (
-            <LoadingButton onClick={handleSyncNow} isLoading={isSyncing}>
-              {t("sync.mode.syncNow")}
-            </LoadingButton>
+            <>
+              <Button
+                variant="outline"
+                disabled={isSyncing}
+                title={t("sync.mode.rehashSyncDescription")}
+                onClick={() => void handleSyncNow(true)}
+              >
+                {t("sync.mode.rehashSync")}
+              </Button>
+              <LoadingButton
+                onClick={() => void handleSyncNow()}
+                isLoading={isSyncing}
+              >
+                {t("sync.mode.syncNow")}
+              </LoadingButton>
+            </>
           )}
         </DialogFooter>
       </DialogContent>
@@ -1,9 +1,15 @@
 "use client";
 
 import { invoke } from "@tauri-apps/api/core";
+import { open } from "@tauri-apps/plugin-dialog";
 import { useCallback, useEffect, useState } from "react";
 import { useTranslation } from "react-i18next";
 import { toast } from "sonner";
+import {
+  type ExitExpectation,
+  ExitExpectationFields,
+  emptyExitExpectation,
+} from "@/components/exit-expectation-fields";
 import { LoadingButton } from "@/components/loading-button";
 import {
   Dialog,
@@ -21,8 +27,10 @@ import {
   SelectTrigger,
   SelectValue,
 } from "@/components/ui/select";
+import { Textarea } from "@/components/ui/textarea";
 import { translateBackendError } from "@/lib/backend-errors";
-import type { StoredProxy } from "@/types";
+import { formatHostPort } from "@/lib/utils";
+import type { ProxySettings, StoredProxy } from "@/types";
 import { RippleButton } from "./ui/ripple";
 
 interface ProxyFormData {
@@ -32,6 +40,11 @@ interface ProxyFormData {
   port: number;
   username: string;
   password: string;
+  // PEM files presented to an HTTPS proxy during the TLS handshake
+  client_cert_path: string;
+  client_key_path: string;
+  // Proxies dialed before this one, one URL per line
+  entry_hops: string;
 }
 
 interface ProxyFormDialogProps {
@@ -47,8 +60,43 @@ const DEFAULT_FORM: ProxyFormData = {
   port: 8080,
   username: "",
   password: "",
+  client_cert_path: "",
+  client_key_path: "",
+  entry_hops: "",
 };
 
+type ClientCertField = "client_cert_path" | "client_key_path";
+
+function hopToUrl(hop: ProxySettings): string {
+  const credentials = hop.username
+    ? `${encodeURIComponent(hop.username)}${
+        hop.password ? `:${encodeURIComponent(hop.password)}` : ""
+      }@`
+    : "";
+  const hostPort = formatHostPort(hop.host, hop.port);
+  return `${hop.proxy_type}://${credentials}${hostPort}`;
+}
+
+function parseHopUrl(line: string): ProxySettings | null {
+  try {
+    const url = new URL(line);
+    const proxyType = url.protocol.replace(/:$/, "").toLowerCase();
+    const port = Number.parseInt(url.port, 10);
+    if (!url.hostname || !port) {
+      return null;
+    }
+    return {
+      proxy_type: proxyType,
+      host: url.hostname,
+      port,
+      username: url.username ? decodeURIComponent(url.username) : undefined,
+      password: url.password ? decodeURIComponent(url.password) : undefined,
+    };
+  } catch {
+    return null;
+  }
+}
+
 export function ProxyFormDialog({
   isOpen,
   onClose,
@@ -57,9 +105,12 @@ export function ProxyFormDialog({
   const { t } = useTranslation();
   const [isSubmitting, setIsSubmitting] = useState(false);
   const [form, setForm] = useState<ProxyFormData>(DEFAULT_FORM);
+  const [exitExpectation, setExitExpectation] =
+    useState<ExitExpectation>(emptyExitExpectation);
 
   const resetForm = useCallback(() => {
     setForm(DEFAULT_FORM);
+    setExitExpectation(emptyExitExpectation);
   }, []);
 
   useEffect(() => {
@@ -79,9 +130,40 @@ export function ProxyFormDialog({
       port: editingProxy.proxy_settings.port,
       username: editingProxy.proxy_settings.username ?? "",
       password: editingProxy.proxy_settings.password ?? "",
+      client_cert_path: editingProxy.proxy_settings.client_cert_path ?? "",
+      client_key_path: editingProxy.proxy_settings.client_key_path ?? "",
+      entry_hops: (editingProxy.chain ?? [])
+        .slice(0, -1)
+        .map(hopToUrl)
+        .join("\n"),
+    });
+    setExitExpectation({
+      country: editingProxy.expected_country ?? "",
+      ipPrefix: editingProxy.expected_ip_prefix ?? "",
     });
   }, [editingProxy, isOpen, resetForm]);
 
+  const handleBrowsePem = useCallback(
+    async (field: ClientCertField) => {
+      try {
+        const selected = await open({
+          multiple: false,
+          title:
+            field === "client_cert_path"
+              ? t("proxies.form.clientCert")
+              : t("proxies.form.clientKey"),
+          filters: [{ name: "PEM", extensions: ["pem", "crt", "key"] }],
+        });
+        if (selected && typeof selected === "string") {
+          setForm((prev) => ({ ...prev, [field]: selected }));
+        }
+      } catch (error) {
+        console.error("Failed to open file dialog:", error);
+      }
+    },
+    [t],
+  );
+
   const handleSubmit = useCallback(async () => {
     if (!form.name.trim()) {
       toast.error(t("proxies.form.nameRequired"));
@@ -101,29 +183,71 @@ export function ProxyFormDialog({
       return;
     }
 
+    const entryHops: ProxySettings[] = [];
+    for (const line of form.entry_hops.split("\n")) {
+      if (!line.trim()) {
+        continue;
+      }
+      const hop = parseHopUrl(line.trim());
+      if (!hop) {
+        toast.error(
+          t("proxies.form.entryHopInvalid", { value: line.trim() }),
+        );
+        return;
+      }
+      entryHops.push(hop);
+    }
+
     setIsSubmitting(true);
     try {
+      const proxySettings: ProxySettings = {
+        proxy_type: form.proxy_type,
+        host: form.host.trim(),
+        port: form.port,
+        username: form.username.trim() || undefined,
+        password: form.password.trim() || undefined,
+      };
+      if (form.proxy_type === "https") {
+        proxySettings.client_cert_path =
+          form.client_cert_path.trim() || undefined;
+        proxySettings.client_key_path =
+          form.client_key_path.trim() || undefined;
+      }
+      // The form's own settings are the chain's exit. An empty list clears an
+      // existing chain; leave it out entirely for a proxy that never had one.
+      const chain =
+        entryHops.length > 0
+          ? [...entryHops, proxySettings]
+          : editingProxy?.chain
+            ? []
+            : undefined;
       const payload = {
         name: form.name.trim(),
-        proxySettings: {
-          proxy_type: form.proxy_type,
-          host: form.host.trim(),
-          port: form.port,
-          username: form.username.trim() || undefined,
-          password: form.password.trim() || undefined,
-        },
+        proxySettings,
+        chain,
       };
 
-      if (editingProxy) {
-        await invoke("update_stored_proxy", {
-          proxyId: editingProxy.id,
-          ...payload,
+      const saved = editingProxy
+        ? await invoke<StoredProxy>("update_stored_proxy", {
+            proxyId: editingProxy.id,
+            ...payload,
+          })
+        : await invoke<StoredProxy>("create_stored_proxy", payload);
+      if (
+        exitExpectation.country !== (saved.expected_country ?? "") ||
+        exitExpectation.ipPrefix !== (saved.expected_ip_prefix ?? "")
+      ) {
+        await invoke("update_stored_proxy_exit_expectation", {
+          proxyId: saved.id,
+          expectedCountry: exitExpectation.country,
+          expectedIpPrefix: exitExpectation.ipPrefix,
         });
-        toast.success(t("toasts.success.proxyUpdated"));
-      } else {
-        await invoke("create_stored_proxy", payload);
-        toast.success(t("toasts.success.proxyCreated"));
       }
+      toast.success(
+        editingProxy
+          ? t("toasts.success.proxyUpdated")
+          : t("toasts.success.proxyCreated"),
+      );
 
       onClose();
     } catch (error) {
@@ -136,7 +260,7 @@ export function ProxyFormDialog({
     } finally {
       setIsSubmitting(false);
     }
-  }, [editingProxy, form, onClose, t]);
+  }, [editingProxy, form, exitExpectation, onClose, t]);
 
   const handleClose = useCallback(() => {
     if (!isSubmitting) {
@@ -269,6 +393,68 @@ export function ProxyFormDialog({
               />
             </div>
           </div>
+
+          {form.proxy_type === "https" && (
+            <div className="grid gap-2">
+              <Label>{t("proxies.form.clientCertificate")}</Label>
+              {(["client_cert_path", "client_key_path"] as const).map(
+                (field) => (
+                  <div key={field} className="flex gap-2">
+                    <Input
+                      value={form[field]}
+                      onChange={(e) => {
+                        setForm({ ...form, [field]: e.target.value });
+                      }}
+                      placeholder={
+                        field === "client_cert_path"
+                          ? t("proxies.form.clientCert")
+                          : t("proxies.form.clientKey")
+                      }
+                      className="font-mono text-xs"
+                      disabled={isSubmitting}
+                    />
+                    <RippleButton
+                      variant="outline"
+                      onClick={() => void handleBrowsePem(field)}
+                      disabled={isSubmitting}
+                    >
+                      {t("proxies.form.browse")}
+                    </RippleButton>
+                  </div>
+                ),
+              )}
+              <p className="text-xs text-muted-foreground">
+                {t("proxies.form.clientCertificateHint")}
+              </p>
+            </div>
+          )}
+
+          <div className="grid gap-2">
+            <Label htmlFor="proxy-entry-hops">
+              {t("proxies.form.entryHops")}
+            </Label>
+            <Textarea
+              id="proxy-entry-hops"
+              value={form.entry_hops}
+              onChange={(e) => {
+                setForm({ ...form, entry_hops: e.target.value });
+              }}
+              placeholder={t("proxies.form.entryHopsPlaceholder")}
+              rows={2}
+              className="font-mono text-xs"
+              disabled={isSubmitting}
+            />
+            <p className="text-xs text-muted-foreground">
+              {t("proxies.form.entryHopsHint")}
+            </p>
+          </div>
+
+          <ExitExpectationFields
+            idPrefix="proxy"
+            value={exitExpectation}
+            onChange={setExitExpectation}
+            disabled={isSubmitting}
+          />
         </div>
 
         <DialogFooter>
@@ -19,11 +19,15 @@ import { Input } from "@/components/ui/input";
 import { Label } from "@/components/ui/label";
 import { ScrollArea } from "@/components/ui/scroll-area";
 import { StepTransition } from "@/components/ui/step-transition";
+import { translateBackendError } from "@/lib/backend-errors";
 import { getCurrentOS } from "@/lib/browser-utils";
+import { formatHostPort } from "@/lib/utils";
 import type {
   ParsedProxyLine,
   ProxyImportResult,
   ProxyParseResult,
+  ProxySource,
+  ProxySourceRefreshResult,
 } from "@/types";
 import { RippleButton } from "./ui/ripple";
 
@@ -80,6 +84,9 @@ export function ProxyImportDialog({ isOpen, onClose }: ProxyImportDialogProps) {
   const [namePrefix, setNamePrefix] = useState(
     t("proxies.importDialog.namePrefixDefault"),
   );
+  const [sourceUrl, setSourceUrl] = useState("");
+  const [sourceInterval, setSourceInterval] = useState("");
+  const [isAddingSource, setIsAddingSource] = useState(false);
   const os = getCurrentOS();
   const modKey = os === "macos" ? "⌘" : "Ctrl";
 
@@ -92,6 +99,9 @@ export function ProxyImportDialog({ isOpen, onClose }: ProxyImportDialogProps) {
     setImportResult(null);
     setIsImporting(false);
     setNamePrefix(t("proxies.importDialog.namePrefixDefault"));
+    setSourceUrl("");
+    setSourceInterval("");
+    setIsAddingSource(false);
   }, [t]);
 
   const processContent = useCallback(
@@ -253,6 +263,35 @@ export function ProxyImportDialog({ isOpen, onClose }: ProxyImportDialogProps) {
     }
   }, [parsedProxies, namePrefix, t]);
 
+  const handleAddSource = useCallback(async () => {
+    setIsAddingSource(true);
+    try {
+      const interval = Number.parseInt(sourceInterval, 10);
+      const source = await invoke<ProxySource>("add_proxy_source", {
+        url: sourceUrl,
+        namePrefix:
+          namePrefix.trim() || t("proxies.importDialog.namePrefixDefault"),
+        refreshIntervalMins: Number.isNaN(interval) ? null : interval,
+      });
+      const result = await invoke<ProxySourceRefreshResult>(
+        "refresh_proxy_source",
+        { sourceId: source.id },
+      );
+      setImportResult({
+        imported_count: result.added,
+        skipped_count: result.unchanged,
+        errors: result.errors.map((e) => `${e.line}: ${e.reason}`),
+        proxies: [],
+      });
+      setStep("result");
+    } catch (error) {
+      console.error("Failed to add proxy source:", error);
+      toast.error(translateBackendError(t, error));
+    } finally {
+      setIsAddingSource(false);
+    }
+  }, [sourceUrl, sourceInterval, namePrefix, t]);
+
   const handleAmbiguousFormatSelect = useCallback(
     (index: number, format: string) => {
       setAmbiguousProxies((prev) =>
@@ -375,6 +414,43 @@ export function ProxyImportDialog({ isOpen, onClose }: ProxyImportDialogProps) {
               <p className="text-center text-xs text-muted-foreground">
                 {t("proxies.importDialog.pasteHint", { modKey })}
               </p>
+              <div className="space-y-2 border-t pt-4">
+                <Label htmlFor="proxy-source-url">
+                  {t("proxies.importDialog.sourceUrl")}
+                </Label>
+                <div className="flex gap-2">
+                  <Input
+                    id="proxy-source-url"
+                    placeholder="https://example.com/proxies.txt"
+                    value={sourceUrl}
+                    onChange={(e) => {
+                      setSourceUrl(e.target.value);
+                    }}
+                  />
+                  <Input
+                    type="number"
+                    min={1}
+                    className="w-32"
+                    placeholder={t(
+                      "proxies.importDialog.sourceIntervalPlaceholder",
+                    )}
+                    value={sourceInterval}
+                    onChange={(e) => {
+                      setSourceInterval(e.target.value);
+                    }}
+                  />
+                  <LoadingButton
+                    isLoading={isAddingSource}
+                    onClick={() => void handleAddSource()}
+                    disabled={!sourceUrl.trim()}
+                  >
+                    {t("proxies.importDialog.addSource")}
+                  </LoadingButton>
+                </div>
+                <p className="text-xs text-muted-foreground">
+                  {t("proxies.importDialog.sourceHint")}
+                </p>
+              </div>
             </div>
           )}
 
@@ -429,7 +505,7 @@ export function ProxyImportDialog({ isOpen, onClose }: ProxyImportDialogProps) {
                           </span>
                         )}
                         <span>
-                          {proxy.host}:{proxy.port}
+                          {formatHostPort(proxy.host, proxy.port)}
                         </span>
                       </div>
                     ))}
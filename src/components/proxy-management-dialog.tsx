@@ -69,13 +69,14 @@ import { useProxyEvents } from "@/hooks/use-proxy-events";
 import { useVpnEvents } from "@/hooks/use-vpn-events";
 import { parseBackendError, translateBackendError } from "@/lib/backend-errors";
 import { showErrorToast, showSuccessToast } from "@/lib/toast-utils";
-import { cn } from "@/lib/utils";
+import { cn, formatHostPort } from "@/lib/utils";
 import type { ProxyCheckResult, StoredProxy, VpnConfig } from "@/types";
 import { ProxyCheckButton } from "./proxy-check-button";
 import { RippleButton } from "./ui/ripple";
 import { VpnCheckButton } from "./vpn-check-button";
 import { VpnFormDialog } from "./vpn-form-dialog";
 import { VpnImportDialog } from "./vpn-import-dialog";
+import { VpnThroughputChart } from "./vpn-throughput-chart";
 
 type SyncStatus = "disabled" | "syncing" | "synced" | "error" | "waiting";
 
@@ -217,7 +218,12 @@ export function ProxyManagementDialog({
     useState(false);
 
   const { storedProxies: rawProxies, proxyUsage, isLoading } = useProxyEvents();
-  const { vpnConfigs, vpnUsage, isLoading: isLoadingVpns } = useVpnEvents();
+  const {
+    vpnConfigs,
+    vpnUsage,
+    vpnThroughput,
+    isLoading: isLoadingVpns,
+  } = useVpnEvents();
 
   // Filter out cloud-managed and cloud-derived proxies (cloud proxies are
   // deprecated). Memoized — without this the derived array gets a new
@@ -356,7 +362,11 @@ export function ProxyManagementDialog({
       await emit("stored-proxies-changed");
     } catch (error) {
       console.error("Failed to delete proxy:", error);
-      toast.error(t("proxies.management.deleteFailed"));
+      toast.error(
+        parseBackendError(error)
+          ? translateBackendError(t, error)
+          : t("proxies.management.deleteFailed"),
+      );
     } finally {
       setIsDeleting(false);
       setProxyToDelete(null);
@@ -552,9 +562,23 @@ export function ProxyManagementDialog({
           </Button>
         ),
         cell: ({ row }) => (
-          <span className="block truncate font-medium">
-            {row.original.name}
-          </span>
+          <div className="flex min-w-0 items-center gap-2">
+            <span className="block truncate font-medium">
+              {row.original.name}
+            </span>
+            {row.original.is_stale && (
+              <Tooltip>
+                <TooltipTrigger asChild>
+                  <Badge variant="outline" className="shrink-0">
+                    {t("proxies.management.stale")}
+                  </Badge>
+                </TooltipTrigger>
+                <TooltipContent>
+                  <p>{t("proxies.management.staleTooltip")}</p>
+                </TooltipContent>
+              </Tooltip>
+            )}
+          </div>
         ),
       },
       {
@@ -574,8 +598,10 @@ export function ProxyManagementDialog({
         header: () => t("proxies.management.hostPort"),
         cell: ({ row }) => (
           <span className="block truncate font-mono text-xs text-muted-foreground">
-            {row.original.proxy_settings.host}:
-            {row.original.proxy_settings.port}
+            {formatHostPort(
+              row.original.proxy_settings.host,
+              row.original.proxy_settings.port,
+            )}
           </span>
         ),
       },
@@ -827,6 +853,15 @@ export function ProxyManagementDialog({
           <Badge variant="secondary">{vpnUsage[row.original.id] ?? 0}</Badge>
         ),
       },
+      {
+        id: "throughput",
+        size: 120,
+        enableSorting: false,
+        header: () => t("vpns.throughput.column"),
+        cell: ({ row }) => (
+          <VpnThroughputChart points={vpnThroughput[row.original.id] ?? []} />
+        ),
+      },
       {
         id: "sync",
         size: 96,
@@ -933,6 +968,7 @@ export function ProxyManagementDialog({
       vpnSyncStatus,
       vpnSyncErrors,
       vpnUsage,
+      vpnThroughput,
       isTogglingVpnSync,
       vpnInUse,
       checkingVpnId,
@@ -973,13 +1009,19 @@ export function ProxyManagementDialog({
           invoke("delete_stored_proxy", { proxyId: proxy.id }),
         ),
       );
-      const failed = results.filter((r) => r.status === "rejected").length;
-      const succeeded = results.length - failed;
+      const rejections = results.filter(
+        (r): r is PromiseRejectedResult => r.status === "rejected",
+      );
+      const succeeded = results.length - rejections.length;
       if (succeeded > 0) {
         toast.success(t("proxies.management.deleteSuccess"));
       }
-      if (failed > 0) {
-        toast.error(t("proxies.management.deleteFailed"));
+      if (rejections.length > 0) {
+        toast.error(
+          parseBackendError(rejections[0].reason)
+            ? translateBackendError(t, rejections[0].reason)
+            : t("proxies.management.deleteFailed"),
+        );
       }
       await emit("stored-proxies-changed");
       setProxiesRowSelection({});
@@ -0,0 +1,424 @@
+"use client";
+
+import { invoke } from "@tauri-apps/api/core";
+import { useCallback, useEffect, useState } from "react";
+import { useTranslation } from "react-i18next";
+import { LuCheck, LuLayers, LuPencil, LuTrash2 } from "react-icons/lu";
+import { LoadingButton } from "@/components/loading-button";
+import { Button } from "@/components/ui/button";
+import { Checkbox } from "@/components/ui/checkbox";
+import {
+  Dialog,
+  DialogContent,
+  DialogDescription,
+  DialogFooter,
+  DialogHeader,
+  DialogTitle,
+} from "@/components/ui/dialog";
+import {
+  DropdownMenu,
+  DropdownMenuContent,
+  DropdownMenuItem,
+  DropdownMenuSeparator,
+  DropdownMenuTrigger,
+} from "@/components/ui/dropdown-menu";
+import { Input } from "@/components/ui/input";
+import { Label } from "@/components/ui/label";
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from "@/components/ui/select";
+import { translateBackendError } from "@/lib/backend-errors";
+import { getBrowserDisplayName } from "@/lib/browser-utils";
+import {
+  formatMetadataPairs,
+  parseMetadataPairs,
+  SAVED_VIEW_COLUMNS,
+} from "@/lib/saved-views";
+import { showErrorToast } from "@/lib/toast-utils";
+import type {
+  SavedView,
+  SavedViewFilter,
+  TableSortingSettings,
+} from "@/types";
+import { RippleButton } from "./ui/ripple";
+
+const ANY = "__any__";
+
+type TriState = typeof ANY | "yes" | "no";
+
+const toTriState = (value?: boolean | null): TriState =>
+  value == null ? ANY : value ? "yes" : "no";
+
+const fromTriState = (value: TriState): boolean | null =>
+  value === ANY ? null : value === "yes";
+
+interface SavedViewsMenuProps {
+  savedViews: SavedView[];
+  activeViewId: string | null;
+  onActiveViewChange: (id: string | null) => void;
+  /** Seeds the filter of a newly saved view from the current screen. */
+  currentGroupId: string | null;
+  browsers: string[];
+}
+
+export function SavedViewsMenu({
+  savedViews,
+  activeViewId,
+  onActiveViewChange,
+  currentGroupId,
+  browsers,
+}: SavedViewsMenuProps) {
+  const { t } = useTranslation();
+  const [editing, setEditing] = useState<SavedView | "new" | null>(null);
+  const activeView = savedViews.find((v) => v.id === activeViewId);
+
+  const handleDelete = useCallback(
+    async (view: SavedView) => {
+      try {
+        await invoke("delete_saved_view", { id: view.id });
+        if (view.id === activeViewId) onActiveViewChange(null);
+      } catch (error) {
+        showErrorToast(translateBackendError(t, error));
+      }
+    },
+    [activeViewId, onActiveViewChange, t],
+  );
+
+  return (
+    <>
+      <DropdownMenu>
+        <DropdownMenuTrigger asChild>
+          <Button
+            variant="ghost"
+            size="sm"
+            className="flex h-7 max-w-40 shrink-0 items-center gap-1.5 px-2 text-xs"
+          >
+            <LuLayers className="size-3.5 shrink-0" />
+            <span className="truncate">
+              {activeView?.name ?? t("savedViews.trigger")}
+            </span>
+          </Button>
+        </DropdownMenuTrigger>
+        <DropdownMenuContent align="end" className="w-56">
+          <DropdownMenuItem
+            onSelect={() => {
+              onActiveViewChange(null);
+            }}
+          >
+            <LuCheck
+              className={activeViewId ? "invisible size-3.5" : "size-3.5"}
+            />
+            {t("savedViews.allProfiles")}
+          </DropdownMenuItem>
+          {savedViews.map((view) => (
+            <DropdownMenuItem
+              key={view.id}
+              className="group"
+              onSelect={() => {
+                onActiveViewChange(view.id);
+              }}
+            >
+              <LuCheck
+                className={
+                  view.id === activeViewId ? "size-3.5" : "invisible size-3.5"
+                }
+              />
+              <span className="flex-1 truncate">{view.name}</span>
+              <button
+                type="button"
+                aria-label={t("common.buttons.edit")}
+                className="invisible text-muted-foreground group-hover:visible hover:text-foreground"
+                onClick={(e) => {
+                  e.stopPropagation();
+                  setEditing(view);
+                }}
+              >
+                <LuPencil className="size-3" />
+              </button>
+              <button
+                type="button"
+                aria-label={t("common.buttons.delete")}
+                className="invisible text-muted-foreground group-hover:visible hover:text-destructive"
+                onClick={(e) => {
+                  e.stopPropagation();
+                  void handleDelete(view);
+                }}
+              >
+                <LuTrash2 className="size-3" />
+              </button>
+            </DropdownMenuItem>
+          ))}
+          <DropdownMenuSeparator />
+          <DropdownMenuItem
+            onSelect={() => {
+              setEditing("new");
+            }}
+          >
+            {t("savedViews.saveCurrent")}
+          </DropdownMenuItem>
+        </DropdownMenuContent>
+      </DropdownMenu>
+
+      <SavedViewDialog
+        view={editing}
+        currentGroupId={currentGroupId}
+        browsers={browsers}
+        onClose={() => {
+          setEditing(null);
+        }}
+        onSaved={(view) => {
+          setEditing(null);
+          onActiveViewChange(view.id);
+        }}
+      />
+    </>
+  );
+}
+
+interface SavedViewDialogProps {
+  view: SavedView | "new" | null;
+  currentGroupId: string | null;
+  browsers: string[];
+  onClose: () => void;
+  onSaved: (view: SavedView) => void;
+}
+
+function SavedViewDialog({
+  view,
+  currentGroupId,
+  browsers,
+  onClose,
+  onSaved,
+}: SavedViewDialogProps) {
+  const { t } = useTranslation();
+  const [name, setName] = useState("");
+  const [tags, setTags] = useState("");
+  const [metadata, setMetadata] = useState("");
+  const [browser, setBrowser] = useState<string>(ANY);
+  const [running, setRunning] = useState<TriState>(ANY);
+  const [hasProxy, setHasProxy] = useState<TriState>(ANY);
+  const [columns, setColumns] = useState<string[]>([...SAVED_VIEW_COLUMNS]);
+  const [isSaving, setIsSaving] = useState(false);
+
+  useEffect(() => {
+    if (!view) return;
+    const existing = view === "new" ? null : view;
+    setName(existing?.name ?? "");
+    setTags(existing?.filter.tags.join(", ") ?? "");
+    setMetadata(formatMetadataPairs(existing?.filter.metadata));
+    setBrowser(existing?.filter.browser ?? ANY);
+    setRunning(toTriState(existing?.filter.running));
+    setHasProxy(toTriState(existing?.filter.has_proxy));
+    setColumns(existing?.visible_columns ?? [...SAVED_VIEW_COLUMNS]);
+  }, [view]);
+
+  const handleSave = useCallback(async () => {
+    if (!view) return;
+    setIsSaving(true);
+    try {
+      const existing = view === "new" ? null : view;
+      const filter: SavedViewFilter = {
+        tags: tags
+          .split(",")
+          .map((tag) => tag.trim())
+          .filter(Boolean),
+        group_id:
+          existing?.filter.group_id ??
+          (currentGroupId && currentGroupId !== "__all__"
+            ? currentGroupId
+            : null),
+        browser: browser === ANY ? null : browser,
+        running: fromTriState(running),
+        has_proxy: fromTriState(hasProxy),
+        metadata: parseMetadataPairs(metadata),
+      };
+      const visibleColumns =
+        columns.length === SAVED_VIEW_COLUMNS.length ? null : columns;
+
+      const saved = existing
+        ? await invoke<SavedView>("update_saved_view", {
+            view: {
+              ...existing,
+              name,
+              filter,
+              visible_columns: visibleColumns,
+            },
+          })
+        : await invoke<SavedView>("create_saved_view", {
+            name,
+            filter,
+            sorting: await invoke<TableSortingSettings>(
+              "get_table_sorting_settings",
+            ),
+            visibleColumns,
+          });
+      onSaved(saved);
+    } catch (error) {
+      showErrorToast(translateBackendError(t, error));
+    } finally {
+      setIsSaving(false);
+    }
+  }, [
+    view,
+    name,
+    tags,
+    metadata,
+    browser,
+    running,
+    hasProxy,
+    columns,
+    currentGroupId,
+    onSaved,
+    t,
+  ]);
+
+  const triStateSelect = (
+    id: string,
+    value: TriState,
+    onChange: (value: TriState) => void,
+  ) => (
+    <Select
+      value={value}
+      onValueChange={(v) => {
+        onChange(v as TriState);
+      }}
+    >
+      <SelectTrigger id={id}>
+        <SelectValue />
+      </SelectTrigger>
+      <SelectContent>
+        <SelectItem value={ANY}>{t("savedViews.any")}</SelectItem>
+        <SelectItem value="yes">{t("savedViews.yes")}</SelectItem>
+        <SelectItem value="no">{t("savedViews.no")}</SelectItem>
+      </SelectContent>
+    </Select>
+  );
+
+  return (
+    <Dialog open={view !== null} onOpenChange={onClose}>
+      <DialogContent className="max-w-md">
+        <DialogHeader>
+          <DialogTitle>
+            {view === "new"
+              ? t("savedViews.createTitle")
+              : t("savedViews.editTitle")}
+          </DialogTitle>
+          <DialogDescription>{t("savedViews.description")}</DialogDescription>
+        </DialogHeader>
+
+        <div className="space-y-3">
+          <div className="space-y-1.5">
+            <Label htmlFor="saved-view-name">{t("savedViews.name")}</Label>
+            <Input
+              id="saved-view-name"
+              value={name}
+              onChange={(e) => {
+                setName(e.target.value);
+              }}
+              disabled={isSaving}
+            />
+          </div>
+          <div className="space-y-1.5">
+            <Label htmlFor="saved-view-tags">{t("savedViews.tags")}</Label>
+            <Input
+              id="saved-view-tags"
+              value={tags}
+              placeholder={t("savedViews.tagsPlaceholder")}
+              onChange={(e) => {
+                setTags(e.target.value);
+              }}
+              disabled={isSaving}
+            />
+          </div>
+          <div className="space-y-1.5">
+            <Label htmlFor="saved-view-metadata">
+              {t("savedViews.metadata")}
+            </Label>
+            <Input
+              id="saved-view-metadata"
+              value={metadata}
+              placeholder="account_id=42, persona_name=Ann"
+              onChange={(e) => {
+                setMetadata(e.target.value);
+              }}
+              disabled={isSaving}
+            />
+          </div>
+          <div className="grid grid-cols-3 gap-2">
+            <div className="space-y-1.5">
+              <Label htmlFor="saved-view-browser">
+                {t("savedViews.browser")}
+              </Label>
+              <Select value={browser} onValueChange={setBrowser}>
+                <SelectTrigger id="saved-view-browser">
+                  <SelectValue />
+                </SelectTrigger>
+                <SelectContent>
+                  <SelectItem value={ANY}>{t("savedViews.any")}</SelectItem>
+                  {browsers.map((b) => (
+                    <SelectItem key={b} value={b}>
+                      {getBrowserDisplayName(b)}
+                    </SelectItem>
+                  ))}
+                </SelectContent>
+              </Select>
+            </div>
+            <div className="space-y-1.5">
+              <Label htmlFor="saved-view-running">
+                {t("savedViews.running")}
+              </Label>
+              {triStateSelect("saved-view-running", running, setRunning)}
+            </div>
+            <div className="space-y-1.5">
+              <Label htmlFor="saved-view-proxy">
+                {t("savedViews.hasProxy")}
+              </Label>
+              {triStateSelect("saved-view-proxy", hasProxy, setHasProxy)}
+            </div>
+          </div>
+          <div className="space-y-1.5">
+            <Label>{t("savedViews.columns.title")}</Label>
+            <div className="grid grid-cols-3 gap-1.5">
+              {SAVED_VIEW_COLUMNS.map((column) => (
+                <label
+                  key={column}
+                  className="flex cursor-pointer items-center gap-2 text-sm"
+                >
+                  <Checkbox
+                    checked={columns.includes(column)}
+                    onCheckedChange={(checked) => {
+                      setColumns((prev) =>
+                        checked === true
+                          ? [...prev, column]
+                          : prev.filter((c) => c !== column),
+                      );
+                    }}
+                    disabled={isSaving}
+                  />
+                  {t(`savedViews.columns.${column}`)}
+                </label>
+              ))}
+            </div>
+          </div>
+        </div>
+
+        <DialogFooter>
+          <RippleButton variant="outline" onClick={onClose} disabled={isSaving}>
+            {t("common.buttons.cancel")}
+          </RippleButton>
+          <LoadingButton
+            isLoading={isSaving}
+            onClick={() => void handleSave()}
+            disabled={!name.trim()}
+          >
+            {t("common.buttons.save")}
+          </LoadingButton>
+        </DialogFooter>
+      </DialogContent>
+    </Dialog>
+  );
+}
@@ -8,6 +8,8 @@ import { useCallback, useEffect, useRef, useState } from "react";
 import { useTranslation } from "react-i18next";
 import { BsCamera, BsMic } from "react-icons/bs";
 import { DnsBlocklistDialog } from "@/components/dns-blocklist-dialog";
+import { DownloadedBrowsersDialog } from "@/components/downloaded-browsers-dialog";
+import { E2ePasswordChangeForm } from "@/components/e2e-password-change-form";
 import { LoadingButton } from "@/components/loading-button";
 import { useTheme } from "@/components/theme-provider";
 import { AnimatedSwitch } from "@/components/ui/animated-switch";
@@ -50,6 +52,7 @@ import { useCommercialTrial } from "@/hooks/use-commercial-trial";
 import { useLanguage } from "@/hooks/use-language";
 import type { PermissionType } from "@/hooks/use-permissions";
 import { usePermissions } from "@/hooks/use-permissions";
+import { translateBackendError } from "@/lib/backend-errors";
 import {
   getThemeByColors,
   getThemeById,
@@ -59,7 +62,10 @@ import {
 } from "@/lib/themes";
 import { showErrorToast, showSuccessToast } from "@/lib/toast-utils";
 import { cn } from "@/lib/utils";
+import type { LogRecord, ProcessPriority, ResourceLimits } from "@/types";
 import { RippleButton } from "./ui/ripple";
+import { WorkspaceBackupDialog } from "./workspace-backup-dialog";
+import { WorkspacesDialog } from "./workspaces-dialog";
 
 interface AppSettings {
   set_as_default_browser: boolean;
@@ -70,8 +76,35 @@ interface AppSettings {
   api_token?: string;
   disable_auto_updates?: boolean;
   keep_decrypted_profiles_in_ram?: boolean;
+  auto_update_extensions?: boolean;
+  snapshot_retention?: number;
+  max_concurrent_launches?: number;
+  disable_orphan_process_cleanup?: boolean;
+  preflight_before_launch?: boolean;
+  slow_launch_threshold_secs?: number;
+  sync_upload_limit_kbps?: number;
+  sync_download_limit_kbps?: number;
+  sync_window?: { start: string; end: string };
+  offline_mode?: boolean;
+  strict_sync_uploads?: boolean;
+  default_resource_limits?: ResourceLimits;
+  warm_pool?: WarmPoolSettings;
 }
 
+interface WarmPoolSettings {
+  enabled: boolean;
+  max_instances: number;
+  idle_timeout_minutes: number;
+  max_memory_mb: number;
+}
+
+const DEFAULT_WARM_POOL: WarmPoolSettings = {
+  enabled: false,
+  max_instances: 3,
+  idle_timeout_minutes: 30,
+  max_memory_mb: 2048,
+};
+
 interface CustomThemeState {
   selectedThemeId: string | null;
   colors: Record<string, string>;
@@ -123,6 +156,7 @@ export function SettingsDialog({
   const [isSaving, setIsSaving] = useState(false);
   const [isSettingDefault, setIsSettingDefault] = useState(false);
   const [isClearingCache, setIsClearingCache] = useState(false);
+  const [isVerifyingBrowsers, setIsVerifyingBrowsers] = useState(false);
   const [isClearingTraffic, setIsClearingTraffic] = useState(false);
   const [consistencyWarningEnabled, setConsistencyWarningEnabled] = useState(
     () => {
@@ -139,6 +173,11 @@ export function SettingsDialog({
     useState<PermissionType | null>(null);
   const [isMacOS, setIsMacOS] = useState(false);
   const [dnsBlocklistDialogOpen, setDnsBlocklistDialogOpen] = useState(false);
+  const [workspaceBackupDialogOpen, setWorkspaceBackupDialogOpen] =
+    useState(false);
+  const [workspacesDialogOpen, setWorkspacesDialogOpen] = useState(false);
+  const [downloadedBrowsersDialogOpen, setDownloadedBrowsersDialogOpen] =
+    useState(false);
   const [isLinux, setIsLinux] = useState(false);
   const [hasE2ePassword, setHasE2ePassword] = useState(false);
   const [e2ePassword, setE2ePassword] = useState("");
@@ -146,6 +185,7 @@ export function SettingsDialog({
   const [e2eError, setE2eError] = useState("");
   const [isSavingE2e, setIsSavingE2e] = useState(false);
   const [isRemovingE2e, setIsRemovingE2e] = useState(false);
+  const [isChangingE2e, setIsChangingE2e] = useState(false);
   const [isVerifyE2eOpen, setIsVerifyE2eOpen] = useState(false);
   const [verifyE2ePassword, setVerifyE2ePassword] = useState("");
   const [isVerifyingE2e, setIsVerifyingE2e] = useState(false);
@@ -407,6 +447,27 @@ export function SettingsDialog({
     }
   }, [t]);
 
+  const handleVerifyBrowsers = useCallback(async () => {
+    setIsVerifyingBrowsers(true);
+    try {
+      const results = await invoke<{ status: string }[]>(
+        "verify_downloaded_browsers",
+      );
+      const corrupted = results.filter((r) => r.status === "corrupted").length;
+      if (corrupted > 0) {
+        showErrorToast(
+          t("settings.advanced.verifyBrowsersCorrupted", { count: corrupted }),
+        );
+      } else {
+        showSuccessToast(t("settings.advanced.verifyBrowsersOk"));
+      }
+    } catch (error) {
+      showErrorToast(translateBackendError(t, error));
+    } finally {
+      setIsVerifyingBrowsers(false);
+    }
+  }, [t]);
+
   const handleRequestPermission = useCallback(
     async (permissionType: PermissionType) => {
       setRequestingPermission(permissionType);
@@ -555,13 +616,36 @@ export function SettingsDialog({
   const updateSetting = useCallback(
     (
       key: keyof AppSettings,
-      value: boolean | string | Record<string, string> | undefined,
+      value:
+        | boolean
+        | number
+        | string
+        | Record<string, string>
+        | ResourceLimits
+        | WarmPoolSettings
+        | undefined,
     ) => {
       setSettings((prev) => ({ ...prev, [key]: value as unknown as never }));
     },
     [],
   );
 
+  // Applied right away rather than on save, like the backend treats it.
+  const handleOfflineModeChange = useCallback(
+    async (enabled: boolean) => {
+      try {
+        await invoke("set_offline_mode", { enabled });
+        setSettings((prev) => ({ ...prev, offline_mode: enabled }));
+        setOriginalSettings((prev) => ({ ...prev, offline_mode: enabled }));
+      } catch (error) {
+        showErrorToast(t("settings.offlineModeFailed"), {
+          description: String(error),
+        });
+      }
+    },
+    [t],
+  );
+
   const handleClose = useCallback(() => {
     // Restore original theme when closing without saving
     if (originalSettings.theme === "custom" && originalSettings.custom_theme) {
@@ -1074,6 +1158,40 @@ export function SettingsDialog({
                 </RippleButton>
               </div>
 
+              {/* Workspaces Section */}
+              <div className="space-y-4">
+                <Label className="text-base font-medium">
+                  {t("workspaces.title")}
+                </Label>
+                <p className="text-xs text-muted-foreground">
+                  {t("workspaces.settingsDescription")}
+                </p>
+                <RippleButton
+                  variant="outline"
+                  className="w-full"
+                  onClick={() => setWorkspacesDialogOpen(true)}
+                >
+                  {t("workspaces.manage")}
+                </RippleButton>
+              </div>
+
+              {/* Workspace Backup Section */}
+              <div className="space-y-4">
+                <Label className="text-base font-medium">
+                  {t("workspaceBackup.title")}
+                </Label>
+                <p className="text-xs text-muted-foreground">
+                  {t("workspaceBackup.settingsDescription")}
+                </p>
+                <RippleButton
+                  variant="outline"
+                  className="w-full"
+                  onClick={() => setWorkspaceBackupDialogOpen(true)}
+                >
+                  {t("workspaceBackup.open")}
+                </RippleButton>
+              </div>
+
               {/* Sync Encryption Section */}
               <div className="space-y-4">
                 <Label className="text-base font-medium">
@@ -1087,6 +1205,10 @@ export function SettingsDialog({
                   <p className="text-sm text-muted-foreground">
                     {t("settings.encryption.requiresProOrOwner")}
                   </p>
+                ) : hasE2ePassword && isChangingE2e ? (
+                  <E2ePasswordChangeForm
+                    onDone={() => setIsChangingE2e(false)}
+                  />
                 ) : hasE2ePassword ? (
                   <div className="space-y-3">
                     <div className="flex items-center gap-2">
@@ -1113,12 +1235,7 @@ export function SettingsDialog({
                         variant="outline"
                         size="sm"
                         disabled={isRemovingE2e}
-                        onClick={() => {
-                          setHasE2ePassword(false);
-                          setE2ePassword("");
-                          setE2ePasswordConfirm("");
-                          setE2eError("");
-                        }}
+                        onClick={() => setIsChangingE2e(true)}
                       >
                         {t("settings.encryption.changePassword")}
                       </Button>
@@ -1291,6 +1408,27 @@ export function SettingsDialog({
                   {t("settings.advanced.title")}
                 </Label>
 
+                <div className="flex items-start gap-x-3 rounded-lg border p-3">
+                  <Checkbox
+                    id="offline-mode"
+                    checked={settings.offline_mode ?? false}
+                    onCheckedChange={(checked) => {
+                      void handleOfflineModeChange(checked as boolean);
+                    }}
+                  />
+                  <div className="space-y-1">
+                    <Label
+                      htmlFor="offline-mode"
+                      className="text-sm font-medium"
+                    >
+                      {t("settings.offlineMode")}
+                    </Label>
+                    <p className="text-xs text-muted-foreground">
+                      {t("settings.offlineModeDescription")}
+                    </p>
+                  </div>
+                </div>
+
                 {!isLinux && (
                   <div className="flex items-start gap-x-3 rounded-lg border p-3">
                     <Checkbox
@@ -1341,6 +1479,413 @@ export function SettingsDialog({
                   </div>
                 </div>
 
+                <div className="flex items-start gap-x-3 rounded-lg border p-3">
+                  <Checkbox
+                    id="auto-update-extensions"
+                    checked={settings.auto_update_extensions ?? false}
+                    onCheckedChange={(checked) => {
+                      updateSetting(
+                        "auto_update_extensions",
+                        checked as boolean,
+                      );
+                    }}
+                  />
+                  <div className="space-y-1">
+                    <Label
+                      htmlFor="auto-update-extensions"
+                      className="text-sm font-medium"
+                    >
+                      {t("settings.autoUpdateExtensions")}
+                    </Label>
+                    <p className="text-xs text-muted-foreground">
+                      {t("settings.autoUpdateExtensionsDescription")}
+                    </p>
+                  </div>
+                </div>
+
+                <div className="flex items-start gap-x-3 rounded-lg border p-3">
+                  <Checkbox
+                    id="disable-orphan-process-cleanup"
+                    checked={settings.disable_orphan_process_cleanup ?? false}
+                    onCheckedChange={(checked) => {
+                      updateSetting(
+                        "disable_orphan_process_cleanup",
+                        checked as boolean,
+                      );
+                    }}
+                  />
+                  <div className="space-y-1">
+                    <Label
+                      htmlFor="disable-orphan-process-cleanup"
+                      className="text-sm font-medium"
+                    >
+                      {t("settings.disableOrphanProcessCleanup")}
+                    </Label>
+                    <p className="text-xs text-muted-foreground">
+                      {t("settings.disableOrphanProcessCleanupDescription")}
+                    </p>
+                  </div>
+                </div>
+
+                <div className="flex items-start gap-x-3 rounded-lg border p-3">
+                  <Checkbox
+                    id="preflight-before-launch"
+                    checked={settings.preflight_before_launch ?? false}
+                    onCheckedChange={(checked) => {
+                      updateSetting(
+                        "preflight_before_launch",
+                        checked as boolean,
+                      );
+                    }}
+                  />
+                  <div className="space-y-1">
+                    <Label
+                      htmlFor="preflight-before-launch"
+                      className="text-sm font-medium"
+                    >
+                      {t("settings.preflightBeforeLaunch")}
+                    </Label>
+                    <p className="text-xs text-muted-foreground">
+                      {t("settings.preflightBeforeLaunchDescription")}
+                    </p>
+                  </div>
+                </div>
+
+                <div className="space-y-2 rounded-lg border p-3">
+                  <Label
+                    htmlFor="snapshot-retention"
+                    className="text-sm font-medium"
+                  >
+                    {t("settings.snapshotRetention")}
+                  </Label>
+                  <Input
+                    id="snapshot-retention"
+                    type="number"
+                    min={0}
+                    className="w-32"
+                    value={settings.snapshot_retention ?? 5}
+                    onChange={(e) => {
+                      const value = parseInt(e.target.value, 10);
+                      updateSetting(
+                        "snapshot_retention",
+                        Number.isNaN(value) ? 0 : Math.max(0, value),
+                      );
+                    }}
+                  />
+                  <p className="text-xs text-muted-foreground">
+                    {t("settings.snapshotRetentionDescription")}
+                  </p>
+                </div>
+
+                <div className="space-y-2 rounded-lg border p-3">
+                  <Label
+                    htmlFor="max-concurrent-launches"
+                    className="text-sm font-medium"
+                  >
+                    {t("settings.maxConcurrentLaunches")}
+                  </Label>
+                  <Input
+                    id="max-concurrent-launches"
+                    type="number"
+                    min={0}
+                    className="w-32"
+                    value={settings.max_concurrent_launches ?? 3}
+                    onChange={(e) => {
+                      const value = parseInt(e.target.value, 10);
+                      updateSetting(
+                        "max_concurrent_launches",
+                        Number.isNaN(value) ? 0 : Math.max(0, value),
+                      );
+                    }}
+                  />
+                  <p className="text-xs text-muted-foreground">
+                    {t("settings.maxConcurrentLaunchesDescription")}
+                  </p>
+                </div>
+
+                <div className="space-y-2 rounded-lg border p-3">
+                  <Label className="text-sm font-medium">
+                    {t("settings.defaultResourceLimits")}
+                  </Label>
+                  <div className="flex gap-3">
+                    <div className="space-y-1">
+                      <Label className="text-xs text-muted-foreground">
+                        {t("resourceLimits.priority")}
+                      </Label>
+                      <Select
+                        value={
+                          settings.default_resource_limits?.priority ?? "normal"
+                        }
+                        onValueChange={(v) => {
+                          updateSetting("default_resource_limits", {
+                            ...settings.default_resource_limits,
+                            priority:
+                              v === "normal" ? null : (v as ProcessPriority),
+                          });
+                        }}
+                      >
+                        <SelectTrigger className="w-32">
+                          <SelectValue />
+                        </SelectTrigger>
+                        <SelectContent>
+                          <SelectItem value="low">
+                            {t("resourceLimits.priorities.low")}
+                          </SelectItem>
+                          <SelectItem value="normal">
+                            {t("resourceLimits.priorities.normal")}
+                          </SelectItem>
+                          <SelectItem value="high">
+                            {t("resourceLimits.priorities.high")}
+                          </SelectItem>
+                        </SelectContent>
+                      </Select>
+                    </div>
+                    <div className="space-y-1">
+                      <Label
+                        htmlFor="default-memory-limit"
+                        className="text-xs text-muted-foreground"
+                      >
+                        {t("resourceLimits.memoryLimit")}
+                      </Label>
+                      <Input
+                        id="default-memory-limit"
+                        type="number"
+                        min={256}
+                        className="w-32"
+                        placeholder={t("settings.noMemoryLimit")}
+                        value={
+                          settings.default_resource_limits?.memory_limit_mb ??
+                          ""
+                        }
+                        onChange={(e) => {
+                          const value = parseInt(e.target.value, 10);
+                          updateSetting("default_resource_limits", {
+                            ...settings.default_resource_limits,
+                            memory_limit_mb: Number.isNaN(value)
+                              ? null
+                              : value,
+                          });
+                        }}
+                      />
+                    </div>
+                  </div>
+                  <p className="text-xs text-muted-foreground">
+                    {t("settings.defaultResourceLimitsDescription")}
+                  </p>
+                </div>
+
+                <div className="space-y-2 rounded-lg border p-3">
+                  <div className="flex items-center gap-2">
+                    <Checkbox
+                      id="warm-pool-enabled"
+                      checked={settings.warm_pool?.enabled ?? false}
+                      onCheckedChange={(checked) => {
+                        updateSetting("warm_pool", {
+                          ...DEFAULT_WARM_POOL,
+                          ...settings.warm_pool,
+                          enabled: checked === true,
+                        });
+                      }}
+                    />
+                    <Label
+                      htmlFor="warm-pool-enabled"
+                      className="text-sm font-medium"
+                    >
+                      {t("settings.warmPool")}
+                    </Label>
+                  </div>
+                  <p className="text-xs text-muted-foreground">
+                    {t("settings.warmPoolDescription")}
+                  </p>
+                  {settings.warm_pool?.enabled && (
+                    <div className="flex gap-3">
+                      {(
+                        [
+                          ["max_instances", "warmPoolMaxInstances"],
+                          ["idle_timeout_minutes", "warmPoolIdleTimeout"],
+                          ["max_memory_mb", "warmPoolMaxMemory"],
+                        ] as const
+                      ).map(([key, label]) => (
+                        <div key={key} className="space-y-1">
+                          <Label
+                            htmlFor={`warm-pool-${key}`}
+                            className="text-xs text-muted-foreground"
+                          >
+                            {t(`settings.${label}`)}
+                          </Label>
+                          <Input
+                            id={`warm-pool-${key}`}
+                            type="number"
+                            min={0}
+                            className="w-32"
+                            value={
+                              settings.warm_pool?.[key] ??
+                              DEFAULT_WARM_POOL[key]
+                            }
+                            onChange={(e) => {
+                              const value = parseInt(e.target.value, 10);
+                              updateSetting("warm_pool", {
+                                ...DEFAULT_WARM_POOL,
+                                ...settings.warm_pool,
+                                [key]: Number.isNaN(value)
+                                  ? 0
+                                  : Math.max(0, value),
+                              });
+                            }}
+                          />
+                        </div>
+                      ))}
+                    </div>
+                  )}
+                </div>
+
+                <div className="space-y-2 rounded-lg border p-3">
+                  <Label
+                    htmlFor="slow-launch-threshold"
+                    className="text-sm font-medium"
+                  >
+                    {t("settings.slowLaunchThreshold")}
+                  </Label>
+                  <Input
+                    id="slow-launch-threshold"
+                    type="number"
+                    min={0}
+                    className="w-32"
+                    value={settings.slow_launch_threshold_secs ?? 15}
+                    onChange={(e) => {
+                      const value = parseInt(e.target.value, 10);
+                      updateSetting(
+                        "slow_launch_threshold_secs",
+                        Number.isNaN(value) ? 0 : Math.max(0, value),
+                      );
+                    }}
+                  />
+                  <p className="text-xs text-muted-foreground">
+                    {t("settings.slowLaunchThresholdDescription")}
+                  </p>
+                </div>
+
+                <div className="space-y-2 rounded-lg border p-3">
+                  <Label className="text-sm font-medium">
+                    {t("settings.syncBandwidth")}
+                  </Label>
+                  <div className="flex gap-3">
+                    <div className="space-y-1">
+                      <Label
+                        htmlFor="sync-upload-limit"
+                        className="text-xs text-muted-foreground"
+                      >
+                        {t("settings.syncUploadLimit")}
+                      </Label>
+                      <Input
+                        id="sync-upload-limit"
+                        type="number"
+                        min={0}
+                        className="w-32"
+                        value={settings.sync_upload_limit_kbps ?? 0}
+                        onChange={(e) => {
+                          const value = parseInt(e.target.value, 10);
+                          updateSetting(
+                            "sync_upload_limit_kbps",
+                            Number.isNaN(value) ? 0 : Math.max(0, value),
+                          );
+                        }}
+                      />
+                    </div>
+                    <div className="space-y-1">
+                      <Label
+                        htmlFor="sync-download-limit"
+                        className="text-xs text-muted-foreground"
+                      >
+                        {t("settings.syncDownloadLimit")}
+                      </Label>
+                      <Input
+                        id="sync-download-limit"
+                        type="number"
+                        min={0}
+                        className="w-32"
+                        value={settings.sync_download_limit_kbps ?? 0}
+                        onChange={(e) => {
+                          const value = parseInt(e.target.value, 10);
+                          updateSetting(
+                            "sync_download_limit_kbps",
+                            Number.isNaN(value) ? 0 : Math.max(0, value),
+                          );
+                        }}
+                      />
+                    </div>
+                  </div>
+                  <p className="text-xs text-muted-foreground">
+                    {t("settings.syncBandwidthDescription")}
+                  </p>
+                  <div className="flex items-center gap-2">
+                    <Checkbox
+                      id="sync-window-enabled"
+                      checked={settings.sync_window !== undefined}
+                      onCheckedChange={(checked) => {
+                        updateSetting(
+                          "sync_window",
+                          checked
+                            ? { start: "01:00", end: "07:00" }
+                            : undefined,
+                        );
+                      }}
+                    />
+                    <Label htmlFor="sync-window-enabled" className="text-sm">
+                      {t("settings.syncWindow")}
+                    </Label>
+                  </div>
+                  {settings.sync_window && (
+                    <div className="flex items-center gap-2">
+                      <Input
+                        type="time"
+                        className="w-32"
+                        aria-label={t("settings.syncWindowStart")}
+                        value={settings.sync_window.start}
+                        onChange={(e) => {
+                          if (!settings.sync_window || !e.target.value) return;
+                          updateSetting("sync_window", {
+                            ...settings.sync_window,
+                            start: e.target.value,
+                          });
+                        }}
+                      />
+                      <span className="text-sm text-muted-foreground">–</span>
+                      <Input
+                        type="time"
+                        className="w-32"
+                        aria-label={t("settings.syncWindowEnd")}
+                        value={settings.sync_window.end}
+                        onChange={(e) => {
+                          if (!settings.sync_window || !e.target.value) return;
+                          updateSetting("sync_window", {
+                            ...settings.sync_window,
+                            end: e.target.value,
+                          });
+                        }}
+                      />
+                    </div>
+                  )}
+                  <p className="text-xs text-muted-foreground">
+                    {t("settings.syncWindowDescription")}
+                  </p>
+                  <div className="flex items-center gap-2">
+                    <Checkbox
+                      id="strict-sync-uploads"
+                      checked={settings.strict_sync_uploads ?? false}
+                      onCheckedChange={(checked) => {
+                        updateSetting("strict_sync_uploads", checked === true);
+                      }}
+                    />
+                    <Label htmlFor="strict-sync-uploads" className="text-sm">
+                      {t("settings.strictSyncUploads")}
+                    </Label>
+                  </div>
+                  <p className="text-xs text-muted-foreground">
+                    {t("settings.strictSyncUploadsDescription")}
+                  </p>
+                </div>
+
                 <LoadingButton
                   isLoading={isClearingCache}
                   onClick={() => {
@@ -1358,7 +1903,36 @@ export function SettingsDialog({
                   {t("settings.advanced.clearCacheDescription")}
                 </p>
 
-                <div className="grid grid-cols-2 gap-2 pt-2">
+                <LoadingButton
+                  isLoading={isVerifyingBrowsers}
+                  onClick={() => {
+                    handleVerifyBrowsers().catch((err: unknown) => {
+                      console.error(err);
+                    });
+                  }}
+                  variant="outline"
+                  className="w-full"
+                >
+                  {t("settings.advanced.verifyBrowsers")}
+                </LoadingButton>
+
+                <p className="text-xs text-muted-foreground">
+                  {t("settings.advanced.verifyBrowsersDescription")}
+                </p>
+
+                <RippleButton
+                  variant="outline"
+                  className="w-full"
+                  onClick={() => setDownloadedBrowsersDialogOpen(true)}
+                >
+                  {t("settings.advanced.manageBrowsers")}
+                </RippleButton>
+
+                <p className="text-xs text-muted-foreground">
+                  {t("settings.advanced.manageBrowsersDescription")}
+                </p>
+
+                <div className="grid grid-cols-3 gap-2 pt-2">
                   <RippleButton
                     variant="outline"
                     className="text-xs"
@@ -1376,6 +1950,38 @@ export function SettingsDialog({
                   >
                     {t("settings.advanced.copyLogs")}
                   </RippleButton>
+                  <RippleButton
+                    variant="outline"
+                    className="text-xs"
+                    onClick={async () => {
+                      try {
+                        const records = await invoke<LogRecord[]>(
+                          "get_recent_logs",
+                          { level: "warn", limit: 1000 },
+                        );
+                        if (records.length === 0) {
+                          showSuccessToast(
+                            t("settings.advanced.noRecentWarnings"),
+                          );
+                          return;
+                        }
+                        const content = records
+                          .map(
+                            (r) =>
+                              `${new Date(r.timestamp).toISOString()} [${r.level}] ${r.target}: ${r.message}`,
+                          )
+                          .join("\n");
+                        await writeClipboardText(content);
+                        showSuccessToast(
+                          t("settings.advanced.copyLogsSuccess"),
+                        );
+                      } catch (err) {
+                        showErrorToast(String(err));
+                      }
+                    }}
+                  >
+                    {t("settings.advanced.copyRecentWarnings")}
+                  </RippleButton>
                   <RippleButton
                     variant="outline"
                     className="text-xs"
@@ -1493,6 +2099,18 @@ export function SettingsDialog({
         isOpen={dnsBlocklistDialogOpen}
         onClose={() => setDnsBlocklistDialogOpen(false)}
       />
+      <WorkspaceBackupDialog
+        isOpen={workspaceBackupDialogOpen}
+        onClose={() => setWorkspaceBackupDialogOpen(false)}
+      />
+      <WorkspacesDialog
+        isOpen={workspacesDialogOpen}
+        onClose={() => setWorkspacesDialogOpen(false)}
+      />
+      <DownloadedBrowsersDialog
+        isOpen={downloadedBrowsersDialogOpen}
+        onClose={() => setDownloadedBrowsersDialogOpen(false)}
+      />
       <Dialog
         open={isVerifyE2eOpen}
         onOpenChange={(open) => {
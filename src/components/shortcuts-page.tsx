@@ -1,13 +1,28 @@
 "use client";
 
+import { invoke } from "@tauri-apps/api/core";
+import { useCallback, useEffect, useState } from "react";
 import { useTranslation } from "react-i18next";
+import { LuTrash2 } from "react-icons/lu";
 
+import { Button } from "@/components/ui/button";
+import { Input } from "@/components/ui/input";
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from "@/components/ui/select";
+import { translateBackendError } from "@/lib/backend-errors";
 import {
   formatGroupShortcut,
   formatShortcut,
   SHORTCUTS,
   type ShortcutDef,
 } from "@/lib/shortcuts";
+import { showErrorToast } from "@/lib/toast-utils";
+import type { BrowserProfile } from "@/types";
 
 interface GroupTarget {
   id: string;
@@ -17,6 +32,7 @@ interface GroupTarget {
 interface ShortcutsPageProps {
   /** Ordered list — first 9 entries display their Mod+digit binding. */
   groupTargets: GroupTarget[];
+  profiles: BrowserProfile[];
 }
 
 function Tokens({ tokens }: { tokens: string[] }) {
@@ -38,7 +54,126 @@ function ShortcutTokens({ shortcut }: { shortcut: ShortcutDef }) {
   return <Tokens tokens={formatShortcut(shortcut)} />;
 }
 
-export function ShortcutsPage({ groupTargets }: ShortcutsPageProps) {
+/** OS-wide shortcuts that launch a profile, or focus it when running. */
+function ProfileShortcutsSection({
+  profiles,
+}: {
+  profiles: BrowserProfile[];
+}) {
+  const { t } = useTranslation();
+  const [shortcuts, setShortcuts] = useState<Record<string, string>>({});
+  const [profileId, setProfileId] = useState("");
+  const [accelerator, setAccelerator] = useState("");
+
+  const loadShortcuts = useCallback(async () => {
+    try {
+      const settings = await invoke<{
+        profile_shortcuts: Record<string, string>;
+      }>("get_app_settings");
+      setShortcuts(settings.profile_shortcuts);
+    } catch (err) {
+      console.error("Failed to load profile shortcuts:", err);
+    }
+  }, []);
+
+  useEffect(() => {
+    void loadShortcuts();
+  }, [loadShortcuts]);
+
+  const saveShortcut = async (id: string, value: string | null) => {
+    try {
+      await invoke("set_profile_shortcut", {
+        profileId: id,
+        accelerator: value,
+      });
+      await loadShortcuts();
+      return true;
+    } catch (err) {
+      showErrorToast(t("shortcutsPage.profiles.saveFailed"), {
+        description: translateBackendError(t, err),
+      });
+      return false;
+    }
+  };
+
+  const assigned = profiles.filter((p) => shortcuts[p.id]);
+  const unassigned = profiles.filter((p) => !shortcuts[p.id]);
+
+  return (
+    <section className="flex flex-col gap-2">
+      <h2 className="text-[10px] tracking-wide text-muted-foreground uppercase">
+        {t("shortcutsPage.profiles.title")}
+      </h2>
+      <p className="text-xs text-muted-foreground">
+        {t("shortcutsPage.profiles.description")}
+      </p>
+      <div className="divide-y divide-border rounded-md border bg-card">
+        {assigned.map((profile) => (
+          <div
+            key={profile.id}
+            className="flex items-center justify-between gap-4 px-3 py-2"
+          >
+            <span className="min-w-0 truncate text-sm" title={profile.name}>
+              {profile.name}
+            </span>
+            <div className="flex items-center gap-2">
+              <Tokens tokens={shortcuts[profile.id].split("+")} />
+              <Button
+                variant="ghost"
+                size="icon"
+                className="size-7"
+                aria-label={t("shortcutsPage.profiles.remove")}
+                onClick={() => void saveShortcut(profile.id, null)}
+              >
+                <LuTrash2 className="size-3.5" />
+              </Button>
+            </div>
+          </div>
+        ))}
+        <div className="flex items-center gap-2 px-3 py-2">
+          <Select value={profileId} onValueChange={setProfileId}>
+            <SelectTrigger className="h-8 min-w-0 flex-1">
+              <SelectValue
+                placeholder={t("shortcutsPage.profiles.selectProfile")}
+              />
+            </SelectTrigger>
+            <SelectContent>
+              {unassigned.map((profile) => (
+                <SelectItem key={profile.id} value={profile.id}>
+                  {profile.name}
+                </SelectItem>
+              ))}
+            </SelectContent>
+          </Select>
+          <Input
+            className="h-8 w-40"
+            placeholder="Ctrl+Alt+1"
+            value={accelerator}
+            onChange={(e) => {
+              setAccelerator(e.target.value);
+            }}
+          />
+          <Button
+            size="sm"
+            disabled={!profileId || !accelerator.trim()}
+            onClick={() => {
+              void saveShortcut(profileId, accelerator).then((saved) => {
+                if (saved) {
+                  setProfileId("");
+                  setAccelerator("");
+                }
+              });
+            }}
+          >
+            {t("shortcutsPage.profiles.assign")}
+          </Button>
+        </div>
+      </div>
+    </section>
+  );
+}
+
+export function ShortcutsPage({ groupTargets, profiles }: ShortcutsPageProps) {
   const { t } = useTranslation();
 
   const sections: Array<{ key: ShortcutDef["group"]; titleKey: string }> = [
@@ -109,6 +244,8 @@ export function ShortcutsPage({ groupTargets }: ShortcutsPageProps) {
             </div>
           </section>
         ) : null}
+
+        <ProfileShortcutsSection profiles={profiles} />
       </div>
     </div>
   );
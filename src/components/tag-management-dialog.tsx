@@ -0,0 +1,170 @@
+"use client";
+
+import { invoke } from "@tauri-apps/api/core";
+import { useCallback, useEffect, useMemo, useState } from "react";
+import { useTranslation } from "react-i18next";
+import { LoadingButton } from "@/components/loading-button";
+import { Badge } from "@/components/ui/badge";
+import { Checkbox } from "@/components/ui/checkbox";
+import {
+  Dialog,
+  DialogContent,
+  DialogDescription,
+  DialogFooter,
+  DialogHeader,
+  DialogTitle,
+} from "@/components/ui/dialog";
+import { FadingScrollArea } from "@/components/ui/fading-scroll-area";
+import { Input } from "@/components/ui/input";
+import { Label } from "@/components/ui/label";
+import { translateBackendError } from "@/lib/backend-errors";
+import { showErrorToast, showSuccessToast } from "@/lib/toast-utils";
+import type { BrowserProfile } from "@/types";
+import { RippleButton } from "./ui/ripple";
+
+interface TagManagementDialogProps {
+  isOpen: boolean;
+  onClose: () => void;
+  profiles: BrowserProfile[];
+}
+
+export function TagManagementDialog({
+  isOpen,
+  onClose,
+  profiles,
+}: TagManagementDialogProps) {
+  const { t } = useTranslation();
+  const [selected, setSelected] = useState<string[]>([]);
+  const [destination, setDestination] = useState("");
+  const [isSaving, setIsSaving] = useState(false);
+
+  const tagCounts = useMemo(() => {
+    const counts = new Map<string, number>();
+    for (const profile of profiles) {
+      for (const tag of profile.tags ?? []) {
+        counts.set(tag, (counts.get(tag) ?? 0) + 1);
+      }
+    }
+    return [...counts.entries()].sort(([a], [b]) => a.localeCompare(b));
+  }, [profiles]);
+
+  useEffect(() => {
+    if (!isOpen) {
+      setSelected([]);
+      setDestination("");
+    }
+  }, [isOpen]);
+
+  const toggle = (tag: string, checked: boolean) => {
+    setSelected((prev) => {
+      const next = checked ? [...prev, tag] : prev.filter((s) => s !== tag);
+      if (next.length === 1) setDestination(next[0]);
+      return next;
+    });
+  };
+
+  const isMerge = selected.length > 1;
+  const target = destination.trim();
+  const canApply =
+    selected.length > 0 &&
+    target.length > 0 &&
+    !(selected.length === 1 && selected[0] === target);
+
+  const handleApply = useCallback(async () => {
+    setIsSaving(true);
+    try {
+      const changed = isMerge
+        ? await invoke<number>("merge_tags", {
+            sources: selected,
+            destination: target,
+          })
+        : await invoke<number>("rename_tag", {
+            from: selected[0],
+            to: target,
+          });
+      showSuccessToast(t("tags.management.updated", { count: changed }));
+      setSelected([]);
+      setDestination("");
+    } catch (error) {
+      showErrorToast(translateBackendError(t, error));
+    } finally {
+      setIsSaving(false);
+    }
+  }, [isMerge, selected, target, t]);
+
+  return (
+    <Dialog open={isOpen} onOpenChange={onClose}>
+      <DialogContent className="max-w-md">
+        <DialogHeader>
+          <DialogTitle>{t("tags.management.title")}</DialogTitle>
+          <DialogDescription>
+            {t("tags.management.description")}
+          </DialogDescription>
+        </DialogHeader>
+
+        <div className="space-y-4">
+          {tagCounts.length === 0 ? (
+            <p className="text-sm text-muted-foreground">
+              {t("tags.management.empty")}
+            </p>
+          ) : (
+            <FadingScrollArea className="max-h-64">
+              <div className="space-y-1">
+                {tagCounts.map(([tag, count]) => (
+                  <label
+                    key={tag}
+                    className="flex cursor-pointer items-center gap-2 rounded-md px-2 py-1.5 hover:bg-muted/50"
+                  >
+                    <Checkbox
+                      checked={selected.includes(tag)}
+                      onCheckedChange={(checked) => {
+                        toggle(tag, checked === true);
+                      }}
+                      disabled={isSaving}
+                    />
+                    <span className="flex-1 truncate text-sm">{tag}</span>
+                    <Badge variant="secondary">{count}</Badge>
+                  </label>
+                ))}
+              </div>
+            </FadingScrollArea>
+          )}
+
+          <div className="space-y-2">
+            <Label htmlFor="tag-destination">
+              {isMerge
+                ? t("tags.management.mergeInto")
+                : t("tags.management.newName")}
+            </Label>
+            <Input
+              id="tag-destination"
+              value={destination}
+              onChange={(e) => {
+                setDestination(e.target.value);
+              }}
+              onKeyDown={(e) => {
+                if (e.key === "Enter" && canApply) {
+                  void handleApply();
+                }
+              }}
+              disabled={isSaving || selected.length === 0}
+            />
+          </div>
+        </div>
+
+        <DialogFooter>
+          <RippleButton variant="outline" onClick={onClose} disabled={isSaving}>
+            {t("common.buttons.close")}
+          </RippleButton>
+          <LoadingButton
+            isLoading={isSaving}
+            onClick={() => void handleApply()}
+            disabled={!canApply}
+          >
+            {isMerge ? t("tags.management.merge") : t("tags.management.rename")}
+          </LoadingButton>
+        </DialogFooter>
+      </DialogContent>
+    </Dialog>
+  );
+}
@@ -5,7 +5,13 @@ import { emit } from "@tauri-apps/api/event";
 import { useCallback, useEffect, useState } from "react";
 import { useTranslation } from "react-i18next";
 import { toast } from "sonner";
+import {
+  type ExitExpectation,
+  ExitExpectationFields,
+  emptyExitExpectation,
+} from "@/components/exit-expectation-fields";
 import { LoadingButton } from "@/components/loading-button";
+import { Checkbox } from "@/components/ui/checkbox";
 import {
   Dialog,
   DialogContent,
@@ -79,15 +85,25 @@ export function VpnFormDialog({
   const [isSubmitting, setIsSubmitting] = useState(false);
   const [wireGuardForm, setWireGuardForm] =
     useState<WireGuardFormData>(defaultWireGuardForm);
+  const [autoDisconnect, setAutoDisconnect] = useState(false);
+  const [exitExpectation, setExitExpectation] =
+    useState<ExitExpectation>(emptyExitExpectation);
 
   const resetForms = useCallback(() => {
     setWireGuardForm(defaultWireGuardForm);
+    setAutoDisconnect(false);
+    setExitExpectation(emptyExitExpectation);
   }, []);
 
   useEffect(() => {
     if (isOpen) {
       if (editingVpn) {
         setWireGuardForm({ ...defaultWireGuardForm, name: editingVpn.name });
+        setAutoDisconnect(editingVpn.auto_disconnect ?? false);
+        setExitExpectation({
+          country: editingVpn.expected_country ?? "",
+          ipPrefix: editingVpn.expected_ip_prefix ?? "",
+        });
       } else {
         resetForms();
       }
@@ -115,6 +131,22 @@ export function VpnFormDialog({
           vpnId: editingVpn.id,
           name,
         });
+        if (autoDisconnect !== (editingVpn.auto_disconnect ?? false)) {
+          await invoke("update_vpn_auto_disconnect", {
+            vpnId: editingVpn.id,
+            autoDisconnect,
+          });
+        }
+        if (
+          exitExpectation.country !== (editingVpn.expected_country ?? "") ||
+          exitExpectation.ipPrefix !== (editingVpn.expected_ip_prefix ?? "")
+        ) {
+          await invoke("update_vpn_exit_expectation", {
+            vpnId: editingVpn.id,
+            expectedCountry: exitExpectation.country,
+            expectedIpPrefix: exitExpectation.ipPrefix,
+          });
+        }
         await emit("vpn-configs-changed");
         toast.success(t("vpns.form.updated"));
         onClose();
@@ -155,11 +187,24 @@ export function VpnFormDialog({
     setIsSubmitting(true);
     try {
       const configData = buildWireGuardConfig(wireGuardForm);
-      await invoke("create_vpn_config_manual", {
+      const created = await invoke<VpnConfig>("create_vpn_config_manual", {
         name: name.trim(),
         vpnType: "WireGuard",
         configData,
       });
+      if (autoDisconnect) {
+        await invoke("update_vpn_auto_disconnect", {
+          vpnId: created.id,
+          autoDisconnect,
+        });
+      }
+      if (exitExpectation.country || exitExpectation.ipPrefix) {
+        await invoke("update_vpn_exit_expectation", {
+          vpnId: created.id,
+          expectedCountry: exitExpectation.country,
+          expectedIpPrefix: exitExpectation.ipPrefix,
+        });
+      }
       await emit("vpn-configs-changed");
       toast.success(t("vpns.form.created"));
       onClose();
@@ -170,7 +215,14 @@ export function VpnFormDialog({
     } finally {
       setIsSubmitting(false);
     }
-  }, [editingVpn, wireGuardForm, onClose, t]);
+  }, [
+    editingVpn,
+    wireGuardForm,
+    autoDisconnect,
+    exitExpectation,
+    onClose,
+    t,
+  ]);
 
   const updateWireGuard = useCallback(
     (field: keyof WireGuardFormData, value: string) => {
@@ -347,6 +399,35 @@ export function VpnFormDialog({
                 </div>
               </>
             )}
+
+            <div className="flex items-start gap-x-3 rounded-lg border p-3">
+              <Checkbox
+                id="wg-auto-disconnect"
+                checked={autoDisconnect}
+                onCheckedChange={(checked) => {
+                  setAutoDisconnect(checked === true);
+                }}
+                disabled={isSubmitting}
+              />
+              <div className="space-y-1">
+                <Label
+                  htmlFor="wg-auto-disconnect"
+                  className="text-sm font-medium"
+                >
+                  {t("vpns.form.autoDisconnect")}
+                </Label>
+                <p className="text-xs text-muted-foreground">
+                  {t("vpns.form.autoDisconnectDescription")}
+                </p>
+              </div>
+            </div>
+
+            <ExitExpectationFields
+              idPrefix="wg"
+              value={exitExpectation}
+              onChange={setExitExpectation}
+              disabled={isSubmitting}
+            />
           </div>
         </ScrollArea>
 
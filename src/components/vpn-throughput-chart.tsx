@@ -0,0 +1,77 @@
+"use client";
+
+import { useTranslation } from "react-i18next";
+import { Area, AreaChart, ResponsiveContainer } from "recharts";
+import type { VpnThroughputPoint } from "@/types";
+
+interface VpnThroughputChartProps {
+  points: VpnThroughputPoint[];
+}
+
+function formatRate(bytesPerSec: number): string {
+  if (bytesPerSec < 1024) return `${Math.round(bytesPerSec)} B/s`;
+  if (bytesPerSec < 1024 * 1024) {
+    return `${(bytesPerSec / 1024).toFixed(1)} KB/s`;
+  }
+  return `${(bytesPerSec / (1024 * 1024)).toFixed(1)} MB/s`;
+}
+
+/** Sent and received rates of a connected tunnel, from `vpn-stats`. */
+export function VpnThroughputChart({ points }: VpnThroughputChartProps) {
+  const { t } = useTranslation();
+  const latest = points[points.length - 1];
+
+  if (!latest) {
+    return <span className="text-xs text-muted-foreground">—</span>;
+  }
+
+  return (
+    <div
+      className="flex w-full min-w-0 items-center gap-1.5"
+      title={t("vpns.throughput.tooltip", {
+        sent: formatRate(latest.sent_per_sec),
+        received: formatRate(latest.received_per_sec),
+      })}
+    >
+      <div className="pointer-events-none h-3 min-w-0 flex-1">
+        <ResponsiveContainer
+          width="100%"
+          height="100%"
+          minWidth={1}
+          minHeight={1}
+        >
+          <AreaChart
+            data={points}
+            margin={{ top: 0, right: 0, bottom: 0, left: 0 }}
+          >
+            <Area
+              type="monotone"
+              dataKey="received_per_sec"
+              stroke="var(--chart-1)"
+              strokeWidth={1}
+              fill="var(--chart-1)"
+              fillOpacity={0.3}
+              isAnimationActive={false}
+              dot={false}
+              activeDot={false}
+            />
+            <Area
+              type="monotone"
+              dataKey="sent_per_sec"
+              stroke="var(--chart-2)"
+              strokeWidth={1}
+              fill="var(--chart-2)"
+              fillOpacity={0.3}
+              isAnimationActive={false}
+              dot={false}
+              activeDot={false}
+            />
+          </AreaChart>
+        </ResponsiveContainer>
+      </div>
+      <span className="shrink-0 text-right text-xs whitespace-nowrap text-muted-foreground">
+        {formatRate(latest.sent_per_sec + latest.received_per_sec)}
+      </span>
+    </div>
+  );
+}
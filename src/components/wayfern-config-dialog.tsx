@@ -138,7 +138,6 @@ export function WayfernConfigDialog({
               crossOsUnlocked={crossOsUnlocked}
               limitedMode={!crossOsUnlocked}
               profileVersion={profile.version}
-              profileBrowser="wayfern"
             />
           </div>
         </ScrollArea>
@@ -34,7 +34,6 @@ interface WayfernConfigFormProps {
   crossOsUnlocked?: boolean;
   limitedMode?: boolean;
   profileVersion?: string;
-  profileBrowser?: string;
 }
 
 const isFingerprintEditingDisabled = (config: WayfernConfig): boolean => {
@@ -67,7 +66,6 @@ export function WayfernConfigForm({
   crossOsUnlocked = false,
   limitedMode = false,
   profileVersion,
-  profileBrowser,
 }: WayfernConfigFormProps) {
   const { t } = useTranslation();
   const [activeTab, setActiveTab] = useState(
@@ -82,13 +80,11 @@ export function WayfernConfigForm({
     if (!profileVersion) return;
     setIsGeneratingFingerprint(true);
     try {
-      const configJson = JSON.stringify(config);
-      const result = await invoke<string>("generate_sample_fingerprint", {
-        browser: profileBrowser ?? "wayfern",
-        version: profileVersion,
-        configJson,
-      });
-      onConfigChange("fingerprint", result);
+      const [fingerprint] = await invoke<Record<string, unknown>[]>(
+        "generate_fingerprint",
+        { version: profileVersion, config },
+      );
+      onConfigChange("fingerprint", JSON.stringify(fingerprint));
     } catch (error) {
       console.error("Failed to generate fingerprint:", error);
     } finally {
@@ -244,6 +240,22 @@ export function WayfernConfigForm({
         <p className="ml-6 text-sm text-muted-foreground">
           {t("fingerprint.generateRandomDescription")}
         </p>
+        <div className="flex items-center gap-x-2">
+          <Checkbox
+            id="reject-inconsistent"
+            checked={config.reject_inconsistent_fingerprints ?? false}
+            onCheckedChange={(checked) => {
+              onConfigChange("reject_inconsistent_fingerprints", checked);
+            }}
+            disabled={readOnly}
+          />
+          <Label htmlFor="reject-inconsistent" className="font-medium">
+            {t("fingerprint.rejectInconsistent")}
+          </Label>
+        </div>
+        <p className="ml-6 text-sm text-muted-foreground">
+          {t("fingerprint.rejectInconsistentDescription")}
+        </p>
       </div>
 
       {/* Automatic Location Configuration */}
@@ -259,6 +271,50 @@ export function WayfernConfigForm({
             {t("fingerprint.autoLocationDescription")}
           </Label>
         </div>
+        <div className="space-y-2">
+          <Label htmlFor="locale-override">
+            {t("fingerprint.localeOverride")}
+          </Label>
+          <Input
+            id="locale-override"
+            value={config.locale_override ?? ""}
+            onChange={(e) => {
+              onConfigChange(
+                "locale_override",
+                e.target.value.trim() || undefined,
+              );
+            }}
+            placeholder={t("common.placeholders.example", {
+              value: "de-DE, en-US",
+            })}
+            disabled={readOnly}
+          />
+          <p className="text-sm text-muted-foreground">
+            {t("fingerprint.localeOverrideDescription")}
+          </p>
+        </div>
+        <div className="space-y-2">
+          <Label htmlFor="timezone-override">
+            {t("fingerprint.timezoneOverride")}
+          </Label>
+          <Input
+            id="timezone-override"
+            value={config.timezone_override ?? ""}
+            onChange={(e) => {
+              onConfigChange(
+                "timezone_override",
+                e.target.value.trim() || undefined,
+              );
+            }}
+            placeholder={t("common.placeholders.example", {
+              value: "Asia/Tokyo",
+            })}
+            disabled={readOnly}
+          />
+          <p className="text-sm text-muted-foreground">
+            {t("fingerprint.timezoneOverrideDescription")}
+          </p>
+        </div>
       </div>
 
       <div
@@ -1200,6 +1256,22 @@ export function WayfernConfigForm({
               <p className="ml-6 text-sm text-muted-foreground">
                 {t("fingerprint.generateRandomDescription")}
               </p>
+              <div className="flex items-center gap-x-2">
+                <Checkbox
+                  id="reject-inconsistent-auto"
+                  checked={config.reject_inconsistent_fingerprints ?? false}
+                  onCheckedChange={(checked) => {
+                    onConfigChange("reject_inconsistent_fingerprints", checked);
+                  }}
+                  disabled={readOnly}
+                />
+                <Label htmlFor="reject-inconsistent-auto" className="font-medium">
+                  {t("fingerprint.rejectInconsistent")}
+                </Label>
+              </div>
+              <p className="ml-6 text-sm text-muted-foreground">
+                {t("fingerprint.rejectInconsistentDescription")}
+              </p>
             </div>
 
             {/* Automatic Location Configuration */}
@@ -0,0 +1,222 @@
+"use client";
+
+import { invoke } from "@tauri-apps/api/core";
+import {
+  open as openDialog,
+  save as saveDialog,
+} from "@tauri-apps/plugin-dialog";
+import { useCallback, useState } from "react";
+import { useTranslation } from "react-i18next";
+import { LoadingButton } from "@/components/loading-button";
+import { Checkbox } from "@/components/ui/checkbox";
+import {
+  Dialog,
+  DialogContent,
+  DialogDescription,
+  DialogFooter,
+  DialogHeader,
+  DialogTitle,
+} from "@/components/ui/dialog";
+import { Input } from "@/components/ui/input";
+import { Label } from "@/components/ui/label";
+import { translateBackendError } from "@/lib/backend-errors";
+import { showErrorToast, showSuccessToast } from "@/lib/toast-utils";
+import type { WorkspaceExportSummary, WorkspaceImportReport } from "@/types";
+import { RippleButton } from "./ui/ripple";
+
+const ARCHIVE_EXTENSION = "tgz";
+
+interface WorkspaceBackupDialogProps {
+  isOpen: boolean;
+  onClose: () => void;
+}
+
+export function WorkspaceBackupDialog({
+  isOpen,
+  onClose,
+}: WorkspaceBackupDialogProps) {
+  const { t } = useTranslation();
+  const [passphrase, setPassphrase] = useState("");
+  const [includeBrowserData, setIncludeBrowserData] = useState(false);
+  const [force, setForce] = useState(false);
+  const [busy, setBusy] = useState<"export" | "import" | null>(null);
+  const [report, setReport] = useState<WorkspaceImportReport | null>(null);
+
+  const handleExport = useCallback(async () => {
+    try {
+      const date = new Date().toISOString().slice(0, 10);
+      const path = await saveDialog({
+        defaultPath: `donut-workspace-${date}.${ARCHIVE_EXTENSION}`,
+        filters: [
+          {
+            name: t("workspaceBackup.fileType"),
+            extensions: [ARCHIVE_EXTENSION],
+          },
+        ],
+      });
+      if (!path) return;
+      setBusy("export");
+      const summary = await invoke<WorkspaceExportSummary>(
+        "export_workspace",
+        { path, passphrase, includeBrowserData },
+      );
+      showSuccessToast(
+        t("workspaceBackup.exported", {
+          profiles: summary.profiles,
+          proxies: summary.proxies,
+        }),
+      );
+    } catch (error) {
+      showErrorToast(translateBackendError(t, error));
+    } finally {
+      setBusy(null);
+    }
+  }, [includeBrowserData, passphrase, t]);
+
+  const handleImport = useCallback(async () => {
+    try {
+      const path = await openDialog({
+        multiple: false,
+        filters: [
+          {
+            name: t("workspaceBackup.fileType"),
+            extensions: [ARCHIVE_EXTENSION, "gz"],
+          },
+        ],
+      });
+      if (!path || typeof path !== "string") return;
+      setBusy("import");
+      const result = await invoke<WorkspaceImportReport>("import_workspace", {
+        path,
+        passphrase,
+        force,
+      });
+      setReport(result);
+      showSuccessToast(
+        t("workspaceBackup.imported", { count: result.imported.length }),
+      );
+    } catch (error) {
+      showErrorToast(translateBackendError(t, error));
+    } finally {
+      setBusy(null);
+    }
+  }, [force, passphrase, t]);
+
+  const handleClose = useCallback(() => {
+    if (busy) return;
+    setPassphrase("");
+    setIncludeBrowserData(false);
+    setForce(false);
+    setReport(null);
+    onClose();
+  }, [busy, onClose]);
+
+  return (
+    <Dialog open={isOpen} onOpenChange={handleClose}>
+      <DialogContent className="max-w-lg">
+        <DialogHeader>
+          <DialogTitle>{t("workspaceBackup.title")}</DialogTitle>
+          <DialogDescription>
+            {t("workspaceBackup.description")}
+          </DialogDescription>
+        </DialogHeader>
+
+        <div className="space-y-4">
+          <div className="space-y-2">
+            <Label htmlFor="workspace-passphrase">
+              {t("workspaceBackup.passphrase")}
+            </Label>
+            <Input
+              id="workspace-passphrase"
+              type="password"
+              value={passphrase}
+              onChange={(e) => {
+                setPassphrase(e.target.value);
+              }}
+            />
+            <p className="text-xs text-muted-foreground">
+              {t("workspaceBackup.passphraseDescription")}
+            </p>
+          </div>
+
+          <div className="flex items-start gap-x-3 rounded-lg border p-3">
+            <Checkbox
+              id="workspace-include-browser-data"
+              checked={includeBrowserData}
+              onCheckedChange={(checked) => {
+                setIncludeBrowserData(checked as boolean);
+              }}
+            />
+            <div className="space-y-1">
+              <Label htmlFor="workspace-include-browser-data">
+                {t("workspaceBackup.includeBrowserData")}
+              </Label>
+              <p className="text-xs text-muted-foreground">
+                {t("workspaceBackup.includeBrowserDataDescription")}
+              </p>
+            </div>
+          </div>
+
+          <div className="flex items-start gap-x-3 rounded-lg border p-3">
+            <Checkbox
+              id="workspace-force"
+              checked={force}
+              onCheckedChange={(checked) => {
+                setForce(checked as boolean);
+              }}
+            />
+            <div className="space-y-1">
+              <Label htmlFor="workspace-force">
+                {t("workspaceBackup.force")}
+              </Label>
+              <p className="text-xs text-muted-foreground">
+                {t("workspaceBackup.forceDescription")}
+              </p>
+            </div>
+          </div>
+
+          {report && (
+            <div className="space-y-1 rounded-md border bg-muted/40 p-3 text-sm">
+              <p>
+                {t("workspaceBackup.report", {
+                  imported: report.imported.length,
+                  skipped: report.skipped.length,
+                  failed: report.failed.length,
+                })}
+              </p>
+              {report.failed.map((failure) => (
+                <p
+                  key={`${failure.kind}-${failure.id}`}
+                  className="text-xs text-destructive"
+                >
+                  {failure.name}: {translateBackendError(t, failure.error)}
+                </p>
+              ))}
+            </div>
+          )}
+        </div>
+
+        <DialogFooter className="flex-col gap-2 sm:flex-row">
+          <RippleButton variant="outline" onClick={handleClose}>
+            {t("common.buttons.close")}
+          </RippleButton>
+          <LoadingButton
+            variant="outline"
+            isLoading={busy === "import"}
+            disabled={busy !== null || !passphrase}
+            onClick={() => void handleImport()}
+          >
+            {t("common.buttons.import")}
+          </LoadingButton>
+          <LoadingButton
+            isLoading={busy === "export"}
+            disabled={busy !== null || !passphrase}
+            onClick={() => void handleExport()}
+          >
+            {t("common.buttons.export")}
+          </LoadingButton>
+        </DialogFooter>
+      </DialogContent>
+    </Dialog>
+  );
+}
@@ -0,0 +1,176 @@
+"use client";
+
+import { invoke } from "@tauri-apps/api/core";
+import { useCallback, useEffect, useState } from "react";
+import { useTranslation } from "react-i18next";
+import { LoadingButton } from "@/components/loading-button";
+import { Badge } from "@/components/ui/badge";
+import { Checkbox } from "@/components/ui/checkbox";
+import {
+  Dialog,
+  DialogContent,
+  DialogDescription,
+  DialogFooter,
+  DialogHeader,
+  DialogTitle,
+} from "@/components/ui/dialog";
+import { Input } from "@/components/ui/input";
+import { Label } from "@/components/ui/label";
+import { translateBackendError } from "@/lib/backend-errors";
+import { showErrorToast, showSuccessToast } from "@/lib/toast-utils";
+import type { Workspace, WorkspaceList } from "@/types";
+import { RippleButton } from "./ui/ripple";
+
+interface WorkspacesDialogProps {
+  isOpen: boolean;
+  onClose: () => void;
+}
+
+export function WorkspacesDialog({ isOpen, onClose }: WorkspacesDialogProps) {
+  const { t } = useTranslation();
+  const [list, setList] = useState<WorkspaceList | null>(null);
+  const [name, setName] = useState("");
+  const [sharedBinaries, setSharedBinaries] = useState(true);
+  const [creating, setCreating] = useState(false);
+  const [switchingId, setSwitchingId] = useState<string | null>(null);
+
+  const loadWorkspaces = useCallback(async () => {
+    try {
+      setList(await invoke<WorkspaceList>("list_workspaces"));
+    } catch (error) {
+      showErrorToast(translateBackendError(t, error));
+    }
+  }, [t]);
+
+  useEffect(() => {
+    if (isOpen) void loadWorkspaces();
+  }, [isOpen, loadWorkspaces]);
+
+  const handleCreate = useCallback(async () => {
+    setCreating(true);
+    try {
+      const workspace = await invoke<Workspace>("create_workspace", {
+        name,
+        sharedBinaries,
+      });
+      showSuccessToast(t("workspaces.created", { name: workspace.name }));
+      setName("");
+      await loadWorkspaces();
+    } catch (error) {
+      showErrorToast(translateBackendError(t, error));
+    } finally {
+      setCreating(false);
+    }
+  }, [loadWorkspaces, name, sharedBinaries, t]);
+
+  const handleSwitch = useCallback(
+    async (workspaceId: string) => {
+      setSwitchingId(workspaceId);
+      try {
+        // The app restarts into the new workspace when this succeeds.
+        await invoke("switch_workspace", { workspaceId });
+      } catch (error) {
+        showErrorToast(translateBackendError(t, error));
+        setSwitchingId(null);
+      }
+    },
+    [t],
+  );
+
+  const handleClose = useCallback(() => {
+    if (switchingId) return;
+    setName("");
+    setSharedBinaries(true);
+    onClose();
+  }, [onClose, switchingId]);
+
+  return (
+    <Dialog open={isOpen} onOpenChange={handleClose}>
+      <DialogContent className="max-w-lg">
+        <DialogHeader>
+          <DialogTitle>{t("workspaces.title")}</DialogTitle>
+          <DialogDescription>{t("workspaces.description")}</DialogDescription>
+        </DialogHeader>
+
+        <div className="space-y-4">
+          <div className="space-y-2">
+            {list?.workspaces.map((workspace) => {
+              const isActive = workspace.id === list.active;
+              return (
+                <div
+                  key={workspace.id}
+                  className="flex items-center justify-between gap-2 rounded-md border p-2"
+                >
+                  <div className="flex min-w-0 items-center gap-2">
+                    <span className="truncate text-sm">{workspace.name}</span>
+                    {isActive && (
+                      <Badge variant="secondary">
+                        {t("workspaces.active")}
+                      </Badge>
+                    )}
+                  </div>
+                  {!isActive && (
+                    <LoadingButton
+                      size="sm"
+                      variant="outline"
+                      isLoading={switchingId === workspace.id}
+                      disabled={switchingId !== null}
+                      onClick={() => void handleSwitch(workspace.id)}
+                    >
+                      {t("workspaces.switch")}
+                    </LoadingButton>
+                  )}
+                </div>
+              );
+            })}
+            <p className="text-xs text-muted-foreground">
+              {t("workspaces.switchDescription")}
+            </p>
+          </div>
+
+          <div className="space-y-2">
+            <Label htmlFor="workspace-name">{t("workspaces.newName")}</Label>
+            <Input
+              id="workspace-name"
+              value={name}
+              onChange={(e) => {
+                setName(e.target.value);
+              }}
+            />
+          </div>
+
+          <div className="flex items-start gap-x-3 rounded-lg border p-3">
+            <Checkbox
+              id="workspace-shared-binaries"
+              checked={sharedBinaries}
+              onCheckedChange={(checked) => {
+                setSharedBinaries(checked as boolean);
+              }}
+            />
+            <div className="space-y-1">
+              <Label htmlFor="workspace-shared-binaries">
+                {t("workspaces.sharedBinaries")}
+              </Label>
+              <p className="text-xs text-muted-foreground">
+                {t("workspaces.sharedBinariesDescription")}
+              </p>
+            </div>
+          </div>
+        </div>
+
+        <DialogFooter className="flex-col gap-2 sm:flex-row">
+          <RippleButton variant="outline" onClick={handleClose}>
+            {t("common.buttons.close")}
+          </RippleButton>
+          <LoadingButton
+            isLoading={creating}
+            disabled={creating || !name.trim()}
+            onClick={() => void handleCreate()}
+          >
+            {t("workspaces.create")}
+          </LoadingButton>
+        </DialogFooter>
+      </DialogContent>
+    </Dialog>
+  );
+}
@@ -40,6 +40,24 @@ interface DownloadProgress {
   stage: string;
 }
 
+/** Payload of `browser-versions-updated`. */
+interface BrowserVersionsUpdated {
+  browser: string;
+  versions: BrowserVersionInfo[];
+  new_versions_count: number;
+}
+
+function toGithubReleases(versionInfos: BrowserVersionInfo[]) {
+  return versionInfos.map(
+    (versionInfo): GithubRelease => ({
+      tag_name: versionInfo.version,
+      assets: [],
+      published_at: versionInfo.date,
+      is_nightly: false,
+    }),
+  );
+}
+
 interface BrowserVersionsResult {
   versions: string[];
   new_versions_count?: number;
@@ -94,14 +112,7 @@ export function useBrowserDownload() {
       );
 
       // Convert BrowserVersionInfo to GithubRelease format for compatibility
-      const githubReleases: GithubRelease[] = versionInfos.map(
-        (versionInfo) => ({
-          tag_name: versionInfo.version,
-          assets: [],
-          published_at: versionInfo.date,
-          is_nightly: false,
-        }),
-      );
+      const githubReleases = toGithubReleases(versionInfos);
 
       setAvailableVersions(githubReleases);
       return githubReleases;
@@ -140,14 +151,7 @@ export function useBrowserDownload() {
       );
 
       // Convert BrowserVersionInfo to GithubRelease format for compatibility
-      const githubReleases: GithubRelease[] = versionInfos.map(
-        (versionInfo) => ({
-          tag_name: versionInfo.version,
-          assets: [],
-          published_at: versionInfo.date,
-          is_nightly: false,
-        }),
-      );
+      const githubReleases = toGithubReleases(versionInfos);
 
       setAvailableVersions(githubReleases);
 
@@ -290,6 +294,26 @@ export function useBrowserDownload() {
   // Legacy isDownloading for backwards compatibility
   const isDownloading = downloadingBrowsers.size > 0;
 
+  // Cached-first loads return the cached list at once; a background refresh
+  // replaces it here when newer data lands.
+  useEffect(() => {
+    let unlisten: (() => void) | null = null;
+    let disposed = false;
+    void listen<BrowserVersionsUpdated>(
+      "browser-versions-updated",
+      (event) => {
+        setAvailableVersions(toGithubReleases(event.payload.versions));
+      },
+    ).then((fn) => {
+      if (disposed) fn();
+      else unlisten = fn;
+    });
+    return () => {
+      disposed = true;
+      unlisten?.();
+    };
+  }, []);
+
   // Listen for download progress events (browsers) and GeoIP progress events
   useEffect(() => {
     let unlistenBrowser: (() => void) | null = null;
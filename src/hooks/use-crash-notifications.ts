@@ -0,0 +1,68 @@
+import { listen } from "@tauri-apps/api/event";
+import { useEffect } from "react";
+import i18n from "@/i18n";
+import { showErrorToast, showSuccessToast } from "@/lib/toast-utils";
+
+interface ProfileCrashedEvent {
+  id: string;
+  name: string;
+  reason: string | null;
+  restarting: boolean;
+  restart_limit_reached: boolean;
+  max_restarts_per_hour: number;
+}
+
+interface ProfileRestartedEvent {
+  id: string;
+  name: string;
+  reason: string | null;
+  restarts_this_hour: number;
+}
+
+/** Toasts for browsers that died on their own and for automatic restarts. */
+export function useCrashNotifications() {
+  useEffect(() => {
+    const unlistenCrashed = listen<ProfileCrashedEvent>(
+      "profile-crashed",
+      (event) => {
+        const crash = event.payload;
+        let description = crash.reason ?? undefined;
+        if (crash.restart_limit_reached) {
+          description = i18n.t("restartPolicy.toast.limitReached", {
+            max: crash.max_restarts_per_hour,
+          });
+        } else if (crash.restarting) {
+          description = i18n.t("restartPolicy.toast.restarting");
+        }
+        showErrorToast(
+          i18n.t("restartPolicy.toast.crashed", { name: crash.name }),
+          {
+            id: `profile-crashed-${crash.id}`,
+            description,
+            duration: crash.restart_limit_reached ? 15000 : undefined,
+          },
+        );
+      },
+    );
+    const unlistenRestarted = listen<ProfileRestartedEvent>(
+      "profile-restarted",
+      (event) => {
+        showSuccessToast(
+          i18n.t("restartPolicy.toast.restarted", {
+            name: event.payload.name,
+          }),
+          { id: `profile-crashed-${event.payload.id}` },
+        );
+      },
+    );
+
+    return () => {
+      void unlistenCrashed.then((fn) => {
+        fn();
+      });
+      void unlistenRestarted.then((fn) => {
+        fn();
+      });
+    };
+  }, []);
+}
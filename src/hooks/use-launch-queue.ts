@@ -0,0 +1,41 @@
+import { invoke } from "@tauri-apps/api/core";
+import { listen } from "@tauri-apps/api/event";
+import { useCallback, useEffect, useState } from "react";
+import type { QueuedLaunch } from "@/types";
+
+/** Launches waiting for a slot, keyed by profile id. */
+export function useLaunchQueue() {
+  const [queued, setQueued] = useState<Map<string, QueuedLaunch>>(new Map());
+
+  const apply = useCallback((launches: QueuedLaunch[]) => {
+    setQueued(new Map(launches.map((l) => [l.profile_id, l])));
+  }, []);
+
+  useEffect(() => {
+    void invoke<QueuedLaunch[]>("get_launch_queue")
+      .then(apply)
+      .catch((error: unknown) => {
+        console.error("Failed to load launch queue:", error);
+      });
+
+    const unlisten = listen<QueuedLaunch[]>("launch-queue-changed", (event) => {
+      apply(event.payload);
+    });
+
+    return () => {
+      void unlisten.then((fn) => {
+        fn();
+      });
+    };
+  }, [apply]);
+
+  const cancelQueuedLaunch = useCallback(async (profileId: string) => {
+    try {
+      await invoke<boolean>("cancel_queued_launch", { profileId });
+    } catch (error) {
+      console.error("Failed to cancel queued launch:", error);
+    }
+  }, []);
+
+  return { queued, cancelQueuedLaunch };
+}
@@ -4,8 +4,14 @@ import { useCallback, useEffect, useState } from "react";
 import i18n from "@/i18n";
 import type { BrowserProfile, GroupWithCount } from "@/types";
 
+interface UseProfileEventsOptions {
+  /** Also load archived profiles, returned in `archivedProfiles`. */
+  includeArchived?: boolean;
+}
+
 interface UseProfileEventsReturn {
   profiles: BrowserProfile[];
+  archivedProfiles: BrowserProfile[];
   groups: GroupWithCount[];
   runningProfiles: Set<string>;
   isLoading: boolean;
@@ -20,8 +26,13 @@ interface UseProfileEventsReturn {
  * This hook eliminates the need for manual UI refreshes by automatically
  * updating state when the backend emits profile change events.
  */
-export function useProfileEvents(): UseProfileEventsReturn {
+export function useProfileEvents({
+  includeArchived = false,
+}: UseProfileEventsOptions = {}): UseProfileEventsReturn {
   const [profiles, setProfiles] = useState<BrowserProfile[]>([]);
+  const [archivedProfiles, setArchivedProfiles] = useState<BrowserProfile[]>(
+    [],
+  );
   const [groups, setGroups] = useState<GroupWithCount[]>([]);
   const [runningProfiles, setRunningProfiles] = useState<Set<string>>(
     new Set(),
@@ -34,8 +45,10 @@ export function useProfileEvents(): UseProfileEventsReturn {
     try {
       const profileList = await invoke<BrowserProfile[]>(
         "list_browser_profiles",
+        { includeArchived },
       );
-      setProfiles(profileList);
+      setProfiles(profileList.filter((p) => !p.archived));
+      setArchivedProfiles(profileList.filter((p) => p.archived));
       setError(null);
     } catch (err: unknown) {
       console.error("Failed to load profiles:", err);
@@ -43,7 +56,7 @@ export function useProfileEvents(): UseProfileEventsReturn {
         i18n.t("errors.loadProfilesFailed", { error: JSON.stringify(err) }),
       );
     }
-  }, []);
+  }, [includeArchived]);
 
   // Load groups from backend
   const loadGroups = useCallback(async () => {
@@ -145,6 +158,7 @@ export function useProfileEvents(): UseProfileEventsReturn {
 
   return {
     profiles,
+    archivedProfiles,
     groups,
     runningProfiles,
     isLoading,
@@ -0,0 +1,44 @@
+import { invoke } from "@tauri-apps/api/core";
+import { listen } from "@tauri-apps/api/event";
+import { useCallback, useEffect, useState } from "react";
+import type { SavedView } from "@/types";
+
+/**
+ * Loads the saved table views and keeps them fresh when they change locally
+ * or arrive through sync. list_saved_views only reads the settings file, so
+ * calling it on every mount is fine.
+ */
+export function useSavedViews() {
+  const [savedViews, setSavedViews] = useState<SavedView[]>([]);
+
+  const loadSavedViews = useCallback(async () => {
+    try {
+      setSavedViews(await invoke<SavedView[]>("list_saved_views"));
+    } catch (err) {
+      console.error("Failed to load saved views:", err);
+    }
+  }, []);
+
+  useEffect(() => {
+    let unlisten: (() => void) | undefined;
+
+    const setup = async () => {
+      await loadSavedViews();
+      try {
+        unlisten = await listen("saved-views-changed", () => {
+          void loadSavedViews();
+        });
+      } catch (err) {
+        console.error("Failed to listen for saved view changes:", err);
+      }
+    };
+
+    void setup();
+
+    return () => {
+      if (unlisten) unlisten();
+    };
+  }, [loadSavedViews]);
+
+  return { savedViews, loadSavedViews };
+}
@@ -0,0 +1,36 @@
+import { invoke } from "@tauri-apps/api/core";
+import { listen } from "@tauri-apps/api/event";
+import { useCallback, useEffect, useState } from "react";
+import type { SyncQueueStatus } from "@/types";
+
+/** Changes waiting for the sync server to become reachable again. */
+export function useSyncQueue() {
+  const [status, setStatus] = useState<SyncQueueStatus>({
+    pending: [],
+    server_reachable: true,
+  });
+
+  const fetchStatus = useCallback(async () => {
+    try {
+      setStatus(await invoke<SyncQueueStatus>("get_sync_queue_status"));
+    } catch (error) {
+      console.error("Failed to load sync queue status:", error);
+    }
+  }, []);
+
+  useEffect(() => {
+    void fetchStatus();
+
+    const unlisten = listen<SyncQueueStatus>("sync-queue-changed", (event) => {
+      setStatus(event.payload);
+    });
+
+    return () => {
+      void unlisten.then((fn) => {
+        fn();
+      });
+    };
+  }, [fetchStatus]);
+
+  return { ...status, refetch: fetchStatus };
+}
@@ -0,0 +1,43 @@
+import { invoke } from "@tauri-apps/api/core";
+import { listen } from "@tauri-apps/api/event";
+import { useCallback, useEffect, useState } from "react";
+import type { SyncSubscriptionStatus } from "@/types";
+
+/** State of the live event stream from the sync server. */
+export function useSyncSubscription() {
+  const [status, setStatus] = useState<SyncSubscriptionStatus>({
+    state: "disconnected",
+    last_event_at: null,
+    connected_since: null,
+    failed_attempts: 0,
+  });
+
+  const fetchStatus = useCallback(async () => {
+    try {
+      setStatus(
+        await invoke<SyncSubscriptionStatus>("get_sync_subscription_status"),
+      );
+    } catch (error) {
+      console.error("Failed to load sync subscription status:", error);
+    }
+  }, []);
+
+  useEffect(() => {
+    void fetchStatus();
+
+    const unlisten = listen<SyncSubscriptionStatus>(
+      "sync-subscription-status",
+      (event) => {
+        setStatus(event.payload);
+      },
+    );
+
+    return () => {
+      void unlisten.then((fn) => {
+        fn();
+      });
+    };
+  }, [fetchStatus]);
+
+  return { ...status, refetch: fetchStatus };
+}
@@ -1,8 +1,10 @@
 import { invoke } from "@tauri-apps/api/core";
 import { useCallback, useRef, useState } from "react";
 import i18n from "@/i18n";
+import { translateBackendError } from "@/lib/backend-errors";
 import { getBrowserDisplayName } from "@/lib/browser-utils";
-import { dismissToast, showToast } from "@/lib/toast-utils";
+import { dismissToast, showErrorToast, showToast } from "@/lib/toast-utils";
+import type { BrowserUpdateResult } from "@/types";
 
 interface UpdateNotification {
   id: string;
@@ -35,6 +37,35 @@ export function useUpdateNotifications(
   // Track browser types being downloaded (not browser-version pairs)
   const activeDownloads = useRef<Set<string>>(new Set()); // Track browser types
 
+  const rollbackUpdate = useCallback(
+    async (browser: string) => {
+      try {
+        const result = await invoke<{
+          from_version: string;
+          updated_profiles: string[];
+        }>("rollback_browser_update", { browser });
+        showToast({
+          id: `auto-update-rolled-back-${browser}-${result.from_version}`,
+          type: "success",
+          title: i18n.t("versionUpdater.toast.rolledBack", {
+            browser: getBrowserDisplayName(browser),
+            version: result.from_version,
+          }),
+          description: i18n.t("versionUpdater.toast.rolledBackDescription", {
+            count: result.updated_profiles.length,
+          }),
+          duration: 6000,
+        });
+        if (onProfilesUpdated) {
+          await onProfilesUpdated();
+        }
+      } catch (error) {
+        showErrorToast(translateBackendError(i18n.t, error));
+      }
+    },
+    [onProfilesUpdated],
+  );
+
   const handleAutoUpdate = useCallback(
     async (browser: string, newVersion: string, notificationId: string) => {
       // Check if this browser type is already being downloaded
@@ -114,7 +145,10 @@ export function useUpdateNotifications(
           }
 
           // Complete the update with auto-update of profile versions
-          const updatedProfiles = await invoke<string[]>(
+          const {
+            updated_profiles: updatedProfiles,
+            skipped_pinned: skippedPinned,
+          } = await invoke<BrowserUpdateResult>(
             "complete_browser_update_with_auto_update",
             {
               browser,
@@ -122,10 +156,15 @@ export function useUpdateNotifications(
             },
           );
 
+          const pinnedNote =
+            skippedPinned > 0
+              ? ` ${i18n.t("versionUpdater.toast.pinnedSkipped", { count: skippedPinned })}`
+              : "";
+
           // Show success message based on whether profiles were updated
           if (updatedProfiles.length > 0) {
             const description =
-              updatedProfiles.length === 1
+              (updatedProfiles.length === 1
                 ? i18n.t("versionUpdater.toast.singleProfileUpdated", {
                     name: updatedProfiles[0],
                     version: newVersion,
@@ -133,7 +172,7 @@ export function useUpdateNotifications(
                 : i18n.t("versionUpdater.toast.multipleProfilesUpdated", {
                     count: updatedProfiles.length,
                     version: newVersion,
-                  });
+                  })) + pinnedNote;
 
             showToast({
               id: `auto-update-success-${browser}-${newVersion}`,
@@ -142,7 +181,11 @@ export function useUpdateNotifications(
                 browser: browserDisplayName,
               }),
               description,
-              duration: 6000,
+              duration: 15000,
+              action: {
+                label: i18n.t("versionUpdater.toast.revert"),
+                onClick: () => void rollbackUpdate(browser),
+              },
             });
           } else {
             showToast({
@@ -151,9 +194,10 @@ export function useUpdateNotifications(
               title: i18n.t("versionUpdater.toast.updateCompleted", {
                 browser: browserDisplayName,
               }),
-              description: i18n.t("versionUpdater.toast.versionAvailable", {
-                version: newVersion,
-              }),
+              description:
+                i18n.t("versionUpdater.toast.versionAvailable", {
+                  version: newVersion,
+                }) + pinnedNote,
               duration: 6000,
             });
           }
@@ -191,7 +235,7 @@ export function useUpdateNotifications(
         });
       }
     },
-    [onProfilesUpdated],
+    [onProfilesUpdated, rollbackUpdate],
   );
 
   const checkForUpdates = useCallback(async () => {
@@ -8,6 +8,7 @@ import {
   showErrorToast,
   showSuccessToast,
 } from "@/lib/toast-utils";
+import type { BrowserUpdateResult } from "@/types";
 
 interface VersionUpdateProgress {
   current_browser: string;
@@ -189,7 +190,10 @@ export function useVersionUpdater() {
                 }
 
                 // Complete the update with auto-update of profile versions
-                const updatedProfiles = await invoke<string[]>(
+                const {
+                  updated_profiles: updatedProfiles,
+                  skipped_pinned: skippedPinned,
+                } = await invoke<BrowserUpdateResult>(
                   "complete_browser_update_with_auto_update",
                   {
                     browser,
@@ -197,10 +201,15 @@ export function useVersionUpdater() {
                   },
                 );
 
+                const pinnedNote =
+                  skippedPinned > 0
+                    ? ` ${i18n.t("versionUpdater.toast.pinnedSkipped", { count: skippedPinned })}`
+                    : "";
+
                 // Show success message based on whether profiles were updated
                 if (updatedProfiles.length > 0) {
                   const description =
-                    updatedProfiles.length === 1
+                    (updatedProfiles.length === 1
                       ? i18n.t("versionUpdater.toast.singleProfileUpdated", {
                           name: updatedProfiles[0],
                           version: new_version,
@@ -208,7 +217,7 @@ export function useVersionUpdater() {
                       : i18n.t("versionUpdater.toast.multipleProfilesUpdated", {
                           count: updatedProfiles.length,
                           version: new_version,
-                        });
+                        })) + pinnedNote;
 
                   showSuccessToast(
                     i18n.t("versionUpdater.toast.updateCompleted", {
@@ -225,10 +234,10 @@ export function useVersionUpdater() {
                       browser: browserDisplayName,
                     }),
                     {
-                      description: i18n.t(
-                        "versionUpdater.toast.versionAvailable",
-                        { version: new_version },
-                      ),
+                      description:
+                        i18n.t("versionUpdater.toast.versionAvailable", {
+                          version: new_version,
+                        }) + pinnedNote,
                       duration: 6000,
                     },
                   );
@@ -1,8 +1,17 @@
 import { invoke } from "@tauri-apps/api/core";
 import { listen } from "@tauri-apps/api/event";
-import { useCallback, useEffect, useState } from "react";
+import { useCallback, useEffect, useRef, useState } from "react";
 import i18n from "@/i18n";
-import type { VpnConfig } from "@/types";
+import type { VpnConfig, VpnStatus, VpnThroughputPoint } from "@/types";
+
+// `vpn-stats` arrives every 10 seconds, so this keeps five minutes.
+const MAX_THROUGHPUT_POINTS = 30;
+
+interface TunnelTotals {
+  at: number;
+  sent: number;
+  received: number;
+}
 
 /**
  * Custom hook to manage VPN-related state and listen for backend events.
@@ -12,6 +21,10 @@ import type { VpnConfig } from "@/types";
 export function useVpnEvents() {
   const [vpnConfigs, setVpnConfigs] = useState<VpnConfig[]>([]);
   const [vpnUsage, setVpnUsage] = useState<Record<string, number>>({});
+  const [vpnThroughput, setVpnThroughput] = useState<
+    Record<string, VpnThroughputPoint[]>
+  >({});
+  const lastTotals = useRef<Record<string, TunnelTotals>>({});
   const [isLoading, setIsLoading] = useState(true);
   const [error, setError] = useState<string | null>(null);
 
@@ -44,6 +57,41 @@ export function useVpnEvents() {
     }
   }, [loadVpnUsage]);
 
+  const recordVpnStats = useCallback((statuses: VpnStatus[]) => {
+    const at = Date.now() / 1000;
+    const previous = lastTotals.current;
+    const next: Record<string, TunnelTotals> = {};
+    const points: Record<string, VpnThroughputPoint> = {};
+    for (const status of statuses) {
+      const sent = status.bytes_sent ?? 0;
+      const received = status.bytes_received ?? 0;
+      next[status.vpn_id] = { at, sent, received };
+      const last = previous[status.vpn_id];
+      // Counters restart with the tunnel; skip the sample after a reconnect.
+      if (!last || sent < last.sent || received < last.received) {
+        continue;
+      }
+      const elapsed = Math.max(at - last.at, 1);
+      points[status.vpn_id] = {
+        timestamp: Math.floor(at),
+        sent_per_sec: (sent - last.sent) / elapsed,
+        received_per_sec: (received - last.received) / elapsed,
+      };
+    }
+    lastTotals.current = next;
+    setVpnThroughput((current) => {
+      const updated: Record<string, VpnThroughputPoint[]> = {};
+      for (const vpnId of Object.keys(next)) {
+        const history = current[vpnId] ?? [];
+        const point = points[vpnId];
+        updated[vpnId] = point
+          ? [...history, point].slice(-MAX_THROUGHPUT_POINTS)
+          : history;
+      }
+      return updated;
+    });
+  }, []);
+
   const clearError = useCallback(() => {
     setError(null);
   }, []);
@@ -51,6 +99,8 @@ export function useVpnEvents() {
   useEffect(() => {
     let vpnConfigsUnlisten: (() => void) | undefined;
     let profilesUnlisten: (() => void) | undefined;
+    let statsUnlisten: (() => void) | undefined;
+    let statusUnlisten: (() => void) | undefined;
 
     const setupListeners = async () => {
       try {
@@ -63,6 +113,23 @@ export function useVpnEvents() {
         profilesUnlisten = await listen("profiles-changed", () => {
           void loadVpnUsage();
         });
+
+        statsUnlisten = await listen<VpnStatus[]>("vpn-stats", (event) => {
+          recordVpnStats(event.payload);
+        });
+
+        statusUnlisten = await listen<{ vpn_id: string; connected: boolean }>(
+          "vpn-status-changed",
+          (event) => {
+            if (event.payload.connected) return;
+            const vpnId = event.payload.vpn_id;
+            delete lastTotals.current[vpnId];
+            setVpnThroughput((current) => {
+              const { [vpnId]: _dropped, ...rest } = current;
+              return rest;
+            });
+          },
+        );
       } catch (err) {
         console.error("Failed to setup VPN event listeners:", err);
         setError(
@@ -80,12 +147,15 @@ export function useVpnEvents() {
     return () => {
       if (vpnConfigsUnlisten) vpnConfigsUnlisten();
       if (profilesUnlisten) profilesUnlisten();
+      if (statsUnlisten) statsUnlisten();
+      if (statusUnlisten) statusUnlisten();
     };
-  }, [loadVpnConfigs, loadVpnUsage]);
+  }, [loadVpnConfigs, loadVpnUsage, recordVpnStats]);
 
   return {
     vpnConfigs,
     vpnUsage,
+    vpnThroughput,
     isLoading,
     error,
     loadVpnConfigs,
@@ -12,15 +12,46 @@ export type BackendErrorCode =
   | "PROFILE_ALREADY_PROTECTED"
   | "PROFILE_RUNNING"
   | "PROFILE_EPHEMERAL"
+  | "PROFILE_ARCHIVED"
+  | "PROFILE_PASSWORD_PROTECTED"
   | "PROFILE_MISSING_SALT"
   | "PROFILE_LOCKED"
   | "INVALID_PROFILE_ID"
   | "PASSWORD_TOO_SHORT"
   | "INVALID_LAUNCH_HOOK_URL"
+  | "INVALID_STARTUP_URL"
+  | "UNSUPPORTED_PREFERENCES"
+  | "INVALID_METADATA_KEY"
+  | "METADATA_VALUE_TOO_LONG"
+  | "METADATA_TOO_LARGE"
+  | "INVALID_RESTART_LIMIT"
+  | "INVALID_MEMORY_LIMIT"
+  | "INVALID_DOH_RESOLVER_URL"
+  | "API_TOKEN_NOT_FOUND"
+  | "API_TOKEN_NAME_TAKEN"
+  | "API_TOKEN_SCOPES_EMPTY"
   | "COOKIE_DB_LOCKED"
   | "COOKIE_DB_UNAVAILABLE"
   | "SELF_HOSTED_REQUIRES_LOGOUT"
   | "PROXY_NOT_FOUND"
+  | "PROXY_IN_USE"
+  | "PROXY_POOL_NOT_FOUND"
+  | "PROXY_POOL_EMPTY"
+  | "PROXY_POOL_IN_USE"
+  | "PROXY_CHAIN_TOO_SHORT"
+  | "PROXY_CHAIN_UNSUPPORTED_HOP"
+  | "PROXY_CHAIN_INVALID_HOP"
+  | "PROXY_CHAIN_CLIENT_CERT"
+  | "INVALID_PROXY_HOST"
+  | "PROXY_HOST_ZONE_ID"
+  | "PROXY_CLIENT_CERT_INCOMPLETE"
+  | "PROXY_CLIENT_CERT_REQUIRES_HTTPS"
+  | "PROXY_CLIENT_CERT_UNREADABLE"
+  | "PROXY_CLIENT_CERT_INVALID"
+  | "PROXY_CLIENT_KEY_ENCRYPTED"
+  | "PROXY_CLIENT_KEY_NOT_PKCS8"
+  | "PROXY_CLIENT_CERT_KEY_MISMATCH"
+  | "PROXY_SOURCE_INVALID_URL"
   | "GROUP_NOT_FOUND"
   | "GROUP_ALREADY_EXISTS"
   | "NAME_CANNOT_BE_EMPTY"
@@ -28,13 +59,27 @@ export type BackendErrorCode =
   | "VPN_NOT_FOUND"
   | "EXTENSION_NOT_FOUND"
   | "EXTENSION_GROUP_NOT_FOUND"
+  | "EXTENSION_SOURCE_INVALID"
+  | "EXTENSION_DIRECTORY_NOT_FOUND"
+  | "EXTENSION_MANIFEST_INVALID"
+  | "EXTENSION_MANIFEST_V2_UNSUPPORTED"
   | "CANNOT_MODIFY_CLOUD_MANAGED_PROXY"
   | "SYNC_LOCKED_BY_PROFILE"
   | "SYNC_NOT_CONFIGURED"
+  | "SYNC_SERVER_UNREACHABLE"
   | "FINGERPRINT_REQUIRES_PRO"
   | "PROXY_NOT_WORKING"
   | "PROXY_PAYMENT_REQUIRED"
   | "VPN_NOT_WORKING"
+  | "VPN_CONNECT_FAILED"
+  | "EXIT_LOCATION_MISMATCH"
+  | "EXIT_CHECK_FAILED"
+  | "INVALID_EXPECTED_COUNTRY"
+  | "INVALID_EXPECTED_IP_PREFIX"
+  | "LAUNCH_CANCELLED"
+  | "WEBRTC_MODE_PROFILE_RUNNING"
+  | "INVALID_WEBRTC_IP"
+  | "WEBRTC_EXIT_IP_FAILED"
   | "CAMOUFOX_IMPORT_DEPRECATED"
   | "PROXY_SIDECAR_VERSION_MISMATCH"
   | "UPDATE_CHECKSUMS_UNAVAILABLE"
@@ -44,6 +89,14 @@ export type BackendErrorCode =
   | "PROFILE_NAME_EXISTS"
   | "IMPORT_SOURCE_NOT_FOUND"
   | "IMPORT_NO_ITEMS"
+  | "IMPORT_UNKNOWN_FORMAT"
+  | "IMPORT_UNSUPPORTED_PROXY_TYPE"
+  | "IMPORT_TIMEZONE_IGNORED"
+  | "IMPORT_OS_REQUIRES_PRO"
+  | "IMPORT_PROXY_FAILED"
+  | "IMPORT_COOKIES_FAILED"
+  | "IMPORT_NO_CATEGORIES"
+  | "IMPORT_CATEGORY_NOT_PORTABLE"
   | "BROWSER_NOT_DOWNLOADED"
   | "ARCHIVE_EXTRACTION_FAILED"
   | "UNSUPPORTED_ARCHIVE_FORMAT"
@@ -54,6 +107,41 @@ export type BackendErrorCode =
   | "UNSUPPORTED_DNS_RULES_FORMAT"
   | "DNS_RULES_SAVE_FAILED"
   | "DNS_RULES_EXPORT_FAILED"
+  | "ROLLBACK_UNAVAILABLE"
+  | "BROWSER_CHECKSUM_MISMATCH"
+  | "BROWSER_SIGNATURE_INVALID"
+  | "BROWSER_VERSION_NOT_FOUND"
+  | "BROWSER_VERSION_IN_USE"
+  | "BROWSER_VERSION_RUNNING"
+  | "PROFILE_NOT_RUNNING"
+  | "PROFILE_RUNNING_EXTERNALLY"
+  | "COOKIE_KEY_NOT_PORTABLE"
+  | "CDP_UNAVAILABLE"
+  | "CDP_PORT_IN_USE"
+  | "SAVED_VIEW_NOT_FOUND"
+  | "SAVED_VIEW_NAME_EXISTS"
+  | "E2E_PASSWORD_INCORRECT"
+  | "NO_E2E_PASSWORD_SET"
+  | "SNAPSHOT_NOT_FOUND"
+  | "WORKSPACE_PASSPHRASE_REQUIRED"
+  | "WORKSPACE_PASSPHRASE_INVALID"
+  | "WORKSPACE_ARCHIVE_INVALID"
+  | "WORKSPACE_ARCHIVE_UNSUPPORTED"
+  | "WORKSPACE_NAME_EXISTS"
+  | "WORKSPACE_NOT_FOUND"
+  | "WORKSPACE_SWITCH_BLOCKED"
+  | "PREFLIGHT_FAILED"
+  | "INVALID_LOCALE"
+  | "LOCALE_CONFLICTS_WITH_FINGERPRINT"
+  | "INVALID_TIMEZONE"
+  | "API_LISTEN_UNSUPPORTED"
+  | "API_SOCKET_IN_USE"
+  | "API_SERVER_NOT_RUNNING"
+  | "OBSERVER_MODE"
+  | "INVALID_SHORTCUT"
+  | "SHORTCUT_IN_USE"
+  | "SHORTCUT_UNAVAILABLE"
+  | "WINDOW_FOCUS_FAILED"
   | "INTERNAL_ERROR";
 
 export interface BackendError {
@@ -84,6 +172,23 @@ export function parseBackendError(err: unknown): BackendError | null {
   return null;
 }
 
+/** `params` of `WORKSPACE_SWITCH_BLOCKED`, which aren't plain strings. */
+interface WorkspaceSwitchBlockers {
+  running_profiles?: string[];
+  api_server?: boolean;
+  mcp_server?: boolean;
+}
+
+function describeSwitchBlockers(
+  t: TFunction,
+  blockers: WorkspaceSwitchBlockers | undefined,
+): string {
+  const items = [...(blockers?.running_profiles ?? [])];
+  if (blockers?.api_server) items.push(t("workspaces.blockerApiServer"));
+  if (blockers?.mcp_server) items.push(t("workspaces.blockerMcpServer"));
+  return items.join(", ");
+}
+
 /**
  * Translate a backend error to a localized string. Falls back to the raw
  * message if the error isn't a structured backend error.
@@ -112,6 +217,12 @@ export function translateBackendError(t: TFunction, err: unknown): string {
       return t("backendErrors.profileRunning");
     case "PROFILE_EPHEMERAL":
       return t("backendErrors.profileEphemeral");
+    case "PROFILE_PASSWORD_PROTECTED":
+      return t("backendErrors.profilePasswordProtected");
+    case "PROFILE_ARCHIVED":
+      return t("backendErrors.profileArchived", {
+        name: parsed.params?.name ?? "",
+      });
     case "PROFILE_MISSING_SALT":
       return t("backendErrors.profileMissingSalt");
     case "PROFILE_LOCKED":
@@ -124,6 +235,44 @@ export function translateBackendError(t: TFunction, err: unknown): string {
     }
     case "INVALID_LAUNCH_HOOK_URL":
       return t("backendErrors.invalidLaunchHookUrl");
+    case "API_TOKEN_NOT_FOUND":
+      return t("backendErrors.apiTokenNotFound");
+    case "API_TOKEN_NAME_TAKEN":
+      return t("backendErrors.apiTokenNameTaken", {
+        name: parsed.params?.name ?? "",
+      });
+    case "API_TOKEN_SCOPES_EMPTY":
+      return t("backendErrors.apiTokenScopesEmpty");
+    case "INVALID_STARTUP_URL":
+      return t("backendErrors.invalidStartupUrl", {
+        url: parsed.params?.url ?? "",
+      });
+    case "UNSUPPORTED_PREFERENCES":
+      return t("backendErrors.unsupportedPreferences", {
+        keys: parsed.params?.keys ?? "",
+      });
+    case "INVALID_METADATA_KEY":
+      return t("backendErrors.invalidMetadataKey", {
+        key: parsed.params?.key ?? "",
+      });
+    case "METADATA_VALUE_TOO_LONG":
+      return t("backendErrors.metadataValueTooLong", {
+        key: parsed.params?.key ?? "",
+      });
+    case "METADATA_TOO_LARGE":
+      return t("backendErrors.metadataTooLarge", {
+        maxKeys: parsed.params?.maxKeys ?? "",
+      });
+    case "INVALID_RESTART_LIMIT":
+      return t("backendErrors.invalidRestartLimit", {
+        max: parsed.params?.max ?? "",
+      });
+    case "INVALID_MEMORY_LIMIT":
+      return t("backendErrors.invalidMemoryLimit", {
+        min: parsed.params?.min ?? "",
+      });
+    case "INVALID_DOH_RESOLVER_URL":
+      return t("backendErrors.invalidDohResolverUrl");
     case "COOKIE_DB_LOCKED":
       return t("backendErrors.cookieDbLocked");
     case "COOKIE_DB_UNAVAILABLE":
@@ -132,6 +281,67 @@ export function translateBackendError(t: TFunction, err: unknown): string {
       return t("backendErrors.selfHostedRequiresLogout");
     case "PROXY_NOT_FOUND":
       return t("backendErrors.proxyNotFound");
+    case "PROXY_IN_USE":
+      return t("backendErrors.proxyInUse", {
+        count: Number(parsed.params?.count ?? 0),
+      });
+    case "PROXY_POOL_NOT_FOUND":
+      return t("backendErrors.proxyPoolNotFound");
+    case "PROXY_POOL_EMPTY":
+      return t("backendErrors.proxyPoolEmpty", {
+        name: parsed.params?.name ?? "",
+      });
+    case "PROXY_POOL_IN_USE":
+      return t("backendErrors.proxyPoolInUse", {
+        count: Number(parsed.params?.count ?? 0),
+      });
+    case "PROXY_CHAIN_TOO_SHORT":
+      return t("backendErrors.proxyChainTooShort");
+    case "PROXY_CHAIN_UNSUPPORTED_HOP":
+      return t("backendErrors.proxyChainUnsupportedHop", {
+        hop: parsed.params?.hop ?? "",
+        type: parsed.params?.type ?? "",
+      });
+    case "PROXY_CHAIN_INVALID_HOP":
+      return t("backendErrors.proxyChainInvalidHop", {
+        hop: parsed.params?.hop ?? "",
+      });
+    case "PROXY_CHAIN_CLIENT_CERT":
+      return t("backendErrors.proxyChainClientCert", {
+        hop: parsed.params?.hop ?? "",
+      });
+    case "INVALID_PROXY_HOST":
+      return t("backendErrors.invalidProxyHost", {
+        host: parsed.params?.host ?? "",
+      });
+    case "PROXY_HOST_ZONE_ID":
+      return t("backendErrors.proxyHostZoneId", {
+        host: parsed.params?.host ?? "",
+      });
+    case "PROXY_CLIENT_CERT_INCOMPLETE":
+      return t("backendErrors.proxyClientCertIncomplete");
+    case "PROXY_CLIENT_CERT_REQUIRES_HTTPS":
+      return t("backendErrors.proxyClientCertRequiresHttps");
+    case "PROXY_CLIENT_CERT_UNREADABLE":
+      return t("backendErrors.proxyClientCertUnreadable", {
+        path: parsed.params?.path ?? "",
+      });
+    case "PROXY_CLIENT_CERT_INVALID":
+      return t("backendErrors.proxyClientCertInvalid", {
+        path: parsed.params?.path ?? "",
+      });
+    case "PROXY_CLIENT_KEY_ENCRYPTED":
+      return t("backendErrors.proxyClientKeyEncrypted", {
+        path: parsed.params?.path ?? "",
+      });
+    case "PROXY_CLIENT_KEY_NOT_PKCS8":
+      return t("backendErrors.proxyClientKeyNotPkcs8", {
+        path: parsed.params?.path ?? "",
+      });
+    case "PROXY_CLIENT_CERT_KEY_MISMATCH":
+      return t("backendErrors.proxyClientCertKeyMismatch");
+    case "PROXY_SOURCE_INVALID_URL":
+      return t("backendErrors.proxySourceInvalidUrl");
     case "GROUP_NOT_FOUND":
       return t("backendErrors.groupNotFound");
     case "GROUP_ALREADY_EXISTS":
@@ -147,6 +357,20 @@ export function translateBackendError(t: TFunction, err: unknown): string {
       return t("backendErrors.vpnNotFound");
     case "EXTENSION_NOT_FOUND":
       return t("backendErrors.extensionNotFound");
+    case "EXTENSION_SOURCE_INVALID":
+      return t("backendErrors.extensionSourceInvalid");
+    case "EXTENSION_DIRECTORY_NOT_FOUND":
+      return t("backendErrors.extensionDirectoryNotFound", {
+        path: parsed.params?.path ?? "",
+      });
+    case "EXTENSION_MANIFEST_INVALID":
+      return t("backendErrors.extensionManifestInvalid", {
+        detail: parsed.params?.detail ?? "",
+      });
+    case "EXTENSION_MANIFEST_V2_UNSUPPORTED":
+      return t("backendErrors.extensionManifestV2Unsupported", {
+        name: parsed.params?.name ?? "",
+      });
     case "EXTENSION_GROUP_NOT_FOUND":
       return t("backendErrors.extensionGroupNotFound");
     case "CANNOT_MODIFY_CLOUD_MANAGED_PROXY":
@@ -155,6 +379,8 @@ export function translateBackendError(t: TFunction, err: unknown): string {
       return t("backendErrors.syncLockedByProfile");
     case "SYNC_NOT_CONFIGURED":
       return t("backendErrors.syncNotConfigured");
+    case "SYNC_SERVER_UNREACHABLE":
+      return t("backendErrors.syncServerUnreachable");
     case "FINGERPRINT_REQUIRES_PRO":
       return t("backendErrors.fingerprintRequiresPro");
     case "PROXY_NOT_WORKING":
@@ -163,6 +389,44 @@ export function translateBackendError(t: TFunction, err: unknown): string {
       return t("backendErrors.proxyPaymentRequired");
     case "VPN_NOT_WORKING":
       return t("backendErrors.vpnNotWorking");
+    case "VPN_CONNECT_FAILED":
+      return t("backendErrors.vpnConnectFailed", {
+        name: parsed.params?.name ?? "",
+        error: parsed.params?.error ?? "",
+      });
+    case "EXIT_LOCATION_MISMATCH":
+      return t("backendErrors.exitLocationMismatch", {
+        name: parsed.params?.name ?? "",
+        expected: parsed.params?.expected ?? "",
+        actual: parsed.params?.actual ?? "",
+      });
+    case "EXIT_CHECK_FAILED":
+      return t("backendErrors.exitCheckFailed", {
+        name: parsed.params?.name ?? "",
+        error: parsed.params?.error ?? "",
+      });
+    case "INVALID_EXPECTED_COUNTRY":
+      return t("backendErrors.invalidExpectedCountry", {
+        value: parsed.params?.value ?? "",
+      });
+    case "INVALID_EXPECTED_IP_PREFIX":
+      return t("backendErrors.invalidExpectedIpPrefix", {
+        value: parsed.params?.value ?? "",
+      });
+    case "LAUNCH_CANCELLED":
+      return t("backendErrors.launchCancelled");
+    case "WEBRTC_MODE_PROFILE_RUNNING":
+      return t("backendErrors.webrtcModeProfileRunning", {
+        name: parsed.params?.name ?? "",
+      });
+    case "INVALID_WEBRTC_IP":
+      return t("backendErrors.invalidWebrtcIp", {
+        value: parsed.params?.value ?? "",
+      });
+    case "WEBRTC_EXIT_IP_FAILED":
+      return t("backendErrors.webrtcExitIpFailed", {
+        error: parsed.params?.error ?? "",
+      });
     case "CAMOUFOX_IMPORT_DEPRECATED":
       return t("backendErrors.camoufoxImportDeprecated");
     case "PROXY_SIDECAR_VERSION_MISMATCH":
@@ -187,6 +451,32 @@ export function translateBackendError(t: TFunction, err: unknown): string {
       return t("backendErrors.importSourceNotFound");
     case "IMPORT_NO_ITEMS":
       return t("backendErrors.importNoItems");
+    case "IMPORT_UNKNOWN_FORMAT":
+      return t("backendErrors.importUnknownFormat");
+    case "IMPORT_UNSUPPORTED_PROXY_TYPE":
+      return t("backendErrors.importUnsupportedProxyType", {
+        type: parsed.params?.type ?? "",
+      });
+    case "IMPORT_TIMEZONE_IGNORED":
+      return t("backendErrors.importTimezoneIgnored");
+    case "IMPORT_OS_REQUIRES_PRO":
+      return t("backendErrors.importOsRequiresPro", {
+        os: parsed.params?.os ?? "",
+      });
+    case "IMPORT_PROXY_FAILED":
+      return t("backendErrors.importProxyFailed", {
+        detail: parsed.params?.detail ?? "",
+      });
+    case "IMPORT_COOKIES_FAILED":
+      return t("backendErrors.importCookiesFailed", {
+        detail: parsed.params?.detail ?? "",
+      });
+    case "IMPORT_NO_CATEGORIES":
+      return t("backendErrors.importNoCategories");
+    case "IMPORT_CATEGORY_NOT_PORTABLE":
+      return t("backendErrors.importCategoryNotPortable", {
+        category: t(`importProfile.categories.${parsed.params?.category}`),
+      });
     case "BROWSER_NOT_DOWNLOADED":
       return t("backendErrors.browserNotDownloaded", {
         browser: parsed.params?.browser ?? "",
@@ -211,8 +501,122 @@ export function translateBackendError(t: TFunction, err: unknown): string {
       return t("backendErrors.dnsRulesSaveFailed");
     case "DNS_RULES_EXPORT_FAILED":
       return t("backendErrors.dnsRulesExportFailed");
+    case "BROWSER_CHECKSUM_MISMATCH":
+      return t("backendErrors.browserChecksumMismatch", {
+        browser: parsed.params?.browser ?? "",
+        version: parsed.params?.version ?? "",
+      });
+    case "BROWSER_SIGNATURE_INVALID":
+      return t("backendErrors.browserSignatureInvalid", {
+        browser: parsed.params?.browser ?? "",
+        version: parsed.params?.version ?? "",
+      });
+    case "BROWSER_VERSION_NOT_FOUND":
+      return t("backendErrors.browserVersionNotFound");
+    case "BROWSER_VERSION_IN_USE":
+      return t("backendErrors.browserVersionInUse");
+    case "BROWSER_VERSION_RUNNING":
+      return t("backendErrors.browserVersionRunning");
+    case "PROFILE_NOT_RUNNING":
+      return t("backendErrors.profileNotRunning");
+    case "PROFILE_RUNNING_EXTERNALLY":
+      return t("backendErrors.profileRunningExternally", {
+        pid: parsed.params?.pid ?? "",
+      });
+    case "COOKIE_KEY_NOT_PORTABLE":
+      return t("backendErrors.cookieKeyNotPortable");
+    case "CDP_UNAVAILABLE":
+      return t("backendErrors.cdpUnavailable");
+    case "CDP_PORT_IN_USE":
+      return t("backendErrors.cdpPortInUse", {
+        port: parsed.params?.port ?? "",
+      });
+    case "SAVED_VIEW_NOT_FOUND":
+      return t("backendErrors.savedViewNotFound");
+    case "SAVED_VIEW_NAME_EXISTS":
+      return t("backendErrors.savedViewNameExists", {
+        name: parsed.params?.name ?? "",
+      });
+    case "ROLLBACK_UNAVAILABLE":
+      return t("backendErrors.rollbackUnavailable", {
+        browser: parsed.params?.browser ?? "",
+      });
     case "CLEAR_ON_CLOSE_UNAVAILABLE":
       return t("backendErrors.clearOnCloseUnavailable");
+    case "E2E_PASSWORD_INCORRECT":
+      return t("backendErrors.e2ePasswordIncorrect");
+    case "NO_E2E_PASSWORD_SET":
+      return t("backendErrors.noE2ePasswordSet");
+    case "SNAPSHOT_NOT_FOUND":
+      return t("backendErrors.snapshotNotFound");
+    case "WORKSPACE_PASSPHRASE_REQUIRED":
+      return t("backendErrors.workspacePassphraseRequired");
+    case "WORKSPACE_PASSPHRASE_INVALID":
+      return t("backendErrors.workspacePassphraseInvalid");
+    case "WORKSPACE_ARCHIVE_INVALID":
+      return t("backendErrors.workspaceArchiveInvalid");
+    case "WORKSPACE_ARCHIVE_UNSUPPORTED":
+      return t("backendErrors.workspaceArchiveUnsupported");
+    case "WORKSPACE_NAME_EXISTS":
+      return t("backendErrors.workspaceNameExists", {
+        name: parsed.params?.name ?? "",
+      });
+    case "WORKSPACE_NOT_FOUND":
+      return t("backendErrors.workspaceNotFound");
+    case "PREFLIGHT_FAILED":
+      return t("backendErrors.preflightFailed", {
+        checks: parsed.params?.checks ?? "",
+      });
+    case "WORKSPACE_SWITCH_BLOCKED":
+      return t("backendErrors.workspaceSwitchBlocked", {
+        blockers: describeSwitchBlockers(
+          t,
+          parsed.params as unknown as WorkspaceSwitchBlockers,
+        ),
+      });
+    case "INVALID_LOCALE":
+      return t("backendErrors.invalidLocale", {
+        locale: parsed.params?.locale ?? "",
+      });
+    case "LOCALE_CONFLICTS_WITH_FINGERPRINT":
+      return t("backendErrors.localeConflictsWithFingerprint", {
+        locale: parsed.params?.locale ?? "",
+      });
+    case "INVALID_TIMEZONE":
+      return t("backendErrors.invalidTimezone", {
+        timezone: parsed.params?.timezone ?? "",
+        suggestions: parsed.params?.suggestions ?? "",
+      });
+    case "API_LISTEN_UNSUPPORTED":
+      return t("backendErrors.apiListenUnsupported", {
+        mode: t(
+          parsed.params?.mode === "unix"
+            ? "integrations.apiTransport.unix"
+            : "integrations.apiTransport.windowsPipe",
+        ),
+      });
+    case "API_SOCKET_IN_USE":
+      return t("backendErrors.apiSocketInUse", {
+        path: parsed.params?.path ?? "",
+      });
+    case "API_SERVER_NOT_RUNNING":
+      return t("backendErrors.apiServerNotRunning");
+    case "OBSERVER_MODE":
+      return t("backendErrors.observerMode");
+    case "INVALID_SHORTCUT":
+      return t("backendErrors.invalidShortcut", {
+        accelerator: parsed.params?.accelerator ?? "",
+      });
+    case "SHORTCUT_IN_USE":
+      return t("backendErrors.shortcutInUse", {
+        profile: parsed.params?.profile ?? "",
+      });
+    case "SHORTCUT_UNAVAILABLE":
+      return t("backendErrors.shortcutUnavailable", {
+        accelerator: parsed.params?.accelerator ?? "",
+      });
+    case "WINDOW_FOCUS_FAILED":
+      return t("backendErrors.windowFocusFailed");
     case "INTERNAL_ERROR":
       return t("backendErrors.internal", {
         detail: parsed.params?.detail ?? "",
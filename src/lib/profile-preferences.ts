@@ -0,0 +1,41 @@
+/**
+ * Chromium preference paths a profile may pin, mirroring
+ * `SUPPORTED_PREFERENCES` in `src-tauri/src/profile/preferences.rs`. The
+ * backend rejects any other key, so the editor only offers these.
+ */
+export const SUPPORTED_PREFERENCES = [
+  "alternate_error_pages.enabled",
+  "autofill.credit_card_enabled",
+  "autofill.profile_enabled",
+  "bookmark_bar.show_on_all_tabs",
+  "browser.show_home_button",
+  "credentials_enable_service",
+  "download.default_directory",
+  "download.prompt_for_download",
+  "enable_do_not_track",
+  "profile.block_third_party_cookies",
+  "profile.cookie_controls_mode",
+  "profile.default_content_setting_values.geolocation",
+  "profile.default_content_setting_values.images",
+  "profile.default_content_setting_values.javascript",
+  "profile.default_content_setting_values.media_stream_camera",
+  "profile.default_content_setting_values.media_stream_mic",
+  "profile.default_content_setting_values.notifications",
+  "profile.default_content_setting_values.popups",
+  "profile.password_manager_enabled",
+  "safebrowsing.enabled",
+  "search.suggest_enabled",
+  "translate.enabled",
+  "webrtc.ip_handling_policy",
+  "webrtc.multiple_routes_enabled",
+  "webrtc.nonproxied_udp_enabled",
+] as const;
+
+/** Parse an editor value as JSON, falling back to the raw text as a string. */
+export function parsePreferenceValue(raw: string): unknown {
+  try {
+    return JSON.parse(raw);
+  } catch {
+    return raw;
+  }
+}
@@ -0,0 +1,74 @@
+import type { BrowserProfile, SavedViewFilter } from "@/types";
+
+/** Columns a saved view may hide; the rest are always shown. */
+export const SAVED_VIEW_COLUMNS = [
+  "tags",
+  "note",
+  "proxy",
+  "ext",
+  "dns",
+  "sync",
+] as const;
+
+export function matchesSavedViewFilter(
+  profile: BrowserProfile,
+  filter: SavedViewFilter,
+  runningProfiles: Set<string>,
+): boolean {
+  if (filter.tags.some((tag) => !profile.tags?.includes(tag))) return false;
+  if (filter.group_id) {
+    const groupId = profile.group_id ?? "default";
+    if (groupId !== filter.group_id) return false;
+  }
+  if (filter.browser && profile.browser !== filter.browser) return false;
+  if (
+    filter.running != null &&
+    runningProfiles.has(profile.id) !== filter.running
+  ) {
+    return false;
+  }
+  if (filter.has_proxy != null) {
+    const hasProxy = Boolean(profile.proxy_id || profile.vpn_id);
+    if (hasProxy !== filter.has_proxy) return false;
+  }
+  return matchesMetadata(profile, filter.metadata ?? {});
+}
+
+/** Whether the profile holds every key in `expected` with exactly that value. */
+export function matchesMetadata(
+  profile: BrowserProfile,
+  expected: Record<string, string>,
+): boolean {
+  return Object.entries(expected).every(
+    ([key, value]) => profile.metadata?.[key] === value,
+  );
+}
+
+/** Parses `key=value, key2=value2` as typed into a filter input. */
+export function parseMetadataPairs(text: string): Record<string, string> {
+  const pairs: Record<string, string> = {};
+  for (const part of text.split(",")) {
+    const eq = part.indexOf("=");
+    if (eq <= 0) continue;
+    const key = part.slice(0, eq).trim();
+    if (key) pairs[key] = part.slice(eq + 1).trim();
+  }
+  return pairs;
+}
+
+export function formatMetadataPairs(pairs?: Record<string, string>): string {
+  return Object.entries(pairs ?? {})
+    .map(([key, value]) => `${key}=${value}`)
+    .join(", ");
+}
+
+/**
+ * A search box query of the form `metadata.<key>=<value>`, the same syntax
+ * the local API accepts for list filters.
+ */
+export function parseMetadataQuery(
+  query: string,
+): Record<string, string> | null {
+  const match = /^metadata\.([^=\s]+)=(.*)$/.exec(query.trim());
+  return match ? { [match[1]]: match[2].trim() } : null;
+}
@@ -8,3 +8,10 @@ export function cn(...inputs: ClassValue[]) {
 export function sleep(ms: number) {
   return new Promise((resolve) => setTimeout(resolve, ms));
 }
+
+/** `host:port`, bracketing IPv6 literals the way URLs write them. */
+export function formatHostPort(host: string, port: number) {
+  return host.includes(":") && !host.startsWith("[")
+    ? `[${host}]:${port}`
+    : `${host}:${port}`;
+}
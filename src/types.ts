@@ -4,6 +4,9 @@ export interface ProxySettings {
   port: number;
   username?: string;
   password?: string;
+  // PEM client certificate and PKCS#8 key for an "https" proxy
+  client_cert_path?: string;
+  client_key_path?: string;
 }
 
 export interface TableSortingSettings {
@@ -11,6 +14,29 @@ export interface TableSortingSettings {
   direction: string; // "asc" or "desc"
 }
 
+export interface SavedViewFilter {
+  tags: string[]; // Profile must carry all of these tags
+  group_id?: string | null;
+  browser?: string | null;
+  running?: boolean | null;
+  has_proxy?: boolean | null; // Proxy or VPN assigned
+  metadata?: Record<string, string>; // Every key must hold exactly this value
+}
+
+export type ProfileMetadataUpdate =
+  | { mode: "set"; metadata: Record<string, string> }
+  | { mode: "merge"; metadata: Record<string, string> }
+  | { mode: "delete"; keys: string[] };
+
+export interface SavedView {
+  id: string;
+  name: string;
+  filter: SavedViewFilter;
+  sorting: TableSortingSettings;
+  visible_columns?: string[] | null; // null shows every column
+  updated_at?: number;
+}
+
 export interface BrowserProfile {
   id: string; // UUID of the profile
   name: string;
@@ -18,8 +44,11 @@ export interface BrowserProfile {
   version: string;
   proxy_id?: string; // Reference to stored proxy
   vpn_id?: string; // Reference to stored VPN config
+  /** Proxy pool a member is picked from at each launch. */
+  proxy_pool_id?: string | null;
   launch_hook?: string;
   process_id?: number;
+  cdp_port?: number;
   last_launch?: number;
   release_type: string;
   wayfern_config?: WayfernConfig; // Wayfern configuration
@@ -42,6 +71,196 @@ export interface BrowserProfile {
   created_at?: number;
   dns_blocklist?: string;
   password_protected?: boolean;
+  startup_urls?: string[];
+  session_restore?: SessionRestore;
+  /** Chromium preferences pinned for this profile, keyed by preference path. */
+  preference_overrides?: Record<string, unknown>;
+  metadata?: Record<string, string>; // Free-form key/value fields
+  /** Keep the profile on its current browser version; auto-updates skip it. */
+  pin_browser_version?: boolean;
+  restart_policy?: RestartPolicy;
+  dns_mode?: DnsMode;
+  exit_mismatch_action?: ExitMismatchAction;
+  resource_limits?: ResourceLimits;
+  /** Hidden from the profile list and never launched until unarchived. */
+  archived?: boolean;
+  /** Limits the running browser was launched with; unset when stopped. */
+  applied_resource_limits?: AppliedResourceLimits | null;
+}
+
+/** When the app relaunches a profile whose browser exited on its own. */
+export type RestartMode = "never" | "on_crash" | "always";
+
+export interface RestartPolicy {
+  mode: RestartMode;
+  max_restarts_per_hour: number;
+}
+
+/** Where a profile's browser resolves hostnames. */
+export type DnsMode =
+  | { mode: "system" }
+  | { mode: "proxy_remote" }
+  | { mode: "doh"; resolver_url: string };
+
+/** How a profile's browser exposes WebRTC; unset keeps the browser default.
+ * A mock without `ip` reports the exit IP found at launch. */
+export type WebRtcMode =
+  | { mode: "disabled" }
+  | { mode: "relay_only" }
+  | { mode: "mock"; ip?: string | null };
+
+/** What a launch does when the proxy or VPN exits in an unexpected place. */
+export type ExitMismatchAction = "warn" | "block";
+
+/** OS scheduling priority of a profile's browser and its child processes. */
+export type ProcessPriority = "low" | "normal" | "high";
+
+/** Unset fields fall back to the app-wide default limits. */
+export interface ResourceLimits {
+  priority?: ProcessPriority | null;
+  memory_limit_mb?: number | null;
+}
+
+/** What a launch actually enforced. */
+export interface AppliedResourceLimits {
+  priority?: ProcessPriority | null;
+  priority_applied: boolean;
+  memory_limit_mb?: number | null;
+  memory_limit_applied: boolean;
+  /** `systemd_scope`, `cgroupfs` or `job_object`. */
+  mechanism?: string | null;
+  errors: string[];
+}
+
+/** Payload of `exit-mismatch`, emitted when a profile launched anyway. */
+export interface ExitMismatch {
+  profile_id: string;
+  profile_name: string;
+  route_name: string;
+  expected_country?: string | null;
+  expected_ip_prefix?: string | null;
+  exit_ip?: string | null;
+  exit_country?: string | null;
+  /** Set when the exit could not be resolved at all. */
+  error?: string | null;
+}
+
+/** Who asked for a launch; interactive launches leave the queue first. */
+export type LaunchPriority = "interactive" | "background";
+
+/** A launch waiting for a slot, from `launch-queue-changed`. */
+export interface QueuedLaunch {
+  profile_id: string;
+  /** Launches that will start before this one. */
+  ahead: number;
+  priority: LaunchPriority;
+}
+
+export type LaunchPhase =
+  | "proxy_start"
+  | "geoip_lookup"
+  | "fingerprint"
+  | "config_build"
+  | "process_spawn"
+  | "cdp_ready";
+
+export interface PhaseTiming {
+  phase: LaunchPhase;
+  duration_ms: number;
+}
+
+/** From `get_last_launch_timings`; phases in the order they ran. */
+export interface LaunchTimings {
+  profile_id: string;
+  started_at: number;
+  total_ms: number;
+  phases: PhaseTiming[];
+}
+
+export type ProfileExitKind = "user_closed" | "killed" | "crashed" | "unknown";
+
+/** From `get_profile_exit_history`, newest first. */
+export interface ProfileExit {
+  timestamp: number;
+  exit_kind: ProfileExitKind;
+  exit_code: number | null;
+  signal: number | null;
+  uptime_secs: number | null;
+}
+
+/** Payload of `launch-slow`: the slowest phase over the threshold. */
+export interface SlowLaunch {
+  profile_id: string;
+  profile_name: string;
+  phase: LaunchPhase;
+  duration_ms: number;
+  threshold_ms: number;
+}
+
+export type ExtensionLoadFailureReason =
+  | "missing"
+  | "unpack_failed"
+  | "manifest_invalid"
+  | "manifest_v2"
+  | "unsupported_path"
+  | "not_loaded";
+
+export interface ExtensionLoadFailure {
+  extension_id: string;
+  name: string;
+  reason: ExtensionLoadFailureReason;
+  detail?: string | null;
+}
+
+/** Payload of `extensions-load-failed`, emitted after a launch. */
+export interface ExtensionsLoadFailed {
+  profile_id: string;
+  profile_name: string;
+  failures: ExtensionLoadFailure[];
+}
+
+/** Result of moving a browser's profiles onto a newly installed version. */
+export interface BrowserUpdateResult {
+  updated_profiles: string[];
+  skipped_pinned: number;
+}
+
+/** What a profile opens on launch when no explicit URL is given. */
+export type SessionRestore = "homepage" | "restore" | "urls";
+
+export type ApiScope =
+  | "profiles:read"
+  | "profiles:write"
+  | "profiles:launch"
+  | "proxies:read"
+  | "proxies:write"
+  | "settings:read"
+  | "settings:write"
+  | "admin";
+
+export interface ApiTokenInfo {
+  id: string;
+  name: string;
+  scopes: ApiScope[];
+  created_at: number;
+}
+
+/** Transport the local API is configured to use (`api_listen`). */
+export type ApiListen =
+  | { mode: "tcp" }
+  | { mode: "unix"; path: string }
+  | { mode: "windows_pipe"; name: string };
+
+/** Where the running API server can be reached. */
+export type ApiEndpoint =
+  | { mode: "tcp"; port: number }
+  | { mode: "unix"; path: string }
+  | { mode: "windows_pipe"; path: string };
+
+export interface ApiConnectionInfo {
+  endpoint: ApiEndpoint;
+  base_url: string;
+  token?: string | null;
 }
 
 export interface Extension {
@@ -58,6 +277,22 @@ export interface Extension {
   description?: string;
   author?: string;
   homepage_url?: string;
+  source?: ExtensionSource | null;
+  unpacked_path?: string | null;
+}
+
+export type ExtensionSource =
+  | { kind: "chrome_web_store"; id: string }
+  | { kind: "amo"; slug: string }
+  | { kind: "url"; url: string };
+
+export interface ExtensionUpdateResult {
+  extension_id: string;
+  name: string;
+  status: "up_to_date" | "updated" | "deferred" | "failed";
+  current_version?: string | null;
+  available_version?: string | null;
+  error?: string | null;
 }
 
 export interface ExtensionGroup {
@@ -68,6 +303,7 @@ export interface ExtensionGroup {
   updated_at: number;
   sync_enabled?: boolean;
   last_sync?: number;
+  protected?: boolean;
 }
 
 export type SyncMode = "Disabled" | "Regular" | "Encrypted";
@@ -79,6 +315,89 @@ export interface SyncSettings {
   sync_token?: string;
 }
 
+/** A sync held back because the server couldn't be reached. */
+export interface QueuedSyncItem {
+  entity_type:
+    | "profile"
+    | "proxy"
+    | "proxy_pool"
+    | "group"
+    | "vpn"
+    | "extension"
+    | "extension_group";
+  entity_id: string;
+  operation: "sync" | "sync_metadata";
+  queued_at: number;
+}
+
+export interface SyncQueueStatus {
+  pending: QueuedSyncItem[];
+  server_reachable: boolean;
+}
+
+export type SyncSubscriptionState =
+  | "disconnected"
+  | "connected"
+  | "reconnecting"
+  | "degraded";
+
+/** Live connection to the sync server's event stream. Times are unix secs. */
+export interface SyncSubscriptionStatus {
+  state: SyncSubscriptionState;
+  last_event_at?: number | null;
+  connected_since?: number | null;
+  failed_attempts: number;
+}
+
+export type PreflightCheckKind =
+  | "wayfern_terms"
+  | "binary"
+  | "executable"
+  | "proxy"
+  | "vpn"
+  | "geoip"
+  | "disk_space"
+  | "profile_locks";
+
+export type PreflightStatus = "pass" | "warn" | "fail" | "skipped";
+
+/** Named after the command that performs the fix. */
+export type PreflightFix =
+  | "accept_wayfern_terms"
+  | "download_browser"
+  | "download_geoip_database"
+  | "cleanup_orphan_processes";
+
+export interface PreflightCheck {
+  check: PreflightCheckKind;
+  status: PreflightStatus;
+  detail: string | null;
+  fixable_by: PreflightFix | null;
+}
+
+/** Counts from a pass of `cleanup_orphan_processes` / `orphans-cleaned`. */
+export interface OrphanCleanupSummary {
+  browsers_killed: number;
+  workers_killed: number;
+  locks_removed: number;
+}
+
+/** What the next sync of a profile would transfer, from a dry run. */
+export interface SyncPreview {
+  remote_manifest_found: boolean;
+  upload_files: number;
+  upload_bytes: number;
+  download_files: number;
+  download_bytes: number;
+  delete_local_files: number;
+  delete_remote_files: number;
+  largest_files: {
+    path: string;
+    size: number;
+    direction: "upload" | "download";
+  }[];
+}
+
 /**
  * Capability/limit set derived from the plan by the backend. Features are gated
  * on these flags instead of a single "is paid?" check, so a plan like the future
@@ -168,6 +487,52 @@ export interface StoredProxy {
   geo_region?: string;
   geo_city?: string;
   geo_isp?: string;
+  /** Proxies dialed in order; the last is the exit mirrored in `proxy_settings`. */
+  chain?: ProxySettings[] | null;
+  /** Proxy source the proxy was imported from, if any. */
+  source_id?: string | null;
+  /** Dropped from its source's list but kept because a profile uses it. */
+  is_stale?: boolean;
+  /** ISO country code the exit is expected in; checked before launch. */
+  expected_country?: string | null;
+  /** CIDR block or leading part of the address the exit is expected in. */
+  expected_ip_prefix?: string | null;
+}
+
+export type PoolSelectionPolicy =
+  | "round_robin"
+  | "random"
+  | "least_recently_used"
+  | "sticky_per_profile";
+
+/** Stored proxies a profile can reference as a whole; see `proxy_pool_id`. */
+export interface ProxyPool {
+  id: string;
+  name: string;
+  proxy_ids: string[];
+  policy: PoolSelectionPolicy;
+  sync_enabled?: boolean;
+  last_sync?: number | null;
+  updated_at?: number | null;
+}
+
+export interface ProxySource {
+  id: string;
+  url: string;
+  name_prefix: string;
+  refresh_interval_mins?: number | null;
+  last_refreshed_at?: number | null;
+  last_error?: string | null;
+  created_at: number;
+}
+
+export interface ProxySourceRefreshResult {
+  source_id: string;
+  added: number;
+  removed: number;
+  stale: number;
+  unchanged: number;
+  errors: { line: string; reason: string }[];
 }
 
 export interface LocationItem {
@@ -180,22 +545,36 @@ export interface ProfileGroup {
   name: string;
   sync_enabled?: boolean;
   last_sync?: number;
+  protected?: boolean;
 }
 
 export interface GroupWithCount {
   id: string;
   name: string;
   count: number;
+  archived_count?: number;
   sync_enabled?: boolean;
   last_sync?: number;
+  protected?: boolean;
 }
 
+export type ImportCategory =
+  | "cookies"
+  | "logins"
+  | "bookmarks"
+  | "history"
+  | "extensions";
+
 export interface DetectedProfile {
   browser: string;
   name: string;
   path: string;
   description: string;
   mapped_browser: string;
+  size_bytes: number;
+  data_categories: ImportCategory[];
+  /** Categories bound to the source machine (keychain-encrypted logins). */
+  non_portable_categories: ImportCategory[];
 }
 
 export interface ImportProfileItem {
@@ -205,6 +584,8 @@ export interface ImportProfileItem {
   /** Mutually exclusive with `vpn_id`; the importer rejects setting both. */
   proxy_id?: string | null;
   vpn_id?: string | null;
+  /** Copy only these categories; omitted copies the whole profile. */
+  categories?: ImportCategory[] | null;
 }
 
 export interface ProfileImportItemResult {
@@ -213,6 +594,8 @@ export interface ProfileImportItemResult {
   status: "imported" | "skipped" | "failed";
   profile_id: string | null;
   error: string | null;
+  /** Structured codes for exported data that could not be carried over. */
+  warnings?: string[];
 }
 
 export interface ProfileImportBatchResult {
@@ -222,6 +605,75 @@ export interface ProfileImportBatchResult {
   results: ProfileImportItemResult[];
 }
 
+export interface ExternalProfileImportResult {
+  name: string;
+  source_file: string;
+  format: "adspower" | "multilogin" | null;
+  status: "imported" | "failed";
+  profile_id: string | null;
+  proxy_id: string | null;
+  cookies_imported: number;
+  warnings: string[];
+  error: string | null;
+}
+
+export interface ExternalImportResult {
+  imported_count: number;
+  failed_count: number;
+  proxies_created: number;
+  results: ExternalProfileImportResult[];
+}
+
+export type WorkspaceEntityKind =
+  | "profile"
+  | "group"
+  | "proxy"
+  | "proxy_pool"
+  | "vpn_config"
+  | "extension"
+  | "extension_group";
+
+export interface WorkspaceEntity {
+  kind: WorkspaceEntityKind;
+  id: string;
+  name: string;
+}
+
+export interface WorkspaceImportFailure extends WorkspaceEntity {
+  error: string;
+}
+
+export interface WorkspaceImportReport {
+  imported: WorkspaceEntity[];
+  skipped: WorkspaceEntity[];
+  failed: WorkspaceImportFailure[];
+  settings_applied: boolean;
+}
+
+export interface WorkspaceExportSummary {
+  path: string;
+  profiles: number;
+  groups: number;
+  proxies: number;
+  proxy_pools: number;
+  vpn_configs: number;
+  extensions: number;
+  includes_browser_data: boolean;
+  size_bytes: number;
+}
+
+export interface Workspace {
+  id: string;
+  name: string;
+  shared_binaries: boolean;
+  created_at: number;
+}
+
+export interface WorkspaceList {
+  active: string;
+  workspaces: Workspace[];
+}
+
 export interface ArchiveScanResult {
   extracted_dir: string;
   profiles: DetectedProfile[];
@@ -232,7 +684,38 @@ export interface ProfileImportProgress {
   completed: number;
   index: number;
   name: string;
-  status: "importing" | "imported" | "skipped" | "failed";
+  status: "importing" | "copying" | "imported" | "skipped" | "failed";
+  category?: ImportCategory;
+}
+
+export interface MissingBinary {
+  browser: string;
+  version: string;
+  /** Names of the profiles waiting on this version. */
+  profiles: string[];
+}
+
+export interface BinaryEnsureProgress {
+  browser: string;
+  version: string;
+  status:
+    | "queued"
+    | "downloading"
+    | "extracting"
+    | "done"
+    | "failed"
+    | "skipped";
+  percentage: number | null;
+  total_bytes: number | null;
+  error: string | null;
+}
+
+export interface EnsureBinariesSummary {
+  downloaded: MissingBinary[];
+  failed: { browser: string; version: string; error: string }[];
+  /** Not started because the run was cancelled. */
+  skipped: MissingBinary[];
+  geoip_downloaded: boolean;
 }
 
 export interface BrowserReleaseTypes {
@@ -280,6 +763,22 @@ export interface WayfernConfig {
   randomize_fingerprint_on_launch?: boolean; // Generate new fingerprint on every launch
   os?: WayfernOS; // Operating system for fingerprint generation
   geo_proxy_signature?: string; // Internal: routing the fingerprint's location was computed for
+  locale_override?: string; // BCP 47 locale or ordered list ("de-DE, en-US")
+  timezone_override?: string; // IANA zone pinned regardless of the exit IP
+  gpu_tier?: "low" | "mid" | "high"; // GPU class the WebGL renderer is sampled from
+  reject_inconsistent_fingerprints?: boolean; // Re-sample fingerprints failing error-level quality checks
+  webrtc_mode?: WebRtcMode | null;
+}
+
+export interface FingerprintFinding {
+  check: string;
+  severity: "warning" | "error";
+  message: string;
+}
+
+export interface FingerprintReport {
+  score: number;
+  findings: FingerprintFinding[];
 }
 
 // Wayfern fingerprint config - matches the C++ FingerprintData structure
@@ -565,6 +1064,9 @@ export interface VpnConfig {
   last_used?: number;
   sync_enabled?: boolean;
   last_sync?: number;
+  auto_disconnect?: boolean;
+  expected_country?: string | null;
+  expected_ip_prefix?: string | null;
 }
 
 export interface VpnImportResult {
@@ -583,3 +1085,106 @@ export interface VpnStatus {
   bytes_received?: number;
   last_handshake?: number;
 }
+
+/** Tunnel throughput between two `vpn-stats` events. */
+export interface VpnThroughputPoint {
+  /** Epoch seconds. */
+  timestamp: number;
+  sent_per_sec: number;
+  received_per_sec: number;
+}
+
+/** A record from the in-memory log buffer (`get_recent_logs`). */
+export interface LogRecord {
+  /** Epoch milliseconds. */
+  timestamp: number;
+  level: string;
+  target: string;
+  message: string;
+}
+
+/** Who started an audited action. */
+export type AuditActor =
+  | { kind: "gui" }
+  | { kind: "api"; token_name: string }
+  | { kind: "mcp"; tool: string };
+
+/** A record from the audit log (`get_audit_log`), newest first. */
+export interface AuditRecord {
+  /** Epoch seconds. */
+  timestamp: number;
+  actor: AuditActor;
+  action: string;
+  entity_type: string;
+  entity_id: string | null;
+  outcome: "success" | "failure";
+  error: string | null;
+}
+
+export interface ProfileDeletionFailure {
+  profile_id: string;
+  /** Null when the ID didn't match any profile. */
+  profile_name: string | null;
+  error: string;
+}
+
+/** Result of `delete_selected_profiles`. */
+export interface BulkDeleteResult {
+  deleted: string[];
+  failed: ProfileDeletionFailure[];
+}
+
+export interface ProfileRef {
+  id: string;
+  name: string;
+}
+
+/** Profiles referencing a stored proxy, VPN or group. */
+export interface EntityUsage {
+  id: string;
+  profiles: ProfileRef[];
+  any_running: boolean;
+  any_sync_enabled: boolean;
+}
+
+export interface ProfileSnapshot {
+  id: string;
+  profile_id: string;
+  label: string | null;
+  created_at: number;
+  browser: string;
+  browser_version: string;
+  size_bytes: number;
+  encrypted: boolean;
+}
+
+/** An installed browser version, from `list_downloaded_browsers`. */
+export interface DownloadedBrowser {
+  browser: string;
+  version: string;
+  size_bytes: number;
+  last_used: number | null;
+  profiles: ProfileRef[];
+  in_use: boolean;
+}
+
+export interface BinaryRemoval {
+  browser: string;
+  version: string;
+  size_bytes: number;
+  reason: "unused" | "superseded" | "deleted";
+}
+
+export interface BinaryCleanupRecord {
+  timestamp: number;
+  freed_bytes: number;
+  removed: BinaryRemoval[];
+}
+
+export interface BinariesDiskUsage {
+  total_bytes: number;
+  unused_bytes: number;
+  version_count: number;
+  unused_count: number;
+  cleanups: BinaryCleanupRecord[];
+}